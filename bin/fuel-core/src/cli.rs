@@ -1,13 +1,16 @@
 use clap::Parser;
+use fuel_core::admin_api::ports::LogFilterPort;
 use std::{
     env,
     path::PathBuf,
     str::FromStr,
+    sync::OnceLock,
 };
 use tracing_subscriber::{
     filter::EnvFilter,
     layer::SubscriberExt,
     registry,
+    reload,
     Layer,
 };
 
@@ -16,10 +19,20 @@ use dotenvy::dotenv;
 
 lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
+    pub static ref DEFAULT_SNAPSHOT_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("snapshots");
 }
 
+pub mod chain_config;
+pub mod db;
+#[cfg(feature = "parquet")]
+pub mod export;
+mod json_log;
+mod otlp;
+pub mod p2p;
+pub mod replay;
 pub mod run;
 pub mod snapshot;
+pub mod test_vector;
 
 #[derive(Parser, Debug)]
 #[clap(
@@ -38,10 +51,24 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Db(db::Command),
+    #[cfg(feature = "parquet")]
+    Export(export::Command),
+    ChainConfig(chain_config::Command),
+    Replay(replay::Command),
+    P2p(p2p::Command),
+    TestVector(test_vector::Command),
 }
 
 pub const LOG_FILTER: &str = "RUST_LOG";
 pub const HUMAN_LOGGING: &str = "HUMAN_LOGGING";
+/// The spans are exported to the OTLP collector only if the endpoint is set.
+pub const OTLP_ENDPOINT: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+pub const OTLP_SERVICE_NAME: &str = "OTEL_SERVICE_NAME";
+
+/// The handle to replace the log filter after the logging is initialized.
+static LOG_FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, registry::Registry>> =
+    OnceLock::new();
 
 #[cfg(feature = "env")]
 fn init_environment() -> Option<PathBuf> {
@@ -60,6 +87,8 @@ pub async fn init_logging() -> anyhow::Result<()> {
         }
         None => EnvFilter::new("info"),
     };
+    let (filter, handle) = reload::Layer::new(filter);
+    let _ = LOG_FILTER_HANDLE.set(handle);
 
     let human_logging = env::var_os(HUMAN_LOGGING)
         .map(|s| {
@@ -68,38 +97,73 @@ pub async fn init_logging() -> anyhow::Result<()> {
         })
         .unwrap_or(true);
 
-    let layer = tracing_subscriber::fmt::Layer::default().with_writer(std::io::stderr);
-
     let fmt = if human_logging {
         // use pretty logs
-        layer
+        tracing_subscriber::fmt::Layer::default()
+            .with_writer(std::io::stderr)
             .with_ansi(true)
             .with_level(true)
             .with_line_number(true)
             .boxed()
     } else {
-        // use machine parseable structured logs
-        layer
-            // disable terminal colors
-            .with_ansi(false)
-            .with_level(true)
-            .with_line_number(true)
-            // use json
-            .json()
-            .boxed()
+        // use machine parseable structured logs with the stable schema
+        json_log::JsonLayer::new(std::io::stderr).boxed()
+    };
+
+    let otlp = match env::var(OTLP_ENDPOINT) {
+        Ok(endpoint) => {
+            let service_name =
+                env::var(OTLP_SERVICE_NAME).unwrap_or_else(|_| "fuel-core".to_string());
+            Some(otlp::layer(&endpoint, service_name)?)
+        }
+        Err(_) => None,
     };
 
     let subscriber = registry::Registry::default() // provide underlying span data store
         .with(filter) // filter out low-level debug tracing (eg tokio executor)
-        .with(fmt); // log to stdout
+        .with(fmt) // log to stdout
+        .with(otlp); // export spans to the OpenTelemetry collector
 
     tracing::subscriber::set_global_default(subscriber)
         .expect("setting global default failed");
     Ok(())
 }
 
+/// Replaces the log filter of the running node. The `filter` uses the `RUST_LOG` syntax.
+pub fn set_log_filter(filter: &str) -> anyhow::Result<()> {
+    let filter = EnvFilter::try_new(filter)?;
+    LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("The logging is not initialized"))?
+        .reload(filter)?;
+    Ok(())
+}
+
+/// Exposes the log filter of the node to the admin API.
+#[derive(Debug)]
+pub struct ReloadableLogFilter;
+
+impl LogFilterPort for ReloadableLogFilter {
+    fn current(&self) -> String {
+        LOG_FILTER_HANDLE
+            .get()
+            .and_then(|handle| handle.with_current(|filter| filter.to_string()).ok())
+            .unwrap_or_default()
+    }
+
+    fn set(&self, filter: &str) -> anyhow::Result<()> {
+        set_log_filter(filter)
+    }
+}
+
 pub async fn run_cli() -> anyhow::Result<()> {
     init_logging().await?;
+    let result = run_command().await;
+    otlp::shutdown();
+    result
+}
+
+async fn run_command() -> anyhow::Result<()> {
     if let Some(path) = init_environment() {
         let path = path.display();
         tracing::info!("Loading environment variables from {path}");
@@ -117,6 +181,13 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Db(command) => db::exec(command).await,
+            #[cfg(feature = "parquet")]
+            Fuel::Export(command) => export::exec(command).await,
+            Fuel::ChainConfig(command) => chain_config::exec(command).await,
+            Fuel::Replay(command) => replay::exec(command).await,
+            Fuel::P2p(command) => p2p::exec(command).await,
+            Fuel::TestVector(command) => test_vector::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -0,0 +1,274 @@
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        CoinConfig,
+        ConsensusConfig,
+        ContractConfig,
+        StateConfig,
+    },
+    types::fuel_types::{
+        Address,
+        ChainId,
+    },
+};
+use std::{
+    io::Write,
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Generate, validate and compare chain configs.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the chain config operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Creates a new chain config with the initial state and default consensus parameters.
+    New(NewArgs),
+    /// Validates the chain config against the known constraints.
+    #[command(arg_required_else_help = true)]
+    Check {
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        #[clap(name = "CHAIN_CONFIG")]
+        chain_config: String,
+    },
+    /// Prints the differences between two chain configs.
+    #[command(arg_required_else_help = true)]
+    Diff {
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        #[clap(name = "FROM")]
+        from: String,
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        #[clap(name = "TO")]
+        to: String,
+    },
+    /// Imports the contract created by the `snapshot contract` into the initial state
+    /// of the chain config, replacing the contract with the same id.
+    #[command(arg_required_else_help = true)]
+    ImportContract {
+        /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+        #[clap(name = "CHAIN_CONFIG")]
+        chain_config: String,
+        /// The file with the snapshot of the contract.
+        #[clap(long = "contract")]
+        contract: PathBuf,
+        /// The file to write the chain config to. It is printed to stdout by default.
+        #[clap(long = "output", short = 'o')]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Parser)]
+pub struct NewArgs {
+    /// The name of the chain.
+    #[clap(long = "chain-name", default_value = "local")]
+    pub chain_name: String,
+
+    /// The id of the chain used to sign transactions.
+    #[clap(long = "chain-id", default_value = "0")]
+    pub chain_id: u64,
+
+    /// The address of the PoA block producer.
+    /// The development key of the `fuel-core` is used by default.
+    #[clap(long = "poa-signing-key")]
+    pub signing_key: Option<Address>,
+
+    /// The initial coins of the base asset in the format `<ADDRESS>:<AMOUNT>`.
+    #[clap(long = "coin", value_parser = parse_coin, value_delimiter = ',')]
+    pub coins: Vec<(Address, u64)>,
+
+    /// The file to write the chain config to. It is printed to stdout by default.
+    #[clap(long = "output", short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+impl NewArgs {
+    fn chain_config(&self) -> ChainConfig {
+        let mut config = ChainConfig {
+            chain_name: self.chain_name.clone(),
+            ..Default::default()
+        };
+        config.consensus_parameters.chain_id = ChainId::new(self.chain_id);
+        if let Some(signing_key) = self.signing_key {
+            config.consensus = ConsensusConfig::PoA { signing_key };
+        }
+        if !self.coins.is_empty() {
+            let coins = self
+                .coins
+                .iter()
+                .map(|(owner, amount)| CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: None,
+                    owner: *owner,
+                    amount: *amount,
+                    asset_id: ChainConfig::BASE_ASSET,
+                })
+                .collect();
+            config.initial_state = Some(StateConfig {
+                coins: Some(coins),
+                ..Default::default()
+            });
+        }
+        config
+    }
+}
+
+fn parse_coin(value: &str) -> anyhow::Result<(Address, u64)> {
+    let (owner, amount) = value
+        .split_once(':')
+        .context("Expected the coin in the format `<ADDRESS>:<AMOUNT>`")?;
+    let owner = Address::from_str(owner)
+        .map_err(|e| anyhow::anyhow!("Invalid address `{owner}`: {e}"))?;
+    let amount = amount
+        .parse()
+        .with_context(|| format!("Invalid amount `{amount}`"))?;
+    Ok((owner, amount))
+}
+
+fn load(chain_config: &str) -> anyhow::Result<ChainConfig> {
+    ChainConfig::from_str(chain_config)
+        .with_context(|| format!("failed to load the chain config `{chain_config}`"))
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    match command.subcommand {
+        SubCommands::New(args) => {
+            let config = args.chain_config();
+            let errors = config.validate();
+            if let Some(error) = errors.first() {
+                anyhow::bail!("The generated chain config is invalid: {error}");
+            }
+            write(&config, args.output, &mut stdout)?;
+        }
+        SubCommands::Check { chain_config } => {
+            let errors = load(&chain_config)?.validate();
+            if errors.is_empty() {
+                writeln!(stdout, "The chain config `{chain_config}` is valid")?;
+            } else {
+                for error in &errors {
+                    writeln!(stdout, "{error}")?;
+                }
+                anyhow::bail!(
+                    "The chain config `{chain_config}` has {} problem(s)",
+                    errors.len()
+                );
+            }
+        }
+        SubCommands::Diff { from, to } => {
+            for difference in load(&from)?.diff(&load(&to)?)? {
+                writeln!(stdout, "{difference}")?;
+            }
+        }
+        SubCommands::ImportContract {
+            chain_config,
+            contract,
+            output,
+        } => {
+            let mut config = load(&chain_config)?;
+            let json = std::fs::read_to_string(&contract).with_context(|| {
+                format!("failed to read the contract from {}", contract.display())
+            })?;
+            let contract: ContractConfig = serde_json::from_str(&json)
+                .context("failed to parse the snapshot of the contract")?;
+            config
+                .initial_state
+                .get_or_insert_with(Default::default)
+                .import_contract(contract);
+            let errors = config.validate();
+            if let Some(error) = errors.first() {
+                anyhow::bail!("The chain config with the contract is invalid: {error}");
+            }
+            write(&config, output, &mut stdout)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the `config` to the `output` file, or to the `stdout`.
+fn write(
+    config: &ChainConfig,
+    output: Option<PathBuf>,
+    stdout: &mut impl Write,
+) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(config)
+        .context("failed to serialize the chain config to JSON")?;
+    match output {
+        Some(path) => std::fs::write(&path, json).with_context(|| {
+            format!("failed to write the chain config to {}", path.display())
+        })?,
+        None => writeln!(stdout, "{json}")?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use test_case::test_case;
+
+    fn parse(args: &[&str]) -> Result<NewArgs, ()> {
+        NewArgs::try_parse_from(args).map_err(|_| ())
+    }
+
+    #[test]
+    fn new__default_config_is_valid() {
+        let args = parse(&[""]).unwrap();
+
+        let config = args.chain_config();
+
+        assert_eq!(config.validate(), vec![]);
+        assert_eq!(config.initial_state, None);
+    }
+
+    #[test]
+    fn new__creates_coins_and_consensus() {
+        let owner = Address::from([1; 32]);
+        let args = parse(&[
+            "",
+            "--chain-name=devnet",
+            "--chain-id=7",
+            &format!("--poa-signing-key={owner}"),
+            &format!("--coin={owner}:100,{owner}:200"),
+        ])
+        .unwrap();
+
+        let config = args.chain_config();
+
+        assert_eq!(config.chain_name, "devnet");
+        assert_eq!(config.consensus_parameters.chain_id, ChainId::new(7));
+        assert_eq!(
+            config.consensus,
+            ConsensusConfig::PoA { signing_key: owner }
+        );
+        let amounts: Vec<_> = config
+            .initial_state
+            .unwrap()
+            .coins
+            .unwrap()
+            .into_iter()
+            .map(|coin| (coin.owner, coin.amount))
+            .collect();
+        assert_eq!(amounts, vec![(owner, 100), (owner, 200)]);
+    }
+
+    #[test_case(&["", "--coin=0x01"] => Err(()); "coin without amount")]
+    #[test_case(&["", "--coin=0x01:100"] => Err(()); "coin with short address")]
+    #[test_case(&["", "--coin=0000000000000000000000000000000000000000000000000000000000000000:-1"] => Err(()); "coin with negative amount")]
+    fn parse_invalid_coin(args: &[&str]) -> Result<(), ()> {
+        parse(args).map(|_| ())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::database::Column;
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Work with the database of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Inspects the raw content of the database.
+    #[command(subcommand)]
+    Inspect(Inspect),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Subcommand)]
+pub enum Inspect {
+    /// Prints the decoded value of the key in the table.
+    #[command(arg_required_else_help = true)]
+    Get {
+        /// The name of the table, e.g. `Coins` or `FuelBlocks`.
+        #[clap(long = "table", value_parser = parse_column)]
+        column: Column,
+        /// The hex encoded key.
+        #[clap(long = "key", value_parser = parse_hex)]
+        key: HexKey,
+    },
+    /// Lists the hex encoded keys of the table in the range.
+    #[command(arg_required_else_help = true)]
+    Keys {
+        /// The name of the table, e.g. `Coins` or `FuelBlocks`.
+        #[clap(long = "table", value_parser = parse_column)]
+        column: Column,
+        /// The hex encoded first key of the range (inclusive).
+        #[clap(long = "from", value_parser = parse_hex)]
+        start: Option<HexKey>,
+        /// The hex encoded last key of the range (exclusive).
+        #[clap(long = "to", value_parser = parse_hex)]
+        end: Option<HexKey>,
+        /// The maximum number of keys to print.
+        #[clap(long = "limit", default_value = "100")]
+        limit: usize,
+    },
+    /// Prints the number of entries and the size of each table.
+    Sizes,
+    /// Dumps all entries of the table to JSON.
+    #[command(arg_required_else_help = true)]
+    Dump {
+        /// The name of the table, e.g. `Coins` or `FuelBlocks`.
+        #[clap(long = "table", value_parser = parse_column)]
+        column: Column,
+    },
+}
+
+fn parse_column(name: &str) -> anyhow::Result<Column> {
+    Column::from_str(name).map_err(|_| {
+        anyhow::anyhow!(
+            "Unknown table `{name}`, the `inspect sizes` command lists all tables"
+        )
+    })
+}
+
+/// The raw key of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexKey(Vec<u8>);
+
+fn parse_hex(value: &str) -> anyhow::Result<HexKey> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    Ok(HexKey(hex::decode(value)?))
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::database::Database;
+    use std::io::Write;
+
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open_read_only(&path, None)
+            .context(format!(
+                "failed to open database at path {}",
+                path.display()
+            ))?;
+    let db = Database::new(std::sync::Arc::new(data_source));
+    let mut stdout = std::io::stdout().lock();
+
+    let SubCommands::Inspect(inspect) = command.subcommand;
+    match inspect {
+        Inspect::Get { column, key } => {
+            let value = db
+                .inspect_value(column, &key.0)?
+                .with_context(|| format!("the key is not found in `{column:?}`"))?;
+            serde_json::to_writer_pretty(&mut stdout, &value)
+                .context("failed to print the value to JSON")?;
+            writeln!(stdout)?;
+        }
+        Inspect::Keys {
+            column,
+            start,
+            end,
+            limit,
+        } => {
+            let start = start.as_ref().map(|key| key.0.as_slice());
+            let end = end.as_ref().map(|key| key.0.as_slice());
+            for key in db.inspect_keys(column, start, end, limit)? {
+                writeln!(stdout, "{}", hex::encode(key))?;
+            }
+        }
+        Inspect::Sizes => {
+            let sizes = db.inspect_column_sizes()?;
+            serde_json::to_writer_pretty(&mut stdout, &sizes)
+                .context("failed to print the sizes to JSON")?;
+            writeln!(stdout)?;
+        }
+        Inspect::Dump { column } => {
+            // Writes the entries one by one to not keep the whole table in memory.
+            write!(stdout, "[")?;
+            for (i, entry) in db.inspect_entries(column).enumerate() {
+                if i > 0 {
+                    write!(stdout, ",")?;
+                }
+                serde_json::to_writer(&mut stdout, &entry?)
+                    .context("failed to dump the table to JSON")?;
+            }
+            writeln!(stdout, "]")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&["", "get", "--table", "coins", "--key", "0x0102"] => Ok(Inspect::Get { column: Column::Coins, key: HexKey(vec![1, 2]) }); "table name is case insensitive")]
+    #[test_case(&["", "keys", "--table", "FuelBlocks", "--from", "00"] => Ok(Inspect::Keys { column: Column::FuelBlocks, start: Some(HexKey(vec![0])), end: None, limit: 100 }); "keys from the start")]
+    #[test_case(&["", "sizes"] => Ok(Inspect::Sizes); "sizes of all tables")]
+    #[test_case(&["", "dump", "--table", "Unknown"] => Err(()); "unknown table")]
+    #[test_case(&["", "get", "--table", "Coins", "--key", "xyz"] => Err(()); "invalid hex key")]
+    fn parse(args: &[&str]) -> Result<Inspect, ()> {
+        #[derive(Debug, Clone, Parser)]
+        struct Command {
+            #[command(subcommand)]
+            inspect: Inspect,
+        }
+
+        Command::try_parse_from(args)
+            .map_err(|_| ())
+            .map(|c| c.inspect)
+    }
+}
@@ -0,0 +1,584 @@
+// Without the RocksDB, only the tests use the export.
+#![cfg_attr(
+    not(any(feature = "rocksdb", feature = "rocksdb-production")),
+    allow(dead_code)
+)]
+
+use crate::cli::DEFAULT_DB_PATH;
+use arrow_array::{
+    builder::{
+        BinaryBuilder,
+        Int64Builder,
+        StringBuilder,
+        UInt32Builder,
+        UInt64Builder,
+    },
+    ArrayRef,
+    RecordBatch,
+};
+use arrow_schema::{
+    DataType,
+    Field,
+    Schema,
+    SchemaRef,
+};
+use clap::{
+    Parser,
+    ValueEnum,
+};
+use fuel_core::{
+    database::Database,
+    schema::tx::receipt::ReceiptType,
+    types::{
+        blockchain::block::CompressedBlock,
+        fuel_tx::Transaction,
+        fuel_types::{
+            canonical::Serialize,
+            BlockHeight,
+        },
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        FuelBlocks,
+        Receipts,
+        Transactions,
+    },
+    StorageAsRef,
+};
+use parquet::arrow::ArrowWriter;
+use std::{
+    fs::File,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+/// The number of the rows written to the file at once.
+const BATCH_ROWS: usize = 8192;
+
+/// Exports the chain history into the columnar files, one file per table,
+/// so it can be loaded into the analytics tools. The schema of the files is stable:
+/// the ids are `0x`-prefixed hex strings and the times are the UNIX seconds.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The format of the exported files.
+    #[clap(long = "format", value_enum, default_value = "parquet")]
+    format: Format,
+
+    /// The comma separated tables to export.
+    #[clap(
+        long = "tables",
+        value_enum,
+        value_delimiter = ',',
+        default_value = "blocks,transactions,receipts"
+    )]
+    tables: Vec<Table>,
+
+    /// The height of the first exported block.
+    #[clap(long = "from", default_value = "0")]
+    from: u32,
+
+    /// The height of the last exported block. The latest block by default.
+    #[clap(long = "to")]
+    to: Option<u32>,
+
+    /// The directory of the exported files. Each table is written to `<table>.parquet`.
+    #[clap(long = "output", default_value = ".")]
+    output: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Parquet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Table {
+    /// The headers of the blocks.
+    Blocks,
+    /// The transactions with their canonical encoding.
+    Transactions,
+    /// The receipts of the executed transactions.
+    Receipts,
+}
+
+impl Table {
+    fn name(&self) -> &'static str {
+        match self {
+            Table::Blocks => "blocks",
+            Table::Transactions => "transactions",
+            Table::Receipts => "receipts",
+        }
+    }
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open_read_only(&path, None)
+            .context(format!(
+                "failed to open database at path {}",
+                path.display()
+            ))?;
+    let db = Database::new(std::sync::Arc::new(data_source));
+
+    let Format::Parquet = command.format;
+    let to = match command.to {
+        Some(to) => to.into(),
+        None => db.latest_height()?,
+    };
+    std::fs::create_dir_all(&command.output)?;
+    let rows = export(
+        &db,
+        &command.tables,
+        command.from.into(),
+        to,
+        &command.output,
+    )?;
+    for (table, rows) in command.tables.iter().zip(rows) {
+        println!(
+            "Exported {rows} rows of the {} of the blocks {}..={to}",
+            table.name(),
+            command.from
+        );
+    }
+    Ok(())
+}
+
+/// Writes the `tables` of the blocks in the `from..=to` range into the `output`
+/// directory. Returns the number of the written rows of each table.
+pub fn export(
+    db: &Database,
+    tables: &[Table],
+    from: BlockHeight,
+    to: BlockHeight,
+    output: &Path,
+) -> anyhow::Result<Vec<usize>> {
+    let mut writers = tables
+        .iter()
+        .map(|table| TableWriter::new(*table, output))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    for ids in db.all_block_ids(Some(from), IterDirection::Forward) {
+        let (height, id) = ids?;
+        if height > to {
+            break
+        }
+        let block = db
+            .storage::<FuelBlocks>()
+            .get(&id)?
+            .ok_or_else(|| anyhow::anyhow!("The block {height} is missing"))?;
+        for writer in writers.iter_mut() {
+            writer.append_block(db, &block)?;
+        }
+    }
+
+    writers.into_iter().map(TableWriter::close).collect()
+}
+
+struct TableWriter {
+    writer: ArrowWriter<File>,
+    rows: Box<dyn Rows>,
+    written: usize,
+}
+
+impl TableWriter {
+    fn new(table: Table, output: &Path) -> anyhow::Result<Self> {
+        let rows: Box<dyn Rows> = match table {
+            Table::Blocks => Box::<BlockRows>::default(),
+            Table::Transactions => Box::<TransactionRows>::default(),
+            Table::Receipts => Box::<ReceiptRows>::default(),
+        };
+        let file = File::create(output.join(format!("{}.parquet", table.name())))?;
+        let writer = ArrowWriter::try_new(file, rows.schema(), None)?;
+        Ok(Self {
+            writer,
+            rows,
+            written: 0,
+        })
+    }
+
+    fn append_block(
+        &mut self,
+        db: &Database,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<()> {
+        self.rows.append_block(db, block)?;
+        if self.rows.len() >= BATCH_ROWS {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        let len = self.rows.len();
+        if len > 0 {
+            let batch = RecordBatch::try_new(self.rows.schema(), self.rows.finish())?;
+            self.writer.write(&batch)?;
+            self.written += len;
+        }
+        Ok(())
+    }
+
+    fn close(mut self) -> anyhow::Result<usize> {
+        self.flush()?;
+        self.writer.close()?;
+        Ok(self.written)
+    }
+}
+
+/// The columns of the table that are filled block by block.
+trait Rows {
+    /// The schema of the table. It must not change between the releases.
+    fn schema(&self) -> SchemaRef;
+
+    /// Appends the rows of the `block`.
+    fn append_block(
+        &mut self,
+        db: &Database,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<()>;
+
+    /// Returns the number of the appended rows.
+    fn len(&self) -> usize;
+
+    /// Takes the appended rows in the order of the schema fields.
+    fn finish(&mut self) -> Vec<ArrayRef>;
+}
+
+fn hex(value: impl std::fmt::LowerHex) -> String {
+    format!("{value:#x}")
+}
+
+#[derive(Default)]
+struct BlockRows {
+    height: UInt32Builder,
+    id: StringBuilder,
+    time: Int64Builder,
+    da_height: UInt64Builder,
+    prev_root: StringBuilder,
+    transactions_count: UInt64Builder,
+    message_receipt_count: UInt64Builder,
+    transactions_root: StringBuilder,
+    message_receipt_root: StringBuilder,
+}
+
+impl Rows for BlockRows {
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("height", DataType::UInt32, false),
+            Field::new("id", DataType::Utf8, false),
+            Field::new("time", DataType::Int64, false),
+            Field::new("da_height", DataType::UInt64, false),
+            Field::new("prev_root", DataType::Utf8, false),
+            Field::new("transactions_count", DataType::UInt64, false),
+            Field::new("message_receipt_count", DataType::UInt64, false),
+            Field::new("transactions_root", DataType::Utf8, false),
+            Field::new("message_receipt_root", DataType::Utf8, false),
+        ]))
+    }
+
+    fn append_block(
+        &mut self,
+        _: &Database,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<()> {
+        let header = block.header();
+        self.height.append_value(**header.height());
+        self.id.append_value(hex(header.id()));
+        self.time.append_value(header.time().to_unix());
+        self.da_height.append_value(header.da_height.0);
+        self.prev_root.append_value(hex(*header.prev_root()));
+        self.transactions_count
+            .append_value(header.application.transactions_count);
+        self.message_receipt_count
+            .append_value(header.application.message_receipt_count);
+        self.transactions_root
+            .append_value(hex(header.application.transactions_root));
+        self.message_receipt_root
+            .append_value(hex(header.application.message_receipt_root));
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.height.len()
+    }
+
+    fn finish(&mut self) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(self.height.finish()),
+            Arc::new(self.id.finish()),
+            Arc::new(self.time.finish()),
+            Arc::new(self.da_height.finish()),
+            Arc::new(self.prev_root.finish()),
+            Arc::new(self.transactions_count.finish()),
+            Arc::new(self.message_receipt_count.finish()),
+            Arc::new(self.transactions_root.finish()),
+            Arc::new(self.message_receipt_root.finish()),
+        ]
+    }
+}
+
+#[derive(Default)]
+struct TransactionRows {
+    block_height: UInt32Builder,
+    index: UInt32Builder,
+    id: StringBuilder,
+    kind: StringBuilder,
+    size: UInt64Builder,
+    raw: BinaryBuilder,
+}
+
+impl Rows for TransactionRows {
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("block_height", DataType::UInt32, false),
+            Field::new("index", DataType::UInt32, false),
+            Field::new("id", DataType::Utf8, false),
+            Field::new("kind", DataType::Utf8, false),
+            Field::new("size", DataType::UInt64, false),
+            Field::new("raw", DataType::Binary, false),
+        ]))
+    }
+
+    fn append_block(
+        &mut self,
+        db: &Database,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<()> {
+        let height = **block.header().height();
+        for (index, tx_id) in block.transactions().iter().enumerate() {
+            let tx = db
+                .storage::<Transactions>()
+                .get(tx_id)?
+                .ok_or_else(|| anyhow::anyhow!("The transaction {tx_id} is missing"))?;
+            let kind = match tx.as_ref() {
+                Transaction::Script(_) => "script",
+                Transaction::Create(_) => "create",
+                Transaction::Mint(_) => "mint",
+            };
+            let raw = tx.into_owned().to_bytes();
+            self.block_height.append_value(height);
+            self.index.append_value(index as u32);
+            self.id.append_value(hex(*tx_id));
+            self.kind.append_value(kind);
+            self.size.append_value(raw.len() as u64);
+            self.raw.append_value(raw);
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.block_height.len()
+    }
+
+    fn finish(&mut self) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(self.block_height.finish()),
+            Arc::new(self.index.finish()),
+            Arc::new(self.id.finish()),
+            Arc::new(self.kind.finish()),
+            Arc::new(self.size.finish()),
+            Arc::new(self.raw.finish()),
+        ]
+    }
+}
+
+#[derive(Default)]
+struct ReceiptRows {
+    block_height: UInt32Builder,
+    tx_id: StringBuilder,
+    index: UInt32Builder,
+    kind: StringBuilder,
+    contract_id: StringBuilder,
+    to_contract: StringBuilder,
+    to_address: StringBuilder,
+    asset_id: StringBuilder,
+    amount: UInt64Builder,
+    gas_used: UInt64Builder,
+    result: UInt64Builder,
+    data: BinaryBuilder,
+}
+
+impl Rows for ReceiptRows {
+    fn schema(&self) -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("block_height", DataType::UInt32, false),
+            Field::new("tx_id", DataType::Utf8, false),
+            Field::new("index", DataType::UInt32, false),
+            Field::new("kind", DataType::Utf8, false),
+            Field::new("contract_id", DataType::Utf8, true),
+            Field::new("to_contract", DataType::Utf8, true),
+            Field::new("to_address", DataType::Utf8, true),
+            Field::new("asset_id", DataType::Utf8, true),
+            Field::new("amount", DataType::UInt64, true),
+            Field::new("gas_used", DataType::UInt64, true),
+            Field::new("result", DataType::UInt64, true),
+            Field::new("data", DataType::Binary, true),
+        ]))
+    }
+
+    fn append_block(
+        &mut self,
+        db: &Database,
+        block: &CompressedBlock,
+    ) -> anyhow::Result<()> {
+        let height = **block.header().height();
+        for tx_id in block.transactions() {
+            let Some(receipts) = db.storage::<Receipts>().get(tx_id)? else {
+                continue
+            };
+            for (index, receipt) in receipts.iter().enumerate() {
+                self.block_height.append_value(height);
+                self.tx_id.append_value(hex(*tx_id));
+                self.index.append_value(index as u32);
+                self.kind
+                    .append_value(ReceiptType::from(receipt).to_string());
+                self.contract_id
+                    .append_option(receipt.id().copied().map(hex));
+                self.to_contract
+                    .append_option(receipt.to().copied().map(hex));
+                self.to_address
+                    .append_option(receipt.to_address().copied().map(hex));
+                self.asset_id
+                    .append_option(receipt.asset_id().copied().map(hex));
+                self.amount.append_option(receipt.amount());
+                self.gas_used.append_option(receipt.gas_used());
+                self.result
+                    .append_option(receipt.result().map(|result| (*result).into()));
+                self.data.append_option(receipt.data());
+            }
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        self.block_height.len()
+    }
+
+    fn finish(&mut self) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(self.block_height.finish()),
+            Arc::new(self.tx_id.finish()),
+            Arc::new(self.index.finish()),
+            Arc::new(self.kind.finish()),
+            Arc::new(self.contract_id.finish()),
+            Arc::new(self.to_contract.finish()),
+            Arc::new(self.to_address.finish()),
+            Arc::new(self.asset_id.finish()),
+            Arc::new(self.amount.finish()),
+            Arc::new(self.gas_used.finish()),
+            Arc::new(self.result.finish()),
+            Arc::new(self.data.finish()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core::types::{
+        blockchain::{
+            block::Block,
+            header::{
+                ApplicationHeader,
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_types::ChainId,
+        tai64::Tai64,
+    };
+    use fuel_core_storage::StorageAsMut;
+    use parquet::file::reader::{
+        FileReader,
+        SerializedFileReader,
+    };
+
+    #[test]
+    fn parse_tables_and_range() {
+        let command = Command::try_parse_from([
+            "",
+            "--tables",
+            "blocks,receipts",
+            "--from",
+            "2",
+            "--to",
+            "10",
+        ])
+        .unwrap();
+
+        assert_eq!(command.format, Format::Parquet);
+        assert_eq!(command.tables, vec![Table::Blocks, Table::Receipts]);
+        assert_eq!(command.from, 2);
+        assert_eq!(command.to, Some(10));
+        assert!(Command::try_parse_from(["", "--format", "csv"]).is_err());
+    }
+
+    #[test]
+    fn export_writes_the_rows_of_the_range() {
+        let mut db = Database::in_memory();
+        let chain_id = ChainId::default();
+        for height in 0..3u32 {
+            let header = PartialBlockHeader {
+                application: ApplicationHeader {
+                    da_height: Default::default(),
+                    generated: Empty,
+                },
+                consensus: ConsensusHeader {
+                    prev_root: Default::default(),
+                    height: height.into(),
+                    time: Tai64::UNIX_EPOCH,
+                    generated: Empty,
+                },
+            };
+            let block = Block::new(header, vec![], &[]);
+            db.storage::<FuelBlocks>()
+                .insert(&block.id(), &block.compress(&chain_id))
+                .unwrap();
+        }
+        let output = tempfile::tempdir().unwrap();
+
+        let rows = export(
+            &db,
+            &[Table::Blocks, Table::Transactions, Table::Receipts],
+            1u32.into(),
+            1u32.into(),
+            output.path(),
+        )
+        .unwrap();
+
+        assert_eq!(rows, vec![1, 0, 0]);
+        let file = File::open(output.path().join("blocks.parquet")).unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 1);
+        assert_eq!(metadata.schema_descr().column(0).name(), "height");
+        assert!(output.path().join("receipts.parquet").exists());
+    }
+}
@@ -0,0 +1,244 @@
+//! The machine parseable logs with the stable JSON schema. Each event is written as
+//! one JSON object per line:
+//!
+//! ```json
+//! {
+//!   "timestamp": "2023-09-01T10:00:00.000000Z",
+//!   "level": "INFO",
+//!   "target": "fuel_core_importer::importer",
+//!   "line": 200,
+//!   "message": "Committed block",
+//!   "service": "BlockImporter",
+//!   "iteration": 42,
+//!   "block_height": 10,
+//!   "spans": ["initialize_loop", "run", "_commit_result"],
+//!   "fields": {}
+//! }
+//! ```
+//!
+//! The `service`, `iteration` and `block_height` are taken from the closest span that
+//! recorded them and are `null` without such a span. The other fields of the event are
+//! nested inside of the `fields`.
+
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+use std::{
+    fmt,
+    io::Write,
+    time::SystemTime,
+};
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    span::{
+        Attributes,
+        Id,
+        Record,
+    },
+    Event,
+    Subscriber,
+};
+use tracing_subscriber::{
+    fmt::MakeWriter,
+    layer::Context,
+    registry::LookupSpan,
+    Layer,
+};
+
+/// The span fields that are lifted to the top level of the log entry.
+const CONTEXT_FIELDS: [&str; 3] = ["service", "iteration", "block_height"];
+
+/// The layer that writes the events in the JSON format to the `writer`.
+pub struct JsonLayer<W> {
+    writer: W,
+}
+
+impl<W> JsonLayer<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+/// The recorded fields of the span.
+struct SpanFields(Map<String, Value>);
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), json!(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), json!(format!("{value:?}")));
+    }
+}
+
+impl<S, W> Layer<S> for JsonLayer<W>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + 'static,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Map::new();
+        attrs.record(&mut JsonVisitor(&mut fields));
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(fields));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(SpanFields(fields)) = span.extensions_mut().get_mut() {
+                values.record(&mut JsonVisitor(fields));
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut entry = format_event(event, ctx);
+        entry.push('\n');
+        let _ = self.writer.make_writer().write_all(entry.as_bytes());
+    }
+}
+
+fn format_event<S>(event: &Event<'_>, ctx: Context<'_, S>) -> String
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let mut fields = Map::new();
+    event.record(&mut JsonVisitor(&mut fields));
+    let message = fields.remove("message").unwrap_or(Value::Null);
+
+    let mut context = [Value::Null, Value::Null, Value::Null];
+    let mut spans = vec![];
+    if let Some(scope) = ctx.event_scope(event) {
+        for span in scope.from_root() {
+            if let Some(SpanFields(span_fields)) = span.extensions().get() {
+                for (value, name) in context.iter_mut().zip(CONTEXT_FIELDS) {
+                    if let Some(field) = span_fields.get(name) {
+                        *value = field.clone();
+                    }
+                }
+            }
+            spans.push(span.name());
+        }
+    }
+    let [service, iteration, block_height] = context;
+
+    let metadata = event.metadata();
+    json!({
+        "timestamp": humantime::format_rfc3339_micros(SystemTime::now()).to_string(),
+        "level": metadata.level().as_str(),
+        "target": metadata.target(),
+        "line": metadata.line(),
+        "message": message,
+        "service": service,
+        "iteration": iteration,
+        "block_height": block_height,
+        "spans": spans,
+        "fields": fields,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Arc,
+        Mutex,
+    };
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for Buffer {
+        type Writer = Self;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn entries(log: impl FnOnce()) -> Vec<Value> {
+        let buffer = Buffer::default();
+        let subscriber =
+            tracing_subscriber::registry().with(JsonLayer::new(buffer.clone()));
+        tracing::subscriber::with_default(subscriber, log);
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn event_contains_context_of_spans() {
+        let entries = entries(|| {
+            let _service =
+                tracing::info_span!("initialize_loop", service = "TxPool").entered();
+            let _run = tracing::info_span!("run", iteration = 3u64).entered();
+            let block =
+                tracing::info_span!("commit", block_height = tracing::field::Empty)
+                    .entered();
+            block.record("block_height", 10u32);
+            tracing::info!(tx_count = 2, "Committed block");
+        });
+
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry["level"], "INFO");
+        assert_eq!(entry["message"], "Committed block");
+        assert_eq!(entry["service"], "TxPool");
+        assert_eq!(entry["iteration"], 3);
+        assert_eq!(entry["block_height"], 10);
+        assert_eq!(entry["spans"], json!(["initialize_loop", "run", "commit"]));
+        assert_eq!(entry["fields"], json!({ "tx_count": 2 }));
+    }
+
+    #[test]
+    fn event_without_spans_has_null_context() {
+        let entries = entries(|| tracing::warn!("no context"));
+
+        let entry = &entries[0];
+        assert_eq!(entry["level"], "WARN");
+        assert_eq!(entry["service"], Value::Null);
+        assert_eq!(entry["iteration"], Value::Null);
+        assert_eq!(entry["block_height"], Value::Null);
+        assert_eq!(entry["spans"], json!([]));
+    }
+}
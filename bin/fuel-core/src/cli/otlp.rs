@@ -0,0 +1,61 @@
+//! Exports the spans of the node to the OpenTelemetry collector over OTLP, so the
+//! path of the transaction from the submission to the inclusion into the block is
+//! visible in Jaeger or Tempo.
+
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{
+    trace,
+    Resource,
+};
+use tracing::Subscriber;
+use tracing_subscriber::{
+    registry::LookupSpan,
+    Layer,
+};
+
+/// Creates the layer that exports the spans to the OTLP gRPC `endpoint`.
+/// The connection to the collector is established lazily.
+pub fn layer<S>(endpoint: &str, service_name: String) -> anyhow::Result<impl Layer<S>>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    let tracer =
+        opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint),
+            )
+            .with_trace_config(trace::config().with_resource(Resource::new([
+                KeyValue::new("service.name", service_name),
+            ])))
+            .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Exports the remaining spans before the exit.
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    // The shutdown blocks the thread until the batch task exports the spans.
+    #[tokio::test(flavor = "multi_thread")]
+    async fn layer_is_created_without_running_collector() {
+        let layer = layer("http://127.0.0.1:4317", "fuel-core".to_string()).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("produce_block").entered();
+            tracing::info!("inside of the exported span");
+        });
+
+        shutdown();
+    }
+}
@@ -0,0 +1,178 @@
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::admin_api::PeerEntry;
+use std::{
+    io::Write,
+    time::Duration,
+};
+
+/// Inspect the P2P network of the running node via its admin API.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The URL of the admin API of the node.
+    #[clap(long = "admin-api-url", env, default_value = "http://127.0.0.1:4001")]
+    admin_api_url: String,
+
+    /// The token that the admin API expects in the `Authorization: Bearer <token>` header.
+    #[clap(
+        long = "admin-api-token",
+        env = "ADMIN_API_TOKEN",
+        hide_env_values = true
+    )]
+    admin_api_token: String,
+
+    /// The sub-command of the P2P operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Prints the table of the connected peers.
+    Peers {
+        /// Prints the peers as JSON instead of the table.
+        #[clap(long = "json")]
+        json: bool,
+    },
+}
+
+async fn fetch_peers(url: &str, token: &str) -> anyhow::Result<Vec<PeerEntry>> {
+    let url = format!("{}/peers", url.trim_end_matches('/'));
+    let response = reqwest::Client::new()
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .with_context(|| format!("failed to request {url}"))?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        anyhow::bail!("The admin API responded with {status}: {body}");
+    }
+    serde_json::from_str(&body).context("failed to parse the peers")
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B")
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Formats the peers as the table with one row per peer. The peer with several
+/// addresses shows the first one.
+fn format_peers(peers: &[PeerEntry]) -> String {
+    let header = [
+        "PEER ID",
+        "ADDRESS",
+        "CLIENT",
+        "REPUTATION",
+        "HEIGHT",
+        "CONNECTED",
+        "IN",
+        "OUT",
+        "PROTOCOLS",
+    ];
+    let rows: Vec<[String; 9]> = peers
+        .iter()
+        .map(|peer| {
+            [
+                peer.peer_id.clone(),
+                peer.addresses.first().cloned().unwrap_or_default(),
+                peer.client_version.clone().unwrap_or_default(),
+                format!("{:.2}", peer.reputation),
+                peer.block_height
+                    .map(|height| height.to_string())
+                    .unwrap_or_default(),
+                humantime::format_duration(Duration::from_secs(peer.connected_secs))
+                    .to_string(),
+                format_bytes(peer.inbound_bytes),
+                format_bytes(peer.outbound_bytes),
+                peer.protocols.join(","),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    let header = header.map(str::to_string);
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    match command.subcommand {
+        SubCommands::Peers { json } => {
+            let peers =
+                fetch_peers(&command.admin_api_url, &command.admin_api_token).await?;
+            if json {
+                writeln!(stdout, "{}", serde_json::to_string_pretty(&peers)?)?;
+            } else {
+                write!(stdout, "{}", format_peers(&peers))?;
+                writeln!(stdout, "{} peer(s) connected", peers.len())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_uses_binary_units() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+    }
+
+    #[test]
+    fn format_peers_aligns_columns() {
+        let peers = vec![PeerEntry {
+            peer_id: "16Uiu2HAm".to_string(),
+            addresses: vec!["/ip4/127.0.0.1/tcp/30333".to_string()],
+            client_version: Some("fuel-core/0.20.4".to_string()),
+            reputation: 12.5,
+            block_height: Some(100),
+            connected_secs: 90,
+            inbound_bytes: 2048,
+            outbound_bytes: 10,
+            protocols: vec!["/fuel/1.0".to_string(), "/meshsub/1.1.0".to_string()],
+        }];
+
+        let table = format_peers(&peers);
+
+        assert_eq!(
+            table,
+            "PEER ID    ADDRESS                   CLIENT            REPUTATION  HEIGHT  CONNECTED  IN       OUT   PROTOCOLS\n\
+             16Uiu2HAm  /ip4/127.0.0.1/tcp/30333  fuel-core/0.20.4  12.50       100     1m 30s     2.0 KiB  10 B  /fuel/1.0,/meshsub/1.1.0\n"
+        );
+    }
+}
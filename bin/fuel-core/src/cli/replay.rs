@@ -0,0 +1,95 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Re-executes the historical blocks and compares the results with the stored ones.
+/// The parent state of the blocks is rebuilt in memory from the genesis.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    /// It must be the same chain config that was used to create the database.
+    #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+    chain_config: String,
+
+    /// The height of the first block to compare.
+    #[clap(long = "from", default_value = "1")]
+    from: u32,
+
+    /// The height of the last block to compare.
+    #[clap(long = "to")]
+    to: u32,
+
+    /// Enable full utxo stateful validation, as the nodes of the network do.
+    #[clap(long = "utxo-validation")]
+    utxo_validation: bool,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        chain_config::ChainConfig,
+        database::Database,
+        service::{
+            replay::replay,
+            Config,
+        },
+    };
+
+    let path = command.database_path;
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open_read_only(&path, None)
+            .context(format!(
+                "failed to open database at path {}",
+                path.display()
+            ))?;
+    let db = Database::new(std::sync::Arc::new(data_source));
+
+    let mut config = Config::local_node();
+    config.chain_conf = command.chain_config.parse::<ChainConfig>()?;
+    config.utxo_validation = command.utxo_validation;
+
+    let (from, to) = (command.from, command.to);
+    match replay(&db, &config, from.into(), to.into())? {
+        Some(divergence) => Err(anyhow::anyhow!("{divergence}")),
+        None => {
+            println!("The blocks {from}..={to} match their re-execution");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_range() {
+        let command = Command::try_parse_from(["", "--to", "10"]).unwrap();
+
+        assert_eq!(command.from, 1);
+        assert_eq!(command.to, 10);
+    }
+
+    #[test]
+    fn to_is_required() {
+        assert!(Command::try_parse_from(["", "--from", "2"]).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 use crate::{
     cli::{
         run::consensus::PoATriggerArgs,
+        ReloadableLogFilter,
         DEFAULT_DB_PATH,
     },
     FuelService,
@@ -12,20 +13,32 @@ use anyhow::{
 };
 use clap::Parser;
 use fuel_core::{
+    admin_api::service::ConfigReload,
     chain_config::{
         default_consensus_dev_key,
         ChainConfig,
     },
-    producer::Config as ProducerConfig,
+    gas_price::Config as GasPriceConfig,
+    producer::{
+        Config as ProducerConfig,
+        DryRunLimits,
+    },
     service::{
+        adapters::consensus_module::poa::RemoteCosignerConfig,
         config::Trigger,
         Config,
         DbType,
+        NodeMode,
         RelayerVerifierConfig,
         ServiceTrait,
+        StartupChecks,
         VMConfig,
     },
-    txpool::Config as TxPoolConfig,
+    txpool::{
+        sponsorship::SponsorshipConfig,
+        transaction_selector::Strategy as SelectionStrategy,
+        Config as TxPoolConfig,
+    },
     types::{
         blockchain::primitives::SecretKeyWrapper,
         fuel_tx::Address,
@@ -50,6 +63,7 @@ use std::{
     ops::Deref,
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
 };
 use tracing::{
     info,
@@ -64,10 +78,26 @@ const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
 #[cfg(feature = "p2p")]
 mod p2p;
 
+mod admin_api;
+mod compaction;
+mod config_watcher;
 mod consensus;
+mod da_source;
+mod disk_monitor;
+mod durability;
+#[cfg(feature = "event-bus")]
+mod event_bus;
+#[cfg(feature = "fault-injection")]
+mod fault_injection;
+mod persisted_queries;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod resource_budget;
+mod stage_deadlines;
+mod state_diff;
+#[cfg(feature = "wasm-policy")]
+mod tx_policy;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -78,6 +108,25 @@ pub struct Command {
     #[clap(long = "port", default_value = "4000", env)]
     pub port: u16,
 
+    /// The address of the gRPC API server, like `127.0.0.1:4001`. The server exposes
+    /// the submission of the transactions, the blocks and the transactions with their
+    /// statuses in the binary format. It is disabled if not set.
+    #[cfg(feature = "grpc")]
+    #[clap(long = "grpc-addr", env)]
+    pub grpc_addr: Option<net::SocketAddr>,
+
+    /// The `read-replica` follows the primary node set by the `--primary` and serves
+    /// only the read API. It disables the `TxPool`, the block production, the relayer
+    /// and the P2P network, so the public API can be scaled by adding the replicas.
+    #[clap(
+        long = "mode",
+        default_value = "full",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub mode: NodeMode,
+
     /// Vanity name for node, used in telemetry
     #[clap(long = "service-name", default_value = "fuel-core", value_parser, env)]
     pub service_name: String,
@@ -108,6 +157,9 @@ pub struct Command {
     )]
     pub database_type: DbType,
 
+    #[clap(flatten)]
+    pub durability: durability::DurabilityArgs,
+
     /// Specify either an alias to a built-in configuration or filepath to a JSON file.
     #[arg(
         name = "CHAIN_CONFIG",
@@ -117,6 +169,24 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// Bootstraps the regenesis from the snapshot uploaded by the
+    /// `snapshot everything --upload-to`, instead of the `--chain`. The snapshot
+    /// is downloaded and verified against its manifest before the node starts.
+    #[cfg(feature = "object-storage")]
+    #[arg(
+        long = "snapshot-from-url",
+        conflicts_with = "CHAIN_CONFIG",
+        requires = "snapshot_manifest_sha256",
+        env
+    )]
+    pub snapshot_from_url: Option<url::Url>,
+
+    /// The trusted SHA-256 of the manifest of the `--snapshot-from-url`, printed by
+    /// the upload of the snapshot. The location of the snapshot isn't trusted.
+    #[cfg(feature = "object-storage")]
+    #[arg(long = "snapshot-manifest-sha256", requires = "snapshot_from_url", env)]
+    pub snapshot_manifest_sha256: Option<fuel_core::types::fuel_types::Bytes32>,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
@@ -133,15 +203,68 @@ pub struct Command {
     #[arg(long = "utxo-validation", env)]
     pub utxo_validation: bool,
 
+    /// The max number of the blocks ahead of the next block that the callers
+    /// of the dry run can simulate with the overridden block height.
+    #[arg(long = "dry-run-max-height-offset", default_value = "2592000", env)]
+    pub dry_run_max_height_offset: u32,
+
+    /// The max time ahead of the current time that the callers of the dry run
+    /// can simulate with the overridden block time.
+    #[arg(long = "dry-run-max-time-offset", default_value = "30d", env)]
+    pub dry_run_max_time_offset: humantime::Duration,
+
     /// The minimum allowed gas price
     #[arg(long = "min-gas-price", default_value = "0", env)]
     pub min_gas_price: u64,
 
+    /// The gas price of the first block after the start of the node.
+    /// It can't be lower than `min_gas_price`.
+    #[arg(long = "starting-gas-price", default_value = "0", env)]
+    pub starting_gas_price: u64,
+
+    /// The percentage of the gas price change after each block.
+    #[arg(long = "gas-price-change-percent", default_value = "10", env)]
+    pub gas_price_change_percent: u64,
+
+    /// The block fullness in percent that keeps the gas price unchanged.
+    /// The gas price grows if blocks are fuller than this threshold and falls otherwise.
+    #[arg(long = "gas-price-threshold-percent", default_value = "50", env)]
+    pub gas_price_threshold_percent: u64,
+
+    /// Rejects the transactions paying less than the gas price of the next block
+    /// in the `TxPool` and the block production.
+    #[arg(long = "enforce-gas-price", env)]
+    pub enforce_gas_price: bool,
+
+    #[clap(flatten)]
+    pub da_source_args: da_source::DaSourceArgs,
+
     /// The signing key used when producing blocks.
     /// Setting via the `CONSENSUS_KEY_SECRET` ENV var is preferred.
     #[arg(long = "consensus-key", env)]
     pub consensus_key: Option<String>,
 
+    /// The additional signing keys sealing the blocks together with the `consensus-key`
+    /// when the chain uses the multi-signature PoA.
+    /// Setting via the `CONSENSUS_COSIGNER_KEYS` ENV var is preferred.
+    #[arg(long = "consensus-cosigner-keys", value_delimiter = ',', env)]
+    pub consensus_cosigner_keys: Vec<String>,
+
+    /// The admin API URLs of the nodes cosigning the blocks of the multi-signature PoA
+    /// when the local keys don't reach the threshold.
+    #[arg(
+        long = "consensus-cosigner",
+        value_delimiter = ',',
+        requires = "consensus_cosigner_token",
+        env
+    )]
+    pub consensus_cosigners: Vec<String>,
+
+    /// The admin API token of the cosigning nodes.
+    /// Setting via the `CONSENSUS_COSIGNER_TOKEN` ENV var is preferred.
+    #[arg(long = "consensus-cosigner-token", env)]
+    pub consensus_cosigner_token: Option<String>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
@@ -161,6 +284,10 @@ pub struct Command {
     #[cfg(feature = "relayer")]
     pub relayer_args: relayer::RelayerArgs,
 
+    #[cfg_attr(feature = "relayer", clap(flatten))]
+    #[cfg(feature = "relayer")]
+    pub block_committer_args: relayer::BlockCommitterArgs,
+
     #[cfg_attr(feature = "p2p", clap(flatten))]
     #[cfg(feature = "p2p")]
     pub p2p_args: p2p::P2PArgs,
@@ -169,6 +296,10 @@ pub struct Command {
     #[cfg(feature = "p2p")]
     pub sync_args: p2p::SyncArgs,
 
+    #[cfg_attr(feature = "fault-injection", clap(flatten))]
+    #[cfg(feature = "fault-injection")]
+    pub fault_injection_args: fault_injection::FaultInjectionArgs,
+
     #[arg(long = "metrics", env)]
     pub metrics: bool,
 
@@ -194,6 +325,31 @@ pub struct Command {
     #[clap(long = "tx-number-active-subscriptions", default_value = "4064", env)]
     pub tx_number_active_subscriptions: usize,
 
+    /// The sponsor that pays the fees of the transactions of other users, in the
+    /// `<ADDRESS>:<MAX_TXS>` format, where `MAX_TXS` is the max number of the sponsored
+    /// transactions admitted into the `TxPool` per `tx-sponsor-period`.
+    #[arg(long = "tx-sponsor", value_parser = parse_sponsor, value_delimiter = ',', env)]
+    pub tx_sponsors: Vec<(Address, usize)>,
+
+    /// The period of the rate limit of the sponsors.
+    #[clap(long = "tx-sponsor-period", default_value = "1m", env)]
+    pub tx_sponsor_period: humantime::Duration,
+
+    /// The strategy of the selection of the transactions into the next block:
+    /// `fee-priority`, `fifo` or `deadline-first`.
+    #[clap(long = "tx-selection-strategy", default_value = "fee-priority", env)]
+    pub tx_selection_strategy: SelectionStrategy,
+
+    /// How often the transactions of the `TxPool` are re-validated against the minimal
+    /// gas price and the latest state. The transactions invalidated by the recent blocks
+    /// are expired before the block production. `0s` disables the re-validation.
+    #[clap(long = "tx-revalidation-interval", default_value = "10s", env)]
+    pub tx_revalidation_interval: humantime::Duration,
+
+    #[cfg_attr(feature = "wasm-policy", clap(flatten))]
+    #[cfg(feature = "wasm-policy")]
+    pub tx_policy_args: tx_policy::TxPolicyArgs,
+
     /// The number of reserved peers to connect to before starting to sync.
     #[clap(long = "min-connected-reserved-peers", default_value = "0", env)]
     pub min_connected_reserved_peers: usize,
@@ -206,6 +362,106 @@ pub struct Command {
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
 
+    /// Collects the execution statistics of the GraphQL operations by their names.
+    /// The statistics are served by the admin API on `/graphql/query-stats`.
+    #[clap(long = "enable-query-stats", env)]
+    pub enable_query_stats: bool,
+
+    /// The number of the latest block and transaction status events buffered for
+    /// the resumable subscriptions. The reconnected clients receive the events they
+    /// missed from the buffer. `0` disables the resumable subscriptions.
+    #[clap(long = "subscription-buffer-size", default_value = "10000", env)]
+    pub subscription_buffer_size: usize,
+
+    /// The JSON file with the settings that are applied to the running node at the start
+    /// and on each `SIGHUP`. The keys are the names of the flags. Only the log filter
+    /// (`log-filter`), the `TxPool` limits and the relayer intervals are reloadable,
+    /// other settings are reported as requiring the restart.
+    #[clap(long = "config-file", env)]
+    pub config_file: Option<PathBuf>,
+
+    /// The number of the recent blocks with the cached Merkle trees of the output messages.
+    /// The trees are built on the block import and reused by the `messageProof` queries.
+    /// `0` disables the cache.
+    #[clap(long = "message-proof-cache-size", default_value = "1024", env)]
+    pub message_proof_cache_size: usize,
+
+    /// How long the history of the transaction statuses is kept after the first status.
+    /// The history includes the statuses that aren't stored otherwise, like the
+    /// submission or the squeezing out of the `TxPool`. `0s` disables the archive.
+    #[clap(long = "tx-status-retention", default_value = "1h", env)]
+    pub tx_status_retention: humantime::Duration,
+
+    /// How often the approximate size, key count and write rate of each database
+    /// table are refreshed. They are reported by the `nodeInfo` query, the admin API
+    /// and the metrics. `0s` disables the statistics.
+    #[clap(long = "storage-stats-interval", default_value = "1m", env)]
+    pub storage_stats_interval: humantime::Duration,
+
+    /// Enables the registry of the assets issued by the contracts. It indexes the mint
+    /// and burn receipts of the imported blocks, starting from the genesis, and serves
+    /// the metadata of the assets via the `assetMetadata` query.
+    #[arg(long = "enable-asset-registry", env)]
+    pub enable_asset_registry: bool,
+
+    /// Enables the account watcher. The clients receive the received and spent coins
+    /// and the received messages of the addresses via the `accountEvents` subscription.
+    /// The addresses are watched while the subscription is active.
+    #[arg(long = "enable-account-watcher", env)]
+    pub enable_account_watcher: bool,
+
+    /// Enables the analytics of the calls of the contracts in the window of the given
+    /// number of the latest blocks. It counts the call receipts of the imported blocks
+    /// and serves the most called contracts via the `topContracts` query.
+    #[arg(long = "contract-calls-window", env)]
+    pub contract_calls_window: Option<u32>,
+
+    /// The consistency checks of the database run before the node starts serving.
+    /// The `fast` checks verify the latest block against its transactions and
+    /// the Merkle root of the previous blocks, the `full` checks verify all blocks
+    /// and the relayer's finalized DA height against the stored messages.
+    /// The node refuses to start if any check fails.
+    #[clap(
+        long = "startup-checks",
+        default_value = "off",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub startup_checks: StartupChecks,
+
+    /// Moves the bytecode of the contracts stored before the deduplication into
+    /// the table shared by the contracts with the same bytecode, and removes
+    /// the bytecode not referenced by any contract. Runs before the node starts
+    /// serving, so it may take a while on the large databases.
+    #[arg(long = "compact-contracts-bytecode", env)]
+    pub compact_contracts_bytecode: bool,
+
+    #[clap(flatten)]
+    pub state_diff_args: state_diff::StateDiffArgs,
+
+    #[cfg_attr(feature = "event-bus", clap(flatten))]
+    #[cfg(feature = "event-bus")]
+    pub event_bus_args: event_bus::EventBusArgs,
+
+    #[clap(flatten)]
+    pub disk_monitor: disk_monitor::DiskMonitorArgs,
+
+    #[clap(flatten)]
+    pub compaction: compaction::CompactionArgs,
+
+    #[clap(flatten)]
+    pub resource_budget: resource_budget::ResourceBudgetArgs,
+
+    #[clap(flatten)]
+    pub stage_deadlines: stage_deadlines::StageDeadlinesArgs,
+
+    #[clap(flatten)]
+    pub persisted_queries: persisted_queries::PersistedQueriesArgs,
+
+    #[clap(flatten)]
+    pub admin_api: admin_api::AdminApiArgs,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
 }
@@ -215,25 +471,47 @@ impl Command {
         let Command {
             ip,
             port,
+            #[cfg(feature = "grpc")]
+            grpc_addr,
+            mode,
             service_name: name,
             max_database_cache_size,
             database_path,
             database_type,
+            durability,
             chain_config,
+            #[cfg(feature = "object-storage")]
+            snapshot_from_url: _,
+            #[cfg(feature = "object-storage")]
+            snapshot_manifest_sha256: _,
             vm_backtrace,
             debug,
             utxo_validation,
+            dry_run_max_height_offset,
+            dry_run_max_time_offset,
             min_gas_price,
+            starting_gas_price,
+            gas_price_change_percent,
+            gas_price_threshold_percent,
+            enforce_gas_price,
+            da_source_args,
             consensus_key,
+            consensus_cosigner_keys,
+            consensus_cosigners,
+            consensus_cosigner_token,
             poa_trigger,
             consensus_dev_key,
             coinbase_recipient,
             #[cfg(feature = "relayer")]
             relayer_args,
+            #[cfg(feature = "relayer")]
+            block_committer_args,
             #[cfg(feature = "p2p")]
             p2p_args,
             #[cfg(feature = "p2p")]
             sync_args,
+            #[cfg(feature = "fault-injection")]
+            fault_injection_args,
             metrics,
             max_da_lag,
             max_wait_time,
@@ -241,22 +519,67 @@ impl Command {
             tx_max_number,
             tx_max_depth,
             tx_number_active_subscriptions,
+            tx_sponsors,
+            tx_sponsor_period,
+            tx_selection_strategy,
+            tx_revalidation_interval,
+            #[cfg(feature = "wasm-policy")]
+            tx_policy_args,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
+            enable_query_stats,
+            subscription_buffer_size,
+            config_file: _,
+            message_proof_cache_size,
+            tx_status_retention,
+            storage_stats_interval,
+            enable_asset_registry,
+            enable_account_watcher,
+            contract_calls_window,
+            startup_checks,
+            compact_contracts_bytecode,
+            state_diff_args,
+            #[cfg(feature = "event-bus")]
+            event_bus_args,
+            disk_monitor,
+            compaction,
+            resource_budget,
+            stage_deadlines,
+            persisted_queries,
+            admin_api: _,
             profiling: _,
         } = self;
 
         let addr = net::SocketAddr::new(ip, port);
 
+        if mode == NodeMode::ReadReplica && state_diff_args.state_diff_upstream.is_none()
+        {
+            return Err(anyhow!("The read replica requires the `--primary`"))
+        }
+
         let chain_conf: ChainConfig = chain_config.as_str().parse()?;
 
+        #[cfg(feature = "relayer")]
+        let origin_relayers_cfg = relayer_args.origin_relayers();
         #[cfg(feature = "relayer")]
         let relayer_cfg = relayer_args.into_config();
 
+        #[cfg(feature = "relayer")]
+        let commitment_reader_cfg = block_committer_args.reader_config();
+        #[cfg(feature = "relayer")]
+        let block_committer_cfg = block_committer_args.into_config()?;
+
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(metrics)?;
 
+        #[cfg(feature = "wasm-policy")]
+        let admission_policy = tx_policy_args.into_policy()?;
+        #[cfg(not(feature = "wasm-policy"))]
+        let admission_policy = None;
+
+        let persisted_queries = persisted_queries.into_config()?;
+
         let trigger: Trigger = poa_trigger.into();
 
         if trigger != Trigger::Never {
@@ -284,6 +607,28 @@ impl Command {
             warn!("Consensus key configured but block production is disabled!")
         }
 
+        let consensus_cosigner_keys = consensus_cosigner_keys
+            .iter()
+            .map(|key| {
+                let key = SecretKey::from_str(key)
+                    .context("failed to parse consensus cosigner key")?;
+                Ok(Secret::new(key.into()))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if !consensus_cosigner_keys.is_empty() && consensus_key.is_none() {
+            return Err(anyhow!(
+                "The consensus cosigner keys require the consensus key"
+            ))
+        }
+
+        let consensus_cosigners = consensus_cosigners
+            .into_iter()
+            .map(|url| RemoteCosignerConfig {
+                url,
+                token: Secret::new(consensus_cosigner_token.clone().unwrap_or_default()),
+            })
+            .collect();
+
         let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
             Address::from_str(coinbase_recipient.as_str()).map_err(|err| anyhow!(err))?
         } else {
@@ -302,11 +647,32 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
+        let block_limits = chain_conf.block_limits();
+        let gas_price = GasPriceConfig {
+            starting_gas_price,
+            min_gas_price,
+            gas_price_change_percent,
+            gas_price_threshold_percent,
+            block_gas_limit: chain_conf.block_gas_limit,
+            chain_id: chain_conf.consensus_parameters.chain_id,
+            enforce_gas_price,
+            blob_fee: da_source_args.blob_fee_config(),
+            da_source: da_source_args.into_config(),
+        };
+
+        let disk_monitor =
+            disk_monitor.into_config(database_path.clone(), database_type.clone())?;
+        let compaction_scheduler = compaction.into_config(database_type.clone())?;
+
         let config = Config {
             addr,
+            #[cfg(feature = "grpc")]
+            grpc_addr,
+            mode,
             max_database_cache_size,
             database_path,
             database_type,
+            database_durability: durability.into_durability(),
             chain_conf: chain_conf.clone(),
             debug,
             utxo_validation,
@@ -314,35 +680,84 @@ impl Command {
             vm: VMConfig {
                 backtrace: vm_backtrace,
             },
-            txpool: TxPoolConfig::new(
-                tx_max_number,
-                tx_max_depth,
-                chain_conf,
-                min_gas_price,
-                utxo_validation,
-                metrics,
-                tx_pool_ttl.into(),
-                tx_number_active_subscriptions,
-            ),
+            txpool: TxPoolConfig {
+                admission_policy,
+                sponsorship: SponsorshipConfig {
+                    sponsors: tx_sponsors.into_iter().collect(),
+                    period: tx_sponsor_period.into(),
+                },
+                selection_strategy: Arc::new(tx_selection_strategy),
+                revalidation_interval: (!tx_revalidation_interval.is_zero())
+                    .then(|| tx_revalidation_interval.into()),
+                ..TxPoolConfig::new(
+                    tx_max_number,
+                    tx_max_depth,
+                    chain_conf,
+                    min_gas_price,
+                    utxo_validation,
+                    metrics,
+                    tx_pool_ttl.into(),
+                    tx_number_active_subscriptions,
+                )
+            },
             block_producer: ProducerConfig {
                 utxo_validation,
                 coinbase_recipient,
+                block_limits,
+                stage_deadlines: stage_deadlines.into_deadlines(),
+                dry_run_limits: DryRunLimits {
+                    max_height_offset: dry_run_max_height_offset,
+                    max_time_offset: dry_run_max_time_offset.into(),
+                },
                 metrics,
             },
             block_executor: Default::default(),
-            block_importer: Default::default(),
+            block_importer: fuel_core::importer::Config {
+                block_limits,
+                ..state_diff_args.importer_config()
+            },
+            gas_price,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
+            #[cfg(feature = "relayer")]
+            block_committer: block_committer_cfg,
+            #[cfg(feature = "relayer")]
+            commitment_reader: commitment_reader_cfg,
+            #[cfg(feature = "relayer")]
+            origin_relayers: origin_relayers_cfg,
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             consensus_key,
+            consensus_cosigner_keys,
+            consensus_cosigners,
+            #[cfg(feature = "fault-injection")]
+            fault_injection: fault_injection_args.into(),
             name,
             verifier,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            query_stats: enable_query_stats,
+            persisted_queries,
+            subscription_buffer_size,
+            message_proof_cache_size,
+            tx_status_retention: (!tx_status_retention.is_zero())
+                .then(|| tx_status_retention.into()),
+            storage_stats_interval: (!storage_stats_interval.is_zero())
+                .then(|| storage_stats_interval.into()),
+            asset_registry: enable_asset_registry,
+            account_watcher: enable_account_watcher,
+            contract_calls_window,
+            state_diff_upstream: state_diff_args.upstream_config(),
+            disk_monitor,
+            startup_checks,
+            compact_contracts_bytecode,
+            compaction_scheduler,
+            resource_limits: resource_budget.into_limits(),
+            #[cfg(feature = "event-bus")]
+            event_bus: event_bus_args.into_config(),
         };
         Ok(config)
     }
@@ -364,6 +779,42 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     .to_string();
 
     let profiling = command.profiling.clone();
+    let admin_api_config = command.admin_api.clone().into_config();
+    let mut config_watcher = command
+        .config_file
+        .clone()
+        .map(config_watcher::ConfigWatcher::new);
+    // Fails before starting the node if the config file is invalid.
+    let changes = config_watcher
+        .as_mut()
+        .map(config_watcher::ConfigWatcher::read)
+        .transpose()?;
+    #[cfg(feature = "object-storage")]
+    let mut command = command;
+    // The downloaded snapshot is removed after the config is read from it.
+    #[cfg(feature = "object-storage")]
+    let _snapshot_file = match (
+        command.snapshot_from_url.take(),
+        command.snapshot_manifest_sha256.take(),
+    ) {
+        (Some(url), Some(manifest_sha256)) => {
+            let file = tempfile::Builder::new()
+                .prefix("fuel-core-snapshot-")
+                .suffix(".json")
+                .tempfile()?;
+            info!("Downloading the snapshot from {url}");
+            let manifest = crate::cli::snapshot::object_storage::download(
+                &url,
+                &manifest_sha256,
+                file.path(),
+            )
+            .await?;
+            info!("The snapshot of {} bytes is verified", manifest.size);
+            command.chain_config = file.path().display().to_string();
+            Some(file)
+        }
+        _ => None,
+    };
     let config = command.get_config()?;
 
     // start profiling agent if url is configured
@@ -374,14 +825,42 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     trace!("Initializing in TRACE mode.");
     // initialize the server
     let server = FuelService::new_node(config).await?;
+    if let Some(changes) = changes {
+        config_watcher::log(&changes.apply(&server.shared));
+    }
+    let config_reloader = config_watcher.map(|watcher| {
+        Arc::new(config_watcher::ConfigReloader::new(
+            watcher,
+            server.shared.clone(),
+        ))
+    });
+    let admin_api = admin_api_config
+        .map(|config| {
+            fuel_core::admin_api::service::new_service(
+                config,
+                server.shared.clone(),
+                Some(Arc::new(ReloadableLogFilter)),
+                config_reloader
+                    .clone()
+                    .map(|reloader| reloader as ConfigReload),
+            )
+        })
+        .transpose()?;
+    if let Some(admin_api) = &admin_api {
+        admin_api.start_and_await().await?;
+    }
     // pause the main task while service is running
     tokio::select! {
         result = server.await_stop() => {
             result?;
         }
         _ = shutdown_signal() => {}
+        _ = config_watcher::watch(config_reloader) => {}
     }
 
+    if let Some(admin_api) = admin_api {
+        admin_api.stop_and_await().await?;
+    }
     server.stop_and_await().await?;
 
     Ok(())
@@ -407,6 +886,17 @@ fn load_consensus_key(
     }
 }
 
+fn parse_sponsor(input: &str) -> anyhow::Result<(Address, usize)> {
+    let (sponsor, max_txs) = input
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Expected `<ADDRESS>:<MAX_TXS>`, got `{input}`"))?;
+    let sponsor = Address::from_str(sponsor).map_err(|err| anyhow!(err))?;
+    let max_txs = max_txs
+        .parse()
+        .context("failed to parse the max number of the sponsored transactions")?;
+    Ok((sponsor, max_txs))
+}
+
 fn start_pyroscope_agent(
     profiling_args: profiling::ProfilingArgs,
     config: &Config,
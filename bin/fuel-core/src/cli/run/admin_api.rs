@@ -0,0 +1,92 @@
+use crate::cli::DEFAULT_SNAPSHOT_PATH;
+use clap::Args;
+use fuel_core::{
+    admin_api::Config,
+    types::secrecy::Secret,
+};
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct AdminApiArgs {
+    /// Enables the admin API on the address, e.g. `127.0.0.1:4001`. The admin API hosts
+    /// the operational endpoints (pause the relayer, ban a peer, change the log filter,
+    /// create a snapshot, reload the config file) separately from the public GraphQL API.
+    #[clap(long = "admin-api-addr", env, requires = "admin_api_token")]
+    pub admin_api_addr: Option<SocketAddr>,
+
+    /// The token that the admin API expects in the `Authorization: Bearer <token>` header.
+    /// Prefer the env variable over the flag to not expose the token in the process list.
+    #[clap(
+        long = "admin-api-token",
+        env = "ADMIN_API_TOKEN",
+        hide_env_values = true
+    )]
+    pub admin_api_token: Option<String>,
+
+    /// The directory where the admin API writes the snapshots of the state.
+    #[clap(
+        long = "admin-api-snapshot-dir",
+        env,
+        default_value = (*DEFAULT_SNAPSHOT_PATH).to_str().unwrap()
+    )]
+    pub admin_api_snapshot_dir: PathBuf,
+}
+
+impl AdminApiArgs {
+    pub fn into_config(self) -> Option<Config> {
+        let addr = self.admin_api_addr?;
+        let token = self
+            .admin_api_token
+            .expect("The token is required by the `admin-api-addr`");
+        Some(Config {
+            addr,
+            token: Secret::new(token),
+            snapshot_dir: self.admin_api_snapshot_dir,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        admin_api: AdminApiArgs,
+    }
+
+    #[test]
+    fn admin_api_is_disabled_by_default() {
+        let command = Command::try_parse_from([""]).unwrap();
+
+        assert!(command.admin_api.into_config().is_none());
+    }
+
+    #[test]
+    fn admin_api_requires_token() {
+        let result = Command::try_parse_from(["", "--admin-api-addr=127.0.0.1:4001"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn admin_api_config_is_created() {
+        let command = Command::try_parse_from([
+            "",
+            "--admin-api-addr=127.0.0.1:4001",
+            "--admin-api-token=secret",
+            "--admin-api-snapshot-dir=/tmp/snapshots",
+        ])
+        .unwrap();
+
+        let config = command.admin_api.into_config().unwrap();
+
+        assert_eq!(config.addr, "127.0.0.1:4001".parse().unwrap());
+        assert_eq!(config.snapshot_dir, PathBuf::from("/tmp/snapshots"));
+    }
+}
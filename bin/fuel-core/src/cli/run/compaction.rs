@@ -0,0 +1,105 @@
+use clap::Args;
+use fuel_core::{
+    database::Column,
+    service::{
+        compaction_scheduler::{
+            CompactionWindow,
+            Config,
+        },
+        config::DbType,
+    },
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct CompactionArgs {
+    /// The comma-separated database tables compacted during the `--compaction-window`
+    /// and by the `/storage/compaction` endpoint of the admin API by default, like
+    /// `ContractsState,Coins`. Unset disables the compaction scheduler.
+    #[clap(long = "compaction-tables", value_delimiter = ',', env)]
+    pub compaction_tables: Vec<Column>,
+
+    /// The low-traffic time of the day in UTC when the tables are compacted,
+    /// like `02:00-04:00`. Unset allows only the manual compaction via the admin API.
+    #[clap(long = "compaction-window", requires = "compaction_tables", env)]
+    pub compaction_window: Option<CompactionWindow>,
+
+    /// How often the compaction debt of the tables is refreshed
+    /// and the window is checked. It must be positive.
+    #[clap(long = "compaction-check-interval", default_value = "1m", env)]
+    pub compaction_check_interval: humantime::Duration,
+}
+
+impl CompactionArgs {
+    pub fn into_config(self, database_type: DbType) -> anyhow::Result<Option<Config>> {
+        if self.compaction_tables.is_empty() || database_type == DbType::InMemory {
+            return Ok(None)
+        }
+        anyhow::ensure!(
+            !self.compaction_check_interval.is_zero(),
+            "The `--compaction-check-interval` must be positive"
+        );
+        Ok(Some(Config {
+            columns: self.compaction_tables,
+            window: self.compaction_window,
+            interval: self.compaction_check_interval.into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        compaction: CompactionArgs,
+    }
+
+    fn config(args: &[&str]) -> anyhow::Result<Option<Config>> {
+        let command = Command::try_parse_from([""].iter().chain(args))?;
+        command.compaction.into_config(DbType::RocksDb)
+    }
+
+    #[test]
+    fn tables_and_window_are_parsed() {
+        let config = config(&[
+            "--compaction-tables",
+            "ContractsState,coins",
+            "--compaction-window",
+            "02:00-04:00",
+        ])
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.columns, vec![Column::ContractsState, Column::Coins]);
+        assert_eq!(config.window, Some("02:00-04:00".parse().unwrap()));
+    }
+
+    #[test]
+    fn zero_check_interval_is_rejected() {
+        let result = config(&[
+            "--compaction-tables",
+            "Coins",
+            "--compaction-check-interval",
+            "0s",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn scheduler_is_disabled_by_default() {
+        assert!(config(&[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn unknown_tables_and_malformed_windows_are_rejected() {
+        assert!(config(&["--compaction-tables", "Unknown"]).is_err());
+        assert!(
+            config(&["--compaction-tables", "Coins", "--compaction-window", "2-4"])
+                .is_err()
+        );
+    }
+}
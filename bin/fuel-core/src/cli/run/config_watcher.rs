@@ -0,0 +1,320 @@
+//! Re-reads the config file of the node on `SIGHUP` and applies the changed settings
+//! that can be reloaded without restarting the node.
+//!
+//! The config file is a JSON object where the keys are the names of the command line
+//! flags of the `run` command, e.g. `{ "tx-max-number": 1000, "tx-pool-ttl": "1m" }`.
+//! The values use the same format as the command line flags.
+
+use crate::cli::{
+    run::Command,
+    set_log_filter,
+};
+use anyhow::Context;
+use clap::CommandFactory;
+use fuel_core::{
+    admin_api::ports::{
+        ConfigReloadPort,
+        ConfigReloadReport as Report,
+    },
+    service::{
+        ReloadableConfig,
+        SharedState,
+    },
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use std::{
+    path::PathBuf,
+    str::FromStr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tracing_subscriber::EnvFilter;
+
+/// The key of the log filter in the `RUST_LOG` syntax.
+const LOG_FILTER_KEY: &str = "log-filter";
+
+/// The keys of the reloadable settings with the names of the corresponding
+/// [`ReloadableConfig`] fields.
+const RELOADABLE_KEYS: [(&str, &str); 6] = [
+    ("tx-max-number", "tx_max_number"),
+    ("tx-max-depth", "tx_max_depth"),
+    ("tx-pool-ttl", "tx_pool_ttl"),
+    ("relayer-min-duration-s", "relayer_sync_minimum_duration"),
+    (
+        "relayer-eth-sync-call-freq-s",
+        "relayer_syncing_call_frequency",
+    ),
+    (
+        "relayer-eth-sync-log-freq-s",
+        "relayer_syncing_log_frequency",
+    ),
+];
+
+/// Tracks the content of the config file between the reloads.
+pub struct ConfigWatcher {
+    path: PathBuf,
+    values: Map<String, Value>,
+}
+
+/// The settings of the config file that were changed since the previous read.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Changes {
+    log_filter: Option<String>,
+    reloadable: ReloadableConfig,
+    requires_restart: Vec<String>,
+    unknown: Vec<String>,
+}
+
+/// Reloads the config file of the running node.
+pub struct ConfigReloader {
+    watcher: Mutex<ConfigWatcher>,
+    shared: SharedState,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            values: Default::default(),
+        }
+    }
+
+    /// Reads the config file and returns the settings changed since the previous read.
+    /// The removed keys are ignored, and the node keeps using their current values.
+    pub fn read(&mut self) -> anyhow::Result<Changes> {
+        let file = std::fs::read_to_string(&self.path).with_context(|| {
+            format!("failed to read the config file {}", self.path.display())
+        })?;
+        let values: Map<String, Value> =
+            serde_json::from_str(&file).with_context(|| {
+                format!("failed to parse the config file {}", self.path.display())
+            })?;
+        let changes = Changes::new(&self.values, &values)?;
+        self.values = values;
+        Ok(changes)
+    }
+}
+
+impl Changes {
+    fn new(
+        previous: &Map<String, Value>,
+        current: &Map<String, Value>,
+    ) -> anyhow::Result<Self> {
+        let flags: Vec<_> = Command::command()
+            .get_arguments()
+            .filter_map(|arg| arg.get_long().map(str::to_string))
+            .collect();
+        let mut changes = Changes::default();
+        let config = &mut changes.reloadable;
+
+        for (key, value) in current {
+            if previous.get(key) == Some(value) {
+                continue
+            }
+            let text = match value {
+                Value::String(text) => text.clone(),
+                value => value.to_string(),
+            };
+            let parse_error = || format!("invalid value `{text}` of `{key}`");
+            match key.as_str() {
+                LOG_FILTER_KEY => {
+                    EnvFilter::try_new(&text).with_context(parse_error)?;
+                    changes.log_filter = Some(text);
+                }
+                "tx-max-number" => {
+                    config.tx_max_number =
+                        Some(usize::from_str(&text).with_context(parse_error)?)
+                }
+                "tx-max-depth" => {
+                    config.tx_max_depth =
+                        Some(usize::from_str(&text).with_context(parse_error)?)
+                }
+                "tx-pool-ttl" => {
+                    config.tx_pool_ttl =
+                        Some(humantime::parse_duration(&text).with_context(parse_error)?)
+                }
+                "relayer-min-duration-s" => {
+                    config.relayer_sync_minimum_duration =
+                        Some(parse_secs(&text).with_context(parse_error)?)
+                }
+                "relayer-eth-sync-call-freq-s" => {
+                    config.relayer_syncing_call_frequency =
+                        Some(parse_secs(&text).with_context(parse_error)?)
+                }
+                "relayer-eth-sync-log-freq-s" => {
+                    config.relayer_syncing_log_frequency =
+                        Some(parse_secs(&text).with_context(parse_error)?)
+                }
+                key if flags.iter().any(|flag| flag == key) => {
+                    changes.requires_restart.push(key.to_string())
+                }
+                key => changes.unknown.push(key.to_string()),
+            }
+        }
+        Ok(changes)
+    }
+
+    /// Applies the changes to the running node.
+    pub fn apply(self, shared: &SharedState) -> Report {
+        let mut report = Report {
+            applied: vec![],
+            requires_restart: self.requires_restart,
+            unknown: self.unknown,
+        };
+        if let Some(filter) = self.log_filter {
+            match set_log_filter(&filter) {
+                Ok(()) => report.applied.push(LOG_FILTER_KEY.to_string()),
+                Err(err) => tracing::warn!("failed to update the log filter: {err:?}"),
+            }
+        }
+
+        let reloaded = shared.reload(&self.reloadable);
+        let key = |field: &str| {
+            RELOADABLE_KEYS
+                .iter()
+                .find(|(_, name)| *name == field)
+                .map(|(key, _)| key.to_string())
+                .expect("All fields of the `ReloadableConfig` have a key")
+        };
+        report.applied.extend(reloaded.applied.into_iter().map(key));
+        report
+            .requires_restart
+            .extend(reloaded.requires_restart.into_iter().map(key));
+        report
+    }
+}
+
+pub fn log(report: &Report) {
+    if !report.applied.is_empty() {
+        tracing::info!(
+            "Applied the settings from the config file: {:?}",
+            report.applied
+        );
+    }
+    if !report.requires_restart.is_empty() {
+        tracing::warn!(
+            "The settings from the config file require the restart of the node: {:?}",
+            report.requires_restart
+        );
+    }
+    if !report.unknown.is_empty() {
+        tracing::warn!("Unknown settings in the config file: {:?}", report.unknown);
+    }
+}
+
+impl ConfigReloader {
+    pub fn new(watcher: ConfigWatcher, shared: SharedState) -> Self {
+        Self {
+            watcher: Mutex::new(watcher),
+            shared,
+        }
+    }
+}
+
+impl ConfigReloadPort for ConfigReloader {
+    fn reload(&self) -> anyhow::Result<Report> {
+        let changes = self
+            .watcher
+            .lock()
+            .expect("The config watcher is poisoned")
+            .read()?;
+        let report = changes.apply(&self.shared);
+        log(&report);
+        Ok(report)
+    }
+}
+
+fn parse_secs(text: &str) -> anyhow::Result<Duration> {
+    Ok(Duration::from_secs(u64::from_str(text)?))
+}
+
+/// Reloads the config file on each `SIGHUP` until the node is stopped.
+/// Never completes if the `reloader` is not set.
+pub async fn watch(reloader: Option<Arc<ConfigReloader>>) {
+    #[cfg(unix)]
+    if let Some(reloader) = reloader {
+        let mut sighup = match tokio::signal::unix::signal(
+            tokio::signal::unix::SignalKind::hangup(),
+        ) {
+            Ok(sighup) => sighup,
+            Err(err) => {
+                tracing::error!("failed to listen for `SIGHUP`: {err:?}");
+                return std::future::pending().await
+            }
+        };
+        while sighup.recv().await.is_some() {
+            tracing::info!("sighup received, reloading the config file");
+            if let Err(err) = reloader.reload() {
+                tracing::error!("failed to reload the config file: {err:?}");
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = reloader;
+    std::future::pending().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn values(json: &str) -> Map<String, Value> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test_case(
+        r#"{"tx-max-number": 10, "tx-pool-ttl": "1m", "log-filter": "debug"}"# => Changes {
+            log_filter: Some("debug".to_string()),
+            reloadable: ReloadableConfig {
+                tx_max_number: Some(10),
+                tx_pool_ttl: Some(Duration::from_secs(60)),
+                ..Default::default()
+            },
+            ..Default::default()
+        }; "reloadable settings"
+    )]
+    #[test_case(
+        r#"{"relayer-min-duration-s": "3", "utxo-validation": true, "unknown": 1}"# => Changes {
+            reloadable: ReloadableConfig {
+                relayer_sync_minimum_duration: Some(Duration::from_secs(3)),
+                ..Default::default()
+            },
+            requires_restart: vec!["utxo-validation".to_string()],
+            unknown: vec!["unknown".to_string()],
+            ..Default::default()
+        }; "not reloadable and unknown settings"
+    )]
+    fn changes_from_empty_file(json: &str) -> Changes {
+        Changes::new(&Map::new(), &values(json)).unwrap()
+    }
+
+    #[test]
+    fn changes_skip_unchanged_settings() {
+        let previous = values(r#"{"tx-max-number": 10, "tx-max-depth": 5}"#);
+        let current = values(r#"{"tx-max-number": 10, "tx-max-depth": 6}"#);
+
+        let changes = Changes::new(&previous, &current).unwrap();
+
+        assert_eq!(
+            changes.reloadable,
+            ReloadableConfig {
+                tx_max_depth: Some(6),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test_case(r#"{"tx-max-number": -1}"#; "negative number")]
+    #[test_case(r#"{"tx-pool-ttl": "soon"}"#; "invalid duration")]
+    #[test_case(r#"{"log-filter": "[["}"#; "invalid log filter")]
+    fn changes_reject_invalid_values(json: &str) {
+        assert!(Changes::new(&Map::new(), &values(json)).is_err());
+    }
+}
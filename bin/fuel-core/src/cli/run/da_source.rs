@@ -0,0 +1,69 @@
+use clap::Args;
+use fuel_core::{
+    gas_price::config::{
+        BlobFeeConfig,
+        DaSourceConfig,
+    },
+    types::fuel_crypto::PublicKey,
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Args)]
+pub struct DaSourceArgs {
+    /// The URL of the block committer API that reports the DA costs.
+    /// If not set, the DA costs are not included into the gas price.
+    #[arg(long = "da-committer-url", env)]
+    #[arg(requires = "da_committer_public_key")]
+    pub da_committer_url: Option<String>,
+
+    /// The public key of the block committer. The DA costs not signed by it are rejected.
+    #[arg(long = "da-committer-public-key", value_parser = parse_public_key, env)]
+    pub da_committer_public_key: Option<PublicKey>,
+
+    /// How often to request the DA costs from the block committer.
+    #[arg(long = "da-polling-interval", default_value = "10s", env)]
+    pub da_polling_interval: humantime::Duration,
+
+    /// The DA gas price used while the block committer is unreachable and no costs
+    /// were accepted yet. Afterwards, the last accepted DA gas price is kept.
+    #[arg(long = "da-fallback-gas-price", default_value = "0", env)]
+    pub da_fallback_gas_price: u64,
+
+    /// How often to request the blob base fee from the Ethereum node used by the relayer.
+    /// If set, the DA gas price follows the blob base fee instead of the costs reported
+    /// by the block committer.
+    #[arg(long = "da-blob-fee-polling-interval", env)]
+    pub da_blob_fee_polling_interval: Option<humantime::Duration>,
+
+    /// The expected size of the L2 block posted to the DA layer in bytes. It is used to
+    /// price the blob until the block committer reports the actual size of the blocks.
+    #[arg(long = "da-blob-bytes-per-block", default_value = "10000", env)]
+    pub da_blob_bytes_per_block: u64,
+}
+
+pub fn parse_public_key(input: &str) -> anyhow::Result<PublicKey> {
+    PublicKey::from_str(input).map_err(|e| anyhow::anyhow!("Invalid public key: {e:?}"))
+}
+
+impl DaSourceArgs {
+    pub fn blob_fee_config(&self) -> Option<BlobFeeConfig> {
+        let polling_interval = self.da_blob_fee_polling_interval?;
+
+        Some(BlobFeeConfig {
+            polling_interval: polling_interval.into(),
+            bytes_per_block: self.da_blob_bytes_per_block,
+        })
+    }
+
+    pub fn into_config(self) -> Option<DaSourceConfig> {
+        let url = self.da_committer_url?;
+        let committer_public_key = self.da_committer_public_key?;
+
+        Some(DaSourceConfig {
+            url,
+            committer_public_key,
+            polling_interval: self.da_polling_interval.into(),
+            fallback_gas_price: self.da_fallback_gas_price,
+        })
+    }
+}
@@ -0,0 +1,113 @@
+use clap::Args;
+use fuel_core::service::{
+    config::DbType,
+    disk_monitor::Config,
+};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub struct DiskMonitorArgs {
+    /// Switches the node into the degraded mode when the disk with the database has
+    /// fewer free bytes. In the degraded mode, the node imports the blocks, but doesn't
+    /// accept new transactions and pauses the optional indexes. Unset disables
+    /// the monitoring.
+    #[clap(long = "min-free-disk-space", env)]
+    pub min_free_disk_space: Option<u64>,
+
+    /// The node leaves the degraded mode when the disk has more free bytes.
+    /// Defaults to twice the `--min-free-disk-space`.
+    #[clap(long = "resume-free-disk-space", requires = "min_free_disk_space", env)]
+    pub resume_free_disk_space: Option<u64>,
+
+    /// How often the free space of the disk is checked. It must be positive.
+    #[clap(long = "disk-check-interval", default_value = "10s", env)]
+    pub disk_check_interval: humantime::Duration,
+}
+
+impl DiskMonitorArgs {
+    pub fn into_config(
+        self,
+        database_path: PathBuf,
+        database_type: DbType,
+    ) -> anyhow::Result<Option<Config>> {
+        let Some(min_free_space) = self.min_free_disk_space else {
+            return Ok(None)
+        };
+        if database_type == DbType::InMemory {
+            return Ok(None)
+        }
+        let resume_free_space = self
+            .resume_free_disk_space
+            .unwrap_or_else(|| min_free_space.saturating_mul(2));
+        anyhow::ensure!(
+            resume_free_space >= min_free_space,
+            "The `--resume-free-disk-space` must not be less than the `--min-free-disk-space`"
+        );
+        anyhow::ensure!(
+            !self.disk_check_interval.is_zero(),
+            "The `--disk-check-interval` must be positive"
+        );
+        Ok(Some(Config {
+            path: database_path,
+            interval: self.disk_check_interval.into(),
+            min_free_space,
+            resume_free_space,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        disk_monitor: DiskMonitorArgs,
+    }
+
+    fn config(args: &[&str]) -> anyhow::Result<Option<Config>> {
+        let command = Command::try_parse_from([""].iter().chain(args))?;
+        command
+            .disk_monitor
+            .into_config(PathBuf::from("db"), DbType::RocksDb)
+    }
+
+    #[test]
+    fn resume_threshold_defaults_to_twice_the_min() {
+        let config = config(&["--min-free-disk-space", "100"]).unwrap().unwrap();
+
+        assert_eq!(config.min_free_space, 100);
+        assert_eq!(config.resume_free_space, 200);
+    }
+
+    #[test]
+    fn resume_threshold_below_the_min_is_rejected() {
+        let result = config(&[
+            "--min-free-disk-space",
+            "100",
+            "--resume-free-disk-space",
+            "50",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn zero_check_interval_is_rejected() {
+        let result = config(&[
+            "--min-free-disk-space",
+            "100",
+            "--disk-check-interval",
+            "0s",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn monitoring_is_disabled_by_default() {
+        assert!(config(&[]).unwrap().is_none());
+    }
+}
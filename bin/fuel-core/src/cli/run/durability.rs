@@ -0,0 +1,83 @@
+use clap::{
+    Args,
+    ValueEnum,
+};
+use fuel_core::state::Durability;
+use std::num::NonZeroU32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DurabilityMode {
+    /// Syncs every commit to the disk. Recommended for the block producers and
+    /// the validators.
+    Sync,
+    /// Syncs every `--db-sync-period` committed blocks to the disk.
+    Periodic,
+    /// Leaves the sync to the operating system. Recommended for the throwaway
+    /// networks.
+    Async,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct DurabilityArgs {
+    /// How often the writes of the database are synced to the disk. The database
+    /// survives the crash of the node in any mode, but the writes that aren't synced
+    /// are lost if the machine loses power or the operating system crashes. The node
+    /// then starts from the last synced block and imports the lost blocks again.
+    /// The block producer may produce a conflicting block at the lost height,
+    /// so it should use the `sync` mode.
+    #[clap(
+        long = "db-durability",
+        default_value = "async",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub db_durability: DurabilityMode,
+
+    /// The number of the committed blocks between the syncs in the `periodic` mode.
+    /// Up to `db-sync-period - 1` of the latest blocks may be lost.
+    #[clap(long = "db-sync-period", default_value = "10", env)]
+    pub db_sync_period: NonZeroU32,
+}
+
+impl DurabilityArgs {
+    pub fn into_durability(self) -> Durability {
+        match self.db_durability {
+            DurabilityMode::Sync => Durability::Sync,
+            DurabilityMode::Periodic => Durability::Periodic(self.db_sync_period),
+            DurabilityMode::Async => Durability::Async,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        durability: DurabilityArgs,
+    }
+
+    fn durability(args: &[&str]) -> Durability {
+        Command::try_parse_from([""].iter().chain(args))
+            .unwrap()
+            .durability
+            .into_durability()
+    }
+
+    #[test]
+    fn async_by_default() {
+        assert_eq!(durability(&[]), Durability::Async);
+    }
+
+    #[test]
+    fn periodic_uses_the_sync_period() {
+        let durability =
+            durability(&["--db-durability", "periodic", "--db-sync-period", "5"]);
+
+        assert_eq!(durability, Durability::Periodic(5.try_into().unwrap()));
+    }
+}
@@ -0,0 +1,33 @@
+use clap::Args;
+use fuel_core::service::event_bus::{
+    Config,
+    Role,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct EventBusArgs {
+    /// The URL of the Redis server of the event bus, e.g. `redis://127.0.0.1:6379`.
+    /// The executing node publishes the events of the block and the transaction status
+    /// subscriptions to it, and the API frontends serve the subscriptions from it.
+    #[clap(long = "event-bus-url", env, requires = "event_bus_role")]
+    pub event_bus_url: Option<String>,
+
+    /// The role of the node: `publisher` on the executing node,
+    /// `subscriber` on the API frontends.
+    #[clap(long = "event-bus-role", value_enum, ignore_case = true, env)]
+    pub event_bus_role: Option<Role>,
+
+    /// The pub/sub channel of the events.
+    #[clap(long = "event-bus-channel", default_value = "fuel-core-events", env)]
+    pub event_bus_channel: String,
+}
+
+impl EventBusArgs {
+    pub fn into_config(self) -> Option<Config> {
+        Some(Config {
+            url: self.event_bus_url?,
+            channel: self.event_bus_channel,
+            role: self.event_bus_role?,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use clap::Args;
+use fuel_core::service::fault_injection::FaultInjectionConfig;
+
+/// The byzantine faults injected into the P2P behaviour of the node, to test the
+/// defenses of the network. Never enable them on the production nodes.
+#[derive(Debug, Clone, Args)]
+pub struct FaultInjectionArgs {
+    /// Serves the block headers with the seals that don't match them.
+    #[clap(long = "fault-invalid-blocks", env)]
+    pub fault_invalid_blocks: bool,
+
+    /// Serves the headers of the conflicting blocks at the same heights, sealed with
+    /// the consensus key.
+    #[clap(long = "fault-equivocation", env)]
+    pub fault_equivocation: bool,
+
+    /// Doesn't serve the transactions of the blocks to the peers.
+    #[clap(long = "fault-withhold-bodies", env)]
+    pub fault_withhold_bodies: bool,
+
+    /// Delays the gossip of the new block heights and the transactions.
+    #[clap(long = "fault-gossip-delay", env)]
+    pub fault_gossip_delay: Option<humantime::Duration>,
+}
+
+impl From<FaultInjectionArgs> for FaultInjectionConfig {
+    fn from(args: FaultInjectionArgs) -> Self {
+        Self {
+            invalid_blocks: args.fault_invalid_blocks,
+            equivocation: args.fault_equivocation,
+            withhold_bodies: args.fault_withhold_bodies,
+            gossip_delay: args.fault_gossip_delay.map(Into::into),
+        }
+    }
+}
@@ -18,6 +18,7 @@ use fuel_core::{
     types::{
         fuel_crypto,
         fuel_crypto::SecretKey,
+        services::executor::STATE_TRANSITION_VERSION,
     },
 };
 use std::{
@@ -72,6 +73,33 @@ pub struct P2PArgs {
     #[clap(long = "max-headers-per-request", default_value = "100", env)]
     pub max_headers_per_request: u32,
 
+    /// Defers the subscription to the transaction gossip and rejects the submitted
+    /// transactions while the node is more than this number of blocks behind
+    /// the median height reported by the peers.
+    #[clap(long = "tx-gossip-sync-threshold", env)]
+    pub tx_gossip_sync_threshold: Option<u32>,
+
+    /// Stops speaking the previous version of the P2P protocols. By default, the node
+    /// speaks the latest and the previous versions, so it stays connected to the peers
+    /// that aren't upgraded yet. It should be set once the whole network is upgraded.
+    #[clap(long = "disable-previous-p2p-protocol", env)]
+    pub disable_previous_p2p_protocol: bool,
+
+    /// The maximum number of the concurrently served block and header requests of the
+    /// reserved nodes. Their requests are served before the requests of the other peers.
+    #[clap(long = "max-concurrent-reserved-requests", default_value = "16", env)]
+    pub max_concurrent_reserved_requests: usize,
+
+    /// The maximum number of the concurrently served block and header requests
+    /// of the peers that aren't reserved, like the nodes syncing the history.
+    #[clap(long = "max-concurrent-bulk-requests", default_value = "4", env)]
+    pub max_concurrent_bulk_requests: usize,
+
+    /// The maximum number of the queued block and header requests of the reserved
+    /// nodes and of the other peers. The requests above the limit are rejected.
+    #[clap(long = "max-queued-requests", default_value = "256", env)]
+    pub max_queued_requests: usize,
+
     /// Addresses of the bootstrap nodes
     /// They should contain PeerId within their `Multiaddr`
     #[clap(long = "bootstrap-nodes", value_delimiter = ',', env)]
@@ -292,6 +320,11 @@ impl P2PArgs {
             keypair: local_keypair,
             network_name: self.network.expect("mandatory value"),
             checksum: Default::default(),
+            chain_id: Default::default(),
+            genesis_block_id: Default::default(),
+            state_transition_versions: STATE_TRANSITION_VERSION
+                ..=STATE_TRANSITION_VERSION,
+            support_previous_protocol_version: !self.disable_previous_p2p_protocol,
             address: self
                 .address
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
@@ -299,6 +332,10 @@ impl P2PArgs {
             tcp_port: self.peering_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            tx_gossip_sync_threshold: self.tx_gossip_sync_threshold,
+            max_concurrent_reserved_requests: self.max_concurrent_reserved_requests,
+            max_concurrent_bulk_requests: self.max_concurrent_bulk_requests,
+            max_queued_requests: self.max_queued_requests,
             bootstrap_nodes: self.bootstrap_nodes,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
@@ -0,0 +1,34 @@
+use clap::Args;
+use fuel_core::fuel_core_graphql_api::persisted_queries::PersistedQueriesConfig;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Args)]
+pub struct PersistedQueriesArgs {
+    /// The max number of the GraphQL queries registered by the clients under their
+    /// SHA-256 hashes with the `persistedQuery` extension of the request.
+    /// `0` disables the registration.
+    #[clap(long = "persisted-queries-cache-size", default_value = "0", env)]
+    pub cache_size: usize,
+
+    /// The JSON file with the array of the only GraphQL queries executed by the node.
+    /// The whitespaces of the queries don't matter, and their hashes are accepted
+    /// without the registration.
+    #[clap(long = "query-allow-list", env)]
+    pub allow_list: Option<PathBuf>,
+}
+
+impl PersistedQueriesArgs {
+    pub fn into_config(self) -> anyhow::Result<Option<PersistedQueriesConfig>> {
+        let allow_list = self
+            .allow_list
+            .map(|path| PersistedQueriesConfig::load_allow_list(&path))
+            .transpose()?;
+        if self.cache_size == 0 && allow_list.is_none() {
+            return Ok(None)
+        }
+        Ok(Some(PersistedQueriesConfig {
+            cache_size: self.cache_size,
+            allow_list,
+        }))
+    }
+}
@@ -5,12 +5,22 @@ use clap::{
 use core::time::Duration;
 use fuel_core::{
     relayer::{
+        committer::{
+            Config as BlockCommitterConfig,
+            LocalWallet,
+            ReaderConfig as CommitmentReaderConfig,
+        },
         Config,
+        OriginConfig,
         H160,
+        U256,
     },
     types::blockchain::primitives::DaBlockHeight,
 };
-use std::str::FromStr;
+use std::{
+    num::NonZeroU32,
+    str::FromStr,
+};
 
 #[derive(Debug, Clone, Args)]
 pub struct RelayerArgs {
@@ -51,13 +61,135 @@ pub struct RelayerArgs {
 
     #[clap(long = "relayer-eth-sync-log-freq-s", default_value_t = Config::DEFAULT_SYNCING_LOG_FREQ.as_secs(), env)]
     pub syncing_log_frequency_secs: u64,
+
+    /// The number of the latest finalized DA blocks whose base fee and blob base fee
+    /// are kept for the gas price estimation. Zero disables the recording.
+    #[clap(long = "relayer-da-fee-window", default_value_t = Config::DEFAULT_DA_FEE_WINDOW, env)]
+    pub da_fee_window: usize,
+
+    /// The denominator of the blob base fee growth of the active Ethereum fork,
+    /// like `3338477` before the Prague upgrade. It must be positive.
+    #[clap(
+        long = "relayer-blob-base-fee-update-fraction",
+        default_value_t = Config::DEFAULT_BLOB_BASE_FEE_UPDATE_FRACTION,
+        value_parser = clap::value_parser!(u64).range(1..),
+        env
+    )]
+    pub blob_base_fee_update_fraction: u64,
+
+    /// The relayer of an additional DA network, in the
+    /// `<CHAIN_ID>,<DA_DEPLOY_HEIGHT>,<URL>,<CONTRACT>[,<CONTRACT>...]` format.
+    /// The messages of the network are stored under its chain id and aren't spendable.
+    /// The flag can be repeated, one per network. The relayers share the log page size
+    /// and the intervals of the main relayer.
+    #[arg(long = "origin-relayer", value_parser = parse_origin_relayer)]
+    pub origin_relayers: Vec<OriginRelayerArg>,
+}
+
+/// The settings of the relayer of an additional DA network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OriginRelayerArg {
+    pub origin_chain_id: u64,
+    pub da_deploy_height: u64,
+    pub relayer: url::Url,
+    pub contracts: Vec<H160>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BlockCommitterArgs {
+    /// Enable the block committer. It posts the commitments of the blocks to the DA layer
+    /// through the Ethereum node of the relayer, so the separate committer isn't required.
+    #[clap(long = "enable-block-committer", action, requires = "enable_relayer")]
+    pub enable_block_committer: bool,
+
+    /// The address of the `FuelChainState` contract that accepts the commitments.
+    /// Without `--enable-block-committer`, the node only reads the commitments
+    /// to serve the `withdrawals` query.
+    #[arg(long = "block-committer-state-contract", value_parser = parse_h160, env)]
+    #[arg(required_if_eq("enable_block_committer", "true"))]
+    pub state_contract: Option<H160>,
+
+    /// The hex-encoded private key of the Ethereum account that signs the commitments.
+    #[arg(long = "block-committer-key", env)]
+    #[arg(required_if_eq("enable_block_committer", "true"))]
+    pub key: Option<String>,
+
+    /// The commitment is posted for each block with the height divisible by the interval.
+    #[clap(long = "block-committer-interval", default_value = "1", env)]
+    pub commit_interval: NonZeroU32,
+
+    /// How often the balance of the Ethereum account of the block committer is checked.
+    #[clap(
+        long = "block-committer-balance-check-interval",
+        default_value = "1m",
+        env
+    )]
+    pub balance_check_interval: humantime::Duration,
+
+    /// The balance of the Ethereum account of the block committer in wei below which
+    /// the low balance is reported by the metrics and the `syncStatus` query.
+    #[clap(long = "block-committer-min-balance", env)]
+    pub min_balance: Option<u128>,
 }
 
 pub fn parse_h160(input: &str) -> Result<H160, <H160 as FromStr>::Err> {
     H160::from_str(input)
 }
 
+pub fn parse_origin_relayer(input: &str) -> anyhow::Result<OriginRelayerArg> {
+    let expected = "`<CHAIN_ID>,<DA_DEPLOY_HEIGHT>,<URL>,<CONTRACT>[,<CONTRACT>...]`";
+    let mut parts = input.split(',');
+    let (Some(origin_chain_id), Some(da_deploy_height), Some(relayer)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        anyhow::bail!("Expected {expected}, got `{input}`")
+    };
+    let contracts = parts
+        .map(|contract| {
+            parse_h160(contract)
+                .map_err(|e| anyhow::anyhow!("Invalid contract `{contract}`: {e}"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if contracts.is_empty() {
+        anyhow::bail!("Expected {expected}, got `{input}`")
+    }
+    Ok(OriginRelayerArg {
+        origin_chain_id: origin_chain_id.parse()?,
+        da_deploy_height: da_deploy_height.parse()?,
+        relayer: relayer.parse()?,
+        contracts,
+    })
+}
+
 impl RelayerArgs {
+    /// Returns the configs of the relayers of the additional DA networks.
+    pub fn origin_relayers(&self) -> Vec<OriginConfig> {
+        self.origin_relayers
+            .iter()
+            .map(|origin| OriginConfig {
+                origin_chain_id: origin.origin_chain_id,
+                relayer: Config {
+                    da_deploy_height: DaBlockHeight(origin.da_deploy_height),
+                    relayer: Some(origin.relayer.clone()),
+                    eth_v2_listening_contracts: origin.contracts.clone(),
+                    log_page_size: self.log_page_size,
+                    sync_minimum_duration: Duration::from_secs(
+                        self.sync_minimum_duration_secs,
+                    ),
+                    syncing_call_frequency: Duration::from_secs(
+                        self.syncing_call_frequency_secs,
+                    ),
+                    syncing_log_frequency: Duration::from_secs(
+                        self.syncing_log_frequency_secs,
+                    ),
+                    da_fee_window: 0,
+                    blob_base_fee_update_fraction: self.blob_base_fee_update_fraction,
+                    metrics: false,
+                },
+            })
+            .collect()
+    }
+
     pub fn into_config(self) -> Option<Config> {
         if !self.enable_relayer {
             tracing::info!("Relayer service disabled");
@@ -72,8 +204,118 @@ impl RelayerArgs {
             sync_minimum_duration: Duration::from_secs(self.sync_minimum_duration_secs),
             syncing_call_frequency: Duration::from_secs(self.syncing_call_frequency_secs),
             syncing_log_frequency: Duration::from_secs(self.syncing_log_frequency_secs),
+            da_fee_window: self.da_fee_window,
+            blob_base_fee_update_fraction: self.blob_base_fee_update_fraction,
             metrics: false,
         };
         Some(config)
     }
 }
+
+impl BlockCommitterArgs {
+    /// Returns the config of the read-only access to the commitments. It is used
+    /// to serve the withdrawals when the node doesn't post the commitments itself.
+    pub fn reader_config(&self) -> Option<CommitmentReaderConfig> {
+        self.state_contract
+            .map(|state_contract| CommitmentReaderConfig {
+                state_contract,
+                commit_interval: self.commit_interval,
+            })
+    }
+
+    pub fn into_config(self) -> anyhow::Result<Option<BlockCommitterConfig>> {
+        if !self.enable_block_committer {
+            return Ok(None)
+        }
+
+        let state_contract = self.state_contract.ok_or_else(|| {
+            anyhow::anyhow!("The block committer requires the state contract address")
+        })?;
+        let key = self.key.ok_or_else(|| {
+            anyhow::anyhow!("The block committer requires the signing key")
+        })?;
+        let wallet = LocalWallet::from_str(&key).map_err(|e| {
+            anyhow::anyhow!("Failed to parse the block committer key: {e}")
+        })?;
+
+        let config = BlockCommitterConfig {
+            state_contract,
+            wallet,
+            commit_interval: self.commit_interval,
+            balance_check_interval: self.balance_check_interval.into(),
+            min_wallet_balance: self.min_balance.map(U256::from),
+        };
+        Ok(Some(config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_origin_relayer_with_contracts() {
+        let origin = parse_origin_relayer(
+            "5,100,http://localhost:8545/,\
+             0x03E4538018285e1c03CCce2F92C9538c87606911,\
+             0x03E4538018285e1c03CCce2F92C9538c87606912",
+        )
+        .unwrap();
+
+        assert_eq!(origin.origin_chain_id, 5);
+        assert_eq!(origin.da_deploy_height, 100);
+        assert_eq!(origin.relayer.as_str(), "http://localhost:8545/");
+        assert_eq!(origin.contracts.len(), 2);
+    }
+
+    #[test]
+    fn parse_origin_relayer_requires_contract() {
+        assert!(parse_origin_relayer("5,100,http://localhost:8545/").is_err());
+        assert!(parse_origin_relayer("5,100").is_err());
+    }
+
+    #[test]
+    fn state_contract_without_committer_only_reads_commitments() {
+        #[derive(clap::Parser)]
+        struct Command {
+            #[clap(flatten)]
+            committer: BlockCommitterArgs,
+        }
+        let command = <Command as clap::Parser>::try_parse_from([
+            "",
+            "--block-committer-state-contract",
+            "0x03E4538018285e1c03CCce2F92C9538c87606911",
+            "--block-committer-interval",
+            "10",
+        ])
+        .unwrap();
+
+        let reader = command.committer.reader_config().unwrap();
+        assert_eq!(reader.commit_interval.get(), 10);
+        assert!(command.committer.into_config().unwrap().is_none());
+    }
+    #[test]
+    fn zero_blob_base_fee_update_fraction_is_rejected() {
+        #[derive(clap::Parser)]
+        struct Command {
+            #[clap(flatten)]
+            relayer: RelayerArgs,
+        }
+        let parse = |fraction: &str| {
+            <Command as clap::Parser>::try_parse_from([
+                "",
+                "--relayer-blob-base-fee-update-fraction",
+                fraction,
+            ])
+        };
+
+        assert!(parse("0").is_err());
+        assert_eq!(
+            parse("3338477")
+                .unwrap()
+                .relayer
+                .blob_base_fee_update_fraction,
+            3_338_477
+        );
+    }
+}
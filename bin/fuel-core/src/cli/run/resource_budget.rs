@@ -0,0 +1,86 @@
+use clap::Args;
+use fuel_core::services::ResourceLimits;
+
+#[derive(Debug, Clone, Args)]
+pub struct ResourceBudgetArgs {
+    /// The memory in bytes of the transactions of the `TxPool`. The new transactions
+    /// are rejected when the budget is exhausted. Unset doesn't limit the memory.
+    #[clap(long = "memory-budget", env)]
+    pub memory_budget: Option<usize>,
+
+    /// The memory in bytes of the caches, like the cached message trees of the API.
+    /// The entries aren't cached when the budget is exhausted, so the caches never
+    /// take the memory of the `TxPool`. Unset doesn't limit the memory.
+    #[clap(long = "cache-memory-budget", env)]
+    pub cache_memory_budget: Option<usize>,
+
+    /// The maximum number of the concurrent dry runs. The dry runs above the limit are
+    /// rejected with the error instead of waiting. Unset doesn't limit the dry runs.
+    #[clap(long = "max-concurrent-dry-runs", env)]
+    pub max_concurrent_dry_runs: Option<usize>,
+
+    /// The maximum number of the iterators over the database opened by the queries
+    /// of the API. The queries above the limit are rejected with the error.
+    /// Unset doesn't limit the iterators.
+    #[clap(long = "max-open-iterators", env)]
+    pub max_open_iterators: Option<usize>,
+}
+
+impl ResourceBudgetArgs {
+    pub fn into_limits(self) -> ResourceLimits {
+        ResourceLimits {
+            memory: self.memory_budget,
+            cache_memory: self.cache_memory_budget,
+            dry_runs: self.max_concurrent_dry_runs,
+            iterators: self.max_open_iterators,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Debug, Clone, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        resource_budget: ResourceBudgetArgs,
+    }
+
+    fn limits(args: &[&str]) -> ResourceLimits {
+        Command::try_parse_from([""].iter().chain(args))
+            .unwrap()
+            .resource_budget
+            .into_limits()
+    }
+
+    #[test]
+    fn resources_are_unlimited_by_default() {
+        assert_eq!(limits(&[]), ResourceLimits::default());
+    }
+
+    #[test]
+    fn limits_are_parsed() {
+        let limits = limits(&[
+            "--memory-budget",
+            "1000",
+            "--cache-memory-budget",
+            "100",
+            "--max-concurrent-dry-runs",
+            "4",
+            "--max-open-iterators",
+            "64",
+        ]);
+
+        assert_eq!(
+            limits,
+            ResourceLimits {
+                memory: Some(1000),
+                cache_memory: Some(100),
+                dry_runs: Some(4),
+                iterators: Some(64),
+            }
+        );
+    }
+}
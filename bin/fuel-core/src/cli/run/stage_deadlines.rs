@@ -0,0 +1,80 @@
+use clap::Args;
+use fuel_core::producer::StageDeadlines;
+use humantime::Duration;
+
+#[derive(Debug, Clone, Args)]
+pub struct StageDeadlinesArgs {
+    /// The deadline of the selection of the DA height of the produced block. If the
+    /// relayer doesn't catch up in time, the block doesn't include the new messages of
+    /// the DA layer. Unset waits for the relayer.
+    #[clap(long = "block-selection-deadline", env)]
+    pub selection: Option<Duration>,
+
+    /// The deadline of the execution of the produced block. The transactions of the
+    /// `TxPool` aren't included into the block after the deadline. Unset includes
+    /// the transactions up to the limits of the block.
+    #[clap(long = "block-execution-deadline", env)]
+    pub execution: Option<Duration>,
+
+    /// The deadline of the sealing and the import of the produced block. The gas limit
+    /// of the next block is halved while the sealing exceeds the deadline.
+    /// Unset doesn't limit the sealing.
+    #[clap(long = "block-sealing-deadline", env)]
+    pub sealing: Option<Duration>,
+}
+
+impl StageDeadlinesArgs {
+    pub fn into_deadlines(self) -> StageDeadlines {
+        StageDeadlines {
+            selection: self.selection.map(Into::into),
+            execution: self.execution.map(Into::into),
+            sealing: self.sealing.map(Into::into),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::time::Duration as StdDuration;
+
+    #[derive(Debug, Clone, Parser)]
+    struct Command {
+        #[clap(flatten)]
+        stage_deadlines: StageDeadlinesArgs,
+    }
+
+    fn deadlines(args: &[&str]) -> StageDeadlines {
+        Command::try_parse_from([""].iter().chain(args))
+            .unwrap()
+            .stage_deadlines
+            .into_deadlines()
+    }
+
+    #[test]
+    fn stages_are_unlimited_by_default() {
+        assert_eq!(deadlines(&[]), StageDeadlines::default());
+    }
+
+    #[test]
+    fn deadlines_are_parsed() {
+        let deadlines = deadlines(&[
+            "--block-selection-deadline",
+            "100ms",
+            "--block-execution-deadline",
+            "1s",
+            "--block-sealing-deadline",
+            "300ms",
+        ]);
+
+        assert_eq!(
+            deadlines,
+            StageDeadlines {
+                selection: Some(StdDuration::from_millis(100)),
+                execution: Some(StdDuration::from_secs(1)),
+                sealing: Some(StdDuration::from_millis(300)),
+            }
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use clap::Args;
+use fuel_core::{
+    service::state_diff_follower::Config,
+    types::secrecy::Secret,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct StateDiffArgs {
+    /// The number of the recent blocks whose state diffs are recorded on the import and
+    /// served to the follower nodes via the `/state-diffs/<height>` endpoint of the admin
+    /// API. The historical state of the contracts is restored from them. `0` disables
+    /// the recording.
+    #[clap(long = "state-diff-retention", default_value = "1000", env)]
+    pub state_diff_retention: u32,
+
+    /// The URL of the admin API of the upstream node, e.g. `http://127.0.0.1:4001`.
+    /// If set, the node follows the upstream by applying its state diffs without
+    /// the execution of the blocks. The node must start from the same genesis.
+    #[clap(
+        long = "state-diff-upstream",
+        visible_alias = "primary",
+        env,
+        requires = "state_diff_upstream_token"
+    )]
+    pub state_diff_upstream: Option<String>,
+
+    /// The admin API token of the upstream node.
+    #[clap(
+        long = "state-diff-upstream-token",
+        visible_alias = "primary-token",
+        env = "STATE_DIFF_UPSTREAM_TOKEN",
+        hide_env_values = true
+    )]
+    pub state_diff_upstream_token: Option<String>,
+
+    /// How often to request the next state diff while the follower is up to date
+    /// with the upstream.
+    #[clap(long = "state-diff-poll-interval", default_value = "1s", env)]
+    pub state_diff_poll_interval: humantime::Duration,
+}
+
+impl StateDiffArgs {
+    pub fn importer_config(&self) -> fuel_core::importer::Config {
+        fuel_core::importer::Config {
+            state_diff_retention: (self.state_diff_retention > 0)
+                .then_some(self.state_diff_retention),
+            ..Default::default()
+        }
+    }
+
+    pub fn upstream_config(self) -> Option<Config> {
+        let url = self.state_diff_upstream?;
+        let token = self
+            .state_diff_upstream_token
+            .expect("The token is required by the `state-diff-upstream`");
+        Some(Config {
+            url,
+            token: Secret::new(token),
+            poll_interval: self.state_diff_poll_interval.into(),
+        })
+    }
+}
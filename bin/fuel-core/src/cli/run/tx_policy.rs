@@ -0,0 +1,45 @@
+use clap::Args;
+use fuel_core::txpool::{
+    ports::AdmissionPolicy,
+    wasm_policy::{
+        WasmPolicy,
+        WasmPolicyLimits,
+    },
+};
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
+
+#[derive(Debug, Clone, Args)]
+pub struct TxPolicyArgs {
+    /// The WASM module with the extra policy for the admission of the transactions into
+    /// the `TxPool`. The module exports the `memory`, `alloc(len) -> ptr` and
+    /// `check(ptr, len) -> code` functions and can't import anything. The `check`
+    /// receives the canonically serialized transaction and returns `0` to accept it.
+    #[arg(long = "tx-admission-policy", env)]
+    pub tx_admission_policy: Option<PathBuf>,
+
+    /// The max fuel consumed by one check of the admission policy.
+    /// The transaction is rejected if the check runs out of the fuel.
+    #[arg(long = "tx-admission-policy-fuel", default_value = "10000000", env)]
+    pub tx_admission_policy_fuel: u64,
+
+    /// The max size of the memory of the admission policy in bytes.
+    #[arg(long = "tx-admission-policy-memory", default_value = "16777216", env)]
+    pub tx_admission_policy_memory: usize,
+}
+
+impl TxPolicyArgs {
+    pub fn into_policy(self) -> anyhow::Result<Option<Arc<dyn AdmissionPolicy>>> {
+        let Some(path) = self.tx_admission_policy else {
+            return Ok(None)
+        };
+        let limits = WasmPolicyLimits {
+            fuel: self.tx_admission_policy_fuel,
+            memory_bytes: self.tx_admission_policy_memory,
+        };
+        let policy = WasmPolicy::load(&path, limits)?;
+        Ok(Some(Arc::new(policy)))
+    }
+}
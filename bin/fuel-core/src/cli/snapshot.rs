@@ -1,10 +1,25 @@
 use crate::cli::DEFAULT_DB_PATH;
+use anyhow::Context;
 use clap::{
     Parser,
     Subcommand,
 };
-use fuel_core::types::fuel_types::ContractId;
-use std::path::PathBuf;
+use fuel_core::{
+    chain_config::{
+        ChainConfig,
+        StateConfig,
+    },
+    database::Database,
+    types::fuel_types::ContractId,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+#[cfg(feature = "object-storage")]
+pub mod object_storage;
+pub mod verify;
 
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
@@ -31,43 +46,65 @@ pub enum SubCommands {
         /// Specify either an alias to a built-in configuration or filepath to a JSON file.
         #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
         chain_config: String,
+        /// The file to write the snapshot to, instead of the stdout.
+        #[clap(long = "output")]
+        output: Option<PathBuf>,
+        /// Uploads the snapshot file with its checksummed manifest to the object
+        /// storage location, like `s3://bucket/snapshots/` or `gs://bucket/snapshots/`.
+        /// Prints the SHA-256 of the manifest, which the nodes bootstrapping from
+        /// the snapshot must trust.
+        #[cfg(feature = "object-storage")]
+        #[clap(long = "upload-to", requires = "output")]
+        upload_to: Option<url::Url>,
     },
-    /// Creates a config for the contract.
+    /// Creates a config for the contract with its bytecode, storage slots and balances.
+    /// It can be imported into the chain config of another network with the
+    /// `chain-config import-contract`.
     #[command(arg_required_else_help = true)]
     Contract {
         /// The id of the contract to snapshot.
         #[clap(long = "id")]
         contract_id: ContractId,
+        /// The height of the block after which the state of the contract is taken,
+        /// the latest one by default. The historical heights are restored from
+        /// the recorded state diffs (`state-diff-retention`).
+        #[clap(long = "height")]
+        height: Option<u32>,
+        /// The file to write the snapshot to, instead of the stdout.
+        #[clap(long = "output")]
+        output: Option<PathBuf>,
     },
+    /// Verifies the snapshot against the genesis block before the regenesis.
+    #[command(arg_required_else_help = true)]
+    Verify(verify::Command),
 }
 
 #[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
-pub async fn exec(command: Command) -> anyhow::Result<()> {
+fn open_database(path: &Path) -> anyhow::Result<Database> {
     Err(anyhow::anyhow!(
         "Rocksdb must be enabled to use the database at {}",
-        command.database_path.display()
+        path.display()
     ))
 }
 
 #[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
-pub async fn exec(command: Command) -> anyhow::Result<()> {
-    use anyhow::Context;
-    use fuel_core::{
-        chain_config::{
-            ChainConfig,
-            StateConfig,
-        },
-        database::Database,
-    };
-    let path = command.database_path;
+fn open_database(path: &Path) -> anyhow::Result<Database> {
     let data_source =
-        fuel_core::state::rocks_db::RocksDb::default_open(&path, None).context(
+        fuel_core::state::rocks_db::RocksDb::default_open(path, None).context(
             format!("failed to open database at path {}", path.display()),
         )?;
-    let db = Database::new(std::sync::Arc::new(data_source));
+    Ok(Database::new(std::sync::Arc::new(data_source)))
+}
 
+pub async fn exec(command: Command) -> anyhow::Result<()> {
     match command.subcommand {
-        SubCommands::Everything { chain_config } => {
+        SubCommands::Everything {
+            chain_config,
+            output,
+            #[cfg(feature = "object-storage")]
+            upload_to,
+        } => {
+            let db = open_database(&command.database_path)?;
             let config: ChainConfig = chain_config.parse()?;
             let state_conf = StateConfig::generate_state_config(db)?;
 
@@ -76,18 +113,67 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
                 ..config
             };
 
-            let stdout = std::io::stdout().lock();
+            match &output {
+                Some(output) => {
+                    let file = std::fs::File::create(output).with_context(|| {
+                        format!("failed to create {}", output.display())
+                    })?;
+                    serde_json::to_writer_pretty(
+                        std::io::BufWriter::new(file),
+                        &chain_conf,
+                    )
+                    .context("failed to dump snapshot to JSON")?;
+                }
+                None => {
+                    let stdout = std::io::stdout().lock();
+
+                    serde_json::to_writer_pretty(stdout, &chain_conf)
+                        .context("failed to dump snapshot to JSON")?;
+                }
+            }
 
-            serde_json::to_writer_pretty(stdout, &chain_conf)
-                .context("failed to dump snapshot to JSON")?;
+            #[cfg(feature = "object-storage")]
+            if let (Some(output), Some(url)) = (output, upload_to) {
+                let (manifest, manifest_sha256) =
+                    object_storage::upload(&output, &url).await?;
+                eprintln!(
+                    "The snapshot of {} bytes is uploaded to {url}, \
+                    the SHA-256 of its manifest is {manifest_sha256}",
+                    manifest.size
+                );
+            }
         }
-        SubCommands::Contract { contract_id } => {
-            let config = db.get_contract_config_by_id(contract_id)?;
-            let stdout = std::io::stdout().lock();
+        SubCommands::Contract {
+            contract_id,
+            height,
+            output,
+        } => {
+            let db = open_database(&command.database_path)?;
+            let height = match height {
+                Some(height) => height.into(),
+                None => db.latest_height()?,
+            };
+            let Some(config) = db.contract_config_at(&contract_id, height, None)? else {
+                anyhow::bail!("The contract {contract_id} doesn't exist at {height}")
+            };
+
+            match &output {
+                Some(output) => {
+                    let file = std::fs::File::create(output).with_context(|| {
+                        format!("failed to create {}", output.display())
+                    })?;
+                    serde_json::to_writer_pretty(std::io::BufWriter::new(file), &config)
+                        .context("failed to dump contract snapshot to JSON")?;
+                }
+                None => {
+                    let stdout = std::io::stdout().lock();
 
-            serde_json::to_writer_pretty(stdout, &config)
-                .context("failed to dump contract snapshot to JSON")?;
+                    serde_json::to_writer_pretty(stdout, &config)
+                        .context("failed to dump contract snapshot to JSON")?;
+                }
+            }
         }
+        SubCommands::Verify(command) => verify::exec(command).await?,
     }
     Ok(())
 }
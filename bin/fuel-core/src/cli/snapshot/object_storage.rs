@@ -0,0 +1,240 @@
+//! Uploads the snapshots to the object storage and downloads them from there.
+//!
+//! The snapshot is uploaded next to its manifest, `manifest.json`, with the size and
+//! the SHA-256 of the snapshot. The location of the snapshot isn't trusted, so the
+//! downloaded manifest is verified against the SHA-256 of the manifest printed by the
+//! upload and distributed over a trusted channel, and the downloaded snapshot is
+//! verified against the manifest before the node uses it.
+//!
+//! The `s3://` URLs use the credentials of the `AWS_*` environment variables, the
+//! `AWS_ENDPOINT` selects the S3-compatible storage. The `gs://` URLs use
+//! the `GOOGLE_*` environment variables. The `http(s)://` URLs are only downloaded.
+
+use anyhow::Context;
+use fuel_core::types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+use futures::StreamExt;
+use object_store::{
+    aws::AmazonS3Builder,
+    gcp::GoogleCloudStorageBuilder,
+    path::Path as ObjectPath,
+    ObjectStore,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::path::Path;
+use tokio::io::{
+    AsyncReadExt,
+    AsyncWriteExt,
+};
+use url::Url;
+
+/// The name of the manifest in the snapshot location.
+pub const MANIFEST: &str = "manifest.json";
+
+/// The size of the chunks read from the snapshot file during the upload.
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Describes the snapshot uploaded to the object storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The name of the snapshot file, relative to the manifest.
+    pub snapshot: String,
+    /// The size of the snapshot file in bytes.
+    pub size: u64,
+    /// The SHA-256 of the snapshot file.
+    pub sha256: Bytes32,
+}
+
+/// Returns the store of the `url` and the path of the location inside it.
+fn object_store(url: &Url) -> anyhow::Result<(Box<dyn ObjectStore>, ObjectPath)> {
+    let store: Box<dyn ObjectStore> = match url.scheme() {
+        "s3" => Box::new(AmazonS3Builder::from_env().with_url(url.as_str()).build()?),
+        "gs" => Box::new(
+            GoogleCloudStorageBuilder::from_env()
+                .with_url(url.as_str())
+                .build()?,
+        ),
+        _ => return Ok(object_store::parse_url(url)?),
+    };
+    Ok((store, ObjectPath::from_url_path(url.path())?))
+}
+
+/// Uploads the snapshot `file` with its manifest to the `url` location. Returns the
+/// manifest with its SHA-256, which the nodes downloading the snapshot must trust.
+/// The snapshot is uploaded in parts, so its size is not limited by the memory.
+pub async fn upload(file: &Path, url: &Url) -> anyhow::Result<(Manifest, Bytes32)> {
+    let (store, location) = object_store(url)?;
+    let name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .with_context(|| format!("invalid snapshot file name {}", file.display()))?
+        .to_string();
+    let snapshot_path = location.child(name.as_str());
+
+    let mut reader = tokio::fs::File::open(file)
+        .await
+        .with_context(|| format!("failed to open {}", file.display()))?;
+    let (multipart_id, mut writer) = store.put_multipart(&snapshot_path).await?;
+    let mut hasher = Hasher::default();
+    let mut size = 0u64;
+    let mut chunk = vec![0; CHUNK_SIZE];
+    let written: anyhow::Result<()> = async {
+        loop {
+            let read = reader.read(&mut chunk).await?;
+            if read == 0 {
+                break
+            }
+            hasher.input(&chunk[..read]);
+            size += read as u64;
+            writer.write_all(&chunk[..read]).await?;
+        }
+        writer.shutdown().await?;
+        Ok(())
+    }
+    .await;
+    if let Err(e) = written {
+        let _ = store.abort_multipart(&snapshot_path, &multipart_id).await;
+        return Err(e.context(format!("failed to upload the snapshot to {url}")))
+    }
+
+    let manifest = Manifest {
+        snapshot: name,
+        size,
+        sha256: hasher.finalize(),
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+    let manifest_sha256 = Hasher::hash(&manifest_bytes);
+    store
+        .put(&location.child(MANIFEST), manifest_bytes.into())
+        .await
+        .with_context(|| format!("failed to upload the manifest to {url}"))?;
+    Ok((manifest, manifest_sha256))
+}
+
+/// Downloads the snapshot from the `url` location into the `file`. The manifest of
+/// the location is verified against the trusted `manifest_sha256`. The snapshot is
+/// streamed to the file and verified against the manifest.
+pub async fn download(
+    url: &Url,
+    manifest_sha256: &Bytes32,
+    file: &Path,
+) -> anyhow::Result<Manifest> {
+    let (store, location) = object_store(url)?;
+    let manifest = store
+        .get(&location.child(MANIFEST))
+        .await
+        .with_context(|| format!("failed to download the manifest from {url}"))?
+        .bytes()
+        .await?;
+    let sha256 = Hasher::hash(&manifest);
+    anyhow::ensure!(
+        sha256 == *manifest_sha256,
+        "The manifest has the SHA-256 {sha256}, but {manifest_sha256} is trusted"
+    );
+    let manifest: Manifest = serde_json::from_slice(&manifest)
+        .with_context(|| format!("failed to parse the manifest from {url}"))?;
+
+    let mut stream = store
+        .get(&location.child(manifest.snapshot.as_str()))
+        .await
+        .with_context(|| format!("failed to download the snapshot from {url}"))?
+        .into_stream();
+    let mut writer = tokio::fs::File::create(file)
+        .await
+        .with_context(|| format!("failed to create {}", file.display()))?;
+    let mut hasher = Hasher::default();
+    let mut size = 0u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        hasher.input(&chunk);
+        size += chunk.len() as u64;
+        writer.write_all(&chunk).await?;
+    }
+    writer.flush().await?;
+
+    anyhow::ensure!(
+        size == manifest.size,
+        "The snapshot has {size} bytes, but the manifest expects {}",
+        manifest.size
+    );
+    let sha256 = hasher.finalize();
+    anyhow::ensure!(
+        sha256 == manifest.sha256,
+        "The snapshot has the SHA-256 {sha256}, but the manifest expects {}",
+        manifest.sha256
+    );
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn location(dir: &Path) -> Url {
+        Url::from_directory_path(dir.join("snapshots")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn download_returns_the_uploaded_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = dir.path().join("snapshot.json");
+        std::fs::write(&snapshot, b"{\"chain_name\":\"local\"}").unwrap();
+        std::fs::create_dir(dir.path().join("snapshots")).unwrap();
+        let url = location(dir.path());
+
+        let (uploaded, manifest_sha256) = upload(&snapshot, &url).await.unwrap();
+        let downloaded_file = dir.path().join("downloaded.json");
+        let downloaded = download(&url, &manifest_sha256, &downloaded_file)
+            .await
+            .unwrap();
+
+        assert_eq!(uploaded, downloaded);
+        assert_eq!(
+            std::fs::read(&downloaded_file).unwrap(),
+            std::fs::read(&snapshot).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn download_fails_on_the_checksum_mismatch() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = dir.path().join("snapshot.json");
+        std::fs::write(&snapshot, b"{\"chain_name\":\"local\"}").unwrap();
+        std::fs::create_dir(dir.path().join("snapshots")).unwrap();
+        let url = location(dir.path());
+        let (_, manifest_sha256) = upload(&snapshot, &url).await.unwrap();
+        std::fs::write(
+            dir.path().join("snapshots").join("snapshot.json"),
+            b"{\"chain_name\":\"other\"}",
+        )
+        .unwrap();
+
+        let result =
+            download(&url, &manifest_sha256, &dir.path().join("downloaded.json")).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn download_fails_on_the_untrusted_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot = dir.path().join("snapshot.json");
+        std::fs::write(&snapshot, b"{\"chain_name\":\"local\"}").unwrap();
+        std::fs::create_dir(dir.path().join("snapshots")).unwrap();
+        let url = location(dir.path());
+        let (_, manifest_sha256) = upload(&snapshot, &url).await.unwrap();
+        // The location serves another snapshot with the matching manifest.
+        std::fs::write(&snapshot, b"{\"chain_name\":\"other\"}").unwrap();
+        upload(&snapshot, &url).await.unwrap();
+
+        let result =
+            download(&url, &manifest_sha256, &dir.path().join("downloaded.json")).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,253 @@
+//! Verifies the snapshot before the regenesis. The roots of the coins, contracts and
+//! messages are recomputed from the contents of the snapshot and compared with the roots
+//! of the genesis block, fetched from the trusted node or provided inline.
+
+use anyhow::Context;
+use clap::Args;
+use fuel_core::{
+    chain_config::ChainConfig,
+    service::genesis::genesis_roots,
+    types::{
+        blockchain::consensus::Genesis,
+        fuel_types::Bytes32,
+    },
+};
+use serde_json::json;
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
+use url::Url;
+
+const GENESIS_QUERY: &str = "query($height: U64) {
+    block(height: $height) {
+        consensus {
+            __typename
+            ... on Genesis {
+                chainConfigHash
+                coinsRoot
+                contractsRoot
+                messagesRoot
+            }
+        }
+    }
+}";
+
+/// Verifies the roots of the snapshot against the genesis block of the regenesis.
+#[derive(Debug, Clone, Args)]
+pub struct Command {
+    /// The snapshot file created by the `snapshot everything`.
+    #[clap(long = "snapshot")]
+    snapshot: PathBuf,
+
+    /// The URL of the GraphQL API of the trusted node, like `https://node/graphql`.
+    /// The expected roots are fetched from its genesis block.
+    #[clap(
+        long = "trusted-node",
+        required_unless_present_all = [
+            "chain_config_hash",
+            "coins_root",
+            "contracts_root",
+            "messages_root",
+        ]
+    )]
+    trusted_node: Option<Url>,
+
+    /// The height of the genesis block on the trusted node.
+    /// By default, the height of the snapshot.
+    #[clap(long = "height", requires = "trusted_node")]
+    height: Option<u32>,
+
+    /// The expected hash of the chain config.
+    #[clap(long = "chain-config-hash", conflicts_with = "trusted_node")]
+    chain_config_hash: Option<Bytes32>,
+
+    /// The expected root of the coins.
+    #[clap(long = "coins-root", conflicts_with = "trusted_node")]
+    coins_root: Option<Bytes32>,
+
+    /// The expected root of the contracts.
+    #[clap(long = "contracts-root", conflicts_with = "trusted_node")]
+    contracts_root: Option<Bytes32>,
+
+    /// The expected root of the messages.
+    #[clap(long = "messages-root", conflicts_with = "trusted_node")]
+    messages_root: Option<Bytes32>,
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let path = command.snapshot.display().to_string();
+    let chain_conf: ChainConfig = path
+        .parse()
+        .with_context(|| format!("failed to load the snapshot {path}"))?;
+
+    let expected = match &command.trusted_node {
+        Some(url) => {
+            let height = command.height.unwrap_or_else(|| {
+                chain_conf
+                    .initial_state
+                    .as_ref()
+                    .and_then(|state| state.height)
+                    .map(u32::from)
+                    .unwrap_or_default()
+            });
+            fetch_genesis(url, height).await?
+        }
+        None => Genesis {
+            chain_config_hash: command.chain_config_hash.unwrap_or_default(),
+            coins_root: command.coins_root.unwrap_or_default(),
+            contracts_root: command.contracts_root.unwrap_or_default(),
+            messages_root: command.messages_root.unwrap_or_default(),
+        },
+    };
+
+    let actual = tokio::task::spawn_blocking(move || genesis_roots(&chain_conf))
+        .await?
+        .context("failed to compute the roots of the snapshot")?;
+
+    let mismatches = mismatches(&expected, &actual);
+    if mismatches.is_empty() {
+        println!("The snapshot {path} matches the genesis block");
+        return Ok(())
+    }
+    for mismatch in &mismatches {
+        eprintln!("{mismatch}");
+    }
+    anyhow::bail!("The snapshot {path} doesn't match the genesis block")
+}
+
+/// Fetches the roots of the genesis block at the `height` from the trusted node.
+async fn fetch_genesis(url: &Url, height: u32) -> anyhow::Result<Genesis> {
+    let request = json!({
+        "query": GENESIS_QUERY,
+        "variables": { "height": height.to_string() },
+    });
+    let response = reqwest::Client::new()
+        .post(url.clone())
+        .header("content-type", "application/json")
+        .body(request.to_string())
+        .send()
+        .await
+        .with_context(|| format!("failed to request {url}"))?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        anyhow::bail!("The trusted node responded with {status}: {body}");
+    }
+    let body: serde_json::Value =
+        serde_json::from_str(&body).context("failed to parse the response")?;
+    parse_genesis(&body, height)
+}
+
+/// Parses the roots of the genesis block from the GraphQL `response`.
+fn parse_genesis(response: &serde_json::Value, height: u32) -> anyhow::Result<Genesis> {
+    if let Some(errors) = response.get("errors") {
+        anyhow::bail!("The trusted node responded with errors: {errors}");
+    }
+    let consensus = response
+        .pointer("/data/block/consensus")
+        .with_context(|| format!("The trusted node has no block at {height}"))?;
+    let kind = consensus["__typename"].as_str().unwrap_or_default();
+    if kind != "Genesis" {
+        anyhow::bail!("The block at {height} is not a genesis block, but {kind}");
+    }
+    let root = |field: &str| -> anyhow::Result<Bytes32> {
+        let value = consensus[field]
+            .as_str()
+            .with_context(|| format!("The genesis block has no `{field}`"))?;
+        Bytes32::from_str(value)
+            .map_err(|e| anyhow::anyhow!("Invalid `{field}` of the genesis block: {e}"))
+    };
+    Ok(Genesis {
+        chain_config_hash: root("chainConfigHash")?,
+        coins_root: root("coinsRoot")?,
+        contracts_root: root("contractsRoot")?,
+        messages_root: root("messagesRoot")?,
+    })
+}
+
+/// Describes the roots of the `actual` genesis that differ from the `expected` ones.
+fn mismatches(expected: &Genesis, actual: &Genesis) -> Vec<String> {
+    [
+        (
+            "chain config hash",
+            expected.chain_config_hash,
+            actual.chain_config_hash,
+        ),
+        ("coins root", expected.coins_root, actual.coins_root),
+        (
+            "contracts root",
+            expected.contracts_root,
+            actual.contracts_root,
+        ),
+        (
+            "messages root",
+            expected.messages_root,
+            actual.messages_root,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, expected, actual)| expected != actual)
+    .map(|(name, expected, actual)| {
+        format!("The {name} is {actual}, but {expected} is expected")
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn genesis() -> Genesis {
+        Genesis {
+            chain_config_hash: [1; 32].into(),
+            coins_root: [2; 32].into(),
+            contracts_root: [3; 32].into(),
+            messages_root: [4; 32].into(),
+        }
+    }
+
+    #[test]
+    fn genesis_is_parsed_from_response() {
+        let expected = genesis();
+        let response = json!({
+            "data": {
+                "block": {
+                    "consensus": {
+                        "__typename": "Genesis",
+                        "chainConfigHash": expected.chain_config_hash.to_string(),
+                        "coinsRoot": expected.coins_root.to_string(),
+                        "contractsRoot": expected.contracts_root.to_string(),
+                        "messagesRoot": expected.messages_root.to_string(),
+                    }
+                }
+            }
+        });
+
+        assert_eq!(parse_genesis(&response, 0).unwrap(), expected);
+    }
+
+    #[test]
+    fn block_without_genesis_consensus_is_rejected() {
+        let response = json!({
+            "data": { "block": { "consensus": { "__typename": "PoAConsensus" } } }
+        });
+
+        assert!(parse_genesis(&response, 5).is_err());
+    }
+
+    #[test]
+    fn only_different_roots_are_reported() {
+        let expected = genesis();
+        let actual = Genesis {
+            contracts_root: [9; 32].into(),
+            ..genesis()
+        };
+
+        let mismatches = mismatches(&expected, &actual);
+
+        assert_eq!(mismatches.len(), 1);
+        assert!(mismatches[0].starts_with("The contracts root"));
+        assert!(super::mismatches(&expected, &expected).is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+use crate::cli::DEFAULT_DB_PATH;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    chain_config::ChainConfig,
+    service::{
+        test_vector::{
+            run,
+            TestVector,
+        },
+        Config,
+    },
+};
+use std::path::PathBuf;
+
+/// Exports the execution of the blocks as the self-contained test vectors and runs
+/// the vectors against the executor of this node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// Specify either an alias to a built-in configuration or filepath to a JSON file.
+    /// It must be the same chain config that was used to create the database.
+    #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
+    chain_config: String,
+
+    /// Enable full utxo stateful validation, as the nodes of the network do.
+    #[clap(long = "utxo-validation")]
+    utxo_validation: bool,
+
+    /// The sub-command of the test vector operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Exports the block at the height with the state it reads as the JSON test vector.
+    Export {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+        )]
+        database_path: PathBuf,
+        /// The height of the exported block.
+        #[clap(long = "height")]
+        height: u32,
+        /// The path to the file of the test vector.
+        #[clap(long = "output")]
+        output: PathBuf,
+    },
+    /// Re-executes the test vectors and reports the ones that diverge.
+    Run {
+        /// The paths to the files of the test vectors.
+        #[clap(required = true)]
+        vectors: Vec<PathBuf>,
+    },
+}
+
+impl Command {
+    fn config(&self) -> anyhow::Result<Config> {
+        let mut config = Config::local_node();
+        config.chain_conf = self.chain_config.parse::<ChainConfig>()?;
+        config.utxo_validation = self.utxo_validation;
+        Ok(config)
+    }
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    let config = command.config()?;
+    match command.subcommand {
+        SubCommands::Export {
+            database_path,
+            height,
+            output,
+        } => export(&database_path, &config, height, &output),
+        SubCommands::Run { vectors } => {
+            let mut diverged = 0;
+            for path in &vectors {
+                let file = std::fs::File::open(path)
+                    .with_context(|| format!("failed to open {}", path.display()))?;
+                let vector: TestVector = serde_json::from_reader(file)
+                    .with_context(|| format!("failed to parse {}", path.display()))?;
+                match run(&vector, &config)? {
+                    Some(divergence) => {
+                        diverged += 1;
+                        println!(
+                            "{}: exported by {}: {divergence}",
+                            path.display(),
+                            vector.node_version
+                        );
+                    }
+                    None => println!("{}: ok", path.display()),
+                }
+            }
+            anyhow::ensure!(
+                diverged == 0,
+                "{diverged} of {} test vectors diverged",
+                vectors.len()
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+fn export(
+    database_path: &std::path::Path,
+    _: &Config,
+    _: u32,
+    _: &std::path::Path,
+) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn export(
+    database_path: &std::path::Path,
+    config: &Config,
+    height: u32,
+    output: &std::path::Path,
+) -> anyhow::Result<()> {
+    use fuel_core::database::Database;
+
+    let data_source =
+        fuel_core::state::rocks_db::RocksDb::default_open(database_path, None).context(
+            format!(
+                "failed to open database at path {}",
+                database_path.display()
+            ),
+        )?;
+    let db = Database::new(std::sync::Arc::new(data_source));
+
+    let vector = fuel_core::service::test_vector::export(&db, config, height.into())?;
+    let file = std::fs::File::create(output)
+        .with_context(|| format!("failed to create {}", output.display()))?;
+    serde_json::to_writer_pretty(file, &vector)?;
+    println!(
+        "The block at height {height} is exported to {}",
+        output.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_export() {
+        let command = Command::try_parse_from([
+            "",
+            "export",
+            "--height",
+            "10",
+            "--output",
+            "vector.json",
+        ])
+        .unwrap();
+
+        let SubCommands::Export { height, output, .. } = command.subcommand else {
+            panic!("Expected the export sub-command");
+        };
+        assert_eq!(height, 10);
+        assert_eq!(output, PathBuf::from("vector.json"));
+    }
+
+    #[test]
+    fn run_requires_vectors() {
+        assert!(Command::try_parse_from(["", "run"]).is_err());
+    }
+}
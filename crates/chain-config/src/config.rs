@@ -2,21 +2,29 @@ mod chain;
 mod coin;
 mod consensus;
 mod contract;
+mod diff;
 mod message;
 mod state;
+mod upgrade;
+mod validation;
 
 pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
+pub use diff::*;
 pub use message::*;
 pub use state::*;
+pub use upgrade::*;
+pub use validation::*;
 
 #[cfg(test)]
 mod tests {
+    use crate::GenesisCommitment;
     use fuel_core_types::{
         blockchain::primitives::DaBlockHeight,
         fuel_asm::op,
+        fuel_crypto::Hasher,
         fuel_tx::{
             TxPointer,
             UtxoId,
@@ -219,6 +227,25 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn config_without_block_size_limit_keeps_its_hash() {
+        let config = ChainConfig::local_testnet();
+        let hash_before_block_size_limit = *Hasher::default()
+            .chain(config.chain_name.as_bytes())
+            .chain(config.block_gas_limit.to_be_bytes())
+            .chain(config.consensus_parameters.root().unwrap())
+            .chain(config.consensus.root().unwrap())
+            .finalize();
+
+        assert_eq!(config.root().unwrap(), hash_before_block_size_limit);
+
+        let config = ChainConfig {
+            block_size_limit: Some(ChainConfig::DEFAULT_BLOCK_SIZE_LIMIT),
+            ..config
+        };
+        assert_ne!(config.root().unwrap(), hash_before_block_size_limit);
+    }
+
     fn test_config_contract(
         state: bool,
         balances: bool,
@@ -17,6 +17,10 @@ use fuel_core_types::{
         Bytes32,
     },
     fuel_vm::SecretKey,
+    services::executor::{
+        BlockLimits,
+        FeeShare,
+    },
 };
 use itertools::Itertools;
 use rand::{
@@ -59,10 +63,18 @@ pub const TESTNET_INITIAL_BALANCE: u64 = 10_000_000;
 pub struct ChainConfig {
     pub chain_name: String,
     pub block_gas_limit: u64,
+    /// The maximum size in bytes of the transactions of one block.
+    /// [`ChainConfig::DEFAULT_BLOCK_SIZE_LIMIT`] if not set.
+    #[serde(default)]
+    pub block_size_limit: Option<u64>,
     #[serde(default)]
     pub initial_state: Option<StateConfig>,
     pub consensus_parameters: ConsensusParameters,
     pub consensus: ConsensusConfig,
+    /// The shares of the collected fees that go to other recipients than the coinbase
+    /// recipient. Each share is paid by a separate output of the coinbase transaction.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fee_shares: Vec<FeeShare>,
 }
 
 impl Default for ChainConfig {
@@ -70,15 +82,30 @@ impl Default for ChainConfig {
         Self {
             chain_name: "local".into(),
             block_gas_limit: TxParameters::DEFAULT.max_gas_per_tx * 10, /* TODO: Pick a sensible default */
+            block_size_limit: None,
             consensus_parameters: ConsensusParameters::default(),
             initial_state: None,
             consensus: ConsensusConfig::default_poa(),
+            fee_shares: vec![],
         }
     }
 }
 
 impl ChainConfig {
     pub const BASE_ASSET: AssetId = AssetId::zeroed();
+    /// Fits the largest contract allowed by the default consensus parameters,
+    /// and the block still fits into one response of the P2P.
+    pub const DEFAULT_BLOCK_SIZE_LIMIT: u64 = 17 * 1024 * 1024;
+
+    /// The limits of the block fullness enforced by the producer, executor and importer.
+    pub fn block_limits(&self) -> BlockLimits {
+        BlockLimits {
+            gas: self.block_gas_limit,
+            size: self
+                .block_size_limit
+                .unwrap_or(Self::DEFAULT_BLOCK_SIZE_LIMIT),
+        }
+    }
 
     pub fn local_testnet() -> Self {
         // endow some preset accounts with an initial balance
@@ -163,19 +190,29 @@ impl GenesisCommitment for ChainConfig {
         let ChainConfig {
             chain_name,
             block_gas_limit,
+            block_size_limit,
             // Skip the `initial_state` bec
             initial_state: _,
             consensus_parameters,
             consensus,
+            fee_shares,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(consensus_parameters.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // The chains without the explicit block size limit keep their hash.
+        if let Some(block_size_limit) = block_size_limit {
+            hasher = hasher.chain(block_size_limit.to_be_bytes());
+        }
+        // The chains without the fee shares keep their hash.
+        if !fee_shares.is_empty() {
+            hasher = hasher.chain(postcard::to_stdvec(fee_shares)?);
+        }
+        let config_hash = *hasher.finalize();
 
         Ok(config_hash)
     }
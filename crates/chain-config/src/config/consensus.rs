@@ -11,7 +11,14 @@ use crate::default_consensus_dev_key;
 
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub enum ConsensusConfig {
-    PoA { signing_key: Address },
+    PoA {
+        signing_key: Address,
+    },
+    /// The blocks are sealed by at least `threshold` of the `signing_keys`.
+    PoAMultiSig {
+        signing_keys: Vec<Address>,
+        threshold: u16,
+    },
 }
 
 impl ConsensusConfig {
@@ -0,0 +1,153 @@
+use super::chain::ChainConfig;
+use serde_json::Value;
+use std::fmt;
+
+/// The difference between two chain configs at the `path` in their JSON representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigDifference {
+    /// The field exists only in the second config.
+    Added { path: String, value: Value },
+    /// The field exists only in the first config.
+    Removed { path: String, value: Value },
+    /// The field has different values in the configs.
+    Changed {
+        path: String,
+        from: Value,
+        to: Value,
+    },
+}
+
+impl fmt::Display for ConfigDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigDifference::Added { path, value } => write!(f, "+ {path}: {value}"),
+            ConfigDifference::Removed { path, value } => {
+                write!(f, "- {path}: {value}")
+            }
+            ConfigDifference::Changed { path, from, to } => {
+                write!(f, "~ {path}: {from} -> {to}")
+            }
+        }
+    }
+}
+
+impl ChainConfig {
+    /// Returns the differences between `self` and the `other` config.
+    pub fn diff(&self, other: &ChainConfig) -> anyhow::Result<Vec<ConfigDifference>> {
+        let from = serde_json::to_value(self)?;
+        let to = serde_json::to_value(other)?;
+        let mut differences = vec![];
+        diff_values(String::new(), from, to, &mut differences);
+        Ok(differences)
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn diff_values(
+    path: String,
+    from: Value,
+    to: Value,
+    differences: &mut Vec<ConfigDifference>,
+) {
+    match (from, to) {
+        (Value::Object(mut from), Value::Object(mut to)) => {
+            let mut keys: Vec<_> = from.keys().chain(to.keys()).cloned().collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let path = child_path(&path, &key);
+                match (from.remove(&key), to.remove(&key)) {
+                    (Some(from), Some(to)) => diff_values(path, from, to, differences),
+                    (Some(value), None) => {
+                        differences.push(ConfigDifference::Removed { path, value })
+                    }
+                    (None, Some(value)) => {
+                        differences.push(ConfigDifference::Added { path, value })
+                    }
+                    (None, None) => unreachable!("The key is taken from one of the maps"),
+                }
+            }
+        }
+        (Value::Array(from), Value::Array(to)) => {
+            let len = from.len().max(to.len());
+            let mut from = from.into_iter();
+            let mut to = to.into_iter();
+            for i in 0..len {
+                let path = format!("{path}[{i}]");
+                match (from.next(), to.next()) {
+                    (Some(from), Some(to)) => diff_values(path, from, to, differences),
+                    (Some(value), None) => {
+                        differences.push(ConfigDifference::Removed { path, value })
+                    }
+                    (None, Some(value)) => {
+                        differences.push(ConfigDifference::Added { path, value })
+                    }
+                    (None, None) => unreachable!("The index is less than the max length"),
+                }
+            }
+        }
+        (from, to) => {
+            if from != to {
+                differences.push(ConfigDifference::Changed { path, from, to })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn diff__of_same_configs_is_empty() {
+        let config = ChainConfig::local_testnet();
+
+        assert_eq!(config.diff(&config).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn diff__reports_changed_added_and_removed_fields() {
+        let from = ChainConfig::local_testnet();
+        let mut to = from.clone();
+        to.chain_name = "devnet".to_string();
+        to.consensus_parameters.tx_params.max_inputs = 10;
+        let coins = to.initial_state.as_mut().unwrap().coins.as_mut().unwrap();
+        let removed = coins.pop().unwrap();
+        to.initial_state.as_mut().unwrap().height = Some(10u32.into());
+
+        let differences = from.diff(&to).unwrap();
+
+        assert_eq!(
+            differences,
+            vec![
+                ConfigDifference::Changed {
+                    path: "chain_name".to_string(),
+                    from: "local_testnet".into(),
+                    to: "devnet".into(),
+                },
+                ConfigDifference::Changed {
+                    path: "consensus_parameters.tx_params.max_inputs".to_string(),
+                    from: 255.into(),
+                    to: 10.into(),
+                },
+                ConfigDifference::Removed {
+                    path: "initial_state.coins[4]".to_string(),
+                    value: serde_json::to_value(removed).unwrap(),
+                },
+                ConfigDifference::Added {
+                    path: "initial_state.height".to_string(),
+                    value: serde_json::to_value(&to).unwrap()["initial_state"]["height"]
+                        .clone(),
+                },
+            ]
+        );
+    }
+}
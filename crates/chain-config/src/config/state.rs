@@ -47,6 +47,27 @@ impl StateConfig {
             height: Some(db.get_block_height()?),
         })
     }
+
+    /// Imports the `contract` exported from another chain, replacing the contract with
+    /// the same id. The UTXO and the `TxPointer` of the contract refer to the blocks of
+    /// the other chain, so they are generated at the genesis instead.
+    pub fn import_contract(&mut self, contract: ContractConfig) {
+        let contract = ContractConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            ..contract
+        };
+        let contracts = self.contracts.get_or_insert_with(Vec::new);
+        match contracts
+            .iter_mut()
+            .find(|existing| existing.contract_id == contract.contract_id)
+        {
+            Some(existing) => *existing = contract,
+            None => contracts.push(contract),
+        }
+    }
 }
 
 pub trait ChainConfigDb {
@@ -59,3 +80,43 @@ pub trait ChainConfigDb {
     /// Returns the last available block height.
     fn get_block_height(&self) -> StorageResult<BlockHeight>;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn contract(id: u8, code: Vec<u8>) -> ContractConfig {
+        ContractConfig {
+            contract_id: [id; 32].into(),
+            code,
+            salt: Default::default(),
+            state: Some(vec![([1; 32].into(), [2; 32].into())]),
+            balances: Some(vec![([3; 32].into(), 100)]),
+            tx_id: Some([4; 32].into()),
+            output_index: Some(1),
+            tx_pointer_block_height: Some(1000u32.into()),
+            tx_pointer_tx_idx: Some(2),
+        }
+    }
+
+    #[test]
+    fn import_contract__replaces_the_contract_with_the_same_id() {
+        let mut state = StateConfig {
+            contracts: Some(vec![contract(1, vec![1]), contract(2, vec![2])]),
+            ..Default::default()
+        };
+
+        state.import_contract(contract(2, vec![3]));
+        state.import_contract(contract(3, vec![4]));
+
+        let contracts = state.contracts.unwrap();
+        let codes: Vec<_> = contracts.iter().map(|c| c.code.clone()).collect();
+        assert_eq!(codes, vec![vec![1], vec![3], vec![4]]);
+        let imported = &contracts[1];
+        assert_eq!(imported.state, Some(vec![([1; 32].into(), [2; 32].into())]));
+        assert_eq!(imported.balances, Some(vec![([3; 32].into(), 100)]));
+        assert_eq!(imported.tx_id, None);
+        assert_eq!(imported.tx_pointer_block_height, None);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::ConsensusConfig;
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Hasher,
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Input,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+        ChainId,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::skip_serializing_none;
+
+/// The upgrade of the chain effective from the `height`. The nodes older than
+/// the `required_version` stop producing and importing blocks at the `height`.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct UpgradeDeclaration {
+    /// The first height of the upgraded chain.
+    pub height: BlockHeight,
+    /// The minimal version of the node supporting the upgrade in the
+    /// `major.minor.patch` format.
+    pub required_version: String,
+    /// The consensus parameters from the `height`, if the upgrade changes them.
+    pub consensus_parameters: Option<ConsensusParameters>,
+    /// The root of the state transition bytecode from the `height`,
+    /// if the upgrade changes it.
+    pub state_transition_bytecode_root: Option<Bytes32>,
+}
+
+/// The fields of the declaration hashed into its id. The fields not set by
+/// the declaration are skipped, so the ids of the already signed declarations
+/// stay the same. The declaration itself is stored with all fields, because
+/// postcard can't decode the skipped ones.
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct DeclarationIdFields<'a> {
+    height: &'a BlockHeight,
+    required_version: &'a str,
+    consensus_parameters: Option<&'a ConsensusParameters>,
+    state_transition_bytecode_root: Option<&'a Bytes32>,
+}
+
+impl UpgradeDeclaration {
+    /// The hash of the declaration for the chain with the `chain_id` signed by
+    /// the consensus authority. The declarations of the chains with the same authority
+    /// have different ids.
+    pub fn id(&self, chain_id: &ChainId) -> anyhow::Result<Bytes32> {
+        let UpgradeDeclaration {
+            height,
+            required_version,
+            consensus_parameters,
+            state_transition_bytecode_root,
+        } = self;
+        let bytes = postcard::to_stdvec(&DeclarationIdFields {
+            height,
+            required_version,
+            consensus_parameters: consensus_parameters.as_ref(),
+            state_transition_bytecode_root: state_transition_bytecode_root.as_ref(),
+        })?;
+        Ok(Hasher::default()
+            .chain(chain_id.to_be_bytes())
+            .chain(bytes)
+            .finalize())
+    }
+
+    /// Signs the declaration for the chain with the `chain_id` with the `secret_key`
+    /// of the consensus authority.
+    pub fn sign(
+        self,
+        secret_key: &SecretKey,
+        chain_id: &ChainId,
+    ) -> anyhow::Result<SignedUpgradeDeclaration> {
+        let message = Message::from_bytes(*self.id(chain_id)?);
+        let signature = Signature::sign(secret_key, &message);
+        Ok(SignedUpgradeDeclaration {
+            declaration: self,
+            signature,
+        })
+    }
+
+    /// Returns `true` if the node of the `version` supports the upgrade.
+    pub fn is_supported_by(&self, version: &str) -> anyhow::Result<bool> {
+        Ok(parse_version(version)? >= parse_version(&self.required_version)?)
+    }
+}
+
+/// The upgrade declaration with the signature of the consensus authority.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SignedUpgradeDeclaration {
+    pub declaration: UpgradeDeclaration,
+    pub signature: Signature,
+}
+
+impl SignedUpgradeDeclaration {
+    /// Verifies that the declaration is well-formed and signed for the chain with
+    /// the `chain_id` by the authority of the `consensus`. Any of the multi-signature
+    /// authorities can declare the upgrade.
+    pub fn verify(
+        &self,
+        consensus: &ConsensusConfig,
+        chain_id: &ChainId,
+    ) -> anyhow::Result<()> {
+        parse_version(&self.declaration.required_version)?;
+        let message = Message::from_bytes(*self.declaration.id(chain_id)?);
+        let public_key = self.signature.recover(&message).map_err(|e| {
+            anyhow!("Invalid signature of the upgrade declaration: {e:?}")
+        })?;
+        match consensus {
+            ConsensusConfig::PoA { signing_key } => ensure!(
+                Input::owner(&public_key) == *signing_key,
+                "The upgrade declaration is not signed by the consensus authority"
+            ),
+            ConsensusConfig::PoAMultiSig { signing_keys, .. } => ensure!(
+                signing_keys.contains(&Input::owner(&public_key)),
+                "The upgrade declaration is not signed by the consensus authority"
+            ),
+        }
+        Ok(())
+    }
+}
+
+/// Parses the `major.minor.patch` version, ignoring the pre-release and build metadata.
+fn parse_version(version: &str) -> anyhow::Result<(u64, u64, u64)> {
+    let core = version
+        .split(|c| c == '-' || c == '+')
+        .next()
+        .unwrap_or_default();
+    let mut parts = core.split('.').map(str::parse::<u64>);
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => {
+            Ok((major, minor, patch))
+        }
+        _ => Err(anyhow!(
+            "Invalid version `{version}`, expected `major.minor.patch`"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::default_consensus_dev_key;
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn declaration(required_version: &str) -> UpgradeDeclaration {
+        UpgradeDeclaration {
+            height: 100u32.into(),
+            required_version: required_version.to_string(),
+            consensus_parameters: Some(ConsensusParameters::default()),
+            state_transition_bytecode_root: None,
+        }
+    }
+
+    #[test]
+    fn declaration_signed_by_authority_is_valid() {
+        let signed = declaration("0.21.0")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+
+        signed
+            .verify(&ConsensusConfig::default_poa(), &ChainId::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn declaration_signed_by_another_key_is_rejected() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let signed = declaration("0.21.0")
+            .sign(&SecretKey::random(&mut rng), &ChainId::default())
+            .unwrap();
+
+        assert!(signed
+            .verify(&ConsensusConfig::default_poa(), &ChainId::default())
+            .is_err());
+    }
+
+    #[test]
+    fn declaration_signed_for_another_chain_is_rejected() {
+        let signed = declaration("0.21.0")
+            .sign(&default_consensus_dev_key(), &ChainId::new(1))
+            .unwrap();
+
+        assert!(signed
+            .verify(&ConsensusConfig::default_poa(), &ChainId::new(2))
+            .is_err());
+    }
+
+    #[test]
+    fn modified_declaration_is_rejected() {
+        let mut signed = declaration("0.21.0")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+        signed.declaration.height = 101u32.into();
+
+        assert!(signed
+            .verify(&ConsensusConfig::default_poa(), &ChainId::default())
+            .is_err());
+    }
+
+    #[test]
+    fn declaration_id_skips_unset_fields() {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct SignedLayout {
+            height: BlockHeight,
+            required_version: String,
+            consensus_parameters: Option<ConsensusParameters>,
+            state_transition_bytecode_root: Option<Bytes32>,
+        }
+        let declaration = declaration("0.21.0");
+        let bytes = postcard::to_stdvec(&SignedLayout {
+            height: declaration.height,
+            required_version: declaration.required_version.clone(),
+            consensus_parameters: declaration.consensus_parameters.clone(),
+            state_transition_bytecode_root: declaration.state_transition_bytecode_root,
+        })
+        .unwrap();
+        let expected_id = Hasher::default()
+            .chain(ChainId::default().to_be_bytes())
+            .chain(bytes)
+            .finalize();
+
+        assert_eq!(declaration.id(&ChainId::default()).unwrap(), expected_id);
+    }
+
+    #[test]
+    fn declaration_without_consensus_parameters_roundtrips_through_postcard() {
+        let declaration = UpgradeDeclaration {
+            consensus_parameters: None,
+            state_transition_bytecode_root: Some(Bytes32::from([1; 32])),
+            ..declaration("0.21.0")
+        };
+
+        let bytes = postcard::to_stdvec(&declaration).unwrap();
+
+        assert_eq!(
+            postcard::from_bytes::<UpgradeDeclaration>(&bytes).unwrap(),
+            declaration
+        );
+    }
+
+    #[test]
+    fn declaration_with_invalid_version_is_rejected() {
+        let signed = declaration("latest")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+
+        assert!(signed
+            .verify(&ConsensusConfig::default_poa(), &ChainId::default())
+            .is_err());
+    }
+
+    #[test]
+    fn is_supported_by_compares_versions() {
+        let declaration = declaration("0.21.0");
+
+        assert!(declaration.is_supported_by("0.21.0").unwrap());
+        assert!(declaration.is_supported_by("0.21.1-rc.1").unwrap());
+        assert!(declaration.is_supported_by("1.0.0").unwrap());
+        assert!(!declaration.is_supported_by("0.20.9").unwrap());
+        assert!(declaration.is_supported_by("0.21").is_err());
+    }
+}
@@ -0,0 +1,364 @@
+use super::{
+    chain::ChainConfig,
+    consensus::ConsensusConfig,
+};
+use fuel_core_types::{
+    fuel_tx::UtxoId,
+    fuel_types::Address,
+    services::executor::FeeShare,
+};
+use std::{
+    collections::HashSet,
+    fmt,
+};
+
+/// The problem of the chain config that prevents the node from starting the chain
+/// or breaks the processing of the transactions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The path to the invalid field, e.g. `consensus_parameters.tx_params.max_inputs`.
+    pub field: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.field, self.reason)
+    }
+}
+
+impl ChainConfig {
+    /// Checks the chain config against the known constraints and returns all found problems.
+    pub fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        let mut error = |field: &str, reason: String| {
+            errors.push(ValidationError {
+                field: field.to_string(),
+                reason,
+            })
+        };
+        let params = &self.consensus_parameters;
+
+        if self.chain_name.is_empty() {
+            error("chain_name", "the name of the chain is empty".to_string());
+        }
+        if self.block_gas_limit < params.tx_params.max_gas_per_tx {
+            error(
+                "block_gas_limit",
+                format!(
+                    "the limit {} is less than the gas limit of one transaction {}",
+                    self.block_gas_limit, params.tx_params.max_gas_per_tx
+                ),
+            );
+        }
+        let block_size_limit = self.block_limits().size;
+        if block_size_limit < params.contract_params.contract_max_size {
+            error(
+                "block_size_limit",
+                format!(
+                    "the limit {} is less than the maximum size of one contract {}",
+                    block_size_limit, params.contract_params.contract_max_size
+                ),
+            );
+        }
+
+        // The inputs, outputs and witnesses are referenced by `u8` indexes.
+        for (field, value) in [
+            (
+                "consensus_parameters.tx_params.max_inputs",
+                params.tx_params.max_inputs,
+            ),
+            (
+                "consensus_parameters.tx_params.max_outputs",
+                params.tx_params.max_outputs,
+            ),
+            (
+                "consensus_parameters.tx_params.max_witnesses",
+                params.tx_params.max_witnesses,
+            ),
+        ] {
+            if value == 0 || value > u8::MAX as u64 {
+                error(field, format!("{value} is not in the range 1..=255"));
+            }
+        }
+        for (field, value) in [
+            (
+                "consensus_parameters.tx_params.max_gas_per_tx",
+                params.tx_params.max_gas_per_tx,
+            ),
+            (
+                "consensus_parameters.predicate_params.max_gas_per_predicate",
+                params.predicate_params.max_gas_per_predicate,
+            ),
+            (
+                "consensus_parameters.contract_params.contract_max_size",
+                params.contract_params.contract_max_size,
+            ),
+            (
+                "consensus_parameters.fee_params.gas_price_factor",
+                params.fee_params.gas_price_factor,
+            ),
+        ] {
+            if value == 0 {
+                error(field, "the value should be greater than zero".to_string());
+            }
+        }
+
+        if self.fee_shares.len() > FeeShare::MAX_SHARES {
+            error(
+                "fee_shares",
+                format!("more than {} shares", FeeShare::MAX_SHARES),
+            );
+        }
+        let total_basis_points: u32 = self
+            .fee_shares
+            .iter()
+            .map(|share| share.basis_points as u32)
+            .sum();
+        if total_basis_points > FeeShare::MAX_BASIS_POINTS as u32 {
+            error(
+                "fee_shares",
+                format!(
+                    "the shares exceed {} basis points in total",
+                    FeeShare::MAX_BASIS_POINTS
+                ),
+            );
+        }
+
+        match &self.consensus {
+            ConsensusConfig::PoA { signing_key } => {
+                if *signing_key == Address::zeroed() {
+                    error(
+                        "consensus.PoA.signing_key",
+                        "the zero address can't sign blocks".to_string(),
+                    );
+                }
+            }
+            ConsensusConfig::PoAMultiSig {
+                signing_keys,
+                threshold,
+            } => {
+                if *threshold == 0 || *threshold as usize > signing_keys.len() {
+                    error(
+                        "consensus.PoAMultiSig.threshold",
+                        format!(
+                            "the threshold should be between 1 and the number \
+                            of the signing keys {}",
+                            signing_keys.len()
+                        ),
+                    );
+                }
+                let mut unique_keys = HashSet::new();
+                for (i, signing_key) in signing_keys.iter().enumerate() {
+                    if *signing_key == Address::zeroed() {
+                        error(
+                            &format!("consensus.PoAMultiSig.signing_keys[{i}]"),
+                            "the zero address can't sign blocks".to_string(),
+                        );
+                    } else if !unique_keys.insert(signing_key) {
+                        error(
+                            &format!("consensus.PoAMultiSig.signing_keys[{i}]"),
+                            format!("the signing key {signing_key:#x} is duplicated"),
+                        );
+                    }
+                }
+            }
+        }
+
+        let Some(state) = &self.initial_state else {
+            return errors
+        };
+
+        let mut utxo_ids = HashSet::new();
+        for (i, coin) in state.coins.iter().flatten().enumerate() {
+            if let (Some(tx_id), Some(output_index)) = (coin.tx_id, coin.output_index) {
+                if !utxo_ids.insert(UtxoId::new(tx_id, output_index)) {
+                    error(
+                        &format!("initial_state.coins[{i}]"),
+                        format!("the utxo id {tx_id:#x}:{output_index} is duplicated"),
+                    );
+                }
+            }
+        }
+
+        let mut contract_ids = HashSet::new();
+        for (i, contract) in state.contracts.iter().flatten().enumerate() {
+            if !contract_ids.insert(contract.contract_id) {
+                error(
+                    &format!("initial_state.contracts[{i}].contract_id"),
+                    format!("the contract {:#x} is duplicated", contract.contract_id),
+                );
+            }
+            if contract.code.len() as u64 > params.contract_params.contract_max_size {
+                error(
+                    &format!("initial_state.contracts[{i}].code"),
+                    format!(
+                        "the size of the code {} exceeds the maximum size {}",
+                        contract.code.len(),
+                        params.contract_params.contract_max_size
+                    ),
+                );
+            }
+        }
+
+        let mut nonces = HashSet::new();
+        for (i, message) in state.messages.iter().flatten().enumerate() {
+            if !nonces.insert(message.nonce) {
+                error(
+                    &format!("initial_state.messages[{i}].nonce"),
+                    format!("the nonce {:#x} is duplicated", message.nonce),
+                );
+            }
+            if message.data.len() as u64 > params.predicate_params.max_message_data_length
+            {
+                error(
+                    &format!("initial_state.messages[{i}].data"),
+                    format!(
+                        "the size of the data {} exceeds the maximum size {}",
+                        message.data.len(),
+                        params.predicate_params.max_message_data_length
+                    ),
+                );
+            }
+        }
+
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use crate::{
+        CoinConfig,
+        ContractConfig,
+        StateConfig,
+    };
+
+    fn fields(config: &ChainConfig) -> Vec<String> {
+        config
+            .validate()
+            .into_iter()
+            .map(|error| error.field)
+            .collect()
+    }
+
+    #[test]
+    fn validate__accepts_local_testnet() {
+        assert_eq!(ChainConfig::local_testnet().validate(), vec![]);
+    }
+
+    #[test]
+    fn validate__rejects_invalid_consensus_parameters() {
+        let mut config = ChainConfig::local_testnet();
+        config.consensus_parameters.tx_params.max_inputs = 256;
+        config.consensus_parameters.fee_params.gas_price_factor = 0;
+        config.block_gas_limit = config.consensus_parameters.tx_params.max_gas_per_tx - 1;
+        config.block_size_limit = Some(
+            config
+                .consensus_parameters
+                .contract_params
+                .contract_max_size
+                - 1,
+        );
+
+        assert_eq!(
+            fields(&config),
+            vec![
+                "block_gas_limit",
+                "block_size_limit",
+                "consensus_parameters.tx_params.max_inputs",
+                "consensus_parameters.fee_params.gas_price_factor",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate__rejects_invalid_multisig_consensus() {
+        let signing_key = Address::from([1; 32]);
+        let mut config = ChainConfig::local_testnet();
+        config.consensus = ConsensusConfig::PoAMultiSig {
+            signing_keys: vec![signing_key, Address::zeroed(), signing_key],
+            threshold: 4,
+        };
+
+        assert_eq!(
+            fields(&config),
+            vec![
+                "consensus.PoAMultiSig.threshold",
+                "consensus.PoAMultiSig.signing_keys[1]",
+                "consensus.PoAMultiSig.signing_keys[2]",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate__rejects_fee_shares_above_whole_fee() {
+        let share = FeeShare {
+            recipient: Address::from([1; 32]),
+            basis_points: 6_000,
+        };
+        let mut config = ChainConfig::local_testnet();
+        config.fee_shares = vec![share];
+        assert_eq!(config.validate(), vec![]);
+
+        config.fee_shares = vec![share, share];
+        assert_eq!(fields(&config), vec!["fee_shares"]);
+    }
+
+    #[test]
+    fn validate__rejects_too_many_fee_shares() {
+        let share = FeeShare {
+            recipient: Address::from([1; 32]),
+            basis_points: 1,
+        };
+        let mut config = ChainConfig::local_testnet();
+        config.fee_shares = vec![share; FeeShare::MAX_SHARES];
+        assert_eq!(config.validate(), vec![]);
+
+        config.fee_shares = vec![share; FeeShare::MAX_SHARES + 1];
+        assert_eq!(fields(&config), vec!["fee_shares"]);
+    }
+
+    #[test]
+    fn validate__rejects_duplicated_state() {
+        let coin = CoinConfig {
+            tx_id: Some([1; 32].into()),
+            output_index: Some(0),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner: Default::default(),
+            amount: 100,
+            asset_id: Default::default(),
+        };
+        let contract = ContractConfig {
+            contract_id: [2; 32].into(),
+            code: vec![],
+            salt: Default::default(),
+            state: None,
+            balances: None,
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+        };
+        let config = ChainConfig {
+            initial_state: Some(StateConfig {
+                coins: Some(vec![coin.clone(), coin]),
+                contracts: Some(vec![contract.clone(), contract]),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+
+        assert_eq!(
+            fields(&config),
+            vec![
+                "initial_state.coins[1]",
+                "initial_state.contracts[1].contract_id"
+            ]
+        );
+    }
+}
@@ -2,12 +2,21 @@ use crate::client::{
     schema::{
         block::BlockByHeightArgs,
         coins::{
+            CoinsToConsolidateArgs,
             ExcludeInput,
             SpendQueryElementInput,
+            UsableCoinsArgs,
         },
         contract::ContractBalanceQueryArgs,
-        message::MessageStatusArgs,
-        tx::DryRunArg,
+        message::{
+            DepositsArgs,
+            MessageStatusArgs,
+            WithdrawalsArgs,
+        },
+        tx::{
+            DryRunArg,
+            DryRunBundleArg,
+        },
         Tai64Timestamp,
         TransactionId,
     },
@@ -26,13 +35,18 @@ use anyhow::Context;
 #[cfg(feature = "subscriptions")]
 use cynic::StreamingOperation;
 use cynic::{
-    http::ReqwestExt,
+    http::CynicReqwestError,
     GraphQlResponse,
     Id,
     MutationBuilder,
     Operation,
     QueryBuilder,
 };
+use failover::{
+    EndpointHealth,
+    Endpoints,
+    RetryConfig,
+};
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
@@ -48,15 +62,23 @@ use fuel_core_types::{
     fuel_types::{
         canonical::Serialize,
         BlockHeight,
+        Bytes32,
         Nonce,
     },
+    services::executor::{
+        StateChange,
+        TransactionProfile,
+    },
 };
+use futures::Stream;
 #[cfg(feature = "subscriptions")]
 use futures::StreamExt;
 use itertools::Itertools;
 use pagination::{
+    paginate,
     PageDirection,
     PaginatedResult,
+    PaginationConfig,
     PaginationRequest,
 };
 use schema::{
@@ -108,44 +130,38 @@ use types::{
 };
 
 use self::schema::{
-    block::ProduceBlockArgs,
+    block::{
+        AdvanceTimeArgs,
+        ProduceBlockArgs,
+    },
     message::MessageProofArgs,
+    node_info::SignedNodeInfoArgs,
+    tx::ReceiptProofArgs,
 };
 
+pub mod failover;
 pub mod pagination;
 pub mod schema;
+pub mod transaction_helper;
 pub mod types;
+#[cfg(feature = "subscriptions")]
+mod ws;
 
 #[derive(Debug, Clone)]
 pub struct FuelClient {
     client: reqwest::Client,
     #[cfg(feature = "subscriptions")]
     cookie: Arc<reqwest::cookie::Jar>,
-    url: reqwest::Url,
+    endpoints: Arc<Endpoints>,
+    retry: RetryConfig,
+    pagination: PaginationConfig,
 }
 
 impl FromStr for FuelClient {
     type Err = anyhow::Error;
 
     fn from_str(str: &str) -> Result<Self, Self::Err> {
-        let mut raw_url = str.to_string();
-        if !raw_url.starts_with("http") {
-            raw_url = format!("http://{raw_url}");
-        }
-
-        let mut url = reqwest::Url::parse(&raw_url)
-            .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
-        url.set_path("/graphql");
-        let cookie = Arc::new(reqwest::cookie::Jar::default());
-        let client = reqwest::Client::builder()
-            .cookie_provider(cookie.clone())
-            .build()?;
-        Ok(Self {
-            client,
-            #[cfg(feature = "subscriptions")]
-            cookie,
-            url,
-        })
+        Self::with_urls(&[str])
     }
 }
 
@@ -177,6 +193,63 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Creates the client that fails over between the nodes behind the `urls`.
+    ///
+    /// The requests go to the first URL until it fails with a transient error
+    /// (connection error, timeout or 5xx response). After that, the client switches
+    /// to the healthiest of other URLs and sticks to it.
+    pub fn with_urls(urls: &[impl AsRef<str>]) -> anyhow::Result<Self> {
+        if urls.is_empty() {
+            return Err(anyhow::anyhow!("At least one fuel-core URL is required"))
+        }
+        let urls = urls
+            .iter()
+            .map(|url| Self::parse_url(url.as_ref()))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let cookie = Arc::new(reqwest::cookie::Jar::default());
+        let client = reqwest::Client::builder()
+            .cookie_provider(cookie.clone())
+            .build()?;
+        Ok(Self {
+            client,
+            #[cfg(feature = "subscriptions")]
+            cookie,
+            endpoints: Arc::new(Endpoints::new(urls)),
+            retry: RetryConfig::default(),
+            pagination: PaginationConfig::default(),
+        })
+    }
+
+    fn parse_url(str: &str) -> anyhow::Result<reqwest::Url> {
+        let mut raw_url = str.to_string();
+        if !raw_url.starts_with("http") {
+            raw_url = format!("http://{raw_url}");
+        }
+
+        let mut url = reqwest::Url::parse(&raw_url)
+            .with_context(|| format!("Invalid fuel-core URL: {str}"))?;
+        url.set_path("/graphql");
+        Ok(url)
+    }
+
+    /// Sets the policy of retrying the requests failed with transient errors.
+    /// The mutations are retried only if they didn't reach the node.
+    pub fn with_retry_config(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the page size and the rate limit of the streams of the paginated queries.
+    pub fn with_pagination_config(mut self, pagination: PaginationConfig) -> Self {
+        self.pagination = pagination;
+        self
+    }
+
+    /// Returns the health of the endpoints of the client.
+    pub fn endpoints_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.health()
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -186,16 +259,55 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
-            .run_graphql(q)
-            .await
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let is_mutation = failover::is_mutation(&q.query);
+        let body = serde_json::to_vec(&q)?;
+        let mut attempt = 0;
+        let response = loop {
+            let (index, url) = self.endpoints.active();
+            let request = self
+                .client
+                .post(url.clone())
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone());
+            match Self::run_graphql(request).await {
+                Ok(response) => {
+                    self.endpoints.report_success(index);
+                    break response
+                }
+                Err(e) if failover::is_transient(&e) => {
+                    self.endpoints.report_failure(index);
+                    attempt += 1;
+                    if attempt >= self.retry.max_attempts
+                        || !failover::is_retriable(&e, is_mutation)
+                    {
+                        return Err(io::Error::new(io::ErrorKind::Other, e))
+                    }
+                    tracing::debug!("Retrying the request after the error: {e}");
+                    tokio::time::sleep(self.retry.backoff(attempt - 1)).await;
+                }
+                Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+            }
+        };
 
         Self::decode_response(response)
     }
 
+    async fn run_graphql<R>(
+        request: reqwest::RequestBuilder,
+    ) -> Result<GraphQlResponse<R>, CynicReqwestError>
+    where
+        R: serde::de::DeserializeOwned + 'static,
+    {
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return serde_json::from_str::<GraphQlResponse<R>>(&body)
+                .map_err(|_| CynicReqwestError::ErrorResponse(status, body))
+        }
+        Ok(response.json::<GraphQlResponse<R>>().await?)
+    }
+
     fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
@@ -223,7 +335,9 @@ impl FuelClient {
         use eventsource_client as es;
         use hyper_rustls as _;
         use reqwest::cookie::CookieStore;
-        let mut url = self.url.clone();
+        // The subscription sticks to the active endpoint for its whole lifetime.
+        let (_, active_url) = self.endpoints.active();
+        let mut url = active_url.clone();
         url.set_path("/graphql-sub");
         let json_query = serde_json::to_string(&q)?;
         let mut client_builder = es::ClientBuilder::for_url(url.as_str())
@@ -243,7 +357,7 @@ impl FuelClient {
                 )
             })?;
 
-        if let Some(value) = self.cookie.deref().cookies(&self.url) {
+        if let Some(value) = self.cookie.deref().cookies(active_url) {
             let value = value.to_str().map_err(|e| {
                 io::Error::new(
                     io::ErrorKind::Other,
@@ -329,11 +443,88 @@ impl FuelClient {
         self.query(query).await.map(|r| r.node_info.into())
     }
 
+    /// Returns the approximate storage statistics of the database tables.
+    /// Returns `None` if the node doesn't collect them.
+    pub async fn table_stats(&self) -> io::Result<Option<Vec<types::TableStats>>> {
+        let query = schema::node_info::QueryNodeTableStats::build(());
+        let stats = self.query(query).await?.node_info.table_stats;
+        Ok(stats.map(|stats| stats.into_iter().map(Into::into).collect()))
+    }
+
+    /// Returns the upgrades of the chain scheduled above the latest height.
+    pub async fn pending_upgrades(&self) -> io::Result<Vec<types::PendingUpgrade>> {
+        let query = schema::node_info::QueryNodePendingUpgrades::build(());
+        let upgrades = self.query(query).await?.node_info.pending_upgrades;
+        Ok(upgrades.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the information about the node signed by its P2P identity key. The
+    /// `challenge` is included into the signed message to prove that the signature is fresh.
+    pub async fn signed_node_info(
+        &self,
+        challenge: Option<&Bytes32>,
+    ) -> io::Result<types::SignedNodeInfo> {
+        let query = schema::node_info::QuerySignedNodeInfo::build(SignedNodeInfoArgs {
+            challenge: challenge.map(|challenge| (*challenge).into()),
+        });
+        let info = self.query(query).await?.signed_node_info;
+        Ok(info.try_into()?)
+    }
+
+    /// Returns the synchronization status of the node with the network and the DA layer.
+    pub async fn sync_status(&self) -> io::Result<types::SyncStatus> {
+        let query = schema::node_info::QuerySyncStatus::build(());
+        self.query(query).await.map(|r| r.sync_status.into())
+    }
+
     pub async fn chain_info(&self) -> io::Result<types::ChainInfo> {
         let query = schema::chain::ChainQuery::build(());
         self.query(query).await.map(|r| r.chain.into())
     }
 
+    /// Estimates the gas price required to include the transaction
+    /// in one of the next `block_horizon` blocks.
+    pub async fn estimate_gas_price(
+        &self,
+        block_horizon: u32,
+    ) -> io::Result<types::EstimateGasPrice> {
+        let query = schema::gas_price::QueryEstimateGasPrice::build(
+            schema::gas_price::BlockHorizonArgs {
+                block_horizon: Some(block_horizon.into()),
+            },
+        );
+        self.query(query).await.map(|r| r.estimate_gas_price.into())
+    }
+
+    /// Returns the fees of the `last` finalized DA blocks recorded by the relayer
+    /// of the node, or all recorded fees if `last` is not specified.
+    pub async fn da_fee_history(
+        &self,
+        last: Option<u32>,
+    ) -> io::Result<Vec<types::DaFeeSample>> {
+        let query = schema::gas_price::DaFeeHistoryQuery::build(
+            schema::gas_price::DaFeeHistoryArgs {
+                last: last.map(Into::into),
+            },
+        );
+        let samples = self.query(query).await?.da_fee_history;
+        Ok(samples.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the metadata of the asset recorded by the asset registry of the node.
+    pub async fn asset_metadata(
+        &self,
+        asset_id: &AssetId,
+    ) -> io::Result<Option<types::AssetMetadata>> {
+        let query =
+            schema::asset::AssetMetadataQuery::build(schema::asset::AssetMetadataArgs {
+                asset_id: (*asset_id).into(),
+            });
+        self.query(query)
+            .await
+            .map(|r| r.asset_metadata.map(Into::into))
+    }
+
     /// Default dry run, matching the exact configuration as the node
     pub async fn dry_run(&self, tx: &Transaction) -> io::Result<Vec<Receipt>> {
         self.dry_run_opt(tx, None).await
@@ -345,11 +536,26 @@ impl FuelClient {
         tx: &Transaction,
         // Disable utxo input checks (exists, unspent, and valid signature)
         utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<Receipt>> {
+        self.dry_run_at(tx, utxo_validation, None, None).await
+    }
+
+    /// Dry run in the simulated block at the `block_height` and the `block_time`
+    /// instead of the next block. The node limits how far ahead the block can be.
+    pub async fn dry_run_at(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        block_height: Option<BlockHeight>,
+        block_time: Option<Tai64>,
     ) -> io::Result<Vec<Receipt>> {
         let tx = tx.clone().to_bytes();
         let query = schema::tx::DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx)),
             utxo_validation,
+            block_height: block_height.map(Into::into),
+            block_time: block_time.map(Into::into),
         });
         let receipts = self.query(query).await.map(|r| r.dry_run)?;
         receipts
@@ -358,6 +564,61 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the transaction and profile the execution of its script.
+    pub async fn dry_run_profile(
+        &self,
+        tx: &Transaction,
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<(Vec<Receipt>, TransactionProfile)> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::DryRunProfileMutation::build(DryRunArg {
+            tx: HexString(Bytes(tx)),
+            utxo_validation,
+            block_height: None,
+            block_time: None,
+        });
+        let result = self.query(query).await?.dry_run_profile;
+        let receipts = result
+            .receipts
+            .into_iter()
+            .map(|receipt| receipt.try_into().map_err(Into::into))
+            .collect::<io::Result<Vec<_>>>()?;
+        let profile = serde_json::from_str(result.profile.as_str())?;
+        Ok((receipts, profile))
+    }
+
+    /// Dry run the ordered bundle of transactions on top of the same state. Returns
+    /// the receipts of each transaction and the changes of the state made by the bundle.
+    pub async fn dry_run_bundle(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<(Vec<Vec<Receipt>>, Vec<StateChange>)> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.clone().to_bytes())))
+            .collect();
+        let query = schema::tx::DryRunBundleMutation::build(DryRunBundleArg {
+            txs,
+            utxo_validation,
+        });
+        let result = self.query(query).await?.dry_run_bundle;
+        let receipts = result
+            .transactions
+            .into_iter()
+            .map(|tx| {
+                tx.receipts
+                    .into_iter()
+                    .map(|receipt| receipt.try_into().map_err(Into::into))
+                    .collect::<io::Result<Vec<_>>>()
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        let state_diff = result.state_diff.into_iter().map(Into::into).collect();
+        Ok((receipts, state_diff))
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -383,6 +644,21 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the private transaction, it isn't gossiped to the peers before
+    /// its inclusion. The node must produce the blocks.
+    pub async fn submit_private(
+        &self,
+        tx: &Transaction,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitPrivate::build(TxArg {
+            tx: HexString(Bytes(tx)),
+        });
+
+        let id = self.query(query).await.map(|r| r.submit_private)?.id.into();
+        Ok(id)
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -570,6 +846,26 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the statuses of the transaction recorded by the status archive of the
+    /// node, from the oldest to the newest.
+    pub async fn transaction_status_history(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Vec<types::ArchivedTransactionStatus>> {
+        let query = schema::tx::TransactionStatusHistoryQuery::build(TxIdArgs {
+            id: (*id).into(),
+        });
+        let history = self
+            .query(query)
+            .await?
+            .transaction_status_history
+            .into_iter()
+            .map(TryInto::try_into)
+            .try_collect()?;
+
+        Ok(history)
+    }
+
     #[tracing::instrument(skip(self), level = "debug")]
     #[cfg(feature = "subscriptions")]
     /// Subscribe to the status of a transaction
@@ -592,6 +888,170 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the status of the transaction over WebSocket.
+    ///
+    /// Unlike [`Self::subscribe_transaction_status`], the subscription survives
+    /// the connection loss: it reconnects according to the retry config of the client
+    /// and continues from the current status of the transaction.
+    pub async fn subscribe_transaction_status_ws(
+        &self,
+        id: &TxId,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionStatus>>> {
+        use cynic::SubscriptionBuilder;
+        let tx_id: TransactionId = (*id).into();
+        let s = schema::tx::StatusChangeSubscription::build(TxIdArgs { id: tx_id });
+        let subscription =
+            ws::TransactionStatusSubscription::new(self.clone(), &s).await?;
+        Ok(subscription.into_stream())
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the blocks imported by the node over WebSocket.
+    ///
+    /// The stream yields every block after the current latest block once,
+    /// in order of height. The subscription
+    /// reconnects after the connection loss according to the retry config of the client
+    /// and requests the blocks missed in the meantime by their heights.
+    pub async fn subscribe_blocks(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::Block>>> {
+        let subscription = ws::BlocksSubscription::new(self.clone()).await?;
+        Ok(subscription.into_stream())
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the blocks imported by the node, like the [`Self::subscribe_blocks`],
+    /// and fetches the transactions of each block with their receipts.
+    ///
+    /// Up to the `concurrency` transactions of the block are fetched at the same time.
+    /// The blocks are yielded in order of height, and the transactions in order
+    /// of the block. The failure to fetch the transactions is yielded as the error
+    /// of the block, the stream continues with the next block.
+    pub async fn listen_blocks_with_receipts(
+        &self,
+        concurrency: usize,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::BlockWithReceipts>>>
+    {
+        use futures::TryStreamExt;
+        let concurrency = concurrency.max(1);
+        let client = self.clone();
+        let stream = self.subscribe_blocks().await?.then(move |block| {
+            let client = client.clone();
+            async move {
+                let block = block?;
+                let transactions = futures::stream::iter(block.transactions.clone())
+                    .map(|id| {
+                        let client = client.clone();
+                        async move { client.transaction_with_receipts(&id).await }
+                    })
+                    .buffered(concurrency)
+                    .try_collect()
+                    .await?;
+                Ok(types::BlockWithReceipts {
+                    block,
+                    transactions,
+                })
+            }
+        });
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    async fn transaction_with_receipts(
+        &self,
+        id: &TxId,
+    ) -> io::Result<types::TransactionWithReceipts> {
+        let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
+
+        let mut tx = self.query(query).await?.transaction.ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, format!("transaction {id} not found"))
+        })?;
+
+        let receipts = tx
+            .receipts
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::<Receipt>::try_into)
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+
+        Ok(types::TransactionWithReceipts {
+            id: *id,
+            transaction: tx.try_into()?,
+            receipts,
+        })
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the status of the transaction with the cursor of each status.
+    ///
+    /// Without the `resume_from`, the stream starts with the current status.
+    /// After the connection loss, pass the cursor of the last received status
+    /// to receive the statuses sent by the node in the meantime.
+    pub async fn subscribe_transaction_status_events(
+        &self,
+        id: &TxId,
+        resume_from: Option<&str>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<(String, TransactionStatus)>>>
+    {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::StatusChangeEventsSubscription::build(
+            schema::tx::StatusChangeEventsArgs {
+                id: (*id).into(),
+                resume_from: resume_from.map(str::to_string),
+            },
+        );
+        let stream = self.subscribe(s).await?.map(|event| {
+            let event = event?.status_change_events;
+            Ok((event.cursor, event.status.try_into()?))
+        });
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the blocks imported by the node with the cursor of each block.
+    ///
+    /// Without the `resume_from`, the stream starts from the next block.
+    /// After the connection loss, pass the cursor of the last received block
+    /// to receive the blocks imported in the meantime.
+    pub async fn subscribe_block_events(
+        &self,
+        resume_from: Option<&str>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<(String, types::Block)>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::block::NewBlockEventsSubscription::build(
+            schema::block::NewBlockEventsArgs {
+                resume_from: resume_from.map(str::to_string),
+            },
+        );
+        let stream = self.subscribe(s).await?.map(|event| {
+            let event = event?.new_block_events;
+            Ok((event.cursor, event.block.into()))
+        });
+        Ok(stream)
+    }
+
+    #[cfg(feature = "subscriptions")]
+    /// Subscribes to the events of the `addresses`, starting from the next block.
+    /// The node watches the addresses until the stream is dropped.
+    pub async fn subscribe_account_events(
+        &self,
+        addresses: &[Address],
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::AccountEvent>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::account::AccountEventsSubscription::build(
+            schema::account::AccountEventsArgs {
+                addresses: addresses.iter().map(|address| (*address).into()).collect(),
+            },
+        );
+        let stream = self.subscribe(s).await?.map(|event| {
+            let event = event?.account_events.try_into()?;
+            Ok(event)
+        });
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -632,6 +1092,17 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns the stream of all transactions sorted by block height.
+    pub fn transactions_stream(
+        &self,
+    ) -> impl Stream<Item = io::Result<TransactionResponse>> + Send + 'static {
+        let client = self.clone();
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.transactions(request).await }
+        })
+    }
+
     /// Returns a paginated set of transactions associated with a txo owner address.
     pub async fn transactions_by_owner(
         &self,
@@ -645,6 +1116,36 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions associated with a txo owner address,
+    /// with the funds in the `direction` relative to the owner.
+    pub async fn transactions_by_owner_with_direction(
+        &self,
+        owner: &Address,
+        direction: types::TransactionDirection,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let owner: schema::Address = (*owner).into();
+        let query = schema::tx::TransactionsByOwnerQuery::build(
+            (owner, Some(direction.into()), request).into(),
+        );
+
+        let transactions = self.query(query).await?.transactions_by_owner.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the stream of all transactions associated with a txo owner address.
+    pub fn transactions_by_owner_stream(
+        &self,
+        owner: &Address,
+    ) -> impl Stream<Item = io::Result<TransactionResponse>> + Send + 'static {
+        let client = self.clone();
+        let owner = *owner;
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.transactions_by_owner(&owner, request).await }
+        })
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -693,6 +1194,18 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Advances the clock of the chain by `seconds`. Returns the current time of
+    /// the chain. Requires the `debug` mode of the node.
+    pub async fn advance_time(&self, seconds: u64) -> io::Result<Tai64> {
+        let query = schema::block::AdvanceTimeMutation::build(AdvanceTimeArgs {
+            seconds: seconds.into(),
+        });
+
+        let time = self.query(query).await?.advance_time;
+
+        Ok(time.0)
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -713,6 +1226,40 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Returns the keys of the state changed by the block at the `height`. Returns `None`
+    /// if the node doesn't have the state diff of the block.
+    pub async fn state_diff(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::BlockStateDiff>> {
+        let query = schema::block::StateDiffQuery::build(schema::block::StateDiffArgs {
+            block_height: height.into(),
+        });
+
+        let diff = self.query(query).await?.state_diff.map(Into::into);
+
+        Ok(diff)
+    }
+
+    /// Returns the gas prices and the fullness of the `last_n` latest blocks,
+    /// the oldest first.
+    pub async fn fee_history(&self, last_n: u32) -> io::Result<Vec<types::BlockFees>> {
+        let query =
+            schema::block::FeeHistoryQuery::build(schema::block::FeeHistoryArgs {
+                last_n: last_n.into(),
+            });
+
+        let history = self
+            .query(query)
+            .await?
+            .fee_history
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(history)
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -725,6 +1272,17 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Returns the stream of all blocks.
+    pub fn blocks_stream(
+        &self,
+    ) -> impl Stream<Item = io::Result<types::Block>> + Send + 'static {
+        let client = self.clone();
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.blocks(request).await }
+        })
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -751,6 +1309,21 @@ impl FuelClient {
         Ok(coins)
     }
 
+    /// Returns the stream of all coins of the owner
+    pub fn coins_stream(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> impl Stream<Item = io::Result<types::Coin>> + Send + 'static {
+        let client = self.clone();
+        let owner = *owner;
+        let asset_id = asset_id.copied();
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.coins(&owner, asset_id.as_ref(), request).await }
+        })
+    }
+
     /// Retrieve coins to spend in a transaction
     pub async fn coins_to_spend(
         &self,
@@ -758,6 +1331,23 @@ impl FuelClient {
         spend_query: Vec<(AssetId, u64, Option<u64>)>,
         // (Utxos, Messages Nonce)
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        self.coins_to_spend_with_strategy(owner, spend_query, excluded_ids, None, None)
+            .await
+    }
+
+    /// Retrieve coins to spend in a transaction selected by the `strategy`.
+    /// The total number of coins can't exceed `max_inputs`. The `strategy` and
+    /// the `max_inputs` are sent only if they are set, because the older nodes
+    /// don't support them.
+    pub async fn coins_to_spend_with_strategy(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u64>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        strategy: Option<types::CoinSelectionStrategy>,
+        max_inputs: Option<u64>,
     ) -> io::Result<Vec<Vec<types::CoinType>>> {
         let owner: schema::Address = (*owner).into();
         let spend_query: Vec<SpendQueryElementInput> = spend_query
@@ -780,20 +1370,69 @@ impl FuelClient {
                 },
             )
             .map(Into::into);
-        let query = schema::coins::CoinsToSpendQuery::build(
-            (owner, spend_query, excluded_ids).into(),
-        );
-
-        let coins_per_asset = self
-            .query(query)
-            .await?
-            .coins_to_spend
+        let args = (owner, spend_query, excluded_ids);
+        let coins_per_asset = if strategy.is_none() && max_inputs.is_none() {
+            let query = schema::coins::CoinsToSpendQuery::build(args.into());
+            self.query(query).await?.coins_to_spend
+        } else {
+            let query = schema::coins::CoinsToSpendWithStrategyQuery::build(
+                (args, strategy.map(Into::into), max_inputs.map(Into::into)).into(),
+            );
+            self.query(query).await?.coins_to_spend
+        };
+        let coins_per_asset = coins_per_asset
             .into_iter()
             .map(|v| v.into_iter().map(Into::into).collect::<Vec<_>>())
             .collect::<Vec<_>>();
         Ok(coins_per_asset)
     }
 
+    /// Retrieve the dust coins of the `asset_id` below the `dust_threshold`
+    /// to consolidate them in one transaction.
+    pub async fn coins_to_consolidate(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        dust_threshold: u64,
+        max: Option<u64>,
+    ) -> io::Result<Vec<types::CoinType>> {
+        let query =
+            schema::coins::CoinsToConsolidateQuery::build(CoinsToConsolidateArgs {
+                owner: (*owner).into(),
+                asset_id: (*asset_id).into(),
+                dust_threshold: dust_threshold.into(),
+                max: max.map(Into::into),
+            });
+
+        let coins = self
+            .query(query)
+            .await?
+            .coins_to_consolidate
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        Ok(coins)
+    }
+
+    /// Retrieve the coins of the `owner` usable by the next transaction. The coins spent
+    /// by the transactions in the pool are skipped, and the coins created by them are
+    /// returned separately.
+    pub async fn usable_coins(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        max: Option<u64>,
+    ) -> io::Result<types::UsableCoins> {
+        let query = schema::coins::UsableCoinsQuery::build(UsableCoinsArgs {
+            owner: (*owner).into(),
+            asset_id: asset_id.map(|asset_id| (*asset_id).into()),
+            max: max.map(Into::into),
+        });
+
+        let coins = self.query(query).await?.usable_coins.into();
+        Ok(coins)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -802,6 +1441,72 @@ impl FuelClient {
         Ok(contract)
     }
 
+    /// Returns the values of the storage `slots` of the `contract` after the block
+    /// at the `height`, or the latest values if the `height` is not specified.
+    pub async fn contract_slot_values(
+        &self,
+        contract: &ContractId,
+        slots: &[Bytes32],
+        height: Option<BlockHeight>,
+    ) -> io::Result<Vec<(Bytes32, Option<Bytes32>)>> {
+        let query = schema::contract::ContractSlotValuesQuery::build(
+            schema::contract::ContractSlotValuesArgs {
+                contract: (*contract).into(),
+                slots: slots.iter().map(|slot| (*slot).into()).collect(),
+                height: height.map(Into::into),
+            },
+        );
+        let values = self
+            .query(query)
+            .await?
+            .contract_slot_values
+            .into_iter()
+            .map(|value| (value.slot.into(), value.value.map(Into::into)))
+            .collect();
+        Ok(values)
+    }
+
+    /// Returns the bytecode, the storage slots and the balances of the `contract`
+    /// after the block at the `height`, or the latest ones if the `height` is not
+    /// specified. Returns `None` if the contract didn't exist at the `height`.
+    pub async fn contract_snapshot(
+        &self,
+        contract: &ContractId,
+        height: Option<BlockHeight>,
+    ) -> io::Result<Option<types::ContractSnapshot>> {
+        let query = schema::contract::ContractSnapshotQuery::build(
+            schema::contract::ContractSnapshotArgs {
+                contract: (*contract).into(),
+                height: height.map(Into::into),
+            },
+        );
+        let snapshot = self.query(query).await?.contract_snapshot.map(Into::into);
+        Ok(snapshot)
+    }
+
+    /// Returns the `first` most called contracts in the `window` of the latest blocks
+    /// with the number of their calls. The node must enable the contract call analytics.
+    pub async fn top_contracts(
+        &self,
+        window: u32,
+        first: Option<u32>,
+    ) -> io::Result<Vec<(ContractId, u64)>> {
+        let query = schema::contract::TopContractsQuery::build(
+            schema::contract::TopContractsArgs {
+                window: window.into(),
+                first: first.map(Into::into),
+            },
+        );
+        let contracts = self
+            .query(query)
+            .await?
+            .top_contracts
+            .into_iter()
+            .map(|contract| (contract.contract.into(), contract.calls.into()))
+            .collect();
+        Ok(contracts)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -851,6 +1556,19 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the stream of all balances of the owner
+    pub fn balances_stream(
+        &self,
+        owner: &Address,
+    ) -> impl Stream<Item = io::Result<types::Balance>> + Send + 'static {
+        let client = self.clone();
+        let owner = *owner;
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.balances(&owner, request).await }
+        })
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -865,6 +1583,18 @@ impl FuelClient {
         Ok(balances)
     }
 
+    pub fn contract_balances_stream(
+        &self,
+        contract: &ContractId,
+    ) -> impl Stream<Item = io::Result<types::ContractBalance>> + Send + 'static {
+        let client = self.clone();
+        let contract = *contract;
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.contract_balances(&contract, request).await }
+        })
+    }
+
     pub async fn messages(
         &self,
         owner: Option<&Address>,
@@ -878,6 +1608,18 @@ impl FuelClient {
         Ok(messages)
     }
 
+    pub fn messages_stream(
+        &self,
+        owner: Option<&Address>,
+    ) -> impl Stream<Item = io::Result<types::Message>> + Send + 'static {
+        let client = self.clone();
+        let owner = owner.copied();
+        paginate(self.pagination, move |request| {
+            let client = client.clone();
+            async move { client.messages(owner.as_ref(), request).await }
+        })
+    }
+
     pub async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
         let query = schema::message::MessageStatusQuery::build(MessageStatusArgs {
             nonce: (*nonce).into(),
@@ -887,6 +1629,65 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the status of the message with the DA height at which the relayer
+    /// received it and the transaction that spent it.
+    pub async fn message_provenance(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<types::MessageProvenance> {
+        let query = schema::message::MessageStatusQuery::build(MessageStatusArgs {
+            nonce: (*nonce).into(),
+        });
+        let provenance = self.query(query).await?.message_status.into();
+
+        Ok(provenance)
+    }
+
+    /// Tracks the deposits of all messages sent by the DA transaction.
+    pub async fn deposits_by_da_transaction(
+        &self,
+        da_transaction_hash: &Bytes32,
+    ) -> io::Result<Vec<types::Deposit>> {
+        let query = schema::message::DepositsQuery::build(DepositsArgs {
+            da_transaction_hash: Some((*da_transaction_hash).into()),
+            nonce: None,
+        });
+        let deposits = self.query(query).await?.deposits;
+
+        Ok(deposits.into_iter().map(Into::into).collect())
+    }
+
+    /// Tracks the deposit of the message with the `nonce`.
+    pub async fn deposit(&self, nonce: &Nonce) -> io::Result<types::Deposit> {
+        let query = schema::message::DepositsQuery::build(DepositsArgs {
+            da_transaction_hash: None,
+            nonce: Some((*nonce).into()),
+        });
+        let deposit = self
+            .query(query)
+            .await?
+            .deposits
+            .into_iter()
+            .next()
+            .ok_or_else(|| io::Error::new(ErrorKind::Other, "The deposit is missing"))?;
+
+        Ok(deposit.into())
+    }
+
+    /// Returns the withdrawals of the transaction with the state of their commitment
+    /// on the DA layer and the proofs to relay them.
+    pub async fn withdrawals(
+        &self,
+        transaction_id: &TxId,
+    ) -> io::Result<Vec<types::Withdrawal>> {
+        let query = schema::message::WithdrawalsQuery::build(WithdrawalsArgs {
+            transaction_id: (*transaction_id).into(),
+        });
+        let withdrawals = self.query(query).await?.withdrawals;
+
+        Ok(withdrawals.into_iter().map(Into::into).collect())
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
@@ -911,6 +1712,32 @@ impl FuelClient {
 
         Ok(proof)
     }
+
+    /// Returns the proof that the receipt at `receipt_index` was produced by the script
+    /// transaction. Either `commit_block_id` or `commit_block_height` must be set.
+    pub async fn receipt_proof(
+        &self,
+        transaction_id: &TxId,
+        receipt_index: u32,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Option<types::ReceiptProof>> {
+        let query = schema::tx::ReceiptProofQuery::build(ReceiptProofArgs {
+            transaction_id: (*transaction_id).into(),
+            receipt_index: receipt_index.into(),
+            commit_block_id: commit_block_id.map(|id| (*id).into()),
+            commit_block_height: commit_block_height.map(Into::into),
+        });
+
+        let proof = self
+            .query(query)
+            .await?
+            .receipt_proof
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        Ok(proof)
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
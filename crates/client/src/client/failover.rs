@@ -0,0 +1,268 @@
+//! Failover and retry of the requests across several endpoints of the nodes.
+
+use cynic::http::CynicReqwestError;
+use std::{
+    sync::atomic::{
+        AtomicU32,
+        AtomicUsize,
+        Ordering,
+    },
+    time::Duration,
+};
+
+/// The policy of retrying the requests that failed because of transient errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The maximum number of attempts of one request, including the first one.
+    pub max_attempts: usize,
+    /// The delay before the first retry. The delay doubles after each attempt.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Disables the retries, each request is sent only once.
+    pub fn no_retries() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the delay before the next attempt after the `attempt` failed.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let factor = u32::try_from(attempt)
+            .ok()
+            .and_then(|attempt| 1u32.checked_shl(attempt))
+            .unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// The health of the endpoint as seen by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointHealth {
+    /// The URL of the endpoint.
+    pub url: reqwest::Url,
+    /// The number of transient failures since the last successful request.
+    pub consecutive_failures: u32,
+    /// The client sends the requests and subscriptions to this endpoint.
+    pub active: bool,
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    url: reqwest::Url,
+    consecutive_failures: AtomicU32,
+}
+
+/// The list of endpoints with the health tracking.
+///
+/// All requests go to the active endpoint, so the session of the client (cookies,
+/// subscriptions) sticks to one node. The client switches to the healthiest
+/// endpoint only when the active one fails.
+#[derive(Debug)]
+pub(crate) struct Endpoints {
+    endpoints: Vec<Endpoint>,
+    active: AtomicUsize,
+}
+
+impl Endpoints {
+    /// Creates the endpoints from the non-empty list of URLs.
+    pub fn new(urls: Vec<reqwest::Url>) -> Self {
+        assert!(!urls.is_empty(), "At least one endpoint is required");
+        let endpoints = urls
+            .into_iter()
+            .map(|url| Endpoint {
+                url,
+                consecutive_failures: AtomicU32::new(0),
+            })
+            .collect();
+        Self {
+            endpoints,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the index and the URL of the active endpoint.
+    pub fn active(&self) -> (usize, &reqwest::Url) {
+        let index = self.active.load(Ordering::Acquire);
+        (index, &self.endpoints[index].url)
+    }
+
+    pub fn report_success(&self, index: usize) {
+        self.endpoints[index]
+            .consecutive_failures
+            .store(0, Ordering::Release);
+    }
+
+    /// Records the failure of the endpoint and, if it was active, switches
+    /// to the endpoint with the fewest consecutive failures.
+    pub fn report_failure(&self, index: usize) {
+        self.endpoints[index]
+            .consecutive_failures
+            .fetch_add(1, Ordering::AcqRel);
+
+        let len = self.endpoints.len();
+        let next = (1..len)
+            .map(|offset| (index + offset) % len)
+            .min_by_key(|i| {
+                self.endpoints[*i]
+                    .consecutive_failures
+                    .load(Ordering::Acquire)
+            });
+        if let Some(next) = next {
+            // Another request could already switch the active endpoint.
+            let _ = self.active.compare_exchange(
+                index,
+                next,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            );
+        }
+    }
+
+    pub fn health(&self) -> Vec<EndpointHealth> {
+        let active = self.active.load(Ordering::Acquire);
+        self.endpoints
+            .iter()
+            .enumerate()
+            .map(|(index, endpoint)| EndpointHealth {
+                url: endpoint.url.clone(),
+                consecutive_failures: endpoint
+                    .consecutive_failures
+                    .load(Ordering::Acquire),
+                active: index == active,
+            })
+            .collect()
+    }
+}
+
+/// Returns `true` if the request may succeed on retry or on another node.
+pub(crate) fn is_transient(error: &CynicReqwestError) -> bool {
+    match error {
+        CynicReqwestError::ReqwestError(error) => {
+            error.is_connect()
+                || error.is_timeout()
+                || error.status().map_or(false, |s| s.is_server_error())
+        }
+        CynicReqwestError::ErrorResponse(status, _) => status.is_server_error(),
+    }
+}
+
+/// Returns `true` if the request failed with the transient `error` may be retried.
+/// The mutation may be applied by the node before the failure, so it is retried only
+/// if it didn't reach the node.
+pub(crate) fn is_retriable(error: &CynicReqwestError, is_mutation: bool) -> bool {
+    if is_mutation {
+        matches!(error, CynicReqwestError::ReqwestError(error) if error.is_connect())
+    } else {
+        is_transient(error)
+    }
+}
+
+/// Returns `true` if the GraphQL `query` is a mutation.
+pub(crate) fn is_mutation(query: &str) -> bool {
+    query.trim_start().starts_with("mutation")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn endpoints(count: usize) -> Endpoints {
+        let urls = (0..count)
+            .map(|i| {
+                format!("http://127.0.0.1:{}/graphql", 4000 + i)
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        Endpoints::new(urls)
+    }
+
+    #[test]
+    fn report_failure__switches_to_next_endpoint() {
+        let endpoints = endpoints(3);
+
+        endpoints.report_failure(0);
+
+        assert_eq!(endpoints.active().0, 1);
+    }
+
+    #[test]
+    fn report_failure__switches_to_healthiest_endpoint() {
+        let endpoints = endpoints(3);
+        endpoints.report_failure(0);
+        endpoints.report_failure(1);
+        assert_eq!(endpoints.active().0, 2);
+
+        endpoints.report_failure(2);
+
+        // Both other endpoints failed once, the first one after the failed is chosen.
+        assert_eq!(endpoints.active().0, 0);
+        endpoints.report_failure(0);
+        assert_eq!(endpoints.active().0, 1);
+    }
+
+    #[test]
+    fn report_failure__of_inactive_endpoint_keeps_active() {
+        let endpoints = endpoints(3);
+
+        endpoints.report_failure(2);
+
+        assert_eq!(endpoints.active().0, 0);
+        assert_eq!(endpoints.health()[2].consecutive_failures, 1);
+    }
+
+    #[test]
+    fn report_success__resets_failures() {
+        let endpoints = endpoints(2);
+        endpoints.report_failure(0);
+
+        endpoints.report_success(0);
+
+        assert_eq!(endpoints.health()[0].consecutive_failures, 0);
+        // The client sticks to the new active endpoint.
+        assert_eq!(endpoints.active().0, 1);
+    }
+
+    #[test]
+    fn backoff__doubles_up_to_max() {
+        let config = RetryConfig {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+        };
+
+        assert_eq!(config.backoff(0), Duration::from_millis(100));
+        assert_eq!(config.backoff(1), Duration::from_millis(200));
+        assert_eq!(config.backoff(2), Duration::from_millis(400));
+        assert_eq!(config.backoff(3), Duration::from_millis(500));
+        assert_eq!(config.backoff(100), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn is_mutation__detects_operation_type() {
+        assert!(is_mutation(
+            "mutation($tx: HexString!) { submit(tx: $tx) { id } }"
+        ));
+        assert!(is_mutation("\n  mutation { startSession }"));
+        assert!(!is_mutation("query { health }"));
+        assert!(!is_mutation("subscription { statusChange { status } }"));
+    }
+}
@@ -1,3 +1,13 @@
+use futures::{
+    Future,
+    Stream,
+    StreamExt,
+};
+use std::{
+    io,
+    time::Duration,
+};
+
 /// Specifies the direction of a paginated query
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum PageDirection {
@@ -22,3 +32,152 @@ pub struct PaginatedResult<T, C> {
     pub has_next_page: bool,
     pub has_previous_page: bool,
 }
+
+/// The configuration of the streams that follow the cursors of the paginated queries.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PaginationConfig {
+    /// The number of results requested per page.
+    pub page_size: usize,
+    /// The direction of the iteration.
+    pub direction: PageDirection,
+    /// The delay between the requests of the pages. It limits the rate of requests
+    /// sent to the node by the bulk consumers.
+    pub page_delay: Duration,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            direction: PageDirection::Forward,
+            page_delay: Duration::ZERO,
+        }
+    }
+}
+
+/// Returns the stream of all results of the paginated query. The `fetch` requests
+/// the page by the cursor of the previous page until the last page is received.
+///
+/// The stream ends after the first error.
+pub fn paginate<T, F, Fut>(
+    config: PaginationConfig,
+    fetch: F,
+) -> impl Stream<Item = io::Result<T>>
+where
+    F: FnMut(PaginationRequest<String>) -> Fut,
+    Fut: Future<Output = io::Result<PaginatedResult<T, String>>>,
+{
+    let state = PaginationState {
+        fetch,
+        // `Some(None)` is the request of the first page, `None` means that
+        // there are no more pages.
+        cursor: Some(None),
+        first: true,
+    };
+    futures::stream::unfold(state, move |mut state| async move {
+        let cursor = state.cursor.take()?;
+        if !state.first && !config.page_delay.is_zero() {
+            tokio::time::sleep(config.page_delay).await;
+        }
+        state.first = false;
+
+        let request = PaginationRequest {
+            cursor,
+            results: config.page_size,
+            direction: config.direction,
+        };
+        match (state.fetch)(request).await {
+            Ok(page) => {
+                if page.has_next_page {
+                    state.cursor = page.cursor.map(Some);
+                }
+                let results = page.results.into_iter().map(Ok).collect::<Vec<_>>();
+                Some((futures::stream::iter(results), state))
+            }
+            Err(e) => Some((futures::stream::iter(vec![Err(e)]), state)),
+        }
+    })
+    .flatten()
+}
+
+struct PaginationState<F> {
+    fetch: F,
+    cursor: Option<Option<String>>,
+    first: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use futures::{
+        executor::block_on,
+        StreamExt,
+    };
+
+    /// Returns the pages of the numbers from `0` to `len` where the cursor is the
+    /// last number of the page.
+    fn fetch_numbers(
+        len: usize,
+        requests: &mut Vec<PaginationRequest<String>>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<usize, String>> {
+        requests.push(request.clone());
+        let start = request
+            .cursor
+            .map(|cursor| cursor.parse::<usize>().unwrap() + 1)
+            .unwrap_or_default();
+        let end = (start + request.results).min(len);
+        let results: Vec<_> = (start..end).collect();
+        Ok(PaginatedResult {
+            cursor: results.last().map(ToString::to_string),
+            results,
+            has_next_page: end < len,
+            has_previous_page: start > 0,
+        })
+    }
+
+    #[test]
+    fn paginate__follows_cursors_until_last_page() {
+        let mut requests = vec![];
+        let config = PaginationConfig {
+            page_size: 3,
+            ..Default::default()
+        };
+
+        let results: Vec<_> = block_on(
+            paginate(config, |request| {
+                futures::future::ready(fetch_numbers(8, &mut requests, request))
+            })
+            .collect(),
+        );
+
+        let results: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, (0..8).collect::<Vec<_>>());
+        let cursors: Vec<_> = requests.into_iter().map(|r| r.cursor).collect();
+        assert_eq!(
+            cursors,
+            vec![None, Some("2".to_string()), Some("5".to_string())]
+        );
+    }
+
+    #[test]
+    fn paginate__ends_after_error() {
+        let mut calls = 0;
+
+        let results: Vec<io::Result<usize>> = block_on(
+            paginate(PaginationConfig::default(), |_| {
+                calls += 1;
+                futures::future::ready(Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "The node is unavailable",
+                )))
+            })
+            .collect(),
+        );
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+        assert_eq!(calls, 1);
+    }
+}
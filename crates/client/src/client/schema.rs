@@ -26,11 +26,14 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod account;
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod gas_price;
 pub mod message;
 pub mod node_info;
 pub mod primitives;
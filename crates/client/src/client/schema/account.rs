@@ -0,0 +1,65 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    AssetId,
+    Nonce,
+    TransactionId,
+    UtxoId,
+    U32,
+    U64,
+};
+
+#[derive(cynic::InlineFragments, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum AccountEvent {
+    CoinReceivedEvent(CoinReceivedEvent),
+    CoinSpentEvent(CoinSpentEvent),
+    MessageReceivedEvent(MessageReceivedEvent),
+    #[cynic(fallback)]
+    Unknown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinReceivedEvent {
+    pub owner: Address,
+    pub utxo_id: UtxoId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinSpentEvent {
+    pub owner: Address,
+    pub utxo_id: UtxoId,
+    pub transaction_id: TransactionId,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MessageReceivedEvent {
+    pub recipient: Address,
+    pub nonce: Nonce,
+    pub amount: U64,
+    pub da_height: U64,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AccountEventsArgs {
+    pub addresses: Vec<Address>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "AccountEventsArgs"
+)]
+pub struct AccountEventsSubscription {
+    #[arguments(addresses: $addresses)]
+    pub account_events: AccountEvent,
+}
@@ -0,0 +1,48 @@
+use crate::client::schema::{
+    schema,
+    AssetId,
+    Bytes32,
+    ContractId,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetMetadata {
+    pub asset_id: AssetId,
+    pub contract_id: ContractId,
+    pub sub_id: Bytes32,
+    pub total_supply: U64,
+    pub first_issued_at: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetMetadataArgs {
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetMetadataArgs"
+)]
+pub struct AssetMetadataQuery {
+    #[arguments(assetId: $asset_id)]
+    pub asset_metadata: Option<AssetMetadata>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_metadata_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetMetadataQuery::build(AssetMetadataArgs {
+            asset_id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -2,9 +2,12 @@ use crate::client::schema::{
     schema,
     BlockId,
     ConnectionArgs,
+    ContractId,
+    Nonce,
     PageInfo,
     Signature,
     Tai64Timestamp,
+    UtxoId,
     U32,
     U64,
 };
@@ -47,6 +50,65 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StateDiffArgs {
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "StateDiffArgs"
+)]
+pub struct StateDiffQuery {
+    #[arguments(blockHeight: $block_height)]
+    pub state_diff: Option<BlockStateDiff>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockStateDiff {
+    pub height: U32,
+    pub coins_created: Vec<UtxoId>,
+    pub coins_spent: Vec<UtxoId>,
+    pub contract_slots: Vec<ContractSlotWrite>,
+    pub messages_consumed: Vec<Nonce>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct FeeHistoryArgs {
+    pub last_n: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "FeeHistoryArgs"
+)]
+pub struct FeeHistoryQuery {
+    #[arguments(lastN: $last_n)]
+    pub fee_history: Vec<BlockFees>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockFees {
+    pub height: U32,
+    pub min_gas_price: U64,
+    pub gas_used: U64,
+    pub bytes_used: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlotWrite {
+    pub contract_id: ContractId,
+    pub slot: Bytes32,
+    pub value: Option<Bytes32>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -81,6 +143,35 @@ pub struct Block {
     pub transactions: Vec<TransactionIdFragment>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct NewBlocksSubscription {
+    pub new_blocks: Block,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewBlockEventsArgs {
+    pub resume_from: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockEvent {
+    pub cursor: String,
+    pub block: Block,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlockEventsArgs"
+)]
+pub struct NewBlockEventsSubscription {
+    #[arguments(resumeFrom: $resume_from)]
+    pub new_block_events: BlockEvent,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
 pub struct BlockIdFragment {
@@ -104,6 +195,22 @@ pub struct BlockMutation {
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AdvanceTimeArgs {
+    pub seconds: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "AdvanceTimeArgs",
+    graphql_type = "Mutation"
+)]
+pub struct AdvanceTimeMutation {
+    #[arguments(seconds: $seconds)]
+    pub advance_time: Tai64Timestamp,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -124,6 +231,7 @@ pub struct Header {
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    PoAMultiSigConsensus(PoAMultiSigConsensus),
     #[cynic(fallback)]
     Unknown,
 }
@@ -143,6 +251,12 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PoAMultiSigConsensus {
+    pub signatures: Vec<Signature>,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
@@ -154,6 +268,10 @@ impl Block {
                 let producer_pub_key = signature.recover(&message);
                 producer_pub_key.ok()
             }
+            Consensus::PoAMultiSigConsensus(multisig) => {
+                let signature = multisig.signatures.first()?.clone().into_signature();
+                signature.recover(&message).ok()
+            }
             Consensus::Unknown => None,
         }
     }
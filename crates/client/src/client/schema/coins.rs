@@ -178,6 +178,14 @@ impl CoinType {
     }
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum CoinSelectionStrategy {
+    LargestFirst,
+    BranchAndBound,
+    Random,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct CoinsToSpendArgs {
     /// The `Address` of the assets' coins owner.
@@ -212,6 +220,108 @@ pub struct CoinsToSpendQuery {
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+/// The arguments of the `coinsToSpend` with the `strategy` and the `max_inputs`,
+/// which are not supported by the older nodes.
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToSpendWithStrategyArgs {
+    /// The `Address` of the assets' coins owner.
+    owner: Address,
+    /// The total amount of each asset type to spend.
+    query_per_asset: Vec<SpendQueryElementInput>,
+    /// A list of ids to exclude from the selection.
+    excluded_ids: Option<ExcludeInput>,
+    /// The algorithm of the selection.
+    strategy: Option<CoinSelectionStrategy>,
+    /// The maximum number of coins of all assets in the selection.
+    max_inputs: Option<U64>,
+}
+
+impl
+    From<(
+        CoinsToSpendArgsTuple,
+        Option<CoinSelectionStrategy>,
+        Option<U64>,
+    )> for CoinsToSpendWithStrategyArgs
+{
+    fn from(
+        (r, strategy, max_inputs): (
+            CoinsToSpendArgsTuple,
+            Option<CoinSelectionStrategy>,
+            Option<U64>,
+        ),
+    ) -> Self {
+        CoinsToSpendWithStrategyArgs {
+            owner: r.0,
+            query_per_asset: r.1,
+            excluded_ids: r.2,
+            strategy,
+            max_inputs,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToSpendWithStrategyArgs"
+)]
+pub struct CoinsToSpendWithStrategyQuery {
+    #[arguments(owner: $owner, queryPerAsset: $query_per_asset, excludedIds: $excluded_ids, strategy: $strategy, maxInputs: $max_inputs)]
+    pub coins_to_spend: Vec<Vec<CoinType>>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinsToConsolidateArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// The asset of the coins.
+    pub asset_id: AssetId,
+    /// The coins with smaller amounts are the dust.
+    pub dust_threshold: U64,
+    /// The maximum number of coins.
+    pub max: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinsToConsolidateArgs"
+)]
+pub struct CoinsToConsolidateQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, dustThreshold: $dust_threshold, max: $max)]
+    pub coins_to_consolidate: Vec<CoinType>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UsableCoinsArgs {
+    /// The `Address` of the coins owner.
+    pub owner: Address,
+    /// Returns coins only with `asset_id`.
+    pub asset_id: Option<AssetId>,
+    /// The maximum number of coins of each kind.
+    pub max: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct UsableCoins {
+    pub coins: Vec<CoinType>,
+    pub pending_coins: Vec<Coin>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "UsableCoinsArgs"
+)]
+pub struct UsableCoinsQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, max: $max)]
+    pub usable_coins: UsableCoins,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +335,41 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn coins_to_spend_query_doesnt_send_strategy() {
+        use cynic::QueryBuilder;
+        let operation = CoinsToSpendQuery::build(CoinsToSpendArgs::from((
+            Address::default(),
+            vec![],
+            None,
+        )));
+        assert!(!operation.query.contains("strategy"));
+        assert!(!operation.query.contains("maxInputs"));
+    }
+
+    #[test]
+    fn coins_to_consolidate_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = CoinsToConsolidateQuery::build(CoinsToConsolidateArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+            dust_threshold: 100u64.into(),
+            max: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn usable_coins_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = UsableCoinsQuery::build(UsableCoinsArgs {
+            owner: Address::default(),
+            asset_id: None,
+            max: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn coins_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -28,6 +30,82 @@ pub struct ContractByIdQuery {
     pub contract: Option<Contract>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractSlotValuesArgs {
+    pub contract: ContractId,
+    pub slots: Vec<Bytes32>,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractSlotValuesArgs"
+)]
+pub struct ContractSlotValuesQuery {
+    #[arguments(contract: $contract, slots: $slots, height: $height)]
+    pub contract_slot_values: Vec<ContractSlotValue>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlotValue {
+    pub slot: Bytes32,
+    pub value: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractSnapshotArgs {
+    pub contract: ContractId,
+    pub height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractSnapshotArgs"
+)]
+pub struct ContractSnapshotQuery {
+    #[arguments(contract: $contract, height: $height)]
+    pub contract_snapshot: Option<ContractSnapshot>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSnapshot {
+    pub contract: ContractId,
+    pub bytecode: HexString,
+    pub salt: Salt,
+    pub slots: Vec<ContractSlotValue>,
+    pub balances: Vec<ContractBalance>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TopContractsArgs {
+    pub window: U32,
+    pub first: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TopContractsArgs"
+)]
+pub struct TopContractsQuery {
+    #[arguments(window: $window, first: $first)]
+    pub top_contracts: Vec<ContractCalls>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCalls {
+    pub contract: ContractId,
+    pub calls: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalance {
@@ -59,6 +137,7 @@ pub struct Contract {
     pub id: ContractId,
     pub bytecode: HexString,
     pub salt: Salt,
+    pub bytecode_root: Bytes32,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -0,0 +1,65 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EstimateGasPrice {
+    pub gas_price: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlockHorizonArgs {
+    pub block_horizon: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockHorizonArgs"
+)]
+pub struct QueryEstimateGasPrice {
+    #[arguments(blockHorizon: $block_horizon)]
+    pub estimate_gas_price: EstimateGasPrice,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaFeeSample {
+    pub da_height: U64,
+    pub base_fee: U64,
+    pub blob_base_fee: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaFeeHistoryArgs {
+    pub last: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaFeeHistoryArgs"
+)]
+pub struct DaFeeHistoryQuery {
+    #[arguments(last: $last)]
+    pub da_fee_history: Vec<DaFeeSample>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_gas_price_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = QueryEstimateGasPrice::build(BlockHorizonArgs {
+            block_horizon: Some(U32(10)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -35,6 +35,10 @@ pub struct Message {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MessageStatus {
     pub(crate) state: MessageState,
+    pub(crate) da_transaction_hash: Option<Bytes32>,
+    pub(crate) da_height: Option<U64>,
+    pub(crate) spending_transaction_id: Option<TransactionId>,
+    pub(crate) spending_block_height: Option<U32>,
 }
 
 #[derive(cynic::Enum, Debug)]
@@ -170,6 +174,73 @@ pub struct MessageStatusArgs {
     pub nonce: Nonce,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Deposit {
+    pub nonce: Nonce,
+    pub state: MessageState,
+    pub da_transaction_hash: Option<Bytes32>,
+    pub da_height: Option<U64>,
+    pub message: Option<Message>,
+    pub spending_transaction_id: Option<TransactionId>,
+    pub spending_block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DepositsArgs"
+)]
+pub struct DepositsQuery {
+    #[arguments(daTransactionHash: $da_transaction_hash, nonce: $nonce)]
+    pub deposits: Vec<Deposit>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DepositsArgs {
+    /// The query supports either `da_transaction_hash`, or `nonce` set on, not both.
+
+    /// The hash of the DA transaction that sent the messages.
+    pub da_transaction_hash: Option<Bytes32>,
+    /// The nonce of the message.
+    pub nonce: Option<Nonce>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Withdrawal {
+    pub nonce: Nonce,
+    pub state: WithdrawalState,
+    pub commit_block_height: U32,
+    pub proof: Option<MessageProof>,
+}
+
+#[derive(cynic::Enum, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum WithdrawalState {
+    NotCommitted,
+    Committed,
+    Finalized,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "WithdrawalsArgs"
+)]
+pub struct WithdrawalsQuery {
+    #[arguments(transactionId: $transaction_id)]
+    pub withdrawals: Vec<Withdrawal>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct WithdrawalsArgs {
+    /// Transaction id that contains the output messages.
+    pub transaction_id: TransactionId,
+}
+
 impl From<(Option<Address>, PaginationRequest<String>)> for OwnedMessagesConnectionArgs {
     fn from(r: (Option<Address>, PaginationRequest<String>)) -> Self {
         match r.1.direction {
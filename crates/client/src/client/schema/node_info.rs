@@ -1,5 +1,11 @@
 use crate::client::schema::{
     schema,
+    BlockId,
+    Bytes32,
+    HexString,
+    Signature,
+    Tai64Timestamp,
+    U32,
     U64,
 };
 
@@ -20,6 +26,106 @@ pub struct QueryNodeInfo {
     pub node_info: NodeInfo,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "NodeInfo")]
+pub struct NodeTableStats {
+    pub table_stats: Option<Vec<TableStats>>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TableStats {
+    pub table: String,
+    pub size: U64,
+    pub keys: U64,
+    pub write_rate: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNodeTableStats {
+    pub node_info: NodeTableStats,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "NodeInfo")]
+pub struct NodePendingUpgrades {
+    pub pending_upgrades: Vec<PendingUpgrade>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingUpgrade {
+    pub height: U32,
+    pub required_version: String,
+    pub changes_consensus_parameters: bool,
+    pub state_transition_bytecode_root: Option<Bytes32>,
+    pub supported: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QueryNodePendingUpgrades {
+    pub node_info: NodePendingUpgrades,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum DaErrorKind {
+    Rpc,
+    Decode,
+    Storage,
+    Transient,
+    Shutdown,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SyncStatus {
+    pub is_syncing: bool,
+    pub current_height: U32,
+    pub network_height: Option<U32>,
+    pub time_to_tip: Option<U64>,
+    pub da_lag: Option<U64>,
+    pub da_error: Option<DaErrorKind>,
+    pub committer_balance_low: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct QuerySyncStatus {
+    pub sync_status: SyncStatus,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SignedNodeInfoArgs {
+    pub challenge: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SignedNodeInfo {
+    pub node_version: String,
+    pub chain_id: U64,
+    pub block_height: U32,
+    pub block_id: BlockId,
+    pub timestamp: Tai64Timestamp,
+    pub challenge: Option<Bytes32>,
+    pub public_key: HexString,
+    pub signature: Signature,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "SignedNodeInfoArgs",
+    graphql_type = "Query"
+)]
+pub struct QuerySignedNodeInfo {
+    #[arguments(challenge: $challenge)]
+    pub signed_node_info: SignedNodeInfo,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,15 +1,24 @@
-use super::block::BlockIdFragment;
+use super::{
+    block::{
+        BlockIdFragment,
+        Header,
+    },
+    message::MerkleProof,
+};
 use crate::client::{
     schema::{
         schema,
         tx::transparent_receipt::Receipt,
         Address,
+        BlockId,
         ConnectionArgs,
         ConversionError,
         HexString,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        U32,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -49,6 +58,24 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TransactionStatusHistoryQuery {
+    #[arguments(id: $id)]
+    pub transaction_status_history: Vec<ArchivedTransactionStatus>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ArchivedTransactionStatus {
+    pub time: Tai64Timestamp,
+    pub status: TransactionStatus,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -207,29 +234,58 @@ pub struct TransactionsByOwnerConnectionArgs {
     /// Retrieve the last n transactions in order (backward pagination).
     /// Can't be used at the same time as `first`.
     pub last: Option<i32>,
+    /// Select only transactions with the funds in the direction relative to the `owner`
+    pub direction: Option<TransactionDirection>,
 }
 
 impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectionArgs {
     fn from(r: (Address, PaginationRequest<String>)) -> Self {
-        match r.1.direction {
+        (r.0, None, r.1).into()
+    }
+}
+
+impl
+    From<(
+        Address,
+        Option<TransactionDirection>,
+        PaginationRequest<String>,
+    )> for TransactionsByOwnerConnectionArgs
+{
+    fn from(
+        r: (
+            Address,
+            Option<TransactionDirection>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        match r.2.direction {
             PageDirection::Forward => TransactionsByOwnerConnectionArgs {
                 owner: r.0,
-                after: r.1.cursor,
+                after: r.2.cursor,
                 before: None,
-                first: Some(r.1.results as i32),
+                first: Some(r.2.results as i32),
                 last: None,
+                direction: r.1,
             },
             PageDirection::Backward => TransactionsByOwnerConnectionArgs {
                 owner: r.0,
                 after: None,
-                before: r.1.cursor,
+                before: r.2.cursor,
                 first: None,
-                last: Some(r.1.results as i32),
+                last: Some(r.2.results as i32),
+                direction: r.1,
             },
         }
     }
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransactionDirection {
+    Incoming,
+    Outgoing,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -237,7 +293,7 @@ impl From<(Address, PaginationRequest<String>)> for TransactionsByOwnerConnectio
     variables = "TransactionsByOwnerConnectionArgs"
 )]
 pub struct TransactionsByOwnerQuery {
-    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last, direction: $direction)]
     pub transactions_by_owner: TransactionConnection,
 }
 
@@ -252,6 +308,30 @@ pub struct StatusChangeSubscription {
     pub status_change: TransactionStatus,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StatusChangeEventsArgs {
+    pub id: TransactionId,
+    pub resume_from: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransactionStatusEvent {
+    pub cursor: String,
+    pub status: TransactionStatus,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "StatusChangeEventsArgs"
+)]
+pub struct StatusChangeEventsSubscription {
+    #[arguments(id: $id, resumeFrom: $resume_from)]
+    pub status_change_events: TransactionStatusEvent,
+}
+
 // mutations
 
 #[derive(cynic::QueryVariables)]
@@ -274,6 +354,8 @@ pub struct EstimatePredicates {
 pub struct DryRunArg {
     pub tx: HexString,
     pub utxo_validation: Option<bool>,
+    pub block_height: Option<U32>,
+    pub block_time: Option<Tai64Timestamp>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -283,10 +365,88 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(tx: $tx, utxoValidation: $utxo_validation)]
+    #[arguments(
+        tx: $tx,
+        utxoValidation: $utxo_validation,
+        blockHeight: $block_height,
+        blockTime: $block_time
+    )]
     pub dry_run: Vec<Receipt>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunProfile {
+    pub receipts: Vec<Receipt>,
+    pub profile: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunProfileMutation {
+    #[arguments(
+        tx: $tx,
+        utxoValidation: $utxo_validation,
+        blockHeight: $block_height,
+        blockTime: $block_time
+    )]
+    pub dry_run_profile: DryRunProfile,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunBundleArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunTransaction {
+    pub id: TransactionId,
+    pub receipts: Vec<Receipt>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateChange {
+    pub column: String,
+    pub key: HexString,
+    pub value: Option<HexString>,
+}
+
+impl From<StateChange> for fuel_core_types::services::executor::StateChange {
+    fn from(change: StateChange) -> Self {
+        Self {
+            column: change.column,
+            key: change.key.into(),
+            value: change.value.map(Into::into),
+            previous: None,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DryRunBundle {
+    pub transactions: Vec<DryRunTransaction>,
+    pub state_diff: Vec<StateChange>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunBundleArg"
+)]
+pub struct DryRunBundleMutation {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    pub dry_run_bundle: DryRunBundle,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -298,6 +458,17 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxArg"
+)]
+pub struct SubmitPrivate {
+    #[arguments(tx: $tx)]
+    pub submit_private: TransactionIdFragment,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -309,6 +480,50 @@ pub struct SubmitAndAwaitSubscription {
     pub submit_and_await: TransactionStatus,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReceiptProofArgs {
+    /// The script transaction that produced the receipt.
+    pub transaction_id: TransactionId,
+    /// The index of the receipt in the receipts of the transaction.
+    pub receipt_index: U32,
+    /// The block id of the commitment block.
+    /// If it is `None`, the `commit_block_height` should be `Some`.
+    pub commit_block_id: Option<BlockId>,
+    /// The block height of the commitment block.
+    /// If it is `None`, the `commit_block_id` should be `Some`.
+    pub commit_block_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ReceiptProofArgs"
+)]
+pub struct ReceiptProofQuery {
+    #[arguments(
+        transactionId: $transaction_id,
+        receiptIndex: $receipt_index,
+        commitBlockId: $commit_block_id,
+        commitBlockHeight: $commit_block_height
+    )]
+    pub receipt_proof: Option<ReceiptProof>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptProof {
+    pub receipt_proof: MerkleProof,
+    pub transaction_proof: MerkleProof,
+    pub block_proof: MerkleProof,
+    pub block_header: Header,
+    pub commit_block_header: Header,
+    pub receipt: Receipt,
+    pub raw_receipt: HexString,
+    pub receipts_count: U64,
+    pub raw_transaction: HexString,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
 pub struct AllReceipts {
@@ -361,6 +576,7 @@ pub mod tests {
                 before: None,
                 first: None,
                 last: None,
+                direction: None,
             });
         insta::assert_snapshot!(operation.query)
     }
@@ -372,6 +588,8 @@ pub mod tests {
         let query = DryRun::build(DryRunArg {
             tx: HexString(Bytes(tx.to_bytes())),
             utxo_validation: None,
+            block_height: None,
+            block_time: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -385,4 +603,14 @@ pub mod tests {
         });
         insta::assert_snapshot!(query.query)
     }
+
+    #[test]
+    fn submit_private_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitPrivate::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(query.query)
+    }
 }
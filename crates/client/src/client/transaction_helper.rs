@@ -0,0 +1,448 @@
+//! The helper that builds ready to submit transactions with the parameters of the chain.
+
+use crate::client::{
+    types::CoinType,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+            Witnesses,
+        },
+        Address,
+        AssetId,
+        Bytes32,
+        Chargeable,
+        ConsensusParameters,
+        ContractId,
+        Input,
+        Output,
+        Receipt,
+        ScriptExecutionResult,
+        Transaction,
+        TransactionBuilder,
+        TransactionFee,
+        TxPointer,
+        UniqueIdentifier,
+        UtxoId,
+        Witness,
+    },
+    fuel_types::Word,
+};
+use std::{
+    collections::BTreeMap,
+    io,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The default number of blocks in which the built transaction should be included.
+pub const DEFAULT_BLOCK_HORIZON: u32 = 10;
+/// The fee depends on the number of inputs that depends on the fee, so the
+/// helper repeats the selection of coins until the fee stops growing.
+const MAX_FEE_ITERATIONS: usize = 4;
+
+#[derive(Debug, Clone)]
+struct CachedParameters {
+    /// The height of the latest block when the parameters were fetched.
+    fetched_at: u32,
+    /// The height of the next pending upgrade that changes the consensus parameters.
+    next_upgrade: Option<u32>,
+    consensus_parameters: ConsensusParameters,
+}
+
+/// The wrapper around the [`FuelClient`] that caches the consensus parameters and
+/// builds the transactions with the estimated gas price and gas limit.
+///
+/// The consensus parameters change only by the upgrades of the chain. The helper caches
+/// them with the height they were fetched at and fetches them again only while the
+/// upgrade changing them is pending. The upgrades declared after the parameters were
+/// fetched are noticed only after the [`TransactionHelper::invalidate`].
+#[derive(Debug, Clone)]
+pub struct TransactionHelper {
+    client: FuelClient,
+    cache: Arc<Mutex<Option<CachedParameters>>>,
+    block_horizon: u32,
+}
+
+impl TransactionHelper {
+    pub fn new(client: FuelClient) -> Self {
+        Self {
+            client,
+            cache: Default::default(),
+            block_horizon: DEFAULT_BLOCK_HORIZON,
+        }
+    }
+
+    /// Sets the number of blocks in which the built transactions should be included.
+    /// The gas price of the transactions is enough for any of these blocks.
+    pub fn with_block_horizon(mut self, block_horizon: u32) -> Self {
+        self.block_horizon = block_horizon;
+        self
+    }
+
+    pub fn client(&self) -> &FuelClient {
+        &self.client
+    }
+
+    /// Drops the cached consensus parameters, the next request fetches them again.
+    pub fn invalidate(&self) {
+        *self.cache.lock().expect("The lock is poisoned") = None;
+    }
+
+    /// Returns the consensus parameters, fetching them only if they aren't cached
+    /// or an upgrade of the chain may change them.
+    pub async fn consensus_parameters(&self) -> io::Result<ConsensusParameters> {
+        let cached = self.cache.lock().expect("The lock is poisoned").clone();
+        if let Some(cached) = cached {
+            if cached.next_upgrade.is_none() {
+                return Ok(cached.consensus_parameters)
+            }
+        }
+
+        let chain_info = self.client.chain_info().await?;
+        let fetched_at = chain_info.latest_block.header.height;
+        let next_upgrade = self
+            .client
+            .pending_upgrades()
+            .await?
+            .into_iter()
+            .filter(|upgrade| upgrade.changes_consensus_parameters)
+            .map(|upgrade| upgrade.height)
+            .min();
+        let consensus_parameters = chain_info.consensus_parameters;
+        *self.cache.lock().expect("The lock is poisoned") = Some(CachedParameters {
+            fetched_at,
+            next_upgrade,
+            consensus_parameters: consensus_parameters.clone(),
+        });
+        Ok(consensus_parameters)
+    }
+
+    /// Returns the height of the latest block when the cached consensus parameters
+    /// were fetched.
+    pub fn consensus_parameters_height(&self) -> Option<u32> {
+        self.cache
+            .lock()
+            .expect("The lock is poisoned")
+            .as_ref()
+            .map(|cached| cached.fetched_at)
+    }
+
+    /// Builds the transaction that transfers the `amount` of the `asset_id`
+    /// from the owner of the `secret` to the `recipient`.
+    pub async fn build_transfer(
+        &self,
+        secret: SecretKey,
+        recipient: Address,
+        amount: Word,
+        asset_id: AssetId,
+    ) -> io::Result<Transaction> {
+        let script = Script {
+            code: vec![],
+            data: vec![],
+            contracts: vec![],
+            payments: vec![(recipient, asset_id, amount)],
+        };
+        self.build_script(secret, script).await
+    }
+
+    /// Builds the transaction that runs the `script` with the `script_data` against
+    /// the `contract_id`. The script receives the contract as the first input.
+    pub async fn build_contract_call(
+        &self,
+        secret: SecretKey,
+        contract_id: ContractId,
+        script: Vec<u8>,
+        script_data: Vec<u8>,
+    ) -> io::Result<Transaction> {
+        let script = Script {
+            code: script,
+            data: script_data,
+            contracts: vec![contract_id],
+            payments: vec![],
+        };
+        self.build_script(secret, script).await
+    }
+
+    async fn build_script(
+        &self,
+        secret: SecretKey,
+        script: Script,
+    ) -> io::Result<Transaction> {
+        let params = self.consensus_parameters().await?;
+        let owner = Input::owner(&secret.public_key());
+
+        // The dry run of the transaction without the fee measures the used gas.
+        let draft = self
+            .assemble(
+                &secret,
+                owner,
+                &params,
+                &script,
+                0,
+                params.tx_params.max_gas_per_tx,
+                0,
+            )
+            .await?;
+        let receipts = self.client.dry_run_opt(&draft, Some(false)).await?;
+        let gas_limit = receipts
+            .iter()
+            .find_map(|receipt| match receipt {
+                Receipt::ScriptResult { result, gas_used } => Some((*result, *gas_used)),
+                _ => None,
+            })
+            .map(|(result, gas_used)| match result {
+                ScriptExecutionResult::Success => Ok(gas_used),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("The script failed during the estimation: {result:?}"),
+                )),
+            })
+            .unwrap_or(Ok(0))?;
+
+        let gas_price = self
+            .client
+            .estimate_gas_price(self.block_horizon)
+            .await?
+            .gas_price
+            .max(self.client.node_info().await?.min_gas_price);
+
+        let mut fee = 0;
+        for _ in 0..MAX_FEE_ITERATIONS {
+            let tx = self
+                .assemble(&secret, owner, &params, &script, gas_price, gas_limit, fee)
+                .await?;
+            let required_fee = match &tx {
+                Transaction::Script(script) => {
+                    TransactionFee::checked_from_tx(params.fee_params(), script)
+                        .map(|fee| fee.max_fee())
+                }
+                _ => None,
+            }
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "The fee overflows"))?;
+            if required_fee <= fee {
+                return Ok(tx)
+            }
+            fee = required_fee;
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "Unable to select the coins to cover the fee",
+        ))
+    }
+
+    /// Attaches the coins of the `sponsor` to the transaction of another user to pay
+    /// its fee, and signs them. The unspent base asset returns to the sponsor, so the
+    /// transaction can't have its own change output of the base asset.
+    ///
+    /// The new inputs change the id of the transaction, so the user signs its inputs
+    /// after the sponsorship.
+    pub async fn sponsor(
+        &self,
+        tx: Transaction,
+        sponsor: SecretKey,
+    ) -> io::Result<Transaction> {
+        match tx {
+            Transaction::Script(script) => {
+                Ok(self.sponsor_inner(script, sponsor).await?.into())
+            }
+            Transaction::Create(create) => {
+                Ok(self.sponsor_inner(create, sponsor).await?.into())
+            }
+            Transaction::Mint(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The mint transaction can't be sponsored",
+            )),
+        }
+    }
+
+    async fn sponsor_inner<Tx>(&self, tx: Tx, sponsor: SecretKey) -> io::Result<Tx>
+    where
+        Tx: Chargeable + Inputs + Outputs + Witnesses + UniqueIdentifier + Clone,
+    {
+        let params = self.consensus_parameters().await?;
+        let base_asset_id = *params.base_asset_id();
+        let owner = Input::owner(&sponsor.public_key());
+        let has_base_change = tx.outputs().iter().any(|output| {
+            matches!(output, Output::Change { asset_id, .. } if asset_id == &base_asset_id)
+        });
+        if has_base_change {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "The sponsored transaction can't have the change of the base asset",
+            ))
+        }
+        let witness_index = u8::try_from(tx.witnesses().len()).map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "Too many witnesses")
+        })?;
+
+        let mut fee = 0;
+        for _ in 0..MAX_FEE_ITERATIONS {
+            // The transaction should have at least one input of the sponsor.
+            let spend_query = vec![(base_asset_id, fee.max(1), None)];
+            let coins = self
+                .client
+                .coins_to_spend(&owner, spend_query, None)
+                .await?;
+
+            let mut sponsored = tx.clone();
+            for coin in coins.into_iter().flatten() {
+                let input = match coin {
+                    CoinType::Coin(coin) => Input::coin_signed(
+                        coin.utxo_id,
+                        owner,
+                        coin.amount,
+                        coin.asset_id,
+                        TxPointer::default(),
+                        witness_index,
+                        coin.maturity.into(),
+                    ),
+                    CoinType::MessageCoin(message) => Input::message_coin_signed(
+                        message.sender,
+                        owner,
+                        message.amount,
+                        message.nonce,
+                        witness_index,
+                    ),
+                    CoinType::Unknown => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::Other,
+                            "Unknown type of the coin",
+                        ))
+                    }
+                };
+                sponsored.inputs_mut().push(input);
+            }
+            sponsored
+                .outputs_mut()
+                .push(Output::change(owner, 0, base_asset_id));
+            sponsored.witnesses_mut().push(Witness::default());
+
+            let required_fee =
+                TransactionFee::checked_from_tx(params.fee_params(), &sponsored)
+                    .map(|fee| fee.max_fee())
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::Other, "The fee overflows")
+                    })?;
+            if required_fee <= fee {
+                let id = sponsored.id(&params.chain_id);
+                let signature = Signature::sign(&sponsor, &Message::from_bytes(*id));
+                sponsored.witnesses_mut()[witness_index as usize] =
+                    signature.as_ref().to_vec().into();
+                return Ok(sponsored)
+            }
+            fee = required_fee;
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::Other,
+            "Unable to select the coins of the sponsor to cover the fee",
+        ))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn assemble(
+        &self,
+        secret: &SecretKey,
+        owner: Address,
+        params: &ConsensusParameters,
+        script: &Script,
+        gas_price: Word,
+        gas_limit: Word,
+        fee: Word,
+    ) -> io::Result<Transaction> {
+        let base_asset_id = *params.base_asset_id();
+        let mut spend = BTreeMap::<AssetId, Word>::new();
+        for (_, asset_id, amount) in &script.payments {
+            let total = spend.entry(*asset_id).or_default();
+            *total = total.saturating_add(*amount);
+        }
+        // The transaction should have at least one input to be valid.
+        let total = spend.entry(base_asset_id).or_default();
+        *total = total.saturating_add(fee).max(1);
+
+        let spend_query = spend
+            .iter()
+            .map(|(asset_id, amount)| (*asset_id, *amount, None))
+            .collect();
+        let coins = self
+            .client
+            .coins_to_spend(&owner, spend_query, None)
+            .await?;
+
+        let mut builder =
+            TransactionBuilder::script(script.code.clone(), script.data.clone());
+        builder
+            .with_params(params.clone())
+            .gas_price(gas_price)
+            .gas_limit(gas_limit);
+        for (index, contract_id) in script.contracts.iter().enumerate() {
+            builder
+                .add_input(Input::contract(
+                    UtxoId::default(),
+                    Bytes32::zeroed(),
+                    Bytes32::zeroed(),
+                    TxPointer::default(),
+                    *contract_id,
+                ))
+                .add_output(Output::contract(
+                    index as u8,
+                    Bytes32::zeroed(),
+                    Bytes32::zeroed(),
+                ));
+        }
+        for coin in coins.into_iter().flatten() {
+            match coin {
+                CoinType::Coin(coin) => builder.add_unsigned_coin_input(
+                    *secret,
+                    coin.utxo_id,
+                    coin.amount,
+                    coin.asset_id,
+                    TxPointer::default(),
+                    coin.maturity.into(),
+                ),
+                CoinType::MessageCoin(message) => builder.add_unsigned_message_input(
+                    *secret,
+                    message.sender,
+                    message.nonce,
+                    message.amount,
+                    vec![],
+                ),
+                CoinType::Unknown => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        "Unknown type of the coin",
+                    ))
+                }
+            };
+        }
+        for (recipient, asset_id, amount) in &script.payments {
+            builder.add_output(Output::coin(*recipient, *amount, *asset_id));
+        }
+        for asset_id in spend.keys() {
+            builder.add_output(Output::change(owner, 0, *asset_id));
+        }
+
+        Ok(builder.finalize_as_transaction())
+    }
+}
+
+/// The description of the script transaction built by the helper.
+struct Script {
+    code: Vec<u8>,
+    data: Vec<u8>,
+    contracts: Vec<ContractId>,
+    /// The coin outputs of the transaction.
+    payments: Vec<(Address, AssetId, Word)>,
+}
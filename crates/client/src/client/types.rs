@@ -1,48 +1,80 @@
+pub mod account;
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
 pub mod coins;
 pub mod contract;
 pub mod gas_costs;
+pub mod gas_price;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
 
+pub use account::AccountEvent;
+pub use asset::AssetMetadata;
 pub use balance::Balance;
 pub use block::{
     Block,
+    BlockFees,
+    BlockStateDiff,
     Consensus,
+    ContractSlotWrite,
 };
 pub use chain_info::ChainInfo;
 pub use coins::{
     Coin,
+    CoinSelectionStrategy,
     CoinType,
     MessageCoin,
+    UsableCoins,
 };
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractSnapshot,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
-pub use merkle_proof::MerkleProof;
+pub use gas_price::{
+    DaFeeSample,
+    EstimateGasPrice,
+};
+pub use merkle_proof::{
+    MerkleProof,
+    ReceiptProof,
+};
 pub use message::{
+    Deposit,
     Message,
     MessageProof,
+    MessageProvenance,
+    Withdrawal,
+};
+pub use node_info::{
+    NodeInfo,
+    PendingUpgrade,
+    SignedNodeInfo,
+    SyncStatus,
+    TableStats,
 };
-pub use node_info::NodeInfo;
 
 use crate::client::schema::{
     tx::{
+        ArchivedTransactionStatus as SchemaArchivedTxStatus,
         OpaqueTransaction,
+        TransactionDirection as SchemaTxDirection,
         TransactionStatus as SchemaTxStatus,
     },
     ConversionError,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Receipt,
+        Transaction,
+    },
     fuel_types::canonical::Deserialize,
     fuel_vm::ProgramState,
 };
@@ -81,6 +113,23 @@ pub struct TransactionResponse {
     pub status: TransactionStatus,
 }
 
+/// The transaction of the block with its receipts.
+#[derive(Debug, Clone)]
+pub struct TransactionWithReceipts {
+    pub id: primitives::TransactionId,
+    pub transaction: TransactionResponse,
+    /// The receipts of the transaction. Empty if the transaction has no receipts.
+    pub receipts: Vec<Receipt>,
+}
+
+/// The block with its transactions and their receipts.
+#[derive(Debug, Clone)]
+pub struct BlockWithReceipts {
+    pub block: Block,
+    /// The transactions in order of the block.
+    pub transactions: Vec<TransactionWithReceipts>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     Submitted {
@@ -131,6 +180,43 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
     }
 }
 
+/// The status of the transaction recorded by the status archive of the node.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ArchivedTransactionStatus {
+    /// The time when the node recorded the status.
+    pub time: Tai64,
+    pub status: TransactionStatus,
+}
+
+impl TryFrom<SchemaArchivedTxStatus> for ArchivedTransactionStatus {
+    type Error = ConversionError;
+
+    fn try_from(value: SchemaArchivedTxStatus) -> Result<Self, Self::Error> {
+        Ok(Self {
+            time: value.time.0,
+            status: value.status.try_into()?,
+        })
+    }
+}
+
+/// The direction of the funds in the transaction relative to the owner.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TransactionDirection {
+    /// The owner receives the outputs of the transaction.
+    Incoming,
+    /// The owner spends the coins or messages in the inputs of the transaction.
+    Outgoing,
+}
+
+impl From<TransactionDirection> for SchemaTxDirection {
+    fn from(value: TransactionDirection) -> Self {
+        match value {
+            TransactionDirection::Incoming => Self::Incoming,
+            TransactionDirection::Outgoing => Self::Outgoing,
+        }
+    }
+}
+
 impl TryFrom<OpaqueTransaction> for TransactionResponse {
     type Error = ConversionError;
 
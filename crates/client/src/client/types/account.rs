@@ -0,0 +1,81 @@
+use crate::client::schema::{
+    self,
+    ConversionError,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        Nonce,
+    },
+};
+
+/// The change of the account watched by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountEvent {
+    /// The account received the coin.
+    CoinReceived {
+        owner: Address,
+        utxo_id: UtxoId,
+        asset_id: AssetId,
+        amount: u64,
+        block_height: u32,
+    },
+    /// The account spent the coin in the transaction.
+    CoinSpent {
+        owner: Address,
+        utxo_id: UtxoId,
+        tx_id: TxId,
+        block_height: u32,
+    },
+    /// The message from the DA layer to the account became spendable at the block.
+    MessageReceived {
+        recipient: Address,
+        nonce: Nonce,
+        amount: u64,
+        da_height: u64,
+        block_height: u32,
+    },
+}
+
+impl TryFrom<schema::account::AccountEvent> for AccountEvent {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::account::AccountEvent) -> Result<Self, Self::Error> {
+        Ok(match value {
+            schema::account::AccountEvent::CoinReceivedEvent(event) => {
+                AccountEvent::CoinReceived {
+                    owner: event.owner.into(),
+                    utxo_id: event.utxo_id.into(),
+                    asset_id: event.asset_id.into(),
+                    amount: event.amount.into(),
+                    block_height: event.block_height.into(),
+                }
+            }
+            schema::account::AccountEvent::CoinSpentEvent(event) => {
+                AccountEvent::CoinSpent {
+                    owner: event.owner.into(),
+                    utxo_id: event.utxo_id.into(),
+                    tx_id: event.transaction_id.into(),
+                    block_height: event.block_height.into(),
+                }
+            }
+            schema::account::AccountEvent::MessageReceivedEvent(event) => {
+                AccountEvent::MessageReceived {
+                    recipient: event.recipient.into(),
+                    nonce: event.nonce.into(),
+                    amount: event.amount.into(),
+                    da_height: event.da_height.into(),
+                    block_height: event.block_height.into(),
+                }
+            }
+            schema::account::AccountEvent::Unknown => {
+                return Err(ConversionError::UnknownVariant("AccountEvent"))
+            }
+        })
+    }
+}
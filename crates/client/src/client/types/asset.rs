@@ -0,0 +1,36 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+};
+
+/// The metadata of the asset issued by the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetMetadata {
+    pub asset_id: AssetId,
+    /// The contract that issued the asset.
+    pub contract_id: ContractId,
+    /// The sub identifier of the asset in the contract.
+    pub sub_id: Bytes32,
+    /// The amount minted minus the amount burned.
+    pub total_supply: u64,
+    /// The height of the block with the first issuance of the asset.
+    pub first_issued_at: u32,
+}
+
+// GraphQL Translation
+
+impl From<schema::asset::AssetMetadata> for AssetMetadata {
+    fn from(value: schema::asset::AssetMetadata) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            contract_id: value.contract_id.into(),
+            sub_id: value.sub_id.into(),
+            total_supply: value.total_supply.into(),
+            first_issued_at: value.first_issued_at.into(),
+        }
+    }
+}
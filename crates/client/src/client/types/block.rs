@@ -2,17 +2,21 @@ use crate::client::{
     schema,
     types::primitives::{
         BlockId,
+        Bytes32,
+        ContractId,
         Hash,
         MerkleRoot,
+        Nonce,
         PublicKey,
         Signature,
         TransactionId,
+        UtxoId,
     },
     PaginatedResult,
 };
 use tai64::Tai64;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Block {
     pub id: BlockId,
     pub header: Header,
@@ -27,7 +31,7 @@ impl Block {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Header {
     pub id: BlockId,
     pub da_height: u64,
@@ -41,14 +45,15 @@ pub struct Header {
     pub application_hash: Hash,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Consensus {
     Genesis(Genesis),
     PoAConsensus(PoAConsensus),
+    PoAMultiSigConsensus(PoAMultiSigConsensus),
     Unknown,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Genesis {
     pub chain_config_hash: Hash,
     pub coins_root: MerkleRoot,
@@ -56,11 +61,47 @@ pub struct Genesis {
     pub messages_root: MerkleRoot,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(Debug, Clone)]
+pub struct PoAMultiSigConsensus {
+    /// The signatures ordered by the addresses of the signers.
+    pub signatures: Vec<Signature>,
+}
+
+/// The keys of the state changed by the block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockStateDiff {
+    pub height: u32,
+    pub coins_created: Vec<UtxoId>,
+    /// The coins created and spent by the same block are reported only as spent.
+    pub coins_spent: Vec<UtxoId>,
+    pub contract_slots: Vec<ContractSlotWrite>,
+    pub messages_consumed: Vec<Nonce>,
+}
+
+/// The gas price and the fullness of the block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFees {
+    pub height: u32,
+    /// The lowest gas price paid by the transactions of the block.
+    pub min_gas_price: u64,
+    pub gas_used: u64,
+    pub bytes_used: u64,
+}
+
+/// The write of the storage slot of the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSlotWrite {
+    pub contract_id: ContractId,
+    pub slot: Bytes32,
+    /// The new value of the slot, or `None` if the slot is cleared.
+    pub value: Option<Bytes32>,
+}
+
 // GraphQL Translation
 
 impl From<schema::block::Header> for Header {
@@ -89,6 +130,9 @@ impl From<schema::block::Consensus> for Consensus {
             schema::block::Consensus::PoAConsensus(poa) => {
                 Consensus::PoAConsensus(poa.into())
             }
+            schema::block::Consensus::PoAMultiSigConsensus(multisig) => {
+                Consensus::PoAMultiSigConsensus(multisig.into())
+            }
             schema::block::Consensus::Unknown => Consensus::Unknown,
         }
     }
@@ -114,6 +158,20 @@ impl From<schema::block::PoAConsensus> for PoAConsensus {
     }
 }
 
+impl From<schema::block::PoAMultiSigConsensus> for PoAMultiSigConsensus {
+    fn from(value: schema::block::PoAMultiSigConsensus) -> Self {
+        let signatures = value
+            .signatures
+            .into_iter()
+            .map(|signature| {
+                let bytes: [u8; 64] = signature.0 .0.into();
+                Signature::from_bytes(bytes)
+            })
+            .collect();
+        Self { signatures }
+    }
+}
+
 impl From<schema::block::Block> for Block {
     fn from(value: schema::block::Block) -> Self {
         let transactions = value
@@ -133,6 +191,43 @@ impl From<schema::block::Block> for Block {
     }
 }
 
+impl From<schema::block::BlockStateDiff> for BlockStateDiff {
+    fn from(value: schema::block::BlockStateDiff) -> Self {
+        Self {
+            height: value.height.into(),
+            coins_created: value.coins_created.into_iter().map(Into::into).collect(),
+            coins_spent: value.coins_spent.into_iter().map(Into::into).collect(),
+            contract_slots: value.contract_slots.into_iter().map(Into::into).collect(),
+            messages_consumed: value
+                .messages_consumed
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<schema::block::BlockFees> for BlockFees {
+    fn from(value: schema::block::BlockFees) -> Self {
+        Self {
+            height: value.height.into(),
+            min_gas_price: value.min_gas_price.into(),
+            gas_used: value.gas_used.into(),
+            bytes_used: value.bytes_used.into(),
+        }
+    }
+}
+
+impl From<schema::block::ContractSlotWrite> for ContractSlotWrite {
+    fn from(value: schema::block::ContractSlotWrite) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            slot: value.slot.into(),
+            value: value.value.map(Into::into),
+        }
+    }
+}
+
 impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     fn from(conn: schema::block::BlockConnection) -> Self {
         PaginatedResult {
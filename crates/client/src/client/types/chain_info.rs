@@ -4,6 +4,7 @@ use crate::client::{
 };
 use fuel_core_types::fuel_tx::ConsensusParameters;
 
+#[derive(Debug, Clone)]
 pub struct ChainInfo {
     pub da_height: u64,
     pub name: String,
@@ -45,8 +45,39 @@ pub struct MessageCoin {
     pub da_height: u64,
 }
 
+/// The coins of the owner usable by the next transaction.
+#[derive(Debug)]
+pub struct UsableCoins {
+    /// The coins and the messages not spent by the transactions in the pool.
+    pub coins: Vec<CoinType>,
+    /// The coins created by the transactions in the pool.
+    pub pending_coins: Vec<Coin>,
+}
+
+/// The algorithm that selects the coins to spend.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Selects the biggest coins first.
+    LargestFirst,
+    /// Selects the smallest number of coins that add up exactly to the target amount.
+    BranchAndBound,
+    /// Selects the random coins and adds the dust to them.
+    #[default]
+    Random,
+}
+
 // GraphQL Translation
 
+impl From<CoinSelectionStrategy> for schema::coins::CoinSelectionStrategy {
+    fn from(value: CoinSelectionStrategy) -> Self {
+        match value {
+            CoinSelectionStrategy::LargestFirst => Self::LargestFirst,
+            CoinSelectionStrategy::BranchAndBound => Self::BranchAndBound,
+            CoinSelectionStrategy::Random => Self::Random,
+        }
+    }
+}
+
 impl From<schema::coins::CoinType> for CoinType {
     fn from(value: schema::coins::CoinType) -> Self {
         match value {
@@ -84,6 +115,15 @@ impl From<schema::coins::MessageCoin> for MessageCoin {
     }
 }
 
+impl From<schema::coins::UsableCoins> for UsableCoins {
+    fn from(value: schema::coins::UsableCoins) -> Self {
+        Self {
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            pending_coins: value.pending_coins.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl From<schema::coins::CoinConnection> for PaginatedResult<Coin, String> {
     fn from(conn: schema::coins::CoinConnection) -> Self {
         PaginatedResult {
@@ -3,6 +3,7 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
     },
@@ -13,6 +14,7 @@ pub struct Contract {
     pub id: ContractId,
     pub bytecode: Bytes,
     pub salt: Salt,
+    pub bytecode_root: Bytes32,
 }
 
 #[derive(Debug)]
@@ -22,6 +24,18 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+/// The state of the contract at the block height.
+#[derive(Debug)]
+pub struct ContractSnapshot {
+    pub id: ContractId,
+    pub bytecode: Bytes,
+    pub salt: Salt,
+    /// The non-empty storage slots, ordered by the key.
+    pub slots: Vec<(Bytes32, Bytes32)>,
+    /// The non-zero balances, ordered by the asset id.
+    pub balances: Vec<(AssetId, u64)>,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -30,6 +44,7 @@ impl From<schema::contract::Contract> for Contract {
             id: value.id.into(),
             bytecode: value.bytecode.into(),
             salt: value.salt.into(),
+            bytecode_root: value.bytecode_root.into(),
         }
     }
 }
@@ -44,6 +59,26 @@ impl From<schema::contract::ContractBalance> for ContractBalance {
     }
 }
 
+impl From<schema::contract::ContractSnapshot> for ContractSnapshot {
+    fn from(value: schema::contract::ContractSnapshot) -> Self {
+        Self {
+            id: value.contract.into(),
+            bytecode: value.bytecode.into(),
+            salt: value.salt.into(),
+            slots: value
+                .slots
+                .into_iter()
+                .filter_map(|slot| Some((slot.slot.into(), slot.value?.into())))
+                .collect(),
+            balances: value
+                .balances
+                .into_iter()
+                .map(|balance| (balance.asset_id.into(), balance.amount.into()))
+                .collect(),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalanceConnection>
     for PaginatedResult<ContractBalance, String>
 {
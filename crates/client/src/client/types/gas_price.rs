@@ -0,0 +1,32 @@
+use crate::client::schema;
+
+pub struct EstimateGasPrice {
+    pub gas_price: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaFeeSample {
+    pub da_height: u64,
+    pub base_fee: u64,
+    pub blob_base_fee: Option<u64>,
+}
+
+// GraphQL Translation
+
+impl From<schema::gas_price::EstimateGasPrice> for EstimateGasPrice {
+    fn from(value: schema::gas_price::EstimateGasPrice) -> Self {
+        Self {
+            gas_price: value.gas_price.into(),
+        }
+    }
+}
+
+impl From<schema::gas_price::DaFeeSample> for DaFeeSample {
+    fn from(value: schema::gas_price::DaFeeSample) -> Self {
+        Self {
+            da_height: value.da_height.into(),
+            base_fee: value.base_fee.into(),
+            blob_base_fee: value.blob_base_fee.map(Into::into),
+        }
+    }
+}
@@ -1,7 +1,17 @@
 use crate::client::{
-    schema,
-    types::primitives::MerkleRoot,
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::{
+        block::Header,
+        primitives::{
+            Bytes,
+            MerkleRoot,
+        },
+    },
 };
+use fuel_core_types::fuel_tx::Receipt;
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -11,6 +21,30 @@ pub struct MerkleProof {
     pub proof_index: u64,
 }
 
+/// Proves that the receipt was produced by the transaction included in a Fuel block.
+#[derive(Debug)]
+pub struct ReceiptProof {
+    /// Proof that the receipt is contained within the receipts root of the transaction.
+    pub receipt_proof: MerkleProof,
+    /// Proof that the transaction is contained within the transactions root
+    /// of the block header.
+    pub transaction_proof: MerkleProof,
+    /// Proof that the block header is contained within the blockchain history.
+    pub block_proof: MerkleProof,
+    /// The header of the block that contains the transaction.
+    pub block_header: Header,
+    /// The header of the block used as the root of the block proof.
+    pub commit_block_header: Header,
+    /// The proven receipt.
+    pub receipt: Receipt,
+    /// The serialized receipt, the leaf of the receipt proof.
+    pub raw_receipt: Bytes,
+    /// The number of the receipts of the transaction.
+    pub receipts_count: u64,
+    /// The serialized transaction, the leaf of the transaction proof.
+    pub raw_transaction: Bytes,
+}
+
 // GraphQL Translation
 
 impl From<schema::message::MerkleProof> for MerkleProof {
@@ -28,3 +62,21 @@ impl From<schema::message::MerkleProof> for MerkleProof {
         }
     }
 }
+
+impl TryFrom<schema::tx::ReceiptProof> for ReceiptProof {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::ReceiptProof) -> Result<Self, Self::Error> {
+        Ok(Self {
+            receipt_proof: value.receipt_proof.into(),
+            transaction_proof: value.transaction_proof.into(),
+            block_proof: value.block_proof.into(),
+            block_header: value.block_header.into(),
+            commit_block_header: value.commit_block_header.into(),
+            receipt: value.receipt.try_into()?,
+            raw_receipt: value.raw_receipt.into(),
+            receipts_count: value.receipts_count.into(),
+            raw_transaction: value.raw_transaction.into(),
+        })
+    }
+}
@@ -5,7 +5,9 @@ use crate::client::{
         primitives::{
             Address,
             Bytes,
+            Bytes32,
             Nonce,
+            TransactionId,
         },
         MerkleProof,
     },
@@ -53,9 +55,9 @@ pub enum MessageStatus {
     NotFound,
 }
 
-impl From<schema::message::MessageStatus> for MessageStatus {
-    fn from(value: schema::message::MessageStatus) -> Self {
-        match value.state {
+impl From<schema::message::MessageState> for MessageStatus {
+    fn from(value: schema::message::MessageState) -> Self {
+        match value {
             schema::message::MessageState::Unspent => Self::Unspent,
             schema::message::MessageState::Spent => Self::Spent,
             schema::message::MessageState::NotFound => Self::NotFound,
@@ -63,6 +65,62 @@ impl From<schema::message::MessageStatus> for MessageStatus {
     }
 }
 
+impl From<schema::message::MessageStatus> for MessageStatus {
+    fn from(value: schema::message::MessageStatus) -> Self {
+        value.state.into()
+    }
+}
+
+/// The status of the message with the provenance of its relay and spending.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageProvenance {
+    pub status: MessageStatus,
+    /// The hash of the DA transaction that sent the message.
+    pub da_transaction_hash: Option<Bytes32>,
+    /// The DA height at which the relayer received the message.
+    pub da_height: Option<u64>,
+    /// The transaction that spent the message.
+    pub spending_transaction_id: Option<TransactionId>,
+    /// The height of the block with the transaction that spent the message.
+    pub spending_block_height: Option<u32>,
+}
+
+/// The lifecycle of the deposit from the DA layer.
+#[derive(Debug)]
+pub struct Deposit {
+    pub nonce: Nonce,
+    pub status: MessageStatus,
+    /// The hash of the DA transaction that sent the message.
+    pub da_transaction_hash: Option<Bytes32>,
+    /// The DA height at which the relayer received the message.
+    pub da_height: Option<u64>,
+    /// The message stored by the relayer. It is removed after it is spent.
+    pub message: Option<Message>,
+    /// The transaction that spent the message.
+    pub spending_transaction_id: Option<TransactionId>,
+    /// The height of the block with the transaction that spent the message.
+    pub spending_block_height: Option<u32>,
+}
+
+/// The state of the commitment of the block with the withdrawal on the DA layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WithdrawalState {
+    NotCommitted,
+    Committed,
+    Finalized,
+}
+
+/// The lifecycle of the withdrawal to the DA layer.
+#[derive(Debug)]
+pub struct Withdrawal {
+    pub nonce: Nonce,
+    pub state: WithdrawalState,
+    /// The height of the committed block used as the root of the proof.
+    pub commit_block_height: u32,
+    /// The proof of the message. It is available after the block is committed.
+    pub proof: Option<MessageProof>,
+}
+
 // GraphQL Translation
 
 impl From<schema::message::Message> for Message {
@@ -78,6 +136,53 @@ impl From<schema::message::Message> for Message {
     }
 }
 
+impl From<schema::message::Deposit> for Deposit {
+    fn from(value: schema::message::Deposit) -> Self {
+        Self {
+            nonce: value.nonce.into(),
+            status: value.state.into(),
+            da_transaction_hash: value.da_transaction_hash.map(Into::into),
+            da_height: value.da_height.map(Into::into),
+            message: value.message.map(Into::into),
+            spending_transaction_id: value.spending_transaction_id.map(Into::into),
+            spending_block_height: value.spending_block_height.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::message::MessageStatus> for MessageProvenance {
+    fn from(value: schema::message::MessageStatus) -> Self {
+        Self {
+            status: value.state.into(),
+            da_transaction_hash: value.da_transaction_hash.map(Into::into),
+            da_height: value.da_height.map(Into::into),
+            spending_transaction_id: value.spending_transaction_id.map(Into::into),
+            spending_block_height: value.spending_block_height.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::message::WithdrawalState> for WithdrawalState {
+    fn from(value: schema::message::WithdrawalState) -> Self {
+        match value {
+            schema::message::WithdrawalState::NotCommitted => Self::NotCommitted,
+            schema::message::WithdrawalState::Committed => Self::Committed,
+            schema::message::WithdrawalState::Finalized => Self::Finalized,
+        }
+    }
+}
+
+impl From<schema::message::Withdrawal> for Withdrawal {
+    fn from(value: schema::message::Withdrawal) -> Self {
+        Self {
+            nonce: value.nonce.into(),
+            state: value.state.into(),
+            commit_block_height: value.commit_block_height.into(),
+            proof: value.proof.map(Into::into),
+        }
+    }
+}
+
 impl From<schema::message::MessageConnection> for PaginatedResult<Message, String> {
     fn from(conn: schema::message::MessageConnection) -> Self {
         PaginatedResult {
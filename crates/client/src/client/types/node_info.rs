@@ -1,4 +1,19 @@
-use crate::client::schema;
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::primitives::{
+        Bytes32,
+        PublicKey,
+        Signature,
+    },
+};
+use fuel_core_types::services::{
+    graphql_api::NodeAttestation,
+    relayer::RelayerErrorKind,
+};
+use std::time::Duration;
 
 pub struct NodeInfo {
     pub utxo_validation: bool,
@@ -23,3 +38,135 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
         }
     }
 }
+
+/// The approximate storage statistics of the database table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStats {
+    pub table: String,
+    /// The size of the table on the disk in bytes.
+    pub size: u64,
+    pub keys: u64,
+    /// The number of bytes written into the table per second since the previous refresh.
+    pub write_rate: u64,
+}
+
+impl From<schema::node_info::TableStats> for TableStats {
+    fn from(value: schema::node_info::TableStats) -> Self {
+        Self {
+            table: value.table,
+            size: value.size.into(),
+            keys: value.keys.into(),
+            write_rate: value.write_rate.into(),
+        }
+    }
+}
+
+/// The upgrade of the chain scheduled at the future height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingUpgrade {
+    pub height: u32,
+    pub required_version: String,
+    pub changes_consensus_parameters: bool,
+    pub state_transition_bytecode_root: Option<Bytes32>,
+    /// Whether the node supports the upgrade.
+    pub supported: bool,
+}
+
+impl From<schema::node_info::PendingUpgrade> for PendingUpgrade {
+    fn from(value: schema::node_info::PendingUpgrade) -> Self {
+        Self {
+            height: value.height.into(),
+            required_version: value.required_version,
+            changes_consensus_parameters: value.changes_consensus_parameters,
+            state_transition_bytecode_root: value
+                .state_transition_bytecode_root
+                .map(Into::into),
+            supported: value.supported,
+        }
+    }
+}
+
+impl From<schema::node_info::DaErrorKind> for RelayerErrorKind {
+    fn from(value: schema::node_info::DaErrorKind) -> Self {
+        match value {
+            schema::node_info::DaErrorKind::Rpc => Self::Rpc,
+            schema::node_info::DaErrorKind::Decode => Self::Decode,
+            schema::node_info::DaErrorKind::Storage => Self::Storage,
+            schema::node_info::DaErrorKind::Transient => Self::Transient,
+            schema::node_info::DaErrorKind::Shutdown => Self::Shutdown,
+        }
+    }
+}
+
+/// The synchronization status of the node with the network and the DA layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncStatus {
+    /// Whether the network is ahead of the node.
+    pub is_syncing: bool,
+    pub current_height: u32,
+    /// The highest height observed from the network, if the node syncs with it.
+    pub network_height: Option<u32>,
+    /// The estimated time to import the blocks up to the `network_height`.
+    pub time_to_tip: Option<Duration>,
+    /// The number of the finalized DA blocks that the relayer hasn't synced yet.
+    pub da_lag: Option<u64>,
+    /// The kind of the error that failed the latest iteration of the relayer.
+    pub da_error: Option<RelayerErrorKind>,
+    /// Whether the balance of the wallet of the block committer is below the threshold.
+    pub committer_balance_low: Option<bool>,
+}
+
+impl From<schema::node_info::SyncStatus> for SyncStatus {
+    fn from(value: schema::node_info::SyncStatus) -> Self {
+        Self {
+            is_syncing: value.is_syncing,
+            current_height: value.current_height.into(),
+            network_height: value.network_height.map(Into::into),
+            time_to_tip: value
+                .time_to_tip
+                .map(|secs| Duration::from_secs(secs.into())),
+            da_lag: value.da_lag.map(Into::into),
+            da_error: value.da_error.map(Into::into),
+            committer_balance_low: value.committer_balance_low,
+        }
+    }
+}
+
+/// The information about the node signed by its P2P identity key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedNodeInfo {
+    /// The signed information about the node.
+    pub attestation: NodeAttestation,
+    /// The public key of the P2P identity of the node.
+    pub public_key: PublicKey,
+    pub signature: Signature,
+}
+
+impl SignedNodeInfo {
+    /// Returns `true` if the information is signed by the `public_key`.
+    pub fn verify(&self) -> bool {
+        self.attestation.verify(&self.signature, &self.public_key)
+    }
+}
+
+impl TryFrom<schema::node_info::SignedNodeInfo> for SignedNodeInfo {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::node_info::SignedNodeInfo) -> Result<Self, Self::Error> {
+        let public_key = PublicKey::try_from(&value.public_key.0 .0[..])
+            .map_err(|_| ConversionError::BytesLength)?;
+        let signature: [u8; 64] = value.signature.0 .0.into();
+        Ok(Self {
+            attestation: NodeAttestation {
+                node_version: value.node_version,
+                chain_id: value.chain_id.0.into(),
+                block_height: value.block_height.into(),
+                block_id: Bytes32::from(value.block_id).into(),
+                timestamp: value.timestamp.0,
+                challenge: value.challenge.map(Into::into),
+            },
+            public_key,
+            signature: Signature::from_bytes(signature),
+        })
+    }
+}
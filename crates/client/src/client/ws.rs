@@ -0,0 +1,390 @@
+//! Subscriptions over WebSocket with the
+//! [graphql-ws](https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md) protocol.
+
+use super::{
+    schema::{
+        block::NewBlocksSubscription,
+        tx::StatusChangeSubscription,
+    },
+    types::{
+        Block,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use cynic::{
+    GraphQlResponse,
+    StreamingOperation,
+};
+use futures::{
+    stream::BoxStream,
+    SinkExt,
+    StreamExt,
+};
+use serde_json::json;
+use std::{
+    collections::VecDeque,
+    io,
+};
+use tokio_tungstenite::tungstenite::{
+    client::IntoClientRequest,
+    http::HeaderValue,
+    Message,
+};
+
+/// The protocol supported by the `/graphql-ws` endpoint of the node.
+const PROTOCOL: &str = "graphql-transport-ws";
+/// The id of the subscription. Each connection serves one subscription.
+const SUBSCRIPTION_ID: &str = "1";
+
+/// The event of the subscription over WebSocket.
+#[derive(Debug)]
+pub(crate) enum WsEvent<R> {
+    /// The next item of the subscription or the GraphQL error.
+    Next(io::Result<R>),
+    /// The node completed the subscription, there will be no more items.
+    Complete,
+    /// The connection to the node is lost. The subscription can be restarted.
+    Disconnected,
+}
+
+/// Returns the WebSocket URL of the node behind the GraphQL `url`.
+pub(crate) fn ws_url(url: &reqwest::Url) -> reqwest::Url {
+    let mut url = url.clone();
+    let scheme = if url.scheme() == "https" { "wss" } else { "ws" };
+    // The scheme of the `http` and `https` URLs can always be changed to `ws` or `wss`.
+    let _ = url.set_scheme(scheme);
+    url.set_path("/graphql-ws");
+    url
+}
+
+/// Serializes the operation to reuse it on every reconnection.
+pub(crate) fn payload<ResponseData, Vars>(
+    operation: &StreamingOperation<ResponseData, Vars>,
+) -> io::Result<serde_json::Value>
+where
+    Vars: serde::Serialize,
+{
+    Ok(serde_json::to_value(operation)?)
+}
+
+fn other_error(error: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, error.to_string())
+}
+
+/// Connects to the node and starts the subscription with the `payload`.
+///
+/// The returned stream ends after [`WsEvent::Complete`] or [`WsEvent::Disconnected`].
+pub(crate) async fn subscribe<ResponseData>(
+    url: &reqwest::Url,
+    payload: serde_json::Value,
+) -> io::Result<BoxStream<'static, WsEvent<ResponseData>>>
+where
+    ResponseData: serde::de::DeserializeOwned + Send + 'static,
+{
+    let mut request = url.as_str().into_client_request().map_err(other_error)?;
+    request
+        .headers_mut()
+        .insert("Sec-WebSocket-Protocol", HeaderValue::from_static(PROTOCOL));
+    let (mut socket, _) = tokio_tungstenite::connect_async(request)
+        .await
+        .map_err(other_error)?;
+
+    socket
+        .send(Message::Text(
+            json!({ "type": "connection_init" }).to_string(),
+        ))
+        .await
+        .map_err(other_error)?;
+    loop {
+        let message = socket
+            .next()
+            .await
+            .ok_or_else(|| other_error("The connection is closed before ack"))?
+            .map_err(other_error)?;
+        if let Message::Text(text) = message {
+            let message: ServerMessage = serde_json::from_str(&text)?;
+            if message.r#type == "connection_ack" {
+                break
+            }
+        }
+    }
+
+    socket
+        .send(Message::Text(
+            json!({ "id": SUBSCRIPTION_ID, "type": "subscribe", "payload": payload })
+                .to_string(),
+        ))
+        .await
+        .map_err(other_error)?;
+
+    let stream = futures::stream::unfold(Some(socket), |socket| async move {
+        let mut socket = socket?;
+        loop {
+            let Some(Ok(message)) = socket.next().await else {
+                return Some((WsEvent::Disconnected, None))
+            };
+            let text = match message {
+                Message::Text(text) => text,
+                Message::Close(_) => return Some((WsEvent::Disconnected, None)),
+                _ => continue,
+            };
+            let message: ServerMessage = match serde_json::from_str(&text) {
+                Ok(message) => message,
+                Err(e) => return Some((WsEvent::Next(Err(e.into())), Some(socket))),
+            };
+            match message.r#type.as_str() {
+                "next" => {
+                    let result = serde_json::from_value::<GraphQlResponse<ResponseData>>(
+                        message.payload,
+                    )
+                    .map_err(Into::into)
+                    .and_then(FuelClient::decode_response);
+                    return Some((WsEvent::Next(result), Some(socket)))
+                }
+                "error" => {
+                    let error =
+                        other_error(format!("Subscription error: {}", message.payload));
+                    return Some((WsEvent::Next(Err(error)), Some(socket)))
+                }
+                "complete" => return Some((WsEvent::Complete, None)),
+                "ping" => {
+                    let pong = Message::Text(json!({ "type": "pong" }).to_string());
+                    if socket.send(pong).await.is_err() {
+                        return Some((WsEvent::Disconnected, None))
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+
+    Ok(stream.boxed())
+}
+
+/// The item of the [`Subscription`].
+enum Item<R> {
+    Next(io::Result<R>),
+    /// The subscription was restarted after the connection loss,
+    /// the items sent by the node in the meantime are lost.
+    Reconnected,
+}
+
+/// The subscription that reconnects to the active endpoint of the client
+/// and restarts itself after the connection loss.
+struct Subscription<R> {
+    client: FuelClient,
+    payload: serde_json::Value,
+    events: Option<BoxStream<'static, WsEvent<R>>>,
+}
+
+impl<R> Subscription<R>
+where
+    R: serde::de::DeserializeOwned + Send + 'static,
+{
+    async fn new(client: FuelClient, payload: serde_json::Value) -> io::Result<Self> {
+        let mut subscription = Self {
+            client,
+            payload,
+            events: None,
+        };
+        subscription.connect().await?;
+        Ok(subscription)
+    }
+
+    /// Connects to the active endpoint according to the retry config of the client.
+    async fn connect(&mut self) -> io::Result<()> {
+        let mut attempt = 0;
+        loop {
+            let (index, url) = self.client.endpoints.active();
+            match subscribe(&ws_url(url), self.payload.clone()).await {
+                Ok(events) => {
+                    self.client.endpoints.report_success(index);
+                    self.events = Some(events);
+                    return Ok(())
+                }
+                Err(e) => {
+                    self.client.endpoints.report_failure(index);
+                    attempt += 1;
+                    if attempt >= self.client.retry.max_attempts {
+                        return Err(e)
+                    }
+                    tokio::time::sleep(self.client.retry.backoff(attempt - 1)).await;
+                }
+            }
+        }
+    }
+
+    /// Returns the next item or `None` if the node completed the subscription.
+    async fn next(&mut self) -> Option<Item<R>> {
+        let Some(events) = self.events.as_mut() else {
+            return match self.connect().await {
+                Ok(()) => Some(Item::Reconnected),
+                Err(e) => Some(Item::Next(Err(e))),
+            }
+        };
+        match events.next().await {
+            Some(WsEvent::Next(result)) => Some(Item::Next(result)),
+            Some(WsEvent::Complete) => None,
+            Some(WsEvent::Disconnected) | None => {
+                tracing::debug!("The subscription is disconnected, reconnecting");
+                self.events = None;
+                match self.connect().await {
+                    Ok(()) => Some(Item::Reconnected),
+                    Err(e) => Some(Item::Next(Err(e))),
+                }
+            }
+        }
+    }
+}
+
+/// The subscription to the blocks after the latest block at the moment of
+/// subscription. It backfills the blocks missed because of the connection loss
+/// or the slow polling.
+pub(crate) struct BlocksSubscription {
+    subscription: Subscription<NewBlocksSubscription>,
+    last_height: u32,
+    pending: VecDeque<Block>,
+}
+
+impl BlocksSubscription {
+    pub async fn new(client: FuelClient) -> io::Result<Self> {
+        use cynic::SubscriptionBuilder;
+        let payload = payload(&NewBlocksSubscription::build(()))?;
+        let subscription = Subscription::new(client, payload).await?;
+        let last_height = subscription
+            .client
+            .chain_info()
+            .await?
+            .latest_block
+            .header
+            .height;
+        Ok(Self {
+            subscription,
+            last_height,
+            pending: VecDeque::new(),
+        })
+    }
+
+    pub fn into_stream(self) -> impl futures::Stream<Item = io::Result<Block>> {
+        futures::stream::unfold(self, |mut subscription| async move {
+            let block = subscription.next().await?;
+            Some((block, subscription))
+        })
+    }
+
+    async fn next(&mut self) -> Option<io::Result<Block>> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Some(Ok(block))
+            }
+            match self.subscription.next().await? {
+                Item::Next(Ok(response)) => {
+                    let block: Block = response.new_blocks.into();
+                    let height = block.header.height;
+                    if height <= self.last_height {
+                        continue
+                    }
+                    if let Err(e) = self.backfill(height).await {
+                        return Some(Err(e))
+                    }
+                    self.last_height = height;
+                    self.pending.push_back(block);
+                }
+                Item::Next(Err(e)) => return Some(Err(e)),
+                Item::Reconnected => {
+                    let latest_height = match self.subscription.client.chain_info().await
+                    {
+                        Ok(info) => info.latest_block.header.height,
+                        Err(e) => return Some(Err(e)),
+                    };
+                    if let Err(e) = self.backfill(latest_height.saturating_add(1)).await {
+                        return Some(Err(e))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Requests the blocks after the last received block and below the `height`.
+    async fn backfill(&mut self, height: u32) -> io::Result<()> {
+        for missed in self.last_height.saturating_add(1)..height {
+            let block = self
+                .subscription
+                .client
+                .block_by_height(missed as u64)
+                .await?
+                .ok_or_else(|| other_error(format!("The block {missed} is not found")))?;
+            self.pending.push_back(block);
+            self.last_height = missed;
+        }
+        Ok(())
+    }
+}
+
+/// The subscription to the status of the transaction. After the reconnection,
+/// the node sends the current status, so no status is missed.
+pub(crate) struct TransactionStatusSubscription {
+    subscription: Subscription<StatusChangeSubscription>,
+}
+
+impl TransactionStatusSubscription {
+    pub async fn new(
+        client: FuelClient,
+        operation: &StreamingOperation<
+            StatusChangeSubscription,
+            super::schema::tx::TxIdArgs,
+        >,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            subscription: Subscription::new(client, payload(operation)?).await?,
+        })
+    }
+
+    pub fn into_stream(
+        self,
+    ) -> impl futures::Stream<Item = io::Result<TransactionStatus>> {
+        futures::stream::unfold(self, |mut subscription| async move {
+            loop {
+                match subscription.subscription.next().await? {
+                    Item::Next(result) => {
+                        let status = result
+                            .and_then(|r| r.status_change.try_into().map_err(Into::into));
+                        return Some((status, subscription))
+                    }
+                    Item::Reconnected => continue,
+                }
+            }
+        })
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct ServerMessage {
+    r#type: String,
+    #[serde(default)]
+    payload: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    #[test]
+    fn ws_url__uses_ws_scheme_for_http() {
+        let url = "http://127.0.0.1:4000/graphql".parse().unwrap();
+
+        assert_eq!(ws_url(&url).as_str(), "ws://127.0.0.1:4000/graphql-ws");
+    }
+
+    #[test]
+    fn ws_url__uses_wss_scheme_for_https() {
+        let url = "https://beta-4.fuel.network/graphql".parse().unwrap();
+
+        assert_eq!(
+            ws_url(&url).as_str(),
+            "wss://beta-4.fuel.network/graphql-ws"
+        );
+    }
+}
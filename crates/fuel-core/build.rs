@@ -0,0 +1,12 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // The schema of the gRPC API is compiled only if the API is enabled.
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/fuel.proto");
+
+        // The bundled `protoc` is used, so the build doesn't require it in the system.
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+        tonic_build::compile_protos("proto/fuel.proto")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! The admin API is the HTTP server for the operators of the node. It is bound to its own
+//! address, separate from the public GraphQL API, and requires the bearer token for every
+//! request.
+
+use fuel_core_types::secrecy::Secret;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+};
+
+pub mod ports;
+pub mod service;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The address of the admin API server.
+    pub addr: SocketAddr,
+    /// The token expected in the `Authorization: Bearer <token>` header.
+    pub token: Secret<String>,
+    /// The directory where the `/snapshot` endpoint writes the snapshots.
+    pub snapshot_dir: PathBuf,
+}
+
+/// The connected peer listed by the `/peers` endpoint.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PeerEntry {
+    pub peer_id: String,
+    pub addresses: Vec<String>,
+    pub client_version: Option<String>,
+    pub reputation: f64,
+    /// The latest block height reported by the heartbeat of the peer.
+    pub block_height: Option<u32>,
+    /// The number of seconds since the first connection with the peer.
+    pub connected_secs: u64,
+    pub inbound_bytes: u64,
+    pub outbound_bytes: u64,
+    pub protocols: Vec<String>,
+}
@@ -0,0 +1,22 @@
+/// Reads and replaces the log filter of the running node.
+pub trait LogFilterPort: Send + Sync + core::fmt::Debug {
+    /// Returns the current log filter in the `RUST_LOG` syntax.
+    fn current(&self) -> String;
+
+    /// Replaces the log filter. Returns an error if the `filter` is invalid.
+    fn set(&self, filter: &str) -> anyhow::Result<()>;
+}
+
+/// Reloads the config file of the running node.
+pub trait ConfigReloadPort: Send + Sync {
+    /// Reads the config file and applies the changed settings.
+    fn reload(&self) -> anyhow::Result<ConfigReloadReport>;
+}
+
+/// The keys of the config file grouped by the outcome of the reload.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConfigReloadReport {
+    pub applied: Vec<String>,
+    pub requires_restart: Vec<String>,
+    pub unknown: Vec<String>,
+}
@@ -0,0 +1,754 @@
+use crate::{
+    admin_api::{
+        ports::{
+            ConfigReloadPort,
+            LogFilterPort,
+        },
+        Config,
+    },
+    chain_config::{
+        ChainConfig,
+        ConsensusConfig,
+        SignedUpgradeDeclaration,
+        StateConfig,
+    },
+    database::Column,
+    service::{
+        upgrades,
+        SharedState as NodeState,
+    },
+};
+use anyhow::Context;
+use axum::{
+    extract::{
+        Extension,
+        Path,
+        Query,
+    },
+    http::{
+        header::{
+            AUTHORIZATION,
+            CONTENT_TYPE,
+        },
+        Request,
+        StatusCode,
+    },
+    middleware::{
+        self,
+        Next,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+    routing::{
+        get,
+        post,
+    },
+    Json,
+    Router,
+};
+use fuel_core_services::{
+    Resource,
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+        SealedBlock,
+    },
+    fuel_crypto::Signature,
+    fuel_tx::Input,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+};
+use parking_lot::Mutex;
+use serde_json::json;
+use std::{
+    future::Future,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    ops::Deref,
+    path::PathBuf,
+    pin::Pin,
+    sync::Arc,
+};
+use tower_http::trace::TraceLayer;
+
+pub type Service = fuel_core_services::ServiceRunner<AdminService>;
+
+/// The number of the slowest contracts returned without the `limit`.
+const DEFAULT_SLOWEST_CONTRACTS: usize = 10;
+
+pub type LogFilter = Arc<dyn LogFilterPort>;
+pub type ConfigReload = Arc<dyn ConfigReloadPort>;
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub bound_address: SocketAddr,
+}
+
+pub struct AdminService {
+    bound_address: SocketAddr,
+}
+
+pub struct ServerParams {
+    router: Router,
+    listener: TcpListener,
+}
+
+pub struct Task {
+    // Ugly workaround because of https://github.com/hyperium/hyper/issues/2582
+    server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+}
+
+/// The parts of the node used by the endpoints.
+struct Node {
+    state: NodeState,
+    config_reload: Option<ConfigReload>,
+    snapshot_dir: PathBuf,
+    /// Serializes the check and the update of the latest block signed by the `cosign`.
+    cosign_lock: Mutex<()>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for AdminService {
+    const NAME: &'static str = "AdminAPI";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            bound_address: self.bound_address,
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let ServerParams { router, listener } = params;
+
+        let server = axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router.into_make_service())
+            .with_graceful_shutdown(async move {
+                state
+                    .while_started()
+                    .await
+                    .expect("The service is destroyed");
+            });
+
+        Ok(Task {
+            server: Box::pin(server),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
+        // error or stop signal.
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        // The `axum::Server` was already gracefully shutdown at this point.
+        Ok(())
+    }
+}
+
+/// Creates the admin API server for the running node. The endpoints that require
+/// the `log_filter` or the `config_reload` return `404` if they are not provided.
+pub fn new_service(
+    config: Config,
+    node: NodeState,
+    log_filter: Option<LogFilter>,
+    config_reload: Option<ConfigReload>,
+) -> anyhow::Result<Service> {
+    let Config {
+        addr,
+        token,
+        snapshot_dir,
+    } = config;
+    let token = Arc::new(token);
+    let node = Arc::new(Node {
+        state: node,
+        config_reload,
+        snapshot_dir,
+        cosign_lock: Mutex::new(()),
+    });
+
+    let router = Router::new()
+        .route(
+            "/relayer/pause",
+            post(|node| set_relayer_paused(node, true)),
+        )
+        .route(
+            "/relayer/resume",
+            post(|node| set_relayer_paused(node, false)),
+        )
+        .route("/peers", get(peers))
+        .route("/peers/:peer_id/ban", post(ban_peer))
+        .route("/snapshot", post(snapshot))
+        .route("/config/reload", post(reload_config))
+        .route("/importer/competing-blocks", get(competing_blocks))
+        .route("/storage/tables", get(table_stats))
+        .route(
+            "/storage/compaction",
+            get(compaction_status).post(start_compaction),
+        )
+        .route("/execution/slowest-contracts", get(slowest_contracts))
+        .route("/resources", get(resource_usage))
+        .route(
+            "/graphql/query-stats",
+            get(query_stats).delete(reset_query_stats),
+        )
+        .route("/upgrades", get(upgrades).post(declare_upgrade))
+        .route("/state-diffs/:height", get(state_diff))
+        .route("/consensus/cosign", post(cosign));
+    let router = match log_filter {
+        Some(log_filter) => router
+            .route("/log-filter", get(get_log_filter).put(set_log_filter))
+            .layer(Extension(log_filter)),
+        None => router,
+    };
+    let router = router
+        .layer(Extension(node))
+        .layer(middleware::from_fn(move |req, next| {
+            authorize(token.clone(), req, next)
+        }))
+        .layer(TraceLayer::new_for_http());
+
+    let listener = TcpListener::bind(addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding admin API to {}", bound_address);
+
+    Ok(Service::new_with_params(
+        AdminService { bound_address },
+        ServerParams { router, listener },
+    ))
+}
+
+async fn authorize<B>(
+    token: Arc<Secret<String>>,
+    req: Request<B>,
+    next: Next<B>,
+) -> Response {
+    let authorized = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map_or(false, |provided| {
+            constant_time_eq(provided.as_bytes(), token.expose_secret().as_bytes())
+        });
+    if authorized {
+        next.run(req).await
+    } else {
+        StatusCode::UNAUTHORIZED.into_response()
+    }
+}
+
+/// Compares the tokens without leaking the length of the matching prefix.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+fn error(status: StatusCode, error: impl ToString) -> Response {
+    (status, error.to_string()).into_response()
+}
+
+async fn set_relayer_paused(node: Extension<Arc<Node>>, paused: bool) -> Response {
+    #[cfg(feature = "relayer")]
+    if let Some(relayer) = &node.state.relayer {
+        relayer.set_paused(paused);
+        tracing::info!(
+            "The relayer is {}",
+            if paused { "paused" } else { "resumed" }
+        );
+        return Json(json!({ "paused": paused })).into_response()
+    }
+    let _ = (node, paused);
+    error(StatusCode::NOT_FOUND, "The relayer is disabled")
+}
+
+/// Lists the connected peers with their addresses, reputation and bandwidth.
+async fn peers(node: Extension<Arc<Node>>) -> Response {
+    #[cfg(feature = "p2p")]
+    if let Some(network) = &node.state.network {
+        return match network.get_all_peers().await {
+            Ok(peers) => {
+                let peers: Vec<_> = peers
+                    .into_iter()
+                    .map(|(peer_id, info)| peer_entry(peer_id, info))
+                    .collect();
+                Json(peers).into_response()
+            }
+            Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+        }
+    }
+    let _ = node;
+    error(StatusCode::NOT_FOUND, "The P2P network is disabled")
+}
+
+#[cfg(feature = "p2p")]
+fn peer_entry(
+    peer_id: fuel_core_p2p::PeerId,
+    info: fuel_core_p2p::PeerInfo,
+) -> crate::admin_api::PeerEntry {
+    crate::admin_api::PeerEntry {
+        peer_id: peer_id.to_string(),
+        addresses: info
+            .peer_addresses
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        client_version: info.client_version,
+        reputation: info.score,
+        block_height: info.heartbeat_data.block_height.map(Into::into),
+        connected_secs: info.connected_at.elapsed().as_secs(),
+        inbound_bytes: info.bandwidth.inbound_bytes,
+        outbound_bytes: info.bandwidth.outbound_bytes,
+        protocols: info.protocols,
+    }
+}
+
+async fn ban_peer(node: Extension<Arc<Node>>, peer_id: Path<String>) -> Response {
+    #[cfg(feature = "p2p")]
+    if let Some(network) = &node.state.network {
+        let peer_id = match peer_id.parse::<fuel_core_p2p::PeerId>() {
+            Ok(peer_id) => peer_id,
+            Err(e) => {
+                return error(
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid peer id `{}`: {e}", peer_id.0),
+                )
+            }
+        };
+        return match network.ban_peer(peer_id) {
+            Ok(()) => {
+                tracing::info!("The peer {peer_id} is banned");
+                Json(json!({ "banned": peer_id.to_string() })).into_response()
+            }
+            Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+        }
+    }
+    let _ = (node, peer_id);
+    error(StatusCode::NOT_FOUND, "The P2P network is disabled")
+}
+
+async fn snapshot(node: Extension<Arc<Node>>) -> Response {
+    let node = node.0;
+    let result = tokio::task::spawn_blocking(move || write_snapshot(&node)).await;
+    match result {
+        Ok(Ok(path)) => {
+            tracing::info!("The snapshot is written to {}", path.display());
+            Json(json!({ "path": path })).into_response()
+        }
+        Ok(Err(e)) => error(StatusCode::INTERNAL_SERVER_ERROR, format!("{e:?}")),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// Writes the chain config with the current state of the node. The blocks imported
+/// during the snapshot may be partially included into it.
+fn write_snapshot(node: &Node) -> anyhow::Result<PathBuf> {
+    let state = StateConfig::generate_state_config(node.state.database.clone())?;
+    let height = state.height.map(u32::from).unwrap_or_default();
+    let chain_config = ChainConfig {
+        initial_state: Some(state),
+        ..node.state.config.chain_conf.clone()
+    };
+
+    std::fs::create_dir_all(&node.snapshot_dir).with_context(|| {
+        format!(
+            "failed to create the snapshot directory {}",
+            node.snapshot_dir.display()
+        )
+    })?;
+    let path = node.snapshot_dir.join(format!("snapshot-{height}.json"));
+    let file = std::fs::File::create(&path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    serde_json::to_writer_pretty(file, &chain_config)
+        .context("failed to dump snapshot to JSON")?;
+    Ok(path)
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LogFilterBody {
+    filter: String,
+}
+
+async fn get_log_filter(log_filter: Extension<LogFilter>) -> Json<LogFilterBody> {
+    Json(LogFilterBody {
+        filter: log_filter.current(),
+    })
+}
+
+async fn set_log_filter(
+    log_filter: Extension<LogFilter>,
+    body: Json<LogFilterBody>,
+) -> Response {
+    match log_filter.set(&body.filter) {
+        Ok(()) => {
+            tracing::info!("The log filter is changed to `{}`", body.filter);
+            get_log_filter(log_filter).await.into_response()
+        }
+        Err(e) => error(StatusCode::BAD_REQUEST, e),
+    }
+}
+
+async fn reload_config(node: Extension<Arc<Node>>) -> Response {
+    let Some(config_reload) = &node.config_reload else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "The node is started without the config file",
+        )
+    };
+    match config_reload.reload() {
+        Ok(report) => Json(report).into_response(),
+        Err(e) => error(StatusCode::BAD_REQUEST, format!("{e:?}")),
+    }
+}
+
+/// Lists the valid blocks received at the heights of the already imported blocks.
+async fn competing_blocks(node: Extension<Arc<Node>>) -> Response {
+    let competing_blocks = match node.state.database.competing_blocks() {
+        Ok(competing_blocks) => competing_blocks,
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    };
+    let competing_blocks: Vec<_> = competing_blocks
+        .into_iter()
+        .map(|competing| {
+            json!({
+                "height": **competing.sealed_block.entity.header().height(),
+                "canonical_block_id": competing.canonical,
+                "block_id": competing.sealed_block.entity.id(),
+                "block": competing.sealed_block,
+            })
+        })
+        .collect();
+    Json(competing_blocks).into_response()
+}
+
+/// Lists the approximate storage statistics of the database tables.
+async fn table_stats(node: Extension<Arc<Node>>) -> Response {
+    let Some(storage_stats) = &node.state.storage_stats else {
+        return error(StatusCode::NOT_FOUND, "The storage statistics are disabled")
+    };
+    let tables: Vec<_> = storage_stats
+        .table_stats()
+        .into_iter()
+        .map(|stats| {
+            json!({
+                "table": stats.table,
+                "size": stats.size,
+                "keys": stats.keys,
+                "write_rate": stats.write_rate,
+            })
+        })
+        .collect();
+    Json(tables).into_response()
+}
+
+/// Returns the estimated compaction debt of the scheduled tables
+/// and the outcome of the latest compaction.
+async fn compaction_status(node: Extension<Arc<Node>>) -> Response {
+    let Some(scheduler) = &node.state.compaction_scheduler else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "The compaction scheduler is disabled",
+        )
+    };
+    let debt = match scheduler.compaction_debt(scheduler.columns()) {
+        Ok(debt) => debt,
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    };
+    let tables: Vec<_> = debt
+        .into_iter()
+        .map(|(column, debt)| {
+            json!({
+                "table": column.as_ref(),
+                "compaction_debt": debt,
+            })
+        })
+        .collect();
+    let last_compaction = scheduler.last_compaction().map(|report| {
+        json!({
+            "tables": report
+                .columns
+                .iter()
+                .map(|column| column.as_ref())
+                .collect::<Vec<_>>(),
+            "finished_at": report
+                .finished_at
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            "duration_ms": report.duration.as_millis() as u64,
+            "error": report.error,
+        })
+    });
+    Json(json!({
+        "compacting": scheduler.is_compacting(),
+        "tables": tables,
+        "last_compaction": last_compaction,
+    }))
+    .into_response()
+}
+
+#[derive(serde::Deserialize)]
+struct CompactionRequest {
+    tables: Option<Vec<String>>,
+}
+
+/// Starts the compaction of the requested tables, or of the scheduled ones without
+/// the body, in the background. The progress is reported by the `GET`.
+async fn start_compaction(
+    node: Extension<Arc<Node>>,
+    request: Option<Json<CompactionRequest>>,
+) -> Response {
+    let Some(scheduler) = &node.state.compaction_scheduler else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "The compaction scheduler is disabled",
+        )
+    };
+    let columns = match request.and_then(|request| request.0.tables) {
+        Some(tables) => match tables
+            .iter()
+            .map(|table| table.parse::<Column>())
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(columns) => columns,
+            Err(e) => return error(StatusCode::BAD_REQUEST, e),
+        },
+        None => scheduler.columns().to_vec(),
+    };
+    if scheduler.start_compaction(columns) {
+        StatusCode::ACCEPTED.into_response()
+    } else {
+        error(StatusCode::CONFLICT, "The compaction is already running")
+    }
+}
+
+/// Lists the usage and the limits of the resource budgets with the number of
+/// the rejected acquisitions.
+async fn resource_usage(node: Extension<Arc<Node>>) -> Response {
+    let resources: Vec<_> = Resource::ALL
+        .into_iter()
+        .map(|resource| {
+            let usage = node.state.resource_budget.usage(resource);
+            json!({
+                "resource": resource.to_string(),
+                "used": usage.used,
+                "limit": usage.limit,
+                "rejected": usage.rejected,
+            })
+        })
+        .collect();
+    Json(resources).into_response()
+}
+
+#[derive(serde::Deserialize)]
+struct SlowestContractsParams {
+    limit: Option<usize>,
+}
+
+/// Lists the contracts with the highest total execution time of their transactions
+/// in the latest blocks, the slowest first.
+async fn slowest_contracts(
+    node: Extension<Arc<Node>>,
+    params: Query<SlowestContractsParams>,
+) -> Response {
+    let limit = params.limit.unwrap_or(DEFAULT_SLOWEST_CONTRACTS);
+    Json(node.state.execution_stats.slowest_contracts(limit)).into_response()
+}
+
+/// Lists the execution statistics of the GraphQL operations, the slowest first.
+async fn query_stats(node: Extension<Arc<Node>>) -> Response {
+    match &node.state.graph_ql.query_stats {
+        Some(query_stats) => Json(query_stats.operations()).into_response(),
+        None => error(StatusCode::NOT_FOUND, "The query statistics are disabled"),
+    }
+}
+
+async fn reset_query_stats(node: Extension<Arc<Node>>) -> Response {
+    match &node.state.graph_ql.query_stats {
+        Some(query_stats) => {
+            query_stats.reset();
+            StatusCode::NO_CONTENT.into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "The query statistics are disabled"),
+    }
+}
+
+/// Lists the upgrades declared by the consensus authority, including the applied ones.
+async fn upgrades(node: Extension<Arc<Node>>) -> Response {
+    match node.state.database.upgrades() {
+        Ok(upgrades) => Json(upgrades).into_response(),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// Schedules the upgrade signed by the consensus authority.
+async fn declare_upgrade(
+    node: Extension<Arc<Node>>,
+    upgrade: Json<SignedUpgradeDeclaration>,
+) -> Response {
+    let height = upgrade.declaration.height;
+    match upgrades::declare_upgrade(
+        &node.state.database,
+        &node.state.config.chain_conf.consensus,
+        &node.state.config.chain_conf.consensus_parameters.chain_id,
+        upgrade.0,
+    ) {
+        Ok(()) => Json(json!({ "height": *height })).into_response(),
+        Err(e) => error(StatusCode::BAD_REQUEST, format!("{e:?}")),
+    }
+}
+
+/// Returns the postcard encoded state diff of the block at the `height`
+/// for the follower nodes.
+async fn state_diff(node: Extension<Arc<Node>>, height: Path<u32>) -> Response {
+    match node.state.database.state_diff(&height.0.into()) {
+        Ok(Some(diff)) => match postcard::to_allocvec(&diff) {
+            Ok(bytes) => {
+                ([(CONTENT_TYPE, "application/octet-stream")], bytes).into_response()
+            }
+            Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+        },
+        Ok(None) => error(
+            StatusCode::NOT_FOUND,
+            format!("The state diff at height {} is not recorded", height.0),
+        ),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// Signs the id of the postcard encoded next block by the consensus key of the node
+/// for the producer of the multi-signature PoA. The block is validated by the importer
+/// before the signing. Only one block is signed per height, and it is stored in
+/// the database before the signature is returned, so the node never cosigns
+/// the competing blocks, even after the restart.
+async fn cosign(node: Extension<Arc<Node>>, body: axum::body::Bytes) -> Response {
+    let mut block: Block = match postcard::from_bytes(&body) {
+        Ok(block) => block,
+        Err(e) => return error(StatusCode::BAD_REQUEST, format!("Invalid block: {e}")),
+    };
+    // The id of the header is signed, so don't trust the one provided by the producer.
+    block.header_mut().recalculate_metadata();
+
+    let config = &node.state.config;
+    let ConsensusConfig::PoAMultiSig { signing_keys, .. } = &config.chain_conf.consensus
+    else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "The chain doesn't use the multi-signature PoA",
+        )
+    };
+    let Some(key) = &config.consensus_key else {
+        return error(
+            StatusCode::NOT_FOUND,
+            "The node doesn't have the consensus key",
+        )
+    };
+    let key = key.expose_secret().deref();
+    if !signing_keys.contains(&Input::owner(&key.public_key())) {
+        return error(
+            StatusCode::FORBIDDEN,
+            "The consensus key of the node isn't authorized to seal the blocks",
+        )
+    }
+
+    let height = *block.header().height();
+    let next_height = match node.state.database.latest_height() {
+        Ok(latest) => u32::from(latest).checked_add(1).map(BlockHeight::from),
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    };
+    if Some(height) != next_height {
+        return error(
+            StatusCode::CONFLICT,
+            format!("The block at height {height} isn't the next block"),
+        )
+    }
+
+    let id = block.id();
+    // The importer verifies the fields of the block and executes it in the validation
+    // mode without the commit. The seal isn't verified, so it is left empty.
+    let importer = node.state.block_importer.block_importer.clone();
+    let sealed_block = SealedBlock {
+        entity: block,
+        consensus: Consensus::PoAMultiSig(Default::default()),
+    };
+    let validation = tokio::task::spawn_blocking(move || {
+        importer.verify_and_execute_block(sealed_block).map(|_| ())
+    })
+    .await;
+    match validation {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            return error(StatusCode::BAD_REQUEST, format!("Invalid block: {e}"))
+        }
+        Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+
+    {
+        let _guard = node.cosign_lock.lock();
+        let database = &node.state.database;
+        match database.get_last_cosigned() {
+            Ok(Some((last_height, last_id)))
+                if height < last_height || (height == last_height && id != last_id) =>
+            {
+                return error(
+                    StatusCode::CONFLICT,
+                    format!("Another block at height {last_height} is already cosigned"),
+                )
+            }
+            Ok(_) => {}
+            Err(e) => return error(StatusCode::INTERNAL_SERVER_ERROR, e),
+        }
+        if let Err(e) = database.set_last_cosigned(height, id) {
+            return error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        }
+    }
+
+    let signature = Signature::sign(key, &id.into_message());
+    match postcard::to_allocvec(&signature) {
+        Ok(bytes) => {
+            ([(CONTENT_TYPE, "application/octet-stream")], bytes).into_response()
+        }
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_time_eq_compares_tokens() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(!constant_time_eq(b"", b"secret"));
+    }
+}
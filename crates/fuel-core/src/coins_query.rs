@@ -55,6 +55,7 @@ pub struct SpendQuery {
     query_per_asset: Vec<AssetSpendTarget>,
     exclude: Exclude,
     base_asset_id: AssetId,
+    max_inputs: usize,
 }
 
 impl SpendQuery {
@@ -86,9 +87,16 @@ impl SpendQuery {
             query_per_asset: query_per_asset.into(),
             exclude,
             base_asset_id,
+            max_inputs: usize::MAX,
         })
     }
 
+    /// Limits the total number of inputs of all assets in the selection.
+    pub fn with_max_inputs(mut self, max_inputs: u64) -> Self {
+        self.max_inputs = max_inputs as usize;
+        self
+    }
+
     /// Return [`Asset`]s.
     pub fn assets(&self) -> &Vec<AssetSpendTarget> {
         &self.query_per_asset
@@ -121,13 +129,79 @@ impl SpendQuery {
     }
 }
 
+/// The algorithm that selects the coins to spend.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelectionStrategy {
+    /// Selects the biggest coins first. It produces the smallest number of inputs.
+    LargestFirst,
+    /// Searches for the coins that add up exactly to the target, so the transaction
+    /// doesn't need the change. Falls back to the largest first if there are no such coins.
+    BranchAndBound,
+    /// Selects the random coins and improves the selection with the dust. It doesn't
+    /// reveal the balance of the owner and consolidates the dust over time.
+    #[default]
+    Random,
+}
+
+/// Selects the coins for each asset of the `spend_query` with the `strategy`.
+///
+/// If the selection exceeds the maximum number of inputs of the query, it falls back
+/// to the [`largest_first`] that produces the smallest number of inputs.
+pub fn select_coins(
+    db: &Database,
+    spend_query: &SpendQuery,
+    strategy: CoinSelectionStrategy,
+) -> Result<Vec<Vec<CoinType>>, CoinsQueryError> {
+    let coins_per_asset: Vec<Vec<_>> = match strategy {
+        CoinSelectionStrategy::LargestFirst => spend_query
+            .asset_queries(db)
+            .iter()
+            .map(largest_first)
+            .try_collect()?,
+        CoinSelectionStrategy::BranchAndBound => spend_query
+            .asset_queries(db)
+            .iter()
+            .map(branch_and_bound)
+            .try_collect()?,
+        CoinSelectionStrategy::Random => random_improve(db, spend_query)?,
+    };
+
+    if number_of_inputs(&coins_per_asset) <= spend_query.max_inputs {
+        return Ok(coins_per_asset)
+    }
+    if strategy == CoinSelectionStrategy::LargestFirst {
+        return Err(CoinsQueryError::MaxCoinsReached)
+    }
+
+    let coins_per_asset: Vec<_> = spend_query
+        .asset_queries(db)
+        .iter()
+        .map(largest_first)
+        .try_collect()?;
+    if number_of_inputs(&coins_per_asset) > spend_query.max_inputs {
+        return Err(CoinsQueryError::MaxCoinsReached)
+    }
+    Ok(coins_per_asset)
+}
+
+fn number_of_inputs(coins_per_asset: &[Vec<CoinType>]) -> usize {
+    coins_per_asset.iter().map(Vec::len).sum()
+}
+
 /// Returns the biggest inputs of the `owner` to satisfy the required `target` of the asset. The
 /// number of inputs for each asset can't exceed `max_inputs`, otherwise throw an error that query
 /// can't be satisfied.
 pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
     let mut inputs: Vec<_> = query.coins().try_collect()?;
     inputs.sort_by_key(|coin| Reverse(coin.amount()));
+    select_largest_first(query, inputs)
+}
 
+/// Selects the `inputs` sorted from the biggest to the smallest one until they reach the target.
+fn select_largest_first(
+    query: &AssetQuery,
+    inputs: Vec<CoinType>,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
     let mut collected_amount = 0u64;
     let mut coins = vec![];
 
@@ -157,6 +231,123 @@ pub fn largest_first(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryErro
     Ok(coins)
 }
 
+/// The maximum number of the branches visited by the [`branch_and_bound`] before it gives up.
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
+/// Returns the smallest set of the inputs of the `owner` that add up exactly to the `target` of
+/// the asset, so the transaction doesn't need the change output. The search visits at most
+/// [`BRANCH_AND_BOUND_MAX_TRIES`] branches. If there is no exact match, it falls back to the
+/// [`largest_first`].
+pub fn branch_and_bound(query: &AssetQuery) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query.coins().try_collect()?;
+    inputs.sort_by_key(|coin| Reverse(coin.amount()));
+
+    let amounts = inputs.iter().map(CoinType::amount).collect_vec();
+    let mut search = ExactMatchSearch::new(&amounts, query.asset.target, query.asset.max);
+    search.run(0, 0);
+
+    match search.best {
+        Some(selected) => Ok(selected.into_iter().map(|index| inputs[index]).collect()),
+        None => select_largest_first(query, inputs),
+    }
+}
+
+/// The depth-first search of the exact match over the amounts sorted from the biggest
+/// to the smallest one.
+struct ExactMatchSearch<'a> {
+    amounts: &'a [Word],
+    /// The sum of the amounts starting from each index.
+    remaining: Vec<u128>,
+    target: u128,
+    max: usize,
+    tries: usize,
+    selected: Vec<usize>,
+    best: Option<Vec<usize>>,
+}
+
+impl<'a> ExactMatchSearch<'a> {
+    fn new(amounts: &'a [Word], target: Word, max: usize) -> Self {
+        let mut remaining = vec![0u128; amounts.len() + 1];
+        for (index, amount) in amounts.iter().enumerate().rev() {
+            remaining[index] = remaining[index + 1] + *amount as u128;
+        }
+        Self {
+            amounts,
+            remaining,
+            target: target as u128,
+            max,
+            tries: BRANCH_AND_BOUND_MAX_TRIES,
+            selected: vec![],
+            best: None,
+        }
+    }
+
+    /// Tries each of the amounts starting from the `start` as the next selected one.
+    fn run(&mut self, start: usize, collected: u128) {
+        if collected == self.target {
+            self.best = Some(self.selected.clone());
+            return
+        }
+
+        let mut index = start;
+        while index < self.amounts.len() {
+            if self.tries == 0 {
+                return
+            }
+            self.tries -= 1;
+
+            // The rest of the amounts can't reach the target.
+            if collected + self.remaining[index] < self.target {
+                return
+            }
+            // The selection can't be better than the best one.
+            let limit = self
+                .best
+                .as_ref()
+                .map_or(self.max, |best| best.len().saturating_sub(1));
+            if self.selected.len() >= limit {
+                return
+            }
+
+            let amount = self.amounts[index];
+            let next_collected = collected + amount as u128;
+            if next_collected <= self.target {
+                self.selected.push(index);
+                self.run(index + 1, next_collected);
+                self.selected.pop();
+            }
+
+            // Skipping the amount, skip the same amounts too, because they lead to the same sets.
+            index += 1;
+            while index < self.amounts.len() && self.amounts[index] == amount {
+                index += 1;
+            }
+        }
+    }
+}
+
+/// Returns the dust inputs of the `owner`, whose amounts are below the `dust_threshold`,
+/// starting from the smallest one. Spending them in one transaction consolidates the dust,
+/// so the later selections need fewer inputs. The number of inputs can't exceed the `max`
+/// of the asset, and the `target` is ignored. Returns nothing if there are fewer than two
+/// dust inputs, because there is nothing to consolidate.
+pub fn dust_coins(
+    query: &AssetQuery,
+    dust_threshold: Word,
+) -> Result<Vec<CoinType>, CoinsQueryError> {
+    let mut inputs: Vec<_> = query
+        .coins()
+        .filter_ok(|coin| coin.amount() < dust_threshold)
+        .try_collect()?;
+    inputs.sort_by_key(CoinType::amount);
+    inputs.truncate(query.asset.max);
+
+    if inputs.len() < 2 {
+        inputs.clear();
+    }
+    Ok(inputs)
+}
+
 // An implementation of the method described on: https://iohk.io/en/blog/posts/2018/07/03/self-organisation-in-coin-selection/
 pub fn random_improve(
     db: &Database,
@@ -219,6 +410,7 @@ impl From<StorageError> for CoinsQueryError {
 mod tests {
     use crate::{
         coins_query::{
+            branch_and_bound,
             largest_first,
             random_improve,
             CoinsQueryError,
@@ -799,6 +991,206 @@ mod tests {
         }
     }
 
+    mod branch_and_bound {
+        use super::*;
+
+        fn query(
+            target: Word,
+            max: u64,
+            owner: &Address,
+            asset_id: AssetId,
+            base_asset_id: &AssetId,
+            db: &ServiceDatabase,
+        ) -> Result<Vec<Word>, CoinsQueryError> {
+            let asset = AssetSpendTarget::new(asset_id, target, max);
+            let coins = branch_and_bound(&AssetQuery::new(
+                owner,
+                &asset,
+                base_asset_id,
+                None,
+                db,
+            ))?;
+            Ok(coins.iter().map(|coin| coin.amount()).collect())
+        }
+
+        #[test]
+        fn finds_the_smallest_exact_match() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let db = db.service_database();
+
+            // The coins are 5, 4, 3, 2 and 1, so any target up to the balance has an exact match
+            for target in 0..=15 {
+                let coins =
+                    query(target, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db)
+                        .unwrap();
+                assert_eq!(coins.iter().sum::<Word>(), target);
+            }
+
+            assert_eq!(
+                query(7, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db),
+                Ok(vec![5, 2])
+            );
+            assert_eq!(
+                query(12, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db),
+                Ok(vec![5, 4, 3])
+            );
+        }
+
+        #[test]
+        fn falls_back_to_largest_first_without_exact_match() {
+            let mut db = TestDatabase::new();
+            let owner = Address::default();
+            let asset_id = AssetId::from([1; 32]);
+            let base_asset_id = AssetId::from([2; 32]);
+            db.make_coin(owner, 10, asset_id);
+            db.make_coin(owner, 10, asset_id);
+            let db = db.service_database();
+
+            assert_eq!(
+                query(15, u64::MAX, &owner, asset_id, &base_asset_id, &db),
+                Ok(vec![10, 10])
+            );
+            assert_eq!(
+                query(25, u64::MAX, &owner, asset_id, &base_asset_id, &db),
+                Err(CoinsQueryError::InsufficientCoins {
+                    asset_id,
+                    collected_amount: 20,
+                })
+            );
+        }
+
+        #[test]
+        fn respects_the_max_number_of_coins() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let db = db.service_database();
+
+            // The exact match needs four coins
+            assert_eq!(
+                query(14, 2, &owner, asset_ids[0], &base_asset_id, &db),
+                Err(CoinsQueryError::MaxCoinsReached)
+            );
+            assert_eq!(
+                query(14, 4, &owner, asset_ids[0], &base_asset_id, &db),
+                Ok(vec![5, 4, 3, 2])
+            );
+        }
+    }
+
+    mod select_coins {
+        use super::*;
+        use crate::coins_query::{
+            select_coins,
+            CoinSelectionStrategy,
+        };
+
+        fn query(
+            strategy: CoinSelectionStrategy,
+            max_inputs: u64,
+            db: &TestDatabase,
+        ) -> Result<usize, CoinsQueryError> {
+            let owner = Address::default();
+            let asset_id = AssetId::from([1; 32]);
+            let base_asset_id = AssetId::from([2; 32]);
+            let spend_query = SpendQuery::new(
+                owner,
+                &[AssetSpendTarget::new(asset_id, 6, u64::MAX)],
+                None,
+                base_asset_id,
+            )?
+            .with_max_inputs(max_inputs);
+            let coins = select_coins(&db.service_database(), &spend_query, strategy)?;
+            Ok(coins[0].len())
+        }
+
+        fn setup() -> TestDatabase {
+            let mut db = TestDatabase::new();
+            let asset_id = AssetId::from([1; 32]);
+            for amount in [6, 3, 2, 1, 1, 1, 1] {
+                db.make_coin(Address::default(), amount, asset_id);
+            }
+            db
+        }
+
+        #[test]
+        fn falls_back_to_largest_first_above_max_inputs() {
+            let db = setup();
+
+            for strategy in [
+                CoinSelectionStrategy::LargestFirst,
+                CoinSelectionStrategy::BranchAndBound,
+                CoinSelectionStrategy::Random,
+            ] {
+                assert_eq!(query(strategy, 1, &db), Ok(1));
+            }
+        }
+
+        #[test]
+        fn fails_when_the_largest_coins_exceed_max_inputs() {
+            let db = setup();
+
+            for strategy in [
+                CoinSelectionStrategy::LargestFirst,
+                CoinSelectionStrategy::BranchAndBound,
+                CoinSelectionStrategy::Random,
+            ] {
+                assert_eq!(
+                    query(strategy, 0, &db),
+                    Err(CoinsQueryError::MaxCoinsReached)
+                );
+            }
+        }
+    }
+
+    mod dust_coins {
+        use super::*;
+        use crate::coins_query::dust_coins;
+
+        fn query(
+            dust_threshold: Word,
+            max: u64,
+            owner: &Address,
+            asset_id: AssetId,
+            base_asset_id: &AssetId,
+            db: &ServiceDatabase,
+        ) -> Vec<Word> {
+            let asset = AssetSpendTarget::new(asset_id, 0, max);
+            let query = AssetQuery::new(owner, &asset, base_asset_id, None, db);
+            dust_coins(&query, dust_threshold)
+                .unwrap()
+                .iter()
+                .map(|coin| coin.amount())
+                .collect()
+        }
+
+        #[test]
+        fn returns_the_smallest_coins_below_the_threshold() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let db = db.service_database();
+
+            assert_eq!(
+                query(4, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db),
+                vec![1, 2, 3]
+            );
+            assert_eq!(
+                query(4, 2, &owner, asset_ids[0], &base_asset_id, &db),
+                vec![1, 2]
+            );
+        }
+
+        #[test]
+        fn returns_nothing_without_dust_to_consolidate() {
+            let (owner, asset_ids, base_asset_id, db) = setup_coins();
+            let db = db.service_database();
+
+            assert!(
+                query(2, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db).is_empty()
+            );
+            assert!(
+                query(1, u64::MAX, &owner, asset_ids[0], &base_asset_id, &db).is_empty()
+            );
+        }
+    }
+
     #[derive(Clone, Debug)]
     struct TestCase {
         db_amount: Vec<Word>,
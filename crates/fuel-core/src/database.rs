@@ -2,6 +2,8 @@ use crate::{
     database::transaction::DatabaseTransaction,
     state::{
         in_memory::memory_store::MemoryStore,
+        recording::RecordingStorage,
+        ColumnStats,
         DataSource,
         WriteOperation,
     },
@@ -12,6 +14,10 @@ use fuel_core_chain_config::{
     ContractConfig,
     MessageConfig,
 };
+use fuel_core_services::{
+    Resource,
+    ResourceBudget,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     transactional::{
@@ -21,7 +27,10 @@ use fuel_core_storage::{
     Result as StorageResult,
 };
 use fuel_core_types::fuel_types::BlockHeight;
-use itertools::Itertools;
+use itertools::{
+    Either,
+    Itertools,
+};
 use serde::{
     de::DeserializeOwned,
     Serialize,
@@ -45,7 +54,10 @@ type DatabaseResult<T> = Result<T>;
 
 // TODO: Extract `Database` and all belongs into `fuel-core-database`.
 #[cfg(feature = "rocksdb")]
-use crate::state::rocks_db::RocksDb;
+use crate::state::{
+    rocks_db::RocksDb,
+    Durability,
+};
 #[cfg(feature = "rocksdb")]
 use std::path::Path;
 use strum::EnumCount;
@@ -54,20 +66,27 @@ use tempfile::TempDir;
 
 // Storages implementation
 // TODO: Move to separate `database/storage` folder, because it is only implementation of storages traits.
+mod asset;
 mod block;
 mod code_root;
+mod competing_blocks;
+mod contract_calls;
 mod contracts;
+mod fee_history;
 mod message;
 mod receipts;
 #[cfg(feature = "relayer")]
 mod relayer;
 mod sealed_block;
 mod state;
+mod upgrade;
 
 pub(crate) mod coin;
 
 pub mod balances;
+pub mod inspect;
 pub mod metadata;
+pub mod state_diff;
 pub mod storage;
 pub mod transaction;
 pub mod transactions;
@@ -76,8 +95,18 @@ pub mod vm_database;
 /// Database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
 #[derive(
-    Copy, Clone, Debug, strum_macros::EnumCount, PartialEq, Eq, enum_iterator::Sequence,
+    Copy,
+    Clone,
+    Debug,
+    strum_macros::EnumCount,
+    strum_macros::EnumString,
+    strum_macros::AsRefStr,
+    strum_macros::IntoStaticStr,
+    PartialEq,
+    Eq,
+    enum_iterator::Sequence,
 )]
+#[strum(ascii_case_insensitive)]
 pub enum Column {
     /// The column id of metadata about the blockchain
     Metadata = 0,
@@ -132,6 +161,56 @@ pub enum Column {
     ContractsStateMerkleData = 23,
     /// See [`ContractsStateMerkleMetadata`](storage::ContractsStateMerkleMetadata)
     ContractsStateMerkleMetadata = 24,
+    /// See [`MessageDeposits`](fuel_core_storage::tables::MessageDeposits)
+    MessageDeposits = 25,
+    /// The column of the table that stores `true` if the da transaction sent `Message` with `nonce`
+    DaTransactionMessageIds = 26,
+    /// The id of the transaction that spent the `Message`
+    MessageSpendingTransactions = 27,
+    /// The history of the statuses of the transaction
+    TransactionStatusArchive = 28,
+    /// The column of the table that stores `true` for the `time + tx_id` key,
+    /// where `time` is the first archived status of the transaction.
+    /// It is used to prune the expired history.
+    TransactionStatusArchiveExpiry = 29,
+    /// The metadata of the assets issued by the contracts, recorded by the asset registry
+    AssetMetadata = 30,
+    /// The column of the table that stores the total amount of the `owner`'s coins
+    /// per `asset_id`
+    OwnedCoinsBalances = 31,
+    /// The column of the table that stores the total amount of the `owner`'s messages
+    /// spendable as the base asset
+    OwnedMessagesBalances = 32,
+    /// The upgrades of the chain declared by the consensus authority, by the height
+    Upgrades = 33,
+    /// The changes of the state made by the recent blocks, by the height
+    StateDiffs = 34,
+    /// The finalized heights of the relayers of the additional DA networks
+    /// See [`OriginRelayerMetadata`](fuel_core_relayer::ports::OriginRelayerMetadata)
+    OriginRelayerMetadata = 35,
+    /// See [`OriginMessages`](fuel_core_relayer::ports::OriginMessages)
+    OriginMessages = 36,
+    /// The fee samples of the latest finalized DA blocks
+    /// See [`DaFeeSamples`](fuel_core_relayer::ports::DaFeeSamples)
+    DaFeeSamples = 37,
+    /// The bytecode of the contracts by its hash, shared by the contracts with
+    /// the same bytecode
+    ContractsBytecode = 38,
+    /// The number of the contracts referencing the bytecode, by its hash
+    ContractsBytecodeRefs = 39,
+    /// The hash of the bytecode of the contract, by the contract id.
+    /// The contracts missing here store the bytecode in the `ContractsRawCode`.
+    ContractsBytecodeHash = 40,
+    /// The number of the calls of the contract in the block by the height and
+    /// the contract id, recorded by the contract call analytics
+    ContractCalls = 41,
+    /// See [`MessageCancellations`](fuel_core_storage::tables::MessageCancellations)
+    MessageCancellations = 42,
+    /// The gas price and the fullness of the block by its height
+    FeeHistory = 43,
+    /// The valid blocks received at the heights of the already imported blocks,
+    /// by the height and the block id
+    CompetingBlocks = 44,
 }
 
 impl Column {
@@ -142,11 +221,18 @@ impl Column {
     pub fn as_usize(&self) -> usize {
         *self as usize
     }
+
+    /// Returns the name of the `Column`. It is used as the label of the metrics.
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct Database {
     data: DataSource,
+    /// Limits the open iterators of this view of the database, if any.
+    resource_budget: Option<ResourceBudget>,
     // used for RAII
     _drop: Arc<DropResources>,
 }
@@ -187,17 +273,23 @@ impl Database {
     pub fn new(data_source: DataSource) -> Self {
         Self {
             data: data_source,
+            resource_budget: None,
             _drop: Default::default(),
         }
     }
 
     #[cfg(feature = "rocksdb")]
-    pub fn open(path: &Path, capacity: impl Into<Option<usize>>) -> DatabaseResult<Self> {
+    pub fn open(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        durability: Durability,
+    ) -> DatabaseResult<Self> {
         use anyhow::Context;
-        let db = RocksDb::default_open(path, capacity.into()).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?;
+        let db = RocksDb::default_open(path, capacity.into()).context("Failed to open rocksdb, you may need to wipe a pre-existing incompatible db `rm -rf ~/.fuel/db`")?.with_durability(durability);
 
         Ok(Database {
             data: Arc::new(db),
+            resource_budget: None,
             _drop: Default::default(),
         })
     }
@@ -205,6 +297,7 @@ impl Database {
     pub fn in_memory() -> Self {
         Self {
             data: Arc::new(MemoryStore::default()),
+            resource_budget: None,
             _drop: Default::default(),
         }
     }
@@ -215,6 +308,7 @@ impl Database {
         let db = RocksDb::default_open(tmp_dir.path(), None).unwrap();
         Self {
             data: Arc::new(db),
+            resource_budget: None,
             _drop: Arc::new(
                 {
                     move || {
@@ -230,6 +324,34 @@ impl Database {
     pub fn transaction(&self) -> DatabaseTransaction {
         self.into()
     }
+
+    /// Returns the view of the database that takes the open iterators from
+    /// the `budget`. The iteration fails when the budget is exhausted.
+    pub fn with_resource_budget(mut self, budget: ResourceBudget) -> Self {
+        self.resource_budget = Some(budget);
+        self
+    }
+
+    /// Returns the view of the database that records the values read through it.
+    pub fn recording(&self) -> (Self, Arc<RecordingStorage>) {
+        let recording = Arc::new(RecordingStorage::new(self.data.clone()));
+        (Self::new(recording.clone()), recording)
+    }
+
+    /// Returns the approximate statistics of the `column`.
+    pub fn column_stats(&self, column: Column) -> DatabaseResult<ColumnStats> {
+        self.data.column_stats(column)
+    }
+
+    /// Compacts the `column` and blocks until the compaction is finished.
+    pub fn compact_column(&self, column: Column) -> DatabaseResult<()> {
+        self.data.compact_column(column)
+    }
+
+    /// Returns the estimated number of bytes pending the compaction in the `column`.
+    pub fn compaction_debt(&self, column: Column) -> DatabaseResult<u64> {
+        self.data.compaction_debt(column)
+    }
 }
 
 /// Mutable methods.
@@ -402,21 +524,36 @@ impl Database {
         P: AsRef<[u8]>,
         S: AsRef<[u8]>,
     {
-        self.data
+        // The permit is held until the iterator is dropped.
+        let permit = match &self.resource_budget {
+            Some(budget) => match budget.try_acquire(Resource::Iterators, 1) {
+                Ok(permit) => Some(permit),
+                Err(e) => {
+                    return Either::Left(core::iter::once(Err(DatabaseError::Other(
+                        e.into(),
+                    ))))
+                }
+            },
+            None => None,
+        };
+        let iter = self
+            .data
             .iter_all(
                 column,
                 prefix.as_ref().map(|p| p.as_ref()),
                 start.as_ref().map(|s| s.as_ref()),
                 direction.unwrap_or_default(),
             )
-            .map(|val| {
+            .map(move |val| {
+                let _permit = &permit;
                 val.and_then(|(key, value)| {
                     let key = K::from(key);
                     let value: V =
                         postcard::from_bytes(&value).map_err(|_| DatabaseError::Codec)?;
                     Ok((key, value))
                 })
-            })
+            });
+        Either::Right(iter)
     }
 }
 
@@ -487,3 +624,19 @@ fn column_keys_not_exceed_count() {
         assert!(column.as_usize() < Column::COUNT);
     }
 }
+
+#[test]
+fn iterators_are_rejected_when_the_budget_is_exhausted() {
+    let budget = ResourceBudget::new(fuel_core_services::ResourceLimits {
+        iterators: Some(1),
+        ..Default::default()
+    });
+    let database = Database::default().with_resource_budget(budget.clone());
+
+    let first = database.iter_all::<Vec<u8>, Vec<u8>>(Column::Metadata, None);
+    let mut second = database.iter_all::<Vec<u8>, Vec<u8>>(Column::Metadata, None);
+
+    assert!(matches!(second.next(), Some(Err(_))));
+    drop(first);
+    assert_eq!(budget.usage(Resource::Iterators).used, 0);
+}
@@ -0,0 +1,42 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_types::{
+    entities::asset::AssetMetadata,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+    },
+};
+
+/// The height of the last block indexed by the asset registry.
+pub(crate) const ASSET_REGISTRY_HEIGHT_KEY: &[u8] = b"asset_registry_height";
+
+impl Database {
+    /// Returns the metadata of the asset recorded by the asset registry.
+    pub fn asset_metadata(
+        &self,
+        asset_id: &AssetId,
+    ) -> DatabaseResult<Option<AssetMetadata>> {
+        self.get(asset_id.as_ref(), Column::AssetMetadata)
+    }
+
+    pub fn insert_asset_metadata(&self, metadata: &AssetMetadata) -> DatabaseResult<()> {
+        let _: Option<AssetMetadata> =
+            self.insert(metadata.asset_id.as_ref(), Column::AssetMetadata, metadata)?;
+        Ok(())
+    }
+
+    /// Returns the height of the last block indexed by the asset registry.
+    pub fn asset_registry_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        self.get(ASSET_REGISTRY_HEIGHT_KEY, Column::Metadata)
+    }
+
+    pub fn set_asset_registry_height(&self, height: BlockHeight) -> DatabaseResult<()> {
+        let _: Option<BlockHeight> =
+            self.insert(ASSET_REGISTRY_HEIGHT_KEY, Column::Metadata, &height)?;
+        Ok(())
+    }
+}
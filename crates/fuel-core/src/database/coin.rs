@@ -23,6 +23,7 @@ use fuel_core_types::{
     entities::coins::coin::CompressedCoin,
     fuel_tx::{
         Address,
+        AssetId,
         Bytes32,
         UtxoId,
     },
@@ -44,6 +45,17 @@ fn utxo_id_to_bytes(utxo_id: &UtxoId) -> [u8; TxId::LEN + 1] {
     default
 }
 
+/// Get a Key by chaining Owner + AssetId
+fn owner_asset_id_key(owner: &Address, asset_id: &AssetId) -> OwnedBalanceKey {
+    let mut default = [0u8; Address::LEN + AssetId::LEN];
+    default[0..Address::LEN].copy_from_slice(owner.as_ref());
+    default[Address::LEN..].copy_from_slice(asset_id.as_ref());
+    default
+}
+
+/// The storage key for the balance of owned coins: `Address ++ AssetId`
+pub type OwnedBalanceKey = [u8; Address::LEN + AssetId::LEN];
+
 /// The storage table of owned coin ids. Maps addresses to owned coins.
 pub struct OwnedCoins;
 /// The storage key for owned coins: `Address ++ UtxoId`
@@ -87,6 +99,11 @@ impl StorageMutate<Coins> for Database {
         // insert secondary index by owner
         self.storage_as_mut::<OwnedCoins>()
             .insert(&coin_by_owner, &true)?;
+        // update the balance of the owner
+        if let Some(coin) = &insert {
+            self.decrease_owned_coins_balance(&coin.owner, &coin.asset_id, coin.amount)?;
+        }
+        self.increase_owned_coins_balance(&value.owner, &value.asset_id, value.amount)?;
         Ok(insert)
     }
 
@@ -98,6 +115,7 @@ impl StorageMutate<Coins> for Database {
         if let Some(coin) = &coin {
             let key = owner_coin_id_key(&coin.owner, key);
             self.storage_as_mut::<OwnedCoins>().remove(&key)?;
+            self.decrease_owned_coins_balance(&coin.owner, &coin.asset_id, coin.amount)?;
         }
 
         Ok(coin)
@@ -128,6 +146,100 @@ impl Database {
         })
     }
 
+    /// Returns the total amount of the `asset_id` coins owned by the `owner`.
+    pub fn owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> DatabaseResult<u64> {
+        self.get(
+            &owner_asset_id_key(owner, asset_id),
+            Column::OwnedCoinsBalances,
+        )
+        .map(|balance| balance.unwrap_or_default())
+    }
+
+    /// Returns the total amounts of the coins owned by the `owner` per asset,
+    /// ordered by the asset id.
+    pub fn owned_coins_balances(
+        &self,
+        owner: &Address,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = DatabaseResult<(AssetId, u64)>> + '_ {
+        self.iter_all_filtered::<Vec<u8>, u64, _, Vec<u8>>(
+            Column::OwnedCoinsBalances,
+            Some(*owner),
+            None,
+            direction,
+        )
+        .map(|res| {
+            res.map(|(key, balance)| {
+                let asset_id = AssetId::try_from(&key[Address::LEN..])
+                    .expect("The slice has size 32");
+                (asset_id, balance)
+            })
+        })
+    }
+
+    /// Builds the balances of the owned coins from the existing coins.
+    /// It is used to migrate the databases created before the balances were indexed.
+    pub(crate) fn index_owned_coins_balances(&self) -> DatabaseResult<()> {
+        for coin in self.iter_all::<Vec<u8>, CompressedCoin>(Column::Coins, None) {
+            let (_, coin) = coin?;
+            self.increase_owned_coins_balance(&coin.owner, &coin.asset_id, coin.amount)?;
+        }
+        Ok(())
+    }
+
+    fn increase_owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u64,
+    ) -> DatabaseResult<()> {
+        let balance = self
+            .owned_coins_balance(owner, asset_id)?
+            .checked_add(amount)
+            .ok_or_else(|| {
+                anyhow::anyhow!("The balance of {asset_id} of {owner} overflows")
+            })?;
+        self.set_owned_coins_balance(owner, asset_id, balance)
+    }
+
+    fn decrease_owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        amount: u64,
+    ) -> DatabaseResult<()> {
+        let balance = self
+            .owned_coins_balance(owner, asset_id)?
+            .checked_sub(amount)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The balance of {asset_id} of {owner} is less than {amount}"
+                )
+            })?;
+        self.set_owned_coins_balance(owner, asset_id, balance)
+    }
+
+    /// Stores the `balance` or removes it when the owner doesn't have the asset anymore.
+    fn set_owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        balance: u64,
+    ) -> DatabaseResult<()> {
+        let key = owner_asset_id_key(owner, asset_id);
+        if balance == 0 {
+            let _: Option<u64> = self.remove(&key, Column::OwnedCoinsBalances)?;
+        } else {
+            let _: Option<u64> =
+                self.insert(key, Column::OwnedCoinsBalances, &balance)?;
+        }
+        Ok(())
+    }
+
     pub fn coin(&self, utxo_id: &UtxoId) -> StorageResult<CompressedCoin> {
         let coin = self
             .storage_as_ref::<Coins>()
@@ -164,3 +276,99 @@ impl Database {
         Ok(Some(configs))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn coin(owner: Address, asset_id: AssetId, amount: u64) -> CompressedCoin {
+        CompressedCoin {
+            owner,
+            amount,
+            asset_id,
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    #[test]
+    fn owned_coins_balances_follow_coins() {
+        let mut db = Database::default();
+        let owner = Address::new([1; 32]);
+        let first_asset = AssetId::new([1; 32]);
+        let second_asset = AssetId::new([2; 32]);
+        let first_id = UtxoId::new([1; 32].into(), 0);
+        let second_id = UtxoId::new([2; 32].into(), 0);
+        let third_id = UtxoId::new([3; 32].into(), 0);
+
+        db.storage_as_mut::<Coins>()
+            .insert(&first_id, &coin(owner, first_asset, 10))
+            .unwrap();
+        db.storage_as_mut::<Coins>()
+            .insert(&second_id, &coin(owner, first_asset, 20))
+            .unwrap();
+        db.storage_as_mut::<Coins>()
+            .insert(&third_id, &coin(owner, second_asset, 5))
+            .unwrap();
+        let balances: Vec<_> =
+            db.owned_coins_balances(&owner, None).try_collect().unwrap();
+        assert_eq!(balances, vec![(first_asset, 30), (second_asset, 5)]);
+
+        db.storage_as_mut::<Coins>().remove(&first_id).unwrap();
+        db.storage_as_mut::<Coins>().remove(&third_id).unwrap();
+        let balances: Vec<_> =
+            db.owned_coins_balances(&owner, None).try_collect().unwrap();
+        assert_eq!(balances, vec![(first_asset, 20)]);
+        assert_eq!(db.owned_coins_balance(&owner, &second_asset).unwrap(), 0);
+    }
+
+    #[test]
+    fn owned_coins_balance_overflow_is_an_error() {
+        let mut db = Database::default();
+        let owner = Address::new([1; 32]);
+        let asset_id = AssetId::new([1; 32]);
+        db.storage_as_mut::<Coins>()
+            .insert(
+                &UtxoId::new([1; 32].into(), 0),
+                &coin(owner, asset_id, u64::MAX),
+            )
+            .unwrap();
+
+        let result = db
+            .storage_as_mut::<Coins>()
+            .insert(&UtxoId::new([2; 32].into(), 0), &coin(owner, asset_id, 1));
+
+        assert!(result.is_err());
+        assert_eq!(db.owned_coins_balance(&owner, &asset_id).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn owned_coins_balances_are_ordered_by_asset_id() {
+        let mut db = Database::default();
+        let owner = Address::new([1; 32]);
+        for i in 0..3u8 {
+            db.storage_as_mut::<Coins>()
+                .insert(
+                    &UtxoId::new([i; 32].into(), 0),
+                    &coin(owner, AssetId::new([3 - i; 32]), 1),
+                )
+                .unwrap();
+        }
+
+        let assets: Vec<_> = db
+            .owned_coins_balances(&owner, Some(IterDirection::Reverse))
+            .map_ok(|(asset_id, _)| asset_id)
+            .try_collect()
+            .unwrap();
+
+        assert_eq!(
+            assets,
+            vec![
+                AssetId::new([3; 32]),
+                AssetId::new([2; 32]),
+                AssetId::new([1; 32])
+            ]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_types::services::block_importer::CompetingBlock;
+use itertools::Itertools;
+
+impl Database {
+    /// Stores the competing block and removes the ones with the lowest heights beyond
+    /// the `max_competing_blocks`. Returns `false` if the block is already stored.
+    pub fn insert_competing_block(
+        &self,
+        competing_block: &CompetingBlock,
+        max_competing_blocks: usize,
+    ) -> DatabaseResult<bool> {
+        let key = competing_block_key(competing_block);
+        if self.contains_key(&key, Column::CompetingBlocks)? {
+            return Ok(false)
+        }
+        if max_competing_blocks == 0 {
+            return Ok(true)
+        }
+        let _: Option<CompetingBlock> =
+            self.insert(key, Column::CompetingBlocks, competing_block)?;
+
+        let keys: Vec<Vec<u8>> = self
+            .iter_all::<Vec<u8>, CompetingBlock>(Column::CompetingBlocks, None)
+            .map_ok(|(key, _)| key)
+            .try_collect()?;
+        let expired = keys.len().saturating_sub(max_competing_blocks);
+        for key in &keys[..expired] {
+            let _: Option<CompetingBlock> = self.remove(key, Column::CompetingBlocks)?;
+        }
+        Ok(true)
+    }
+
+    /// Returns the stored competing blocks, the lowest heights first.
+    pub fn competing_blocks(&self) -> DatabaseResult<Vec<CompetingBlock>> {
+        self.iter_all::<Vec<u8>, CompetingBlock>(Column::CompetingBlocks, None)
+            .map_ok(|(_, competing_block)| competing_block)
+            .try_collect()
+    }
+}
+
+fn competing_block_key(competing_block: &CompetingBlock) -> Vec<u8> {
+    let block = &competing_block.sealed_block.entity;
+    [
+        &block.header().height().to_bytes()[..],
+        block.id().as_slice(),
+    ]
+    .concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::blockchain::SealedBlock;
+
+    fn competing_block(height: u32) -> CompetingBlock {
+        let mut sealed_block = SealedBlock::default();
+        sealed_block.entity.header_mut().consensus.height = height.into();
+        sealed_block.entity.header_mut().recalculate_metadata();
+        CompetingBlock {
+            canonical: Default::default(),
+            sealed_block,
+        }
+    }
+
+    fn heights(database: &Database) -> Vec<u32> {
+        database
+            .competing_blocks()
+            .unwrap()
+            .iter()
+            .map(|block| **block.sealed_block.entity.header().height())
+            .collect()
+    }
+
+    #[test]
+    fn insert_competing_block_stores_block_once() {
+        let database = Database::default();
+
+        assert!(database
+            .insert_competing_block(&competing_block(1), 2)
+            .unwrap());
+        assert!(!database
+            .insert_competing_block(&competing_block(1), 2)
+            .unwrap());
+
+        assert_eq!(
+            database.competing_blocks().unwrap(),
+            vec![competing_block(1)]
+        );
+    }
+
+    #[test]
+    fn insert_competing_block_removes_lowest_heights_beyond_limit() {
+        let database = Database::default();
+
+        for height in [3, 1, 256] {
+            database
+                .insert_competing_block(&competing_block(height), 2)
+                .unwrap();
+        }
+
+        assert_eq!(heights(&database), vec![3, 256]);
+    }
+
+    #[test]
+    fn insert_competing_block_stores_nothing_without_limit() {
+        let database = Database::default();
+
+        assert!(database
+            .insert_competing_block(&competing_block(1), 0)
+            .unwrap());
+
+        assert!(database.competing_blocks().unwrap().is_empty());
+    }
+}
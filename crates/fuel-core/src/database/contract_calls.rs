@@ -0,0 +1,157 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_types::{
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::graphql_api::ContractCalls,
+};
+use itertools::Itertools;
+use std::{
+    collections::HashMap,
+    mem::size_of,
+};
+
+/// The height of the last block indexed by the contract call analytics.
+pub(crate) const CONTRACT_CALLS_HEIGHT_KEY: &[u8] = b"contract_calls_height";
+
+const BLOCK_HEIGHT_SIZE: usize = size_of::<BlockHeight>();
+
+impl Database {
+    /// Records the number of the calls of each contract in the block at the `height`.
+    pub fn insert_contract_calls(
+        &self,
+        height: BlockHeight,
+        calls: &HashMap<ContractId, u64>,
+    ) -> DatabaseResult<()> {
+        self.batch_insert(
+            Column::ContractCalls,
+            calls.iter().map(|(contract_id, calls)| {
+                (contract_calls_key(height, contract_id), calls)
+            }),
+        )
+    }
+
+    /// Returns the `limit` most called contracts in the blocks starting from the `from`
+    /// height, ordered by the number of the calls.
+    pub fn top_contracts(
+        &self,
+        from: BlockHeight,
+        limit: usize,
+    ) -> DatabaseResult<Vec<ContractCalls>> {
+        let mut calls = HashMap::<ContractId, u64>::new();
+        for entry in self.iter_all_by_start::<Vec<u8>, u64, _>(
+            Column::ContractCalls,
+            Some(from.to_bytes()),
+            None,
+        ) {
+            let (key, block_calls) = entry?;
+            let contract_id = ContractId::try_from(&key[BLOCK_HEIGHT_SIZE..])
+                .expect("The key has the size of the height and the contract id");
+            let total = calls.entry(contract_id).or_default();
+            *total = total.saturating_add(block_calls);
+        }
+
+        Ok(calls
+            .into_iter()
+            .map(|(contract_id, calls)| ContractCalls { contract_id, calls })
+            .sorted_by(|a, b| {
+                b.calls
+                    .cmp(&a.calls)
+                    .then_with(|| a.contract_id.cmp(&b.contract_id))
+            })
+            .take(limit)
+            .collect())
+    }
+
+    /// Removes the calls recorded in the blocks below the `height`.
+    /// Returns the number of removed records.
+    pub fn prune_contract_calls(&self, height: BlockHeight) -> DatabaseResult<usize> {
+        let cutoff = height.to_bytes();
+        let expired: Vec<Vec<u8>> = self
+            .iter_all::<Vec<u8>, u64>(Column::ContractCalls, None)
+            .map_ok(|(key, _)| key)
+            .take_while(|key| {
+                key.as_ref()
+                    .map(|key| key[..BLOCK_HEIGHT_SIZE] < cutoff[..])
+                    .unwrap_or(true)
+            })
+            .try_collect()?;
+
+        for key in expired.iter() {
+            let _: Option<u64> = self.remove(key, Column::ContractCalls)?;
+        }
+        Ok(expired.len())
+    }
+
+    /// Returns the height of the last block indexed by the contract call analytics.
+    pub fn contract_calls_height(&self) -> DatabaseResult<Option<BlockHeight>> {
+        self.get(CONTRACT_CALLS_HEIGHT_KEY, Column::Metadata)
+    }
+
+    pub fn set_contract_calls_height(&self, height: BlockHeight) -> DatabaseResult<()> {
+        let _: Option<BlockHeight> =
+            self.insert(CONTRACT_CALLS_HEIGHT_KEY, Column::Metadata, &height)?;
+        Ok(())
+    }
+}
+
+fn contract_calls_key(height: BlockHeight, contract_id: &ContractId) -> Vec<u8> {
+    let mut key = Vec::with_capacity(BLOCK_HEIGHT_SIZE + ContractId::LEN);
+    key.extend_from_slice(&height.to_bytes());
+    key.extend_from_slice(contract_id.as_ref());
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert(database: &Database, height: u32, calls: &[(u8, u64)]) {
+        let calls = calls
+            .iter()
+            .map(|(id, calls)| (ContractId::from([*id; 32]), *calls))
+            .collect();
+        database
+            .insert_contract_calls(height.into(), &calls)
+            .unwrap();
+    }
+
+    fn top(database: &Database, from: u32, limit: usize) -> Vec<(u8, u64)> {
+        database
+            .top_contracts(from.into(), limit)
+            .unwrap()
+            .into_iter()
+            .map(|calls| (calls.contract_id[0], calls.calls))
+            .collect()
+    }
+
+    #[test]
+    fn top_contracts_sums_calls_of_the_window() {
+        let database = Database::default();
+        insert(&database, 1, &[(1, 10), (2, 1)]);
+        insert(&database, 2, &[(2, 3), (3, 2)]);
+        insert(&database, 3, &[(2, 1), (3, 2)]);
+
+        assert_eq!(top(&database, 1, 10), vec![(1, 10), (2, 5), (3, 4)]);
+        assert_eq!(top(&database, 2, 10), vec![(2, 4), (3, 4)]);
+        assert_eq!(top(&database, 2, 1), vec![(2, 4)]);
+        assert_eq!(top(&database, 4, 10), vec![]);
+    }
+
+    #[test]
+    fn prune_removes_calls_below_the_height() {
+        let database = Database::default();
+        insert(&database, 1, &[(1, 10), (2, 1)]);
+        insert(&database, 2, &[(2, 3)]);
+        insert(&database, 256, &[(3, 1)]);
+
+        assert_eq!(database.prune_contract_calls(2u32.into()).unwrap(), 2);
+        assert_eq!(top(&database, 0, 10), vec![(2, 3), (3, 1)]);
+        assert_eq!(database.prune_contract_calls(2u32.into()).unwrap(), 0);
+    }
+}
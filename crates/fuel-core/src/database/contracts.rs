@@ -26,7 +26,11 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     entities::contract::ContractUtxoInfo,
-    fuel_tx::Contract,
+    fuel_crypto::Hasher,
+    fuel_tx::{
+        Contract,
+        Salt,
+    },
     fuel_types::{
         AssetId,
         Bytes32,
@@ -34,7 +38,10 @@ use fuel_core_types::{
         Word,
     },
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
 
 impl DatabaseColumn for ContractsLatestUtxo {
     fn column() -> Column {
@@ -51,7 +58,7 @@ impl StorageInspect<ContractsRawCode> for Database {
     ) -> Result<Option<Cow<<ContractsRawCode as Mappable>::OwnedValue>>, Self::Error>
     {
         Ok(self
-            .read_alloc(key.as_ref(), Column::ContractsRawCode)?
+            .contract_bytecode(key)?
             .map(|v| Cow::Owned(Contract::from(v))))
     }
 
@@ -59,8 +66,8 @@ impl StorageInspect<ContractsRawCode> for Database {
         &self,
         key: &<ContractsRawCode as Mappable>::Key,
     ) -> Result<bool, Self::Error> {
-        self.contains_key(key.as_ref(), Column::ContractsRawCode)
-            .map_err(Into::into)
+        Ok(self.contract_bytecode_hash(key)?.is_some()
+            || self.contains_key(key.as_ref(), Column::ContractsRawCode)?)
     }
 }
 
@@ -68,15 +75,19 @@ impl StorageInspect<ContractsRawCode> for Database {
 // and deserialization. Because the value is a contract byte code represented by bytes,
 // we don't use `serde::Deserialization` and `serde::Serialization` for `Vec`, because we don't
 // need to store the size of the contract. We store/load raw bytes.
+//
+// The bytecode is stored once per its hash in the `ContractsBytecode` with the number of
+// the contracts referencing it, so the contracts deployed with the same bytecode share it.
+// The contracts written before the deduplication keep the bytecode in the `ContractsRawCode`
+// until the compaction moves it.
 impl StorageMutate<ContractsRawCode> for Database {
     fn insert(
         &mut self,
         key: &<ContractsRawCode as Mappable>::Key,
         value: &<ContractsRawCode as Mappable>::Value,
     ) -> Result<Option<<ContractsRawCode as Mappable>::OwnedValue>, Self::Error> {
-        let existing =
-            Database::replace(self, key.as_ref(), Column::ContractsRawCode, value)?;
-        Ok(existing.1.map(Contract::from))
+        let existing = self.write_contract_bytecode(key, value.as_ref())?;
+        Ok(existing.map(Contract::from))
     }
 
     fn remove(
@@ -92,7 +103,12 @@ impl StorageMutate<ContractsRawCode> for Database {
 
 impl StorageSize<ContractsRawCode> for Database {
     fn size_of_value(&self, key: &ContractId) -> Result<Option<usize>, Self::Error> {
-        Ok(self.size_of_value(key.as_ref(), Column::ContractsRawCode)?)
+        match self.contract_bytecode_hash(key)? {
+            Some(hash) => {
+                Ok(self.size_of_value(hash.as_ref(), Column::ContractsBytecode)?)
+            }
+            None => Ok(self.size_of_value(key.as_ref(), Column::ContractsRawCode)?),
+        }
     }
 }
 
@@ -102,22 +118,21 @@ impl StorageRead<ContractsRawCode> for Database {
         key: &ContractId,
         buf: &mut [u8],
     ) -> Result<Option<usize>, Self::Error> {
-        Ok(self.read(key.as_ref(), Column::ContractsRawCode, buf)?)
+        match self.contract_bytecode_hash(key)? {
+            Some(hash) => Ok(self.read(hash.as_ref(), Column::ContractsBytecode, buf)?),
+            None => Ok(self.read(key.as_ref(), Column::ContractsRawCode, buf)?),
+        }
     }
 
     fn read_alloc(&self, key: &ContractId) -> Result<Option<Vec<u8>>, Self::Error> {
-        Ok(self.read_alloc(key.as_ref(), Column::ContractsRawCode)?)
+        Ok(self.contract_bytecode(key)?)
     }
 }
 
 impl StorageWrite<ContractsRawCode> for Database {
     fn write(&mut self, key: &ContractId, buf: Vec<u8>) -> Result<usize, Self::Error> {
-        Ok(Database::write(
-            self,
-            key.as_ref(),
-            Column::ContractsRawCode,
-            &buf,
-        )?)
+        self.write_contract_bytecode(key, &buf)?;
+        Ok(buf.len())
     }
 
     fn replace(
@@ -128,23 +143,195 @@ impl StorageWrite<ContractsRawCode> for Database {
     where
         Self: StorageSize<ContractsRawCode>,
     {
-        Ok(Database::replace(
-            self,
-            key.as_ref(),
-            Column::ContractsRawCode,
-            &buf,
-        )?)
+        let existing = self.write_contract_bytecode(key, &buf)?;
+        Ok((buf.len(), existing))
     }
 
     fn take(
         &mut self,
         key: &<ContractsRawCode as Mappable>::Key,
     ) -> Result<Option<Vec<u8>>, Self::Error> {
-        Ok(Database::take(
-            self,
-            key.as_ref(),
-            Column::ContractsRawCode,
-        )?)
+        Ok(self.take_contract_bytecode(key)?)
+    }
+}
+
+/// The number of the legacy contracts moved into the deduplicated table per transaction.
+const COMPACTION_BATCH_SIZE: usize = 1000;
+
+/// The outcome of the compaction of the contracts bytecode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BytecodeCompaction {
+    /// The number of the contracts moved from the `ContractsRawCode`.
+    pub migrated: usize,
+    /// The number of the fixed reference counters.
+    pub recounted: usize,
+    /// The number of the removed bytecodes without references.
+    pub removed: usize,
+}
+
+impl Database {
+    fn contract_bytecode_hash(
+        &self,
+        contract_id: &ContractId,
+    ) -> DatabaseResult<Option<Bytes32>> {
+        self.read_alloc(contract_id.as_ref(), Column::ContractsBytecodeHash)?
+            .map(|hash| Ok(Bytes32::new(hash.as_slice().try_into()?)))
+            .transpose()
+    }
+
+    fn contract_bytecode(
+        &self,
+        contract_id: &ContractId,
+    ) -> DatabaseResult<Option<Vec<u8>>> {
+        match self.contract_bytecode_hash(contract_id)? {
+            Some(hash) => self.read_alloc(hash.as_ref(), Column::ContractsBytecode),
+            None => self.read_alloc(contract_id.as_ref(), Column::ContractsRawCode),
+        }
+    }
+
+    /// References the deduplicated `bytecode` from the contract and returns
+    /// the previous bytecode of the contract.
+    fn write_contract_bytecode(
+        &self,
+        contract_id: &ContractId,
+        bytecode: &[u8],
+    ) -> DatabaseResult<Option<Vec<u8>>> {
+        let existing = self.take_contract_bytecode(contract_id)?;
+
+        let hash = Hasher::hash(bytecode);
+        let refs: u64 = self
+            .get(hash.as_ref(), Column::ContractsBytecodeRefs)?
+            .unwrap_or_default();
+        if refs == 0 {
+            self.write(hash.as_ref(), Column::ContractsBytecode, bytecode)?;
+        }
+        let _: Option<u64> =
+            self.insert(hash, Column::ContractsBytecodeRefs, &(refs + 1))?;
+        self.write(
+            contract_id.as_ref(),
+            Column::ContractsBytecodeHash,
+            hash.as_ref(),
+        )?;
+        Ok(existing)
+    }
+
+    /// Removes the reference of the contract and returns its bytecode. The bytecode
+    /// itself is removed only with the last reference.
+    fn take_contract_bytecode(
+        &self,
+        contract_id: &ContractId,
+    ) -> DatabaseResult<Option<Vec<u8>>> {
+        let Some(hash) = self.contract_bytecode_hash(contract_id)? else {
+            return self.take(contract_id.as_ref(), Column::ContractsRawCode)
+        };
+        self.take(contract_id.as_ref(), Column::ContractsBytecodeHash)?;
+
+        let refs: u64 = self
+            .get(hash.as_ref(), Column::ContractsBytecodeRefs)?
+            .unwrap_or_default();
+        if refs <= 1 {
+            let _: Option<u64> =
+                self.remove(hash.as_ref(), Column::ContractsBytecodeRefs)?;
+            self.take(hash.as_ref(), Column::ContractsBytecode)
+        } else {
+            let _: Option<u64> =
+                self.insert(hash, Column::ContractsBytecodeRefs, &(refs - 1))?;
+            self.read_alloc(hash.as_ref(), Column::ContractsBytecode)
+        }
+    }
+
+    /// Moves the bytecode of the contracts written before the deduplication into
+    /// the deduplicated table, recounts the references of the bytecodes and removes
+    /// the bytecodes without references.
+    ///
+    /// The compaction must not run concurrently with the block importer, otherwise
+    /// the references of the imported contracts may be miscounted.
+    pub fn compact_contracts_bytecode(&self) -> DatabaseResult<BytecodeCompaction> {
+        let mut compaction = BytecodeCompaction::default();
+
+        // The migrated contracts are removed from the `ContractsRawCode`,
+        // so each batch starts from the beginning of the table.
+        loop {
+            let legacy = self
+                .data
+                .iter_all(Column::ContractsRawCode, None, None, IterDirection::Forward)
+                .take(COMPACTION_BATCH_SIZE)
+                .map(|item| item.map(|(key, _)| key))
+                .collect::<DatabaseResult<Vec<_>>>()?;
+            if legacy.is_empty() {
+                break
+            }
+
+            let mut transaction = self.transaction();
+            let database: &Database = transaction.as_mut();
+            for key in legacy {
+                let contract_id = ContractId::new(key.as_slice().try_into()?);
+                if let Some(bytecode) =
+                    database.read_alloc(contract_id.as_ref(), Column::ContractsRawCode)?
+                {
+                    database.write_contract_bytecode(&contract_id, &bytecode)?;
+                    compaction.migrated += 1;
+                }
+            }
+            transaction
+                .commit()
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+
+        let mut refs = HashMap::<Bytes32, u64>::new();
+        for item in self.data.iter_all(
+            Column::ContractsBytecodeHash,
+            None,
+            None,
+            IterDirection::Forward,
+        ) {
+            let (_, hash) = item?;
+            *refs
+                .entry(Bytes32::new(hash.as_slice().try_into()?))
+                .or_default() += 1;
+        }
+
+        let bytecodes = self
+            .data
+            .iter_all(
+                Column::ContractsBytecode,
+                None,
+                None,
+                IterDirection::Forward,
+            )
+            .map(|item| item.map(|(key, _)| key))
+            .collect::<DatabaseResult<Vec<_>>>()?;
+
+        let mut transaction = self.transaction();
+        let database: &Database = transaction.as_mut();
+        for key in bytecodes {
+            let hash = Bytes32::new(key.as_slice().try_into()?);
+            let stored: Option<u64> =
+                database.get(hash.as_ref(), Column::ContractsBytecodeRefs)?;
+            match refs.get(&hash) {
+                Some(count) => {
+                    if stored != Some(*count) {
+                        let _: Option<u64> = database.insert(
+                            hash,
+                            Column::ContractsBytecodeRefs,
+                            count,
+                        )?;
+                        compaction.recounted += 1;
+                    }
+                }
+                None => {
+                    let _: Option<u64> =
+                        database.remove(hash.as_ref(), Column::ContractsBytecodeRefs)?;
+                    database.take(hash.as_ref(), Column::ContractsBytecode)?;
+                    compaction.removed += 1;
+                }
+            }
+        }
+        transaction
+            .commit()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+
+        Ok(compaction)
     }
 }
 
@@ -247,7 +434,7 @@ impl Database {
 
     pub fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>> {
         let configs = self
-            .iter_all::<Vec<u8>, Word>(Column::ContractsRawCode, None)
+            .iter_all::<Vec<u8>, (Salt, Bytes32)>(Column::ContractsInfo, None)
             .map(|raw_contract_id| -> StorageResult<ContractConfig> {
                 let contract_id = ContractId::new(
                     raw_contract_id.unwrap().0[..32]
@@ -382,6 +569,153 @@ mod tests {
             .unwrap());
     }
 
+    #[test]
+    fn raw_code_is_shared_by_the_contracts_with_the_same_bytecode() {
+        let first: ContractId = ContractId::from([1u8; 32]);
+        let second: ContractId = ContractId::from([2u8; 32]);
+        let contract: Contract = Contract::from(vec![32u8; 64]);
+        let hash = Hasher::hash(contract.as_ref());
+
+        let database = &mut Database::default();
+        database
+            .storage::<ContractsRawCode>()
+            .insert(&first, contract.as_ref())
+            .unwrap();
+        database
+            .storage::<ContractsRawCode>()
+            .insert(&second, contract.as_ref())
+            .unwrap();
+
+        let refs: Option<u64> = database
+            .get(hash.as_ref(), Column::ContractsBytecodeRefs)
+            .unwrap();
+        assert_eq!(refs, Some(2));
+        assert_eq!(
+            database
+                .column_stats(Column::ContractsBytecode)
+                .unwrap()
+                .keys,
+            1
+        );
+
+        database
+            .storage::<ContractsRawCode>()
+            .remove(&first)
+            .unwrap();
+        assert_eq!(
+            database
+                .storage::<ContractsRawCode>()
+                .get(&second)
+                .unwrap()
+                .unwrap()
+                .into_owned(),
+            contract
+        );
+
+        database
+            .storage::<ContractsRawCode>()
+            .remove(&second)
+            .unwrap();
+        assert!(!database
+            .contains_key(hash.as_ref(), Column::ContractsBytecode)
+            .unwrap());
+        assert!(!database
+            .contains_key(hash.as_ref(), Column::ContractsBytecodeRefs)
+            .unwrap());
+    }
+
+    #[test]
+    fn compaction_moves_the_legacy_raw_code() {
+        let first: ContractId = ContractId::from([1u8; 32]);
+        let second: ContractId = ContractId::from([2u8; 32]);
+        let contract: Contract = Contract::from(vec![32u8; 64]);
+        let hash = Hasher::hash(contract.as_ref());
+
+        let database = &mut Database::default();
+        Database::write(
+            database,
+            first.as_ref(),
+            Column::ContractsRawCode,
+            contract.as_ref(),
+        )
+        .unwrap();
+        Database::write(
+            database,
+            second.as_ref(),
+            Column::ContractsRawCode,
+            contract.as_ref(),
+        )
+        .unwrap();
+        assert!(database
+            .storage::<ContractsRawCode>()
+            .contains_key(&first)
+            .unwrap());
+
+        let compaction = database.compact_contracts_bytecode().unwrap();
+
+        assert_eq!(
+            compaction,
+            BytecodeCompaction {
+                migrated: 2,
+                recounted: 0,
+                removed: 0,
+            }
+        );
+        assert!(!database
+            .contains_key(first.as_ref(), Column::ContractsRawCode)
+            .unwrap());
+        let refs: Option<u64> = database
+            .get(hash.as_ref(), Column::ContractsBytecodeRefs)
+            .unwrap();
+        assert_eq!(refs, Some(2));
+        assert_eq!(
+            database
+                .storage::<ContractsRawCode>()
+                .get(&first)
+                .unwrap()
+                .unwrap()
+                .into_owned(),
+            contract
+        );
+    }
+
+    #[test]
+    fn compaction_fixes_the_references_and_removes_the_orphans() {
+        let contract_id: ContractId = ContractId::from([1u8; 32]);
+        let contract: Contract = Contract::from(vec![32u8; 64]);
+        let hash = Hasher::hash(contract.as_ref());
+        let orphan = Hasher::hash([1u8]);
+
+        let database = &mut Database::default();
+        database
+            .storage::<ContractsRawCode>()
+            .insert(&contract_id, contract.as_ref())
+            .unwrap();
+        let _: Option<u64> =
+            Database::insert(database, hash, Column::ContractsBytecodeRefs, &5u64)
+                .unwrap();
+        Database::write(database, orphan.as_ref(), Column::ContractsBytecode, &[1u8])
+            .unwrap();
+
+        let compaction = database.compact_contracts_bytecode().unwrap();
+
+        assert_eq!(
+            compaction,
+            BytecodeCompaction {
+                migrated: 0,
+                recounted: 1,
+                removed: 1,
+            }
+        );
+        let refs: Option<u64> = database
+            .get(hash.as_ref(), Column::ContractsBytecodeRefs)
+            .unwrap();
+        assert_eq!(refs, Some(1));
+        assert!(!database
+            .contains_key(orphan.as_ref(), Column::ContractsBytecode)
+            .unwrap());
+    }
+
     #[test]
     fn latest_utxo_get() {
         let contract_id: ContractId = ContractId::from([1u8; 32]);
@@ -0,0 +1,61 @@
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_types::services::graphql_api::BlockFees;
+use itertools::Itertools;
+
+impl Database {
+    /// Records the gas price and the fullness of the block.
+    pub fn insert_block_fees(&self, fees: &BlockFees) -> DatabaseResult<()> {
+        let _: Option<BlockFees> =
+            self.insert(fees.height.to_bytes(), Column::FeeHistory, fees)?;
+        Ok(())
+    }
+
+    /// Returns the gas prices and the fullness of the `last` latest blocks,
+    /// the oldest first.
+    pub fn fee_history(&self, last: usize) -> DatabaseResult<Vec<BlockFees>> {
+        let mut history: Vec<BlockFees> = self
+            .iter_all::<Vec<u8>, BlockFees>(
+                Column::FeeHistory,
+                Some(IterDirection::Reverse),
+            )
+            .map_ok(|(_, fees)| fees)
+            .take(last)
+            .try_collect()?;
+        history.reverse();
+        Ok(history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fees(height: u32) -> BlockFees {
+        BlockFees {
+            height: height.into(),
+            min_gas_price: height as u64,
+            gas_used: 100,
+            bytes_used: 10,
+        }
+    }
+
+    #[test]
+    fn fee_history_returns_latest_blocks_oldest_first() {
+        let database = Database::default();
+        for height in [1, 2, 255, 256] {
+            database.insert_block_fees(&fees(height)).unwrap();
+        }
+
+        assert_eq!(
+            database.fee_history(3).unwrap(),
+            vec![fees(2), fees(255), fees(256)]
+        );
+        assert_eq!(database.fee_history(10).unwrap().len(), 4);
+        assert_eq!(database.fee_history(0).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,353 @@
+//! Read-only inspection of the raw content of the database. It is used to debug the
+//! corrupted or unexpected state without writing the code against `fuel-core-storage`.
+
+use crate::{
+    chain_config::SignedUpgradeDeclaration,
+    database::{
+        coin::OwnedCoins,
+        state_diff::StateDiff,
+        storage::{
+            ContractsAssetsMerkleData,
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+            FuelBlockMerkleData,
+            FuelBlockMerkleMetadata,
+            FuelBlockSecondaryKeyBlockHeights,
+        },
+        Column,
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        Coins,
+        ContractsAssets,
+        ContractsInfo,
+        ContractsLatestUtxo,
+        ContractsState,
+        FuelBlocks,
+        MessageCancellations,
+        MessageDeposits,
+        Messages,
+        Receipts,
+        SealedBlockConsensus,
+        SpentMessages,
+        Transactions,
+    },
+    Mappable,
+};
+use fuel_core_types::{
+    entities::{
+        asset::AssetMetadata,
+        message::Message,
+    },
+    fuel_tx::Bytes32,
+    services::{
+        block_importer::CompetingBlock,
+        graphql_api::BlockFees,
+        relayer::DaFeeSample,
+        txpool::{
+            ArchivedTransactionStatus,
+            TransactionStatus,
+        },
+    },
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+/// The number of entries and the total size of keys and values in the column.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnSize {
+    /// The name of the column.
+    pub column: String,
+    /// The number of entries in the column.
+    pub entries: u64,
+    /// The total size of the keys in bytes.
+    pub keys_size: u64,
+    /// The total size of the values in bytes.
+    pub values_size: u64,
+}
+
+/// The entry of the column with the hex encoded key and the decoded value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Entry {
+    /// The hex encoded key.
+    pub key: String,
+    /// The decoded value.
+    pub value: serde_json::Value,
+}
+
+impl Database {
+    /// Returns the decoded value of the `key` in the `column`.
+    pub fn inspect_value(
+        &self,
+        column: Column,
+        key: &[u8],
+    ) -> DatabaseResult<Option<serde_json::Value>> {
+        self.data
+            .get(key, column)?
+            .map(|value| decode_value(column, &value))
+            .transpose()
+    }
+
+    /// Returns up to the `limit` keys of the `column` in the range
+    /// from the `start`(inclusive) to the `end`(exclusive).
+    pub fn inspect_keys(
+        &self,
+        column: Column,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        limit: usize,
+    ) -> DatabaseResult<Vec<Vec<u8>>> {
+        self.data
+            .iter_all(column, None, start, IterDirection::Forward)
+            .map(|result| result.map(|(key, _)| key))
+            .take_while(|result| match (result, end) {
+                (Ok(key), Some(end)) => key.as_slice() < end,
+                _ => true,
+            })
+            .take(limit)
+            .collect()
+    }
+
+    /// Returns the sizes of all columns of the database.
+    pub fn inspect_column_sizes(&self) -> DatabaseResult<Vec<ColumnSize>> {
+        enum_iterator::all::<Column>()
+            .map(|column| {
+                let mut size = ColumnSize {
+                    column: column.as_ref().to_string(),
+                    entries: 0,
+                    keys_size: 0,
+                    values_size: 0,
+                };
+                for entry in
+                    self.data
+                        .iter_all(column, None, None, IterDirection::Forward)
+                {
+                    let (key, value) = entry?;
+                    size.entries += 1;
+                    size.keys_size += key.len() as u64;
+                    size.values_size += value.len() as u64;
+                }
+                Ok(size)
+            })
+            .collect()
+    }
+
+    /// Iterates over all entries of the `column` with decoded values.
+    pub fn inspect_entries(
+        &self,
+        column: Column,
+    ) -> impl Iterator<Item = DatabaseResult<Entry>> + '_ {
+        self.data
+            .iter_all(column, None, None, IterDirection::Forward)
+            .map(move |result| {
+                let (key, value) = result?;
+                Ok(Entry {
+                    key: hex::encode(key),
+                    value: decode_value(column, &value)?,
+                })
+            })
+    }
+}
+
+/// Decodes the raw `value` of the `column` into JSON. The values of the columns
+/// without the single type are returned as a hex string.
+pub fn decode_value(column: Column, value: &[u8]) -> DatabaseResult<serde_json::Value> {
+    match column {
+        Column::Metadata
+        | Column::ContractsRawCode
+        | Column::ContractsBytecode
+        | Column::ContractsBytecodeHash
+        | Column::RelayerMetadata
+        | Column::OriginRelayerMetadata => {
+            Ok(serde_json::Value::String(hex::encode(value)))
+        }
+        Column::ContractsInfo => decode::<ContractsInfo>(value),
+        Column::ContractsState => decode::<ContractsState>(value),
+        Column::ContractsLatestUtxo => decode::<ContractsLatestUtxo>(value),
+        Column::ContractsAssets => decode::<ContractsAssets>(value),
+        Column::Coins => decode::<Coins>(value),
+        Column::OwnedCoins => decode::<OwnedCoins>(value),
+        Column::Transactions => decode::<Transactions>(value),
+        Column::TransactionStatus => {
+            to_json(postcard_decode::<TransactionStatus>(value)?)
+        }
+        Column::TransactionsByOwnerBlockIdx => {
+            to_json(postcard_decode::<Bytes32>(value)?)
+        }
+        Column::Receipts => decode::<Receipts>(value),
+        Column::FuelBlocks => decode::<FuelBlocks>(value),
+        Column::FuelBlockSecondaryKeyBlockHeights => {
+            decode::<FuelBlockSecondaryKeyBlockHeights>(value)
+        }
+        Column::Messages => decode::<Messages>(value),
+        Column::OwnedMessageIds => to_json(postcard_decode::<bool>(value)?),
+        Column::FuelBlockConsensus => decode::<SealedBlockConsensus>(value),
+        Column::FuelBlockMerkleData => decode::<FuelBlockMerkleData>(value),
+        Column::FuelBlockMerkleMetadata => decode::<FuelBlockMerkleMetadata>(value),
+        Column::SpentMessages => decode::<SpentMessages>(value),
+        Column::ContractsAssetsMerkleData => decode::<ContractsAssetsMerkleData>(value),
+        Column::ContractsAssetsMerkleMetadata => {
+            decode::<ContractsAssetsMerkleMetadata>(value)
+        }
+        Column::ContractsStateMerkleData => decode::<ContractsStateMerkleData>(value),
+        Column::ContractsStateMerkleMetadata => {
+            decode::<ContractsStateMerkleMetadata>(value)
+        }
+        Column::MessageDeposits => decode::<MessageDeposits>(value),
+        Column::MessageCancellations => decode::<MessageCancellations>(value),
+        Column::DaTransactionMessageIds => to_json(postcard_decode::<bool>(value)?),
+        Column::MessageSpendingTransactions => {
+            to_json(postcard_decode::<Bytes32>(value)?)
+        }
+        Column::TransactionStatusArchive => {
+            to_json(postcard_decode::<Vec<ArchivedTransactionStatus>>(value)?)
+        }
+        Column::TransactionStatusArchiveExpiry => {
+            to_json(postcard_decode::<bool>(value)?)
+        }
+        Column::AssetMetadata => to_json(postcard_decode::<AssetMetadata>(value)?),
+        Column::OwnedCoinsBalances | Column::OwnedMessagesBalances => {
+            to_json(postcard_decode::<u64>(value)?)
+        }
+        Column::Upgrades => to_json(postcard_decode::<SignedUpgradeDeclaration>(value)?),
+        Column::StateDiffs => to_json(postcard_decode::<StateDiff>(value)?),
+        Column::OriginMessages => to_json(postcard_decode::<Message>(value)?),
+        Column::DaFeeSamples => to_json(postcard_decode::<Vec<DaFeeSample>>(value)?),
+        Column::ContractsBytecodeRefs | Column::ContractCalls => {
+            to_json(postcard_decode::<u64>(value)?)
+        }
+        Column::FeeHistory => to_json(postcard_decode::<BlockFees>(value)?),
+        Column::CompetingBlocks => to_json(postcard_decode::<CompetingBlock>(value)?),
+    }
+}
+
+fn decode<M>(value: &[u8]) -> DatabaseResult<serde_json::Value>
+where
+    M: Mappable,
+    M::OwnedValue: DeserializeOwned + Serialize,
+{
+    to_json(postcard_decode::<M::OwnedValue>(value)?)
+}
+
+fn postcard_decode<T: DeserializeOwned>(value: &[u8]) -> DatabaseResult<T> {
+    postcard::from_bytes(value).map_err(|_| DatabaseError::Codec)
+}
+
+fn to_json<T: Serialize>(value: T) -> DatabaseResult<serde_json::Value> {
+    serde_json::to_value(value).map_err(|_| DatabaseError::Codec)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+
+    fn database_with_coins(count: u8) -> Database {
+        let mut database = Database::default();
+        for i in 0..count {
+            let coin = CompressedCoin {
+                owner: Default::default(),
+                amount: i as u64,
+                asset_id: Default::default(),
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            };
+            database
+                .storage::<Coins>()
+                .insert(&UtxoId::new([i; 32].into(), 0), &coin)
+                .unwrap();
+        }
+        database
+    }
+
+    #[test]
+    fn inspect_value__decodes_coin() {
+        let database = database_with_coins(3);
+        let key = database
+            .inspect_keys(Column::Coins, None, None, usize::MAX)
+            .unwrap()
+            .remove(1);
+
+        let value = database
+            .inspect_value(Column::Coins, &key)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value["amount"], 1);
+    }
+
+    #[test]
+    fn inspect_value__returns_none_for_unknown_key() {
+        let database = database_with_coins(1);
+
+        let value = database.inspect_value(Column::Coins, &[0xff; 33]).unwrap();
+
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn inspect_keys__returns_keys_in_range() {
+        let database = database_with_coins(5);
+        let all = database
+            .inspect_keys(Column::Coins, None, None, usize::MAX)
+            .unwrap();
+        assert_eq!(all.len(), 5);
+
+        let range = database
+            .inspect_keys(Column::Coins, Some(&all[1]), Some(&all[4]), usize::MAX)
+            .unwrap();
+        assert_eq!(range, all[1..4].to_vec());
+
+        let limited = database
+            .inspect_keys(Column::Coins, Some(&all[1]), None, 2)
+            .unwrap();
+        assert_eq!(limited, all[1..3].to_vec());
+    }
+
+    #[test]
+    fn inspect_column_sizes__counts_entries_of_each_column() {
+        let database = database_with_coins(4);
+
+        let sizes = database.inspect_column_sizes().unwrap();
+
+        assert_eq!(sizes.len(), Column::COUNT);
+        let coins = sizes.iter().find(|size| size.column == "Coins").unwrap();
+        assert_eq!(coins.entries, 4);
+        assert_eq!(coins.keys_size, 4 * 33);
+        let blocks = sizes
+            .iter()
+            .find(|size| size.column == "FuelBlocks")
+            .unwrap();
+        assert_eq!(blocks.entries, 0);
+    }
+
+    #[test]
+    fn inspect_entries__dumps_all_entries_of_column() {
+        let database = database_with_coins(2);
+
+        let entries: Vec<_> = database
+            .inspect_entries(Column::Coins)
+            .collect::<DatabaseResult<_>>()
+            .unwrap();
+
+        let keys = database
+            .inspect_keys(Column::Coins, None, None, usize::MAX)
+            .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].key, hex::encode(&keys[0]));
+        assert_eq!(entries[1].value["amount"], 1);
+    }
+}
@@ -8,6 +8,8 @@ use fuel_core_chain_config::MessageConfig;
 use fuel_core_storage::{
     iter::IterDirection,
     tables::{
+        MessageCancellations,
+        MessageDeposits,
         Messages,
         SpentMessages,
     },
@@ -17,9 +19,14 @@ use fuel_core_storage::{
     StorageMutate,
 };
 use fuel_core_types::{
-    entities::message::Message,
+    entities::message::{
+        Message,
+        MessageDeposit,
+    },
+    fuel_tx::TxId,
     fuel_types::{
         Address,
+        Bytes32,
         Nonce,
     },
 };
@@ -62,6 +69,12 @@ impl StorageMutate<Messages> for Database {
             &true,
         )?;
 
+        // update the balance of the recipient
+        if let Some(message) = &result {
+            self.decrease_owned_messages_balance(message)?;
+        }
+        self.increase_owned_messages_balance(value)?;
+
         Ok(result)
     }
 
@@ -75,6 +88,63 @@ impl StorageMutate<Messages> for Database {
                 &owner_msg_id_key(&message.recipient, key),
                 Column::OwnedMessageIds,
             )?;
+            self.decrease_owned_messages_balance(message)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl StorageInspect<MessageDeposits> for Database {
+    type Error = StorageError;
+
+    fn get(&self, key: &Nonce) -> Result<Option<Cow<MessageDeposit>>, Self::Error> {
+        let key = key.database_key();
+        Database::get(self, key.as_ref(), Column::MessageDeposits).map_err(Into::into)
+    }
+
+    fn contains_key(&self, key: &Nonce) -> Result<bool, Self::Error> {
+        let key = key.database_key();
+        Database::contains_key(self, key.as_ref(), Column::MessageDeposits)
+            .map_err(Into::into)
+    }
+}
+
+impl StorageMutate<MessageDeposits> for Database {
+    fn insert(
+        &mut self,
+        key: &Nonce,
+        value: &MessageDeposit,
+    ) -> Result<Option<MessageDeposit>, Self::Error> {
+        // insert primary record
+        let result = Database::insert(
+            self,
+            key.database_key().as_ref(),
+            Column::MessageDeposits,
+            value,
+        )?;
+
+        // insert secondary record by da transaction
+        let _: Option<bool> = Database::insert(
+            self,
+            da_transaction_msg_id_key(&value.da_transaction, key),
+            Column::DaTransactionMessageIds,
+            &true,
+        )?;
+
+        Ok(result)
+    }
+
+    fn remove(&mut self, key: &Nonce) -> Result<Option<MessageDeposit>, Self::Error> {
+        let result: Option<MessageDeposit> =
+            Database::remove(self, key.database_key().as_ref(), Column::MessageDeposits)?;
+
+        if let Some(deposit) = &result {
+            Database::remove::<bool>(
+                self,
+                &da_transaction_msg_id_key(&deposit.da_transaction, key),
+                Column::DaTransactionMessageIds,
+            )?;
         }
 
         Ok(result)
@@ -87,6 +157,12 @@ impl DatabaseColumn for SpentMessages {
     }
 }
 
+impl DatabaseColumn for MessageCancellations {
+    fn column() -> Column {
+        Column::MessageCancellations
+    }
+}
+
 impl Database {
     pub fn owned_message_ids(
         &self,
@@ -108,6 +184,100 @@ impl Database {
         })
     }
 
+    /// Returns the nonces of the messages sent by the da transaction.
+    pub fn da_transaction_message_ids(
+        &self,
+        da_transaction: &Bytes32,
+    ) -> impl Iterator<Item = DatabaseResult<Nonce>> + '_ {
+        self.iter_all_by_prefix::<Vec<u8>, bool, _>(
+            Column::DaTransactionMessageIds,
+            Some(*da_transaction),
+        )
+        .map(|res| {
+            res.map(|(key, _)| {
+                Nonce::try_from(&key[Bytes32::LEN..Bytes32::LEN + Nonce::LEN])
+                    .expect("key is always {Nonce::LEN} bytes")
+            })
+        })
+    }
+
+    /// Returns the total amount of the messages without data owned by the `owner`.
+    /// Such messages are spendable as the base asset.
+    pub fn owned_messages_balance(&self, owner: &Address) -> DatabaseResult<u64> {
+        self.get(owner.as_ref(), Column::OwnedMessagesBalances)
+            .map(|balance| balance.unwrap_or_default())
+    }
+
+    /// Builds the balances of the owned messages from the existing messages.
+    /// It is used to migrate the databases created before the balances were indexed.
+    pub(crate) fn index_owned_messages_balances(&self) -> DatabaseResult<()> {
+        for message in self.all_messages(None, None) {
+            self.increase_owned_messages_balance(&message?)?;
+        }
+        Ok(())
+    }
+
+    fn increase_owned_messages_balance(&self, message: &Message) -> DatabaseResult<()> {
+        if !message.data.is_empty() {
+            return Ok(())
+        }
+        let balance = self
+            .owned_messages_balance(&message.recipient)?
+            .checked_add(message.amount)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The balance of the messages of {} overflows",
+                    message.recipient
+                )
+            })?;
+        self.set_owned_messages_balance(&message.recipient, balance)
+    }
+
+    fn decrease_owned_messages_balance(&self, message: &Message) -> DatabaseResult<()> {
+        if !message.data.is_empty() {
+            return Ok(())
+        }
+        let balance = self
+            .owned_messages_balance(&message.recipient)?
+            .checked_sub(message.amount)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "The balance of the messages of {} is less than {}",
+                    message.recipient,
+                    message.amount
+                )
+            })?;
+        self.set_owned_messages_balance(&message.recipient, balance)
+    }
+
+    /// Stores the `balance` or removes it when the owner doesn't have messages anymore.
+    fn set_owned_messages_balance(
+        &self,
+        owner: &Address,
+        balance: u64,
+    ) -> DatabaseResult<()> {
+        if balance == 0 {
+            let _: Option<u64> =
+                self.remove(owner.as_ref(), Column::OwnedMessagesBalances)?;
+        } else {
+            let _: Option<u64> =
+                self.insert(owner, Column::OwnedMessagesBalances, &balance)?;
+        }
+        Ok(())
+    }
+
+    pub fn record_message_spender(
+        &self,
+        nonce: &Nonce,
+        tx_id: &TxId,
+    ) -> DatabaseResult<Option<TxId>> {
+        self.insert(nonce, Column::MessageSpendingTransactions, tx_id)
+    }
+
+    pub fn get_message_spender(&self, nonce: &Nonce) -> DatabaseResult<Option<TxId>> {
+        self.get(nonce.as_ref(), Column::MessageSpendingTransactions)
+    }
+
     pub fn all_messages(
         &self,
         start: Option<Nonce>,
@@ -168,6 +338,17 @@ fn owner_msg_id_key(owner: &Address, nonce: &Nonce) -> [u8; Address::LEN + Nonce
     default
 }
 
+/// Get a Key by chaining da transaction hash + Nonce
+fn da_transaction_msg_id_key(
+    da_transaction: &Bytes32,
+    nonce: &Nonce,
+) -> [u8; Bytes32::LEN + Nonce::LEN] {
+    let mut default = [0u8; Bytes32::LEN + Nonce::LEN];
+    default[0..Bytes32::LEN].copy_from_slice(da_transaction.as_ref());
+    default[Bytes32::LEN..].copy_from_slice(nonce.as_ref());
+    default
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,4 +392,74 @@ mod tests {
         let owned_msg_ids = db.owned_message_ids(&message.recipient, None, None);
         assert_eq!(owned_msg_ids.count(), 0);
     }
+
+    #[test]
+    fn owned_messages_balance_counts_messages_without_data() {
+        let mut db = Database::default();
+        let recipient = Address::new([1; 32]);
+        let message = |amount: u64, data: Vec<u8>| Message {
+            recipient,
+            amount,
+            data,
+            ..Default::default()
+        };
+
+        db.storage::<Messages>()
+            .insert(&1.into(), &message(10, vec![]))
+            .unwrap();
+        db.storage::<Messages>()
+            .insert(&2.into(), &message(20, vec![]))
+            .unwrap();
+        db.storage::<Messages>()
+            .insert(&3.into(), &message(100, vec![1]))
+            .unwrap();
+        assert_eq!(db.owned_messages_balance(&recipient).unwrap(), 30);
+
+        db.storage::<Messages>().remove(&1.into()).unwrap();
+        assert_eq!(db.owned_messages_balance(&recipient).unwrap(), 20);
+
+        db.storage::<Messages>().remove(&2.into()).unwrap();
+        assert_eq!(db.owned_messages_balance(&recipient).unwrap(), 0);
+    }
+
+    #[test]
+    fn da_transaction_message_ids() {
+        let mut db = Database::default();
+        let deposit = MessageDeposit {
+            da_transaction: Bytes32::new([1; 32]),
+            da_height: 1u64.into(),
+        };
+        let first_id = 1.into();
+        let second_id = 2.into();
+        db.storage::<MessageDeposits>()
+            .insert(&first_id, &deposit)
+            .unwrap();
+        db.storage::<MessageDeposits>()
+            .insert(&second_id, &deposit)
+            .unwrap();
+        // the deposit of another da transaction
+        db.storage::<MessageDeposits>()
+            .insert(
+                &3.into(),
+                &MessageDeposit {
+                    da_transaction: Bytes32::new([2; 32]),
+                    ..deposit
+                },
+            )
+            .unwrap();
+
+        let ids: Vec<_> = db
+            .da_transaction_message_ids(&deposit.da_transaction)
+            .collect::<DatabaseResult<_>>()
+            .unwrap();
+        assert_eq!(ids, vec![first_id, second_id]);
+
+        // remove the first deposit with its given id
+        let _ = db.storage::<MessageDeposits>().remove(&first_id).unwrap();
+        let ids: Vec<_> = db
+            .da_transaction_message_ids(&deposit.da_transaction)
+            .collect::<DatabaseResult<_>>()
+            .unwrap();
+        assert_eq!(ids, vec![second_id]);
+    }
 }
@@ -5,15 +5,31 @@ use crate::database::{
     Result as DatabaseResult,
 };
 use fuel_core_chain_config::ChainConfig;
+use fuel_core_gas_price_service::AlgorithmState;
+use fuel_core_storage::transactional::Transaction;
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::BlockHeight,
+};
 
 pub(crate) const DB_VERSION_KEY: &[u8] = b"version";
 pub(crate) const CHAIN_NAME_KEY: &[u8] = b"chain_name";
 /// Tracks the total number of transactions written to the chain
 /// It's useful for analyzing TPS or other metrics.
 pub(crate) const TX_COUNT: &[u8] = b"total_tx_count";
+/// The seconds added to the wall clock by the `advanceTime` of the development mode.
+pub(crate) const TIME_OFFSET: &[u8] = b"time_offset";
+/// The state of the gas price algorithm, so the gas price continues after the restart.
+pub(crate) const GAS_PRICE_ALGORITHM_STATE: &[u8] = b"gas_price_algorithm_state";
+
+/// The height and the id of the latest block signed by the cosign endpoint
+/// of the admin API, so the node never cosigns two blocks at one height.
+pub(crate) const LAST_COSIGNED: &[u8] = b"last_cosigned";
 
 /// Can be used to perform migrations in the future.
-pub(crate) const DB_VERSION: u32 = 0x00;
+pub(crate) const DB_VERSION: u32 = 0x01;
+/// The version of the database before the balances of the owners were indexed.
+const DB_VERSION_WITHOUT_OWNED_BALANCES: u32 = 0x00;
 
 impl Database {
     /// Ensures the database is initialized and that the database version is correct
@@ -31,7 +47,11 @@ impl Database {
         }
 
         // Ensure the database version is correct
-        if let Some(version) = self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
+        if let Some(mut version) = self.get::<u32>(DB_VERSION_KEY, Column::Metadata)? {
+            if version == DB_VERSION_WITHOUT_OWNED_BALANCES {
+                self.migrate_owned_balances()?;
+                version = DB_VERSION;
+            }
             if version != DB_VERSION {
                 return Err(DatabaseError::InvalidDatabaseVersion {
                     found: version,
@@ -45,6 +65,21 @@ impl Database {
         Ok(())
     }
 
+    /// Builds the balances of the owners from the existing coins and messages
+    /// and updates the version of the database in one database transaction.
+    fn migrate_owned_balances(&self) -> DatabaseResult<()> {
+        let mut transaction = self.transaction();
+        let database = transaction.as_mut();
+        database.index_owned_coins_balances()?;
+        database.index_owned_messages_balances()?;
+        let _: Option<u32> =
+            database.insert(DB_VERSION_KEY, Column::Metadata, &DB_VERSION)?;
+        transaction
+            .commit()
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
     pub fn get_chain_name(&self) -> DatabaseResult<Option<String>> {
         self.get(CHAIN_NAME_KEY, Column::Metadata)
     }
@@ -63,4 +98,171 @@ impl Database {
         self.get(TX_COUNT, Column::Metadata)
             .map(|v| v.unwrap_or_default())
     }
+
+    pub fn get_time_offset(&self) -> DatabaseResult<u64> {
+        self.get(TIME_OFFSET, Column::Metadata)
+            .map(|v| v.unwrap_or_default())
+    }
+
+    pub fn set_time_offset(&self, time_offset: u64) -> DatabaseResult<()> {
+        self.insert::<_, _, u64>(TIME_OFFSET, Column::Metadata, &time_offset)?;
+        Ok(())
+    }
+
+    pub fn get_gas_price_algorithm_state(
+        &self,
+    ) -> DatabaseResult<Option<AlgorithmState>> {
+        self.get(GAS_PRICE_ALGORITHM_STATE, Column::Metadata)
+    }
+
+    pub fn set_gas_price_algorithm_state(
+        &self,
+        state: &AlgorithmState,
+    ) -> DatabaseResult<()> {
+        self.insert::<_, _, AlgorithmState>(
+            GAS_PRICE_ALGORITHM_STATE,
+            Column::Metadata,
+            state,
+        )?;
+        Ok(())
+    }
+
+    pub fn get_last_cosigned(&self) -> DatabaseResult<Option<(BlockHeight, BlockId)>> {
+        self.get(LAST_COSIGNED, Column::Metadata)
+    }
+
+    pub fn set_last_cosigned(
+        &self,
+        height: BlockHeight,
+        id: BlockId,
+    ) -> DatabaseResult<()> {
+        self.insert::<_, _, (BlockHeight, BlockId)>(
+            LAST_COSIGNED,
+            Column::Metadata,
+            &(height, id),
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::Coins,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+        fuel_types::{
+            Address,
+            AssetId,
+        },
+    };
+
+    #[test]
+    fn init_migrates_owned_balances() {
+        let mut db = Database::default();
+        let owner = Address::new([1; 32]);
+        let asset_id = AssetId::new([2; 32]);
+        let coin = CompressedCoin {
+            owner,
+            amount: 100,
+            asset_id,
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        };
+        db.storage::<Coins>()
+            .insert(&UtxoId::new([1; 32].into(), 0), &coin)
+            .unwrap();
+        // Simulate the database created before the balances were indexed.
+        let _: Option<u64> = db
+            .remove(
+                &[owner.as_ref(), asset_id.as_ref()].concat(),
+                Column::OwnedCoinsBalances,
+            )
+            .unwrap();
+        let _: Option<u32> = db
+            .insert(
+                DB_VERSION_KEY,
+                Column::Metadata,
+                &DB_VERSION_WITHOUT_OWNED_BALANCES,
+            )
+            .unwrap();
+        assert_eq!(db.owned_coins_balance(&owner, &asset_id).unwrap(), 0);
+
+        db.init(&ChainConfig::local_testnet()).unwrap();
+
+        assert_eq!(db.owned_coins_balance(&owner, &asset_id).unwrap(), 100);
+        assert_eq!(
+            db.get::<u32>(DB_VERSION_KEY, Column::Metadata).unwrap(),
+            Some(DB_VERSION)
+        );
+    }
+
+    #[cfg(feature = "rocksdb")]
+    #[test]
+    fn init_migrates_owned_balances_of_database_without_new_columns() {
+        use crate::state::{
+            rocks_db::RocksDb,
+            Durability,
+        };
+        use std::sync::Arc;
+
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let owner = Address::new([1; 32]);
+        let asset_id = AssetId::new([2; 32]);
+        {
+            // The columns of the database before the balances were indexed.
+            let columns = enum_iterator::all::<Column>()
+                .take_while(|column| {
+                    column.as_usize() <= Column::ContractsStateMerkleMetadata.as_usize()
+                })
+                .collect();
+            let db = Database::new(Arc::new(
+                RocksDb::open(tmp_dir.path(), columns, None).unwrap(),
+            ));
+            let coin = CompressedCoin {
+                owner,
+                amount: 100,
+                asset_id,
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            };
+            let _: Option<CompressedCoin> =
+                db.insert([1; 33], Column::Coins, &coin).unwrap();
+            let _: Option<u32> = db
+                .insert(
+                    DB_VERSION_KEY,
+                    Column::Metadata,
+                    &DB_VERSION_WITHOUT_OWNED_BALANCES,
+                )
+                .unwrap();
+        }
+
+        let db = Database::open(tmp_dir.path(), None, Durability::default()).unwrap();
+        db.init(&ChainConfig::local_testnet()).unwrap();
+
+        assert_eq!(db.owned_coins_balance(&owner, &asset_id).unwrap(), 100);
+        assert_eq!(
+            db.get::<u32>(DB_VERSION_KEY, Column::Metadata).unwrap(),
+            Some(DB_VERSION)
+        );
+    }
+
+    #[test]
+    fn init_rejects_unknown_version() {
+        let db = Database::default();
+        let _: Option<u32> = db
+            .insert(DB_VERSION_KEY, Column::Metadata, &(DB_VERSION + 1))
+            .unwrap();
+
+        let result = db.init(&ChainConfig::local_testnet());
+
+        assert!(matches!(
+            result,
+            Err(DatabaseError::InvalidDatabaseVersion { .. })
+        ));
+    }
 }
@@ -1,5 +1,15 @@
-use crate::database::Column;
-use fuel_core_relayer::ports::RelayerMetadata;
+use crate::database::{
+    Column,
+    Database,
+    Result as DatabaseResult,
+};
+use fuel_core_relayer::ports::{
+    DaFeeSamples,
+    OriginMessages,
+    OriginRelayerMetadata,
+    RelayerMetadata,
+};
+use fuel_core_types::entities::message::Message;
 
 use super::storage::DatabaseColumn;
 
@@ -8,3 +18,87 @@ impl DatabaseColumn for RelayerMetadata {
         Column::RelayerMetadata
     }
 }
+
+impl DatabaseColumn for OriginRelayerMetadata {
+    fn column() -> Column {
+        Column::OriginRelayerMetadata
+    }
+}
+
+impl DatabaseColumn for OriginMessages {
+    fn column() -> Column {
+        Column::OriginMessages
+    }
+}
+
+impl DatabaseColumn for DaFeeSamples {
+    fn column() -> Column {
+        Column::DaFeeSamples
+    }
+}
+
+impl Database {
+    /// Returns the messages sent from the additional DA network with the
+    /// `origin_chain_id`, ordered by the nonce.
+    pub fn origin_messages(
+        &self,
+        origin_chain_id: u64,
+    ) -> impl Iterator<Item = DatabaseResult<Message>> + '_ {
+        self.iter_all_by_prefix::<Vec<u8>, Message, _>(
+            Column::OriginMessages,
+            Some(origin_chain_id.to_be_bytes()),
+        )
+        .map(|res| res.map(|(_, message)| message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_relayer::ports::{
+        origin_message_key,
+        RelayerDb,
+    };
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::services::relayer::DaFeeSample;
+
+    #[test]
+    fn origin_messages_are_namespaced_by_chain_id() {
+        let mut db = Database::default();
+        for (origin_chain_id, nonce) in [(1u64, 2u64), (2, 1), (1, 1)] {
+            let message = Message {
+                nonce: nonce.into(),
+                amount: origin_chain_id,
+                ..Default::default()
+            };
+            let key = origin_message_key(origin_chain_id, &message.nonce);
+            db.storage::<OriginMessages>()
+                .insert(&key, &message)
+                .unwrap();
+        }
+
+        let messages = db
+            .origin_messages(1)
+            .map(|message| message.map(|message| (message.amount, message.nonce)))
+            .collect::<DatabaseResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(messages, vec![(1, 1u64.into()), (1, 2u64.into())]);
+    }
+
+    #[test]
+    fn da_fee_samples_are_recorded_in_order() {
+        let mut db = Database::default();
+        let sample = |da_height: u64| DaFeeSample {
+            da_height: da_height.into(),
+            base_fee: 10,
+            blob_base_fee: Some(1),
+        };
+
+        for da_height in [1, 2, 2, 3] {
+            db.insert_da_fee_sample(&sample(da_height), 2).unwrap();
+        }
+
+        assert_eq!(db.get_da_fee_samples().unwrap(), vec![sample(2), sample(3)]);
+    }
+}
@@ -0,0 +1,898 @@
+use crate::{
+    database::{
+        Column,
+        Database,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::WriteOperation,
+};
+use fuel_core_chain_config::ContractConfig;
+use fuel_core_storage::{
+    tables::{
+        ContractsInfo,
+        ContractsState,
+    },
+    ContractsStateKey,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    entities::contract::ContractUtxoInfo,
+    fuel_tx::UtxoId,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Nonce,
+    },
+    services::graphql_api::{
+        BlockStateChanges,
+        ContractSlotChange,
+    },
+};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::BTreeMap,
+    str::FromStr,
+    sync::Arc,
+};
+
+pub use fuel_core_types::services::executor::{
+    StateChange,
+    StateDiff,
+};
+
+/// The maximum number of blocks below the latest one whose state can be restored
+/// from the recorded state diffs. It bounds the number of the diffs read per query.
+pub const MAX_HISTORICAL_DEPTH: u32 = 10_000;
+
+impl Database {
+    /// Returns the state diff of the block at the `height`, if it is recorded.
+    pub fn state_diff(&self, height: &BlockHeight) -> DatabaseResult<Option<StateDiff>> {
+        self.get(&height.to_bytes()[..], Column::StateDiffs)
+    }
+
+    /// Returns the keys changed by the block at the `height` decoded from its state
+    /// diff, or `None` if the diff is not recorded.
+    pub fn state_changes(
+        &self,
+        height: &BlockHeight,
+    ) -> DatabaseResult<Option<BlockStateChanges>> {
+        self.state_diff(height)?
+            .map(|diff| decode_state_changes(&diff))
+            .transpose()
+    }
+
+    /// Returns the uncommitted changes of the database transaction as the state diff
+    /// of the block at the `height`.
+    pub fn uncommitted_state_diff(
+        &self,
+        height: BlockHeight,
+    ) -> DatabaseResult<StateDiff> {
+        let mut changes = self
+            .data
+            .uncommitted_changes()
+            .ok_or_else(|| {
+                DatabaseError::Other(anyhow::anyhow!(
+                    "The state diff can be recorded only inside of the transaction"
+                ))
+            })?
+            .into_iter()
+            .map(|(column, key, op)| {
+                let previous = self.data.committed_get(&key, column)?;
+                Ok(StateChange {
+                    column: column.as_ref().to_string(),
+                    key,
+                    value: match op {
+                        WriteOperation::Insert(value) => Some(value.as_ref().clone()),
+                        WriteOperation::Remove => None,
+                    },
+                    previous: previous.map(|value| value.as_ref().clone()),
+                })
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        changes.sort_by(|a, b| (&a.column, &a.key).cmp(&(&b.column, &b.key)));
+        Ok(StateDiff { height, changes })
+    }
+
+    /// Records the uncommitted changes of the database transaction as the state diff
+    /// of the block at the `height` and removes the diffs older than `retention` blocks.
+    pub fn record_state_diff(
+        &self,
+        height: BlockHeight,
+        retention: u32,
+    ) -> DatabaseResult<()> {
+        let diff = self.uncommitted_state_diff(height)?;
+        let _: Option<StateDiff> =
+            self.insert(&height.to_bytes()[..], Column::StateDiffs, &diff)?;
+
+        if let Some(expired) = u32::from(height).checked_sub(retention) {
+            let expired = BlockHeight::from(expired);
+            let _: Option<StateDiff> =
+                self.remove(&expired.to_bytes()[..], Column::StateDiffs)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the changes of the `diff` into the database at once.
+    pub fn apply_state_diff(&self, diff: &StateDiff) -> DatabaseResult<()> {
+        let changes = diff
+            .changes
+            .iter()
+            .map(|change| {
+                let column = Column::from_str(&change.column).map_err(|_| {
+                    DatabaseError::Other(anyhow::anyhow!(
+                        "Unknown column `{}` in the state diff",
+                        change.column
+                    ))
+                })?;
+                let op = match &change.value {
+                    Some(value) => WriteOperation::Insert(Arc::new(value.clone())),
+                    None => WriteOperation::Remove,
+                };
+                Ok((change.key.clone(), column, op))
+            })
+            .collect::<DatabaseResult<Vec<_>>>()?;
+        self.data.batch_write(&mut changes.into_iter())
+    }
+
+    /// Returns the values of the storage `slots` of the `contract` after the block
+    /// at the `height`, or `None` if the contract didn't exist at the `height`.
+    /// The slots changed after the `height` are restored from the previous values
+    /// of their first changes in the recorded state diffs.
+    pub fn contract_state_at(
+        &self,
+        contract: &ContractId,
+        slots: &[Bytes32],
+        height: BlockHeight,
+    ) -> StorageResult<Option<Vec<Option<Bytes32>>>> {
+        let latest = self.historical_latest_height(height)?;
+        // The contracts are never removed.
+        if !self.storage::<ContractsInfo>().contains_key(contract)? {
+            return Ok(None)
+        }
+        let info_column = Column::ContractsInfo.as_ref();
+        let state_column = Column::ContractsState.as_ref();
+        let keys: Vec<_> = slots
+            .iter()
+            .map(|slot| ContractsStateKey::new(contract, slot))
+            .collect();
+
+        // The first change after the `height` knows the value at the `height`.
+        let mut values: Vec<Option<Option<Bytes32>>> = vec![None; keys.len()];
+        for diff_height in u32::from(height) + 1..=u32::from(latest) {
+            let diff = self.recorded_state_diff(diff_height.into(), height)?;
+            if let Some(change) = find_change(&diff, info_column, contract.as_ref()) {
+                if change.previous.is_none() {
+                    // The contract is created after the `height`.
+                    return Ok(None)
+                }
+            }
+            for (key, value) in keys.iter().zip(values.iter_mut()) {
+                if value.is_some() {
+                    continue
+                }
+                if let Some(change) = find_change(&diff, state_column, key.as_ref()) {
+                    *value = Some(change.previous.as_deref().map(decode).transpose()?);
+                }
+            }
+        }
+
+        // The slots not changed after the `height` still have the same values.
+        let mut result = Vec::with_capacity(keys.len());
+        for (key, value) in keys.iter().zip(values) {
+            let value = match value {
+                Some(value) => value,
+                None => self
+                    .storage::<ContractsState>()
+                    .get(key)?
+                    .map(|value| *value),
+            };
+            result.push(value);
+        }
+        Ok(Some(result))
+    }
+
+    /// Returns the config of the `contract` with its bytecode, storage slots and
+    /// balances after the block at the `height`, or `None` if the contract didn't exist.
+    /// The values changed after the `height` are restored from the previous values
+    /// of their first changes in the recorded state diffs.
+    ///
+    /// If `max_entries` is set, it fails when the contract has more storage slots
+    /// and balances, without loading them.
+    pub fn contract_config_at(
+        &self,
+        contract: &ContractId,
+        height: BlockHeight,
+        max_entries: Option<usize>,
+    ) -> StorageResult<Option<ContractConfig>> {
+        let latest = self.historical_latest_height(height)?;
+        // The contracts are never removed.
+        if !self.storage::<ContractsInfo>().contains_key(contract)? {
+            return Ok(None)
+        }
+        if let Some(max_entries) = max_entries {
+            self.check_contract_entries(contract, max_entries)?;
+        }
+
+        // The first change after the `height` knows the value at the `height`.
+        let mut restored = BTreeMap::new();
+        for diff_height in u32::from(height) + 1..=u32::from(latest) {
+            let diff = self.recorded_state_diff(diff_height.into(), height)?;
+            for change in diff.changes.iter() {
+                let Some(key) = contract_key(change, contract) else {
+                    continue
+                };
+                if key == ContractKey::Info && change.previous.is_none() {
+                    // The contract is created after the `height`.
+                    return Ok(None)
+                }
+                restored.entry(key).or_insert(change.previous.as_deref());
+            }
+            if let Some(max_entries) = max_entries {
+                if restored.len() > max_entries {
+                    return Err(Self::too_many_entries(contract, max_entries).into())
+                }
+            }
+        }
+
+        let mut config = self.get_contract_config_by_id(*contract)?;
+        let mut state: BTreeMap<Bytes32, Bytes32> =
+            config.state.take().into_iter().flatten().collect();
+        let mut balances: BTreeMap<AssetId, u64> =
+            config.balances.take().into_iter().flatten().collect();
+        for (key, value) in restored {
+            match key {
+                ContractKey::Slot(slot) => restore(&mut state, slot, value)?,
+                ContractKey::Asset(asset) => restore(&mut balances, asset, value)?,
+                ContractKey::LatestUtxo => {
+                    let Some(ContractUtxoInfo {
+                        utxo_id,
+                        tx_pointer,
+                    }) = value.map(decode::<ContractUtxoInfo>).transpose()?
+                    else {
+                        continue
+                    };
+                    config.tx_id = Some(*utxo_id.tx_id());
+                    config.output_index = Some(utxo_id.output_index());
+                    config.tx_pointer_block_height = Some(tx_pointer.block_height());
+                    config.tx_pointer_tx_idx = Some(tx_pointer.tx_index());
+                }
+                ContractKey::Info => {}
+            }
+        }
+        if let Some(max_entries) = max_entries {
+            if state.len() + balances.len() > max_entries {
+                return Err(Self::too_many_entries(contract, max_entries).into())
+            }
+        }
+        config.state = Some(state.into_iter().collect());
+        config.balances = Some(balances.into_iter().collect());
+        Ok(Some(config))
+    }
+
+    /// Fails if the `contract` has more than `max_entries` storage slots and balances.
+    fn check_contract_entries(
+        &self,
+        contract: &ContractId,
+        max_entries: usize,
+    ) -> StorageResult<()> {
+        let slots = self
+            .iter_all_by_prefix::<Vec<u8>, Bytes32, _>(
+                Column::ContractsState,
+                Some(contract.as_ref()),
+            )
+            .take(max_entries.saturating_add(1))
+            .count();
+        let balances = self
+            .iter_all_by_prefix::<Vec<u8>, u64, _>(
+                Column::ContractsAssets,
+                Some(contract.as_ref()),
+            )
+            .take(max_entries.saturating_add(1))
+            .count();
+        if slots.saturating_add(balances) > max_entries {
+            return Err(Self::too_many_entries(contract, max_entries).into())
+        }
+        Ok(())
+    }
+
+    fn too_many_entries(contract: &ContractId, max_entries: usize) -> DatabaseError {
+        DatabaseError::Other(anyhow::anyhow!(
+            "The contract {contract} has more than {max_entries} storage slots and balances"
+        ))
+    }
+
+    /// Returns the latest height if the state at the `height` can be restored from
+    /// the recorded state diffs, or an error if the `height` is out of the range.
+    fn historical_latest_height(
+        &self,
+        height: BlockHeight,
+    ) -> StorageResult<BlockHeight> {
+        let latest = self.latest_height()?;
+        if height > latest {
+            return Err(anyhow::anyhow!(
+                "The height {height} is above the latest block {latest}"
+            )
+            .into())
+        }
+        if u32::from(latest) - u32::from(height) > MAX_HISTORICAL_DEPTH {
+            return Err(anyhow::anyhow!(
+                "The height {height} is more than {MAX_HISTORICAL_DEPTH} blocks \
+                 below the latest block {latest}"
+            )
+            .into())
+        }
+        Ok(latest)
+    }
+
+    /// Returns the state diff at the `height` required to restore the state
+    /// at the `target` height.
+    fn recorded_state_diff(
+        &self,
+        height: BlockHeight,
+        target: BlockHeight,
+    ) -> DatabaseResult<StateDiff> {
+        self.state_diff(&height)?
+            .ok_or_else(|| Self::too_old(target))
+    }
+
+    fn too_old(height: BlockHeight) -> DatabaseError {
+        DatabaseError::Other(anyhow::anyhow!(
+            "The state at the height {height} is older than the recorded state diffs"
+        ))
+    }
+}
+
+/// Decodes the changes of the coins, the contract slots and the spent messages of the
+/// `diff`. The changes of the rest of the columns are skipped.
+fn decode_state_changes(diff: &StateDiff) -> DatabaseResult<BlockStateChanges> {
+    let mut changes = BlockStateChanges {
+        height: diff.height,
+        ..Default::default()
+    };
+    for change in diff.changes.iter() {
+        let Ok(column) = Column::from_str(&change.column) else {
+            continue
+        };
+        let key = change.key.as_slice();
+        match column {
+            Column::Coins => {
+                // The key of the coin is the id of the transaction and the output index.
+                let (tx_id, output_index) = match key {
+                    [tx_id @ .., output_index] => (tx_id, *output_index),
+                    [] => return Err(DatabaseError::Codec),
+                };
+                let tx_id = Bytes32::try_from(tx_id).map_err(|_| DatabaseError::Codec)?;
+                let utxo_id = UtxoId::new(tx_id, output_index);
+                match change.value {
+                    Some(_) => changes.coins_created.push(utxo_id),
+                    None => changes.coins_spent.push(utxo_id),
+                }
+            }
+            Column::ContractsState => {
+                if key.len() != ContractId::LEN + Bytes32::LEN {
+                    return Err(DatabaseError::Codec)
+                }
+                let (contract_id, slot) = key.split_at(ContractId::LEN);
+                let value = change
+                    .value
+                    .as_ref()
+                    .map(|value| postcard::from_bytes(value))
+                    .transpose()
+                    .map_err(|_| DatabaseError::Codec)?;
+                changes.contract_slots.push(ContractSlotChange {
+                    contract_id: ContractId::try_from(contract_id)
+                        .map_err(|_| DatabaseError::Codec)?,
+                    slot: Bytes32::try_from(slot).map_err(|_| DatabaseError::Codec)?,
+                    value,
+                });
+            }
+            Column::SpentMessages if change.value.is_some() => {
+                let nonce = Nonce::try_from(key).map_err(|_| DatabaseError::Codec)?;
+                changes.messages_consumed.push(nonce);
+            }
+            _ => {}
+        }
+    }
+    Ok(changes)
+}
+
+/// The value of the contract changed by the state diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ContractKey {
+    Info,
+    LatestUtxo,
+    Slot(Bytes32),
+    Asset(AssetId),
+}
+
+/// Returns the value of the `contract` changed by the `change`, if any.
+fn contract_key(change: &StateChange, contract: &ContractId) -> Option<ContractKey> {
+    let column = Column::from_str(&change.column).ok()?;
+    let key = change.key.as_slice();
+    match column {
+        Column::ContractsInfo if key == contract.as_ref() => Some(ContractKey::Info),
+        Column::ContractsLatestUtxo if key == contract.as_ref() => {
+            Some(ContractKey::LatestUtxo)
+        }
+        Column::ContractsState | Column::ContractsAssets => {
+            let suffix: [u8; 32] =
+                key.strip_prefix(contract.as_ref())?.try_into().ok()?;
+            if column == Column::ContractsState {
+                Some(ContractKey::Slot(suffix.into()))
+            } else {
+                Some(ContractKey::Asset(suffix.into()))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn decode<V: DeserializeOwned>(value: &[u8]) -> DatabaseResult<V> {
+    postcard::from_bytes(value).map_err(|_| DatabaseError::Codec)
+}
+
+/// Sets the `key` of the `values` to the encoded `value`, or removes it.
+fn restore<K: Ord, V: DeserializeOwned>(
+    values: &mut BTreeMap<K, V>,
+    key: K,
+    value: Option<&[u8]>,
+) -> DatabaseResult<()> {
+    match value {
+        Some(value) => {
+            values.insert(key, decode(value)?);
+        }
+        None => {
+            values.remove(&key);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the change of the `key` in the `column`, the changes of the diff are sorted.
+fn find_change<'a>(
+    diff: &'a StateDiff,
+    column: &str,
+    key: &[u8],
+) -> Option<&'a StateChange> {
+    diff.changes
+        .binary_search_by(|change| {
+            (change.column.as_str(), change.key.as_slice()).cmp(&(column, key))
+        })
+        .ok()
+        .map(|index| &diff.changes[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        tables::{
+            Coins,
+            ContractsAssets,
+            ContractsLatestUtxo,
+            ContractsRawCode,
+            FuelBlocks,
+            SpentMessages,
+        },
+        transactional::Transaction,
+        ContractsAssetKey,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+        },
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::{
+            Contract,
+            Salt,
+            TxPointer,
+            UtxoId,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn coin(amount: u64) -> CompressedCoin {
+        CompressedCoin {
+            owner: Default::default(),
+            amount,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        }
+    }
+
+    #[test]
+    fn recorded_diff_reproduces_changes_of_transaction() {
+        let mut upstream = Database::default();
+        let mut follower = Database::default();
+        let spent = UtxoId::new([1; 32].into(), 0);
+        let created = UtxoId::new([2; 32].into(), 0);
+        for database in [&mut upstream, &mut follower] {
+            database
+                .storage::<Coins>()
+                .insert(&spent, &coin(10))
+                .unwrap();
+        }
+
+        let mut transaction = upstream.transaction();
+        let database = transaction.as_mut();
+        database.storage::<Coins>().remove(&spent).unwrap();
+        database
+            .storage::<Coins>()
+            .insert(&created, &coin(20))
+            .unwrap();
+        database.record_state_diff(1u32.into(), 10).unwrap();
+        transaction.commit().unwrap();
+
+        let diff = upstream.state_diff(&1u32.into()).unwrap().unwrap();
+        assert_eq!(diff.height, 1u32.into());
+        // Only the spent coin existed before the block.
+        let previous: Vec<_> = diff
+            .changes
+            .iter()
+            .map(|change| change.previous.is_some())
+            .collect();
+        assert_eq!(previous, vec![true, false]);
+        follower.apply_state_diff(&diff).unwrap();
+
+        assert!(follower.storage::<Coins>().get(&spent).unwrap().is_none());
+        assert_eq!(
+            follower
+                .storage::<Coins>()
+                .get(&created)
+                .unwrap()
+                .unwrap()
+                .amount,
+            20
+        );
+    }
+
+    #[test]
+    fn state_changes_are_decoded_from_diff() {
+        let mut database = Database::default();
+        let spent = UtxoId::new([1; 32].into(), 0);
+        let created = UtxoId::new([2; 32].into(), 3);
+        let contract_id = ContractId::from([3; 32]);
+        let slot = Bytes32::from([4; 32]);
+        let nonce = Nonce::from([5; 32]);
+        database
+            .storage::<Coins>()
+            .insert(&spent, &coin(10))
+            .unwrap();
+
+        let mut transaction = database.transaction();
+        let db = transaction.as_mut();
+        db.storage::<Coins>().remove(&spent).unwrap();
+        db.storage::<Coins>().insert(&created, &coin(20)).unwrap();
+        db.storage::<ContractsState>()
+            .insert(
+                &ContractsStateKey::new(&contract_id, &slot),
+                &[6; 32].into(),
+            )
+            .unwrap();
+        db.storage::<SpentMessages>().insert(&nonce, &()).unwrap();
+        db.record_state_diff(1u32.into(), 10).unwrap();
+        transaction.commit().unwrap();
+
+        assert_eq!(
+            database.state_changes(&1u32.into()).unwrap(),
+            Some(BlockStateChanges {
+                height: 1u32.into(),
+                coins_created: vec![created],
+                coins_spent: vec![spent],
+                contract_slots: vec![ContractSlotChange {
+                    contract_id,
+                    slot,
+                    value: Some([6; 32].into()),
+                }],
+                messages_consumed: vec![nonce],
+            })
+        );
+        assert_eq!(database.state_changes(&2u32.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn old_diffs_are_removed() {
+        let database = Database::default();
+
+        for height in 1..=3u32 {
+            let mut transaction = database.transaction();
+            transaction
+                .as_ref()
+                .record_state_diff(height.into(), 2)
+                .unwrap();
+            transaction.commit().unwrap();
+        }
+
+        assert!(database.state_diff(&1u32.into()).unwrap().is_none());
+        assert!(database.state_diff(&2u32.into()).unwrap().is_some());
+        assert!(database.state_diff(&3u32.into()).unwrap().is_some());
+    }
+
+    #[test]
+    fn diff_is_not_recorded_outside_of_transaction() {
+        let database = Database::default();
+
+        assert!(database.record_state_diff(1u32.into(), 10).is_err());
+    }
+
+    /// Imports the block at the `height` with the changes of the `write`
+    /// and records its state diff.
+    fn import_block_with(
+        database: &mut Database,
+        height: u32,
+        write: impl FnOnce(&mut Database),
+    ) {
+        let mut transaction = database.transaction();
+        let db = transaction.as_mut();
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader {
+                height: height.into(),
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+        db.storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&ChainId::default()))
+            .unwrap();
+        write(db);
+        db.record_state_diff(height.into(), 3).unwrap();
+        transaction.commit().unwrap();
+    }
+
+    /// Imports the block at the `height` with the `slots` written or removed
+    /// and records its state diff.
+    fn import_block(
+        database: &mut Database,
+        height: u32,
+        contract: &ContractId,
+        slots: &[(Bytes32, Option<Bytes32>)],
+    ) {
+        import_block_with(database, height, |db| {
+            for (slot, value) in slots {
+                let key = ContractsStateKey::new(contract, slot);
+                match value {
+                    Some(value) => {
+                        db.storage::<ContractsState>().insert(&key, value).unwrap();
+                    }
+                    None => {
+                        db.storage::<ContractsState>().remove(&key).unwrap();
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn contract_state_at_restores_the_historical_values() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let (first, second, third) = (
+            Bytes32::from([1; 32]),
+            Bytes32::from([2; 32]),
+            Bytes32::from([3; 32]),
+        );
+        let value = |byte| Some(Bytes32::from([byte; 32]));
+        import_block_with(&mut database, 1, |db| {
+            db.storage::<ContractsInfo>()
+                .insert(&contract, &(Salt::zeroed(), Default::default()))
+                .unwrap();
+        });
+        import_block(&mut database, 2, &contract, &[(first, value(10))]);
+        import_block(&mut database, 3, &contract, &[(second, value(20))]);
+        import_block(
+            &mut database,
+            4,
+            &contract,
+            &[(first, value(11)), (second, None), (third, value(30))],
+        );
+        let slots = [first, second, third];
+
+        assert_eq!(
+            database
+                .contract_state_at(&contract, &slots, 4u32.into())
+                .unwrap(),
+            Some(vec![value(11), None, value(30)])
+        );
+        // The slots created after the `height` didn't exist at it.
+        assert_eq!(
+            database
+                .contract_state_at(&contract, &slots, 3u32.into())
+                .unwrap(),
+            Some(vec![value(10), value(20), None])
+        );
+        assert_eq!(
+            database
+                .contract_state_at(&contract, &slots, 1u32.into())
+                .unwrap(),
+            Some(vec![None, None, None])
+        );
+        assert!(database
+            .contract_state_at(&contract, &slots, 5u32.into())
+            .is_err());
+    }
+
+    #[test]
+    fn contract_state_at_returns_none_before_the_contract_is_created() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let slot = Bytes32::from([1; 32]);
+        import_block(&mut database, 1, &contract, &[]);
+        import_block_with(&mut database, 2, |db| {
+            db.storage::<ContractsInfo>()
+                .insert(&contract, &(Salt::zeroed(), Default::default()))
+                .unwrap();
+        });
+
+        assert_eq!(
+            database
+                .contract_state_at(&contract, &[slot], 1u32.into())
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            database
+                .contract_state_at(&ContractId::from([2; 32]), &[slot], 2u32.into())
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn contract_state_at_fails_without_the_recorded_diffs() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        import_block_with(&mut database, 1, |db| {
+            db.storage::<ContractsInfo>()
+                .insert(&contract, &(Salt::zeroed(), Default::default()))
+                .unwrap();
+        });
+        for height in 2..=5 {
+            import_block(&mut database, height, &contract, &[]);
+        }
+
+        // The diffs are retained for 3 blocks only.
+        assert!(database
+            .contract_state_at(&contract, &[], 1u32.into())
+            .is_err());
+        assert!(database
+            .contract_state_at(&contract, &[], 2u32.into())
+            .is_ok());
+    }
+
+    #[test]
+    fn contract_config_at_restores_the_historical_contract() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let asset = AssetId::from([2; 32]);
+        let (first, second) = (Bytes32::from([1; 32]), Bytes32::from([2; 32]));
+        let utxo = |byte| ContractUtxoInfo {
+            utxo_id: UtxoId::new([byte; 32].into(), 0),
+            tx_pointer: TxPointer::new(u32::from(byte).into(), 0),
+        };
+        let bytecode = Contract::from(vec![1, 2, 3]);
+        import_block_with(&mut database, 1, |db| {
+            db.storage::<ContractsRawCode>()
+                .insert(&contract, bytecode.as_ref())
+                .unwrap();
+            db.storage::<ContractsInfo>()
+                .insert(&contract, &(Salt::zeroed(), bytecode.root()))
+                .unwrap();
+            db.storage::<ContractsLatestUtxo>()
+                .insert(&contract, &utxo(1))
+                .unwrap();
+            db.storage::<ContractsState>()
+                .insert(&ContractsStateKey::new(&contract, &first), &[10; 32].into())
+                .unwrap();
+            db.storage::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(&contract, &asset), &100)
+                .unwrap();
+        });
+        import_block_with(&mut database, 2, |db| {
+            db.storage::<ContractsLatestUtxo>()
+                .insert(&contract, &utxo(2))
+                .unwrap();
+            db.storage::<ContractsState>()
+                .insert(&ContractsStateKey::new(&contract, &first), &[11; 32].into())
+                .unwrap();
+            db.storage::<ContractsState>()
+                .insert(
+                    &ContractsStateKey::new(&contract, &second),
+                    &[20; 32].into(),
+                )
+                .unwrap();
+            db.storage::<ContractsAssets>()
+                .insert(&ContractsAssetKey::new(&contract, &asset), &50)
+                .unwrap();
+        });
+
+        let latest = database
+            .contract_config_at(&contract, 2u32.into(), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            latest.state,
+            Some(vec![(first, [11; 32].into()), (second, [20; 32].into())])
+        );
+        assert_eq!(latest.balances, Some(vec![(asset, 50)]));
+        assert_eq!(latest.tx_pointer_block_height, Some(2u32.into()));
+
+        let historical = database
+            .contract_config_at(&contract, 1u32.into(), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(historical.code, latest.code);
+        assert_eq!(historical.state, Some(vec![(first, [10; 32].into())]));
+        assert_eq!(historical.balances, Some(vec![(asset, 100)]));
+        assert_eq!(historical.tx_id, Some([1; 32].into()));
+        assert_eq!(historical.tx_pointer_block_height, Some(1u32.into()));
+
+        // The contract is created after the genesis block.
+        assert_eq!(
+            database
+                .contract_config_at(&contract, 0u32.into(), None)
+                .unwrap(),
+            None
+        );
+        assert!(database
+            .contract_config_at(&contract, 3u32.into(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn contract_config_at_restores_the_contract_created_before_the_diffs() {
+        let mut database = Database::default();
+        let contract = ContractId::from([1; 32]);
+        let slot = Bytes32::from([1; 32]);
+        let bytecode = Contract::from(vec![1, 2, 3]);
+        import_block_with(&mut database, 1, |db| {
+            db.storage::<ContractsRawCode>()
+                .insert(&contract, bytecode.as_ref())
+                .unwrap();
+            db.storage::<ContractsInfo>()
+                .insert(&contract, &(Salt::zeroed(), bytecode.root()))
+                .unwrap();
+            db.storage::<ContractsLatestUtxo>()
+                .insert(&contract, &ContractUtxoInfo::default())
+                .unwrap();
+        });
+        for height in 2..=5u8 {
+            import_block(
+                &mut database,
+                height.into(),
+                &contract,
+                &[(slot, Some([height; 32].into()))],
+            );
+        }
+
+        // The diff of the block that created the contract isn't retained anymore.
+        let config = database
+            .contract_config_at(&contract, 2u32.into(), None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(config.state, Some(vec![(slot, [2; 32].into())]));
+
+        assert!(database
+            .contract_config_at(&contract, 2u32.into(), Some(1))
+            .is_ok());
+        import_block(
+            &mut database,
+            6,
+            &contract,
+            &[([2; 32].into(), Some([6; 32].into()))],
+        );
+        assert!(database
+            .contract_config_at(&contract, 6u32.into(), Some(1))
+            .is_err());
+    }
+}
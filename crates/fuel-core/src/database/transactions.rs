@@ -19,8 +19,13 @@ use fuel_core_types::{
         Address,
         BlockHeight,
     },
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        ArchivedTransactionStatus,
+        TransactionStatus,
+    },
+    tai64::Tai64,
 };
+use itertools::Itertools;
 use std::{
     mem::size_of,
     ops::Deref,
@@ -98,6 +103,67 @@ impl Database {
     ) -> DatabaseResult<Option<TransactionStatus>> {
         self.get(&id.deref()[..], Column::TransactionStatus)
     }
+
+    /// Appends the `status` to the archived history of the transaction.
+    pub fn archive_tx_status(
+        &self,
+        id: &Bytes32,
+        status: TransactionStatus,
+        time: Tai64,
+    ) -> DatabaseResult<()> {
+        let mut history = self.get_tx_status_history(id)?.unwrap_or_default();
+        if history.is_empty() {
+            let _: Option<bool> = self.insert(
+                tx_status_expiry_key(time, id),
+                Column::TransactionStatusArchiveExpiry,
+                &true,
+            )?;
+        }
+        history.push(ArchivedTransactionStatus { time, status });
+        let _: Option<Vec<ArchivedTransactionStatus>> =
+            self.insert(id, Column::TransactionStatusArchive, &history)?;
+        Ok(())
+    }
+
+    /// Returns the archived history of the statuses of the transaction.
+    pub fn get_tx_status_history(
+        &self,
+        id: &Bytes32,
+    ) -> DatabaseResult<Option<Vec<ArchivedTransactionStatus>>> {
+        self.get(&id.deref()[..], Column::TransactionStatusArchive)
+    }
+
+    /// Removes the archived histories of the transactions with the first status recorded
+    /// before the `time`. Returns the number of removed histories.
+    pub fn prune_tx_status_archive(&self, time: Tai64) -> DatabaseResult<usize> {
+        let cutoff = time.0.to_be_bytes();
+        let expired: Vec<Vec<u8>> = self
+            .iter_all::<Vec<u8>, bool>(Column::TransactionStatusArchiveExpiry, None)
+            .map_ok(|(key, _)| key)
+            .take_while(|key| {
+                key.as_ref()
+                    .map(|key| key[..TAI64_SIZE] < cutoff[..])
+                    .unwrap_or(true)
+            })
+            .try_collect()?;
+
+        for key in expired.iter() {
+            let _: Option<bool> =
+                self.remove(key, Column::TransactionStatusArchiveExpiry)?;
+            let _: Option<Vec<ArchivedTransactionStatus>> =
+                self.remove(&key[TAI64_SIZE..], Column::TransactionStatusArchive)?;
+        }
+        Ok(expired.len())
+    }
+}
+
+const TAI64_SIZE: usize = size_of::<u64>();
+
+fn tx_status_expiry_key(time: Tai64, id: &Bytes32) -> Vec<u8> {
+    let mut key = Vec::with_capacity(TAI64_SIZE + Bytes32::LEN);
+    key.extend_from_slice(&time.0.to_be_bytes());
+    key.extend_from_slice(id.as_ref());
+    key
 }
 
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
@@ -184,3 +250,45 @@ impl From<OwnedTransactionIndexCursor> for Vec<u8> {
         bytes
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_tx_status_archive_removes_only_expired_histories() {
+        let db = Database::default();
+        let expired = Bytes32::new([1; 32]);
+        let retained = Bytes32::new([2; 32]);
+        let submitted = |time| TransactionStatus::Submitted { time };
+
+        db.archive_tx_status(&expired, submitted(Tai64(10)), Tai64(10))
+            .unwrap();
+        db.archive_tx_status(&retained, submitted(Tai64(20)), Tai64(20))
+            .unwrap();
+        // The later status doesn't extend the retention of the history.
+        db.archive_tx_status(
+            &expired,
+            TransactionStatus::SqueezedOut {
+                reason: "TTL".to_string(),
+            },
+            Tai64(30),
+        )
+        .unwrap();
+        assert_eq!(
+            db.get_tx_status_history(&expired).unwrap().unwrap().len(),
+            2
+        );
+
+        assert_eq!(db.prune_tx_status_archive(Tai64(15)).unwrap(), 1);
+        assert_eq!(db.get_tx_status_history(&expired).unwrap(), None);
+        assert_eq!(
+            db.get_tx_status_history(&retained).unwrap(),
+            Some(vec![ArchivedTransactionStatus {
+                time: Tai64(20),
+                status: submitted(Tai64(20)),
+            }])
+        );
+        assert_eq!(db.prune_tx_status_archive(Tai64(15)).unwrap(), 0);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    chain_config::SignedUpgradeDeclaration,
+    database::{
+        Column,
+        Database,
+        Result as DatabaseResult,
+    },
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use itertools::Itertools;
+
+impl Database {
+    /// Returns the upgrade declared at the `height`, if any.
+    pub fn upgrade(
+        &self,
+        height: &BlockHeight,
+    ) -> DatabaseResult<Option<SignedUpgradeDeclaration>> {
+        self.get(&height.to_bytes()[..], Column::Upgrades)
+    }
+
+    /// Stores the upgrade declaration, replacing the declaration at the same height.
+    pub fn insert_upgrade(
+        &self,
+        upgrade: &SignedUpgradeDeclaration,
+    ) -> DatabaseResult<()> {
+        let height = upgrade.declaration.height;
+        let _: Option<SignedUpgradeDeclaration> =
+            self.insert(&height.to_bytes()[..], Column::Upgrades, upgrade)?;
+        Ok(())
+    }
+
+    /// Returns the declared upgrades ordered by the height.
+    pub fn upgrades(&self) -> DatabaseResult<Vec<SignedUpgradeDeclaration>> {
+        self.iter_all::<Vec<u8>, SignedUpgradeDeclaration>(Column::Upgrades, None)
+            .map_ok(|(_, upgrade)| upgrade)
+            .try_collect()
+    }
+}
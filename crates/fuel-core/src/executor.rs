@@ -67,6 +67,8 @@ use fuel_core_types::{
         AssetId,
         Bytes32,
         Cacheable,
+        Chargeable,
+        CheckError,
         Input,
         Mint,
         Output,
@@ -107,17 +109,22 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            BlockLimits,
             Error as ExecutorError,
             ExecutionKind,
             ExecutionResult,
             ExecutionType,
             ExecutionTypes,
+            FeeShare,
             Result as ExecutorResult,
+            StateDiff,
             TransactionExecutionResult,
             TransactionExecutionStatus,
+            TransactionProfile,
             TransactionValidityError,
             UncommittedResult,
         },
+        graphql_api::BlockFees,
         txpool::TransactionStatus,
     },
 };
@@ -125,19 +132,27 @@ use fuel_core_types::{
 use parking_lot::Mutex as ParkingMutex;
 use std::{
     borrow::Cow,
+    iter,
     ops::{
         Deref,
         DerefMut,
     },
     sync::Arc,
+    time::Instant,
 };
 use tracing::{
     debug,
     warn,
 };
 
+pub mod execution_stats;
 mod ports;
+mod profiler;
 
+use execution_stats::{
+    ExecutionStats,
+    TransactionStats,
+};
 pub use ports::{
     MaybeCheckedTransaction,
     RelayerPort,
@@ -182,6 +197,8 @@ where
     pub database: Database,
     pub relayer: R,
     pub config: Arc<Config>,
+    /// Collects the execution time of the transactions. `None` for the dry runs.
+    pub execution_stats: Option<ExecutionStats>,
 }
 
 /// Data that is generated after executing all transactions.
@@ -191,6 +208,7 @@ struct ExecutionData {
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
     skipped_transactions: Vec<(TxId, ExecutorError)>,
+    tx_stats: Vec<TransactionStats>,
 }
 
 /// Per-block execution options
@@ -235,6 +253,7 @@ where
                 header_to_produce: block.header,
                 transactions_source: OnceTransactionsSource::new(block.transactions),
                 gas_limit: u64::MAX,
+                gas_price: 0,
             }),
             ExecutionTypes::Validation(block) => ExecutionTypes::Validation(block),
         };
@@ -253,6 +272,7 @@ impl Executor<Database> {
             relayer: database.clone(),
             database,
             config: Arc::new(config),
+            execution_stats: None,
         }
     }
 }
@@ -277,6 +297,38 @@ where
         component: Components<Transaction>,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: vec![component.transactions_source],
+            gas_limit: component.gas_limit,
+            gas_price: component.gas_price,
+        };
+
+        let (receipts, _) = self.dry_run_inner(component, utxo_validation)?;
+        // drop `temporary_db` without committing to avoid altering state.
+        Ok(receipts)
+    }
+
+    /// Executes the dry run of the ordered bundle of transactions. Each transaction
+    /// sees the changes made by the previous ones, and the changes of the whole bundle
+    /// are returned as the state diff.
+    pub fn dry_run_bundle(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)> {
+        let height = *component.header_to_produce.height();
+        let (receipts, temporary_db) = self.dry_run_inner(component, utxo_validation)?;
+        let diff = temporary_db.as_ref().uncommitted_state_diff(height)?;
+        // drop `temporary_db` without committing to avoid altering state.
+        Ok((receipts, diff))
+    }
+
+    fn dry_run_inner(
+        &self,
+        component: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StorageTransaction<Database>)> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
             utxo_validation.unwrap_or(self.config.utxo_validation_default);
@@ -285,10 +337,11 @@ where
 
         let component = Components {
             header_to_produce: component.header_to_produce,
-            transactions_source: OnceTransactionsSource::new(vec![
+            transactions_source: OnceTransactionsSource::new(
                 component.transactions_source,
-            ]),
+            ),
             gas_limit: component.gas_limit,
+            gas_price: component.gas_price,
         };
 
         let (
@@ -307,7 +360,7 @@ where
             return Err(err)
         }
 
-        block
+        let receipts = block
             .transactions()
             .iter()
             .map(|tx| {
@@ -317,9 +370,76 @@ where
                     .unwrap_or_else(|| Ok(Default::default()))
                     .map(|v| v.into_owned())
             })
-            .collect::<Result<Vec<Vec<Receipt>>, _>>()
-            .map_err(Into::into)
-        // drop `temporary_db` without committing to avoid altering state.
+            .collect::<Result<Vec<Vec<Receipt>>, _>>()?;
+        Ok((receipts, temporary_db))
+    }
+
+    /// Executes the dry run of the transaction and collects the profile of its script.
+    ///
+    /// The script is executed the second time in the single stepping mode on top of
+    /// the same state, because the interrupted execution doesn't produce the same
+    /// receipts as the regular one.
+    pub fn dry_run_profile(
+        &self,
+        component: Components<Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)> {
+        let header = component.header_to_produce.clone();
+        let transaction = component.transactions_source.clone();
+
+        let receipts = self
+            .dry_run(component, utxo_validation)?
+            .into_iter()
+            .flatten()
+            .collect();
+        let profile = self.profile(&header, transaction)?;
+
+        Ok((receipts, profile))
+    }
+
+    fn profile(
+        &self,
+        header: &PartialBlockHeader,
+        transaction: Transaction,
+    ) -> ExecutorResult<TransactionProfile> {
+        let chain_id = &self.config.consensus_parameters.chain_id;
+        let tx_id = transaction.id(chain_id);
+        let script = match transaction {
+            Transaction::Script(script) => script,
+            // Only scripts execute the bytecode.
+            Transaction::Create(_) => return Ok(TransactionProfile::default()),
+            Transaction::Mint(_) => {
+                return Err(ExecutorError::NotSupportedTransaction(tx_id))
+            }
+        };
+        let checked_tx = script
+            .into_checked_basic(*header.height(), &self.config.consensus_parameters)?;
+
+        // The database transaction is dropped without committing the changes.
+        let db_transaction = self.database.transaction();
+        let vm_db = VmDatabase::new(
+            db_transaction.as_ref().clone(),
+            &header.consensus,
+            self.config.coinbase_recipient,
+        );
+        let mut vm = Interpreter::with_storage(
+            vm_db,
+            InterpreterParams::from(&self.config.consensus_parameters),
+        );
+        vm.set_single_stepping(true);
+
+        let vm_error = |error| ExecutorError::VmExecution {
+            error: InterpreterError::Storage(anyhow::anyhow!(error)),
+            transaction_id: tx_id,
+        };
+        let mut profiler = profiler::Profiler::default();
+        let mut state = *vm.transact(checked_tx).map_err(vm_error)?.state();
+        while state.debug_ref().is_some() {
+            profiler.record(&vm);
+            state = vm.resume().map_err(vm_error)?;
+        }
+
+        Ok(profiler.finish(&vm))
     }
 }
 
@@ -330,6 +450,7 @@ mod private {
         pub empty_block: &'a mut PartialFuelBlock,
         pub transactions_source: TxSource,
         pub gas_limit: u64,
+        pub gas_price: u64,
         /// The private marker to allow creation of the type only by constructor.
         _marker: core::marker::PhantomData<()>,
     }
@@ -341,6 +462,7 @@ mod private {
                 empty_block: block,
                 transactions_source: OnceTransactionsSource::new(transaction),
                 gas_limit: u64::MAX,
+                gas_price: 0,
                 _marker: Default::default(),
             }
         }
@@ -351,12 +473,14 @@ mod private {
             block: &'a mut PartialFuelBlock,
             transactions_source: TxSource,
             gas_limit: u64,
+            gas_price: u64,
         ) -> Self {
             debug_assert!(block.transactions.is_empty());
             PartialBlockComponent {
                 empty_block: block,
                 transactions_source,
                 gas_limit,
+                gas_price,
                 _marker: Default::default(),
             }
         }
@@ -396,6 +520,7 @@ where
                     &mut block,
                     component.transactions_source,
                     component.gas_limit,
+                    component.gas_price,
                 );
 
                 let execution_data = self.execute_transactions(
@@ -412,6 +537,7 @@ where
                     &mut block,
                     component.transactions_source,
                     component.gas_limit,
+                    component.gas_price,
                 );
 
                 let execution_data = self.execute_transactions(
@@ -438,6 +564,7 @@ where
             message_ids,
             tx_status,
             skipped_transactions,
+            tx_stats,
         } = execution_data;
 
         // Now that the transactions have been executed, generate the full header.
@@ -461,6 +588,10 @@ where
             }
         }
 
+        if let Some(execution_stats) = &self.execution_stats {
+            execution_stats.record_block(*block.header().height(), &tx_stats);
+        }
+
         let result = ExecutionResult {
             block,
             skipped_transactions,
@@ -475,6 +606,13 @@ where
         // save the associated owner for each transaction in the block
         self.index_tx_owners_for_block(&result.block, &mut block_db_transaction)?;
 
+        // save the gas price and the fullness of the block for the fee history
+        self.persist_block_fees(
+            &result.block,
+            used_gas,
+            block_db_transaction.deref_mut(),
+        )?;
+
         // ------------ GraphQL API Functionality   END ------------
 
         // insert block into database
@@ -512,6 +650,7 @@ where
             message_ids: Vec::new(),
             tx_status: Vec::new(),
             skipped_transactions: Vec::new(),
+            tx_stats: Vec::new(),
         };
         let execution_data = &mut data;
 
@@ -519,7 +658,10 @@ where
         let (execution_kind, component) = block.split();
         let block = component.empty_block;
         let source = component.transactions_source;
-        let mut remaining_gas_limit = component.gas_limit;
+        let block_limits = self.config.block_limits;
+        let gas_limit = component.gas_limit.min(block_limits.gas);
+        let gas_price = component.gas_price;
+        let mut remaining_gas_limit = gas_limit;
 
         let block_height = *block.header.height();
 
@@ -535,11 +677,7 @@ where
                 // We will add actual amount of `Output::Coin` at the end of transactions execution.
                 Transaction::mint(
                     TxPointer::new(block_height, Default::default()),
-                    vec![Output::coin(
-                        self.config.coinbase_recipient,
-                        0, // We will set it later
-                        *self.config.consensus_parameters.base_asset_id(),
-                    )],
+                    self.coinbase_outputs(&self.split_fee(0)),
                 )
             }
             ExecutionKind::Validation => {
@@ -562,10 +700,53 @@ where
         } else {
             0
         };
+        let mut block_size = BlockLimits::size_of(block.transactions.iter());
 
         while iter.peek().is_some() {
             for transaction in iter {
+                let tx_size = transaction.size();
+                let size = block_size.saturating_add(tx_size);
+                if size > block_limits.size {
+                    let err = ExecutorError::BlockSizeLimitExceeded {
+                        size,
+                        limit: block_limits.size,
+                    };
+                    match execution_kind {
+                        ExecutionKind::Production => {
+                            // The transaction doesn't fit into the block, but the next
+                            // smaller transactions still may fit.
+                            execution_data.skipped_transactions.push((
+                                transaction
+                                    .id(&self.config.consensus_parameters.chain_id),
+                                err,
+                            ));
+                            continue
+                        }
+                        ExecutionKind::DryRun | ExecutionKind::Validation => {
+                            return Err(err)
+                        }
+                    }
+                }
+
+                // The gas price of the block is known only to its producer,
+                // so the validators don't check it.
+                if execution_kind == ExecutionKind::Production {
+                    let price = transaction.price().unwrap_or_default();
+                    if price < gas_price {
+                        execution_data.skipped_transactions.push((
+                            transaction.id(&self.config.consensus_parameters.chain_id),
+                            ExecutorError::GasPriceTooLow {
+                                price,
+                                required: gas_price,
+                            },
+                        ));
+                        continue
+                    }
+                }
+
                 let mut filter_tx = |tx: MaybeCheckedTransaction, idx| {
+                    let start = Instant::now();
+                    let used_gas_before = execution_data.used_gas;
                     let mut tx_db_transaction = block_db_transaction.transaction();
                     let tx_id = tx.id(&self.config.consensus_parameters.chain_id);
                     let result = self.execute_transaction(
@@ -606,6 +787,13 @@ where
                     if let Err(err) = tx_db_transaction.commit() {
                         return Some(Err(err.into()))
                     }
+                    if execution_kind != ExecutionKind::DryRun {
+                        execution_data.tx_stats.push(TransactionStats::new(
+                            &tx,
+                            start.elapsed(),
+                            execution_data.used_gas.saturating_sub(used_gas_before),
+                        ));
+                    }
                     Some(Ok(tx))
                 };
 
@@ -613,33 +801,35 @@ where
                 if let Some(result) = filtered_tx {
                     let tx = result?;
                     tx_index += 1;
+                    block_size = size;
                     block.transactions.push(tx);
                 }
             }
 
-            remaining_gas_limit =
-                component.gas_limit.saturating_sub(execution_data.used_gas);
+            remaining_gas_limit = gas_limit.saturating_sub(execution_data.used_gas);
 
             iter = source.next(remaining_gas_limit).into_iter().peekable();
         }
 
+        // The gas of the transactions is known only after the execution, so the block
+        // may exceed the limit if its producer ignored it.
+        if execution_data.used_gas > block_limits.gas {
+            return Err(ExecutorError::BlockGasLimitExceeded {
+                used: execution_data.used_gas,
+                limit: block_limits.gas,
+            })
+        }
+
         // After the execution of all transactions in production mode, we can set the final fee.
+        let amounts = self.split_fee(execution_data.coinbase);
         if execution_kind == ExecutionKind::Production {
-            coinbase_tx.outputs_mut().clear();
-            coinbase_tx.outputs_mut().push(Output::coin(
-                self.config.coinbase_recipient,
-                execution_data.coinbase,
-                *self.config.consensus_parameters.base_asset_id(),
-            ));
+            *coinbase_tx.outputs_mut() = self.coinbase_outputs(&amounts);
             block.transactions[0] = coinbase_tx.clone().into();
         }
 
         if execution_kind != ExecutionKind::DryRun {
-            coinbase_tx = self.check_coinbase(
-                block_height,
-                coinbase_tx,
-                Some(execution_data.coinbase),
-            )?;
+            coinbase_tx =
+                self.check_coinbase(block_height, coinbase_tx, Some(&amounts))?;
             self.apply_coinbase(
                 coinbase_tx,
                 block,
@@ -709,6 +899,42 @@ where
         }
     }
 
+    /// Splits the collected fee between the coinbase recipient and the fee shares.
+    /// Returns the amounts of the outputs of the coinbase transaction: the amount of
+    /// the coinbase recipient followed by the amount of each share.
+    ///
+    /// The amount of each share is rounded down, so the coinbase recipient receives
+    /// the remainder of the division.
+    fn split_fee(&self, fee: Word) -> Vec<Word> {
+        let mut remaining = fee;
+        let shares: Vec<_> = self
+            .config
+            .fee_shares
+            .iter()
+            .map(|share| {
+                let amount = (fee as u128)
+                    .saturating_mul(share.basis_points as u128)
+                    .checked_div(FeeShare::MAX_BASIS_POINTS as u128)
+                    .unwrap_or_default();
+                let amount = Word::try_from(amount).unwrap_or(Word::MAX).min(remaining);
+                remaining -= amount;
+                amount
+            })
+            .collect();
+        iter::once(remaining).chain(shares).collect()
+    }
+
+    /// Returns the outputs of the coinbase transaction with the `amounts`
+    /// returned by the [`Self::split_fee`].
+    fn coinbase_outputs(&self, amounts: &[Word]) -> Vec<Output> {
+        let base_asset_id = *self.config.consensus_parameters.base_asset_id();
+        iter::once(self.config.coinbase_recipient)
+            .chain(self.config.fee_shares.iter().map(|share| share.recipient))
+            .zip(amounts)
+            .map(|(recipient, amount)| Output::coin(recipient, *amount, base_asset_id))
+            .collect()
+    }
+
     fn apply_coinbase(
         &self,
         coinbase_tx: Mint,
@@ -744,41 +970,59 @@ where
         Ok(())
     }
 
+    /// Checks the coinbase transaction. Its first output pays the coinbase recipient,
+    /// and each next output pays the fee share of the chain. If the `expected_amounts`
+    /// are known, the outputs must pay them.
     fn check_coinbase(
         &self,
         block_height: BlockHeight,
-        mint: Mint,
-        expected_amount: Option<Word>,
+        mut mint: Mint,
+        expected_amounts: Option<&[Word]>,
     ) -> ExecutorResult<Mint> {
-        let checked_mint =
-            mint.into_checked(block_height, &self.config.consensus_parameters)?;
+        // The outputs of the fee shares have the same asset, which is forbidden by
+        // the rules of the `Mint` of the `fuel-tx`. So the rules are checked here.
+        if mint.tx_pointer().block_height() != block_height {
+            return Err(CheckError::TransactionMintIncorrectBlockHeight.into())
+        }
 
-        if checked_mint.transaction().tx_pointer().tx_index() != 0 {
+        if mint.tx_pointer().tx_index() != 0 {
             return Err(ExecutorError::CoinbaseIsNotFirstTransaction)
         }
 
-        if checked_mint.transaction().outputs().len() > 1 {
+        let fee_shares = &self.config.fee_shares;
+        if mint.outputs().len() > fee_shares.len() + 1 {
             return Err(ExecutorError::CoinbaseSeveralOutputs)
         }
+        if mint.outputs().len() != fee_shares.len() + 1 {
+            return Err(ExecutorError::CoinbaseOutputIsInvalid)
+        }
 
-        if let Some(Output::Coin {
-            asset_id, amount, ..
-        }) = checked_mint.transaction().outputs().first()
-        {
+        for (index, output) in mint.outputs().iter().enumerate() {
+            let Output::Coin {
+                to,
+                asset_id,
+                amount,
+            } = output
+            else {
+                return Err(ExecutorError::CoinbaseOutputIsInvalid)
+            };
             if asset_id != self.config.consensus_parameters.base_asset_id() {
                 return Err(ExecutorError::CoinbaseOutputIsInvalid)
             }
-
-            if let Some(expected_amount) = expected_amount {
-                if expected_amount != *amount {
+            // The first output pays the coinbase recipient chosen by the producer.
+            if let Some(share) = index.checked_sub(1).map(|index| &fee_shares[index]) {
+                if *to != share.recipient {
+                    return Err(ExecutorError::CoinbaseOutputIsInvalid)
+                }
+            }
+            if let Some(expected_amounts) = expected_amounts {
+                if expected_amounts.get(index) != Some(amount) {
                     return Err(ExecutorError::CoinbaseAmountMismatch)
                 }
             }
-        } else {
-            return Err(ExecutorError::CoinbaseOutputIsInvalid)
         }
 
-        let (mint, _) = checked_mint.into();
+        mint.precompute(&self.config.consensus_parameters.chain_id)?;
         Ok(mint)
     }
 
@@ -894,7 +1138,7 @@ where
         }
 
         // change the spent status of the tx inputs
-        self.spend_input_utxos(&tx, tx_db_transaction.deref_mut(), reverted)?;
+        self.spend_input_utxos(&tx, &tx_id, tx_db_transaction.deref_mut(), reverted)?;
 
         // Persist utxos first and after calculate the not utxo outputs
         self.persist_output_utxos(
@@ -1032,6 +1276,15 @@ where
                             TransactionValidityError::MessageAlreadySpent(*nonce).into()
                         )
                     }
+                    if self
+                        .relayer
+                        .is_message_cancelled(nonce, &block_da_height)
+                        .map_err(|e| ExecutorError::RelayerError(e.into()))?
+                    {
+                        return Err(
+                            TransactionValidityError::MessageCancelled(*nonce).into()
+                        )
+                    }
                     if let Some(message) = self
                         .relayer
                         .get_message(nonce, &block_da_height)
@@ -1096,6 +1349,7 @@ where
     fn spend_input_utxos<Tx>(
         &self,
         tx: &Tx,
+        tx_id: &TxId,
         db: &mut Database,
         reverted: bool,
     ) -> ExecutorResult<()>
@@ -1129,6 +1383,8 @@ where
                     }
                     // cleanup message contents
                     db.storage::<Messages>().remove(nonce)?;
+                    // index the spending transaction to track the deposit
+                    db.record_message_spender(nonce, tx_id)?;
                 }
                 _ => {}
             }
@@ -1601,10 +1857,22 @@ where
     ) -> ExecutorResult<()> {
         let mut owners = vec![];
         for input in inputs {
-            if let Input::CoinSigned(CoinSigned { owner, .. })
-            | Input::CoinPredicate(CoinPredicate { owner, .. }) = input
-            {
-                owners.push(owner);
+            match input {
+                Input::CoinSigned(CoinSigned { owner, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, .. }) => {
+                    owners.push(owner);
+                }
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient, ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient, ..
+                }) => {
+                    owners.push(recipient);
+                }
+                Input::Contract(_) => {}
             }
         }
 
@@ -1635,6 +1903,31 @@ where
         Ok(())
     }
 
+    fn persist_block_fees(
+        &self,
+        block: &Block,
+        gas_used: u64,
+        db: &Database,
+    ) -> ExecutorResult<()> {
+        let min_gas_price = block
+            .transactions()
+            .iter()
+            .filter_map(|tx| match tx {
+                Transaction::Script(script) => Some(script.price()),
+                Transaction::Create(create) => Some(create.price()),
+                Transaction::Mint(_) => None,
+            })
+            .min()
+            .unwrap_or_default();
+        db.insert_block_fees(&BlockFees {
+            height: *block.header().height(),
+            min_gas_price,
+            gas_used,
+            bytes_used: BlockLimits::size_of(block.transactions()),
+        })?;
+        Ok(())
+    }
+
     fn persist_transaction_status(
         &self,
         result: &ExecutionResult,
@@ -2043,6 +2336,7 @@ mod tests {
                             script.into()
                         ]),
                         gas_limit: u64::MAX,
+                        gas_price: 0,
                     }),
                     Default::default(),
                 )
@@ -2129,6 +2423,166 @@ mod tests {
             );
         }
 
+        fn fee_sharing_producer(fee_shares: Vec<FeeShare>) -> Executor<Database> {
+            let config = Config {
+                coinbase_recipient: [1u8; 32].into(),
+                fee_shares,
+                consensus_parameters: ConsensusParameters {
+                    fee_params: FeeParameters {
+                        gas_price_factor: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            Executor::test(Default::default(), config)
+        }
+
+        fn fee_paying_block() -> Block {
+            let script = TxBuilder::new(2322u64)
+                .gas_limit(0)
+                .gas_price(1)
+                .coin_input(AssetId::BASE, 10000)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone();
+            let mut block = Block::default();
+            *block.transactions_mut() = vec![script.into()];
+            block
+        }
+
+        #[test]
+        fn executor_splits_coinbase_between_fee_shares() {
+            let operator = [2u8; 32].into();
+            let treasury = [3u8; 32].into();
+            let producer = fee_sharing_producer(vec![
+                FeeShare {
+                    recipient: operator,
+                    basis_points: 2_500,
+                },
+                FeeShare {
+                    recipient: treasury,
+                    basis_points: 1_000,
+                },
+            ]);
+
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(fee_paying_block().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let mint = block.transactions()[0].as_mint().unwrap();
+            let mint_id = mint.id(&producer.config.consensus_parameters.chain_id);
+            let outputs: Vec<_> = mint
+                .outputs()
+                .iter()
+                .map(|output| match output {
+                    Output::Coin {
+                        to,
+                        amount,
+                        asset_id,
+                    } => {
+                        assert_eq!(asset_id, &AssetId::BASE);
+                        (*to, *amount)
+                    }
+                    _ => panic!("Invalid outputs of coinbase"),
+                })
+                .collect();
+            let fee: Word = outputs.iter().map(|(_, amount)| amount).sum();
+            assert!(fee > 0);
+            let coinbase_amount = fee - fee * 2_500 / 10_000 - fee / 10;
+            assert_eq!(
+                outputs,
+                vec![
+                    ([1u8; 32].into(), coinbase_amount),
+                    (operator, fee * 2_500 / 10_000),
+                    (treasury, fee / 10)
+                ]
+            );
+            // The shares are paid by the outputs, not by the receipts.
+            assert!(producer
+                .database
+                .storage::<Receipts>()
+                .get(&mint_id)
+                .unwrap()
+                .is_none());
+            for (index, (recipient, amount)) in outputs.into_iter().enumerate() {
+                let coin = producer
+                    .database
+                    .storage::<Coins>()
+                    .get(&UtxoId::new(mint_id, index as u8))
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(coin.owner, recipient);
+                assert_eq!(coin.amount, amount);
+                assert_eq!(coin.asset_id, AssetId::BASE);
+            }
+        }
+
+        #[test]
+        fn validator_accepts_coinbase_split_with_same_fee_shares() {
+            let fee_shares = vec![FeeShare {
+                recipient: [2u8; 32].into(),
+                basis_points: 3_333,
+            }];
+            let producer = fee_sharing_producer(fee_shares.clone());
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(fee_paying_block().into()),
+                    Default::default(),
+                )
+                .unwrap();
+            let produced_txs = block.transactions().to_vec();
+
+            let validator = fee_sharing_producer(fee_shares);
+            let ExecutionResult {
+                block: validated_block,
+                ..
+            } = validator
+                .execute_and_commit(ExecutionBlock::Validation(block), Default::default())
+                .unwrap();
+
+            assert_eq!(validated_block.transactions(), produced_txs);
+        }
+
+        #[test]
+        fn validator_rejects_coinbase_split_with_other_fee_shares() {
+            let producer = fee_sharing_producer(vec![FeeShare {
+                recipient: [2u8; 32].into(),
+                basis_points: 5_000,
+            }]);
+            let ExecutionResult { block, .. } = producer
+                .execute_and_commit(
+                    ExecutionBlock::Production(fee_paying_block().into()),
+                    Default::default(),
+                )
+                .unwrap();
+
+            let validator = fee_sharing_producer(vec![]);
+            let result = validator.execute_and_commit(
+                ExecutionBlock::Validation(block.clone()),
+                Default::default(),
+            );
+            assert!(matches!(result, Err(ExecutorError::CoinbaseSeveralOutputs)));
+
+            let validator = fee_sharing_producer(vec![FeeShare {
+                recipient: [3u8; 32].into(),
+                basis_points: 5_000,
+            }]);
+            let result = validator.execute_and_commit(
+                ExecutionBlock::Validation(block),
+                Default::default(),
+            );
+            assert!(matches!(
+                result,
+                Err(ExecutorError::CoinbaseOutputIsInvalid)
+            ));
+        }
+
         #[test]
         fn execute_cb_command() {
             fn compare_coinbase_addresses(
@@ -2733,6 +3187,114 @@ mod tests {
         ));
     }
 
+    fn script_with_gas(seed: u64) -> Transaction {
+        TxBuilder::new(seed)
+            .gas_limit(10_000)
+            .start_script(vec![op::ret(RegId::ONE)], vec![])
+            .coin_input(AssetId::BASE, 1000)
+            .change_output(AssetId::BASE)
+            .build()
+            .transaction()
+            .clone()
+            .into()
+    }
+
+    fn executor_with_limits(block_limits: BlockLimits) -> Executor<Database> {
+        Executor::test(
+            Database::default(),
+            Config {
+                block_limits,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn producer_skips_transactions_exceeding_block_size_limit() {
+        let first = script_with_gas(1);
+        let second = script_with_gas(2);
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![first.clone()],
+        };
+        let ExecutionResult { block, .. } = executor_with_limits(Default::default())
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+        let executor = executor_with_limits(BlockLimits {
+            size: BlockLimits::size_of(block.transactions()),
+            ..Default::default()
+        });
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![first, second.clone()],
+        };
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = executor
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        assert_eq!(block.transactions().len(), 2);
+        assert_eq!(skipped_transactions.len(), 1);
+        assert_eq!(skipped_transactions[0].0, second.id(&ChainId::default()));
+        assert!(matches!(
+            skipped_transactions[0].1,
+            ExecutorError::BlockSizeLimitExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn validator_rejects_block_exceeding_block_size_limit() {
+        let producer = executor_with_limits(Default::default());
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![script_with_gas(1), script_with_gas(2)],
+        };
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+        let size = BlockLimits::size_of(block.transactions());
+
+        let validator = executor_with_limits(BlockLimits {
+            size: size - 1,
+            ..Default::default()
+        });
+        let result = validator
+            .execute_and_commit(ExecutionBlock::Validation(block), Default::default());
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::BlockSizeLimitExceeded { limit, .. }) if limit == size - 1
+        ));
+    }
+
+    #[test]
+    fn validator_rejects_block_exceeding_block_gas_limit() {
+        let producer = executor_with_limits(Default::default());
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![script_with_gas(1)],
+        };
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(ExecutionBlock::Production(block), Default::default())
+            .unwrap();
+
+        let validator = executor_with_limits(BlockLimits {
+            gas: 0,
+            ..Default::default()
+        });
+        let result = validator
+            .execute_and_commit(ExecutionBlock::Validation(block), Default::default());
+
+        assert!(matches!(
+            result,
+            Err(ExecutorError::BlockGasLimitExceeded { used, limit: 0 }) if used > 0
+        ));
+    }
+
     #[test]
     fn skipped_tx_not_changed_spent_status() {
         // `tx2` has two inputs: one used by `tx1` and on random. So after the execution of `tx1`,
@@ -2893,6 +3455,65 @@ mod tests {
         assert_eq!(tx2_index_in_the_block, 1);
     }
 
+    #[test]
+    fn production_skips_txs_below_gas_price_of_block() {
+        let tx = |price| -> Transaction {
+            TxBuilder::new(2322u64)
+                .gas_price(price)
+                .coin_input(AssetId::BASE, 10000)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone()
+                .into()
+        };
+        let cheap_tx = tx(1);
+        let tx = tx(10);
+
+        let executor = Executor::test(Default::default(), Default::default());
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = executor
+            .execute_without_commit(
+                ExecutionTypes::Production(Components {
+                    header_to_produce: Default::default(),
+                    transactions_source: OnceTransactionsSource::new(vec![
+                        cheap_tx.clone(),
+                        tx.clone(),
+                    ]),
+                    gas_limit: u64::MAX,
+                    gas_price: 10,
+                }),
+                Default::default(),
+            )
+            .unwrap()
+            .into_result();
+
+        assert_eq!(
+            block.transactions().len(),
+            2 // coinbase and `tx`
+        );
+        assert_eq!(
+            block.transactions()[1].id(&ChainId::default()),
+            tx.id(&ChainId::default())
+        );
+        assert_eq!(skipped_transactions.len(), 1);
+        assert_eq!(
+            &skipped_transactions[0].0,
+            &cheap_tx.id(&ChainId::default())
+        );
+        assert!(matches!(
+            skipped_transactions[0].1,
+            ExecutorError::GasPriceTooLow {
+                price: 1,
+                required: 10
+            }
+        ));
+    }
+
     #[test]
     fn input_coins_are_marked_as_spent() {
         // ensure coins are marked as spent after tx is processed
@@ -3759,6 +4380,36 @@ mod tests {
             .expect("block validation failed unexpectedly");
     }
 
+    #[test]
+    fn message_input_indexes_transaction_by_recipient() {
+        let mut rng = StdRng::seed_from_u64(2322);
+
+        let (tx, message) = make_tx_and_message(&mut rng, 0);
+        let tx_id = tx.id(&ChainId::default());
+
+        let block = PartialFuelBlock {
+            header: Default::default(),
+            transactions: vec![tx],
+        };
+
+        let executor = make_executor(&[&message]);
+        executor
+            .execute_and_commit(
+                ExecutionBlock::Production(block),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+            .expect("block execution failed unexpectedly");
+
+        let owned_tx_ids: Vec<_> = executor
+            .database
+            .owned_transactions(message.recipient, None, None)
+            .map(|result| result.unwrap().1)
+            .collect();
+        assert_eq!(owned_tx_ids, vec![tx_id]);
+    }
+
     #[test]
     fn successful_execution_consume_all_messages() {
         let mut rng = StdRng::seed_from_u64(2322);
@@ -3939,6 +4590,69 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn message_fails_when_spending_cancelled_message() {
+        let mut rng = StdRng::seed_from_u64(2322);
+
+        let (tx, message) = make_tx_and_message(&mut rng, 0);
+        let make_executor = || {
+            let mut database = Database::default();
+            database
+                .storage::<Messages>()
+                .insert(message.id(), &message)
+                .unwrap();
+            database
+                .storage::<fuel_core_storage::tables::MessageCancellations>()
+                .insert(message.id(), &Default::default())
+                .unwrap();
+            Executor::test(
+                database,
+                Config {
+                    utxo_validation_default: true,
+                    ..Default::default()
+                },
+            )
+        };
+
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![tx.clone()];
+
+        let ExecutionResult {
+            skipped_transactions,
+            mut block,
+            ..
+        } = make_executor()
+            .execute_and_commit(
+                ExecutionBlock::Production(block.clone().into()),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+            .unwrap();
+        let err = &skipped_transactions[0].1;
+        assert!(matches!(
+            err,
+            &ExecutorError::TransactionValidity(
+                TransactionValidityError::MessageCancelled(_)
+            )
+        ));
+
+        // Invalidate block by returning back `tx` with the cancelled message
+        block.transactions_mut().push(tx);
+        let res = make_executor().execute_and_commit(
+            ExecutionBlock::Validation(block),
+            ExecutionOptions {
+                utxo_validation: true,
+            },
+        );
+        assert!(matches!(
+            res,
+            Err(ExecutorError::TransactionValidity(
+                TransactionValidityError::MessageCancelled(_)
+            ))
+        ));
+    }
+
     #[test]
     fn message_fails_when_spending_da_height_gt_block_da_height() {
         let mut rng = StdRng::seed_from_u64(2322);
@@ -0,0 +1,224 @@
+//! Collects the wall time and the gas of the transactions executed in the produced
+//! and validated blocks, so the operators can find the contracts that slow down
+//! the execution. The time of each transaction is attributed to every contract
+//! of its inputs.
+
+use fuel_core_metrics::execution_metrics::execution_metrics;
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            GasLimit,
+            Inputs,
+        },
+        ContractId,
+        Input,
+        Transaction,
+    },
+    fuel_types::BlockHeight,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+    time::Duration,
+};
+
+/// The number of the latest blocks used for the slowest contracts.
+pub const STATS_WINDOW_BLOCKS: usize = 1000;
+
+/// The execution of one transaction.
+#[derive(Clone, Debug)]
+pub(crate) struct TransactionStats {
+    pub duration: Duration,
+    pub gas_used: u64,
+    /// The gas limit of the script, the other transactions don't have the limit.
+    pub gas_limit: Option<u64>,
+    pub contracts: Vec<ContractId>,
+}
+
+impl TransactionStats {
+    pub fn new(tx: &Transaction, duration: Duration, gas_used: u64) -> Self {
+        let (inputs, gas_limit) = match tx {
+            Transaction::Script(script) => (script.inputs(), Some(*script.gas_limit())),
+            Transaction::Create(create) => (create.inputs(), None),
+            Transaction::Mint(_) => (&[][..], None),
+        };
+        let contracts = inputs
+            .iter()
+            .filter_map(|input| match input {
+                Input::Contract(contract) => Some(contract.contract_id),
+                _ => None,
+            })
+            .collect();
+        Self {
+            duration,
+            gas_used,
+            gas_limit,
+            contracts,
+        }
+    }
+}
+
+/// The aggregated executions of the transactions with the contract.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ContractExecutionStats {
+    pub contract_id: ContractId,
+    /// The number of the executed transactions with the contract.
+    pub transactions: u64,
+    pub gas_used: u64,
+    pub total_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Totals {
+    transactions: u64,
+    gas_used: u64,
+    total: Duration,
+    max: Duration,
+}
+
+impl Totals {
+    fn add(&mut self, other: &Totals) {
+        self.transactions += other.transactions;
+        self.gas_used = self.gas_used.saturating_add(other.gas_used);
+        self.total += other.total;
+        self.max = self.max.max(other.max);
+    }
+}
+
+struct BlockStats {
+    height: BlockHeight,
+    contracts: HashMap<ContractId, Totals>,
+}
+
+/// The execution statistics of the contracts in the latest [`STATS_WINDOW_BLOCKS`] blocks.
+#[derive(Clone, Default)]
+pub struct ExecutionStats {
+    blocks: Arc<Mutex<VecDeque<BlockStats>>>,
+}
+
+impl ExecutionStats {
+    /// Records the transactions of the executed block at the `height`. The block replaces
+    /// the previously executed blocks at the same or higher heights.
+    pub(crate) fn record_block(
+        &self,
+        height: BlockHeight,
+        transactions: &[TransactionStats],
+    ) {
+        let mut contracts = HashMap::<ContractId, Totals>::new();
+        for tx in transactions {
+            let gas_used_ratio = tx
+                .gas_limit
+                .filter(|limit| *limit > 0)
+                .map(|limit| tx.gas_used as f64 / limit as f64);
+            execution_metrics()
+                .observe_transaction(tx.duration.as_secs_f64(), gas_used_ratio);
+
+            let totals = Totals {
+                transactions: 1,
+                gas_used: tx.gas_used,
+                total: tx.duration,
+                max: tx.duration,
+            };
+            for contract_id in &tx.contracts {
+                contracts.entry(*contract_id).or_default().add(&totals);
+            }
+        }
+
+        let mut blocks = self.blocks.lock();
+        while blocks.back().map_or(false, |block| block.height >= height) {
+            blocks.pop_back();
+        }
+        if blocks.len() == STATS_WINDOW_BLOCKS {
+            blocks.pop_front();
+        }
+        blocks.push_back(BlockStats { height, contracts });
+    }
+
+    /// Returns up to the `limit` contracts with the highest total execution time.
+    pub fn slowest_contracts(&self, limit: usize) -> Vec<ContractExecutionStats> {
+        let mut contracts = HashMap::<ContractId, Totals>::new();
+        for block in self.blocks.lock().iter() {
+            for (contract_id, totals) in &block.contracts {
+                contracts.entry(*contract_id).or_default().add(totals);
+            }
+        }
+
+        let mut stats: Vec<_> = contracts
+            .into_iter()
+            .map(|(contract_id, totals)| {
+                let total_ms = millis(totals.total);
+                ContractExecutionStats {
+                    contract_id,
+                    transactions: totals.transactions,
+                    gas_used: totals.gas_used,
+                    total_ms,
+                    mean_ms: total_ms / totals.transactions as f64,
+                    max_ms: millis(totals.max),
+                }
+            })
+            .collect();
+        stats.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+        stats.truncate(limit);
+        stats
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tx(millis: u64, contracts: &[u8]) -> TransactionStats {
+        TransactionStats {
+            duration: Duration::from_millis(millis),
+            gas_used: 10,
+            gas_limit: Some(100),
+            contracts: contracts
+                .iter()
+                .map(|byte| ContractId::from([*byte; 32]))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn slowest_contracts_are_sorted_by_the_total_time() {
+        let stats = ExecutionStats::default();
+        stats.record_block(1u32.into(), &[tx(5, &[1]), tx(3, &[1, 2])]);
+        stats.record_block(2u32.into(), &[tx(10, &[2]), tx(1, &[])]);
+
+        let slowest = stats.slowest_contracts(10);
+
+        assert_eq!(slowest.len(), 2);
+        assert_eq!(slowest[0].contract_id, ContractId::from([2; 32]));
+        assert_eq!(slowest[0].transactions, 2);
+        assert_eq!(slowest[0].total_ms, 13.0);
+        assert_eq!(slowest[0].max_ms, 10.0);
+        assert_eq!(slowest[1].total_ms, 8.0);
+        assert_eq!(stats.slowest_contracts(1).len(), 1);
+    }
+
+    #[test]
+    fn reexecuted_blocks_replace_the_previous_ones() {
+        let stats = ExecutionStats::default();
+        for height in 0..STATS_WINDOW_BLOCKS as u32 + 5 {
+            stats.record_block(height.into(), &[tx(1, &[1])]);
+        }
+        let last = STATS_WINDOW_BLOCKS as u32 + 3;
+        stats.record_block(last.into(), &[tx(1, &[1])]);
+
+        let slowest = stats.slowest_contracts(10);
+
+        // The window keeps the latest blocks, and the re-executed block
+        // at the height `last` replaces the blocks from that height.
+        assert_eq!(slowest[0].transactions, STATS_WINDOW_BLOCKS as u64 - 1);
+    }
+}
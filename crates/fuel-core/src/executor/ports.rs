@@ -3,10 +3,15 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
     fuel_tx,
-    fuel_tx::UniqueIdentifier,
+    fuel_tx::{
+        Chargeable,
+        UniqueIdentifier,
+    },
     fuel_types::{
+        canonical::Serialize,
         ChainId,
         Nonce,
+        Word,
     },
     fuel_vm::checked_transaction::CheckedTransaction,
 };
@@ -32,6 +37,45 @@ impl MaybeCheckedTransaction {
             MaybeCheckedTransaction::Transaction(tx) => tx.id(chain_id),
         }
     }
+
+    /// The gas price of the transaction. It is `None` for the `Mint` transaction.
+    pub fn price(&self) -> Option<Word> {
+        match self {
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(
+                tx,
+            )) => Some(tx.transaction().price()),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Create(
+                tx,
+            )) => Some(tx.transaction().price()),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(_)) => {
+                None
+            }
+            MaybeCheckedTransaction::Transaction(fuel_tx::Transaction::Script(tx)) => {
+                Some(tx.price())
+            }
+            MaybeCheckedTransaction::Transaction(fuel_tx::Transaction::Create(tx)) => {
+                Some(tx.price())
+            }
+            MaybeCheckedTransaction::Transaction(fuel_tx::Transaction::Mint(_)) => None,
+        }
+    }
+
+    /// The size of the canonically serialized transaction.
+    pub fn size(&self) -> u64 {
+        let size = match self {
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Script(
+                tx,
+            )) => tx.transaction().size(),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Create(
+                tx,
+            )) => tx.transaction().size(),
+            MaybeCheckedTransaction::CheckedTransaction(CheckedTransaction::Mint(tx)) => {
+                tx.transaction().size()
+            }
+            MaybeCheckedTransaction::Transaction(tx) => tx.size(),
+        };
+        size as u64
+    }
 }
 
 pub trait TransactionsSource {
@@ -47,6 +91,14 @@ pub trait RelayerPort {
         id: &Nonce,
         da_height: &DaBlockHeight,
     ) -> anyhow::Result<Option<Message>>;
+
+    /// Returns `true` if the message was cancelled by the relayer
+    /// at the da height <= the given da height.
+    fn is_message_cancelled(
+        &self,
+        id: &Nonce,
+        da_height: &DaBlockHeight,
+    ) -> anyhow::Result<bool>;
 }
 
 #[cfg(test)]
@@ -66,4 +118,19 @@ impl RelayerPort for crate::database::Database {
         use std::borrow::Cow;
         Ok(self.storage::<Messages>().get(id)?.map(Cow::into_owned))
     }
+
+    fn is_message_cancelled(
+        &self,
+        id: &Nonce,
+        da_height: &DaBlockHeight,
+    ) -> anyhow::Result<bool> {
+        use fuel_core_storage::{
+            tables::MessageCancellations,
+            StorageAsRef,
+        };
+        Ok(self
+            .storage::<MessageCancellations>()
+            .get(id)?
+            .map_or(false, |cancellation| cancellation.da_height <= *da_height))
+    }
 }
@@ -0,0 +1,146 @@
+//! Collects the profile of the script executed by the VM in the single stepping mode.
+//! The VM stops before each instruction, so the gas consumed by the previous
+//! instruction is the change of the `$ggas` register between two stops.
+
+use crate::database::vm_database::VmDatabase;
+use fuel_core_types::{
+    fuel_asm::{
+        Opcode,
+        RegId,
+        Word,
+    },
+    fuel_tx::Script,
+    fuel_vm::Interpreter,
+    services::executor::{
+        OpcodeProfile,
+        StackProfile,
+        TransactionProfile,
+    },
+};
+use std::{
+    collections::HashMap,
+    iter,
+};
+
+/// The name of the outermost frame of each stack.
+const SCRIPT_FRAME: &str = "script";
+
+type Vm = Interpreter<VmDatabase, Script>;
+
+/// The instruction that is executed by the VM right now.
+struct Step {
+    frames: Vec<String>,
+    opcode: String,
+    gas_before: Word,
+}
+
+/// The boundaries of the memory used by the script.
+struct Memory {
+    stack_start: Word,
+    max_stack_pointer: Word,
+    heap_start: Word,
+    min_heap_pointer: Word,
+}
+
+#[derive(Default)]
+pub(crate) struct Profiler {
+    /// The number of executions and the gas consumed by each opcode.
+    opcodes: HashMap<String, (u64, u64)>,
+    stacks: HashMap<Vec<String>, u64>,
+    step: Option<Step>,
+    memory: Option<Memory>,
+}
+
+impl Profiler {
+    /// Records the state of the VM stopped before the next instruction.
+    pub(crate) fn record(&mut self, vm: &Vm) {
+        let registers: &[Word] = vm.registers();
+        self.finish_step(registers[RegId::GGAS]);
+
+        let memory = self.memory.get_or_insert(Memory {
+            stack_start: registers[RegId::SSP],
+            max_stack_pointer: registers[RegId::SP],
+            heap_start: registers[RegId::HP],
+            min_heap_pointer: registers[RegId::HP],
+        });
+        memory.max_stack_pointer = memory.max_stack_pointer.max(registers[RegId::SP]);
+        memory.min_heap_pointer = memory.min_heap_pointer.min(registers[RegId::HP]);
+
+        let opcode = usize::try_from(registers[RegId::PC])
+            .ok()
+            .and_then(|pc| vm.memory().get(pc).copied())
+            .and_then(|byte| Opcode::try_from(byte).ok())
+            .map(|opcode| format!("{opcode:?}"))
+            .unwrap_or_else(|| "INVALID".to_string());
+        let frames = iter::once(SCRIPT_FRAME.to_string())
+            .chain(
+                vm.call_stack()
+                    .iter()
+                    .map(|frame| format!("{:#x}", frame.to())),
+            )
+            .collect();
+
+        self.step = Some(Step {
+            frames,
+            opcode,
+            gas_before: registers[RegId::GGAS],
+        });
+    }
+
+    /// Finishes the profile after the VM completed the execution.
+    pub(crate) fn finish(mut self, vm: &Vm) -> TransactionProfile {
+        let registers: &[Word] = vm.registers();
+        self.finish_step(registers[RegId::GGAS]);
+
+        let mut opcodes: Vec<_> = self
+            .opcodes
+            .into_iter()
+            .map(|(opcode, (count, gas))| OpcodeProfile { opcode, count, gas })
+            .collect();
+        opcodes.sort_by(|a, b| b.gas.cmp(&a.gas).then_with(|| a.opcode.cmp(&b.opcode)));
+
+        let mut stacks: Vec<_> = self
+            .stacks
+            .into_iter()
+            .map(|(frames, gas)| StackProfile { frames, gas })
+            .collect();
+        stacks.sort_by(|a, b| a.frames.cmp(&b.frames));
+
+        let (stack_high_water_mark, heap_high_water_mark) = self
+            .memory
+            .map(|memory| {
+                (
+                    memory.max_stack_pointer.saturating_sub(memory.stack_start),
+                    memory.heap_start.saturating_sub(memory.min_heap_pointer),
+                )
+            })
+            .unwrap_or_default();
+
+        TransactionProfile {
+            opcodes,
+            stacks,
+            stack_high_water_mark,
+            heap_high_water_mark,
+        }
+    }
+
+    fn finish_step(&mut self, gas_after: Word) {
+        let Some(Step {
+            mut frames,
+            opcode,
+            gas_before,
+        }) = self.step.take()
+        else {
+            return
+        };
+        let gas = gas_before.saturating_sub(gas_after);
+
+        let (count, total) = self.opcodes.entry(opcode.clone()).or_default();
+        *count = count.saturating_add(1);
+        *total = total.saturating_add(gas);
+
+        frames.push(opcode);
+        let total = self.stacks.entry(frames).or_default();
+        *total = total.saturating_add(gas);
+    }
+}
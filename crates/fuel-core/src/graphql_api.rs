@@ -10,7 +10,9 @@ use fuel_core_types::{
 use std::net::SocketAddr;
 
 pub(crate) mod metrics_extension;
+pub mod persisted_queries;
 pub mod ports;
+pub mod query_stats;
 pub mod service;
 
 #[derive(Clone, Debug)]
@@ -24,6 +26,25 @@ pub struct Config {
     pub max_depth: usize,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The P2P identity key of the node that signs the `signedNodeInfo`.
+    pub node_key: Option<Secret<SecretKeyWrapper>>,
+    /// The number of the blocks with the cached message trees for the `messageProof`.
+    pub message_proof_cache_size: usize,
+    /// Rejects the submission of the transactions, e.g. on the read replica.
+    pub read_only: bool,
+    /// The node produces the blocks, so it accepts the private transactions.
+    pub block_production: bool,
+    /// Collects the execution statistics of the operations by their names.
+    pub query_stats: bool,
+    /// The number of the latest blocks covered by the contract call analytics.
+    /// `None` if the analytics is disabled.
+    pub contract_calls_window: Option<u32>,
+    /// The persisted queries and the allow-list of the queries.
+    /// `None` if the persisted queries are disabled.
+    pub persisted_queries: Option<persisted_queries::PersistedQueriesConfig>,
+    /// The budget of the resources shared with other subsystems of the node.
+    /// The cached message trees take the memory from it.
+    pub resource_budget: fuel_core_services::ResourceBudget,
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,282 @@
+//! The persisted queries of the GraphQL API. The clients send the SHA-256 hash of the
+//! query in the `persistedQuery` extension of the request instead of the query itself,
+//! following the automatic persisted queries protocol. The unknown hash is answered
+//! with the `PersistedQueryNotFound` error, and the client retries with the full query
+//! that is registered under its hash.
+//!
+//! In the allow-list mode, the node executes only the queries of the allow-list
+//! configured by the operator, and the hashes of these queries are known from the start.
+
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextPrepareRequest,
+    },
+    Request,
+    ServerError,
+    ServerResult,
+};
+use fuel_core_types::fuel_crypto::Hasher;
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    num::NonZeroUsize,
+    path::Path,
+    sync::Arc,
+};
+
+/// The name of the extension of the request with the hash of the query.
+const PERSISTED_QUERY_EXTENSION: &str = "persistedQuery";
+
+/// The error returned for the unknown hash. The clients of the protocol
+/// react to it by resending the full query.
+pub const PERSISTED_QUERY_NOT_FOUND: &str = "PersistedQueryNotFound";
+
+/// The error returned for the query that isn't in the allow-list.
+pub const QUERY_NOT_ALLOWED: &str = "The query is not in the allow-list";
+
+/// The configuration of the persisted queries.
+#[derive(Clone, Debug, Default)]
+pub struct PersistedQueriesConfig {
+    /// The max number of the queries registered by the clients.
+    /// `0` disables the registration.
+    pub cache_size: usize,
+    /// The queries allowed to be executed. `None` allows any query.
+    pub allow_list: Option<Vec<String>>,
+}
+
+impl PersistedQueriesConfig {
+    /// Loads the allow-list from the JSON file with the array of the queries.
+    pub fn load_allow_list(path: &Path) -> anyhow::Result<Vec<String>> {
+        let file = std::fs::read(path).map_err(|e| {
+            anyhow::anyhow!("Failed to read the allow-list {}: {e}", path.display())
+        })?;
+        let queries = serde_json::from_slice(&file).map_err(|e| {
+            anyhow::anyhow!("Failed to parse the allow-list {}: {e}", path.display())
+        })?;
+        Ok(queries)
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct PersistedQuery {
+    version: u32,
+    #[serde(rename = "sha256Hash")]
+    sha256_hash: String,
+}
+
+/// The queries of the allow-list by their hashes and by their normalized text.
+struct AllowList {
+    by_hash: HashMap<String, String>,
+    normalized: HashSet<String>,
+}
+
+/// The registry of the persisted queries by their hashes.
+#[derive(Clone)]
+pub struct PersistedQueries {
+    cache: Option<Arc<Mutex<LruCache<String, String>>>>,
+    allow_list: Option<Arc<AllowList>>,
+}
+
+impl PersistedQueries {
+    pub fn new(config: PersistedQueriesConfig) -> Self {
+        let allow_list = config.allow_list.map(|queries| {
+            let by_hash = queries
+                .iter()
+                .map(|query| (query_hash(query), query.clone()))
+                .collect();
+            let normalized = queries.iter().map(|query| normalize(query)).collect();
+            Arc::new(AllowList {
+                by_hash,
+                normalized,
+            })
+        });
+        Self {
+            cache: NonZeroUsize::new(config.cache_size)
+                .map(|capacity| Arc::new(Mutex::new(LruCache::new(capacity)))),
+            allow_list,
+        }
+    }
+
+    /// Returns the query to execute for the `query` and the `hash` of the request.
+    fn resolve(&self, query: String, hash: Option<String>) -> Result<String, String> {
+        let Some(hash) = hash else {
+            self.ensure_allowed(&query)?;
+            return Ok(query)
+        };
+        let hash = hash.to_lowercase();
+
+        if query.is_empty() {
+            let cached = self
+                .cache
+                .as_ref()
+                .and_then(|cache| cache.lock().get(&hash).cloned());
+            let allowed = self
+                .allow_list
+                .as_ref()
+                .and_then(|allow_list| allow_list.by_hash.get(&hash).cloned());
+            return cached
+                .or(allowed)
+                .ok_or_else(|| PERSISTED_QUERY_NOT_FOUND.to_string())
+        }
+
+        if query_hash(&query) != hash {
+            return Err("The hash doesn't match the query".to_string())
+        }
+        self.ensure_allowed(&query)?;
+        if let Some(cache) = &self.cache {
+            cache.lock().put(hash, query.clone());
+        }
+        Ok(query)
+    }
+
+    fn ensure_allowed(&self, query: &str) -> Result<(), String> {
+        match &self.allow_list {
+            Some(allow_list) if !allow_list.normalized.contains(&normalize(query)) => {
+                Err(QUERY_NOT_ALLOWED.to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the hex of the SHA-256 hash of the `query`.
+pub fn query_hash(query: &str) -> String {
+    hex::encode(Hasher::hash(query.as_bytes()))
+}
+
+/// Collapses the whitespaces, so the formatting of the query doesn't affect the
+/// allow-list.
+fn normalize(query: &str) -> String {
+    query.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub(crate) struct PersistedQueriesExtension {
+    queries: PersistedQueries,
+}
+
+impl PersistedQueriesExtension {
+    pub fn new(queries: PersistedQueries) -> Self {
+        Self { queries }
+    }
+}
+
+impl ExtensionFactory for PersistedQueriesExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PersistedQueriesExtension {
+            queries: self.queries.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for PersistedQueriesExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let hash = match request.extensions.remove(PERSISTED_QUERY_EXTENSION) {
+            Some(value) => {
+                let persisted: PersistedQuery = async_graphql::from_value(value)
+                    .map_err(|_| {
+                        ServerError::new("Invalid `persistedQuery` extension", None)
+                    })?;
+                if persisted.version != 1 {
+                    return Err(ServerError::new(
+                        format!(
+                            "The version {} of the `persistedQuery` is not supported",
+                            persisted.version
+                        ),
+                        None,
+                    ))
+                }
+                Some(persisted.sha256_hash)
+            }
+            None => None,
+        };
+        let query = std::mem::take(&mut request.query);
+        request.query = self
+            .queries
+            .resolve(query, hash)
+            .map_err(|e| ServerError::new(e, None))?;
+        next.run(ctx, request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUERY: &str = "query { chain { name } }";
+
+    fn queries(cache_size: usize, allow_list: Option<Vec<&str>>) -> PersistedQueries {
+        PersistedQueries::new(PersistedQueriesConfig {
+            cache_size,
+            allow_list: allow_list
+                .map(|queries| queries.into_iter().map(str::to_string).collect()),
+        })
+    }
+
+    #[test]
+    fn unknown_hash_is_registered_by_the_full_query() {
+        let queries = queries(10, None);
+        let hash = query_hash(QUERY);
+
+        assert_eq!(
+            queries.resolve(String::new(), Some(hash.clone())),
+            Err(PERSISTED_QUERY_NOT_FOUND.to_string())
+        );
+        assert_eq!(
+            queries.resolve(QUERY.to_string(), Some(hash.clone())),
+            Ok(QUERY.to_string())
+        );
+        assert_eq!(
+            queries.resolve(String::new(), Some(hash)),
+            Ok(QUERY.to_string())
+        );
+    }
+
+    #[test]
+    fn query_with_the_wrong_hash_is_rejected() {
+        let queries = queries(10, None);
+
+        let result = queries.resolve(QUERY.to_string(), Some(query_hash("{ health }")));
+
+        assert!(result.is_err());
+        assert_eq!(
+            queries.resolve(String::new(), Some(query_hash("{ health }"))),
+            Err(PERSISTED_QUERY_NOT_FOUND.to_string())
+        );
+    }
+
+    #[test]
+    fn allow_list_accepts_only_its_queries() {
+        let queries = queries(10, Some(vec![QUERY]));
+
+        // The hashes of the allow-list are known without the registration.
+        assert_eq!(
+            queries.resolve(String::new(), Some(query_hash(QUERY))),
+            Ok(QUERY.to_string())
+        );
+        // The formatting of the query doesn't matter.
+        assert!(queries
+            .resolve("query {\n  chain {\n    name\n  }\n}".to_string(), None)
+            .is_ok());
+        assert_eq!(
+            queries.resolve("{ health }".to_string(), None),
+            Err(QUERY_NOT_ALLOWED.to_string())
+        );
+        assert_eq!(
+            queries.resolve("{ health }".to_string(), Some(query_hash("{ health }"))),
+            Err(QUERY_NOT_ALLOWED.to_string())
+        );
+    }
+}
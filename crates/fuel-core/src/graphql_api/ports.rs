@@ -1,3 +1,7 @@
+use crate::chain_config::{
+    ContractConfig,
+    UpgradeDeclaration,
+};
 use async_trait::async_trait;
 use fuel_core_services::stream::{
     BoxFuture,
@@ -14,6 +18,7 @@ use fuel_core_storage::{
         ContractsInfo,
         ContractsRawCode,
         FuelBlocks,
+        MessageDeposits,
         Messages,
         Receipts,
         SealedBlockConsensus,
@@ -30,9 +35,13 @@ use fuel_core_types::{
         BlockId,
         DaBlockHeight,
     },
-    entities::message::{
-        MerkleProof,
-        Message,
+    entities::{
+        asset::AssetMetadata,
+        message::{
+            CommitmentState,
+            MerkleProof,
+            Message,
+        },
     },
     fuel_tx::{
         Receipt,
@@ -45,19 +54,41 @@ use fuel_core_types::{
         Address,
         AssetId,
         BlockHeight,
+        Bytes32,
         ContractId,
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_producer::DryRunOverrides,
+        executor::{
+            StateDiff,
+            TransactionProfile,
+        },
+        graphql_api::{
+            AccountEvent,
+            BlockFees,
+            BlockStateChanges,
+            ContractBalance,
+            ContractCalls,
+            SubscriptionCursor,
+            SubscriptionEvent,
+            SyncStatus,
+            TableStats,
+        },
+        relayer::DaFeeSample,
         txpool::{
+            ArchivedTransactionStatus,
             InsertionResult,
+            PendingCoins,
             TransactionStatus,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 /// The database port expected by GraphQL API service.
 pub trait DatabasePort:
@@ -69,6 +100,8 @@ pub trait DatabasePort:
     + DatabaseCoins
     + DatabaseContracts
     + DatabaseChain
+    + DatabaseAssets
+    + DatabaseUpgrades
     + DatabaseMessageProof
 {
 }
@@ -87,6 +120,17 @@ pub trait DatabaseBlocks:
     ) -> BoxedIter<'_, StorageResult<(BlockHeight, BlockId)>>;
 
     fn ids_of_latest_block(&self) -> StorageResult<(BlockHeight, BlockId)>;
+
+    /// Returns the keys changed by the block at the `height`, or `None`
+    /// if the state diff of the block is not recorded.
+    fn state_changes(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockStateChanges>>;
+
+    /// Returns the gas prices and the fullness of the `last` latest blocks,
+    /// the oldest first.
+    fn fee_history(&self, last: usize) -> StorageResult<Vec<BlockFees>>;
 }
 
 /// Trait that specifies all the getters required for transactions.
@@ -96,6 +140,12 @@ pub trait DatabaseTransactions:
 {
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    /// Returns the archived history of the statuses of the transaction.
+    fn tx_status_history(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Vec<ArchivedTransactionStatus>>;
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -108,6 +158,7 @@ pub trait DatabaseTransactions:
 pub trait DatabaseMessages:
     StorageInspect<Messages, Error = StorageError>
     + StorageInspect<SpentMessages, Error = StorageError>
+    + StorageInspect<MessageDeposits, Error = StorageError>
 {
     fn owned_message_ids(
         &self,
@@ -125,6 +176,18 @@ pub trait DatabaseMessages:
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
 
     fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool>;
+
+    /// Returns the nonces of the messages sent by the da transaction.
+    fn da_transaction_message_ids(
+        &self,
+        da_transaction: &Bytes32,
+    ) -> BoxedIter<'_, StorageResult<Nonce>>;
+
+    /// Returns the id of the transaction that spent the message.
+    fn message_spender(&self, nonce: &Nonce) -> StorageResult<Option<TxId>>;
+
+    /// Returns the total amount of the messages without data owned by the `owner`.
+    fn owned_messages_balance(&self, owner: &Address) -> StorageResult<u64>;
 }
 
 /// Trait that specifies all the getters required for coins.
@@ -135,6 +198,21 @@ pub trait DatabaseCoins: StorageInspect<Coins, Error = StorageError> {
         start_coin: Option<UtxoId>,
         direction: IterDirection,
     ) -> BoxedIter<'_, StorageResult<UtxoId>>;
+
+    /// Returns the total amount of the `asset_id` coins owned by the `owner`.
+    fn owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64>;
+
+    /// Returns the total amounts of the coins owned by the `owner` per asset,
+    /// ordered by the asset id.
+    fn owned_coins_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>>;
 }
 
 /// Trait that specifies all the getters required for contract.
@@ -149,6 +227,47 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    /// Returns the values of the storage `slots` of the `contract` after the block
+    /// at the `height`, or the latest values if the `height` is not specified.
+    /// Returns `None` if the contract didn't exist at the `height`.
+    fn contract_slot_values(
+        &self,
+        contract: &ContractId,
+        slots: &[Bytes32],
+        height: Option<BlockHeight>,
+    ) -> StorageResult<Option<Vec<Option<Bytes32>>>>;
+
+    /// Returns the config of the `contract` with its bytecode, storage slots and balances
+    /// after the block at the `height`, or at the latest block if the `height` is not
+    /// specified. Returns `None` if the contract didn't exist at the `height`.
+    /// Fails if the contract has more than `max_entries` storage slots and balances.
+    fn contract_config_at(
+        &self,
+        contract: &ContractId,
+        height: Option<BlockHeight>,
+        max_entries: usize,
+    ) -> StorageResult<Option<ContractConfig>>;
+
+    /// Returns the `limit` most called contracts in the blocks starting from the `from`
+    /// height. The calls are recorded by the contract call analytics.
+    fn top_contracts(
+        &self,
+        from: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<ContractCalls>>;
+}
+
+/// Trait that specifies all the getters required for assets.
+pub trait DatabaseAssets {
+    /// Returns the metadata of the asset recorded by the asset registry.
+    fn asset_metadata(&self, asset_id: &AssetId) -> StorageResult<Option<AssetMetadata>>;
+}
+
+/// Trait that specifies all the getters required for the upgrades of the chain.
+pub trait DatabaseUpgrades {
+    /// Returns the declared upgrades above the latest height ordered by the height.
+    fn pending_upgrades(&self) -> StorageResult<Vec<UpgradeDeclaration>>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -156,6 +275,10 @@ pub trait DatabaseChain {
     fn chain_name(&self) -> StorageResult<String>;
 
     fn da_height(&self) -> StorageResult<DaBlockHeight>;
+
+    /// Returns the fee samples of the latest finalized DA blocks recorded
+    /// by the relayer, the oldest first.
+    fn da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>>;
 }
 
 #[async_trait]
@@ -169,10 +292,20 @@ pub trait TxPoolPort: Send + Sync {
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Inserts the transactions without gossiping them to the peers.
+    async fn insert_private(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
+
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
     ) -> BoxFuture<'_, BoxStream<TxStatusMessage>>;
+
+    /// Returns the coins and the messages of the `owner` spent and created
+    /// by the transactions in the pool.
+    fn pending_coins(&self, owner: &Address) -> PendingCoins;
 }
 
 #[async_trait]
@@ -180,9 +313,23 @@ pub trait DryRunExecution {
     async fn dry_run_tx(
         &self,
         transaction: Transaction,
-        height: Option<BlockHeight>,
+        overrides: DryRunOverrides,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>>;
+
+    async fn dry_run_profile_tx(
+        &self,
+        transaction: Transaction,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<Receipt>, TransactionProfile)>;
+
+    async fn dry_run_bundle_txs(
+        &self,
+        transactions: Vec<Transaction>,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<Vec<Receipt>>, StateDiff)>;
 }
 
 pub trait BlockProducerPort: Send + Sync + DryRunExecution {}
@@ -194,6 +341,71 @@ pub trait ConsensusModulePort: Send + Sync {
         start_time: Option<Tai64>,
         number_of_blocks: u32,
     ) -> anyhow::Result<()>;
+
+    /// Advances the clock of the chain by the `duration`. Returns the current time
+    /// of the chain.
+    async fn advance_time(&self, duration: Duration) -> anyhow::Result<Tai64>;
+}
+
+pub trait BlockImporterPort: Send + Sync {
+    /// Returns a stream of the heights of the blocks imported by the node.
+    fn block_events(&self) -> BoxStream<BlockHeight>;
+}
+
+/// Checks the commitments of the Fuel blocks on the DA layer.
+#[async_trait]
+pub trait BlockCommitterPort: Send + Sync {
+    /// Returns the height of the committed block used to prove the output messages
+    /// of the block at `height`.
+    fn commit_height(&self, height: BlockHeight) -> BlockHeight;
+
+    /// Returns the state of the commitment of the block on the DA layer.
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState>;
+}
+
+/// Reports the storage statistics of the database tables.
+pub trait StorageStatsPort: Send + Sync {
+    /// Returns the statistics of the tables from the latest refresh.
+    fn table_stats(&self) -> Vec<TableStats>;
+}
+
+/// Reports the synchronization status of the node.
+pub trait SyncStatusPort: Send + Sync {
+    /// Returns the status consolidated from the sync service, the block importer
+    /// and the relayer.
+    fn sync_status(&self) -> anyhow::Result<SyncStatus>;
+}
+
+/// Notifies about the changes of the watched accounts.
+pub trait AccountWatcherPort: Send + Sync {
+    /// Returns the stream of the events of the `addresses` from the next block.
+    /// The addresses are watched until the stream is dropped.
+    fn subscribe(
+        &self,
+        addresses: Vec<Address>,
+    ) -> anyhow::Result<BoxStream<AccountEvent>>;
+}
+
+/// Buffers the latest events of the subscriptions, so the clients can resume them.
+pub trait SubscriptionBufferPort: Send + Sync {
+    /// Returns the cursor of the latest buffered event.
+    fn latest_cursor(&self) -> SubscriptionCursor;
+
+    /// Returns the stream of the buffered and the new events after the `cursor`.
+    /// Returns an error if some of the events after the `cursor` are no longer buffered.
+    fn events_after(
+        &self,
+        cursor: SubscriptionCursor,
+    ) -> anyhow::Result<BoxStream<anyhow::Result<(SubscriptionCursor, SubscriptionEvent)>>>;
+}
+
+pub trait GasPriceEstimate: Send + Sync {
+    /// Returns the highest gas price that can be required for the block at the `height`.
+    fn worst_case_gas_price(&self, height: BlockHeight) -> u64;
 }
 
 /// Trait that specifies queries supported by the database.
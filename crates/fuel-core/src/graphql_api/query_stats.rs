@@ -0,0 +1,251 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextExecute,
+    },
+    Response,
+    Value,
+};
+use fuel_core_metrics::graphql_metrics::{
+    graphql_metrics,
+    OTHER_OPERATIONS_LABEL,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The max number of the tracked operation names. The operations with other names
+/// are recorded under the [`OTHER_OPERATIONS`], so the clients can't exhaust
+/// the memory with the random names.
+pub const MAX_OPERATIONS: usize = 1000;
+
+/// The name of the operations without the name.
+pub const ANONYMOUS_OPERATION: &str = "<anonymous>";
+
+/// The name of the operations above the [`MAX_OPERATIONS`] limit. The metrics use
+/// the same name for the operations above their own limit of the labels, which
+/// isn't reset together with the statistics.
+pub const OTHER_OPERATIONS: &str = OTHER_OPERATIONS_LABEL;
+
+/// The number of the latest latencies of each operation used for the percentiles.
+const LATENCY_SAMPLES: usize = 1024;
+
+/// The statistics of the executions of one operation.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OperationStats {
+    pub operation: String,
+    pub count: u64,
+    pub errors: u64,
+    /// The share of the executions that returned the errors.
+    pub error_rate: f64,
+    /// The total number of the items in the lists of the responses.
+    pub rows: u64,
+    /// The latency percentiles of the latest executions, in milliseconds.
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Default)]
+struct Operation {
+    count: u64,
+    errors: u64,
+    rows: u64,
+    latencies: VecDeque<Duration>,
+}
+
+/// Collects the statistics of the executed GraphQL operations by their names.
+#[derive(Clone, Default)]
+pub struct QueryStats {
+    operations: Arc<Mutex<HashMap<String, Operation>>>,
+}
+
+impl QueryStats {
+    fn record(
+        &self,
+        operation: Option<&str>,
+        latency: Duration,
+        failed: bool,
+        rows: u64,
+    ) {
+        let name = {
+            let mut operations = self.operations.lock();
+            let name = match operation {
+                Some(name)
+                    if operations.contains_key(name)
+                        || operations.len() < MAX_OPERATIONS =>
+                {
+                    name
+                }
+                Some(_) => OTHER_OPERATIONS,
+                None => ANONYMOUS_OPERATION,
+            };
+            let stats = operations.entry(name.to_string()).or_default();
+            stats.count += 1;
+            stats.errors += failed as u64;
+            stats.rows += rows;
+            if stats.latencies.len() == LATENCY_SAMPLES {
+                stats.latencies.pop_front();
+            }
+            stats.latencies.push_back(latency);
+            name.to_string()
+        };
+        graphql_metrics().operation_observe(&name, latency.as_secs_f64(), failed, rows);
+    }
+
+    /// Returns the statistics of all operations, the slowest by the p99 first.
+    pub fn operations(&self) -> Vec<OperationStats> {
+        let operations = self.operations.lock();
+        let mut stats: Vec<_> = operations
+            .iter()
+            .map(|(name, operation)| {
+                let mut latencies: Vec<_> = operation.latencies.iter().copied().collect();
+                latencies.sort();
+                OperationStats {
+                    operation: name.clone(),
+                    count: operation.count,
+                    errors: operation.errors,
+                    error_rate: operation.errors as f64 / operation.count as f64,
+                    rows: operation.rows,
+                    p50_ms: percentile(&latencies, 50),
+                    p90_ms: percentile(&latencies, 90),
+                    p99_ms: percentile(&latencies, 99),
+                    max_ms: percentile(&latencies, 100),
+                }
+            })
+            .collect();
+        stats.sort_by(|a, b| b.p99_ms.total_cmp(&a.p99_ms));
+        stats
+    }
+
+    /// Removes the collected statistics.
+    pub fn reset(&self) {
+        self.operations.lock().clear();
+    }
+}
+
+/// Returns the `percent` percentile of the sorted `latencies` in milliseconds.
+fn percentile(latencies: &[Duration], percent: usize) -> f64 {
+    if latencies.is_empty() {
+        return 0.0
+    }
+    let index = ((latencies.len() * percent + 99) / 100).saturating_sub(1);
+    latencies[index].as_micros() as f64 / 1000.0
+}
+
+/// Returns the number of the items in all lists of the `value`.
+fn rows(value: &Value) -> u64 {
+    match value {
+        Value::List(items) => items.len() as u64 + items.iter().map(rows).sum::<u64>(),
+        Value::Object(fields) => fields.values().map(rows).sum(),
+        _ => 0,
+    }
+}
+
+pub(crate) struct QueryStatsExtension {
+    stats: QueryStats,
+}
+
+impl QueryStatsExtension {
+    pub fn new(stats: QueryStats) -> Self {
+        Self { stats }
+    }
+}
+
+impl ExtensionFactory for QueryStatsExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(QueryStatsExtension {
+            stats: self.stats.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for QueryStatsExtension {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let start_time = Instant::now();
+        let response = next.run(ctx, operation_name).await;
+        self.stats.record(
+            operation_name,
+            start_time.elapsed(),
+            response.is_err(),
+            rows(&response.data),
+        );
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_are_aggregated_by_name() {
+        let stats = QueryStats::default();
+        for millis in 1..=100 {
+            stats.record(Some("Blocks"), Duration::from_millis(millis), false, 2);
+        }
+        stats.record(Some("Blocks"), Duration::from_millis(1), true, 0);
+        stats.record(None, Duration::from_millis(1), false, 0);
+
+        let operations = stats.operations();
+
+        assert_eq!(operations.len(), 2);
+        let blocks = &operations[0];
+        assert_eq!(blocks.operation, "Blocks");
+        assert_eq!(blocks.count, 101);
+        assert_eq!(blocks.errors, 1);
+        assert_eq!(blocks.rows, 200);
+        assert_eq!(blocks.p50_ms, 50.0);
+        assert_eq!(blocks.max_ms, 100.0);
+        assert_eq!(operations[1].operation, ANONYMOUS_OPERATION);
+    }
+
+    #[test]
+    fn operations_above_the_limit_are_recorded_together() {
+        let stats = QueryStats::default();
+        for i in 0..=MAX_OPERATIONS {
+            stats.record(Some(&format!("Query{i}")), Duration::ZERO, false, 0);
+        }
+        stats.record(Some("Query0"), Duration::ZERO, false, 0);
+
+        let operations = stats.operations();
+
+        assert_eq!(operations.len(), MAX_OPERATIONS + 1);
+        let other = operations
+            .iter()
+            .find(|stats| stats.operation == OTHER_OPERATIONS)
+            .unwrap();
+        assert_eq!(other.count, 1);
+    }
+
+    #[test]
+    fn rows_count_the_items_of_nested_lists() {
+        let value = Value::from_json(serde_json::json!({
+            "blocks": {
+                "nodes": [
+                    { "transactions": [1, 2] },
+                    { "transactions": [3] },
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(rows(&value), 5);
+    }
+}
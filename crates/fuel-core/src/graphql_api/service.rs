@@ -1,15 +1,32 @@
 use crate::{
     fuel_core_graphql_api::ports::{
+        AccountWatcherPort,
+        BlockCommitterPort,
+        BlockImporterPort,
         BlockProducerPort,
         ConsensusModulePort,
         DatabasePort,
+        GasPriceEstimate,
+        StorageStatsPort,
+        SubscriptionBufferPort,
+        SyncStatusPort,
         TxPoolPort,
     },
     graphql_api::{
         metrics_extension::MetricsExtension,
+        persisted_queries::{
+            PersistedQueries,
+            PersistedQueriesExtension,
+        },
+        query_stats::{
+            QueryStats,
+            QueryStatsExtension,
+        },
         Config,
     },
+    query::MessageProofCache,
     schema::{
+        node_info::SignedNodeInfoLimiter,
         CoreSchema,
         CoreSchemaBuilder,
     },
@@ -19,12 +36,22 @@ use async_graphql::{
     http::{
         playground_source,
         GraphQLPlaygroundConfig,
+        WebSocket as GraphQLWebSocket,
+        WebSocketProtocols,
+        WsMessage,
+        ALL_WEBSOCKET_PROTOCOLS,
     },
     Request,
     Response,
 };
 use axum::{
     extract::{
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocket,
+            WebSocketUpgrade,
+        },
         DefaultBodyLimit,
         Extension,
     },
@@ -33,8 +60,11 @@ use axum::{
             ACCESS_CONTROL_ALLOW_HEADERS,
             ACCESS_CONTROL_ALLOW_METHODS,
             ACCESS_CONTROL_ALLOW_ORIGIN,
+            SEC_WEBSOCKET_PROTOCOL,
         },
+        HeaderMap,
         HeaderValue,
+        StatusCode,
     },
     response::{
         sse::Event,
@@ -50,11 +80,16 @@ use axum::{
     Router,
 };
 use fuel_core_services::{
+    stream::BoxStream,
     RunnableService,
     RunnableTask,
     StateWatcher,
 };
-use futures::Stream;
+use fuel_core_types::fuel_types::BlockHeight;
+use futures::{
+    SinkExt,
+    Stream,
+};
 use serde_json::json;
 use std::{
     future::Future,
@@ -63,6 +98,8 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    str::FromStr,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
@@ -80,24 +117,43 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type GasPriceEstimator = Box<dyn GasPriceEstimate>;
+pub type BlockImporter = Box<dyn BlockImporterPort>;
+pub type BlockCommitter = Box<dyn BlockCommitterPort>;
+pub type StorageStats = Box<dyn StorageStatsPort>;
+pub type SyncStatusSource = Box<dyn SyncStatusPort>;
+pub type AccountWatcher = Box<dyn AccountWatcherPort>;
+pub type SubscriptionBuffer = Box<dyn SubscriptionBufferPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
+    /// The execution statistics of the operations. It is `None` if they are disabled.
+    pub query_stats: Option<QueryStats>,
 }
 
 pub struct GraphqlService {
     bound_address: SocketAddr,
+    query_stats: Option<QueryStats>,
 }
 
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    message_proofs: MessageProofPrecompute,
+}
+
+/// Builds the message trees of the imported blocks before they are requested.
+struct MessageProofPrecompute {
+    database: Arc<dyn DatabasePort>,
+    cache: Arc<MessageProofCache>,
+    block_events: BoxStream<BlockHeight>,
 }
 
 pub struct Task {
     // Ugly workaround because of https://github.com/hyperium/hyper/issues/2582
     server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send + 'static>>,
+    message_proofs: MessageProofPrecompute,
 }
 
 #[async_trait::async_trait]
@@ -111,6 +167,7 @@ impl RunnableService for GraphqlService {
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
             bound_address: self.bound_address,
+            query_stats: self.query_stats.clone(),
         }
     }
 
@@ -120,7 +177,11 @@ impl RunnableService for GraphqlService {
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         let mut state = state.clone();
-        let ServerParams { router, listener } = params;
+        let ServerParams {
+            router,
+            listener,
+            message_proofs,
+        } = params;
 
         let server = axum::Server::from_tcp(listener)
             .unwrap()
@@ -134,6 +195,7 @@ impl RunnableService for GraphqlService {
 
         Ok(Task {
             server: Box::pin(server),
+            message_proofs,
         })
     }
 }
@@ -141,10 +203,18 @@ impl RunnableService for GraphqlService {
 #[async_trait::async_trait]
 impl RunnableTask for Task {
     async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
-        self.server.as_mut().await?;
-        // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
-        // error or stop signal.
-        Ok(false /* should_continue */)
+        tokio::select! {
+            result = self.server.as_mut() => {
+                result?;
+                // The `axum::Server` has its internal loop. If `await` is finished, we get an internal
+                // error or stop signal.
+                Ok(false /* should_continue */)
+            }
+            Some(height) = self.message_proofs.block_events.next() => {
+                self.message_proofs.precompute(height).await;
+                Ok(true /* should_continue */)
+            }
+        }
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
@@ -155,17 +225,60 @@ impl RunnableTask for Task {
     }
 }
 
+impl MessageProofPrecompute {
+    /// The message tree is built from the database on the blocking thread.
+    async fn precompute(&self, height: BlockHeight) {
+        let database = self.database.clone();
+        let cache = self.cache.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            database
+                .block_id(&height)
+                .and_then(|block_id| cache.precompute(database.as_ref(), &block_id))
+        })
+        .await;
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => tracing::warn!(
+                "Failed to precompute the message tree of the block {height}: {e}"
+            ),
+            Err(e) => tracing::warn!(
+                "Failed to precompute the message tree of the block {height}: {e}"
+            ),
+        }
+    }
+}
+
 // Need a separate Data Object for each Query endpoint, cannot be avoided
+#[allow(clippy::too_many_arguments)]
 pub fn new_service(
     config: Config,
     schema: CoreSchemaBuilder,
     database: Database,
+    message_proof_database: Database,
     txpool: TxPool,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
+    gas_price_estimator: GasPriceEstimator,
+    block_importer: BlockImporter,
+    block_committer: Option<BlockCommitter>,
+    storage_stats: Option<StorageStats>,
+    sync_status: SyncStatusSource,
+    account_watcher: Option<AccountWatcher>,
+    subscription_buffer: Option<SubscriptionBuffer>,
     _log_threshold_ms: Duration,
 ) -> anyhow::Result<Service> {
     let network_addr = config.addr;
+    let query_stats = config.query_stats.then(QueryStats::default);
+    let persisted_queries = config.persisted_queries.clone().map(PersistedQueries::new);
+    let message_proof_cache = Arc::new(
+        MessageProofCache::new(config.message_proof_cache_size)
+            .with_resource_budget(config.resource_budget.clone()),
+    );
+    let message_proofs = MessageProofPrecompute {
+        database: Arc::from(message_proof_database),
+        cache: message_proof_cache.clone(),
+        block_events: block_importer.block_events(),
+    };
 
     let schema = schema
         .data(config)
@@ -173,9 +286,43 @@ pub fn new_service(
         .data(txpool)
         .data(producer)
         .data(consensus_module)
+        .data(gas_price_estimator)
+        .data(block_importer)
+        .data(sync_status)
+        .data(message_proof_cache)
+        .data(SignedNodeInfoLimiter::default());
+    let schema = match block_committer {
+        Some(block_committer) => schema.data(block_committer),
+        None => schema,
+    };
+    let schema = match storage_stats {
+        Some(storage_stats) => schema.data(storage_stats),
+        None => schema,
+    };
+    let schema = match account_watcher {
+        Some(account_watcher) => schema.data(account_watcher),
+        None => schema,
+    };
+    let schema = match subscription_buffer {
+        Some(subscription_buffer) => schema.data(subscription_buffer),
+        None => schema,
+    };
+    let schema = schema
         .extension(async_graphql::extensions::Tracing)
-        .extension(MetricsExtension::new(_log_threshold_ms))
-        .finish();
+        .extension(MetricsExtension::new(_log_threshold_ms));
+    let schema = match &query_stats {
+        Some(query_stats) => {
+            schema.extension(QueryStatsExtension::new(query_stats.clone()))
+        }
+        None => schema,
+    };
+    let schema = match persisted_queries {
+        Some(persisted_queries) => {
+            schema.extension(PersistedQueriesExtension::new(persisted_queries))
+        }
+        None => schema,
+    };
+    let schema = schema.finish();
 
     let router = Router::new()
         .route("/playground", get(graphql_playground))
@@ -184,8 +331,10 @@ pub fn new_service(
             "/graphql-sub",
             post(graphql_subscription_handler).options(ok),
         )
+        .route("/graphql-ws", get(graphql_ws_handler))
         .route("/metrics", get(metrics))
-        .route("/health", get(health))
+        .route("/health", get(health));
+    let router = router
         .layer(Extension(schema))
         .layer(TraceLayer::new_for_http())
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -208,8 +357,15 @@ pub fn new_service(
     tracing::info!("Binding GraphQL provider to {}", bound_address);
 
     Ok(Service::new_with_params(
-        GraphqlService { bound_address },
-        ServerParams { router, listener },
+        GraphqlService {
+            bound_address,
+            query_stats,
+        },
+        ServerParams {
+            router,
+            listener,
+            message_proofs,
+        },
     ))
 }
 
@@ -239,6 +395,55 @@ async fn graphql_subscription_handler(
         .keep_alive(axum::response::sse::KeepAlive::new().text("keep-alive-text"))
 }
 
+async fn graphql_ws_handler(
+    schema: Extension<CoreSchema>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> axum::response::Response {
+    let protocol = headers
+        .get(SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|protocols| {
+            protocols
+                .split(',')
+                .find_map(|protocol| WebSocketProtocols::from_str(protocol.trim()).ok())
+        });
+    let Some(protocol) = protocol else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Unsupported Sec-WebSocket-Protocol",
+        )
+            .into_response()
+    };
+    let schema = schema.0;
+    ws.protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| graphql_ws(schema, socket, protocol))
+}
+
+async fn graphql_ws(schema: CoreSchema, socket: WebSocket, protocol: WebSocketProtocols) {
+    let (mut sink, stream) = futures::StreamExt::split(socket);
+    let input = stream
+        .take_while(|message| message.is_ok())
+        .filter_map(|message| match message {
+            Ok(Message::Text(text)) => Some(text.into_bytes()),
+            Ok(Message::Binary(bytes)) => Some(bytes),
+            _ => None,
+        });
+    let mut output =
+        GraphQLWebSocket::new(schema, input, protocol).map(|message| match message {
+            WsMessage::Text(text) => Message::Text(text),
+            WsMessage::Close(code, reason) => Message::Close(Some(CloseFrame {
+                code,
+                reason: reason.into(),
+            })),
+        });
+    while let Some(message) = output.next().await {
+        if sink.send(message).await.is_err() {
+            break
+        }
+    }
+}
+
 async fn ok() -> anyhow::Result<(), ()> {
     Ok(())
 }
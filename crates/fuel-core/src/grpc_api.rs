@@ -0,0 +1,24 @@
+//! The gRPC API is the binary alternative to the GraphQL API for the integrators whose
+//! throughput is limited by the JSON encoding. It exposes the core operations only, and
+//! its schema is defined in the `proto/fuel.proto`.
+
+use fuel_core_types::fuel_types::ChainId;
+use std::net::SocketAddr;
+
+pub mod service;
+
+/// The types and the client generated from the `proto/fuel.proto`.
+#[allow(clippy::all)]
+pub mod proto {
+    tonic::include_proto!("fuel.v1");
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The address of the gRPC API server.
+    pub addr: SocketAddr,
+    /// The id of the chain used to calculate the ids of the submitted transactions.
+    pub chain_id: ChainId,
+    /// Rejects the submission of the transactions if `true`.
+    pub read_only: bool,
+}
@@ -0,0 +1,349 @@
+use crate::{
+    fuel_core_graphql_api::service::{
+        Database,
+        TxPool,
+    },
+    grpc_api::{
+        proto::{
+            self,
+            fuel_server::{
+                Fuel,
+                FuelServer,
+            },
+            get_block_request,
+            transaction_status,
+        },
+        Config,
+    },
+    query::{
+        BlockQueryData,
+        SimpleBlockData,
+        SimpleTransactionData,
+    },
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    fuel_tx::{
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        canonical::{
+            Deserialize,
+            Serialize,
+        },
+        Bytes32,
+    },
+    services::txpool::TransactionStatus,
+};
+use futures::{
+    stream::BoxStream,
+    StreamExt,
+};
+use std::{
+    future::Future,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    pin::Pin,
+    sync::Arc,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+
+pub type Service = fuel_core_services::ServiceRunner<GrpcService>;
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub bound_address: SocketAddr,
+}
+
+pub struct GrpcService {
+    bound_address: SocketAddr,
+}
+
+pub struct ServerParams {
+    api: FuelApi,
+    listener: TcpListener,
+}
+
+pub struct Task {
+    server: Pin<Box<dyn Future<Output = Result<(), tonic::transport::Error>> + Send>>,
+}
+
+/// Implements the `Fuel` service of the `proto/fuel.proto`.
+struct FuelApi {
+    config: Config,
+    database: Database,
+    txpool: TxPool,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for GrpcService {
+    const NAME: &'static str = "GrpcAPI";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            bound_address: self.bound_address,
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let ServerParams { api, listener } = params;
+        listener.set_nonblocking(true)?;
+        let incoming =
+            TcpListenerStream::new(tokio::net::TcpListener::from_std(listener)?);
+
+        let server = tonic::transport::Server::builder()
+            .add_service(FuelServer::new(api))
+            .serve_with_incoming_shutdown(incoming, async move {
+                state
+                    .while_started()
+                    .await
+                    .expect("The service is destroyed");
+            });
+
+        Ok(Task {
+            server: Box::pin(server),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        // The server has its internal loop. If `await` is finished, we get an internal
+        // error or stop signal.
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The server was already gracefully shutdown at this point.
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    config: Config,
+    database: Database,
+    txpool: TxPool,
+) -> anyhow::Result<Service> {
+    let listener = TcpListener::bind(config.addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding gRPC API to {}", bound_address);
+
+    let api = FuelApi {
+        config,
+        database,
+        txpool,
+    };
+    Ok(Service::new_with_params(
+        GrpcService { bound_address },
+        ServerParams { api, listener },
+    ))
+}
+
+#[tonic::async_trait]
+impl Fuel for FuelApi {
+    async fn submit_transaction(
+        &self,
+        request: Request<proto::SubmitTransactionRequest>,
+    ) -> Result<Response<proto::SubmitTransactionResponse>, Status> {
+        if self.config.read_only {
+            return Err(Status::failed_precondition(
+                "The node is a read replica and doesn't accept the transactions",
+            ))
+        }
+        let tx = Transaction::from_bytes(&request.into_inner().transaction)
+            .map_err(|e| Status::invalid_argument(format!("{e:?}")))?;
+        let id = tx.id(&self.config.chain_id);
+
+        for result in self.txpool.insert(vec![Arc::new(tx)]).await {
+            result.map_err(|e| Status::invalid_argument(e.to_string()))?;
+        }
+
+        Ok(Response::new(proto::SubmitTransactionResponse {
+            id: id.to_vec(),
+        }))
+    }
+
+    async fn get_block(
+        &self,
+        request: Request<proto::GetBlockRequest>,
+    ) -> Result<Response<proto::GetBlockResponse>, Status> {
+        let id = match request.into_inner().block {
+            Some(get_block_request::Block::Height(height)) => self
+                .database
+                .block_id(&height.into())
+                .map_err(storage_error)?,
+            Some(get_block_request::Block::Id(id)) => parse_id(&id)?.into(),
+            None => return Err(Status::invalid_argument("The block is not specified")),
+        };
+        let block = self.database.block(&id).map_err(storage_error)?;
+
+        Ok(Response::new(proto::GetBlockResponse {
+            block: Some(block_to_proto(id, &block)),
+        }))
+    }
+
+    async fn get_transaction(
+        &self,
+        request: Request<proto::GetTransactionRequest>,
+    ) -> Result<Response<proto::GetTransactionResponse>, Status> {
+        let id = parse_id(&request.into_inner().id)?;
+        let tx = match self.database.transaction(&id) {
+            Ok(tx) => tx,
+            Err(StorageError::NotFound(_, _)) => self
+                .txpool
+                .transaction(id)
+                .ok_or_else(|| Status::not_found("The transaction is not found"))?,
+            Err(e) => return Err(storage_error(e)),
+        };
+        let status = self.status(&id)?;
+
+        Ok(Response::new(proto::GetTransactionResponse {
+            transaction: tx.to_bytes(),
+            status: status.map(Into::into),
+        }))
+    }
+
+    type SubscribeTransactionStatusStream =
+        BoxStream<'static, Result<proto::TransactionStatus, Status>>;
+
+    async fn subscribe_transaction_status(
+        &self,
+        request: Request<proto::SubscribeTransactionStatusRequest>,
+    ) -> Result<Response<Self::SubscribeTransactionStatusStream>, Status> {
+        let id = parse_id(&request.into_inner().id)?;
+        // Subscribes before the check of the current status to not miss the updates.
+        let updates = self.txpool.tx_update_subscribe(id).await;
+        let current = self.status(&id)?.map(TxStatusMessage::Status);
+
+        let stream = futures::stream::iter(current).chain(updates).scan(
+            false,
+            |finished, message| {
+                if *finished {
+                    return futures::future::ready(None)
+                }
+                let status = match message {
+                    TxStatusMessage::Status(status) => {
+                        *finished =
+                            !matches!(status, TransactionStatus::Submitted { .. });
+                        Ok(status.into())
+                    }
+                    TxStatusMessage::FailedStatus => {
+                        *finished = true;
+                        Err(Status::internal("Failed to get the transaction status"))
+                    }
+                };
+                futures::future::ready(Some(status))
+            },
+        );
+
+        Ok(Response::new(stream.boxed()))
+    }
+}
+
+impl FuelApi {
+    /// Returns the status from the database or the `TxPool`.
+    fn status(&self, id: &TxId) -> Result<Option<TransactionStatus>, Status> {
+        match self.database.tx_status(id) {
+            Ok(status) => Ok(Some(status)),
+            Err(StorageError::NotFound(_, _)) => Ok(self
+                .txpool
+                .submission_time(*id)
+                .map(|time| TransactionStatus::Submitted { time })),
+            Err(e) => Err(storage_error(e)),
+        }
+    }
+}
+
+fn parse_id(bytes: &[u8]) -> Result<Bytes32, Status> {
+    Bytes32::try_from(bytes)
+        .map_err(|_| Status::invalid_argument("The id must be 32 bytes"))
+}
+
+fn storage_error(error: StorageError) -> Status {
+    match error {
+        StorageError::NotFound(_, _) => Status::not_found(error.to_string()),
+        error => Status::internal(error.to_string()),
+    }
+}
+
+fn block_to_proto(id: BlockId, block: &CompressedBlock) -> proto::Block {
+    let header = block.header();
+    proto::Block {
+        id: id.as_slice().to_vec(),
+        header: Some(proto::BlockHeader {
+            da_height: header.da_height.0,
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root.to_vec(),
+            message_receipt_root: header.message_receipt_root.to_vec(),
+            prev_root: header.prev_root().to_vec(),
+            height: (*header.height()).into(),
+            time: header.time().0,
+            application_hash: header.application_hash().to_vec(),
+        }),
+        transaction_ids: block.transactions().iter().map(|id| id.to_vec()).collect(),
+    }
+}
+
+impl From<TransactionStatus> for proto::TransactionStatus {
+    fn from(status: TransactionStatus) -> Self {
+        let status = match status {
+            TransactionStatus::Submitted { time } => {
+                transaction_status::Status::Submitted(proto::Submitted { time: time.0 })
+            }
+            TransactionStatus::Success { block_id, time, .. } => {
+                transaction_status::Status::Success(proto::Success {
+                    block_id: block_id.as_slice().to_vec(),
+                    time: time.0,
+                })
+            }
+            TransactionStatus::SqueezedOut { reason } => {
+                transaction_status::Status::SqueezedOut(proto::SqueezedOut { reason })
+            }
+            TransactionStatus::Failed {
+                block_id,
+                time,
+                reason,
+                ..
+            } => transaction_status::Status::Failed(proto::Failed {
+                block_id: block_id.as_slice().to_vec(),
+                time: time.0,
+                reason,
+            }),
+        };
+        proto::TransactionStatus {
+            status: Some(status),
+        }
+    }
+}
@@ -3,6 +3,10 @@
 
 #[doc(no_inline)]
 pub use fuel_core_chain_config as chain_config;
+#[doc(no_inline)]
+pub use fuel_core_gas_price_service as gas_price;
+#[doc(no_inline)]
+pub use fuel_core_importer as importer;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
@@ -11,6 +15,8 @@ pub use fuel_core_producer as producer;
 #[cfg(feature = "relayer")]
 #[doc(no_inline)]
 pub use fuel_core_relayer as relayer;
+#[doc(no_inline)]
+pub use fuel_core_services as services;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_sync as sync;
@@ -19,9 +25,12 @@ pub use fuel_core_txpool as txpool;
 #[doc(no_inline)]
 pub use fuel_core_types as types;
 
+pub mod admin_api;
 pub mod coins_query;
 pub mod database;
 pub mod executor;
+#[cfg(feature = "grpc")]
+pub mod grpc_api;
 pub mod model;
 #[cfg(all(feature = "p2p", feature = "test-helpers"))]
 pub mod p2p_test_helpers;
@@ -29,6 +38,8 @@ pub mod query;
 pub mod schema;
 pub mod service;
 pub mod state;
+#[cfg(feature = "test-helpers")]
+pub mod test_helpers;
 
 // In the future this module will be a separate crate for `fuel-core-graphql-api`.
 mod graphql_api;
@@ -71,6 +71,9 @@ pub struct ProducerSetup {
     pub secret: SecretKey,
     /// Number of test transactions to create for this producer.
     pub num_test_txs: usize,
+    /// The byzantine faults injected by the producer.
+    #[cfg(feature = "fault-injection")]
+    pub faults: crate::service::fault_injection::FaultInjectionConfig,
 }
 
 #[derive(Clone)]
@@ -114,7 +117,9 @@ impl Bootstrap {
     pub async fn new(node_config: &Config) -> Self {
         let bootstrap_config = extract_p2p_config(node_config);
         let codec = PostcardCodec::new(bootstrap_config.max_block_size);
-        let mut bootstrap = FuelP2PService::new(bootstrap_config, codec);
+        let (reserved_peers_updates, _) = broadcast::channel(1);
+        let mut bootstrap =
+            FuelP2PService::new(reserved_peers_updates, bootstrap_config, codec);
         bootstrap.start().await.unwrap();
 
         let listeners = bootstrap.multiaddrs();
@@ -229,11 +234,10 @@ pub async fn make_nodes(
                         chain_config.clone(),
                     );
                     if let Some(BootstrapSetup { pub_key, .. }) = boot {
-                        match &mut node_config.chain_conf.consensus {
-                            crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                                *signing_key = pub_key;
-                            }
-                        }
+                        node_config.chain_conf.consensus =
+                            crate::chain_config::ConsensusConfig::PoA {
+                                signing_key: pub_key,
+                            };
                     }
                     Bootstrap::new(&node_config).await
                 }
@@ -258,15 +262,19 @@ pub async fn make_nodes(
         node_config.block_production = Trigger::Instant;
         node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
 
-        if let Some((ProducerSetup { secret, .. }, txs)) = s {
+        if let Some((producer, txs)) = s {
+            let secret = producer.secret;
             let pub_key = secret.public_key();
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = Input::owner(&pub_key);
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: Input::owner(&pub_key),
+                };
 
             node_config.consensus_key = Some(Secret::new(secret.into()));
+            #[cfg(feature = "fault-injection")]
+            {
+                node_config.fault_injection = producer.faults;
+            }
 
             test_txs = txs;
         }
@@ -289,11 +297,10 @@ pub async fn make_nodes(
         node_config.p2p.as_mut().unwrap().bootstrap_nodes = boots.clone();
 
         if let Some(ValidatorSetup { pub_key, .. }) = s {
-            match &mut node_config.chain_conf.consensus {
-                crate::chain_config::ConsensusConfig::PoA { signing_key } => {
-                    *signing_key = pub_key;
-                }
-            }
+            node_config.chain_conf.consensus =
+                crate::chain_config::ConsensusConfig::PoA {
+                    signing_key: pub_key,
+                };
         }
         validators.push(make_node(node_config, Vec::with_capacity(0)).await)
     }
@@ -336,9 +343,14 @@ fn extract_p2p_config(node_config: &Config) -> fuel_core_p2p::config::Config {
     let bootstrap_config = node_config.p2p.clone();
     let db = Database::in_memory();
     maybe_initialize_state(node_config, &db).unwrap();
+    let (_, genesis_block_id) = db.ids_of_genesis_block().unwrap();
     bootstrap_config
         .unwrap()
-        .init(db.get_genesis().unwrap())
+        .init(
+            db.get_genesis().unwrap(),
+            node_config.chain_conf.consensus_parameters.chain_id,
+            genesis_block_id,
+        )
         .unwrap()
 }
 
@@ -453,6 +465,8 @@ impl ProducerSetup {
             name: Default::default(),
             secret,
             num_test_txs: Default::default(),
+            #[cfg(feature = "fault-injection")]
+            faults: Default::default(),
         }
     }
 
@@ -469,6 +483,14 @@ impl ProducerSetup {
             ..self
         }
     }
+
+    #[cfg(feature = "fault-injection")]
+    pub fn with_faults(
+        self,
+        faults: crate::service::fault_injection::FaultInjectionConfig,
+    ) -> Self {
+        Self { faults, ..self }
+    }
 }
 
 impl ValidatorSetup {
@@ -4,6 +4,7 @@ mod chain;
 mod coin;
 mod contract;
 mod message;
+mod receipt;
 mod subscriptions;
 mod tx;
 
@@ -14,5 +15,6 @@ pub use chain::*;
 pub use coin::*;
 pub use contract::*;
 pub use message::*;
+pub use receipt::*;
 pub(crate) use subscriptions::*;
 pub use tx::*;
@@ -1,8 +1,9 @@
-use crate::fuel_core_graphql_api::service::Database;
-use asset_query::{
-    AssetQuery,
-    AssetSpendTarget,
-    AssetsQuery,
+use crate::fuel_core_graphql_api::{
+    ports::{
+        DatabaseCoins,
+        DatabaseMessages,
+    },
+    service::Database,
 };
 use fuel_core_storage::{
     iter::{
@@ -20,10 +21,7 @@ use fuel_core_types::{
     services::graphql_api::AddressBalance,
 };
 use itertools::Itertools;
-use std::{
-    cmp::Ordering,
-    collections::HashMap,
-};
+use std::iter;
 
 pub mod asset_query;
 
@@ -50,23 +48,10 @@ impl BalanceQueryData for Database {
         asset_id: AssetId,
         base_asset_id: AssetId,
     ) -> StorageResult<AddressBalance> {
-        let amount = AssetQuery::new(
-            &owner,
-            &AssetSpendTarget::new(asset_id, u64::MAX, u64::MAX),
-            &base_asset_id,
-            None,
-            self,
-        )
-        .coins()
-        .map(|res| res.map(|coins| coins.amount()))
-        .try_fold(0u64, |mut balance, res| -> StorageResult<_> {
-            let amount = res?;
-
-            // Increase the balance
-            balance += amount;
-
-            Ok(balance)
-        })?;
+        let mut amount = self.owned_coins_balance(&owner, &asset_id)?;
+        if asset_id == base_asset_id {
+            amount = add_messages_balance(amount, self.owned_messages_balance(&owner)?)?;
+        }
 
         Ok(AddressBalance {
             owner,
@@ -81,47 +66,63 @@ impl BalanceQueryData for Database {
         direction: IterDirection,
         base_asset_id: AssetId,
     ) -> BoxedIter<StorageResult<AddressBalance>> {
-        let mut amounts_per_asset = HashMap::new();
-        let mut errors = vec![];
-
-        for coin in AssetsQuery::new(&owner, None, None, self, &base_asset_id).coins() {
-            match coin {
-                Ok(coin) => {
-                    *amounts_per_asset
-                        .entry(*coin.asset_id(&base_asset_id))
-                        .or_default() += coin.amount();
-                }
-                Err(err) => {
-                    errors.push(err);
-                }
-            }
+        match owned_balances(self, owner, direction, base_asset_id) {
+            Ok(balances) => balances.into_iter().map(Ok).into_boxed(),
+            Err(err) => iter::once(Err(err)).into_boxed(),
         }
+    }
+}
 
-        let mut balances = amounts_per_asset
-            .into_iter()
-            .map(|(asset_id, amount)| AddressBalance {
-                owner,
-                amount,
-                asset_id,
-            })
-            .collect_vec();
+/// Adds the balance of the messages spendable as the base asset to the `coins` balance.
+fn add_messages_balance(coins: u64, messages: u64) -> StorageResult<u64> {
+    coins
+        .checked_add(messages)
+        .ok_or_else(|| anyhow::anyhow!("The balance of the base asset overflows").into())
+}
 
-        balances.sort_by(|l, r| {
-            if l.asset_id < r.asset_id {
-                Ordering::Less
-            } else {
-                Ordering::Greater
-            }
-        });
+/// Returns the balances of the `owner` ordered by the asset id in the `direction`.
+/// The balances are read from the index, so it doesn't iterate over the coins.
+fn owned_balances(
+    database: &Database,
+    owner: Address,
+    direction: IterDirection,
+    base_asset_id: AssetId,
+) -> StorageResult<Vec<AddressBalance>> {
+    let mut balances: Vec<_> = database
+        .owned_coins_balances(&owner, direction)
+        .map_ok(|(asset_id, amount)| AddressBalance {
+            owner,
+            amount,
+            asset_id,
+        })
+        .try_collect()?;
 
-        if direction == IterDirection::Reverse {
-            balances.reverse();
+    // The messages without data are spendable as the base asset.
+    let messages_amount = database.owned_messages_balance(&owner)?;
+    if messages_amount > 0 {
+        match balances
+            .iter_mut()
+            .find(|balance| balance.asset_id == base_asset_id)
+        {
+            Some(balance) => {
+                balance.amount = add_messages_balance(balance.amount, messages_amount)?;
+            }
+            None => {
+                let position = balances.partition_point(|balance| match direction {
+                    IterDirection::Forward => balance.asset_id < base_asset_id,
+                    IterDirection::Reverse => balance.asset_id > base_asset_id,
+                });
+                balances.insert(
+                    position,
+                    AddressBalance {
+                        owner,
+                        amount: messages_amount,
+                        asset_id: base_asset_id,
+                    },
+                );
+            }
         }
-
-        balances
-            .into_iter()
-            .map(Ok)
-            .chain(errors.into_iter().map(Err))
-            .into_boxed()
     }
+
+    Ok(balances)
 }
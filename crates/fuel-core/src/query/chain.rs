@@ -1,11 +1,16 @@
 use crate::graphql_api::ports::DatabasePort;
 use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::blockchain::primitives::DaBlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    services::relayer::DaFeeSample,
+};
 
 pub trait ChainQueryData: Send + Sync {
     fn name(&self) -> StorageResult<String>;
 
     fn da_height(&self) -> StorageResult<DaBlockHeight>;
+
+    fn da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>>;
 }
 
 impl<D: DatabasePort + ?Sized> ChainQueryData for D {
@@ -16,4 +21,8 @@ impl<D: DatabasePort + ?Sized> ChainQueryData for D {
     fn da_height(&self) -> StorageResult<DaBlockHeight> {
         self.da_height()
     }
+
+    fn da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>> {
+        self.da_fee_samples()
+    }
 }
@@ -1,4 +1,7 @@
-use crate::graphql_api::ports::DatabasePort;
+use crate::{
+    chain_config::ContractConfig,
+    graphql_api::ports::DatabasePort,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -16,10 +19,15 @@ use fuel_core_storage::{
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        BlockHeight,
+        Bytes32,
         ContractId,
     },
     fuel_vm::Salt,
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractCalls,
+    },
 };
 
 pub trait ContractQueryData: Send + Sync {
@@ -29,6 +37,8 @@ pub trait ContractQueryData: Send + Sync {
 
     fn contract_salt(&self, id: ContractId) -> StorageResult<Salt>;
 
+    fn contract_root(&self, id: ContractId) -> StorageResult<Bytes32>;
+
     fn contract_balance(
         &self,
         contract_id: ContractId,
@@ -41,6 +51,26 @@ pub trait ContractQueryData: Send + Sync {
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_slot_values(
+        &self,
+        contract_id: &ContractId,
+        slots: &[Bytes32],
+        height: Option<BlockHeight>,
+    ) -> StorageResult<Option<Vec<Option<Bytes32>>>>;
+
+    fn contract_config_at(
+        &self,
+        contract_id: &ContractId,
+        height: Option<BlockHeight>,
+        max_entries: usize,
+    ) -> StorageResult<Option<ContractConfig>>;
+
+    fn top_contracts(
+        &self,
+        from: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<ContractCalls>>;
 }
 
 impl<D: DatabasePort + ?Sized> ContractQueryData for D {
@@ -73,6 +103,16 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
         Ok(salt)
     }
 
+    fn contract_root(&self, id: ContractId) -> StorageResult<Bytes32> {
+        let (_, root) = self
+            .storage::<ContractsInfo>()
+            .get(&id)?
+            .ok_or(not_found!(ContractsInfo))?
+            .into_owned();
+
+        Ok(root)
+    }
+
     fn contract_balance(
         &self,
         contract_id: ContractId,
@@ -99,4 +139,30 @@ impl<D: DatabasePort + ?Sized> ContractQueryData for D {
     ) -> BoxedIter<StorageResult<ContractBalance>> {
         self.contract_balances(contract_id, start_asset, direction)
     }
+
+    fn contract_slot_values(
+        &self,
+        contract_id: &ContractId,
+        slots: &[Bytes32],
+        height: Option<BlockHeight>,
+    ) -> StorageResult<Option<Vec<Option<Bytes32>>>> {
+        self.contract_slot_values(contract_id, slots, height)
+    }
+
+    fn contract_config_at(
+        &self,
+        contract_id: &ContractId,
+        height: Option<BlockHeight>,
+        max_entries: usize,
+    ) -> StorageResult<Option<ContractConfig>> {
+        self.contract_config_at(contract_id, height, max_entries)
+    }
+
+    fn top_contracts(
+        &self,
+        from: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<ContractCalls>> {
+        self.top_contracts(from, limit)
+    }
 }
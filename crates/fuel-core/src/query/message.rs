@@ -13,6 +13,11 @@ use crate::{
         TransactionQueryData,
     },
 };
+use fuel_core_services::{
+    Resource,
+    ResourceBudget,
+    ResourcePermit,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -20,7 +25,10 @@ use fuel_core_storage::{
         IterDirection,
     },
     not_found,
-    tables::Messages,
+    tables::{
+        MessageDeposits,
+        Messages,
+    },
     Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
@@ -33,6 +41,7 @@ use fuel_core_types::{
     entities::message::{
         MerkleProof,
         Message,
+        MessageDeposit,
         MessageProof,
         MessageStatus,
     },
@@ -44,6 +53,7 @@ use fuel_core_types::{
     },
     fuel_types::{
         Address,
+        BlockHeight,
         Bytes32,
         MessageId,
         Nonce,
@@ -51,7 +61,13 @@ use fuel_core_types::{
     services::txpool::TransactionStatus,
 };
 use itertools::Itertools;
-use std::borrow::Cow;
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
 #[cfg(test)]
 mod test;
@@ -137,10 +153,140 @@ impl<D: DatabasePort + ?Sized> MessageProofData for D {
     }
 }
 
+/// The Merkle tree of the output messages of the block.
+#[derive(Debug, Clone)]
+pub struct BlockMessages {
+    message_ids: Vec<MessageId>,
+    tree: MerkleTree,
+}
+
+impl BlockMessages {
+    fn new<T: MessageProofData + ?Sized>(
+        database: &T,
+        message_block_txs: &[Bytes32],
+    ) -> StorageResult<Self> {
+        // Get the message receipts from the block.
+        let leaves: Vec<Vec<Receipt>> = message_block_txs
+            .iter()
+            .map(|id| database.receipts(id))
+            .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
+            .try_collect()?;
+        // Flatten the receipts after filtering on output messages
+        // and mapping to message ids.
+        let message_ids: Vec<_> = leaves
+            .into_iter()
+            .flat_map(|receipts| receipts.into_iter().filter_map(|r| r.message_id()))
+            .collect();
+
+        let mut tree = MerkleTree::new();
+        for id in message_ids.iter() {
+            tree.push(id.as_ref());
+        }
+
+        Ok(Self { message_ids, tree })
+    }
+
+    /// Returns the approximate memory used by the ids and the tree of the messages.
+    fn memory_size(&self) -> usize {
+        // Each message takes its id, the leaf and the node of the tree.
+        core::mem::size_of::<Self>()
+            + self.message_ids.len() * 3 * core::mem::size_of::<MessageId>()
+    }
+
+    /// Generates the proof of the message in the block.
+    pub fn prove(&self, message_id: &MessageId) -> Option<MerkleProof> {
+        let proof_index = self.message_ids.iter().position(|id| id == message_id)? as u64;
+        let (_, proof_set) = self.tree.prove(proof_index)?;
+        Some(MerkleProof {
+            proof_set,
+            proof_index,
+        })
+    }
+}
+
+/// The LRU cache of the [`BlockMessages`] keyed by the block id. The trees are built
+/// once per block instead of once per proof request.
+pub struct MessageProofCache {
+    blocks: Option<Mutex<LruCache<BlockId, CachedBlockMessages>>>,
+    resource_budget: Option<ResourceBudget>,
+}
+
+/// The cached messages with the memory acquired from the resource budget.
+struct CachedBlockMessages {
+    messages: Arc<BlockMessages>,
+    _permit: Option<ResourcePermit>,
+}
+
+impl MessageProofCache {
+    /// Creates the cache for the `capacity` blocks. The cache is disabled if it is `0`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            blocks: NonZeroUsize::new(capacity)
+                .map(|capacity| Mutex::new(LruCache::new(capacity))),
+            resource_budget: None,
+        }
+    }
+
+    /// Takes the memory of the cached trees from the `budget`. The trees that don't
+    /// fit into the budget are built for each request without caching.
+    pub fn with_resource_budget(mut self, budget: ResourceBudget) -> Self {
+        self.resource_budget = Some(budget);
+        self
+    }
+
+    /// Returns the message tree of the block, building it if it is not cached.
+    pub fn block_messages<T: MessageProofData + ?Sized>(
+        &self,
+        database: &T,
+        block_id: &BlockId,
+        message_block_txs: &[Bytes32],
+    ) -> StorageResult<Arc<BlockMessages>> {
+        let Some(blocks) = &self.blocks else {
+            return Ok(Arc::new(BlockMessages::new(database, message_block_txs)?))
+        };
+        if let Some(cached) = blocks.lock().get(block_id) {
+            return Ok(cached.messages.clone())
+        }
+        let messages = Arc::new(BlockMessages::new(database, message_block_txs)?);
+        let permit = match &self.resource_budget {
+            Some(budget) => {
+                match budget.try_acquire(Resource::CacheMemory, messages.memory_size()) {
+                    Ok(permit) => Some(permit),
+                    Err(_) => return Ok(messages),
+                }
+            }
+            None => None,
+        };
+        blocks.lock().put(
+            *block_id,
+            CachedBlockMessages {
+                messages: messages.clone(),
+                _permit: permit,
+            },
+        );
+        Ok(messages)
+    }
+
+    /// Builds and caches the message tree of the block ahead of the proof requests.
+    pub fn precompute<T: MessageProofData + ?Sized>(
+        &self,
+        database: &T,
+        block_id: &BlockId,
+    ) -> StorageResult<()> {
+        if self.blocks.is_none() {
+            return Ok(())
+        }
+        let block = database.block(block_id)?;
+        self.block_messages(database, block_id, block.transactions())?;
+        Ok(())
+    }
+}
+
 /// Generate an output proof.
 // TODO: Do we want to return `Option` here?
 pub fn message_proof<T: MessageProofData + ?Sized>(
     database: &T,
+    cache: &MessageProofCache,
     transaction_id: Bytes32,
     desired_nonce: Nonce,
     commit_block_id: BlockId,
@@ -190,11 +336,13 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
 
     let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
 
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
-            Some(proof) => proof,
-            None => return Ok(None),
-        };
+    let message_proof = match cache
+        .block_messages(database, &message_block_id, &message_block_txs)?
+        .prove(&message_id)
+    {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
 
     // Get the commit fuel block header.
     let commit_block_header = match database
@@ -229,53 +377,30 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
     }))
 }
 
-fn message_receipts_proof<T: MessageProofData + ?Sized>(
+/// Returns the height of the block with the transaction and the nonces of the messages
+/// sent by it. Returns `None` if the transaction is not in a block or it failed.
+pub fn transaction_output_messages<T: MessageProofData + ?Sized>(
     database: &T,
-    message_id: MessageId,
-    message_block_txs: &[Bytes32],
-) -> StorageResult<Option<MerkleProof>> {
-    // Get the message receipts from the block.
-    let leaves: Vec<Vec<Receipt>> = message_block_txs
+    transaction_id: &TxId,
+) -> StorageResult<Option<(BlockHeight, Vec<Nonce>)>> {
+    let block_id = match database
+        .transaction_status(transaction_id)
+        .into_api_result::<TransactionStatus, StorageError>()?
+    {
+        Some(TransactionStatus::Success { block_id, .. }) => block_id,
+        _ => return Ok(None),
+    };
+    let block_height = *database.block(&block_id)?.header().height();
+    let nonces = database
+        .receipts(transaction_id)?
         .iter()
-        .map(|id| database.receipts(id))
-        .filter_map(|result| result.into_api_result::<_, StorageError>().transpose())
-        .try_collect()?;
-    let leaves = leaves.into_iter()
-        // Flatten the receipts after filtering on output messages
-        // and mapping to message ids.
-        .flat_map(|receipts|
-            receipts.into_iter().filter_map(|r| r.message_id()));
-
-    // Build the merkle proof from the above iterator.
-    let mut tree = MerkleTree::new();
-
-    let mut proof_index = None;
-
-    for (index, id) in leaves.enumerate() {
-        // Check if this is the message id being proved.
-        if message_id == id {
-            // Save the index of this message to use as the proof index.
-            proof_index = Some(index as u64);
-        }
-
-        // Build the merkle tree.
-        tree.push(id.as_ref());
-    }
+        .filter_map(|receipt| match receipt {
+            Receipt::MessageOut { .. } => receipt.nonce().copied(),
+            _ => None,
+        })
+        .collect();
 
-    // If we found the leaf proof index then return the proof.
-    match proof_index {
-        Some(proof_index) => {
-            // Generate the actual merkle proof.
-            match tree.prove(proof_index) {
-                Some((_, proof_set)) => Ok(Some(MerkleProof {
-                    proof_set,
-                    proof_index,
-                })),
-                None => Ok(None),
-            }
-        }
-        None => Ok(None),
-    }
+    Ok(Some((block_height, nonces)))
 }
 
 pub fn message_status<T: DatabaseMessages + ?Sized>(
@@ -290,3 +415,73 @@ pub fn message_status<T: DatabaseMessages + ?Sized>(
         Ok(MessageStatus::not_found())
     }
 }
+
+/// The lifecycle of the deposit of the message from the da layer.
+pub struct DepositStatus {
+    /// The nonce of the message.
+    pub nonce: Nonce,
+    /// The current status of the message.
+    pub status: MessageStatus,
+    /// The da transaction that sent the message, if it was received by the relayer.
+    pub deposit: Option<MessageDeposit>,
+    /// The message while it is not spent.
+    pub message: Option<Message>,
+    /// The transaction that spent the message.
+    pub spending_transaction: Option<TxId>,
+    /// The height of the block with the spending transaction.
+    pub spending_block_height: Option<BlockHeight>,
+}
+
+/// Returns the lifecycle of the deposit of the message with the `nonce`.
+pub fn deposit_status<T: DatabasePort + ?Sized>(
+    database: &T,
+    nonce: Nonce,
+) -> StorageResult<DepositStatus> {
+    let status = message_status(database, nonce)?;
+    let deposit = database
+        .storage::<MessageDeposits>()
+        .get(&nonce)?
+        .map(Cow::into_owned);
+    let message = database
+        .storage::<Messages>()
+        .get(&nonce)?
+        .map(Cow::into_owned);
+
+    let spending_transaction = database.message_spender(&nonce)?;
+    let spending_block_height = match spending_transaction {
+        Some(tx_id) => {
+            let block_id = match database.tx_status(&tx_id)? {
+                TransactionStatus::Success { block_id, .. }
+                | TransactionStatus::Failed { block_id, .. } => block_id,
+                _ => {
+                    return Err(anyhow::anyhow!(
+                        "The transaction that spent the message is not in a block"
+                    )
+                    .into())
+                }
+            };
+            Some(*database.block(&block_id)?.header().height())
+        }
+        None => None,
+    };
+
+    Ok(DepositStatus {
+        nonce,
+        status,
+        deposit,
+        message,
+        spending_transaction,
+        spending_block_height,
+    })
+}
+
+/// Returns the lifecycles of the deposits of all messages sent by the da transaction.
+pub fn da_transaction_deposits<T: DatabasePort + ?Sized>(
+    database: &T,
+    da_transaction: &Bytes32,
+) -> StorageResult<Vec<DepositStatus>> {
+    database
+        .da_transaction_message_ids(da_transaction)
+        .map(|nonce| nonce.and_then(|nonce| deposit_status(database, nonce)))
+        .try_collect()
+}
@@ -201,6 +201,7 @@ async fn can_build_message_proof() {
 
     let proof = message_proof(
         data.deref(),
+        &MessageProofCache::new(0),
         transaction_id,
         nonce.to_owned(),
         commit_block.id(),
@@ -221,3 +222,67 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[test]
+fn message_proof_cache_builds_tree_once_per_block() {
+    let receipts = [receipt(Some(1)), receipt(None), receipt(Some(2))];
+    let message_ids: Vec<MessageId> =
+        receipts.iter().filter_map(|r| r.message_id()).collect();
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts()
+        .once()
+        .returning(move |_| Ok(receipts.to_vec()));
+    let cache = MessageProofCache::new(1);
+    let block_id = BlockId::from([1; 32]);
+    let txs = [txn_id(1)];
+
+    let first = cache.block_messages(&data, &block_id, &txs).unwrap();
+    let second = cache.block_messages(&data, &block_id, &txs).unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    let proof = second.prove(&message_ids[1]).unwrap();
+    assert_eq!(proof.proof_index, 1);
+    let mut tree = MerkleTree::new();
+    for id in message_ids.iter() {
+        tree.push(id.as_ref());
+    }
+    assert_eq!(proof.proof_set, tree.prove(1).unwrap().1);
+    assert!(second.prove(&MessageId::zeroed()).is_none());
+}
+
+#[test]
+fn message_proof_cache_evicts_least_recently_used_block() {
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts()
+        .times(3)
+        .returning(|_| Ok(vec![receipt(Some(1))]));
+    let cache = MessageProofCache::new(1);
+    let txs = [txn_id(1)];
+    let first_block = BlockId::from([1; 32]);
+    let second_block = BlockId::from([2; 32]);
+
+    cache.block_messages(&data, &first_block, &txs).unwrap();
+    cache.block_messages(&data, &second_block, &txs).unwrap();
+    // The first block was evicted by the second one, so its tree is built again.
+    cache.block_messages(&data, &first_block, &txs).unwrap();
+}
+
+#[test]
+fn message_proof_cache_skips_blocks_exceeding_the_memory_budget() {
+    let mut data = MockProofDataStorage::new();
+    data.expect_receipts()
+        .times(2)
+        .returning(|_| Ok(vec![receipt(Some(1))]));
+    let budget = ResourceBudget::new(fuel_core_services::ResourceLimits {
+        cache_memory: Some(1),
+        ..Default::default()
+    });
+    let cache = MessageProofCache::new(1).with_resource_budget(budget.clone());
+    let block_id = BlockId::from([1; 32]);
+    let txs = [txn_id(1)];
+
+    cache.block_messages(&data, &block_id, &txs).unwrap();
+    // The tree doesn't fit into the budget, so it is built again.
+    cache.block_messages(&data, &block_id, &txs).unwrap();
+    assert_eq!(budget.usage(Resource::CacheMemory).used, 0);
+}
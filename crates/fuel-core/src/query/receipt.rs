@@ -0,0 +1,141 @@
+use crate::{
+    fuel_core_graphql_api::IntoApiResult,
+    query::MessageProofData,
+};
+use fuel_core_storage::{
+    Error as StorageError,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    entities::{
+        message::MerkleProof,
+        receipt::ReceiptProof,
+    },
+    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_tx::{
+        field::ReceiptsRoot,
+        Receipt,
+        Transaction,
+        TxId,
+    },
+    fuel_types::canonical::Serialize,
+    services::txpool::TransactionStatus,
+};
+
+/// Generates the proof that the receipt at `receipt_index` was produced by the script
+/// transaction. Only the script transactions commit to their receipts, so the proof is
+/// `None` for other transactions.
+pub fn receipt_proof<T: MessageProofData + ?Sized>(
+    database: &T,
+    transaction_id: TxId,
+    receipt_index: u32,
+    commit_block_id: BlockId,
+) -> StorageResult<Option<ReceiptProof>> {
+    let receipts = match database
+        .receipts(&transaction_id)
+        .into_api_result::<Vec<Receipt>, StorageError>()?
+    {
+        Some(receipts) => receipts,
+        None => return Ok(None),
+    };
+    let receipt = match receipts.get(receipt_index as usize) {
+        Some(receipt) => receipt.clone(),
+        None => return Ok(None),
+    };
+
+    let transaction = database.transaction(&transaction_id)?;
+    let receipts_root = match &transaction {
+        Transaction::Script(script) => *script.receipts_root(),
+        Transaction::Create(_) | Transaction::Mint(_) => return Ok(None),
+    };
+
+    // Get the block id from the transaction status if it's included into the block.
+    let block_id = match database
+        .transaction_status(&transaction_id)
+        .into_api_result::<TransactionStatus, StorageError>()?
+    {
+        Some(TransactionStatus::Success { block_id, .. })
+        | Some(TransactionStatus::Failed { block_id, .. }) => block_id,
+        _ => return Ok(None),
+    };
+
+    let mut receipt_tree = MerkleTree::new();
+    for receipt in receipts.iter() {
+        receipt_tree.push(receipt.to_bytes().as_slice());
+    }
+    if receipt_tree.root() != *receipts_root {
+        return Err(anyhow::anyhow!(
+            "The receipts of the transaction don't match its receipts root"
+        )
+        .into())
+    }
+    let receipt_proof = match prove(&receipt_tree, receipt_index as u64) {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
+
+    // Get the fuel block header with the transaction.
+    let (block_header, block_txs) = match database
+        .block(&block_id)
+        .into_api_result::<CompressedBlock, StorageError>()?
+    {
+        Some(t) => t.into_inner(),
+        None => return Ok(None),
+    };
+
+    // The leaves of the transactions root are the serialized transactions.
+    let mut transaction_tree = MerkleTree::new();
+    for id in block_txs.iter() {
+        let tx = database.transaction(id)?;
+        transaction_tree.push(tx.to_bytes().as_slice());
+    }
+    let transaction_index = match block_txs.iter().position(|id| *id == transaction_id) {
+        Some(index) => index as u64,
+        None => return Ok(None),
+    };
+    let transaction_proof = match prove(&transaction_tree, transaction_index) {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
+
+    // Get the commit fuel block header.
+    let commit_block_header = match database
+        .block(&commit_block_id)
+        .into_api_result::<CompressedBlock, StorageError>()?
+    {
+        Some(t) => t.into_inner().0,
+        None => return Ok(None),
+    };
+
+    let block_height = *commit_block_header.height();
+    if block_height == 0u32.into() {
+        // Cannot look beyond the genesis block
+        return Ok(None)
+    }
+    let verifiable_commit_block_height = block_height - 1u32.into();
+    let block_proof = database
+        .block_history_proof(block_header.height(), &verifiable_commit_block_height)?;
+
+    Ok(Some(ReceiptProof {
+        receipt_proof,
+        transaction_proof,
+        block_proof,
+        block_header,
+        commit_block_header,
+        receipt,
+        receipts_count: receipts.len() as u64,
+        transaction,
+    }))
+}
+
+fn prove(tree: &MerkleTree, proof_index: u64) -> Option<MerkleProof> {
+    let (_, proof_set) = tree.prove(proof_index)?;
+    Some(MerkleProof {
+        proof_set,
+        proof_index,
+    })
+}
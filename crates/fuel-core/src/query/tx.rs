@@ -16,14 +16,80 @@ use fuel_core_storage::{
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Input,
+        Output,
         Receipt,
         Transaction,
         TxPointer,
     },
     fuel_types::Address,
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        ArchivedTransactionStatus,
+        TransactionStatus,
+    },
 };
 
+/// The direction of the funds in the transaction relative to the owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionDirection {
+    /// The owner receives the outputs of the transaction.
+    Incoming,
+    /// The owner spends the coins or messages in the inputs of the transaction.
+    Outgoing,
+}
+
+impl TransactionDirection {
+    /// Returns `true` if the `owner` takes part in the `tx` in this direction.
+    pub fn matches(&self, tx: &Transaction, owner: &Address) -> bool {
+        let (inputs, outputs) = match tx {
+            Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Mint(tx) => (&[][..], tx.outputs().as_slice()),
+        };
+        match self {
+            TransactionDirection::Incoming => outputs.iter().any(|output| match output {
+                Output::Coin { to, .. }
+                | Output::Change { to, .. }
+                | Output::Variable { to, .. } => to == owner,
+                Output::Contract { .. } | Output::ContractCreated { .. } => false,
+            }),
+            TransactionDirection::Outgoing => inputs.iter().any(|input| match input {
+                Input::CoinSigned(CoinSigned {
+                    owner: input_owner, ..
+                })
+                | Input::CoinPredicate(CoinPredicate {
+                    owner: input_owner, ..
+                }) => input_owner == owner,
+                Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    recipient, ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    recipient, ..
+                }) => recipient == owner,
+                Input::Contract(_) => false,
+            }),
+        }
+    }
+}
+
 pub trait SimpleTransactionData: Send + Sync {
     /// Return all receipts in the given transaction.
     fn receipts(&self, transaction_id: &TxId) -> StorageResult<Vec<Receipt>>;
@@ -49,6 +115,11 @@ impl<D: DatabasePort + ?Sized> SimpleTransactionData for D {
 pub trait TransactionQueryData: Send + Sync + SimpleTransactionData {
     fn status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    fn status_history(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Vec<ArchivedTransactionStatus>>;
+
     fn owned_transactions(
         &self,
         owner: Address,
@@ -62,6 +133,13 @@ impl<D: DatabasePort + ?Sized> TransactionQueryData for D {
         self.tx_status(tx_id)
     }
 
+    fn status_history(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Vec<ArchivedTransactionStatus>> {
+        self.tx_status_history(tx_id)
+    }
+
     fn owned_transactions(
         &self,
         owner: Address,
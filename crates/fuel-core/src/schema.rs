@@ -1,3 +1,7 @@
+use crate::{
+    fuel_core_graphql_api::service::SubscriptionBuffer,
+    service::subscription_buffer,
+};
 use anyhow::anyhow;
 use async_graphql::{
     connection::{
@@ -7,6 +11,7 @@ use async_graphql::{
         Edge,
         EmptyFields,
     },
+    Context,
     MergedObject,
     MergedSubscription,
     OutputType,
@@ -17,14 +22,18 @@ use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
 };
+use fuel_core_types::services::graphql_api::SubscriptionCursor;
 use itertools::Itertools;
 
+pub mod account;
+pub mod asset;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
 pub mod dap;
+pub mod gas_price;
 pub mod health;
 pub mod message;
 pub mod node_info;
@@ -44,13 +53,19 @@ pub struct Query(
     contract::ContractBalanceQuery,
     node_info::NodeQuery,
     message::MessageQuery,
+    gas_price::EstimateGasPriceQuery,
+    asset::AssetMetadataQuery,
 );
 
 #[derive(MergedObject, Default)]
 pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    block::BlockSubscription,
+    account::AccountSubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -189,3 +204,20 @@ where
     )
     .await
 }
+
+/// Returns the cursor to start the resumable subscription from and the events after it.
+/// Without the `resume_from`, the subscription starts from the latest buffered event.
+fn subscription_events(
+    ctx: &Context<'_>,
+    resume_from: Option<String>,
+) -> async_graphql::Result<(SubscriptionCursor, subscription_buffer::Events)> {
+    let buffer = ctx.data_opt::<SubscriptionBuffer>().ok_or_else(|| {
+        async_graphql::Error::new("The resumable subscriptions are disabled on the node")
+    })?;
+    let cursor = match resume_from {
+        Some(cursor) => cursor.parse().map_err(async_graphql::Error::new)?,
+        None => buffer.latest_cursor(),
+    };
+    let events = buffer.events_after(cursor)?;
+    Ok((cursor, events))
+}
@@ -0,0 +1,135 @@
+use crate::{
+    fuel_core_graphql_api::service::AccountWatcher,
+    schema::scalars::{
+        Address,
+        AssetId,
+        Nonce,
+        TransactionId,
+        UtxoId,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    SimpleObject,
+    Subscription,
+    Union,
+};
+use fuel_core_types::services::graphql_api;
+use futures::{
+    Stream,
+    StreamExt,
+};
+
+/// The change of the account watched by the node.
+#[derive(Union)]
+pub enum AccountEvent {
+    CoinReceived(CoinReceivedEvent),
+    CoinSpent(CoinSpentEvent),
+    MessageReceived(MessageReceivedEvent),
+}
+
+/// The account received the coin.
+#[derive(SimpleObject)]
+pub struct CoinReceivedEvent {
+    owner: Address,
+    utxo_id: UtxoId,
+    asset_id: AssetId,
+    amount: U64,
+    /// The height of the block that created the coin.
+    block_height: U32,
+}
+
+/// The account spent the coin.
+#[derive(SimpleObject)]
+pub struct CoinSpentEvent {
+    owner: Address,
+    utxo_id: UtxoId,
+    /// The transaction that spent the coin.
+    transaction_id: TransactionId,
+    /// The height of the block that spent the coin.
+    block_height: U32,
+}
+
+/// The message from the DA layer to the account became spendable.
+#[derive(SimpleObject)]
+pub struct MessageReceivedEvent {
+    recipient: Address,
+    nonce: Nonce,
+    amount: U64,
+    da_height: U64,
+    /// The height of the first block that can spend the message.
+    block_height: U32,
+}
+
+impl From<graphql_api::AccountEvent> for AccountEvent {
+    fn from(event: graphql_api::AccountEvent) -> Self {
+        match event {
+            graphql_api::AccountEvent::CoinReceived {
+                owner,
+                utxo_id,
+                asset_id,
+                amount,
+                block_height,
+            } => AccountEvent::CoinReceived(CoinReceivedEvent {
+                owner: owner.into(),
+                utxo_id: utxo_id.into(),
+                asset_id: asset_id.into(),
+                amount: amount.into(),
+                block_height: block_height.into(),
+            }),
+            graphql_api::AccountEvent::CoinSpent {
+                owner,
+                utxo_id,
+                tx_id,
+                block_height,
+            } => AccountEvent::CoinSpent(CoinSpentEvent {
+                owner: owner.into(),
+                utxo_id: utxo_id.into(),
+                transaction_id: tx_id.into(),
+                block_height: block_height.into(),
+            }),
+            graphql_api::AccountEvent::MessageReceived {
+                recipient,
+                nonce,
+                amount,
+                da_height,
+                block_height,
+            } => AccountEvent::MessageReceived(MessageReceivedEvent {
+                recipient: recipient.into(),
+                nonce: nonce.into(),
+                amount: amount.into(),
+                da_height: da_height.as_u64().into(),
+                block_height: block_height.into(),
+            }),
+        }
+    }
+}
+
+fn account_watcher<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a AccountWatcher> {
+    ctx.data_opt::<AccountWatcher>().ok_or_else(|| {
+        async_graphql::Error::new("The account watcher is disabled on the node")
+    })
+}
+
+#[derive(Default)]
+pub struct AccountSubscription;
+
+#[Subscription]
+impl AccountSubscription {
+    /// Watches the `addresses` while the subscription is active and returns a stream
+    /// of their events, starting from the next block.
+    ///
+    /// It is possible for the stream to skip events if it is polled slower
+    /// than the blocks are imported.
+    async fn account_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The addresses of the accounts.")] addresses: Vec<Address>,
+    ) -> async_graphql::Result<impl Stream<Item = AccountEvent> + 'a> {
+        let addresses = addresses.into_iter().map(Into::into).collect();
+        let events = account_watcher(ctx)?.subscribe(addresses)?;
+        Ok(events.map(Into::into))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    fuel_core_graphql_api::service::Database,
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::entities::asset;
+
+/// The metadata of the asset issued by the contract.
+pub struct AssetMetadata(asset::AssetMetadata);
+
+#[Object]
+impl AssetMetadata {
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    /// The contract that issued the asset.
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The sub identifier of the asset in the contract.
+    async fn sub_id(&self) -> Bytes32 {
+        self.0.sub_id.into()
+    }
+
+    /// The amount minted minus the amount burned.
+    async fn total_supply(&self) -> U64 {
+        self.0.total_supply.into()
+    }
+
+    /// The height of the block with the first issuance of the asset.
+    async fn first_issued_at(&self) -> U32 {
+        self.0.first_issued_at.into()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetMetadataQuery;
+
+#[Object]
+impl AssetMetadataQuery {
+    /// Returns the metadata of the asset issued by the contract. The metadata is recorded
+    /// by the asset registry, so it is `null` if the registry is disabled on the node.
+    async fn asset_metadata(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The id of the asset.")] asset_id: AssetId,
+    ) -> async_graphql::Result<Option<AssetMetadata>> {
+        let db: &Database = ctx.data_unchecked();
+        Ok(db.asset_metadata(&asset_id.0)?.map(AssetMetadata))
+    }
+}
@@ -5,6 +5,7 @@ use super::scalars::{
 use crate::{
     fuel_core_graphql_api::{
         service::{
+            BlockImporter,
             ConsensusModule,
             Database,
         },
@@ -19,10 +20,14 @@ use crate::{
     schema::{
         scalars::{
             BlockId,
+            ContractId,
+            Nonce,
             Signature,
+            UtxoId,
             U32,
             U64,
         },
+        subscription_events,
         tx::types::Transaction,
     },
 };
@@ -35,6 +40,7 @@ use async_graphql::{
     Context,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
 use fuel_core_storage::{
@@ -52,7 +58,22 @@ use fuel_core_types::{
     },
     fuel_types,
     fuel_types::BlockHeight,
+    services::graphql_api::{
+        self,
+        BlockStateChanges,
+        ContractSlotChange,
+        SubscriptionCursor,
+        SubscriptionEvent,
+    },
+};
+use futures::{
+    Stream,
+    StreamExt,
 };
+use std::time::Duration;
+
+/// The max number of the blocks returned by the `feeHistory` query.
+const MAX_FEE_HISTORY_BLOCKS: u32 = 1024;
 
 pub struct Block(pub(crate) CompressedBlock);
 
@@ -62,6 +83,7 @@ pub struct Header(pub(crate) BlockHeader);
 pub enum Consensus {
     Genesis(Genesis),
     PoA(PoAConsensus),
+    PoAMultiSig(PoAMultiSigConsensus),
 }
 
 type CoreGenesis = fuel_core_types::blockchain::consensus::Genesis;
@@ -84,6 +106,10 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+pub struct PoAMultiSigConsensus {
+    signatures: Vec<Signature>,
+}
+
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
@@ -181,6 +207,172 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl PoAMultiSigConsensus {
+    /// Gets the signatures of the block sealed by the multi-signature `PoA` consensus,
+    /// ordered by the addresses of the signers.
+    async fn signatures(&self) -> Vec<Signature> {
+        self.signatures.clone()
+    }
+}
+
+/// The keys of the state changed by the block.
+pub struct BlockStateDiff(BlockStateChanges);
+
+#[Object]
+impl BlockStateDiff {
+    /// The height of the block.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    /// The coins created by the block.
+    async fn coins_created(&self) -> Vec<UtxoId> {
+        self.0.coins_created.iter().map(|id| (*id).into()).collect()
+    }
+
+    /// The coins spent by the block. The coins created and spent by the same block
+    /// are reported only as spent.
+    async fn coins_spent(&self) -> Vec<UtxoId> {
+        self.0.coins_spent.iter().map(|id| (*id).into()).collect()
+    }
+
+    /// The storage slots of the contracts written by the block.
+    async fn contract_slots(&self) -> Vec<ContractSlotWrite> {
+        self.0
+            .contract_slots
+            .iter()
+            .cloned()
+            .map(ContractSlotWrite)
+            .collect()
+    }
+
+    /// The messages consumed by the block.
+    async fn messages_consumed(&self) -> Vec<Nonce> {
+        self.0
+            .messages_consumed
+            .iter()
+            .map(|nonce| (*nonce).into())
+            .collect()
+    }
+}
+
+/// The gas price and the fullness of the block.
+pub struct BlockFees(graphql_api::BlockFees);
+
+#[Object]
+impl BlockFees {
+    /// The height of the block.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    /// The lowest gas price paid by the transactions of the block,
+    /// `0` for the block without the transactions.
+    async fn min_gas_price(&self) -> U64 {
+        self.0.min_gas_price.into()
+    }
+
+    /// The gas used by the transactions of the block.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// The size of the transactions of the block in bytes.
+    async fn bytes_used(&self) -> U64 {
+        self.0.bytes_used.into()
+    }
+}
+
+/// The write of the storage slot of the contract.
+pub struct ContractSlotWrite(ContractSlotChange);
+
+#[Object]
+impl ContractSlotWrite {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn slot(&self) -> Bytes32 {
+        self.0.slot.into()
+    }
+
+    /// The new value of the slot, or `null` if the slot is cleared.
+    async fn value(&self) -> Option<Bytes32> {
+        self.0.value.map(Into::into)
+    }
+}
+
+/// The block with the cursor of the resumable subscription.
+#[derive(SimpleObject)]
+pub struct BlockEvent {
+    /// The cursor to resume the subscription after this block.
+    cursor: String,
+    block: Block,
+}
+
+fn block_event(
+    query: &Database,
+    cursor: SubscriptionCursor,
+    height: BlockHeight,
+) -> async_graphql::Result<BlockEvent> {
+    let id = query.block_id(&height)?;
+    Ok(BlockEvent {
+        cursor: cursor.to_string(),
+        block: Block(query.block(&id)?),
+    })
+}
+
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of the blocks imported by the node, starting from the next block.
+    ///
+    /// It is possible for the stream to skip blocks if it is polled slower
+    /// than the blocks are imported. The skipped blocks can be requested by their heights.
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<Block>> + 'a {
+        let importer: &BlockImporter = ctx.data_unchecked();
+        let query: &Database = ctx.data_unchecked();
+        importer.block_events().map(move |height| {
+            let id = query.block_id(&height)?;
+            Ok(Block(query.block(&id)?))
+        })
+    }
+
+    /// Returns a stream of the blocks imported by the node with their cursors.
+    /// The stream starts after the block with the `resumeFrom` cursor, so the client
+    /// reconnected with the cursor of the last received block doesn't miss any block.
+    /// Without the cursor, the stream starts from the next block.
+    ///
+    /// The node buffers only the latest events, and the subscription fails
+    /// if the blocks after the cursor are no longer buffered. The stream ends
+    /// with an error if it is polled slower than the blocks are imported.
+    async fn new_block_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The cursor of the last event.")] resume_from: Option<String>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<BlockEvent>> + 'a>
+    {
+        let (_, events) = subscription_events(ctx, resume_from)?;
+        let query: &Database = ctx.data_unchecked();
+        Ok(events.filter_map(move |event| {
+            let event = match event {
+                Ok((cursor, SubscriptionEvent::Block(height))) => {
+                    Some(block_event(query, cursor, height))
+                }
+                Ok(_) => None,
+                Err(e) => Some(Err(e.into())),
+            };
+            futures::future::ready(event)
+        }))
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -227,6 +419,41 @@ impl BlockQuery {
         })
         .await
     }
+
+    /// The keys of the state changed by the block at the `blockHeight`: the created and
+    /// spent coins, the written storage slots of the contracts and the consumed messages.
+    /// It is `null` if the node doesn't have the state diff of the block, the node keeps
+    /// the diffs of the latest blocks only if `--state-diff-retention` is set.
+    async fn state_diff(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] block_height: U32,
+    ) -> async_graphql::Result<Option<BlockStateDiff>> {
+        let db: &Database = ctx.data_unchecked();
+        Ok(db.state_changes(&block_height.into())?.map(BlockStateDiff))
+    }
+
+    /// The gas prices and the fullness of the `lastN` latest blocks, the oldest first,
+    /// to render the fee charts without downloading the blocks.
+    async fn fee_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks")] last_n: U32,
+    ) -> async_graphql::Result<Vec<BlockFees>> {
+        let last_n = last_n.0;
+        if last_n > MAX_FEE_HISTORY_BLOCKS {
+            return Err(anyhow!(
+                "The query can't request more than {MAX_FEE_HISTORY_BLOCKS} blocks"
+            )
+            .into())
+        }
+        let db: &Database = ctx.data_unchecked();
+        Ok(db
+            .fee_history(last_n as usize)?
+            .into_iter()
+            .map(BlockFees)
+            .collect())
+    }
 }
 
 #[derive(Default)]
@@ -312,6 +539,29 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Advances the clock of the chain by `seconds`. The timestamps of the next blocks
+    /// and of the dry runs include the advanced time, so the time-dependent logic of the
+    /// contracts can be tested without waiting. The advanced time is kept across the
+    /// restarts of the node. Returns the current time of the chain.
+    async fn advance_time(
+        &self,
+        ctx: &Context<'_>,
+        seconds: U64,
+    ) -> async_graphql::Result<Tai64Timestamp> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        let seconds: u64 = seconds.into();
+        let time = consensus_module
+            .advance_time(Duration::from_secs(seconds))
+            .await?;
+        Ok(Tai64Timestamp(time))
+    }
 }
 
 impl From<CompressedBlock> for Block {
@@ -350,6 +600,11 @@ impl From<CoreConsensus> for Consensus {
             CoreConsensus::PoA(poa) => Consensus::PoA(PoAConsensus {
                 signature: poa.signature.into(),
             }),
+            CoreConsensus::PoAMultiSig(multisig) => {
+                Consensus::PoAMultiSig(PoAMultiSigConsensus {
+                    signatures: multisig.signatures.into_iter().map(Into::into).collect(),
+                })
+            }
         }
     }
 }
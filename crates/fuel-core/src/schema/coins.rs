@@ -1,15 +1,25 @@
 use crate::{
     coins_query::{
-        random_improve,
+        self,
+        dust_coins,
+        select_coins,
         SpendQuery,
     },
     fuel_core_graphql_api::{
         Config as GraphQLConfig,
         IntoApiResult,
     },
-    graphql_api::service::Database,
+    graphql_api::service::{
+        Database,
+        TxPool,
+    },
     query::{
-        asset_query::AssetSpendTarget,
+        asset_query::{
+            AssetQuery,
+            AssetSpendTarget,
+            AssetsQuery,
+            Exclude,
+        },
         CoinQueryData,
     },
     schema::scalars::{
@@ -40,6 +50,7 @@ use fuel_core_types::{
     fuel_tx,
 };
 use itertools::Itertools;
+use std::collections::HashSet;
 
 pub struct Coin(pub(crate) CoinModel);
 
@@ -116,6 +127,18 @@ pub enum CoinType {
     MessageCoin(MessageCoin),
 }
 
+/// The coins of the owner usable by the next transaction.
+#[derive(async_graphql::SimpleObject)]
+pub struct UsableCoins {
+    /// The coins and the messages from the state of the chain that are not spent by
+    /// the transactions in the pool.
+    coins: Vec<CoinType>,
+    /// The coins created by the transactions in the pool, which the dependent
+    /// transactions can spend before the inclusion into the block. They aren't in
+    /// a block yet, so their `blockCreated` is zero.
+    pending_coins: Vec<Coin>,
+}
+
 #[derive(async_graphql::InputObject)]
 struct CoinFilterInput {
     /// Returns coins owned by the `owner`.
@@ -134,6 +157,30 @@ pub struct SpendQueryElementInput {
     max: Option<U64>,
 }
 
+/// The algorithm that selects the coins to spend.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum CoinSelectionStrategy {
+    /// Selects the biggest coins first. It produces the smallest number of coins.
+    LargestFirst,
+    /// Selects the smallest number of coins that add up exactly to the target amount,
+    /// so the transaction doesn't need the change. Falls back to the largest first
+    /// if there are no such coins.
+    BranchAndBound,
+    /// Selects the random coins and adds the dust to them. It doesn't reveal the balance
+    /// of the owner and consolidates the dust over time.
+    Random,
+}
+
+impl From<CoinSelectionStrategy> for coins_query::CoinSelectionStrategy {
+    fn from(value: CoinSelectionStrategy) -> Self {
+        match value {
+            CoinSelectionStrategy::LargestFirst => Self::LargestFirst,
+            CoinSelectionStrategy::BranchAndBound => Self::BranchAndBound,
+            CoinSelectionStrategy::Random => Self::Random,
+        }
+    }
+}
+
 #[derive(async_graphql::InputObject)]
 pub struct ExcludeInput {
     /// Utxos to exclude from the selection.
@@ -198,7 +245,10 @@ impl CoinQuery {
     /// `owner` that add up at least the query amount. The returned coins can be spent.
     /// The number of coins is optimized to prevent dust accumulation.
     ///
-    /// The query supports excluding and maximum the number of coins.
+    /// The query supports excluding and maximum the number of coins. The `strategy` selects
+    /// the algorithm of the selection, the random one by default. The total number of coins
+    /// can't exceed `max_inputs`; if the selection of the `strategy` exceeds it, the biggest
+    /// coins are selected instead.
     ///
     /// Returns:
     ///     The list of spendable coins per asset from the query. The length of the result is
@@ -216,6 +266,11 @@ impl CoinQuery {
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "The algorithm of the selection.")] strategy: Option<
+            CoinSelectionStrategy,
+        >,
+        #[graphql(desc = "The maximum number of coins of all assets in the selection.")]
+        max_inputs: Option<U64>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
@@ -244,13 +299,19 @@ impl CoinQuery {
             utxos.chain(messages).collect()
         });
 
+        let max_inputs = max_inputs
+            .map(|max| max.0)
+            .unwrap_or(config.consensus_parameters.tx_params().max_inputs);
+        let strategy = strategy.map(Into::into).unwrap_or_default();
+
         let base_asset_id = config.consensus_parameters.base_asset_id();
         let spend_query =
-            SpendQuery::new(owner, &query_per_asset, excluded_ids, *base_asset_id)?;
+            SpendQuery::new(owner, &query_per_asset, excluded_ids, *base_asset_id)?
+                .with_max_inputs(max_inputs);
 
         let db = ctx.data_unchecked::<Database>();
 
-        let coins = random_improve(db, &spend_query)?
+        let coins = select_coins(db, &spend_query, strategy)?
             .into_iter()
             .map(|coins| {
                 coins
@@ -267,6 +328,95 @@ impl CoinQuery {
 
         Ok(coins)
     }
+
+    /// Returns the dust coins of the `asset_id` owned by the `owner`, whose amounts are below
+    /// the `dust_threshold`, starting from the smallest one. Spending them in one transaction
+    /// consolidates the dust into one coin, so the later selections need fewer coins.
+    ///
+    /// Returns nothing if there are fewer than two dust coins.
+    async fn coins_to_consolidate(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The asset of the coins.")] asset_id: AssetId,
+        #[graphql(desc = "The coins with smaller amounts are the dust.")]
+        dust_threshold: U64,
+        #[graphql(desc = "The maximum number of coins.")] max: Option<U64>,
+    ) -> async_graphql::Result<Vec<CoinType>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let db = ctx.data_unchecked::<Database>();
+
+        let asset = AssetSpendTarget::new(
+            asset_id.0,
+            0,
+            max.map(|max| max.0)
+                .unwrap_or(config.consensus_parameters.tx_params().max_inputs),
+        );
+        let base_asset_id = config.consensus_parameters.base_asset_id();
+        let query = AssetQuery::new(&owner.0, &asset, base_asset_id, None, db);
+        let coins = dust_coins(&query, dust_threshold.0)?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(coins)
+    }
+
+    /// Returns the coins and the messages of the `owner` usable by the next transaction,
+    /// considering both the state of the chain and the transactions in the pool.
+    /// The coins and the messages spent by the pending transactions are skipped, so
+    /// the senders of many transactions don't race themselves by spending them twice.
+    async fn usable_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "Returns coins only with `asset_id`.")] asset_id: Option<
+            AssetId,
+        >,
+        #[graphql(desc = "The maximum number of coins of each kind.")] max: Option<U64>,
+    ) -> async_graphql::Result<UsableCoins> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let db = ctx.data_unchecked::<Database>();
+        let txpool = ctx.data_unchecked::<TxPool>();
+
+        let owner: fuel_tx::Address = owner.0;
+        let asset_id = asset_id.map(|asset_id| asset_id.0);
+        let max = max
+            .map(|max| max.0)
+            .unwrap_or(config.consensus_parameters.tx_params().max_inputs)
+            as usize;
+
+        let pending = txpool.pending_coins(&owner);
+        let spent_coins = pending.spent_coins.iter().copied().map(coins::CoinId::Utxo);
+        let spent_messages = pending
+            .spent_messages
+            .iter()
+            .copied()
+            .map(coins::CoinId::Message);
+        let exclude = Exclude::new(spent_coins.chain(spent_messages).collect());
+
+        let assets = asset_id.as_ref().map(|asset_id| HashSet::from([asset_id]));
+        let base_asset_id = config.consensus_parameters.base_asset_id();
+        let query = AssetsQuery::new(&owner, assets, Some(&exclude), db, base_asset_id);
+        let coins: Vec<CoinType> = query
+            .coins()
+            .take(max)
+            .map(|coin| coin.map(Into::into))
+            .try_collect()?;
+
+        let pending_coins = pending
+            .created_coins
+            .into_iter()
+            .filter(|coin| asset_id.map_or(true, |asset_id| coin.asset_id == asset_id))
+            .take(max)
+            .map(Into::into)
+            .collect();
+
+        Ok(UsableCoins {
+            coins,
+            pending_coins,
+        })
+    }
 }
 
 impl From<CoinModel> for Coin {
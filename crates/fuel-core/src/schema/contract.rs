@@ -1,14 +1,21 @@
 use crate::{
+    chain_config::ContractConfig,
     fuel_core_graphql_api::{
         service::Database,
+        Config as GraphQLConfig,
         IntoApiResult,
     },
-    query::ContractQueryData,
+    query::{
+        BlockQueryData,
+        ContractQueryData,
+    },
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
+        U32,
         U64,
     },
 };
@@ -27,6 +34,15 @@ use fuel_core_types::{
     services::graphql_api,
 };
 
+/// The max number of the storage slots requested by one `contractSlotValues` query.
+const MAX_CONTRACT_SLOTS_PER_QUERY: usize = 256;
+
+/// The default number of the contracts returned by the `topContracts` query.
+const DEFAULT_TOP_CONTRACTS: u32 = 10;
+
+/// The max number of the contracts returned by the `topContracts` query.
+const MAX_TOP_CONTRACTS: u32 = 100;
+
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
 impl From<fuel_types::ContractId> for Contract {
@@ -56,6 +72,16 @@ impl Contract {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// The root of the bytecode of the contract. The verification tools compare it with
+    /// the root of the source build instead of the whole bytecode.
+    async fn bytecode_root(&self, ctx: &Context<'_>) -> async_graphql::Result<Bytes32> {
+        let context: &Database = ctx.data_unchecked();
+        context
+            .contract_root(self.0)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
 }
 
 #[derive(Default)]
@@ -71,6 +97,184 @@ impl ContractQuery {
         let data: &Database = ctx.data_unchecked();
         data.contract_id(id.0).into_api_result()
     }
+
+    /// Returns the values of the storage slots of the contract in one request.
+    /// The historical heights are served from the recorded state diffs, so the query
+    /// fails if the contract didn't exist at the height or the diffs aren't recorded.
+    async fn contract_slot_values(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The keys of the storage slots")] slots: Vec<Bytes32>,
+        #[graphql(desc = "The height of the block, the latest one if not specified")]
+        height: Option<U32>,
+    ) -> async_graphql::Result<Vec<ContractSlotValue>> {
+        if slots.len() > MAX_CONTRACT_SLOTS_PER_QUERY {
+            return Err(anyhow!(
+                "The query can't request more than {MAX_CONTRACT_SLOTS_PER_QUERY} slots"
+            )
+            .into())
+        }
+        let data: &Database = ctx.data_unchecked();
+        let contract_id = contract.0;
+        let slots: Vec<fuel_types::Bytes32> =
+            slots.into_iter().map(|slot| slot.0).collect();
+        let values = data
+            .contract_slot_values(
+                &contract_id,
+                &slots,
+                height.map(|height| height.0.into()),
+            )?
+            .ok_or_else(|| {
+                anyhow!("The contract {contract_id} didn't exist at the height")
+            })?;
+        Ok(slots
+            .into_iter()
+            .zip(values)
+            .map(|(slot, value)| ContractSlotValue { slot, value })
+            .collect())
+    }
+
+    /// Returns the bytecode, the storage slots and the balances of the contract,
+    /// or `null` if the contract didn't exist at the height. The historical heights
+    /// are served from the recorded state diffs. The query fails if the contract has
+    /// more storage slots and balances than `contractSlotValues` can request, such
+    /// contracts are exported by the `snapshot contract` command.
+    async fn contract_snapshot(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Contract")] contract: ContractId,
+        #[graphql(desc = "The height of the block, the latest one if not specified")]
+        height: Option<U32>,
+    ) -> async_graphql::Result<Option<ContractSnapshot>> {
+        let data: &Database = ctx.data_unchecked();
+        let config = data.contract_config_at(
+            &contract.0,
+            height.map(|height| height.0.into()),
+            MAX_CONTRACT_SLOTS_PER_QUERY,
+        )?;
+        Ok(config.map(ContractSnapshot))
+    }
+
+    /// Returns the most called contracts in the window of the latest blocks, ordered by
+    /// the number of the calls. The calls are counted by the contract call analytics,
+    /// so the query fails if the analytics is disabled on the node.
+    async fn top_contracts(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest blocks")] window: U32,
+        #[graphql(desc = "The max number of the contracts, 10 if not specified")]
+        first: Option<U32>,
+    ) -> async_graphql::Result<Vec<ContractCalls>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let Some(max_window) = config.contract_calls_window else {
+            return Err(
+                anyhow!("The contract call analytics is disabled on the node").into(),
+            )
+        };
+        let window = window.0;
+        if window == 0 || window > max_window {
+            return Err(
+                anyhow!("The window must be between 1 and {max_window} blocks").into(),
+            )
+        }
+        let first = first.map(|first| first.0).unwrap_or(DEFAULT_TOP_CONTRACTS);
+        if first > MAX_TOP_CONTRACTS {
+            return Err(anyhow!(
+                "The query can't request more than {MAX_TOP_CONTRACTS} contracts"
+            )
+            .into())
+        }
+
+        let data: &Database = ctx.data_unchecked();
+        let latest: u32 = data.latest_block_height()?.into();
+        let from = latest.saturating_add(1).saturating_sub(window);
+        Ok(data
+            .top_contracts(from.into(), first as usize)?
+            .into_iter()
+            .map(ContractCalls)
+            .collect())
+    }
+}
+
+/// The number of the calls of the contract in the window of the latest blocks.
+pub struct ContractCalls(graphql_api::ContractCalls);
+
+#[Object]
+impl ContractCalls {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    /// The number of the calls of the contract, including the calls
+    /// of the reverted transactions.
+    async fn calls(&self) -> U64 {
+        self.0.calls.into()
+    }
+}
+
+pub struct ContractSlotValue {
+    slot: fuel_types::Bytes32,
+    value: Option<fuel_types::Bytes32>,
+}
+
+#[Object]
+impl ContractSlotValue {
+    async fn slot(&self) -> Bytes32 {
+        self.slot.into()
+    }
+
+    /// The value of the slot, `null` if the slot is empty.
+    async fn value(&self) -> Option<Bytes32> {
+        self.value.map(Into::into)
+    }
+}
+
+/// The state of the contract at the block height, to fork it into another chain.
+pub struct ContractSnapshot(ContractConfig);
+
+#[Object]
+impl ContractSnapshot {
+    async fn contract(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn bytecode(&self) -> HexString {
+        HexString(self.0.code.clone())
+    }
+
+    async fn salt(&self) -> Salt {
+        self.0.salt.into()
+    }
+
+    /// The non-empty storage slots of the contract, ordered by the key.
+    async fn slots(&self) -> Vec<ContractSlotValue> {
+        self.0
+            .state
+            .iter()
+            .flatten()
+            .map(|(slot, value)| ContractSlotValue {
+                slot: *slot,
+                value: Some(*value),
+            })
+            .collect()
+    }
+
+    /// The non-zero balances of the contract, ordered by the asset id.
+    async fn balances(&self) -> Vec<ContractBalance> {
+        self.0
+            .balances
+            .iter()
+            .flatten()
+            .map(|(asset_id, amount)| {
+                ContractBalance(graphql_api::ContractBalance {
+                    owner: self.0.contract_id,
+                    amount: *amount,
+                    asset_id: *asset_id,
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct ContractBalance(graphql_api::ContractBalance);
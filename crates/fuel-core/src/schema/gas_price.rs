@@ -0,0 +1,96 @@
+use super::scalars::{
+    U32,
+    U64,
+};
+use crate::{
+    fuel_core_graphql_api::service::{
+        Database,
+        GasPriceEstimator,
+    },
+    query::{
+        BlockQueryData,
+        ChainQueryData,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_types::services::relayer;
+
+pub struct EstimateGasPrice {
+    gas_price: U64,
+}
+
+#[Object]
+impl EstimateGasPrice {
+    async fn gas_price(&self) -> U64 {
+        self.gas_price
+    }
+}
+
+/// The fees of the finalized DA block observed by the relayer.
+pub struct DaFeeSample(relayer::DaFeeSample);
+
+#[Object]
+impl DaFeeSample {
+    async fn da_height(&self) -> U64 {
+        self.0.da_height.0.into()
+    }
+
+    /// The base fee per gas of the DA block in wei.
+    async fn base_fee(&self) -> U64 {
+        self.0.base_fee.into()
+    }
+
+    /// The base fee per blob gas of the DA block in wei.
+    async fn blob_base_fee(&self) -> Option<U64> {
+        self.0.blob_base_fee.map(Into::into)
+    }
+}
+
+#[derive(Default)]
+pub struct EstimateGasPriceQuery {}
+
+#[Object]
+impl EstimateGasPriceQuery {
+    /// Estimates the highest gas price that can be required to include the transaction
+    /// in one of the next `block_horizon` blocks. The estimation assumes that all of the
+    /// blocks until the horizon are full.
+    async fn estimate_gas_price(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(
+            desc = "Number of blocks into the future to estimate the gas price for"
+        )]
+        block_horizon: Option<U32>,
+    ) -> async_graphql::Result<EstimateGasPrice> {
+        let query: &Database = ctx.data_unchecked();
+        let estimator = ctx.data_unchecked::<GasPriceEstimator>();
+
+        let block_horizon: u32 = block_horizon.map(Into::into).unwrap_or(0);
+        let latest_block_height: u32 = query.latest_block_height()?.into();
+        let target_block = latest_block_height.saturating_add(block_horizon);
+        let gas_price = estimator.worst_case_gas_price(target_block.into());
+
+        Ok(EstimateGasPrice {
+            gas_price: gas_price.into(),
+        })
+    }
+
+    /// Returns the fees of the latest finalized DA blocks recorded by the relayer,
+    /// the oldest first. It is empty if the relayer is disabled.
+    async fn da_fee_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The number of the latest samples to return")] last: Option<U32>,
+    ) -> async_graphql::Result<Vec<DaFeeSample>> {
+        let query: &Database = ctx.data_unchecked();
+        let samples = query.da_fee_samples()?;
+        let skip = match last {
+            Some(last) => samples.len().saturating_sub(u32::from(last) as usize),
+            None => 0,
+        };
+        Ok(samples.into_iter().skip(skip).map(DaFeeSample).collect())
+    }
+}
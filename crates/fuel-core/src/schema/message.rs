@@ -1,4 +1,7 @@
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    sync::Arc,
+};
 
 use super::{
     block::Header,
@@ -12,8 +15,14 @@ use super::{
     },
 };
 use crate::{
-    fuel_core_graphql_api::service::Database,
-    query::MessageQueryData,
+    fuel_core_graphql_api::service::{
+        BlockCommitter,
+        Database,
+    },
+    query::{
+        MessageProofCache,
+        MessageQueryData,
+    },
     schema::scalars::{
         BlockId,
         U32,
@@ -29,7 +38,10 @@ use async_graphql::{
     Enum,
     Object,
 };
-use fuel_core_types::entities;
+use fuel_core_types::{
+    entities,
+    entities::message::CommitmentState,
+};
 
 pub struct Message(pub(crate) entities::message::Message);
 
@@ -134,8 +146,10 @@ impl MessageQuery {
             ))?,
         };
 
+        let cache: &Arc<MessageProofCache> = ctx.data_unchecked();
         Ok(crate::query::message_proof(
             data.deref(),
+            cache,
             transaction_id.into(),
             nonce.into(),
             block_id,
@@ -143,14 +157,98 @@ impl MessageQuery {
         .map(MessageProof))
     }
 
+    /// Returns the state of the message with the DA height at which the relayer
+    /// received it and the transaction that spent it.
     async fn message_status(
         &self,
         ctx: &Context<'_>,
         nonce: Nonce,
     ) -> async_graphql::Result<MessageStatus> {
         let data: &Database = ctx.data_unchecked();
-        let status = crate::query::message_status(data.deref(), nonce.into())?;
-        Ok(status.into())
+        let status = crate::query::deposit_status(data.deref(), nonce.into())?;
+        Ok(MessageStatus(status))
+    }
+
+    /// Tracks the deposits from the DA layer either by the hash of the DA transaction
+    /// that sent them or by the nonce of the message.
+    async fn deposits(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The hash of the DA transaction that sent the messages")]
+        da_transaction_hash: Option<Bytes32>,
+        #[graphql(desc = "The nonce of the message")] nonce: Option<Nonce>,
+    ) -> async_graphql::Result<Vec<Deposit>> {
+        let data: &Database = ctx.data_unchecked();
+        let deposits = match (da_transaction_hash, nonce) {
+            (Some(da_transaction_hash), None) => crate::query::da_transaction_deposits(
+                data.deref(),
+                &da_transaction_hash.0,
+            )?,
+            (None, Some(nonce)) => {
+                vec![crate::query::deposit_status(data.deref(), nonce.into())?]
+            }
+            _ => Err(anyhow::anyhow!(
+                "Either `da_transaction_hash` or `nonce` must be provided exclusively"
+            ))?,
+        };
+        Ok(deposits.into_iter().map(Deposit).collect())
+    }
+
+    /// Returns the withdrawals of the transaction with the state of their
+    /// commitment on the DA layer and the proofs to relay them.
+    async fn withdrawals(
+        &self,
+        ctx: &Context<'_>,
+        transaction_id: TransactionId,
+    ) -> async_graphql::Result<Vec<Withdrawal>> {
+        let committer: &BlockCommitter = ctx
+            .data_opt()
+            .ok_or_else(|| anyhow!("The block committer is not enabled"))?;
+        let data: &Database = ctx.data_unchecked();
+        let transaction_id = transaction_id.into();
+        let (block_height, nonces) = match crate::query::transaction_output_messages(
+            data.deref(),
+            &transaction_id,
+        )? {
+            Some(messages) => messages,
+            None => return Ok(vec![]),
+        };
+
+        let commit_block_height = committer.commit_height(block_height);
+        let (latest_height, _) = data.ids_of_latest_block()?;
+        let (state, commit_block_id) = if commit_block_height <= latest_height {
+            let commit_block_id = data.block_id(&commit_block_height)?;
+            let state = committer
+                .commitment_state(commit_block_height, commit_block_id)
+                .await?;
+            (state, Some(commit_block_id))
+        } else {
+            (CommitmentState::NotCommitted, None)
+        };
+
+        let cache: &Arc<MessageProofCache> = ctx.data_unchecked();
+        nonces
+            .into_iter()
+            .map(|nonce| {
+                let proof = match (state, commit_block_id) {
+                    (CommitmentState::NotCommitted, _) | (_, None) => None,
+                    (_, Some(commit_block_id)) => crate::query::message_proof(
+                        data.deref(),
+                        cache,
+                        transaction_id,
+                        nonce,
+                        commit_block_id,
+                    )?
+                    .map(MessageProof),
+                };
+                Ok(Withdrawal {
+                    nonce,
+                    state,
+                    commit_block_height,
+                    proof,
+                })
+            })
+            .collect()
     }
 }
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
@@ -224,7 +322,7 @@ impl From<entities::message::MerkleProof> for MerkleProof {
     }
 }
 
-pub struct MessageStatus(pub(crate) entities::message::MessageStatus);
+pub struct MessageStatus(pub(crate) crate::query::DepositStatus);
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 enum MessageState {
@@ -236,16 +334,119 @@ enum MessageState {
 #[Object]
 impl MessageStatus {
     async fn state(&self) -> MessageState {
-        match self.0.state {
-            entities::message::MessageState::Unspent => MessageState::Unspent,
-            entities::message::MessageState::Spent => MessageState::Spent,
-            entities::message::MessageState::NotFound => MessageState::NotFound,
+        (&self.0.status.state).into()
+    }
+
+    /// The hash of the DA transaction that sent the message.
+    async fn da_transaction_hash(&self) -> Option<Bytes32> {
+        self.0.deposit.map(|deposit| deposit.da_transaction.into())
+    }
+
+    /// The DA height at which the relayer received the message.
+    async fn da_height(&self) -> Option<U64> {
+        let deposit_da_height = self.0.deposit.map(|deposit| deposit.da_height);
+        let message_da_height = self.0.message.as_ref().map(|message| message.da_height);
+        deposit_da_height
+            .or(message_da_height)
+            .map(|da_height| da_height.as_u64().into())
+    }
+
+    /// The transaction that spent the message.
+    async fn spending_transaction_id(&self) -> Option<TransactionId> {
+        self.0.spending_transaction.map(Into::into)
+    }
+
+    /// The height of the block with the transaction that spent the message.
+    async fn spending_block_height(&self) -> Option<U32> {
+        self.0.spending_block_height.map(Into::into)
+    }
+}
+
+pub struct Deposit(pub(crate) crate::query::DepositStatus);
+
+#[Object]
+impl Deposit {
+    async fn nonce(&self) -> Nonce {
+        self.0.nonce.into()
+    }
+
+    async fn state(&self) -> MessageState {
+        (&self.0.status.state).into()
+    }
+
+    /// The hash of the DA transaction that sent the message.
+    async fn da_transaction_hash(&self) -> Option<Bytes32> {
+        self.0.deposit.map(|deposit| deposit.da_transaction.into())
+    }
+
+    /// The DA height at which the relayer received the message.
+    async fn da_height(&self) -> Option<U64> {
+        self.0
+            .deposit
+            .map(|deposit| deposit.da_height.as_u64().into())
+    }
+
+    /// The message stored by the relayer. It is removed after it is spent.
+    async fn message(&self) -> Option<Message> {
+        self.0.message.clone().map(Into::into)
+    }
+
+    /// The transaction that spent the message.
+    async fn spending_transaction_id(&self) -> Option<TransactionId> {
+        self.0.spending_transaction.map(Into::into)
+    }
+
+    /// The height of the block with the transaction that spent the message.
+    async fn spending_block_height(&self) -> Option<U32> {
+        self.0.spending_block_height.map(Into::into)
+    }
+}
+
+pub struct Withdrawal {
+    nonce: fuel_core_types::fuel_types::Nonce,
+    state: CommitmentState,
+    commit_block_height: fuel_core_types::fuel_types::BlockHeight,
+    proof: Option<MessageProof>,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+enum WithdrawalState {
+    NotCommitted,
+    Committed,
+    Finalized,
+}
+
+#[Object]
+impl Withdrawal {
+    async fn nonce(&self) -> Nonce {
+        self.nonce.into()
+    }
+
+    async fn state(&self) -> WithdrawalState {
+        match self.state {
+            CommitmentState::NotCommitted => WithdrawalState::NotCommitted,
+            CommitmentState::Committed => WithdrawalState::Committed,
+            CommitmentState::Finalized => WithdrawalState::Finalized,
         }
     }
+
+    /// The height of the committed block used as the root of the proof.
+    async fn commit_block_height(&self) -> U32 {
+        self.commit_block_height.into()
+    }
+
+    /// The proof of the message. It is available after the block is committed.
+    async fn proof(&self) -> Option<&MessageProof> {
+        self.proof.as_ref()
+    }
 }
 
-impl From<entities::message::MessageStatus> for MessageStatus {
-    fn from(status: entities::message::MessageStatus) -> Self {
-        MessageStatus(status)
+impl From<&entities::message::MessageState> for MessageState {
+    fn from(state: &entities::message::MessageState) -> Self {
+        match state {
+            entities::message::MessageState::Unspent => MessageState::Unspent,
+            entities::message::MessageState::Spent => MessageState::Spent,
+            entities::message::MessageState::NotFound => MessageState::NotFound,
+        }
     }
 }
@@ -1,9 +1,51 @@
-use super::scalars::U64;
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use super::scalars::{
+    BlockId,
+    Bytes32,
+    HexString,
+    Signature,
+    Tai64Timestamp,
+    U32,
+    U64,
+};
+use crate::{
+    chain_config::UpgradeDeclaration,
+    fuel_core_graphql_api::{
+        service::{
+            Database,
+            StorageStats,
+            SyncStatusSource,
+        },
+        Config as GraphQLConfig,
+    },
+    service::upgrades::NODE_VERSION,
+};
+use anyhow::anyhow;
 use async_graphql::{
     Context,
+    Enum,
     Object,
 };
+use fuel_core_types::{
+    fuel_crypto::PublicKey,
+    secrecy::ExposeSecret,
+    services::{
+        graphql_api::NodeAttestation,
+        relayer::RelayerErrorKind,
+    },
+    tai64::Tai64,
+};
+use parking_lot::Mutex;
+use std::{
+    ops::Deref,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The maximum number of the signatures of the `signedNodeInfo` per second,
+/// so the clients can't load the node by the signing.
+const MAX_SIGNED_NODE_INFO_PER_SECOND: u32 = 10;
 
 pub struct NodeInfo {
     utxo_validation: bool,
@@ -39,6 +81,243 @@ impl NodeInfo {
     async fn node_version(&self) -> String {
         self.node_version.to_owned()
     }
+
+    /// The approximate storage statistics of the database tables from the latest
+    /// refresh. It is `null` if the storage statistics are disabled.
+    async fn table_stats(&self, ctx: &Context<'_>) -> Option<Vec<TableStats>> {
+        let storage_stats: &StorageStats = ctx.data_opt()?;
+        Some(
+            storage_stats
+                .table_stats()
+                .into_iter()
+                .map(TableStats)
+                .collect(),
+        )
+    }
+
+    /// The upgrades of the chain declared by the consensus authority
+    /// above the latest height.
+    async fn pending_upgrades(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<PendingUpgrade>> {
+        let db: &Database = ctx.data_unchecked();
+        Ok(db
+            .pending_upgrades()?
+            .into_iter()
+            .map(PendingUpgrade)
+            .collect())
+    }
+}
+
+/// The upgrade of the chain scheduled at the future height.
+pub struct PendingUpgrade(UpgradeDeclaration);
+
+#[Object]
+impl PendingUpgrade {
+    /// The first height of the upgraded chain.
+    async fn height(&self) -> U32 {
+        self.0.height.into()
+    }
+
+    /// The minimal version of the node supporting the upgrade.
+    async fn required_version(&self) -> &str {
+        &self.0.required_version
+    }
+
+    /// Whether the upgrade changes the consensus parameters.
+    async fn changes_consensus_parameters(&self) -> bool {
+        self.0.consensus_parameters.is_some()
+    }
+
+    /// The root of the state transition bytecode from the height of the upgrade,
+    /// if the upgrade changes it.
+    async fn state_transition_bytecode_root(&self) -> Option<Bytes32> {
+        self.0.state_transition_bytecode_root.map(Into::into)
+    }
+
+    /// Whether this node supports the upgrade. The node that doesn't support it stops
+    /// producing and importing blocks at the height of the upgrade.
+    async fn supported(&self) -> async_graphql::Result<bool> {
+        Ok(self.0.is_supported_by(NODE_VERSION)?)
+    }
+}
+
+/// The approximate storage statistics of the database table.
+pub struct TableStats(fuel_core_types::services::graphql_api::TableStats);
+
+#[Object]
+impl TableStats {
+    /// The name of the table.
+    async fn table(&self) -> &str {
+        &self.0.table
+    }
+
+    /// The size of the table on the disk in bytes.
+    async fn size(&self) -> U64 {
+        self.0.size.into()
+    }
+
+    /// The number of keys in the table.
+    async fn keys(&self) -> U64 {
+        self.0.keys.into()
+    }
+
+    /// The number of bytes written into the table per second since the previous refresh.
+    async fn write_rate(&self) -> U64 {
+        self.0.write_rate.into()
+    }
+}
+
+/// The kind of the error that failed the iteration of the relayer.
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum DaErrorKind {
+    /// The DA node is unreachable.
+    Rpc,
+    /// The data of the DA node is malformed.
+    Decode,
+    /// The database of the node failed.
+    Storage,
+    /// The DA node doesn't have the requested data yet, the relayer retries.
+    Transient,
+    /// The relayer got the stop signal.
+    Shutdown,
+}
+
+impl From<RelayerErrorKind> for DaErrorKind {
+    fn from(kind: RelayerErrorKind) -> Self {
+        match kind {
+            RelayerErrorKind::Rpc => DaErrorKind::Rpc,
+            RelayerErrorKind::Decode => DaErrorKind::Decode,
+            RelayerErrorKind::Storage => DaErrorKind::Storage,
+            RelayerErrorKind::Transient => DaErrorKind::Transient,
+            RelayerErrorKind::Shutdown => DaErrorKind::Shutdown,
+        }
+    }
+}
+
+/// The synchronization status of the node with the network and the DA layer.
+pub struct SyncStatus(fuel_core_types::services::graphql_api::SyncStatus);
+
+#[Object]
+impl SyncStatus {
+    /// Whether the network is ahead of the node.
+    async fn is_syncing(&self) -> bool {
+        self.0.is_syncing()
+    }
+
+    /// The height of the latest block of the node.
+    async fn current_height(&self) -> U32 {
+        self.0.current_height.into()
+    }
+
+    /// The highest height observed from the network. It is `null` if the node
+    /// doesn't sync with the network.
+    async fn network_height(&self) -> Option<U32> {
+        self.0.network_height.map(Into::into)
+    }
+
+    /// The estimated time in seconds to import the blocks up to the network height.
+    /// It is `null` until the node imports enough blocks to estimate the rate.
+    async fn time_to_tip(&self) -> Option<U64> {
+        self.0.time_to_tip.map(|time| time.as_secs().into())
+    }
+
+    /// The number of the finalized DA blocks that the relayer hasn't synced yet.
+    /// It is `null` if the relayer is disabled or hasn't observed the DA layer yet.
+    async fn da_lag(&self) -> Option<U64> {
+        self.0.da_lag.map(Into::into)
+    }
+
+    /// The kind of the error that failed the latest iteration of the relayer.
+    /// It is `null` if the relayer is disabled or its latest iteration succeeded.
+    async fn da_error(&self) -> Option<DaErrorKind> {
+        self.0.da_error.map(Into::into)
+    }
+
+    /// Whether the balance of the Ethereum account of the block committer is below
+    /// the configured threshold. The commitments halt when the balance runs out.
+    /// It is `null` if the block committer is disabled or hasn't checked the balance yet.
+    async fn committer_balance_low(&self) -> Option<bool> {
+        self.0.committer_balance_low
+    }
+}
+
+/// The information about the node signed by its P2P identity key.
+pub struct SignedNodeInfo {
+    attestation: NodeAttestation,
+    public_key: PublicKey,
+    signature: fuel_core_types::fuel_crypto::Signature,
+}
+
+#[Object]
+impl SignedNodeInfo {
+    /// The version of the node.
+    async fn node_version(&self) -> &str {
+        &self.attestation.node_version
+    }
+
+    /// The id of the chain followed by the node.
+    async fn chain_id(&self) -> U64 {
+        (*self.attestation.chain_id).into()
+    }
+
+    /// The height of the latest block of the node.
+    async fn block_height(&self) -> U32 {
+        self.attestation.block_height.into()
+    }
+
+    /// The id of the latest block of the node.
+    async fn block_id(&self) -> BlockId {
+        self.attestation.block_id.into()
+    }
+
+    /// The time of the signing.
+    async fn timestamp(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.attestation.timestamp)
+    }
+
+    /// The challenge of the requester included into the signed message.
+    async fn challenge(&self) -> Option<Bytes32> {
+        self.attestation.challenge.map(Into::into)
+    }
+
+    /// The public key of the P2P identity of the node.
+    async fn public_key(&self) -> HexString {
+        HexString(self.public_key.as_ref().to_vec())
+    }
+
+    /// The signature of the information by the P2P identity key of the node.
+    async fn signature(&self) -> Signature {
+        self.signature.into()
+    }
+}
+
+/// Limits the number of the signatures of the `signedNodeInfo` per second.
+#[derive(Default)]
+pub struct SignedNodeInfoLimiter {
+    /// The start of the current second and the number of the signatures in it.
+    window: Mutex<Option<(Instant, u32)>>,
+}
+
+impl SignedNodeInfoLimiter {
+    /// Returns `false` if the limit of the current second is reached.
+    fn try_acquire(&self) -> bool {
+        let now = Instant::now();
+        let mut window = self.window.lock();
+        match window.as_mut() {
+            Some((started, count))
+                if now.duration_since(*started) < Duration::from_secs(1) =>
+            {
+                if *count >= MAX_SIGNED_NODE_INFO_PER_SECOND {
+                    return false
+                }
+                *count += 1;
+            }
+            _ => *window = Some((now, 1)),
+        }
+        true
+    }
 }
 
 #[derive(Default)]
@@ -49,15 +328,56 @@ impl NodeQuery {
     async fn node_info(&self, ctx: &Context<'_>) -> async_graphql::Result<NodeInfo> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
-        const VERSION: &str = env!("CARGO_PKG_VERSION");
-
         Ok(NodeInfo {
             utxo_validation: config.utxo_validation,
             vm_backtrace: config.vm_backtrace,
             min_gas_price: config.min_gas_price.into(),
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
-            node_version: VERSION.to_owned(),
+            node_version: NODE_VERSION.to_owned(),
         })
     }
+
+    /// The information about the node signed by its P2P identity key, so the clients can
+    /// verify which version of the node and which chain serve the API. The `challenge`
+    /// is included into the signed message to prove that the signature is fresh.
+    /// The number of the signatures per second is limited.
+    async fn signed_node_info(
+        &self,
+        ctx: &Context<'_>,
+        challenge: Option<Bytes32>,
+    ) -> async_graphql::Result<SignedNodeInfo> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let node_key = config
+            .node_key
+            .as_ref()
+            .ok_or_else(|| anyhow!("The node doesn't have the P2P identity key"))?;
+        let limiter: &SignedNodeInfoLimiter = ctx.data_unchecked();
+        if !limiter.try_acquire() {
+            return Err(anyhow!("Too many requests of the signed node info").into())
+        }
+        let db: &Database = ctx.data_unchecked();
+        let (block_height, block_id) = db.ids_of_latest_block()?;
+
+        let attestation = NodeAttestation {
+            node_version: NODE_VERSION.to_owned(),
+            chain_id: config.consensus_parameters.chain_id,
+            block_height,
+            block_id,
+            timestamp: Tai64::now(),
+            challenge: challenge.map(Into::into),
+        };
+        let secret_key = node_key.expose_secret().deref();
+        Ok(SignedNodeInfo {
+            signature: attestation.sign(secret_key),
+            public_key: secret_key.public_key(),
+            attestation,
+        })
+    }
+
+    /// The synchronization status of the node with the network and the DA layer.
+    async fn sync_status(&self, ctx: &Context<'_>) -> async_graphql::Result<SyncStatus> {
+        let sync_status: &SyncStatusSource = ctx.data_unchecked();
+        Ok(SyncStatus(sync_status.sync_status()?))
+    }
 }
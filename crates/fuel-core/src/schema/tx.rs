@@ -14,12 +14,18 @@ use crate::{
         SimpleTransactionData,
         TransactionQueryData,
     },
-    schema::scalars::{
-        Address,
-        HexString,
-        SortedTxCursor,
-        TransactionId,
-        TxPointer,
+    schema::{
+        scalars::{
+            Address,
+            BlockId,
+            HexString,
+            SortedTxCursor,
+            Tai64Timestamp,
+            TransactionId,
+            TxPointer,
+            U32,
+        },
+        subscription_events,
     },
 };
 use anyhow::anyhow;
@@ -30,6 +36,7 @@ use async_graphql::{
     },
     Context,
     Object,
+    SimpleObject,
     Subscription,
 };
 use fuel_core_storage::{
@@ -50,7 +57,12 @@ use fuel_core_types::{
     fuel_types,
     fuel_types::canonical::Deserialize,
     fuel_vm::checked_transaction::EstimatePredicates,
-    services::txpool,
+    services::{
+        block_producer::DryRunOverrides,
+        executor,
+        graphql_api::SubscriptionEvent,
+        txpool,
+    },
 };
 use futures::{
     Stream,
@@ -59,12 +71,16 @@ use futures::{
 use itertools::Itertools;
 use std::{
     iter,
+    ops::Deref,
     sync::Arc,
 };
 use tokio_stream::StreamExt;
 use types::Transaction;
 
-use self::types::TransactionStatus;
+use self::types::{
+    ArchivedTransactionStatus,
+    TransactionStatus,
+};
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
 
 pub mod input;
@@ -72,6 +88,38 @@ pub mod output;
 pub mod receipt;
 pub mod types;
 
+/// The maximum number of transactions in the bundle of the `dryRunBundle`.
+const MAX_DRY_RUN_BUNDLE_SIZE: usize = 256;
+
+/// Returns the overrides of the block simulated by the dry run.
+fn dry_run_overrides(
+    block_height: Option<U32>,
+    block_time: Option<Tai64Timestamp>,
+) -> DryRunOverrides {
+    DryRunOverrides {
+        block_height: block_height.map(Into::into),
+        block_time: block_time.map(|time| time.0),
+    }
+}
+
+/// The direction of the funds in the transaction relative to the owner.
+#[derive(async_graphql::Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransactionDirection {
+    /// The owner receives the outputs of the transaction.
+    Incoming,
+    /// The owner spends the coins or messages in the inputs of the transaction.
+    Outgoing,
+}
+
+impl From<TransactionDirection> for crate::query::TransactionDirection {
+    fn from(value: TransactionDirection) -> Self {
+        match value {
+            TransactionDirection::Incoming => Self::Incoming,
+            TransactionDirection::Outgoing => Self::Outgoing,
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TxQuery;
 
@@ -166,6 +214,8 @@ impl TxQuery {
         after: Option<String>,
         last: Option<i32>,
         before: Option<String>,
+        #[graphql(desc = "Filters the transactions by the direction of the funds")]
+        direction: Option<TransactionDirection>,
     ) -> async_graphql::Result<Connection<TxPointer, Transaction, EmptyFields, EmptyFields>>
     {
         // Rocksdb doesn't support reverse iteration over a prefix
@@ -178,6 +228,7 @@ impl TxQuery {
         let query: &Database = ctx.data_unchecked();
         let config = ctx.data_unchecked::<Config>();
         let owner = fuel_types::Address::from(owner);
+        let filter = direction.map(crate::query::TransactionDirection::from);
 
         crate::schema::query_pagination(
             after,
@@ -186,21 +237,67 @@ impl TxQuery {
             last,
             |start: &Option<TxPointer>, direction| {
                 let start = (*start).map(Into::into);
-                let txs =
-                    query
-                        .owned_transactions(owner, start, direction)
-                        .map(|result| {
-                            result.map(|(cursor, tx)| {
-                                let tx_id = tx.id(&config.consensus_parameters.chain_id);
-                                (cursor.into(), Transaction::from_tx(tx_id, tx))
-                            })
-                        });
+                let txs = query
+                    .owned_transactions(owner, start, direction)
+                    .filter_ok(move |(_, tx)| {
+                        filter.map_or(true, |filter| filter.matches(tx, &owner))
+                    })
+                    .map(|result| {
+                        result.map(|(cursor, tx)| {
+                            let tx_id = tx.id(&config.consensus_parameters.chain_id);
+                            (cursor.into(), Transaction::from_tx(tx_id, tx))
+                        })
+                    });
                 Ok(txs)
             },
         )
         .await
     }
 
+    /// Returns the statuses of the transaction recorded by the node, from the oldest
+    /// to the newest. The history is kept for the configured retention period.
+    async fn transaction_status_history(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Vec<ArchivedTransactionStatus>> {
+        let query: &Database = ctx.data_unchecked();
+        let history = query.status_history(&id.0)?;
+        Ok(history.into_iter().map(ArchivedTransactionStatus).collect())
+    }
+
+    /// Returns the proof that the receipt at `receipt_index` was produced by the script
+    /// transaction. The block with the transaction is proven against the history of
+    /// the commit block, so the receipt can be verified without trusting the node.
+    async fn receipt_proof(
+        &self,
+        ctx: &Context<'_>,
+        transaction_id: TransactionId,
+        receipt_index: U32,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<U32>,
+    ) -> async_graphql::Result<Option<receipt::ReceiptProof>> {
+        let query: &Database = ctx.data_unchecked();
+        let block_id = match (commit_block_id, commit_block_height) {
+            (Some(commit_block_id), None) => commit_block_id.0.into(),
+            (None, Some(commit_block_height)) => {
+                let block_height = commit_block_height.0.into();
+                query.block_id(&block_height)?
+            }
+            _ => Err(anyhow!(
+                "Either `commit_block_id` or `commit_block_height` must be provided exclusively"
+            ))?,
+        };
+
+        Ok(crate::query::receipt_proof(
+            query.deref(),
+            transaction_id.0,
+            receipt_index.0,
+            block_id,
+        )?
+        .map(receipt::ReceiptProof))
+    }
+
     /// Estimate the predicate gas for the provided transaction
     async fn estimate_predicates(
         &self,
@@ -232,6 +329,78 @@ impl TxQuery {
     }
 }
 
+pub struct DryRunProfile {
+    receipts: Vec<receipt::Receipt>,
+    profile: String,
+}
+
+#[Object]
+impl DryRunProfile {
+    /// The receipts of the dry-run.
+    async fn receipts(&self) -> &Vec<receipt::Receipt> {
+        &self.receipts
+    }
+
+    /// The JSON profile of the script execution.
+    async fn profile(&self) -> &str {
+        &self.profile
+    }
+}
+
+pub struct DryRunBundle {
+    transactions: Vec<DryRunTransaction>,
+    state_diff: Vec<StateChange>,
+}
+
+#[Object]
+impl DryRunBundle {
+    /// The results of the dry-run of the transactions in the order of the bundle.
+    async fn transactions(&self) -> &Vec<DryRunTransaction> {
+        &self.transactions
+    }
+
+    /// The changes of the state made by the whole bundle, ordered by the column and the key.
+    async fn state_diff(&self) -> &Vec<StateChange> {
+        &self.state_diff
+    }
+}
+
+pub struct DryRunTransaction {
+    id: fuel_types::Bytes32,
+    receipts: Vec<receipt::Receipt>,
+}
+
+#[Object]
+impl DryRunTransaction {
+    async fn id(&self) -> TransactionId {
+        self.id.into()
+    }
+
+    /// The receipts of the dry-run of the transaction.
+    async fn receipts(&self) -> &Vec<receipt::Receipt> {
+        &self.receipts
+    }
+}
+
+pub struct StateChange(executor::StateChange);
+
+#[Object]
+impl StateChange {
+    /// The name of the database column.
+    async fn column(&self) -> &str {
+        &self.0.column
+    }
+
+    async fn key(&self) -> HexString {
+        HexString(self.0.key.clone())
+    }
+
+    /// The new value, or `null` if the value is removed.
+    async fn value(&self) -> Option<HexString> {
+        self.0.value.clone().map(HexString)
+    }
+}
+
 #[derive(Default)]
 pub struct TxMutation;
 
@@ -246,6 +415,10 @@ impl TxMutation {
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
+        // The height of the simulated block instead of the next height.
+        block_height: Option<U32>,
+        // The time of the simulated block instead of the current time.
+        block_time: Option<Tai64Timestamp>,
     ) -> async_graphql::Result<Vec<receipt::Receipt>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let config = ctx.data_unchecked::<Config>();
@@ -253,10 +426,101 @@ impl TxMutation {
         let mut tx = FuelTx::from_bytes(&tx.0)?;
         tx.precompute(&config.consensus_parameters.chain_id)?;
 
-        let receipts = block_producer.dry_run_tx(tx, None, utxo_validation).await?;
+        let overrides = dry_run_overrides(block_height, block_time);
+        let receipts = block_producer
+            .dry_run_tx(tx, overrides, utxo_validation)
+            .await?;
         Ok(receipts.iter().map(Into::into).collect())
     }
 
+    /// Execute a dry-run of the transaction like the `dryRun`, and profile the execution
+    /// of its script. The profile is a JSON with the gas consumed by each opcode and by
+    /// each stack of called contracts, and with the high-water marks of the memory.
+    async fn dry_run_profile(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+        // The height of the simulated block instead of the next height.
+        block_height: Option<U32>,
+        // The time of the simulated block instead of the current time.
+        block_time: Option<Tai64Timestamp>,
+    ) -> async_graphql::Result<DryRunProfile> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+
+        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        tx.precompute(&config.consensus_parameters.chain_id)?;
+
+        let (receipts, profile) = block_producer
+            .dry_run_profile_tx(
+                tx,
+                dry_run_overrides(block_height, block_time),
+                utxo_validation,
+            )
+            .await?;
+        Ok(DryRunProfile {
+            receipts: receipts.iter().map(Into::into).collect(),
+            profile: serde_json::to_string(&profile)?,
+        })
+    }
+
+    /// Execute a dry-run of the ordered bundle of transactions using a fork of current
+    /// state, no changes are committed. Each transaction is executed on top of the changes
+    /// made by the previous ones, so the bundle can simulate a multi-step flow.
+    async fn dry_run_bundle(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        // If set to false, disable input utxo validation, overriding the configuration of the node.
+        utxo_validation: Option<bool>,
+        // The height of the simulated block instead of the next height.
+        block_height: Option<U32>,
+        // The time of the simulated block instead of the current time.
+        block_time: Option<Tai64Timestamp>,
+    ) -> async_graphql::Result<DryRunBundle> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+        let chain_id = &config.consensus_parameters.chain_id;
+
+        if txs.len() > MAX_DRY_RUN_BUNDLE_SIZE {
+            return Err(anyhow!(
+                "The bundle can't contain more than {MAX_DRY_RUN_BUNDLE_SIZE} transactions"
+            )
+            .into())
+        }
+
+        let txs = txs
+            .into_iter()
+            .map(|tx| {
+                let mut tx = FuelTx::from_bytes(&tx.0)?;
+                tx.precompute(chain_id)?;
+                Ok(tx)
+            })
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+        let ids: Vec<_> = txs.iter().map(|tx| tx.id(chain_id)).collect();
+
+        let (receipts, diff) = block_producer
+            .dry_run_bundle_txs(
+                txs,
+                dry_run_overrides(block_height, block_time),
+                utxo_validation,
+            )
+            .await?;
+        Ok(DryRunBundle {
+            transactions: ids
+                .into_iter()
+                .zip(receipts)
+                .map(|(id, receipts)| DryRunTransaction {
+                    id,
+                    receipts: receipts.iter().map(Into::into).collect(),
+                })
+                .collect(),
+            state_diff: diff.changes.into_iter().map(StateChange).collect(),
+        })
+    }
+
     /// Submits transaction to the `TxPool`.
     ///
     /// Returns submitted transaction if the transaction is included in the `TxPool` without problems.
@@ -267,6 +531,7 @@ impl TxMutation {
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
+        ensure_writable(config)?;
         let tx = FuelTx::from_bytes(&tx.0)?;
 
         let _: Vec<_> = txpool
@@ -279,6 +544,39 @@ impl TxMutation {
         let tx = Transaction(tx, id);
         Ok(tx)
     }
+
+    /// Submits the private transaction to the `TxPool` of the block producer.
+    ///
+    /// Unlike `submit`, the transaction isn't gossiped to the peers before its inclusion,
+    /// only the block producer of this node includes it. The mutation fails if the node
+    /// doesn't produce the blocks.
+    async fn submit_private(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Transaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let config = ctx.data_unchecked::<Config>();
+        ensure_writable(config)?;
+        if !config.block_production {
+            return Err(anyhow::anyhow!(
+                "The node doesn't produce the blocks and doesn't accept \
+                the private transactions"
+            )
+            .into())
+        }
+        let tx = FuelTx::from_bytes(&tx.0)?;
+
+        let _: Vec<_> = txpool
+            .insert_private(vec![Arc::new(tx.clone())])
+            .await
+            .into_iter()
+            .try_collect()?;
+        let id = tx.id(&config.consensus_parameters.chain_id);
+
+        let tx = Transaction(tx, id);
+        Ok(tx)
+    }
 }
 
 #[derive(Default)]
@@ -307,23 +605,73 @@ impl TxStatusSubscription {
         let db = ctx.data_unchecked::<Database>();
         let rx = txpool.tx_update_subscribe(id.into()).await;
 
-        transaction_status_change(
-            move |id| match db.tx_status(&id) {
-                Ok(status) => Ok(Some(status)),
-                Err(StorageError::NotFound(_, _)) => {
-                    Ok(txpool.submission_time(id).map(|time| {
-                        fuel_core_types::services::txpool::TransactionStatus::Submitted {
-                            time,
-                        }
-                    }))
+        transaction_status_change(move |id| current_status(db, txpool, id), rx, id.into())
+            .await
+            .map_err(async_graphql::Error::from)
+    }
+
+    /// Returns a stream of the status updates of the transaction with their cursors.
+    /// Without the `resumeFrom`, the stream starts with the current status
+    /// if the transaction is known to the node. With the cursor of the last received
+    /// status, the stream starts with the statuses after it, so the reconnected client
+    /// doesn't miss any update. The stream ends after the final status.
+    ///
+    /// The node buffers only the latest events, and the subscription fails
+    /// if the statuses after the cursor are no longer buffered. The stream ends
+    /// with an error if it is polled slower than the updates arrive.
+    async fn status_change_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+        #[graphql(desc = "The cursor of the last event.")] resume_from: Option<String>,
+    ) -> async_graphql::Result<
+        impl Stream<Item = async_graphql::Result<TransactionStatusEvent>> + 'a,
+    > {
+        let resumed = resume_from.is_some();
+        let (cursor, events) = subscription_events(ctx, resume_from)?;
+        let id: fuel_types::Bytes32 = id.into();
+        // The resumed client already received the status at the cursor.
+        let current = if resumed {
+            None
+        } else {
+            let txpool = ctx.data_unchecked::<TxPool>();
+            let db = ctx.data_unchecked::<Database>();
+            current_status(db, txpool, id)?.map(|status| (cursor, status))
+        };
+
+        let initial = current.as_ref().map(|(_, status)| status.clone());
+        let updates = events.filter_map(move |event| match event {
+            Ok((cursor, SubscriptionEvent::TxStatus(tx_id, status)))
+                if tx_id == id && Some(&status) != initial.as_ref() =>
+            {
+                Some(Ok((cursor, status)))
+            }
+            Ok(_) => None,
+            Err(e) => Some(Err(e)),
+        });
+        let statuses =
+            futures::stream::iter(current.map(Ok::<_, anyhow::Error>)).chain(updates);
+        Ok(futures::StreamExt::scan(
+            statuses,
+            false,
+            |finished, status| {
+                if *finished {
+                    return futures::future::ready(None)
                 }
-                Err(err) => Err(err),
+                // The stream ends after the final status or the error.
+                *finished = !matches!(
+                    status,
+                    Ok((_, txpool::TransactionStatus::Submitted { .. }))
+                );
+                let event = status
+                    .map(|(cursor, status)| TransactionStatusEvent {
+                        cursor: cursor.to_string(),
+                        status: status.into(),
+                    })
+                    .map_err(Into::into);
+                futures::future::ready(Some(event))
             },
-            rx,
-            id.into(),
-        )
-        .await
-        .map_err(async_graphql::Error::from)
+        ))
     }
 
     /// Submits transaction to the `TxPool` and await either confirmation or failure.
@@ -336,6 +684,7 @@ impl TxStatusSubscription {
     > {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
+        ensure_writable(config)?;
         let tx = FuelTx::from_bytes(&tx.0)?;
         let tx_id = tx.id(&config.consensus_parameters.chain_id);
         let subscription = txpool.tx_update_subscribe(tx_id).await;
@@ -362,3 +711,38 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+/// The status of the transaction with the cursor of the resumable subscription.
+#[derive(SimpleObject)]
+pub struct TransactionStatusEvent {
+    /// The cursor to resume the subscription after this status.
+    cursor: String,
+    status: TransactionStatus,
+}
+
+/// Returns the status of the transaction from the database, or the `Submitted` status
+/// if the transaction is in the `TxPool`.
+fn current_status(
+    db: &Database,
+    txpool: &TxPool,
+    id: fuel_types::Bytes32,
+) -> StorageResult<Option<txpool::TransactionStatus>> {
+    match db.tx_status(&id) {
+        Ok(status) => Ok(Some(status)),
+        Err(StorageError::NotFound(_, _)) => Ok(txpool
+            .submission_time(id)
+            .map(|time| txpool::TransactionStatus::Submitted { time })),
+        Err(err) => Err(err),
+    }
+}
+
+/// Rejects the submission of the transaction if the node doesn't accept them.
+fn ensure_writable(config: &Config) -> async_graphql::Result<()> {
+    if config.read_only {
+        return Err(anyhow::anyhow!(
+            "The node is a read replica and doesn't accept the transactions"
+        )
+        .into())
+    }
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use crate::schema::{
+    block::Header,
     contract::Contract,
+    message::MerkleProof,
     scalars::{
         Address,
         AssetId,
@@ -16,8 +18,10 @@ use async_graphql::{
 };
 use derive_more::Display;
 use fuel_core_types::{
+    entities,
     fuel_asm::Word,
     fuel_tx,
+    fuel_types::canonical::Serialize,
 };
 
 #[derive(Copy, Clone, Debug, Display, Enum, Eq, PartialEq, strum_macros::EnumIter)]
@@ -159,6 +163,58 @@ impl From<fuel_tx::Receipt> for Receipt {
     }
 }
 
+/// Proves that the receipt was produced by the transaction included in a Fuel block.
+pub struct ReceiptProof(pub(crate) entities::receipt::ReceiptProof);
+
+#[Object]
+impl ReceiptProof {
+    /// Proof that the receipt is contained within the receipts root of the transaction.
+    /// The leaves are the serialized receipts of the transaction.
+    async fn receipt_proof(&self) -> MerkleProof {
+        self.0.receipt_proof.clone().into()
+    }
+
+    /// Proof that the transaction is contained within the transactions root
+    /// of the block header. The leaves are the serialized transactions of the block.
+    async fn transaction_proof(&self) -> MerkleProof {
+        self.0.transaction_proof.clone().into()
+    }
+
+    /// Proof that the block header is contained within the blockchain history.
+    async fn block_proof(&self) -> MerkleProof {
+        self.0.block_proof.clone().into()
+    }
+
+    /// The header of the block that contains the transaction.
+    async fn block_header(&self) -> Header {
+        self.0.block_header.clone().into()
+    }
+
+    /// The header of the block used as the root of the block proof.
+    async fn commit_block_header(&self) -> Header {
+        self.0.commit_block_header.clone().into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        self.0.receipt.clone().into()
+    }
+
+    /// The serialized receipt, the leaf of the receipt proof.
+    async fn raw_receipt(&self) -> HexString {
+        HexString(self.0.receipt.to_bytes())
+    }
+
+    /// The number of the receipts of the transaction.
+    async fn receipts_count(&self) -> U64 {
+        self.0.receipts_count.into()
+    }
+
+    /// The serialized transaction, the leaf of the transaction proof.
+    async fn raw_transaction(&self) -> HexString {
+        HexString(self.0.transaction.to_bytes())
+    }
+}
+
 #[cfg(feature = "test-helpers")]
 pub fn all_receipts() -> Vec<fuel_tx::Receipt> {
     use strum::IntoEnumIterator;
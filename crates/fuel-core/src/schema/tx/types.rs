@@ -123,6 +123,21 @@ pub enum TransactionStatus {
     Failed(FailureStatus),
 }
 
+/// The status of the transaction recorded by the status archive.
+pub struct ArchivedTransactionStatus(pub txpool::ArchivedTransactionStatus);
+
+#[Object]
+impl ArchivedTransactionStatus {
+    /// The time when the node recorded the status.
+    async fn time(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.0.time)
+    }
+
+    async fn status(&self) -> TransactionStatus {
+        self.0.status.clone().into()
+    }
+}
+
 #[derive(Debug)]
 pub struct SubmittedStatus(pub Tai64);
 
@@ -15,25 +15,55 @@ use tracing::warn;
 pub use config::{
     Config,
     DbType,
+    NodeMode,
+    StartupChecks,
     VMConfig,
 };
 pub use fuel_core_services::Service as ServiceTrait;
+pub use instances::FuelServices;
+pub use reload::{
+    ReloadReport,
+    ReloadableConfig,
+};
 
 pub use fuel_core_consensus_module::RelayerVerifierConfig;
 
 use self::adapters::BlockImporterAdapter;
 
+pub mod account_watcher;
 pub mod adapters;
+pub mod asset_registry;
+pub mod compaction_scheduler;
 pub mod config;
+pub mod contract_calls;
+pub mod disk_monitor;
+#[cfg(feature = "event-bus")]
+pub mod event_bus;
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
 pub mod genesis;
+pub mod instances;
 pub mod metrics;
 mod query;
+mod reload;
+pub mod replay;
+pub mod startup_checks;
+pub mod state_diff_follower;
+pub mod storage_stats;
 pub mod sub_services;
+pub mod subscription_buffer;
+pub mod test_vector;
+pub mod tx_status_archive;
+pub mod upgrades;
 
 #[derive(Clone)]
 pub struct SharedState {
     /// The transaction pool shared state.
     pub txpool: fuel_core_txpool::service::SharedState<P2PAdapter, Database>,
+    /// The gas price shared state.
+    pub gas_price: fuel_core_gas_price_service::service::SharedState,
+    /// The PoA shared state. It is `None` if the block production is disabled.
+    pub poa: Option<fuel_core_poa::service::SharedState>,
     /// The P2P network shared state.
     #[cfg(feature = "p2p")]
     pub network: Option<fuel_core_p2p::service::SharedState>,
@@ -42,10 +72,23 @@ pub struct SharedState {
     pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
     /// The GraphQL shared state.
     pub graph_ql: crate::fuel_core_graphql_api::service::SharedState,
+    /// The gRPC API shared state. It is `None` if the gRPC API is disabled.
+    #[cfg(feature = "grpc")]
+    pub grpc: Option<crate::grpc_api::service::SharedState>,
+    /// The storage statistics of the database tables. It is `None` if they are disabled.
+    pub storage_stats: Option<storage_stats::SharedState>,
+    /// The disk space state of the node. It is `None` if the monitoring is disabled.
+    pub disk_monitor: Option<disk_monitor::SharedState>,
+    /// The compaction of the database tables. It is `None` if the scheduler is disabled.
+    pub compaction_scheduler: Option<compaction_scheduler::SharedState>,
     /// The underlying database.
     pub database: Database,
     /// Subscribe to new block production.
     pub block_importer: BlockImporterAdapter,
+    /// The execution statistics of the contracts in the latest blocks.
+    pub execution_stats: crate::executor::execution_stats::ExecutionStats,
+    /// The budget of the resources shared by the subsystems.
+    pub resource_budget: fuel_core_services::ResourceBudget,
     /// The config of the service.
     pub config: Config,
 }
@@ -68,6 +111,16 @@ impl FuelService {
     #[tracing::instrument(skip_all, fields(name = %config.name))]
     pub fn new(database: Database, config: Config) -> anyhow::Result<Self> {
         let config = config.make_config_consistent();
+        // The executor pays the fee shares as they are configured, so the chain
+        // can't start with the shares rejected by the validation.
+        if let Some(error) = config
+            .chain_conf
+            .validate()
+            .into_iter()
+            .find(|error| error.field == "fee_shares")
+        {
+            anyhow::bail!("Invalid chain config: {error}");
+        }
         database.init(&config.chain_conf)?;
         let task = Task::new(database, config)?;
         let runner = ServiceRunner::new(task);
@@ -93,7 +146,11 @@ impl FuelService {
                     );
                     Database::default()
                 } else {
-                    Database::open(&config.database_path, config.max_database_cache_size)?
+                    Database::open(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        config.database_durability,
+                    )?
                 }
             }
             DbType::InMemory => Database::in_memory(),
@@ -183,6 +240,13 @@ impl Task {
     pub fn new(database: Database, config: Config) -> anyhow::Result<Task> {
         // initialize state
         genesis::maybe_initialize_state(&config, &database)?;
+        startup_checks::run(&database, &config)?;
+        if config.compact_contracts_bytecode {
+            // Runs before the block importer starts, so the references of the
+            // imported contracts are not miscounted.
+            let compaction = database.compact_contracts_bytecode()?;
+            tracing::info!("Compacted the bytecode of the contracts: {compaction:?}");
+        }
 
         // initialize sub services
         let (services, shared) = sub_services::init_sub_services(&config, &database)?;
@@ -294,9 +358,9 @@ mod tests {
             i += 1;
         }
 
-        // current services: graphql, txpool, PoA
+        // current services: graphql, txpool, gas price, PoA
         #[allow(unused_mut)]
-        let mut expected_services = 3;
+        let mut expected_services = 4;
 
         // Relayer service is disabled with `Config::local_node`.
         // #[cfg(feature = "relayer")]
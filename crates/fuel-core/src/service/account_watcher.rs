@@ -0,0 +1,523 @@
+//! Notifies the clients about the changes of the accounts they watch. Each subscription
+//! watches its addresses of interest, and the watcher follows the imported blocks and
+//! delivers the coins received and spent by those addresses and the messages they
+//! received, so the wallet backends don't need to scan every block for every user.
+//!
+//! The addresses are watched only while the subscription is alive, so nothing
+//! stays registered after the client disconnects.
+
+use crate::database::Database;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    tables::Messages,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        Input,
+        Output,
+        Transaction,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
+        ChainId,
+    },
+    services::{
+        block_importer::ImportResult,
+        graphql_api::AccountEvent,
+    },
+};
+use futures::Stream;
+use parking_lot::RwLock;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    pin::Pin,
+    sync::Arc,
+    task::{
+        Context,
+        Poll,
+    },
+};
+use tokio::sync::{
+    broadcast::{
+        self,
+        error::RecvError,
+    },
+    mpsc,
+};
+
+/// The max number of the addresses watched at the same time.
+pub const MAX_WATCHED_ACCOUNTS: usize = 10_000;
+
+/// The number of the events buffered for each slow subscription.
+const EVENTS_CAPACITY: usize = 1024;
+
+pub type Service = ServiceRunner<NotInitializedTask>;
+
+type SubscriptionId = u64;
+
+#[derive(Default)]
+struct Subscriptions {
+    next_id: SubscriptionId,
+    /// The subscriptions to the events of each watched address.
+    by_account: HashMap<Address, HashMap<SubscriptionId, mpsc::Sender<AccountEvent>>>,
+}
+
+/// The subscriptions to the events of the watched addresses.
+#[derive(Clone, Default)]
+pub struct SharedState {
+    subscriptions: Arc<RwLock<Subscriptions>>,
+}
+
+impl SharedState {
+    /// Subscribes to the events of the `addresses`, starting from the next block.
+    /// The addresses are watched until the returned subscription is dropped.
+    pub fn subscribe(
+        &self,
+        addresses: impl IntoIterator<Item = Address>,
+    ) -> anyhow::Result<AccountSubscription> {
+        let addresses: HashSet<Address> = addresses.into_iter().collect();
+        let mut subscriptions = self.subscriptions.write();
+        let new_accounts = addresses
+            .iter()
+            .filter(|address| !subscriptions.by_account.contains_key(*address))
+            .count();
+        if subscriptions.by_account.len() + new_accounts > MAX_WATCHED_ACCOUNTS {
+            return Err(anyhow::anyhow!(
+                "The node can't watch more than {MAX_WATCHED_ACCOUNTS} accounts"
+            ))
+        }
+
+        let id = subscriptions.next_id;
+        subscriptions.next_id += 1;
+        let (sender, receiver) = mpsc::channel(EVENTS_CAPACITY);
+        for address in addresses.iter() {
+            subscriptions
+                .by_account
+                .entry(*address)
+                .or_default()
+                .insert(id, sender.clone());
+        }
+        Ok(AccountSubscription {
+            receiver,
+            _registration: Registration {
+                id,
+                addresses,
+                subscriptions: self.subscriptions.clone(),
+            },
+        })
+    }
+
+    /// Returns `true` if the `address` is watched by any subscription.
+    pub fn is_watched(&self, address: &Address) -> bool {
+        self.subscriptions.read().by_account.contains_key(address)
+    }
+
+    /// Delivers the `event` to the subscriptions of its account.
+    fn publish(&self, event: AccountEvent) {
+        let subscriptions = self.subscriptions.read();
+        let Some(senders) = subscriptions.by_account.get(event.account()) else {
+            return
+        };
+        for sender in senders.values() {
+            // The event is skipped if the subscriber doesn't keep up with the blocks.
+            let _ = sender.try_send(event.clone());
+        }
+    }
+}
+
+/// The stream of the events of the watched addresses.
+/// The addresses are unwatched when it is dropped.
+pub struct AccountSubscription {
+    receiver: mpsc::Receiver<AccountEvent>,
+    _registration: Registration,
+}
+
+impl Stream for AccountSubscription {
+    type Item = AccountEvent;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+/// Removes the addresses of the subscription from the watched ones on drop.
+struct Registration {
+    id: SubscriptionId,
+    addresses: HashSet<Address>,
+    subscriptions: Arc<RwLock<Subscriptions>>,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        let mut subscriptions = self.subscriptions.write();
+        for address in self.addresses.iter() {
+            if let Some(senders) = subscriptions.by_account.get_mut(address) {
+                senders.remove(&self.id);
+                if senders.is_empty() {
+                    subscriptions.by_account.remove(address);
+                }
+            }
+        }
+    }
+}
+
+pub struct NotInitializedTask {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    chain_id: ChainId,
+    shared: SharedState,
+}
+
+pub struct Task {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    chain_id: ChainId,
+    shared: SharedState,
+    /// The DA height of the latest processed block.
+    da_height: DaBlockHeight,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for NotInitializedTask {
+    const NAME: &'static str = "AccountWatcher";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            database,
+            blocks,
+            chain_id,
+            shared,
+        } = self;
+        let da_height = database
+            .get_current_block()?
+            .map(|block| block.header().da_height)
+            .unwrap_or_default();
+        Ok(Task {
+            database,
+            blocks,
+            chain_id,
+            shared,
+            da_height,
+        })
+    }
+}
+
+impl Task {
+    /// Publishes the events of the watched addresses from the imported block.
+    fn process_block(&mut self, result: &ImportResult) -> anyhow::Result<()> {
+        let block = &result.sealed_block.entity;
+        let height = *block.header().height();
+        let previous_da_height = self.da_height;
+        self.da_height = block.header().da_height;
+
+        let events = {
+            let subscriptions = self.shared.subscriptions.read();
+            let accounts = &subscriptions.by_account;
+            if accounts.is_empty() {
+                return Ok(())
+            }
+            let mut events =
+                coin_events(block.transactions(), &self.chain_id, height, |address| {
+                    accounts.contains_key(address)
+                });
+            if self.da_height > previous_da_height {
+                for recipient in accounts.keys() {
+                    events.extend(self.received_messages(
+                        recipient,
+                        previous_da_height,
+                        height,
+                    )?);
+                }
+            }
+            events
+        };
+
+        for event in events {
+            self.shared.publish(event);
+        }
+        Ok(())
+    }
+
+    /// Returns the messages of the `recipient` with the DA height above the `after`
+    /// and up to the DA height of the block. They became spendable with the block.
+    fn received_messages(
+        &self,
+        recipient: &Address,
+        after: DaBlockHeight,
+        block_height: BlockHeight,
+    ) -> anyhow::Result<Vec<AccountEvent>> {
+        let mut events = vec![];
+        for nonce in self.database.owned_message_ids(recipient, None, None) {
+            let nonce = nonce?;
+            let Some(message) = self.database.storage::<Messages>().get(&nonce)? else {
+                continue
+            };
+            if message.da_height > after && message.da_height <= self.da_height {
+                events.push(AccountEvent::MessageReceived {
+                    recipient: *recipient,
+                    nonce,
+                    amount: message.amount,
+                    da_height: message.da_height,
+                    block_height,
+                });
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Returns the coins spent and received by the watched addresses in the `transactions`.
+/// The outputs without the amount don't create the coins.
+fn coin_events(
+    transactions: &[Transaction],
+    chain_id: &ChainId,
+    block_height: BlockHeight,
+    is_watched: impl Fn(&Address) -> bool,
+) -> Vec<AccountEvent> {
+    let mut events = vec![];
+    for tx in transactions {
+        let tx_id = tx.id(chain_id);
+        let (inputs, outputs): (&[Input], &[Output]) = match tx {
+            Transaction::Script(script) => (script.inputs(), script.outputs()),
+            Transaction::Create(create) => (create.inputs(), create.outputs()),
+            Transaction::Mint(mint) => (&[], mint.outputs()),
+        };
+
+        for input in inputs {
+            match input {
+                Input::CoinSigned(CoinSigned { owner, utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { owner, utxo_id, .. })
+                    if is_watched(owner) =>
+                {
+                    events.push(AccountEvent::CoinSpent {
+                        owner: *owner,
+                        utxo_id: *utxo_id,
+                        tx_id,
+                        block_height,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        for (index, output) in outputs.iter().enumerate() {
+            match output {
+                Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Change {
+                    to,
+                    amount,
+                    asset_id,
+                }
+                | Output::Variable {
+                    to,
+                    amount,
+                    asset_id,
+                } if *amount > 0 && is_watched(to) => {
+                    events.push(AccountEvent::CoinReceived {
+                        owner: *to,
+                        utxo_id: UtxoId::new(tx_id, index as u8),
+                        asset_id: *asset_id,
+                        amount: *amount,
+                        block_height,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            block = self.blocks.recv() => match block {
+                Ok(result) => {
+                    if let Err(e) = self.process_block(&result) {
+                        tracing::error!("The account watcher failed to process the block: {e}");
+                    }
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "The account watcher skipped the events of {skipped} blocks"
+                    );
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    chain_id: ChainId,
+) -> Service {
+    ServiceRunner::new(NotInitializedTask {
+        database,
+        blocks,
+        chain_id,
+        shared: SharedState::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::TransactionBuilder,
+        fuel_types::AssetId,
+    };
+    use futures::StreamExt;
+
+    fn message_received(recipient: Address) -> AccountEvent {
+        AccountEvent::MessageReceived {
+            recipient,
+            nonce: Default::default(),
+            amount: 1,
+            da_height: 1u64.into(),
+            block_height: 1u32.into(),
+        }
+    }
+
+    #[test]
+    fn addresses_are_watched_until_subscription_is_dropped() {
+        let shared = SharedState::default();
+        let address = Address::from([1; 32]);
+
+        let first = shared.subscribe([address]).unwrap();
+        let second = shared.subscribe([address]).unwrap();
+        drop(first);
+        assert!(shared.is_watched(&address));
+        drop(second);
+        assert!(!shared.is_watched(&address));
+    }
+
+    #[tokio::test]
+    async fn events_are_delivered_only_to_subscriptions_of_account() {
+        let shared = SharedState::default();
+        let first = Address::from([1; 32]);
+        let second = Address::from([2; 32]);
+        let mut first_events = shared.subscribe([first]).unwrap();
+        let mut both_events = shared.subscribe([first, second]).unwrap();
+
+        shared.publish(message_received(second));
+        shared.publish(message_received(first));
+
+        assert_eq!(both_events.next().await, Some(message_received(second)));
+        assert_eq!(both_events.next().await, Some(message_received(first)));
+        assert_eq!(first_events.next().await, Some(message_received(first)));
+        assert!(first_events.receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn subscription_is_rejected_above_max_watched_accounts() {
+        let shared = SharedState::default();
+        let addresses = (0..MAX_WATCHED_ACCOUNTS as u64).map(|i| {
+            let mut address = [0; 32];
+            address[..8].copy_from_slice(&i.to_be_bytes());
+            Address::from(address)
+        });
+        let _subscription = shared.subscribe(addresses).unwrap();
+
+        // The already watched addresses don't count against the limit.
+        assert!(shared.subscribe([Address::zeroed()]).is_ok());
+        assert!(shared.subscribe([Address::from([1; 32])]).is_err());
+    }
+
+    #[test]
+    fn coin_events_include_only_watched_owners() {
+        let watched = Address::from([1; 32]);
+        let other = Address::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+        let spent = UtxoId::new([4; 32].into(), 0);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                spent,
+                watched,
+                10,
+                asset_id,
+                Default::default(),
+                0,
+                0u32.into(),
+            ))
+            .add_output(Output::coin(other, 7, asset_id))
+            .add_output(Output::change(watched, 3, asset_id))
+            .add_output(Output::variable(watched, 0, asset_id))
+            .finalize_as_transaction();
+        let chain_id = ChainId::default();
+        let tx_id = tx.id(&chain_id);
+
+        let events =
+            coin_events(&[tx], &chain_id, 5u32.into(), |address| address == &watched);
+
+        assert_eq!(
+            events,
+            vec![
+                AccountEvent::CoinSpent {
+                    owner: watched,
+                    utxo_id: spent,
+                    tx_id,
+                    block_height: 5u32.into(),
+                },
+                AccountEvent::CoinReceived {
+                    owner: watched,
+                    utxo_id: UtxoId::new(tx_id, 1),
+                    asset_id,
+                    amount: 3,
+                    block_height: 5u32.into(),
+                },
+            ]
+        );
+    }
+}
@@ -4,18 +4,35 @@ use crate::{
 };
 use fuel_core_consensus_module::block_verifier::Verifier;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
-use fuel_core_types::fuel_types::BlockHeight;
 #[cfg(feature = "p2p")]
 use fuel_core_types::services::p2p::peer_reputation::AppScore;
-use std::sync::Arc;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    services::txpool::ArcPoolTx,
+};
+use parking_lot::Mutex;
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Instant,
+};
 
 pub mod block_importer;
 pub mod consensus_module;
 pub mod executor;
+pub mod gas_price;
 pub mod graphql_api;
 #[cfg(feature = "p2p")]
 pub mod p2p;
 pub mod producer;
+#[cfg(feature = "relayer")]
+pub mod relayer;
 #[cfg(feature = "p2p")]
 pub mod sync;
 pub mod txpool;
@@ -25,6 +42,67 @@ pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
 }
 
+/// The clock of the chain advanced by the `advanceTime` in the development mode.
+/// The advanced time is stored in the database, so it survives the restarts.
+#[derive(Clone, Debug)]
+pub struct ChainClockAdapter {
+    database: Database,
+    time_offset: Arc<AtomicU64>,
+}
+
+impl ChainClockAdapter {
+    pub fn new(database: Database) -> anyhow::Result<Self> {
+        let time_offset = database.get_time_offset()?;
+        Ok(Self {
+            database,
+            time_offset: Arc::new(AtomicU64::new(time_offset)),
+        })
+    }
+
+    /// Returns the seconds added to the wall clock.
+    pub fn time_offset(&self) -> u64 {
+        self.time_offset.load(Ordering::Acquire)
+    }
+
+    /// Stores the seconds added to the wall clock.
+    pub fn set_time_offset(&self, time_offset: u64) -> anyhow::Result<()> {
+        self.database.set_time_offset(time_offset)?;
+        self.time_offset.store(time_offset, Ordering::Release);
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GasPriceAdapter {
+    shared_state: fuel_core_gas_price_service::service::SharedState,
+}
+
+impl GasPriceAdapter {
+    pub fn new(shared_state: fuel_core_gas_price_service::service::SharedState) -> Self {
+        Self { shared_state }
+    }
+}
+
+/// Requests the blob base fee from the Ethereum node used by the relayer.
+/// Falls back to the blob base fee of the latest finalized DA block recorded
+/// by the relayer if the request fails.
+pub struct BlobBaseFeeAdapter {
+    #[cfg(feature = "relayer")]
+    source: fuel_core_relayer::blob_fee::EthBlobBaseFee,
+    #[cfg(feature = "relayer")]
+    database: Database,
+}
+
+#[cfg(feature = "relayer")]
+impl BlobBaseFeeAdapter {
+    pub fn new(
+        source: fuel_core_relayer::blob_fee::EthBlobBaseFee,
+        database: Database,
+    ) -> Self {
+        Self { source, database }
+    }
+}
+
 #[derive(Clone)]
 pub struct TxPoolAdapter {
     service: TxPoolSharedState<P2PAdapter, Database>,
@@ -36,20 +114,37 @@ impl TxPoolAdapter {
     }
 }
 
+/// Serves the status subscriptions of the `TxPool` from the events
+/// of the executing node received via the event bus.
+#[cfg(feature = "event-bus")]
+#[derive(Clone)]
+pub struct EventBusTxPoolAdapter {
+    pub txpool: TxPoolAdapter,
+    pub events: crate::service::event_bus::SharedState,
+}
+
 #[derive(Clone)]
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database>,
     _block_height: BlockHeight,
+    /// The transactions aren't supplied to the executor after the deadline.
+    deadline: Option<Instant>,
+    /// The selected transactions that aren't yet supplied to the executor.
+    /// Used only with the `deadline`.
+    selected: Arc<Mutex<Option<VecDeque<ArcPoolTx>>>>,
 }
 
 impl TransactionsSource {
     pub fn new(
         txpool: TxPoolSharedState<P2PAdapter, Database>,
         block_height: BlockHeight,
+        deadline: Option<Instant>,
     ) -> Self {
         Self {
             txpool,
             _block_height: block_height,
+            deadline,
+            selected: Default::default(),
         }
     }
 }
@@ -58,11 +153,15 @@ impl TransactionsSource {
 pub struct ExecutorAdapter {
     pub relayer: MaybeRelayerAdapter,
     pub config: Arc<fuel_core_executor::Config>,
+    pub execution_stats: crate::executor::execution_stats::ExecutionStats,
+    /// The budget of the concurrent dry runs.
+    pub resource_budget: fuel_core_services::ResourceBudget,
 }
 
 #[derive(Clone)]
 pub struct VerifierAdapter {
     pub block_verifier: Arc<Verifier<Database, MaybeRelayerAdapter>>,
+    pub database: Database,
 }
 
 #[derive(Clone)]
@@ -85,11 +184,30 @@ pub struct BlockImporterAdapter {
         Arc<fuel_core_importer::Importer<Database, ExecutorAdapter, VerifierAdapter>>,
 }
 
+/// Consolidates the synchronization status of the node for the GraphQL API.
+#[derive(Clone)]
+pub struct SyncStatusAdapter {
+    pub database: Database,
+    #[cfg(feature = "p2p")]
+    pub sync: Option<fuel_core_sync::service::SharedState>,
+    #[cfg(feature = "relayer")]
+    pub relayer: Option<fuel_core_relayer::SharedState<Database>>,
+    #[cfg(feature = "relayer")]
+    pub block_committer: Option<
+        fuel_core_relayer::committer::SharedState<
+            std::sync::Arc<fuel_core_relayer::committer::EthCommitter>,
+        >,
+    >,
+}
+
 #[cfg(feature = "p2p")]
 #[derive(Clone)]
 pub struct P2PAdapter {
     service: Option<fuel_core_p2p::service::SharedState>,
     peer_report_config: PeerReportConfig,
+    /// Delays the gossip of the transactions, injected as a byzantine fault.
+    #[cfg(feature = "fault-injection")]
+    gossip_delay: Option<std::time::Duration>,
 }
 
 #[cfg(feature = "p2p")]
@@ -115,6 +233,16 @@ impl P2PAdapter {
         Self {
             service,
             peer_report_config,
+            #[cfg(feature = "fault-injection")]
+            gossip_delay: None,
+        }
+    }
+
+    #[cfg(feature = "fault-injection")]
+    pub fn with_gossip_delay(self, gossip_delay: Option<std::time::Duration>) -> Self {
+        Self {
+            gossip_delay,
+            ..self
         }
     }
 }
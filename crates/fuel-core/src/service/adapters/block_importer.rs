@@ -1,12 +1,16 @@
 use crate::{
     database::Database,
-    service::adapters::{
-        BlockImporterAdapter,
-        ExecutorAdapter,
-        VerifierAdapter,
+    service::{
+        adapters::{
+            BlockImporterAdapter,
+            ExecutorAdapter,
+            VerifierAdapter,
+        },
+        upgrades,
     },
 };
 use fuel_core_importer::{
+    importer::BatchImportResult,
     ports::{
         BlockVerifier,
         Executor,
@@ -34,10 +38,14 @@ use fuel_core_types::{
         SealedBlock,
     },
     fuel_types::BlockHeight,
-    services::executor::{
-        ExecutionTypes,
-        Result as ExecutorResult,
-        UncommittedResult as UncommittedExecutionResult,
+    services::{
+        block_importer::CompetingBlock,
+        executor::{
+            ExecutionTypes,
+            Result as ExecutorResult,
+            StateDiff,
+            UncommittedResult as UncommittedExecutionResult,
+        },
     },
 };
 use std::sync::Arc;
@@ -69,15 +77,40 @@ impl BlockImporterAdapter {
         .await??;
         Ok(())
     }
+
+    pub async fn execute_and_commit_batch(
+        &self,
+        sealed_blocks: Vec<SealedBlock>,
+    ) -> anyhow::Result<BatchImportResult> {
+        let result = tokio::task::spawn_blocking({
+            let importer = self.block_importer.clone();
+            move || importer.execute_and_commit_batch(sealed_blocks)
+        })
+        .await?;
+        Ok(result)
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
+    type Database = Database;
+
     fn verify_block_fields(
         &self,
         consensus: &Consensus,
         block: &Block,
     ) -> anyhow::Result<()> {
-        self.block_verifier.verify_block_fields(consensus, block)
+        self.verify_block_fields_on(&self.database, consensus, block)
+    }
+
+    fn verify_block_fields_on(
+        &self,
+        database: &Database,
+        consensus: &Consensus,
+        block: &Block,
+    ) -> anyhow::Result<()> {
+        upgrades::ensure_supported(database, *block.header().height())?;
+        self.block_verifier
+            .verify_block_fields_on(database, consensus, block)
     }
 }
 
@@ -116,9 +149,22 @@ impl ImporterDatabase for Database {
         self.latest_height()
     }
 
+    fn block_id(&self, height: &BlockHeight) -> StorageResult<Option<BlockId>> {
+        self.get_block_id(height)
+    }
+
     fn increase_tx_count(&self, new_txs_count: u64) -> StorageResult<u64> {
         self.increase_tx_count(new_txs_count).map_err(Into::into)
     }
+
+    fn insert_competing_block(
+        &self,
+        competing_block: &CompetingBlock,
+        max_competing_blocks: usize,
+    ) -> StorageResult<bool> {
+        Database::insert_competing_block(self, competing_block, max_competing_blocks)
+            .map_err(Into::into)
+    }
 }
 
 impl ExecutorDatabase for Database {
@@ -131,6 +177,18 @@ impl ExecutorDatabase for Database {
             .insert(block_id, consensus)
             .map_err(Into::into)
     }
+
+    fn uncommitted_state_diff(&self, height: &BlockHeight) -> StorageResult<StateDiff> {
+        Database::uncommitted_state_diff(self, *height).map_err(Into::into)
+    }
+
+    fn record_state_diff(
+        &mut self,
+        height: &BlockHeight,
+        retention: u32,
+    ) -> StorageResult<()> {
+        Database::record_state_diff(self, *height, retention).map_err(Into::into)
+    }
 }
 
 impl Executor for ExecutorAdapter {
@@ -143,4 +201,13 @@ impl Executor for ExecutorAdapter {
     {
         self._execute_without_commit(ExecutionTypes::Validation(block))
     }
+
+    fn execute_without_commit_on(
+        &self,
+        database: &Self::Database,
+        block: Block,
+    ) -> ExecutorResult<UncommittedExecutionResult<StorageTransaction<Self::Database>>>
+    {
+        self._execute_without_commit_on(database, ExecutionTypes::Validation(block))
+    }
 }
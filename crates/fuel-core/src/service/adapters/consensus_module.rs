@@ -36,7 +36,8 @@ impl VerifierAdapter {
         let config =
             VerifierConfig::new(config.chain_conf.clone(), config.verifier.clone());
         Self {
-            block_verifier: Arc::new(Verifier::new(config, database, relayer)),
+            block_verifier: Arc::new(Verifier::new(config, database.clone(), relayer)),
+            database,
         }
     }
 }
@@ -1,19 +1,27 @@
-use std::ops::Deref;
+use std::{
+    ops::Deref,
+    time::Duration,
+};
 
 use crate::{
     database::Database,
     fuel_core_graphql_api::ports::ConsensusModulePort,
-    service::adapters::{
-        BlockImporterAdapter,
-        BlockProducerAdapter,
-        P2PAdapter,
-        PoAAdapter,
-        TxPoolAdapter,
+    service::{
+        adapters::{
+            BlockImporterAdapter,
+            BlockProducerAdapter,
+            ChainClockAdapter,
+            P2PAdapter,
+            PoAAdapter,
+            TxPoolAdapter,
+        },
+        upgrades,
     },
 };
 use anyhow::anyhow;
 use fuel_core_poa::{
     ports::{
+        BlockCosigner,
         BlockImporter,
         P2pPort,
         TransactionPool,
@@ -23,9 +31,15 @@ use fuel_core_poa::{
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
+    blockchain::block::Block,
     fuel_asm::Word,
+    fuel_crypto::Signature,
     fuel_tx::TxId,
     fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
     services::{
         block_importer::{
             BlockImportInfo,
@@ -60,6 +74,75 @@ impl ConsensusModulePort for PoAAdapter {
             .manually_produce_block(start_time, number_of_blocks)
             .await
     }
+
+    async fn advance_time(&self, duration: Duration) -> anyhow::Result<Tai64> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))?
+            .advance_time(duration)
+            .await
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoteCosignerConfig {
+    /// The URL of the admin API of the cosigning node.
+    pub url: String,
+    /// The admin API token of the cosigning node.
+    pub token: Secret<String>,
+}
+
+/// Requests the cosignature of the block from the admin API of the node
+/// holding one of the keys of the multi-signature PoA.
+#[derive(Debug)]
+pub struct RemoteCosigner {
+    client: reqwest::Client,
+    config: RemoteCosignerConfig,
+}
+
+impl RemoteCosigner {
+    pub fn new(config: RemoteCosignerConfig) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            config,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockCosigner for RemoteCosigner {
+    async fn cosign(&self, block: &Block) -> anyhow::Result<Signature> {
+        let bytes = self
+            .client
+            .post(format!(
+                "{}/consensus/cosign",
+                self.config.url.trim_end_matches('/')
+            ))
+            .bearer_auth(self.config.token.expose_secret())
+            .body(postcard::to_allocvec(block)?)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        Ok(postcard::from_bytes(&bytes)?)
+    }
+}
+
+impl fuel_core_poa::ports::ChainClock for ChainClockAdapter {
+    fn time_offset(&self) -> Duration {
+        Duration::from_secs(ChainClockAdapter::time_offset(self))
+    }
+
+    fn set_time_offset(&self, time_offset: Duration) -> anyhow::Result<()> {
+        ChainClockAdapter::set_time_offset(self, time_offset.as_secs())
+    }
+}
+
+impl fuel_core_producer::ports::ChainClock for ChainClockAdapter {
+    fn now(&self) -> Tai64 {
+        Tai64(Tai64::now().0.saturating_add(self.time_offset()))
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
@@ -93,6 +176,7 @@ impl fuel_core_poa::ports::BlockProducer for BlockProducerAdapter {
         block_time: Tai64,
         max_gas: Word,
     ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
+        upgrades::ensure_supported(&self.block_producer.db, height)?;
         self.block_producer
             .produce_and_execute_block(height, block_time, max_gas)
             .await
@@ -5,6 +5,8 @@ use crate::{
         ExecutionBlockWithSource,
         Executor,
         MaybeCheckedTransaction,
+        OnceTransactionsSource,
+        TransactionsSource as _,
     },
     service::adapters::{
         ExecutorAdapter,
@@ -12,6 +14,11 @@ use crate::{
     },
 };
 use fuel_core_executor::refs::ContractStorageTrait;
+use fuel_core_producer::ports::InjectedTransactions;
+use fuel_core_services::{
+    Resource,
+    ResourcePermit,
+};
 use fuel_core_storage::{
     transactional::StorageTransaction,
     Error as StorageError,
@@ -25,47 +32,170 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            Error as ExecutorError,
+            ExecutionTypes,
             Result as ExecutorResult,
+            StateDiff,
+            TransactionProfile,
             UncommittedResult,
         },
+        txpool::ArcPoolTx,
     },
 };
+use std::time::Instant;
 
 impl crate::executor::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
-        self.txpool
-            .select_transactions(gas_limit)
+        let into_checked = |tx: ArcPoolTx| {
+            MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into())
+        };
+        let Some(deadline) = self.deadline else {
+            return self
+                .txpool
+                .select_transactions(gas_limit)
+                .into_iter()
+                .map(into_checked)
+                .collect()
+        };
+
+        if Instant::now() >= deadline {
+            return vec![]
+        }
+        // The transactions are supplied one by one, so the executor stops taking
+        // them as soon as the deadline is reached, and the block is smaller.
+        let mut selected = self.selected.lock();
+        selected
+            .get_or_insert_with(|| self.txpool.select_transactions(gas_limit).into())
+            .pop_front()
+            .map(into_checked)
             .into_iter()
-            .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
     }
 }
 
+/// Returns the injected transactions first, and then the transactions of the `TxPool`.
+struct InjectedTransactionsSource {
+    injected: OnceTransactionsSource,
+    txpool: Option<TransactionsSource>,
+}
+
+impl crate::executor::TransactionsSource for InjectedTransactionsSource {
+    fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
+        let injected = self.injected.next(gas_limit);
+        if !injected.is_empty() {
+            return injected
+        }
+        self.txpool
+            .as_ref()
+            .map(|txpool| txpool.next(gas_limit))
+            .unwrap_or_default()
+    }
+}
+
 impl ExecutorAdapter {
     pub(crate) fn _execute_without_commit(
         &self,
         block: ExecutionBlockWithSource<TransactionsSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        self._execute_without_commit_on(&self.relayer.database, block)
+    }
+
+    /// Executes the block on top of the `database`, it may be the uncommitted state.
+    pub(crate) fn _execute_without_commit_on(
+        &self,
+        database: &Database,
+        block: ExecutionBlockWithSource<TransactionsSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
         let executor = Executor {
-            database: self.relayer.database.clone(),
+            database: database.clone(),
             relayer: self.relayer.clone(),
             config: self.config.clone(),
+            execution_stats: Some(self.execution_stats.clone()),
         };
         executor.execute_without_commit(block, self.config.as_ref().into())
     }
 
+    pub(crate) fn _execute_injected_without_commit(
+        &self,
+        component: Components<InjectedTransactions<TransactionsSource>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        let InjectedTransactions {
+            transactions,
+            source,
+        } = component.transactions_source;
+        let component = Components {
+            header_to_produce: component.header_to_produce,
+            transactions_source: InjectedTransactionsSource {
+                injected: OnceTransactionsSource::new(transactions),
+                txpool: source,
+            },
+            gas_limit: component.gas_limit,
+            gas_price: component.gas_price,
+        };
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+            execution_stats: Some(self.execution_stats.clone()),
+        };
+        executor.execute_without_commit(
+            ExecutionTypes::Production(component),
+            self.config.as_ref().into(),
+        )
+    }
+
+    /// Takes the dry run from the budget shared with other subsystems, so the
+    /// concurrent dry runs are rejected instead of exhausting the node's resources.
+    fn dry_run_permit(&self) -> ExecutorResult<ResourcePermit> {
+        self.resource_budget
+            .try_acquire(Resource::DryRuns, 1)
+            .map_err(|e| ExecutorError::ResourceBudgetExhausted(e.to_string()))
+    }
+
     pub(crate) fn _dry_run(
         &self,
         block: Components<fuel_tx::Transaction>,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
+        let _permit = self.dry_run_permit()?;
         let executor = Executor {
             database: self.relayer.database.clone(),
             relayer: self.relayer.clone(),
             config: self.config.clone(),
+            execution_stats: None,
         };
         executor.dry_run(block, utxo_validation)
     }
+
+    pub(crate) fn _dry_run_profile(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)> {
+        let _permit = self.dry_run_permit()?;
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+            execution_stats: None,
+        };
+        executor.dry_run_profile(block, utxo_validation)
+    }
+
+    pub(crate) fn _dry_run_bundle(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)> {
+        let _permit = self.dry_run_permit()?;
+        let executor = Executor {
+            database: self.relayer.database.clone(),
+            relayer: self.relayer.clone(),
+            config: self.config.clone(),
+            execution_stats: None,
+        };
+        executor.dry_run_bundle(block, utxo_validation)
+    }
 }
 
 /// Implemented to satisfy: `GenesisCommitment for ContractRef<&'a mut Database>`
@@ -103,4 +233,25 @@ impl crate::executor::RelayerPort for MaybeRelayerAdapter {
             Ok(None)
         }
     }
+
+    fn is_message_cancelled(
+        &self,
+        id: &Nonce,
+        da_height: &DaBlockHeight,
+    ) -> anyhow::Result<bool> {
+        #[cfg(feature = "relayer")]
+        {
+            match self.relayer_synced.as_ref() {
+                Some(sync) => Ok(sync.get_message_cancellation(id, da_height)?.is_some()),
+                // Only the relayer cancels the messages.
+                None => Ok(false),
+            }
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = id;
+            let _ = da_height;
+            Ok(false)
+        }
+    }
 }
@@ -0,0 +1,106 @@
+use crate::{
+    database::Database,
+    fuel_core_graphql_api::ports::GasPriceEstimate,
+    service::adapters::{
+        BlobBaseFeeAdapter,
+        BlockImporterAdapter,
+        GasPriceAdapter,
+    },
+};
+use fuel_core_gas_price_service::{
+    ports::{
+        BlobBaseFeeSource,
+        BlockImporter,
+        GasPriceDb,
+    },
+    AlgorithmState,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    tables::Receipts,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::{
+    borrow::Cow,
+    sync::Arc,
+};
+
+impl BlockImporter for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok()),
+        )
+    }
+}
+
+impl GasPriceDb for Database {
+    fn receipts(&self, tx_id: &TxId) -> StorageResult<Option<Vec<Receipt>>> {
+        Ok(self.storage::<Receipts>().get(tx_id)?.map(Cow::into_owned))
+    }
+
+    fn algorithm_state(&self) -> StorageResult<Option<AlgorithmState>> {
+        self.get_gas_price_algorithm_state().map_err(Into::into)
+    }
+
+    fn store_algorithm_state(&self, state: &AlgorithmState) -> StorageResult<()> {
+        self.set_gas_price_algorithm_state(state)
+            .map_err(Into::into)
+    }
+}
+
+impl fuel_core_txpool::ports::GasPriceProvider for GasPriceAdapter {
+    fn gas_price(&self) -> u64 {
+        self.shared_state.latest_gas_price()
+    }
+}
+
+impl fuel_core_producer::ports::GasPriceProvider for GasPriceAdapter {
+    fn gas_price(&self) -> u64 {
+        self.shared_state.latest_gas_price()
+    }
+}
+
+impl GasPriceEstimate for GasPriceAdapter {
+    fn worst_case_gas_price(&self, height: BlockHeight) -> u64 {
+        self.shared_state.worst_case_gas_price(height)
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobBaseFeeSource for BlobBaseFeeAdapter {
+    async fn blob_base_fee(&self) -> anyhow::Result<u128> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_relayer::ports::RelayerDb;
+            match self.source.blob_base_fee().await {
+                Ok(blob_base_fee) => Ok(blob_base_fee),
+                Err(err) => {
+                    let recorded = self
+                        .database
+                        .get_da_fee_samples()?
+                        .last()
+                        .and_then(|sample| sample.blob_base_fee);
+                    recorded.map(u128::from).ok_or(err)
+                }
+            }
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Err(anyhow::anyhow!("The blob base fee requires the relayer"))
+        }
+    }
+}
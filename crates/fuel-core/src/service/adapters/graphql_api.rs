@@ -1,10 +1,17 @@
 use crate::{
+    chain_config::{
+        ContractConfig,
+        UpgradeDeclaration,
+    },
     database::{
         transactions::OwnedTransactionIndexCursor,
         Database,
     },
     fuel_core_graphql_api::ports::{
+        AccountWatcherPort,
+        BlockImporterPort,
         BlockProducerPort,
+        DatabaseAssets,
         DatabaseBlocks,
         DatabaseChain,
         DatabaseCoins,
@@ -13,10 +20,24 @@ use crate::{
         DatabaseMessages,
         DatabasePort,
         DatabaseTransactions,
+        DatabaseUpgrades,
         DryRunExecution,
+        StorageStatsPort,
+        SubscriptionBufferPort,
+        SyncStatusPort,
         TxPoolPort,
     },
-    service::adapters::TxPoolAdapter,
+    service::{
+        account_watcher,
+        adapters::{
+            BlockImporterAdapter,
+            SyncStatusAdapter,
+            TxPoolAdapter,
+        },
+        storage_stats,
+        subscription_buffer,
+        upgrades,
+    },
 };
 use async_trait::async_trait;
 use fuel_core_services::stream::{
@@ -45,9 +66,12 @@ use fuel_core_types::{
         BlockId,
         DaBlockHeight,
     },
-    entities::message::{
-        MerkleProof,
-        Message,
+    entities::{
+        asset::AssetMetadata,
+        message::{
+            MerkleProof,
+            Message,
+        },
     },
     fuel_tx::{
         Address,
@@ -59,12 +83,30 @@ use fuel_core_types::{
     },
     fuel_types::{
         BlockHeight,
+        Bytes32,
         Nonce,
     },
     services::{
-        graphql_api::ContractBalance,
+        block_producer::DryRunOverrides,
+        executor::{
+            StateDiff,
+            TransactionProfile,
+        },
+        graphql_api::{
+            AccountEvent,
+            BlockFees,
+            BlockStateChanges,
+            ContractBalance,
+            ContractCalls,
+            SubscriptionCursor,
+            SyncStatus,
+            TableStats,
+        },
+        relayer::DaFeeSample,
         txpool::{
+            ArchivedTransactionStatus,
             InsertionResult,
+            PendingCoins,
             TransactionStatus,
         },
     },
@@ -97,6 +139,17 @@ impl DatabaseBlocks for Database {
             .transpose()
             .ok_or(not_found!("BlockId"))??)
     }
+
+    fn state_changes(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<BlockStateChanges>> {
+        Ok(Database::state_changes(self, height)?)
+    }
+
+    fn fee_history(&self, last: usize) -> StorageResult<Vec<BlockFees>> {
+        Ok(Database::fee_history(self, last)?)
+    }
 }
 
 impl DatabaseTransactions for Database {
@@ -107,6 +160,13 @@ impl DatabaseTransactions for Database {
             .ok_or(not_found!("TransactionId"))??)
     }
 
+    fn tx_status_history(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Vec<ArchivedTransactionStatus>> {
+        Ok(self.get_tx_status_history(tx_id)?.unwrap_or_default())
+    }
+
     fn owned_transactions_ids(
         &self,
         owner: Address,
@@ -152,6 +212,23 @@ impl DatabaseMessages for Database {
     fn message_exists(&self, nonce: &Nonce) -> StorageResult<bool> {
         self.message_exists(nonce)
     }
+
+    fn da_transaction_message_ids(
+        &self,
+        da_transaction: &Bytes32,
+    ) -> BoxedIter<'_, StorageResult<Nonce>> {
+        self.da_transaction_message_ids(da_transaction)
+            .map(|result| result.map_err(StorageError::from))
+            .into_boxed()
+    }
+
+    fn message_spender(&self, nonce: &Nonce) -> StorageResult<Option<TxId>> {
+        Ok(self.get_message_spender(nonce)?)
+    }
+
+    fn owned_messages_balance(&self, owner: &Address) -> StorageResult<u64> {
+        Ok(self.owned_messages_balance(owner)?)
+    }
 }
 
 impl DatabaseCoins for Database {
@@ -165,6 +242,24 @@ impl DatabaseCoins for Database {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn owned_coins_balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+    ) -> StorageResult<u64> {
+        Ok(self.owned_coins_balance(owner, asset_id)?)
+    }
+
+    fn owned_coins_balances(
+        &self,
+        owner: &Address,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<(AssetId, u64)>> {
+        self.owned_coins_balances(owner, Some(direction))
+            .map(|res| res.map_err(StorageError::from))
+            .into_boxed()
+    }
 }
 
 impl DatabaseContracts for Database {
@@ -186,6 +281,40 @@ impl DatabaseContracts for Database {
             })
             .into_boxed()
     }
+
+    fn contract_slot_values(
+        &self,
+        contract: &ContractId,
+        slots: &[Bytes32],
+        height: Option<BlockHeight>,
+    ) -> StorageResult<Option<Vec<Option<Bytes32>>>> {
+        let height = match height {
+            Some(height) => height,
+            None => self.latest_height()?,
+        };
+        self.contract_state_at(contract, slots, height)
+    }
+
+    fn contract_config_at(
+        &self,
+        contract: &ContractId,
+        height: Option<BlockHeight>,
+        max_entries: usize,
+    ) -> StorageResult<Option<ContractConfig>> {
+        let height = match height {
+            Some(height) => height,
+            None => self.latest_height()?,
+        };
+        Database::contract_config_at(self, contract, height, Some(max_entries))
+    }
+
+    fn top_contracts(
+        &self,
+        from: BlockHeight,
+        limit: usize,
+    ) -> StorageResult<Vec<ContractCalls>> {
+        Ok(Database::top_contracts(self, from, limit)?)
+    }
 }
 
 impl DatabaseChain for Database {
@@ -208,6 +337,29 @@ impl DatabaseChain for Database {
             Ok(0u64.into())
         }
     }
+    fn da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_relayer::ports::RelayerDb;
+            self.get_da_fee_samples()
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(vec![])
+        }
+    }
+}
+
+impl DatabaseAssets for Database {
+    fn asset_metadata(&self, asset_id: &AssetId) -> StorageResult<Option<AssetMetadata>> {
+        Ok(Database::asset_metadata(self, asset_id)?)
+    }
+}
+
+impl DatabaseUpgrades for Database {
+    fn pending_upgrades(&self) -> StorageResult<Vec<UpgradeDeclaration>> {
+        Ok(upgrades::pending_upgrades(self)?)
+    }
 }
 
 impl DatabasePort for Database {}
@@ -233,9 +385,153 @@ impl TxPoolPort for TxPoolAdapter {
         self.service.insert(txs).await
     }
 
+    async fn insert_private(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service.insert_private(txs).await
+    }
+
     fn tx_update_subscribe(&self, id: TxId) -> BoxFuture<BoxStream<TxStatusMessage>> {
         Box::pin(self.service.tx_update_subscribe(id))
     }
+
+    fn pending_coins(&self, owner: &Address) -> PendingCoins {
+        self.service.pending_coins(owner)
+    }
+}
+
+#[cfg(feature = "event-bus")]
+#[async_trait]
+impl TxPoolPort for crate::service::adapters::EventBusTxPoolAdapter {
+    fn transaction(&self, id: TxId) -> Option<Transaction> {
+        self.txpool.transaction(id)
+    }
+
+    fn submission_time(&self, id: TxId) -> Option<Tai64> {
+        self.txpool.submission_time(id)
+    }
+
+    async fn insert(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.txpool.insert(txs).await
+    }
+
+    async fn insert_private(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.txpool.insert_private(txs).await
+    }
+
+    fn tx_update_subscribe(&self, id: TxId) -> BoxFuture<BoxStream<TxStatusMessage>> {
+        Box::pin(futures::future::ready(self.events.tx_update_subscribe(id)))
+    }
+
+    fn pending_coins(&self, owner: &Address) -> PendingCoins {
+        self.txpool.pending_coins(owner)
+    }
+}
+
+#[cfg(feature = "event-bus")]
+impl BlockImporterPort for crate::service::event_bus::SharedState {
+    fn block_events(&self) -> BoxStream<BlockHeight> {
+        crate::service::event_bus::SharedState::block_events(self)
+    }
+}
+
+impl BlockImporterPort for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<BlockHeight> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe()).filter_map(|result| {
+                result
+                    .ok()
+                    .map(|result| *result.sealed_block.entity.header().height())
+            }),
+        )
+    }
+}
+
+impl StorageStatsPort for storage_stats::SharedState {
+    fn table_stats(&self) -> Vec<TableStats> {
+        storage_stats::SharedState::table_stats(self)
+    }
+}
+
+impl AccountWatcherPort for account_watcher::SharedState {
+    fn subscribe(
+        &self,
+        addresses: Vec<Address>,
+    ) -> anyhow::Result<BoxStream<AccountEvent>> {
+        Ok(Box::pin(account_watcher::SharedState::subscribe(
+            self, addresses,
+        )?))
+    }
+}
+
+impl SubscriptionBufferPort for subscription_buffer::SharedState {
+    fn latest_cursor(&self) -> SubscriptionCursor {
+        subscription_buffer::SharedState::latest_cursor(self)
+    }
+
+    fn events_after(
+        &self,
+        cursor: SubscriptionCursor,
+    ) -> anyhow::Result<subscription_buffer::Events> {
+        subscription_buffer::SharedState::events_after(self, cursor)
+    }
+}
+
+impl SyncStatusPort for SyncStatusAdapter {
+    fn sync_status(&self) -> anyhow::Result<SyncStatus> {
+        let current_height = self.database.latest_height()?;
+        #[cfg(feature = "p2p")]
+        let (network_height, time_to_tip) =
+            self.sync.as_ref().map_or((None, None), |sync| {
+                (sync.highest_height(), sync.time_to_tip())
+            });
+        #[cfg(not(feature = "p2p"))]
+        let (network_height, time_to_tip) = (None, None);
+        #[cfg(feature = "relayer")]
+        let da_lag = self
+            .relayer
+            .as_ref()
+            .map(|relayer| relayer.da_lag())
+            .transpose()?
+            .flatten();
+        #[cfg(feature = "relayer")]
+        let da_error = self
+            .relayer
+            .as_ref()
+            .and_then(|relayer| relayer.last_error());
+        #[cfg(feature = "relayer")]
+        let committer_balance_low = self
+            .block_committer
+            .as_ref()
+            .and_then(|committer| committer.wallet_balance())
+            .map(|balance| balance.low);
+        #[cfg(not(feature = "relayer"))]
+        let da_lag = None;
+        #[cfg(not(feature = "relayer"))]
+        let da_error = None;
+        #[cfg(not(feature = "relayer"))]
+        let committer_balance_low = None;
+
+        Ok(SyncStatus {
+            current_height,
+            network_height,
+            time_to_tip,
+            da_lag,
+            da_error,
+            committer_balance_low,
+        })
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -253,11 +549,33 @@ impl DryRunExecution for BlockProducerAdapter {
     async fn dry_run_tx(
         &self,
         transaction: Transaction,
-        height: Option<BlockHeight>,
+        overrides: DryRunOverrides,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<TxReceipt>> {
         self.block_producer
-            .dry_run(transaction, height, utxo_validation)
+            .dry_run(transaction, overrides, utxo_validation)
+            .await
+    }
+
+    async fn dry_run_profile_tx(
+        &self,
+        transaction: Transaction,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<TxReceipt>, TransactionProfile)> {
+        self.block_producer
+            .dry_run_profile(transaction, overrides, utxo_validation)
+            .await
+    }
+
+    async fn dry_run_bundle_txs(
+        &self,
+        transactions: Vec<Transaction>,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<Vec<TxReceipt>>, StateDiff)> {
+        self.block_producer
+            .dry_run_bundle(transactions, overrides, utxo_validation)
             .await
     }
 }
@@ -11,7 +11,10 @@ use crate::{
         sub_services::BlockProducerService,
     },
 };
-use fuel_core_producer::ports::TxPool;
+use fuel_core_producer::ports::{
+    InjectedTransactions,
+    TxPool,
+};
 use fuel_core_storage::{
     not_found,
     tables::FuelBlocks,
@@ -35,6 +38,8 @@ use fuel_core_types::{
         executor::{
             ExecutionTypes,
             Result as ExecutorResult,
+            StateDiff,
+            TransactionProfile,
             UncommittedResult,
         },
     },
@@ -42,6 +47,7 @@ use fuel_core_types::{
 use std::{
     borrow::Cow,
     sync::Arc,
+    time::Instant,
 };
 
 impl BlockProducerAdapter {
@@ -56,8 +62,12 @@ impl BlockProducerAdapter {
 impl TxPool for TxPoolAdapter {
     type TxSource = TransactionsSource;
 
-    fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
-        TransactionsSource::new(self.service.clone(), block_height)
+    fn get_source(
+        &self,
+        block_height: BlockHeight,
+        deadline: Option<Instant>,
+    ) -> Self::TxSource {
+        TransactionsSource::new(self.service.clone(), block_height, deadline)
     }
 }
 
@@ -73,6 +83,13 @@ impl fuel_core_producer::ports::Executor for ExecutorAdapter {
         self._execute_without_commit(ExecutionTypes::Production(component))
     }
 
+    fn execute_injected_without_commit(
+        &self,
+        component: Components<InjectedTransactions<Self::TxSource>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Database>>> {
+        self._execute_injected_without_commit(component)
+    }
+
     fn dry_run(
         &self,
         block: Components<fuel_tx::Transaction>,
@@ -80,6 +97,22 @@ impl fuel_core_producer::ports::Executor for ExecutorAdapter {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         self._dry_run(block, utxo_validation)
     }
+
+    fn dry_run_profile(
+        &self,
+        block: Components<fuel_tx::Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)> {
+        self._dry_run_profile(block, utxo_validation)
+    }
+
+    fn dry_run_bundle(
+        &self,
+        block: Components<Vec<fuel_tx::Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)> {
+        self._dry_run_bundle(block, utxo_validation)
+    }
 }
 
 #[async_trait::async_trait]
@@ -0,0 +1,89 @@
+use super::BlockImporterAdapter;
+use crate::fuel_core_graphql_api::ports::BlockCommitterPort;
+use fuel_core_relayer::{
+    committer::{
+        CommitmentPoster,
+        CommitmentReader,
+        SharedState as BlockCommitterSharedState,
+    },
+    ports::{
+        ImportedBlocks,
+        ImportedDaHeights,
+    },
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::primitives::{
+        BlockId,
+        DaBlockHeight,
+    },
+    entities::message::CommitmentState,
+    fuel_types::BlockHeight,
+};
+
+impl ImportedBlocks for BlockImporterAdapter {
+    fn imported_blocks(&self) -> BoxStream<(BlockHeight, BlockId)> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| {
+                    let block = &result.sealed_block.entity;
+                    (*block.header().height(), block.id())
+                }),
+        )
+    }
+}
+
+impl ImportedDaHeights for BlockImporterAdapter {
+    fn imported_da_heights(&self) -> BoxStream<(BlockHeight, DaBlockHeight)> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        Box::pin(
+            BroadcastStream::new(self.block_importer.subscribe())
+                .filter_map(|result| result.ok())
+                .map(|result| {
+                    let header = result.sealed_block.entity.header();
+                    (*header.height(), header.da_height)
+                }),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> BlockCommitterPort for BlockCommitterSharedState<C>
+where
+    C: CommitmentPoster,
+{
+    fn commit_height(&self, height: BlockHeight) -> BlockHeight {
+        BlockCommitterSharedState::commit_height(self, height)
+    }
+
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        BlockCommitterSharedState::commitment_state(self, height, block_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockCommitterPort for CommitmentReader {
+    fn commit_height(&self, height: BlockHeight) -> BlockHeight {
+        CommitmentReader::commit_height(self, height)
+    }
+
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        CommitmentReader::commitment_state(self, height, block_id).await
+    }
+}
@@ -5,6 +5,7 @@ use super::{
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_sync::ports::{
+    BatchImportResult,
     BlockImporterPort,
     ConsensusPort,
     PeerReportReason,
@@ -137,8 +138,20 @@ impl BlockImporterPort for BlockImporterAdapter {
                 }),
         )
     }
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
-        self.execute_and_commit(block).await
+    async fn execute_and_commit_batch(
+        &self,
+        blocks: Vec<SealedBlock>,
+    ) -> BatchImportResult {
+        match self.execute_and_commit_batch(blocks).await {
+            Ok(result) => BatchImportResult {
+                committed: result.committed,
+                error: result.error.map(Into::into),
+            },
+            Err(error) => BatchImportResult {
+                committed: 0,
+                error: Some(error),
+            },
+        }
     }
 }
 
@@ -11,6 +11,7 @@ use fuel_core_storage::{
     tables::{
         Coins,
         ContractsRawCode,
+        MessageCancellations,
         Messages,
         SpentMessages,
     },
@@ -25,6 +26,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -37,7 +39,10 @@ use fuel_core_types::{
         p2p::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
+            SyncProgress,
             TransactionGossipData,
+            TxRejection,
         },
     },
 };
@@ -57,10 +62,20 @@ impl BlockImporter for BlockImporterAdapter {
 }
 
 #[cfg(feature = "p2p")]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
     fn broadcast_transaction(&self, transaction: Arc<Transaction>) -> anyhow::Result<()> {
+        #[cfg(feature = "fault-injection")]
+        if let (Some(service), Some(delay)) = (&self.service, self.gossip_delay) {
+            let service = service.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                let _ = service.broadcast_transaction(transaction);
+            });
+            return Ok(())
+        }
         if let Some(service) = &self.service {
             service.broadcast_transaction(transaction)
         } else {
@@ -94,9 +109,88 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
             Ok(())
         }
     }
+
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_new_peers())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    fn notify_tx_rejection(
+        &self,
+        peer_id: PeerId,
+        rejection: TxRejection,
+    ) -> anyhow::Result<()> {
+        if let Some(service) = &self.service {
+            service.notify_tx_rejection(peer_id.into(), rejection)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn tx_rejection_events(&self) -> BoxStream<(PeerId, TxRejection)> {
+        use tokio_stream::{
+            wrappers::BroadcastStream,
+            StreamExt,
+        };
+        if let Some(service) = &self.service {
+            Box::pin(
+                BroadcastStream::new(service.subscribe_tx_rejections())
+                    .filter_map(|result| result.ok()),
+            )
+        } else {
+            fuel_core_services::stream::IntoBoxStream::into_boxed(tokio_stream::pending())
+        }
+    }
+
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>> {
+        if let Some(service) = &self.service {
+            service
+                .get_pooled_tx_ids_from_peer(peer_id.into())
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("The peer didn't return the pooled tx ids")
+                })
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        if let Some(service) = &self.service {
+            service
+                .get_pooled_transactions_from_peer(peer_id.into(), tx_ids)
+                .await?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("The peer didn't return the pooled transactions")
+                })
+        } else {
+            Err(anyhow::anyhow!("No P2P service available"))
+        }
+    }
+
+    fn sync_progress(&self) -> Option<SyncProgress> {
+        self.service
+            .as_ref()
+            .and_then(|service| service.sync_progress())
+    }
 }
 
 #[cfg(not(feature = "p2p"))]
+#[async_trait::async_trait]
 impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     type GossipedTransaction = TransactionGossipData;
 
@@ -118,6 +212,38 @@ impl fuel_core_txpool::ports::PeerToPeer for P2PAdapter {
     ) -> anyhow::Result<()> {
         Ok(())
     }
+
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
+
+    fn notify_tx_rejection(
+        &self,
+        _peer_id: PeerId,
+        _rejection: TxRejection,
+    ) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn tx_rejection_events(&self) -> BoxStream<(PeerId, TxRejection)> {
+        Box::pin(fuel_core_services::stream::pending())
+    }
+
+    async fn request_tx_ids(&self, _peer_id: PeerId) -> anyhow::Result<Vec<TxId>> {
+        Err(anyhow::anyhow!("No P2P service available"))
+    }
+
+    async fn request_txs(
+        &self,
+        _peer_id: PeerId,
+        _tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>> {
+        Err(anyhow::anyhow!("No P2P service available"))
+    }
+
+    fn sync_progress(&self) -> Option<SyncProgress> {
+        None
+    }
 }
 
 impl fuel_core_txpool::ports::TxPoolDb for Database {
@@ -141,6 +267,10 @@ impl fuel_core_txpool::ports::TxPoolDb for Database {
         self.storage::<SpentMessages>().contains_key(id)
     }
 
+    fn is_message_cancelled(&self, id: &Nonce) -> StorageResult<bool> {
+        self.storage::<MessageCancellations>().contains_key(id)
+    }
+
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
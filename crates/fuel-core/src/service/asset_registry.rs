@@ -0,0 +1,401 @@
+//! Records the metadata of the assets issued by the contracts. The registry follows the
+//! imported blocks and indexes the mint and burn receipts of their successful transactions,
+//! so the wallets can query the assets without re-indexing the chain.
+//!
+//! The registry indexes the blocks starting from the genesis, so it catches up with the
+//! chain when it is enabled on the node with the existing database.
+
+use crate::{
+    database::Database,
+    service::disk_monitor,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    tables::{
+        FuelBlocks,
+        Receipts,
+    },
+    transactional::Transactional,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    entities::asset::AssetMetadata,
+    fuel_crypto::Hasher,
+    fuel_tx::Receipt,
+    fuel_types::{
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+    },
+    services::{
+        block_importer::ImportResult,
+        txpool::TransactionStatus,
+    },
+};
+use std::sync::Arc;
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// The max number of blocks indexed in one iteration while the registry catches up.
+const BLOCKS_PER_ITERATION: u32 = 100;
+
+pub type Service = ServiceRunner<NotInitializedTask>;
+
+pub struct NotInitializedTask {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+pub struct Task {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    /// The registry doesn't write while the node is low on the disk space.
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for NotInitializedTask {
+    const NAME: &'static str = "AssetRegistry";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            database,
+            blocks,
+            disk_monitor,
+        } = self;
+        Ok(Task {
+            database,
+            blocks,
+            disk_monitor,
+        })
+    }
+}
+
+impl Task {
+    /// Indexes the next blocks after the last indexed one.
+    /// Returns `true` if the registry caught up with the chain.
+    fn index_next_blocks(&self) -> anyhow::Result<bool> {
+        let latest: u32 = self.database.latest_height()?.into();
+        let next: u32 = match self.database.asset_registry_height()? {
+            Some(height) => u32::from(height).saturating_add(1),
+            None => self.database.ids_of_genesis_block()?.0.into(),
+        };
+        if next > latest {
+            return Ok(true)
+        }
+
+        let last = latest.min(next.saturating_add(BLOCKS_PER_ITERATION - 1));
+        for height in next..=last {
+            self.index_block(height.into())?;
+        }
+        Ok(last == latest)
+    }
+
+    /// Records the issuances of the block at the `height` and moves
+    /// the height of the registry in one database transaction.
+    fn index_block(&self, height: BlockHeight) -> anyhow::Result<()> {
+        let mut transaction = Transactional::transaction(&self.database);
+        let database = transaction.as_mut();
+
+        let block_id = database
+            .get_block_id(&height)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))?;
+        let block = database
+            .storage::<FuelBlocks>()
+            .get(&block_id)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))?
+            .into_owned();
+
+        for tx_id in block.transactions() {
+            // The reverted transactions don't change the supply of the assets.
+            if !matches!(
+                database.get_tx_status(tx_id)?,
+                Some(TransactionStatus::Success { .. })
+            ) {
+                continue
+            }
+            let Some(receipts) = database.storage::<Receipts>().get(tx_id)? else {
+                continue
+            };
+            for receipt in receipts.iter() {
+                record_issuance(database, receipt, height)?;
+            }
+        }
+
+        database.set_asset_registry_height(height)?;
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+/// Updates the metadata of the asset minted or burned by the `receipt`.
+fn record_issuance(
+    database: &Database,
+    receipt: &Receipt,
+    height: BlockHeight,
+) -> anyhow::Result<()> {
+    let (contract_id, sub_id, minted, burned) = match receipt {
+        Receipt::Mint {
+            contract_id,
+            sub_id,
+            val,
+            ..
+        } => (contract_id, sub_id, *val, 0),
+        Receipt::Burn {
+            contract_id,
+            sub_id,
+            val,
+            ..
+        } => (contract_id, sub_id, 0, *val),
+        _ => return Ok(()),
+    };
+
+    let asset_id = asset_id(contract_id, sub_id);
+    let mut metadata =
+        database
+            .asset_metadata(&asset_id)?
+            .unwrap_or_else(|| AssetMetadata {
+                asset_id,
+                contract_id: *contract_id,
+                sub_id: *sub_id,
+                total_supply: 0,
+                first_issued_at: height,
+            });
+    metadata.total_supply = metadata
+        .total_supply
+        .saturating_add(minted)
+        .saturating_sub(burned);
+    database.insert_asset_metadata(&metadata)?;
+    Ok(())
+}
+
+/// Calculates the id of the asset of the contract with the `sub_id`.
+fn asset_id(contract_id: &ContractId, sub_id: &Bytes32) -> AssetId {
+    let id = *Hasher::default()
+        .chain(contract_id.as_ref())
+        .chain(sub_id.as_ref())
+        .finalize();
+    id.into()
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        // The blocks imported in the meantime are indexed after the recovery.
+        if let Some(disk_monitor) = self.disk_monitor.as_ref() {
+            if disk_monitor.is_degraded() {
+                tokio::select! {
+                    biased;
+
+                    _ = watcher.while_started() => return Ok(false),
+                    _ = disk_monitor.recovered() => return Ok(true),
+                }
+            }
+        }
+
+        match self.index_next_blocks() {
+            // Continue to catch up without waiting for the next block.
+            Ok(false) => return Ok(true),
+            Ok(true) => {}
+            // Retry after the next block instead of failing in the loop.
+            Err(e) => tracing::error!("The asset registry failed to index blocks: {e}"),
+        }
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            block = self.blocks.recv() => match block {
+                // The skipped blocks are indexed from the database anyway.
+                Ok(_) | Err(RecvError::Lagged(_)) => Ok(true),
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    disk_monitor: Option<disk_monitor::SharedState>,
+) -> Service {
+    ServiceRunner::new(NotInitializedTask {
+        database,
+        blocks,
+        disk_monitor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        blockchain::{
+            block::CompressedBlock,
+            header::BlockHeader,
+        },
+        fuel_tx::TxId,
+        tai64::Tai64,
+    };
+
+    fn insert_block(database: &mut Database, height: u32, txs: Vec<(TxId, bool)>) {
+        let block = CompressedBlock::test(
+            BlockHeader::new_block(height.into(), Tai64::UNIX_EPOCH),
+            txs.iter().map(|(id, _)| *id).collect(),
+        );
+        let block_id = block.id();
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block_id, &block)
+            .unwrap();
+        for (tx_id, success) in txs {
+            let status = if success {
+                TransactionStatus::Success {
+                    block_id,
+                    time: Tai64::UNIX_EPOCH,
+                    result: None,
+                }
+            } else {
+                TransactionStatus::Failed {
+                    block_id,
+                    time: Tai64::UNIX_EPOCH,
+                    reason: "Reverted".to_string(),
+                    result: None,
+                }
+            };
+            database.update_tx_status(&tx_id, status).unwrap();
+        }
+    }
+
+    fn insert_receipts(database: &mut Database, tx_id: TxId, receipts: Vec<Receipt>) {
+        database
+            .storage::<Receipts>()
+            .insert(&tx_id, &receipts)
+            .unwrap();
+    }
+
+    fn mint(contract_id: ContractId, sub_id: Bytes32, val: u64) -> Receipt {
+        Receipt::Mint {
+            sub_id,
+            contract_id,
+            val,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    fn burn(contract_id: ContractId, sub_id: Bytes32, val: u64) -> Receipt {
+        Receipt::Burn {
+            sub_id,
+            contract_id,
+            val,
+            pc: 0,
+            is: 0,
+        }
+    }
+
+    #[test]
+    fn indexes_issuances_of_successful_transactions() {
+        let mut database = Database::default();
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::from([2; 32]);
+        let asset_id = asset_id(&contract_id, &sub_id);
+
+        insert_block(&mut database, 0, vec![]);
+        insert_block(&mut database, 1, vec![([1; 32].into(), true)]);
+        insert_receipts(
+            &mut database,
+            [1; 32].into(),
+            vec![mint(contract_id, sub_id, 100)],
+        );
+        insert_block(
+            &mut database,
+            2,
+            vec![([2; 32].into(), true), ([3; 32].into(), false)],
+        );
+        insert_receipts(
+            &mut database,
+            [2; 32].into(),
+            vec![burn(contract_id, sub_id, 30)],
+        );
+        insert_receipts(
+            &mut database,
+            [3; 32].into(),
+            vec![mint(contract_id, sub_id, 1000)],
+        );
+
+        let (_, blocks) = broadcast::channel(1);
+        let task = Task {
+            database,
+            blocks,
+            disk_monitor: None,
+        };
+
+        assert!(task.index_next_blocks().unwrap());
+
+        assert_eq!(
+            task.database.asset_metadata(&asset_id).unwrap(),
+            Some(AssetMetadata {
+                asset_id,
+                contract_id,
+                sub_id,
+                total_supply: 70,
+                first_issued_at: 1u32.into(),
+            })
+        );
+        assert_eq!(
+            task.database.asset_registry_height().unwrap(),
+            Some(2u32.into())
+        );
+    }
+
+    #[test]
+    fn catches_up_in_batches() {
+        let mut database = Database::default();
+        for height in 0..=BLOCKS_PER_ITERATION {
+            insert_block(&mut database, height, vec![]);
+        }
+
+        let (_, blocks) = broadcast::channel(1);
+        let task = Task {
+            database,
+            blocks,
+            disk_monitor: None,
+        };
+
+        assert!(!task.index_next_blocks().unwrap());
+        assert_eq!(
+            task.database.asset_registry_height().unwrap(),
+            Some((BLOCKS_PER_ITERATION - 1).into())
+        );
+        assert!(task.index_next_blocks().unwrap());
+        assert_eq!(
+            task.database.asset_registry_height().unwrap(),
+            Some(BLOCKS_PER_ITERATION.into())
+        );
+    }
+}
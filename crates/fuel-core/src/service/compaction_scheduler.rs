@@ -0,0 +1,450 @@
+//! Compacts the selected database tables during the configured low-traffic window of
+//! the day. The background compaction of RocksDB starts when the accumulated changes
+//! exceed its thresholds, which often happens at the peak time and slows down the block
+//! import. Compacting the tables in advance keeps the compaction debt low.
+//!
+//! The compaction may also be triggered manually via the admin API. The estimated
+//! compaction debt of the selected tables is reported by the metrics.
+
+use crate::database::{
+    Column,
+    Database,
+};
+use fuel_core_metrics::storage_metrics::storage_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use parking_lot::RwLock;
+use std::{
+    str::FromStr,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+use tokio::{
+    sync::{
+        oneshot,
+        Mutex,
+    },
+    time::{
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+const SECONDS_PER_DAY: u32 = 24 * 60 * 60;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The tables compacted by the scheduler and by the manual compaction by default.
+    pub columns: Vec<Column>,
+    /// The time of the day when the tables are compacted.
+    /// `None` allows only the manual compaction.
+    pub window: Option<CompactionWindow>,
+    /// How often the compaction debt is refreshed and the window is checked.
+    /// It must be positive.
+    pub interval: Duration,
+}
+
+/// The time range of the day in UTC. The range wraps around midnight
+/// if its end is before its start.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompactionWindow {
+    /// The start of the window in seconds since midnight.
+    start: u32,
+    /// The end of the window in seconds since midnight, exclusive.
+    end: u32,
+}
+
+impl CompactionWindow {
+    /// Returns `true` if the window contains the `seconds` since midnight.
+    pub fn contains(&self, seconds: u32) -> bool {
+        if self.start <= self.end {
+            self.start <= seconds && seconds < self.end
+        } else {
+            seconds >= self.start || seconds < self.end
+        }
+    }
+}
+
+/// Parses the window in the `HH:MM-HH:MM` format, like `02:00-04:30`.
+impl FromStr for CompactionWindow {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_time = |time: &str| -> anyhow::Result<u32> {
+            let (hours, minutes) = time
+                .trim()
+                .split_once(':')
+                .ok_or_else(|| anyhow::anyhow!("The time `{time}` is not `HH:MM`"))?;
+            let hours: u32 = hours.parse()?;
+            let minutes: u32 = minutes.parse()?;
+            anyhow::ensure!(
+                hours < 24 && minutes < 60,
+                "The time `{time}` is out of the day"
+            );
+            Ok(hours * 60 * 60 + minutes * 60)
+        };
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| anyhow::anyhow!("The window `{s}` is not `HH:MM-HH:MM`"))?;
+        let window = Self {
+            start: parse_time(start)?,
+            end: parse_time(end)?,
+        };
+        anyhow::ensure!(window.start != window.end, "The window `{s}` is empty");
+        Ok(window)
+    }
+}
+
+/// The outcome of the latest finished compaction.
+#[derive(Clone, Debug)]
+pub struct CompactionReport {
+    /// The compacted tables.
+    pub columns: Vec<Column>,
+    /// The end of the compaction.
+    pub finished_at: SystemTime,
+    pub duration: Duration,
+    /// The error that interrupted the compaction.
+    pub error: Option<String>,
+}
+
+pub type Service = ServiceRunner<Task>;
+
+#[derive(Clone)]
+pub struct SharedState {
+    database: Database,
+    columns: Arc<Vec<Column>>,
+    /// Held while the compaction is running, so the compactions never overlap.
+    compacting: Arc<Mutex<()>>,
+    last_compaction: Arc<RwLock<Option<CompactionReport>>>,
+}
+
+impl SharedState {
+    /// Returns the tables compacted by default.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns `true` if the compaction is running.
+    pub fn is_compacting(&self) -> bool {
+        self.compacting.try_lock().is_err()
+    }
+
+    /// Returns the outcome of the latest finished compaction.
+    pub fn last_compaction(&self) -> Option<CompactionReport> {
+        self.last_compaction.read().clone()
+    }
+
+    /// Returns the estimated compaction debt of the `columns` in bytes
+    /// and updates the metrics.
+    pub fn compaction_debt(
+        &self,
+        columns: &[Column],
+    ) -> anyhow::Result<Vec<(Column, u64)>> {
+        columns
+            .iter()
+            .map(|column| {
+                let debt = self.database.compaction_debt(*column)?;
+                storage_metrics().set_compaction_debt(column.name(), debt);
+                Ok((*column, debt))
+            })
+            .collect()
+    }
+
+    /// Starts the compaction of the `columns` in the background. Returns `false`
+    /// without starting it if another compaction is running.
+    pub fn start_compaction(&self, columns: Vec<Column>) -> bool {
+        self.spawn_compaction(columns).is_some()
+    }
+
+    /// Starts the compaction of the `columns` in the background like
+    /// [`Self::start_compaction`]. The returned receiver gets `true`
+    /// if the compaction succeeds.
+    fn spawn_compaction(&self, columns: Vec<Column>) -> Option<oneshot::Receiver<bool>> {
+        let guard = self.compacting.clone().try_lock_owned().ok()?;
+        let shared = self.clone();
+        let (sender, receiver) = oneshot::channel();
+        tokio::task::spawn_blocking(move || {
+            let _guard = guard;
+            let _ = sender.send(shared.compact(columns));
+        });
+        Some(receiver)
+    }
+
+    /// Compacts the `columns` and returns `true` on success.
+    fn compact(&self, columns: Vec<Column>) -> bool {
+        tracing::info!("Compacting the tables {columns:?}");
+        let started_at = Instant::now();
+        let mut error = None;
+        for column in columns.iter() {
+            if let Err(e) = self.database.compact_column(*column) {
+                tracing::error!("Failed to compact the table {column:?}: {e}");
+                error = Some(e.to_string());
+                break
+            }
+            storage_metrics().record_compaction(column.name());
+        }
+        let duration = started_at.elapsed();
+        tracing::info!("Finished the compaction of the tables in {duration:?}");
+        let _ = self.compaction_debt(&columns);
+        let succeeded = error.is_none();
+        *self.last_compaction.write() = Some(CompactionReport {
+            columns,
+            finished_at: SystemTime::now(),
+            duration,
+            error,
+        });
+        succeeded
+    }
+}
+
+pub struct Task {
+    shared: SharedState,
+    window: Option<CompactionWindow>,
+    check_interval: Interval,
+    /// Whether the tables are compacted successfully in the current window.
+    compacted_in_window: bool,
+    /// The outcome of the scheduled compaction running in the current window.
+    scheduled: Option<oneshot::Receiver<bool>>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "CompactionScheduler";
+
+    type SharedData = SharedState;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Task {
+    fn new(database: Database, config: Config) -> Self {
+        let Config {
+            columns,
+            window,
+            interval,
+        } = config;
+        let mut check_interval = tokio::time::interval(interval);
+        check_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Self {
+            shared: SharedState {
+                database,
+                columns: Arc::new(columns),
+                compacting: Default::default(),
+                last_compaction: Default::default(),
+            },
+            window,
+            check_interval,
+            compacted_in_window: false,
+            scheduled: None,
+        }
+    }
+
+    /// Refreshes the compaction debt and starts the compaction once per window.
+    fn check(&mut self, seconds_of_day: u32) -> anyhow::Result<()> {
+        self.shared.compaction_debt(self.shared.columns())?;
+        let Some(window) = self.window else {
+            return Ok(())
+        };
+        if !window.contains(seconds_of_day) {
+            self.compacted_in_window = false;
+            self.scheduled = None;
+        } else if !self.compacted_in_window {
+            if let Some(scheduled) = self.scheduled.as_mut() {
+                match scheduled.try_recv() {
+                    Err(oneshot::error::TryRecvError::Empty) => return Ok(()),
+                    result => self.compacted_in_window = result.unwrap_or(false),
+                }
+            }
+            // The failed compaction is retried. The manual compaction may be
+            // running, then the scheduled one is retried on the next check.
+            self.scheduled = if self.compacted_in_window {
+                None
+            } else {
+                self.shared.spawn_compaction(self.shared.columns.to_vec())
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of seconds since midnight in UTC.
+fn seconds_of_day() -> u32 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    (now.as_secs() % SECONDS_PER_DAY as u64) as u32
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            _ = self.check_interval.tick() => {
+                self.check(seconds_of_day())?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(database: Database, config: Config) -> anyhow::Result<Service> {
+    anyhow::ensure!(
+        !config.interval.is_zero(),
+        "The interval of the compaction check must be positive"
+    );
+    Ok(ServiceRunner::new(Task::new(database, config)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window(s: &str) -> CompactionWindow {
+        s.parse().unwrap()
+    }
+
+    fn task(window: Option<CompactionWindow>) -> Task {
+        let config = Config {
+            columns: vec![Column::Coins, Column::ContractsState],
+            window,
+            interval: Duration::from_secs(60),
+        };
+        Task::new(Database::default(), config)
+    }
+
+    async fn wait_for_compaction(shared: &SharedState) -> CompactionReport {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Some(report) = shared.last_compaction() {
+                    return report
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("The compaction should finish")
+    }
+
+    #[test]
+    fn window_is_parsed_from_the_time_range() {
+        let window = window("02:00-04:30");
+
+        assert!(!window.contains(2 * 60 * 60 - 1));
+        assert!(window.contains(2 * 60 * 60));
+        assert!(window.contains(4 * 60 * 60 + 29 * 60));
+        assert!(!window.contains(4 * 60 * 60 + 30 * 60));
+    }
+
+    #[test]
+    fn window_wraps_around_midnight() {
+        let window = window("23:00-01:00");
+
+        assert!(window.contains(23 * 60 * 60));
+        assert!(window.contains(0));
+        assert!(!window.contains(60 * 60));
+        assert!(!window.contains(12 * 60 * 60));
+    }
+
+    #[test]
+    fn malformed_windows_are_rejected() {
+        for window in ["02:00", "2-4", "25:00-04:00", "02:60-04:00", "02:00-02:00"] {
+            assert!(window.parse::<CompactionWindow>().is_err(), "{window}");
+        }
+    }
+
+    #[tokio::test]
+    async fn tables_are_compacted_once_per_window() {
+        let mut task = task(Some(window("02:00-04:00")));
+
+        task.check(60 * 60).unwrap();
+        assert!(!task.compacted_in_window);
+
+        task.check(3 * 60 * 60).unwrap();
+        assert!(!task.compacted_in_window);
+        assert!(task.scheduled.is_some());
+        let report = wait_for_compaction(&task.shared).await;
+        assert_eq!(report.columns, vec![Column::Coins, Column::ContractsState]);
+        assert!(report.error.is_none());
+        while task.shared.is_compacting() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        task.check(3 * 60 * 60).unwrap();
+        assert!(task.compacted_in_window);
+        assert!(task.scheduled.is_none());
+        task.check(3 * 60 * 60).unwrap();
+        assert!(task.scheduled.is_none());
+
+        task.check(5 * 60 * 60).unwrap();
+        assert!(!task.compacted_in_window);
+    }
+
+    #[tokio::test]
+    async fn failed_compaction_is_retried_in_the_same_window() {
+        let mut task = task(Some(window("02:00-04:00")));
+        let (sender, receiver) = oneshot::channel();
+        sender.send(false).unwrap();
+        task.scheduled = Some(receiver);
+
+        task.check(3 * 60 * 60).unwrap();
+
+        assert!(!task.compacted_in_window);
+        assert!(task.scheduled.is_some());
+        assert!(wait_for_compaction(&task.shared).await.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn zero_check_interval_is_rejected() {
+        let config = Config {
+            columns: vec![Column::Coins],
+            window: None,
+            interval: Duration::ZERO,
+        };
+
+        assert!(new_service(Database::default(), config).is_err());
+    }
+
+    #[tokio::test]
+    async fn manual_compaction_is_rejected_while_another_is_running() {
+        let task = task(None);
+        let guard = task.shared.compacting.clone().try_lock_owned().unwrap();
+
+        assert!(task.shared.is_compacting());
+        assert!(!task.shared.start_compaction(vec![Column::Coins]));
+
+        drop(guard);
+        assert!(task.shared.start_compaction(vec![Column::Coins]));
+        assert_eq!(
+            wait_for_compaction(&task.shared).await.columns,
+            vec![Column::Coins]
+        );
+    }
+}
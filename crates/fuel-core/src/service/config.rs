@@ -1,8 +1,17 @@
+use crate::{
+    service::adapters::consensus_module::poa::{
+        RemoteCosigner,
+        RemoteCosignerConfig,
+    },
+    state::Durability,
+};
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
 };
+use fuel_core_poa::ports::BlockCosigner;
+use fuel_core_services::ResourceLimits;
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     secrecy::Secret,
@@ -13,6 +22,7 @@ use std::{
         SocketAddr,
     },
     path::PathBuf,
+    sync::Arc,
     time::Duration,
 };
 use strum_macros::{
@@ -28,16 +38,29 @@ use fuel_core_p2p::config::{
 };
 
 #[cfg(feature = "relayer")]
-use fuel_core_relayer::Config as RelayerConfig;
+use fuel_core_relayer::{
+    committer::{
+        Config as BlockCommitterConfig,
+        ReaderConfig as CommitmentReaderConfig,
+    },
+    Config as RelayerConfig,
+    OriginConfig as OriginRelayerConfig,
+};
 
 pub use fuel_core_poa::Trigger;
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
+    /// The address of the gRPC API server. `None` disables the server.
+    #[cfg(feature = "grpc")]
+    pub grpc_addr: Option<SocketAddr>,
+    pub mode: NodeMode,
     pub max_database_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
+    /// How often the writes of the database are synced to the disk.
+    pub database_durability: Durability,
     pub chain_conf: ChainConfig,
     /// When `true`:
     /// - Enables manual block production.
@@ -52,13 +75,34 @@ pub struct Config {
     pub block_producer: fuel_core_producer::Config,
     pub block_executor: fuel_core_executor::Config,
     pub block_importer: fuel_core_importer::Config,
+    pub gas_price: fuel_core_gas_price_service::Config,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
+    /// Posts the commitments of the blocks to the DA layer. Requires the `relayer`.
+    #[cfg(feature = "relayer")]
+    pub block_committer: Option<BlockCommitterConfig>,
+    /// Reads the commitments of the blocks from the DA layer to serve the withdrawals
+    /// when the `block_committer` is disabled. Requires the `relayer`.
+    #[cfg(feature = "relayer")]
+    pub commitment_reader: Option<CommitmentReaderConfig>,
+    /// The relayers of the additional DA networks. Their messages are stored
+    /// by the chain id of the network and aren't spendable on the chain.
+    #[cfg(feature = "relayer")]
+    pub origin_relayers: Vec<OriginRelayerConfig>,
     #[cfg(feature = "p2p")]
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The additional keys sealing the blocks of the multi-signature PoA
+    /// together with the `consensus_key`.
+    pub consensus_cosigner_keys: Vec<Secret<SecretKeyWrapper>>,
+    /// The nodes asked for the signatures of the multi-signature PoA when
+    /// the local keys don't reach the threshold.
+    pub consensus_cosigners: Vec<RemoteCosignerConfig>,
+    /// The byzantine faults injected into the P2P behaviour of the node.
+    #[cfg(feature = "fault-injection")]
+    pub fault_injection: crate::service::fault_injection::FaultInjectionConfig,
     pub name: String,
     pub verifier: fuel_core_consensus_module::RelayerVerifierConfig,
     /// The number of reserved peers to connect to before starting to sync.
@@ -67,6 +111,50 @@ pub struct Config {
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// The number of the recent blocks with the cached message trees for the `messageProof`.
+    pub message_proof_cache_size: usize,
+    /// How long the history of the transaction statuses is kept in the archive.
+    /// `None` or zero disables the archive.
+    pub tx_status_retention: Option<Duration>,
+    /// How often the storage statistics of the database tables are refreshed.
+    /// `None` or zero disables the statistics.
+    pub storage_stats_interval: Option<Duration>,
+    /// Enables the registry of the assets issued by the contracts.
+    pub asset_registry: bool,
+    /// Enables the notifications about the changes of the accounts watched by the clients.
+    pub account_watcher: bool,
+    /// The number of the latest blocks with the calls of the contracts counted for
+    /// the `topContracts` query. `None` disables the contract call analytics.
+    pub contract_calls_window: Option<u32>,
+    /// Collects the execution statistics of the GraphQL operations by their names.
+    pub query_stats: bool,
+    /// The persisted queries of the GraphQL API and the allow-list of the queries.
+    /// `None` disables the persisted queries.
+    pub persisted_queries:
+        Option<crate::fuel_core_graphql_api::persisted_queries::PersistedQueriesConfig>,
+    /// The number of the latest block and transaction status events buffered
+    /// for the resumption of the subscriptions. `0` disables the resumption.
+    pub subscription_buffer_size: usize,
+    /// Follows the upstream node by applying its state diffs instead of the execution.
+    pub state_diff_upstream: Option<crate::service::state_diff_follower::Config>,
+    /// Switches the node into the degraded mode when the disk is low on the free space.
+    /// `None` disables the monitoring.
+    pub disk_monitor: Option<crate::service::disk_monitor::Config>,
+    /// The consistency checks of the database run before the services start.
+    pub startup_checks: StartupChecks,
+    /// Moves the legacy bytecode of the contracts into the deduplicated table and
+    /// removes the unreferenced bytecode before the services start.
+    pub compact_contracts_bytecode: bool,
+    /// Compacts the selected database tables during the low-traffic window and
+    /// on demand via the admin API. `None` disables the scheduler.
+    pub compaction_scheduler: Option<crate::service::compaction_scheduler::Config>,
+    /// The global limits of the resources shared by the API, the `TxPool` and
+    /// the executor. The work is rejected when the budget is exhausted.
+    pub resource_limits: ResourceLimits,
+    /// Publishes the events of the subscriptions to the event bus, or serves
+    /// the subscriptions from it. `None` disables the event bus.
+    #[cfg(feature = "event-bus")]
+    pub event_bus: Option<crate::service::event_bus::Config>,
 }
 
 impl Config {
@@ -74,12 +162,19 @@ impl Config {
         let chain_conf = ChainConfig::local_testnet();
         let utxo_validation = false;
         let min_gas_price = 0;
+        let block_gas_limit = chain_conf.block_gas_limit;
+        let block_limits = chain_conf.block_limits();
+        let chain_id = chain_conf.consensus_parameters.chain_id;
 
         Self {
             addr: SocketAddr::new(Ipv4Addr::new(127, 0, 0, 1).into(), 0),
+            #[cfg(feature = "grpc")]
+            grpc_addr: None,
+            mode: NodeMode::Full,
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
             database_path: Default::default(),
+            database_durability: Default::default(),
             #[cfg(feature = "rocksdb")]
             database_type: DbType::RocksDb,
             #[cfg(not(feature = "rocksdb"))]
@@ -96,21 +191,61 @@ impl Config {
                 transaction_ttl: Duration::from_secs(60 * 100000000),
                 ..fuel_core_txpool::Config::default()
             },
-            block_producer: Default::default(),
+            block_producer: fuel_core_producer::Config {
+                block_limits,
+                ..Default::default()
+            },
             block_executor: Default::default(),
-            block_importer: Default::default(),
+            block_importer: fuel_core_importer::Config {
+                block_limits,
+                ..Default::default()
+            },
+            gas_price: fuel_core_gas_price_service::Config {
+                min_gas_price,
+                starting_gas_price: min_gas_price,
+                block_gas_limit,
+                chain_id,
+                ..Default::default()
+            },
             #[cfg(feature = "relayer")]
             relayer: None,
+            #[cfg(feature = "relayer")]
+            block_committer: None,
+            #[cfg(feature = "relayer")]
+            commitment_reader: None,
+            #[cfg(feature = "relayer")]
+            origin_relayers: vec![],
             #[cfg(feature = "p2p")]
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            consensus_cosigner_keys: vec![],
+            consensus_cosigners: vec![],
+            #[cfg(feature = "fault-injection")]
+            fault_injection: Default::default(),
             name: String::default(),
             verifier: Default::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            message_proof_cache_size: 1024,
+            tx_status_retention: None,
+            storage_stats_interval: None,
+            asset_registry: false,
+            account_watcher: false,
+            contract_calls_window: None,
+            query_stats: false,
+            persisted_queries: None,
+            subscription_buffer_size: 10_000,
+            state_diff_upstream: None,
+            disk_monitor: None,
+            startup_checks: StartupChecks::Off,
+            compact_contracts_bytecode: false,
+            compaction_scheduler: None,
+            resource_limits: Default::default(),
+            #[cfg(feature = "event-bus")]
+            event_bus: None,
         }
     }
 
@@ -135,6 +270,58 @@ impl Config {
             tracing::warn!("The `utxo_validation` of `BlockProducer` was inconsistent");
             self.block_producer.utxo_validation = self.utxo_validation;
         }
+        if self.gas_price.min_gas_price != self.txpool.min_gas_price {
+            tracing::warn!("The `min_gas_price` of `GasPrice` was inconsistent");
+            self.gas_price.min_gas_price = self.txpool.min_gas_price;
+        }
+        if self.gas_price.block_gas_limit != self.chain_conf.block_gas_limit {
+            tracing::warn!("The `block_gas_limit` of `GasPrice` was inconsistent");
+            self.gas_price.block_gas_limit = self.chain_conf.block_gas_limit;
+        }
+        if self.gas_price.chain_id != self.chain_conf.consensus_parameters.chain_id {
+            tracing::warn!("The `chain_id` of `GasPrice` was inconsistent");
+            self.gas_price.chain_id = self.chain_conf.consensus_parameters.chain_id;
+        }
+        let block_limits = self.chain_conf.block_limits();
+        if self.block_producer.block_limits != block_limits {
+            tracing::warn!("The `block_limits` of `BlockProducer` was inconsistent");
+            self.block_producer.block_limits = block_limits;
+        }
+        if self.block_importer.block_limits != block_limits {
+            tracing::warn!("The `block_limits` of `BlockImporter` was inconsistent");
+            self.block_importer.block_limits = block_limits;
+        }
+        #[cfg(feature = "p2p")]
+        if let Some(p2p) = &self.p2p {
+            if (p2p.max_block_size as u64) < block_limits.size {
+                tracing::warn!(
+                    "The `max_block_size` of P2P is less than the `block_size_limit`, \
+                    the largest blocks can't be synced"
+                );
+            }
+        }
+
+        if self.mode == NodeMode::ReadReplica {
+            if self.block_production != Trigger::Never {
+                tracing::warn!("The block production is disabled on the read replica");
+                self.block_production = Trigger::Never;
+            }
+            #[cfg(feature = "relayer")]
+            if self.relayer.is_some()
+                || self.block_committer.is_some()
+                || !self.origin_relayers.is_empty()
+            {
+                tracing::warn!("The relayer is disabled on the read replica");
+                self.relayer = None;
+                self.block_committer = None;
+                self.origin_relayers.clear();
+            }
+            #[cfg(feature = "p2p")]
+            if self.p2p.take().is_some() {
+                tracing::warn!("The P2P network is disabled on the read replica");
+            }
+            self.txpool.read_only = true;
+        }
 
         self
     }
@@ -146,10 +333,22 @@ impl From<&Config> for fuel_core_poa::Config {
             trigger: config.block_production,
             block_gas_limit: config.chain_conf.block_gas_limit,
             signing_key: config.consensus_key.clone(),
+            consensus: config.chain_conf.consensus.clone(),
+            cosigning_keys: config.consensus_cosigner_keys.clone(),
+            cosigners: config
+                .consensus_cosigners
+                .iter()
+                .map(|cosigner| {
+                    Arc::new(RemoteCosigner::new(cosigner.clone()))
+                        as Arc<dyn BlockCosigner>
+                })
+                .collect(),
             metrics: false,
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            sealing_deadline: config.block_producer.stage_deadlines.sealing,
+            clock: None,
         }
     }
 }
@@ -159,6 +358,53 @@ pub struct VMConfig {
     pub backtrace: bool,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    Eq,
+    PartialEq,
+    EnumString,
+    EnumVariantNames,
+    ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum NodeMode {
+    /// Produces or validates the blocks and accepts the transactions.
+    #[default]
+    Full,
+    /// Follows the primary node by applying its state diffs. It doesn't run the
+    /// `TxPool`, the block production, the relayer and the P2P network, and only
+    /// serves the read API.
+    ReadReplica,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Display,
+    Eq,
+    PartialEq,
+    EnumString,
+    EnumVariantNames,
+    ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum StartupChecks {
+    /// The node starts without the checks.
+    #[default]
+    Off,
+    /// Verifies the latest block against the stored transactions and Merkle roots.
+    Fast,
+    /// Verifies all blocks and the finalized DA height of the relayer
+    /// against the stored messages.
+    Full,
+}
+
 #[derive(
     Clone, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
 )]
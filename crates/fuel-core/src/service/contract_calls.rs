@@ -0,0 +1,310 @@
+//! Counts the calls of the contracts in the window of the latest blocks. The analytics
+//! follows the imported blocks and indexes the call receipts of their transactions,
+//! so the explorers can show the most active contracts without the external indexer.
+//!
+//! The calls of the blocks that left the window are removed, so the table doesn't grow
+//! with the chain.
+
+use crate::{
+    database::Database,
+    service::disk_monitor,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    tables::{
+        FuelBlocks,
+        Receipts,
+    },
+    transactional::Transactional,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::{
+        BlockHeight,
+        ContractId,
+    },
+    services::block_importer::ImportResult,
+};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// The max number of blocks indexed in one iteration while the analytics catches up.
+const BLOCKS_PER_ITERATION: u32 = 100;
+
+pub type Service = ServiceRunner<NotInitializedTask>;
+
+pub struct NotInitializedTask {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    window: u32,
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+pub struct Task {
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    /// The number of the latest blocks with the recorded calls.
+    window: u32,
+    /// The analytics doesn't write while the node is low on the disk space.
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for NotInitializedTask {
+    const NAME: &'static str = "ContractCalls";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            database,
+            blocks,
+            window,
+            disk_monitor,
+        } = self;
+        Ok(Task {
+            database,
+            blocks,
+            window: window.max(1),
+            disk_monitor,
+        })
+    }
+}
+
+impl Task {
+    /// Indexes the next blocks after the last indexed one.
+    /// Returns `true` if the analytics caught up with the chain.
+    fn index_next_blocks(&self) -> anyhow::Result<bool> {
+        let latest: u32 = self.database.latest_height()?.into();
+        // The blocks outside the window are never indexed.
+        let first_in_window = latest.saturating_add(1).saturating_sub(self.window);
+        let next: u32 = match self.database.contract_calls_height()? {
+            Some(height) => u32::from(height).saturating_add(1),
+            None => self.database.ids_of_genesis_block()?.0.into(),
+        }
+        .max(first_in_window);
+        if next > latest {
+            return Ok(true)
+        }
+
+        let last = latest.min(next.saturating_add(BLOCKS_PER_ITERATION - 1));
+        for height in next..=last {
+            self.index_block(height.into())?;
+        }
+        Ok(last == latest)
+    }
+
+    /// Records the calls of the block at the `height`, removes the calls of the block
+    /// that left the window and moves the height of the analytics in one
+    /// database transaction.
+    fn index_block(&self, height: BlockHeight) -> anyhow::Result<()> {
+        let mut transaction = Transactional::transaction(&self.database);
+        let database = transaction.as_mut();
+
+        let block_id = database
+            .get_block_id(&height)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))?;
+        let block = database
+            .storage::<FuelBlocks>()
+            .get(&block_id)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))?
+            .into_owned();
+
+        // The reverted transactions are counted too, the calls were made anyway.
+        let mut calls = HashMap::<ContractId, u64>::new();
+        for tx_id in block.transactions() {
+            let Some(receipts) = database.storage::<Receipts>().get(tx_id)? else {
+                continue
+            };
+            for receipt in receipts.iter() {
+                if let Receipt::Call { to, .. } = receipt {
+                    *calls.entry(*to).or_default() += 1;
+                }
+            }
+        }
+        database.insert_contract_calls(height, &calls)?;
+
+        let first_in_window = u32::from(height)
+            .saturating_add(1)
+            .saturating_sub(self.window);
+        database.prune_contract_calls(first_in_window.into())?;
+
+        database.set_contract_calls_height(height)?;
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        // The blocks imported in the meantime are indexed after the recovery.
+        if let Some(disk_monitor) = self.disk_monitor.as_ref() {
+            if disk_monitor.is_degraded() {
+                tokio::select! {
+                    biased;
+
+                    _ = watcher.while_started() => return Ok(false),
+                    _ = disk_monitor.recovered() => return Ok(true),
+                }
+            }
+        }
+
+        match self.index_next_blocks() {
+            // Continue to catch up without waiting for the next block.
+            Ok(false) => return Ok(true),
+            Ok(true) => {}
+            // Retry after the next block instead of failing in the loop.
+            Err(e) => {
+                tracing::error!("The contract call analytics failed to index blocks: {e}")
+            }
+        }
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            block = self.blocks.recv() => match block {
+                // The skipped blocks are indexed from the database anyway.
+                Ok(_) | Err(RecvError::Lagged(_)) => Ok(true),
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    window: u32,
+    disk_monitor: Option<disk_monitor::SharedState>,
+) -> Service {
+    ServiceRunner::new(NotInitializedTask {
+        database,
+        blocks,
+        window,
+        disk_monitor,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        blockchain::{
+            block::CompressedBlock,
+            header::BlockHeader,
+        },
+        fuel_tx::TxId,
+        tai64::Tai64,
+    };
+
+    fn insert_block(database: &mut Database, height: u32, calls: &[u8]) {
+        let tx_id = TxId::from([height as u8; 32]);
+        let block = CompressedBlock::test(
+            BlockHeader::new_block(height.into(), Tai64::UNIX_EPOCH),
+            vec![tx_id],
+        );
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block)
+            .unwrap();
+        let receipts: Vec<_> = calls
+            .iter()
+            .map(|id| Receipt::Call {
+                id: ContractId::zeroed(),
+                to: ContractId::from([*id; 32]),
+                amount: 0,
+                asset_id: Default::default(),
+                gas: 0,
+                param1: 0,
+                param2: 0,
+                pc: 0,
+                is: 0,
+            })
+            .collect();
+        database
+            .storage::<Receipts>()
+            .insert(&tx_id, &receipts)
+            .unwrap();
+    }
+
+    fn task(database: Database, window: u32) -> Task {
+        let (_, blocks) = broadcast::channel(1);
+        Task {
+            database,
+            blocks,
+            window,
+            disk_monitor: None,
+        }
+    }
+
+    fn top(task: &Task) -> Vec<(u8, u64)> {
+        task.database
+            .top_contracts(0u32.into(), 10)
+            .unwrap()
+            .into_iter()
+            .map(|calls| (calls.contract_id[0], calls.calls))
+            .collect()
+    }
+
+    #[test]
+    fn counts_calls_in_the_window() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0, &[]);
+        insert_block(&mut database, 1, &[1, 1, 2]);
+        insert_block(&mut database, 2, &[2, 3]);
+        insert_block(&mut database, 3, &[3]);
+        let task = task(database, 2);
+
+        assert!(task.index_next_blocks().unwrap());
+
+        // The block 1 is outside the window of the 2 latest blocks.
+        assert_eq!(top(&task), vec![(3, 2), (2, 1)]);
+        assert_eq!(
+            task.database.contract_calls_height().unwrap(),
+            Some(3u32.into())
+        );
+    }
+
+    #[test]
+    fn removes_calls_of_blocks_leaving_the_window() {
+        let mut database = Database::default();
+        insert_block(&mut database, 0, &[]);
+        insert_block(&mut database, 1, &[1, 1]);
+        let mut task = task(database, 2);
+        assert!(task.index_next_blocks().unwrap());
+        assert_eq!(top(&task), vec![(1, 2)]);
+
+        insert_block(&mut task.database, 2, &[2]);
+        insert_block(&mut task.database, 3, &[2]);
+        assert!(task.index_next_blocks().unwrap());
+
+        assert_eq!(top(&task), vec![(2, 2)]);
+    }
+}
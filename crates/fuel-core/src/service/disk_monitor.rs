@@ -0,0 +1,219 @@
+//! Monitors the free space of the disk with the database. When the space drops below
+//! the threshold, the node switches into the degraded mode instead of failing in the
+//! middle of the write: the `TxPool` stops accepting new transactions and the optional
+//! indexes pause their writes, while the blocks are still imported.
+//!
+//! The node leaves the degraded mode when the free space grows above the resume threshold.
+
+use crate::{
+    database::Database,
+    service::adapters::P2PAdapter,
+};
+use fuel_core_metrics::storage_metrics::storage_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::watch,
+    time::{
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+type TxPoolSharedState = fuel_core_txpool::service::SharedState<P2PAdapter, Database>;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The directory of the database.
+    pub path: PathBuf,
+    /// How often the free space is checked. It must be positive.
+    pub interval: Duration,
+    /// The node switches into the degraded mode below this number of free bytes.
+    pub min_free_space: u64,
+    /// The node leaves the degraded mode above this number of free bytes.
+    pub resume_free_space: u64,
+}
+
+pub type Service = ServiceRunner<Task>;
+
+/// Whether the node is in the degraded mode.
+#[derive(Clone)]
+pub struct SharedState {
+    degraded: Arc<watch::Sender<bool>>,
+}
+
+impl SharedState {
+    fn new() -> Self {
+        let (degraded, _) = watch::channel(false);
+        Self {
+            degraded: Arc::new(degraded),
+        }
+    }
+
+    /// Returns `true` if the node is low on the disk space.
+    pub fn is_degraded(&self) -> bool {
+        *self.degraded.borrow()
+    }
+
+    /// Waits until the node leaves the degraded mode.
+    pub async fn recovered(&self) {
+        let mut receiver = self.degraded.subscribe();
+        let _ = receiver.wait_for(|degraded| !degraded).await;
+    }
+}
+
+pub struct Task {
+    config: Config,
+    txpool: TxPoolSharedState,
+    shared: SharedState,
+    check_interval: Interval,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "DiskMonitor";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Task {
+    fn check(&mut self) -> anyhow::Result<()> {
+        let free_space = fs2::available_space(&self.config.path)?;
+        storage_metrics().set_disk_free_space(free_space);
+
+        let degraded = self.shared.is_degraded();
+        let next = next_degraded(degraded, free_space, &self.config);
+        if next != degraded {
+            if next {
+                tracing::error!(
+                    "The disk with the database has only {free_space} free bytes. \
+                    The node stops accepting the transactions and pauses \
+                    the optional indexes until the space is freed."
+                );
+            } else {
+                tracing::warn!(
+                    "The disk with the database has {free_space} free bytes. \
+                    The node leaves the degraded mode."
+                );
+            }
+            self.txpool.pause_insertions(next);
+            storage_metrics().set_disk_degraded(next);
+            self.shared.degraded.send_replace(next);
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the node should be in the degraded mode with the `free_space`.
+/// Between the thresholds, the node stays in its current mode.
+fn next_degraded(degraded: bool, free_space: u64, config: &Config) -> bool {
+    if free_space < config.min_free_space {
+        true
+    } else if free_space > config.resume_free_space {
+        false
+    } else {
+        degraded
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            _ = self.check_interval.tick() => {
+                if let Err(e) = self.check() {
+                    tracing::error!("Failed to check the free disk space: {e}");
+                }
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(config: Config, txpool: TxPoolSharedState) -> anyhow::Result<Service> {
+    anyhow::ensure!(
+        !config.interval.is_zero(),
+        "The interval of the disk space check must be positive"
+    );
+    let mut check_interval = tokio::time::interval(config.interval);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    Ok(ServiceRunner::new(Task {
+        config,
+        txpool,
+        shared: SharedState::new(),
+        check_interval,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            path: Default::default(),
+            interval: Duration::from_secs(1),
+            min_free_space: 100,
+            resume_free_space: 200,
+        }
+    }
+
+    #[test]
+    fn degraded_mode_has_hysteresis() {
+        let config = config();
+
+        assert!(!next_degraded(false, 150, &config));
+        assert!(next_degraded(false, 99, &config));
+        assert!(next_degraded(true, 150, &config));
+        assert!(next_degraded(true, 200, &config));
+        assert!(!next_degraded(true, 201, &config));
+    }
+
+    #[tokio::test]
+    async fn recovered_waits_for_the_end_of_the_degraded_mode() {
+        let shared = SharedState::new();
+        shared.degraded.send_replace(true);
+        assert!(shared.is_degraded());
+
+        let waiter = tokio::spawn({
+            let shared = shared.clone();
+            async move { shared.recovered().await }
+        });
+        shared.degraded.send_replace(false);
+
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("Should recover")
+            .unwrap();
+    }
+}
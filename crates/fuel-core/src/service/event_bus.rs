@@ -0,0 +1,541 @@
+//! Fans out the events of the block and the transaction status subscriptions via
+//! the Redis pub/sub, so several API frontends behind a load balancer serve the
+//! subscriptions sourced from a single executing node.
+//!
+//! The executing node publishes the imported blocks and the status updates of its
+//! `TxPool` to the channel. The frontends, usually the read replicas, subscribe to
+//! the channel and serve the `newBlocks` and `statusChange` subscriptions from it.
+//! The frontend forwards the event only after its database has the block of the event,
+//! so the subscribers can query the block right away.
+//!
+//! Redis delivers the events at most once. The events published while the frontend
+//! is disconnected are lost, like the events skipped by a slow subscriber.
+//!
+//! Only Redis is supported as the event bus.
+
+use crate::database::Database;
+use clap::ValueEnum;
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::{
+    tables::FuelBlocks,
+    StorageAsRef,
+};
+use fuel_core_txpool::service::{
+    TxStatusMessage,
+    TxUpdate,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_tx::TxId,
+    fuel_types::BlockHeight,
+    services::{
+        block_importer::ImportResult,
+        graphql_api::SubscriptionEvent,
+        txpool::TransactionStatus,
+    },
+};
+use futures::StreamExt;
+use redis::AsyncCommands;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::Duration,
+};
+use strum_macros::{
+    Display,
+    EnumString,
+    EnumVariantNames,
+};
+use tokio::{
+    sync::broadcast::{
+        self,
+        error::RecvError,
+    },
+    time::Instant,
+};
+
+/// The number of the received events buffered for the slow subscribers,
+/// and of the received events waiting for their blocks.
+const EVENTS_CAPACITY: usize = 1024;
+
+/// How long to wait before reconnecting to Redis after the failure.
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the frontend checks that its database has the blocks of the waiting events.
+const BLOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long the frontend waits for the block of the event. The event is forwarded
+/// anyway after it, so the lagging database doesn't stop the subscriptions.
+const BLOCK_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(
+    Clone, Copy, Debug, Display, Eq, PartialEq, EnumString, EnumVariantNames, ValueEnum,
+)]
+#[strum(serialize_all = "kebab_case")]
+pub enum Role {
+    /// Publishes the events of the node.
+    Publisher,
+    /// Serves the subscriptions from the published events.
+    Subscriber,
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The URL of the Redis server.
+    pub url: String,
+    /// The pub/sub channel of the events.
+    pub channel: String,
+    pub role: Role,
+}
+
+pub type PublisherService = ServiceRunner<Publisher>;
+pub type SubscriberService = ServiceRunner<Subscriber>;
+
+/// Publishes the events of the executing node to the channel.
+pub struct Publisher {
+    client: redis::Client,
+    channel: String,
+    connection: Option<redis::aio::MultiplexedConnection>,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    tx_updates: broadcast::Receiver<TxUpdate>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Publisher {
+    const NAME: &'static str = "EventBusPublisher";
+
+    type SharedData = ();
+    type Task = Publisher;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Publisher {
+    async fn publish(&mut self, event: SubscriptionEvent) {
+        let payload = match serde_json::to_string(&event) {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::error!("Failed to encode the event {event:?}: {e}");
+                return
+            }
+        };
+        if let Err(e) = self.try_publish(payload).await {
+            tracing::warn!("Failed to publish the event to the event bus: {e}");
+        }
+    }
+
+    /// Publishes the `payload`. The failed connection is dropped
+    /// and reopened for the next event.
+    async fn try_publish(&mut self, payload: String) -> redis::RedisResult<()> {
+        let mut connection = match self.connection.take() {
+            Some(connection) => connection,
+            None => self.client.get_multiplexed_tokio_connection().await?,
+        };
+        connection
+            .publish::<_, _, ()>(&self.channel, payload)
+            .await?;
+        self.connection = Some(connection);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Publisher {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let event = tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => return Ok(false),
+            block = self.blocks.recv() => match block {
+                Ok(result) => {
+                    SubscriptionEvent::Block(*result.sealed_block.entity.header().height())
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("The event bus skipped {skipped} events");
+                    return Ok(true)
+                }
+                Err(RecvError::Closed) => return Ok(false),
+            },
+            update = self.tx_updates.recv() => match update {
+                Ok(update) => {
+                    let tx_id = *update.tx_id();
+                    let TxStatusMessage::Status(status) = update.into_msg() else {
+                        return Ok(true)
+                    };
+                    SubscriptionEvent::TxStatus(tx_id, status)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!("The event bus skipped {skipped} events");
+                    return Ok(true)
+                }
+                Err(RecvError::Closed) => return Ok(false),
+            },
+        };
+        self.publish(event).await;
+        Ok(true)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// The events received by the frontend.
+#[derive(Clone)]
+pub struct SharedState {
+    events: broadcast::Sender<SubscriptionEvent>,
+}
+
+impl SharedState {
+    /// Returns the stream of the heights of the blocks imported by the executing node.
+    pub fn block_events(&self) -> BoxStream<BlockHeight> {
+        self.subscribe(|event| match event {
+            SubscriptionEvent::Block(height) => Some(height),
+            _ => None,
+        })
+    }
+
+    /// Returns the stream of the status updates of the transaction with the `tx_id`.
+    pub fn tx_update_subscribe(&self, tx_id: TxId) -> BoxStream<TxStatusMessage> {
+        self.subscribe(move |event| match event {
+            SubscriptionEvent::TxStatus(id, status) if id == tx_id => {
+                Some(TxStatusMessage::Status(status))
+            }
+            _ => None,
+        })
+    }
+
+    fn subscribe<T, F>(&self, filter: F) -> BoxStream<T>
+    where
+        T: Send + 'static,
+        F: Fn(SubscriptionEvent) -> Option<T> + Send + 'static,
+    {
+        use tokio_stream::wrappers::BroadcastStream;
+
+        Box::pin(
+            BroadcastStream::new(self.events.subscribe()).filter_map(move |event| {
+                futures::future::ready(event.ok().and_then(&filter))
+            }),
+        )
+    }
+}
+
+/// Receives the events of the executing node on the frontend.
+pub struct Subscriber {
+    client: redis::Client,
+    channel: String,
+    database: Database,
+    messages: Option<BoxStream<redis::Msg>>,
+    /// The received events waiting for their blocks, the oldest first.
+    pending: VecDeque<PendingEvent>,
+    shared: SharedState,
+}
+
+struct PendingEvent {
+    event: SubscriptionEvent,
+    block: Option<BlockRef>,
+    /// The event is forwarded after it even if the database doesn't have the block.
+    deadline: Instant,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Subscriber {
+    const NAME: &'static str = "EventBusSubscriber";
+
+    type SharedData = SharedState;
+    type Task = Subscriber;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Subscriber {
+    async fn subscribe(&self) -> redis::RedisResult<BoxStream<redis::Msg>> {
+        let mut pubsub = self.client.get_async_connection().await?.into_pubsub();
+        pubsub.subscribe(&self.channel).await?;
+        Ok(Box::pin(pubsub.into_on_message()))
+    }
+
+    /// Returns the block of the event, the database of the frontend must have it
+    /// before the event is forwarded.
+    fn block_of(event: &SubscriptionEvent) -> Option<BlockRef> {
+        match event {
+            SubscriptionEvent::Block(height) => Some(BlockRef::Height(*height)),
+            SubscriptionEvent::TxStatus(_, status) => match status {
+                TransactionStatus::Success { block_id, .. }
+                | TransactionStatus::Failed { block_id, .. } => {
+                    Some(BlockRef::Id(*block_id))
+                }
+                _ => None,
+            },
+        }
+    }
+
+    fn has_block(&self, block: &BlockRef) -> anyhow::Result<bool> {
+        let has_block = match block {
+            BlockRef::Height(height) => self.database.latest_height()? >= *height,
+            BlockRef::Id(id) => self.database.storage::<FuelBlocks>().contains_key(id)?,
+        };
+        Ok(has_block)
+    }
+
+    fn receive(&mut self, message: redis::Msg) {
+        let event = message
+            .get_payload::<String>()
+            .map_err(anyhow::Error::from)
+            .and_then(|payload| {
+                serde_json::from_str::<SubscriptionEvent>(&payload).map_err(Into::into)
+            });
+        let event = match event {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!("Failed to decode the event of the event bus: {e}");
+                return
+            }
+        };
+        self.push(event);
+    }
+
+    /// Queues the `event` until the database has its block.
+    fn push(&mut self, event: SubscriptionEvent) {
+        if self.pending.len() >= EVENTS_CAPACITY {
+            // The oldest event is forwarded without its block to bound the queue.
+            if let Some(pending) = self.pending.pop_front() {
+                tracing::warn!("The database doesn't have the block {:?}", pending.block);
+                self.send(pending.event);
+            }
+        }
+        self.pending.push_back(PendingEvent {
+            block: Self::block_of(&event),
+            event,
+            deadline: Instant::now() + BLOCK_WAIT_TIMEOUT,
+        });
+        self.forward_ready();
+    }
+
+    /// Forwards the pending events in the order of their arrival, up to the first
+    /// event whose block isn't in the database yet.
+    fn forward_ready(&mut self) {
+        let now = Instant::now();
+        while let Some(pending) = self.pending.front() {
+            let ready = match &pending.block {
+                None => true,
+                Some(block) => self.has_block(block).unwrap_or_else(|e| {
+                    tracing::warn!("Failed to check the block {block:?}: {e}");
+                    false
+                }),
+            };
+            if !ready {
+                if pending.deadline > now {
+                    break
+                }
+                tracing::warn!("The database doesn't have the block {:?}", pending.block);
+            }
+            if let Some(pending) = self.pending.pop_front() {
+                self.send(pending.event);
+            }
+        }
+    }
+
+    fn send(&self, event: SubscriptionEvent) {
+        // Nobody listens to the events if the send fails.
+        let _ = self.shared.events.send(event);
+    }
+}
+
+#[derive(Debug)]
+enum BlockRef {
+    Height(BlockHeight),
+    Id(BlockId),
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Subscriber {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let Some(messages) = &mut self.messages else {
+            let messages = tokio::select! {
+                biased;
+
+                _ = watcher.while_started() => return Ok(false),
+                messages = self.subscribe() => messages,
+            };
+            match messages {
+                Ok(messages) => self.messages = Some(messages),
+                Err(e) => {
+                    tracing::warn!("Failed to subscribe to the event bus: {e}");
+                    tokio::time::sleep(RECONNECT_INTERVAL).await;
+                }
+            }
+            return Ok(true)
+        };
+
+        let waiting = !self.pending.is_empty();
+        // `None` if it is time to check the blocks of the pending events again.
+        let message = tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => return Ok(false),
+            message = messages.next() => Some(message),
+            _ = tokio::time::sleep(BLOCK_POLL_INTERVAL), if waiting => None,
+        };
+        match message {
+            Some(Some(message)) => self.receive(message),
+            Some(None) => {
+                tracing::warn!("The event bus closed the subscription");
+                self.messages = None;
+            }
+            None => self.forward_ready(),
+        }
+        Ok(true)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// The service of the configured role.
+pub enum Service {
+    Publisher(PublisherService),
+    Subscriber(SubscriberService),
+}
+
+pub fn new_service(
+    config: Config,
+    database: Database,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    tx_updates: broadcast::Receiver<TxUpdate>,
+) -> anyhow::Result<Service> {
+    let client = redis::Client::open(config.url.as_str())?;
+    let service = match config.role {
+        Role::Publisher => Service::Publisher(ServiceRunner::new(Publisher {
+            client,
+            channel: config.channel,
+            connection: None,
+            blocks,
+            tx_updates,
+        })),
+        Role::Subscriber => {
+            let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+            Service::Subscriber(ServiceRunner::new(Subscriber {
+                client,
+                channel: config.channel,
+                database,
+                messages: None,
+                pending: VecDeque::new(),
+                shared: SharedState { events },
+            }))
+        }
+    };
+    Ok(service)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::tai64::Tai64;
+
+    #[test]
+    fn events_are_encoded_for_the_channel() {
+        let event = SubscriptionEvent::TxStatus(
+            TxId::from([1; 32]),
+            TransactionStatus::Success {
+                block_id: BlockId::from([2; 32]),
+                time: Tai64(3),
+                result: None,
+            },
+        );
+
+        let payload = serde_json::to_string(&event).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<SubscriptionEvent>(&payload).unwrap(),
+            event
+        );
+    }
+
+    #[tokio::test]
+    async fn frontend_streams_filter_the_events() {
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        let shared = SharedState { events };
+        let tx_id = TxId::from([1; 32]);
+        let mut blocks = shared.block_events();
+        let mut updates = shared.tx_update_subscribe(tx_id);
+
+        let status = TransactionStatus::Submitted { time: Tai64(1) };
+        shared
+            .events
+            .send(SubscriptionEvent::TxStatus(
+                TxId::from([2; 32]),
+                status.clone(),
+            ))
+            .unwrap();
+        shared
+            .events
+            .send(SubscriptionEvent::TxStatus(tx_id, status.clone()))
+            .unwrap();
+        shared
+            .events
+            .send(SubscriptionEvent::Block(5u32.into()))
+            .unwrap();
+
+        assert_eq!(blocks.next().await, Some(5u32.into()));
+        assert_eq!(updates.next().await, Some(TxStatusMessage::Status(status)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn events_wait_for_their_blocks_without_blocking_the_subscriber() {
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        let mut subscriber = Subscriber {
+            client: redis::Client::open("redis://127.0.0.1").unwrap(),
+            channel: "events".to_string(),
+            database: Database::default(),
+            messages: None,
+            pending: VecDeque::new(),
+            shared: SharedState { events },
+        };
+        let mut received = subscriber.shared.events.subscribe();
+        let missing_block = SubscriptionEvent::Block(5u32.into());
+        let submitted = SubscriptionEvent::TxStatus(
+            TxId::from([1; 32]),
+            TransactionStatus::Submitted { time: Tai64(1) },
+        );
+
+        subscriber.push(missing_block.clone());
+        subscriber.push(submitted.clone());
+        assert!(received.try_recv().is_err());
+        assert_eq!(subscriber.pending.len(), 2);
+
+        // The events are forwarded in their order after the timeout.
+        tokio::time::advance(BLOCK_WAIT_TIMEOUT).await;
+        subscriber.forward_ready();
+        assert_eq!(received.try_recv().unwrap(), missing_block);
+        assert_eq!(received.try_recv().unwrap(), submitted);
+        assert!(subscriber.pending.is_empty());
+    }
+}
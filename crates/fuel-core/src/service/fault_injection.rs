@@ -0,0 +1,284 @@
+//! Injects the byzantine faults into the P2P behaviour of the node, so the P2P
+//! simulations and the long-running testnets can validate the peer scoring and
+//! the defenses of the block importer. Available only with the `fault-injection`
+//! feature, which must never be enabled on the production nodes.
+//!
+//! The faults affect only what the node serves to its peers and gossips. The database
+//! of the node and its own block production stay honest, so the faulty node keeps
+//! following the canonical chain.
+
+use crate::{
+    database::Database,
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_p2p::ports::{
+    BlockHeightImporter,
+    P2pDb,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        header::BlockHeader,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_crypto::Signature,
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+    services::p2p::Transactions,
+    signer::SecretKeyWrapper,
+    tai64::Tai64,
+};
+use futures::StreamExt;
+use std::{
+    ops::{
+        Deref,
+        Range,
+    },
+    time::Duration,
+};
+
+/// The faults injected by the node. All faults are disabled by default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FaultInjectionConfig {
+    /// Serves the block headers with the seals that don't match them.
+    pub invalid_blocks: bool,
+    /// Serves the headers of the conflicting blocks at the same heights, sealed
+    /// with the consensus key of the node. Without the key, the served headers
+    /// are invalid instead.
+    pub equivocation: bool,
+    /// Doesn't serve the transactions of the blocks to the peers.
+    pub withhold_bodies: bool,
+    /// Delays the gossip of the new block heights and the transactions.
+    pub gossip_delay: Option<Duration>,
+}
+
+impl FaultInjectionConfig {
+    /// Returns `true` if any fault is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self != &Self::default()
+    }
+}
+
+/// Wraps the `inner` port of the P2P service and injects the configured faults into it.
+#[derive(Clone)]
+pub struct FaultInjector<T> {
+    inner: T,
+    config: FaultInjectionConfig,
+    consensus_key: Option<Secret<SecretKeyWrapper>>,
+}
+
+impl<T> FaultInjector<T> {
+    pub fn new(
+        inner: T,
+        config: FaultInjectionConfig,
+        consensus_key: Option<Secret<SecretKeyWrapper>>,
+    ) -> Self {
+        if config.is_enabled() {
+            tracing::warn!("The byzantine faults are injected: {config:?}");
+        }
+        Self {
+            inner,
+            config,
+            consensus_key,
+        }
+    }
+
+    fn corrupt(&self, header: SealedBlockHeader) -> SealedBlockHeader {
+        if !self.config.invalid_blocks && !self.config.equivocation {
+            return header
+        }
+        let SealedBlockHeader {
+            entity: header,
+            consensus,
+        } = header;
+        let header = conflicting_header(header);
+        let consensus = match &self.consensus_key {
+            Some(key) if self.config.equivocation => {
+                let signing_key = key.expose_secret().deref();
+                let signature = Signature::sign(signing_key, &header.id().into_message());
+                Consensus::PoA(PoAConsensus::new(signature))
+            }
+            // The original seal doesn't match the changed header.
+            _ => consensus,
+        };
+        SealedBlockHeader {
+            entity: header,
+            consensus,
+        }
+    }
+}
+
+/// Returns the header of another block at the same height, with the same transactions.
+fn conflicting_header(mut header: BlockHeader) -> BlockHeader {
+    header.consensus.time = Tai64(header.consensus.time.0.wrapping_add(1));
+    header.recalculate_metadata();
+    header
+}
+
+impl P2pDb for FaultInjector<Database> {
+    fn get_sealed_block(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<SealedBlock>> {
+        let block = self.inner.get_sealed_block(height)?;
+        Ok(block.map(|block| {
+            let SealedBlock { entity, consensus } = block;
+            let (header, transactions) = entity.into_inner();
+            let sealed_header = self.corrupt(SealedBlockHeader {
+                entity: header,
+                consensus,
+            });
+            let mut entity = Block::default();
+            *entity.header_mut() = sealed_header.entity;
+            *entity.transactions_mut() = transactions;
+            SealedBlock {
+                entity,
+                consensus: sealed_header.consensus,
+            }
+        }))
+    }
+
+    fn get_sealed_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<SealedBlockHeader>> {
+        let header = self.inner.get_sealed_header(height)?;
+        Ok(header.map(|header| self.corrupt(header)))
+    }
+
+    fn get_sealed_headers(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Vec<SealedBlockHeader>> {
+        let headers = self.inner.get_sealed_headers(block_height_range)?;
+        Ok(headers
+            .into_iter()
+            .map(|header| self.corrupt(header))
+            .collect())
+    }
+
+    fn get_transactions(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> StorageResult<Option<Vec<Transactions>>> {
+        if self.config.withhold_bodies {
+            return Ok(None)
+        }
+        self.inner.get_transactions(block_height_range)
+    }
+
+    fn latest_block_height(&self) -> StorageResult<BlockHeight> {
+        self.inner.latest_block_height()
+    }
+}
+
+impl BlockHeightImporter for FaultInjector<BlockImporterAdapter> {
+    fn next_block_height(&self) -> BoxStream<BlockHeight> {
+        let heights = self.inner.next_block_height();
+        match self.config.gossip_delay {
+            Some(delay) => Box::pin(heights.then(move |height| async move {
+                tokio::time::sleep(delay).await;
+                height
+            })),
+            None => heights,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_tx::Input,
+        fuel_types::Address,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn sealed_header(secret: &SecretKey) -> SealedBlockHeader {
+        let block = Block::new(Default::default(), vec![], &[]);
+        let signature = Signature::sign(secret, &block.id().into_message());
+        SealedBlockHeader {
+            entity: block.header().clone(),
+            consensus: Consensus::PoA(PoAConsensus::new(signature)),
+        }
+    }
+
+    fn signer(header: &SealedBlockHeader) -> Option<Address> {
+        let Consensus::PoA(poa) = &header.consensus else {
+            return None
+        };
+        let public_key = poa
+            .signature
+            .recover(&header.entity.id().into_message())
+            .ok()?;
+        Some(Input::owner(&public_key))
+    }
+
+    #[test]
+    fn invalid_blocks_keep_the_original_seal() {
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1));
+        let header = sealed_header(&secret);
+        let config = FaultInjectionConfig {
+            invalid_blocks: true,
+            ..Default::default()
+        };
+        let injector = FaultInjector::new(
+            Database::default(),
+            config,
+            Some(Secret::new(secret.into())),
+        );
+
+        let corrupted = injector.corrupt(header.clone());
+
+        assert_eq!(corrupted.entity.height(), header.entity.height());
+        assert_ne!(corrupted.entity.id(), header.entity.id());
+        assert_eq!(corrupted.consensus, header.consensus);
+        assert_ne!(signer(&corrupted), signer(&header));
+    }
+
+    #[test]
+    fn equivocation_seals_the_conflicting_header_with_the_key() {
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(2));
+        let header = sealed_header(&secret);
+        let config = FaultInjectionConfig {
+            equivocation: true,
+            ..Default::default()
+        };
+        let injector = FaultInjector::new(
+            Database::default(),
+            config,
+            Some(Secret::new(secret.into())),
+        );
+
+        let equivocated = injector.corrupt(header.clone());
+
+        assert_eq!(equivocated.entity.height(), header.entity.height());
+        assert_ne!(equivocated.entity.id(), header.entity.id());
+        assert_eq!(signer(&equivocated), signer(&header));
+    }
+
+    #[test]
+    fn withheld_bodies_are_not_served() {
+        let config = FaultInjectionConfig {
+            withhold_bodies: true,
+            ..Default::default()
+        };
+        let injector = FaultInjector::new(Database::default(), config, None);
+
+        assert!(injector.get_transactions(0..1).unwrap().is_none());
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
 };
 use anyhow::anyhow;
 use fuel_core_chain_config::{
+    ChainConfig,
     ContractConfig,
     GenesisCommitment,
     StateConfig,
@@ -84,19 +85,7 @@ fn import_genesis_block(
     let database = database_transaction.as_mut();
     // Initialize the chain id and height.
 
-    let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
-
-    let genesis = Genesis {
-        chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
-    };
+    let genesis = init_genesis_state(database, &config.chain_conf)?;
 
     let block = Block::new(
         PartialBlockHeader {
@@ -149,6 +138,32 @@ fn import_genesis_block(
     Ok(())
 }
 
+/// Computes the roots of the genesis block of the `chain_conf` without
+/// the persistent database. It allows verifying the snapshot before the regenesis.
+pub fn genesis_roots(chain_conf: &ChainConfig) -> anyhow::Result<Genesis> {
+    let mut database = Database::in_memory();
+    init_genesis_state(&mut database, chain_conf)
+}
+
+/// Writes the initial state of the `chain_conf` into the `database` and returns
+/// the roots of the genesis block.
+fn init_genesis_state(
+    database: &mut Database,
+    chain_conf: &ChainConfig,
+) -> anyhow::Result<Genesis> {
+    let chain_config_hash = chain_conf.root()?.into();
+    let coins_root = init_coin_state(database, &chain_conf.initial_state)?.into();
+    let contracts_root = init_contracts(database, &chain_conf.initial_state)?.into();
+    let messages_root = init_da_messages(database, &chain_conf.initial_state)?.into();
+
+    Ok(Genesis {
+        chain_config_hash,
+        coins_root,
+        contracts_root,
+        messages_root,
+    })
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
@@ -742,6 +757,52 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn genesis_roots_match_roots_of_genesis_block() {
+        let mut rng = StdRng::seed_from_u64(10);
+        let chain_conf = ChainConfig {
+            initial_state: Some(StateConfig {
+                coins: Some(vec![CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: None,
+                    owner: rng.gen(),
+                    amount: rng.next_u64(),
+                    asset_id: rng.gen(),
+                }]),
+                contracts: Some(vec![ContractConfig {
+                    contract_id: rng.gen(),
+                    code: op::ret(0x10).to_bytes().to_vec(),
+                    salt: rng.gen(),
+                    state: Some(vec![(rng.gen(), rng.gen())]),
+                    balances: Some(vec![(rng.gen(), rng.next_u64())]),
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                }]),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+        let service_config = Config {
+            chain_conf: chain_conf.clone(),
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            genesis_roots(&chain_conf).unwrap(),
+            db.get_genesis().unwrap()
+        );
+    }
+
     fn get_coins(db: &Database, owner: &Address) -> Vec<Coin> {
         db.owned_coins_ids(owner, None, None)
             .map(|r| {
@@ -0,0 +1,129 @@
+//! Runs several independent node instances in one process. Each instance has its own
+//! chain config, database and ports, while all of them share the same runtime.
+
+use crate::service::{
+    Config,
+    DbType,
+    FuelService,
+    ServiceTrait,
+};
+use std::collections::HashSet;
+
+/// The set of independent node instances running in the same process.
+pub struct FuelServices {
+    instances: Vec<FuelService>,
+}
+
+impl FuelServices {
+    /// Creates and starts the node instance for each config. The names of the
+    /// instances must be unique, and they can't share ports or database paths.
+    ///
+    /// If any of the instances fails to start, the already started ones are stopped.
+    pub async fn new_nodes(configs: Vec<Config>) -> anyhow::Result<Self> {
+        validate(&configs)?;
+
+        let mut instances = Vec::with_capacity(configs.len());
+        for config in configs {
+            let name = config.name.clone();
+            match FuelService::new_node(config).await {
+                Ok(instance) => instances.push(instance),
+                Err(err) => {
+                    let services = Self { instances };
+                    services.stop_and_await().await;
+                    return Err(err
+                        .context(format!("Failed to start the node instance `{name}`")))
+                }
+            }
+        }
+
+        Ok(Self { instances })
+    }
+
+    /// Returns all node instances in the order of their configs.
+    pub fn instances(&self) -> &[FuelService] {
+        &self.instances
+    }
+
+    /// Returns the node instance with the `name`.
+    pub fn instance(&self, name: &str) -> Option<&FuelService> {
+        self.instances
+            .iter()
+            .find(|instance| instance.shared.config.name == name)
+    }
+
+    /// Waits until all node instances are stopped. The instances are independent,
+    /// so the stop of one of them doesn't affect others.
+    pub async fn await_stop(&self) {
+        let stops = self.instances.iter().map(|instance| async move {
+            if let Err(err) = instance.await_stop().await {
+                tracing::error!(
+                    "The node instance `{}` stopped with an error: {}",
+                    instance.shared.config.name,
+                    err
+                );
+            }
+        });
+        futures::future::join_all(stops).await;
+    }
+
+    /// Stops all node instances and waits until they are stopped.
+    pub async fn stop_and_await(&self) {
+        let stops = self.instances.iter().map(|instance| async move {
+            if let Err(err) = instance.stop_and_await().await {
+                tracing::error!(
+                    "Got an error during stopping of the node instance `{}`: {}",
+                    instance.shared.config.name,
+                    err
+                );
+            }
+        });
+        futures::future::join_all(stops).await;
+    }
+}
+
+/// Checks that the instances don't conflict with each other.
+fn validate(configs: &[Config]) -> anyhow::Result<()> {
+    let mut names = HashSet::new();
+    let mut addresses = HashSet::new();
+    let mut database_paths = HashSet::new();
+    #[cfg(feature = "p2p")]
+    let mut p2p_ports = HashSet::new();
+
+    for config in configs {
+        if config.name.is_empty() {
+            return Err(anyhow::anyhow!("Each node instance requires a name"))
+        }
+        if !names.insert(config.name.as_str()) {
+            return Err(anyhow::anyhow!(
+                "The node instance name `{}` is used more than once",
+                config.name
+            ))
+        }
+        // The zero port is assigned by the system, so it can't conflict.
+        if config.addr.port() != 0 && !addresses.insert(config.addr) {
+            return Err(anyhow::anyhow!(
+                "The API address `{}` is used by more than one node instance",
+                config.addr
+            ))
+        }
+        let uses_database_path = config.database_type != DbType::InMemory
+            && !config.database_path.as_os_str().is_empty();
+        if uses_database_path && !database_paths.insert(config.database_path.as_path()) {
+            return Err(anyhow::anyhow!(
+                "The database path `{}` is used by more than one node instance",
+                config.database_path.display()
+            ))
+        }
+        #[cfg(feature = "p2p")]
+        if let Some(p2p) = &config.p2p {
+            if p2p.tcp_port != 0 && !p2p_ports.insert(p2p.tcp_port) {
+                return Err(anyhow::anyhow!(
+                    "The P2P port `{}` is used by more than one node instance",
+                    p2p.tcp_port
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+//! Applies the new values of the reloadable settings to the running node.
+
+use super::SharedState;
+use std::time::Duration;
+
+/// The settings that can be changed without restarting the node.
+/// `None` means that the setting keeps its current value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReloadableConfig {
+    /// The maximum number of transactions in the pool.
+    pub tx_max_number: Option<usize>,
+    /// The maximum depth of the dependent transactions in the pool.
+    pub tx_max_depth: Option<usize>,
+    /// The time to live of the transactions in the pool.
+    pub tx_pool_ttl: Option<Duration>,
+    /// The minimum duration of one iteration of the relayer loop.
+    pub relayer_sync_minimum_duration: Option<Duration>,
+    /// How often the relayer calls the DA node while it is syncing.
+    pub relayer_syncing_call_frequency: Option<Duration>,
+    /// How often the relayer logs the progress of the DA node syncing.
+    pub relayer_syncing_log_frequency: Option<Duration>,
+}
+
+/// The names of the [`ReloadableConfig`] fields grouped by the outcome of the reload.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReloadReport {
+    /// The settings that were applied to the running node.
+    pub applied: Vec<&'static str>,
+    /// The settings that can't be applied because the corresponding service
+    /// is not running. The node should be restarted to use them.
+    pub requires_restart: Vec<&'static str>,
+}
+
+impl SharedState {
+    /// Applies the set fields of the `config` to the running services.
+    pub fn reload(&self, config: &ReloadableConfig) -> ReloadReport {
+        let mut report = ReloadReport::default();
+
+        let mut limits = self.txpool.limits();
+        for (name, value, field) in [
+            ("tx_max_number", config.tx_max_number, &mut limits.max_tx),
+            ("tx_max_depth", config.tx_max_depth, &mut limits.max_depth),
+        ] {
+            if let Some(value) = value {
+                *field = value;
+                report.applied.push(name);
+            }
+        }
+        if let Some(value) = config.tx_pool_ttl {
+            limits.transaction_ttl = value;
+            report.applied.push("tx_pool_ttl");
+        }
+        self.txpool.set_limits(limits);
+
+        let relayer_fields = [
+            (
+                "relayer_sync_minimum_duration",
+                config.relayer_sync_minimum_duration,
+            ),
+            (
+                "relayer_syncing_call_frequency",
+                config.relayer_syncing_call_frequency,
+            ),
+            (
+                "relayer_syncing_log_frequency",
+                config.relayer_syncing_log_frequency,
+            ),
+        ];
+        #[cfg(feature = "relayer")]
+        if let Some(relayer) = &self.relayer {
+            let mut intervals = relayer.poll_intervals();
+            let fields = [
+                &mut intervals.sync_minimum_duration,
+                &mut intervals.syncing_call_frequency,
+                &mut intervals.syncing_log_frequency,
+            ];
+            for ((name, value), field) in relayer_fields.into_iter().zip(fields) {
+                if let Some(value) = value {
+                    *field = value;
+                    report.applied.push(name);
+                }
+            }
+            relayer.set_poll_intervals(intervals);
+            return report
+        }
+        for (name, value) in relayer_fields {
+            if value.is_some() {
+                report.requires_restart.push(name);
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use crate::service::{
+        Config,
+        FuelService,
+    };
+
+    #[tokio::test]
+    async fn reload__applies_txpool_limits() {
+        let service = FuelService::new_node(Config::local_node()).await.unwrap();
+        let config = ReloadableConfig {
+            tx_max_number: Some(10),
+            tx_pool_ttl: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+
+        let report = service.shared.reload(&config);
+
+        assert_eq!(report.applied, vec!["tx_max_number", "tx_pool_ttl"]);
+        assert_eq!(report.requires_restart, Vec::<&str>::new());
+        let limits = service.shared.txpool.limits();
+        assert_eq!(limits.max_tx, 10);
+        assert_eq!(limits.max_depth, service.shared.config.txpool.max_depth);
+        assert_eq!(limits.transaction_ttl, Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn reload__requires_restart_for_disabled_relayer() {
+        let service = FuelService::new_node(Config::local_node()).await.unwrap();
+        let config = ReloadableConfig {
+            relayer_syncing_log_frequency: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+
+        let report = service.shared.reload(&config);
+
+        assert_eq!(report.applied, Vec::<&str>::new());
+        assert_eq!(
+            report.requires_restart,
+            vec!["relayer_syncing_log_frequency"]
+        );
+    }
+}
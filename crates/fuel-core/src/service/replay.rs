@@ -0,0 +1,432 @@
+//! Re-executes the historical blocks and compares the results with the stored ones to
+//! detect the nondeterminism of the execution or the silent corruption of the database.
+//!
+//! The database keeps only the latest state, so the parent state of the historical
+//! blocks is rebuilt in memory by executing all blocks since the genesis.
+
+use crate::{
+    database::{
+        state_diff::StateChange,
+        Database,
+    },
+    executor::{
+        ExecutionOptions,
+        Executor,
+        OnceTransactionsSource,
+        RelayerPort,
+    },
+    service::{
+        genesis::maybe_initialize_state,
+        Config,
+    },
+};
+use fuel_core_importer::Importer;
+use fuel_core_storage::{
+    tables::{
+        MessageDeposits,
+        Messages,
+        Receipts,
+    },
+    transactional::StorageTransaction,
+    StorageAsRef,
+    StorageInspect,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        primitives::DaBlockHeight,
+        SealedBlock,
+    },
+    entities::message::Message,
+    fuel_tx::{
+        field::Inputs,
+        input::message::{
+            MessageCoinPredicate,
+            MessageCoinSigned,
+            MessageDataPredicate,
+            MessageDataSigned,
+        },
+        Input,
+        Receipt,
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::{
+        block_importer::{
+            ImportResult,
+            UncommittedResult,
+        },
+        executor::{
+            ExecutionResult,
+            ExecutionTypes,
+        },
+    },
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+/// The first difference between the stored blocks and their re-execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The stored block is not valid on top of its parent state.
+    InvalidBlock { height: BlockHeight, error: String },
+    /// The transaction of the block produced other receipts than the stored ones.
+    Receipts {
+        height: BlockHeight,
+        tx_id: TxId,
+        expected: Vec<Receipt>,
+        actual: Vec<Receipt>,
+    },
+    /// The block made other changes of the state than the recorded ones.
+    StateChange {
+        height: BlockHeight,
+        expected: Option<StateChange>,
+        actual: Option<StateChange>,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Divergence::InvalidBlock { height, error } => {
+                write!(
+                    f,
+                    "The block at height {height} failed re-execution: {error}"
+                )
+            }
+            Divergence::Receipts {
+                height,
+                tx_id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The transaction {tx_id:#x} at height {height} produced other receipts.\n\
+                Stored: {expected:?}\nRe-executed: {actual:?}"
+            ),
+            Divergence::StateChange {
+                height,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The block at height {height} made other state changes.\n\
+                Stored: {expected:?}\nRe-executed: {actual:?}"
+            ),
+        }
+    }
+}
+
+/// Re-executes the blocks from the genesis up to the `to` height against the state
+/// rebuilt from the `config`, and compares the receipts of the blocks starting at
+/// the `from` height with the stored ones.
+///
+/// Returns the first found divergence, if any.
+pub fn replay(
+    database: &Database,
+    config: &Config,
+    from: BlockHeight,
+    to: BlockHeight,
+) -> anyhow::Result<Option<Divergence>> {
+    anyhow::ensure!(from <= to, "The range of heights {from}..={to} is empty");
+    let replayer = Replayer::new(database, config, to)?;
+    anyhow::ensure!(
+        from > replayer.genesis_height,
+        "The genesis block at height {} can't be re-executed",
+        replayer.genesis_height
+    );
+    let chain_id = &config.chain_conf.consensus_parameters.chain_id;
+
+    for height in (*replayer.genesis_height + 1)..=*to {
+        let height = BlockHeight::from(height);
+        let sealed_block = replayer.sealed_block(&height)?;
+        let messages = replayer.spent_messages(&sealed_block.entity)?;
+
+        let (result, db_transaction) =
+            match replayer.execute(&replayer.state, &sealed_block.entity, messages) {
+                Ok(result) => result,
+                Err(error) => return Ok(Some(Divergence::InvalidBlock { height, error })),
+            };
+
+        if height >= from {
+            for tx in sealed_block.entity.transactions() {
+                let tx_id = tx.id(chain_id);
+                let expected = receipts(database, &tx_id)?;
+                let actual = receipts(db_transaction.as_ref(), &tx_id)?;
+                if expected != actual {
+                    return Ok(Some(Divergence::Receipts {
+                        height,
+                        tx_id,
+                        expected,
+                        actual,
+                    }))
+                }
+            }
+        }
+
+        replayer.commit(sealed_block, result, db_transaction)?;
+    }
+
+    Ok(None)
+}
+
+/// Rebuilds the state of the historical blocks of the `database` in memory.
+pub(crate) struct Replayer<'a> {
+    database: &'a Database,
+    /// The state after the latest committed block.
+    pub state: Database,
+    importer: Importer<Database, (), ()>,
+    executor_config: Arc<fuel_core_executor::Config>,
+    options: ExecutionOptions,
+    pub genesis_height: BlockHeight,
+}
+
+impl<'a> Replayer<'a> {
+    /// Creates the state of the genesis block. The `database` must have
+    /// the blocks up to the `to` height.
+    pub fn new(
+        database: &'a Database,
+        config: &Config,
+        to: BlockHeight,
+    ) -> anyhow::Result<Self> {
+        let latest_height = database.latest_height()?;
+        anyhow::ensure!(
+            to <= latest_height,
+            "The database has blocks only up to the height {latest_height}"
+        );
+
+        let state = Database::in_memory();
+        maybe_initialize_state(config, &state)?;
+        let (genesis_height, genesis_id) = state.ids_of_genesis_block()?;
+        anyhow::ensure!(
+            database.ids_of_genesis_block()? == (genesis_height, genesis_id),
+            "The chain config doesn't match the genesis block of the database"
+        );
+
+        let importer =
+            Importer::new(config.block_importer.clone(), state.clone(), (), ());
+        let (executor_config, options) = executor_config(config);
+        Ok(Self {
+            database,
+            state,
+            importer,
+            executor_config,
+            options,
+            genesis_height,
+        })
+    }
+
+    /// Returns the stored block at the `height`.
+    pub fn sealed_block(&self, height: &BlockHeight) -> anyhow::Result<SealedBlock> {
+        self.database
+            .get_sealed_block_by_height(height)?
+            .ok_or_else(|| anyhow::anyhow!("The block at height {height} is missing"))
+    }
+
+    /// Collects the messages spent by the `block` on top of the current state.
+    pub fn spent_messages(
+        &self,
+        block: &Block,
+    ) -> anyhow::Result<HashMap<Nonce, Message>> {
+        spent_messages(self.database, &self.state, block)
+    }
+
+    /// Executes the `block` on top of the `state` without committing the result.
+    /// Returns the description of the error if the block is invalid.
+    pub fn execute(
+        &self,
+        state: &Database,
+        block: &Block,
+        messages: HashMap<Nonce, Message>,
+    ) -> Result<(ExecutionResult, StorageTransaction<Database>), String> {
+        execute_block(
+            state,
+            block,
+            messages,
+            self.executor_config.clone(),
+            self.options,
+        )
+    }
+
+    /// Commits the result of the execution into the state.
+    pub fn commit(
+        &self,
+        sealed_block: SealedBlock,
+        result: ExecutionResult,
+        db_transaction: StorageTransaction<Database>,
+    ) -> anyhow::Result<()> {
+        self.importer.commit_result(UncommittedResult::new(
+            ImportResult::new_from_network(sealed_block, result.tx_status),
+            db_transaction,
+        ))?;
+        Ok(())
+    }
+}
+
+/// Returns the configuration of the executor used by the node with the `config`.
+pub(crate) fn executor_config(
+    config: &Config,
+) -> (Arc<fuel_core_executor::Config>, ExecutionOptions) {
+    let executor_config = Arc::new(fuel_core_executor::Config {
+        consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+        coinbase_recipient: config.block_producer.coinbase_recipient,
+        fee_shares: config.chain_conf.fee_shares.clone(),
+        block_limits: config.chain_conf.block_limits(),
+        backtrace: config.vm.backtrace,
+        utxo_validation_default: config.utxo_validation,
+    });
+    let options = ExecutionOptions {
+        utxo_validation: config.utxo_validation,
+    };
+    (executor_config, options)
+}
+
+/// Validates the `block` on top of the `state` with the spent `messages` provided
+/// by the relayer. Returns the description of the error if the block is invalid.
+pub(crate) fn execute_block(
+    state: &Database,
+    block: &Block,
+    messages: HashMap<Nonce, Message>,
+    config: Arc<fuel_core_executor::Config>,
+    options: ExecutionOptions,
+) -> Result<(ExecutionResult, StorageTransaction<Database>), String> {
+    let executor = Executor {
+        database: state.clone(),
+        relayer: ReplayRelayer {
+            messages: Arc::new(messages),
+        },
+        config,
+        execution_stats: None,
+    };
+    let (result, db_transaction) = executor
+        .execute_without_commit::<OnceTransactionsSource>(
+            ExecutionTypes::Validation(block.clone()),
+            options,
+        )
+        .map_err(|error| error.to_string())?
+        .into();
+    if let Some((tx_id, error)) = result.skipped_transactions.first() {
+        return Err(format!("The transaction {tx_id:#x} was skipped: {error}"))
+    }
+    Ok((result, db_transaction))
+}
+
+pub(crate) fn receipts(
+    database: &Database,
+    tx_id: &TxId,
+) -> anyhow::Result<Vec<Receipt>> {
+    Ok(StorageInspect::<Receipts>::get(database, tx_id)?
+        .map(|receipts| receipts.into_owned())
+        .unwrap_or_default())
+}
+
+/// Collects the messages spent by the `block`. The spent messages are removed from
+/// the database, so they are restored from the inputs of the block and the deposits
+/// recorded by the relayer.
+fn spent_messages(
+    database: &Database,
+    state: &Database,
+    block: &Block,
+) -> anyhow::Result<HashMap<Nonce, Message>> {
+    let mut messages = HashMap::new();
+    for tx in block.transactions() {
+        let inputs = match tx {
+            Transaction::Script(script) => script.inputs(),
+            Transaction::Create(create) => create.inputs(),
+            Transaction::Mint(_) => continue,
+        };
+        for input in inputs {
+            let (sender, recipient, amount, nonce) = match input {
+                Input::MessageCoinSigned(MessageCoinSigned {
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    ..
+                })
+                | Input::MessageCoinPredicate(MessageCoinPredicate {
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    ..
+                })
+                | Input::MessageDataSigned(MessageDataSigned {
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    ..
+                })
+                | Input::MessageDataPredicate(MessageDataPredicate {
+                    sender,
+                    recipient,
+                    amount,
+                    nonce,
+                    ..
+                }) => (sender, recipient, amount, nonce),
+                _ => continue,
+            };
+
+            // The messages from the genesis are in the rebuilt state,
+            // the not spent messages are still in the database.
+            let message = match state.storage::<Messages>().get(nonce)? {
+                Some(message) => Some(message.into_owned()),
+                None => database
+                    .storage::<Messages>()
+                    .get(nonce)?
+                    .map(|message| message.into_owned()),
+            };
+            let message = match message {
+                Some(message) => message,
+                None => {
+                    let Some(deposit) =
+                        database.storage::<MessageDeposits>().get(nonce)?
+                    else {
+                        // The executor reports the missing message.
+                        continue
+                    };
+                    Message {
+                        sender: *sender,
+                        recipient: *recipient,
+                        nonce: *nonce,
+                        amount: *amount,
+                        data: input.input_data().map(<[u8]>::to_vec).unwrap_or_default(),
+                        da_height: deposit.da_height,
+                    }
+                }
+            };
+            messages.insert(*nonce, message);
+        }
+    }
+    Ok(messages)
+}
+
+/// Provides the messages spent by the replayed block.
+#[derive(Clone)]
+struct ReplayRelayer {
+    messages: Arc<HashMap<Nonce, Message>>,
+}
+
+impl RelayerPort for ReplayRelayer {
+    fn get_message(
+        &self,
+        id: &Nonce,
+        _da_height: &DaBlockHeight,
+    ) -> anyhow::Result<Option<Message>> {
+        Ok(self.messages.get(id).cloned())
+    }
+
+    // The replayed block was accepted by the network, so its messages weren't cancelled.
+    fn is_message_cancelled(&self, _: &Nonce, _: &DaBlockHeight) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,263 @@
+//! Verifies the critical invariants of the database before the node starts its services,
+//! so a corrupted data directory is caught before the node serves the traffic.
+//!
+//! The `fast` checks verify only the latest block: its header matches the block id,
+//! the root of its transactions and the Merkle root of the previous blocks.
+//! The `full` checks verify every block from the genesis and that the finalized
+//! DA height of the relayer covers all stored messages.
+//!
+//! The `TxPool` keeps the transactions only in memory, so there is nothing
+//! persisted to verify for it.
+
+use crate::{
+    database::{
+        storage::FuelBlockMerkleMetadata,
+        Database,
+    },
+    service::{
+        Config,
+        StartupChecks,
+    },
+};
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_types::{
+        canonical::Serialize,
+        BlockHeight,
+        Bytes32,
+    },
+};
+use std::time::Instant;
+
+/// Runs the checks selected by the [`Config::startup_checks`].
+/// Returns an error describing the first violated invariant.
+pub fn run(database: &Database, config: &Config) -> anyhow::Result<()> {
+    let checks = config.startup_checks;
+    if checks == StartupChecks::Off {
+        return Ok(())
+    }
+    let start = Instant::now();
+
+    let Some((latest, _)) = database.ids_of_latest_block()? else {
+        return Ok(())
+    };
+    let (genesis, _) = database.ids_of_genesis_block()?;
+    let first = match checks {
+        StartupChecks::Full => genesis,
+        _ => latest,
+    };
+
+    let mut expected_height = first;
+    for ids in database.all_block_ids(Some(first), IterDirection::Forward) {
+        let (height, id) = ids?;
+        ensure!(
+            height == expected_height,
+            "The block {expected_height} is missing from the heights index"
+        );
+        check_block(database, genesis, height, &id)?;
+        expected_height = (*height).saturating_add(1).into();
+    }
+
+    #[cfg(feature = "relayer")]
+    if checks == StartupChecks::Full && config.relayer.is_some() {
+        check_relayer_messages(database)?;
+    }
+
+    tracing::info!(
+        "The {checks} startup checks passed in {:?} up to the block {latest}",
+        start.elapsed()
+    );
+    Ok(())
+}
+
+/// Verifies the block at the `height` with the `id` from the heights index.
+fn check_block(
+    database: &Database,
+    genesis: BlockHeight,
+    height: BlockHeight,
+    id: &BlockId,
+) -> anyhow::Result<()> {
+    let block = database
+        .storage::<FuelBlocks>()
+        .get(id)?
+        .ok_or_else(|| anyhow!("The block {height} with the id {id} is missing"))?;
+    let header = block.header();
+    ensure!(
+        *header.height() == height,
+        "The block {id} is indexed at the height {height}, but has the height {}",
+        header.height()
+    );
+    ensure!(
+        header.consensus.application_hash == header.application.hash(),
+        "The application hash of the block {height} doesn't match its header"
+    );
+    ensure!(
+        header.consensus.hash() == *id,
+        "The header of the block {height} doesn't match its id {id}"
+    );
+    check_transactions(database, &block)?;
+
+    let metadata = database
+        .storage::<FuelBlockMerkleMetadata>()
+        .get(&height)?
+        .ok_or_else(|| anyhow!("The Merkle root of the block {height} is missing"))?;
+    let expected_version = u64::from(*height) - u64::from(*genesis) + 1;
+    ensure!(
+        metadata.version == expected_version,
+        "The Merkle tree of the blocks has {} leaves at the block {height}, \
+        but {expected_version} are expected",
+        metadata.version
+    );
+    if height > genesis {
+        let prev_height: BlockHeight = (*height - 1).into();
+        let prev_root = database
+            .storage::<FuelBlockMerkleMetadata>()
+            .get(&prev_height)?
+            .ok_or_else(|| {
+                anyhow!("The Merkle root of the block {prev_height} is missing")
+            })?
+            .root;
+        ensure!(
+            *header.prev_root() == Bytes32::from(prev_root),
+            "The previous root of the block {height} doesn't match the stored \
+            Merkle root of the block {prev_height}"
+        );
+    }
+    Ok(())
+}
+
+/// Verifies that all transactions of the `block` are stored and match its header.
+fn check_transactions(
+    database: &Database,
+    block: &CompressedBlock,
+) -> anyhow::Result<()> {
+    let header = block.header();
+    let height = header.height();
+    let tx_ids = block.transactions();
+    ensure!(
+        tx_ids.len() as u64 == header.application.transactions_count,
+        "The block {height} has {} transactions, but the header counts {}",
+        tx_ids.len(),
+        header.application.transactions_count
+    );
+
+    let mut tree = MerkleTree::new();
+    for tx_id in tx_ids {
+        let tx = database
+            .storage::<Transactions>()
+            .get(tx_id)?
+            .ok_or_else(|| {
+                anyhow!("The transaction {tx_id} of the block {height} is missing")
+            })?;
+        tree.push(tx.into_owned().to_bytes().as_ref());
+    }
+    ensure!(
+        Bytes32::from(tree.root()) == header.application.transactions_root,
+        "The transactions of the block {height} don't match its transactions root"
+    );
+    Ok(())
+}
+
+/// Verifies that the relayer finalized the DA heights of all stored messages.
+/// The relayer that never synced has nothing to compare with.
+#[cfg(feature = "relayer")]
+fn check_relayer_messages(database: &Database) -> anyhow::Result<()> {
+    use fuel_core_relayer::ports::RelayerDb;
+
+    let finalized = database.get_finalized_da_height()?;
+    if finalized == Default::default() {
+        return Ok(())
+    }
+    for message in database.all_messages(None, None) {
+        let message = message?;
+        ensure!(
+            message.da_height <= finalized,
+            "The message {} has the DA height {}, above the finalized DA height {} \
+            of the relayer",
+            message.nonce,
+            message.da_height,
+            finalized
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        blockchain::block::Block,
+        fuel_tx::{
+            Transaction,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+
+    fn config(startup_checks: StartupChecks) -> Config {
+        Config {
+            startup_checks,
+            ..Config::local_node()
+        }
+    }
+
+    #[test]
+    fn empty_database_passes_the_checks() {
+        let database = Database::default();
+
+        run(&database, &config(StartupChecks::Full)).unwrap();
+    }
+
+    #[test]
+    fn block_with_the_wrong_id_fails_the_checks() {
+        let mut database = Database::default();
+        let block = Block::new(Default::default(), vec![], &[]);
+        let id = BlockId::from([1; 32]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&id, &block.compress(&ChainId::default()))
+            .unwrap();
+
+        run(&database, &config(StartupChecks::Off)).unwrap();
+        let error = run(&database, &config(StartupChecks::Fast)).unwrap_err();
+        assert!(error.to_string().contains("doesn't match its id"));
+    }
+
+    #[test]
+    fn missing_transaction_fails_the_checks() {
+        let mut database = Database::default();
+        let chain_id = ChainId::default();
+        let tx = Transaction::default_test_tx();
+        let block = Block::new(Default::default(), vec![tx.clone()], &[]);
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block.compress(&chain_id))
+            .unwrap();
+
+        let error = run(&database, &config(StartupChecks::Fast)).unwrap_err();
+        assert!(error.to_string().contains("is missing"));
+
+        database
+            .storage::<Transactions>()
+            .insert(&tx.id(&chain_id), &tx)
+            .unwrap();
+        run(&database, &config(StartupChecks::Full)).unwrap();
+    }
+}
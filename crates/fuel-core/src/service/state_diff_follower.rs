@@ -0,0 +1,175 @@
+//! Follows the trusted upstream node by applying the state diffs of its blocks without
+//! the execution. The upstream serves the diffs recorded by its block importer via the
+//! admin API, so the follower uses the admin token of the upstream.
+//!
+//! The follower must start from the same genesis as the upstream and shouldn't produce
+//! or import the blocks itself, otherwise its state diverges from the upstream.
+//!
+//! The applied blocks are committed by the block importer, so its subscribers are
+//! notified about them. The statuses of the transactions are not part of the diff,
+//! so the notifications don't contain them.
+
+use crate::{
+    database::{
+        state_diff::StateDiff,
+        Database,
+    },
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::transactional::Transactional;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    secrecy::{
+        ExposeSecret,
+        Secret,
+    },
+    services::block_importer::{
+        ImportResult,
+        UncommittedResult as UncommittedImportResult,
+    },
+};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The URL of the admin API of the upstream node.
+    pub url: String,
+    /// The admin API token of the upstream node.
+    pub token: Secret<String>,
+    /// How long to wait before requesting the next diff if the upstream doesn't have it yet.
+    pub poll_interval: Duration,
+}
+
+pub type Service = ServiceRunner<Task>;
+
+pub struct Task {
+    database: Database,
+    block_importer: BlockImporterAdapter,
+    client: reqwest::Client,
+    config: Config,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "StateDiffFollower";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Task {
+    /// Requests the state diff at the `height`. Returns `None` if the upstream
+    /// doesn't have it yet.
+    async fn fetch(&self, height: BlockHeight) -> anyhow::Result<Option<StateDiff>> {
+        let response = self
+            .client
+            .get(format!(
+                "{}/state-diffs/{}",
+                self.config.url.trim_end_matches('/'),
+                *height
+            ))
+            .bearer_auth(self.config.token.expose_secret())
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None)
+        }
+        let bytes = response.error_for_status()?.bytes().await?;
+        Ok(Some(postcard::from_bytes(&bytes)?))
+    }
+
+    /// Applies the next state diff of the upstream.
+    /// Returns `false` if the upstream doesn't have it yet.
+    async fn follow_next_block(&self) -> anyhow::Result<bool> {
+        let next: BlockHeight = u32::from(self.database.latest_height()?)
+            .checked_add(1)
+            .ok_or_else(|| anyhow::anyhow!("The height overflowed"))?
+            .into();
+        let Some(diff) = self.fetch(next).await? else {
+            return Ok(false)
+        };
+        anyhow::ensure!(
+            diff.height == next,
+            "The upstream returned the diff at height {} instead of {next}",
+            diff.height
+        );
+
+        let database = self.database.clone();
+        let block_importer = self.block_importer.clone();
+        tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+            let mut db_tx = Transactional::transaction(&database);
+            db_tx.as_mut().apply_state_diff(&diff)?;
+            let sealed_block = db_tx
+                .as_mut()
+                .get_sealed_block_by_height(&next)?
+                .ok_or_else(|| {
+                    anyhow::anyhow!("The state diff at height {next} has no block")
+                })?;
+            block_importer.block_importer.commit_applied_result(
+                UncommittedImportResult::new(
+                    ImportResult::new_from_network(sealed_block, vec![]),
+                    db_tx,
+                ),
+            )?;
+            Ok(())
+        })
+        .await??;
+        tracing::debug!("Applied the state diff at height {next}");
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        match self.follow_next_block().await {
+            // Continue to catch up without waiting.
+            Ok(true) => return Ok(true),
+            Ok(false) => {}
+            // The upstream may be restarting, so retry after the interval.
+            Err(e) => tracing::error!("Failed to follow the upstream: {e}"),
+        }
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            _ = tokio::time::sleep(self.config.poll_interval) => Ok(true),
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    block_importer: BlockImporterAdapter,
+    config: Config,
+) -> Service {
+    ServiceRunner::new(Task {
+        database,
+        block_importer,
+        client: reqwest::Client::new(),
+        config,
+    })
+}
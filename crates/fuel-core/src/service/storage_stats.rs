@@ -0,0 +1,210 @@
+//! Periodically refreshes the approximate size, the number of keys and the write rate
+//! of each database table, so the operators can see which tables take the disk space.
+
+use crate::database::{
+    Column,
+    Database,
+};
+use fuel_core_metrics::storage_metrics::storage_metrics;
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::services::graphql_api::TableStats;
+use parking_lot::RwLock;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use strum::EnumCount;
+use tokio::time::{
+    Instant,
+    Interval,
+};
+
+pub type Service = ServiceRunner<NotInitializedTask>;
+
+/// The statistics of the tables from the latest refresh.
+#[derive(Clone, Default)]
+pub struct SharedState {
+    tables: Arc<RwLock<Vec<TableStats>>>,
+}
+
+impl SharedState {
+    /// Returns the statistics of all tables. It is empty before the first refresh.
+    pub fn table_stats(&self) -> Vec<TableStats> {
+        self.tables.read().clone()
+    }
+}
+
+pub struct NotInitializedTask {
+    database: Database,
+    interval: Duration,
+    shared: SharedState,
+}
+
+pub struct Task {
+    database: Database,
+    shared: SharedState,
+    refresh_interval: Interval,
+    /// The number of bytes written into each column at the previous refresh.
+    bytes_written: [u64; Column::COUNT],
+    last_refresh: Instant,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for NotInitializedTask {
+    const NAME: &'static str = "StorageStats";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            database,
+            interval,
+            shared,
+        } = self;
+        let mut bytes_written = [0; Column::COUNT];
+        for column in enum_iterator::all::<Column>() {
+            bytes_written[column.as_usize()] =
+                storage_metrics().bytes_written(column.name());
+        }
+        Ok(Task {
+            database,
+            shared,
+            refresh_interval: tokio::time::interval(interval),
+            bytes_written,
+            last_refresh: Instant::now(),
+        })
+    }
+}
+
+impl Task {
+    fn refresh(&mut self) -> anyhow::Result<()> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refresh);
+        let mut tables = Vec::with_capacity(Column::COUNT);
+        for column in enum_iterator::all::<Column>() {
+            let table = column.name();
+            let stats = self.database.column_stats(column)?;
+            let bytes_written = storage_metrics().bytes_written(table);
+            let previous = core::mem::replace(
+                &mut self.bytes_written[column.as_usize()],
+                bytes_written,
+            );
+            let write_rate = write_rate(bytes_written.saturating_sub(previous), elapsed);
+            storage_metrics().set_table_stats(table, stats.size, stats.keys, write_rate);
+            tables.push(TableStats {
+                table: table.to_string(),
+                size: stats.size,
+                keys: stats.keys,
+                write_rate,
+            });
+        }
+        *self.shared.tables.write() = tables;
+        self.last_refresh = now;
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes written per second.
+fn write_rate(bytes_written: u64, elapsed: Duration) -> u64 {
+    if elapsed.is_zero() {
+        0
+    } else {
+        (bytes_written as f64 / elapsed.as_secs_f64()) as u64
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            _ = self.refresh_interval.tick() => {
+                self.refresh()?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(database: Database, interval: Duration) -> Service {
+    ServiceRunner::new(NotInitializedTask {
+        database,
+        interval,
+        shared: SharedState::default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::Service as _;
+    use fuel_core_storage::{
+        tables::Coins,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        entities::coins::coin::CompressedCoin,
+        fuel_tx::UtxoId,
+    };
+
+    #[test]
+    fn write_rate_is_bytes_per_second() {
+        assert_eq!(write_rate(1000, Duration::from_secs(2)), 500);
+        assert_eq!(write_rate(1000, Duration::from_millis(500)), 2000);
+        assert_eq!(write_rate(1000, Duration::ZERO), 0);
+    }
+
+    #[tokio::test]
+    async fn refresh_reports_stats_of_all_tables() {
+        let mut database = Database::default();
+        for i in 0..3 {
+            let coin = CompressedCoin {
+                owner: Default::default(),
+                amount: i as u64,
+                asset_id: Default::default(),
+                maturity: Default::default(),
+                tx_pointer: Default::default(),
+            };
+            database
+                .storage::<Coins>()
+                .insert(&UtxoId::new([i; 32].into(), 0), &coin)
+                .unwrap();
+        }
+        let service = new_service(database, Duration::from_secs(60));
+
+        service.start_and_await().await.unwrap();
+        // The first tick of the interval refreshes the stats immediately.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let tables = service.shared.table_stats();
+
+        assert_eq!(tables.len(), Column::COUNT);
+        let coins = tables
+            .iter()
+            .find(|stats| stats.table == Column::Coins.as_ref())
+            .unwrap();
+        assert_eq!(coins.keys, 3);
+        assert!(coins.size > 0);
+        service.stop_and_await().await.unwrap();
+    }
+}
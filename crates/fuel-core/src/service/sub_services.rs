@@ -3,24 +3,32 @@ use super::adapters::P2PAdapter;
 
 use crate::{
     database::Database,
+    executor::execution_stats::ExecutionStats,
     fuel_core_graphql_api::Config as GraphQLConfig,
     schema::build_schema,
     service::{
         adapters::{
+            BlobBaseFeeAdapter,
             BlockImporterAdapter,
             BlockProducerAdapter,
+            ChainClockAdapter,
             ExecutorAdapter,
+            GasPriceAdapter,
             MaybeRelayerAdapter,
             PoAAdapter,
+            SyncStatusAdapter,
             TxPoolAdapter,
             VerifierAdapter,
         },
+        config::NodeMode,
         Config,
         SharedState,
         SubServices,
     },
 };
+use fuel_core_gas_price_service::da_source::block_committer::BlockCommitterHttpApi;
 use fuel_core_poa::Trigger;
+use fuel_core_services::ResourceBudget;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -33,9 +41,15 @@ pub type PoAService =
     fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
 #[cfg(feature = "relayer")]
 pub type RelayerService = fuel_core_relayer::Service<Database>;
-#[cfg(feature = "p2p")]
-pub type P2PService = fuel_core_p2p::service::Service<Database>;
+#[cfg(all(feature = "p2p", not(feature = "fault-injection")))]
+pub type P2PService = fuel_core_p2p::service::Service<Database, TxPoolAdapter>;
+#[cfg(feature = "fault-injection")]
+pub type P2PService = fuel_core_p2p::service::Service<
+    crate::service::fault_injection::FaultInjector<Database>,
+    TxPoolAdapter,
+>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
+pub type GasPriceService = fuel_core_gas_price_service::service::Service<Database>;
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
     TxPoolAdapter,
@@ -50,6 +64,13 @@ pub fn init_sub_services(
     let last_block = database.get_current_block()?.ok_or(anyhow::anyhow!(
         "The blockchain is not initialized with any block"
     ))?;
+    let resource_budget = ResourceBudget::new(config.resource_limits);
+    let read_replica = config.mode == NodeMode::ReadReplica;
+    if read_replica && config.state_diff_upstream.is_none() {
+        return Err(anyhow::anyhow!(
+            "The read replica requires the upstream node to follow"
+        ))
+    }
     #[cfg(feature = "relayer")]
     let relayer_service = if let Some(config) = &config.relayer {
         Some(fuel_core_relayer::new_service(
@@ -59,6 +80,14 @@ pub fn init_sub_services(
     } else {
         None
     };
+    #[cfg(feature = "relayer")]
+    let origin_relayer_services = config
+        .origin_relayers
+        .iter()
+        .map(|config| {
+            fuel_core_relayer::new_origin_service(database.clone(), config.clone())
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     let relayer_adapter = MaybeRelayerAdapter {
         database: database.clone(),
@@ -71,14 +100,19 @@ pub fn init_sub_services(
         ),
     };
 
+    let execution_stats = ExecutionStats::default();
     let executor = ExecutorAdapter {
         relayer: relayer_adapter.clone(),
         config: Arc::new(fuel_core_executor::Config {
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
             coinbase_recipient: config.block_producer.coinbase_recipient,
+            fee_shares: config.chain_conf.fee_shares.clone(),
+            block_limits: config.chain_conf.block_limits(),
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
         }),
+        execution_stats: execution_stats.clone(),
+        resource_budget: resource_budget.clone(),
     };
 
     let verifier =
@@ -91,18 +125,45 @@ pub fn init_sub_services(
         verifier.clone(),
     );
 
+    #[cfg(feature = "relayer")]
+    let block_committer = match (&config.relayer, &config.block_committer) {
+        (Some(relayer), Some(committer)) => {
+            Some(fuel_core_relayer::committer::new_service(
+                relayer,
+                committer.clone(),
+                importer_adapter.clone(),
+            )?)
+        }
+        (None, Some(_)) => {
+            return Err(anyhow::anyhow!(
+                "The block committer requires the relayer to be enabled"
+            ))
+        }
+        _ => None,
+    };
+
+    #[cfg(feature = "relayer")]
+    let message_latency = relayer_service
+        .as_ref()
+        .and_then(|relayer| relayer.shared.message_latencies())
+        .map(|latencies| {
+            fuel_core_relayer::latency::new_service(latencies, importer_adapter.clone())
+        });
+
+    // The P2P service serves the pool of the `TxPool`, while the `TxPool` requests the
+    // transactions via the P2P. So the shared state of the P2P is created before
+    // the `TxPool`, and the service itself after it.
     #[cfg(feature = "p2p")]
-    let mut network = {
+    let network_state = {
+        let chain_id = config.chain_conf.consensus_parameters.chain_id;
         if let Some(config) = config.p2p.clone() {
-            let p2p_db = database.clone();
-            let genesis = p2p_db.get_genesis()?;
-            let p2p_config = config.init(genesis)?;
+            let genesis = database.get_genesis()?;
+            let (_, genesis_block_id) = database.ids_of_genesis_block()?;
+            let p2p_config = config.init(genesis, chain_id, genesis_block_id)?;
+            let (shared_state, request_receiver) =
+                fuel_core_p2p::service::build_shared_state(&p2p_config);
 
-            Some(fuel_core_p2p::service::new_service(
-                p2p_config,
-                p2p_db,
-                importer_adapter.clone(),
-            ))
+            Some((p2p_config, shared_state, request_receiver))
         } else {
             None
         }
@@ -122,7 +183,9 @@ pub fn init_sub_services(
             invalid_transactions: -100.,
         };
         P2PAdapter::new(
-            network.as_ref().map(|network| network.shared.clone()),
+            network_state
+                .as_ref()
+                .map(|(_, shared_state, _)| shared_state.clone()),
             peer_report_config,
         )
     };
@@ -131,29 +194,185 @@ pub fn init_sub_services(
     let p2p_adapter = P2PAdapter::new();
 
     let p2p_adapter = p2p_adapter;
+    #[cfg(feature = "fault-injection")]
+    let p2p_adapter = p2p_adapter.with_gossip_delay(config.fault_injection.gossip_delay);
+
+    #[cfg(feature = "relayer")]
+    let blob_fee_source = match (&config.relayer, &config.gas_price.blob_fee) {
+        (Some(relayer), Some(_)) => Some(BlobBaseFeeAdapter::new(
+            fuel_core_relayer::blob_fee::new_blob_base_fee(relayer)?,
+            database.clone(),
+        )),
+        (None, Some(_)) => {
+            return Err(anyhow::anyhow!(
+                "The blob fee tracking requires the relayer to be enabled"
+            ))
+        }
+        _ => None,
+    };
+    #[cfg(not(feature = "relayer"))]
+    let blob_fee_source: Option<BlobBaseFeeAdapter> = match &config.gas_price.blob_fee {
+        Some(_) => {
+            return Err(anyhow::anyhow!(
+                "The blob fee tracking requires the relayer to be enabled"
+            ))
+        }
+        None => None,
+    };
+
+    let gas_price = fuel_core_gas_price_service::service::new_service(
+        config.gas_price.clone(),
+        *last_block.header().height(),
+        importer_adapter.clone(),
+        database.clone(),
+        config
+            .gas_price
+            .da_source
+            .as_ref()
+            .map(|da_source| BlockCommitterHttpApi::new(da_source.url.clone())),
+        blob_fee_source,
+    )?;
+    let gas_price_adapter = GasPriceAdapter::new(gas_price.shared.clone());
 
+    let mut txpool_config = config.txpool.clone();
+    txpool_config.resource_budget = resource_budget.clone();
+    if config.gas_price.enforce_gas_price {
+        txpool_config.gas_price_provider = Some(Arc::new(gas_price_adapter.clone()));
+    }
     let txpool = fuel_core_txpool::new_service(
-        config.txpool.clone(),
+        txpool_config,
         database.clone(),
         importer_adapter.clone(),
         p2p_adapter.clone(),
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
+    #[cfg(feature = "p2p")]
+    let mut network =
+        network_state.map(|(p2p_config, shared_state, request_receiver)| {
+            #[cfg(not(feature = "fault-injection"))]
+            let (p2p_database, p2p_importer) =
+                (database.clone(), importer_adapter.clone());
+            #[cfg(feature = "fault-injection")]
+            let (p2p_database, p2p_importer) = {
+                use crate::service::fault_injection::FaultInjector;
+                let faults = &config.fault_injection;
+                let key = &config.consensus_key;
+                (
+                    FaultInjector::new(database.clone(), faults.clone(), key.clone()),
+                    FaultInjector::new(importer_adapter.clone(), faults.clone(), None),
+                )
+            };
+            fuel_core_p2p::service::new_service(
+                p2p_config,
+                shared_state,
+                request_receiver,
+                p2p_database,
+                p2p_importer,
+                tx_pool_adapter.clone(),
+            )
+        });
+
+    let disk_monitor = config
+        .disk_monitor
+        .clone()
+        .map(|disk_monitor| {
+            super::disk_monitor::new_service(disk_monitor, txpool.shared.clone())
+        })
+        .transpose()?;
+    let tx_status_archive = config
+        .tx_status_retention
+        .filter(|retention| !retention.is_zero())
+        .map(|retention| {
+            super::tx_status_archive::new_service(
+                database.clone(),
+                txpool.shared.tx_updates_subscribe(),
+                retention,
+                disk_monitor.as_ref().map(|monitor| monitor.shared.clone()),
+            )
+        });
+    let storage_stats = config
+        .storage_stats_interval
+        .filter(|interval| !interval.is_zero())
+        .map(|interval| super::storage_stats::new_service(database.clone(), interval));
+    let compaction_scheduler = config
+        .compaction_scheduler
+        .clone()
+        .map(|scheduler| {
+            super::compaction_scheduler::new_service(database.clone(), scheduler)
+        })
+        .transpose()?;
+    let asset_registry = config.asset_registry.then(|| {
+        super::asset_registry::new_service(
+            database.clone(),
+            importer_adapter.block_importer.subscribe(),
+            disk_monitor.as_ref().map(|monitor| monitor.shared.clone()),
+        )
+    });
+    let contract_calls = config.contract_calls_window.map(|window| {
+        super::contract_calls::new_service(
+            database.clone(),
+            importer_adapter.block_importer.subscribe(),
+            window,
+            disk_monitor.as_ref().map(|monitor| monitor.shared.clone()),
+        )
+    });
+    let account_watcher = config.account_watcher.then(|| {
+        super::account_watcher::new_service(
+            database.clone(),
+            importer_adapter.block_importer.subscribe(),
+            config.chain_conf.consensus_parameters.chain_id,
+        )
+    });
+    let subscription_buffer = (config.subscription_buffer_size > 0).then(|| {
+        super::subscription_buffer::new_service(
+            config.subscription_buffer_size,
+            importer_adapter.block_importer.subscribe(),
+            txpool.shared.tx_updates_subscribe(),
+        )
+    });
+    #[cfg(feature = "event-bus")]
+    let event_bus = config
+        .event_bus
+        .clone()
+        .map(|event_bus| {
+            super::event_bus::new_service(
+                event_bus,
+                database.clone(),
+                importer_adapter.block_importer.subscribe(),
+                txpool.shared.tx_updates_subscribe(),
+            )
+        })
+        .transpose()?;
+    let state_diff_follower = config.state_diff_upstream.clone().map(|upstream| {
+        super::state_diff_follower::new_service(
+            database.clone(),
+            importer_adapter.clone(),
+            upstream,
+        )
+    });
+
+    let chain_clock = ChainClockAdapter::new(database.clone())?;
     let block_producer = fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         db: database.clone(),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
         relayer: Box::new(relayer_adapter),
+        gas_price_provider: config
+            .gas_price
+            .enforce_gas_price
+            .then(|| Box::new(gas_price_adapter.clone()) as _),
+        clock: Box::new(chain_clock.clone()),
         lock: Mutex::new(()),
     };
     let producer_adapter = BlockProducerAdapter::new(block_producer);
 
-    let poa_config: fuel_core_poa::Config = config.into();
+    let mut poa_config: fuel_core_poa::Config = config.into();
+    poa_config.clock = Some(Arc::new(chain_clock));
     let mut production_enabled = !matches!(poa_config.trigger, Trigger::Never);
 
-    if !production_enabled && config.debug {
+    if !production_enabled && config.debug && !read_replica {
         production_enabled = true;
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
@@ -179,6 +398,20 @@ pub fn init_sub_services(
         config.sync,
     )?;
 
+    #[cfg(feature = "relayer")]
+    let block_committer_port =
+        match (&block_committer, &config.relayer, &config.commitment_reader) {
+            (Some(committer), _, _) => Some(Box::new(committer.shared.clone())
+                as crate::fuel_core_graphql_api::service::BlockCommitter),
+            (None, Some(relayer), Some(reader)) => Some(Box::new(
+                fuel_core_relayer::committer::new_reader(relayer, reader)?,
+            )
+                as crate::fuel_core_graphql_api::service::BlockCommitter),
+            _ => None,
+        };
+    #[cfg(not(feature = "relayer"))]
+    let block_committer_port = None;
+
     // TODO: Figure out on how to move it into `fuel-core-graphql-api`.
     let schema = crate::schema::dap::init(
         build_schema(),
@@ -187,6 +420,61 @@ pub fn init_sub_services(
     )
     .data(database.clone());
 
+    #[cfg(feature = "grpc")]
+    let grpc = config
+        .grpc_addr
+        .map(|addr| {
+            crate::grpc_api::service::new_service(
+                crate::grpc_api::Config {
+                    addr,
+                    chain_id: config.chain_conf.consensus_parameters.chain_id,
+                    read_only: read_replica,
+                },
+                Box::new(database.clone()),
+                Box::new(tx_pool_adapter.clone()),
+            )
+        })
+        .transpose()?;
+
+    let sync_status_adapter = SyncStatusAdapter {
+        database: database.clone(),
+        // The sync service runs only with the P2P network.
+        #[cfg(feature = "p2p")]
+        sync: network.as_ref().map(|_| sync.shared.clone()),
+        #[cfg(feature = "relayer")]
+        relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
+        #[cfg(feature = "relayer")]
+        block_committer: block_committer.as_ref().map(|c| c.shared.clone()),
+    };
+
+    // The frontend serves the subscriptions from the events of the executing node.
+    let graphql_tx_pool: crate::fuel_core_graphql_api::service::TxPool =
+        Box::new(tx_pool_adapter.clone());
+    let graphql_importer: crate::fuel_core_graphql_api::service::BlockImporter =
+        Box::new(importer_adapter.clone());
+    #[cfg(feature = "event-bus")]
+    let (graphql_tx_pool, graphql_importer) = match &event_bus {
+        Some(super::event_bus::Service::Subscriber(subscriber)) => (
+            Box::new(crate::service::adapters::EventBusTxPoolAdapter {
+                txpool: tx_pool_adapter.clone(),
+                events: subscriber.shared.clone(),
+            }) as crate::fuel_core_graphql_api::service::TxPool,
+            Box::new(subscriber.shared.clone())
+                as crate::fuel_core_graphql_api::service::BlockImporter,
+        ),
+        _ => (graphql_tx_pool, graphql_importer),
+    };
+
+    // The P2P identity key signs the information about the node for the clients.
+    #[cfg(feature = "p2p")]
+    let node_key = config
+        .p2p
+        .as_ref()
+        .and_then(|p2p| p2p.identity_secret_key())
+        .map(|key| fuel_core_types::secrecy::Secret::new(key.into()));
+    #[cfg(not(feature = "p2p"))]
+    let node_key = None;
+
     let graph_ql = crate::fuel_core_graphql_api::service::new_service(
         GraphQLConfig {
             addr: config.addr,
@@ -198,24 +486,66 @@ pub fn init_sub_services(
             max_depth: config.txpool.max_depth,
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
             consensus_key: config.consensus_key.clone(),
+            node_key,
+            message_proof_cache_size: config.message_proof_cache_size,
+            read_only: read_replica,
+            block_production: production_enabled,
+            query_stats: config.query_stats,
+            contract_calls_window: config.contract_calls_window,
+            persisted_queries: config.persisted_queries.clone(),
+            resource_budget: resource_budget.clone(),
         },
         schema,
+        // The queries of the API take the iterators from the budget, while the import
+        // and the precomputation of the message trees are never rejected.
+        Box::new(
+            database
+                .clone()
+                .with_resource_budget(resource_budget.clone()),
+        ),
         Box::new(database.clone()),
-        Box::new(tx_pool_adapter),
+        graphql_tx_pool,
         Box::new(producer_adapter),
         Box::new(poa_adapter),
+        Box::new(gas_price_adapter),
+        graphql_importer,
+        block_committer_port,
+        storage_stats.as_ref().map(|stats| {
+            Box::new(stats.shared.clone())
+                as crate::fuel_core_graphql_api::service::StorageStats
+        }),
+        Box::new(sync_status_adapter),
+        account_watcher.as_ref().map(|watcher| {
+            Box::new(watcher.shared.clone())
+                as crate::fuel_core_graphql_api::service::AccountWatcher
+        }),
+        subscription_buffer.as_ref().map(|buffer| {
+            Box::new(buffer.shared.clone())
+                as crate::fuel_core_graphql_api::service::SubscriptionBuffer
+        }),
         config.query_log_threshold_time,
     )?;
 
     let shared = SharedState {
         txpool: txpool.shared.clone(),
+        gas_price: gas_price.shared.clone(),
+        poa: poa.as_ref().map(|poa| poa.shared.clone()),
         #[cfg(feature = "p2p")]
         network: network.as_ref().map(|n| n.shared.clone()),
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
+        #[cfg(feature = "grpc")]
+        grpc: grpc.as_ref().map(|grpc| grpc.shared.clone()),
+        storage_stats: storage_stats.as_ref().map(|stats| stats.shared.clone()),
+        disk_monitor: disk_monitor.as_ref().map(|monitor| monitor.shared.clone()),
+        compaction_scheduler: compaction_scheduler
+            .as_ref()
+            .map(|scheduler| scheduler.shared.clone()),
         database: database.clone(),
         block_importer: importer_adapter,
+        execution_stats,
+        resource_budget,
         config: config.clone(),
     };
 
@@ -224,18 +554,91 @@ pub fn init_sub_services(
     let mut services: SubServices = vec![
         // GraphQL should be shutdown first, so let's start it first.
         Box::new(graph_ql),
-        Box::new(txpool),
     ];
 
+    #[cfg(feature = "grpc")]
+    if let Some(grpc) = grpc {
+        services.push(Box::new(grpc));
+    }
+
+    // The `TxPool` of the read replica rejects the transactions and stays empty.
+    if !read_replica {
+        services.push(Box::new(txpool));
+    }
+
+    services.push(Box::new(gas_price));
+
+    if let Some(disk_monitor) = disk_monitor {
+        services.push(Box::new(disk_monitor));
+    }
+
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
 
+    if let Some(tx_status_archive) = tx_status_archive {
+        services.push(Box::new(tx_status_archive));
+    }
+
+    if let Some(storage_stats) = storage_stats {
+        services.push(Box::new(storage_stats));
+    }
+
+    if let Some(compaction_scheduler) = compaction_scheduler {
+        services.push(Box::new(compaction_scheduler));
+    }
+
+    if let Some(asset_registry) = asset_registry {
+        services.push(Box::new(asset_registry));
+    }
+
+    if let Some(contract_calls) = contract_calls {
+        services.push(Box::new(contract_calls));
+    }
+
+    if let Some(account_watcher) = account_watcher {
+        services.push(Box::new(account_watcher));
+    }
+
+    if let Some(subscription_buffer) = subscription_buffer {
+        services.push(Box::new(subscription_buffer));
+    }
+
+    if let Some(state_diff_follower) = state_diff_follower {
+        services.push(Box::new(state_diff_follower));
+    }
+
+    #[cfg(feature = "event-bus")]
+    match event_bus {
+        Some(super::event_bus::Service::Publisher(publisher)) => {
+            services.push(Box::new(publisher))
+        }
+        Some(super::event_bus::Service::Subscriber(subscriber)) => {
+            services.push(Box::new(subscriber))
+        }
+        None => {}
+    }
+
     #[cfg(feature = "relayer")]
     if let Some(relayer) = relayer_service {
         services.push(Box::new(relayer));
     }
 
+    #[cfg(feature = "relayer")]
+    for origin_relayer in origin_relayer_services {
+        services.push(Box::new(origin_relayer));
+    }
+
+    #[cfg(feature = "relayer")]
+    if let Some(block_committer) = block_committer {
+        services.push(Box::new(block_committer));
+    }
+
+    #[cfg(feature = "relayer")]
+    if let Some(message_latency) = message_latency {
+        services.push(Box::new(message_latency));
+    }
+
     #[cfg(feature = "p2p")]
     {
         if let Some(network) = network.take() {
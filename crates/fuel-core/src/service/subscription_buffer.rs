@@ -0,0 +1,324 @@
+//! Buffers the latest events of the block and the transaction status subscriptions.
+//! Each event has the cursor, and the client that lost the connection passes the cursor
+//! of the last received event to receive the events it missed in the meantime.
+//!
+//! Only the latest events are kept, so the subscription can't be resumed after
+//! a long disconnection. The buffer is empty after the restart of the node.
+
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_txpool::service::{
+    TxStatusMessage,
+    TxUpdate,
+};
+use fuel_core_types::services::{
+    block_importer::ImportResult,
+    graphql_api::{
+        SubscriptionCursor,
+        SubscriptionEvent,
+    },
+};
+use parking_lot::Mutex;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+
+/// The number of the new events buffered for the slow subscribers.
+const EVENTS_CAPACITY: usize = 1024;
+
+pub type Service = ServiceRunner<Task>;
+
+/// The stream of the events with their cursors.
+pub type Events = BoxStream<anyhow::Result<(SubscriptionCursor, SubscriptionEvent)>>;
+
+struct Buffer {
+    capacity: usize,
+    /// The sequence number of the next event.
+    next_sequence: u64,
+    events: VecDeque<(u64, SubscriptionEvent)>,
+}
+
+impl Buffer {
+    /// Returns the sequence number of the oldest event that can be resumed from.
+    fn oldest_sequence(&self) -> u64 {
+        self.next_sequence - self.events.len() as u64
+    }
+}
+
+/// The latest events and the stream of the new ones.
+#[derive(Clone)]
+pub struct SharedState {
+    epoch: u64,
+    buffer: Arc<Mutex<Buffer>>,
+    events: broadcast::Sender<(SubscriptionCursor, SubscriptionEvent)>,
+}
+
+impl SharedState {
+    fn new(capacity: usize) -> Self {
+        let epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default();
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        Self {
+            epoch,
+            buffer: Arc::new(Mutex::new(Buffer {
+                capacity,
+                next_sequence: 1,
+                events: VecDeque::with_capacity(capacity),
+            })),
+            events,
+        }
+    }
+
+    fn cursor(&self, sequence: u64) -> SubscriptionCursor {
+        SubscriptionCursor {
+            epoch: self.epoch,
+            sequence,
+        }
+    }
+
+    fn push(&self, event: SubscriptionEvent) {
+        let mut buffer = self.buffer.lock();
+        let sequence = buffer.next_sequence;
+        buffer.next_sequence += 1;
+        if buffer.events.len() >= buffer.capacity {
+            buffer.events.pop_front();
+        }
+        buffer.events.push_back((sequence, event.clone()));
+        // Nobody listens to the events if the send fails.
+        let _ = self.events.send((self.cursor(sequence), event));
+    }
+
+    /// Forgets the buffered events, so the subscriptions can't be resumed over the gap.
+    fn clear(&self) {
+        self.buffer.lock().events.clear();
+    }
+
+    /// Returns the cursor of the latest event.
+    pub fn latest_cursor(&self) -> SubscriptionCursor {
+        self.cursor(self.buffer.lock().next_sequence - 1)
+    }
+
+    /// Returns the stream of the events after the `cursor`: the buffered ones first,
+    /// then the new ones. Returns an error if some of the events after the `cursor`
+    /// are no longer buffered. The stream ends with an error if it lags behind
+    /// the new events, the client resumes it from the last received cursor.
+    pub fn events_after(&self, cursor: SubscriptionCursor) -> anyhow::Result<Events> {
+        use futures::StreamExt;
+        use tokio_stream::wrappers::BroadcastStream;
+
+        // The buffer is locked while the new events are subscribed,
+        // so no event is missed or repeated between the two.
+        let (buffered, receiver) = {
+            let buffer = self.buffer.lock();
+            if cursor.epoch != self.epoch {
+                return Err(anyhow::anyhow!(
+                    "The cursor was issued before the restart of the node"
+                ))
+            }
+            if cursor.sequence >= buffer.next_sequence {
+                return Err(anyhow::anyhow!("The cursor is unknown to the node"))
+            }
+            if cursor.sequence + 1 < buffer.oldest_sequence() {
+                return Err(anyhow::anyhow!(
+                    "The events after the cursor are no longer buffered by the node"
+                ))
+            }
+            let buffered: Vec<anyhow::Result<_>> = buffer
+                .events
+                .iter()
+                .filter(|(sequence, _)| *sequence > cursor.sequence)
+                .map(|(sequence, event)| Ok((self.cursor(*sequence), event.clone())))
+                .collect();
+            (buffered, self.events.subscribe())
+        };
+
+        let new = BroadcastStream::new(receiver).map(|event| {
+            event.map_err(|_| {
+                anyhow::anyhow!(
+                    "The subscription lagged behind the events, \
+                    resume it from the last cursor"
+                )
+            })
+        });
+        let events =
+            futures::stream::iter(buffered)
+                .chain(new)
+                .scan(false, |failed, event| {
+                    // The stream ends after the first error.
+                    if *failed {
+                        return futures::future::ready(None)
+                    }
+                    *failed = event.is_err();
+                    futures::future::ready(Some(event))
+                });
+        Ok(Box::pin(events))
+    }
+}
+
+pub struct Task {
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    tx_updates: broadcast::Receiver<TxUpdate>,
+    shared: SharedState,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "SubscriptionBuffer";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+impl Task {
+    fn lagged(&self, skipped: u64) {
+        tracing::warn!(
+            "The subscription buffer skipped {skipped} events, \
+            the subscriptions can't be resumed over them"
+        );
+        self.shared.clear();
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            block = self.blocks.recv() => match block {
+                Ok(result) => {
+                    let height = *result.sealed_block.entity.header().height();
+                    self.shared.push(SubscriptionEvent::Block(height));
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    self.lagged(skipped);
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+            update = self.tx_updates.recv() => match update {
+                Ok(update) => {
+                    let tx_id = *update.tx_id();
+                    if let TxStatusMessage::Status(status) = update.into_msg() {
+                        self.shared.push(SubscriptionEvent::TxStatus(tx_id, status));
+                    }
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    self.lagged(skipped);
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    capacity: usize,
+    blocks: broadcast::Receiver<Arc<ImportResult>>,
+    tx_updates: broadcast::Receiver<TxUpdate>,
+) -> Service {
+    ServiceRunner::new(Task {
+        blocks,
+        tx_updates,
+        shared: SharedState::new(capacity),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    fn block(height: u32) -> SubscriptionEvent {
+        SubscriptionEvent::Block(height.into())
+    }
+
+    #[tokio::test]
+    async fn events_after_replays_the_buffered_events_before_the_new_ones() {
+        let shared = SharedState::new(10);
+        shared.push(block(1));
+        let cursor = shared.latest_cursor();
+        shared.push(block(2));
+        shared.push(block(3));
+
+        let mut events = shared.events_after(cursor).unwrap();
+        shared.push(block(4));
+
+        for height in 2..=4 {
+            let (cursor, event) = events.next().await.unwrap().unwrap();
+            assert_eq!(event, block(height));
+            assert_eq!(cursor.sequence, height as u64);
+        }
+    }
+
+    #[test]
+    fn events_after_rejects_the_evicted_and_foreign_cursors() {
+        let shared = SharedState::new(2);
+        let first = shared.latest_cursor();
+        shared.push(block(1));
+        let second = shared.latest_cursor();
+        shared.push(block(2));
+        shared.push(block(3));
+
+        assert!(shared.events_after(first).is_err());
+        assert!(shared.events_after(second).is_ok());
+        let foreign = SubscriptionCursor {
+            epoch: second.epoch + 1,
+            ..second
+        };
+        assert!(shared.events_after(foreign).is_err());
+        let unknown = SubscriptionCursor {
+            sequence: 4,
+            ..second
+        };
+        assert!(shared.events_after(unknown).is_err());
+    }
+
+    #[test]
+    fn cursor_roundtrips_through_the_string() {
+        let cursor = SubscriptionCursor {
+            epoch: 0xabc,
+            sequence: 42,
+        };
+
+        assert_eq!(cursor.to_string().parse(), Ok(cursor));
+        assert!("42".parse::<SubscriptionCursor>().is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! The test vectors of the block execution. The vector is the block with the subset
+//! of the state read by its execution and the results of the execution. Running the
+//! vectors exported by one version of the node against another version detects
+//! the changes in the behavior of the executor.
+
+use crate::{
+    database::{
+        state_diff::{
+            StateChange,
+            StateDiff,
+        },
+        Database,
+    },
+    service::{
+        replay::{
+            execute_block,
+            executor_config,
+            receipts,
+            Divergence,
+            Replayer,
+        },
+        upgrades::NODE_VERSION,
+        Config,
+    },
+};
+use fuel_core_types::{
+    blockchain::block::Block,
+    entities::message::Message,
+    fuel_tx::{
+        Receipt,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::BlockHeight,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The self-contained test vector of the execution of one block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// The version of the node that exported the vector.
+    pub node_version: String,
+    /// The executed block.
+    pub block: Block,
+    /// The values read by the execution from the state of the previous block.
+    pub state: Vec<StateChange>,
+    /// The messages from the DA layer spent by the block.
+    pub messages: Vec<Message>,
+    /// The receipts of the transactions of the block, in the order of the block.
+    pub receipts: Vec<TransactionReceipts>,
+    /// The changes of the state made by the block.
+    pub state_diff: StateDiff,
+}
+
+/// The receipts of one transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionReceipts {
+    pub tx_id: TxId,
+    pub receipts: Vec<Receipt>,
+}
+
+/// Exports the test vector of the block at the `height` of the `database`. The state
+/// of the previous block is rebuilt in memory from the genesis, as for the replay.
+pub fn export(
+    database: &Database,
+    config: &Config,
+    height: BlockHeight,
+) -> anyhow::Result<TestVector> {
+    let replayer = Replayer::new(database, config, height)?;
+    anyhow::ensure!(
+        height > replayer.genesis_height,
+        "The genesis block at height {} can't be exported",
+        replayer.genesis_height
+    );
+
+    for parent in (*replayer.genesis_height + 1)..*height {
+        let sealed_block = replayer.sealed_block(&parent.into())?;
+        let messages = replayer.spent_messages(&sealed_block.entity)?;
+        let (result, db_transaction) = replayer
+            .execute(&replayer.state, &sealed_block.entity, messages)
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "The block at height {parent} failed re-execution: {error}"
+                )
+            })?;
+        replayer.commit(sealed_block, result, db_transaction)?;
+    }
+
+    let block = replayer.sealed_block(&height)?.entity;
+    let messages = replayer.spent_messages(&block)?;
+    let (recording_state, recording) = replayer.state.recording();
+    let (_, db_transaction) = replayer
+        .execute(&recording_state, &block, messages.clone())
+        .map_err(|error| {
+            anyhow::anyhow!("The block at height {height} failed re-execution: {error}")
+        })?;
+
+    let chain_id = &config.chain_conf.consensus_parameters.chain_id;
+    let receipts = block
+        .transactions()
+        .iter()
+        .map(|tx| {
+            let tx_id = tx.id(chain_id);
+            let receipts = receipts(db_transaction.as_ref(), &tx_id)?;
+            Ok(TransactionReceipts { tx_id, receipts })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let state_diff = db_transaction.as_ref().uncommitted_state_diff(height)?;
+    let state = recording
+        .recorded()
+        .into_iter()
+        .map(|(column, key, value)| StateChange {
+            column: column.as_ref().to_string(),
+            key,
+            value: Some(value.as_ref().clone()),
+            previous: None,
+        })
+        .collect();
+    let mut messages: Vec<_> = messages.into_values().collect();
+    messages.sort_by_key(|message| message.nonce);
+
+    Ok(TestVector {
+        node_version: NODE_VERSION.to_string(),
+        block,
+        state,
+        messages,
+        receipts,
+        state_diff,
+    })
+}
+
+/// Re-executes the block of the `vector` on top of its state with the executor
+/// of this node and compares the results with the recorded ones.
+///
+/// Returns the first found divergence, if any.
+pub fn run(vector: &TestVector, config: &Config) -> anyhow::Result<Option<Divergence>> {
+    let height = *vector.block.header().height();
+    let state = Database::in_memory();
+    state.apply_state_diff(&StateDiff {
+        height,
+        changes: vector.state.clone(),
+    })?;
+    let messages = vector
+        .messages
+        .iter()
+        .map(|message| (message.nonce, message.clone()))
+        .collect();
+
+    let (executor_config, options) = executor_config(config);
+    let (_, db_transaction) =
+        match execute_block(&state, &vector.block, messages, executor_config, options) {
+            Ok(result) => result,
+            Err(error) => return Ok(Some(Divergence::InvalidBlock { height, error })),
+        };
+
+    for expected in &vector.receipts {
+        let actual = receipts(db_transaction.as_ref(), &expected.tx_id)?;
+        if expected.receipts != actual {
+            return Ok(Some(Divergence::Receipts {
+                height,
+                tx_id: expected.tx_id,
+                expected: expected.receipts.clone(),
+                actual,
+            }))
+        }
+    }
+
+    let state_diff = db_transaction.as_ref().uncommitted_state_diff(height)?;
+    if let Some((expected, actual)) = first_difference(&vector.state_diff, &state_diff) {
+        return Ok(Some(Divergence::StateChange {
+            height,
+            expected,
+            actual,
+        }))
+    }
+
+    Ok(None)
+}
+
+/// Returns the first pair of the changes that differ between the `expected` and
+/// the `actual` diffs. The change is `None` if the diff has fewer changes.
+fn first_difference(
+    expected: &StateDiff,
+    actual: &StateDiff,
+) -> Option<(Option<StateChange>, Option<StateChange>)> {
+    let mut expected = expected.changes.iter();
+    let mut actual = actual.changes.iter();
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => return None,
+            (expected, actual) if expected == actual => continue,
+            (expected, actual) => return Some((expected.cloned(), actual.cloned())),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Archives the statuses of the transactions, so they are still available after the
+//! transactions leave the `TxPool`. The history is removed after the retention period.
+
+use crate::{
+    database::Database,
+    service::disk_monitor,
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_txpool::service::{
+    TxStatusMessage,
+    TxUpdate,
+};
+use fuel_core_types::tai64::Tai64;
+use std::time::Duration;
+use tokio::{
+    sync::broadcast::{
+        self,
+        error::RecvError,
+    },
+    time::Interval,
+};
+
+/// The max interval between the prunings of the expired history.
+const MAX_PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+pub type Service = ServiceRunner<NotInitializedTask>;
+
+pub struct NotInitializedTask {
+    database: Database,
+    updates: broadcast::Receiver<TxUpdate>,
+    retention: Duration,
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+pub struct Task {
+    database: Database,
+    updates: broadcast::Receiver<TxUpdate>,
+    retention: Duration,
+    prune_interval: Interval,
+    /// The statuses aren't archived while the node is low on the disk space.
+    disk_monitor: Option<disk_monitor::SharedState>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for NotInitializedTask {
+    const NAME: &'static str = "TxStatusArchive";
+
+    type SharedData = ();
+    type Task = Task;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            database,
+            updates,
+            retention,
+            disk_monitor,
+        } = self;
+        let prune_interval = tokio::time::interval(retention.min(MAX_PRUNE_INTERVAL));
+        Ok(Task {
+            database,
+            updates,
+            retention,
+            prune_interval,
+            disk_monitor,
+        })
+    }
+}
+
+impl Task {
+    fn archive(&self, update: TxUpdate) -> anyhow::Result<()> {
+        if let Some(disk_monitor) = self.disk_monitor.as_ref() {
+            if disk_monitor.is_degraded() {
+                return Ok(())
+            }
+        }
+        let tx_id = *update.tx_id();
+        if let TxStatusMessage::Status(status) = update.into_msg() {
+            self.database
+                .archive_tx_status(&tx_id, status, Tai64::now())?;
+        }
+        Ok(())
+    }
+
+    fn prune(&self) -> anyhow::Result<()> {
+        let now = Tai64::now();
+        let cutoff = Tai64(now.0.saturating_sub(self.retention.as_secs()));
+        let pruned = self.database.prune_tx_status_archive(cutoff)?;
+        if pruned > 0 {
+            tracing::debug!("Pruned the status history of {pruned} transactions");
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => Ok(false),
+            update = self.updates.recv() => match update {
+                Ok(update) => {
+                    self.archive(update)?;
+                    Ok(true)
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        "The status archive skipped {skipped} transaction status updates"
+                    );
+                    Ok(true)
+                }
+                Err(RecvError::Closed) => Ok(false),
+            },
+            _ = self.prune_interval.tick() => {
+                self.prune()?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub fn new_service(
+    database: Database,
+    updates: broadcast::Receiver<TxUpdate>,
+    retention: Duration,
+    disk_monitor: Option<disk_monitor::SharedState>,
+) -> Service {
+    ServiceRunner::new(NotInitializedTask {
+        database,
+        updates,
+        retention,
+        disk_monitor,
+    })
+}
@@ -0,0 +1,182 @@
+//! Schedules the upgrades of the chain declared by the consensus authority. The node
+//! refuses to produce and import the blocks from the height of the upgrade if its
+//! version is older than the version required by the upgrade, so the operators can't
+//! accidentally fork from the upgraded chain.
+
+use crate::{
+    chain_config::{
+        ConsensusConfig,
+        SignedUpgradeDeclaration,
+        UpgradeDeclaration,
+    },
+    database::Database,
+};
+use anyhow::ensure;
+use fuel_core_types::fuel_types::{
+    BlockHeight,
+    ChainId,
+};
+
+/// The version of the node compared with the versions required by the upgrades.
+pub const NODE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Verifies and stores the `upgrade` signed for the chain with the `chain_id`
+/// by the authority of the `consensus`. The upgrade can be declared only for
+/// the future heights.
+pub fn declare_upgrade(
+    database: &Database,
+    consensus: &ConsensusConfig,
+    chain_id: &ChainId,
+    upgrade: SignedUpgradeDeclaration,
+) -> anyhow::Result<()> {
+    upgrade.verify(consensus, chain_id)?;
+    let height = upgrade.declaration.height;
+    let latest = database.latest_height()?;
+    ensure!(
+        height > latest,
+        "The upgrade at height {height} is not above the latest height {latest}"
+    );
+    database.insert_upgrade(&upgrade)?;
+    tracing::info!(
+        "Scheduled the upgrade at height {height} requiring version {}",
+        upgrade.declaration.required_version
+    );
+    Ok(())
+}
+
+/// Returns the declared upgrades above the latest height of the chain.
+pub fn pending_upgrades(database: &Database) -> anyhow::Result<Vec<UpgradeDeclaration>> {
+    let latest = database.latest_height()?;
+    Ok(database
+        .upgrades()?
+        .into_iter()
+        .map(|upgrade| upgrade.declaration)
+        .filter(|declaration| declaration.height > latest)
+        .collect())
+}
+
+/// Returns an error if the node doesn't support one of the upgrades
+/// effective at the `height`.
+pub fn ensure_supported(database: &Database, height: BlockHeight) -> anyhow::Result<()> {
+    for upgrade in database.upgrades()? {
+        let declaration = upgrade.declaration;
+        if declaration.height > height {
+            break
+        }
+        ensure!(
+            declaration.is_supported_by(NODE_VERSION)?,
+            "The upgrade at height {} requires the node version {}, but the node is {NODE_VERSION}",
+            declaration.height,
+            declaration.required_version,
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain_config::default_consensus_dev_key;
+    use fuel_core_storage::{
+        tables::FuelBlocks,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::CompressedBlock,
+            header::BlockHeader,
+        },
+        fuel_crypto::SecretKey,
+        fuel_tx::Input,
+        tai64::Tai64,
+    };
+
+    fn declaration(height: u32, required_version: &str) -> UpgradeDeclaration {
+        UpgradeDeclaration {
+            height: height.into(),
+            required_version: required_version.to_string(),
+            consensus_parameters: None,
+            state_transition_bytecode_root: None,
+        }
+    }
+
+    fn consensus() -> ConsensusConfig {
+        ConsensusConfig::PoA {
+            signing_key: Input::owner(&default_consensus_dev_key().public_key()),
+        }
+    }
+
+    fn insert_block(database: &mut Database, height: u32) {
+        let block = CompressedBlock::test(
+            BlockHeader::new_block(height.into(), Tai64::UNIX_EPOCH),
+            vec![],
+        );
+        database
+            .storage::<FuelBlocks>()
+            .insert(&block.id(), &block)
+            .unwrap();
+    }
+
+    fn database_at(height: u32) -> Database {
+        let mut database = Database::default();
+        insert_block(&mut database, height);
+        database
+    }
+
+    #[test]
+    fn declared_upgrade_is_pending_until_its_height() {
+        let mut database = database_at(5);
+        let upgrade = declaration(10, "0.0.1")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+
+        declare_upgrade(&database, &consensus(), &ChainId::default(), upgrade).unwrap();
+
+        assert_eq!(
+            pending_upgrades(&database).unwrap(),
+            vec![declaration(10, "0.0.1")]
+        );
+        insert_block(&mut database, 10);
+        assert_eq!(pending_upgrades(&database).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn upgrade_for_past_height_is_rejected() {
+        let database = database_at(5);
+        let upgrade = declaration(5, "0.0.1")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+
+        assert!(
+            declare_upgrade(&database, &consensus(), &ChainId::default(), upgrade)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn upgrade_signed_by_other_key_is_rejected() {
+        let database = database_at(5);
+        let secret_key = SecretKey::try_from([7u8; 32].as_slice()).unwrap();
+        let upgrade = declaration(10, "0.0.1")
+            .sign(&secret_key, &ChainId::default())
+            .unwrap();
+
+        assert!(
+            declare_upgrade(&database, &consensus(), &ChainId::default(), upgrade)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn unsupported_upgrade_stops_the_chain_at_its_height() {
+        let database = database_at(5);
+        let upgrade = declaration(10, "999.0.0")
+            .sign(&default_consensus_dev_key(), &ChainId::default())
+            .unwrap();
+        declare_upgrade(&database, &consensus(), &ChainId::default(), upgrade).unwrap();
+
+        assert!(ensure_supported(&database, 9u32.into()).is_ok());
+        assert!(ensure_supported(&database, 10u32.into()).is_err());
+        assert!(ensure_supported(&database, 11u32.into()).is_err());
+    }
+}
@@ -8,6 +8,7 @@ use fuel_core_storage::iter::{
 };
 use std::{
     fmt::Debug,
+    num::NonZeroU32,
     sync::Arc,
 };
 
@@ -80,14 +81,84 @@ pub trait BatchOperations: KeyValueStore {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum WriteOperation {
     Insert(Value),
     Remove,
 }
 
-pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {}
+/// How often the writes of the database are synced to the disk.
+///
+/// The writes are appended to the write-ahead log before they are applied, and the database
+/// replays the log after the restart. The log survives the crash of the node process in any
+/// mode, but the writes that are not synced yet are lost if the operating system crashes or
+/// the machine loses power. After such a crash, the database is restored to the last synced
+/// commit, which is always consistent, and the node imports the lost blocks again.
+///
+/// The block producer may produce a conflicting block at the height of the lost one,
+/// so it should use the `Sync` mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Durability {
+    /// Syncs the log with every commit. Each block is committed in one batch, so no
+    /// imported block is lost, at the cost of the fsync per block.
+    Sync,
+    /// Syncs the log with every `n`-th committed block. Up to `n - 1` of the latest
+    /// blocks may be lost.
+    Periodic(NonZeroU32),
+    /// Leaves the sync of the log to the operating system. The fastest mode, but
+    /// the writes of the last seconds may be lost.
+    #[default]
+    Async,
+}
+
+/// The approximate statistics of the column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The size of the column in bytes.
+    pub size: u64,
+    /// The number of keys in the column.
+    pub keys: u64,
+}
+
+pub trait TransactableStorage: BatchOperations + Debug + Send + Sync {
+    /// Returns the statistics of the `column`. The default implementation
+    /// iterates over all entries of the column.
+    fn column_stats(&self, column: Column) -> DatabaseResult<ColumnStats> {
+        let mut stats = ColumnStats::default();
+        for entry in self.iter_all(column, None, None, IterDirection::Forward) {
+            let (key, value) = entry?;
+            stats.keys += 1;
+            stats.size += (key.len() + value.len()) as u64;
+        }
+        Ok(stats)
+    }
+
+    /// Compacts all entries of the `column` and blocks until the compaction is finished.
+    /// The default implementation does nothing.
+    fn compact_column(&self, _column: Column) -> DatabaseResult<()> {
+        Ok(())
+    }
+
+    /// Returns the estimated number of bytes that the background compaction of
+    /// the `column` has to rewrite to reach the steady state.
+    fn compaction_debt(&self, _column: Column) -> DatabaseResult<u64> {
+        Ok(0)
+    }
+
+    /// Returns the changes that are not committed into the underlying storage yet.
+    /// It is `None` if the storage doesn't have the uncommitted changes.
+    fn uncommitted_changes(&self) -> Option<Vec<(Column, Vec<u8>, WriteOperation)>> {
+        None
+    }
+
+    /// Returns the value of the `key` in the underlying storage, ignoring
+    /// the uncommitted changes.
+    fn committed_get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.get(key, column)
+    }
+}
 
 pub mod in_memory;
+pub mod recording;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
@@ -26,12 +26,21 @@ use std::{
     },
 };
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct MemoryStore {
     // TODO: Remove `Mutex`.
     inner: [Mutex<BTreeMap<Vec<u8>, Value>>; Column::COUNT],
 }
 
+impl Default for MemoryStore {
+    fn default() -> Self {
+        // `Default` of the arrays is implemented only up to 32 elements.
+        Self {
+            inner: core::array::from_fn(|_| Default::default()),
+        }
+    }
+}
+
 impl MemoryStore {
     pub fn iter_all(
         &self,
@@ -217,6 +226,7 @@ impl TransactableStorage for MemoryStore {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::ColumnStats;
     use std::sync::Arc;
 
     #[test]
@@ -247,6 +257,24 @@ mod tests {
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
 
+    #[test]
+    fn column_stats_sum_the_keys_and_values() {
+        let db = MemoryStore::default();
+        db.put(&[0x00], Column::Metadata, Arc::new(vec![1, 2, 3]))
+            .unwrap();
+        db.put(&[0x01, 0x02], Column::Metadata, Arc::new(vec![]))
+            .unwrap();
+
+        assert_eq!(
+            db.column_stats(Column::Metadata).unwrap(),
+            ColumnStats { size: 6, keys: 2 }
+        );
+        assert_eq!(
+            db.column_stats(Column::Coins).unwrap(),
+            ColumnStats::default()
+        );
+    }
+
     #[test]
     fn can_use_unit_key() {
         let key: Vec<u8> = Vec::with_capacity(0);
@@ -47,7 +47,7 @@ impl MemoryTransactionView {
     pub fn new(source: DataSource) -> Self {
         Self {
             view_layer: MemoryStore::default(),
-            changes: Default::default(),
+            changes: core::array::from_fn(|_| Default::default()),
             data_source: source,
         }
     }
@@ -284,7 +284,26 @@ impl KeyValueStore for MemoryTransactionView {
 
 impl BatchOperations for MemoryTransactionView {}
 
-impl TransactableStorage for MemoryTransactionView {}
+impl TransactableStorage for MemoryTransactionView {
+    fn uncommitted_changes(&self) -> Option<Vec<(Column, Vec<u8>, WriteOperation)>> {
+        let changes = self
+            .changes
+            .iter()
+            .zip(enum_iterator::all::<Column>())
+            .flat_map(|(column_map, column)| {
+                let map = column_map.lock().expect("poisoned lock");
+                map.iter()
+                    .map(|(key, op)| (column, key.clone(), op.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Some(changes)
+    }
+
+    fn committed_get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.data_source.get(key, column)
+    }
+}
 
 #[cfg(test)]
 mod tests {
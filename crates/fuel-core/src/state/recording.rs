@@ -0,0 +1,198 @@
+use crate::{
+    database::{
+        Column,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        BatchOperations,
+        DataSource,
+        IterDirection,
+        KVItem,
+        KeyValueStore,
+        TransactableStorage,
+        Value,
+    },
+};
+use fuel_core_storage::iter::{
+    BoxedIter,
+    IntoBoxedIter,
+};
+use std::{
+    collections::BTreeMap,
+    sync::Mutex,
+};
+
+/// Records the values read from the underlying data source. The recorded values
+/// are the subset of the state that is enough to repeat the same reads.
+///
+/// The writes go to the underlying data source without recording.
+#[derive(Debug)]
+pub struct RecordingStorage {
+    data_source: DataSource,
+    // The value is `None` if the key was absent at the first read.
+    reads: [Mutex<BTreeMap<Vec<u8>, Option<Value>>>; Column::COUNT],
+}
+
+impl RecordingStorage {
+    pub fn new(source: DataSource) -> Self {
+        Self {
+            data_source: source,
+            reads: core::array::from_fn(|_| Default::default()),
+        }
+    }
+
+    /// Returns the values present at their first read, ordered by the column and the key.
+    pub fn recorded(&self) -> Vec<(Column, Vec<u8>, Value)> {
+        self.reads
+            .iter()
+            .zip(enum_iterator::all::<Column>())
+            .flat_map(|(column_map, column)| {
+                let map = column_map.lock().expect("poisoned lock");
+                map.iter()
+                    .filter_map(|(key, value)| {
+                        value.clone().map(|value| (column, key.clone(), value))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn record(&self, key: &[u8], column: Column, value: Option<Value>) {
+        self.reads[column.as_usize()]
+            .lock()
+            .expect("poisoned lock")
+            .entry(key.to_vec())
+            .or_insert(value);
+    }
+
+    fn get_and_record(
+        &self,
+        key: &[u8],
+        column: Column,
+    ) -> DatabaseResult<Option<Value>> {
+        let value = self.data_source.get(key, column)?;
+        self.record(key, column, value.clone());
+        Ok(value)
+    }
+}
+
+impl KeyValueStore for RecordingStorage {
+    fn put(
+        &self,
+        key: &[u8],
+        column: Column,
+        value: Value,
+    ) -> DatabaseResult<Option<Value>> {
+        self.data_source.put(key, column, value)
+    }
+
+    fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
+        self.data_source.write(key, column, buf)
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Column,
+        buf: &[u8],
+    ) -> DatabaseResult<(usize, Option<Value>)> {
+        self.data_source.replace(key, column, buf)
+    }
+
+    fn take(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.data_source.take(key, column)
+    }
+
+    fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.data_source.delete(key, column)
+    }
+
+    fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
+        Ok(self.get_and_record(key, column)?.is_some())
+    }
+
+    fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
+        Ok(self.get_and_record(key, column)?.map(|value| value.len()))
+    }
+
+    fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.get_and_record(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Column,
+        mut buf: &mut [u8],
+    ) -> DatabaseResult<Option<usize>> {
+        self.get_and_record(key, column)?
+            .map(|value| {
+                let read = value.len();
+                std::io::Write::write_all(&mut buf, value.as_ref())
+                    .map_err(|e| DatabaseError::Other(anyhow::anyhow!(e)))?;
+                DatabaseResult::Ok(read)
+            })
+            .transpose()
+    }
+
+    fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
+        self.get_and_record(key, column)
+    }
+
+    fn iter_all(
+        &self,
+        column: Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        // Only the visited entries are recorded, so the same iteration over
+        // the recorded values stops at the same entry.
+        self.data_source
+            .iter_all(column, prefix, start, direction)
+            .inspect(move |item| {
+                if let Ok((key, value)) = item {
+                    self.record(key, column, Some(value.clone()));
+                }
+            })
+            .into_boxed()
+    }
+}
+
+impl BatchOperations for RecordingStorage {}
+
+impl TransactableStorage for RecordingStorage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::memory_store::MemoryStore;
+    use std::sync::Arc;
+
+    #[test]
+    fn records_only_the_read_values() {
+        let store = Arc::new(MemoryStore::default());
+        for key in [1u8, 2, 3] {
+            store
+                .put(&[key], Column::Metadata, Arc::new(vec![key]))
+                .unwrap();
+        }
+        let recording = RecordingStorage::new(store);
+
+        assert!(recording.exists(&[1], Column::Metadata).unwrap());
+        assert!(!recording.exists(&[4], Column::Metadata).unwrap());
+        let first = recording
+            .iter_all(Column::Metadata, None, Some(&[3]), IterDirection::Forward)
+            .next();
+        assert!(first.is_some());
+
+        assert_eq!(
+            recording.recorded(),
+            vec![
+                (Column::Metadata, vec![1], Arc::new(vec![1])),
+                (Column::Metadata, vec![3], Arc::new(vec![3])),
+            ]
+        );
+    }
+}
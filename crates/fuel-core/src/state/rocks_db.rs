@@ -7,6 +7,8 @@ use crate::{
     },
     state::{
         BatchOperations,
+        ColumnStats,
+        Durability,
         IterDirection,
         KVItem,
         KeyValueStore,
@@ -15,7 +17,10 @@ use crate::{
         WriteOperation,
     },
 };
-use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_metrics::{
+    core_metrics::database_metrics,
+    storage_metrics::storage_metrics,
+};
 use fuel_core_storage::iter::{
     BoxedIter,
     IntoBoxedIter,
@@ -32,17 +37,27 @@ use rocksdb::{
     ReadOptions,
     SliceTransform,
     WriteBatch,
+    WriteOptions,
 };
 use std::{
     iter,
     path::Path,
-    sync::Arc,
+    sync::{
+        atomic::{
+            AtomicU32,
+            Ordering,
+        },
+        Arc,
+    },
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
 #[derive(Debug)]
 pub struct RocksDb {
     db: DB,
+    durability: Durability,
+    /// The number of the committed blocks since the last sync of the write-ahead log.
+    unsynced_blocks: AtomicU32,
 }
 
 impl RocksDb {
@@ -70,6 +85,8 @@ impl RocksDb {
 
         let mut opts = Options::default();
         opts.create_if_missing(true);
+        // The database created by the previous version doesn't have the new columns.
+        opts.create_missing_column_families(true);
         opts.set_compression_type(DBCompressionType::Lz4);
         if let Some(capacity) = capacity {
             let cache = Cache::new_lru_cache(capacity);
@@ -94,14 +111,87 @@ impl RocksDb {
             ok => ok,
         }
         .map_err(|e| DatabaseError::Other(e.into()))?;
-        let rocks_db = RocksDb { db };
+        let rocks_db = RocksDb {
+            db,
+            durability: Durability::default(),
+            unsynced_blocks: AtomicU32::new(0),
+        };
         Ok(rocks_db)
     }
 
-    fn cf(&self, column: Column) -> Arc<BoundColumnFamily> {
-        self.db
-            .cf_handle(&RocksDb::col_name(column))
-            .expect("invalid column state")
+    /// Opens the existing database at the `path` only for the reads, so the tools
+    /// inspecting the database can't modify it. The database may be used by the
+    /// running node at the same time.
+    ///
+    /// The database created by the previous version may not have the new columns,
+    /// so only the existing columns are opened, and the missing ones are read as empty.
+    pub fn default_open_read_only<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<RocksDb> {
+        let mut opts = Options::default();
+        opts.set_compression_type(DBCompressionType::Lz4);
+        if let Some(capacity) = capacity {
+            let cache = Cache::new_lru_cache(capacity);
+            opts.set_row_cache(&cache);
+        }
+
+        let existing_columns =
+            DB::list_cf(&opts, &path).map_err(|e| DatabaseError::Other(e.into()))?;
+        let cf_descriptors: Vec<_> = enum_iterator::all::<Column>()
+            .filter(|i| existing_columns.contains(&RocksDb::col_name(*i)))
+            .map(|i| ColumnFamilyDescriptor::new(RocksDb::col_name(i), Self::cf_opts(i)))
+            .collect();
+
+        let db = DB::open_cf_descriptors_read_only(&opts, &path, cf_descriptors, false)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(RocksDb {
+            db,
+            durability: Durability::default(),
+            unsynced_blocks: AtomicU32::new(0),
+        })
+    }
+
+    /// Sets how often the writes are synced to the disk.
+    pub fn with_durability(mut self, durability: Durability) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    fn write_options(&self) -> WriteOptions {
+        let mut options = WriteOptions::default();
+        options.set_sync(self.durability == Durability::Sync);
+        options
+    }
+
+    /// Syncs the write-ahead log if the committed block completes the period.
+    fn on_block_commit(&self) -> DatabaseResult<()> {
+        let Durability::Periodic(period) = self.durability else {
+            return Ok(())
+        };
+        let blocks = self.unsynced_blocks.fetch_add(1, Ordering::Relaxed) + 1;
+        if blocks >= period.get() {
+            self.unsynced_blocks.store(0, Ordering::Relaxed);
+            self.db
+                .flush_wal(true)
+                .map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+        Ok(())
+    }
+
+    fn cf(&self, column: Column) -> DatabaseResult<Arc<BoundColumnFamily>> {
+        self.existing_cf(column).ok_or_else(|| {
+            DatabaseError::Other(anyhow::anyhow!(
+                "The column {} is not opened",
+                column.name()
+            ))
+        })
+    }
+
+    /// Returns the handle of the column, if the database has it.
+    /// It is missing only in the read-only database created by the previous version.
+    fn existing_cf(&self, column: Column) -> Option<Arc<BoundColumnFamily>> {
+        self.db.cf_handle(&RocksDb::col_name(column))
     }
 
     fn col_name(column: Column) -> String {
@@ -116,13 +206,19 @@ impl RocksDb {
         // All double-keys should be configured here
         match column {
             Column::OwnedCoins
+            | Column::OwnedCoinsBalances
             | Column::TransactionsByOwnerBlockIdx
             | Column::OwnedMessageIds
+            | Column::DaTransactionMessageIds
             | Column::ContractsAssets
             | Column::ContractsState => {
                 // prefix is address length
                 opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(32))
             }
+            Column::OriginMessages => {
+                // prefix is the chain id of the DA network
+                opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(8))
+            }
             _ => {}
         };
 
@@ -134,9 +230,12 @@ impl RocksDb {
         column: Column,
         opts: ReadOptions,
         iter_mode: IteratorMode,
-    ) -> impl Iterator<Item = KVItem> + '_ {
+    ) -> BoxedIter<KVItem> {
+        let Some(cf) = self.existing_cf(column) else {
+            return iter::empty().into_boxed()
+        };
         self.db
-            .iterator_cf_opt(&self.cf(column), opts, iter_mode)
+            .iterator_cf_opt(&cf, opts, iter_mode)
             .map(|item| {
                 item.map(|(key, value)| {
                     let value_as_vec = Vec::from(value);
@@ -151,15 +250,19 @@ impl RocksDb {
                 })
                 .map_err(|e| DatabaseError::Other(e.into()))
             })
+            .into_boxed()
     }
 }
 
 impl KeyValueStore for RocksDb {
     fn get(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
         database_metrics().read_meter.inc();
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(None)
+        };
         let value = self
             .db
-            .get_cf(&self.cf(column), key)
+            .get_cf(&cf, key)
             .map_err(|e| DatabaseError::Other(e.into()));
 
         if let Ok(Some(value)) = &value {
@@ -177,14 +280,20 @@ impl KeyValueStore for RocksDb {
     ) -> DatabaseResult<Option<Value>> {
         database_metrics().write_meter.inc();
         database_metrics().bytes_written.observe(value.len() as f64);
+        storage_metrics().record_write(column.name(), key.len() + value.len());
 
         // FIXME: This is a race condition. We should use a transaction.
         let prev = self.get(key, column)?;
         // FIXME: This is a race condition. We should use a transaction.
         self.db
-            .put_cf(&self.cf(column), key, value.as_ref())
-            .map_err(|e| DatabaseError::Other(e.into()))
-            .map(|_| prev)
+            .put_cf_opt(
+                &self.cf(column)?,
+                key,
+                value.as_ref(),
+                &self.write_options(),
+            )
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(prev)
     }
 
     fn delete(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
@@ -192,16 +301,19 @@ impl KeyValueStore for RocksDb {
         let prev = self.get(key, column)?;
         // FIXME: This is a race condition. We should use a transaction.
         self.db
-            .delete_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))
-            .map(|_| prev)
+            .delete_cf_opt(&self.cf(column)?, key, &self.write_options())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(prev)
     }
 
     fn exists(&self, key: &[u8], column: Column) -> DatabaseResult<bool> {
         // use pinnable mem ref to avoid memcpy of values associated with the key
         // since we're just checking for the existence of the key
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(false)
+        };
         self.db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf(&cf, key)
             .map_err(|e| DatabaseError::Other(e.into()))
             .map(|v| v.is_some())
     }
@@ -223,7 +335,6 @@ impl KeyValueStore for RocksDb {
                         IterDirection::Reverse => IteratorMode::End,
                     };
                 self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .into_boxed()
             }
             (Some(prefix), None) => {
                 // start iterating in a certain direction within the keyspace
@@ -232,14 +343,13 @@ impl KeyValueStore for RocksDb {
                 let mut opts = ReadOptions::default();
                 opts.set_prefix_same_as_start(true);
 
-                self._iter_all(column, opts, iter_mode).into_boxed()
+                self._iter_all(column, opts, iter_mode)
             }
             (None, Some(start)) => {
                 // start iterating in a certain direction from the start key
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
                 self._iter_all(column, ReadOptions::default(), iter_mode)
-                    .into_boxed()
             }
             (Some(prefix), Some(start)) => {
                 // TODO: Maybe we want to allow the `start` to be without a `prefix` in the future.
@@ -268,10 +378,13 @@ impl KeyValueStore for RocksDb {
 
     fn size_of_value(&self, key: &[u8], column: Column) -> DatabaseResult<Option<usize>> {
         database_metrics().read_meter.inc();
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(None)
+        };
 
         Ok(self
             .db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf(&cf, key)
             .map_err(|e| DatabaseError::Other(e.into()))?
             .map(|value| value.len()))
     }
@@ -283,10 +396,13 @@ impl KeyValueStore for RocksDb {
         mut buf: &mut [u8],
     ) -> DatabaseResult<Option<usize>> {
         database_metrics().read_meter.inc();
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(None)
+        };
 
         let r = self
             .db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf(&cf, key)
             .map_err(|e| DatabaseError::Other(e.into()))?
             .map(|value| {
                 let read = value.len();
@@ -306,10 +422,11 @@ impl KeyValueStore for RocksDb {
     fn write(&self, key: &[u8], column: Column, buf: &[u8]) -> DatabaseResult<usize> {
         database_metrics().write_meter.inc();
         database_metrics().bytes_written.observe(buf.len() as f64);
+        storage_metrics().record_write(column.name(), key.len() + buf.len());
 
         let r = buf.len();
         self.db
-            .put_cf(&self.cf(column), key, buf)
+            .put_cf_opt(&self.cf(column)?, key, buf, &self.write_options())
             .map_err(|e| DatabaseError::Other(e.into()))?;
 
         Ok(r)
@@ -317,10 +434,13 @@ impl KeyValueStore for RocksDb {
 
     fn read_alloc(&self, key: &[u8], column: Column) -> DatabaseResult<Option<Value>> {
         database_metrics().read_meter.inc();
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(None)
+        };
 
         let r = self
             .db
-            .get_pinned_cf(&self.cf(column), key)
+            .get_pinned_cf(&cf, key)
             .map_err(|e| DatabaseError::Other(e.into()))?
             .map(|value| value.to_vec());
 
@@ -350,9 +470,9 @@ impl KeyValueStore for RocksDb {
         let prev = self.read_alloc(key, column)?;
         // FIXME: This is a race condition. We should use a transaction.
         self.db
-            .delete_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))
-            .map(|_| prev)
+            .delete_cf_opt(&self.cf(column)?, key, &self.write_options())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(prev)
     }
 }
 
@@ -362,14 +482,19 @@ impl BatchOperations for RocksDb {
         entries: &mut dyn Iterator<Item = (Vec<u8>, Column, WriteOperation)>,
     ) -> DatabaseResult<()> {
         let mut batch = WriteBatch::default();
+        // Each block is committed in one batch with the block itself.
+        let mut commits_block = false;
 
         for (key, column, op) in entries {
+            commits_block |= column == Column::FuelBlocks;
             match op {
                 WriteOperation::Insert(value) => {
-                    batch.put_cf(&self.cf(column), key, value.as_ref());
+                    storage_metrics()
+                        .record_write(column.name(), key.len() + value.len());
+                    batch.put_cf(&self.cf(column)?, key, value.as_ref());
                 }
                 WriteOperation::Remove => {
-                    batch.delete_cf(&self.cf(column), key);
+                    batch.delete_cf(&self.cf(column)?, key);
                 }
             }
         }
@@ -380,12 +505,50 @@ impl BatchOperations for RocksDb {
             .observe(batch.size_in_bytes() as f64);
 
         self.db
-            .write(batch)
-            .map_err(|e| DatabaseError::Other(e.into()))
+            .write_opt(batch, &self.write_options())
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        if commits_block {
+            self.on_block_commit()?;
+        }
+        Ok(())
     }
 }
 
-impl TransactableStorage for RocksDb {}
+impl TransactableStorage for RocksDb {
+    fn column_stats(&self, column: Column) -> DatabaseResult<ColumnStats> {
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(ColumnStats::default())
+        };
+        let property = |name: &str| {
+            self.db
+                .property_int_value_cf(&cf, name)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| DatabaseError::Other(e.into()))
+        };
+        // The size includes the memtables that are not flushed to the disk yet.
+        Ok(ColumnStats {
+            size: property("rocksdb.total-sst-files-size")?
+                + property("rocksdb.size-all-mem-tables")?,
+            keys: property("rocksdb.estimate-num-keys")?,
+        })
+    }
+
+    fn compact_column(&self, column: Column) -> DatabaseResult<()> {
+        self.db
+            .compact_range_cf(&self.cf(column)?, None::<&[u8]>, None::<&[u8]>);
+        Ok(())
+    }
+
+    fn compaction_debt(&self, column: Column) -> DatabaseResult<u64> {
+        let Some(cf) = self.existing_cf(column) else {
+            return Ok(0)
+        };
+        self.db
+            .property_int_value_cf(&cf, "rocksdb.estimate-pending-compaction-bytes")
+            .map(Option::unwrap_or_default)
+            .map_err(|e| DatabaseError::Other(e.into()))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -425,6 +588,127 @@ mod tests {
         assert_eq!(prev, Some(expected));
     }
 
+    #[test]
+    fn writes_are_persisted_in_each_durability_mode() {
+        let modes = [
+            Durability::Sync,
+            Durability::Periodic(2.try_into().unwrap()),
+            Durability::Async,
+        ];
+        for durability in modes {
+            let tmp_dir = TempDir::new().unwrap();
+            let db = RocksDb::default_open(tmp_dir.path(), None)
+                .unwrap()
+                .with_durability(durability);
+            for key in 0u8..3 {
+                db.put(&[key], Column::Metadata, Arc::new(vec![key]))
+                    .unwrap();
+            }
+            drop(db);
+
+            let db = RocksDb::default_open(tmp_dir.path(), None).unwrap();
+            for key in 0u8..3 {
+                assert_eq!(
+                    db.get(&[key], Column::Metadata).unwrap(),
+                    Some(Arc::new(vec![key])),
+                    "{durability:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn read_only_database_rejects_writes() {
+        let (db, tmp) = create_db();
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+        drop(db);
+
+        let db = RocksDb::default_open_read_only(tmp.path(), None).unwrap();
+
+        assert_eq!(
+            db.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert!(db.put(&[2], Column::Metadata, Arc::new(vec![2])).is_err());
+    }
+
+    #[test]
+    fn read_only_database_reads_missing_columns_as_empty() {
+        let tmp = TempDir::new().unwrap();
+        let db = RocksDb::open(tmp.path(), vec![Column::Metadata], None).unwrap();
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+        drop(db);
+
+        let db = RocksDb::default_open_read_only(tmp.path(), None).unwrap();
+
+        assert_eq!(
+            db.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(db.get(&[1], Column::Coins).unwrap(), None);
+        assert!(!db.exists(&[1], Column::Coins).unwrap());
+        assert_eq!(
+            db.iter_all(Column::Coins, None, None, IterDirection::Forward)
+                .count(),
+            0
+        );
+        assert_eq!(
+            db.column_stats(Column::Coins).unwrap(),
+            ColumnStats::default()
+        );
+        assert!(db.put(&[2], Column::Coins, Arc::new(vec![2])).is_err());
+    }
+
+    #[test]
+    fn periodic_durability_counts_only_committed_blocks() {
+        let (db, _tmp) = create_db();
+        let db = db.with_durability(Durability::Periodic(3.try_into().unwrap()));
+
+        db.put(&[1], Column::Metadata, Arc::new(vec![1])).unwrap();
+        let ops = vec![(vec![2], Column::Metadata, WriteOperation::Remove)];
+        db.batch_write(&mut ops.into_iter()).unwrap();
+        assert_eq!(db.unsynced_blocks.load(Ordering::Relaxed), 0);
+
+        let block = Arc::new(vec![3]);
+        let ops = vec![(vec![3], Column::FuelBlocks, WriteOperation::Insert(block))];
+        db.batch_write(&mut ops.into_iter()).unwrap();
+        assert_eq!(db.unsynced_blocks.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn column_stats_count_the_inserted_keys() {
+        let (db, _tmp) = create_db();
+        for key in 0u8..3 {
+            db.put(&[key], Column::Metadata, Arc::new(vec![1, 2, 3]))
+                .unwrap();
+        }
+
+        let stats = db.column_stats(Column::Metadata).unwrap();
+
+        assert_eq!(stats.keys, 3);
+        assert!(stats.size > 0);
+        assert_eq!(db.column_stats(Column::Coins).unwrap().keys, 0);
+    }
+
+    #[test]
+    fn compact_column_keeps_the_entries() {
+        let (db, _tmp) = create_db();
+        for key in 0u8..3 {
+            db.put(&[key], Column::Metadata, Arc::new(vec![key]))
+                .unwrap();
+        }
+        db.delete(&[0], Column::Metadata).unwrap();
+
+        db.compact_column(Column::Metadata).unwrap();
+
+        assert_eq!(db.get(&[0], Column::Metadata).unwrap(), None);
+        assert_eq!(
+            db.get(&[1], Column::Metadata).unwrap(),
+            Some(Arc::new(vec![1]))
+        );
+        assert_eq!(db.compaction_debt(Column::Metadata).unwrap(), 0);
+    }
+
     #[test]
     fn delete_and_get() {
         let key = vec![0xA, 0xB, 0xC];
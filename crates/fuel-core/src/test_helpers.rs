@@ -0,0 +1,230 @@
+//! # Helpers for running a node in the integration tests
+//!
+//! The [`TestNodeBuilder`] starts an in-memory node without automatic block
+//! production. The blocks are produced only by the [`TestNode::produce_blocks`],
+//! and their timestamps are controlled by the test via the node's clock.
+
+use crate::{
+    chain_config::ChainConfig,
+    database::Database,
+    service::{
+        Config,
+        DbType,
+        FuelService,
+        ServiceTrait,
+    },
+};
+use fuel_core_poa::Trigger;
+use fuel_core_types::{
+    fuel_types::BlockHeight,
+    tai64::Tai64,
+};
+use std::{
+    net::SocketAddr,
+    time::Duration,
+};
+
+#[cfg(feature = "relayer")]
+use fuel_core_relayer::test_helpers::{
+    eth_node::{
+        spawn_eth_node,
+        EthNodeHandle,
+    },
+    middleware::MockMiddleware,
+};
+#[cfg(feature = "relayer")]
+use std::sync::Arc;
+
+/// The default interval between the timestamps of the produced blocks.
+pub const DEFAULT_BLOCK_TIME: Duration = Duration::from_secs(1);
+
+/// Builds the [`TestNode`].
+pub struct TestNodeBuilder {
+    config: Config,
+    start_time: Tai64,
+    block_time: Duration,
+    #[cfg(feature = "relayer")]
+    eth_node: Option<Arc<MockMiddleware>>,
+}
+
+impl Default for TestNodeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestNodeBuilder {
+    /// Creates the builder of the local node with the in-memory database.
+    pub fn new() -> Self {
+        let mut config = Config::local_node();
+        config.database_type = DbType::InMemory;
+        config.block_production = Trigger::Never;
+        // The `debug` flag enables the manual block production.
+        config.debug = true;
+        Self {
+            config,
+            // The genesis block has the `UNIX_EPOCH` timestamp.
+            start_time: Tai64::UNIX_EPOCH,
+            block_time: DEFAULT_BLOCK_TIME,
+            #[cfg(feature = "relayer")]
+            eth_node: None,
+        }
+    }
+
+    /// Sets the chain config of the node.
+    pub fn with_chain_config(mut self, chain_config: ChainConfig) -> Self {
+        self.config.chain_conf = chain_config;
+        self
+    }
+
+    /// Enables or disables the UTXO validation.
+    pub fn with_utxo_validation(mut self, utxo_validation: bool) -> Self {
+        self.config.utxo_validation = utxo_validation;
+        self.config.txpool.utxo_validation = utxo_validation;
+        self
+    }
+
+    /// Sets the time of the node's clock at the start. The first block produced
+    /// by the node has the timestamp `start_time + block_time`.
+    pub fn with_start_time(mut self, start_time: Tai64) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Sets the interval between the timestamps of the produced blocks.
+    pub fn with_block_time(mut self, block_time: Duration) -> Self {
+        self.block_time = block_time;
+        self
+    }
+
+    /// Modifies the config of the node with the `f`.
+    pub fn with_config<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(&mut Config),
+    {
+        f(&mut self.config);
+        self
+    }
+
+    #[cfg(feature = "relayer")]
+    /// Enables the relayer that uses the `eth_node` as the Ethereum node.
+    /// The test can update the data of the `eth_node` while the node is running.
+    pub fn with_mock_relayer(mut self, eth_node: Arc<MockMiddleware>) -> Self {
+        self.eth_node = Some(eth_node);
+        self
+    }
+
+    /// Starts the node.
+    pub async fn start(self) -> anyhow::Result<TestNode> {
+        let TestNodeBuilder {
+            #[allow(unused_mut)]
+            mut config,
+            start_time,
+            block_time,
+            #[cfg(feature = "relayer")]
+            eth_node,
+        } = self;
+
+        #[cfg(feature = "relayer")]
+        let eth_node = match eth_node {
+            Some(eth_node) => {
+                let handle = spawn_eth_node(eth_node).await;
+                let mut relayer = config.relayer.take().unwrap_or_default();
+                relayer.relayer = Some(handle.url());
+                config.relayer = Some(relayer);
+                Some(handle)
+            }
+            None => None,
+        };
+
+        let database = Database::in_memory();
+        let service = FuelService::from_database(database.clone(), config).await?;
+
+        Ok(TestNode {
+            service,
+            database,
+            now: start_time,
+            block_time,
+            #[cfg(feature = "relayer")]
+            eth_node,
+        })
+    }
+}
+
+/// The running node created by the [`TestNodeBuilder`].
+pub struct TestNode {
+    /// The service of the node.
+    pub service: FuelService,
+    /// The database of the node.
+    pub database: Database,
+    now: Tai64,
+    block_time: Duration,
+    #[cfg(feature = "relayer")]
+    eth_node: Option<EthNodeHandle>,
+}
+
+impl TestNode {
+    /// The address of the GraphQL API of the node.
+    pub fn bound_address(&self) -> SocketAddr {
+        self.service.bound_address
+    }
+
+    /// The current time of the node's clock.
+    pub fn now(&self) -> Tai64 {
+        self.now
+    }
+
+    /// Moves the node's clock forward by `duration`.
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.now = Tai64(self.now.0.saturating_add(duration.as_secs()));
+    }
+
+    /// Produces `number_of_blocks` blocks one by one. The clock is advanced by the
+    /// block time before each block, and the block gets the time of the clock.
+    ///
+    /// Returns the height of the latest block.
+    pub async fn produce_blocks(
+        &mut self,
+        number_of_blocks: u32,
+    ) -> anyhow::Result<BlockHeight> {
+        let poa = self
+            .service
+            .shared
+            .poa
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("The block production is disabled"))?;
+        for _ in 0..number_of_blocks {
+            let block_time = self
+                .now
+                .0
+                .checked_add(self.block_time.as_secs())
+                .map(Tai64)
+                .ok_or_else(|| anyhow::anyhow!("The time of the clock overflowed"))?;
+            poa.manually_produce_block(Some(block_time), 1).await?;
+            self.now = block_time;
+        }
+        Ok(self.database.latest_height()?)
+    }
+
+    #[cfg(feature = "relayer")]
+    /// The address of the mock Ethereum node used by the relayer.
+    pub fn eth_node_address(&self) -> Option<SocketAddr> {
+        self.eth_node.as_ref().map(|handle| handle.address)
+    }
+
+    #[cfg(feature = "relayer")]
+    /// Waits until the relayer is synced with the mock Ethereum node.
+    pub async fn await_relayer_synced(&self) -> anyhow::Result<()> {
+        self.service.await_relayer_synced().await
+    }
+
+    /// Stops the node and the mock Ethereum node.
+    pub async fn shutdown(self) -> anyhow::Result<()> {
+        self.service.stop_and_await().await?;
+        #[cfg(feature = "relayer")]
+        if let Some(eth_node) = self.eth_node {
+            let _ = eth_node.shutdown.send(());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+//! The crate `fuel-core-light-client` verifies the chain of the Fuel block headers and
+//! their consensus without the state of the full node. It allows bridges and wallets to
+//! follow the chain by downloading only the sealed headers starting from the trusted genesis.
+
+#![deny(unused_crate_dependencies)]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+use fuel_core_types::{
+    blockchain::{
+        consensus::{
+            poa::{
+                PoAConsensus,
+                PoAMultiSigConsensus,
+            },
+            Consensus,
+        },
+        header::BlockHeader,
+        primitives::BlockId,
+        SealedBlockHeader,
+    },
+    fuel_merkle::binary::in_memory::MerkleTree,
+    fuel_tx::Input,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Bytes32,
+    },
+};
+use std::fmt;
+
+#[cfg(test)]
+mod tests;
+
+/// The trusted parameters of the chain required to verify its headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenesisConfig<'a> {
+    /// The id of the genesis block of the chain.
+    pub genesis_block_id: BlockId,
+    /// The keys that seal the headers of the chain.
+    pub signing_keys: SigningKeys<'a>,
+}
+
+/// The keys that seal the headers, as defined by the consensus of the chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SigningKeys<'a> {
+    /// The address of the PoA block producer.
+    PoA(Address),
+    /// Each header is signed by at least `threshold` of the `signing_keys`.
+    PoAMultiSig {
+        /// The addresses of the authorized keys.
+        signing_keys: &'a [Address],
+        /// The required number of the signatures.
+        threshold: u16,
+    },
+}
+
+/// The reason why the header is not valid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The chain doesn't have any header.
+    EmptyChain,
+    /// The first header of the chain is not the trusted genesis header.
+    GenesisMismatch {
+        /// The id of the trusted genesis block.
+        expected: BlockId,
+        /// The id of the first header of the chain.
+        actual: BlockId,
+    },
+    /// The header has the unexpected type of the consensus.
+    UnexpectedConsensus(BlockHeight),
+    /// The header doesn't follow the previous header.
+    HeightMismatch {
+        /// The height that follows the previous header.
+        expected: BlockHeight,
+        /// The height of the header.
+        actual: BlockHeight,
+    },
+    /// The previous root of the header doesn't match the root of the previous headers.
+    PrevRootMismatch(BlockHeight),
+    /// The DA height of the header is lower than the DA height of the previous header.
+    DaHeightDecreased(BlockHeight),
+    /// The time of the header is lower than the time of the previous header.
+    TimeDecreased(BlockHeight),
+    /// The application hash of the header doesn't match its application header.
+    ApplicationHashMismatch(BlockHeight),
+    /// The header is not signed by the block producer.
+    InvalidSignature(BlockHeight),
+    /// The header is signed by fewer authorized keys than the threshold.
+    NotEnoughSignatures {
+        /// The height of the header.
+        height: BlockHeight,
+        /// The number of the signatures of the header.
+        signatures: usize,
+        /// The required number of the signatures.
+        threshold: u16,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyChain => write!(f, "The chain of headers is empty"),
+            Error::GenesisMismatch { expected, actual } => write!(
+                f,
+                "The genesis block {actual:#x} doesn't match the trusted one {expected:#x}"
+            ),
+            Error::UnexpectedConsensus(height) => {
+                write!(f, "The header at height {height} has unexpected consensus")
+            }
+            Error::HeightMismatch { expected, actual } => write!(
+                f,
+                "The height of the next block should be {expected}, but it is {actual}"
+            ),
+            Error::PrevRootMismatch(_) => write!(
+                f,
+                "Previous root of the next block should match the previous block root"
+            ),
+            Error::DaHeightDecreased(_) => {
+                write!(f, "The `da_height` of the next block can't be lower")
+            }
+            Error::TimeDecreased(_) => {
+                write!(f, "The `time` of the next block can't be lower")
+            }
+            Error::ApplicationHashMismatch(_) => write!(f, "The application hash mismatch."),
+            Error::InvalidSignature(height) => write!(
+                f,
+                "The header at height {height} is not signed by the block producer"
+            ),
+            Error::NotEnoughSignatures {
+                height,
+                signatures,
+                threshold,
+            } => write!(
+                f,
+                "The header at height {height} has {signatures} signatures, \
+                but {threshold} are required"
+            ),
+        }
+    }
+}
+
+/// Verifies the chain of the sealed `headers` starting from the genesis header.
+///
+/// Each header should follow the previous one, commit to the root of all previous headers
+/// and be signed by the keys from the `genesis_config`.
+///
+/// Returns the id of the last header of the chain.
+pub fn verify_header_chain(
+    headers: &[SealedBlockHeader],
+    genesis_config: &GenesisConfig,
+) -> Result<BlockId, Error> {
+    let (genesis, headers) = headers.split_first().ok_or(Error::EmptyChain)?;
+    let genesis_id = verify_genesis_header(genesis, genesis_config)?;
+
+    let mut tree = MerkleTree::new();
+    tree.push(genesis_id.as_slice());
+    let mut prev_header = &genesis.entity;
+    let mut last_id = genesis_id;
+    for sealed_header in headers {
+        let header = &sealed_header.entity;
+        verify_next_header(prev_header, &tree.root().into(), header)?;
+        match (&genesis_config.signing_keys, &sealed_header.consensus) {
+            (SigningKeys::PoA(signing_key), Consensus::PoA(consensus)) => {
+                verify_poa_consensus(signing_key, header, consensus)?
+            }
+            (
+                SigningKeys::PoAMultiSig {
+                    signing_keys,
+                    threshold,
+                },
+                Consensus::PoAMultiSig(consensus),
+            ) => verify_poa_multisig_consensus(
+                signing_keys,
+                *threshold,
+                header,
+                consensus,
+            )?,
+            _ => return Err(Error::UnexpectedConsensus(*header.height())),
+        }
+
+        last_id = header_id(header);
+        tree.push(last_id.as_slice());
+        prev_header = header;
+    }
+
+    Ok(last_id)
+}
+
+/// Verifies that the `header` is the trusted genesis header.
+///
+/// Returns the id of the genesis header.
+pub fn verify_genesis_header(
+    header: &SealedBlockHeader,
+    genesis_config: &GenesisConfig,
+) -> Result<BlockId, Error> {
+    let height = *header.entity.height();
+    if !matches!(header.consensus, Consensus::Genesis(_)) {
+        return Err(Error::UnexpectedConsensus(height))
+    }
+    verify_application_hash(&header.entity)?;
+
+    let actual = header_id(&header.entity);
+    if actual != genesis_config.genesis_block_id {
+        return Err(Error::GenesisMismatch {
+            expected: genesis_config.genesis_block_id,
+            actual,
+        })
+    }
+    Ok(actual)
+}
+
+/// Verifies the fields of the `header` that follows the `prev_header`.
+///
+/// The `prev_root` is the root of the merkle tree of all block ids up to
+/// and including the `prev_header`.
+pub fn verify_next_header(
+    prev_header: &BlockHeader,
+    prev_root: &Bytes32,
+    header: &BlockHeader,
+) -> Result<(), Error> {
+    let height = *header.height();
+    let expected_height = prev_header
+        .height()
+        .checked_add(1u32)
+        .map(BlockHeight::from);
+    if Some(height) != expected_height {
+        return Err(Error::HeightMismatch {
+            expected: expected_height.unwrap_or(*prev_header.height()),
+            actual: height,
+        })
+    }
+    if header.prev_root() != prev_root {
+        return Err(Error::PrevRootMismatch(height))
+    }
+    if header.da_height < prev_header.da_height {
+        return Err(Error::DaHeightDecreased(height))
+    }
+    if header.time() < prev_header.time() {
+        return Err(Error::TimeDecreased(height))
+    }
+    verify_application_hash(header)
+}
+
+/// Verifies that the `header` is signed by the block producer with the `signing_key`.
+pub fn verify_poa_consensus(
+    signing_key: &Address,
+    header: &BlockHeader,
+    consensus: &PoAConsensus,
+) -> Result<(), Error> {
+    let id = header_id(header);
+    let signed = consensus
+        .signature
+        .recover(id.as_message())
+        .is_ok_and(|public_key| Input::owner(&public_key) == *signing_key);
+    if signed {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature(*header.height()))
+    }
+}
+
+/// Verifies that the `header` is signed by at least `threshold` of the `signing_keys`.
+///
+/// The signatures should be ordered by the addresses of their signers,
+/// so no key signs the header twice.
+pub fn verify_poa_multisig_consensus(
+    signing_keys: &[Address],
+    threshold: u16,
+    header: &BlockHeader,
+    consensus: &PoAMultiSigConsensus,
+) -> Result<(), Error> {
+    let height = *header.height();
+    let id = header_id(header);
+    let mut prev_signer: Option<Address> = None;
+    for signature in &consensus.signatures {
+        let signer = signature
+            .recover(id.as_message())
+            .map(|public_key| Input::owner(&public_key))
+            .map_err(|_| Error::InvalidSignature(height))?;
+        let ordered = prev_signer.is_none_or(|prev_signer| prev_signer < signer);
+        if !ordered || !signing_keys.contains(&signer) {
+            return Err(Error::InvalidSignature(height))
+        }
+        prev_signer = Some(signer);
+    }
+
+    let signatures = consensus.signatures.len();
+    if signatures < threshold as usize {
+        return Err(Error::NotEnoughSignatures {
+            height,
+            signatures,
+            threshold,
+        })
+    }
+    Ok(())
+}
+
+fn verify_application_hash(header: &BlockHeader) -> Result<(), Error> {
+    if header.consensus.application_hash == header.application.hash() {
+        Ok(())
+    } else {
+        Err(Error::ApplicationHashMismatch(*header.height()))
+    }
+}
+
+/// Calculates the id of the header without trusting the cached one.
+fn header_id(header: &BlockHeader) -> BlockId {
+    header.consensus.hash()
+}
@@ -0,0 +1,266 @@
+use super::*;
+use fuel_core_types::{
+    blockchain::consensus::Sealed,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    tai64::Tai64,
+};
+use test_case::test_case;
+
+const CHAIN_LENGTH: u32 = 5;
+
+fn secret_key(byte: u8) -> SecretKey {
+    SecretKey::try_from(&[byte; 32][..]).unwrap()
+}
+
+fn sign(header: &BlockHeader, secret_key: &SecretKey) -> Consensus {
+    let signature = Signature::sign(secret_key, header.id().as_message());
+    Consensus::PoA(PoAConsensus::new(signature))
+}
+
+/// Creates the valid chain where each header is modified by `modify`
+/// before its metadata is calculated.
+fn chain_with(modify: impl Fn(&mut BlockHeader)) -> Vec<SealedBlockHeader> {
+    let secret_key = secret_key(0xAA);
+    let mut tree = MerkleTree::new();
+
+    let mut genesis = BlockHeader::default();
+    modify(&mut genesis);
+    genesis.recalculate_metadata();
+    tree.push(genesis.id().as_slice());
+    let mut headers = Vec::new();
+    headers.push(Sealed {
+        entity: genesis,
+        consensus: Consensus::Genesis(Default::default()),
+    });
+
+    for height in 1..CHAIN_LENGTH {
+        let mut header = BlockHeader::default();
+        header.consensus.height = height.into();
+        header.consensus.prev_root = tree.root().into();
+        header.consensus.time = Tai64(height as u64);
+        modify(&mut header);
+        header.recalculate_metadata();
+        tree.push(header.id().as_slice());
+        headers.push(Sealed {
+            consensus: sign(&header, &secret_key),
+            entity: header,
+        });
+    }
+    headers
+}
+
+fn genesis_config(headers: &[SealedBlockHeader]) -> GenesisConfig<'static> {
+    GenesisConfig {
+        genesis_block_id: headers[0].entity.id(),
+        signing_keys: SigningKeys::PoA(Input::owner(&secret_key(0xAA).public_key())),
+    }
+}
+
+#[test]
+fn verify_header_chain_returns_id_of_the_last_header() {
+    let headers = chain_with(|_| {});
+
+    let result = verify_header_chain(&headers, &genesis_config(&headers));
+
+    assert_eq!(result, Ok(headers.last().unwrap().entity.id()));
+}
+
+#[test]
+fn verify_header_chain_accepts_only_genesis() {
+    let headers = chain_with(|_| {});
+
+    let result = verify_header_chain(&headers[..1], &genesis_config(&headers));
+
+    assert_eq!(result, Ok(headers[0].entity.id()));
+}
+
+#[test]
+fn verify_header_chain_fails_for_empty_chain() {
+    let headers = chain_with(|_| {});
+
+    let result = verify_header_chain(&[], &genesis_config(&headers));
+
+    assert_eq!(result, Err(Error::EmptyChain));
+}
+
+#[test]
+fn verify_header_chain_fails_for_another_genesis() {
+    let headers = chain_with(|_| {});
+    let mut config = genesis_config(&headers);
+    config.genesis_block_id = BlockId::default();
+
+    let result = verify_header_chain(&headers, &config);
+
+    assert_eq!(
+        result,
+        Err(Error::GenesisMismatch {
+            expected: BlockId::default(),
+            actual: headers[0].entity.id(),
+        })
+    );
+}
+
+#[test]
+fn verify_header_chain_fails_for_another_block_producer() {
+    let headers = chain_with(|_| {});
+    let mut config = genesis_config(&headers);
+    config.signing_keys = SigningKeys::PoA(Input::owner(&secret_key(0xBB).public_key()));
+
+    let result = verify_header_chain(&headers, &config);
+
+    assert_eq!(result, Err(Error::InvalidSignature(1u32.into())));
+}
+
+#[test_case(
+    |header| if *header.height() == 2u32.into() { header.consensus.time = Tai64(0) }
+    => Err(Error::TimeDecreased(2u32.into()));
+    "time is lower than the time of the previous header"
+)]
+#[test_case(
+    |header| if *header.height() == 1u32.into() { header.application.da_height = 5u64.into() }
+    => Err(Error::DaHeightDecreased(2u32.into()));
+    "da height is lower than the da height of the previous header"
+)]
+#[test_case(
+    |header| if *header.height() == 3u32.into() { header.consensus.prev_root = Bytes32::zeroed() }
+    => Err(Error::PrevRootMismatch(3u32.into()));
+    "prev root doesn't match the root of the previous headers"
+)]
+fn verify_header_chain_fails_for_invalid_fields(
+    modify: fn(&mut BlockHeader),
+) -> Result<BlockId, Error> {
+    let headers = chain_with(modify);
+
+    verify_header_chain(&headers, &genesis_config(&headers))
+}
+
+#[test_case(
+    |headers| { headers.remove(2); }
+    => Err(Error::HeightMismatch { expected: 2u32.into(), actual: 3u32.into() });
+    "header is skipped"
+)]
+#[test_case(
+    |headers| headers[2].entity.application.generated.transactions_count = 1
+    => Err(Error::ApplicationHashMismatch(2u32.into()));
+    "application header is modified"
+)]
+#[test_case(
+    |headers| headers[2].entity.consensus.time = Tai64(100)
+    => Err(Error::InvalidSignature(2u32.into()));
+    "signed header is modified"
+)]
+#[test_case(
+    |headers| headers[2].consensus = sign(&headers[2].entity, &secret_key(0xBB))
+    => Err(Error::InvalidSignature(2u32.into()));
+    "header is signed by another key"
+)]
+#[test_case(
+    |headers| headers[2].consensus = Consensus::Genesis(Default::default())
+    => Err(Error::UnexpectedConsensus(2u32.into()));
+    "header has genesis consensus"
+)]
+#[test_case(
+    |headers| headers[0].consensus = headers[1].consensus.clone()
+    => Err(Error::UnexpectedConsensus(0u32.into()));
+    "genesis header has PoA consensus"
+)]
+fn verify_header_chain_fails_for_tampered_chain(
+    tamper: fn(&mut Vec<SealedBlockHeader>),
+) -> Result<BlockId, Error> {
+    let mut headers = chain_with(|_| {});
+    let config = genesis_config(&headers);
+    tamper(&mut headers);
+
+    verify_header_chain(&headers, &config)
+}
+
+/// Signs the `header` with the keys in the order of their addresses.
+fn multisig(header: &BlockHeader, key_bytes: &[u8]) -> PoAMultiSigConsensus {
+    let mut keys: Vec<_> = key_bytes.iter().map(|byte| secret_key(*byte)).collect();
+    keys.sort_by_key(|key| Input::owner(&key.public_key()));
+    let signatures = keys
+        .iter()
+        .map(|key| Signature::sign(key, header.id().as_message()))
+        .collect();
+    PoAMultiSigConsensus::new(signatures)
+}
+
+#[test_case(&[0xAA, 0xBB] => Ok(()); "threshold of the authorized keys")]
+#[test_case(&[0xAA, 0xBB, 0xCC] => Ok(()); "all authorized keys")]
+#[test_case(
+    &[0xAA]
+    => Err(Error::NotEnoughSignatures { height: 1u32.into(), signatures: 1, threshold: 2 });
+    "fewer keys than the threshold"
+)]
+#[test_case(
+    &[0xAA, 0xDD]
+    => Err(Error::InvalidSignature(1u32.into()));
+    "unauthorized key"
+)]
+fn verify_poa_multisig_consensus_checks_the_signers(
+    key_bytes: &[u8],
+) -> Result<(), Error> {
+    let headers = chain_with(|_| {});
+    let header = &headers[1].entity;
+    let signing_keys: Vec<_> = [0xAA, 0xBB, 0xCC]
+        .iter()
+        .map(|byte| Input::owner(&secret_key(*byte).public_key()))
+        .collect();
+
+    verify_poa_multisig_consensus(&signing_keys, 2, header, &multisig(header, key_bytes))
+}
+
+#[test]
+fn verify_poa_multisig_consensus_rejects_the_repeated_signer() {
+    let headers = chain_with(|_| {});
+    let header = &headers[1].entity;
+    let signing_keys = [Input::owner(&secret_key(0xAA).public_key())];
+    let mut consensus = multisig(header, &[0xAA]);
+    consensus.signatures.push(consensus.signatures[0]);
+
+    let result = verify_poa_multisig_consensus(&signing_keys, 2, header, &consensus);
+
+    assert_eq!(result, Err(Error::InvalidSignature(1u32.into())));
+}
+
+#[test]
+fn verify_header_chain_verifies_the_multisig_headers() {
+    let mut headers = chain_with(|_| {});
+    for sealed in headers.iter_mut().skip(1) {
+        sealed.consensus =
+            Consensus::PoAMultiSig(multisig(&sealed.entity, &[0xAA, 0xCC]));
+    }
+    let signing_keys: Vec<_> = [0xAA, 0xBB, 0xCC]
+        .iter()
+        .map(|byte| Input::owner(&secret_key(*byte).public_key()))
+        .collect();
+    let mut config = genesis_config(&headers);
+    config.signing_keys = SigningKeys::PoAMultiSig {
+        signing_keys: &signing_keys,
+        threshold: 2,
+    };
+
+    let result = verify_header_chain(&headers, &config);
+    assert_eq!(result, Ok(headers.last().unwrap().entity.id()));
+
+    // The multisig headers don't match the single block producer.
+    let result = verify_header_chain(&headers, &genesis_config(&headers));
+    assert_eq!(result, Err(Error::UnexpectedConsensus(1u32.into())));
+
+    config.signing_keys = SigningKeys::PoAMultiSig {
+        signing_keys: &signing_keys,
+        threshold: 3,
+    };
+    let result = verify_header_chain(&headers, &config);
+    assert_eq!(
+        result,
+        Err(Error::NotEnoughSignatures {
+            height: 1u32.into(),
+            signatures: 2,
+            threshold: 3,
+        })
+    );
+}
@@ -0,0 +1,57 @@
+use prometheus_client::{
+    metrics::histogram::Histogram,
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+pub struct ExecutionMetrics {
+    pub registry: Registry,
+    tx_execution_duration: Histogram,
+    tx_gas_used_ratio: Histogram,
+}
+
+impl ExecutionMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        // The transactions take from microseconds to seconds.
+        let tx_execution_duration = Histogram::new(
+            [
+                0.0001, 0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5,
+                1.0,
+            ]
+            .into_iter(),
+        );
+        let tx_gas_used_ratio = Histogram::new(
+            [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0].into_iter(),
+        );
+
+        registry.register(
+            "executor_tx_execution_duration_s",
+            "The wall time of the execution of each transaction of the produced or validated blocks",
+            tx_execution_duration.clone(),
+        );
+        registry.register(
+            "executor_tx_gas_used_ratio",
+            "The share of the gas limit of each script used by its execution",
+            tx_gas_used_ratio.clone(),
+        );
+
+        Self {
+            registry,
+            tx_execution_duration,
+            tx_gas_used_ratio,
+        }
+    }
+
+    pub fn observe_transaction(&self, duration_secs: f64, gas_used_ratio: Option<f64>) {
+        self.tx_execution_duration.observe(duration_secs);
+        if let Some(ratio) = gas_used_ratio {
+            self.tx_gas_used_ratio.observe(ratio);
+        }
+    }
+}
+
+static EXECUTION_METRICS: OnceLock<ExecutionMetrics> = OnceLock::new();
+pub fn execution_metrics() -> &'static ExecutionMetrics {
+    EXECUTION_METRICS.get_or_init(ExecutionMetrics::new)
+}
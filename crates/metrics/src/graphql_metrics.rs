@@ -2,12 +2,27 @@ use crate::timing_buckets;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         histogram::Histogram,
     },
     registry::Registry,
 };
-use std::sync::OnceLock;
+use std::{
+    collections::HashSet,
+    sync::{
+        Mutex,
+        OnceLock,
+    },
+};
+
+/// The max number of the operation names used as the labels. The rest of the
+/// operations are observed under the [`OTHER_OPERATIONS_LABEL`], so the number of
+/// the series stays bounded across the resets of the statistics of the operations.
+pub const MAX_OPERATION_LABELS: usize = 1000;
+
+/// The label of the operations above the [`MAX_OPERATION_LABELS`] limit.
+pub const OTHER_OPERATIONS_LABEL: &str = "<other>";
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
 pub struct Label {
@@ -15,9 +30,20 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct OperationLabel {
+    // the name of the graphql operation
+    operation: String,
+}
+
 pub struct GraphqlMetrics {
     pub registry: Registry,
     requests: Family<Label, Histogram>,
+    operations: Family<OperationLabel, Histogram>,
+    operation_errors: Family<OperationLabel, Counter>,
+    operation_rows: Family<OperationLabel, Counter>,
+    // It is okay to use std mutex because the lock is held only to check the name.
+    operation_labels: Mutex<HashSet<String>>,
 }
 
 impl GraphqlMetrics {
@@ -27,7 +53,35 @@ impl GraphqlMetrics {
             Histogram::new(timing_buckets().iter().cloned())
         });
         registry.register("graphql_request_duration_seconds", "", requests.clone());
-        Self { registry, requests }
+        let operations =
+            Family::<OperationLabel, Histogram>::new_with_constructor(|| {
+                Histogram::new(timing_buckets().iter().cloned())
+            });
+        let operation_errors = Family::<OperationLabel, Counter>::default();
+        let operation_rows = Family::<OperationLabel, Counter>::default();
+        registry.register(
+            "graphql_operation_duration_seconds",
+            "The execution time of the named GraphQL operations",
+            operations.clone(),
+        );
+        registry.register(
+            "graphql_operation_errors",
+            "The number of the executions of the named GraphQL operations with errors",
+            operation_errors.clone(),
+        );
+        registry.register(
+            "graphql_operation_rows",
+            "The number of the list items returned by the named GraphQL operations",
+            operation_rows.clone(),
+        );
+        Self {
+            registry,
+            requests,
+            operations,
+            operation_errors,
+            operation_rows,
+            operation_labels: Mutex::new(HashSet::new()),
+        }
     }
 
     pub fn graphql_observe(&self, query: &str, time: f64) {
@@ -36,6 +90,48 @@ impl GraphqlMetrics {
         });
         histogram.observe(time);
     }
+
+    pub fn operation_observe(&self, operation: &str, time: f64, failed: bool, rows: u64) {
+        let label = OperationLabel {
+            operation: self.operation_label(operation).to_string(),
+        };
+        self.operations.get_or_create(&label).observe(time);
+        if failed {
+            self.operation_errors.get_or_create(&label).inc();
+        }
+        self.operation_rows.get_or_create(&label).inc_by(rows);
+    }
+
+    fn operation_label<'a>(&self, operation: &'a str) -> &'a str {
+        let mut labels = self.operation_labels.lock().expect("Never poisoned");
+        if labels.contains(operation) {
+            operation
+        } else if labels.len() < MAX_OPERATION_LABELS {
+            labels.insert(operation.to_string());
+            operation
+        } else {
+            OTHER_OPERATIONS_LABEL
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation_labels_are_bounded() {
+        let metrics = GraphqlMetrics::new();
+        for i in 0..MAX_OPERATION_LABELS {
+            assert_eq!(
+                metrics.operation_label(&format!("Query{i}")),
+                format!("Query{i}")
+            );
+        }
+
+        assert_eq!(metrics.operation_label("Query0"), "Query0");
+        assert_eq!(metrics.operation_label("New"), OTHER_OPERATIONS_LABEL);
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
@@ -1,6 +1,7 @@
 use crate::timing_buckets;
 use prometheus_client::{
     metrics::{
+        counter::Counter,
         gauge::Gauge,
         histogram::Histogram,
     },
@@ -18,6 +19,7 @@ pub struct ImporterMetrics {
     pub block_height: Gauge,
     pub latest_block_import_timestamp: Gauge<f64, AtomicU64>,
     pub execute_and_commit_duration: Histogram,
+    pub competing_blocks: Counter,
 }
 
 impl Default for ImporterMetrics {
@@ -29,6 +31,7 @@ impl Default for ImporterMetrics {
         let latest_block_import_ms = Gauge::default();
         let execute_and_commit_duration =
             Histogram::new(timing_buckets().iter().cloned());
+        let competing_blocks = Counter::default();
 
         registry.register(
             "importer_tx_count",
@@ -54,12 +57,19 @@ impl Default for ImporterMetrics {
             execute_and_commit_duration.clone(),
         );
 
+        registry.register(
+            "importer_competing_blocks",
+            "The number of valid blocks that compete with the canonical blocks at the same height",
+            competing_blocks.clone(),
+        );
+
         Self {
             registry,
             total_txs_count: tx_count_gauge,
             block_height: block_height_gauge,
             latest_block_import_timestamp: latest_block_import_ms,
             execute_and_commit_duration,
+            competing_blocks,
         }
     }
 }
@@ -4,12 +4,15 @@
 use std::sync::OnceLock;
 
 pub mod core_metrics;
+pub mod execution_metrics;
 pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod relayer_metrics;
 pub mod response;
 pub mod services;
+pub mod storage_metrics;
 pub mod txpool_metrics;
 
 // recommended bucket defaults for logging response times
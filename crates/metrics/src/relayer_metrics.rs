@@ -0,0 +1,123 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+    registry::Registry,
+};
+use std::sync::{
+    atomic::AtomicU64,
+    OnceLock,
+};
+
+/// The buckets of the latencies of the messages in seconds, from the fast local
+/// import up to the slow finalization of the DA layer.
+const MESSAGE_LATENCY_BUCKETS: [f64; 12] = [
+    0.5, 1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0, 3600.0,
+];
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct Label {
+    // the kind of the relayer error, like `rpc` or `decode`
+    kind: String,
+}
+
+pub struct RelayerMetrics {
+    pub registry: Registry,
+    errors: Family<Label, Counter>,
+    committer_wallet_balance: Gauge<f64, AtomicU64>,
+    committer_wallet_balance_low: Gauge,
+    message_store_latency: Histogram,
+    message_spendable_latency: Histogram,
+    message_e2e_latency: Histogram,
+}
+
+impl RelayerMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let errors = Family::<Label, Counter>::default();
+
+        registry.register(
+            "relayer_errors",
+            "The number of the failed iterations of the relayer by the kind of the error",
+            errors.clone(),
+        );
+
+        let committer_wallet_balance = Gauge::default();
+        registry.register(
+            "relayer_committer_wallet_balance",
+            "The balance of the wallet of the block committer in ETH",
+            committer_wallet_balance.clone(),
+        );
+
+        let committer_wallet_balance_low = Gauge::default();
+        registry.register(
+            "relayer_committer_wallet_balance_low",
+            "1 if the balance of the wallet of the block committer is below the threshold",
+            committer_wallet_balance_low.clone(),
+        );
+
+        let message_store_latency = Histogram::new(MESSAGE_LATENCY_BUCKETS.into_iter());
+        registry.register(
+            "relayer_message_store_latency_seconds",
+            "The time from the finalization of the DA block of the message to its storage",
+            message_store_latency.clone(),
+        );
+
+        let message_spendable_latency =
+            Histogram::new(MESSAGE_LATENCY_BUCKETS.into_iter());
+        registry.register(
+            "relayer_message_spendable_latency_seconds",
+            "The time from the storage of the message to the first block that can spend it",
+            message_spendable_latency.clone(),
+        );
+
+        let message_e2e_latency = Histogram::new(MESSAGE_LATENCY_BUCKETS.into_iter());
+        registry.register(
+            "relayer_message_e2e_latency_seconds",
+            "The time from the finalization of the DA block of the message to the first \
+            block that can spend it",
+            message_e2e_latency.clone(),
+        );
+
+        Self {
+            registry,
+            errors,
+            committer_wallet_balance,
+            committer_wallet_balance_low,
+            message_store_latency,
+            message_spendable_latency,
+            message_e2e_latency,
+        }
+    }
+
+    pub fn record_error(&self, kind: &str) {
+        self.errors
+            .get_or_create(&Label {
+                kind: kind.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn set_committer_wallet_balance(&self, balance: f64, low: bool) {
+        self.committer_wallet_balance.set(balance);
+        self.committer_wallet_balance_low.set(low as i64);
+    }
+
+    pub fn observe_message_stored(&self, since_finalized: f64) {
+        self.message_store_latency.observe(since_finalized);
+    }
+
+    pub fn observe_message_spendable(&self, since_stored: f64, since_finalized: f64) {
+        self.message_spendable_latency.observe(since_stored);
+        self.message_e2e_latency.observe(since_finalized);
+    }
+}
+
+static RELAYER_METRICS: OnceLock<RelayerMetrics> = OnceLock::new();
+pub fn relayer_metrics() -> &'static RelayerMetrics {
+    RELAYER_METRICS.get_or_init(RelayerMetrics::new)
+}
@@ -1,8 +1,11 @@
 use crate::{
+    execution_metrics::execution_metrics,
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    relayer_metrics::relayer_metrics,
     services::services_metrics,
+    storage_metrics::storage_metrics,
     txpool_metrics::txpool_metrics,
 };
 use axum::{
@@ -53,6 +56,18 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &storage_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &execution_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &relayer_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
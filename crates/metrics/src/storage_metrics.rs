@@ -0,0 +1,147 @@
+use prometheus_client::{
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct Label {
+    // the name of the database table
+    table: &'static str,
+}
+
+impl Label {
+    fn new(table: &'static str) -> Self {
+        Self { table }
+    }
+}
+
+pub struct StorageMetrics {
+    pub registry: Registry,
+    bytes_written: Family<Label, Counter>,
+    size: Family<Label, Gauge>,
+    keys: Family<Label, Gauge>,
+    write_rate: Family<Label, Gauge>,
+    compaction_debt: Family<Label, Gauge>,
+    compactions: Family<Label, Counter>,
+    disk_free_space: Gauge,
+    disk_degraded: Gauge,
+}
+
+impl StorageMetrics {
+    fn new() -> Self {
+        let mut registry = Registry::default();
+        let bytes_written = Family::<Label, Counter>::default();
+        let size = Family::<Label, Gauge>::default();
+        let keys = Family::<Label, Gauge>::default();
+        let write_rate = Family::<Label, Gauge>::default();
+        let compaction_debt = Family::<Label, Gauge>::default();
+        let compactions = Family::<Label, Counter>::default();
+        let disk_free_space = Gauge::default();
+        let disk_degraded = Gauge::default();
+
+        registry.register(
+            "storage_table_bytes_written",
+            "The number of bytes of the keys and values inserted into the table",
+            bytes_written.clone(),
+        );
+        registry.register(
+            "storage_table_size_bytes",
+            "The approximate size of the table on the disk",
+            size.clone(),
+        );
+        registry.register(
+            "storage_table_keys",
+            "The approximate number of keys in the table",
+            keys.clone(),
+        );
+        registry.register(
+            "storage_table_write_rate_bytes",
+            "The number of bytes written into the table per second since the previous refresh",
+            write_rate.clone(),
+        );
+        registry.register(
+            "storage_table_compaction_debt_bytes",
+            "The estimated number of bytes the background compaction of the table has to rewrite",
+            compaction_debt.clone(),
+        );
+        registry.register(
+            "storage_table_compactions",
+            "The number of the manual compactions of the table",
+            compactions.clone(),
+        );
+        registry.register(
+            "storage_disk_free_space_bytes",
+            "The free space of the disk with the database",
+            disk_free_space.clone(),
+        );
+        registry.register(
+            "storage_disk_degraded",
+            "Whether the node is in the degraded mode because of the low disk space",
+            disk_degraded.clone(),
+        );
+
+        Self {
+            registry,
+            bytes_written,
+            size,
+            keys,
+            write_rate,
+            compaction_debt,
+            compactions,
+            disk_free_space,
+            disk_degraded,
+        }
+    }
+
+    pub fn record_write(&self, table: &'static str, bytes: usize) {
+        self.bytes_written
+            .get_or_create(&Label::new(table))
+            .inc_by(bytes as u64);
+    }
+
+    pub fn bytes_written(&self, table: &'static str) -> u64 {
+        self.bytes_written.get_or_create(&Label::new(table)).get()
+    }
+
+    pub fn set_table_stats(
+        &self,
+        table: &'static str,
+        size: u64,
+        keys: u64,
+        write_rate: u64,
+    ) {
+        let label = Label::new(table);
+        self.size.get_or_create(&label).set(size as i64);
+        self.keys.get_or_create(&label).set(keys as i64);
+        self.write_rate.get_or_create(&label).set(write_rate as i64);
+    }
+
+    pub fn set_compaction_debt(&self, table: &'static str, debt: u64) {
+        self.compaction_debt
+            .get_or_create(&Label::new(table))
+            .set(debt as i64);
+    }
+
+    pub fn record_compaction(&self, table: &'static str) {
+        self.compactions.get_or_create(&Label::new(table)).inc();
+    }
+
+    pub fn set_disk_free_space(&self, free_space: u64) {
+        self.disk_free_space.set(free_space as i64);
+    }
+
+    pub fn set_disk_degraded(&self, degraded: bool) {
+        self.disk_degraded.set(degraded as i64);
+    }
+}
+
+static STORAGE_METRICS: OnceLock<StorageMetrics> = OnceLock::new();
+pub fn storage_metrics() -> &'static StorageMetrics {
+    STORAGE_METRICS.get_or_init(StorageMetrics::new)
+}
@@ -1,5 +1,11 @@
 use prometheus_client::{
-    metrics::histogram::Histogram,
+    encoding::EncodeLabelSet,
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
     registry::Registry,
 };
 use std::{
@@ -7,11 +13,36 @@ use std::{
     sync::OnceLock,
 };
 
+/// The upper bounds of the gas price buckets of the pooled transactions.
+const GAS_PRICE_BUCKETS: [u64; 6] = [1, 10, 100, 1_000, 10_000, 100_000];
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct ReasonLabel {
+    // the reason of the rejection or of the eviction, like `insufficient_fee` or `ttl`
+    reason: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct TxTypeLabel {
+    // the type of the transaction, like `script` or `create`
+    tx_type: String,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct GasPriceLabel {
+    // the upper bound of the gas price bucket, or `+Inf`
+    max_gas_price: String,
+}
+
 pub struct TxPoolMetrics {
     // Attaches each Metric to the Registry
     pub registry: Registry,
     pub gas_price_histogram: Histogram,
     pub tx_size_histogram: Histogram,
+    rejected_txs: Family<ReasonLabel, Counter>,
+    evicted_txs: Family<ReasonLabel, Counter>,
+    txs_by_type: Family<TxTypeLabel, Gauge>,
+    txs_by_gas_price: Family<GasPriceLabel, Gauge>,
 }
 
 impl Default for TxPoolMetrics {
@@ -30,6 +61,10 @@ impl Default for TxPoolMetrics {
             registry,
             gas_price_histogram,
             tx_size_histogram,
+            rejected_txs: Default::default(),
+            evicted_txs: Default::default(),
+            txs_by_type: Default::default(),
+            txs_by_gas_price: Default::default(),
         };
 
         metrics.registry.register(
@@ -44,10 +79,75 @@ impl Default for TxPoolMetrics {
             metrics.tx_size_histogram.clone(),
         );
 
+        metrics.registry.register(
+            "txpool_rejected_transactions",
+            "The number of the transactions rejected by the pool by the reason",
+            metrics.rejected_txs.clone(),
+        );
+
+        metrics.registry.register(
+            "txpool_evicted_transactions",
+            "The number of the transactions evicted from the pool by the reason",
+            metrics.evicted_txs.clone(),
+        );
+
+        metrics.registry.register(
+            "txpool_transactions_by_type",
+            "The number of the transactions in the pool by the type",
+            metrics.txs_by_type.clone(),
+        );
+
+        metrics.registry.register(
+            "txpool_transactions_by_gas_price",
+            "The number of the transactions in the pool by the gas price bucket",
+            metrics.txs_by_gas_price.clone(),
+        );
+
         metrics
     }
 }
 
+impl TxPoolMetrics {
+    pub fn record_rejection(&self, reason: &str) {
+        self.rejected_txs
+            .get_or_create(&ReasonLabel {
+                reason: reason.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn record_eviction(&self, reason: &str, count: usize) {
+        self.evicted_txs
+            .get_or_create(&ReasonLabel {
+                reason: reason.to_string(),
+            })
+            .inc_by(count as u64);
+    }
+
+    /// Adds the transaction to the composition of the pool if `delta` is positive,
+    /// or removes it if `delta` is negative.
+    pub fn record_pool_change(&self, tx_type: &str, gas_price: u64, delta: i64) {
+        self.txs_by_type
+            .get_or_create(&TxTypeLabel {
+                tx_type: tx_type.to_string(),
+            })
+            .inc_by(delta);
+        self.txs_by_gas_price
+            .get_or_create(&GasPriceLabel {
+                max_gas_price: gas_price_bucket(gas_price),
+            })
+            .inc_by(delta);
+    }
+}
+
+fn gas_price_bucket(gas_price: u64) -> String {
+    GAS_PRICE_BUCKETS
+        .iter()
+        .find(|max_gas_price| gas_price <= **max_gas_price)
+        .map(|max_gas_price| max_gas_price.to_string())
+        .unwrap_or_else(|| "+Inf".to_string())
+}
+
 static TXPOOL_METRICS: OnceLock<TxPoolMetrics> = OnceLock::new();
 pub fn txpool_metrics() -> &'static TxPoolMetrics {
     TXPOOL_METRICS.get_or_init(TxPoolMetrics::default)
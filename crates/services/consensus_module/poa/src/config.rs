@@ -1,9 +1,15 @@
+use crate::ports::{
+    BlockCosigner,
+    ChainClock,
+};
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
 };
+use std::sync::Arc;
 use tokio::time::Duration;
 
 #[derive(Debug, Clone)]
@@ -11,10 +17,27 @@ pub struct Config {
     pub trigger: Trigger,
     pub block_gas_limit: Word,
     pub signing_key: Option<Secret<SecretKeyWrapper>>,
+    /// The consensus of the chain. It defines whether the blocks are sealed by
+    /// the `signing_key` alone or by the threshold of the authorized keys.
+    pub consensus: ConsensusConfig,
+    /// The additional keys that seal the blocks of the multi-signature PoA
+    /// together with the `signing_key`.
+    pub cosigning_keys: Vec<Secret<SecretKeyWrapper>>,
+    /// The remote signers asked for the signatures of the multi-signature PoA
+    /// when the local keys don't reach the threshold.
+    pub cosigners: Vec<Arc<dyn BlockCosigner>>,
     pub metrics: bool,
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The deadline of the sealing and the import of the produced block. The gas limit
+    /// of the next block is halved each time the deadline is exceeded, and restored
+    /// once the block is sealed in time. `None` doesn't limit the sealing.
+    pub sealing_deadline: Option<Duration>,
+    /// The clock advanced by the `advance_time`. It keeps the advanced time across
+    /// the restarts and shares it with the dry runs. `None` keeps the advanced time
+    /// only in the memory of the service.
+    pub clock: Option<Arc<dyn ChainClock>>,
 }
 
 impl Default for Config {
@@ -23,10 +46,15 @@ impl Default for Config {
             trigger: Trigger::default(),
             block_gas_limit: 0,
             signing_key: None,
+            consensus: ConsensusConfig::default_poa(),
+            cosigning_keys: vec![],
+            cosigners: vec![],
             metrics: false,
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            sealing_deadline: None,
+            clock: None,
         }
     }
 }
@@ -5,10 +5,12 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
         header::BlockHeader,
         primitives::DaBlockHeight,
     },
     fuel_asm::Word,
+    fuel_crypto::Signature,
     fuel_tx::TxId,
     fuel_types::{
         BlockHeight,
@@ -24,6 +26,7 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use std::time::Duration;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait TransactionPool: Send + Sync {
@@ -89,6 +92,23 @@ pub trait RelayerPort {
     ) -> anyhow::Result<()>;
 }
 
+/// The remote signer of the blocks of the multi-signature PoA.
+#[async_trait::async_trait]
+pub trait BlockCosigner: core::fmt::Debug + Send + Sync {
+    /// Validates the `block` and returns the signature of its id by the key
+    /// of the signer.
+    async fn cosign(&self, block: &Block) -> anyhow::Result<Signature>;
+}
+
+/// The storage of the time added to the wall clock by the `advance_time`.
+pub trait ChainClock: core::fmt::Debug + Send + Sync {
+    /// Returns the total time added to the wall clock.
+    fn time_offset(&self) -> Duration;
+
+    /// Stores the total time added to the wall clock.
+    fn set_time_offset(&self, time_offset: Duration) -> anyhow::Result<()>;
+}
+
 #[cfg_attr(test, mockall::automock)]
 pub trait P2pPort: Send + Sync + 'static {
     /// Subscribe to reserved peers connection updates.
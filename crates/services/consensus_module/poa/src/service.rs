@@ -4,8 +4,10 @@ use crate::{
         OnConflict,
     },
     ports::{
+        BlockCosigner,
         BlockImporter,
         BlockProducer,
+        ChainClock,
         P2pPort,
         TransactionPool,
     },
@@ -20,6 +22,7 @@ use anyhow::{
     anyhow,
     Context,
 };
+use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_services::{
     stream::BoxStream,
     RunnableService,
@@ -33,7 +36,10 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::{
-            poa::PoAConsensus,
+            poa::{
+                PoAConsensus,
+                PoAMultiSigConsensus,
+            },
             Consensus,
         },
         header::BlockHeader,
@@ -42,7 +48,10 @@ use fuel_core_types::{
     },
     fuel_asm::Word,
     fuel_crypto::Signature,
-    fuel_tx::TxId,
+    fuel_tx::{
+        Input,
+        TxId,
+    },
     fuel_types::BlockHeight,
     secrecy::{
         ExposeSecret,
@@ -59,7 +68,9 @@ use fuel_core_types::{
     tai64::Tai64,
 };
 use std::{
+    collections::BTreeMap,
     ops::Deref,
+    sync::Arc,
     time::Duration,
 };
 use tokio::{
@@ -72,6 +83,9 @@ use tokio::{
 use tokio_stream::StreamExt;
 
 pub type Service<T, B, I> = ServiceRunner<MainTask<T, B, I>>;
+
+/// How long to wait for the signature of the remote cosigner.
+const COSIGNING_TIMEOUT: Duration = Duration::from_secs(2);
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
@@ -96,6 +110,18 @@ impl SharedState {
             .await?;
         receiver.await?
     }
+
+    /// Advances the clock of the chain by the `duration`. The timestamps of the next
+    /// blocks include the advanced time, so the time-dependent logic of the contracts
+    /// can be tested without waiting. Returns the current time of the chain.
+    pub async fn advance_time(&self, duration: Duration) -> anyhow::Result<Tai64> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::AdvanceTime((duration, sender)))
+            .await?;
+        receiver.await?
+    }
 }
 
 struct ManualProduction {
@@ -108,6 +134,8 @@ enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
     ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    /// Advances the clock of the chain by the `Duration`.
+    AdvanceTime((Duration, oneshot::Sender<anyhow::Result<Tai64>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -123,7 +151,14 @@ pub(crate) enum RequestType {
 
 pub struct MainTask<T, B, I> {
     block_gas_limit: Word,
+    /// The gas limit of the next block reduced after the sealing of the previous
+    /// block exceeded the `sealing_deadline`.
+    reduced_block_gas_limit: Option<Word>,
+    sealing_deadline: Option<Duration>,
     signing_key: Option<Secret<SecretKeyWrapper>>,
+    consensus: ConsensusConfig,
+    cosigning_keys: Vec<Secret<SecretKeyWrapper>>,
+    cosigners: Vec<Arc<dyn BlockCosigner>>,
     block_producer: B,
     block_importer: I,
     txpool: T,
@@ -133,6 +168,11 @@ pub struct MainTask<T, B, I> {
     last_height: BlockHeight,
     last_timestamp: Tai64,
     last_block_created: Instant,
+    /// The total time added to the wall clock by the `Request::AdvanceTime`.
+    time_offset: Duration,
+    clock: Option<Arc<dyn ChainClock>>,
+    /// The time added since the last produced block.
+    time_advanced_since_last_block: Duration,
     trigger: Trigger,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
@@ -154,8 +194,6 @@ where
     ) -> Self {
         let tx_status_update_stream = txpool.transaction_status_events();
         let (request_sender, request_receiver) = mpsc::channel(100);
-        let (last_height, last_timestamp, last_block_created) =
-            Self::extract_block_info(last_block);
 
         let block_stream = block_importer.block_stream();
         let peer_connections_stream = p2p_port.reserved_peers_count();
@@ -163,12 +201,24 @@ where
         let Config {
             block_gas_limit,
             signing_key,
+            consensus,
+            cosigning_keys,
+            cosigners,
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            sealing_deadline,
+            clock,
             ..
         } = config;
 
+        let time_offset = clock
+            .as_ref()
+            .map(|clock| clock.time_offset())
+            .unwrap_or_default();
+        let (last_height, last_timestamp, last_block_created) =
+            Self::extract_block_info(last_block, advanced_now(time_offset));
+
         let sync_task = SyncTask::new(
             peer_connections_stream,
             min_connected_reserved_peers,
@@ -181,7 +231,12 @@ where
 
         Self {
             block_gas_limit,
+            reduced_block_gas_limit: None,
+            sealing_deadline,
             signing_key,
+            consensus,
+            cosigning_keys,
+            cosigners,
             txpool,
             block_producer,
             block_importer,
@@ -191,38 +246,65 @@ where
             last_height,
             last_timestamp,
             last_block_created,
+            time_offset,
+            clock,
+            time_advanced_since_last_block: Duration::ZERO,
             trigger,
             timer: DeadlineClock::new(),
             sync_task_handle,
         }
     }
 
-    fn extract_block_info(last_block: &BlockHeader) -> (BlockHeight, Tai64, Instant) {
+    fn extract_block_info(
+        last_block: &BlockHeader,
+        now: Tai64,
+    ) -> (BlockHeight, Tai64, Instant) {
         let last_timestamp = last_block.time();
-        let duration =
-            Duration::from_secs(Tai64::now().0.saturating_sub(last_timestamp.0));
+        let duration = Duration::from_secs(now.0.saturating_sub(last_timestamp.0));
         let last_block_created = Instant::now() - duration;
         let last_height = *last_block.height();
         (last_height, last_timestamp, last_block_created)
     }
 
+    fn advance_time(&mut self, duration: Duration) -> anyhow::Result<Tai64> {
+        let time_offset = self
+            .time_offset
+            .checked_add(duration)
+            .ok_or(anyhow!("The provided time parameters lead to an overflow"))?;
+        increase_time(Tai64::now(), time_offset)?;
+        if let Some(clock) = &self.clock {
+            clock.set_time_offset(time_offset)?;
+        }
+        self.time_offset = time_offset;
+        self.time_advanced_since_last_block += duration;
+        tracing::info!("The clock of the chain is advanced by {duration:?}");
+        Ok(self.now())
+    }
+
     fn next_height(&self) -> BlockHeight {
         self.last_height + 1u32.into()
     }
 
+    /// Returns the current time of the chain: the wall clock advanced by the `time_offset`.
+    fn now(&self) -> Tai64 {
+        advanced_now(self.time_offset)
+    }
+
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
         match request_type {
             RequestType::Manual => match self.trigger {
                 Trigger::Never | Trigger::Instant => {
-                    let duration = self.last_block_created.elapsed();
+                    let duration = self.last_block_created.elapsed()
+                        + self.time_advanced_since_last_block;
                     increase_time(self.last_timestamp, duration)
                 }
-                Trigger::Interval { block_time } => {
-                    increase_time(self.last_timestamp, block_time)
-                }
+                Trigger::Interval { block_time } => increase_time(
+                    self.last_timestamp,
+                    block_time + self.time_advanced_since_last_block,
+                ),
             },
             RequestType::Trigger => {
-                let now = Tai64::now();
+                let now = self.now();
                 if now > self.last_timestamp {
                     Ok(now)
                 } else {
@@ -245,8 +327,10 @@ where
         height: BlockHeight,
         block_time: Tai64,
     ) -> anyhow::Result<UncommittedExecutionResult<StorageTransaction<D>>> {
+        let block_gas_limit =
+            self.reduced_block_gas_limit.unwrap_or(self.block_gas_limit);
         self.block_producer
-            .produce_and_execute_block(height, block_time, self.block_gas_limit)
+            .produce_and_execute_block(height, block_time, block_gas_limit)
             .await
     }
 
@@ -276,6 +360,7 @@ where
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(block_height = *height))]
     async fn produce_block(
         &mut self,
         height: BlockHeight,
@@ -314,7 +399,15 @@ where
         self.txpool.remove_txs(tx_ids_to_remove);
 
         // Sign the block and seal it
-        let seal = seal_block(&self.signing_key, &block)?;
+        let sealing_started = Instant::now();
+        let seal = seal_block(
+            &self.consensus,
+            &self.signing_key,
+            &self.cosigning_keys,
+            &self.cosigners,
+            &block,
+        )
+        .await?;
         let block = SealedBlock {
             entity: block,
             consensus: seal,
@@ -324,11 +417,13 @@ where
             ImportResult::new_from_local(block, tx_status),
             db_transaction,
         ))?;
+        self.on_block_sealed(height, sealing_started.elapsed());
 
         // Update last block time
         self.last_height = height;
         self.last_timestamp = block_time;
         self.last_block_created = last_block_created;
+        self.time_advanced_since_last_block = Duration::ZERO;
 
         // Set timer for the next block
         match (self.trigger, request_type) {
@@ -352,6 +447,25 @@ where
         Ok(())
     }
 
+    /// Reduces the gas limit of the next block if the sealing of the block at the `height`
+    /// exceeded the deadline. The import time grows with the size of the block, so
+    /// the smaller blocks are sealed in time.
+    fn on_block_sealed(&mut self, height: BlockHeight, sealing_time: Duration) {
+        self.reduced_block_gas_limit = match self.sealing_deadline {
+            Some(deadline) if sealing_time > deadline => {
+                let gas_limit =
+                    self.reduced_block_gas_limit.unwrap_or(self.block_gas_limit) / 2;
+                tracing::warn!(
+                    "The sealing of the block {height} took {sealing_time:?} exceeding \
+                    the deadline {deadline:?}, the gas limit of the next block is \
+                    reduced to {gas_limit}"
+                );
+                Some(gas_limit)
+            }
+            _ => None,
+        };
+    }
+
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
         match self.trigger {
             Trigger::Instant => {
@@ -435,7 +549,7 @@ where
                 _ = self.sync_task_handle.shared.changed() => {
                     if let SyncState::Synced(block_header) = &*self.sync_task_handle.shared.borrow() {
                         let (last_height, last_timestamp, last_block_created) =
-                            Self::extract_block_info(block_header);
+                            Self::extract_block_info(block_header, self.now());
                         self.last_height = last_height;
                         self.last_timestamp = last_timestamp;
                         self.last_block_created = last_block_created;
@@ -456,6 +570,10 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::AdvanceTime((duration, response)) => {
+                            let result = self.advance_time(duration);
+                            let _ = response.send(result);
+                        }
                     }
                     should_continue = true;
                 } else {
@@ -515,23 +633,102 @@ where
     ))
 }
 
-fn seal_block(
+/// Seals the block as required by the `consensus` of the chain. The multi-signature
+/// seal is signed by the `signing_key` and the `cosigning_keys`, and the remote
+/// `cosigners` are asked for the rest of the signatures up to the threshold.
+pub(crate) async fn seal_block(
+    consensus: &ConsensusConfig,
     signing_key: &Option<Secret<SecretKeyWrapper>>,
+    cosigning_keys: &[Secret<SecretKeyWrapper>],
+    cosigners: &[Arc<dyn BlockCosigner>],
     block: &Block,
 ) -> anyhow::Result<Consensus> {
-    if let Some(key) = signing_key {
-        let block_hash = block.id();
-        let message = block_hash.into_message();
-
-        // The length of the secret is checked
-        let signing_key = key.expose_secret().deref();
-
-        let poa_signature = Signature::sign(signing_key, &message);
-        let seal = Consensus::PoA(PoAConsensus::new(poa_signature));
-        Ok(seal)
-    } else {
-        Err(anyhow!("no PoA signing key configured"))
+    let Some(key) = signing_key else {
+        return Err(anyhow!("no PoA signing key configured"))
+    };
+    let message = block.id().into_message();
+    let (signing_keys, threshold) = match consensus {
+        ConsensusConfig::PoA { .. } => {
+            // The length of the secret is checked
+            let signing_key = key.expose_secret().deref();
+
+            let poa_signature = Signature::sign(signing_key, &message);
+            return Ok(Consensus::PoA(PoAConsensus::new(poa_signature)))
+        }
+        ConsensusConfig::PoAMultiSig {
+            signing_keys,
+            threshold,
+        } => (signing_keys, usize::from(*threshold)),
+    };
+
+    // The signatures by the addresses of their signers, so each key signs once.
+    let mut signatures = BTreeMap::new();
+    for key in std::iter::once(key).chain(cosigning_keys) {
+        let key = key.expose_secret().deref();
+        let signer = Input::owner(&key.public_key());
+        if signing_keys.contains(&signer) {
+            signatures.insert(signer, Signature::sign(key, &message));
+        }
     }
+
+    if signatures.len() < threshold {
+        let mut requests = tokio::task::JoinSet::new();
+        for cosigner in cosigners {
+            let cosigner = cosigner.clone();
+            let block = block.clone();
+            requests.spawn(async move {
+                tokio::time::timeout(COSIGNING_TIMEOUT, cosigner.cosign(&block)).await
+            });
+        }
+        // The rest of the requests are aborted once the threshold is reached.
+        while signatures.len() < threshold {
+            let Some(result) = requests.join_next().await else {
+                break
+            };
+            let signature = match result {
+                Ok(Ok(Ok(signature))) => signature,
+                Ok(Ok(Err(e))) => {
+                    tracing::warn!("The cosigner failed to sign the block: {e:?}");
+                    continue
+                }
+                Ok(Err(_)) => {
+                    tracing::warn!("The cosigner didn't sign the block in time");
+                    continue
+                }
+                Err(e) => {
+                    tracing::warn!("The request to the cosigner failed: {e}");
+                    continue
+                }
+            };
+            match signature.recover(&message) {
+                Ok(public_key) if signing_keys.contains(&Input::owner(&public_key)) => {
+                    signatures
+                        .entry(Input::owner(&public_key))
+                        .or_insert(signature);
+                }
+                _ => {
+                    tracing::warn!("The cosigner signed the block by an unauthorized key")
+                }
+            }
+        }
+    }
+
+    if signatures.len() < threshold {
+        return Err(anyhow!(
+            "The block is signed by {} keys, but {threshold} are required",
+            signatures.len()
+        ))
+    }
+    // The signatures are ordered by the addresses of the signers.
+    let signatures = signatures.into_values().collect();
+    Ok(Consensus::PoAMultiSig(PoAMultiSigConsensus::new(
+        signatures,
+    )))
+}
+
+/// Returns the wall clock advanced by the `time_offset`.
+fn advanced_now(time_offset: Duration) -> Tai64 {
+    Tai64(Tai64::now().0.saturating_add(time_offset.as_secs()))
 }
 
 fn increase_time(time: Tai64, duration: Duration) -> anyhow::Result<Tai64> {
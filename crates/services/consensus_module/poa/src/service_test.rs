@@ -1,12 +1,17 @@
 use crate::{
     new_service,
     ports::{
+        BlockCosigner,
+        ChainClock,
         MockBlockImporter,
         MockBlockProducer,
         MockP2pPort,
         MockTransactionPool,
     },
-    service::MainTask,
+    service::{
+        seal_block,
+        MainTask,
+    },
     Config,
     Service,
     Trigger,
@@ -22,11 +27,16 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::{
+        block::Block,
+        consensus::Consensus,
         header::BlockHeader,
         primitives::SecretKeyWrapper,
         SealedBlock,
     },
-    fuel_crypto::SecretKey,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::{
         field::GasLimit,
         *,
@@ -393,6 +403,106 @@ async fn does_not_produce_when_txpool_empty_in_instant_mode() {
     task.on_txpool_event().await.unwrap();
 }
 
+#[tokio::test]
+async fn block_gas_limit_is_reduced_while_sealing_exceeds_deadline() {
+    let mut block_producer = MockBlockProducer::default();
+    block_producer
+        .expect_produce_and_execute_block()
+        .returning(|_, _, _| panic!("Block production should not be called"));
+    let mut block_importer = MockBlockImporter::default();
+    block_importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1000,
+        signing_key: Some(test_signing_key()),
+        sealing_deadline: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+    let mut task = MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        MockTransactionPool::no_tx_updates(),
+        block_producer,
+        block_importer,
+        generate_p2p_port(),
+    );
+
+    task.on_block_sealed(2u32.into(), Duration::from_secs(2));
+    assert_eq!(task.reduced_block_gas_limit, Some(500));
+    task.on_block_sealed(3u32.into(), Duration::from_secs(2));
+    assert_eq!(task.reduced_block_gas_limit, Some(250));
+    task.on_block_sealed(4u32.into(), Duration::from_millis(500));
+    assert_eq!(task.reduced_block_gas_limit, None);
+}
+
+/// Signs the blocks by its key, or fails if it doesn't have the key.
+#[derive(Debug)]
+struct TestCosigner(Option<SecretKey>);
+
+#[async_trait::async_trait]
+impl crate::ports::BlockCosigner for TestCosigner {
+    async fn cosign(&self, block: &Block) -> anyhow::Result<Signature> {
+        let key = self
+            .0
+            .ok_or_else(|| anyhow::anyhow!("The signer is offline"))?;
+        Ok(Signature::sign(&key, &block.id().into_message()))
+    }
+}
+
+#[tokio::test]
+async fn seal_block_collects_the_threshold_of_cosignatures() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let keys: Vec<_> = (0..4).map(|_| SecretKey::random(&mut rng)).collect();
+    let signing_keys = keys[..3]
+        .iter()
+        .map(|key| Input::owner(&key.public_key()))
+        .collect();
+    let consensus_config = fuel_core_chain_config::ConsensusConfig::PoAMultiSig {
+        signing_keys,
+        threshold: 2,
+    };
+    let block = Block::new(Default::default(), vec![], &[]);
+    let signing_key = Some(Secret::new(keys[0].into()));
+    let cosigner =
+        |key: Option<SecretKey>| Arc::new(TestCosigner(key)) as Arc<dyn BlockCosigner>;
+
+    // The unauthorized and the offline signers don't count.
+    let cosigners = [cosigner(Some(keys[3])), cosigner(None)];
+    let result =
+        seal_block(&consensus_config, &signing_key, &[], &cosigners, &block).await;
+    assert!(result.is_err());
+
+    let cosigners = [cosigner(None), cosigner(Some(keys[2]))];
+    let multisig = seal_block(&consensus_config, &signing_key, &[], &cosigners, &block)
+        .await
+        .unwrap();
+    assert!(crate::verifier::verify_consensus(
+        &consensus_config,
+        block.header(),
+        &multisig
+    ));
+
+    // The sealing mode is taken from the consensus of the chain.
+    let single = seal_block(
+        &fuel_core_chain_config::ConsensusConfig::default_poa(),
+        &signing_key,
+        &[Secret::new(keys[1].into())],
+        &[],
+        &block,
+    )
+    .await
+    .unwrap();
+    assert!(matches!(single, Consensus::PoA(_)));
+    assert!(!crate::verifier::verify_consensus(
+        &consensus_config,
+        block.header(),
+        &single
+    ));
+}
+
 fn test_signing_key() -> Secret<SecretKeyWrapper> {
     let mut rng = StdRng::seed_from_u64(0);
     let secret_key = SecretKey::random(&mut rng);
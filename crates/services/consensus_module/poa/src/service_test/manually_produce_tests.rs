@@ -116,3 +116,118 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+#[tokio::test]
+async fn advanced_time_is_included_into_next_block_time() {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        signing_key: Some(test_signing_key()),
+        ..Default::default()
+    });
+
+    let mut importer = MockBlockImporter::default();
+    let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+    importer.expect_commit_result().returning(move |r| {
+        tx.try_send(r.into_result().sealed_block.entity.header().time())
+            .unwrap();
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .returning(|_, time, _| {
+            let mut block = Block::default();
+            block.header_mut().consensus.time = time;
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+    let ctx = ctx_builder.build();
+
+    let start_time = Tai64::now();
+    ctx.service
+        .shared
+        .manually_produce_block(Some(start_time), 1)
+        .await
+        .unwrap();
+    assert_eq!(rx.recv().await.unwrap(), start_time);
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    let chain_time = ctx.service.shared.advance_time(day).await.unwrap();
+    assert!(chain_time >= start_time + day.as_secs());
+
+    ctx.service
+        .shared
+        .manually_produce_block(None, 1)
+        .await
+        .unwrap();
+    let block_time = rx.recv().await.unwrap();
+    assert!(block_time >= start_time + day.as_secs());
+    assert!(block_time <= start_time + day.as_secs() + 1);
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[derive(Debug, Default)]
+struct TestClock(Mutex<Duration>);
+
+impl ChainClock for TestClock {
+    fn time_offset(&self) -> Duration {
+        *self.0.lock().unwrap()
+    }
+
+    fn set_time_offset(&self, time_offset: Duration) -> anyhow::Result<()> {
+        *self.0.lock().unwrap() = time_offset;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn advanced_time_is_kept_by_clock_across_restarts() {
+    let clock = Arc::new(TestClock::default());
+    let config = Config {
+        trigger: Trigger::Never,
+        signing_key: Some(test_signing_key()),
+        clock: Some(clock.clone()),
+        ..Default::default()
+    };
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(config.clone());
+    let ctx = ctx_builder.build();
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    ctx.service.shared.advance_time(day).await.unwrap();
+    assert_eq!(clock.time_offset(), day);
+    assert_eq!(ctx.stop().await, State::Stopped);
+
+    // The restarted service continues from the time stored in the clock.
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(config);
+    let ctx = ctx_builder.build();
+
+    let chain_time = ctx
+        .service
+        .shared
+        .advance_time(Duration::ZERO)
+        .await
+        .unwrap();
+    assert!(chain_time.0 >= Tai64::now().0 + day.as_secs() - 1);
+    assert_eq!(clock.time_offset(), day);
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
@@ -1,33 +1,44 @@
 use crate::ports::Database;
-use anyhow::ensure;
+use anyhow::{
+    anyhow,
+    ensure,
+};
 use fuel_core_chain_config::ConsensusConfig;
-use fuel_core_types::{
-    blockchain::{
-        block::Block,
-        consensus::poa::PoAConsensus,
-        header::BlockHeader,
-    },
-    fuel_tx::Input,
+use fuel_core_light_client::{
+    verify_next_header,
+    verify_poa_consensus,
+    verify_poa_multisig_consensus,
+};
+use fuel_core_types::blockchain::{
+    block::Block,
+    consensus::Consensus,
+    header::BlockHeader,
 };
 
 #[cfg(test)]
 mod tests;
 
+/// Verifies the seal of the PoA block. The type of the seal should match
+/// the consensus of the chain.
 // TODO: Make this function `async` and await the synchronization with the relayer.
 pub fn verify_consensus(
     consensus_config: &ConsensusConfig,
     header: &BlockHeader,
-    consensus: &PoAConsensus,
+    consensus: &Consensus,
 ) -> bool {
-    match consensus_config {
-        ConsensusConfig::PoA { signing_key } => {
-            let id = header.id();
-            let m = id.as_message();
-            consensus
-                .signature
-                .recover(m)
-                .map_or(false, |k| Input::owner(&k) == *signing_key)
+    match (consensus_config, consensus) {
+        (ConsensusConfig::PoA { signing_key }, Consensus::PoA(consensus)) => {
+            verify_poa_consensus(signing_key, header, consensus).is_ok()
         }
+        (
+            ConsensusConfig::PoAMultiSig {
+                signing_keys,
+                threshold,
+            },
+            Consensus::PoAMultiSig(consensus),
+        ) => verify_poa_multisig_consensus(signing_keys, *threshold, header, consensus)
+            .is_ok(),
+        _ => false,
     }
 }
 
@@ -35,36 +46,7 @@ pub fn verify_block_fields<D: Database>(
     database: &D,
     block: &Block,
 ) -> anyhow::Result<()> {
-    let height = *block.header().height();
-    ensure!(
-        height != 0u32.into(),
-        "The PoA block can't have the zero height"
-    );
-
-    let prev_height = height - 1u32.into();
-    let prev_root = database.block_header_merkle_root(&prev_height)?;
-    let header = block.header();
-    ensure!(
-        header.prev_root() == &prev_root,
-        "Previous root of the next block should match the previous block root"
-    );
-
-    let prev_header = database.block_header(&prev_height)?;
-
-    ensure!(
-        header.da_height >= prev_header.da_height,
-        "The `da_height` of the next block can't be lower"
-    );
-
-    ensure!(
-        header.time() >= prev_header.time(),
-        "The `time` of the next block can't be lower"
-    );
-
-    ensure!(
-        header.consensus.application_hash == header.application.hash(),
-        "The application hash mismatch."
-    );
+    verify_header_fields(database, block.header())?;
 
     // TODO: We can check the root of the transactions and the root of the messages here.
     //  But we do the same in the executor right now during validation mode. I will not check
@@ -75,3 +57,20 @@ pub fn verify_block_fields<D: Database>(
 
     Ok(())
 }
+
+/// Verifies that the `header` follows the header of the previous block in the `database`.
+fn verify_header_fields<D: Database>(
+    database: &D,
+    header: &BlockHeader,
+) -> anyhow::Result<()> {
+    let height = *header.height();
+    ensure!(
+        height != 0u32.into(),
+        "The PoA block can't have the zero height"
+    );
+
+    let prev_height = height - 1u32.into();
+    let prev_root = database.block_header_merkle_root(&prev_height)?;
+    let prev_header = database.block_header(&prev_height)?;
+    verify_next_header(&prev_header, &prev_root, header).map_err(|e| anyhow!("{e}"))
+}
@@ -95,8 +95,9 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     let mut d = MockDatabase::default();
     d.expect_block_header_merkle_root()
         .returning(move |_| Ok(block_header_merkle_root.into()));
-    d.expect_block_header().returning(move |_| {
+    d.expect_block_header().returning(move |height| {
         let mut h = BlockHeader::default();
+        h.consensus.height = *height;
         h.consensus.time = prev_header_time;
         h.application.da_height = prev_header_da_height.into();
         Ok(h)
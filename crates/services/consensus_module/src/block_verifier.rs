@@ -58,6 +58,21 @@ where
         consensus: &Consensus,
         block: &Block,
     ) -> anyhow::Result<()> {
+        self.verify_block_fields_on(&self.database, consensus, block)
+    }
+
+    /// Verifies **all** fields of the block like [`Self::verify_block_fields`], but reads
+    /// the previous blocks from the `database`. It allows verifying the block on top of
+    /// the uncommitted state of the previous blocks.
+    pub fn verify_block_fields_on<DB>(
+        &self,
+        database: &DB,
+        consensus: &Consensus,
+        block: &Block,
+    ) -> anyhow::Result<()>
+    where
+        DB: PoAVerifierDatabase,
+    {
         match consensus {
             Consensus::Genesis(_) => {
                 let expected_genesis_height = self
@@ -69,8 +84,8 @@ where
                     .unwrap_or_else(|| 0u32.into());
                 verify_genesis_block_fields(expected_genesis_height, block.header())
             }
-            Consensus::PoA(_) => {
-                fuel_core_poa::verifier::verify_block_fields(&self.database, block)
+            Consensus::PoA(_) | Consensus::PoAMultiSig(_) => {
+                fuel_core_poa::verifier::verify_block_fields(database, block)
             }
         }
     }
@@ -83,11 +98,13 @@ where
         } = header;
         match consensus {
             Consensus::Genesis(_) => true,
-            Consensus::PoA(consensus) => fuel_core_poa::verifier::verify_consensus(
-                &self.config.chain_config.consensus,
-                header,
-                consensus,
-            ),
+            Consensus::PoA(_) | Consensus::PoAMultiSig(_) => {
+                fuel_core_poa::verifier::verify_consensus(
+                    &self.config.chain_config.consensus,
+                    header,
+                    consensus,
+                )
+            }
         }
     }
 
@@ -1,6 +1,12 @@
-use fuel_core_types::fuel_tx::{
-    Address,
-    ConsensusParameters,
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        ConsensusParameters,
+    },
+    services::executor::{
+        BlockLimits,
+        FeeShare,
+    },
 };
 
 #[derive(Clone, Debug, Default)]
@@ -9,6 +15,12 @@ pub struct Config {
     pub consensus_parameters: ConsensusParameters,
     /// The address of the fee recipient
     pub coinbase_recipient: Address,
+    /// The shares of the collected fees of the chain that go to other recipients.
+    /// The `coinbase_recipient` receives the rest of the fees.
+    pub fee_shares: Vec<FeeShare>,
+    /// The limits of the block fullness. The production stops filling the block
+    /// at the limits, and the validation rejects the blocks exceeding them.
+    pub block_limits: BlockLimits,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
     /// Default mode for utxo_validation
@@ -0,0 +1,384 @@
+use fuel_core_types::fuel_types::BlockHeight;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("The block at height {got} was already processed. The next expected height is {expected}.")]
+    AlreadyProcessed {
+        expected: BlockHeight,
+        got: BlockHeight,
+    },
+    #[error("The block capacity can't be zero.")]
+    ZeroCapacity,
+}
+
+/// The gas price algorithm based on the fullness of the L2 blocks.
+///
+/// After each block the gas price is increased by `gas_price_change_percent` if the block
+/// was fuller than `gas_price_threshold_percent` and decreased by the same amount otherwise.
+/// The gas price never goes below `min_gas_price`.
+///
+/// The DA gas price reported by the block committer is added on top of the L2 gas price.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlgorithmV0 {
+    /// The L2 gas price for the next block.
+    new_gas_price: u64,
+    /// The gas price required to cover the cost of the DA layer.
+    da_gas_price: u64,
+    /// The lowest gas price the algorithm can produce.
+    min_gas_price: u64,
+    /// The percentage of the gas price change after each block.
+    gas_price_change_percent: u64,
+    /// The block fullness that keeps the gas price unchanged.
+    gas_price_threshold_percent: u64,
+    /// The height of the latest processed block.
+    l2_block_height: BlockHeight,
+}
+
+/// The part of the [`AlgorithmV0`] changed by the blocks and the DA costs. It is stored
+/// after each change, so the gas price continues from the same value after the restart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct AlgorithmState {
+    /// The L2 gas price for the next block.
+    pub new_gas_price: u64,
+    /// The gas price required to cover the cost of the DA layer.
+    pub da_gas_price: u64,
+    /// The height of the latest processed block.
+    pub l2_block_height: u32,
+}
+
+impl AlgorithmV0 {
+    pub fn new(
+        new_gas_price: u64,
+        min_gas_price: u64,
+        gas_price_change_percent: u64,
+        gas_price_threshold_percent: u64,
+        l2_block_height: BlockHeight,
+    ) -> Self {
+        Self {
+            new_gas_price: new_gas_price.max(min_gas_price),
+            da_gas_price: 0,
+            min_gas_price,
+            gas_price_change_percent,
+            gas_price_threshold_percent,
+            l2_block_height,
+        }
+    }
+
+    /// Restores the algorithm from the stored `state`. The parameters of the algorithm
+    /// are not stored, so they may change between the restarts.
+    pub fn from_state(
+        state: AlgorithmState,
+        min_gas_price: u64,
+        gas_price_change_percent: u64,
+        gas_price_threshold_percent: u64,
+    ) -> Self {
+        Self {
+            da_gas_price: state.da_gas_price,
+            ..Self::new(
+                state.new_gas_price,
+                min_gas_price,
+                gas_price_change_percent,
+                gas_price_threshold_percent,
+                state.l2_block_height.into(),
+            )
+        }
+    }
+
+    /// Returns the state of the algorithm that should be stored.
+    pub fn state(&self) -> AlgorithmState {
+        AlgorithmState {
+            new_gas_price: self.new_gas_price,
+            da_gas_price: self.da_gas_price,
+            l2_block_height: self.l2_block_height.into(),
+        }
+    }
+
+    /// Returns the gas price for the next block.
+    pub fn calculate(&self) -> u64 {
+        self.new_gas_price.saturating_add(self.da_gas_price)
+    }
+
+    /// Returns the part of the gas price that covers the cost of the DA layer.
+    pub fn da_gas_price(&self) -> u64 {
+        self.da_gas_price
+    }
+
+    /// Returns the height of the latest processed block.
+    pub fn l2_block_height(&self) -> BlockHeight {
+        self.l2_block_height
+    }
+
+    /// Returns the highest gas price the algorithm can reach at the `height`.
+    ///
+    /// The worst case is when each block until the `height` is full and the gas price
+    /// increases after each of them. The fullness of the block can't exceed 100%,
+    /// so the gas price never increases with the threshold of 100% or more.
+    pub fn worst_case(&self, height: BlockHeight) -> u64 {
+        let next_height = u32::from(self.l2_block_height).saturating_add(1);
+        let blocks = if self.gas_price_threshold_percent >= 100 {
+            0
+        } else {
+            u32::from(height).saturating_sub(next_height)
+        };
+
+        let mut gas_price = self.new_gas_price;
+        for _ in 0..blocks {
+            let increased = self.increase(gas_price);
+            if increased == gas_price {
+                // The gas price can't grow anymore, all next blocks give the same result.
+                break
+            }
+            gas_price = increased;
+        }
+        gas_price.saturating_add(self.da_gas_price)
+    }
+
+    /// Updates the gas price based on the fullness of the block at the `height`.
+    pub fn update_l2_block_data(
+        &mut self,
+        height: BlockHeight,
+        used: u64,
+        capacity: u64,
+    ) -> Result<(), Error> {
+        if height <= self.l2_block_height {
+            return Err(Error::AlreadyProcessed {
+                expected: u32::from(self.l2_block_height).saturating_add(1).into(),
+                got: height,
+            })
+        }
+        if capacity == 0 {
+            return Err(Error::ZeroCapacity)
+        }
+
+        let fullness_percent = (used as u128)
+            .saturating_mul(100)
+            .saturating_div(capacity as u128);
+        let threshold = self.gas_price_threshold_percent as u128;
+
+        self.new_gas_price = match fullness_percent.cmp(&threshold) {
+            core::cmp::Ordering::Greater => self.increase(self.new_gas_price),
+            core::cmp::Ordering::Less => self.decrease(self.new_gas_price),
+            core::cmp::Ordering::Equal => self.new_gas_price,
+        };
+        self.l2_block_height = height;
+        Ok(())
+    }
+
+    /// Updates the part of the gas price that covers the cost of the DA layer.
+    pub fn update_da_gas_price(&mut self, da_gas_price: u64) {
+        self.da_gas_price = da_gas_price;
+    }
+
+    fn change(&self, gas_price: u64) -> u64 {
+        if self.gas_price_change_percent == 0 {
+            return 0
+        }
+        let change = (gas_price as u128)
+            .saturating_mul(self.gas_price_change_percent as u128)
+            .saturating_div(100);
+        // The change is at least one unit, otherwise the zero gas price could never grow.
+        u64::try_from(change).unwrap_or(u64::MAX).max(1)
+    }
+
+    fn increase(&self, gas_price: u64) -> u64 {
+        gas_price.saturating_add(self.change(gas_price))
+    }
+
+    fn decrease(&self, gas_price: u64) -> u64 {
+        gas_price
+            .saturating_sub(self.change(gas_price))
+            .max(self.min_gas_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+
+    fn algorithm(new_gas_price: u64, change_percent: u64) -> AlgorithmV0 {
+        AlgorithmV0::new(new_gas_price, 10, change_percent, 50, 100u32.into())
+    }
+
+    #[test]
+    fn calculate__returns_starting_price_before_any_block() {
+        let algorithm = algorithm(100, 10);
+
+        assert_eq!(algorithm.calculate(), 100);
+    }
+
+    #[test]
+    fn new__starting_price_is_not_below_min_gas_price() {
+        let algorithm = algorithm(1, 10);
+
+        assert_eq!(algorithm.calculate(), 10);
+    }
+
+    #[test]
+    fn update_l2_block_data__increases_price_for_full_block() {
+        let mut algorithm = algorithm(100, 10);
+
+        algorithm
+            .update_l2_block_data(101u32.into(), 100, 100)
+            .unwrap();
+
+        assert_eq!(algorithm.calculate(), 110);
+        assert_eq!(algorithm.l2_block_height(), 101u32.into());
+    }
+
+    #[test]
+    fn update_l2_block_data__decreases_price_for_empty_block() {
+        let mut algorithm = algorithm(100, 10);
+
+        algorithm
+            .update_l2_block_data(101u32.into(), 0, 100)
+            .unwrap();
+
+        assert_eq!(algorithm.calculate(), 90);
+    }
+
+    #[test]
+    fn update_l2_block_data__keeps_price_at_threshold() {
+        let mut algorithm = algorithm(100, 10);
+
+        algorithm
+            .update_l2_block_data(101u32.into(), 50, 100)
+            .unwrap();
+
+        assert_eq!(algorithm.calculate(), 100);
+    }
+
+    #[test]
+    fn update_l2_block_data__never_goes_below_min_gas_price() {
+        let mut algorithm = algorithm(11, 50);
+
+        algorithm
+            .update_l2_block_data(101u32.into(), 0, 100)
+            .unwrap();
+
+        assert_eq!(algorithm.calculate(), 10);
+    }
+
+    #[test]
+    fn update_l2_block_data__rejects_processed_height() {
+        let mut algorithm = algorithm(100, 10);
+
+        let result = algorithm.update_l2_block_data(100u32.into(), 100, 100);
+
+        assert_eq!(
+            result,
+            Err(Error::AlreadyProcessed {
+                expected: 101u32.into(),
+                got: 100u32.into(),
+            })
+        );
+        assert_eq!(algorithm.calculate(), 100);
+    }
+
+    #[test]
+    fn update_l2_block_data__rejects_zero_capacity() {
+        let mut algorithm = algorithm(100, 10);
+
+        let result = algorithm.update_l2_block_data(101u32.into(), 0, 0);
+
+        assert_eq!(result, Err(Error::ZeroCapacity));
+    }
+
+    #[test]
+    fn worst_case__next_block_is_current_price() {
+        let algorithm = algorithm(100, 10);
+
+        assert_eq!(algorithm.worst_case(101u32.into()), 100);
+        assert_eq!(algorithm.worst_case(50u32.into()), 100);
+    }
+
+    #[test]
+    fn worst_case__matches_sequence_of_full_blocks() {
+        let mut expected = algorithm(100, 10);
+        let actual = expected.clone();
+
+        for height in 101u32..110 {
+            expected
+                .update_l2_block_data(height.into(), 100, 100)
+                .unwrap();
+        }
+
+        assert_eq!(actual.worst_case(110u32.into()), expected.calculate());
+    }
+
+    #[test]
+    fn worst_case__zero_change_keeps_price() {
+        let algorithm = algorithm(100, 0);
+
+        assert_eq!(algorithm.worst_case(u32::MAX.into()), 100);
+    }
+
+    #[test]
+    fn worst_case__full_block_threshold_keeps_price() {
+        let mut expected = AlgorithmV0::new(100, 10, 10, 100, 100u32.into());
+        let actual = expected.clone();
+
+        for height in 101u32..110 {
+            expected
+                .update_l2_block_data(height.into(), 100, 100)
+                .unwrap();
+        }
+
+        assert_eq!(expected.calculate(), 100);
+        assert_eq!(actual.worst_case(110u32.into()), 100);
+    }
+
+    #[test]
+    fn update_da_gas_price__is_added_to_l2_gas_price() {
+        let mut algorithm = algorithm(100, 10);
+
+        algorithm.update_da_gas_price(5);
+
+        assert_eq!(algorithm.calculate(), 105);
+        assert_eq!(algorithm.worst_case(102u32.into()), 115);
+    }
+
+    #[test]
+    fn update_da_gas_price__does_not_affect_l2_gas_price() {
+        let mut algorithm = algorithm(100, 10);
+
+        algorithm.update_da_gas_price(50);
+        algorithm
+            .update_l2_block_data(101u32.into(), 100, 100)
+            .unwrap();
+        algorithm.update_da_gas_price(0);
+
+        assert_eq!(algorithm.calculate(), 110);
+    }
+
+    #[test]
+    fn from_state__continues_from_stored_state() {
+        let mut algorithm = algorithm(100, 10);
+        algorithm.update_da_gas_price(5);
+        algorithm
+            .update_l2_block_data(101u32.into(), 100, 100)
+            .unwrap();
+
+        let restored = AlgorithmV0::from_state(algorithm.state(), 10, 10, 50);
+
+        assert_eq!(restored, algorithm);
+        assert_eq!(restored.calculate(), 115);
+        assert_eq!(restored.l2_block_height(), 101u32.into());
+    }
+
+    #[test]
+    fn from_state__stored_price_is_not_below_new_min_gas_price() {
+        let algorithm = algorithm(100, 10);
+
+        let restored = AlgorithmV0::from_state(algorithm.state(), 200, 10, 50);
+
+        assert_eq!(restored.calculate(), 200);
+    }
+
+    #[test]
+    fn worst_case__saturates_for_far_horizon() {
+        let algorithm = algorithm(100, 10);
+
+        assert_eq!(algorithm.worst_case(u32::MAX.into()), u64::MAX);
+    }
+}
@@ -0,0 +1,71 @@
+use fuel_core_types::{
+    fuel_crypto::PublicKey,
+    fuel_types::ChainId,
+};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The gas price of the first block after the start of the node.
+    pub starting_gas_price: u64,
+    /// The lowest gas price the algorithm can produce. It should be equal to the
+    /// minimal gas price accepted by the `TxPool`.
+    pub min_gas_price: u64,
+    /// The percentage of the gas price change after each block.
+    pub gas_price_change_percent: u64,
+    /// The block fullness in percent that keeps the gas price unchanged.
+    /// The gas price grows above this threshold and falls below it.
+    pub gas_price_threshold_percent: u64,
+    /// The maximum gas of the block, used to calculate the fullness of the block.
+    pub block_gas_limit: u64,
+    /// The id of the chain. The block committer signs the DA costs for it.
+    pub chain_id: ChainId,
+    /// Whether the `TxPool` and the block producer reject the transactions paying less
+    /// than the gas price of the next block. Each node calculates the gas price locally,
+    /// so it is disabled by default.
+    pub enforce_gas_price: bool,
+    /// The source of the DA costs. If it is not set, the DA gas price is zero.
+    pub da_source: Option<DaSourceConfig>,
+    /// The source of the blob base fee of the DA layer. If it is set, the DA gas price
+    /// follows the blob base fee instead of the costs reported by the block committer.
+    pub blob_fee: Option<BlobFeeConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaSourceConfig {
+    /// The URL of the block committer API.
+    pub url: String,
+    /// The public key of the block committer. The costs not signed by it are rejected.
+    pub committer_public_key: PublicKey,
+    /// How often to request the latest costs from the block committer.
+    pub polling_interval: Duration,
+    /// The DA gas price used while the block committer is unreachable and no costs were
+    /// accepted yet. Afterwards, the last accepted DA gas price is kept. It should be
+    /// high enough to cover the DA costs in the worst case.
+    pub fallback_gas_price: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobFeeConfig {
+    /// How often to request the blob base fee from the Ethereum node.
+    pub polling_interval: Duration,
+    /// The expected size of the L2 block posted to the DA layer in bytes. It is used
+    /// until the block committer reports the actual size of the committed blocks.
+    pub bytes_per_block: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            starting_gas_price: 0,
+            min_gas_price: 0,
+            gas_price_change_percent: 10,
+            gas_price_threshold_percent: 50,
+            block_gas_limit: 1_000_000_000,
+            chain_id: ChainId::default(),
+            enforce_gas_price: false,
+            da_source: None,
+            blob_fee: None,
+        }
+    }
+}
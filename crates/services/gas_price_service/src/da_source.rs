@@ -0,0 +1,232 @@
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        Signature,
+    },
+    fuel_types::ChainId,
+};
+use std::ops::RangeInclusive;
+
+pub mod block_committer;
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("The DA block costs are not signed by the block committer.")]
+    InvalidSignature,
+    #[error("The DA block costs don't cover any L2 block.")]
+    EmptyBlockRange,
+}
+
+/// The cost of the L2 blocks bundle committed to the DA layer by the block committer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaBlockCosts {
+    /// The heights of the L2 blocks included in the bundle.
+    pub l2_blocks: RangeInclusive<u32>,
+    /// The size of the bundle in bytes.
+    pub blob_size_bytes: u32,
+    /// The cost paid on the DA layer for the bundle.
+    pub blob_cost: u128,
+}
+
+impl DaBlockCosts {
+    /// The domain separator of the signed costs, so the signature of the block committer
+    /// can't be reused for another message.
+    pub const DOMAIN: &'static [u8] = b"fuel-core/da-block-costs/v1";
+
+    /// Returns the message that the block committer signs to authenticate the costs
+    /// of the chain with the `chain_id`.
+    pub fn signing_message(&self, chain_id: &ChainId) -> Message {
+        let mut bytes = Vec::with_capacity(Self::DOMAIN.len() + 8 + 4 + 4 + 4 + 16);
+        bytes.extend_from_slice(Self::DOMAIN);
+        bytes.extend_from_slice(&u64::from(*chain_id).to_be_bytes());
+        bytes.extend_from_slice(&self.l2_blocks.start().to_be_bytes());
+        bytes.extend_from_slice(&self.l2_blocks.end().to_be_bytes());
+        bytes.extend_from_slice(&self.blob_size_bytes.to_be_bytes());
+        bytes.extend_from_slice(&self.blob_cost.to_be_bytes());
+        Message::new(bytes)
+    }
+
+    /// Returns the DA part of the gas price required to cover the cost of the bundle.
+    ///
+    /// The cost is spread over the whole capacity of the blocks in the bundle and is
+    /// rounded up, so the DA layer is never underpaid.
+    pub fn gas_price(&self, block_gas_limit: u64) -> Result<u64, Error> {
+        if self.l2_blocks.is_empty() {
+            return Err(Error::EmptyBlockRange)
+        }
+        let blocks = (*self.l2_blocks.end() - *self.l2_blocks.start()) as u128 + 1;
+        let capacity = blocks.saturating_mul(block_gas_limit as u128).max(1);
+        let gas_price = self.blob_cost.saturating_add(capacity - 1) / capacity;
+        Ok(u64::try_from(gas_price).unwrap_or(u64::MAX))
+    }
+
+    /// Returns the average size of the L2 block in the bundle in bytes, rounded up.
+    pub fn bytes_per_block(&self) -> Result<u64, Error> {
+        if self.l2_blocks.is_empty() {
+            return Err(Error::EmptyBlockRange)
+        }
+        let blocks = (*self.l2_blocks.end() - *self.l2_blocks.start()) as u64 + 1;
+        Ok((self.blob_size_bytes as u64).saturating_add(blocks - 1) / blocks)
+    }
+}
+
+/// Returns the DA part of the gas price required to post `bytes_per_block` bytes of each
+/// L2 block at the `blob_base_fee`. Each byte of the blob costs one unit of the blob gas.
+///
+/// The cost is spread over the capacity of the block and is rounded up, so the DA layer
+/// is never underpaid.
+pub fn blob_gas_price(
+    blob_base_fee: u128,
+    bytes_per_block: u64,
+    block_gas_limit: u64,
+) -> u64 {
+    let cost = blob_base_fee.saturating_mul(bytes_per_block as u128);
+    let capacity = (block_gas_limit as u128).max(1);
+    let gas_price = cost.saturating_add(capacity - 1) / capacity;
+    u64::try_from(gas_price).unwrap_or(u64::MAX)
+}
+
+/// The DA block costs with the signature of the block committer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedDaBlockCosts {
+    pub costs: DaBlockCosts,
+    pub signature: Signature,
+}
+
+impl SignedDaBlockCosts {
+    /// Returns the costs if they are signed by the `committer` for the chain
+    /// with the `chain_id`.
+    pub fn verify(
+        self,
+        committer: &PublicKey,
+        chain_id: &ChainId,
+    ) -> Result<DaBlockCosts, Error> {
+        self.signature
+            .verify(committer, &self.costs.signing_message(chain_id))
+            .map_err(|_| Error::InvalidSignature)?;
+        Ok(self.costs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_types::fuel_crypto::SecretKey;
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+
+    fn costs() -> DaBlockCosts {
+        DaBlockCosts {
+            l2_blocks: 1..=10,
+            blob_size_bytes: 1024,
+            blob_cost: 10_000,
+        }
+    }
+
+    fn sign(costs: DaBlockCosts, secret: &SecretKey) -> SignedDaBlockCosts {
+        let signature =
+            Signature::sign(secret, &costs.signing_message(&ChainId::default()));
+        SignedDaBlockCosts { costs, signature }
+    }
+
+    #[test]
+    fn verify__accepts_costs_signed_by_committer() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let signed = sign(costs(), &secret);
+
+        let result = signed.verify(&secret.public_key(), &ChainId::default());
+
+        assert_eq!(result, Ok(costs()));
+    }
+
+    #[test]
+    fn verify__rejects_costs_signed_by_another_key() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+        let attacker = SecretKey::random(&mut rng);
+        let signed = sign(costs(), &attacker);
+
+        let result = signed.verify(&committer.public_key(), &ChainId::default());
+
+        assert_eq!(result, Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn verify__rejects_costs_signed_for_another_chain() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let signed = sign(costs(), &secret);
+
+        let result = signed.verify(&secret.public_key(), &ChainId::new(1));
+
+        assert_eq!(result, Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn verify__rejects_modified_costs() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let secret = SecretKey::random(&mut rng);
+        let mut signed = sign(costs(), &secret);
+        signed.costs.blob_cost = 1;
+
+        let result = signed.verify(&secret.public_key(), &ChainId::default());
+
+        assert_eq!(result, Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn gas_price__spreads_cost_over_blocks_capacity() {
+        // 10 blocks with 100 gas each cost 10_000, so 10 per gas.
+        assert_eq!(costs().gas_price(100), Ok(10));
+    }
+
+    #[test]
+    fn gas_price__rounds_up() {
+        assert_eq!(costs().gas_price(3), Ok(334));
+    }
+
+    #[test]
+    fn gas_price__rejects_empty_range() {
+        let costs = DaBlockCosts {
+            #[allow(clippy::reversed_empty_ranges)]
+            l2_blocks: 10..=1,
+            ..costs()
+        };
+
+        assert_eq!(costs.gas_price(100), Err(Error::EmptyBlockRange));
+    }
+
+    #[test]
+    fn bytes_per_block__rounds_up() {
+        // 1024 bytes for 10 blocks.
+        assert_eq!(costs().bytes_per_block(), Ok(103));
+    }
+
+    #[test]
+    fn bytes_per_block__rejects_empty_range() {
+        let costs = DaBlockCosts {
+            #[allow(clippy::reversed_empty_ranges)]
+            l2_blocks: 10..=1,
+            ..costs()
+        };
+
+        assert_eq!(costs.bytes_per_block(), Err(Error::EmptyBlockRange));
+    }
+
+    #[test]
+    fn blob_gas_price__spreads_blob_cost_over_block_capacity() {
+        // 1000 bytes at 5 per byte cost 5000, so 50 per gas for 100 gas.
+        assert_eq!(blob_gas_price(5, 1000, 100), 50);
+        assert_eq!(blob_gas_price(10, 103, 100), 11);
+    }
+
+    #[test]
+    fn blob_gas_price__saturates() {
+        assert_eq!(blob_gas_price(u128::MAX, u64::MAX, 1), u64::MAX);
+    }
+}
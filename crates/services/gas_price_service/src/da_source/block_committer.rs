@@ -0,0 +1,106 @@
+use crate::{
+    da_source::{
+        DaBlockCosts,
+        SignedDaBlockCosts,
+    },
+    ports::DaBlockCostsSource,
+};
+use anyhow::Context;
+use fuel_core_types::fuel_crypto::Signature;
+use std::str::FromStr;
+
+/// The HTTP API of the block committer that reports the costs of committed bundles.
+pub struct BlockCommitterHttpApi {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl BlockCommitterHttpApi {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct RawDaBlockCosts {
+    l2_block_start: u32,
+    l2_block_end: u32,
+    blob_size_bytes: u32,
+    // The cost doesn't fit into the JSON number, so it is serialized as a string.
+    blob_cost: String,
+    signature: String,
+}
+
+impl TryFrom<RawDaBlockCosts> for SignedDaBlockCosts {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawDaBlockCosts) -> Result<Self, Self::Error> {
+        let blob_cost = u128::from_str(&raw.blob_cost)
+            .context("The `blob_cost` is not a valid number")?;
+        let signature = Signature::from_str(&raw.signature)
+            .map_err(|_| anyhow::anyhow!("The `signature` is not a valid signature"))?;
+        Ok(SignedDaBlockCosts {
+            costs: DaBlockCosts {
+                l2_blocks: raw.l2_block_start..=raw.l2_block_end,
+                blob_size_bytes: raw.blob_size_bytes,
+                blob_cost,
+            },
+            signature,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DaBlockCostsSource for BlockCommitterHttpApi {
+    async fn request_da_block_costs(&self) -> anyhow::Result<SignedDaBlockCosts> {
+        let raw = self
+            .client
+            .get(format!(
+                "{}/v1/costs/latest",
+                self.url.trim_end_matches('/')
+            ))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<RawDaBlockCosts>()
+            .await?;
+        raw.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_costs_are_parsed_from_json() {
+        let signature = Signature::default();
+        let json = format!(
+            r#"{{
+                "l2_block_start": 1,
+                "l2_block_end": 10,
+                "blob_size_bytes": 1024,
+                "blob_cost": "340282366920938463463374607431768211455",
+                "signature": "{signature}"
+            }}"#
+        );
+
+        let raw: RawDaBlockCosts = serde_json::from_str(&json).unwrap();
+        let signed = SignedDaBlockCosts::try_from(raw).unwrap();
+
+        assert_eq!(
+            signed,
+            SignedDaBlockCosts {
+                costs: DaBlockCosts {
+                    l2_blocks: 1..=10,
+                    blob_size_bytes: 1024,
+                    blob_cost: u128::MAX,
+                },
+                signature,
+            }
+        );
+    }
+}
@@ -0,0 +1,18 @@
+#![deny(unused_crate_dependencies)]
+#![deny(warnings)]
+
+mod algorithm;
+pub mod config;
+pub mod da_source;
+pub mod ports;
+pub mod service;
+
+pub use algorithm::{
+    AlgorithmState,
+    AlgorithmV0,
+    Error,
+};
+pub use config::Config;
+
+#[cfg(test)]
+fuel_core_trace::enable_tracing!();
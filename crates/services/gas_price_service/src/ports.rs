@@ -0,0 +1,42 @@
+use crate::{
+    da_source::SignedDaBlockCosts,
+    AlgorithmState,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    fuel_tx::{
+        Receipt,
+        TxId,
+    },
+    services::block_importer::ImportResult,
+};
+use std::sync::Arc;
+
+pub trait BlockImporter: Send + Sync {
+    /// Wait until the next block is available
+    fn block_events(&self) -> BoxStream<Arc<ImportResult>>;
+}
+
+pub trait GasPriceDb: Send + Sync {
+    /// Returns receipts of the committed transaction.
+    fn receipts(&self, tx_id: &TxId) -> StorageResult<Option<Vec<Receipt>>>;
+
+    /// Returns the state of the gas price algorithm stored after its latest change.
+    fn algorithm_state(&self) -> StorageResult<Option<AlgorithmState>>;
+
+    /// Stores the state of the gas price algorithm.
+    fn store_algorithm_state(&self, state: &AlgorithmState) -> StorageResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait DaBlockCostsSource: Send + Sync {
+    /// Requests the costs of the latest bundle committed by the block committer.
+    async fn request_da_block_costs(&self) -> anyhow::Result<SignedDaBlockCosts>;
+}
+
+#[async_trait::async_trait]
+pub trait BlobBaseFeeSource: Send + Sync {
+    /// Requests the current blob base fee of the DA layer per byte of the blob.
+    async fn blob_base_fee(&self) -> anyhow::Result<u128>;
+}
@@ -0,0 +1,977 @@
+use crate::{
+    algorithm::AlgorithmV0,
+    config::{
+        BlobFeeConfig,
+        DaSourceConfig,
+    },
+    da_source::blob_gas_price,
+    ports::{
+        BlobBaseFeeSource,
+        BlockImporter,
+        DaBlockCostsSource,
+        GasPriceDb,
+    },
+    Config,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    fuel_crypto::PublicKey,
+    fuel_tx::Receipt,
+    fuel_types::{
+        BlockHeight,
+        ChainId,
+    },
+    services::block_importer::ImportResult,
+};
+use parking_lot::RwLock;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    task::JoinHandle,
+    time::MissedTickBehavior,
+};
+use tokio_stream::StreamExt;
+
+pub type Service<DB> = ServiceRunner<Task<DB>>;
+
+#[derive(Clone, Debug)]
+pub struct SharedState {
+    algorithm: Arc<RwLock<AlgorithmV0>>,
+}
+
+impl SharedState {
+    /// Returns the gas price for the next block.
+    pub fn latest_gas_price(&self) -> u64 {
+        self.algorithm.read().calculate()
+    }
+
+    /// Returns the highest gas price that can be required for the block at the `height`.
+    pub fn worst_case_gas_price(&self, height: BlockHeight) -> u64 {
+        self.algorithm.read().worst_case(height)
+    }
+
+    /// Returns the part of the gas price that covers the cost of the DA layer.
+    pub fn da_gas_price(&self) -> u64 {
+        self.algorithm.read().da_gas_price()
+    }
+
+    /// Returns the height of the latest block used to calculate the gas price.
+    pub fn latest_block_height(&self) -> BlockHeight {
+        self.algorithm.read().l2_block_height()
+    }
+}
+
+/// Requests the DA costs from the block committer and accepts only the authenticated ones.
+struct DaSource {
+    source: Box<dyn DaBlockCostsSource>,
+    committer_public_key: PublicKey,
+    chain_id: ChainId,
+    fallback_gas_price: u64,
+    polling_period: Duration,
+    /// The height of the last L2 block covered by the accepted costs.
+    last_l2_block: Option<u32>,
+    /// The DA gas price of the last accepted costs.
+    last_da_gas_price: Option<u64>,
+}
+
+/// The DA gas price reported by the [`DaSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DaCostsUpdate {
+    da_gas_price: u64,
+    /// The average size of the L2 block in the accepted costs.
+    /// It is `None` for the fallback DA gas price.
+    bytes_per_block: Option<u64>,
+}
+
+impl DaSource {
+    fn new(
+        source: Box<dyn DaBlockCostsSource>,
+        config: DaSourceConfig,
+        chain_id: ChainId,
+    ) -> Self {
+        Self {
+            source,
+            committer_public_key: config.committer_public_key,
+            chain_id,
+            fallback_gas_price: config.fallback_gas_price,
+            polling_period: config.polling_interval,
+            last_l2_block: None,
+            last_da_gas_price: None,
+        }
+    }
+
+    /// Polls the block committer and sends the new DA gas prices to the `updates`
+    /// until the service stops. It runs in its own task, so the other events of
+    /// the service don't cancel the requests in flight.
+    async fn run(mut self, block_gas_limit: u64, updates: mpsc::Sender<DaCostsUpdate>) {
+        let mut interval = tokio::time::interval(self.polling_period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            if let Some(update) = self.request_da_gas_price(block_gas_limit).await {
+                if updates.send(update).await.is_err() {
+                    return
+                }
+            }
+        }
+    }
+
+    /// Requests the latest costs and returns the new DA gas price.
+    /// Returns `None` if the DA gas price should stay unchanged.
+    async fn request_da_gas_price(
+        &mut self,
+        block_gas_limit: u64,
+    ) -> Option<DaCostsUpdate> {
+        let signed = match self.source.request_da_block_costs().await {
+            Ok(signed) => signed,
+            Err(err) if self.last_da_gas_price.is_some() => {
+                tracing::warn!(
+                    "The block committer is unreachable, keeping the last DA gas price: {}",
+                    err
+                );
+                return None
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "The block committer is unreachable, using the fallback DA gas price: {}",
+                    err
+                );
+                return Some(DaCostsUpdate {
+                    da_gas_price: self.fallback_gas_price,
+                    bytes_per_block: None,
+                })
+            }
+        };
+
+        let costs = match signed.verify(&self.committer_public_key, &self.chain_id) {
+            Ok(costs) => costs,
+            Err(err) => {
+                tracing::warn!("Rejected the DA block costs: {}", err);
+                return None
+            }
+        };
+
+        let end = *costs.l2_blocks.end();
+        if matches!(self.last_l2_block, Some(last) if end < last) {
+            tracing::debug!(
+                "Ignored the outdated DA block costs up to the block {}",
+                end
+            );
+            return None
+        }
+
+        match costs.gas_price(block_gas_limit) {
+            Ok(gas_price) => {
+                self.last_l2_block = Some(end);
+                self.last_da_gas_price = Some(gas_price);
+                Some(DaCostsUpdate {
+                    da_gas_price: gas_price,
+                    bytes_per_block: costs.bytes_per_block().ok(),
+                })
+            }
+            Err(err) => {
+                tracing::warn!("Rejected the DA block costs: {}", err);
+                None
+            }
+        }
+    }
+}
+
+/// Requests the blob base fee of the DA layer to price the DA costs in near real-time.
+struct BlobFee {
+    source: Box<dyn BlobBaseFeeSource>,
+    polling_period: Duration,
+}
+
+impl BlobFee {
+    fn new(source: Box<dyn BlobBaseFeeSource>, config: &BlobFeeConfig) -> Self {
+        Self {
+            source,
+            polling_period: config.polling_interval,
+        }
+    }
+
+    /// Polls the blob base fee and sends it to the `updates` until the service stops.
+    /// The update is `None` if the request failed. It runs in its own task, so the
+    /// other events of the service don't cancel the requests in flight.
+    async fn run(self, updates: mpsc::Sender<Option<u128>>) {
+        let mut interval = tokio::time::interval(self.polling_period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let blob_base_fee = match self.source.blob_base_fee().await {
+                Ok(blob_base_fee) => Some(blob_base_fee),
+                Err(err) => {
+                    tracing::warn!("Failed to request the blob base fee: {}", err);
+                    None
+                }
+            };
+            if updates.send(blob_base_fee).await.is_err() {
+                return
+            }
+        }
+    }
+}
+
+/// Receives the next update from the polling task. Waits forever if the source
+/// is not configured or its task is finished.
+async fn next_update<T>(updates: &mut Option<mpsc::Receiver<T>>) -> T {
+    if let Some(receiver) = updates {
+        if let Some(update) = receiver.recv().await {
+            return update
+        }
+        *updates = None;
+    }
+    core::future::pending().await
+}
+
+pub struct Task<DB> {
+    committed_block_stream: BoxStream<Arc<ImportResult>>,
+    /// The sources of the DA layer. They are moved into their own tasks on start.
+    da_source: Option<DaSource>,
+    blob_fee: Option<BlobFee>,
+    pollers: Vec<JoinHandle<()>>,
+    da_costs: Option<mpsc::Receiver<DaCostsUpdate>>,
+    blob_base_fees: Option<mpsc::Receiver<Option<u128>>>,
+    /// The average size of the L2 block in the latest costs reported by the block committer.
+    bytes_per_block: Option<u64>,
+    /// The latest blob base fee. It is `None` if the last request failed.
+    blob_base_fee: Option<u128>,
+    /// The size of the L2 block used until the block committer reports the actual one.
+    blob_bytes_per_block: u64,
+    database: DB,
+    block_gas_limit: u64,
+    shared: SharedState,
+}
+
+impl<DB> Task<DB>
+where
+    DB: GasPriceDb,
+{
+    fn process_block(&self, result: &ImportResult) -> anyhow::Result<()> {
+        let height = *result.sealed_block.entity.header().height();
+        let gas_used = self.block_gas_used(result)?;
+        let state = {
+            let mut algorithm = self.shared.algorithm.write();
+            algorithm.update_l2_block_data(height, gas_used, self.block_gas_limit)?;
+            algorithm.state()
+        };
+        self.database.store_algorithm_state(&state)?;
+        Ok(())
+    }
+
+    /// Updates the DA gas price and stores the new state of the algorithm.
+    fn update_da_gas_price(&self, da_gas_price: u64) {
+        let state = {
+            let mut algorithm = self.shared.algorithm.write();
+            algorithm.update_da_gas_price(da_gas_price);
+            algorithm.state()
+        };
+        if let Err(err) = self.database.store_algorithm_state(&state) {
+            tracing::warn!("Failed to store the state of the gas price: {}", err);
+        }
+    }
+
+    fn block_gas_used(&self, result: &ImportResult) -> StorageResult<u64> {
+        let mut gas_used = 0u64;
+        for status in result.tx_status.iter() {
+            let receipts = self.database.receipts(&status.id)?.unwrap_or_default();
+            for receipt in receipts {
+                if let Receipt::ScriptResult { gas_used: used, .. } = receipt {
+                    gas_used = gas_used.saturating_add(used);
+                }
+            }
+        }
+        Ok(gas_used)
+    }
+
+    /// Returns the DA gas price based on the latest blob base fee. The size of the
+    /// blocks is taken from the latest costs reported by the block committer.
+    fn blob_fee_gas_price(&self) -> Option<u64> {
+        let bytes_per_block = self.bytes_per_block.unwrap_or(self.blob_bytes_per_block);
+        self.blob_base_fee.map(|blob_base_fee| {
+            blob_gas_price(blob_base_fee, bytes_per_block, self.block_gas_limit)
+        })
+    }
+
+    /// Updates the DA gas price with the costs reported by the block committer. The
+    /// price based on the blob base fee takes precedence, because the block committer
+    /// reports the costs with a delay.
+    fn update_reported_da_gas_price(&mut self, update: DaCostsUpdate) {
+        if let Some(bytes_per_block) = update.bytes_per_block {
+            self.bytes_per_block = Some(bytes_per_block);
+        }
+        let da_gas_price = self.blob_fee_gas_price().unwrap_or(update.da_gas_price);
+        self.update_da_gas_price(da_gas_price);
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB> RunnableService for Task<DB>
+where
+    DB: GasPriceDb,
+{
+    const NAME: &'static str = "GasPrice";
+
+    type SharedData = SharedState;
+    type Task = Task<DB>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        if let Some(da_source) = self.da_source.take() {
+            let (sender, receiver) = mpsc::channel(1);
+            self.pollers
+                .push(tokio::spawn(da_source.run(self.block_gas_limit, sender)));
+            self.da_costs = Some(receiver);
+        }
+        if let Some(blob_fee) = self.blob_fee.take() {
+            let (sender, receiver) = mpsc::channel(1);
+            self.pollers.push(tokio::spawn(blob_fee.run(sender)));
+            self.blob_base_fees = Some(receiver);
+        }
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB> RunnableTask for Task<DB>
+where
+    DB: GasPriceDb,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let should_continue;
+
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+
+            result = self.committed_block_stream.next() => {
+                if let Some(result) = result {
+                    if let Err(err) = self.process_block(&result) {
+                        // The gas price is only an estimation, so the error should not stop
+                        // the service. The next block will update the price again.
+                        tracing::warn!("Failed to update the gas price: {}", err);
+                    }
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            update = next_update(&mut self.da_costs) => {
+                self.update_reported_da_gas_price(update);
+                should_continue = true;
+            }
+
+            blob_base_fee = next_update(&mut self.blob_base_fees) => {
+                self.blob_base_fee = blob_base_fee;
+                // If the request failed, the price stays unchanged until the next
+                // costs from the block committer.
+                if let Some(da_gas_price) = self.blob_fee_gas_price() {
+                    self.update_da_gas_price(da_gas_price);
+                }
+                should_continue = true;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The pollers don't have any state that should be dumped.
+        for poller in self.pollers {
+            poller.abort();
+        }
+        Ok(())
+    }
+}
+
+/// Creates the gas price service. The `da_source` is used only if `Config::da_source` is set,
+/// and the `blob_fee_source` is used only if `Config::blob_fee` is set.
+///
+/// The algorithm continues from the state stored in the `database`, if any. Otherwise,
+/// it starts from the `Config::starting_gas_price` after the `latest_block_height`.
+pub fn new_service<Importer, DB, Source, BlobSource>(
+    config: Config,
+    latest_block_height: BlockHeight,
+    importer: Importer,
+    database: DB,
+    da_source: Option<Source>,
+    blob_fee_source: Option<BlobSource>,
+) -> anyhow::Result<Service<DB>>
+where
+    Importer: BlockImporter,
+    DB: GasPriceDb + 'static,
+    Source: DaBlockCostsSource + 'static,
+    BlobSource: BlobBaseFeeSource + 'static,
+{
+    let algorithm = match database.algorithm_state()? {
+        Some(state) => AlgorithmV0::from_state(
+            state,
+            config.min_gas_price,
+            config.gas_price_change_percent,
+            config.gas_price_threshold_percent,
+        ),
+        None => AlgorithmV0::new(
+            config.starting_gas_price,
+            config.min_gas_price,
+            config.gas_price_change_percent,
+            config.gas_price_threshold_percent,
+            latest_block_height,
+        ),
+    };
+    let da_source = da_source.zip(config.da_source).map(|(source, da_config)| {
+        DaSource::new(
+            Box::new(source) as Box<dyn DaBlockCostsSource>,
+            da_config,
+            config.chain_id,
+        )
+    });
+    let blob_fee =
+        blob_fee_source
+            .zip(config.blob_fee.as_ref())
+            .map(|(source, config)| {
+                BlobFee::new(Box::new(source) as Box<dyn BlobBaseFeeSource>, config)
+            });
+    let blob_bytes_per_block = config
+        .blob_fee
+        .as_ref()
+        .map(|config| config.bytes_per_block)
+        .unwrap_or_default();
+    let task = Task {
+        committed_block_stream: importer.block_events(),
+        da_source,
+        blob_fee,
+        pollers: vec![],
+        da_costs: None,
+        blob_base_fees: None,
+        bytes_per_block: None,
+        blob_base_fee: None,
+        blob_bytes_per_block,
+        database,
+        block_gas_limit: config.block_gas_limit,
+        shared: SharedState {
+            algorithm: Arc::new(RwLock::new(algorithm)),
+        },
+    };
+
+    Ok(Service::new(task))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use crate::{
+        da_source::{
+            DaBlockCosts,
+            SignedDaBlockCosts,
+        },
+        AlgorithmState,
+    };
+    use fuel_core_services::{
+        stream::IntoBoxStream,
+        Service as ServiceTrait,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            header::BlockHeader,
+            SealedBlock,
+        },
+        fuel_crypto::{
+            SecretKey,
+            Signature,
+        },
+        fuel_tx::{
+            ScriptExecutionResult,
+            TxId,
+        },
+        services::executor::{
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        tai64::Tai64,
+    };
+    use rand::{
+        rngs::StdRng,
+        SeedableRng,
+    };
+    use std::{
+        collections::{
+            HashMap,
+            VecDeque,
+        },
+        sync::atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+    };
+    use tokio_stream::wrappers::ReceiverStream;
+
+    struct MockImporter(mpsc::Receiver<Arc<ImportResult>>);
+
+    impl MockImporter {
+        fn new() -> (Self, mpsc::Sender<Arc<ImportResult>>) {
+            let (sender, receiver) = mpsc::channel(10);
+            (Self(receiver), sender)
+        }
+    }
+
+    impl BlockImporter for std::sync::Mutex<Option<MockImporter>> {
+        fn block_events(&self) -> BoxStream<Arc<ImportResult>> {
+            let importer = self.lock().unwrap().take().expect("Called only once");
+            ReceiverStream::new(importer.0).into_boxed()
+        }
+    }
+
+    #[derive(Default, Clone)]
+    struct MockDb {
+        receipts: HashMap<TxId, Vec<Receipt>>,
+        algorithm_state: Arc<parking_lot::Mutex<Option<AlgorithmState>>>,
+    }
+
+    impl GasPriceDb for MockDb {
+        fn receipts(&self, tx_id: &TxId) -> StorageResult<Option<Vec<Receipt>>> {
+            Ok(self.receipts.get(tx_id).cloned())
+        }
+
+        fn algorithm_state(&self) -> StorageResult<Option<AlgorithmState>> {
+            Ok(*self.algorithm_state.lock())
+        }
+
+        fn store_algorithm_state(&self, state: &AlgorithmState) -> StorageResult<()> {
+            *self.algorithm_state.lock() = Some(*state);
+            Ok(())
+        }
+    }
+
+    struct MockDaSource {
+        responses: parking_lot::Mutex<VecDeque<anyhow::Result<SignedDaBlockCosts>>>,
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl DaBlockCostsSource for MockDaSource {
+        async fn request_da_block_costs(&self) -> anyhow::Result<SignedDaBlockCosts> {
+            let response = self.responses.lock().pop_front();
+            match response {
+                Some(response) => {
+                    self.requests.fetch_add(1, Ordering::SeqCst);
+                    response
+                }
+                None => core::future::pending().await,
+            }
+        }
+    }
+
+    /// Responds slower than the blocks are imported.
+    struct SlowDaSource(SignedDaBlockCosts);
+
+    #[async_trait::async_trait]
+    impl DaBlockCostsSource for SlowDaSource {
+        async fn request_da_block_costs(&self) -> anyhow::Result<SignedDaBlockCosts> {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok(self.0.clone())
+        }
+    }
+
+    struct MockBlobFeeSource {
+        blob_base_fee: u128,
+        requests: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl BlobBaseFeeSource for MockBlobFeeSource {
+        async fn blob_base_fee(&self) -> anyhow::Result<u128> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(self.blob_base_fee)
+        }
+    }
+
+    fn signed_costs(
+        l2_blocks: core::ops::RangeInclusive<u32>,
+        blob_cost: u128,
+        secret: &SecretKey,
+    ) -> SignedDaBlockCosts {
+        let costs = DaBlockCosts {
+            l2_blocks,
+            blob_size_bytes: 1024,
+            blob_cost,
+        };
+        let signature =
+            Signature::sign(secret, &costs.signing_message(&ChainId::default()));
+        SignedDaBlockCosts { costs, signature }
+    }
+
+    /// Runs the service until it processes all `responses` and returns the DA gas price.
+    async fn da_gas_price_after(
+        committer: &SecretKey,
+        responses: Vec<anyhow::Result<SignedDaBlockCosts>>,
+    ) -> u64 {
+        let expected_requests = responses.len();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let da_source = MockDaSource {
+            responses: parking_lot::Mutex::new(responses.into()),
+            requests: requests.clone(),
+        };
+        let (importer, _sender) = MockImporter::new();
+        let config = Config {
+            block_gas_limit: 100,
+            da_source: Some(DaSourceConfig {
+                url: "http://localhost".to_string(),
+                committer_public_key: committer.public_key(),
+                polling_interval: Duration::from_millis(1),
+                fallback_gas_price: 1000,
+            }),
+            ..Default::default()
+        };
+        let service = new_service(
+            config,
+            0u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            MockDb::default(),
+            Some(da_source),
+            None::<MockBlobFeeSource>,
+        )
+        .unwrap();
+        service.start_and_await().await.unwrap();
+
+        while requests.load(Ordering::SeqCst) != expected_requests {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        // The price is updated right after the request.
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let da_gas_price = service.shared.da_gas_price();
+        service.stop_and_await().await.unwrap();
+        da_gas_price
+    }
+
+    #[tokio::test]
+    async fn da_source__authenticated_costs_update_da_gas_price() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        let da_gas_price = da_gas_price_after(
+            &committer,
+            vec![Ok(signed_costs(1..=10, 10_000, &committer))],
+        )
+        .await;
+
+        assert_eq!(da_gas_price, 10);
+    }
+
+    #[tokio::test]
+    async fn da_source__unauthenticated_costs_are_rejected() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+        let attacker = SecretKey::random(&mut rng);
+
+        let da_gas_price = da_gas_price_after(
+            &committer,
+            vec![
+                Ok(signed_costs(1..=10, 10_000, &committer)),
+                Ok(signed_costs(11..=20, 1, &attacker)),
+            ],
+        )
+        .await;
+
+        assert_eq!(da_gas_price, 10);
+    }
+
+    #[tokio::test]
+    async fn da_source__outdated_costs_are_ignored() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        let da_gas_price = da_gas_price_after(
+            &committer,
+            vec![
+                Ok(signed_costs(11..=20, 10_000, &committer)),
+                Ok(signed_costs(1..=10, 1, &committer)),
+            ],
+        )
+        .await;
+
+        assert_eq!(da_gas_price, 10);
+    }
+
+    #[tokio::test]
+    async fn da_source__unreachable_source_keeps_last_da_gas_price() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        let da_gas_price = da_gas_price_after(
+            &committer,
+            vec![
+                Ok(signed_costs(1..=10, 10_000, &committer)),
+                Err(anyhow::anyhow!("Connection refused")),
+            ],
+        )
+        .await;
+
+        assert_eq!(da_gas_price, 10);
+    }
+
+    #[tokio::test]
+    async fn da_source__unreachable_source_uses_fallback_before_first_costs() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        let da_gas_price = da_gas_price_after(
+            &committer,
+            vec![Err(anyhow::anyhow!("Connection refused"))],
+        )
+        .await;
+
+        assert_eq!(da_gas_price, 1000);
+    }
+
+    #[tokio::test]
+    async fn da_source__request_in_flight_is_not_cancelled_by_imported_blocks() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+        let (importer, sender) = MockImporter::new();
+        let config = Config {
+            block_gas_limit: 100,
+            da_source: Some(DaSourceConfig {
+                url: "http://localhost".to_string(),
+                committer_public_key: committer.public_key(),
+                polling_interval: Duration::from_millis(1),
+                fallback_gas_price: 1000,
+            }),
+            ..Default::default()
+        };
+        let service = new_service(
+            config,
+            0u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            MockDb::default(),
+            Some(SlowDaSource(signed_costs(1..=10, 10_000, &committer))),
+            None::<MockBlobFeeSource>,
+        )
+        .unwrap();
+        service.start_and_await().await.unwrap();
+
+        // The blocks are imported more often than the block committer responds.
+        let mut height = 1;
+        while service.shared.da_gas_price() != 10 {
+            sender
+                .send(import_result(height, TxId::zeroed()))
+                .await
+                .unwrap();
+            height += 1;
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        service.stop_and_await().await.unwrap();
+    }
+
+    /// Runs the service with the blob fee source until it processes all `responses`
+    /// of the block committer and returns its shared state.
+    async fn gas_price_with_blob_fee(
+        committer: &SecretKey,
+        responses: Vec<anyhow::Result<SignedDaBlockCosts>>,
+        blob_base_fee: u128,
+    ) -> SharedState {
+        let expected_requests = responses.len();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let da_source = MockDaSource {
+            responses: parking_lot::Mutex::new(responses.into()),
+            requests: requests.clone(),
+        };
+        let blob_requests = Arc::new(AtomicUsize::new(0));
+        let blob_fee_source = MockBlobFeeSource {
+            blob_base_fee,
+            requests: blob_requests.clone(),
+        };
+        let (importer, _sender) = MockImporter::new();
+        let config = Config {
+            starting_gas_price: 7,
+            block_gas_limit: 100,
+            da_source: Some(DaSourceConfig {
+                url: "http://localhost".to_string(),
+                committer_public_key: committer.public_key(),
+                polling_interval: Duration::from_millis(1),
+                fallback_gas_price: 1000,
+            }),
+            blob_fee: Some(BlobFeeConfig {
+                polling_interval: Duration::from_millis(1),
+                bytes_per_block: 1000,
+            }),
+            ..Default::default()
+        };
+        let service = new_service(
+            config,
+            0u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            MockDb::default(),
+            Some(da_source),
+            Some(blob_fee_source),
+        )
+        .unwrap();
+        service.start_and_await().await.unwrap();
+
+        while requests.load(Ordering::SeqCst) != expected_requests {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        // Wait for the blob base fee requested after the costs of the block committer.
+        let blob_requests_before = blob_requests.load(Ordering::SeqCst);
+        while blob_requests.load(Ordering::SeqCst) < blob_requests_before + 2 {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+
+        let shared = service.shared.clone();
+        service.stop_and_await().await.unwrap();
+        shared
+    }
+
+    #[tokio::test]
+    async fn blob_fee__overrides_costs_reported_by_committer() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        // The committer reports 1024 bytes for 10 blocks, so 103 bytes per block.
+        let shared = gas_price_with_blob_fee(
+            &committer,
+            vec![Ok(signed_costs(1..=10, 10_000, &committer))],
+            10,
+        )
+        .await;
+
+        assert_eq!(shared.da_gas_price(), 11);
+    }
+
+    #[tokio::test]
+    async fn blob_fee__uses_configured_block_size_without_committer_costs() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        // The configured 1000 bytes per block at 5 per byte for 100 gas.
+        let shared = gas_price_with_blob_fee(&committer, vec![], 5).await;
+
+        assert_eq!(shared.da_gas_price(), 50);
+    }
+
+    #[tokio::test]
+    async fn blob_fee__is_required_by_gas_price_of_next_block() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let committer = SecretKey::random(&mut rng);
+
+        let shared = gas_price_with_blob_fee(&committer, vec![], 5).await;
+
+        // The txpool and the block producer enforce the latest gas price, so the DA
+        // price is charged on top of the starting L2 price.
+        assert_eq!(shared.latest_gas_price(), 7 + 50);
+    }
+
+    fn import_result(height: u32, tx_id: TxId) -> Arc<ImportResult> {
+        let mut sealed_block = SealedBlock::default();
+        *sealed_block.entity.header_mut() =
+            BlockHeader::new_block(height.into(), Tai64::now());
+        Arc::new(ImportResult::new_from_local(
+            sealed_block,
+            vec![TransactionExecutionStatus {
+                id: tx_id,
+                result: TransactionExecutionResult::Success { result: None },
+            }],
+        ))
+    }
+
+    #[tokio::test]
+    async fn full_block_increases_gas_price() {
+        let tx_id = TxId::from([1u8; 32]);
+        let mut database = MockDb::default();
+        database.receipts.insert(
+            tx_id,
+            vec![Receipt::script_result(ScriptExecutionResult::Success, 1000)],
+        );
+        let (importer, sender) = MockImporter::new();
+        let config = Config {
+            starting_gas_price: 100,
+            block_gas_limit: 1000,
+            ..Default::default()
+        };
+        let service = new_service(
+            config,
+            0u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            database,
+            None::<MockDaSource>,
+            None::<MockBlobFeeSource>,
+        )
+        .unwrap();
+        service.start_and_await().await.unwrap();
+
+        sender.send(import_result(1, tx_id)).await.unwrap();
+        // Wait for the service to process the block.
+        while service.shared.latest_block_height() != 1u32.into() {
+            tokio::task::yield_now().await;
+        }
+
+        assert_eq!(service.shared.latest_gas_price(), 110);
+        assert_eq!(service.shared.worst_case_gas_price(3u32.into()), 121);
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn gas_price_continues_from_stored_state_after_restart() {
+        let database = MockDb::default();
+        let config = Config {
+            starting_gas_price: 100,
+            block_gas_limit: 1000,
+            ..Default::default()
+        };
+        let (importer, sender) = MockImporter::new();
+        let service = new_service(
+            config.clone(),
+            0u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            database.clone(),
+            None::<MockDaSource>,
+            None::<MockBlobFeeSource>,
+        )
+        .unwrap();
+        service.start_and_await().await.unwrap();
+        // The empty block decreases the gas price.
+        sender.send(import_result(1, TxId::zeroed())).await.unwrap();
+        while service.shared.latest_block_height() != 1u32.into() {
+            tokio::task::yield_now().await;
+        }
+        service.stop_and_await().await.unwrap();
+
+        let (importer, _sender) = MockImporter::new();
+        let restarted = new_service(
+            config,
+            1u32.into(),
+            std::sync::Mutex::new(Some(importer)),
+            database,
+            None::<MockDaSource>,
+            None::<MockBlobFeeSource>,
+        )
+        .unwrap();
+
+        assert_eq!(restarted.shared.latest_gas_price(), 90);
+        assert_eq!(restarted.shared.latest_block_height(), 1u32.into());
+    }
+}
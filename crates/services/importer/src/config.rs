@@ -1,7 +1,23 @@
+use fuel_core_types::services::executor::BlockLimits;
+use std::time::Duration;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
+    /// The maximum number of the competing blocks kept in the database.
+    pub max_competing_blocks: usize,
+    /// The maximum time the block imported in a batch may wait for the commit into
+    /// the database. The consecutive blocks imported during this time are written
+    /// into the database at once.
+    pub max_commit_batch_latency: Duration,
+    /// The number of the latest blocks with the recorded state diffs. The state diffs
+    /// are applied by the follower nodes without the execution. `None` disables the
+    /// recording.
+    pub state_diff_retention: Option<u32>,
+    /// The limits of the imported blocks. The blocks exceeding the size limit are
+    /// rejected before the execution.
+    pub block_limits: BlockLimits,
 }
 
 impl Default for Config {
@@ -9,6 +25,10 @@ impl Default for Config {
         Self {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
+            max_competing_blocks: 64,
+            max_commit_batch_latency: Duration::from_secs(1),
+            state_diff_retention: None,
+            block_limits: BlockLimits::default(),
         }
     }
 }
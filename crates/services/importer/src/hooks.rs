@@ -0,0 +1,122 @@
+//! The hooks called by the [`Importer`](crate::Importer) after the commit of each block.
+//! They allow the crates embedding the node to index the blocks inside of the node
+//! process. The hooks are called in the order of the registration, after the commit
+//! of the block into the database and before the notification of the subscribers of
+//! the [`Importer::subscribe`](crate::Importer::subscribe).
+
+use fuel_core_types::services::{
+    block_importer::ImportResult,
+    executor::StateDiff,
+};
+use std::sync::Arc;
+use tokio::{
+    runtime::{
+        Handle,
+        RuntimeFlavor,
+    },
+    sync::mpsc::{
+        self,
+        error::TrySendError,
+    },
+};
+
+/// The committed block with the changes of the state made by it.
+#[derive(Debug)]
+pub struct CommittedBlock {
+    /// The result of the import of the block.
+    pub result: Arc<ImportResult>,
+    /// The changes of the database made by the block.
+    pub state_diff: StateDiff,
+}
+
+/// The synchronous hook called after the commit of each block.
+pub trait CommitHook: Send + Sync {
+    /// Handles the committed `block`. The importer waits for the return before it
+    /// commits the next block, so the slow hook slows down the import. The error is
+    /// logged and doesn't affect the import.
+    fn on_commit(&self, block: &Arc<CommittedBlock>) -> anyhow::Result<()>;
+
+    /// Returns `true` if the hook doesn't need the blocks anymore, so it is removed.
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
+
+/// What the importer does when the queue of the asynchronous hook is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backpressure {
+    /// The importer waits until the hook takes the blocks from its queue, so the
+    /// hook doesn't miss the blocks. The receiver of the hook must be served by
+    /// another thread than the one committing the blocks. The importer can't wait
+    /// inside of the current-thread runtime, so there the block is skipped with
+    /// the error.
+    Wait,
+    /// The hook misses the blocks committed while its queue is full.
+    Skip,
+}
+
+/// The hook that sends the committed blocks into the queue of the asynchronous hook.
+pub(crate) struct ChannelHook {
+    sender: mpsc::Sender<Arc<CommittedBlock>>,
+    backpressure: Backpressure,
+}
+
+impl ChannelHook {
+    /// Creates the hook with the queue of the `capacity` blocks.
+    pub(crate) fn new(
+        capacity: usize,
+        backpressure: Backpressure,
+    ) -> (Self, mpsc::Receiver<Arc<CommittedBlock>>) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (
+            Self {
+                sender,
+                backpressure,
+            },
+            receiver,
+        )
+    }
+}
+
+impl CommitHook for ChannelHook {
+    fn on_commit(&self, block: &Arc<CommittedBlock>) -> anyhow::Result<()> {
+        match self.sender.try_send(block.clone()) {
+            Ok(()) => Ok(()),
+            // The hook is removed by the `is_finished`.
+            Err(TrySendError::Closed(_)) => Ok(()),
+            Err(TrySendError::Full(block)) => match self.backpressure {
+                Backpressure::Wait => {
+                    // The error means that the receiver is dropped during the waiting.
+                    let _ = wait(self.sender.send(block))?;
+                    Ok(())
+                }
+                Backpressure::Skip => Err(anyhow::anyhow!(
+                    "The queue of the hook is full, the block is skipped"
+                )),
+            },
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// Blocks the current thread until the `future` is ready. The worker thread of the
+/// multi-threaded runtime hands over its tasks to another thread before blocking.
+/// Blocking of the current-thread runtime would stall all of its tasks, including
+/// the receiver of the hook, so it is an error.
+fn wait<F: std::future::Future>(future: F) -> anyhow::Result<F::Output> {
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            Ok(tokio::task::block_in_place(|| {
+                futures::executor::block_on(future)
+            }))
+        }
+        Ok(_) => Err(anyhow::anyhow!(
+            "The queue of the hook is full and the current-thread runtime can't wait, \
+             the block is skipped"
+        )),
+        Err(_) => Ok(futures::executor::block_on(future)),
+    }
+}
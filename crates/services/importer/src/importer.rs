@@ -1,4 +1,10 @@
 use crate::{
+    hooks::{
+        Backpressure,
+        ChannelHook,
+        CommitHook,
+        CommittedBlock,
+    },
     ports,
     ports::{
         BlockVerifier,
@@ -25,17 +31,26 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::{
+            CompetingBlock,
             ImportResult,
             UncommittedResult,
         },
         executor,
-        executor::ExecutionResult,
+        executor::{
+            BlockLimits,
+            ExecutionResult,
+            StateDiff,
+        },
         Uncommitted,
     },
 };
 use std::{
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
     time::{
+        Duration,
         Instant,
         SystemTime,
         UNIX_EPOCH,
@@ -43,6 +58,7 @@ use std::{
 };
 use tokio::sync::{
     broadcast,
+    mpsc,
     TryAcquireError,
 };
 
@@ -78,8 +94,12 @@ pub enum Error {
     SkippedTransactionsNotEmpty,
     #[error("It is not possible to execute the genesis block.")]
     ExecuteGenesis,
+    #[error("The block size {size} exceeds the limit {limit}.")]
+    BlockSizeLimitExceeded { size: u64, limit: u64 },
     #[error("The database already contains the data at the height {0}.")]
     NotUnique(BlockHeight),
+    #[error("The block {2} competes with the canonical block {1} at the height {0}.")]
+    CompetingBlock(BlockHeight, BlockId, BlockId),
     #[error(transparent)]
     StorageError(#[from] StorageError),
 }
@@ -91,12 +111,43 @@ impl PartialEq for Error {
     }
 }
 
+/// The result of the import of the batch of consecutive blocks.
+#[derive(Debug)]
+pub struct BatchImportResult {
+    /// The number of the committed blocks. The committed blocks are always
+    /// the beginning of the batch.
+    pub committed: usize,
+    /// The error that interrupted the import of the batch, if any.
+    pub error: Option<Error>,
+}
+
+/// The hook registered in the importer under the `name` used in the logs.
+#[derive(Clone)]
+struct RegisteredHook {
+    name: String,
+    hook: Arc<dyn CommitHook>,
+}
+
+/// The result of the sealing of the block before its commit.
+struct SealedResult {
+    /// The total number of the transactions on the chain after the block.
+    total_txs: u64,
+    /// The changes of the block, collected only if the commit hooks are registered.
+    state_diff: Option<StateDiff>,
+}
+
 pub struct Importer<D, E, V> {
     database: D,
     executor: E,
     verifier: V,
     broadcast: broadcast::Sender<Arc<ImportResult>>,
     guard: tokio::sync::Semaphore,
+    max_competing_blocks: usize,
+    max_commit_batch_latency: Duration,
+    state_diff_retention: Option<u32>,
+    block_limits: BlockLimits,
+    /// The hooks called after the commit of each block, in the order of registration.
+    commit_hooks: Mutex<Vec<RegisteredHook>>,
 }
 
 impl<D, E, V> Importer<D, E, V> {
@@ -109,6 +160,11 @@ impl<D, E, V> Importer<D, E, V> {
             verifier,
             broadcast,
             guard: tokio::sync::Semaphore::new(1),
+            max_competing_blocks: config.max_competing_blocks,
+            max_commit_batch_latency: config.max_commit_batch_latency,
+            state_diff_retention: config.state_diff_retention,
+            block_limits: config.block_limits,
+            commit_hooks: Mutex::new(Vec::new()),
         }
     }
 
@@ -116,6 +172,69 @@ impl<D, E, V> Importer<D, E, V> {
         self.broadcast.subscribe()
     }
 
+    /// Registers the `hook` called after the commit of each block with its state diff.
+    /// The hook is called before the notification of the [`Importer::subscribe`]
+    /// subscribers, and the next block isn't committed until the hook returns.
+    pub fn register_commit_hook(
+        &self,
+        name: impl Into<String>,
+        hook: Arc<dyn CommitHook>,
+    ) {
+        self.commit_hooks
+            .lock()
+            .expect("The lock is not poisoned")
+            .push(RegisteredHook {
+                name: name.into(),
+                hook,
+            });
+    }
+
+    /// Registers the asynchronous hook that receives the committed blocks with their
+    /// state diffs via the queue of the `capacity` blocks. The `backpressure` defines
+    /// what happens with the import when the queue is full. The hook is removed
+    /// when the receiver is dropped.
+    pub fn subscribe_committed(
+        &self,
+        name: impl Into<String>,
+        capacity: usize,
+        backpressure: Backpressure,
+    ) -> mpsc::Receiver<Arc<CommittedBlock>> {
+        let (hook, receiver) = ChannelHook::new(capacity, backpressure);
+        self.register_commit_hook(name, Arc::new(hook));
+        receiver
+    }
+
+    fn has_commit_hooks(&self) -> bool {
+        !self
+            .commit_hooks
+            .lock()
+            .expect("The lock is not poisoned")
+            .is_empty()
+    }
+
+    /// Calls the commit hooks with the `block` and removes the finished ones.
+    fn run_commit_hooks(&self, block: CommittedBlock) {
+        let block = Arc::new(block);
+        // The lock isn't held during the calls, so the hooks may register other hooks.
+        let hooks = self
+            .commit_hooks
+            .lock()
+            .expect("The lock is not poisoned")
+            .clone();
+        for RegisteredHook { name, hook } in hooks {
+            if let Err(err) = hook.on_commit(&block) {
+                tracing::error!(
+                    "The commit hook `{name}` failed on the block at the height {}: {err}",
+                    block.state_diff.height
+                );
+            }
+        }
+        self.commit_hooks
+            .lock()
+            .expect("The lock is not poisoned")
+            .retain(|registered| !registered.hook.is_finished());
+    }
+
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
         let guard = self.guard.try_acquire();
         match guard {
@@ -163,7 +282,7 @@ where
         skip_all,
         fields(
             block_id = %result.result().sealed_block.entity.id(),
-            height = **result.result().sealed_block.entity.header().height(),
+            block_height = **result.result().sealed_block.entity.header().height(),
             tx_status = ?result.result().tx_status,
         ),
         err
@@ -176,74 +295,88 @@ where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
         let (result, mut db_tx) = result.into();
-        let block = &result.sealed_block.entity;
-        let consensus = &result.sealed_block.consensus;
-        let block_id = block.id();
-        let actual_next_height = *block.header().height();
-
-        // During importing of the genesis block, the database should not be initialized
-        // and the genesis block defines the next height.
-        // During the production of the non-genesis block, the next height should be underlying
-        // database height + 1.
-        let expected_next_height = match consensus {
-            Consensus::Genesis(_) => {
-                let result = self.database.latest_block_height();
-                let found = !result.is_not_found();
-                // Because the genesis block is not committed, it should return non found error.
-                // If we find the latest height, something is wrong with the state of the database.
-                if found {
-                    return Err(Error::InvalidUnderlyingDatabaseGenesisState)
-                }
-                actual_next_height
-            }
-            Consensus::PoA(_) => {
-                if actual_next_height == BlockHeight::from(0u32) {
-                    return Err(Error::ZeroNonGenericHeight)
-                }
-
-                let last_db_height = self.database.latest_block_height()?;
-                last_db_height
-                    .checked_add(1u32)
-                    .ok_or(Error::Overflow)?
-                    .into()
-            }
-        };
+        let sealed = seal_result(
+            &self.database,
+            &result,
+            &mut db_tx,
+            self.state_diff_retention,
+            self.has_commit_hooks(),
+        )?;
+        db_tx.commit()?;
+        self.notify_committed(result, sealed);
+        Ok(())
+    }
 
-        if expected_next_height != actual_next_height {
-            return Err(Error::IncorrectBlockHeight(
-                expected_next_height,
-                actual_next_height,
-            ))
+    /// The method commits the block applied from the state diff of the trusted node.
+    /// Unlike the `commit_result`, the `UncommittedResult` already contains the consensus
+    /// data and the metadata of the block, so they are not written again.
+    ///
+    /// After the commit into the database notifies about a new imported block.
+    ///
+    /// # Concurrency
+    ///
+    /// Only one commit may be in progress at the time. All other calls will fail.
+    /// Returns an error if called while another call is in progress.
+    pub fn commit_applied_result<ExecutorDatabase>(
+        &self,
+        result: UncommittedResult<StorageTransaction<ExecutorDatabase>>,
+    ) -> Result<(), Error>
+    where
+        ExecutorDatabase: ports::ExecutorDatabase,
+    {
+        let _guard = self.lock()?;
+        let (result, mut db_tx) = result.into();
+        let height = *result.sealed_block.entity.header().height();
+        let expected_next_height: BlockHeight = self
+            .database
+            .latest_block_height()?
+            .checked_add(1u32)
+            .ok_or(Error::Overflow)?
+            .into();
+        if expected_next_height != height {
+            return Err(Error::IncorrectBlockHeight(expected_next_height, height))
         }
 
         let db_after_execution = db_tx.as_mut();
-
-        // Importer expects that `UncommittedResult` contains the result of block
-        // execution(It includes the block itself).
         let actual_height = db_after_execution.latest_block_height()?;
-        if expected_next_height != actual_height {
+        if height != actual_height {
             return Err(Error::InvalidDatabaseStateAfterExecution(
-                expected_next_height,
+                height,
                 actual_height,
             ))
         }
-
-        db_after_execution
-            .seal_block(&block_id, &result.sealed_block.consensus)?
-            .should_be_unique(&expected_next_height)?;
-
-        // Update the total tx count in chain metadata
-        let total_txs = db_after_execution
-            // Safety: casting len to u64 since it's impossible to execute a block with more than 2^64 txs
-            .increase_tx_count(result.sealed_block.entity.transactions().len() as u64)?;
-
+        let total_txs = db_after_execution.increase_tx_count(0)?;
+        let state_diff = if self.has_commit_hooks() {
+            Some(db_after_execution.uncommitted_state_diff(&height)?)
+        } else {
+            None
+        };
+        if let Some(retention) = self.state_diff_retention {
+            db_after_execution.record_state_diff(&height, retention)?;
+        }
         db_tx.commit()?;
+        self.notify_committed(
+            result,
+            SealedResult {
+                total_txs,
+                state_diff,
+            },
+        );
+        Ok(())
+    }
 
-        // update the importer metrics after the block is successfully committed
+    /// Updates the importer metrics, calls the commit hooks and notifies about
+    /// the committed block.
+    fn notify_committed(&self, result: ImportResult, sealed: SealedResult) {
+        let SealedResult {
+            total_txs,
+            state_diff,
+        } = sealed;
+        let height = *result.sealed_block.entity.header().height();
         importer_metrics().total_txs_count.set(total_txs as i64);
         importer_metrics()
             .block_height
-            .set(actual_height.as_usize() as i64);
+            .set(height.as_usize() as i64);
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -253,8 +386,14 @@ where
             .set(current_time);
 
         tracing::info!("Committed block {:#x}", result.sealed_block.entity.id());
-        let _ = self.broadcast.send(Arc::new(result));
-        Ok(())
+        let result = Arc::new(result);
+        if let Some(state_diff) = state_diff {
+            self.run_commit_hooks(CommittedBlock {
+                result: result.clone(),
+                state_diff,
+            });
+        }
+        let _ = self.broadcast.send(result);
     }
 
     /// Should only be called once after startup to set importer metrics to their initial values
@@ -288,7 +427,7 @@ where
 impl<IDatabase, E, V> Importer<IDatabase, E, V>
 where
     E: Executor,
-    V: BlockVerifier,
+    V: BlockVerifier<Database = E::Database>,
 {
     /// Performs all checks required to commit the block, it includes the execution of
     /// the block(As a result returns the uncommitted state).
@@ -304,13 +443,28 @@ where
     pub fn verify_and_execute_block(
         &self,
         sealed_block: SealedBlock,
+    ) -> Result<UncommittedResult<StorageTransaction<E::Database>>, Error> {
+        self._verify_and_execute_block(None, sealed_block)
+    }
+
+    /// Verifies and executes the block on top of the `parent` uncommitted state,
+    /// or on top of the executor's database if the `parent` is `None`.
+    fn _verify_and_execute_block(
+        &self,
+        parent: Option<&E::Database>,
+        sealed_block: SealedBlock,
     ) -> Result<UncommittedResult<StorageTransaction<E::Database>>, Error> {
         let consensus = sealed_block.consensus;
         let block = sealed_block.entity;
         let sealed_block_id = block.id();
 
-        let result_of_verification =
-            self.verifier.verify_block_fields(&consensus, &block);
+        // The previous blocks of the batch are only in the `parent` uncommitted state.
+        let result_of_verification = match parent {
+            Some(parent) => self
+                .verifier
+                .verify_block_fields_on(parent, &consensus, &block),
+            None => self.verifier.verify_block_fields(&consensus, &block),
+        };
         if let Err(err) = result_of_verification {
             return Err(Error::FailedVerification(err))
         }
@@ -322,7 +476,21 @@ where
             return Err(Error::ExecuteGenesis)
         }
 
+        // The size is known before the execution, so the oversized blocks are
+        // rejected without it. The gas limit is enforced by the executor.
+        let size = BlockLimits::size_of(block.transactions());
+        if size > self.block_limits.size {
+            return Err(Error::BlockSizeLimitExceeded {
+                size,
+                limit: self.block_limits.size,
+            })
+        }
+
         // TODO: Pass `block` into `ExecutionBlock::Validation` by ref
+        let execution_result = match parent {
+            Some(parent) => self.executor.execute_without_commit_on(parent, block),
+            None => self.executor.execute_without_commit(block),
+        };
         let (
             ExecutionResult {
                 block,
@@ -330,11 +498,7 @@ where
                 tx_status,
             },
             db_tx,
-        ) = self
-            .executor
-            .execute_without_commit(block)
-            .map_err(Error::FailedExecution)?
-            .into();
+        ) = execution_result.map_err(Error::FailedExecution)?.into();
 
         // If we skipped transaction, it means that the block is invalid.
         if !skipped_transactions.is_empty() {
@@ -362,12 +526,16 @@ impl<IDatabase, E, V> Importer<IDatabase, E, V>
 where
     IDatabase: ImporterDatabase,
     E: Executor,
-    V: BlockVerifier,
+    V: BlockVerifier<Database = E::Database>,
 {
     /// The method validates the `Block` fields and commits the `SealedBlock`.
     /// It is a combination of the [`Importer::verify_and_execute_block`] and [`Importer::commit_result`].
+    ///
+    /// If the database already has another block at the same height, the valid block
+    /// is stored as the competing one and [`Error::CompetingBlock`] is returned.
     pub fn execute_and_commit(&self, sealed_block: SealedBlock) -> Result<(), Error> {
         let _guard = self.lock()?;
+        self.check_competing_block(&sealed_block)?;
         let start = Instant::now();
         let result = self.verify_and_execute_block(sealed_block)?;
         let commit_result = self._commit_result(result);
@@ -377,6 +545,258 @@ where
         // return execution result
         commit_result
     }
+
+    /// Validates and commits the consecutive `SealedBlock`s, like
+    /// [`Importer::execute_and_commit`] for each of them, but writes the blocks
+    /// into the database in batches. Each block is executed on top of the uncommitted
+    /// state of the previous blocks. The batch is committed when all blocks are
+    /// imported or when its oldest block waits for the commit longer than
+    /// [`Config::max_commit_batch_latency`].
+    ///
+    /// The import stops at the first invalid block. The blocks before it are still
+    /// committed, and the error is returned in the [`BatchImportResult`].
+    pub fn execute_and_commit_batch(
+        &self,
+        sealed_blocks: Vec<SealedBlock>,
+    ) -> BatchImportResult {
+        let mut committed = 0;
+        let error = self
+            ._execute_and_commit_batch(sealed_blocks, &mut committed)
+            .err();
+        BatchImportResult { committed, error }
+    }
+
+    fn _execute_and_commit_batch(
+        &self,
+        sealed_blocks: Vec<SealedBlock>,
+        committed: &mut usize,
+    ) -> Result<(), Error> {
+        let _guard = self.lock()?;
+        let mut pending = None;
+        let mut pending_results = vec![];
+        let mut batch_started = Instant::now();
+
+        for sealed_block in sealed_blocks {
+            if pending.is_none() {
+                batch_started = Instant::now();
+            }
+            let imported = self.execute_into_batch(&mut pending, sealed_block);
+            let imported = match imported {
+                Ok(imported) => imported,
+                Err(err) => {
+                    // Commit the valid blocks imported before the failure.
+                    *committed += self.commit_batch(pending, pending_results)?;
+                    return Err(err)
+                }
+            };
+            pending_results.push(imported);
+
+            if batch_started.elapsed() >= self.max_commit_batch_latency {
+                *committed += self.commit_batch(
+                    pending.take(),
+                    core::mem::take(&mut pending_results),
+                )?;
+            }
+        }
+
+        *committed += self.commit_batch(pending, pending_results)?;
+        Ok(())
+    }
+
+    /// Executes the block on top of the `pending` batch and adds the result into it.
+    /// The first block of the batch starts the `pending` database transaction.
+    fn execute_into_batch(
+        &self,
+        pending: &mut Option<StorageTransaction<E::Database>>,
+        sealed_block: SealedBlock,
+    ) -> Result<(ImportResult, SealedResult), Error> {
+        self.check_competing_block(&sealed_block)?;
+        let start = Instant::now();
+        let collect_state_diff = self.has_commit_hooks();
+        let imported = match pending {
+            Some(pending) => {
+                let parent: &E::Database = pending.as_ref();
+                let (result, mut db_tx) = self
+                    ._verify_and_execute_block(Some(parent), sealed_block)?
+                    .into();
+                let sealed = seal_result(
+                    parent,
+                    &result,
+                    &mut db_tx,
+                    self.state_diff_retention,
+                    collect_state_diff,
+                )?;
+                // Moves the changes of the block into the pending batch.
+                db_tx.commit()?;
+                (result, sealed)
+            }
+            None => {
+                let (result, mut db_tx) =
+                    self.verify_and_execute_block(sealed_block)?.into();
+                let sealed = seal_result(
+                    &self.database,
+                    &result,
+                    &mut db_tx,
+                    self.state_diff_retention,
+                    collect_state_diff,
+                )?;
+                *pending = Some(db_tx);
+                (result, sealed)
+            }
+        };
+        let time = start.elapsed().as_secs_f64();
+        importer_metrics().execute_and_commit_duration.observe(time);
+        Ok(imported)
+    }
+
+    /// Writes the `pending` batch into the database and notifies about its blocks.
+    /// Returns the number of the committed blocks.
+    fn commit_batch(
+        &self,
+        pending: Option<StorageTransaction<E::Database>>,
+        results: Vec<(ImportResult, SealedResult)>,
+    ) -> Result<usize, Error> {
+        let Some(pending) = pending else { return Ok(0) };
+        pending.commit()?;
+        let committed = results.len();
+        for (result, sealed) in results {
+            self.notify_committed(result, sealed);
+        }
+        Ok(committed)
+    }
+}
+
+impl<IDatabase, E, V> Importer<IDatabase, E, V>
+where
+    IDatabase: ImporterDatabase,
+    V: BlockVerifier,
+{
+    /// Compares the block with the canonical block at the same height, if any.
+    ///
+    /// The parent state of the competing block is gone, so only the block fields
+    /// are verified. The valid competing block is stored in the database and reported.
+    fn check_competing_block(&self, sealed_block: &SealedBlock) -> Result<(), Error> {
+        let block = &sealed_block.entity;
+        let height = *block.header().height();
+        let Some(canonical) = self.database.block_id(&height)? else {
+            return Ok(())
+        };
+        let competing = block.id();
+        if canonical == competing {
+            return Err(Error::NotUnique(height))
+        }
+
+        self.verifier
+            .verify_block_fields(&sealed_block.consensus, block)
+            .map_err(Error::FailedVerification)?;
+
+        let competing_block = CompetingBlock {
+            canonical,
+            sealed_block: sealed_block.clone(),
+        };
+        let inserted = self
+            .database
+            .insert_competing_block(&competing_block, self.max_competing_blocks)?;
+        if inserted {
+            importer_metrics().competing_blocks.inc();
+            tracing::warn!(
+                "The block {competing:#x} competes with the canonical block \
+                {canonical:#x} at the height {height}"
+            );
+        }
+        Err(Error::CompetingBlock(height, canonical, competing))
+    }
+}
+
+/// Checks that the `db_tx` contains the result of the execution of the block on top
+/// of the `parent` database and attaches the consensus data to the block.
+/// Returns the total number of the transactions on the chain after the block and,
+/// if `collect_state_diff` is set, the changes of the block.
+fn seal_result<P, ExecutorDatabase>(
+    parent: &P,
+    result: &ImportResult,
+    db_tx: &mut StorageTransaction<ExecutorDatabase>,
+    state_diff_retention: Option<u32>,
+    collect_state_diff: bool,
+) -> Result<SealedResult, Error>
+where
+    P: ImporterDatabase + ?Sized,
+    ExecutorDatabase: ports::ExecutorDatabase,
+{
+    let block = &result.sealed_block.entity;
+    let consensus = &result.sealed_block.consensus;
+    let block_id = block.id();
+    let actual_next_height = *block.header().height();
+
+    // During importing of the genesis block, the database should not be initialized
+    // and the genesis block defines the next height.
+    // During the production of the non-genesis block, the next height should be underlying
+    // database height + 1.
+    let expected_next_height = match consensus {
+        Consensus::Genesis(_) => {
+            let result = parent.latest_block_height();
+            let found = !result.is_not_found();
+            // Because the genesis block is not committed, it should return non found error.
+            // If we find the latest height, something is wrong with the state of the database.
+            if found {
+                return Err(Error::InvalidUnderlyingDatabaseGenesisState)
+            }
+            actual_next_height
+        }
+        Consensus::PoA(_) | Consensus::PoAMultiSig(_) => {
+            if actual_next_height == BlockHeight::from(0u32) {
+                return Err(Error::ZeroNonGenericHeight)
+            }
+
+            let last_db_height = parent.latest_block_height()?;
+            last_db_height
+                .checked_add(1u32)
+                .ok_or(Error::Overflow)?
+                .into()
+        }
+    };
+
+    if expected_next_height != actual_next_height {
+        return Err(Error::IncorrectBlockHeight(
+            expected_next_height,
+            actual_next_height,
+        ))
+    }
+
+    let db_after_execution = db_tx.as_mut();
+
+    // Importer expects that `UncommittedResult` contains the result of block
+    // execution(It includes the block itself).
+    let actual_height = db_after_execution.latest_block_height()?;
+    if expected_next_height != actual_height {
+        return Err(Error::InvalidDatabaseStateAfterExecution(
+            expected_next_height,
+            actual_height,
+        ))
+    }
+
+    db_after_execution
+        .seal_block(&block_id, &result.sealed_block.consensus)?
+        .should_be_unique(&expected_next_height)?;
+
+    // Update the total tx count in chain metadata
+    let total_txs = db_after_execution
+        // Safety: casting len to u64 since it's impossible to execute a block with more than 2^64 txs
+        .increase_tx_count(result.sealed_block.entity.transactions().len() as u64)?;
+
+    // The state diffs are taken the last to include all changes of the block.
+    let state_diff = if collect_state_diff {
+        Some(db_after_execution.uncommitted_state_diff(&actual_next_height)?)
+    } else {
+        None
+    };
+    if let Some(retention) = state_diff_retention {
+        db_after_execution.record_state_diff(&actual_next_height, retention)?;
+    }
+    Ok(SealedResult {
+        total_txs,
+        state_diff,
+    })
 }
 
 trait ShouldBeUnique {
@@ -1,4 +1,9 @@
 use crate::{
+    hooks::{
+        Backpressure,
+        CommitHook,
+        CommittedBlock,
+    },
     importer::Error,
     ports::{
         ExecutorDatabase,
@@ -6,6 +11,7 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -25,20 +31,39 @@ use fuel_core_types::{
         primitives::BlockId,
         SealedBlock,
     },
-    fuel_tx::TxId,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::{
         block_importer::{
+            CompetingBlock,
             ImportResult,
             UncommittedResult,
         },
         executor::{
+            BlockLimits,
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateChange,
+            StateDiff,
         },
         Uncommitted,
     },
+    tai64::Tai64,
+};
+use std::{
+    sync::{
+        atomic::{
+            AtomicU32,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 use test_case::test_case;
 use tokio::sync::{
@@ -51,7 +76,13 @@ mockall::mock! {
 
     impl ImporterDatabase for Database {
         fn latest_block_height(&self) -> StorageResult<BlockHeight>;
+        fn block_id(&self, height: &BlockHeight) -> StorageResult<Option<BlockId>>;
         fn increase_tx_count(&self, new_txs_count: u64) -> StorageResult<u64>;
+        fn insert_competing_block(
+            &self,
+            competing_block: &CompetingBlock,
+            max_competing_blocks: usize,
+        ) -> StorageResult<bool>;
     }
 
     impl ExecutorDatabase for Database {
@@ -60,6 +91,14 @@ mockall::mock! {
             block_id: &BlockId,
             consensus: &Consensus,
         ) -> StorageResult<Option<Consensus>>;
+
+        fn uncommitted_state_diff(&self, height: &BlockHeight) -> StorageResult<StateDiff>;
+
+        fn record_state_diff(
+            &mut self,
+            height: &BlockHeight,
+            retention: u32,
+        ) -> StorageResult<()>;
     }
 
     impl TransactionTrait<MockDatabase> for Database {
@@ -116,6 +155,7 @@ where
         let mut db = MockDatabase::default();
         db.expect_latest_block_height()
             .returning(move || result().map(Into::into));
+        db.expect_block_id().returning(|_| Ok(None));
         db.expect_increase_tx_count().returning(Ok);
         db
     }
@@ -555,3 +595,459 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+#[test]
+fn verify_and_execute_rejects_block_exceeding_size_limit() {
+    let mut sealed_block = poa_block(13);
+    *sealed_block.entity.transactions_mut() = vec![Transaction::default()];
+    let importer = Importer::new(
+        Config {
+            block_limits: BlockLimits {
+                size: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        MockDatabase::default(),
+        // The block is rejected before the execution.
+        MockExecutor::default(),
+        verifier(ok(())),
+    );
+
+    assert!(matches!(
+        importer.verify_and_execute_block(sealed_block),
+        Err(Error::BlockSizeLimitExceeded { limit: 1, .. })
+    ));
+}
+
+fn db_with_canonical_block(canonical: SealedBlock) -> MockDatabase {
+    let height = *canonical.entity.header().height();
+    let canonical_id = canonical.entity.id();
+    let mut db = MockDatabase::default();
+    db.expect_latest_block_height()
+        .returning(move || Ok(height));
+    db.expect_block_id()
+        .returning(move |h| Ok((*h == height).then_some(canonical_id)));
+    db
+}
+
+fn competing_poa_block(height: u32) -> SealedBlock {
+    let mut block = poa_block(height);
+    block.entity.header_mut().consensus.time = Tai64(1);
+    block.entity.header_mut().recalculate_metadata();
+    block
+}
+
+#[test]
+fn execute_and_commit_rejects_already_imported_block() {
+    let importer = Importer::new(
+        Default::default(),
+        db_with_canonical_block(poa_block(113)),
+        MockExecutor::default(),
+        MockBlockVerifier::default(),
+    );
+
+    assert_eq!(
+        importer.execute_and_commit(poa_block(113)),
+        Err(Error::NotUnique(113u32.into()))
+    );
+}
+
+#[test]
+fn execute_and_commit_stores_competing_block() {
+    let canonical = poa_block(113);
+    let competing = competing_poa_block(113);
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_block_fields()
+        .returning(|_, _| Ok(()));
+    let stored = Arc::new(Mutex::new(Vec::<CompetingBlock>::new()));
+    let mut db = db_with_canonical_block(canonical.clone());
+    let stored_blocks = stored.clone();
+    db.expect_insert_competing_block()
+        .times(2)
+        .returning(move |competing_block, _| {
+            let mut stored_blocks = stored_blocks.lock().unwrap();
+            if stored_blocks.contains(competing_block) {
+                return Ok(false)
+            }
+            stored_blocks.push(competing_block.clone());
+            Ok(true)
+        });
+    let importer =
+        Importer::new(Default::default(), db, MockExecutor::default(), verifier);
+    let mut imported_blocks = importer.subscribe();
+
+    // The same competing block is reported every time, but stored only once.
+    for _ in 0..2 {
+        assert_eq!(
+            importer.execute_and_commit(competing.clone()),
+            Err(Error::CompetingBlock(
+                113u32.into(),
+                canonical.entity.id(),
+                competing.entity.id(),
+            ))
+        );
+    }
+
+    assert_eq!(
+        *stored.lock().unwrap(),
+        vec![CompetingBlock {
+            canonical: canonical.entity.id(),
+            sealed_block: competing,
+        }]
+    );
+    assert_eq!(
+        imported_blocks
+            .try_recv()
+            .expect_err("The competing block is not imported"),
+        TryRecvError::Empty
+    );
+}
+
+#[test]
+fn execute_and_commit_ignores_invalid_competing_block() {
+    let importer = Importer::new(
+        Default::default(),
+        db_with_canonical_block(poa_block(113)),
+        MockExecutor::default(),
+        verifier(verification_failure),
+    );
+
+    assert_eq!(
+        importer.execute_and_commit(competing_poa_block(113)),
+        Err(verification_failure_error())
+    );
+}
+
+#[test]
+fn competing_blocks_are_stored_with_the_limit() {
+    let config = Config {
+        max_competing_blocks: 2,
+        ..Default::default()
+    };
+    let mut db = MockDatabase::default();
+    db.expect_block_id()
+        .returning(|h| Ok(Some(poa_block(**h).entity.id())));
+    db.expect_insert_competing_block()
+        .withf(|_, max_competing_blocks| *max_competing_blocks == 2)
+        .times(3)
+        .returning(|_, _| Ok(true));
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_block_fields()
+        .returning(|_, _| Ok(()));
+    let importer = Importer::new(config, db, MockExecutor::default(), verifier);
+
+    for height in 1..=3 {
+        assert!(matches!(
+            importer.execute_and_commit(competing_poa_block(height)),
+            Err(Error::CompetingBlock(..))
+        ));
+    }
+}
+
+#[test]
+fn commit_result_records_state_diff_when_enabled() {
+    let config = Config {
+        state_diff_retention: Some(10),
+        ..Default::default()
+    };
+    let mut executor_db = executor_db(ok(1), ok(None), 1)();
+    executor_db
+        .expect_record_state_diff()
+        .withf(|height, retention| **height == 1 && *retention == 10)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let importer = Importer::new(config, underlying_db(ok(0))(), (), ());
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(1), vec![]),
+        StorageTransaction::new(executor_db),
+    );
+
+    importer.commit_result(uncommitted_result).unwrap();
+}
+
+fn batch_executor(dbs: Vec<MockDatabase>, nested_dbs: Vec<MockDatabase>) -> MockExecutor {
+    let mut dbs = dbs.into_iter();
+    let mut nested_dbs = nested_dbs.into_iter();
+    let mut executor = MockExecutor::default();
+    executor
+        .expect_execute_without_commit()
+        .returning(move |block| {
+            Ok(Uncommitted::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: vec![],
+                    tx_status: vec![],
+                },
+                StorageTransaction::new(dbs.next().expect("Unexpected execution")),
+            ))
+        });
+    executor
+        .expect_execute_without_commit_on()
+        .returning(move |_, block| {
+            Ok(Uncommitted::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: vec![],
+                    tx_status: vec![],
+                },
+                StorageTransaction::new(nested_dbs.next().expect("Unexpected execution")),
+            ))
+        });
+    executor
+}
+
+fn valid_verifier() -> MockBlockVerifier {
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_block_fields()
+        .returning(|_, _| Ok(()));
+    verifier
+        .expect_verify_block_fields_on()
+        .returning(|_, _, _| Ok(()));
+    verifier
+}
+
+#[test]
+fn execute_and_commit_batch_commits_blocks_with_one_write() {
+    // The first block starts the batch, so the batch is committed once.
+    let batch_db = executor_db(ok(113), ok(None), 1)();
+    // The second block is executed on top of the batch and moves its changes into it.
+    let block_db = executor_db(ok(114), ok(None), 1)();
+    let executor = batch_executor(vec![batch_db], vec![block_db]);
+    // The parent of the second block is only in the uncommitted batch,
+    // so the second block is verified on top of it.
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_block_fields()
+        .times(1)
+        .returning(|_, block| {
+            assert_eq!(*block.header().height(), 113u32.into());
+            Ok(())
+        });
+    verifier
+        .expect_verify_block_fields_on()
+        .times(1)
+        .returning(|_, _, block| {
+            assert_eq!(*block.header().height(), 114u32.into());
+            Ok(())
+        });
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db(ok(112))(),
+        executor,
+        verifier,
+    );
+    let mut imported_blocks = importer.subscribe();
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+
+    assert_eq!(result.committed, 2);
+    assert!(result.error.is_none());
+    for height in [113, 114] {
+        let imported = imported_blocks.try_recv().unwrap();
+        assert_eq!(imported.sealed_block, poa_block(height));
+    }
+    assert_eq!(imported_blocks.try_recv().unwrap_err(), TryRecvError::Empty);
+}
+
+#[test]
+fn execute_and_commit_batch_commits_valid_blocks_before_failure() {
+    // The batch with the valid block is committed despite the failure of the next block.
+    let batch_db = executor_db(ok(113), ok(None), 1)();
+    let mut executor = MockExecutor::default();
+    executor
+        .expect_execute_without_commit()
+        .return_once(move |block| {
+            Ok(Uncommitted::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: vec![],
+                    tx_status: vec![],
+                },
+                StorageTransaction::new(batch_db),
+            ))
+        });
+    executor
+        .expect_execute_without_commit_on()
+        .return_once(|_, _| execution_failure());
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db(ok(112))(),
+        executor,
+        valid_verifier(),
+    );
+    let mut imported_blocks = importer.subscribe();
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+
+    assert_eq!(result.committed, 1);
+    assert_eq!(result.error, Some(execution_failure_error()));
+    let imported = imported_blocks.try_recv().unwrap();
+    assert_eq!(imported.sealed_block, poa_block(113));
+    assert_eq!(imported_blocks.try_recv().unwrap_err(), TryRecvError::Empty);
+}
+
+#[test]
+fn execute_and_commit_batch_commits_block_after_max_latency() {
+    // Each block waits for the commit longer than the zero latency,
+    // so the next block starts a new batch on top of the database.
+    let config = Config {
+        max_commit_batch_latency: Duration::ZERO,
+        ..Default::default()
+    };
+    let dbs = vec![
+        executor_db(ok(113), ok(None), 1)(),
+        executor_db(ok(114), ok(None), 1)(),
+    ];
+    let executor = batch_executor(dbs, vec![]);
+    let height = Arc::new(AtomicU32::new(112));
+    let underlying_db =
+        underlying_db(move || Ok(height.fetch_add(1, Ordering::SeqCst)))();
+    let importer = Importer::new(config, underlying_db, executor, valid_verifier());
+    let mut imported_blocks = importer.subscribe();
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+
+    assert_eq!(result.committed, 2);
+    assert!(result.error.is_none());
+    for height in [113, 114] {
+        let imported = imported_blocks.try_recv().unwrap();
+        assert_eq!(imported.sealed_block, poa_block(height));
+    }
+}
+
+/// Returns the state diff of the block at the `height` with one change.
+fn state_diff(height: u32) -> StateDiff {
+    StateDiff {
+        height: height.into(),
+        changes: vec![StateChange {
+            column: "Coins".to_string(),
+            key: height.to_be_bytes().to_vec(),
+            value: None,
+            previous: None,
+        }],
+    }
+}
+
+fn executor_db_with_state_diff(height: u32) -> MockDatabase {
+    let mut db = executor_db(ok(height), ok(None), 1)();
+    db.expect_uncommitted_state_diff()
+        .times(1)
+        .returning(|height| Ok(state_diff(**height)));
+    db
+}
+
+#[derive(Default)]
+struct RecordingHook {
+    blocks: Mutex<Vec<Arc<CommittedBlock>>>,
+}
+
+impl CommitHook for RecordingHook {
+    fn on_commit(&self, block: &Arc<CommittedBlock>) -> anyhow::Result<()> {
+        self.blocks.lock().unwrap().push(block.clone());
+        Ok(())
+    }
+}
+
+#[test]
+fn commit_hook_receives_committed_block_with_state_diff() {
+    let importer = Importer::new(Default::default(), underlying_db(ok(0))(), (), ());
+    let hook = Arc::new(RecordingHook::default());
+    importer.register_commit_hook("recording", hook.clone());
+    let mut imported_blocks = importer.subscribe();
+    let uncommitted_result = UncommittedResult::new(
+        ImportResult::new_from_local(poa_block(1), vec![]),
+        StorageTransaction::new(executor_db_with_state_diff(1)),
+    );
+
+    importer.commit_result(uncommitted_result).unwrap();
+
+    let blocks = hook.blocks.lock().unwrap();
+    assert_eq!(blocks.len(), 1);
+    assert_eq!(blocks[0].result.sealed_block, poa_block(1));
+    assert_eq!(blocks[0].state_diff, state_diff(1));
+    // The subscribers receive the same result as the hooks.
+    let imported = imported_blocks.try_recv().unwrap();
+    assert!(Arc::ptr_eq(&imported, &blocks[0].result));
+}
+
+#[test]
+fn subscribe_committed_skips_blocks_when_queue_is_full() {
+    let executor = batch_executor(
+        vec![executor_db_with_state_diff(113)],
+        vec![executor_db_with_state_diff(114)],
+    );
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db(ok(112))(),
+        executor,
+        valid_verifier(),
+    );
+    let mut committed = importer.subscribe_committed("skip", 1, Backpressure::Skip);
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+
+    // The failure of the hook doesn't affect the import.
+    assert_eq!(result.committed, 2);
+    assert!(result.error.is_none());
+    let block = committed.try_recv().unwrap();
+    assert_eq!(block.result.sealed_block, poa_block(113));
+    assert_eq!(block.state_diff, state_diff(113));
+    assert!(committed.try_recv().is_err());
+}
+
+#[test]
+fn subscribe_committed_waits_for_free_space_in_queue() {
+    let executor = batch_executor(
+        vec![executor_db_with_state_diff(113)],
+        vec![executor_db_with_state_diff(114)],
+    );
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db(ok(112))(),
+        executor,
+        valid_verifier(),
+    );
+    let mut committed = importer.subscribe_committed("wait", 1, Backpressure::Wait);
+    let consumer = std::thread::spawn(move || {
+        let mut heights = vec![];
+        while let Some(block) = committed.blocking_recv() {
+            heights.push(*block.state_diff.height);
+        }
+        heights
+    });
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+    // Dropping of the importer closes the queue.
+    drop(importer);
+
+    assert_eq!(result.committed, 2);
+    assert_eq!(consumer.join().unwrap(), vec![113, 114]);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn subscribe_committed_doesnt_block_current_thread_runtime() {
+    let executor = batch_executor(
+        vec![executor_db_with_state_diff(113)],
+        vec![executor_db_with_state_diff(114)],
+    );
+    let importer = Importer::new(
+        Default::default(),
+        underlying_db(ok(112))(),
+        executor,
+        valid_verifier(),
+    );
+    let mut committed = importer.subscribe_committed("wait", 1, Backpressure::Wait);
+
+    let result = importer.execute_and_commit_batch(vec![poa_block(113), poa_block(114)]);
+
+    // The block that doesn't fit into the queue is skipped instead of the deadlock.
+    assert_eq!(result.committed, 2);
+    assert!(result.error.is_none());
+    let block = committed.try_recv().unwrap();
+    assert_eq!(block.result.sealed_block, poa_block(113));
+    assert!(committed.try_recv().is_err());
+}
@@ -2,10 +2,16 @@
 #![deny(warnings)]
 
 pub mod config;
+pub mod hooks;
 pub mod importer;
 pub mod ports;
 
 pub use config::Config;
+pub use hooks::{
+    Backpressure,
+    CommitHook,
+    CommittedBlock,
+};
 pub use importer::Importer;
 
 #[cfg(test)]
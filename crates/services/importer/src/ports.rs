@@ -9,9 +9,13 @@ use fuel_core_types::{
         primitives::BlockId,
     },
     fuel_types::BlockHeight,
-    services::executor::{
-        Result as ExecutorResult,
-        UncommittedResult,
+    services::{
+        block_importer::CompetingBlock,
+        executor::{
+            Result as ExecutorResult,
+            StateDiff,
+            UncommittedResult,
+        },
     },
 };
 
@@ -27,15 +31,33 @@ pub trait Executor: Send + Sync {
         &self,
         block: Block,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
+
+    /// Executes the block on top of the uncommitted state of the `database` and returns
+    /// the result of execution with uncommitted database transaction. The commit of
+    /// the transaction moves the changes into the `database`.
+    fn execute_without_commit_on(
+        &self,
+        database: &Self::Database,
+        block: Block,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
 }
 
 /// The database port used by the block importer.
 pub trait ImporterDatabase {
     /// Returns the latest block height.
     fn latest_block_height(&self) -> StorageResult<BlockHeight>;
+    /// Returns the id of the block at the `height`, if any.
+    fn block_id(&self, height: &BlockHeight) -> StorageResult<Option<BlockId>>;
     /// Update metadata about the total number of transactions on the chain.
     /// Returns the total count after the update.
     fn increase_tx_count(&self, new_txs_count: u64) -> StorageResult<u64>;
+    /// Stores the competing block and removes the ones with the lowest heights beyond
+    /// the `max_competing_blocks`. Returns `false` if the block is already stored.
+    fn insert_competing_block(
+        &self,
+        competing_block: &CompetingBlock,
+        max_competing_blocks: usize,
+    ) -> StorageResult<bool>;
 }
 
 /// The port for returned database from the executor.
@@ -47,11 +69,25 @@ pub trait ExecutorDatabase: ImporterDatabase {
         block_id: &BlockId,
         consensus: &Consensus,
     ) -> StorageResult<Option<Consensus>>;
+
+    /// Returns the uncommitted changes of the block at the `height` as its state diff.
+    fn uncommitted_state_diff(&self, height: &BlockHeight) -> StorageResult<StateDiff>;
+
+    /// Records the uncommitted changes of the block at the `height` as its state diff
+    /// and removes the state diffs older than the `retention` blocks.
+    fn record_state_diff(
+        &mut self,
+        height: &BlockHeight,
+        retention: u32,
+    ) -> StorageResult<()>;
 }
 
-#[cfg_attr(test, mockall::automock)]
+#[cfg_attr(test, mockall::automock(type Database = crate::importer::test::MockDatabase;))]
 /// The verifier of the block.
 pub trait BlockVerifier {
+    /// The database with the uncommitted state of the previous blocks.
+    type Database;
+
     /// Verifies the consistency of the block fields for the block's height.
     /// It includes the verification of **all** fields, it includes the consensus rules for
     /// the corresponding height.
@@ -62,4 +98,14 @@ pub trait BlockVerifier {
         consensus: &Consensus,
         block: &Block,
     ) -> anyhow::Result<()>;
+
+    /// Verifies the block fields like [`BlockVerifier::verify_block_fields`], but reads
+    /// the previous blocks from the uncommitted state of the `database` instead of
+    /// the committed one.
+    fn verify_block_fields_on(
+        &self,
+        database: &Self::Database,
+        consensus: &Consensus,
+        block: &Block,
+    ) -> anyhow::Result<()>;
 }
@@ -21,7 +21,10 @@ use crate::{
 use fuel_core_types::fuel_types::BlockHeight;
 use libp2p::{
     gossipsub::{
-        error::PublishError,
+        error::{
+            PublishError,
+            SubscriptionError,
+        },
         Gossipsub,
         GossipsubEvent,
         MessageAcceptance,
@@ -98,8 +101,11 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
 
         let peer_report = PeerReportBehaviour::new(p2p_config);
 
-        let req_res_protocol =
-            std::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        // The protocols are negotiated in the order of the preference.
+        let req_res_protocol = p2p_config
+            .protocol_versions()
+            .into_iter()
+            .map(|version| (codec.get_req_res_protocol(version), ProtocolSupport::Full));
 
         let mut req_res_config = RequestResponseConfig::default();
         req_res_config.set_request_timeout(p2p_config.set_request_timeout);
@@ -134,6 +140,14 @@ impl<Codec: NetworkCodec> FuelBehaviour<Codec> {
         self.gossipsub.publish(topic, encoded_data)
     }
 
+    pub fn subscribe(&mut self, topic: &GossipTopic) -> Result<bool, SubscriptionError> {
+        self.gossipsub.subscribe(topic)
+    }
+
+    pub fn unsubscribe(&mut self, topic: &GossipTopic) -> Result<bool, PublishError> {
+        self.gossipsub.unsubscribe(topic)
+    }
+
     pub fn send_request_msg(
         &mut self,
         message_request: RequestMessage,
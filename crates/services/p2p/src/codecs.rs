@@ -14,19 +14,104 @@ use crate::{
     },
 };
 use libp2p::request_response::RequestResponseCodec;
-use std::io;
+use std::{
+    fmt,
+    io,
+};
+
+/// The versions of the request-response and gossip protocols. The node speaks the
+/// latest version and, during the rollout window of a new version, the previous one,
+/// so the upgraded nodes keep talking to the peers that are not upgraded yet.
+/// The versions of the request-response and gossip protocols are bumped separately,
+/// only when the format of their messages changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProtocolVersion {
+    /// The original protocols. The encoded messages don't contain the version.
+    V1,
+    /// The encoded messages are prefixed with the version of the protocol.
+    V2,
+}
+
+impl ProtocolVersion {
+    /// The version of the request-response protocol preferred by the node. The V2 adds
+    /// the requests that the V1 peers can't decode, like the `TxRejection`.
+    pub const LATEST: Self = Self::V2;
+
+    /// The version of the gossip protocol. The gossiped messages didn't change since
+    /// the V1, so they are published once, in the topics of the V1.
+    pub const LATEST_GOSSIP: Self = Self::V1;
+
+    /// Returns the version preceding this one.
+    pub fn previous(self) -> Option<Self> {
+        match self {
+            Self::V1 => None,
+            Self::V2 => Some(Self::V1),
+        }
+    }
+
+    /// Returns the versions of the request-response protocol spoken by the node,
+    /// the preferred one first.
+    pub fn supported(with_previous: bool) -> Vec<Self> {
+        Self::LATEST.with_previous(with_previous)
+    }
+
+    /// Returns the versions of the gossip protocol spoken by the node,
+    /// the preferred one first.
+    pub fn supported_gossip(with_previous: bool) -> Vec<Self> {
+        Self::LATEST_GOSSIP.with_previous(with_previous)
+    }
+
+    fn with_previous(self, with_previous: bool) -> Vec<Self> {
+        let previous = self.previous().filter(|_| with_previous);
+        core::iter::once(self).chain(previous).collect()
+    }
+
+    /// The prefix of the messages encoded with this version.
+    pub(crate) fn tag(self) -> Option<u8> {
+        match self {
+            Self::V1 => None,
+            Self::V2 => Some(2),
+        }
+    }
+
+    /// Returns the payload of the message encoded with this version,
+    /// or `None` if the message is not prefixed with the version.
+    pub(crate) fn payload(self, encoded_data: &[u8]) -> Option<&[u8]> {
+        match self.tag() {
+            Some(tag) => match encoded_data.split_first() {
+                Some((prefix, payload)) if *prefix == tag => Some(payload),
+                _ => None,
+            },
+            None => Some(encoded_data),
+        }
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::V1 => write!(f, "v1"),
+            Self::V2 => write!(f, "v2"),
+        }
+    }
+}
 
 /// Implement this in order to handle serialization & deserialization of Gossipsub messages
 pub trait GossipsubCodec {
     type RequestMessage;
     type ResponseMessage;
 
-    fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error>;
+    fn encode(
+        &self,
+        data: Self::RequestMessage,
+        version: ProtocolVersion,
+    ) -> Result<Vec<u8>, io::Error>;
 
     fn decode(
         &self,
         encoded_data: &[u8],
         gossipsub_topic: GossipTopicTag,
+        version: ProtocolVersion,
     ) -> Result<Self::ResponseMessage, io::Error>;
 }
 
@@ -64,7 +149,10 @@ pub trait NetworkCodec:
     + Send
     + 'static
 {
-    /// Returns RequestResponse's Protocol
+    /// Returns RequestResponse's Protocol of the `version`
     /// Needed for initialization of RequestResponse Behaviour
-    fn get_req_res_protocol(&self) -> <Self as RequestResponseCodec>::Protocol;
+    fn get_req_res_protocol(
+        &self,
+        version: ProtocolVersion,
+    ) -> <Self as RequestResponseCodec>::Protocol;
 }
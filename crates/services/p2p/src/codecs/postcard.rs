@@ -1,6 +1,7 @@
 use super::{
     GossipsubCodec,
     NetworkCodec,
+    ProtocolVersion,
     RequestResponseConverter,
 };
 use crate::{
@@ -15,7 +16,8 @@ use crate::{
         RequestMessage,
         ResponseMessage,
         MAX_REQUEST_SIZE,
-        REQUEST_RESPONSE_PROTOCOL_ID,
+        REQUEST_RESPONSE_PROTOCOL_ID_V1,
+        REQUEST_RESPONSE_PROTOCOL_ID_V2,
     },
 };
 use async_trait::async_trait;
@@ -68,6 +70,34 @@ impl PostcardCodec {
         postcard::to_stdvec(&data)
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
     }
+
+    /// Encodes the message sent over the network with the `version` of the protocol.
+    fn encode_message<D: Serialize>(
+        &self,
+        data: &D,
+        version: ProtocolVersion,
+    ) -> Result<Vec<u8>, io::Error> {
+        let encoded_data = self.serialize(data)?;
+        match version.tag() {
+            Some(tag) => Ok([&[tag][..], &encoded_data].concat()),
+            None => Ok(encoded_data),
+        }
+    }
+
+    /// Decodes the message received over the network with the `version` of the protocol.
+    fn decode_message<'a, R: Deserialize<'a>>(
+        &self,
+        encoded_data: &'a [u8],
+        version: ProtocolVersion,
+    ) -> Result<R, io::Error> {
+        let payload = version.payload(encoded_data).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The message is not encoded with the protocol {version}"),
+            )
+        })?;
+        self.deserialize(payload)
+    }
 }
 
 /// Since Postcard does not support async reads or writes out of the box
@@ -85,7 +115,7 @@ impl RequestResponseCodec for PostcardCodec {
 
     async fn read_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Request>
     where
@@ -93,12 +123,12 @@ impl RequestResponseCodec for PostcardCodec {
     {
         let encoded_data = read_length_prefixed(socket, MAX_REQUEST_SIZE).await?;
 
-        self.deserialize(&encoded_data)
+        self.decode_message(&encoded_data, protocol.version)
     }
 
     async fn read_response<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
     ) -> io::Result<Self::Response>
     where
@@ -106,47 +136,46 @@ impl RequestResponseCodec for PostcardCodec {
     {
         let encoded_data = read_length_prefixed(socket, self.max_response_size).await?;
 
-        self.deserialize(&encoded_data)
+        self.decode_message(&encoded_data, protocol.version)
     }
 
     async fn write_request<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         req: Self::Request,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
-        match postcard::to_stdvec(&req) {
-            Ok(encoded_data) => {
-                write_length_prefixed(socket, encoded_data).await?;
-                socket.close().await?;
-
-                Ok(())
-            }
-            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+        let min_version = req.min_protocol_version();
+        if protocol.version < min_version {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("The request requires the protocol {min_version}"),
+            ))
         }
+        let encoded_data = self.encode_message(&req, protocol.version)?;
+        write_length_prefixed(socket, encoded_data).await?;
+        socket.close().await?;
+
+        Ok(())
     }
 
     async fn write_response<T>(
         &mut self,
-        _protocol: &Self::Protocol,
+        protocol: &Self::Protocol,
         socket: &mut T,
         res: Self::Response,
     ) -> io::Result<()>
     where
         T: futures::AsyncWrite + Unpin + Send,
     {
-        match postcard::to_stdvec(&res) {
-            Ok(encoded_data) => {
-                write_length_prefixed(socket, encoded_data).await?;
-                socket.close().await?;
+        let encoded_data = self.encode_message(&res, protocol.version)?;
+        write_length_prefixed(socket, encoded_data).await?;
+        socket.close().await?;
 
-                Ok(())
-            }
-            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
-        }
+        Ok(())
     }
 }
 
@@ -154,31 +183,38 @@ impl GossipsubCodec for PostcardCodec {
     type RequestMessage = GossipsubBroadcastRequest;
     type ResponseMessage = GossipsubMessage;
 
-    fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
-        let encoded_data = match data {
-            GossipsubBroadcastRequest::ConsensusVote(vote) => postcard::to_stdvec(&*vote),
-            GossipsubBroadcastRequest::NewBlock(block) => postcard::to_stdvec(&*block),
-            GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
-        };
-
-        encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+    fn encode(
+        &self,
+        data: Self::RequestMessage,
+        version: ProtocolVersion,
+    ) -> Result<Vec<u8>, io::Error> {
+        match data {
+            GossipsubBroadcastRequest::ConsensusVote(vote) => {
+                self.encode_message(&*vote, version)
+            }
+            GossipsubBroadcastRequest::NewBlock(block) => {
+                self.encode_message(&*block, version)
+            }
+            GossipsubBroadcastRequest::NewTx(tx) => self.encode_message(&*tx, version),
+        }
     }
 
     fn decode(
         &self,
         encoded_data: &[u8],
         gossipsub_tag: GossipTopicTag,
+        version: ProtocolVersion,
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => {
-                GossipsubMessage::NewTx(self.deserialize(encoded_data)?)
+                GossipsubMessage::NewTx(self.decode_message(encoded_data, version)?)
             }
             GossipTopicTag::NewBlock => {
-                GossipsubMessage::NewBlock(self.deserialize(encoded_data)?)
-            }
-            GossipTopicTag::ConsensusVote => {
-                GossipsubMessage::ConsensusVote(self.deserialize(encoded_data)?)
+                GossipsubMessage::NewBlock(self.decode_message(encoded_data, version)?)
             }
+            GossipTopicTag::ConsensusVote => GossipsubMessage::ConsensusVote(
+                self.decode_message(encoded_data, version)?,
+            ),
         };
 
         Ok(decoded_response)
@@ -220,6 +256,21 @@ impl RequestResponseConverter for PostcardCodec {
                     .transpose()?;
                 Ok(ResponseMessage::SealedHeaders(response))
             }
+            NetworkResponse::PooledTxIds(tx_ids_bytes) => {
+                let response = tx_ids_bytes
+                    .as_ref()
+                    .map(|bytes| self.deserialize(bytes))
+                    .transpose()?;
+                Ok(ResponseMessage::PooledTxIds(response))
+            }
+            NetworkResponse::PooledTransactions(txs_bytes) => {
+                let response = txs_bytes
+                    .as_ref()
+                    .map(|bytes| self.deserialize(bytes))
+                    .transpose()?;
+                Ok(ResponseMessage::PooledTransactions(response))
+            }
+            NetworkResponse::TxRejectionAck => Ok(ResponseMessage::TxRejectionAck),
         }
     }
 
@@ -253,28 +304,64 @@ impl RequestResponseConverter for PostcardCodec {
                     .transpose()?;
                 Ok(NetworkResponse::Headers(response))
             }
+            OutboundResponse::PooledTxIds(tx_ids) => {
+                let response = tx_ids
+                    .as_ref()
+                    .map(|tx_ids| self.serialize(tx_ids.as_ref()))
+                    .transpose()?;
+                Ok(NetworkResponse::PooledTxIds(response))
+            }
+            OutboundResponse::PooledTransactions(txs) => {
+                let response = txs
+                    .as_ref()
+                    .map(|txs| self.serialize(txs.as_ref()))
+                    .transpose()?;
+                Ok(NetworkResponse::PooledTransactions(response))
+            }
+            OutboundResponse::TxRejectionAck => Ok(NetworkResponse::TxRejectionAck),
         }
     }
 }
 
 impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as RequestResponseCodec>::Protocol {
-        MessageExchangePostcardProtocol {}
+    fn get_req_res_protocol(
+        &self,
+        version: ProtocolVersion,
+    ) -> <Self as RequestResponseCodec>::Protocol {
+        MessageExchangePostcardProtocol { version }
     }
 }
 
+/// The request-response protocol of the `version` negotiated with the peer.
 #[derive(Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
+pub struct MessageExchangePostcardProtocol {
+    version: ProtocolVersion,
+}
 
 impl ProtocolName for MessageExchangePostcardProtocol {
     fn protocol_name(&self) -> &[u8] {
-        REQUEST_RESPONSE_PROTOCOL_ID
+        match self.version {
+            ProtocolVersion::V1 => REQUEST_RESPONSE_PROTOCOL_ID_V1,
+            ProtocolVersion::V2 => REQUEST_RESPONSE_PROTOCOL_ID_V2,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::request_response::messages::{
+        MAX_POOLED_TXS_PER_REQUEST,
+        MAX_TX_REJECTION_MESSAGE_LEN,
+    };
+    use fuel_core_types::{
+        fuel_tx::Transaction,
+        services::p2p::{
+            TxRejection,
+            TxRejectionReason,
+        },
+    };
+    use std::sync::Arc;
 
     #[test]
     fn test_request_size_fits() {
@@ -282,4 +369,101 @@ mod tests {
         let m = RequestMessage::Transactions(arbitrary_range);
         assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
     }
+
+    #[test]
+    fn test_pooled_transactions_request_size_fits() {
+        let tx_ids = vec![[u8::MAX; 32].into(); MAX_POOLED_TXS_PER_REQUEST];
+        let m = RequestMessage::PooledTransactions(tx_ids);
+        assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
+    }
+
+    #[test]
+    fn test_tx_rejection_request_size_fits() {
+        let m = RequestMessage::TxRejection(TxRejection {
+            tx_id: [u8::MAX; 32].into(),
+            reason: TxRejectionReason::GasPriceTooLow,
+            message: "x".repeat(MAX_TX_REJECTION_MESSAGE_LEN),
+        });
+        assert!(postcard::to_stdvec(&m).unwrap().len() <= MAX_REQUEST_SIZE);
+    }
+
+    #[tokio::test]
+    async fn tx_rejection_is_not_written_with_previous_protocol_version() {
+        let mut codec = PostcardCodec::new(1024);
+        let request = RequestMessage::TxRejection(TxRejection {
+            tx_id: [1; 32].into(),
+            reason: TxRejectionReason::Invalid,
+            message: "Invalid".to_string(),
+        });
+
+        // The peers speaking only the previous version can't decode the request.
+        let protocol = codec.get_req_res_protocol(ProtocolVersion::V1);
+        let mut socket = futures::io::Cursor::new(Vec::new());
+        let result = codec
+            .write_request(&protocol, &mut socket, request.clone())
+            .await;
+        assert!(result.is_err());
+        assert!(socket.into_inner().is_empty());
+
+        let protocol = codec.get_req_res_protocol(ProtocolVersion::V2);
+        let mut socket = futures::io::Cursor::new(Vec::new());
+        codec
+            .write_request(&protocol, &mut socket, request.clone())
+            .await
+            .unwrap();
+        let mut socket = futures::io::Cursor::new(socket.into_inner());
+        let decoded = codec.read_request(&protocol, &mut socket).await.unwrap();
+        assert_eq!(decoded, request);
+    }
+
+    #[test]
+    fn messages_are_decoded_only_with_their_protocol_version() {
+        let codec = PostcardCodec::new(1024);
+        let request = RequestMessage::Block(7u32.into());
+
+        for version in [ProtocolVersion::V1, ProtocolVersion::V2] {
+            let encoded = codec.encode_message(&request, version).unwrap();
+            let decoded: RequestMessage =
+                codec.decode_message(&encoded, version).unwrap();
+            assert_eq!(decoded, request);
+        }
+
+        // The previous version is encoded without the prefix.
+        let encoded = codec.encode_message(&request, ProtocolVersion::V1).unwrap();
+        assert_eq!(encoded, postcard::to_stdvec(&request).unwrap());
+        let result: Result<RequestMessage, _> =
+            codec.decode_message(&encoded, ProtocolVersion::V2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn gossip_of_different_versions_has_different_encoding() {
+        let codec = PostcardCodec::new(1024);
+        let tx = Arc::new(Transaction::default_test_tx());
+
+        let v1 = codec
+            .encode(
+                GossipsubBroadcastRequest::NewTx(tx.clone()),
+                ProtocolVersion::V1,
+            )
+            .unwrap();
+        let v2 = codec
+            .encode(
+                GossipsubBroadcastRequest::NewTx(tx.clone()),
+                ProtocolVersion::V2,
+            )
+            .unwrap();
+
+        // The message ids are the hashes of the data, so the copies of the message
+        // published in the topics of both versions aren't treated as duplicates.
+        assert_ne!(v1, v2);
+        for (encoded, version) in [(v1, ProtocolVersion::V1), (v2, ProtocolVersion::V2)] {
+            let decoded = codec
+                .decode(&encoded, GossipTopicTag::NewTx, version)
+                .unwrap();
+            assert!(
+                matches!(decoded, GossipsubMessage::NewTx(decoded) if decoded == *tx)
+            );
+        }
+    }
 }
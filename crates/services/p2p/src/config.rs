@@ -1,9 +1,21 @@
 use crate::{
+    codecs::ProtocolVersion,
     gossipsub::config::default_gossipsub_config,
     heartbeat::HeartbeatConfig,
     peer_manager::ConnectionState,
 };
-use fuel_core_types::blockchain::consensus::Genesis;
+use fuel_core_types::{
+    blockchain::{
+        consensus::Genesis,
+        primitives::BlockId,
+    },
+    fuel_crypto,
+    fuel_types::ChainId,
+    services::executor::{
+        StateTransitionVersion,
+        STATE_TRANSITION_VERSION,
+    },
+};
 
 use libp2p::{
     core::{
@@ -32,6 +44,7 @@ use std::{
         IpAddr,
         Ipv4Addr,
     },
+    ops::RangeInclusive,
     sync::{
         Arc,
         RwLock,
@@ -45,9 +58,11 @@ use self::{
     fuel_upgrade::{
         Checksum,
         FuelUpgrade,
+        NodeIdentity,
     },
     guarded_node::GuardedNode,
 };
+pub use self::fuel_upgrade::FuelUpgradeError;
 mod connection_tracker;
 mod fuel_authenticated;
 mod fuel_upgrade;
@@ -79,6 +94,27 @@ pub struct Config<State = Initialized> {
     /// Checksum is a hash(sha256) of [`Genesis`](fuel_core_types::blockchain::consensus::Genesis) - chain id.
     pub checksum: Checksum,
 
+    /// The id of the chain followed by the node.
+    pub chain_id: ChainId,
+
+    /// The id of the genesis block of the chain.
+    pub genesis_block_id: BlockId,
+
+    /// The versions of the state transition supported by the node. The node connects
+    /// only to the peers supporting at least one of these versions.
+    pub state_transition_versions: RangeInclusive<StateTransitionVersion>,
+
+    /// Speaks the previous version of the request-response and gossip protocols and
+    /// accepts the previous handshake along with the latest ones. It keeps the node
+    /// compatible with the peers that aren't upgraded yet and should be disabled once
+    /// the whole network is upgraded.
+    pub support_previous_protocol_version: bool,
+
+    /// Speaks only this version of the protocols, like the nodes that
+    /// aren't upgraded yet.
+    #[cfg(test)]
+    pub(crate) only_protocol_version: Option<ProtocolVersion>,
+
     /// IP address for Swarm to listen on
     pub address: IpAddr,
 
@@ -92,6 +128,22 @@ pub struct Config<State = Initialized> {
     pub max_block_size: usize,
     pub max_headers_per_request: u32,
 
+    /// If set, the node subscribes to the transaction gossip and accepts the submitted
+    /// transactions only while it is within this number of blocks of the median
+    /// height reported by the peers.
+    pub tx_gossip_sync_threshold: Option<u32>,
+
+    /// The maximum number of the concurrently served block and header requests
+    /// of the reserved peers. Their requests are served before the requests
+    /// of the other peers.
+    pub max_concurrent_reserved_requests: usize,
+    /// The maximum number of the concurrently served block and header requests
+    /// of the peers that aren't reserved.
+    pub max_concurrent_bulk_requests: usize,
+    /// The maximum number of the queued block and header requests of the reserved
+    /// peers and of the other peers. The requests above the limit are rejected.
+    pub max_queued_requests: usize,
+
     // `DiscoveryBehaviour` related fields
     pub bootstrap_nodes: Vec<Multiaddr>,
     pub enable_mdns: bool,
@@ -156,18 +208,33 @@ pub struct NotInitialized;
 
 impl Config<NotInitialized> {
     /// Inits the `P2PConfig` with some lazily loaded data.
-    pub fn init(self, genesis: Genesis) -> anyhow::Result<Config<Initialized>> {
+    pub fn init(
+        self,
+        genesis: Genesis,
+        chain_id: ChainId,
+        genesis_block_id: BlockId,
+    ) -> anyhow::Result<Config<Initialized>> {
         use fuel_core_chain_config::GenesisCommitment;
 
         Ok(Config {
             keypair: self.keypair,
             network_name: self.network_name,
             checksum: genesis.root()?.into(),
+            chain_id,
+            genesis_block_id,
+            state_transition_versions: self.state_transition_versions,
+            support_previous_protocol_version: self.support_previous_protocol_version,
+            #[cfg(test)]
+            only_protocol_version: self.only_protocol_version,
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
+            tx_gossip_sync_threshold: self.tx_gossip_sync_threshold,
+            max_concurrent_reserved_requests: self.max_concurrent_reserved_requests,
+            max_concurrent_bulk_requests: self.max_concurrent_bulk_requests,
+            max_queued_requests: self.max_queued_requests,
             bootstrap_nodes: self.bootstrap_nodes,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
@@ -202,6 +269,40 @@ pub fn convert_to_libp2p_keypair(
     Ok(Keypair::Secp256k1(secret_key.into()))
 }
 
+impl<State> Config<State> {
+    /// Returns the versions of the request-response protocol spoken by the node,
+    /// the preferred one first.
+    pub fn protocol_versions(&self) -> Vec<ProtocolVersion> {
+        #[cfg(test)]
+        if let Some(version) = self.only_protocol_version {
+            return vec![version]
+        }
+        ProtocolVersion::supported(self.support_previous_protocol_version)
+    }
+
+    /// Returns the versions of the gossip protocol spoken by the node,
+    /// the preferred one first.
+    pub fn gossip_protocol_versions(&self) -> Vec<ProtocolVersion> {
+        #[cfg(test)]
+        if let Some(version) = self.only_protocol_version {
+            return vec![version]
+        }
+        ProtocolVersion::supported_gossip(self.support_previous_protocol_version)
+    }
+
+    /// Returns the secret key of the P2P identity of the node.
+    /// It is `None` if the identity key isn't a secp256k1 key.
+    pub fn identity_secret_key(&self) -> Option<fuel_crypto::SecretKey> {
+        match &self.keypair {
+            Keypair::Secp256k1(keypair) => {
+                fuel_crypto::SecretKey::try_from(&keypair.secret().to_bytes()[..]).ok()
+            }
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
 impl Config<NotInitialized> {
     pub fn default(network_name: &str) -> Self {
         let keypair = Keypair::generate_secp256k1();
@@ -210,11 +311,22 @@ impl Config<NotInitialized> {
             keypair,
             network_name: network_name.into(),
             checksum: Default::default(),
+            chain_id: Default::default(),
+            genesis_block_id: Default::default(),
+            state_transition_versions: STATE_TRANSITION_VERSION
+                ..=STATE_TRANSITION_VERSION,
+            support_previous_protocol_version: true,
+            #[cfg(test)]
+            only_protocol_version: None,
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
+            tx_gossip_sync_threshold: None,
+            max_concurrent_reserved_requests: 16,
+            max_concurrent_bulk_requests: 4,
+            max_queued_requests: 256,
             bootstrap_nodes: vec![],
             enable_mdns: false,
             max_peers_connected: 50,
@@ -243,7 +355,7 @@ impl Config<NotInitialized> {
 impl Config<Initialized> {
     pub fn default_initialized(network_name: &str) -> Self {
         Config::<NotInitialized>::default(network_name)
-            .init(Default::default())
+            .init(Default::default(), Default::default(), Default::default())
             .expect("Expected correct initialization of config")
     }
 }
@@ -287,7 +399,15 @@ pub(crate) fn build_transport(
         libp2p::core::upgrade::SelectUpgrade::new(yamux_config, mplex_config)
     };
 
-    let fuel_upgrade = FuelUpgrade::new(p2p_config.checksum);
+    let fuel_upgrade = FuelUpgrade::new(
+        NodeIdentity {
+            chain_id: p2p_config.chain_id,
+            genesis_block_id: p2p_config.genesis_block_id,
+            checksum: p2p_config.checksum,
+            state_transition_versions: p2p_config.state_transition_versions.clone(),
+        },
+        p2p_config.support_previous_protocol_version,
+    );
     let connection_state = ConnectionState::new();
 
     let transport = if p2p_config.reserved_nodes_only_mode {
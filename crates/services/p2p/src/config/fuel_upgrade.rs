@@ -1,3 +1,8 @@
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_types::ChainId,
+    services::executor::StateTransitionVersion,
+};
 use futures::{
     AsyncRead,
     AsyncWrite,
@@ -19,11 +24,21 @@ use std::{
     error::Error,
     fmt,
     io,
+    ops::RangeInclusive,
     pin::Pin,
 };
 
+/// The handshake exchanging the identities of the chains.
+const IDENTITY_PROTOCOL: &[u8] = b"/fuel/upgrade/1";
+
+/// The previous handshake, where the outbound node only sends the checksum of the
+/// chain config to the inbound node. It is accepted during the rollout of the
+/// handshake exchanging the identities, so the nodes that aren't upgraded yet
+/// can still connect.
+const CHECKSUM_PROTOCOL: &[u8] = b"/fuel/upgrade/0";
+
 /// Sha256 hash of ChainConfig
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Checksum([u8; 32]);
 
 impl From<[u8; 32]> for Checksum {
@@ -32,32 +47,187 @@ impl From<[u8; 32]> for Checksum {
     }
 }
 
-/// When two nodes want to establish a connection they need to
-/// exchange the Hash of their respective Chain Id and Chain Config.
-/// The connection is only accepted if their hashes match.
-/// This is used to aviod peers having same network name but different configurations connecting to each other.
+/// The identity of the chain followed by the node, exchanged during the handshake.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NodeIdentity {
+    pub chain_id: ChainId,
+    pub genesis_block_id: BlockId,
+    pub checksum: Checksum,
+    pub state_transition_versions: RangeInclusive<StateTransitionVersion>,
+}
+
+impl NodeIdentity {
+    /// chain id + genesis block id + checksum + the first and the last versions.
+    const ENCODED_SIZE: usize = 8 + 32 + 32 + 4 + 4;
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::ENCODED_SIZE);
+        bytes.extend_from_slice(&u64::from(self.chain_id).to_be_bytes());
+        bytes.extend_from_slice(self.genesis_block_id.as_ref());
+        bytes.extend_from_slice(&self.checksum.0);
+        bytes.extend_from_slice(&self.state_transition_versions.start().to_be_bytes());
+        bytes.extend_from_slice(&self.state_transition_versions.end().to_be_bytes());
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, FuelUpgradeError> {
+        if bytes.len() != Self::ENCODED_SIZE {
+            return Err(FuelUpgradeError::MalformedIdentity)
+        }
+        let (chain_id, rest) = bytes.split_at(8);
+        let (genesis_block_id, rest) = rest.split_at(32);
+        let (checksum, rest) = rest.split_at(32);
+        let (first_version, last_version) = rest.split_at(4);
+
+        let chain_id = u64::from_be_bytes(chain_id.try_into().expect("8 bytes"));
+        let genesis_block_id: [u8; 32] = genesis_block_id.try_into().expect("32 bytes");
+        let checksum: [u8; 32] = checksum.try_into().expect("32 bytes");
+        let first_version = StateTransitionVersion::from_be_bytes(
+            first_version.try_into().expect("4 bytes"),
+        );
+        let last_version = StateTransitionVersion::from_be_bytes(
+            last_version.try_into().expect("4 bytes"),
+        );
+
+        Ok(Self {
+            chain_id: chain_id.into(),
+            genesis_block_id: genesis_block_id.into(),
+            checksum: checksum.into(),
+            state_transition_versions: first_version..=last_version,
+        })
+    }
+
+    /// Returns the reason to reject the `remote` node if it follows another chain
+    /// or doesn't support any of the state transition versions of this node.
+    fn verify(&self, remote: &NodeIdentity) -> Result<(), FuelUpgradeError> {
+        if self.chain_id != remote.chain_id {
+            return Err(FuelUpgradeError::ChainIdMismatch {
+                local: self.chain_id,
+                remote: remote.chain_id,
+            })
+        }
+        if self.genesis_block_id != remote.genesis_block_id {
+            return Err(FuelUpgradeError::GenesisMismatch {
+                local: self.genesis_block_id,
+                remote: remote.genesis_block_id,
+            })
+        }
+        if self.checksum != remote.checksum {
+            return Err(FuelUpgradeError::IncorrectChecksum)
+        }
+        let local = &self.state_transition_versions;
+        let remote = &remote.state_transition_versions;
+        if local.start() > remote.end() || remote.start() > local.end() {
+            return Err(FuelUpgradeError::IncompatibleVersions {
+                local: local.clone(),
+                remote: remote.clone(),
+            })
+        }
+        Ok(())
+    }
+}
+
+/// When two nodes want to establish a connection they exchange the identities
+/// of the chains they follow: the chain id, the id of the genesis block, the hash
+/// of the chain config and the supported versions of the state transition.
+/// The connection is only accepted if both nodes follow the same chain and support
+/// a common version. This is used to avoid peers having the same network name
+/// but different chains (e.g. testnet and mainnet) connecting to each other.
+///
+/// The previous handshake, exchanging only the checksum of the chain config,
+/// is accepted along with the new one if `accept_previous` is set.
 #[derive(Debug, Clone)]
 pub(crate) struct FuelUpgrade {
-    checksum: Checksum,
+    identity: NodeIdentity,
+    accept_previous: bool,
 }
 
 impl FuelUpgrade {
-    pub(crate) fn new(checksum: Checksum) -> Self {
-        Self { checksum }
+    pub(crate) fn new(identity: NodeIdentity, accept_previous: bool) -> Self {
+        Self {
+            identity,
+            accept_previous,
+        }
+    }
+
+    async fn verify_remote<C>(&self, socket: &mut C) -> Result<(), FuelUpgradeError>
+    where
+        C: AsyncRead + Unpin,
+    {
+        let bytes = read_length_prefixed(socket, NodeIdentity::ENCODED_SIZE).await?;
+        let remote = NodeIdentity::decode(&bytes).map_err(rejected)?;
+        self.identity.verify(&remote).map_err(rejected)
+    }
+
+    /// Verifies the checksum sent by the outbound node using the previous handshake.
+    async fn verify_remote_checksum<C>(
+        &self,
+        socket: &mut C,
+    ) -> Result<(), FuelUpgradeError>
+    where
+        C: AsyncRead + Unpin,
+    {
+        let checksum =
+            read_length_prefixed(socket, self.identity.checksum.0.len()).await?;
+        if checksum != self.identity.checksum.0 {
+            return Err(rejected(FuelUpgradeError::IncorrectChecksum))
+        }
+        Ok(())
     }
 }
 
+/// Logs the reason to reject the peer. The operators need the reason
+/// to find out why the node doesn't connect to the network.
+fn rejected(reason: FuelUpgradeError) -> FuelUpgradeError {
+    tracing::warn!("Rejected the incompatible peer: {reason}");
+    reason
+}
+
+/// The reason to reject the connection with the peer.
 #[derive(Debug)]
-pub(crate) enum FuelUpgradeError {
+pub enum FuelUpgradeError {
+    ChainIdMismatch {
+        local: ChainId,
+        remote: ChainId,
+    },
+    GenesisMismatch {
+        local: BlockId,
+        remote: BlockId,
+    },
     IncorrectChecksum,
+    IncompatibleVersions {
+        local: RangeInclusive<StateTransitionVersion>,
+        remote: RangeInclusive<StateTransitionVersion>,
+    },
+    MalformedIdentity,
     Io(io::Error),
 }
 
 impl fmt::Display for FuelUpgradeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            FuelUpgradeError::Io(e) => write!(f, "{e}"),            
-            FuelUpgradeError::IncorrectChecksum => f.write_str("Fuel node checksum does not match, either ChainId or ChainConfig are not the same, or both."),            
+            FuelUpgradeError::Io(e) => write!(f, "{e}"),
+            FuelUpgradeError::ChainIdMismatch { local, remote } => write!(
+                f,
+                "The peer follows the chain {} instead of {}",
+                u64::from(*remote),
+                u64::from(*local)
+            ),
+            FuelUpgradeError::GenesisMismatch { local, remote } => write!(
+                f,
+                "The peer has the genesis block {remote} instead of {local}"
+            ),
+            FuelUpgradeError::IncorrectChecksum => f.write_str(
+                "Fuel node checksum does not match, the ChainConfig is not the same.",
+            ),
+            FuelUpgradeError::IncompatibleVersions { local, remote } => write!(
+                f,
+                "The peer supports the state transition versions {remote:?}, \
+                while the node supports {local:?}"
+            ),
+            FuelUpgradeError::MalformedIdentity => {
+                f.write_str("The peer sent the malformed identity of the chain")
+            }
         }
     }
 }
@@ -72,17 +242,22 @@ impl Error for FuelUpgradeError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             FuelUpgradeError::Io(e) => Some(e),
-            FuelUpgradeError::IncorrectChecksum => None,
+            _ => None,
         }
     }
 }
 
 impl UpgradeInfo for FuelUpgrade {
     type Info = &'static [u8];
-    type InfoIter = std::iter::Once<Self::Info>;
+    type InfoIter = std::vec::IntoIter<Self::Info>;
 
     fn protocol_info(&self) -> Self::InfoIter {
-        std::iter::once(b"/fuel/upgrade/0")
+        // The protocols are negotiated in the order of the preference.
+        let mut protocols = vec![IDENTITY_PROTOCOL];
+        if self.accept_previous {
+            protocols.push(CHECKSUM_PROTOCOL);
+        }
+        protocols.into_iter()
     }
 }
 
@@ -94,15 +269,22 @@ where
     type Error = FuelUpgradeError;
     type Future = Pin<Box<dyn Future<Output = Result<Self::Output, Self::Error>> + Send>>;
 
-    fn upgrade_inbound(self, mut socket: C, _: Self::Info) -> Self::Future {
+    fn upgrade_inbound(self, mut socket: C, protocol: Self::Info) -> Self::Future {
         async move {
-            // Inbound node receives the checksum and compares it to its own checksum.
-            // If they do not match the connection is rejected.
-            let res = read_length_prefixed(&mut socket, self.checksum.0.len()).await?;
-            if res != self.checksum.0 {
-                return Err(FuelUpgradeError::IncorrectChecksum)
+            if protocol == CHECKSUM_PROTOCOL {
+                // Inbound node receives the checksum and compares it to its own checksum.
+                // If they do not match the connection is rejected.
+                self.verify_remote_checksum(&mut socket).await?;
+                return Ok(socket)
             }
 
+            // Inbound node receives the identity of the outbound node and replies with
+            // its own identity, so both sides can verify the compatibility and
+            // reject the connection with the reason.
+            let verified = self.verify_remote(&mut socket).await;
+            write_length_prefixed(&mut socket, self.identity.encode()).await?;
+            verified?;
+
             Ok(socket)
         }
         .boxed()
@@ -117,16 +299,123 @@ where
     type Error = FuelUpgradeError;
     type Future = Pin<Box<dyn Future<Output = Result<Self::Output, Self::Error>> + Send>>;
 
-    fn upgrade_outbound(self, mut socket: C, _: Self::Info) -> Self::Future {
+    fn upgrade_outbound(self, mut socket: C, protocol: Self::Info) -> Self::Future {
         async move {
-            // Outbound node sends their own checksum for comparison with the inbound node.
-            write_length_prefixed(&mut socket, &self.checksum.0).await?;
+            if protocol == CHECKSUM_PROTOCOL {
+                // Outbound node sends their own checksum for comparison with the inbound
+                // node, which rejects the connection if the checksums don't match.
+                write_length_prefixed(&mut socket, self.identity.checksum.0).await?;
+                return Ok(socket)
+            }
 
-            // Note: outbound node does not need to receive the checksum from the inbound node,
-            // since inbound node will reject the connection if the two don't match on its side.
+            // Outbound node sends its own identity and verifies the identity
+            // of the inbound node.
+            write_length_prefixed(&mut socket, self.identity.encode()).await?;
+            self.verify_remote(&mut socket).await?;
 
             Ok(socket)
         }
         .boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity() -> NodeIdentity {
+        NodeIdentity {
+            chain_id: 1u64.into(),
+            genesis_block_id: [2u8; 32].into(),
+            checksum: [3u8; 32].into(),
+            state_transition_versions: 4..=6,
+        }
+    }
+
+    #[test]
+    fn identity_is_decoded_from_the_encoding() {
+        let identity = identity();
+
+        let decoded = NodeIdentity::decode(&identity.encode()).unwrap();
+
+        assert_eq!(decoded, identity);
+        assert!(matches!(
+            NodeIdentity::decode(&[0u8; 32]),
+            Err(FuelUpgradeError::MalformedIdentity)
+        ));
+    }
+
+    #[tokio::test]
+    async fn previous_handshake_is_accepted_during_the_rollout() {
+        let upgrade = FuelUpgrade::new(identity(), true);
+        let protocols: Vec<_> = upgrade.protocol_info().collect();
+        assert_eq!(protocols, vec![IDENTITY_PROTOCOL, CHECKSUM_PROTOCOL]);
+
+        let checksum_socket = |checksum: [u8; 32]| async move {
+            let mut socket = futures::io::Cursor::new(Vec::new());
+            write_length_prefixed(&mut socket, checksum).await.unwrap();
+            futures::io::Cursor::new(socket.into_inner())
+        };
+
+        let socket = checksum_socket(identity().checksum.0).await;
+        let accepted = upgrade
+            .clone()
+            .upgrade_inbound(socket, CHECKSUM_PROTOCOL)
+            .await;
+        assert!(accepted.is_ok());
+
+        let socket = checksum_socket([9u8; 32]).await;
+        let rejected = upgrade.upgrade_inbound(socket, CHECKSUM_PROTOCOL).await;
+        assert!(matches!(rejected, Err(FuelUpgradeError::IncorrectChecksum)));
+    }
+
+    #[test]
+    fn previous_handshake_is_not_offered_after_the_rollout() {
+        let upgrade = FuelUpgrade::new(identity(), false);
+        let protocols: Vec<_> = upgrade.protocol_info().collect();
+        assert_eq!(protocols, vec![IDENTITY_PROTOCOL]);
+    }
+
+    #[test]
+    fn peers_of_the_same_chain_with_a_common_version_are_compatible() {
+        let local = identity();
+        let mut remote = identity();
+        remote.state_transition_versions = 6..=8;
+
+        assert!(local.verify(&remote).is_ok());
+        assert!(remote.verify(&local).is_ok());
+    }
+
+    #[test]
+    fn peers_of_other_chains_are_rejected_with_the_reason() {
+        let local = identity();
+
+        let mut remote = identity();
+        remote.chain_id = 9u64.into();
+        assert!(matches!(
+            local.verify(&remote),
+            Err(FuelUpgradeError::ChainIdMismatch { .. })
+        ));
+
+        let mut remote = identity();
+        remote.genesis_block_id = [9u8; 32].into();
+        assert!(matches!(
+            local.verify(&remote),
+            Err(FuelUpgradeError::GenesisMismatch { .. })
+        ));
+
+        let mut remote = identity();
+        remote.checksum = [9u8; 32].into();
+        assert!(matches!(
+            local.verify(&remote),
+            Err(FuelUpgradeError::IncorrectChecksum)
+        ));
+
+        let mut remote = identity();
+        remote.state_transition_versions = 7..=9;
+        assert!(matches!(
+            local.verify(&remote),
+            Err(FuelUpgradeError::IncompatibleVersions { .. })
+        ));
+    }
+}
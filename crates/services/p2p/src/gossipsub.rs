@@ -1,3 +1,4 @@
 pub mod config;
+pub mod copies;
 pub mod messages;
 pub mod topics;
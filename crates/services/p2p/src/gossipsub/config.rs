@@ -26,6 +26,7 @@ use sha2::{
 use std::time::Duration;
 
 use super::topics::{
+    topic_name,
     GossipTopic,
     CON_VOTE_GOSSIP_TOPIC,
     NEW_BLOCK_GOSSIP_TOPIC,
@@ -225,16 +226,28 @@ fn initialize_gossipsub(gossipsub: &mut Gossipsub, p2p_config: &Config) {
         (CON_VOTE_GOSSIP_TOPIC, CON_VOTE_GOSSIP_WEIGHT),
     ];
 
-    // subscribe to gossipsub topics with the network name suffix
+    // subscribe to gossipsub topics of each protocol version with the network name suffix
+    let versions = p2p_config.gossip_protocol_versions();
     for (topic, weight) in topics {
-        let t: GossipTopic = Topic::new(format!("{}/{}", topic, p2p_config.network_name));
-
-        gossipsub
-            .set_topic_params(t.clone(), initialize_topic_score_params(weight))
-            .expect("First time initializing Topic Score");
-
-        gossipsub
-            .subscribe(&t)
-            .expect("Subscription to Topic: {topic} successful");
+        for version in &versions {
+            let t: GossipTopic =
+                Topic::new(topic_name(topic, &p2p_config.network_name, *version));
+
+            gossipsub
+                .set_topic_params(t.clone(), initialize_topic_score_params(weight))
+                .expect("First time initializing Topic Score");
+
+            // The subscription to the transaction gossip is deferred until the node
+            // catches up with the network.
+            if topic == NEW_TX_GOSSIP_TOPIC
+                && p2p_config.tx_gossip_sync_threshold.is_some()
+            {
+                continue
+            }
+
+            gossipsub
+                .subscribe(&t)
+                .expect("Subscription to Topic: {topic} successful");
+        }
     }
 }
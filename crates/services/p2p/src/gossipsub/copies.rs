@@ -0,0 +1,164 @@
+use libp2p::{
+    gossipsub::{
+        MessageAcceptance,
+        MessageId,
+    },
+    PeerId,
+};
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+
+/// The max number of the tracked messages.
+const MAX_TRACKED_MESSAGES: usize = 4096;
+
+/// The status of the received copy of the gossiped message.
+#[derive(Debug)]
+pub enum CopyStatus {
+    /// The first copy of the message. It is validated by the application.
+    First,
+    /// The first copy is not validated yet. The copy is reported along with it.
+    Pending,
+    /// The first copy was validated with this result.
+    Validated(MessageAcceptance),
+}
+
+struct Copies {
+    first: MessageId,
+    acceptance: Option<MessageAcceptance>,
+    pending: Vec<(MessageId, PeerId)>,
+}
+
+/// Tracks the copies of the gossiped messages published in the topics of the different
+/// protocol versions. The copies have different ids, so the gossipsub doesn't treat them
+/// as duplicates. Only the first copy is delivered to the application, and its validation
+/// result is applied to the other copies. It keeps the copies propagating to the peers
+/// of their versions without validating the message twice.
+#[derive(Default)]
+pub struct GossipCopies {
+    /// The copies by the hash of the payload of the message.
+    copies: HashMap<[u8; 32], Copies>,
+    /// The hashes of the payloads by the id of the first copy.
+    firsts: HashMap<MessageId, [u8; 32]>,
+    /// The order of the tracking of the `copies`, the oldest first.
+    order: VecDeque<[u8; 32]>,
+}
+
+impl core::fmt::Debug for GossipCopies {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GossipCopies")
+            .field("tracked", &self.copies.len())
+            .finish()
+    }
+}
+
+impl GossipCopies {
+    /// Records the received copy of the message with the `payload_hash`.
+    pub fn record(
+        &mut self,
+        payload_hash: [u8; 32],
+        message_id: &MessageId,
+        propagation_source: PeerId,
+    ) -> CopyStatus {
+        if let Some(copies) = self.copies.get_mut(&payload_hash) {
+            if &copies.first == message_id {
+                return CopyStatus::First
+            }
+            return match &copies.acceptance {
+                Some(acceptance) => CopyStatus::Validated(clone_acceptance(acceptance)),
+                None => {
+                    copies
+                        .pending
+                        .push((message_id.clone(), propagation_source));
+                    CopyStatus::Pending
+                }
+            }
+        }
+
+        self.copies.insert(
+            payload_hash,
+            Copies {
+                first: message_id.clone(),
+                acceptance: None,
+                pending: vec![],
+            },
+        );
+        self.firsts.insert(message_id.clone(), payload_hash);
+        self.order.push_back(payload_hash);
+        if self.order.len() > MAX_TRACKED_MESSAGES {
+            if let Some(evicted) = self.order.pop_front() {
+                if let Some(copies) = self.copies.remove(&evicted) {
+                    self.firsts.remove(&copies.first);
+                }
+            }
+        }
+        CopyStatus::First
+    }
+
+    /// Records the validation result of the message and returns its pending copies
+    /// with the same result to report.
+    pub fn validated(
+        &mut self,
+        message_id: &MessageId,
+        acceptance: &MessageAcceptance,
+    ) -> Vec<(MessageId, PeerId, MessageAcceptance)> {
+        let Some(copies) = self
+            .firsts
+            .get(message_id)
+            .and_then(|payload_hash| self.copies.get_mut(payload_hash))
+        else {
+            return vec![]
+        };
+        copies.acceptance = Some(clone_acceptance(acceptance));
+        core::mem::take(&mut copies.pending)
+            .into_iter()
+            .map(|(id, source)| (id, source, clone_acceptance(acceptance)))
+            .collect()
+    }
+}
+
+fn clone_acceptance(acceptance: &MessageAcceptance) -> MessageAcceptance {
+    match acceptance {
+        MessageAcceptance::Accept => MessageAcceptance::Accept,
+        MessageAcceptance::Reject => MessageAcceptance::Reject,
+        MessageAcceptance::Ignore => MessageAcceptance::Ignore,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_are_reported_with_the_result_of_the_first_copy() {
+        let mut copies = GossipCopies::default();
+        let first = MessageId::from(&[1u8][..]);
+        let copy = MessageId::from(&[2u8][..]);
+        let late_copy = MessageId::from(&[3u8][..]);
+        let peer = PeerId::random();
+
+        assert!(matches!(
+            copies.record([0; 32], &first, peer),
+            CopyStatus::First
+        ));
+        assert!(matches!(
+            copies.record([0; 32], &copy, peer),
+            CopyStatus::Pending
+        ));
+        let pending = copies.validated(&first, &MessageAcceptance::Accept);
+        assert!(matches!(
+            &pending[..],
+            [(id, source, MessageAcceptance::Accept)] if id == &copy && source == &peer
+        ));
+        assert!(matches!(
+            copies.record([0; 32], &late_copy, peer),
+            CopyStatus::Validated(MessageAcceptance::Accept)
+        ));
+        let other = MessageId::from(&[4u8][..]);
+        assert!(matches!(
+            copies.record([1; 32], &other, peer),
+            CopyStatus::First
+        ));
+    }
+}
@@ -8,31 +8,50 @@ use super::messages::{
     GossipTopicTag,
     GossipsubBroadcastRequest,
 };
+use crate::codecs::ProtocolVersion;
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
 pub const NEW_BLOCK_GOSSIP_TOPIC: &str = "new_block";
 pub const CON_VOTE_GOSSIP_TOPIC: &str = "consensus_vote";
 
-/// Holds used Gossipsub Topics
+/// Returns the name of the `topic` of the network for the `version` of the protocol.
+/// The topics of the first version don't contain the version.
+pub fn topic_name(topic: &str, network_name: &str, version: ProtocolVersion) -> String {
+    match version {
+        ProtocolVersion::V1 => format!("{topic}/{network_name}"),
+        version => format!("{topic}/{version}/{network_name}"),
+    }
+}
+
+/// Holds used Gossipsub Topics of the supported protocol versions
+#[derive(Debug)]
+pub struct GossipsubTopics {
+    /// The topics of the versions, the preferred version first.
+    versions: Vec<VersionedTopics>,
+}
+
 /// Each field contains TopicHash and GossipTopic itself
 /// in order to avoid converting GossipTopic to TopicHash on each received message
 #[derive(Debug)]
-pub struct GossipsubTopics {
+struct VersionedTopics {
+    version: ProtocolVersion,
     new_tx_topic: (TopicHash, GossipTopic),
     new_block_topic: (TopicHash, GossipTopic),
     consensus_vote_topic: (TopicHash, GossipTopic),
 }
 
-impl GossipsubTopics {
-    pub fn new(network_name: &str) -> Self {
-        let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+impl VersionedTopics {
+    fn new(network_name: &str, version: ProtocolVersion) -> Self {
+        let new_tx_topic =
+            Topic::new(topic_name(NEW_TX_GOSSIP_TOPIC, network_name, version));
         let new_block_topic =
-            Topic::new(format!("{NEW_BLOCK_GOSSIP_TOPIC}/{network_name}"));
+            Topic::new(topic_name(NEW_BLOCK_GOSSIP_TOPIC, network_name, version));
         let consensus_vote_topic =
-            Topic::new(format!("{CON_VOTE_GOSSIP_TOPIC}/{network_name}"));
+            Topic::new(topic_name(CON_VOTE_GOSSIP_TOPIC, network_name, version));
 
         Self {
+            version,
             new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
             new_block_topic: (new_block_topic.hash(), new_block_topic),
             consensus_vote_topic: (consensus_vote_topic.hash(), consensus_vote_topic),
@@ -40,14 +59,12 @@ impl GossipsubTopics {
     }
 
     /// Given a TopicHash it will return a matching GossipTopicTag
-    pub fn get_gossipsub_tag(
-        &self,
-        incoming_topic: &TopicHash,
-    ) -> Option<GossipTopicTag> {
-        let GossipsubTopics {
+    fn get_gossipsub_tag(&self, incoming_topic: &TopicHash) -> Option<GossipTopicTag> {
+        let VersionedTopics {
             new_tx_topic,
             new_block_topic,
             consensus_vote_topic,
+            ..
         } = &self;
 
         match incoming_topic {
@@ -60,22 +77,65 @@ impl GossipsubTopics {
         }
     }
 
-    /// Given a `GossipsubBroadcastRequest` retruns a `GossipTopic`
-    /// which is broadcast over the network with the serialized inner value of `GossipsubBroadcastRequest`
-    pub fn get_gossipsub_topic(
+    fn get_gossipsub_topic(
         &self,
         outgoing_request: &GossipsubBroadcastRequest,
-    ) -> GossipTopic {
+    ) -> &GossipTopic {
         match outgoing_request {
-            GossipsubBroadcastRequest::ConsensusVote(_) => {
-                self.consensus_vote_topic.1.clone()
-            }
-            GossipsubBroadcastRequest::NewBlock(_) => self.new_block_topic.1.clone(),
-            GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
+            GossipsubBroadcastRequest::ConsensusVote(_) => &self.consensus_vote_topic.1,
+            GossipsubBroadcastRequest::NewBlock(_) => &self.new_block_topic.1,
+            GossipsubBroadcastRequest::NewTx(_) => &self.new_tx_topic.1,
         }
     }
 }
 
+impl GossipsubTopics {
+    /// Creates the topics of the network for the `versions` of the protocol.
+    pub fn new(network_name: &str, versions: &[ProtocolVersion]) -> Self {
+        let versions = versions
+            .iter()
+            .map(|version| VersionedTopics::new(network_name, *version))
+            .collect();
+
+        Self { versions }
+    }
+
+    /// Given a TopicHash it will return a matching GossipTopicTag
+    /// and the version of the protocol of the topic
+    pub fn get_gossipsub_tag(
+        &self,
+        incoming_topic: &TopicHash,
+    ) -> Option<(GossipTopicTag, ProtocolVersion)> {
+        self.versions.iter().find_map(|topics| {
+            topics
+                .get_gossipsub_tag(incoming_topic)
+                .map(|tag| (tag, topics.version))
+        })
+    }
+
+    /// Returns the transaction topics of all versions.
+    pub fn new_tx_topics(&self) -> impl Iterator<Item = &GossipTopic> {
+        self.versions.iter().map(|topics| &topics.new_tx_topic.1)
+    }
+
+    /// Given a `GossipsubBroadcastRequest` retruns a `GossipTopic` of each version
+    /// which is broadcast over the network with the serialized inner value of `GossipsubBroadcastRequest`
+    pub fn get_gossipsub_topics(
+        &self,
+        outgoing_request: &GossipsubBroadcastRequest,
+    ) -> Vec<(ProtocolVersion, GossipTopic)> {
+        self.versions
+            .iter()
+            .map(|topics| {
+                (
+                    topics.version,
+                    topics.get_gossipsub_topic(outgoing_request).clone(),
+                )
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +152,7 @@ mod tests {
     #[test]
     fn test_gossipsub_topics() {
         let network_name = "fuel_test_network";
+        let version = ProtocolVersion::V1;
         let new_tx_topic: GossipTopic =
             Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
         let new_block_topic: GossipTopic =
@@ -99,50 +160,78 @@ mod tests {
         let consensus_vote_topic: GossipTopic =
             Topic::new(format!("{CON_VOTE_GOSSIP_TOPIC}/{network_name}"));
 
-        let gossipsub_topics = GossipsubTopics::new(network_name);
+        let gossipsub_topics = GossipsubTopics::new(network_name, &[version]);
 
         // Test matching Topic Hashes
-        assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
-        assert_eq!(gossipsub_topics.new_block_topic.0, new_block_topic.hash());
-        assert_eq!(
-            gossipsub_topics.consensus_vote_topic.0,
-            consensus_vote_topic.hash()
-        );
+        let topics = &gossipsub_topics.versions[0];
+        assert_eq!(topics.new_tx_topic.0, new_tx_topic.hash());
+        assert_eq!(topics.new_block_topic.0, new_block_topic.hash());
+        assert_eq!(topics.consensus_vote_topic.0, consensus_vote_topic.hash());
 
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_tx_topic.hash()),
-            Some(GossipTopicTag::NewTx)
+            Some((GossipTopicTag::NewTx, version))
         );
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_block_topic.hash()),
-            Some(GossipTopicTag::NewBlock)
+            Some((GossipTopicTag::NewBlock, version))
         );
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&consensus_vote_topic.hash()),
-            Some(GossipTopicTag::ConsensusVote)
+            Some((GossipTopicTag::ConsensusVote, version))
         );
 
-        // Test given a `GossipsubBroadcastRequest` that `get_gossipsub_topic()` returns matching `Topic`
+        // Test given a `GossipsubBroadcastRequest` that `get_gossipsub_topics()` returns matching `Topic`
         let broadcast_req =
             GossipsubBroadcastRequest::ConsensusVote(Arc::new(ConsensusVote::default()));
         assert_eq!(
-            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            gossipsub_topics.get_gossipsub_topics(&broadcast_req)[0]
+                .1
+                .hash(),
             consensus_vote_topic.hash()
         );
 
         let broadcast_req =
             GossipsubBroadcastRequest::NewBlock(Arc::new(Block::default()));
         assert_eq!(
-            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            gossipsub_topics.get_gossipsub_topics(&broadcast_req)[0]
+                .1
+                .hash(),
             new_block_topic.hash()
         );
 
         let broadcast_req =
             GossipsubBroadcastRequest::NewTx(Arc::new(Transaction::default_test_tx()));
         assert_eq!(
-            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            gossipsub_topics.get_gossipsub_topics(&broadcast_req)[0]
+                .1
+                .hash(),
             new_tx_topic.hash()
         );
     }
+
+    #[test]
+    fn topics_of_each_version_are_separate() {
+        let network_name = "fuel_test_network";
+        let versions = [ProtocolVersion::V2, ProtocolVersion::V1];
+        let gossipsub_topics = GossipsubTopics::new(network_name, &versions);
+        let v2_new_tx_topic: GossipTopic =
+            Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/v2/{network_name}"));
+
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&v2_new_tx_topic.hash()),
+            Some((GossipTopicTag::NewTx, ProtocolVersion::V2))
+        );
+
+        // The message is published in the topics of all versions, the preferred first.
+        let broadcast_req =
+            GossipsubBroadcastRequest::NewTx(Arc::new(Transaction::default_test_tx()));
+        let topics = gossipsub_topics.get_gossipsub_topics(&broadcast_req);
+        assert_eq!(topics.len(), 2);
+        assert_eq!(topics[0].0, ProtocolVersion::V2);
+        assert_eq!(topics[0].1.hash(), v2_new_tx_topic.hash());
+        assert_eq!(topics[1].0, ProtocolVersion::V1);
+        assert_eq!(gossipsub_topics.new_tx_topics().count(), 2);
+    }
 }
@@ -8,11 +8,16 @@ mod p2p_service;
 mod peer_manager;
 mod peer_report;
 pub mod ports;
+mod request_priority;
 mod request_response;
 pub mod service;
 
 pub use gossipsub::config as gossipsub_config;
 pub use heartbeat::HeartbeatConfig;
+pub use peer_manager::{
+    PeerBandwidth,
+    PeerInfo,
+};
 
 pub use libp2p::{
     multiaddr::Protocol,
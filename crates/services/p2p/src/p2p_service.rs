@@ -3,12 +3,19 @@ use crate::{
         FuelBehaviour,
         FuelBehaviourEvent,
     },
-    codecs::NetworkCodec,
+    codecs::{
+        NetworkCodec,
+        ProtocolVersion,
+    },
     config::{
         build_transport,
         Config,
     },
     gossipsub::{
+        copies::{
+            CopyStatus,
+            GossipCopies,
+        },
         messages::{
             GossipsubBroadcastRequest,
             GossipsubMessage as FuelGossipsubMessage,
@@ -37,6 +44,7 @@ use fuel_core_types::{
 };
 use futures::prelude::*;
 use libp2p::{
+    core::ProtocolName,
     gossipsub::{
         error::PublishError,
         GossipsubEvent,
@@ -62,10 +70,15 @@ use libp2p::{
     Swarm,
 };
 use rand::seq::IteratorRandom;
+use sha2::{
+    Digest,
+    Sha256,
+};
 use std::{
     collections::HashMap,
     time::Duration,
 };
+use tokio::sync::broadcast;
 use tracing::{
     debug,
     warn,
@@ -100,7 +113,8 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
     /// Holds the ResponseChannel(s) for the inbound requests from the p2p Network
     /// Once the Response is prepared by the NetworkOrchestrator
     /// It will send it to the specified Peer via its unique ResponseChannel    
-    inbound_requests_table: HashMap<RequestId, ResponseChannel<NetworkResponse>>,
+    inbound_requests_table:
+        HashMap<RequestId, (PeerId, ResponseChannel<NetworkResponse>)>,
 
     /// NetworkCodec used as <GossipsubCodec> for encoding and decoding of Gossipsub messages    
     network_codec: Codec,
@@ -118,11 +132,16 @@ pub struct FuelP2PService<Codec: NetworkCodec> {
 #[derive(Debug)]
 struct GossipsubData {
     topics: GossipsubTopics,
+    /// The copies of the messages received in the topics of the different versions.
+    copies: GossipCopies,
 }
 
 impl GossipsubData {
     pub fn with_topics(topics: GossipsubTopics) -> Self {
-        Self { topics }
+        Self {
+            topics,
+            copies: GossipCopies::default(),
+        }
     }
 }
 
@@ -142,6 +161,7 @@ pub enum FuelP2PEvent {
         message: FuelGossipsubMessage,
     },
     RequestMessage {
+        peer_id: PeerId,
         request_id: RequestId,
         request_message: RequestMessage,
     },
@@ -154,11 +174,17 @@ pub enum FuelP2PEvent {
 }
 
 impl<Codec: NetworkCodec> FuelP2PService<Codec> {
-    pub fn new(config: Config, codec: Codec) -> Self {
+    pub fn new(
+        reserved_peers_updates: broadcast::Sender<usize>,
+        config: Config,
+        codec: Codec,
+    ) -> Self {
         let local_peer_id = PeerId::from(config.keypair.public());
 
-        let gossipsub_data =
-            GossipsubData::with_topics(GossipsubTopics::new(&config.network_name));
+        let gossipsub_data = GossipsubData::with_topics(GossipsubTopics::new(
+            &config.network_name,
+            &config.gossip_protocol_versions(),
+        ));
         let network_metadata = NetworkMetadata { gossipsub_data };
 
         // configure and build P2P Service
@@ -221,6 +247,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             network_metadata,
             metrics,
             peer_manager: PeerManager::new(
+                reserved_peers_updates,
                 reserved_peers,
                 connection_state,
                 config.max_peers_connected as usize,
@@ -279,23 +306,34 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         self.peer_manager.get_peers_ids()
     }
 
+    /// Publishes the message in the topics of all protocol versions spoken by the node.
+    /// Returns the id of the message of the most preferred version that was published.
     pub fn publish_message(
         &mut self,
         message: GossipsubBroadcastRequest,
     ) -> Result<MessageId, PublishError> {
-        let topic = self
+        let topics = self
             .network_metadata
             .gossipsub_data
             .topics
-            .get_gossipsub_topic(&message);
-
-        match self.network_codec.encode(message) {
-            Ok(encoded_data) => self
-                .swarm
-                .behaviour_mut()
-                .publish_message(topic, encoded_data),
-            Err(e) => Err(PublishError::TransformFailed(e)),
+            .get_gossipsub_topics(&message);
+
+        let mut result = Err(PublishError::InsufficientPeers);
+        for (version, topic) in topics {
+            let published = match self.network_codec.encode(message.clone(), version) {
+                Ok(encoded_data) => self
+                    .swarm
+                    .behaviour_mut()
+                    .publish_message(topic, encoded_data),
+                Err(e) => Err(PublishError::TransformFailed(e)),
+            };
+            // The peers may not speak one of the versions anymore,
+            // so it is enough to publish the message in any of them.
+            if result.is_err() {
+                result = published;
+            }
         }
+        result
     }
 
     /// Sends RequestMessage to a peer
@@ -321,6 +359,11 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             }
         };
 
+        let version = message_request.min_protocol_version();
+        if version > ProtocolVersion::V1 && !self.peer_speaks(&peer_id, version) {
+            return Err(RequestError::UnsupportedProtocol(version))
+        }
+
         let request_id = self
             .swarm
             .behaviour_mut()
@@ -332,6 +375,20 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         Ok(request_id)
     }
 
+    /// Returns `true` if the peer reported the request-response protocol
+    /// of the `version` during the identification.
+    fn peer_speaks(&self, peer_id: &PeerId, version: ProtocolVersion) -> bool {
+        let protocol = self.network_codec.get_req_res_protocol(version);
+        let protocol_name = String::from_utf8_lossy(protocol.protocol_name());
+        self.peer_manager
+            .get_peer_info(peer_id)
+            .map_or(false, |info| {
+                info.protocols
+                    .iter()
+                    .any(|protocol| *protocol == protocol_name)
+            })
+    }
+
     /// Sends ResponseMessage to a peer that requested the data
     pub fn send_response_msg(
         &mut self,
@@ -342,7 +399,9 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
             self.network_codec.convert_to_network_response(&message),
             self.inbound_requests_table.remove(&request_id),
         ) {
-            (Ok(message), Some(channel)) => {
+            (Ok(message), Some((peer_id, channel))) => {
+                self.peer_manager
+                    .record_outbound_bytes(&peer_id, message.payload_len());
                 if self
                     .swarm
                     .behaviour_mut()
@@ -370,6 +429,24 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
 
+    /// Subscribes to the transaction gossip topics or unsubscribes from them.
+    pub fn set_tx_gossip_subscription(&mut self, subscribe: bool) -> anyhow::Result<()> {
+        let topics = &self.network_metadata.gossipsub_data.topics;
+        let behaviour = self.swarm.behaviour_mut();
+        for topic in topics.new_tx_topics() {
+            if subscribe {
+                behaviour
+                    .subscribe(topic)
+                    .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+            } else {
+                behaviour
+                    .unsubscribe(topic)
+                    .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+            }
+        }
+        Ok(())
+    }
+
     /// The report is forwarded to gossipsub behaviour
     /// If acceptance is "Rejected" the gossipsub peer score is calculated
     /// And if it's below allowed threshold the peer is banned
@@ -379,6 +456,12 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         propagation_source: PeerId,
         acceptance: MessageAcceptance,
     ) {
+        let copies = self
+            .network_metadata
+            .gossipsub_data
+            .copies
+            .validated(msg_id, &acceptance);
+
         if let Some(gossip_score) = self
             .swarm
             .behaviour_mut()
@@ -390,6 +473,10 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                 &mut self.swarm,
             );
         }
+
+        for (copy_id, copy_source, acceptance) in copies {
+            self.report_message_validation_result(&copy_id, copy_source, acceptance);
+        }
     }
 
     #[cfg(test)]
@@ -414,6 +501,11 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
         );
     }
 
+    /// Bans the peer regardless of its score, the connections to it are closed.
+    pub fn ban_peer(&mut self, peer_id: PeerId) {
+        self.swarm.ban_peer_id(peer_id);
+    }
+
     #[tracing::instrument(skip_all,
         level = "debug",
         fields(
@@ -463,20 +555,46 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                 message,
                 message_id,
             }) => {
-                if let Some(correct_topic) = self
+                self.peer_manager
+                    .record_inbound_bytes(&propagation_source, message.data.len());
+                if let Some((correct_topic, version)) = self
                     .network_metadata
                     .gossipsub_data
                     .topics
                     .get_gossipsub_tag(&message.topic)
                 {
-                    match self.network_codec.decode(&message.data, correct_topic) {
+                    match self
+                        .network_codec
+                        .decode(&message.data, correct_topic, version)
+                    {
                         Ok(decoded_message) => {
-                            return Some(FuelP2PEvent::GossipsubMessage {
-                                peer_id: propagation_source,
-                                message_id,
-                                topic_hash: message.topic,
-                                message: decoded_message,
-                            })
+                            let payload =
+                                version.payload(&message.data).unwrap_or_default();
+                            let status =
+                                self.network_metadata.gossipsub_data.copies.record(
+                                    Sha256::digest(payload).into(),
+                                    &message_id,
+                                    propagation_source,
+                                );
+                            match status {
+                                CopyStatus::First => {
+                                    return Some(FuelP2PEvent::GossipsubMessage {
+                                        peer_id: propagation_source,
+                                        message_id,
+                                        topic_hash: message.topic,
+                                        message: decoded_message,
+                                    })
+                                }
+                                // The copy is reported along with the first copy.
+                                CopyStatus::Pending => {}
+                                CopyStatus::Validated(acceptance) => {
+                                    self.report_message_validation_result(
+                                        &message_id,
+                                        propagation_source,
+                                        acceptance,
+                                    );
+                                }
+                            }
                         }
                         Err(err) => {
                             warn!(target: "fuel-p2p", "Failed to decode a message. ID: {}, Message: {:?} with error: {:?}", message_id, &message.data, err);
@@ -499,6 +617,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                         peer_id,
                         addresses,
                         agent_version,
+                        protocols,
                     } => {
                         if self.metrics {
                             p2p_metrics().unique_peers.inc();
@@ -508,6 +627,7 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                             &peer_id,
                             addresses.clone(),
                             agent_version,
+                            protocols,
                         );
 
                         self.swarm
@@ -572,9 +692,11 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                         channel,
                         request_id,
                     } => {
-                        self.inbound_requests_table.insert(request_id, channel);
+                        self.inbound_requests_table
+                            .insert(request_id, (peer, channel));
 
                         return Some(FuelP2PEvent::RequestMessage {
+                            peer_id: peer,
                             request_id,
                             request_message: request,
                         })
@@ -583,6 +705,8 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                         request_id,
                         response,
                     } => {
+                        self.peer_manager
+                            .record_inbound_bytes(&peer, response.payload_len());
                         match (
                             self.outbound_requests_table.remove(&request_id),
                             self.network_codec.convert_to_response(&response),
@@ -620,6 +744,35 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
                                     );
                                 }
                             }
+                            (
+                                Some(ResponseChannelItem::PooledTxIds(channel)),
+                                Ok(ResponseMessage::PooledTxIds(tx_ids)),
+                            ) => {
+                                if channel.send(tx_ids).is_err() {
+                                    debug!(
+                                        "Failed to send through the channel for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
+                            (
+                                Some(ResponseChannelItem::PooledTransactions(channel)),
+                                Ok(ResponseMessage::PooledTransactions(txs)),
+                            ) => {
+                                if channel.send(txs).is_err() {
+                                    debug!(
+                                        "Failed to send through the channel for {:?}",
+                                        request_id
+                                    );
+                                }
+                            }
+                            (
+                                Some(ResponseChannelItem::TxRejectionAck(channel)),
+                                Ok(ResponseMessage::TxRejectionAck),
+                            ) => {
+                                // Nobody waits for the acknowledgement if the send fails.
+                                let _ = channel.send(());
+                            }
 
                             (Some(_), Err(e)) => {
                                 debug!("Failed to convert IntermediateResponse into a ResponseMessage {:?} with {:?}", response, e);
@@ -661,7 +814,10 @@ impl<Codec: NetworkCodec> FuelP2PService<Codec> {
 mod tests {
     use super::FuelP2PService;
     use crate::{
-        codecs::postcard::PostcardCodec,
+        codecs::{
+            postcard::PostcardCodec,
+            ProtocolVersion,
+        },
         config::Config,
         gossipsub::{
             messages::{
@@ -669,6 +825,7 @@ mod tests {
                 GossipsubMessage,
             },
             topics::{
+                topic_name,
                 GossipTopic,
                 CON_VOTE_GOSSIP_TOPIC,
                 NEW_BLOCK_GOSSIP_TOPIC,
@@ -679,6 +836,7 @@ mod tests {
         peer_manager::PeerInfo,
         request_response::messages::{
             OutboundResponse,
+            RequestError,
             RequestMessage,
             ResponseChannelItem,
         },
@@ -706,6 +864,8 @@ mod tests {
         services::p2p::{
             GossipsubMessageAcceptance,
             Transactions,
+            TxRejection,
+            TxRejectionReason,
         },
     };
     use futures::{
@@ -716,6 +876,7 @@ mod tests {
         gossipsub::{
             error::PublishError,
             Topic,
+            TopicHash,
         },
         identity::Keypair,
         swarm::SwarmEvent,
@@ -731,6 +892,7 @@ mod tests {
         time::Duration,
     };
     use tokio::sync::{
+        broadcast,
         mpsc,
         oneshot,
         watch,
@@ -744,8 +906,13 @@ mod tests {
         p2p_config.keypair = Keypair::generate_secp256k1(); // change keypair for each Node
         let max_block_size = p2p_config.max_block_size;
 
-        let mut service =
-            FuelP2PService::new(p2p_config, PostcardCodec::new(max_block_size));
+        let (reserved_peers_updates, _) = broadcast::channel(1);
+
+        let mut service = FuelP2PService::new(
+            reserved_peers_updates,
+            p2p_config,
+            PostcardCodec::new(max_block_size),
+        );
         service.start().await.unwrap();
         service
     }
@@ -1111,6 +1278,58 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes that are on the same network but follow the chains
+    // with different chain ids, so both of them reject the connection
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_due_to_different_chain_id() {
+        use libp2p::TransportError;
+        use libp2p_swarm::DialError;
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_cannot_connect_due_to_different_chain_id");
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different chain id
+        p2p_config.chain_id = 1u64.into();
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        let mut node_a_rejected = false;
+        let mut node_b_rejected = false;
+        while !node_a_rejected || !node_b_rejected {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError {
+                        error: PendingInboundConnectionError::Transport(
+                            TransportError::Other(_)
+                        ),
+                        ..
+                    } = node_a_event {
+                        node_a_rejected = true;
+                    }
+                },
+                node_b_event = node_b.swarm.select_next_some() => {
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                    match node_b_event {
+                        SwarmEvent::ConnectionEstablished { .. } => {
+                            panic!("Node B should not connect to Node A!")
+                        }
+                        SwarmEvent::OutgoingConnectionError {
+                            error: DialError::Transport(_),
+                            ..
+                        } => {
+                            node_b_rejected = true;
+                        }
+                        _ => {}
+                    }
+                },
+            };
+        }
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1406,14 +1625,23 @@ mod tests {
     ) {
         let mut p2p_config = Config::default_initialized("gossipsub_exchanges_messages");
 
-        let selected_topic: GossipTopic = {
+        // The message is published in the topics of all protocol versions.
+        let selected_topics: Vec<TopicHash> = {
             let topic = match broadcast_request {
                 GossipsubBroadcastRequest::ConsensusVote(_) => CON_VOTE_GOSSIP_TOPIC,
                 GossipsubBroadcastRequest::NewBlock(_) => NEW_BLOCK_GOSSIP_TOPIC,
                 GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
             };
 
-            Topic::new(format!("{}/{}", topic, p2p_config.network_name))
+            p2p_config
+                .gossip_protocol_versions()
+                .into_iter()
+                .map(|version| {
+                    let topic: GossipTopic =
+                        Topic::new(topic_name(topic, &p2p_config.network_name, version));
+                    topic.hash()
+                })
+                .collect()
         };
 
         let mut message_sent = false;
@@ -1456,8 +1684,8 @@ mod tests {
                         // If it's `Ignore` or `Reject`, Node C should not receive anything
                         let msg_acceptance = to_message_acceptance(&acceptance);
                         node_b.report_message_validation_result(&message_id, peer_id, msg_acceptance);
-                        if topic_hash != selected_topic.hash() {
-                            tracing::error!("Wrong topic hash, expected: {} - actual: {}", selected_topic.hash(), topic_hash);
+                        if !selected_topics.contains(&topic_hash) {
+                            tracing::error!("Wrong topic hash, expected: {:?} - actual: {}", selected_topics, topic_hash);
                             panic!("Wrong Topic");
                         }
 
@@ -1632,7 +1860,7 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::RequestMessage{ request_id, request_message: received_request_message }) = &node_b_event {
+                    if let Some(FuelP2PEvent::RequestMessage{ request_id, request_message: received_request_message, .. }) = &node_b_event {
                         match received_request_message {
                             RequestMessage::Block(_) => {
                                 let block = Block::new(PartialBlockHeader::default(), (0..5).map(|_| Transaction::default_test_tx()).collect(), &[]);
@@ -1654,6 +1882,7 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, OutboundResponse::Transactions(Some(Arc::new(transactions))));
                             }
+                            _ => {}
                         }
                     }
 
@@ -1754,4 +1983,139 @@ mod tests {
             };
         }
     }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_upgraded_peer() {
+        let mut p2p_config =
+            Config::default_initialized("request_response_works_with_upgraded_peer");
+
+        // Node A speaks the latest and the previous versions of the protocols
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B speaks only the latest version
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        p2p_config.support_previous_protocol_version = false;
+        let mut node_b = build_service_from_config(p2p_config.clone()).await;
+
+        let (tx_test_end, mut rx_test_end) = mpsc::channel::<bool>(1);
+        let mut request_sent = false;
+
+        loop {
+            tokio::select! {
+                message_received = rx_test_end.recv() => {
+                    assert!(message_received.unwrap(), "Received incorrect or missing message");
+                    break;
+                }
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerInfoUpdated { peer_id, .. }) = node_a_event {
+                        if node_a.peer_manager.get_peer_info(&peer_id).is_some() && !request_sent {
+                            request_sent = true;
+                            let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                            let request = RequestMessage::Block(0.into());
+                            assert!(node_a.send_request_msg(None, request, ResponseChannelItem::Block(tx_orchestrator)).is_ok());
+                            let tx_test_end = tx_test_end.clone();
+
+                            tokio::spawn(async move {
+                                let response = rx_orchestrator.await;
+                                let _ = tx_test_end.send(matches!(response, Ok(Some(_)))).await;
+                            });
+                        }
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::RequestMessage { request_id, .. }) = node_b_event {
+                        let sealed_block = SealedBlock {
+                            entity: Block::default(),
+                            consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+                        };
+                        let _ = node_b.send_response_msg(request_id, OutboundResponse::Block(Some(Arc::new(sealed_block))));
+                    }
+                }
+            };
+        }
+    }
+
+    #[test]
+    fn gossip_is_published_once_until_its_format_changes() {
+        let p2p_config = Config::default_initialized("gossip_is_published_once");
+
+        assert_eq!(
+            p2p_config.protocol_versions(),
+            vec![ProtocolVersion::V2, ProtocolVersion::V1]
+        );
+        assert_eq!(
+            p2p_config.gossip_protocol_versions(),
+            vec![ProtocolVersion::V1]
+        );
+    }
+
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_peer_speaking_only_previous_version() {
+        let mut p2p_config = Config::default_initialized(
+            "request_response_works_with_peer_speaking_only_previous_version",
+        );
+
+        // Node A speaks the latest and the previous versions of the protocols
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B isn't upgraded yet and speaks only the previous version
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        p2p_config.only_protocol_version = Some(ProtocolVersion::V1);
+        let mut node_b = build_service_from_config(p2p_config.clone()).await;
+
+        let (tx_test_end, mut rx_test_end) = mpsc::channel::<bool>(1);
+        let mut request_sent = false;
+
+        loop {
+            tokio::select! {
+                message_received = rx_test_end.recv() => {
+                    assert!(message_received.unwrap(), "Received incorrect or missing message");
+                    break;
+                }
+                node_a_event = node_a.next_event() => {
+                    if let Some(FuelP2PEvent::PeerInfoUpdated { peer_id, .. }) = node_a_event {
+                        let identified = node_a
+                            .peer_manager
+                            .get_peer_info(&peer_id)
+                            .map_or(false, |info| !info.protocols.is_empty());
+                        if identified && !request_sent {
+                            request_sent = true;
+
+                            // Node B can't decode the requests of the latest version.
+                            let (tx_ack, _) = oneshot::channel();
+                            let rejection = RequestMessage::TxRejection(TxRejection {
+                                tx_id: Default::default(),
+                                reason: TxRejectionReason::Invalid,
+                                message: "Invalid".to_string(),
+                            });
+                            let result = node_a.send_request_msg(Some(peer_id), rejection, ResponseChannelItem::TxRejectionAck(tx_ack));
+                            assert!(matches!(result, Err(RequestError::UnsupportedProtocol(ProtocolVersion::V2))));
+
+                            let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                            let request = RequestMessage::Block(0.into());
+                            assert!(node_a.send_request_msg(Some(peer_id), request, ResponseChannelItem::Block(tx_orchestrator)).is_ok());
+                            let tx_test_end = tx_test_end.clone();
+
+                            tokio::spawn(async move {
+                                let response = rx_orchestrator.await;
+                                let _ = tx_test_end.send(matches!(response, Ok(Some(_)))).await;
+                            });
+                        }
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::RequestMessage { request_id, request_message, .. }) = node_b_event {
+                        assert_eq!(request_message, RequestMessage::Block(0.into()));
+                        let sealed_block = SealedBlock {
+                            entity: Block::default(),
+                            consensus: Consensus::PoA(PoAConsensus::new(Default::default())),
+                        };
+                        let _ = node_b.send_response_msg(request_id, OutboundResponse::Block(Some(Arc::new(sealed_block))));
+                    }
+                }
+            };
+        }
+    }
 }
@@ -23,6 +23,7 @@ use std::{
         RwLock,
     },
 };
+use tokio::time::Instant;
 use tracing::{
     debug,
     info,
@@ -43,8 +44,13 @@ const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    /// The protocols supported by the peer, reported by the identify protocol.
+    pub protocols: Vec<String>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
+    /// The time of the first connection with the peer.
+    pub connected_at: Instant,
+    pub bandwidth: PeerBandwidth,
 }
 
 impl PeerInfo {
@@ -52,12 +58,23 @@ impl PeerInfo {
         Self {
             peer_addresses: HashSet::new(),
             client_version: None,
+            protocols: Vec::new(),
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
+            connected_at: Instant::now(),
+            bandwidth: PeerBandwidth::default(),
         }
     }
 }
 
+/// The number of bytes of the gossiped messages and the request-response payloads
+/// exchanged with the peer. The protocol overhead is not counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PeerBandwidth {
+    pub inbound_bytes: u64,
+    pub outbound_bytes: u64,
+}
+
 /// Manages Peers and their events
 #[derive(Debug)]
 pub struct PeerManager {
@@ -72,13 +89,11 @@ pub struct PeerManager {
 
 impl PeerManager {
     pub fn new(
+        reserved_peers_updates: tokio::sync::broadcast::Sender<usize>,
         reserved_peers: HashSet<PeerId>,
         connection_state: Arc<RwLock<ConnectionState>>,
         max_non_reserved_peers: usize,
     ) -> Self {
-        let (reserved_peers_updates, _) =
-            tokio::sync::broadcast::channel(1 + reserved_peers.len() * 2);
-
         Self {
             score_config: ScoreConfig::default(),
             non_reserved_connected_peers: HashMap::with_capacity(max_non_reserved_peers),
@@ -144,10 +159,32 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        protocols: Vec<String>,
     ) {
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
         insert_peer_addresses(peers, peer_id, addresses);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.protocols = protocols;
+        }
+    }
+
+    /// Accounts the bytes received from the peer.
+    pub fn record_inbound_bytes(&mut self, peer_id: &PeerId, bytes: usize) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.bandwidth.inbound_bytes =
+                peer.bandwidth.inbound_bytes.saturating_add(bytes as u64);
+        }
+    }
+
+    /// Accounts the bytes sent to the peer.
+    pub fn record_outbound_bytes(&mut self, peer_id: &PeerId, bytes: usize) {
+        let peers = self.get_assigned_peer_table_mut(peer_id);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.bandwidth.outbound_bytes =
+                peer.bandwidth.outbound_bytes.saturating_add(bytes as u64);
+        }
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
@@ -188,6 +225,11 @@ impl PeerManager {
             .chain(self.reserved_connected_peers.keys())
     }
 
+    /// Returns `true` if the peer is one of the reserved peers.
+    pub fn is_reserved(&self, peer_id: &PeerId) -> bool {
+        self.reserved_peers.contains(peer_id)
+    }
+
     pub fn get_peer_info(&self, peer_id: &PeerId) -> Option<&PeerInfo> {
         if self.reserved_peers.contains(peer_id) {
             return self.reserved_connected_peers.get(peer_id)
@@ -418,7 +460,10 @@ mod tests {
     ) -> PeerManager {
         let connection_state = ConnectionState::new();
 
+        let (reserved_peers_updates, _) = tokio::sync::broadcast::channel(1);
+
         PeerManager::new(
+            reserved_peers_updates,
             reserved_peers.into_iter().collect(),
             connection_state,
             max_non_reserved_peers,
@@ -500,4 +545,34 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn peer_info_records_protocols_and_bandwidth() {
+        let mut peer_manager = initialize_peer_manager(vec![], 5);
+        let peer_id = PeerId::random();
+        peer_manager.handle_initial_connection(&peer_id, vec![]);
+
+        peer_manager.handle_peer_identified(
+            &peer_id,
+            vec![],
+            "fuel-core/0.20.4".to_string(),
+            vec!["/fuel/req_res/0.0.1".to_string()],
+        );
+        peer_manager.record_inbound_bytes(&peer_id, 100);
+        peer_manager.record_inbound_bytes(&peer_id, 20);
+        peer_manager.record_outbound_bytes(&peer_id, 7);
+        // The bytes of the disconnected peers are ignored.
+        peer_manager.record_inbound_bytes(&PeerId::random(), 1000);
+
+        let info = peer_manager.get_peer_info(&peer_id).unwrap();
+        assert_eq!(info.client_version.as_deref(), Some("fuel-core/0.20.4"));
+        assert_eq!(info.protocols, vec!["/fuel/req_res/0.0.1".to_string()]);
+        assert_eq!(
+            info.bandwidth,
+            PeerBandwidth {
+                inbound_bytes: 120,
+                outbound_bytes: 7,
+            }
+        );
+    }
 }
@@ -70,6 +70,7 @@ pub enum PeerReportEvent {
         peer_id: PeerId,
         agent_version: String,
         addresses: Vec<Multiaddr>,
+        protocols: Vec<String>,
     },
     PeerInfoUpdated {
         peer_id: PeerId,
@@ -383,6 +384,7 @@ impl FromAction<Identify> for PeerReportBehaviour {
                             protocol_version,
                             agent_version,
                             mut listen_addrs,
+                            protocols,
                             ..
                         },
                 } => {
@@ -399,6 +401,7 @@ impl FromAction<Identify> for PeerReportBehaviour {
                         peer_id,
                         agent_version,
                         addresses: listen_addrs,
+                        protocols,
                     };
 
                     Some(NetworkBehaviourAction::GenerateEvent(event))
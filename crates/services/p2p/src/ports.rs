@@ -5,6 +5,10 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::Transactions,
 };
@@ -30,9 +34,21 @@ pub trait P2pDb: Send + Sync {
         &self,
         block_height_range: Range<u32>,
     ) -> StorageResult<Option<Vec<Transactions>>>;
+
+    fn latest_block_height(&self) -> StorageResult<BlockHeight>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
     /// Creates a stream of next block heights
     fn next_block_height(&self) -> BoxStream<BlockHeight>;
 }
+
+/// The pool of the transactions reconciled with the peers.
+pub trait TxPool: Send + Sync {
+    /// Returns the ids of the pooled transactions, no more than `max_txs`.
+    fn get_tx_ids(&self, max_txs: usize) -> Vec<TxId>;
+
+    /// Returns the pooled transactions by their ids, or `None` if the transaction
+    /// is not in the pool.
+    fn get_full_txs(&self, tx_ids: Vec<TxId>) -> Vec<Option<Transaction>>;
+}
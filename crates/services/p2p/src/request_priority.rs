@@ -0,0 +1,184 @@
+//! Prioritizes the inbound requests served from the database. The requests of the
+//! reserved peers, like the validators, are served before the requests of the unknown
+//! peers, and each class has its own limit of the concurrently served requests. The bulk
+//! historical sync of the unknown peers can't starve the reserved peers or the block
+//! production of the node.
+
+use std::collections::VecDeque;
+
+/// The class of the inbound request defined by the requesting peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RequestClass {
+    /// The request of the reserved peer.
+    Reserved,
+    /// The request of the peer that isn't reserved.
+    Bulk,
+}
+
+impl RequestClass {
+    pub(crate) fn of_peer(is_reserved: bool) -> Self {
+        if is_reserved {
+            RequestClass::Reserved
+        } else {
+            RequestClass::Bulk
+        }
+    }
+}
+
+/// The limits of the served requests.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RequestLimits {
+    /// The maximum number of the concurrently served requests of the reserved peers.
+    pub max_concurrent_reserved: usize,
+    /// The maximum number of the concurrently served requests of the other peers.
+    pub max_concurrent_bulk: usize,
+    /// The maximum number of the queued requests of each class.
+    pub max_queued: usize,
+}
+
+struct ClassQueue<T> {
+    queue: VecDeque<T>,
+    in_flight: usize,
+    max_in_flight: usize,
+}
+
+impl<T> ClassQueue<T> {
+    fn new(max_in_flight: usize) -> Self {
+        Self {
+            queue: VecDeque::new(),
+            in_flight: 0,
+            // The class is never served with the zero limit.
+            max_in_flight: max_in_flight.max(1),
+        }
+    }
+
+    fn next(&mut self) -> Option<T> {
+        if self.in_flight >= self.max_in_flight {
+            return None
+        }
+        let request = self.queue.pop_front()?;
+        self.in_flight += 1;
+        Some(request)
+    }
+}
+
+/// The queues of the inbound requests waiting to be served.
+pub(crate) struct PrioritizedRequests<T> {
+    reserved: ClassQueue<T>,
+    bulk: ClassQueue<T>,
+    max_queued: usize,
+}
+
+impl<T> PrioritizedRequests<T> {
+    pub(crate) fn new(limits: RequestLimits) -> Self {
+        Self {
+            reserved: ClassQueue::new(limits.max_concurrent_reserved),
+            bulk: ClassQueue::new(limits.max_concurrent_bulk),
+            max_queued: limits.max_queued,
+        }
+    }
+
+    fn class_mut(&mut self, class: RequestClass) -> &mut ClassQueue<T> {
+        match class {
+            RequestClass::Reserved => &mut self.reserved,
+            RequestClass::Bulk => &mut self.bulk,
+        }
+    }
+
+    /// Queues the `request` of the `class`. Returns the request back
+    /// if the queue of the class is full.
+    pub(crate) fn push(&mut self, class: RequestClass, request: T) -> Result<(), T> {
+        let max_queued = self.max_queued;
+        let queue = &mut self.class_mut(class).queue;
+        if queue.len() >= max_queued {
+            return Err(request)
+        }
+        queue.push_back(request);
+        Ok(())
+    }
+
+    /// Takes the next request to serve, the requests of the reserved peers go first.
+    /// Returns `None` if there are no queued requests, or the classes with the queued
+    /// requests reached their limits of the concurrently served requests.
+    pub(crate) fn next(&mut self) -> Option<(RequestClass, T)> {
+        if let Some(request) = self.reserved.next() {
+            return Some((RequestClass::Reserved, request))
+        }
+        self.bulk
+            .next()
+            .map(|request| (RequestClass::Bulk, request))
+    }
+
+    /// Marks the request of the `class` taken by [`Self::next`] as served.
+    pub(crate) fn finish(&mut self, class: RequestClass) {
+        let queue = self.class_mut(class);
+        queue.in_flight = queue.in_flight.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requests(
+        max_concurrent_reserved: usize,
+        max_concurrent_bulk: usize,
+    ) -> PrioritizedRequests<u32> {
+        PrioritizedRequests::new(RequestLimits {
+            max_concurrent_reserved,
+            max_concurrent_bulk,
+            max_queued: 3,
+        })
+    }
+
+    #[test]
+    fn requests_of_reserved_peers_are_served_first() {
+        let mut requests = requests(10, 10);
+        requests.push(RequestClass::Bulk, 1).unwrap();
+        requests.push(RequestClass::Bulk, 2).unwrap();
+        requests.push(RequestClass::Reserved, 3).unwrap();
+
+        assert_eq!(requests.next(), Some((RequestClass::Reserved, 3)));
+        assert_eq!(requests.next(), Some((RequestClass::Bulk, 1)));
+        assert_eq!(requests.next(), Some((RequestClass::Bulk, 2)));
+        assert_eq!(requests.next(), None);
+    }
+
+    #[test]
+    fn classes_have_separate_concurrency_limits() {
+        let mut requests = requests(2, 1);
+        for request in 0..3 {
+            requests.push(RequestClass::Bulk, request).unwrap();
+            requests.push(RequestClass::Reserved, 10 + request).unwrap();
+        }
+
+        assert_eq!(requests.next(), Some((RequestClass::Reserved, 10)));
+        assert_eq!(requests.next(), Some((RequestClass::Reserved, 11)));
+        assert_eq!(requests.next(), Some((RequestClass::Bulk, 0)));
+        // Both classes reached their limits.
+        assert_eq!(requests.next(), None);
+
+        requests.finish(RequestClass::Bulk);
+        assert_eq!(requests.next(), Some((RequestClass::Bulk, 1)));
+        assert_eq!(requests.next(), None);
+
+        requests.finish(RequestClass::Reserved);
+        assert_eq!(requests.next(), Some((RequestClass::Reserved, 12)));
+    }
+
+    #[test]
+    fn requests_above_the_queue_limit_are_rejected() {
+        let mut requests = requests(1, 1);
+        for request in 0..3 {
+            requests.push(RequestClass::Bulk, request).unwrap();
+        }
+
+        assert_eq!(requests.push(RequestClass::Bulk, 3), Err(3));
+        // The reserved peers have their own queue.
+        assert_eq!(requests.push(RequestClass::Reserved, 4), Ok(()));
+
+        assert_eq!(requests.next(), Some((RequestClass::Reserved, 4)));
+        assert_eq!(requests.next(), Some((RequestClass::Bulk, 0)));
+        assert_eq!(requests.push(RequestClass::Bulk, 3), Ok(()));
+    }
+}
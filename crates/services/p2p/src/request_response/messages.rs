@@ -3,13 +3,21 @@ use std::{
     sync::Arc,
 };
 
+use crate::codecs::ProtocolVersion;
 use fuel_core_types::{
     blockchain::{
         SealedBlock,
         SealedBlockHeader,
     },
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
-    services::p2p::Transactions,
+    services::p2p::{
+        Transactions,
+        TxRejection,
+    },
 };
 use libp2p::PeerId;
 use serde::{
@@ -19,10 +27,18 @@ use serde::{
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &[u8] = b"/fuel/req_res/0.0.1";
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID_V1: &[u8] = b"/fuel/req_res/0.0.1";
+pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID_V2: &[u8] = b"/fuel/req_res/0.0.2";
+
+/// The max number of the pooled transactions in one request or response.
+pub const MAX_POOLED_TXS_PER_REQUEST: usize = 1024;
+
+/// The max length of the description of the reason in the [`TxRejection`].
+pub const MAX_TX_REJECTION_MESSAGE_LEN: usize = 256;
 
 /// Max Size in Bytes of the Request Message
-pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>();
+pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>()
+    + MAX_POOLED_TXS_PER_REQUEST * core::mem::size_of::<TxId>();
 
 // Peer receives a `RequestMessage`.
 // It prepares a response in form of `OutboundResponse`
@@ -39,6 +55,25 @@ pub enum RequestMessage {
     Block(BlockHeight),
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    /// Requests the ids of the transactions in the pool of the peer.
+    PooledTxIds,
+    /// Requests the transactions from the pool of the peer by their ids.
+    /// The peer responds with the prefix of them that fits into the response.
+    PooledTransactions(Vec<TxId>),
+    /// Notifies the peer that gossiped the transaction about its rejection.
+    TxRejection(TxRejection),
+}
+
+impl RequestMessage {
+    /// Returns the first version of the request-response protocol able to decode
+    /// the request. The peers speaking only the older versions fail to decode it,
+    /// so the request is sent only to the peers that negotiated this version.
+    pub fn min_protocol_version(&self) -> ProtocolVersion {
+        match self {
+            RequestMessage::TxRejection(_) => ProtocolVersion::V2,
+            _ => ProtocolVersion::V1,
+        }
+    }
 }
 
 /// Final Response Message that p2p service sends to the Orchestrator
@@ -47,6 +82,9 @@ pub enum ResponseMessage {
     SealedBlock(Box<Option<SealedBlock>>),
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    PooledTxIds(Option<Vec<TxId>>),
+    PooledTransactions(Option<Vec<Option<Transaction>>>),
+    TxRejectionAck,
 }
 
 /// Holds oneshot channels for specific responses
@@ -55,6 +93,9 @@ pub enum ResponseChannelItem {
     Block(oneshot::Sender<Option<SealedBlock>>),
     SealedHeaders(oneshot::Sender<(PeerId, Option<Vec<SealedBlockHeader>>)>),
     Transactions(oneshot::Sender<Option<Vec<Transactions>>>),
+    PooledTxIds(oneshot::Sender<Option<Vec<TxId>>>),
+    PooledTransactions(oneshot::Sender<Option<Vec<Option<Transaction>>>>),
+    TxRejectionAck(oneshot::Sender<()>),
 }
 
 /// Response that is sent over the wire
@@ -64,6 +105,25 @@ pub enum NetworkResponse {
     Block(Option<Vec<u8>>),
     Headers(Option<Vec<u8>>),
     Transactions(Option<Vec<u8>>),
+    PooledTxIds(Option<Vec<u8>>),
+    PooledTransactions(Option<Vec<u8>>),
+    TxRejectionAck,
+}
+
+impl NetworkResponse {
+    /// The size of the encoded payload of the response.
+    pub fn payload_len(&self) -> usize {
+        match self {
+            NetworkResponse::Block(payload)
+            | NetworkResponse::Headers(payload)
+            | NetworkResponse::Transactions(payload)
+            | NetworkResponse::PooledTxIds(payload)
+            | NetworkResponse::PooledTransactions(payload) => {
+                payload.as_ref().map_or(0, Vec::len)
+            }
+            NetworkResponse::TxRejectionAck => 0,
+        }
+    }
 }
 
 /// Initial state of the `ResponseMessage` prior to having its inner value serialized
@@ -73,12 +133,17 @@ pub enum OutboundResponse {
     Block(Option<Arc<SealedBlock>>),
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Arc<Vec<Transactions>>>),
+    PooledTxIds(Option<Arc<Vec<TxId>>>),
+    PooledTransactions(Option<Arc<Vec<Option<Transaction>>>>),
+    TxRejectionAck,
 }
 
 #[derive(Debug, Error)]
 pub enum RequestError {
     #[error("Not currently connected to any peers")]
     NoPeersConnected,
+    #[error("The peer doesn't speak the request-response protocol {0}")]
+    UnsupportedProtocol(ProtocolVersion),
 }
 
 #[derive(Debug, Eq, PartialEq, Error)]
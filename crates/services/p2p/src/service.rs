@@ -16,11 +16,19 @@ use crate::{
     ports::{
         BlockHeightImporter,
         P2pDb,
+        TxPool,
+    },
+    request_priority::{
+        PrioritizedRequests,
+        RequestClass,
+        RequestLimits,
     },
     request_response::messages::{
         OutboundResponse,
         RequestMessage,
         ResponseChannelItem,
+        MAX_POOLED_TXS_PER_REQUEST,
+        MAX_TX_REJECTION_MESSAGE_LEN,
     },
 };
 use anyhow::anyhow;
@@ -38,7 +46,10 @@ use fuel_core_types::{
         SealedBlock,
         SealedBlockHeader,
     },
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
     services::p2p::{
         peer_reputation::{
@@ -50,12 +61,15 @@ use fuel_core_types::{
         GossipsubMessageAcceptance,
         GossipsubMessageInfo,
         PeerId as FuelPeerId,
+        SyncProgress,
         TransactionGossipData,
         Transactions,
+        TxRejection,
     },
 };
 use futures::{
     future::BoxFuture,
+    stream::FuturesUnordered,
     StreamExt,
 };
 use libp2p::{
@@ -73,6 +87,7 @@ use tokio::{
         broadcast,
         mpsc,
         oneshot,
+        watch,
     },
     time::{
         Duration,
@@ -81,15 +96,19 @@ use tokio::{
 };
 use tracing::warn;
 
-pub type Service<D> = ServiceRunner<Task<FuelP2PService<PostcardCodec>, D, SharedState>>;
+pub type Service<D, T> =
+    ServiceRunner<Task<FuelP2PService<PostcardCodec>, D, SharedState, T>>;
 
-enum TaskRequest {
+pub enum TaskRequest {
     // Broadcast requests to p2p network
     BroadcastTransaction(Arc<Transaction>),
     BroadcastBlock(Arc<Block>),
     BroadcastVote(Arc<ConsensusVote>),
     // Request to get one-off data from p2p network
     GetPeerIds(oneshot::Sender<Vec<PeerId>>),
+    GetAllPeerInfo {
+        channel: oneshot::Sender<Vec<(PeerId, PeerInfo)>>,
+    },
     GetBlock {
         height: BlockHeight,
         channel: oneshot::Sender<Option<SealedBlock>>,
@@ -103,6 +122,19 @@ enum TaskRequest {
         from_peer: PeerId,
         channel: oneshot::Sender<Option<Vec<Transactions>>>,
     },
+    GetPooledTxIds {
+        from_peer: PeerId,
+        channel: oneshot::Sender<Option<Vec<TxId>>>,
+    },
+    GetPooledTransactions {
+        tx_ids: Vec<TxId>,
+        from_peer: PeerId,
+        channel: oneshot::Sender<Option<Vec<Option<Transaction>>>>,
+    },
+    SendTxRejection {
+        rejection: TxRejection,
+        to_peer: PeerId,
+    },
     // Responds back to the p2p network
     RespondWithGossipsubMessageReport((GossipsubMessageInfo, GossipsubMessageAcceptance)),
     RespondWithPeerReport {
@@ -110,6 +142,9 @@ enum TaskRequest {
         score: AppScore,
         reporting_service: &'static str,
     },
+    BanPeer {
+        peer_id: PeerId,
+    },
 }
 
 impl Debug for TaskRequest {
@@ -128,6 +163,7 @@ pub trait TaskP2PService: Send {
     fn get_peer_ids(&self) -> Vec<PeerId>;
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
     fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn is_reserved_peer(&self, peer_id: &PeerId) -> bool;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -160,7 +196,11 @@ pub trait TaskP2PService: Send {
         reporting_service: &str,
     ) -> anyhow::Result<()>;
 
+    fn ban_peer(&mut self, peer_id: PeerId) -> anyhow::Result<()>;
+
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
+
+    fn set_tx_gossip_subscription(&mut self, subscribe: bool) -> anyhow::Result<()>;
 }
 
 impl TaskP2PService for FuelP2PService<PostcardCodec> {
@@ -176,6 +216,10 @@ impl TaskP2PService for FuelP2PService<PostcardCodec> {
         self.peer_manager().get_peer_id_with_height(height)
     }
 
+    fn is_reserved_peer(&self, peer_id: &PeerId) -> bool {
+        self.peer_manager().is_reserved(peer_id)
+    }
+
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
         Box::pin(self.next_event())
     }
@@ -226,10 +270,19 @@ impl TaskP2PService for FuelP2PService<PostcardCodec> {
         Ok(())
     }
 
+    fn ban_peer(&mut self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.ban_peer(peer_id);
+        Ok(())
+    }
+
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()> {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn set_tx_gossip_subscription(&mut self, subscribe: bool) -> anyhow::Result<()> {
+        self.set_tx_gossip_subscription(subscribe)
+    }
 }
 
 pub trait Broadcast: Send {
@@ -246,6 +299,19 @@ pub trait Broadcast: Send {
     ) -> anyhow::Result<()>;
 
     fn tx_broadcast(&self, transaction: TransactionGossipData) -> anyhow::Result<()>;
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()>;
+
+    fn tx_rejection_broadcast(
+        &self,
+        peer_id: FuelPeerId,
+        rejection: TxRejection,
+    ) -> anyhow::Result<()>;
+
+    fn sync_progress_broadcast(
+        &self,
+        progress: Option<SyncProgress>,
+    ) -> anyhow::Result<()>;
 }
 
 impl Broadcast for SharedState {
@@ -270,24 +336,130 @@ impl Broadcast for SharedState {
         self.tx_broadcast.send(transaction)?;
         Ok(())
     }
+
+    fn new_peer_broadcast(&self, peer_id: FuelPeerId) -> anyhow::Result<()> {
+        self.new_peers_broadcast.send(peer_id)?;
+        Ok(())
+    }
+
+    fn tx_rejection_broadcast(
+        &self,
+        peer_id: FuelPeerId,
+        rejection: TxRejection,
+    ) -> anyhow::Result<()> {
+        self.tx_rejections_broadcast.send((peer_id, rejection))?;
+        Ok(())
+    }
+
+    fn sync_progress_broadcast(
+        &self,
+        progress: Option<SyncProgress>,
+    ) -> anyhow::Result<()> {
+        self.sync_progress.send_replace(progress);
+        Ok(())
+    }
 }
 
 /// Orchestrates various p2p-related events between the inner `P2pService`
 /// and the top level `NetworkService`.
-pub struct Task<P, D, B> {
+pub struct Task<P, D, B, T> {
     p2p_service: P,
     db: Arc<D>,
+    tx_pool: T,
     next_block_height: BoxStream<BlockHeight>,
     /// Receive internal Task Requests
     request_receiver: mpsc::Receiver<TaskRequest>,
     broadcast: B,
     max_headers_per_request: u32,
+    /// The max size of the response accepted by the codec of the peers.
+    max_response_size: usize,
     // milliseconds wait time between peer heartbeat reputation checks
     heartbeat_check_interval: Duration,
     heartbeat_max_avg_interval: Duration,
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    tx_gossip_sync_threshold: Option<u32>,
+    /// The height of the latest block of the node.
+    block_height: BlockHeight,
+    /// `true` if the node isn't subscribed to the transaction gossip.
+    tx_gossip_deferred: bool,
+    /// The inbound requests waiting to be served from the database.
+    inbound_requests: PrioritizedRequests<(RequestId, DbRequest)>,
+    /// The inbound requests being served from the database.
+    served_requests: FuturesUnordered<BoxFuture<'static, ServedRequest>>,
+}
+
+/// The bytes of the response taken by the encoding around the pooled transactions.
+const POOLED_TXS_RESPONSE_OVERHEAD: usize = 64;
+
+/// Returns the prefix of the pooled `txs` that fits into the response of
+/// the `max_response_size`. The requester asks for the rest of them again.
+fn fit_pooled_txs(
+    txs: Vec<Option<Transaction>>,
+    max_response_size: usize,
+) -> Vec<Option<Transaction>> {
+    let mut size = POOLED_TXS_RESPONSE_OVERHEAD;
+    let mut fitting = Vec::with_capacity(txs.len());
+    for tx in txs {
+        let tx_size = postcard::to_stdvec(&tx).map_or(usize::MAX, |bytes| bytes.len());
+        size = size.saturating_add(tx_size);
+        if size > max_response_size {
+            break
+        }
+        fitting.push(tx);
+    }
+    fitting
+}
+
+/// The inbound request served from the database.
+enum DbRequest {
+    Block(BlockHeight),
+    Transactions(Range<u32>),
+    SealedHeaders(Range<u32>),
+}
+
+impl DbRequest {
+    /// Returns the response sent when the request can't be served.
+    fn empty_response(&self) -> OutboundResponse {
+        match self {
+            DbRequest::Block(_) => OutboundResponse::Block(None),
+            DbRequest::Transactions(_) => OutboundResponse::Transactions(None),
+            DbRequest::SealedHeaders(_) => OutboundResponse::SealedHeaders(None),
+        }
+    }
+
+    fn serve<D: P2pDb + ?Sized>(self, db: &D) -> anyhow::Result<OutboundResponse> {
+        let response = match self {
+            DbRequest::Block(block_height) => {
+                let block = db.get_sealed_block(&block_height).map_err(|e| {
+                    anyhow!("Failed to get block at height {block_height:?}: {e:?}")
+                })?;
+                OutboundResponse::Block(block.map(Arc::new))
+            }
+            DbRequest::Transactions(range) => {
+                let transactions = db.get_transactions(range.clone()).map_err(|e| {
+                    anyhow!("Failed to get transactions for range {range:?}: {e:?}")
+                })?;
+                OutboundResponse::Transactions(transactions.map(Arc::new))
+            }
+            DbRequest::SealedHeaders(range) => {
+                let headers = db.get_sealed_headers(range.clone()).map_err(|e| {
+                    anyhow!("Failed to get sealed headers for range {range:?}: {e:?}")
+                })?;
+                OutboundResponse::SealedHeaders(Some(headers))
+            }
+        };
+        Ok(response)
+    }
+}
+
+struct ServedRequest {
+    class: RequestClass,
+    request_id: RequestId,
+    result: anyhow::Result<OutboundResponse>,
+    /// The response sent if the request failed.
+    empty_response: OutboundResponse,
 }
 
 #[derive(Clone)]
@@ -296,11 +468,14 @@ pub struct HeartbeatPeerReputationConfig {
     low_heartbeat_frequency_penalty: AppScore,
 }
 
-impl<D> Task<FuelP2PService<PostcardCodec>, D, SharedState> {
+impl<D, T> Task<FuelP2PService<PostcardCodec>, D, SharedState, T> {
     pub fn new<B: BlockHeightImporter>(
         config: Config,
+        shared_state: SharedState,
+        request_receiver: mpsc::Receiver<TaskRequest>,
         db: Arc<D>,
         block_importer: Arc<B>,
+        tx_pool: T,
     ) -> Self {
         let Config {
             max_block_size,
@@ -308,12 +483,12 @@ impl<D> Task<FuelP2PService<PostcardCodec>, D, SharedState> {
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            tx_gossip_sync_threshold,
+            max_concurrent_reserved_requests,
+            max_concurrent_bulk_requests,
+            max_queued_requests,
             ..
         } = config;
-        let (request_sender, request_receiver) = mpsc::channel(100);
-        let (tx_broadcast, _) = broadcast::channel(100);
-        let (block_height_broadcast, _) = broadcast::channel(100);
-
         // Hardcoded for now, but left here to be configurable in the future.
         // TODO: https://github.com/FuelLabs/fuel-core/issues/1340
         let heartbeat_peer_reputation_config = HeartbeatPeerReputationConfig {
@@ -322,34 +497,43 @@ impl<D> Task<FuelP2PService<PostcardCodec>, D, SharedState> {
         };
 
         let next_block_height = block_importer.next_block_height();
-        let p2p_service = FuelP2PService::new(config, PostcardCodec::new(max_block_size));
-
-        let reserved_peers_broadcast =
-            p2p_service.peer_manager().reserved_peers_updates();
+        let p2p_service = FuelP2PService::new(
+            shared_state.reserved_peers_broadcast.clone(),
+            config,
+            PostcardCodec::new(max_block_size),
+        );
 
         let next_check_time = Instant::now() + heartbeat_check_interval;
 
         Self {
             p2p_service,
             db,
+            tx_pool,
             request_receiver,
             next_block_height,
-            broadcast: SharedState {
-                request_sender,
-                tx_broadcast,
-                reserved_peers_broadcast,
-                block_height_broadcast,
-            },
+            broadcast: shared_state,
             max_headers_per_request,
+            max_response_size: max_block_size,
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            tx_gossip_sync_threshold,
+            block_height: BlockHeight::default(),
+            // The gossipsub is built without the subscription to the transaction
+            // gossip if the threshold is set.
+            tx_gossip_deferred: tx_gossip_sync_threshold.is_some(),
+            inbound_requests: PrioritizedRequests::new(RequestLimits {
+                max_concurrent_reserved: max_concurrent_reserved_requests,
+                max_concurrent_bulk: max_concurrent_bulk_requests,
+                max_queued: max_queued_requests,
+            }),
+            served_requests: FuturesUnordered::new(),
         }
     }
 }
-impl<P: TaskP2PService, D, B: Broadcast> Task<P, D, B> {
+impl<P: TaskP2PService, D, B: Broadcast, T> Task<P, D, B, T> {
     fn peer_heartbeat_reputation_checks(&self) -> anyhow::Result<()> {
         for (peer_id, peer_info) in self.p2p_service.get_all_peer_info() {
             if peer_info.heartbeat_data.duration_since_last_heartbeat()
@@ -390,6 +574,46 @@ impl<P: TaskP2PService, D, B: Broadcast> Task<P, D, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    /// Defers the transaction gossip while the node is further than the threshold
+    /// behind the median height reported by the peers. The heights are unverified,
+    /// so the median doesn't let a minority of peers defer the gossip forever.
+    fn update_sync_progress(&mut self) -> anyhow::Result<()> {
+        let Some(threshold) = self.tx_gossip_sync_threshold else {
+            return Ok(())
+        };
+        let current = self.block_height;
+        let mut heights: Vec<_> = self
+            .p2p_service
+            .get_all_peer_info()
+            .into_iter()
+            .filter_map(|(_, peer_info)| peer_info.heartbeat_data.block_height)
+            .collect();
+        heights.sort();
+        let progress = heights
+            .get(heights.len().saturating_sub(1) / 2)
+            .copied()
+            .filter(|target| {
+                u32::from(*target) > u32::from(current).saturating_add(threshold)
+            })
+            .map(|target| SyncProgress { current, target });
+
+        let deferred = progress.is_some();
+        if deferred != self.tx_gossip_deferred {
+            self.p2p_service.set_tx_gossip_subscription(!deferred)?;
+            self.tx_gossip_deferred = deferred;
+            if let Some(progress) = progress {
+                tracing::info!(
+                    "The node is syncing from {} to {}, the transaction gossip is deferred",
+                    progress.current,
+                    progress.target
+                );
+            } else {
+                tracing::info!("The node caught up with the network, subscribed to the transaction gossip");
+            }
+        }
+        self.broadcast.sync_progress_broadcast(progress)
+    }
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -398,14 +622,15 @@ fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
 }
 
 #[async_trait::async_trait]
-impl<D> RunnableService for Task<FuelP2PService<PostcardCodec>, D, SharedState>
+impl<D, T> RunnableService for Task<FuelP2PService<PostcardCodec>, D, SharedState, T>
 where
     Self: RunnableTask,
+    D: P2pDb,
 {
     const NAME: &'static str = "P2P";
 
     type SharedData = SharedState;
-    type Task = Task<FuelP2PService<PostcardCodec>, D, SharedState>;
+    type Task = Task<FuelP2PService<PostcardCodec>, D, SharedState, T>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
@@ -418,17 +643,70 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.p2p_service.start().await?;
+        self.block_height = self.db.latest_block_height()?;
+        self.update_sync_progress()?;
         Ok(self)
     }
 }
 
+impl<P, D, B, T> Task<P, D, B, T>
+where
+    P: TaskP2PService,
+    D: P2pDb + 'static,
+{
+    /// Queues the request of the peer to be served from the database.
+    /// Rejects the request if too many requests of its class are queued.
+    fn queue_db_request(
+        &mut self,
+        peer_id: &PeerId,
+        request_id: RequestId,
+        request: DbRequest,
+    ) {
+        let class = RequestClass::of_peer(self.p2p_service.is_reserved_peer(peer_id));
+        if let Err((request_id, request)) =
+            self.inbound_requests.push(class, (request_id, request))
+        {
+            tracing::debug!(
+                "Rejecting the request of the peer {peer_id}: \
+                too many {class:?} requests are queued"
+            );
+            let _ = self
+                .p2p_service
+                .send_response_msg(request_id, request.empty_response());
+        }
+        self.serve_inbound_requests();
+    }
+
+    /// Starts serving the queued requests allowed by the limits of their classes.
+    fn serve_inbound_requests(&mut self) {
+        while let Some((class, (request_id, request))) = self.inbound_requests.next() {
+            let db = self.db.clone();
+            let empty_response = request.empty_response();
+            self.served_requests.push(Box::pin(async move {
+                let result =
+                    tokio::task::spawn_blocking(move || request.serve(db.as_ref()))
+                        .await
+                        .map_err(anyhow::Error::from)
+                        .and_then(|result| result);
+                ServedRequest {
+                    class,
+                    request_id,
+                    result,
+                    empty_response,
+                }
+            }));
+        }
+    }
+}
+
 // TODO: Add tests https://github.com/FuelLabs/fuel-core/issues/1275
 #[async_trait::async_trait]
-impl<P, D, B> RunnableTask for Task<P, D, B>
+impl<P, D, B, T> RunnableTask for Task<P, D, B, T>
 where
     P: TaskP2PService + 'static,
     D: P2pDb + 'static,
     B: Broadcast + 'static,
+    T: TxPool + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         tracing::debug!("P2P task is running");
@@ -469,6 +747,14 @@ where
                         let peer_ids = self.p2p_service.get_peer_ids();
                         let _ = channel.send(peer_ids);
                     }
+                    Some(TaskRequest::GetAllPeerInfo { channel }) => {
+                        let peers = self.p2p_service
+                            .get_all_peer_info()
+                            .into_iter()
+                            .map(|(peer_id, peer_info)| (*peer_id, peer_info.clone()))
+                            .collect();
+                        let _ = channel.send(peers);
+                    }
                     Some(TaskRequest::GetBlock { height, channel }) => {
                         let request_msg = RequestMessage::Block(height);
                         let channel_item = ResponseChannelItem::Block(channel);
@@ -491,6 +777,23 @@ where
                         let channel_item = ResponseChannelItem::Transactions(channel);
                         let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
                     }
+                    Some(TaskRequest::GetPooledTxIds { from_peer, channel }) => {
+                        let request_msg = RequestMessage::PooledTxIds;
+                        let channel_item = ResponseChannelItem::PooledTxIds(channel);
+                        let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
+                    }
+                    Some(TaskRequest::GetPooledTransactions { tx_ids, from_peer, channel }) => {
+                        let request_msg = RequestMessage::PooledTransactions(tx_ids);
+                        let channel_item = ResponseChannelItem::PooledTransactions(channel);
+                        let _ = self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel_item);
+                    }
+                    Some(TaskRequest::SendTxRejection { rejection, to_peer }) => {
+                        let request_msg = RequestMessage::TxRejection(rejection);
+                        // Nobody waits for the acknowledgement.
+                        let (channel, _) = oneshot::channel();
+                        let channel_item = ResponseChannelItem::TxRejectionAck(channel);
+                        let _ = self.p2p_service.send_request_msg(Some(to_peer), request_msg, channel_item);
+                    }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
                         // report_message(&mut self.p2p_service, message, acceptance);
                         self.p2p_service.report_message(message, acceptance)?;
@@ -498,6 +801,9 @@ where
                     Some(TaskRequest::RespondWithPeerReport { peer_id, score, reporting_service }) => {
                         let _ = self.p2p_service.report_peer(peer_id, score, reporting_service);
                     }
+                    Some(TaskRequest::BanPeer { peer_id }) => {
+                        let _ = self.p2p_service.ban_peer(peer_id);
+                    }
                     None => {
                         unreachable!("The `Task` is holder of the `Sender`, so it should not be possible");
                     }
@@ -514,6 +820,13 @@ where
                         };
 
                         let _ = self.broadcast.block_height_broadcast(block_height_data);
+                        if let Err(e) = self.update_sync_progress() {
+                            tracing::error!("Failed to update the sync progress: {e:?}");
+                        }
+                    }
+                    Some(FuelP2PEvent::PeerConnected(peer_id)) => {
+                        let peer_id: Vec<u8> = peer_id.into();
+                        let _ = self.broadcast.new_peer_broadcast(peer_id.into());
                     }
                     Some(FuelP2PEvent::GossipsubMessage { message, message_id, peer_id,.. }) => {
                         let message_id = message_id.0;
@@ -533,35 +846,13 @@ where
                             },
                         }
                     },
-                    Some(FuelP2PEvent::RequestMessage { request_message, request_id }) => {
+                    Some(FuelP2PEvent::RequestMessage { peer_id, request_message, request_id }) => {
                         match request_message {
                             RequestMessage::Block(block_height) => {
-                                match self.db.get_sealed_block(&block_height) {
-                                    Ok(maybe_block) => {
-                                        let response = maybe_block.map(Arc::new);
-                                        let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Block(response));
-                                    },
-                                    Err(e) => {
-                                        tracing::error!("Failed to get block at height {:?}: {:?}", block_height, e);
-                                        let response = None;
-                                        let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Block(response));
-                                        return Err(e.into())
-                                    }
-                                }
+                                self.queue_db_request(&peer_id, request_id, DbRequest::Block(block_height));
                             }
                             RequestMessage::Transactions(range) => {
-                                match self.db.get_transactions(range.clone()) {
-                                    Ok(maybe_transactions) => {
-                                        let response = maybe_transactions.map(Arc::new);
-                                        let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Transactions(response));
-                                    },
-                                    Err(e) => {
-                                        tracing::error!("Failed to get transactions for range {:?}: {:?}", range, e);
-                                        let response = None;
-                                        let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::Transactions(response));
-                                        return Err(e.into())
-                                    }
-                                }
+                                self.queue_db_request(&peer_id, request_id, DbRequest::Transactions(range));
                             }
                             RequestMessage::SealedHeaders(range) => {
                                 let max_len = self.max_headers_per_request.try_into().expect("u32 should always fit into usize");
@@ -571,25 +862,48 @@ where
                                     let response = None;
                                     let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeaders(response));
                                 } else {
-                                    match self.db.get_sealed_headers(range.clone()) {
-                                        Ok(headers) => {
-                                            let response = Some(headers);
-                                            let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeaders(response));
-                                        },
-                                        Err(e) => {
-                                            tracing::error!("Failed to get sealed headers for range {:?}: {:?}", range, &e);
-                                            let response = None;
-                                            let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::SealedHeaders(response));
-                                            return Err(e.into())
-                                        }
-                                    }
+                                    self.queue_db_request(&peer_id, request_id, DbRequest::SealedHeaders(range));
                                 };
                             }
+                            RequestMessage::PooledTxIds => {
+                                let tx_ids = self.tx_pool.get_tx_ids(MAX_POOLED_TXS_PER_REQUEST);
+                                let response = Some(Arc::new(tx_ids));
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::PooledTxIds(response));
+                            }
+                            RequestMessage::PooledTransactions(tx_ids) => {
+                                let response = if tx_ids.len() > MAX_POOLED_TXS_PER_REQUEST {
+                                    tracing::error!("Requested too many pooled transactions. Requested length: {:?}, Max length: {:?}", tx_ids.len(), MAX_POOLED_TXS_PER_REQUEST);
+                                    None
+                                } else {
+                                    let txs = self.tx_pool.get_full_txs(tx_ids);
+                                    Some(Arc::new(fit_pooled_txs(txs, self.max_response_size)))
+                                };
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::PooledTransactions(response));
+                            }
+                            RequestMessage::TxRejection(rejection) => {
+                                let _ = self.p2p_service.send_response_msg(request_id, OutboundResponse::TxRejectionAck);
+                                let _ = self.broadcast.tx_rejection_broadcast(FuelPeerId::from(peer_id.to_bytes()), rejection);
+                            }
                         }
                     },
                     _ => (),
                 }
             },
+            Some(served) = self.served_requests.next() => {
+                should_continue = true;
+                self.inbound_requests.finish(served.class);
+                self.serve_inbound_requests();
+                match served.result {
+                    Ok(response) => {
+                        let _ = self.p2p_service.send_response_msg(served.request_id, response);
+                    }
+                    Err(e) => {
+                        tracing::error!("{e:?}");
+                        let _ = self.p2p_service.send_response_msg(served.request_id, served.empty_response);
+                        return Err(e)
+                    }
+                }
+            },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
                 let res = self.peer_heartbeat_reputation_checks();
@@ -599,11 +913,19 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                // The disconnected peers don't report the heights anymore.
+                if let Err(e) = self.update_sync_progress() {
+                    tracing::error!("Failed to update the sync progress: {e:?}");
+                }
                 self.next_check_time += self.heartbeat_check_interval;
             },
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
+                    self.block_height = latest_block_height;
+                    if let Err(e) = self.update_sync_progress() {
+                        tracing::error!("Failed to update the sync progress: {e:?}");
+                    }
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -637,6 +959,12 @@ pub struct SharedState {
     request_sender: mpsc::Sender<TaskRequest>,
     /// Sender of p2p blopck height data
     block_height_broadcast: broadcast::Sender<BlockHeightHeartbeatData>,
+    /// Sender of the ids of the newly connected peers.
+    new_peers_broadcast: broadcast::Sender<FuelPeerId>,
+    /// Sender of the rejections of the transactions gossiped by this node.
+    tx_rejections_broadcast: broadcast::Sender<(FuelPeerId, TxRejection)>,
+    /// The progress of the sync while the transaction gossip is deferred.
+    sync_progress: Arc<watch::Sender<Option<SyncProgress>>>,
 }
 
 impl SharedState {
@@ -653,6 +981,26 @@ impl SharedState {
         Ok(())
     }
 
+    /// Notifies the peer that gossiped the transaction about its rejection.
+    /// The description of the reason is truncated to the [`MAX_TX_REJECTION_MESSAGE_LEN`].
+    pub fn notify_tx_rejection(
+        &self,
+        peer_id: Vec<u8>,
+        mut rejection: TxRejection,
+    ) -> anyhow::Result<()> {
+        let to_peer = PeerId::from_bytes(&peer_id)?;
+        if rejection.message.len() > MAX_TX_REJECTION_MESSAGE_LEN {
+            let mut len = MAX_TX_REJECTION_MESSAGE_LEN;
+            while !rejection.message.is_char_boundary(len) {
+                len -= 1;
+            }
+            rejection.message.truncate(len);
+        }
+        self.request_sender
+            .try_send(TaskRequest::SendTxRejection { rejection, to_peer })?;
+        Ok(())
+    }
+
     pub async fn get_block(
         &self,
         height: BlockHeight,
@@ -712,6 +1060,47 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Requests the ids of the transactions in the pool of the peer.
+    pub async fn get_pooled_tx_ids_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+    ) -> anyhow::Result<Option<Vec<TxId>>> {
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        let request = TaskRequest::GetPooledTxIds {
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Requests the transactions from the pool of the peer by their ids.
+    pub async fn get_pooled_transactions_from_peer(
+        &self,
+        peer_id: Vec<u8>,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Option<Vec<Option<Transaction>>>> {
+        if tx_ids.len() > MAX_POOLED_TXS_PER_REQUEST {
+            return Err(anyhow!(
+                "Cannot request more than {MAX_POOLED_TXS_PER_REQUEST} pooled transactions"
+            ))
+        }
+        let (sender, receiver) = oneshot::channel();
+        let from_peer = PeerId::from_bytes(&peer_id)?;
+
+        let request = TaskRequest::GetPooledTransactions {
+            tx_ids,
+            from_peer,
+            channel: sender,
+        };
+        self.request_sender.send(request).await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
     pub fn broadcast_vote(&self, vote: Arc<ConsensusVote>) -> anyhow::Result<()> {
         self.request_sender
             .try_send(TaskRequest::BroadcastVote(vote))?;
@@ -745,6 +1134,23 @@ impl SharedState {
         receiver.await.map_err(|e| anyhow!("{}", e))
     }
 
+    /// Returns the info about all connected peers.
+    pub async fn get_all_peers(&self) -> anyhow::Result<Vec<(PeerId, PeerInfo)>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(TaskRequest::GetAllPeerInfo { channel: sender })
+            .await?;
+
+        receiver.await.map_err(|e| anyhow!("{}", e))
+    }
+
+    /// Returns the progress of the sync while the node is too far behind the peers
+    /// to accept the transactions.
+    pub fn sync_progress(&self) -> Option<SyncProgress> {
+        *self.sync_progress.borrow()
+    }
+
     pub fn subscribe_tx(&self) -> broadcast::Receiver<TransactionGossipData> {
         self.tx_broadcast.subscribe()
     }
@@ -759,6 +1165,19 @@ impl SharedState {
         self.reserved_peers_broadcast.subscribe()
     }
 
+    /// Subscribes to the ids of the newly connected peers.
+    pub fn subscribe_new_peers(&self) -> broadcast::Receiver<FuelPeerId> {
+        self.new_peers_broadcast.subscribe()
+    }
+
+    /// Subscribes to the rejections of the transactions gossiped by this node
+    /// reported by the peers.
+    pub fn subscribe_tx_rejections(
+        &self,
+    ) -> broadcast::Receiver<(FuelPeerId, TxRejection)> {
+        self.tx_rejections_broadcast.subscribe()
+    }
+
     pub fn report_peer<T: PeerReport>(
         &self,
         peer_id: FuelPeerId,
@@ -784,17 +1203,61 @@ impl SharedState {
             }
         }
     }
+
+    /// Bans the peer and closes the connections to it.
+    pub fn ban_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.request_sender
+            .try_send(TaskRequest::BanPeer { peer_id })?;
+        Ok(())
+    }
 }
 
-pub fn new_service<D, B>(p2p_config: Config, db: D, block_importer: B) -> Service<D>
+/// Builds the shared state of the service before the service itself, so the services
+/// used by the P2P, like the `TxPool`, can use it too.
+pub fn build_shared_state(
+    p2p_config: &Config,
+) -> (SharedState, mpsc::Receiver<TaskRequest>) {
+    let (request_sender, request_receiver) = mpsc::channel(100);
+    let (tx_broadcast, _) = broadcast::channel(100);
+    let (block_height_broadcast, _) = broadcast::channel(100);
+    let (new_peers_broadcast, _) = broadcast::channel(100);
+    let (tx_rejections_broadcast, _) = broadcast::channel(100);
+    let (sync_progress, _) = watch::channel(None);
+    let (reserved_peers_broadcast, _) =
+        broadcast::channel(1 + p2p_config.reserved_nodes.len() * 2);
+
+    let shared_state = SharedState {
+        request_sender,
+        tx_broadcast,
+        reserved_peers_broadcast,
+        block_height_broadcast,
+        new_peers_broadcast,
+        tx_rejections_broadcast,
+        sync_progress: Arc::new(sync_progress),
+    };
+    (shared_state, request_receiver)
+}
+
+pub fn new_service<D, B, T>(
+    p2p_config: Config,
+    shared_state: SharedState,
+    request_receiver: mpsc::Receiver<TaskRequest>,
+    db: D,
+    block_importer: B,
+    tx_pool: T,
+) -> Service<D, T>
 where
     D: P2pDb + 'static,
     B: BlockHeightImporter,
+    T: TxPool + 'static,
 {
     Service::new(Task::new(
         p2p_config,
+        shared_state,
+        request_receiver,
         Arc::new(db),
         Arc::new(block_importer),
+        tx_pool,
     ))
 }
 
@@ -832,7 +1295,10 @@ fn report_message<T: NetworkCodec>(
 #[cfg(test)]
 pub mod tests {
     #![allow(non_snake_case)]
-    use crate::ports::P2pDb;
+    use crate::{
+        config::MAX_RESPONSE_SIZE,
+        ports::P2pDb,
+    };
 
     use super::*;
 
@@ -877,11 +1343,28 @@ pub mod tests {
         ) -> StorageResult<Option<Vec<Transactions>>> {
             unimplemented!()
         }
+
+        fn latest_block_height(&self) -> StorageResult<BlockHeight> {
+            Ok(BlockHeight::default())
+        }
     }
 
     #[derive(Clone, Debug)]
     struct FakeBlockImporter;
 
+    #[derive(Clone, Debug)]
+    struct FakeTxPool;
+
+    impl TxPool for FakeTxPool {
+        fn get_tx_ids(&self, _max_txs: usize) -> Vec<TxId> {
+            vec![]
+        }
+
+        fn get_full_txs(&self, tx_ids: Vec<TxId>) -> Vec<Option<Transaction>> {
+            tx_ids.iter().map(|_| None).collect()
+        }
+    }
+
     impl BlockHeightImporter for FakeBlockImporter {
         fn next_block_height(&self) -> BoxStream<BlockHeight> {
             Box::pin(fuel_core_services::stream::pending())
@@ -891,7 +1374,15 @@ pub mod tests {
     #[tokio::test]
     async fn start_and_stop_awaits_works() {
         let p2p_config = Config::default_initialized("start_stop_works");
-        let service = new_service(p2p_config, FakeDb, FakeBlockImporter);
+        let (shared_state, request_receiver) = build_shared_state(&p2p_config);
+        let service = new_service(
+            p2p_config,
+            shared_state,
+            request_receiver,
+            FakeDb,
+            FakeBlockImporter,
+            FakeTxPool,
+        );
 
         // Node with p2p service started
         assert!(service.start_and_await().await.unwrap().started());
@@ -899,8 +1390,15 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    const REQUEST_LIMITS: RequestLimits = RequestLimits {
+        max_concurrent_reserved: 1,
+        max_concurrent_bulk: 1,
+        max_queued: 1,
+    };
+
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
+        tx_gossip_subscription: Option<bool>,
     }
 
     impl TaskP2PService for FakeP2PService {
@@ -919,6 +1417,10 @@ pub mod tests {
             todo!()
         }
 
+        fn is_reserved_peer(&self, _peer_id: &PeerId) -> bool {
+            todo!()
+        }
+
         fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
             std::future::pending().boxed()
         }
@@ -964,9 +1466,18 @@ pub mod tests {
             todo!()
         }
 
+        fn ban_peer(&mut self, _peer_id: PeerId) -> anyhow::Result<()> {
+            todo!()
+        }
+
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn set_tx_gossip_subscription(&mut self, subscribe: bool) -> anyhow::Result<()> {
+            self.tx_gossip_subscription = Some(subscribe);
+            Ok(())
+        }
     }
 
     struct FakeDB;
@@ -999,6 +1510,10 @@ pub mod tests {
         ) -> StorageResult<Option<Vec<Transactions>>> {
             todo!()
         }
+
+        fn latest_block_height(&self) -> StorageResult<BlockHeight> {
+            todo!()
+        }
     }
 
     struct FakeBroadcast {
@@ -1033,6 +1548,25 @@ pub mod tests {
         ) -> anyhow::Result<()> {
             todo!()
         }
+
+        fn new_peer_broadcast(&self, _peer_id: FuelPeerId) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn tx_rejection_broadcast(
+            &self,
+            _peer_id: FuelPeerId,
+            _rejection: TxRejection,
+        ) -> anyhow::Result<()> {
+            todo!()
+        }
+
+        fn sync_progress_broadcast(
+            &self,
+            _progress: Option<SyncProgress>,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
     }
 
     #[tokio::test]
@@ -1053,11 +1587,17 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            protocols: vec![],
             heartbeat_data,
             score: 100.0,
+            connected_at: Instant::now(),
+            bandwidth: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            tx_gossip_subscription: None,
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1079,15 +1619,22 @@ pub mod tests {
         let mut task = Task {
             p2p_service,
             db: Arc::new(FakeDB),
+            tx_pool: FakeTxPool,
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
+            max_response_size: MAX_RESPONSE_SIZE,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_gossip_sync_threshold: None,
+            block_height: BlockHeight::default(),
+            tx_gossip_deferred: false,
+            inbound_requests: PrioritizedRequests::new(REQUEST_LIMITS),
+            served_requests: FuturesUnordered::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1131,11 +1678,17 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            protocols: vec![],
             heartbeat_data,
             score: 100.0,
+            connected_at: Instant::now(),
+            bandwidth: Default::default(),
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            tx_gossip_subscription: None,
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1157,15 +1710,22 @@ pub mod tests {
         let mut task = Task {
             p2p_service,
             db: Arc::new(FakeDB),
+            tx_pool: FakeTxPool,
             next_block_height: FakeBlockImporter.next_block_height(),
             request_receiver,
             broadcast,
             max_headers_per_request: 0,
+            max_response_size: MAX_RESPONSE_SIZE,
             heartbeat_check_interval: Duration::from_secs(0),
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_gossip_sync_threshold: None,
+            block_height: BlockHeight::default(),
+            tx_gossip_deferred: false,
+            inbound_requests: PrioritizedRequests::new(REQUEST_LIMITS),
+            served_requests: FuturesUnordered::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1189,4 +1749,139 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    #[tokio::test]
+    async fn update_sync_progress__defers_tx_gossip_while_far_behind_peers() {
+        // given
+        let mut peer_info = PeerInfo::new(10);
+        peer_info.heartbeat_data.block_height = Some(100u32.into());
+        let p2p_service = FakeP2PService {
+            peer_info: vec![(PeerId::random(), peer_info)],
+            tx_gossip_subscription: None,
+        };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+
+        let mut task = Task {
+            p2p_service,
+            db: Arc::new(FakeDB),
+            tx_pool: FakeTxPool,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            max_response_size: MAX_RESPONSE_SIZE,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(0),
+            heartbeat_max_time_since_last: Duration::from_secs(0),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 0.,
+                low_heartbeat_frequency_penalty: 0.,
+            },
+            tx_gossip_sync_threshold: Some(10),
+            block_height: 5u32.into(),
+            tx_gossip_deferred: false,
+            inbound_requests: PrioritizedRequests::new(REQUEST_LIMITS),
+            served_requests: FuturesUnordered::new(),
+        };
+
+        // when
+        task.update_sync_progress().unwrap();
+
+        // then
+        assert!(task.tx_gossip_deferred);
+        assert_eq!(task.p2p_service.tx_gossip_subscription, Some(false));
+
+        // when
+        task.block_height = 90u32.into();
+        task.update_sync_progress().unwrap();
+
+        // then
+        assert!(!task.tx_gossip_deferred);
+        assert_eq!(task.p2p_service.tx_gossip_subscription, Some(true));
+    }
+
+    #[tokio::test]
+    async fn update_sync_progress__ignores_height_reported_by_minority_of_peers() {
+        // given
+        let peer_info = |height: u32| {
+            let mut peer_info = PeerInfo::new(10);
+            peer_info.heartbeat_data.block_height = Some(height.into());
+            (PeerId::random(), peer_info)
+        };
+        let p2p_service = FakeP2PService {
+            peer_info: vec![peer_info(u32::MAX), peer_info(5), peer_info(6)],
+            tx_gossip_subscription: None,
+        };
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        let broadcast = FakeBroadcast {
+            peer_reports: report_sender,
+        };
+
+        let mut task = Task {
+            p2p_service,
+            db: Arc::new(FakeDB),
+            tx_pool: FakeTxPool,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver,
+            broadcast,
+            max_headers_per_request: 0,
+            max_response_size: MAX_RESPONSE_SIZE,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(0),
+            heartbeat_max_time_since_last: Duration::from_secs(0),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 0.,
+                low_heartbeat_frequency_penalty: 0.,
+            },
+            tx_gossip_sync_threshold: Some(10),
+            block_height: 5u32.into(),
+            tx_gossip_deferred: false,
+            inbound_requests: PrioritizedRequests::new(REQUEST_LIMITS),
+            served_requests: FuturesUnordered::new(),
+        };
+
+        // when
+        task.update_sync_progress().unwrap();
+
+        // then
+        assert!(!task.tx_gossip_deferred);
+        assert_eq!(task.p2p_service.tx_gossip_subscription, None);
+    }
+
+    #[test]
+    fn fit_pooled_txs__keeps_the_prefix_that_fits_into_the_response() {
+        // given
+        let txs: Vec<_> = (0..10)
+            .map(|_| Some(Transaction::default_test_tx()))
+            .collect();
+        let tx_size = postcard::to_stdvec(&txs[0]).unwrap().len();
+        let max_response_size = POOLED_TXS_RESPONSE_OVERHEAD + 3 * tx_size;
+
+        // when
+        let fitting = fit_pooled_txs(txs.clone(), max_response_size);
+
+        // then
+        assert_eq!(fitting, txs[..3].to_vec());
+    }
+
+    #[test]
+    fn fit_pooled_txs__keeps_all_txs_under_the_max_response_size() {
+        // given
+        let txs: Vec<_> = (0..10)
+            .map(|_| Some(Transaction::default_test_tx()))
+            .collect();
+
+        // when
+        let fitting = fit_pooled_txs(txs.clone(), MAX_RESPONSE_SIZE);
+
+        // then
+        assert_eq!(fitting, txs);
+    }
 }
@@ -0,0 +1,191 @@
+//! Builds the blocks programmatically for the test frameworks and the experiments
+//! embedding the node. The builder reuses the header and execution logic of the
+//! [`Producer`], so the built blocks are valid for the importer of the node.
+
+use crate::{
+    block_producer::Producer,
+    ports,
+    ports::InjectedTransactions,
+};
+use anyhow::Context;
+use fuel_core_storage::transactional::StorageTransaction;
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        primitives::DaBlockHeight,
+        SealedBlock,
+    },
+    fuel_asm::Word,
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::Transaction,
+    fuel_types::BlockHeight,
+    services::{
+        block_producer::Components,
+        executor::UncommittedResult,
+    },
+    tai64::Tai64,
+};
+
+/// Seals the built blocks.
+pub trait BlockSigner {
+    /// Returns the consensus seal of the `block`.
+    fn seal(&self, block: &Block) -> anyhow::Result<Consensus>;
+}
+
+impl BlockSigner for SecretKey {
+    fn seal(&self, block: &Block) -> anyhow::Result<Consensus> {
+        let signature = Signature::sign(self, &block.id().into_message());
+        Ok(Consensus::PoA(PoAConsensus::new(signature)))
+    }
+}
+
+/// Builds the block at the height on top of the latest state of the producer's database.
+/// By default, the block contains the transactions selected from the `TxPool`, the current
+/// time and the latest DA height of the relayer.
+pub struct BlockBuilder<'a, Database, TxPool, Executor> {
+    producer: &'a Producer<Database, TxPool, Executor>,
+    height: BlockHeight,
+    time: Tai64,
+    da_height: Option<DaBlockHeight>,
+    gas_limit: Word,
+    transactions: Vec<Transaction>,
+    select_from_txpool: bool,
+}
+
+impl<Database, TxPool, Executor> Producer<Database, TxPool, Executor> {
+    /// Starts building the block at the `height`.
+    pub fn block_builder(
+        &self,
+        height: BlockHeight,
+    ) -> BlockBuilder<'_, Database, TxPool, Executor> {
+        BlockBuilder {
+            producer: self,
+            height,
+            time: Tai64::now(),
+            da_height: None,
+            gas_limit: self.config.block_limits.gas,
+            transactions: vec![],
+            select_from_txpool: true,
+        }
+    }
+}
+
+impl<'a, Database, TxPool, Executor, ExecutorDB, TxSource>
+    BlockBuilder<'a, Database, TxPool, Executor>
+where
+    Database: ports::BlockProducerDatabase + 'static,
+    TxPool: ports::TxPool<TxSource = TxSource> + 'static,
+    Executor: ports::Executor<Database = ExecutorDB, TxSource = TxSource> + 'static,
+{
+    /// Sets the time of the block.
+    pub fn time(mut self, time: Tai64) -> Self {
+        self.time = time;
+        self
+    }
+
+    /// Sets the DA height of the block instead of waiting for the relayer.
+    /// It must not be below the DA height of the previous block.
+    pub fn da_height(mut self, da_height: DaBlockHeight) -> Self {
+        self.da_height = Some(da_height);
+        self
+    }
+
+    /// Sets the gas limit of the block. It can't exceed the limit of the producer.
+    pub fn gas_limit(mut self, gas_limit: Word) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Injects the `transaction` into the block. The injected transactions are
+    /// executed in order, ahead of the transactions selected from the `TxPool`.
+    pub fn transaction(mut self, transaction: Transaction) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+
+    /// Injects the `transactions` into the block.
+    pub fn transactions(
+        mut self,
+        transactions: impl IntoIterator<Item = Transaction>,
+    ) -> Self {
+        self.transactions.extend(transactions);
+        self
+    }
+
+    /// Sets whether the transactions are selected from the `TxPool`
+    /// after the injected ones.
+    pub fn select_from_txpool(mut self, select: bool) -> Self {
+        self.select_from_txpool = select;
+        self
+    }
+
+    /// Executes the block without committing it. The transactions rejected by
+    /// the executor are skipped, like in the block production.
+    pub async fn execute(
+        self,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<ExecutorDB>>> {
+        let producer = self.producer;
+        let height = self.height;
+        let _production_guard = producer.lock.lock().await;
+
+        let mut header = producer._new_header(height, self.time)?;
+        header.application.da_height = match self.da_height {
+            Some(da_height) => {
+                anyhow::ensure!(
+                    da_height >= header.da_height,
+                    "The DA height {da_height} is below the previous one {}",
+                    header.da_height
+                );
+                da_height
+            }
+            None => producer.select_new_da_height(header.da_height).await?,
+        };
+
+        let component = Components {
+            header_to_produce: header,
+            transactions_source: InjectedTransactions {
+                transactions: self.transactions,
+                source: self
+                    .select_from_txpool
+                    .then(|| producer.txpool.get_source(height, None)),
+            },
+            gas_limit: self.gas_limit.min(producer.config.block_limits.gas),
+            gas_price: producer.required_gas_price(),
+        };
+
+        let result = producer
+            .executor
+            .execute_injected_without_commit(component)
+            .with_context(|| format!("Failed to build block {height:?}"))?;
+        producer.check_block_size(height, &result)?;
+        Ok(result)
+    }
+
+    /// Executes the block without committing it and seals it with the `signer`.
+    pub async fn execute_and_seal<S>(
+        self,
+        signer: &S,
+    ) -> anyhow::Result<(
+        SealedBlock,
+        UncommittedResult<StorageTransaction<ExecutorDB>>,
+    )>
+    where
+        S: BlockSigner,
+    {
+        let result = self.execute().await?;
+        let block = result.result().block.clone();
+        let consensus = signer.seal(&block)?;
+        let sealed_block = SealedBlock {
+            entity: block,
+            consensus,
+        };
+        Ok((sealed_block, result))
+    }
+}
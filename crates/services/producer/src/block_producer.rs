@@ -27,15 +27,29 @@ use fuel_core_types::{
         Bytes32,
     },
     services::{
-        block_producer::Components,
-        executor::UncommittedResult,
+        block_producer::{
+            Components,
+            DryRunOverrides,
+        },
+        executor::{
+            BlockLimits,
+            StateDiff,
+            TransactionProfile,
+            UncommittedResult,
+        },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 use thiserror::Error;
 use tokio::sync::Mutex;
-use tracing::debug;
+use tracing::{
+    debug,
+    warn,
+};
 
 #[cfg(test)]
 mod tests;
@@ -53,6 +67,20 @@ pub enum Error {
         best: DaBlockHeight,
         previous_block: DaBlockHeight,
     },
+    #[error("The produced block {height} has size {size} exceeding the limit {limit}")]
+    BlockSizeLimitExceeded {
+        height: BlockHeight,
+        size: u64,
+        limit: u64,
+    },
+    #[error("The dry run height {height} is outside of the allowed range {min}..={max}")]
+    DryRunHeightOutOfRange {
+        height: BlockHeight,
+        min: BlockHeight,
+        max: BlockHeight,
+    },
+    #[error("The dry run time {time} is outside of the allowed range {min}..={max}")]
+    DryRunTimeOutOfRange { time: u64, min: u64, max: u64 },
 }
 
 pub struct Producer<Database, TxPool, Executor> {
@@ -61,11 +89,25 @@ pub struct Producer<Database, TxPool, Executor> {
     pub txpool: TxPool,
     pub executor: Arc<Executor>,
     pub relayer: Box<dyn ports::Relayer>,
+    /// The gas price of the next block required from the transactions of the produced
+    /// blocks. `None` doesn't require any gas price.
+    pub gas_price_provider: Option<Box<dyn ports::GasPriceProvider>>,
+    pub clock: Box<dyn ports::ChainClock>,
     // use a tokio lock since we want callers to yield until the previous block
     // execution has completed (which may take a while).
     pub lock: Mutex<()>,
 }
 
+impl<Database, TxPool, Executor> Producer<Database, TxPool, Executor> {
+    /// Returns the lowest gas price of the transactions included into the produced block.
+    fn required_gas_price(&self) -> Word {
+        self.gas_price_provider
+            .as_ref()
+            .map(|provider| provider.gas_price())
+            .unwrap_or_default()
+    }
+}
+
 impl<Database, TxPool, Executor, ExecutorDB, TxSource>
     Producer<Database, TxPool, Executor>
 where
@@ -74,6 +116,7 @@ where
     Executor: ports::Executor<Database = ExecutorDB, TxSource = TxSource> + 'static,
 {
     /// Produces and execute block for the specified height
+    #[tracing::instrument(skip_all, fields(block_height = *height))]
     pub async fn produce_and_execute_block(
         &self,
         height: BlockHeight,
@@ -91,14 +134,20 @@ where
         // prevent simultaneous block production calls, the guard will drop at the end of this fn.
         let _production_guard = self.lock.lock().await;
 
-        let source = self.txpool.get_source(height);
-
         let header = self.new_header(height, block_time).await?;
 
+        let execution_deadline = self
+            .config
+            .stage_deadlines
+            .execution
+            .map(|execution| Instant::now() + execution);
+        let source = self.txpool.get_source(height, execution_deadline);
+
         let component = Components {
             header_to_produce: header,
             transactions_source: source,
-            gas_limit: max_gas,
+            gas_limit: max_gas.min(self.config.block_limits.gas),
+            gas_price: self.required_gas_price(),
         };
 
         // Store the context string incase we error.
@@ -108,43 +157,34 @@ where
             .executor
             .execute_without_commit(component)
             .context(context_string)?;
+        self.check_block_size(height, &result)?;
+
+        if let Some(deadline) = execution_deadline {
+            if Instant::now() > deadline {
+                warn!(
+                    "The execution of the block {height} exceeded the deadline, \
+                    the block contains only {} transactions",
+                    result.result().block.transactions().len()
+                );
+            }
+        }
 
         debug!("Produced block with result: {:?}", result.result());
         Ok(result)
     }
 
-    // TODO: Support custom `block_time` for `dry_run`.
     /// Simulate a transaction without altering any state. Does not aquire the production lock
     /// since it is basically a "read only" operation and shouldn't get in the way of normal
     /// production.
     pub async fn dry_run(
         &self,
         transaction: Transaction,
-        height: Option<BlockHeight>,
+        overrides: DryRunOverrides,
         utxo_validation: Option<bool>,
     ) -> anyhow::Result<Vec<Receipt>> {
-        let height = match height {
-            None => self.db.current_block_height()?,
-            Some(height) => height,
-        } + 1.into();
-
         let is_script = transaction.is_script();
-        // The dry run execution should use the state of the blockchain based on the
-        // last available block, not on the upcoming one. It means that we need to
-        // use the same configuration as the last block -> the same DA height.
-        // It is deterministic from the result perspective, plus it is more performant
-        // because we don't need to wait for the relayer to sync.
-        let header = self._new_header(height, Tai64::now())?;
-        let gas_limit = match &transaction {
-            Transaction::Script(script) => *script.gas_limit(),
-            Transaction::Create(create) => *create.gas_limit(),
-            Transaction::Mint(_) => 0,
-        };
-        let component = Components {
-            header_to_produce: header,
-            transactions_source: transaction,
-            gas_limit,
-        };
+        let gas_limit = transaction_gas_limit(&transaction);
+        let component = self.dry_run_component(transaction, gas_limit, overrides)?;
 
         let executor = self.executor.clone();
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
@@ -162,12 +202,144 @@ where
         }
         Ok(res)
     }
+
+    /// Simulate a transaction like the `dry_run` and collect the gas and memory profile
+    /// of its script.
+    pub async fn dry_run_profile(
+        &self,
+        transaction: Transaction,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<Receipt>, TransactionProfile)> {
+        let is_script = transaction.is_script();
+        let gas_limit = transaction_gas_limit(&transaction);
+        let component = self.dry_run_component(transaction, gas_limit, overrides)?;
+
+        let executor = self.executor.clone();
+        // The profiling executes the script step by step, so it is even more important
+        // to keep it away from the main async runtime.
+        let (receipts, profile) = tokio_rayon::spawn_fifo(
+            move || -> anyhow::Result<(Vec<Receipt>, TransactionProfile)> {
+                Ok(executor.dry_run_profile(component, utxo_validation)?)
+            },
+        )
+        .await?;
+        if is_script && receipts.is_empty() {
+            return Err(anyhow!("Expected at least one set of receipts"))
+        }
+        Ok((receipts, profile))
+    }
+
+    /// Simulate an ordered bundle of transactions without altering any state. Each
+    /// transaction is executed on top of the changes made by the previous ones.
+    /// Returns the receipts of each transaction and the state diff of the whole bundle.
+    pub async fn dry_run_bundle(
+        &self,
+        transactions: Vec<Transaction>,
+        overrides: DryRunOverrides,
+        utxo_validation: Option<bool>,
+    ) -> anyhow::Result<(Vec<Vec<Receipt>>, StateDiff)> {
+        let gas_limit = transactions
+            .iter()
+            .map(transaction_gas_limit)
+            .fold(0u64, |sum, gas_limit| sum.saturating_add(gas_limit));
+        let component = self.dry_run_component(transactions, gas_limit, overrides)?;
+
+        let executor = self.executor.clone();
+        // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
+        tokio_rayon::spawn_fifo(
+            move || -> anyhow::Result<(Vec<Vec<Receipt>>, StateDiff)> {
+                Ok(executor.dry_run_bundle(component, utxo_validation)?)
+            },
+        )
+        .await
+    }
+
+    fn dry_run_component<TxSource>(
+        &self,
+        transactions_source: TxSource,
+        gas_limit: u64,
+        overrides: DryRunOverrides,
+    ) -> anyhow::Result<Components<TxSource>> {
+        let height = self.db.current_block_height()? + 1.into();
+        let now = self.clock.now();
+
+        // The dry run execution should use the state of the blockchain based on the
+        // last available block, not on the upcoming one. It means that we need to
+        // use the same configuration as the last block -> the same DA height.
+        // It is deterministic from the result perspective, plus it is more performant
+        // because we don't need to wait for the relayer to sync.
+        let mut header = self._new_header(height, now)?;
+
+        let limits = self.config.dry_run_limits;
+        if let Some(block_height) = overrides.block_height {
+            let max: BlockHeight = u32::from(height)
+                .saturating_add(limits.max_height_offset)
+                .into();
+            if block_height < height || block_height > max {
+                return Err(Error::DryRunHeightOutOfRange {
+                    height: block_height,
+                    min: height,
+                    max,
+                }
+                .into())
+            }
+            header.consensus.height = block_height;
+        }
+        if let Some(block_time) = overrides.block_time {
+            let max = now.0.saturating_add(limits.max_time_offset.as_secs());
+            if block_time.0 < now.0 || block_time.0 > max {
+                return Err(Error::DryRunTimeOutOfRange {
+                    time: block_time.0,
+                    min: now.0,
+                    max,
+                }
+                .into())
+            }
+            header.consensus.time = block_time;
+        }
+
+        Ok(Components {
+            header_to_produce: header,
+            transactions_source,
+            gas_limit,
+            // The dry run simulates the transactions regardless of the gas price.
+            gas_price: 0,
+        })
+    }
+}
+
+fn transaction_gas_limit(transaction: &Transaction) -> u64 {
+    match transaction {
+        Transaction::Script(script) => *script.gas_limit(),
+        Transaction::Create(create) => *create.gas_limit(),
+        Transaction::Mint(_) => 0,
+    }
 }
 
 impl<Database, TxPool, Executor> Producer<Database, TxPool, Executor>
 where
     Database: ports::BlockProducerDatabase,
 {
+    /// The executor fills the block up to the limits, but the block is checked
+    /// again to never gossip the block rejected by other nodes.
+    pub(crate) fn check_block_size<DbTransaction>(
+        &self,
+        height: BlockHeight,
+        result: &UncommittedResult<DbTransaction>,
+    ) -> Result<(), Error> {
+        let size = BlockLimits::size_of(result.result().block.transactions());
+        let limit = self.config.block_limits.size;
+        if size > limit {
+            return Err(Error::BlockSizeLimitExceeded {
+                height,
+                size,
+                limit,
+            })
+        }
+        Ok(())
+    }
+
     /// Create the header for a new block at the provided height
     async fn new_header(
         &self,
@@ -175,14 +347,37 @@ where
         block_time: Tai64,
     ) -> anyhow::Result<PartialBlockHeader> {
         let mut block_header = self._new_header(height, block_time)?;
-        let new_da_height = self.select_new_da_height(block_header.da_height).await?;
+        let previous_da_height = block_header.da_height;
+        let new_da_height = match self.config.stage_deadlines.selection {
+            Some(selection) => {
+                let selected = tokio::time::timeout(
+                    selection,
+                    self.select_new_da_height(previous_da_height),
+                )
+                .await;
+                match selected {
+                    Ok(new_da_height) => new_da_height?,
+                    Err(_) => {
+                        // The block doesn't process the new messages of the DA layer,
+                        // they are processed by the next blocks.
+                        warn!(
+                            "The selection of the DA height of the block {height} \
+                            exceeded the deadline {selection:?}, the DA height \
+                            {previous_da_height} of the previous block is used"
+                        );
+                        previous_da_height
+                    }
+                }
+            }
+            None => self.select_new_da_height(previous_da_height).await?,
+        };
 
         block_header.application.da_height = new_da_height;
 
         Ok(block_header)
     }
 
-    async fn select_new_da_height(
+    pub(crate) async fn select_new_da_height(
         &self,
         previous_da_height: DaBlockHeight,
     ) -> anyhow::Result<DaBlockHeight> {
@@ -199,7 +394,7 @@ where
         Ok(best_height)
     }
 
-    fn _new_header(
+    pub(crate) fn _new_header(
         &self,
         height: BlockHeight,
         block_time: Tai64,
@@ -2,13 +2,16 @@ use crate::{
     block_producer::Error,
     mocks::{
         FailingMockExecutor,
+        MockChainClock,
         MockDb,
         MockExecutor,
         MockRelayer,
         MockTxPool,
     },
     Config,
+    DryRunLimits,
     Producer,
+    StageDeadlines,
 };
 use fuel_core_producer as _;
 use fuel_core_types::{
@@ -17,13 +20,29 @@ use fuel_core_types::{
             CompressedBlock,
             PartialFuelBlock,
         },
+        consensus::Consensus,
         header::{
             ApplicationHeader,
             ConsensusHeader,
             PartialBlockHeader,
         },
     },
-    services::executor::Error as ExecutorError,
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        ConsensusParameters,
+        Finalizable,
+        Transaction,
+        TransactionBuilder,
+    },
+    fuel_vm::checked_transaction::IntoChecked,
+    services::{
+        block_producer::DryRunOverrides,
+        executor::{
+            BlockLimits,
+            Error as ExecutorError,
+        },
+        txpool::PoolTransaction,
+    },
     tai64::Tai64,
 };
 use rand::{
@@ -31,9 +50,12 @@ use rand::{
     Rng,
     SeedableRng,
 };
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
 };
 
 #[tokio::test]
@@ -188,12 +210,287 @@ async fn production_fails_on_execution_error() {
     );
 }
 
+#[tokio::test]
+async fn production_fails_if_block_exceeds_size_limit() {
+    let script = TransactionBuilder::script(vec![], vec![])
+        .add_random_fee_input()
+        .finalize()
+        .into_checked_basic(1u32.into(), &ConsensusParameters::default())
+        .unwrap();
+    let mut ctx = TestContext::default();
+    ctx.txpool = MockTxPool(vec![Arc::new(PoolTransaction::Script(script))]);
+    ctx.config.block_limits = BlockLimits {
+        size: 1,
+        ..Default::default()
+    };
+    let producer = ctx.producer();
+
+    let err = producer
+        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000)
+        .await
+        .expect_err("expected failure");
+
+    assert!(
+        matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::BlockSizeLimitExceeded { limit: 1, .. })
+        ),
+        "unexpected err {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn production_uses_previous_da_height_if_selection_exceeds_deadline() {
+    let prev_da_height = 100u64.into();
+    let prev_height = 1u32.into();
+    let previous_block = PartialFuelBlock {
+        header: PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: prev_da_height,
+                ..Default::default()
+            },
+            consensus: ConsensusHeader {
+                height: prev_height,
+                ..Default::default()
+            },
+        },
+        transactions: vec![],
+    }
+    .generate(&[])
+    .compress(&Default::default());
+
+    let db = MockDb {
+        blocks: Arc::new(Mutex::new(
+            vec![(prev_height, previous_block)].into_iter().collect(),
+        )),
+    };
+    let mut ctx = TestContext {
+        relayer: MockRelayer {
+            best_finalized_height: prev_da_height + 10u64.into(),
+            delay: Duration::from_secs(60),
+            ..Default::default()
+        },
+        ..TestContext::default_from_db(db)
+    };
+    ctx.config.stage_deadlines = StageDeadlines {
+        selection: Some(Duration::from_millis(10)),
+        ..Default::default()
+    };
+    let producer = ctx.producer();
+
+    let result = producer
+        .produce_and_execute_block(prev_height + 1u32.into(), Tai64::now(), 1_000_000_000)
+        .await
+        .expect("The block should be produced");
+
+    assert_eq!(result.result().block.header().da_height, prev_da_height);
+}
+
+#[tokio::test]
+async fn production_skips_txpool_transactions_after_execution_deadline() {
+    let script = TransactionBuilder::script(vec![], vec![])
+        .add_random_fee_input()
+        .finalize()
+        .into_checked_basic(1u32.into(), &ConsensusParameters::default())
+        .unwrap();
+    let mut ctx = TestContext::default();
+    ctx.txpool = MockTxPool(vec![Arc::new(PoolTransaction::Script(script))]);
+    ctx.config.stage_deadlines = StageDeadlines {
+        execution: Some(Duration::ZERO),
+        ..Default::default()
+    };
+    let producer = ctx.producer();
+
+    let result = producer
+        .produce_and_execute_block(1u32.into(), Tai64::now(), 1_000_000_000)
+        .await
+        .expect("The block should be produced");
+
+    assert!(result.result().block.transactions().is_empty());
+}
+
+#[tokio::test]
+async fn block_builder_executes_injected_transactions_ahead_of_txpool() {
+    let pool_tx = TransactionBuilder::script(vec![], vec![])
+        .add_random_fee_input()
+        .finalize()
+        .into_checked_basic(1u32.into(), &ConsensusParameters::default())
+        .unwrap();
+    let pool_tx = Arc::new(PoolTransaction::Script(pool_tx));
+    let injected = TransactionBuilder::script(vec![], vec![])
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let mut ctx = TestContext::default();
+    ctx.txpool = MockTxPool(vec![pool_tx.clone()]);
+    let producer = ctx.producer();
+
+    let result = producer
+        .block_builder(1u32.into())
+        .transaction(injected.clone())
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(
+        result.result().block.transactions(),
+        &[injected.clone(), Transaction::from(pool_tx.as_ref())]
+    );
+
+    let result = producer
+        .block_builder(1u32.into())
+        .transaction(injected.clone())
+        .select_from_txpool(false)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(result.result().block.transactions(), &[injected]);
+}
+
+#[tokio::test]
+async fn block_builder_uses_the_provided_time_and_da_height() {
+    let time = Tai64(1_000);
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+
+    let result = producer
+        .block_builder(1u32.into())
+        .time(time)
+        .da_height(5u64.into())
+        .execute()
+        .await
+        .unwrap();
+
+    let header = result.result().block.header();
+    assert_eq!(header.time(), time);
+    assert_eq!(header.da_height, 5u64.into());
+}
+
+#[tokio::test]
+async fn block_builder_seals_the_block_with_the_signer() {
+    let secret = SecretKey::random(&mut StdRng::seed_from_u64(0));
+    let ctx = TestContext::default();
+    let producer = ctx.producer();
+
+    let (sealed_block, _) = producer
+        .block_builder(1u32.into())
+        .execute_and_seal(&secret)
+        .await
+        .unwrap();
+
+    let Consensus::PoA(poa) = sealed_block.consensus else {
+        panic!("expected the PoA seal")
+    };
+    let public_key = poa
+        .signature
+        .recover(&sealed_block.entity.id().into_message())
+        .unwrap();
+    assert_eq!(public_key, secret.public_key());
+}
+
+#[test]
+fn dry_run_component_uses_overrides_within_limits() {
+    let mut ctx = TestContext::default();
+    ctx.config.dry_run_limits = DryRunLimits {
+        max_height_offset: 10,
+        max_time_offset: Duration::from_secs(100),
+    };
+    let producer = ctx.producer();
+    let time = Tai64(Tai64::now().0 + 50);
+
+    let component = producer
+        .dry_run_component(
+            (),
+            0,
+            DryRunOverrides {
+                block_height: Some(11u32.into()),
+                block_time: Some(time),
+            },
+        )
+        .unwrap();
+    assert_eq!(component.header_to_produce.consensus.height, 11u32.into());
+    assert_eq!(component.header_to_produce.consensus.time, time);
+
+    let err = producer
+        .dry_run_component(
+            (),
+            0,
+            DryRunOverrides {
+                block_height: Some(12u32.into()),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::DryRunHeightOutOfRange { .. })
+    ));
+
+    let err = producer
+        .dry_run_component(
+            (),
+            0,
+            DryRunOverrides {
+                block_time: Some(Tai64(Tai64::now().0 + 1_000)),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::DryRunTimeOutOfRange { .. })
+    ));
+}
+
+#[test]
+fn dry_run_component_uses_time_of_advanced_clock() {
+    let mut ctx = TestContext::default();
+    ctx.config.dry_run_limits = DryRunLimits {
+        max_height_offset: 0,
+        max_time_offset: Duration::from_secs(100),
+    };
+    ctx.clock = MockChainClock { time_offset: 1_000 };
+    let producer = ctx.producer();
+
+    let component = producer
+        .dry_run_component((), 0, DryRunOverrides::default())
+        .unwrap();
+    assert!(component.header_to_produce.consensus.time.0 >= Tai64::now().0 + 1_000);
+
+    let time = Tai64(Tai64::now().0 + 1_050);
+    let component = producer
+        .dry_run_component(
+            (),
+            0,
+            DryRunOverrides {
+                block_time: Some(time),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(component.header_to_produce.consensus.time, time);
+
+    let err = producer
+        .dry_run_component(
+            (),
+            0,
+            DryRunOverrides {
+                block_time: Some(Tai64(Tai64::now().0 + 50)),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::DryRunTimeOutOfRange { .. })
+    ));
+}
+
 struct TestContext<Executor> {
     config: Config,
     db: MockDb,
     relayer: MockRelayer,
     executor: Arc<Executor>,
     txpool: MockTxPool,
+    clock: MockChainClock,
 }
 
 impl TestContext<MockExecutor> {
@@ -233,6 +530,7 @@ impl<Executor> TestContext<Executor> {
             relayer,
             executor: Arc::new(executor),
             txpool,
+            clock: MockChainClock::default(),
         }
     }
 
@@ -243,6 +541,8 @@ impl<Executor> TestContext<Executor> {
             txpool: self.txpool,
             executor: self.executor,
             relayer: Box::new(self.relayer),
+            gas_price_provider: None,
+            clock: Box::new(self.clock),
             lock: Default::default(),
         }
     }
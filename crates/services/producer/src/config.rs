@@ -1,8 +1,54 @@
-use fuel_core_types::fuel_types::Address;
+use fuel_core_types::{
+    fuel_types::Address,
+    services::executor::BlockLimits,
+};
+use std::time::Duration;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     pub utxo_validation: bool,
     pub coinbase_recipient: Address,
+    /// The limits of the produced blocks.
+    pub block_limits: BlockLimits,
+    /// The deadlines of the stages of the block production.
+    pub stage_deadlines: StageDeadlines,
+    /// The limits of the block height and time overridden by the dry runs.
+    pub dry_run_limits: DryRunLimits,
     pub metrics: bool,
 }
+
+/// The limits of the block height and time that the callers of the dry run can
+/// simulate. The overrides are only allowed ahead of the next block, so the contracts
+/// with the time-locked logic can be simulated in the future.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DryRunLimits {
+    /// The max number of the blocks between the next block and the simulated one.
+    pub max_height_offset: u32,
+    /// The max duration between the current time and the time of the simulated block.
+    pub max_time_offset: Duration,
+}
+
+impl Default for DryRunLimits {
+    fn default() -> Self {
+        Self {
+            max_height_offset: 2_592_000,
+            max_time_offset: Duration::from_secs(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+/// The deadlines of the stages of the block production. The stage exceeding its
+/// deadline produces a smaller block instead of delaying it, so a slow DA node or
+/// a slow contract doesn't cause a missed slot. `None` doesn't limit the stage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StageDeadlines {
+    /// The deadline of the selection of the DA height. The block doesn't include
+    /// the new messages of the DA layer if the relayer doesn't catch up in time.
+    pub selection: Option<Duration>,
+    /// The deadline of the execution of the transactions. The executor doesn't take
+    /// the new transactions from the `TxPool` after the deadline.
+    pub execution: Option<Duration>,
+    /// The deadline of the sealing and the import of the block. It is enforced by the
+    /// consensus service, which reduces the gas limit of the next block on overrun.
+    pub sealing: Option<Duration>,
+}
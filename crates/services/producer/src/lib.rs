@@ -2,12 +2,21 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+pub mod block_builder;
 pub mod block_producer;
 pub mod config;
 pub mod ports;
 
+pub use block_builder::{
+    BlockBuilder,
+    BlockSigner,
+};
 pub use block_producer::Producer;
-pub use config::Config;
+pub use config::{
+    Config,
+    DryRunLimits,
+    StageDeadlines,
+};
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mocks;
@@ -1,6 +1,9 @@
 use crate::ports::{
     BlockProducerDatabase,
+    ChainClock,
     Executor,
+    GasPriceProvider,
+    InjectedTransactions,
     Relayer,
     TxPool,
 };
@@ -34,10 +37,13 @@ use fuel_core_types::{
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
+            StateDiff,
+            TransactionProfile,
             UncommittedResult,
         },
         txpool::ArcPoolTx,
     },
+    tai64::Tai64,
 };
 use std::{
     borrow::Cow,
@@ -46,6 +52,10 @@ use std::{
         Arc,
         Mutex,
     },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 // TODO: Replace mocks with `mockall`.
@@ -54,6 +64,8 @@ use std::{
 pub struct MockRelayer {
     pub block_production_key: Address,
     pub best_finalized_height: DaBlockHeight,
+    /// The time the relayer waits before returning the best finalized height.
+    pub delay: Duration,
 }
 
 #[async_trait::async_trait]
@@ -63,10 +75,32 @@ impl Relayer for MockRelayer {
         &self,
         _: &DaBlockHeight,
     ) -> anyhow::Result<DaBlockHeight> {
+        tokio::time::sleep(self.delay).await;
         Ok(self.best_finalized_height)
     }
 }
 
+#[derive(Default, Clone)]
+pub struct MockGasPriceProvider(pub u64);
+
+impl GasPriceProvider for MockGasPriceProvider {
+    fn gas_price(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The wall clock advanced by the `time_offset` seconds.
+#[derive(Default, Clone)]
+pub struct MockChainClock {
+    pub time_offset: u64,
+}
+
+impl ChainClock for MockChainClock {
+    fn now(&self) -> Tai64 {
+        Tai64(Tai64::now().0.saturating_add(self.time_offset))
+    }
+}
+
 #[derive(Default)]
 pub struct MockTxPool(pub Vec<ArcPoolTx>);
 
@@ -74,8 +108,11 @@ pub struct MockTxPool(pub Vec<ArcPoolTx>);
 impl TxPool for MockTxPool {
     type TxSource = Vec<ArcPoolTx>;
 
-    fn get_source(&self, _: BlockHeight) -> Self::TxSource {
-        self.0.clone()
+    fn get_source(&self, _: BlockHeight, deadline: Option<Instant>) -> Self::TxSource {
+        match deadline {
+            Some(deadline) if deadline <= Instant::now() => vec![],
+            _ => self.0.clone(),
+        }
     }
 }
 
@@ -132,16 +169,27 @@ fn to_block(component: Components<Vec<ArcPoolTx>>) -> Block {
     Block::new(component.header_to_produce, transactions, &[])
 }
 
-impl Executor for MockExecutor {
-    type Database = MockDb;
-    /// The source of transaction used by the executor.
-    type TxSource = Vec<ArcPoolTx>;
+fn to_injected_block(
+    component: Components<InjectedTransactions<Vec<ArcPoolTx>>>,
+) -> Block {
+    let InjectedTransactions {
+        mut transactions,
+        source,
+    } = component.transactions_source;
+    transactions.extend(
+        source
+            .into_iter()
+            .flatten()
+            .map(|tx| fuel_tx::Transaction::from(tx.as_ref())),
+    );
+    Block::new(component.header_to_produce, transactions, &[])
+}
 
-    fn execute_without_commit(
+impl MockExecutor {
+    fn execute(
         &self,
-        component: Components<Self::TxSource>,
+        block: Block,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
-        let block = to_block(component);
         // simulate executor inserting a block
         let mut block_db = self.0.blocks.lock().unwrap();
         block_db.insert(
@@ -157,6 +205,26 @@ impl Executor for MockExecutor {
             StorageTransaction::new(self.0.clone()),
         ))
     }
+}
+
+impl Executor for MockExecutor {
+    type Database = MockDb;
+    /// The source of transaction used by the executor.
+    type TxSource = Vec<ArcPoolTx>;
+
+    fn execute_without_commit(
+        &self,
+        component: Components<Self::TxSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.execute(to_block(component))
+    }
+
+    fn execute_injected_without_commit(
+        &self,
+        component: Components<InjectedTransactions<Self::TxSource>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        self.execute(to_injected_block(component))
+    }
 
     fn dry_run(
         &self,
@@ -165,6 +233,21 @@ impl Executor for MockExecutor {
     ) -> ExecutorResult<Vec<Vec<Receipt>>> {
         Ok(Default::default())
     }
+
+    fn dry_run_profile(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)> {
+        Ok(Default::default())
+    }
+    fn dry_run_bundle(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)> {
+        Ok(Default::default())
+    }
 }
 
 pub struct FailingMockExecutor(pub Mutex<Option<ExecutorError>>);
@@ -195,6 +278,25 @@ impl Executor for FailingMockExecutor {
         }
     }
 
+    fn execute_injected_without_commit(
+        &self,
+        component: Components<InjectedTransactions<Self::TxSource>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<MockDb>>> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: to_injected_block(component),
+                    skipped_transactions: vec![],
+                    tx_status: vec![],
+                },
+                StorageTransaction::new(MockDb::default()),
+            ))
+        }
+    }
+
     fn dry_run(
         &self,
         _block: Components<fuel_tx::Transaction>,
@@ -207,6 +309,31 @@ impl Executor for FailingMockExecutor {
             Ok(Default::default())
         }
     }
+
+    fn dry_run_profile(
+        &self,
+        _block: Components<fuel_tx::Transaction>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
+    fn dry_run_bundle(
+        &self,
+        _block: Components<Vec<fuel_tx::Transaction>>,
+        _utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)> {
+        let mut err = self.0.lock().unwrap();
+        if let Some(err) = err.take() {
+            Err(err)
+        } else {
+            Ok(Default::default())
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
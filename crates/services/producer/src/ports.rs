@@ -18,11 +18,17 @@ use fuel_core_types::{
         block_producer::Components,
         executor::{
             Result as ExecutorResult,
+            StateDiff,
+            TransactionProfile,
             UncommittedResult,
         },
     },
+    tai64::Tai64,
+};
+use std::{
+    borrow::Cow,
+    time::Instant,
 };
-use std::borrow::Cow;
 
 pub trait BlockProducerDatabase: Send + Sync {
     /// Gets the committed block at the `height`.
@@ -40,11 +46,13 @@ pub trait TxPool: Send + Sync {
     /// The source of the transactions used by the executor.
     type TxSource;
 
-    /// Returns the source of includable transactions.
+    /// Returns the source of includable transactions. The source doesn't supply
+    /// the transactions after the `deadline`.
     fn get_source(
         &self,
         // could be used by the txpool to filter txs based on maturity
         block_height: BlockHeight,
+        deadline: Option<Instant>,
     ) -> Self::TxSource;
 }
 
@@ -58,6 +66,26 @@ pub trait Relayer: Send + Sync {
     ) -> anyhow::Result<DaBlockHeight>;
 }
 
+/// Provides the gas price of the next block calculated by the gas price algorithm.
+pub trait GasPriceProvider: Send + Sync {
+    /// Returns the gas price of the next block.
+    fn gas_price(&self) -> u64;
+}
+
+/// The clock of the chain. It may run ahead of the wall clock in the development mode.
+pub trait ChainClock: Send + Sync {
+    /// Returns the current time of the chain.
+    fn now(&self) -> Tai64;
+}
+
+/// The transactions injected into the block ahead of the transactions of the `source`.
+pub struct InjectedTransactions<TxSource> {
+    pub transactions: Vec<Transaction>,
+    /// The source of the remaining transactions of the block. `None` if the block
+    /// contains only the injected transactions.
+    pub source: Option<TxSource>,
+}
+
 pub trait Executor: Send + Sync {
     /// The database used by the executor.
     type Database;
@@ -71,6 +99,13 @@ pub trait Executor: Send + Sync {
         component: Components<Self::TxSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
 
+    /// Executes the block with the injected transactions and returns the result
+    /// of execution with uncommitted database transaction.
+    fn execute_injected_without_commit(
+        &self,
+        component: Components<InjectedTransactions<Self::TxSource>>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
+
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
     /// of utxos during execution.
@@ -79,4 +114,21 @@ pub trait Executor: Send + Sync {
         block: Components<Transaction>,
         utxo_validation: Option<bool>,
     ) -> ExecutorResult<Vec<Vec<Receipt>>>;
+
+    /// Executes the dry run like the `dry_run`, and additionally collects the gas and
+    /// memory profile of the executed script.
+    fn dry_run_profile(
+        &self,
+        block: Components<Transaction>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Receipt>, TransactionProfile)>;
+
+    /// Executes the ordered bundle of transactions without committing it to the
+    /// database. Returns the receipts of each transaction and the state diff of the
+    /// whole bundle.
+    fn dry_run_bundle(
+        &self,
+        block: Components<Vec<Transaction>>,
+        utxo_validation: Option<bool>,
+    ) -> ExecutorResult<(Vec<Vec<Receipt>>, StateDiff)>;
 }
@@ -10,4 +10,25 @@ pub mod bridge {
             event MessageSent(bytes32 indexed sender, bytes32 indexed recipient, uint256 indexed nonce, uint64 amount, bytes data)
         ]"#,
     );
+    // The event emitted by the admin of the bridge when the message sent before
+    // is cancelled and can't be spent on the Fuel network anymore.
+    super::abigen!(
+        MessageCancelled,
+        r#"[
+            event MessageCancelled(uint256 indexed nonce)
+        ]"#,
+    );
+}
+
+pub mod fuel_chain_state {
+    // The functions of the `FuelChainState` contract from the `fuel-bridge` repository
+    // that accept and check the commitments of the Fuel blocks.
+    super::abigen!(
+        FuelChainState,
+        r#"[
+            function commit(bytes32 blockHash, uint256 commitHeight) external
+            function blockHashAtCommit(uint256 commitHeight) external view returns (bytes32)
+            function finalized(bytes32 blockHash, uint256 blockHeight) external view returns (bool)
+        ]"#,
+    );
 }
@@ -0,0 +1,108 @@
+//! Reads the blob base fee introduced by EIP-4844 from the Ethereum node used by
+//! the relayer, so the gas price can follow the cost of the DA layer.
+
+use ethers_core::types::U256;
+use ethers_providers::{
+    Http,
+    JsonRpcClient,
+    Provider,
+};
+
+/// The Ethereum JSON-RPC method that returns the current blob base fee.
+const BLOB_BASE_FEE_METHOD: &str = "eth_blobBaseFee";
+
+/// The lowest blob base fee defined by EIP-4844.
+const MIN_BLOB_BASE_FEE: u128 = 1;
+
+/// Requests the blob base fee from the Ethereum node.
+pub struct EthBlobBaseFee<P = Http> {
+    provider: Provider<P>,
+}
+
+impl<P> EthBlobBaseFee<P>
+where
+    P: JsonRpcClient,
+{
+    /// Creates the source of the blob base fee from the `provider`.
+    pub fn new(provider: Provider<P>) -> Self {
+        Self { provider }
+    }
+
+    /// Requests the current blob base fee in wei per unit of the blob gas.
+    pub async fn blob_base_fee(&self) -> anyhow::Result<u128> {
+        let blob_base_fee: U256 = self.provider.request(BLOB_BASE_FEE_METHOD, ()).await?;
+        u128::try_from(blob_base_fee)
+            .map_err(|_| anyhow::anyhow!("The blob base fee {blob_base_fee} is too high"))
+    }
+}
+
+/// Creates the source of the blob base fee that uses the same Ethereum node as
+/// the relayer.
+pub fn new_blob_base_fee(relayer: &crate::Config) -> anyhow::Result<EthBlobBaseFee> {
+    let url = relayer.relayer.clone().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Tried to request the blob base fee without setting an eth_client in the config"
+        )
+    })?;
+    Ok(EthBlobBaseFee::new(Provider::new(Http::new(url))))
+}
+
+/// Calculates the blob base fee of the block from its excess blob gas, as defined
+/// by EIP-4844. The `update_fraction` is the denominator of the fee growth set by
+/// the active Ethereum fork. Saturates at `u128::MAX` if the fee doesn't fit.
+pub fn blob_base_fee_from_excess(excess_blob_gas: u128, update_fraction: u64) -> u128 {
+    let update_fraction = u128::from(update_fraction.max(1));
+    // The Taylor expansion of `MIN_BLOB_BASE_FEE * e ^ (excess / fraction)`.
+    let mut output: u128 = 0;
+    let mut accumulator = MIN_BLOB_BASE_FEE * update_fraction;
+    let mut i = 1;
+    while accumulator > 0 {
+        output = output.saturating_add(accumulator);
+        let Some(next) = accumulator.checked_mul(excess_blob_gas) else {
+            return u128::MAX
+        };
+        accumulator = next / update_fraction.saturating_mul(i);
+        i += 1;
+    }
+    output / update_fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const CANCUN_UPDATE_FRACTION: u64 = 3_338_477;
+    const PRAGUE_UPDATE_FRACTION: u64 = 5_007_716;
+
+    #[test_case(CANCUN_UPDATE_FRACTION; "cancun")]
+    #[test_case(PRAGUE_UPDATE_FRACTION; "prague")]
+    fn blob_base_fee_grows_exponentially_with_excess_blob_gas(update_fraction: u64) {
+        let fraction = u128::from(update_fraction);
+        assert_eq!(
+            blob_base_fee_from_excess(0, update_fraction),
+            MIN_BLOB_BASE_FEE
+        );
+        // `e ^ 1` rounded down.
+        assert_eq!(blob_base_fee_from_excess(fraction, update_fraction), 2);
+        // `e ^ 10` rounded down.
+        assert_eq!(
+            blob_base_fee_from_excess(10 * fraction, update_fraction),
+            22026
+        );
+        assert_eq!(
+            blob_base_fee_from_excess(u128::MAX, update_fraction),
+            u128::MAX
+        );
+    }
+
+    #[test]
+    fn blob_base_fee_depends_on_update_fraction() {
+        let excess = 10 * u128::from(CANCUN_UPDATE_FRACTION);
+
+        assert!(
+            blob_base_fee_from_excess(excess, PRAGUE_UPDATE_FRACTION)
+                < blob_base_fee_from_excess(excess, CANCUN_UPDATE_FRACTION)
+        );
+    }
+}
@@ -0,0 +1,609 @@
+//! The block committer posts the commitments of the Fuel blocks to the `FuelChainState`
+//! contract on the data availability layer. It uses the same Ethereum node as the relayer.
+//!
+//! The balance of the wallet that pays for the commitments is checked periodically and
+//! reported by the metrics, so the commitments don't halt silently when it runs out.
+
+use crate::{
+    abi::fuel_chain_state::FuelChainState,
+    ports::ImportedBlocks,
+};
+use async_trait::async_trait;
+use core::time::Duration;
+use ethers_contract::ContractCall;
+use ethers_core::types::{
+    H160,
+    H256,
+    U256,
+};
+use ethers_middleware::SignerMiddleware;
+use ethers_providers::{
+    Http,
+    Middleware,
+    Provider,
+};
+use ethers_signers::Signer;
+use fuel_core_metrics::relayer_metrics::relayer_metrics;
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    entities::message::CommitmentState,
+    fuel_types::BlockHeight,
+};
+use futures::StreamExt;
+use std::{
+    num::NonZeroU32,
+    sync::Arc,
+};
+use tokio::{
+    sync::{
+        watch,
+        OnceCell,
+    },
+    time::{
+        Instant,
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+pub use ethers_signers::LocalWallet;
+
+#[cfg(test)]
+mod test;
+
+/// The delay before the next attempt to post the failed commitment.
+const RETRY_DELAY: Duration = Duration::from_secs(10);
+/// The deadline of the request of the balance of the wallet. The commitments wait
+/// for the balance check, so it must not hang on the unresponsive Ethereum node.
+const BALANCE_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug)]
+/// Configuration settings for the block committer.
+pub struct Config {
+    /// The address of the `FuelChainState` contract.
+    pub state_contract: H160,
+    /// The wallet that signs the commitments. It must be allowed to commit
+    /// to the `state_contract`.
+    pub wallet: LocalWallet,
+    /// The commitment is posted for each block with the height divisible by
+    /// the interval. It must be equal to the `BLOCKS_PER_COMMIT_INTERVAL` of the
+    /// `state_contract`.
+    pub commit_interval: NonZeroU32,
+    /// How often the balance of the `wallet` is checked. It must be positive.
+    pub balance_check_interval: Duration,
+    /// The balance of the `wallet` in wei below which the low balance is reported.
+    /// `None` never reports the low balance.
+    pub min_wallet_balance: Option<U256>,
+}
+
+/// Configuration settings for reading the commitments without posting them.
+#[derive(Clone, Debug)]
+pub struct ReaderConfig {
+    /// The address of the `FuelChainState` contract.
+    pub state_contract: H160,
+    /// The interval of the commitments. It must be equal to the
+    /// `BLOCKS_PER_COMMIT_INTERVAL` of the `state_contract`.
+    pub commit_interval: NonZeroU32,
+}
+
+/// The balance of the wallet of the block committer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WalletBalance {
+    /// The balance in wei.
+    pub balance: U256,
+    /// `true` if the balance is below the `Config::min_wallet_balance`.
+    pub low: bool,
+}
+
+/// Posts the commitment of the block to the DA layer and checks its state.
+#[async_trait]
+pub trait CommitmentPoster: Send + Sync {
+    /// Posts the commitment and returns the hash of the DA transaction.
+    async fn post_commitment(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<H256>;
+
+    /// Returns the state of the commitment of the block on the DA layer.
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState>;
+
+    /// Returns the balance of the wallet that pays for the commitments in wei.
+    async fn wallet_balance(&self) -> anyhow::Result<U256>;
+}
+
+#[async_trait]
+impl<C> CommitmentPoster for Arc<C>
+where
+    C: CommitmentPoster + ?Sized,
+{
+    async fn post_commitment(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<H256> {
+        self.as_ref().post_commitment(height, block_id).await
+    }
+
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        self.as_ref().commitment_state(height, block_id).await
+    }
+
+    async fn wallet_balance(&self) -> anyhow::Result<U256> {
+        self.as_ref().wallet_balance().await
+    }
+}
+
+/// Reads the commitments of the blocks from the `FuelChainState` contract.
+/// It doesn't sign anything, so it works on any node connected to the Ethereum node.
+#[derive(Clone)]
+pub struct CommitmentReader {
+    contract: FuelChainState<Provider<Http>>,
+    commit_interval: NonZeroU32,
+}
+
+impl CommitmentReader {
+    /// Creates the reader of the commitments of the `state_contract`.
+    pub fn new(
+        provider: Provider<Http>,
+        state_contract: H160,
+        commit_interval: NonZeroU32,
+    ) -> Self {
+        Self {
+            contract: FuelChainState::new(state_contract, provider.into()),
+            commit_interval,
+        }
+    }
+
+    /// Returns the height of the first committed block that follows the block
+    /// at `height`. The output messages of the block are proven against it.
+    pub fn commit_height(&self, height: BlockHeight) -> BlockHeight {
+        commit_height(height, self.commit_interval)
+    }
+
+    /// Returns the state of the commitment of the block on the DA layer.
+    pub async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        let slot = commit_slot(height, self.commit_interval);
+        let block_hash: [u8; 32] = block_id.into();
+        let committed = self.contract.block_hash_at_commit(slot).call().await?;
+        if committed != block_hash {
+            return Ok(CommitmentState::NotCommitted)
+        }
+        let height = U256::from(u32::from(height));
+        if self.contract.finalized(block_hash, height).call().await? {
+            Ok(CommitmentState::Finalized)
+        } else {
+            Ok(CommitmentState::Committed)
+        }
+    }
+}
+
+/// The [`CommitmentPoster`] that sends the commitments to the Ethereum node.
+pub struct EthCommitter {
+    provider: Provider<Http>,
+    wallet: LocalWallet,
+    state_contract: H160,
+    reader: CommitmentReader,
+    /// The contract is created during the first commitment because the chain id
+    /// of the wallet is requested from the Ethereum node.
+    contract: OnceCell<FuelChainState<SignerMiddleware<Provider<Http>, LocalWallet>>>,
+}
+
+impl EthCommitter {
+    /// Creates the committer that signs the commitments with the `wallet`.
+    pub fn new(
+        provider: Provider<Http>,
+        wallet: LocalWallet,
+        state_contract: H160,
+        commit_interval: NonZeroU32,
+    ) -> Self {
+        Self {
+            reader: CommitmentReader::new(
+                provider.clone(),
+                state_contract,
+                commit_interval,
+            ),
+            provider,
+            wallet,
+            state_contract,
+            contract: OnceCell::new(),
+        }
+    }
+
+    async fn contract(
+        &self,
+    ) -> anyhow::Result<&FuelChainState<SignerMiddleware<Provider<Http>, LocalWallet>>>
+    {
+        self.contract
+            .get_or_try_init(|| async {
+                let chain_id = self.provider.get_chainid().await?.as_u64();
+                let client = SignerMiddleware::new(
+                    self.provider.clone(),
+                    self.wallet.clone().with_chain_id(chain_id),
+                );
+                Ok(FuelChainState::new(self.state_contract, client.into()))
+            })
+            .await
+    }
+}
+
+/// Returns the height of the first block divisible by the `commit_interval`
+/// that follows the block at `height`.
+fn commit_height(height: BlockHeight, commit_interval: NonZeroU32) -> BlockHeight {
+    let interval = commit_interval.get();
+    let height = u32::from(height);
+    (height - height % interval).saturating_add(interval).into()
+}
+
+/// Returns the slot of the `FuelChainState` contract that stores the commitment
+/// of the block at `height`. The contract indexes the commitments by the number
+/// of the commit interval, not by the height of the committed block.
+fn commit_slot(height: BlockHeight, commit_interval: NonZeroU32) -> U256 {
+    U256::from(u32::from(height) / commit_interval.get())
+}
+
+/// Creates the call that commits the block at `height` to its slot of the `contract`.
+fn commit_call<M: Middleware>(
+    contract: &FuelChainState<M>,
+    height: BlockHeight,
+    block_id: BlockId,
+    commit_interval: NonZeroU32,
+) -> ContractCall<M, ()> {
+    contract.commit(block_id.into(), commit_slot(height, commit_interval))
+}
+
+#[async_trait]
+impl CommitmentPoster for EthCommitter {
+    async fn post_commitment(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<H256> {
+        let contract = self.contract().await?;
+        let call = commit_call(contract, height, block_id, self.reader.commit_interval);
+        let pending = call.send().await?;
+        let receipt = pending.await?.ok_or_else(|| {
+            anyhow::anyhow!("The commitment transaction was dropped from the mempool")
+        })?;
+        Ok(receipt.transaction_hash)
+    }
+
+    async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        self.reader.commitment_state(height, block_id).await
+    }
+
+    async fn wallet_balance(&self) -> anyhow::Result<U256> {
+        let balance = self
+            .provider
+            .get_balance(self.wallet.address(), None)
+            .await?;
+        Ok(balance)
+    }
+}
+
+/// The alias of runnable block committer service.
+pub type Service = CustomizableService<Arc<EthCommitter>>;
+type CustomizableService<C> = ServiceRunner<NotInitializedTask<C>>;
+
+/// The shared state of the block committer task.
+#[derive(Clone)]
+pub struct SharedState<C> {
+    poster: C,
+    commit_interval: NonZeroU32,
+    wallet_balance: watch::Receiver<Option<WalletBalance>>,
+}
+
+impl<C> SharedState<C>
+where
+    C: CommitmentPoster,
+{
+    /// Returns the height of the first committed block that follows the block
+    /// at `height`. The output messages of the block are proven against it.
+    pub fn commit_height(&self, height: BlockHeight) -> BlockHeight {
+        commit_height(height, self.commit_interval)
+    }
+
+    /// Returns the state of the commitment of the block on the DA layer.
+    pub async fn commitment_state(
+        &self,
+        height: BlockHeight,
+        block_id: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        self.poster.commitment_state(height, block_id).await
+    }
+
+    /// Returns the balance of the wallet from the latest check.
+    /// `None` if the balance wasn't checked yet.
+    pub fn wallet_balance(&self) -> Option<WalletBalance> {
+        *self.wallet_balance.borrow()
+    }
+}
+
+/// Not initialized version of the [`Task`].
+pub struct NotInitializedTask<C> {
+    poster: C,
+    blocks: BoxStream<(BlockHeight, BlockId)>,
+    commit_interval: NonZeroU32,
+    balance_check_interval: Duration,
+    min_wallet_balance: Option<U256>,
+    wallet_balance: watch::Sender<Option<WalletBalance>>,
+}
+
+/// The background task that posts the commitments of the imported blocks.
+pub struct Task<C> {
+    poster: C,
+    blocks: BoxStream<(BlockHeight, BlockId)>,
+    commit_interval: NonZeroU32,
+    /// The latest block that should be committed, but the commitment wasn't posted yet.
+    pending: Option<(BlockHeight, BlockId)>,
+    /// The time of the next attempt to post the failed commitment.
+    retry_at: Option<Instant>,
+    balance_check: Interval,
+    min_wallet_balance: Option<U256>,
+    wallet_balance: watch::Sender<Option<WalletBalance>>,
+}
+
+#[async_trait]
+impl<C> RunnableService for NotInitializedTask<C>
+where
+    C: CommitmentPoster + Clone + 'static,
+{
+    const NAME: &'static str = "BlockCommitter";
+
+    type SharedData = SharedState<C>;
+    type Task = Task<C>;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            poster: self.poster.clone(),
+            commit_interval: self.commit_interval,
+            wallet_balance: self.wallet_balance.subscribe(),
+        }
+    }
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let NotInitializedTask {
+            poster,
+            blocks,
+            commit_interval,
+            balance_check_interval,
+            min_wallet_balance,
+            wallet_balance,
+        } = self;
+        let mut balance_check = tokio::time::interval(balance_check_interval);
+        balance_check.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        Ok(Task {
+            poster,
+            blocks,
+            commit_interval,
+            pending: None,
+            retry_at: None,
+            balance_check,
+            min_wallet_balance,
+            wallet_balance,
+        })
+    }
+}
+
+impl<C> Task<C>
+where
+    C: CommitmentPoster,
+{
+    /// Posts the pending commitment. If it fails, the next attempt is made after
+    /// the [`RETRY_DELAY`].
+    async fn post_pending(&mut self) {
+        let Some((height, block_id)) = self.pending else {
+            return
+        };
+        match self.poster.post_commitment(height, block_id).await {
+            Ok(tx_hash) => {
+                tracing::info!(
+                    "Committed the block {} at {height} to the DA layer in {tx_hash:?}",
+                    block_id
+                );
+                self.pending = None;
+                self.retry_at = None;
+            }
+            Err(err) => {
+                tracing::warn!("Failed to commit the block at {height}: {err:?}");
+                self.retry_at = Some(Instant::now() + RETRY_DELAY);
+            }
+        }
+    }
+
+    /// Checks the balance of the wallet and reports it if it is below the threshold.
+    async fn check_balance(&mut self) {
+        let balance =
+            tokio::time::timeout(BALANCE_CHECK_TIMEOUT, self.poster.wallet_balance())
+                .await;
+        let balance = match balance {
+            Ok(Ok(balance)) => balance,
+            Ok(Err(err)) => {
+                tracing::warn!(
+                    "Failed to get the balance of the committer wallet: {err:?}"
+                );
+                return
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "The request of the balance of the committer wallet timed out"
+                );
+                return
+            }
+        };
+        let low = self.min_wallet_balance.map_or(false, |min| balance < min);
+        if low {
+            tracing::warn!(
+                "The balance of the committer wallet is low: {balance} wei, \
+                 the commitments halt when it runs out"
+            );
+        }
+        // The balance doesn't fit into `u128` only on the test networks.
+        let wei = u128::try_from(balance).unwrap_or(u128::MAX);
+        relayer_metrics().set_committer_wallet_balance(wei as f64 / 1e18, low);
+        self.wallet_balance
+            .send_replace(Some(WalletBalance { balance, low }));
+    }
+
+    fn on_block(&mut self, height: BlockHeight, block_id: BlockId) {
+        if u32::from(height) % self.commit_interval.get() == 0 {
+            // The newer commitment supersedes the pending one.
+            self.pending = Some((height, block_id));
+        }
+    }
+}
+
+#[async_trait]
+impl<C> RunnableTask for Task<C>
+where
+    C: CommitmentPoster + Clone + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let mut should_continue = true;
+        let retry_at = self.retry_at;
+        if self.pending.is_some() && retry_at.map_or(true, |at| at <= Instant::now()) {
+            tokio::select! {
+                biased;
+                _ = watcher.while_started() => {
+                    should_continue = false;
+                }
+                _ = self.post_pending() => {}
+            }
+            return Ok(should_continue)
+        }
+
+        let retry = async move {
+            match retry_at {
+                Some(at) => tokio::time::sleep_until(at).await,
+                None => futures::future::pending().await,
+            }
+        };
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+            block = self.blocks.next() => {
+                if let Some((height, block_id)) = block {
+                    self.on_block(height, block_id);
+                } else {
+                    tracing::error!("The stream of imported blocks is closed");
+                    should_continue = false;
+                }
+            }
+            _ = retry => {}
+            _ = self.balance_check.tick() => {
+                self.check_balance().await;
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // Nothing to shut down because we don't have any temporary state that should be dumped,
+        // and we don't spawn any sub-tasks that we need to finish or await.
+        // The pending commitment is dropped, the next one supersedes it after the restart.
+        Ok(())
+    }
+}
+
+/// Creates an instance of runnable block committer service. It uses the Ethereum
+/// node of the `relayer`.
+pub fn new_service<B>(
+    relayer: &crate::Config,
+    config: Config,
+    blocks: B,
+) -> anyhow::Result<Service>
+where
+    B: ImportedBlocks,
+{
+    let url = relayer.relayer.clone().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Tried to start BlockCommitter without setting an eth_client in the config"
+        )
+    })?;
+    anyhow::ensure!(
+        !config.balance_check_interval.is_zero(),
+        "The interval of the balance check of the committer wallet must be positive"
+    );
+    let provider = Provider::new(Http::new(url));
+    let poster = Arc::new(EthCommitter::new(
+        provider,
+        config.wallet,
+        config.state_contract,
+        config.commit_interval,
+    ));
+    Ok(new_service_internal(
+        poster,
+        blocks.imported_blocks(),
+        config.commit_interval,
+        config.balance_check_interval,
+        config.min_wallet_balance,
+    ))
+}
+
+/// Creates the reader of the commitments that uses the Ethereum node of the `relayer`.
+pub fn new_reader(
+    relayer: &crate::Config,
+    config: &ReaderConfig,
+) -> anyhow::Result<CommitmentReader> {
+    let url = relayer.relayer.clone().ok_or_else(|| {
+        anyhow::anyhow!(
+            "Tried to read the commitments without setting an eth_client in the config"
+        )
+    })?;
+    Ok(CommitmentReader::new(
+        Provider::new(Http::new(url)),
+        config.state_contract,
+        config.commit_interval,
+    ))
+}
+
+fn new_service_internal<C>(
+    poster: C,
+    blocks: BoxStream<(BlockHeight, BlockId)>,
+    commit_interval: NonZeroU32,
+    balance_check_interval: Duration,
+    min_wallet_balance: Option<U256>,
+) -> CustomizableService<C>
+where
+    C: CommitmentPoster + Clone + 'static,
+{
+    let (wallet_balance, _) = watch::channel(None);
+    ServiceRunner::new(NotInitializedTask {
+        poster,
+        blocks,
+        commit_interval,
+        balance_check_interval,
+        min_wallet_balance,
+        wallet_balance,
+    })
+}
@@ -0,0 +1,217 @@
+use super::*;
+use fuel_core_services::stream::IntoBoxStream;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use tokio::sync::mpsc;
+
+/// The balance of the wallet of the [`TestPoster`] in wei.
+const BALANCE: u64 = 1_000_000;
+
+/// Reports each attempt to post the commitment and fails the first `failures` of them.
+/// The balance of the wallet is returned after the `balance_delay`.
+struct TestPoster {
+    attempts: mpsc::UnboundedSender<BlockHeight>,
+    failures: AtomicUsize,
+    balance_delay: Duration,
+}
+
+#[async_trait]
+impl CommitmentPoster for TestPoster {
+    async fn post_commitment(
+        &self,
+        height: BlockHeight,
+        _: BlockId,
+    ) -> anyhow::Result<H256> {
+        self.attempts.send(height).unwrap();
+        let failed = self
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |f| f.checked_sub(1))
+            .is_ok();
+        if failed {
+            Err(anyhow::anyhow!("DA node is unavailable"))
+        } else {
+            Ok(H256::zero())
+        }
+    }
+
+    async fn commitment_state(
+        &self,
+        _: BlockHeight,
+        _: BlockId,
+    ) -> anyhow::Result<CommitmentState> {
+        Ok(CommitmentState::Committed)
+    }
+
+    async fn wallet_balance(&self) -> anyhow::Result<U256> {
+        tokio::time::sleep(self.balance_delay).await;
+        Ok(BALANCE.into())
+    }
+}
+
+async fn start(
+    heights: std::ops::RangeInclusive<u32>,
+    commit_interval: u32,
+    failures: usize,
+) -> (
+    CustomizableService<Arc<TestPoster>>,
+    mpsc::UnboundedReceiver<BlockHeight>,
+) {
+    let (attempts, receiver) = mpsc::unbounded_channel();
+    let poster = Arc::new(TestPoster {
+        attempts,
+        failures: AtomicUsize::new(failures),
+        balance_delay: Duration::ZERO,
+    });
+    let blocks = futures::stream::iter(
+        heights.map(|height| (height.into(), BlockId::from([height as u8; 32]))),
+    )
+    .chain(futures::stream::pending())
+    .into_boxed();
+    let service = new_service_internal(
+        poster,
+        blocks,
+        NonZeroU32::new(commit_interval).unwrap(),
+        Duration::from_secs(60),
+        None,
+    );
+    service.start_and_await().await.unwrap();
+    (service, receiver)
+}
+
+#[tokio::test(start_paused = true)]
+async fn commits_blocks_at_interval() {
+    let (service, mut attempts) = start(1..=5, 2, 0).await;
+
+    assert_eq!(attempts.recv().await, Some(2u32.into()));
+    assert_eq!(attempts.recv().await, Some(4u32.into()));
+    tokio::time::sleep(RETRY_DELAY * 2).await;
+    assert!(attempts.try_recv().is_err());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn retries_failed_commitment() {
+    let (service, mut attempts) = start(1..=2, 2, 1).await;
+
+    assert_eq!(attempts.recv().await, Some(2u32.into()));
+    let failed_at = Instant::now();
+    assert_eq!(attempts.recv().await, Some(2u32.into()));
+    assert!(failed_at.elapsed() >= RETRY_DELAY);
+    tokio::time::sleep(RETRY_DELAY * 2).await;
+    assert!(attempts.try_recv().is_err());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn newer_block_supersedes_failed_commitment() {
+    let (service, mut attempts) = start(1..=4, 2, 1).await;
+
+    assert_eq!(attempts.recv().await, Some(2u32.into()));
+    assert_eq!(attempts.recv().await, Some(4u32.into()));
+    tokio::time::sleep(RETRY_DELAY * 2).await;
+    assert!(attempts.try_recv().is_err());
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn output_messages_are_proven_against_next_commitment() {
+    let (service, _attempts) = start(1..=1, 3, 0).await;
+    let shared = &service.shared;
+
+    assert_eq!(shared.commit_height(0u32.into()), 3u32.into());
+    assert_eq!(shared.commit_height(2u32.into()), 3u32.into());
+    assert_eq!(shared.commit_height(3u32.into()), 6u32.into());
+    assert_eq!(
+        shared
+            .commitment_state(3u32.into(), Default::default())
+            .await
+            .unwrap(),
+        CommitmentState::Committed
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn wallet_balance_below_threshold_is_reported_as_low() {
+    let (attempts, _receiver) = mpsc::unbounded_channel();
+    let poster = Arc::new(TestPoster {
+        attempts,
+        failures: AtomicUsize::new(0),
+        balance_delay: Duration::ZERO,
+    });
+    let service = new_service_internal(
+        poster,
+        futures::stream::pending().into_boxed(),
+        NonZeroU32::new(1).unwrap(),
+        Duration::from_secs(60),
+        Some((BALANCE + 1).into()),
+    );
+    let mut wallet_balance = service.shared.wallet_balance.clone();
+    assert_eq!(service.shared.wallet_balance(), None);
+    service.start_and_await().await.unwrap();
+
+    // The balance is checked right after the start.
+    wallet_balance.changed().await.unwrap();
+    assert_eq!(
+        service.shared.wallet_balance(),
+        Some(WalletBalance {
+            balance: BALANCE.into(),
+            low: true,
+        })
+    );
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test(start_paused = true)]
+async fn unresponsive_balance_check_does_not_halt_commitments() {
+    let (attempts, mut receiver) = mpsc::unbounded_channel();
+    let poster = Arc::new(TestPoster {
+        attempts,
+        failures: AtomicUsize::new(0),
+        balance_delay: Duration::MAX,
+    });
+    // The block is imported while the balance of the wallet is requested.
+    let blocks = futures::stream::once(async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        (1u32.into(), BlockId::from([1; 32]))
+    })
+    .chain(futures::stream::pending())
+    .into_boxed();
+    let service = new_service_internal(
+        poster,
+        blocks,
+        NonZeroU32::new(1).unwrap(),
+        Duration::from_secs(60),
+        None,
+    );
+    service.start_and_await().await.unwrap();
+
+    assert_eq!(receiver.recv().await, Some(1u32.into()));
+    assert_eq!(service.shared.wallet_balance(), None);
+
+    service.stop_and_await().await.unwrap();
+}
+
+#[test]
+fn commitment_is_posted_to_the_slot_of_the_commit_interval() {
+    use crate::abi::fuel_chain_state::CommitCall;
+    use ethers_core::abi::AbiDecode;
+
+    let (provider, _) = Provider::mocked();
+    let contract = FuelChainState::new(H160::zero(), Arc::new(provider));
+    let block_id = BlockId::from([1; 32]);
+
+    let call = commit_call(&contract, 30u32.into(), block_id, 10.try_into().unwrap());
+    let data = call.tx.data().expect("The call has the data");
+    let posted = CommitCall::decode(data).unwrap();
+
+    assert_eq!(posted.commit_height, U256::from(3));
+    assert_eq!(posted.block_hash, <[u8; 32]>::from(block_id));
+}
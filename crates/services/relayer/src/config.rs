@@ -13,6 +13,9 @@ use std::{
 pub(crate) static ETH_LOG_MESSAGE: Lazy<H256> =
     Lazy::new(crate::abi::bridge::MessageSentFilter::signature);
 
+pub(crate) static ETH_LOG_MESSAGE_CANCELLED: Lazy<H256> =
+    Lazy::new(crate::abi::bridge::MessageCancelledFilter::signature);
+
 // TODO: Move settlement fields into `ChainConfig` because it is part of the consensus.
 #[derive(Clone, Debug)]
 /// Configuration settings for the Relayer.
@@ -36,6 +39,12 @@ pub struct Config {
     /// How often progress logs are printed when the DA node is
     /// syncing.
     pub syncing_log_frequency: Duration,
+    /// The number of the latest fee samples of the finalized DA blocks kept
+    /// in the database. The samples aren't recorded if it is zero.
+    pub da_fee_window: usize,
+    /// The denominator of the blob base fee growth of the active Ethereum fork,
+    /// used to calculate the blob base fee of the finalized DA blocks.
+    pub blob_base_fee_update_fraction: u64,
 
     /// Enables metrics on this fuel service
     pub metrics: bool,
@@ -48,6 +57,9 @@ impl Config {
     pub const DEFAULT_SYNC_MINIMUM_DURATION: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_CALL_FREQ: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_LOG_FREQ: Duration = Duration::from_secs(60);
+    pub const DEFAULT_DA_FEE_WINDOW: usize = 256;
+    /// The update fraction of the blob base fee since the Prague upgrade (EIP-7691).
+    pub const DEFAULT_BLOB_BASE_FEE_UPDATE_FRACTION: u64 = 5_007_716;
 }
 
 impl Default for Config {
@@ -63,7 +75,45 @@ impl Default for Config {
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
             syncing_log_frequency: Self::DEFAULT_SYNCING_LOG_FREQ,
+            da_fee_window: Self::DEFAULT_DA_FEE_WINDOW,
+            blob_base_fee_update_fraction: Self::DEFAULT_BLOB_BASE_FEE_UPDATE_FRACTION,
             metrics: false,
         }
     }
 }
+
+/// Configuration settings for the relayer of an additional DA network. Its messages
+/// are stored apart from the messages of the settlement layer, under the chain id
+/// of the network.
+#[derive(Clone, Debug)]
+pub struct OriginConfig {
+    /// The chain id of the DA network.
+    pub origin_chain_id: u64,
+    /// The settings of the relayer of the DA network.
+    pub relayer: Config,
+}
+
+/// The intervals of the relayer loop that can be changed while the relayer is running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PollIntervals {
+    /// This throttles the background relayer loop to
+    /// at least this duration to prevent spamming the DA node.
+    pub sync_minimum_duration: Duration,
+    /// How often calls are made to the DA node when the DA node
+    /// is in the process of syncing.
+    pub syncing_call_frequency: Duration,
+    /// How often progress logs are printed when the DA node is
+    /// syncing.
+    pub syncing_log_frequency: Duration,
+}
+
+impl Config {
+    /// Returns the initial intervals of the relayer loop.
+    pub fn poll_intervals(&self) -> PollIntervals {
+        PollIntervals {
+            sync_minimum_duration: self.sync_minimum_duration,
+            syncing_call_frequency: self.syncing_call_frequency,
+            syncing_log_frequency: self.syncing_log_frequency,
+        }
+    }
+}
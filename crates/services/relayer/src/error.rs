@@ -0,0 +1,51 @@
+//! The errors of the relayer. The kinds of the errors separate the failures of the
+//! DA node from the failures of the node itself.
+
+use ethers_providers::ProviderError;
+use fuel_core_storage::Error as StorageError;
+
+pub use fuel_core_types::services::relayer::RelayerErrorKind;
+
+/// The error that fails the iteration of the relayer loop.
+#[derive(thiserror::Error, Debug)]
+pub enum RelayerError {
+    /// The request to the RPC of the DA node failed.
+    #[error("The RPC request to the DA node failed: {0}")]
+    Rpc(#[from] ProviderError),
+    /// The DA node returned the data that the relayer can't decode,
+    /// or that is inconsistent with the already synced data.
+    #[error("Failed to decode the data of the DA node: {0}")]
+    Decode(String),
+    /// Reading or writing the database failed.
+    #[error("Failed to access the database of the relayer: {0}")]
+    Storage(#[from] StorageError),
+    /// The DA node doesn't have the requested data yet, like the number of the
+    /// finalized block that is still pending. The next iteration retries.
+    #[error("The DA node isn't ready: {0}")]
+    Transient(String),
+    /// The relayer got the stop signal during the iteration.
+    #[error("The relayer got a stop signal")]
+    Shutdown,
+}
+
+impl RelayerError {
+    /// Returns the kind of the error.
+    pub fn kind(&self) -> RelayerErrorKind {
+        match self {
+            RelayerError::Rpc(_) => RelayerErrorKind::Rpc,
+            RelayerError::Decode(_) => RelayerErrorKind::Decode,
+            RelayerError::Storage(_) => RelayerErrorKind::Storage,
+            RelayerError::Transient(_) => RelayerErrorKind::Transient,
+            RelayerError::Shutdown => RelayerErrorKind::Shutdown,
+        }
+    }
+
+    /// Converts the error returned by the event handler. The storage errors are
+    /// kept, while the rest are the errors of the decoding of the logs.
+    pub(crate) fn from_event(error: anyhow::Error) -> Self {
+        match error.downcast::<StorageError>() {
+            Ok(error) => RelayerError::Storage(error),
+            Err(error) => RelayerError::Decode(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! The registry of the handlers of the L1 events. Each handler decodes the logs of one
+//! event signature and writes the decoded events into its table. The relayer downloads
+//! only the logs of the registered events.
+
+use crate::{
+    config,
+    log::{
+        MessageCancellationLog,
+        MessageLog,
+    },
+    ports::{
+        origin_message_key,
+        OriginMessages,
+    },
+};
+use anyhow::anyhow;
+use ethers_core::types::{
+    Log,
+    H256,
+};
+use fuel_core_storage::{
+    tables::{
+        MessageCancellations,
+        MessageDeposits,
+        Messages,
+    },
+    Error as StorageError,
+    StorageAsMut,
+    StorageMutate,
+};
+use fuel_core_types::{
+    entities::message::{
+        Message,
+        MessageCancellation,
+        MessageDeposit,
+    },
+    fuel_types::Nonce,
+};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Handles the logs of one L1 event.
+pub trait EventHandler<S>: Send + Sync {
+    /// The hash of the event signature. It is the first topic of the event logs.
+    fn signature(&self) -> H256;
+
+    /// Decodes the `log` and writes the event into the `storage`. The `storage` is
+    /// the transaction of the DA block that emitted the log.
+    fn handle(&self, log: &Log, storage: &mut S) -> anyhow::Result<()>;
+}
+
+/// The handlers of the L1 events indexed by the event signatures.
+pub struct EventRegistry<S> {
+    handlers: HashMap<H256, Arc<dyn EventHandler<S>>>,
+}
+
+impl<S> Default for EventRegistry<S> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+}
+
+impl<S> Clone for EventRegistry<S> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.handlers.clone(),
+        }
+    }
+}
+
+impl<S> fmt::Debug for EventRegistry<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventRegistry")
+            .field("signatures", &self.signatures())
+            .finish()
+    }
+}
+
+impl<S> EventRegistry<S> {
+    /// Registers the `handler`. It replaces the handler registered
+    /// before for the same event signature.
+    pub fn with_handler<H>(mut self, handler: H) -> Self
+    where
+        H: EventHandler<S> + 'static,
+    {
+        self.handlers.insert(handler.signature(), Arc::new(handler));
+        self
+    }
+
+    /// Returns the signatures of the registered events.
+    pub fn signatures(&self) -> Vec<H256> {
+        let mut signatures: Vec<_> = self.handlers.keys().copied().collect();
+        signatures.sort();
+        signatures
+    }
+
+    /// Writes the event of the `log` into the `storage` with the registered handler.
+    /// The logs of the unknown events are ignored.
+    pub fn handle(&self, log: &Log, storage: &mut S) -> anyhow::Result<()> {
+        let signature = log
+            .topics
+            .first()
+            .ok_or_else(|| anyhow!("Topic list is empty"))?;
+        match self.handlers.get(signature) {
+            Some(handler) => handler.handle(log, storage),
+            None => {
+                tracing::debug!("Ignoring the log of the unknown event {signature:?}");
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<S> EventRegistry<S>
+where
+    S: StorageMutate<Messages, Error = StorageError>
+        + StorageMutate<MessageDeposits, Error = StorageError>
+        + StorageMutate<MessageCancellations, Error = StorageError>,
+{
+    /// Creates the registry with the events of the Fuel bridge.
+    pub fn bridge() -> Self {
+        Self::default()
+            .with_handler(MessageSentHandler)
+            .with_handler(MessageCancelledHandler)
+    }
+}
+
+impl<S> EventRegistry<S>
+where
+    S: StorageMutate<OriginMessages, Error = StorageError>,
+{
+    /// Creates the registry with the events of the bridge of the additional DA network
+    /// with the `origin_chain_id`.
+    pub fn origin_bridge(origin_chain_id: u64) -> Self {
+        Self::default().with_handler(OriginMessageSentHandler { origin_chain_id })
+    }
+}
+
+/// Writes the messages sent from the DA layer into the [`Messages`] table.
+pub struct MessageSentHandler;
+
+impl<S> EventHandler<S> for MessageSentHandler
+where
+    S: StorageMutate<Messages, Error = StorageError>
+        + StorageMutate<MessageDeposits, Error = StorageError>,
+{
+    fn signature(&self) -> H256 {
+        *config::ETH_LOG_MESSAGE
+    }
+
+    fn handle(&self, log: &Log, storage: &mut S) -> anyhow::Result<()> {
+        let message_log = MessageLog::try_from(log)?;
+        let message = Message::from(&message_log);
+        let deposit = MessageDeposit {
+            da_transaction: message_log.da_transaction,
+            da_height: message_log.da_height,
+        };
+        let nonce = message.id();
+
+        let existing_message = storage
+            .storage::<Messages>()
+            .get(nonce)?
+            .map(Cow::into_owned);
+        let message_seen = is_duplicate(nonce, existing_message, &message)?;
+        let existing_deposit = storage
+            .storage::<MessageDeposits>()
+            .get(nonce)?
+            .map(Cow::into_owned);
+        // The spent messages are removed from the `Messages`, but their deposits stay.
+        // So the duplicate of the spent message is recognized by its deposit and isn't
+        // inserted again.
+        if is_duplicate(nonce, existing_deposit, &deposit)? {
+            return Ok(())
+        }
+
+        if !message_seen {
+            storage.storage::<Messages>().insert(nonce, &message)?;
+        }
+        storage
+            .storage::<MessageDeposits>()
+            .insert(nonce, &deposit)?;
+        Ok(())
+    }
+}
+
+/// Writes the cancellations of the messages by the DA layer into the
+/// [`MessageCancellations`] table. The message stays in the [`Messages`], so the blocks
+/// with the DA height below the cancellation still can spend it. Only the cancellation
+/// is supported: the bridge replaces the message by cancelling it and sending the new
+/// one with another nonce.
+pub struct MessageCancelledHandler;
+
+impl<S> EventHandler<S> for MessageCancelledHandler
+where
+    S: StorageMutate<MessageDeposits, Error = StorageError>
+        + StorageMutate<MessageCancellations, Error = StorageError>,
+{
+    fn signature(&self) -> H256 {
+        *config::ETH_LOG_MESSAGE_CANCELLED
+    }
+
+    fn handle(&self, log: &Log, storage: &mut S) -> anyhow::Result<()> {
+        let cancellation_log = MessageCancellationLog::try_from(log)?;
+        let cancellation = MessageCancellation::from(&cancellation_log);
+        let nonce = &cancellation_log.nonce;
+
+        let existing = storage
+            .storage::<MessageCancellations>()
+            .get(nonce)?
+            .map(Cow::into_owned);
+        if is_duplicate(nonce, existing, &cancellation)? {
+            return Ok(())
+        }
+        if !storage.storage::<MessageDeposits>().contains_key(nonce)? {
+            tracing::warn!("The DA layer cancelled the unknown message {nonce}");
+        }
+
+        storage
+            .storage::<MessageCancellations>()
+            .insert(nonce, &cancellation)?;
+        Ok(())
+    }
+}
+
+/// Writes the messages sent from the additional DA network into the [`OriginMessages`]
+/// table under the chain id of the network.
+pub struct OriginMessageSentHandler {
+    /// The chain id of the DA network that emits the logs.
+    pub origin_chain_id: u64,
+}
+
+impl<S> EventHandler<S> for OriginMessageSentHandler
+where
+    S: StorageMutate<OriginMessages, Error = StorageError>,
+{
+    fn signature(&self) -> H256 {
+        *config::ETH_LOG_MESSAGE
+    }
+
+    fn handle(&self, log: &Log, storage: &mut S) -> anyhow::Result<()> {
+        let message = Message::from(&MessageLog::try_from(log)?);
+        let key = origin_message_key(self.origin_chain_id, message.id());
+        let existing = storage
+            .storage::<OriginMessages>()
+            .get(&key)?
+            .map(Cow::into_owned);
+        if !is_duplicate(message.id(), existing, &message)? {
+            storage.storage::<OriginMessages>().insert(&key, &message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the `existing` record of the message with the `nonce` is identical
+/// to the `new` one, so the duplicated delivery of the log can be skipped. The provider
+/// may deliver the same log twice, and the relayer replays the logs after the restart.
+///
+/// The different records with the same nonce mean that the DA layer or the provider
+/// is inconsistent, so the error stops the relayer instead of overwriting the record.
+fn is_duplicate<T>(nonce: &Nonce, existing: Option<T>, new: &T) -> anyhow::Result<bool>
+where
+    T: PartialEq + fmt::Debug,
+{
+    match existing {
+        None => Ok(false),
+        Some(existing) if &existing == new => {
+            tracing::debug!("Skipping the duplicated message {nonce}");
+            Ok(true)
+        }
+        Some(existing) => {
+            tracing::error!(
+                "The message {nonce} conflicts with the stored one: \
+                stored {existing:?}, received {new:?}"
+            );
+            Err(anyhow!(
+                "The message {nonce} conflicts with the stored message with the same nonce"
+            ))
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use super::*;
+use crate::{
+    abi::bridge::{
+        MessageCancelledFilter,
+        MessageSentFilter,
+    },
+    mock_db::Data,
+    test_helpers::{
+        EvtToLog,
+        LogTestHelper,
+    },
+};
+use ethers_contract::EthEvent;
+use ethers_core::types::U256;
+use fuel_core_types::blockchain::primitives::DaBlockHeight;
+
+const CUSTOM_EVENT: H256 = H256::repeat_byte(0xAA);
+
+/// Counts the logs of the custom event.
+struct CountingHandler;
+
+impl EventHandler<usize> for CountingHandler {
+    fn signature(&self) -> H256 {
+        CUSTOM_EVENT
+    }
+
+    fn handle(&self, _: &Log, storage: &mut usize) -> anyhow::Result<()> {
+        *storage += 1;
+        Ok(())
+    }
+}
+
+fn log_with_topics(topics: Vec<H256>) -> Log {
+    Log {
+        topics,
+        block_number: Some(1u64.into()),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn registry_dispatches_log_to_handler_of_its_event() {
+    let registry = EventRegistry::default().with_handler(CountingHandler);
+    let mut count = 0;
+
+    registry
+        .handle(&log_with_topics(vec![CUSTOM_EVENT]), &mut count)
+        .unwrap();
+
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn registry_ignores_logs_of_unknown_events() {
+    let registry = EventRegistry::default().with_handler(CountingHandler);
+    let mut count = 0;
+
+    registry
+        .handle(&log_with_topics(vec![H256::repeat_byte(0xBB)]), &mut count)
+        .unwrap();
+
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn registry_rejects_logs_without_topics() {
+    let registry = EventRegistry::default().with_handler(CountingHandler);
+    let mut count = 0;
+
+    let result = registry.handle(&log_with_topics(vec![]), &mut count);
+
+    assert!(result.is_err());
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn bridge_registry_downloads_only_bridge_events() {
+    let registry = EventRegistry::<Data>::bridge();
+
+    let mut expected = vec![
+        MessageSentFilter::signature(),
+        MessageCancelledFilter::signature(),
+    ];
+    expected.sort();
+    assert_eq!(registry.signatures(), expected);
+}
+
+#[test]
+fn bridge_registry_writes_messages_with_deposits() {
+    let registry = EventRegistry::bridge();
+    let mut log = MessageSentFilter {
+        nonce: U256::from(7),
+        amount: 10,
+        ..Default::default()
+    }
+    .into_log();
+    log.block_number = Some(3u64.into());
+    log.transaction_hash = Some(H256::repeat_byte(0xCC));
+    let mut data = Data::default();
+
+    registry.handle(&log, &mut data).unwrap();
+
+    let message = log.to_msg();
+    assert_eq!(
+        data.messages[&DaBlockHeight::from(3u64)][message.id()],
+        message
+    );
+    assert_eq!(
+        data.deposits[message.id()],
+        MessageDeposit {
+            da_transaction: [0xCC; 32].into(),
+            da_height: 3u64.into(),
+        }
+    );
+}
+
+#[test]
+fn bridge_registry_rejects_logs_without_transaction_hash() {
+    let registry = EventRegistry::bridge();
+    let mut data = Data::default();
+    let mut message = bridge_log(7, 10);
+    message.transaction_hash = None;
+    let mut cancellation = cancellation_log(7, 5);
+    cancellation.transaction_hash = None;
+
+    assert!(registry.handle(&message, &mut data).is_err());
+    assert!(registry.handle(&cancellation, &mut data).is_err());
+    assert!(data.messages.is_empty());
+    assert!(data.deposits.is_empty());
+}
+
+#[test]
+fn origin_bridge_registry_namespaces_messages_by_chain_id() {
+    let registry = EventRegistry::origin_bridge(5);
+    let mut log = MessageSentFilter {
+        nonce: U256::from(7),
+        amount: 10,
+        ..Default::default()
+    }
+    .into_log();
+    log.block_number = Some(3u64.into());
+    let mut data = Data::default();
+
+    registry.handle(&log, &mut data).unwrap();
+
+    let message = log.to_msg();
+    assert_eq!(
+        data.origin_messages[&origin_message_key(5, message.id())],
+        message
+    );
+    assert!(data.messages.is_empty());
+    assert!(data.deposits.is_empty());
+}
+
+fn bridge_log(nonce: u64, amount: u64) -> Log {
+    let mut log = MessageSentFilter {
+        nonce: U256::from(nonce),
+        amount,
+        ..Default::default()
+    }
+    .into_log();
+    log.block_number = Some(3u64.into());
+    log.transaction_hash = Some(H256::repeat_byte(0xCC));
+    log
+}
+
+#[test]
+fn bridge_registry_skips_duplicated_messages() {
+    let registry = EventRegistry::bridge();
+    let log = bridge_log(7, 10);
+    let mut data = Data::default();
+
+    registry.handle(&log, &mut data).unwrap();
+    let expected = data.clone();
+    registry.handle(&log, &mut data).unwrap();
+
+    assert_eq!(data.messages, expected.messages);
+    assert_eq!(data.deposits, expected.deposits);
+}
+
+#[test]
+fn bridge_registry_does_not_restore_spent_messages() {
+    let registry = EventRegistry::bridge();
+    let log = bridge_log(7, 10);
+    let mut data = Data::default();
+    registry.handle(&log, &mut data).unwrap();
+
+    // The spent message is removed, but its deposit stays.
+    let message = log.to_msg();
+    data.storage::<Messages>().remove(message.id()).unwrap();
+    registry.handle(&log, &mut data).unwrap();
+
+    assert!(!data
+        .storage::<Messages>()
+        .contains_key(message.id())
+        .unwrap());
+    assert!(data.deposits.contains_key(message.id()));
+}
+
+#[test]
+fn bridge_registry_rejects_conflicting_messages() {
+    let registry = EventRegistry::bridge();
+    let mut data = Data::default();
+    registry.handle(&bridge_log(7, 10), &mut data).unwrap();
+
+    let result = registry.handle(&bridge_log(7, 20), &mut data);
+
+    assert!(result.is_err());
+    let message = bridge_log(7, 10).to_msg();
+    assert_eq!(
+        data.storage::<Messages>()
+            .get(message.id())
+            .unwrap()
+            .unwrap()
+            .amount,
+        10
+    );
+}
+
+#[test]
+fn origin_bridge_registry_rejects_conflicting_messages() {
+    let registry = EventRegistry::origin_bridge(5);
+    let mut data = Data::default();
+    registry.handle(&bridge_log(7, 10), &mut data).unwrap();
+    registry.handle(&bridge_log(7, 10), &mut data).unwrap();
+
+    let result = registry.handle(&bridge_log(7, 20), &mut data);
+
+    assert!(result.is_err());
+    assert_eq!(data.origin_messages.len(), 1);
+}
+
+fn cancellation_log(nonce: u64, da_height: u64) -> Log {
+    let mut log = MessageCancelledFilter {
+        nonce: U256::from(nonce),
+    }
+    .into_log();
+    log.block_number = Some(da_height.into());
+    log.transaction_hash = Some(H256::repeat_byte(0xDD));
+    log
+}
+
+#[test]
+fn bridge_registry_writes_cancellations_without_removing_messages() {
+    let registry = EventRegistry::bridge();
+    let mut data = Data::default();
+    registry.handle(&bridge_log(7, 10), &mut data).unwrap();
+
+    registry.handle(&cancellation_log(7, 5), &mut data).unwrap();
+
+    let message = bridge_log(7, 10).to_msg();
+    assert!(data
+        .storage::<Messages>()
+        .contains_key(message.id())
+        .unwrap());
+    assert_eq!(
+        data.cancellations[message.id()],
+        MessageCancellation {
+            da_transaction: [0xDD; 32].into(),
+            da_height: 5u64.into(),
+        }
+    );
+}
+
+#[test]
+fn bridge_registry_rejects_conflicting_cancellations() {
+    let registry = EventRegistry::bridge();
+    let mut data = Data::default();
+    registry.handle(&bridge_log(7, 10), &mut data).unwrap();
+    registry.handle(&cancellation_log(7, 5), &mut data).unwrap();
+    // The duplicated delivery of the same log is skipped.
+    registry.handle(&cancellation_log(7, 5), &mut data).unwrap();
+
+    let result = registry.handle(&cancellation_log(7, 6), &mut data);
+
+    assert!(result.is_err());
+    let message = bridge_log(7, 10).to_msg();
+    assert_eq!(data.cancellations[message.id()].da_height, 5u64.into());
+}
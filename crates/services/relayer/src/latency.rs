@@ -0,0 +1,282 @@
+//! Tracks the latency of the messages from the finalization of their DA blocks to the
+//! first Fuel block that can spend them, so the SLOs of the bridge become measurable.
+//!
+//! The finalization time is the time when the relayer observed the DA block as
+//! finalized, so it is late by up to one iteration of the relayer loop. The message
+//! becomes spendable when the Fuel block with at least its DA height is imported.
+//! The records are kept in memory for the latest messages only.
+
+use crate::{
+    config,
+    log::MessageLog,
+    ports::ImportedDaHeights,
+};
+use async_trait::async_trait;
+use ethers_core::types::Log;
+use fuel_core_metrics::relayer_metrics::relayer_metrics;
+use fuel_core_services::{
+    stream::BoxStream,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+};
+use futures::StreamExt;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+        VecDeque,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::SystemTime,
+};
+
+#[cfg(test)]
+mod test;
+
+/// The max number of the messages with the recorded latency.
+const MAX_TRACKED_MESSAGES: usize = 10_000;
+
+/// The max number of the remembered observations of the finalized DA heights.
+const MAX_FINALIZED_OBSERVATIONS: usize = 1024;
+
+/// The timestamps of the message on its way from the DA layer to the Fuel block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageLatency {
+    /// The DA height of the message.
+    pub da_height: DaBlockHeight,
+    /// When the relayer observed the DA block of the message as finalized.
+    pub da_finalized_at: SystemTime,
+    /// When the relayer stored the message.
+    pub stored_at: SystemTime,
+    /// When the first Fuel block that can spend the message was imported.
+    pub spendable_at: Option<SystemTime>,
+    /// The height of the first Fuel block that can spend the message.
+    pub spendable_height: Option<BlockHeight>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// The observed finalized DA heights with the time of the observation,
+    /// the oldest first.
+    finalized: VecDeque<(DaBlockHeight, SystemTime)>,
+    /// The messages of the `records` that are not spendable yet by their DA heights.
+    pending: BTreeMap<DaBlockHeight, Vec<Nonce>>,
+    /// The latencies of the latest messages.
+    records: HashMap<Nonce, MessageLatency>,
+    /// The order of the insertion of the `records`, the oldest first.
+    order: VecDeque<Nonce>,
+}
+
+impl Inner {
+    /// Returns the time when the DA block at the `da_height` was observed as finalized.
+    fn finalized_at(&self, da_height: DaBlockHeight) -> Option<SystemTime> {
+        self.finalized
+            .iter()
+            .find(|(finalized, _)| *finalized >= da_height)
+            .map(|(_, at)| *at)
+    }
+
+    fn insert(&mut self, nonce: Nonce, latency: MessageLatency) {
+        self.records.insert(nonce, latency);
+        self.pending
+            .entry(latency.da_height)
+            .or_default()
+            .push(nonce);
+        self.order.push_back(nonce);
+        if self.order.len() > MAX_TRACKED_MESSAGES {
+            if let Some(evicted) = self.order.pop_front() {
+                self.evict(&evicted);
+            }
+        }
+    }
+
+    fn evict(&mut self, nonce: &Nonce) {
+        let Some(latency) = self.records.remove(nonce) else {
+            return
+        };
+        if let Some(nonces) = self.pending.get_mut(&latency.da_height) {
+            nonces.retain(|pending| pending != nonce);
+            if nonces.is_empty() {
+                self.pending.remove(&latency.da_height);
+            }
+        }
+    }
+}
+
+/// The latencies of the latest messages, shared between the relayer and the tracker
+/// of the imported blocks.
+#[derive(Clone, Default)]
+pub struct MessageLatencies {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl MessageLatencies {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().expect("Never poisoned")
+    }
+
+    /// Records that the relayer observed the DA blocks up to the `da_height`
+    /// as finalized.
+    pub(crate) fn record_finalized(&self, da_height: DaBlockHeight, at: SystemTime) {
+        let mut inner = self.lock();
+        if inner
+            .finalized
+            .back()
+            .map_or(false, |(latest, _)| *latest >= da_height)
+        {
+            return
+        }
+        inner.finalized.push_back((da_height, at));
+        if inner.finalized.len() > MAX_FINALIZED_OBSERVATIONS {
+            inner.finalized.pop_front();
+        }
+    }
+
+    /// Records the messages of the `logs` of the DA block stored by the relayer.
+    pub(crate) fn record_stored(&self, logs: &[Log], at: SystemTime) {
+        let mut inner = self.lock();
+        for log in logs {
+            if log.topics.first() != Some(&*config::ETH_LOG_MESSAGE) {
+                continue
+            }
+            let Ok(message) = MessageLog::try_from(log) else {
+                continue
+            };
+            if inner.records.contains_key(&message.nonce) {
+                // The message is stored again, the first record is kept.
+                continue
+            }
+            // The finalization before the start of the relayer isn't observed.
+            let da_finalized_at = inner.finalized_at(message.da_height).unwrap_or(at);
+            let since_finalized = at
+                .duration_since(da_finalized_at)
+                .unwrap_or_default()
+                .as_secs_f64();
+            relayer_metrics().observe_message_stored(since_finalized);
+            inner.insert(
+                message.nonce,
+                MessageLatency {
+                    da_height: message.da_height,
+                    da_finalized_at,
+                    stored_at: at,
+                    spendable_at: None,
+                    spendable_height: None,
+                },
+            );
+        }
+    }
+
+    /// Records the Fuel block at the `height` that can spend the messages
+    /// up to the `da_height`.
+    pub fn record_fuel_block(
+        &self,
+        height: BlockHeight,
+        da_height: DaBlockHeight,
+        at: SystemTime,
+    ) {
+        let mut inner = self.lock();
+        let still_pending = inner.pending.split_off(&DaBlockHeight(da_height.0 + 1));
+        let spendable = core::mem::replace(&mut inner.pending, still_pending);
+        for nonce in spendable.into_values().flatten() {
+            let Some(latency) = inner.records.get_mut(&nonce) else {
+                // The record was evicted.
+                continue
+            };
+            latency.spendable_at = Some(at);
+            latency.spendable_height = Some(height);
+            let since_stored = at
+                .duration_since(latency.stored_at)
+                .unwrap_or_default()
+                .as_secs_f64();
+            let since_finalized = at
+                .duration_since(latency.da_finalized_at)
+                .unwrap_or_default()
+                .as_secs_f64();
+            relayer_metrics().observe_message_spendable(since_stored, since_finalized);
+        }
+    }
+
+    /// Returns the latency of the message, if it is among the latest messages.
+    pub fn get(&self, nonce: &Nonce) -> Option<MessageLatency> {
+        self.lock().records.get(nonce).copied()
+    }
+}
+
+/// The alias of the runnable tracker of the imported blocks.
+pub type Service = ServiceRunner<Task>;
+
+/// Records the imported Fuel blocks into the [`MessageLatencies`].
+pub struct Task {
+    latencies: MessageLatencies,
+    blocks: BoxStream<(BlockHeight, DaBlockHeight)>,
+}
+
+#[async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "MessageLatency";
+
+    type SharedData = ();
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {}
+
+    async fn into_task(
+        self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        Ok(self)
+    }
+}
+
+#[async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let mut should_continue = true;
+        tokio::select! {
+            biased;
+            _ = watcher.while_started() => {
+                should_continue = false;
+            }
+            block = self.blocks.next() => {
+                if let Some((height, da_height)) = block {
+                    let now = SystemTime::now();
+                    self.latencies.record_fuel_block(height, da_height, now);
+                } else {
+                    tracing::error!("The stream of imported blocks is closed");
+                    should_continue = false;
+                }
+            }
+        }
+        Ok(should_continue)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Creates the tracker of the imported `blocks` that records when the messages
+/// of the `latencies` become spendable.
+pub fn new_service<B>(latencies: MessageLatencies, blocks: B) -> Service
+where
+    B: ImportedDaHeights,
+{
+    ServiceRunner::new(Task {
+        latencies,
+        blocks: blocks.imported_da_heights(),
+    })
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::{
+    abi::bridge::MessageSentFilter,
+    test_helpers::EvtToLog,
+};
+use ethers_core::types::U256;
+use std::time::Duration;
+
+fn message(nonce: u64, da_height: u64) -> Log {
+    let message = MessageSentFilter {
+        nonce: U256::from(nonce),
+        ..Default::default()
+    };
+    let mut log = message.into_log();
+    log.block_number = Some(da_height.into());
+    log
+}
+
+fn nonce(nonce: u64) -> Nonce {
+    let mut result = Nonce::zeroed();
+    U256::from(nonce).to_big_endian(result.as_mut());
+    result
+}
+
+fn time(secs: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[test]
+fn stored_message_has_time_of_finalization() {
+    let latencies = MessageLatencies::default();
+    latencies.record_finalized(5u64.into(), time(10));
+    latencies.record_finalized(8u64.into(), time(20));
+
+    latencies.record_stored(&[message(1, 7)], time(25));
+
+    let latency = latencies.get(&nonce(1)).unwrap();
+    assert_eq!(latency.da_height, 7u64.into());
+    assert_eq!(latency.da_finalized_at, time(20));
+    assert_eq!(latency.stored_at, time(25));
+    assert_eq!(latency.spendable_at, None);
+}
+
+#[test]
+fn fuel_block_makes_spendable_only_messages_up_to_its_da_height() {
+    let latencies = MessageLatencies::default();
+    latencies.record_finalized(10u64.into(), time(10));
+    latencies.record_stored(&[message(1, 5), message(2, 6), message(3, 7)], time(12));
+
+    latencies.record_fuel_block(3u32.into(), 6u64.into(), time(15));
+    latencies.record_fuel_block(4u32.into(), 7u64.into(), time(18));
+    latencies.record_fuel_block(5u32.into(), 7u64.into(), time(19));
+
+    let spendable = |n| {
+        let latency = latencies.get(&nonce(n)).unwrap();
+        (latency.spendable_height, latency.spendable_at)
+    };
+    assert_eq!(spendable(1), (Some(3u32.into()), Some(time(15))));
+    assert_eq!(spendable(2), (Some(3u32.into()), Some(time(15))));
+    assert_eq!(spendable(3), (Some(4u32.into()), Some(time(18))));
+}
+
+#[test]
+fn only_latest_messages_are_tracked() {
+    let latencies = MessageLatencies::default();
+    let logs: Vec<_> = (0..=MAX_TRACKED_MESSAGES as u64)
+        .map(|n| message(n, 1))
+        .collect();
+
+    latencies.record_stored(&logs, time(1));
+
+    assert!(latencies.get(&nonce(0)).is_none());
+    assert!(latencies.get(&nonce(MAX_TRACKED_MESSAGES as u64)).is_some());
+    // The pending messages are bounded together with the records.
+    let pending: usize = latencies.lock().pending.values().map(Vec::len).sum();
+    assert_eq!(pending, MAX_TRACKED_MESSAGES);
+}
+
+#[test]
+fn stored_again_message_keeps_first_record() {
+    let latencies = MessageLatencies::default();
+    latencies.record_stored(&[message(1, 5)], time(10));
+
+    latencies.record_stored(&[message(1, 5)], time(20));
+
+    assert_eq!(latencies.get(&nonce(1)).unwrap().stored_at, time(10));
+    assert_eq!(latencies.lock().pending[&DaBlockHeight(5)], vec![nonce(1)]);
+}
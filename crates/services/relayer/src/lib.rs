@@ -9,6 +9,12 @@ pub(crate) mod abi;
 pub(crate) mod config;
 pub(crate) mod log;
 
+pub mod blob_fee;
+pub mod committer;
+pub mod events;
+pub mod latency;
+
+mod error;
 mod service;
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -22,13 +28,25 @@ pub use abi::*;
 #[cfg(any(test, feature = "test-helpers"))]
 pub use service::new_service_test;
 
-pub use config::Config;
+pub use config::{
+    Config,
+    OriginConfig,
+    PollIntervals,
+};
+pub use error::{
+    RelayerError,
+    RelayerErrorKind,
+};
 pub use ethers_core::types::{
     H160,
     H256,
+    U256,
 };
 pub use service::{
+    new_origin_service,
     new_service,
+    new_service_with_events,
+    OriginService,
     Service,
     SharedState,
 };
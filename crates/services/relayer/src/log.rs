@@ -1,7 +1,4 @@
-use crate::{
-    abi,
-    config,
-};
+use crate::abi;
 use anyhow::anyhow;
 use ethers_contract::EthEvent;
 use ethers_core::{
@@ -10,9 +7,13 @@ use ethers_core::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::message::{
+        Message,
+        MessageCancellation,
+    },
     fuel_types::{
         Address,
+        Bytes32,
         Nonce,
         Word,
     },
@@ -27,6 +28,7 @@ pub struct MessageLog {
     pub amount: Word,
     pub data: Vec<u8>,
     pub da_height: DaBlockHeight,
+    pub da_transaction: Bytes32,
 }
 
 impl From<&MessageLog> for Message {
@@ -42,59 +44,94 @@ impl From<&MessageLog> for Message {
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-pub enum EthEventLog {
-    // Bridge message from da side
-    Message(MessageLog),
-    Ignored,
-}
-
-impl TryFrom<&Log> for EthEventLog {
+impl TryFrom<&Log> for MessageLog {
     type Error = anyhow::Error;
 
     fn try_from(log: &Log) -> Result<Self, Self::Error> {
-        if log.topics.is_empty() {
-            return Err(anyhow!("Topic list is empty"))
+        if log.topics.len() != 4 {
+            return Err(anyhow!("Malformed topics for Message"))
         }
 
-        let log = match log.topics[0] {
-            n if n == *config::ETH_LOG_MESSAGE => {
-                if log.topics.len() != 4 {
-                    return Err(anyhow!("Malformed topics for Message"))
-                }
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
+        };
+
+        let message = abi::bridge::MessageSentFilter::decode_log(&raw_log)?;
+        let amount = message.amount;
+        let data = message.data.to_vec();
+        let mut nonce = Nonce::zeroed();
+        message.nonce.to_big_endian(nonce.as_mut());
+        let recipient = Address::from(message.recipient);
+        let sender = Address::from(message.sender);
+        let da_transaction = log
+            .transaction_hash
+            .ok_or(anyhow!("Log missing transaction hash"))?;
+
+        Ok(Self {
+            amount,
+            data,
+            nonce,
+            sender,
+            recipient,
+            // Safety: logs without block numbers are rejected by
+            // FinalizationQueue::append_eth_log before the conversion to MessageLog happens.
+            // If block_number is none, that means the log is pending.
+            da_height: DaBlockHeight::from(
+                log.block_number
+                    .ok_or(anyhow!("Log missing block height"))?
+                    .as_u64(),
+            ),
+            da_transaction: Bytes32::from(da_transaction.0),
+        })
+    }
+}
+
+/// The cancellation of the bridge message sent from da to fuel network.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MessageCancellationLog {
+    pub nonce: Nonce,
+    pub da_height: DaBlockHeight,
+    pub da_transaction: Bytes32,
+}
+
+impl From<&MessageCancellationLog> for MessageCancellation {
+    fn from(log: &MessageCancellationLog) -> Self {
+        Self {
+            da_transaction: log.da_transaction,
+            da_height: log.da_height,
+        }
+    }
+}
 
-                let raw_log = RawLog {
-                    topics: log.topics.clone(),
-                    data: log.data.to_vec(),
-                };
+impl TryFrom<&Log> for MessageCancellationLog {
+    type Error = anyhow::Error;
 
-                let message = abi::bridge::MessageSentFilter::decode_log(&raw_log)?;
-                let amount = message.amount;
-                let data = message.data.to_vec();
-                let mut nonce = Nonce::zeroed();
-                message.nonce.to_big_endian(nonce.as_mut());
-                let recipient = Address::from(message.recipient);
-                let sender = Address::from(message.sender);
+    fn try_from(log: &Log) -> Result<Self, Self::Error> {
+        if log.topics.len() != 2 {
+            return Err(anyhow!("Malformed topics for MessageCancelled"))
+        }
 
-                Self::Message(MessageLog {
-                    amount,
-                    data,
-                    nonce,
-                    sender,
-                    recipient,
-                    // Safety: logs without block numbers are rejected by
-                    // FinalizationQueue::append_eth_log before the conversion to EthEventLog happens.
-                    // If block_number is none, that means the log is pending.
-                    da_height: DaBlockHeight::from(
-                        log.block_number
-                            .ok_or(anyhow!("Log missing block height"))?
-                            .as_u64(),
-                    ),
-                })
-            }
-            _ => Self::Ignored,
+        let raw_log = RawLog {
+            topics: log.topics.clone(),
+            data: log.data.to_vec(),
         };
 
-        Ok(log)
+        let cancellation = abi::bridge::MessageCancelledFilter::decode_log(&raw_log)?;
+        let mut nonce = Nonce::zeroed();
+        cancellation.nonce.to_big_endian(nonce.as_mut());
+        let da_transaction = log
+            .transaction_hash
+            .ok_or(anyhow!("Log missing transaction hash"))?;
+
+        Ok(Self {
+            nonce,
+            da_height: DaBlockHeight::from(
+                log.block_number
+                    .ok_or(anyhow!("Log missing block height"))?
+                    .as_u64(),
+            ),
+            da_transaction: Bytes32::from(da_transaction.0),
+        })
     }
 }
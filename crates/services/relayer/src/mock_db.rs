@@ -1,16 +1,35 @@
 #![allow(missing_docs)]
 
-use crate::ports::RelayerDb;
+use crate::ports::{
+    push_da_fee_sample,
+    OriginMessages,
+    RelayerDb,
+    ORIGIN_MESSAGE_KEY_SIZE,
+};
 use fuel_core_storage::{
     not_found,
+    tables::{
+        MessageCancellations,
+        MessageDeposits,
+        Messages,
+    },
+    Error as StorageError,
     Result as StorageResult,
+    StorageInspect,
+    StorageMutate,
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::message::{
+        Message,
+        MessageCancellation,
+        MessageDeposit,
+    },
     fuel_types::Nonce,
+    services::relayer::DaFeeSample,
 };
 use std::{
+    borrow::Cow,
     collections::{
         BTreeMap,
         HashMap,
@@ -21,10 +40,129 @@ use std::{
     },
 };
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Data {
     pub messages: BTreeMap<DaBlockHeight, HashMap<Nonce, Message>>,
+    pub deposits: HashMap<Nonce, MessageDeposit>,
+    pub cancellations: HashMap<Nonce, MessageCancellation>,
+    pub origin_messages: BTreeMap<[u8; ORIGIN_MESSAGE_KEY_SIZE], Message>,
     pub finalized_da_height: Option<DaBlockHeight>,
+    pub da_fee_samples: Vec<DaFeeSample>,
+}
+
+impl StorageInspect<Messages> for Data {
+    type Error = StorageError;
+
+    fn get(&self, key: &Nonce) -> StorageResult<Option<Cow<Message>>> {
+        Ok(self
+            .messages
+            .values()
+            .find_map(|map| map.get(key))
+            .map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &Nonce) -> StorageResult<bool> {
+        Ok(self.messages.values().any(|map| map.contains_key(key)))
+    }
+}
+
+impl StorageMutate<Messages> for Data {
+    fn insert(&mut self, key: &Nonce, value: &Message) -> StorageResult<Option<Message>> {
+        let previous = StorageMutate::<Messages>::remove(self, key)?;
+        self.messages
+            .entry(value.da_height)
+            .or_default()
+            .insert(*key, value.clone());
+        Ok(previous)
+    }
+
+    fn remove(&mut self, key: &Nonce) -> StorageResult<Option<Message>> {
+        Ok(self.messages.values_mut().find_map(|map| map.remove(key)))
+    }
+}
+
+impl StorageInspect<MessageDeposits> for Data {
+    type Error = StorageError;
+
+    fn get(&self, key: &Nonce) -> StorageResult<Option<Cow<MessageDeposit>>> {
+        Ok(self.deposits.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &Nonce) -> StorageResult<bool> {
+        Ok(self.deposits.contains_key(key))
+    }
+}
+
+impl StorageMutate<MessageDeposits> for Data {
+    fn insert(
+        &mut self,
+        key: &Nonce,
+        value: &MessageDeposit,
+    ) -> StorageResult<Option<MessageDeposit>> {
+        Ok(self.deposits.insert(*key, *value))
+    }
+
+    fn remove(&mut self, key: &Nonce) -> StorageResult<Option<MessageDeposit>> {
+        Ok(self.deposits.remove(key))
+    }
+}
+
+impl StorageInspect<MessageCancellations> for Data {
+    type Error = StorageError;
+
+    fn get(&self, key: &Nonce) -> StorageResult<Option<Cow<MessageCancellation>>> {
+        Ok(self.cancellations.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &Nonce) -> StorageResult<bool> {
+        Ok(self.cancellations.contains_key(key))
+    }
+}
+
+impl StorageMutate<MessageCancellations> for Data {
+    fn insert(
+        &mut self,
+        key: &Nonce,
+        value: &MessageCancellation,
+    ) -> StorageResult<Option<MessageCancellation>> {
+        Ok(self.cancellations.insert(*key, *value))
+    }
+
+    fn remove(&mut self, key: &Nonce) -> StorageResult<Option<MessageCancellation>> {
+        Ok(self.cancellations.remove(key))
+    }
+}
+
+impl StorageInspect<OriginMessages> for Data {
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &[u8; ORIGIN_MESSAGE_KEY_SIZE],
+    ) -> StorageResult<Option<Cow<Message>>> {
+        Ok(self.origin_messages.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &[u8; ORIGIN_MESSAGE_KEY_SIZE]) -> StorageResult<bool> {
+        Ok(self.origin_messages.contains_key(key))
+    }
+}
+
+impl StorageMutate<OriginMessages> for Data {
+    fn insert(
+        &mut self,
+        key: &[u8; ORIGIN_MESSAGE_KEY_SIZE],
+        value: &Message,
+    ) -> StorageResult<Option<Message>> {
+        Ok(self.origin_messages.insert(*key, value.clone()))
+    }
+
+    fn remove(
+        &mut self,
+        key: &[u8; ORIGIN_MESSAGE_KEY_SIZE],
+    ) -> StorageResult<Option<Message>> {
+        Ok(self.origin_messages.remove(key))
+    }
 }
 
 // TODO: Maybe remove `Arc<Mutex<>>`
@@ -48,20 +186,24 @@ impl MockDb {
 }
 
 impl RelayerDb for MockDb {
-    fn insert_messages(
+    type Storage = Data;
+
+    fn insert_da_block<F>(
         &mut self,
         da_height: &DaBlockHeight,
-        messages: &[Message],
-    ) -> StorageResult<()> {
+        write: F,
+    ) -> StorageResult<()>
+    where
+        F: FnOnce(&mut Data) -> anyhow::Result<()>,
+    {
         let mut m = self.data.lock().unwrap();
-        for message in messages {
-            m.messages
-                .entry(message.da_height)
-                .or_default()
-                .insert(*message.id(), message.clone());
-        }
-        let max = m.finalized_da_height.get_or_insert(0u64.into());
+        // The events are written into the copy of the data,
+        // so they are not visible if the `write` fails.
+        let mut data = m.clone();
+        write(&mut data)?;
+        let max = data.finalized_da_height.get_or_insert(0u64.into());
         *max = (*max).max(*da_height);
+        *m = data;
         Ok(())
     }
 
@@ -82,4 +224,18 @@ impl RelayerDb for MockDb {
             .finalized_da_height
             .ok_or(not_found!("FinalizedDaHeight for test"))
     }
+
+    fn insert_da_fee_sample(
+        &mut self,
+        sample: &DaFeeSample,
+        window: usize,
+    ) -> StorageResult<()> {
+        let mut lock = self.data.lock().unwrap();
+        push_da_fee_sample(&mut lock.da_fee_samples, sample, window);
+        Ok(())
+    }
+
+    fn get_da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>> {
+        Ok(self.data.lock().unwrap().da_fee_samples.clone())
+    }
 }
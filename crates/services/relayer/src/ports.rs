@@ -1,19 +1,28 @@
 //! Ports used by the relayer to access the outside world
 
 use async_trait::async_trait;
+use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
-    tables::Messages,
     transactional::Transactional,
     Error as StorageError,
     Mappable,
     Result as StorageResult,
     StorageAsMut,
     StorageAsRef,
+    StorageInspect,
     StorageMutate,
 };
 use fuel_core_types::{
-    blockchain::primitives::DaBlockHeight,
+    blockchain::primitives::{
+        BlockId,
+        DaBlockHeight,
+    },
     entities::message::Message,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::relayer::DaFeeSample,
 };
 
 #[cfg(test)]
@@ -22,13 +31,19 @@ mod tests;
 /// Manages state related to supported external chains.
 #[async_trait]
 pub trait RelayerDb: Send + Sync {
-    /// Add bridge messages to database. Messages are not revertible.
+    /// The storage of the DA block transaction passed to the event handlers.
+    type Storage: 'static;
+
+    /// Writes the events of the DA block with the `write` function along with
+    /// the `da_height` of this block. The events are not revertible.
     /// Must only set a new da height if it is greater than the current.
-    fn insert_messages(
+    fn insert_da_block<F>(
         &mut self,
         da_height: &DaBlockHeight,
-        messages: &[Message],
-    ) -> StorageResult<()>;
+        write: F,
+    ) -> StorageResult<()>
+    where
+        F: FnOnce(&mut Self::Storage) -> anyhow::Result<()>;
 
     /// Set finalized da height that represent last block from da layer that got finalized.
     /// This will only set the value if it is greater than the current.
@@ -40,6 +55,29 @@ pub trait RelayerDb: Send + Sync {
     /// Get finalized da height that represent last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of database.
     fn get_finalized_da_height(&self) -> StorageResult<DaBlockHeight>;
+
+    /// Appends the fee `sample` of the finalized DA block and keeps only the latest
+    /// `window` samples. The sample is ignored if it isn't above the latest one.
+    fn insert_da_fee_sample(
+        &mut self,
+        sample: &DaFeeSample,
+        window: usize,
+    ) -> StorageResult<()>;
+
+    /// Returns the recorded fee samples of the finalized DA blocks, the oldest first.
+    fn get_da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>>;
+}
+
+/// The source of the blocks committed by the [`crate::committer`].
+pub trait ImportedBlocks: Send + Sync {
+    /// Returns the stream of the heights and ids of the newly imported blocks.
+    fn imported_blocks(&self) -> BoxStream<(BlockHeight, BlockId)>;
+}
+
+/// The source of the blocks tracked by the [`crate::latency`].
+pub trait ImportedDaHeights: Send + Sync {
+    /// Returns the stream of the heights and DA heights of the newly imported blocks.
+    fn imported_da_heights(&self) -> BoxStream<(BlockHeight, DaBlockHeight)>;
 }
 
 impl<T, Storage> RelayerDb for T
@@ -47,32 +85,28 @@ where
     T: Send + Sync,
     T: Transactional<Storage = Storage>,
     T: StorageMutate<RelayerMetadata, Error = StorageError>,
-    Storage: StorageMutate<Messages, Error = StorageError>
-        + StorageMutate<RelayerMetadata, Error = StorageError>,
+    T: StorageInspect<DaFeeSamples, Error = StorageError>,
+    Storage: StorageMutate<RelayerMetadata, Error = StorageError>
+        + StorageMutate<DaFeeSamples, Error = StorageError>
+        + 'static,
 {
-    fn insert_messages(
+    type Storage = Storage;
+
+    fn insert_da_block<F>(
         &mut self,
         da_height: &DaBlockHeight,
-        messages: &[Message],
-    ) -> StorageResult<()> {
+        write: F,
+    ) -> StorageResult<()>
+    where
+        F: FnOnce(&mut Self::Storage) -> anyhow::Result<()>,
+    {
         // A transaction is required to ensure that the height is
         // set atomically with the insertion based on the current
-        // height. Also so that the messages are inserted atomically
+        // height. Also so that the events are inserted atomically
         // with the height.
         let mut db_tx = self.transaction();
         let db = db_tx.as_mut();
-
-        let mut max_height = None;
-        for message in messages {
-            db.storage::<Messages>().insert(message.id(), message)?;
-            let max = max_height.get_or_insert(0u64);
-            *max = (*max).max(message.da_height.0);
-        }
-        if let Some(height) = max_height {
-            if **da_height < height {
-                return Err(anyhow::anyhow!("Invalid da height").into())
-            }
-        }
+        write(db)?;
         grow_monotonically(db, da_height)?;
         db_tx.commit()?;
         Ok(())
@@ -97,6 +131,49 @@ where
             .get(&METADATA_KEY)?
             .unwrap_or_default())
     }
+
+    fn insert_da_fee_sample(
+        &mut self,
+        sample: &DaFeeSample,
+        window: usize,
+    ) -> StorageResult<()> {
+        let mut db_tx = self.transaction();
+        let db = db_tx.as_mut();
+        let mut samples = (&db)
+            .storage::<DaFeeSamples>()
+            .get(&DA_FEE_SAMPLES_KEY)?
+            .map(|samples| samples.into_owned())
+            .unwrap_or_default();
+        if push_da_fee_sample(&mut samples, sample, window) {
+            db.storage::<DaFeeSamples>()
+                .insert(&DA_FEE_SAMPLES_KEY, &samples)?;
+            db_tx.commit()?;
+        }
+        Ok(())
+    }
+
+    fn get_da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>> {
+        Ok(StorageAsRef::storage::<DaFeeSamples>(&self)
+            .get(&DA_FEE_SAMPLES_KEY)?
+            .map(|samples| samples.into_owned())
+            .unwrap_or_default())
+    }
+}
+
+/// Appends the `sample` to the `samples` and removes the samples above the `window`.
+/// Returns `false` if the `sample` isn't above the latest one.
+pub(crate) fn push_da_fee_sample(
+    samples: &mut Vec<DaFeeSample>,
+    sample: &DaFeeSample,
+    window: usize,
+) -> bool {
+    if matches!(samples.last(), Some(last) if last.da_height >= sample.da_height) {
+        return false
+    }
+    samples.push(*sample);
+    let expired = samples.len().saturating_sub(window);
+    samples.drain(..expired);
+    true
 }
 
 fn grow_monotonically<Storage>(
@@ -125,6 +202,109 @@ where
     Ok(())
 }
 
+/// The database of the relayer of an additional DA network. It keeps the finalized
+/// height of the network in the [`OriginRelayerMetadata`] under the chain id of the
+/// network, so the relayers of several networks can share one database.
+#[derive(Clone, Debug)]
+pub struct OriginDb<D> {
+    database: D,
+    origin_chain_id: u64,
+}
+
+impl<D> OriginDb<D> {
+    /// Namespaces the `database` by the `origin_chain_id` of the DA network.
+    pub fn new(database: D, origin_chain_id: u64) -> Self {
+        Self {
+            database,
+            origin_chain_id,
+        }
+    }
+
+    /// Returns the chain id of the DA network.
+    pub fn origin_chain_id(&self) -> u64 {
+        self.origin_chain_id
+    }
+
+    /// Returns the underlying database.
+    pub fn inner(&self) -> &D {
+        &self.database
+    }
+}
+
+impl<D, Storage> RelayerDb for OriginDb<D>
+where
+    D: Send + Sync,
+    D: Transactional<Storage = Storage>,
+    D: StorageInspect<OriginRelayerMetadata, Error = StorageError>,
+    Storage: StorageMutate<OriginRelayerMetadata, Error = StorageError> + 'static,
+{
+    type Storage = Storage;
+
+    fn insert_da_block<F>(
+        &mut self,
+        da_height: &DaBlockHeight,
+        write: F,
+    ) -> StorageResult<()>
+    where
+        F: FnOnce(&mut Self::Storage) -> anyhow::Result<()>,
+    {
+        let mut db_tx = self.database.transaction();
+        let db = db_tx.as_mut();
+        write(db)?;
+        grow_origin_monotonically(db, self.origin_chain_id, da_height)?;
+        db_tx.commit()?;
+        Ok(())
+    }
+
+    fn set_finalized_da_height_to_at_least(
+        &mut self,
+        height: &DaBlockHeight,
+    ) -> StorageResult<()> {
+        let mut db_tx = self.database.transaction();
+        let db = db_tx.as_mut();
+        grow_origin_monotonically(db, self.origin_chain_id, height)?;
+        db_tx.commit()?;
+        Ok(())
+    }
+
+    fn get_finalized_da_height(&self) -> StorageResult<DaBlockHeight> {
+        Ok(
+            *StorageAsRef::storage::<OriginRelayerMetadata>(&self.database)
+                .get(&self.origin_chain_id)?
+                .unwrap_or_default(),
+        )
+    }
+
+    // The fees of the additional DA networks don't affect the cost of the DA layer,
+    // so only the relayer of the settlement layer records them.
+    fn insert_da_fee_sample(&mut self, _: &DaFeeSample, _: usize) -> StorageResult<()> {
+        Ok(())
+    }
+
+    fn get_da_fee_samples(&self) -> StorageResult<Vec<DaFeeSample>> {
+        Ok(vec![])
+    }
+}
+
+fn grow_origin_monotonically<Storage>(
+    s: &mut Storage,
+    origin_chain_id: u64,
+    height: &DaBlockHeight,
+) -> StorageResult<()>
+where
+    Storage: StorageMutate<OriginRelayerMetadata, Error = StorageError>,
+{
+    let current = (&s)
+        .storage::<OriginRelayerMetadata>()
+        .get(&origin_chain_id)?
+        .map(|cow| cow.as_u64());
+    if current.map_or(true, |current| **height > current) {
+        s.storage::<OriginRelayerMetadata>()
+            .insert(&origin_chain_id, height)?;
+    }
+    Ok(())
+}
+
 /// Metadata for relayer.
 pub struct RelayerMetadata;
 impl Mappable for RelayerMetadata {
@@ -138,3 +318,51 @@ impl Mappable for RelayerMetadata {
 /// If the relayer metadata ever contains more than one key, this should be
 /// changed from a unit value.
 const METADATA_KEY: () = ();
+
+/// The rolling window of the fee samples of the finalized DA blocks, the oldest first.
+pub struct DaFeeSamples;
+impl Mappable for DaFeeSamples {
+    type Key = Self::OwnedKey;
+    type OwnedKey = ();
+    type Value = Self::OwnedValue;
+    type OwnedValue = Vec<DaFeeSample>;
+}
+
+/// Key for the DA fee samples.
+const DA_FEE_SAMPLES_KEY: () = ();
+
+/// The finalized DA heights of the relayers of the additional DA networks,
+/// by the chain id of the network.
+pub struct OriginRelayerMetadata;
+impl Mappable for OriginRelayerMetadata {
+    type Key = Self::OwnedKey;
+    type OwnedKey = u64;
+    type Value = Self::OwnedValue;
+    type OwnedValue = DaBlockHeight;
+}
+
+/// The messages sent from the additional DA networks. Unlike the
+/// [`Messages`](fuel_core_storage::tables::Messages), they aren't spendable by the
+/// transactions. The key is built by the [`origin_message_key`].
+pub struct OriginMessages;
+impl Mappable for OriginMessages {
+    type Key = Self::OwnedKey;
+    type OwnedKey = [u8; ORIGIN_MESSAGE_KEY_SIZE];
+    type Value = Self::OwnedValue;
+    type OwnedValue = Message;
+}
+
+/// The size of the key of the [`OriginMessages`].
+pub const ORIGIN_MESSAGE_KEY_SIZE: usize = 8 + Nonce::LEN;
+
+/// Returns the key of the message with the `nonce` sent from the DA network with the
+/// `origin_chain_id`. The messages of one network share the prefix of the chain id.
+pub fn origin_message_key(
+    origin_chain_id: u64,
+    nonce: &Nonce,
+) -> [u8; ORIGIN_MESSAGE_KEY_SIZE] {
+    let mut key = [0; ORIGIN_MESSAGE_KEY_SIZE];
+    key[..8].copy_from_slice(&origin_chain_id.to_be_bytes());
+    key[8..].copy_from_slice(nonce.as_ref());
+    key
+}
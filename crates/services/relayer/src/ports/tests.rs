@@ -1,13 +1,16 @@
 use std::borrow::Cow;
 
-use fuel_core_storage::test_helpers::MockStorage;
+use fuel_core_storage::{
+    tables::Messages,
+    test_helpers::MockStorage,
+};
 use fuel_core_types::entities::message::Message;
 use test_case::test_case;
 
 use super::*;
 
 #[test]
-fn test_insert_messages() {
+fn test_insert_da_block() {
     let mut db = MockStorage::default();
     db.expect_insert::<Messages>()
         .times(2)
@@ -30,22 +33,30 @@ fn test_insert_messages() {
     let mut m2 = m.clone();
     m2.nonce = 1.into();
     assert_ne!(m.id(), m2.id());
-    let messages = [m, m2];
-    db.insert_messages(&12u64.into(), &messages[..]).unwrap();
+    db.insert_da_block(&12u64.into(), |storage| {
+        for m in [m, m2] {
+            storage.storage::<Messages>().insert(m.id(), &m)?;
+        }
+        Ok(())
+    })
+    .unwrap();
 }
 
 #[test]
-fn insert_always_raises_da_height_monotonically() {
-    let messages: Vec<_> = (0..10)
-        .map(|i| Message {
-            amount: i,
-            da_height: i.into(),
-            ..Default::default()
-        })
-        .collect();
+fn insert_da_block_is_not_committed_on_error() {
+    let mut db = MockStorage::default();
+    db.expect_insert::<RelayerMetadata>().never();
+    db.expect_commit().never();
+    let mut db = db.into_transactional();
+
+    let result = db.insert_da_block(&12u64.into(), |_| Err(anyhow::anyhow!("Failed")));
 
+    assert!(result.is_err());
+}
+
+#[test]
+fn insert_always_raises_da_height_monotonically() {
     let mut db = MockStorage::default();
-    db.expect_insert::<Messages>().returning(|_, _| Ok(None));
     db.expect_insert::<RelayerMetadata>()
         .once()
         .withf(|_, v| **v == 9)
@@ -56,17 +67,16 @@ fn insert_always_raises_da_height_monotonically() {
         .returning(|_| Ok(None));
 
     let mut db = db.into_transactional();
-    db.insert_messages(&9u64.into(), &messages[5..]).unwrap();
+    db.insert_da_block(&9u64.into(), |_| Ok(())).unwrap();
 
     let mut db = MockStorage::default();
-    db.expect_insert::<Messages>().returning(|_, _| Ok(None));
     db.expect_commit().returning(|| Ok(()));
     db.expect_get::<RelayerMetadata>()
         .once()
         .returning(|_| Ok(Some(std::borrow::Cow::Owned(9u64.into()))));
 
     let mut db = db.into_transactional();
-    db.insert_messages(&5u64.into(), &messages[..5]).unwrap();
+    db.insert_da_block(&5u64.into(), |_| Ok(())).unwrap();
 }
 
 #[test_case(None, 0, 0; "can set DA height to 0 when there is none available")]
@@ -98,3 +108,38 @@ fn set_raises_da_height_monotonically(
     db.set_finalized_da_height_to_at_least(&new_height.into())
         .unwrap();
 }
+
+#[test]
+fn origin_db_raises_da_height_of_its_chain_id() {
+    let mut db = MockStorage::default();
+    db.expect_get::<OriginRelayerMetadata>()
+        .once()
+        .withf(|k| *k == 5)
+        .returning(|_| Ok(Some(std::borrow::Cow::Owned(9u64.into()))));
+    db.expect_insert::<OriginRelayerMetadata>()
+        .once()
+        .withf(|k, v| *k == 5 && **v == 12)
+        .returning(|_, _| Ok(None));
+    db.expect_insert::<RelayerMetadata>().never();
+    db.expect_commit().returning(|| Ok(()));
+    let mut db = OriginDb::new(db.into_transactional(), 5);
+
+    db.insert_da_block(&12u64.into(), |_| Ok(())).unwrap();
+}
+
+#[test]
+fn da_fee_samples_keep_the_latest_window() {
+    let sample = |da_height: u64| DaFeeSample {
+        da_height: da_height.into(),
+        base_fee: da_height,
+        blob_base_fee: None,
+    };
+    let mut samples = vec![];
+
+    for da_height in [1, 2, 3] {
+        assert!(push_da_fee_sample(&mut samples, &sample(da_height), 2));
+    }
+    assert!(!push_da_fee_sample(&mut samples, &sample(3), 2));
+
+    assert_eq!(samples, vec![sample(2), sample(3)]);
+}
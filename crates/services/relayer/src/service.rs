@@ -1,19 +1,34 @@
 //! This module handles bridge communications between the fuel node and the data availability layer.
 
 use crate::{
-    log::EthEventLog,
-    ports::RelayerDb,
+    events::EventRegistry,
+    latency::{
+        MessageLatencies,
+        MessageLatency,
+    },
+    ports::{
+        OriginDb,
+        OriginMessages,
+        RelayerDb,
+    },
     service::state::EthLocal,
     Config,
+    OriginConfig,
+    PollIntervals,
+    RelayerError,
+    RelayerErrorKind,
 };
 use async_trait::async_trait;
 use core::time::Duration;
 use ethers_core::types::{
+    Block,
     Filter,
     Log,
     SyncingStatus,
     ValueOrArray,
     H160,
+    H256,
+    U256,
 };
 use ethers_providers::{
     Http,
@@ -21,6 +36,7 @@ use ethers_providers::{
     Provider,
     ProviderError,
 };
+use fuel_core_metrics::relayer_metrics::relayer_metrics;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -28,20 +44,34 @@ use fuel_core_services::{
     StateWatcher,
 };
 use fuel_core_storage::{
-    tables::Messages,
+    tables::{
+        MessageCancellations,
+        MessageDeposits,
+        Messages,
+    },
     StorageAsRef,
     StorageInspect,
+    StorageMutate,
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    entities::message::Message,
+    entities::message::{
+        Message,
+        MessageCancellation,
+    },
     fuel_types::Nonce,
+    services::relayer::DaFeeSample,
 };
 use futures::StreamExt;
 use std::{
     borrow::Cow,
     convert::TryInto,
     ops::Deref,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::SystemTime,
 };
 use synced::update_synced;
 use tokio::sync::watch;
@@ -66,51 +96,105 @@ type NotifySynced = watch::Sender<Option<DaBlockHeight>>;
 /// The alias of runnable relayer service.
 pub type Service<D> = CustomizableService<Provider<Http>, D>;
 type CustomizableService<P, D> = ServiceRunner<NotInitializedTask<P, D>>;
+/// The alias of runnable relayer service of an additional DA network.
+pub type OriginService<D> = Service<OriginDb<D>>;
 
 /// The shared state of the relayer task.
 #[derive(Clone)]
 pub struct SharedState<D> {
     /// Receives signals when the relayer reaches consistency with the DA layer.
     synced: Synced,
+    /// Updates the intervals of the running relayer loop.
+    poll_intervals: Arc<watch::Sender<PollIntervals>>,
+    /// Pauses and resumes the synchronization with the DA layer.
+    paused: Arc<watch::Sender<bool>>,
+    /// The latest finalized height of the DA layer observed by the relayer.
+    da_finalized: watch::Receiver<Option<DaBlockHeight>>,
+    /// The kind of the error that failed the latest iteration of the relayer loop.
+    last_error: watch::Receiver<Option<RelayerErrorKind>>,
+    /// The latencies of the relayed messages, if they are tracked.
+    message_latencies: Option<MessageLatencies>,
     database: D,
 }
 
 /// Not initialized version of the [`Task`].
-pub struct NotInitializedTask<P, D> {
+pub struct NotInitializedTask<P, D: RelayerDb> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
     database: D,
+    /// The handlers of the L1 events.
+    events: EventRegistry<D::Storage>,
     /// Configuration settings.
     config: Config,
+    /// The current intervals of the relayer loop.
+    poll_intervals: Arc<watch::Sender<PollIntervals>>,
+    /// Whether the synchronization with the DA layer is paused.
+    paused: Arc<watch::Sender<bool>>,
+    /// Sends the latest finalized height of the DA layer.
+    da_finalized: watch::Sender<Option<DaBlockHeight>>,
+    /// Sends the kind of the error that failed the latest iteration.
+    last_error: watch::Sender<Option<RelayerErrorKind>>,
+    /// The latencies of the relayed messages, if they are tracked.
+    message_latencies: Option<MessageLatencies>,
 }
 
 /// The actual relayer background task that syncs with the DA layer.
-pub struct Task<P, D> {
+pub struct Task<P, D: RelayerDb> {
     /// Sends signals when the relayer reaches consistency with the DA layer.
     synced: NotifySynced,
     /// The node that communicates with Ethereum.
     eth_node: P,
     /// The fuel database.
     database: D,
+    /// The handlers of the L1 events.
+    events: EventRegistry<D::Storage>,
     /// Configuration settings.
     config: Config,
+    /// The current intervals of the relayer loop.
+    poll_intervals: watch::Receiver<PollIntervals>,
+    /// Whether the synchronization with the DA layer is paused.
+    paused: watch::Receiver<bool>,
+    /// Sends the latest finalized height of the DA layer.
+    da_finalized: watch::Sender<Option<DaBlockHeight>>,
+    /// Sends the kind of the error that failed the latest iteration.
+    last_error: watch::Sender<Option<RelayerErrorKind>>,
+    /// The latencies of the relayed messages, if they are tracked.
+    message_latencies: Option<MessageLatencies>,
+    /// The fee sample of the latest finalized DA block, not recorded yet.
+    pending_da_fee: Mutex<Option<DaFeeSample>>,
     /// The watcher used to track the state of the service. If the service stops,
     /// the task will stop synchronization.
     shutdown: StateWatcher,
 }
 
-impl<P, D> NotInitializedTask<P, D> {
+impl<P, D: RelayerDb> NotInitializedTask<P, D> {
     /// Create a new relayer task.
-    fn new(eth_node: P, database: D, config: Config) -> Self {
+    fn new(
+        eth_node: P,
+        database: D,
+        config: Config,
+        events: EventRegistry<D::Storage>,
+        message_latencies: Option<MessageLatencies>,
+    ) -> Self {
         let (synced, _) = watch::channel(None);
+        let (poll_intervals, _) = watch::channel(config.poll_intervals());
+        let (paused, _) = watch::channel(false);
+        let (da_finalized, _) = watch::channel(None);
+        let (last_error, _) = watch::channel(None);
         Self {
             synced,
             eth_node,
             database,
+            events,
             config,
+            poll_intervals: Arc::new(poll_intervals),
+            paused: Arc::new(paused),
+            da_finalized,
+            last_error,
+            message_latencies,
         }
     }
 }
@@ -124,6 +208,20 @@ where
             .set_finalized_da_height_to_at_least(&self.config.da_deploy_height)
             .expect("Should be able to set the finalized da height");
     }
+
+    /// Reports the kind of the error of the iteration to the metrics
+    /// and the shared state. The stop signal is not a failure.
+    fn record_result(&self, result: &Result<(), RelayerError>) {
+        let kind = match result {
+            Ok(()) => None,
+            Err(RelayerError::Shutdown) => return,
+            Err(error) => {
+                relayer_metrics().record_error(error.kind().as_str());
+                Some(error.kind())
+            }
+        };
+        self.last_error.send_replace(kind);
+    }
 }
 
 #[async_trait]
@@ -132,17 +230,18 @@ where
     P: Middleware<Error = ProviderError> + 'static,
     D: RelayerDb + 'static,
 {
-    async fn wait_if_eth_syncing(&self) -> anyhow::Result<()> {
+    async fn wait_if_eth_syncing(&self) -> Result<(), RelayerError> {
         let mut shutdown = self.shutdown.clone();
+        let intervals = *self.poll_intervals.borrow();
         tokio::select! {
             biased;
             _ = shutdown.while_started() => {
-                Err(anyhow::anyhow!("The relayer got a stop signal"))
+                Err(RelayerError::Shutdown)
             },
             result = syncing::wait_if_eth_syncing(
                 &self.eth_node,
-                self.config.syncing_call_frequency,
-                self.config.syncing_log_frequency,
+                intervals.syncing_call_frequency,
+                intervals.syncing_log_frequency,
             ) => {
                 result
             }
@@ -152,20 +251,45 @@ where
     async fn download_logs(
         &mut self,
         eth_sync_gap: &state::EthSyncGap,
-    ) -> anyhow::Result<()> {
+    ) -> Result<(), RelayerError> {
         let logs = download_logs(
             eth_sync_gap,
             self.config.eth_v2_listening_contracts.clone(),
+            self.events.signatures(),
             &self.eth_node,
             self.config.log_page_size,
         );
         let logs = logs.take_until(self.shutdown.while_started());
-        write_logs(&mut self.database, logs).await
+        write_logs(
+            &mut self.database,
+            &self.events,
+            self.message_latencies.as_ref(),
+            logs,
+        )
+        .await
     }
 
     fn update_synced(&self, state: &state::EthState) {
+        self.da_finalized
+            .send_replace(Some(DaBlockHeight::from(state.remote())));
         update_synced(&self.synced, state)
     }
+
+    fn record_da_fee(&mut self) {
+        let sample = self
+            .pending_da_fee
+            .get_mut()
+            .expect("Never poisoned")
+            .take();
+        if let Some(sample) = sample.filter(|_| self.config.da_fee_window > 0) {
+            if let Err(e) = self
+                .database
+                .insert_da_fee_sample(&sample, self.config.da_fee_window)
+            {
+                tracing::warn!("Failed to record the DA fee sample: {e}");
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -185,6 +309,11 @@ where
 
         SharedState {
             synced,
+            poll_intervals: self.poll_intervals.clone(),
+            paused: self.paused.clone(),
+            da_finalized: self.da_finalized.subscribe(),
+            last_error: self.last_error.subscribe(),
+            message_latencies: self.message_latencies.clone(),
             database: self.database.clone(),
         }
     }
@@ -199,13 +328,26 @@ where
             synced,
             eth_node,
             database,
+            events,
             config,
+            poll_intervals,
+            paused,
+            da_finalized,
+            last_error,
+            message_latencies,
         } = self;
         let mut task = Task {
             synced,
             eth_node,
             database,
+            events,
             config,
+            poll_intervals: poll_intervals.subscribe(),
+            paused: paused.subscribe(),
+            da_finalized,
+            last_error,
+            message_latencies,
+            pending_da_fee: Mutex::new(None),
             shutdown,
         };
         task.set_deploy_height();
@@ -221,24 +363,37 @@ where
     D: RelayerDb + 'static,
 {
     async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
-        let now = tokio::time::Instant::now();
         let should_continue = true;
+        if *self.paused.borrow_and_update() {
+            let mut shutdown = self.shutdown.clone();
+            let resumed = async { self.paused.wait_for(|paused| !paused).await.is_ok() };
+            tokio::select! {
+                biased;
+                _ = shutdown.while_started() => {}
+                resumed = resumed => {
+                    if !resumed {
+                        // Nobody can resume the relayer, so it waits for the shutdown.
+                        let _ = shutdown.while_started().await;
+                    }
+                }
+            }
+            return Ok(should_continue)
+        }
+        let now = tokio::time::Instant::now();
 
         let result = run::run(self).await;
+        self.record_result(&result);
 
         if self.shutdown.borrow_and_update().started()
             && (result.is_err() | self.synced.borrow().is_some())
         {
             // Sleep the loop so the da node is not spammed.
-            tokio::time::sleep(
-                self.config
-                    .sync_minimum_duration
-                    .saturating_sub(now.elapsed()),
-            )
-            .await;
+            let sync_minimum_duration =
+                self.poll_intervals.borrow().sync_minimum_duration;
+            tokio::time::sleep(sync_minimum_duration.saturating_sub(now.elapsed())).await;
         }
 
-        result.map(|_| should_continue)
+        result.map(|_| should_continue).map_err(Into::into)
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
@@ -280,6 +435,58 @@ impl<D> SharedState<D> {
         Ok(())
     }
 
+    /// Returns the current intervals of the relayer loop.
+    pub fn poll_intervals(&self) -> PollIntervals {
+        *self.poll_intervals.borrow()
+    }
+
+    /// Updates the intervals of the running relayer loop.
+    /// They are used starting from the next iteration.
+    pub fn set_poll_intervals(&self, intervals: PollIntervals) {
+        self.poll_intervals.send_replace(intervals);
+    }
+
+    /// Returns `true` if the synchronization with the DA layer is paused.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Pauses or resumes the synchronization with the DA layer. The current
+    /// iteration of the relayer loop is finished before the pause.
+    pub fn set_paused(&self, paused: bool) {
+        self.paused.send_replace(paused);
+    }
+
+    /// Returns the kind of the error that failed the latest iteration of the relayer
+    /// loop. Returns `None` if the latest iteration succeeded.
+    pub fn last_error(&self) -> Option<RelayerErrorKind> {
+        *self.last_error.borrow()
+    }
+
+    /// Returns the number of the finalized DA blocks that the relayer hasn't synced yet.
+    /// Returns `None` if the relayer hasn't observed the DA layer yet.
+    pub fn da_lag(&self) -> anyhow::Result<Option<u64>>
+    where
+        D: RelayerDb + 'static,
+    {
+        let Some(remote) = *self.da_finalized.borrow() else {
+            return Ok(None)
+        };
+        let local = self.get_finalized_da_height()?;
+        Ok(Some(remote.0.saturating_sub(local.0)))
+    }
+
+    /// Returns the latencies of the relayed messages. Returns `None` if the relayer
+    /// doesn't track them.
+    pub fn message_latencies(&self) -> Option<MessageLatencies> {
+        self.message_latencies.clone()
+    }
+
+    /// Returns the latency of the message relayed recently.
+    pub fn message_latency(&self, nonce: &Nonce) -> Option<MessageLatency> {
+        self.message_latencies.as_ref()?.get(nonce)
+    }
+
     /// Get a message if it has been synced
     /// and is <= the given height.
     pub fn get_message(
@@ -298,6 +505,24 @@ impl<D> SharedState<D> {
             .filter(|message| message.da_height <= *da_height))
     }
 
+    /// Get the cancellation of the message if it has been synced
+    /// and is <= the given height.
+    pub fn get_message_cancellation(
+        &self,
+        id: &Nonce,
+        da_height: &DaBlockHeight,
+    ) -> anyhow::Result<Option<MessageCancellation>>
+    where
+        D: StorageInspect<MessageCancellations, Error = fuel_core_storage::Error>,
+    {
+        Ok(self
+            .database
+            .storage::<MessageCancellations>()
+            .get(id)?
+            .map(Cow::into_owned)
+            .filter(|cancellation| cancellation.da_height <= *da_height))
+    }
+
     /// Get finalized da height that represents last block from da layer that got finalized.
     /// Panics if height is not set as of initialization of the relayer.
     pub fn get_finalized_da_height(&self) -> anyhow::Result<DaBlockHeight>
@@ -306,6 +531,14 @@ impl<D> SharedState<D> {
     {
         Ok(self.database.get_finalized_da_height()?)
     }
+
+    /// Returns the recorded fee samples of the finalized DA blocks, the oldest first.
+    pub fn da_fee_samples(&self) -> anyhow::Result<Vec<DaFeeSample>>
+    where
+        D: RelayerDb + 'static,
+    {
+        Ok(self.database.get_da_fee_samples()?)
+    }
 }
 
 #[async_trait]
@@ -314,18 +547,31 @@ where
     P: Middleware<Error = ProviderError>,
     D: RelayerDb + 'static,
 {
-    async fn finalized(&self) -> anyhow::Result<u64> {
+    async fn finalized(&self) -> Result<u64, RelayerError> {
         let mut shutdown = self.shutdown.clone();
         tokio::select! {
             biased;
             _ = shutdown.while_started() => {
-                Err(anyhow::anyhow!("The relayer got a stop signal"))
+                Err(RelayerError::Shutdown)
             },
             block = self.eth_node.get_block(ethers_core::types::BlockNumber::Finalized) => {
-                let block_number = block?
+                let block = block?;
+                let block_number = block
+                    .as_ref()
                     .and_then(|block| block.number)
-                    .ok_or(anyhow::anyhow!("Block pending"))?
+                    .ok_or_else(|| {
+                        RelayerError::Transient("The finalized block is pending".to_string())
+                    })?
                     .as_u64();
+                let fraction = self.config.blob_base_fee_update_fraction;
+                let sample = block
+                    .and_then(|block| da_fee_sample(&block, block_number, fraction));
+                if let Some(sample) = sample {
+                    *self.pending_da_fee.lock().expect("Never poisoned") = Some(sample);
+                }
+                if let Some(latencies) = &self.message_latencies {
+                    latencies.record_finalized(block_number.into(), SystemTime::now());
+                }
                 Ok(block_number)
             }
         }
@@ -343,11 +589,94 @@ where
     }
 }
 
-/// Creates an instance of runnable relayer service.
+/// Returns the fees of the finalized DA `block`. Returns `None` if the block has
+/// no base fee, like the blocks before EIP-1559.
+fn da_fee_sample<TX>(
+    block: &Block<TX>,
+    da_height: u64,
+    blob_base_fee_update_fraction: u64,
+) -> Option<DaFeeSample> {
+    let base_fee = block.base_fee_per_gas?;
+    // The blob fields are not parsed by the `ethers`, so they are read from the rest.
+    let blob_base_fee = block
+        .other
+        .get_deserialized::<U256>("excessBlobGas")
+        .and_then(Result::ok)
+        .map(|excess| {
+            let excess = u128::try_from(excess).unwrap_or(u128::MAX);
+            crate::blob_fee::blob_base_fee_from_excess(
+                excess,
+                blob_base_fee_update_fraction,
+            )
+        });
+    Some(DaFeeSample {
+        da_height: da_height.into(),
+        base_fee: u64::try_from(base_fee).unwrap_or(u64::MAX),
+        blob_base_fee: blob_base_fee
+            .map(|blob_base_fee| u64::try_from(blob_base_fee).unwrap_or(u64::MAX)),
+    })
+}
+
+/// Creates an instance of runnable relayer service with the events of the Fuel bridge.
+/// The service tracks the latencies of the relayed messages.
 pub fn new_service<D>(database: D, config: Config) -> anyhow::Result<Service<D>>
 where
     D: RelayerDb + Clone + 'static,
+    D::Storage: StorageMutate<Messages, Error = fuel_core_storage::Error>
+        + StorageMutate<MessageDeposits, Error = fuel_core_storage::Error>
+        + StorageMutate<MessageCancellations, Error = fuel_core_storage::Error>,
 {
+    Ok(new_service_internal(
+        eth_node(&config)?,
+        database,
+        config,
+        EventRegistry::bridge(),
+        Some(MessageLatencies::default()),
+    ))
+}
+
+/// Creates an instance of runnable relayer service of the additional DA network.
+/// It writes the messages of the network into the [`OriginMessages`] table
+/// under the chain id of the network.
+pub fn new_origin_service<D>(
+    database: D,
+    config: OriginConfig,
+) -> anyhow::Result<OriginService<D>>
+where
+    OriginDb<D>: RelayerDb + Clone + 'static,
+    <OriginDb<D> as RelayerDb>::Storage:
+        StorageMutate<OriginMessages, Error = fuel_core_storage::Error>,
+{
+    let OriginConfig {
+        origin_chain_id,
+        relayer,
+    } = config;
+    new_service_with_events(
+        OriginDb::new(database, origin_chain_id),
+        relayer,
+        EventRegistry::origin_bridge(origin_chain_id),
+    )
+}
+
+/// Creates an instance of runnable relayer service that handles the `events`.
+pub fn new_service_with_events<D>(
+    database: D,
+    config: Config,
+    events: EventRegistry<D::Storage>,
+) -> anyhow::Result<Service<D>>
+where
+    D: RelayerDb + Clone + 'static,
+{
+    Ok(new_service_internal(
+        eth_node(&config)?,
+        database,
+        config,
+        events,
+        None,
+    ))
+}
+
+fn eth_node(config: &Config) -> anyhow::Result<Provider<Http>> {
     let url = config.relayer.clone().ok_or_else(|| {
         anyhow::anyhow!(
             "Tried to start Relayer without setting an eth_client in the config"
@@ -355,8 +684,7 @@ where
     })?;
     // TODO: Does this handle https?
     let http = Http::new(url);
-    let eth_node = Provider::new(http);
-    Ok(new_service_internal(eth_node, database, config))
+    Ok(Provider::new(http))
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
@@ -369,20 +697,32 @@ pub fn new_service_test<P, D>(
 where
     P: Middleware<Error = ProviderError> + 'static,
     D: RelayerDb + Clone + 'static,
+    D::Storage: StorageMutate<Messages, Error = fuel_core_storage::Error>
+        + StorageMutate<MessageDeposits, Error = fuel_core_storage::Error>
+        + StorageMutate<MessageCancellations, Error = fuel_core_storage::Error>,
 {
-    new_service_internal(eth_node, database, config)
+    new_service_internal(
+        eth_node,
+        database,
+        config,
+        EventRegistry::bridge(),
+        Some(MessageLatencies::default()),
+    )
 }
 
 fn new_service_internal<P, D>(
     eth_node: P,
     database: D,
     config: Config,
+    events: EventRegistry<D::Storage>,
+    message_latencies: Option<MessageLatencies>,
 ) -> CustomizableService<P, D>
 where
     P: Middleware<Error = ProviderError> + 'static,
     D: RelayerDb + Clone + 'static,
 {
-    let task = NotInitializedTask::new(eth_node, database, config);
+    let task =
+        NotInitializedTask::new(eth_node, database, config, events, message_latencies);
 
     CustomizableService::new(task)
 }
@@ -1,5 +1,9 @@
 use super::*;
 use futures::TryStreamExt;
+use std::{
+    collections::BTreeMap,
+    time::SystemTime,
+};
 
 #[cfg(test)]
 mod test;
@@ -8,6 +12,7 @@ mod test;
 pub(crate) fn download_logs<'a, P>(
     eth_sync_gap: &state::EthSyncGap,
     contracts: Vec<H160>,
+    signatures: Vec<H256>,
     eth_node: &'a P,
     page_size: u64,
 ) -> impl futures::Stream<Item = Result<(u64, Vec<Log>), ProviderError>> + 'a
@@ -19,6 +24,7 @@ where
         eth_sync_gap.page(page_size),
         move |page: Option<state::EthSyncPage>| {
             let contracts = contracts.clone();
+            let signatures = signatures.clone();
             async move {
                 match page {
                     None => Ok(None),
@@ -28,7 +34,7 @@ where
                             .from_block(page.oldest())
                             .to_block(page.latest())
                             .address(ValueOrArray::Array(contracts))
-                            .topic0(*crate::config::ETH_LOG_MESSAGE);
+                            .topic0(ValueOrArray::Array(signatures));
 
                         tracing::info!(
                             "Downloading logs for block range: {}..={}",
@@ -54,27 +60,66 @@ where
 }
 
 /// Write the logs to the database.
-pub(crate) async fn write_logs<D, S>(database: &mut D, logs: S) -> anyhow::Result<()>
+///
+/// The events of each DA block are committed together with the height of this
+/// block in the ascending order of the heights. It guarantees that the finalized
+/// height never points to a block whose events are only partially written.
+/// The finalized height is set to the end of the page after all its events.
+///
+/// The writing is idempotent: the already stored identical messages are skipped,
+/// while the conflicting messages with the same nonce fail the writing.
+///
+/// The stored messages are recorded into the `latencies`, if any.
+pub(crate) async fn write_logs<D, S>(
+    database: &mut D,
+    events: &EventRegistry<D::Storage>,
+    latencies: Option<&MessageLatencies>,
+    logs: S,
+) -> Result<(), RelayerError>
 where
     D: RelayerDb,
     S: futures::Stream<Item = Result<(u64, Vec<Log>), ProviderError>>,
 {
     tokio::pin!(logs);
-    while let Some((height, events)) = logs.try_next().await? {
-        let messages = events
-            .into_iter()
-            .filter_map(|event| match EthEventLog::try_from(&event) {
-                Ok(event) => {
-                    match event {
-                        EthEventLog::Message(m) => Some(Ok(Message::from(&m))),
-                        // TODO: Log out ignored messages.
-                        EthEventLog::Ignored => None,
-                    }
-                }
-                Err(e) => Some(Err(e)),
-            })
-            .collect::<anyhow::Result<Vec<_>>>()?;
-        database.insert_messages(&height.into(), &messages)?;
+    while let Some((height, page)) = logs.try_next().await? {
+        let mut blocks = BTreeMap::<DaBlockHeight, Vec<_>>::new();
+        for log in page {
+            let da_height = log
+                .block_number
+                .ok_or_else(|| {
+                    RelayerError::Decode("Log missing block height".to_string())
+                })?
+                .as_u64();
+            blocks.entry(da_height.into()).or_default().push(log);
+        }
+        for (da_height, logs) in blocks {
+            if *da_height > height {
+                return Err(RelayerError::Decode(format!(
+                    "The message from the DA block {} is outside of the page ending at {}",
+                    *da_height, height
+                )))
+            }
+            // The error of the handlers is kept aside to not lose its kind
+            // in the error of the storage transaction.
+            let mut event_error = None;
+            let inserted = database.insert_da_block(&da_height, |storage| {
+                logs.iter()
+                    .try_for_each(|log| events.handle(log, storage))
+                    .map_err(|e| {
+                        let message = anyhow::anyhow!(e.to_string());
+                        event_error = Some(RelayerError::from_event(e));
+                        message
+                    })
+            });
+            if let Some(error) = event_error {
+                return Err(error)
+            }
+            inserted?;
+            if let Some(latencies) = latencies {
+                latencies.record_stored(&logs, SystemTime::now());
+            }
+        }
+        database.set_finalized_da_height_to_at_least(&height.into())?;
     }
     Ok(())
 }
@@ -132,6 +132,7 @@ async fn can_paginate_logs(input: Input) -> Expected {
     let result = download_logs(
         &EthSyncGap::new(*eth_gap.start(), *eth_gap.end()),
         contracts,
+        vec![*crate::config::ETH_LOG_MESSAGE],
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
     )
@@ -150,14 +151,14 @@ async fn can_paginate_logs(input: Input) -> Expected {
     ] => 1 ; "Can add single"
 )]
 #[test_case(vec![
-    Ok((1, messages_n(3, 0))),
-    Ok((2, messages_n(1, 4)))
-    ] => 2 ; "Can add two"
+    Ok((3, messages_n(3, 0))),
+    Ok((5, messages_n(1, 4)))
+    ] => 5 ; "Can add two"
 )]
 #[test_case(vec![
-    Ok((1, messages_n(3, 0))),
-    Ok((2, vec![]))
-    ] => 2 ; "Can add empty"
+    Ok((3, messages_n(3, 0))),
+    Ok((4, vec![]))
+    ] => 4 ; "Can add empty"
 )]
 #[test_case(vec![
     Ok((7, messages_n(3, 0))),
@@ -165,6 +166,11 @@ async fn can_paginate_logs(input: Input) -> Expected {
     Err(ProviderError::CustomError("".to_string()))
     ] => 19 ; "Still adds height when error"
 )]
+#[test_case(vec![
+    Ok((1, messages_n(3, 0))),
+    Ok((5, messages_n(1, 4)))
+    ] => 1 ; "Stops at the message outside of the page"
+)]
 #[tokio::test]
 async fn test_da_height_updates(
     stream: Vec<Result<(u64, Vec<Log>), ProviderError>>,
@@ -176,7 +182,135 @@ async fn test_da_height_updates(
 
     let logs = futures::stream::iter(stream);
 
-    let _ = write_logs(&mut mock_db, logs).await;
+    let _ = write_logs(&mut mock_db, &EventRegistry::bridge(), None, logs).await;
 
     *mock_db.get_finalized_da_height().unwrap()
 }
+
+/// Fails to insert the messages of the DA block at `fail_at`.
+struct FailingDb {
+    db: crate::mock_db::MockDb,
+    fail_at: DaBlockHeight,
+}
+
+impl RelayerDb for FailingDb {
+    type Storage = crate::mock_db::Data;
+
+    fn insert_da_block<F>(
+        &mut self,
+        da_height: &DaBlockHeight,
+        write: F,
+    ) -> fuel_core_storage::Result<()>
+    where
+        F: FnOnce(&mut Self::Storage) -> anyhow::Result<()>,
+    {
+        if *da_height == self.fail_at {
+            return Err(anyhow::anyhow!("Failed to write the messages").into())
+        }
+        self.db.insert_da_block(da_height, write)
+    }
+
+    fn set_finalized_da_height_to_at_least(
+        &mut self,
+        height: &DaBlockHeight,
+    ) -> fuel_core_storage::Result<()> {
+        self.db.set_finalized_da_height_to_at_least(height)
+    }
+
+    fn get_finalized_da_height(&self) -> fuel_core_storage::Result<DaBlockHeight> {
+        self.db.get_finalized_da_height()
+    }
+}
+
+#[tokio::test]
+async fn finalized_height_does_not_pass_partially_written_block() {
+    let mut db = FailingDb {
+        db: crate::mock_db::MockDb::default(),
+        fail_at: 4u64.into(),
+    };
+    db.set_finalized_da_height_to_at_least(&0u64.into())
+        .unwrap();
+    // The messages of the blocks `2` and `4` are in the same page.
+    let page = messages(0..=3, 2..=5, 0..=0)
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut log)| {
+            let block_number = if i < 2 { 2u64 } else { 4u64 };
+            log.block_number = Some(block_number.into());
+            log
+        })
+        .collect();
+    let logs = futures::stream::iter(vec![Ok((10, page))]);
+
+    let result = write_logs(&mut db, &EventRegistry::bridge(), None, logs).await;
+
+    assert!(matches!(result, Err(RelayerError::Storage(_))));
+    // Only the messages of the block `2` are committed with its height.
+    assert_eq!(*db.get_finalized_da_height().unwrap(), 2);
+    let stored = db.db.data.lock().unwrap();
+    assert_eq!(stored.messages.len(), 1);
+    assert_eq!(stored.messages[&DaBlockHeight::from(2u64)].len(), 2);
+}
+
+#[tokio::test]
+async fn replayed_logs_are_written_once() {
+    let mut db = crate::mock_db::MockDb::default();
+    db.set_finalized_da_height_to_at_least(&0u64.into())
+        .unwrap();
+    let page = messages_n(3, 0);
+    // The provider delivers the page twice, the second time after the restart.
+    let logs = futures::stream::iter(vec![Ok((3, page.clone())), Ok((3, page.clone()))]);
+    write_logs(&mut db, &EventRegistry::bridge(), None, logs)
+        .await
+        .unwrap();
+    let logs = futures::stream::iter(vec![Ok((5, page))]);
+    write_logs(&mut db, &EventRegistry::bridge(), None, logs)
+        .await
+        .unwrap();
+
+    assert_eq!(*db.get_finalized_da_height().unwrap(), 5);
+    let stored = db.data.lock().unwrap();
+    assert_eq!(stored.messages.values().map(|m| m.len()).sum::<usize>(), 4);
+    assert_eq!(stored.deposits.len(), 4);
+}
+
+#[tokio::test]
+async fn conflicting_logs_stop_the_writing() {
+    let mut db = crate::mock_db::MockDb::default();
+    db.set_finalized_da_height_to_at_least(&0u64.into())
+        .unwrap();
+    // The message with the nonce `1` is sent again with another amount.
+    let mut conflicting = MessageSentFilter {
+        nonce: U256::from(1),
+        amount: 100,
+        ..Default::default()
+    }
+    .into_log();
+    conflicting.address = u32_to_contract(0);
+    conflicting.block_number = Some(2u64.into());
+    let logs = futures::stream::iter(vec![
+        Ok((1, messages_n(1, 0))),
+        Ok((2, vec![conflicting])),
+    ]);
+
+    let result = write_logs(&mut db, &EventRegistry::bridge(), None, logs).await;
+
+    assert!(matches!(result, Err(RelayerError::Decode(_))));
+    assert_eq!(*db.get_finalized_da_height().unwrap(), 1);
+}
+
+#[tokio::test]
+async fn failed_rpc_and_malformed_logs_have_different_kinds() {
+    let mut db = crate::mock_db::MockDb::default();
+    let logs = futures::stream::iter(vec![Err(ProviderError::CustomError(
+        "Connection refused".to_string(),
+    ))]);
+    let result = write_logs(&mut db, &EventRegistry::bridge(), None, logs).await;
+    assert_eq!(result.unwrap_err().kind(), RelayerErrorKind::Rpc);
+
+    let mut log = messages_n(1, 0).remove(0);
+    log.block_number = None;
+    let logs = futures::stream::iter(vec![Ok((1, vec![log]))]);
+    let result = write_logs(&mut db, &EventRegistry::bridge(), None, logs).await;
+    assert_eq!(result.unwrap_err().kind(), RelayerErrorKind::Decode);
+}
@@ -10,6 +10,7 @@ use super::{
     },
 };
 
+use crate::RelayerError;
 use async_trait::async_trait;
 
 #[cfg(test)]
@@ -18,21 +19,25 @@ mod test;
 #[async_trait]
 pub trait RelayerData: EthRemote + EthLocal {
     /// Wait for the Ethereum layer to finish syncing.
-    async fn wait_if_eth_syncing(&self) -> anyhow::Result<()>;
+    async fn wait_if_eth_syncing(&self) -> Result<(), RelayerError>;
 
     /// Download the logs from the DA layer and write them
     /// to the database.
     async fn download_logs(
         &mut self,
         eth_sync_gap: &state::EthSyncGap,
-    ) -> anyhow::Result<()>;
+    ) -> Result<(), RelayerError>;
 
     /// Update the synced state.
     fn update_synced(&self, state: &EthState);
+
+    /// Records the fee sample of the finalized DA block observed
+    /// while building the state.
+    fn record_da_fee(&mut self);
 }
 
 /// A single iteration of the run loop.
-pub async fn run<R>(relayer: &mut R) -> anyhow::Result<()>
+pub async fn run<R>(relayer: &mut R) -> Result<(), RelayerError>
 where
     R: RelayerData,
 {
@@ -51,5 +56,8 @@ where
     // Update the synced state.
     relayer.update_synced(&state);
 
+    // Record the fees of the finalized DA block.
+    relayer.record_da_fee();
+
     Ok(())
 }
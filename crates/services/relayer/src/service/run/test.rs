@@ -7,6 +7,7 @@ async fn can_set_da_height() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
+    relayer.expect_record_da_fee().return_const(());
     relayer.expect_download_logs().returning(|_| Ok(()));
     test_data_source(
         &mut relayer,
@@ -23,6 +24,7 @@ async fn logs_are_downloaded_and_written() {
     let mut relayer = MockRelayerData::default();
     relayer.expect_wait_if_eth_syncing().returning(|| Ok(()));
     relayer.expect_update_synced().return_const(());
+    relayer.expect_record_da_fee().return_const(());
     relayer
         .expect_download_logs()
         .withf(|gap| gap.oldest() == 0 && gap.latest() == 200)
@@ -42,7 +44,7 @@ mockall::mock! {
 
     #[async_trait]
     impl EthRemote for RelayerData {
-        async fn finalized(&self) -> anyhow::Result<u64>;
+        async fn finalized(&self) -> Result<u64, RelayerError>;
     }
 
     impl EthLocal for RelayerData {
@@ -51,14 +53,16 @@ mockall::mock! {
 
     #[async_trait]
     impl RelayerData for RelayerData{
-        async fn wait_if_eth_syncing(&self) -> anyhow::Result<()>;
+        async fn wait_if_eth_syncing(&self) -> Result<(), RelayerError>;
 
         async fn download_logs(
             &mut self,
             eth_sync_gap: &state::EthSyncGap,
-        ) -> anyhow::Result<()>;
+        ) -> Result<(), RelayerError>;
 
         fn update_synced(&self, state: &EthState);
+
+        fn record_da_fee(&mut self);
     }
 }
 
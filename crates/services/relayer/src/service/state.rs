@@ -51,6 +51,11 @@ impl EthState {
         self.local.filter(|local| *local >= self.remote)
     }
 
+    /// The finalized height of the Ethereum node.
+    pub fn remote(&self) -> u64 {
+        self.remote
+    }
+
     /// Get the gap between the relayer and the Ethereum node if
     /// a sync is required.
     pub fn needs_to_sync_eth(&self) -> Option<EthSyncGap> {
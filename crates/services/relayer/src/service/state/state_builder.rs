@@ -1,12 +1,13 @@
 //! Type safe state building
 
 use super::*;
+use crate::RelayerError;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait EthRemote {
     /// The most recently finalized height on the Ethereum node.
-    async fn finalized(&self) -> anyhow::Result<u64>;
+    async fn finalized(&self) -> Result<u64, RelayerError>;
 }
 
 #[async_trait]
@@ -16,7 +17,7 @@ pub trait EthLocal {
 }
 
 /// Build the Ethereum state.
-pub async fn build_eth<T>(t: &T) -> anyhow::Result<EthState>
+pub async fn build_eth<T>(t: &T) -> Result<EthState, RelayerError>
 where
     T: EthRemote + EthLocal + ?Sized,
 {
@@ -40,7 +41,7 @@ pub mod test_builder {
 
     #[async_trait]
     impl EthRemote for TestDataSource {
-        async fn finalized(&self) -> anyhow::Result<u64> {
+        async fn finalized(&self) -> Result<u64, RelayerError> {
             Ok(self.eth_remote_finalized)
         }
     }
@@ -15,7 +15,7 @@ pub async fn wait_if_eth_syncing<P>(
     eth_node: &P,
     sync_call_freq: Duration,
     sync_log_freq: Duration,
-) -> anyhow::Result<()>
+) -> Result<(), RelayerError>
 where
     P: Middleware<Error = ProviderError> + 'static,
 {
@@ -40,17 +40,14 @@ where
     Ok(())
 }
 
-async fn get_status<P>(eth_node: &P) -> anyhow::Result<SyncingStatus>
+async fn get_status<P>(eth_node: &P) -> Result<SyncingStatus, RelayerError>
 where
     P: Middleware<Error = ProviderError> + 'static,
 {
-    match eth_node.syncing().await {
-        Ok(s) => Ok(s),
-        Err(err) => Err(anyhow::anyhow!(
-            "Failed to check if DA layer is syncing {}",
-            err
-        )),
-    }
+    eth_node.syncing().await.map_err(|err| {
+        tracing::warn!("Failed to check if DA layer is syncing {}", err);
+        RelayerError::Rpc(err)
+    })
 }
 
 impl core::fmt::Display for Status {
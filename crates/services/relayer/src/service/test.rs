@@ -32,6 +32,7 @@ async fn can_download_logs() {
     let result = download_logs(
         &eth_state.needs_to_sync_eth().unwrap(),
         contracts,
+        vec![*crate::config::ETH_LOG_MESSAGE],
         &eth_node,
         DEFAULT_LOG_PAGE_SIZE,
     )
@@ -53,7 +54,13 @@ async fn deploy_height_does_not_override() {
         ..Default::default()
     };
     let eth_node = MockMiddleware::default();
-    let relayer = NotInitializedTask::new(eth_node, mock_db.clone(), config);
+    let relayer = NotInitializedTask::new(
+        eth_node,
+        mock_db.clone(),
+        config,
+        EventRegistry::bridge(),
+        None,
+    );
     let _ = relayer.into_task(&Default::default(), ()).await;
 
     assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 50);
@@ -70,8 +77,125 @@ async fn deploy_height_does_override() {
         ..Default::default()
     };
     let eth_node = MockMiddleware::default();
-    let relayer = NotInitializedTask::new(eth_node, mock_db.clone(), config);
+    let relayer = NotInitializedTask::new(
+        eth_node,
+        mock_db.clone(),
+        config,
+        EventRegistry::bridge(),
+        None,
+    );
     let _ = relayer.into_task(&Default::default(), ()).await;
 
     assert_eq!(*mock_db.get_finalized_da_height().unwrap(), 52);
 }
+
+#[tokio::test]
+async fn poll_intervals_are_updated_in_running_task() {
+    let mock_db = crate::mock_db::MockDb::default();
+    let config = Config::default();
+    let eth_node = MockMiddleware::default();
+    let relayer = NotInitializedTask::new(
+        eth_node,
+        mock_db,
+        config.clone(),
+        EventRegistry::bridge(),
+        None,
+    );
+    let shared = relayer.shared_data();
+    let task = relayer.into_task(&Default::default(), ()).await.unwrap();
+    assert_eq!(*task.poll_intervals.borrow(), config.poll_intervals());
+
+    let intervals = PollIntervals {
+        sync_minimum_duration: Duration::from_millis(100),
+        ..shared.poll_intervals()
+    };
+    shared.set_poll_intervals(intervals);
+
+    assert_eq!(*task.poll_intervals.borrow(), intervals);
+}
+
+#[tokio::test]
+async fn paused_task_waits_until_resumed() {
+    let mock_db = crate::mock_db::MockDb::default();
+    let config = Config::default();
+    let eth_node = MockMiddleware::default();
+    let relayer =
+        NotInitializedTask::new(eth_node, mock_db, config, EventRegistry::bridge(), None);
+    let shared = relayer.shared_data();
+    let (_sender, receiver) = watch::channel(fuel_core_services::State::Started);
+    let mut watcher = StateWatcher::from(receiver);
+    let mut task = relayer.into_task(&watcher, ()).await.unwrap();
+    shared.set_poll_intervals(PollIntervals {
+        sync_minimum_duration: Duration::ZERO,
+        ..shared.poll_intervals()
+    });
+    shared.set_paused(true);
+    assert!(shared.is_paused());
+
+    let run = task.run(&mut watcher);
+    tokio::pin!(run);
+    let result = tokio::time::timeout(Duration::from_millis(100), &mut run).await;
+    assert!(result.is_err(), "The paused relayer should not sync");
+
+    shared.set_paused(false);
+    let result = tokio::time::timeout(Duration::from_secs(1), run).await;
+    assert!(matches!(result, Ok(Ok(true))));
+}
+
+#[tokio::test]
+async fn da_lag_is_the_gap_to_the_observed_finalized_height() {
+    let mut mock_db = crate::mock_db::MockDb::default();
+    mock_db
+        .set_finalized_da_height_to_at_least(&50u64.into())
+        .unwrap();
+    let config = Config {
+        da_deploy_height: 20u64.into(),
+        ..Default::default()
+    };
+    let eth_node = MockMiddleware::default();
+    let relayer =
+        NotInitializedTask::new(eth_node, mock_db, config, EventRegistry::bridge(), None);
+    let shared = relayer.shared_data();
+    let task = relayer.into_task(&Default::default(), ()).await.unwrap();
+    assert_eq!(shared.da_lag().unwrap(), None);
+
+    let eth_state = super::state::test_builder::TestDataSource {
+        eth_remote_finalized: 58,
+        eth_local_finalized: Some(50),
+    };
+    let eth_state = state::build_eth(&eth_state).await.unwrap();
+    task.update_synced(&eth_state);
+
+    assert_eq!(shared.da_lag().unwrap(), Some(8));
+}
+
+#[tokio::test]
+async fn last_error_is_the_kind_of_the_failed_iteration() {
+    let eth_node = MockMiddleware::default();
+    // The finalized block without the number is still pending.
+    eth_node.update_data(|data| data.best_block.number = None);
+    let relayer = NotInitializedTask::new(
+        eth_node.clone(),
+        crate::mock_db::MockDb::default(),
+        Config::default(),
+        EventRegistry::bridge(),
+        None,
+    );
+    let shared = relayer.shared_data();
+    let (_sender, receiver) = watch::channel(fuel_core_services::State::Started);
+    let mut watcher = StateWatcher::from(receiver);
+    let mut task = relayer.into_task(&watcher, ()).await.unwrap();
+    shared.set_poll_intervals(PollIntervals {
+        sync_minimum_duration: Duration::ZERO,
+        ..shared.poll_intervals()
+    });
+
+    let error = task.run(&mut watcher).await.unwrap_err();
+    let error = error.downcast::<RelayerError>().unwrap();
+    assert_eq!(error.kind(), RelayerErrorKind::Transient);
+    assert_eq!(shared.last_error(), Some(RelayerErrorKind::Transient));
+
+    eth_node.update_data(|data| data.best_block.number = Some(20u64.into()));
+    task.run(&mut watcher).await.unwrap();
+    assert_eq!(shared.last_error(), None);
+}
@@ -4,7 +4,7 @@ use std::convert::TryFrom;
 
 use crate::{
     config,
-    log::EthEventLog,
+    log::MessageLog,
 };
 use bytes::{
     Bytes,
@@ -26,6 +26,7 @@ use fuel_core_types::{
     fuel_types::Address,
 };
 
+pub mod eth_node;
 pub mod middleware;
 
 pub trait LogTestHelper {
@@ -38,10 +39,9 @@ pub trait EvtToLog {
 
 impl LogTestHelper for Log {
     fn to_msg(&self) -> Message {
-        match EthEventLog::try_from(self).unwrap() {
-            EthEventLog::Message(m) => Message::from(&m),
-            _ => panic!("This log does not form a message"),
-        }
+        let message =
+            MessageLog::try_from(self).expect("This log does not form a message");
+        Message::from(&message)
     }
 }
 
@@ -51,6 +51,12 @@ impl EvtToLog for crate::abi::bridge::MessageSentFilter {
     }
 }
 
+impl EvtToLog for crate::abi::bridge::MessageCancelledFilter {
+    fn into_log(self) -> Log {
+        event_to_log(self, &crate::abi::bridge::MESSAGECANCELLED_ABI)
+    }
+}
+
 pub fn event_to_log<E>(event: E, abi: &ethers_core::abi::Abi) -> Log
 where
     E: EthEvent,
@@ -79,6 +85,7 @@ where
     Log {
         topics,
         data: ethers_core::abi::encode(&data[..]).into(),
+        transaction_hash: Some(H256::default()),
         ..Default::default()
     }
 }
@@ -125,7 +132,7 @@ fn log_default(address: H160, eth_block: u64, topics: Vec<H256>, data: Bytes) ->
         data: EthersBytes(data),
         block_hash: None,
         block_number: Some(U64([eth_block])),
-        transaction_hash: None,
+        transaction_hash: Some(H256::default()),
         transaction_index: None,
         log_index: None,
         transaction_log_index: None,
@@ -0,0 +1,126 @@
+//! The HTTP server that serves the JSON-RPC requests of the relayer with the
+//! [`MockMiddleware`], so the node can use it as an Ethereum node.
+
+use super::middleware::MockMiddleware;
+use ethers_core::types::SyncingStatus;
+use ethers_providers::Middleware;
+use hyper::{
+    service::{
+        make_service_fn,
+        service_fn,
+    },
+    Body,
+    Request,
+    Response,
+    Server,
+};
+use serde_json::json;
+use std::{
+    convert::Infallible,
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
+    sync::Arc,
+};
+use tokio::sync::oneshot::Sender;
+
+pub struct EthNodeHandle {
+    /// Stops the server.
+    pub shutdown: Sender<()>,
+    /// The address of the server.
+    pub address: SocketAddr,
+}
+
+impl EthNodeHandle {
+    /// The URL of the server to be used in the relayer config.
+    pub fn url(&self) -> url::Url {
+        format!("http://{}", self.address)
+            .as_str()
+            .try_into()
+            .expect("The address of the server is a valid URL")
+    }
+}
+
+pub async fn spawn_eth_node(eth_node: Arc<MockMiddleware>) -> EthNodeHandle {
+    // Construct our SocketAddr to listen on...
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
+
+    // And a MakeService to handle each connection...
+    let make_service = make_service_fn(move |_conn| {
+        let eth_node = eth_node.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn({
+                let eth_node = eth_node.clone();
+                move |req| handle(eth_node.clone(), req)
+            }))
+        }
+    });
+
+    // Then bind and serve...
+    let server = Server::bind(&addr).serve(make_service);
+    let addr = server.local_addr();
+
+    let (shutdown, rx) = tokio::sync::oneshot::channel();
+
+    tokio::spawn(async move {
+        let graceful = server.with_graceful_shutdown(async {
+            rx.await.ok();
+        });
+        // And run forever...
+        if let Err(e) = graceful.await {
+            eprintln!("server error: {e}");
+        }
+    });
+    EthNodeHandle {
+        shutdown,
+        address: addr,
+    }
+}
+
+async fn handle(
+    mock: Arc<MockMiddleware>,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let body = hyper::body::to_bytes(req).await.unwrap();
+
+    let v: serde_json::Value = serde_json::from_slice(body.as_ref()).unwrap();
+    let mut o = match v {
+        serde_json::Value::Object(o) => o,
+        _ => unreachable!(),
+    };
+    let id = o.get("id").unwrap().as_u64().unwrap();
+    let method = o.get("method").unwrap().as_str().unwrap();
+    let r = match method {
+        "eth_getBlockByNumber" => {
+            let r = mock.get_block(id).await.unwrap().unwrap();
+            json!({ "id": id, "jsonrpc": "2.0", "result": r })
+        }
+        "eth_syncing" => {
+            let r = mock.syncing().await.unwrap();
+            match r {
+                SyncingStatus::IsFalse => {
+                    json!({ "id": id, "jsonrpc": "2.0", "result": false })
+                }
+                SyncingStatus::IsSyncing(status) => {
+                    json!({ "id": id, "jsonrpc": "2.0", "result": {
+                        "starting_block": status.starting_block,
+                        "current_block": status.current_block,
+                        "highest_block": status.highest_block,
+                    } })
+                }
+            }
+        }
+        "eth_getLogs" => {
+            let params = o.remove("params").unwrap();
+            let params: Vec<_> = serde_json::from_value(params).unwrap();
+            let r = mock.get_logs(&params[0]).await.unwrap();
+            json!({ "id": id, "jsonrpc": "2.0", "result": r })
+        }
+        _ => unreachable!("Mock handler for method not defined"),
+    };
+
+    let r = serde_json::to_vec(&r).unwrap();
+
+    Ok(Response::new(Body::from(r)))
+}
@@ -3,6 +3,7 @@
 #![deny(missing_docs)]
 #![deny(warnings)]
 
+mod resource_budget;
 mod service;
 mod state;
 
@@ -37,6 +38,14 @@ pub mod stream {
     impl<S> IntoBoxStream for S where S: Stream + Send + Sync + 'static {}
 }
 
+pub use resource_budget::{
+    BudgetExhausted,
+    Resource,
+    ResourceBudget,
+    ResourceLimits,
+    ResourcePermit,
+    ResourceUsage,
+};
 pub use service::{
     EmptyShared,
     RunnableService,
@@ -0,0 +1,286 @@
+//! The global budgets of the resources shared by the subsystems of the node. The subsystems
+//! acquire the resources before using them and reject the work when the budget is
+//! exhausted, so the node sheds the load instead of being killed by the OOM killer.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{
+            AtomicU64,
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+/// The resource with the global budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    /// The memory of the in-memory pools in bytes.
+    Memory,
+    /// The memory of the in-memory caches in bytes. The caches have their own budget,
+    /// so the cached entries never starve the pools.
+    CacheMemory,
+    /// The concurrent dry runs of the transactions.
+    DryRuns,
+    /// The open iterators over the database.
+    Iterators,
+}
+
+impl Resource {
+    const COUNT: usize = 4;
+
+    /// All the resources with the budgets.
+    pub const ALL: [Resource; Self::COUNT] = [
+        Resource::Memory,
+        Resource::CacheMemory,
+        Resource::DryRuns,
+        Resource::Iterators,
+    ];
+
+    fn as_usize(&self) -> usize {
+        match self {
+            Resource::Memory => 0,
+            Resource::CacheMemory => 1,
+            Resource::DryRuns => 2,
+            Resource::Iterators => 3,
+        }
+    }
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Resource::Memory => f.write_str("memory"),
+            Resource::CacheMemory => f.write_str("cache memory"),
+            Resource::DryRuns => f.write_str("dry runs"),
+            Resource::Iterators => f.write_str("iterators"),
+        }
+    }
+}
+
+/// The limits of the resources. `None` doesn't limit the resource.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// The memory of the pools in bytes.
+    pub memory: Option<usize>,
+    /// The memory of the caches in bytes.
+    pub cache_memory: Option<usize>,
+    /// The number of the concurrent dry runs.
+    pub dry_runs: Option<usize>,
+    /// The number of the open iterators over the database.
+    pub iterators: Option<usize>,
+}
+
+/// The usage of the resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// The resource.
+    pub resource: Resource,
+    /// The acquired amount of the resource.
+    pub used: usize,
+    /// The limit of the resource, if any.
+    pub limit: Option<usize>,
+    /// The number of the rejected acquisitions since the start of the node.
+    pub rejected: u64,
+}
+
+/// The error returned when the budget of the resource is exhausted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExhausted {
+    /// The exhausted resource.
+    pub resource: Resource,
+    /// The requested amount of the resource.
+    pub requested: usize,
+    /// The limit of the resource.
+    pub limit: usize,
+}
+
+impl fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The node is overloaded: the budget of {} ({}) is exhausted, try again later",
+            self.resource, self.limit
+        )
+    }
+}
+
+impl std::error::Error for BudgetExhausted {}
+
+#[derive(Debug, Default)]
+struct Budget {
+    limit: Option<usize>,
+    used: AtomicUsize,
+    rejected: AtomicU64,
+}
+
+/// The budgets of the resources shared by the subsystems. The clones share the budgets.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceBudget {
+    budgets: Arc<[Budget; Resource::COUNT]>,
+}
+
+impl ResourceBudget {
+    /// Creates the budgets with the `limits`.
+    pub fn new(limits: ResourceLimits) -> Self {
+        let budget = |limit| Budget {
+            limit,
+            ..Default::default()
+        };
+        Self {
+            budgets: Arc::new([
+                budget(limits.memory),
+                budget(limits.cache_memory),
+                budget(limits.dry_runs),
+                budget(limits.iterators),
+            ]),
+        }
+    }
+
+    fn budget(&self, resource: Resource) -> &Budget {
+        &self.budgets[resource.as_usize()]
+    }
+
+    /// Acquires the `amount` of the `resource` until the permit is dropped.
+    pub fn try_acquire(
+        &self,
+        resource: Resource,
+        amount: usize,
+    ) -> Result<ResourcePermit, BudgetExhausted> {
+        self.try_acquire_replacing(resource, amount, 0)
+    }
+
+    /// Acquires the `amount` of the `resource` in place of the `replaced` amount,
+    /// returned by the permits dropped right after the acquisition. The usage may
+    /// exceed the limit until the replaced permits are dropped.
+    pub fn try_acquire_replacing(
+        &self,
+        resource: Resource,
+        amount: usize,
+        replaced: usize,
+    ) -> Result<ResourcePermit, BudgetExhausted> {
+        let budget = self.budget(resource);
+        let acquire = |used: usize| {
+            let acquired = used.checked_add(amount)?;
+            match budget.limit {
+                Some(limit) if acquired.saturating_sub(replaced.min(used)) > limit => {
+                    None
+                }
+                _ => Some(acquired),
+            }
+        };
+        let acquired =
+            budget
+                .used
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, acquire);
+        match acquired {
+            Ok(_) => Ok(ResourcePermit {
+                budget: self.clone(),
+                resource,
+                amount,
+            }),
+            Err(_) => {
+                budget.rejected.fetch_add(1, Ordering::Relaxed);
+                tracing::debug!("The budget of {resource} is exhausted");
+                Err(BudgetExhausted {
+                    resource,
+                    requested: amount,
+                    limit: budget.limit.unwrap_or(usize::MAX),
+                })
+            }
+        }
+    }
+
+    /// Returns the usage of the `resource`.
+    pub fn usage(&self, resource: Resource) -> ResourceUsage {
+        let budget = self.budget(resource);
+        ResourceUsage {
+            resource,
+            used: budget.used.load(Ordering::Acquire),
+            limit: budget.limit,
+            rejected: budget.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The acquired amount of the resource. It is returned to the budget on drop.
+#[derive(Debug)]
+pub struct ResourcePermit {
+    budget: ResourceBudget,
+    resource: Resource,
+    amount: usize,
+}
+
+impl Drop for ResourcePermit {
+    fn drop(&mut self) {
+        self.budget
+            .budget(self.resource)
+            .used
+            .fetch_sub(self.amount, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permits_are_returned_to_the_budget_on_drop() {
+        let budget = ResourceBudget::new(ResourceLimits {
+            dry_runs: Some(2),
+            ..Default::default()
+        });
+
+        let first = budget.try_acquire(Resource::DryRuns, 1).unwrap();
+        let _second = budget.try_acquire(Resource::DryRuns, 1).unwrap();
+        let exhausted = budget.try_acquire(Resource::DryRuns, 1).unwrap_err();
+        assert_eq!(exhausted.limit, 2);
+
+        drop(first);
+        let _third = budget.try_acquire(Resource::DryRuns, 1).unwrap();
+        let usage = budget.usage(Resource::DryRuns);
+        assert_eq!(usage.used, 2);
+        assert_eq!(usage.rejected, 1);
+    }
+
+    #[test]
+    fn resources_have_separate_budgets() {
+        let budget = ResourceBudget::new(ResourceLimits {
+            memory: Some(100),
+            cache_memory: Some(10),
+            ..Default::default()
+        });
+
+        assert!(budget.try_acquire(Resource::Memory, 101).is_err());
+        let _memory = budget.try_acquire(Resource::Memory, 100).unwrap();
+        let _cache = budget.try_acquire(Resource::CacheMemory, 10).unwrap();
+        let _iterators = budget.try_acquire(Resource::Iterators, 1000).unwrap();
+
+        assert_eq!(budget.usage(Resource::Iterators).used, 1000);
+        assert_eq!(budget.usage(Resource::CacheMemory).used, 10);
+        assert_eq!(budget.usage(Resource::DryRuns).used, 0);
+    }
+
+    #[test]
+    fn replaced_amount_is_not_counted_against_the_limit() {
+        let budget = ResourceBudget::new(ResourceLimits {
+            memory: Some(100),
+            ..Default::default()
+        });
+
+        let replaced = budget.try_acquire(Resource::Memory, 80).unwrap();
+        assert!(budget.try_acquire(Resource::Memory, 50).is_err());
+        assert!(budget
+            .try_acquire_replacing(Resource::Memory, 50, 20)
+            .is_err());
+        let replacing = budget
+            .try_acquire_replacing(Resource::Memory, 50, 30)
+            .unwrap();
+
+        drop(replaced);
+        drop(replacing);
+        assert_eq!(budget.usage(Resource::Memory).used, 0);
+    }
+}
@@ -340,9 +340,14 @@ async fn run<S>(
     });
 
     let mut got_panic = None;
+    let mut iteration: u64 = 0;
 
     while state.borrow_and_update().started() {
-        let tracked_task = FutureTracker::new(task.run(&mut state));
+        iteration = iteration.wrapping_add(1);
+        let run = task
+            .run(&mut state)
+            .instrument(tracing::info_span!("run", iteration));
+        let tracked_task = FutureTracker::new(run);
         let task = std::panic::AssertUnwindSafe(tracked_task);
         let panic_result = task.catch_unwind().await;
 
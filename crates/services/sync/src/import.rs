@@ -38,6 +38,7 @@ use tracing::Instrument;
 
 use crate::{
     ports::{
+        BatchImportResult,
         BlockImporterPort,
         ConsensusPort,
         PeerReportReason,
@@ -249,22 +250,8 @@ where
                         results,
                     } = batch;
 
-                    let mut done = vec![];
-                    for sealed_block in results {
-                        let res = execute_and_commit(executor.as_ref(), state, sealed_block).await;
-
-                        match &res {
-                            Ok(_) => {
-                                done.push(());
-                            },
-                            Err(e) => {
-                                // If this fails, then it means that consensus has approved a block that is invalid.
-                                // This would suggest a more serious issue than a bad peer, e.g. a fork or an out-of-date client.
-                                tracing::error!("Failed to execute and commit block from peer {:?}: {:?}", peer, e);
-                                break;
-                            },
-                        };
-                    }
+                    let committed = execute_and_commit_batch(executor.as_ref(), state, &peer, results).await;
+                    let done = vec![(); committed];
 
                     let batch = Batch::new(peer.clone(), range, done);
 
@@ -274,7 +261,7 @@ where
 
                     batch
                 }
-                .instrument(tracing::debug_span!("execute_and_commit"))
+                .instrument(tracing::debug_span!("execute_and_commit_batch"))
                 .in_current_span()
             })
             // Continue the stream unless an error occurs.
@@ -544,33 +531,47 @@ where
     Batch::new(peer, range, blocks)
 }
 
-#[tracing::instrument(
-    skip_all,
-    fields(
-        height = **block.entity.header().height(),
-        id = %block.entity.header().consensus.generated.application_hash
-    ),
-    err
-)]
-async fn execute_and_commit<E>(
+/// Executes and commits the consecutive blocks from the `peer` until the first failure.
+/// Returns the number of the committed blocks.
+#[tracing::instrument(skip_all, fields(blocks = blocks.len()))]
+async fn execute_and_commit_batch<E>(
     executor: &E,
     state: &SharedMutex<State>,
-    block: SealedBlock,
-) -> anyhow::Result<()>
+    peer: &PeerId,
+    blocks: Vec<SealedBlock>,
+) -> usize
 where
     E: BlockImporterPort + Send + Sync + 'static,
 {
-    // Execute and commit the block.
-    let height = *block.entity.header().height();
-    let r = executor.execute_and_commit(block).await;
-
-    // If the block executed successfully, mark it as committed.
-    if r.is_ok() {
-        state.apply(|s| s.commit(*height));
-    } else {
-        tracing::error!("Execution of height {} failed: {:?}", *height, r);
+    if blocks.is_empty() {
+        return 0
+    }
+
+    // Execute and commit the blocks.
+    let heights: Vec<BlockHeight> = blocks
+        .iter()
+        .map(|block| *block.entity.header().height())
+        .collect();
+    let BatchImportResult { committed, error } =
+        executor.execute_and_commit_batch(blocks).await;
+
+    // Mark the successfully executed blocks as committed.
+    for height in heights.iter().take(committed) {
+        state.apply(|s| s.commit(**height));
+    }
+
+    if let Some(e) = error {
+        // If this fails, then it means that consensus has approved a block that is invalid.
+        // This would suggest a more serious issue than a bad peer, e.g. a fork or an out-of-date client.
+        let height = heights.get(committed).map(|height| **height);
+        tracing::error!(
+            "Failed to execute and commit block {:?} from peer {:?}: {:?}",
+            height,
+            peer,
+            e
+        );
     }
-    r
+    committed
 }
 
 /// Extra stream utilities.
@@ -1,6 +1,7 @@
 use crate::{
     import::test_helpers::SharedCounts,
     ports::{
+        BatchImportResult,
         BlockImporterPort,
         MockBlockImporterPort,
     },
@@ -20,21 +21,30 @@ impl BlockImporterPort for PressureBlockImporter {
         self.0.committed_height_stream()
     }
 
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
-        self.2.apply(|c| c.inc_executes());
-        tokio::time::sleep(self.1).await;
-        self.2.apply(|c| {
-            c.dec_executes();
-            c.dec_blocks();
-        });
-        self.0.execute_and_commit(block).await
+    async fn execute_and_commit_batch(
+        &self,
+        blocks: Vec<SealedBlock>,
+    ) -> BatchImportResult {
+        for _ in &blocks {
+            self.2.apply(|c| c.inc_executes());
+            tokio::time::sleep(self.1).await;
+            self.2.apply(|c| {
+                c.dec_executes();
+                c.dec_blocks();
+            });
+        }
+        self.0.execute_and_commit_batch(blocks).await
     }
 }
 
 impl PressureBlockImporter {
     pub fn new(counts: SharedCounts, delays: Duration) -> Self {
         let mut mock = MockBlockImporterPort::default();
-        mock.expect_execute_and_commit().returning(move |_| Ok(()));
+        mock.expect_execute_and_commit_batch()
+            .returning(move |blocks| BatchImportResult {
+                committed: blocks.len(),
+                error: None,
+            });
         Self(mock, delays, counts)
     }
 }
@@ -6,6 +6,7 @@ use crate::{
         random_peer,
     },
     ports::{
+        BatchImportResult,
         MockBlockImporterPort,
         MockConsensusPort,
         MockPeerToPeerPort,
@@ -753,13 +754,16 @@ async fn import__execution_error_on_header_4() {
 
     let mut executor = MockBlockImporterPort::default();
     executor
-        .expect_execute_and_commit()
+        .expect_execute_and_commit_batch()
         .times(1)
-        .returning(|h| {
-            if **h.entity.header().height() == 4 {
-                Err(anyhow::anyhow!("Some execution error"))
-            } else {
-                Ok(())
+        .returning(|blocks| {
+            let committed = blocks
+                .iter()
+                .take_while(|block| **block.entity.header().height() != 4)
+                .count();
+            BatchImportResult {
+                committed,
+                error: Some(anyhow::anyhow!("Some execution error")),
             }
         });
 
@@ -813,13 +817,16 @@ async fn import__execution_error_on_header_5() {
 
     let mut executor = MockBlockImporterPort::default();
     executor
-        .expect_execute_and_commit()
-        .times(2)
-        .returning(|h| {
-            if **h.entity.header().height() == 5 {
-                Err(anyhow::anyhow!("Some execution error"))
-            } else {
-                Ok(())
+        .expect_execute_and_commit_batch()
+        .times(1)
+        .returning(|blocks| {
+            let committed = blocks
+                .iter()
+                .take_while(|block| **block.entity.header().height() != 5)
+                .count();
+            BatchImportResult {
+                committed,
+                error: Some(anyhow::anyhow!("Some execution error")),
             }
         });
 
@@ -1172,7 +1179,12 @@ impl PeerReportTestBuilder {
     fn executor(&self) -> Arc<MockBlockImporterPort> {
         let mut executor = MockBlockImporterPort::default();
 
-        executor.expect_execute_and_commit().returning(|_| Ok(()));
+        executor
+            .expect_execute_and_commit_batch()
+            .returning(|blocks| BatchImportResult {
+                committed: blocks.len(),
+                error: None,
+            });
 
         Arc::new(executor)
     }
@@ -1286,10 +1298,16 @@ impl DefaultMocks for MockBlockImporterPort {
         let mut executor = MockBlockImporterPort::default();
         let t = t.into_iter().next().unwrap();
 
+        // The blocks are committed in batches, so the mock counts the batches
+        // with at least one block, but not more than `t`.
+        let batches = if t == 0 { 0..=0 } else { 1..=t };
         executor
-            .expect_execute_and_commit()
-            .times(t)
-            .returning(move |_| Ok(()));
+            .expect_execute_and_commit_batch()
+            .times(batches)
+            .returning(move |blocks| BatchImportResult {
+                committed: blocks.len(),
+                error: None,
+            });
         executor
     }
 }
@@ -7,6 +7,8 @@
 pub mod import;
 pub mod ports;
 pub mod service;
+#[cfg(any(test, feature = "benchmarking"))]
+pub mod simulation;
 pub mod state;
 pub mod sync;
 mod tracing_helpers;
@@ -75,7 +75,20 @@ pub trait BlockImporterPort {
     /// Stream of newly committed block heights.
     fn committed_height_stream(&self) -> BoxStream<BlockHeight>;
 
-    /// Execute the given sealed block
-    /// and commit it to the database.
-    async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+    /// Execute the given consecutive sealed blocks
+    /// and commit them to the database.
+    /// The importer may commit several blocks with one database write.
+    async fn execute_and_commit_batch(
+        &self,
+        blocks: Vec<SealedBlock>,
+    ) -> BatchImportResult;
+}
+
+/// The result of the import of the consecutive blocks.
+#[derive(Debug)]
+pub struct BatchImportResult {
+    /// The number of the committed blocks from the beginning of the batch.
+    pub committed: usize,
+    /// The error that interrupted the import, if any.
+    pub error: Option<anyhow::Error>,
 }
@@ -1,5 +1,9 @@
 //! Service utilities for running fuel sync.
-use std::sync::Arc;
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
     import::{
@@ -30,7 +34,10 @@ use fuel_core_services::{
 };
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::StreamExt;
-use tokio::sync::Notify;
+use tokio::{
+    sync::Notify,
+    time::Instant,
+};
 
 #[cfg(test)]
 mod tests;
@@ -62,6 +69,74 @@ where
     )?))
 }
 
+/// The number of the latest commits used to estimate the import rate.
+const IMPORT_RATE_WINDOW: usize = 100;
+
+/// The state of the sync service shared with other services.
+#[derive(Clone)]
+pub struct SharedState {
+    state: SharedMutex<State>,
+    /// The committed heights with the time of the commit, the oldest first.
+    commits: SharedMutex<VecDeque<(u32, Instant)>>,
+}
+
+impl SharedState {
+    fn new(state: SharedMutex<State>) -> Self {
+        Self {
+            state,
+            commits: SharedMutex::new(VecDeque::with_capacity(IMPORT_RATE_WINDOW)),
+        }
+    }
+
+    /// Returns the highest height observed from the network or committed by the node.
+    pub fn highest_height(&self) -> Option<BlockHeight> {
+        self.state
+            .apply(|state| state.highest_height())
+            .map(Into::into)
+    }
+
+    /// Estimates the time to import the blocks up to the highest observed height
+    /// from the rate of the latest commits. Returns `None` if the node is behind,
+    /// but has not committed enough blocks to estimate the rate yet.
+    pub fn time_to_tip(&self) -> Option<Duration> {
+        let (committed, highest) = self
+            .state
+            .apply(|state| (state.committed_height(), state.highest_height()));
+        let remaining = highest?.saturating_sub(committed.unwrap_or_default());
+        if remaining == 0 {
+            return Some(Duration::ZERO)
+        }
+        self.commits.apply(|commits| {
+            let (first_height, first_time) = commits.front()?;
+            let (last_height, last_time) = commits.back()?;
+            let blocks = last_height.saturating_sub(*first_height);
+            let elapsed = last_time.saturating_duration_since(*first_time);
+            if blocks == 0 || elapsed.is_zero() {
+                return None
+            }
+            Some(elapsed.mul_f64(remaining as f64 / blocks as f64))
+        })
+    }
+
+    /// Records the time of the commit if the committed height has changed.
+    fn record_commit(&self) {
+        let Some(committed) = self.state.apply(|state| state.committed_height()) else {
+            return
+        };
+        self.commits.apply(|commits| {
+            if commits
+                .back()
+                .map_or(true, |(height, _)| *height < committed)
+            {
+                if commits.len() == IMPORT_RATE_WINDOW {
+                    commits.pop_front();
+                }
+                commits.push_back((committed, Instant::now()));
+            }
+        });
+    }
+}
+
 /// Task for syncing heights.
 /// Contains import task as a child task.
 pub struct SyncTask<P, E, C>
@@ -72,6 +147,7 @@ where
 {
     sync_heights: SyncHeights,
     import_task_handle: ServiceRunner<ImportTask<P, E, C>>,
+    shared: SharedState,
 }
 
 struct ImportTask<P, E, C>(Import<P, E, C>);
@@ -102,11 +178,13 @@ where
             state.clone(),
             notify.clone(),
         );
+        let shared = SharedState::new(state.clone());
         let import = Import::new(state, notify, params, p2p, executor, consensus);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
             import_task_handle,
+            shared,
         })
     }
 }
@@ -120,7 +198,9 @@ where
 {
     #[tracing::instrument(level = "debug", skip_all, err, ret)]
     async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
-        Ok(self.sync_heights.sync().await.is_some())
+        let should_continue = self.sync_heights.sync().await.is_some();
+        self.shared.record_commit();
+        Ok(should_continue)
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
@@ -139,12 +219,14 @@ where
 {
     const NAME: &'static str = "SyncTask";
 
-    type SharedData = ();
+    type SharedData = SharedState;
 
     type Task = SyncTask<P, E, C>;
     type TaskParams = ();
 
-    fn shared_data(&self) -> Self::SharedData {}
+    fn shared_data(&self) -> Self::SharedData {
+        self.shared.clone()
+    }
 
     async fn into_task(
         mut self,
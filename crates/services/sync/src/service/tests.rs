@@ -14,6 +14,7 @@ use crate::{
         random_peer,
     },
     ports::{
+        BatchImportResult,
         MockBlockImporterPort,
         MockConsensusPort,
         MockPeerToPeerPort,
@@ -54,10 +55,17 @@ async fn test_new_service() {
         .expect_committed_height_stream()
         .returning(|| futures::stream::pending::<BlockHeight>().into_boxed());
     let (tx, mut rx) = tokio::sync::mpsc::channel(100);
-    importer.expect_execute_and_commit().returning(move |h| {
-        tx.try_send(**h.entity.header().height()).unwrap();
-        Ok(())
-    });
+    importer
+        .expect_execute_and_commit_batch()
+        .returning(move |blocks| {
+            for block in &blocks {
+                tx.try_send(**block.entity.header().height()).unwrap();
+            }
+            BatchImportResult {
+                committed: blocks.len(),
+                error: None,
+            }
+        });
     let mut consensus = MockConsensusPort::default();
     consensus
         .expect_check_sealed_header()
@@ -87,3 +95,23 @@ async fn test_new_service() {
         fuel_core_services::State::Stopped
     );
 }
+
+#[tokio::test(start_paused = true)]
+async fn time_to_tip_is_estimated_from_the_commit_rate() {
+    let shared = SharedState::new(SharedMutex::new(State::new(10, 30)));
+    assert_eq!(shared.highest_height(), Some(30u32.into()));
+    assert_eq!(shared.time_to_tip(), None);
+
+    shared.record_commit();
+    for height in 11..=15 {
+        tokio::time::advance(Duration::from_secs(2)).await;
+        shared.state.apply(|state| state.commit(height));
+        shared.record_commit();
+    }
+
+    // 5 blocks were committed in 10 seconds, and 15 blocks are left.
+    assert_eq!(shared.time_to_tip(), Some(Duration::from_secs(30)));
+
+    shared.state.apply(|state| state.commit(30));
+    assert_eq!(shared.time_to_tip(), Some(Duration::ZERO));
+}
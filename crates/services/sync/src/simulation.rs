@@ -0,0 +1,517 @@
+//! # Network Simulation
+//! Runs the sync services of several nodes in one process. The nodes are connected
+//! by a virtual network with configurable latency, message loss and partitions.
+//! It allows reproducing scenarios like a partitioned producer or racing syncs.
+//!
+//! All random decisions of the network use the seeded generator, so the runs are
+//! deterministic when the tests use the paused tokio clock.
+
+use crate::{
+    import::{
+        test_helpers::empty_header,
+        Config,
+    },
+    ports::{
+        BatchImportResult,
+        BlockImporterPort,
+        ConsensusPort,
+        PeerReportReason,
+        PeerToPeerPort,
+    },
+    service::{
+        new_service,
+        SyncTask,
+    },
+};
+use anyhow::anyhow;
+use fuel_core_services::{
+    stream::{
+        BoxStream,
+        IntoBoxStream,
+    },
+    Service,
+    ServiceRunner,
+    SharedMutex,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        primitives::DaBlockHeight,
+        SealedBlock,
+        SealedBlockHeader,
+    },
+    fuel_types::BlockHeight,
+    services::p2p::{
+        PeerId,
+        SourcePeer,
+        Transactions,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    ops::Range,
+    time::Duration,
+};
+use tokio::sync::broadcast;
+
+#[cfg(test)]
+mod tests;
+
+/// The sync service of the simulated node.
+pub type SimulatedSync =
+    ServiceRunner<SyncTask<SimulatedPeerToPeer, SimulatedImporter, SimulatedConsensus>>;
+
+/// The configuration of the virtual network.
+#[derive(Clone, Copy, Debug)]
+pub struct NetworkConfig {
+    /// The delay of each message between two nodes.
+    pub latency: Duration,
+    /// The probability in the range `[0, 1]` that a message is lost.
+    pub message_loss: f64,
+    /// The seed of the random decisions of the network.
+    pub seed: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            latency: Duration::from_millis(10),
+            message_loss: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+/// The report of the peer made by the sync of the node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    /// The node that made the report.
+    pub reporter: usize,
+    /// The reported node.
+    pub peer: usize,
+    /// The reason of the report.
+    pub reason: PeerReportReason,
+}
+
+struct Node {
+    peer_id: PeerId,
+    /// The blocks of the node starting from the height `1`.
+    blocks: Vec<SealedBlock>,
+    /// The heights gossiped to the node.
+    gossip: broadcast::Sender<BlockHeight>,
+    /// The heights committed by the node.
+    committed: broadcast::Sender<BlockHeight>,
+}
+
+impl Node {
+    fn height(&self) -> u32 {
+        self.blocks.len() as u32
+    }
+
+    fn block(&self, height: u32) -> Option<&SealedBlock> {
+        self.blocks.get(height.checked_sub(1)? as usize)
+    }
+
+    fn commit(&mut self, block: SealedBlock) -> anyhow::Result<BlockHeight> {
+        let height = *block.entity.header().height();
+        let expected = self.height() + 1;
+        if *height != expected {
+            return Err(anyhow!(
+                "Expected the block at the height {expected}, but got {}",
+                *height
+            ))
+        }
+        self.blocks.push(block);
+        let _ = self.committed.send(height);
+        Ok(height)
+    }
+}
+
+struct State {
+    config: NetworkConfig,
+    rng: StdRng,
+    nodes: Vec<Node>,
+    /// The partition of each node. The nodes reach each other only within one partition.
+    partitions: Vec<usize>,
+    reports: Vec<Report>,
+}
+
+impl State {
+    fn reachable(&self, from: usize, to: usize) -> bool {
+        from != to && self.partitions[from] == self.partitions[to]
+    }
+
+    fn is_lost(&mut self) -> bool {
+        let message_loss = self.config.message_loss.clamp(0.0, 1.0);
+        message_loss > 0.0 && self.rng.gen_bool(message_loss)
+    }
+
+    fn node_index(&self, peer_id: &PeerId) -> Option<usize> {
+        self.nodes.iter().position(|node| &node.peer_id == peer_id)
+    }
+
+    /// Selects the random reachable peer that has all blocks of the `range`.
+    /// If there is no such peer, selects the reachable peer with the highest block.
+    fn select_peer(&mut self, from: usize, range: &Range<u32>) -> Option<usize> {
+        let reachable: Vec<_> = (0..self.nodes.len())
+            .filter(|to| self.reachable(from, *to))
+            .collect();
+        let last = range.end.saturating_sub(1);
+        let complete: Vec<_> = reachable
+            .iter()
+            .copied()
+            .filter(|to| self.nodes[*to].height() >= last)
+            .collect();
+        if complete.is_empty() {
+            reachable
+                .into_iter()
+                .filter(|to| self.nodes[*to].height() >= range.start)
+                .max_by_key(|to| self.nodes[*to].height())
+        } else {
+            Some(complete[self.rng.gen_range(0..complete.len())])
+        }
+    }
+}
+
+/// The virtual network connecting the simulated nodes.
+#[derive(Clone)]
+pub struct SimulatedNetwork {
+    state: SharedMutex<State>,
+}
+
+impl SimulatedNetwork {
+    /// Creates the network of `number_of_nodes` nodes. All nodes start with the
+    /// genesis block only and can reach each other.
+    pub fn new(config: NetworkConfig, number_of_nodes: usize) -> Self {
+        let nodes = (0..number_of_nodes)
+            .map(|index| Node {
+                peer_id: PeerId::from((index as u64).to_be_bytes().to_vec()),
+                blocks: vec![],
+                gossip: broadcast::channel(1024).0,
+                committed: broadcast::channel(1024).0,
+            })
+            .collect();
+        let state = State {
+            config,
+            rng: StdRng::seed_from_u64(config.seed),
+            nodes,
+            partitions: vec![0; number_of_nodes],
+            reports: vec![],
+        };
+        Self {
+            state: SharedMutex::new(state),
+        }
+    }
+
+    /// Returns the peer id of the `node`.
+    pub fn peer_id(&self, node: usize) -> PeerId {
+        self.state.apply(|state| state.nodes[node].peer_id.clone())
+    }
+
+    /// Returns the height of the latest block of the `node`.
+    pub fn height(&self, node: usize) -> BlockHeight {
+        self.state.apply(|state| state.nodes[node].height().into())
+    }
+
+    /// Returns the blocks of the `node` starting from the height `1`.
+    pub fn blocks(&self, node: usize) -> Vec<SealedBlock> {
+        self.state.apply(|state| state.nodes[node].blocks.clone())
+    }
+
+    /// Returns all reports of the peers made by the nodes.
+    pub fn reports(&self) -> Vec<Report> {
+        self.state.apply(|state| state.reports.clone())
+    }
+
+    /// Changes the configuration of the network. It affects only the messages sent
+    /// after the change.
+    pub fn update_config(&self, f: impl FnOnce(&mut NetworkConfig)) {
+        self.state.apply(|state| f(&mut state.config))
+    }
+
+    /// Splits the network into the `groups`. The nodes reach each other only within
+    /// one group. Each node missing in the `groups` is isolated from all others.
+    pub fn partition(&self, groups: &[&[usize]]) {
+        self.state.apply(|state| {
+            let isolated = groups.len();
+            for (index, partition) in state.partitions.iter_mut().enumerate() {
+                *partition = isolated + index;
+            }
+            for (group, nodes) in groups.iter().enumerate() {
+                for node in nodes.iter() {
+                    state.partitions[*node] = group;
+                }
+            }
+        })
+    }
+
+    /// Removes all partitions, so all nodes can reach each other again.
+    pub fn heal(&self) {
+        self.state.apply(|state| {
+            state
+                .partitions
+                .iter_mut()
+                .for_each(|partition| *partition = 0)
+        })
+    }
+
+    /// Produces the next block on the `node` and gossips its height.
+    pub fn produce_block(&self, node: usize) -> BlockHeight {
+        let height = self.state.apply(|state| {
+            let node = &mut state.nodes[node];
+            let block = new_block(node.height() + 1);
+            node.commit(block)
+                .expect("The new block is always the next one")
+        });
+        self.gossip(node, height);
+        height
+    }
+
+    /// Starts the sync service of the `node`.
+    pub async fn start_sync(
+        &self,
+        node: usize,
+        config: Config,
+    ) -> anyhow::Result<SimulatedSync> {
+        let p2p = SimulatedPeerToPeer {
+            network: self.clone(),
+            node,
+        };
+        let importer = SimulatedImporter {
+            network: self.clone(),
+            node,
+        };
+        let service =
+            new_service(self.height(node), p2p, importer, SimulatedConsensus, config)?;
+        service.start_and_await().await?;
+        Ok(service)
+    }
+
+    /// Waits until the `node` has the block at the `height`.
+    pub async fn await_height(&self, node: usize, height: BlockHeight) {
+        let mut committed = self
+            .state
+            .apply(|state| state.nodes[node].committed.subscribe());
+        while self.height(node) < height {
+            if let Err(broadcast::error::RecvError::Closed) = committed.recv().await {
+                return
+            }
+        }
+    }
+
+    /// Sends the `height` to all other nodes. Each message is delivered after the
+    /// latency, if it is not lost and the receiver is reachable at that moment.
+    fn gossip(&self, from: usize, height: BlockHeight) {
+        let (latency, number_of_nodes) = self
+            .state
+            .apply(|state| (state.config.latency, state.nodes.len()));
+        for to in (0..number_of_nodes).filter(|to| *to != from) {
+            let network = self.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(latency).await;
+                network.state.apply(|state| {
+                    if state.reachable(from, to) && !state.is_lost() {
+                        let _ = state.nodes[to].gossip.send(height);
+                    }
+                });
+            });
+        }
+    }
+
+    /// Sends the request from the node `from` to the node `to`. The `respond` is
+    /// called on the node `to` after the latency, if the request is not lost and
+    /// the node is still reachable. The response arrives after one more latency.
+    async fn request<T>(
+        &self,
+        from: usize,
+        to: usize,
+        respond: impl FnOnce(&Node) -> T,
+    ) -> anyhow::Result<T> {
+        let latency = self.state.apply(|state| state.config.latency);
+        tokio::time::sleep(latency).await;
+        let response = self.state.apply(|state| {
+            if !state.reachable(from, to) {
+                return Err(anyhow!("The peer is unreachable"))
+            }
+            if state.is_lost() {
+                return Err(anyhow!("The request timed out"))
+            }
+            Ok(respond(&state.nodes[to]))
+        })?;
+        tokio::time::sleep(latency).await;
+        Ok(response)
+    }
+}
+
+fn new_block(height: u32) -> SealedBlock {
+    let header = empty_header(height);
+    SealedBlock {
+        entity: Block::try_from_executed(header.entity, vec![])
+            .expect("The header of the empty block matches no transactions"),
+        consensus: header.consensus,
+    }
+}
+
+fn broadcast_stream(
+    receiver: broadcast::Receiver<BlockHeight>,
+) -> BoxStream<BlockHeight> {
+    futures::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(height) => return Some((height, receiver)),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+    .into_boxed()
+}
+
+/// The [`PeerToPeerPort`] of the simulated node.
+pub struct SimulatedPeerToPeer {
+    network: SimulatedNetwork,
+    node: usize,
+}
+
+#[async_trait::async_trait]
+impl PeerToPeerPort for SimulatedPeerToPeer {
+    fn height_stream(&self) -> BoxStream<BlockHeight> {
+        let receiver = self
+            .network
+            .state
+            .apply(|state| state.nodes[self.node].gossip.subscribe());
+        broadcast_stream(receiver)
+    }
+
+    async fn get_sealed_block_headers(
+        &self,
+        block_height_range: Range<u32>,
+    ) -> anyhow::Result<SourcePeer<Option<Vec<SealedBlockHeader>>>> {
+        let (peer, peer_id) = self
+            .network
+            .state
+            .apply(|state| {
+                let peer = state.select_peer(self.node, &block_height_range)?;
+                Some((peer, state.nodes[peer].peer_id.clone()))
+            })
+            .ok_or_else(|| anyhow!("No peer has the requested headers"))?;
+        let headers = self
+            .network
+            .request(self.node, peer, |node| {
+                block_height_range
+                    .filter_map(|height| node.block(height))
+                    .map(|block| SealedBlockHeader {
+                        entity: block.entity.header().clone(),
+                        consensus: block.consensus.clone(),
+                    })
+                    .collect()
+            })
+            .await?;
+        Ok(peer_id.bind(Some(headers)))
+    }
+
+    async fn get_transactions(
+        &self,
+        block_ids: SourcePeer<Range<u32>>,
+    ) -> anyhow::Result<Option<Vec<Transactions>>> {
+        let SourcePeer {
+            peer_id,
+            data: range,
+        } = block_ids;
+        let peer = self
+            .network
+            .state
+            .apply(|state| state.node_index(&peer_id))
+            .ok_or_else(|| anyhow!("Unknown peer {peer_id:?}"))?;
+        self.network
+            .request(self.node, peer, |node| {
+                range
+                    .map(|height| {
+                        node.block(height).map(|block| {
+                            Transactions(block.entity.transactions().to_vec())
+                        })
+                    })
+                    .collect()
+            })
+            .await
+    }
+
+    fn report_peer(&self, peer: PeerId, reason: PeerReportReason) -> anyhow::Result<()> {
+        self.network.state.apply(|state| {
+            let peer = state
+                .node_index(&peer)
+                .ok_or_else(|| anyhow!("Unknown peer {peer:?}"))?;
+            state.reports.push(Report {
+                reporter: self.node,
+                peer,
+                reason,
+            });
+            Ok(())
+        })
+    }
+}
+
+/// The [`BlockImporterPort`] of the simulated node. It gossips the height of
+/// each imported block, so the nodes also learn about the blocks from each other.
+pub struct SimulatedImporter {
+    network: SimulatedNetwork,
+    node: usize,
+}
+
+#[async_trait::async_trait]
+impl BlockImporterPort for SimulatedImporter {
+    fn committed_height_stream(&self) -> BoxStream<BlockHeight> {
+        let receiver = self
+            .network
+            .state
+            .apply(|state| state.nodes[self.node].committed.subscribe());
+        broadcast_stream(receiver)
+    }
+
+    async fn execute_and_commit_batch(
+        &self,
+        blocks: Vec<SealedBlock>,
+    ) -> BatchImportResult {
+        let mut committed = 0;
+        for block in blocks {
+            let height = self
+                .network
+                .state
+                .apply(|state| state.nodes[self.node].commit(block));
+            match height {
+                Ok(height) => {
+                    self.network.gossip(self.node, height);
+                    committed += 1;
+                }
+                Err(error) => {
+                    return BatchImportResult {
+                        committed,
+                        error: Some(error),
+                    }
+                }
+            }
+        }
+        BatchImportResult {
+            committed,
+            error: None,
+        }
+    }
+}
+
+/// The [`ConsensusPort`] of the simulated node. It accepts all headers.
+pub struct SimulatedConsensus;
+
+#[async_trait::async_trait]
+impl ConsensusPort for SimulatedConsensus {
+    fn check_sealed_header(&self, _: &SealedBlockHeader) -> anyhow::Result<bool> {
+        Ok(true)
+    }
+
+    async fn await_da_height(&self, _: &DaBlockHeight) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
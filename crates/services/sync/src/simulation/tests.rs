@@ -0,0 +1,114 @@
+use super::*;
+
+const PRODUCER: usize = 0;
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+async fn start_network(
+    config: NetworkConfig,
+    number_of_nodes: usize,
+) -> (SimulatedNetwork, Vec<SimulatedSync>) {
+    let network = SimulatedNetwork::new(config, number_of_nodes);
+    let mut syncs = vec![];
+    for node in 0..number_of_nodes {
+        syncs.push(network.start_sync(node, Config::default()).await.unwrap());
+    }
+    (network, syncs)
+}
+
+async fn await_height(network: &SimulatedNetwork, nodes: Range<usize>, height: u32) {
+    for node in nodes {
+        tokio::time::timeout(TIMEOUT, network.await_height(node, height.into()))
+            .await
+            .unwrap_or_else(|_| {
+                panic!(
+                    "The node {node} is at the height {}, but expected {height}",
+                    *network.height(node)
+                )
+            });
+    }
+}
+
+fn assert_same_chain(network: &SimulatedNetwork, nodes: Range<usize>) {
+    let expected: Vec<_> = network
+        .blocks(PRODUCER)
+        .into_iter()
+        .map(|block| block.entity.id())
+        .collect();
+    for node in nodes {
+        let actual: Vec<_> = network
+            .blocks(node)
+            .into_iter()
+            .map(|block| block.entity.id())
+            .collect();
+        assert_eq!(actual, expected, "The node {node} has a different chain");
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn followers_sync_blocks_of_the_producer() {
+    let config = NetworkConfig {
+        latency: Duration::from_millis(50),
+        ..Default::default()
+    };
+    let (network, _syncs) = start_network(config, 4).await;
+
+    for _ in 0..10 {
+        network.produce_block(PRODUCER);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    await_height(&network, 1..4, 10).await;
+    assert_same_chain(&network, 1..4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn followers_partitioned_from_the_producer_catch_up_after_heal() {
+    let (network, _syncs) = start_network(NetworkConfig::default(), 4).await;
+    network.partition(&[&[PRODUCER, 1], &[2, 3]]);
+
+    for _ in 0..5 {
+        network.produce_block(PRODUCER);
+    }
+    await_height(&network, 1..2, 5).await;
+    tokio::time::sleep(Duration::from_secs(10)).await;
+    assert_eq!(*network.height(2), 0);
+    assert_eq!(*network.height(3), 0);
+
+    network.heal();
+    network.produce_block(PRODUCER);
+
+    await_height(&network, 1..4, 6).await;
+    assert_same_chain(&network, 1..4);
+}
+
+#[tokio::test(start_paused = true)]
+async fn racing_syncs_converge_despite_message_loss() {
+    let config = NetworkConfig {
+        latency: Duration::from_millis(20),
+        message_loss: 0.2,
+        seed: 0xF00D,
+    };
+    let (network, _syncs) = start_network(config, 6).await;
+
+    // The blocks are produced faster than the latency, so the followers race
+    // to sync the same ranges from the producer and from each other.
+    for _ in 0..20 {
+        network.produce_block(PRODUCER);
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    // The lost gossip is recovered by the gossip of the next blocks.
+    let mut converged = false;
+    for _ in 0..100 {
+        let height = network.height(PRODUCER);
+        converged = (1..6).all(|node| network.height(node) == height);
+        if converged {
+            break
+        }
+        network.produce_block(PRODUCER);
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    assert!(converged, "The followers didn't converge with the producer");
+    assert_same_chain(&network, 1..6);
+}
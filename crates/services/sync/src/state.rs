@@ -200,6 +200,25 @@ impl State {
         }
     }
 
+    /// Get the highest known height, the observed height while processing
+    /// or the committed height otherwise.
+    pub fn highest_height(&self) -> Option<u32> {
+        match &self.status {
+            Status::Uninitialized => None,
+            Status::Processing(range) => Some(*range.end()),
+            Status::Committed(height) => Some(*height),
+        }
+    }
+
+    /// Get the committed height.
+    pub fn committed_height(&self) -> Option<u32> {
+        match &self.status {
+            Status::Uninitialized => None,
+            Status::Processing(range) => range.start().checked_sub(1),
+            Status::Committed(height) => Some(*height),
+        }
+    }
+
     #[cfg(test)]
     /// Get the current observed height.
     pub fn proposed_height(&self) -> Option<&u32> {
@@ -113,3 +113,12 @@ fn test_failed(mut state: State, range: RangeInclusive<u32>) -> Status {
     state.failed_to_process(range);
     state.status
 }
+
+#[test_case(State::new(None, None) => (None, None))]
+#[test_case(State::new(10, None) => (Some(10), Some(10)))]
+#[test_case(State::new(None, 10) => (None, Some(10)))]
+#[test_case(State::new(1, 10) => (Some(1), Some(10)))]
+#[test_case(State::new(11, 10) => (Some(11), Some(11)))]
+fn test_committed_and_highest_heights(state: State) -> (Option<u32>, Option<u32>) {
+    (state.committed_height(), state.highest_height())
+}
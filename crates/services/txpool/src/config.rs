@@ -1,5 +1,20 @@
+use crate::{
+    ports::{
+        AdmissionPolicy,
+        GasPriceProvider,
+    },
+    sponsorship::SponsorshipConfig,
+    transaction_selector::{
+        SelectionStrategy,
+        Strategy,
+    },
+};
 use fuel_core_chain_config::ChainConfig;
-use std::time::Duration;
+use fuel_core_services::ResourceBudget;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -9,6 +24,9 @@ pub struct Config {
     pub max_depth: usize,
     /// The minimum allowed gas price
     pub min_gas_price: u64,
+    /// The source of the gas price of the next block. The transactions paying less than
+    /// it, or less than the `min_gas_price`, are not admitted into the pool.
+    pub gas_price_provider: Option<Arc<dyn GasPriceProvider>>,
     /// Flag to disable utxo existence and signature checks
     pub utxo_validation: bool,
     /// chain config
@@ -19,6 +37,21 @@ pub struct Config {
     pub transaction_ttl: Duration,
     /// The number of allowed active transaction status subscriptions.
     pub number_of_active_subscription: usize,
+    /// The extra policy of the operator for the admission of the transactions, if any.
+    pub admission_policy: Option<Arc<dyn AdmissionPolicy>>,
+    /// The registered sponsors that pay the fees of the transactions of other users.
+    pub sponsorship: SponsorshipConfig,
+    /// The strategy of the selection of the transactions into the next block.
+    pub selection_strategy: Arc<dyn SelectionStrategy>,
+    /// The budget of the resources shared with other subsystems of the node.
+    /// The pooled transactions take the memory from it.
+    pub resource_budget: ResourceBudget,
+    /// How often the pooled transactions are re-validated against the minimal gas
+    /// price and the latest state, expiring the ones invalidated by the recent blocks.
+    /// `None` disables the re-validation.
+    pub revalidation_interval: Option<Duration>,
+    /// Rejects all submitted transactions, like on the read replica.
+    pub read_only: bool,
 }
 
 impl Default for Config {
@@ -62,11 +95,51 @@ impl Config {
             max_tx,
             max_depth,
             min_gas_price,
+            gas_price_provider: None,
             utxo_validation,
             chain_config,
             metrics,
             transaction_ttl,
             number_of_active_subscription,
+            admission_policy: None,
+            sponsorship: Default::default(),
+            selection_strategy: Arc::new(Strategy::default()),
+            resource_budget: Default::default(),
+            revalidation_interval: Some(Duration::from_secs(10)),
+            read_only: false,
+        }
+    }
+}
+
+impl Config {
+    /// Returns the lowest gas price of the transactions admitted into the pool.
+    pub fn required_gas_price(&self) -> u64 {
+        let gas_price = self
+            .gas_price_provider
+            .as_ref()
+            .map(|provider| provider.gas_price())
+            .unwrap_or_default();
+        gas_price.max(self.min_gas_price)
+    }
+}
+
+/// The limits of the pool that can be changed while the pool is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum number of transactions inside the pool
+    pub max_tx: usize,
+    /// max depth of connected UTXO excluding contracts
+    pub max_depth: usize,
+    /// Transaction TTL
+    pub transaction_ttl: Duration,
+}
+
+impl Config {
+    pub fn limits(&self) -> Limits {
+        Limits {
+            max_tx: self.max_tx,
+            max_depth: self.max_depth,
+            transaction_ttl: self.transaction_ttl,
         }
     }
 }
@@ -106,6 +106,10 @@ impl Dependency {
         }
     }
 
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.max_depth = max_depth;
+    }
+
     /// find all dependent Transactions that are inside txpool.
     /// Does not check db. They can be sorted by gasPrice to get order of dependency
     pub(crate) fn find_dependent(
@@ -447,6 +451,13 @@ impl Dependency {
                                     Error::NotInsertedInputMessageSpent(*nonce).into()
                                 )
                             }
+                            // the cancelled message can't be spent by the next blocks
+                            if db.is_message_cancelled(nonce)? {
+                                return Err(Error::NotInsertedInputMessageCancelled(
+                                    *nonce,
+                                )
+                                .into())
+                            }
                         } else {
                             return Err(
                                 Error::NotInsertedInputMessageUnknown(*nonce).into()
@@ -546,6 +557,103 @@ impl Dependency {
         Ok((max_depth, db_coins, db_contracts, db_messages, collided))
     }
 
+    /// Returns the pooled transactions spending the messages cancelled by the DA layer.
+    pub(crate) fn cancelled_message_spenders(
+        &self,
+        db: &dyn TxPoolDb,
+    ) -> anyhow::Result<Vec<(Nonce, TxId)>> {
+        let mut spenders = vec![];
+        for (nonce, state) in self.messages.iter() {
+            if db.is_message_cancelled(nonce)? {
+                spenders.push((*nonce, state.spent_by));
+            }
+        }
+        Ok(spenders)
+    }
+
+    /// Returns the inputs of the pooled `tx` taken from the database. The inputs
+    /// produced by other pooled transactions are skipped.
+    pub(crate) fn database_inputs(&self, tx: &ArcPoolTx) -> Vec<Input> {
+        tx.inputs()
+            .iter()
+            .filter(|input| match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => self
+                    .coins
+                    .get(utxo_id)
+                    .map_or(true, CoinState::is_in_database),
+                Input::Contract(Contract { contract_id, .. }) => self
+                    .contracts
+                    .get(contract_id)
+                    .map_or(true, ContractState::is_in_database),
+                _ => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Checks that the `inputs` taken from the database are still there.
+    pub(crate) fn check_database_inputs(
+        db: &dyn TxPoolDb,
+        utxo_validation: bool,
+        inputs: &[Input],
+    ) -> anyhow::Result<()> {
+        for input in inputs {
+            match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    if utxo_validation {
+                        let coin = db
+                            .utxo(utxo_id)?
+                            .ok_or(Error::NotInsertedInputUtxoIdNotExisting(*utxo_id))?;
+                        Self::check_if_coin_input_can_spend_db_coin(&coin, input)?;
+                    }
+                }
+                Input::MessageCoinSigned(MessageCoinSigned { nonce, .. })
+                | Input::MessageCoinPredicate(MessageCoinPredicate { nonce, .. })
+                | Input::MessageDataSigned(MessageDataSigned { nonce, .. })
+                | Input::MessageDataPredicate(MessageDataPredicate { nonce, .. }) => {
+                    if utxo_validation {
+                        if db.message(nonce)?.is_none() {
+                            return Err(
+                                Error::NotInsertedInputMessageUnknown(*nonce).into()
+                            )
+                        }
+                        if db.is_message_spent(nonce)? {
+                            return Err(Error::NotInsertedInputMessageSpent(*nonce).into())
+                        }
+                        if db.is_message_cancelled(nonce)? {
+                            return Err(
+                                Error::NotInsertedInputMessageCancelled(*nonce).into()
+                            )
+                        }
+                    }
+                }
+                Input::Contract(Contract { contract_id, .. }) => {
+                    if !db.contract_exist(contract_id)? {
+                        return Err(Error::NotInsertedInputContractNotExisting(
+                            *contract_id,
+                        )
+                        .into())
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the ids of the transactions colliding with the `tx`. The insertion of
+    /// the `tx` removes them with their dependents from the txpool.
+    pub(crate) fn collided(
+        &self,
+        txs: &HashMap<TxId, TxInfo>,
+        db: &dyn TxPoolDb,
+        tx: &ArcPoolTx,
+    ) -> anyhow::Result<Vec<TxId>> {
+        let (_, _, _, _, collided) = self.check_for_collision(txs, db, tx)?;
+        Ok(collided)
+    }
+
     /// insert tx inside dependency
     /// return list of transactions that are removed from txpool
     pub(crate) fn insert<'a, DB>(
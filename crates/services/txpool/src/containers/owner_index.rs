@@ -0,0 +1,53 @@
+use crate::types::*;
+use fuel_core_types::fuel_tx::{
+    Address,
+    Output,
+};
+use std::collections::{
+    hash_map::Entry,
+    HashMap,
+    HashSet,
+};
+
+/// The transactions of the pool indexed by the owners of their inputs and
+/// the recipients of their coin outputs.
+#[derive(Default, Debug)]
+pub struct OwnerIndex {
+    txs: HashMap<Address, HashSet<TxId>>,
+}
+
+impl OwnerIndex {
+    pub fn insert(&mut self, tx: &PoolTransaction) {
+        for owner in owners(tx) {
+            self.txs.entry(owner).or_default().insert(tx.id());
+        }
+    }
+
+    pub fn remove(&mut self, tx: &PoolTransaction) {
+        for owner in owners(tx) {
+            if let Entry::Occupied(mut entry) = self.txs.entry(owner) {
+                entry.get_mut().remove(&tx.id());
+                if entry.get().is_empty() {
+                    entry.remove();
+                }
+            }
+        }
+    }
+
+    /// Returns the transactions spending or receiving the coins of the `owner`.
+    pub fn txs(&self, owner: &Address) -> impl Iterator<Item = &TxId> {
+        self.txs.get(owner).into_iter().flatten()
+    }
+}
+
+fn owners(tx: &PoolTransaction) -> HashSet<Address> {
+    let inputs = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| input.input_owner().copied());
+    let outputs = tx.outputs().iter().filter_map(|output| match output {
+        Output::Coin { to, .. } => Some(*to),
+        _ => None,
+    });
+    inputs.chain(outputs).collect()
+}
@@ -1,6 +1,7 @@
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
 
+use fuel_core_services::ResourcePermit;
 use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
@@ -10,6 +11,7 @@ use fuel_core_types::{
 };
 use std::{
     ops::Deref,
+    sync::Arc,
     time::Duration,
 };
 
@@ -17,16 +19,22 @@ pub mod config;
 mod containers;
 pub mod ports;
 pub mod service;
-mod transaction_selector;
+pub mod sponsorship;
+pub mod transaction_selector;
 pub mod txpool;
 pub mod types;
+#[cfg(feature = "wasm-policy")]
+pub mod wasm_policy;
 
 #[cfg(any(test, feature = "test-helpers"))]
 pub mod mock_db;
 #[cfg(any(test, feature = "test-helpers"))]
 pub use mock_db::MockDb;
 
-pub use config::Config;
+pub use config::{
+    Config,
+    Limits,
+};
 pub use fuel_core_types::services::txpool::Error;
 pub use service::{
     new_service,
@@ -46,6 +54,18 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    /// The span lives while the transaction is inside of the pool. The block production
+    /// links to it to trace the transaction from the submission to the inclusion.
+    span: tracing::Span,
+    /// The private transaction isn't gossiped or shared with the peers,
+    /// only the local block producer includes it.
+    private: bool,
+    /// The memory of the transaction acquired from the resource budget of the node.
+    /// It is returned to the budget when the transaction leaves the pool.
+    budget_permit: Option<Arc<ResourcePermit>>,
+    /// The time by which the transaction should be included, like the forced one.
+    /// The `DeadlineFirst` selection strategy includes such transactions first.
+    deadline: Option<tokio::time::Instant>,
 }
 
 #[allow(missing_docs)]
@@ -55,13 +75,25 @@ impl TxInfo {
             .duration_since(std::time::UNIX_EPOCH)
             .expect("Now is bellow of the `UNIX_EPOCH`");
 
+        let span = tracing::info_span!("pending_transaction", tx_id = %tx.id());
+
         Self {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            span,
+            private: false,
+            budget_permit: None,
+            deadline: None,
         }
     }
 
+    /// Sets the time by which the transaction should be included.
+    pub fn with_deadline(mut self, deadline: tokio::time::Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
     pub fn tx(&self) -> &ArcPoolTx {
         &self.tx
     }
@@ -73,6 +105,18 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn span(&self) -> &tracing::Span {
+        &self.span
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private
+    }
+
+    pub fn deadline(&self) -> Option<tokio::time::Instant> {
+        self.deadline
+    }
 }
 
 impl Deref for TxInfo {
@@ -35,6 +35,7 @@ pub struct Data {
     pub contracts: HashMap<ContractId, Contract>,
     pub messages: HashMap<Nonce, Message>,
     pub spent_messages: HashSet<Nonce>,
+    pub cancelled_messages: HashSet<Nonce>,
 }
 
 #[derive(Clone, Default)]
@@ -62,6 +63,10 @@ impl MockDb {
     pub fn spend_message(&self, id: Nonce) {
         self.data.lock().unwrap().spent_messages.insert(id);
     }
+
+    pub fn cancel_message(&self, id: Nonce) {
+        self.data.lock().unwrap().cancelled_messages.insert(id);
+    }
 }
 
 impl TxPoolDb for MockDb {
@@ -92,6 +97,10 @@ impl TxPoolDb for MockDb {
         Ok(self.data.lock().unwrap().spent_messages.contains(id))
     }
 
+    fn is_message_cancelled(&self, id: &Nonce) -> StorageResult<bool> {
+        Ok(self.data.lock().unwrap().cancelled_messages.contains(id))
+    }
+
     fn current_block_height(&self) -> StorageResult<BlockHeight> {
         Ok(Default::default())
     }
@@ -7,6 +7,7 @@ use fuel_core_types::{
     },
     fuel_tx::{
         Transaction,
+        TxId,
         UtxoId,
     },
     fuel_types::{
@@ -21,12 +22,19 @@ use fuel_core_types::{
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
             NetworkData,
+            PeerId,
+            SyncProgress,
+            TxRejection,
         },
         txpool::TransactionStatus,
     },
 };
-use std::sync::Arc;
+use std::{
+    fmt::Debug,
+    sync::Arc,
+};
 
+#[async_trait::async_trait]
 pub trait PeerToPeer: Send + Sync {
     type GossipedTransaction: NetworkData<Transaction>;
 
@@ -42,6 +50,37 @@ pub trait PeerToPeer: Send + Sync {
         message_info: GossipsubMessageInfo,
         validity: GossipsubMessageAcceptance,
     ) -> anyhow::Result<()>;
+
+    /// Creates a stream of the newly connected peers.
+    fn subscribe_new_peers(&self) -> BoxStream<PeerId>;
+
+    /// Notifies the peer that gossiped the transaction about its rejection
+    /// or its removal from the pool.
+    fn notify_tx_rejection(
+        &self,
+        peer_id: PeerId,
+        rejection: TxRejection,
+    ) -> anyhow::Result<()>;
+
+    /// Creates a stream of the rejections of the transactions gossiped by this node
+    /// reported by the peers, with the ids of the reporting peers.
+    fn tx_rejection_events(&self) -> BoxStream<(PeerId, TxRejection)>;
+
+    /// Requests the ids of the transactions in the pool of the peer.
+    async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+    /// Requests the transactions from the pool of the peer by their ids.
+    /// The peer returns the transactions for the prefix of the `tx_ids` that fits
+    /// into its response, with `None` for the ones missing in its pool.
+    async fn request_txs(
+        &self,
+        peer_id: PeerId,
+        tx_ids: Vec<TxId>,
+    ) -> anyhow::Result<Vec<Option<Transaction>>>;
+
+    /// Returns the progress of the sync while the node is too far behind the network
+    /// to validate the transactions against its state.
+    fn sync_progress(&self) -> Option<SyncProgress>;
 }
 
 pub trait BlockImporter: Send + Sync {
@@ -58,7 +97,23 @@ pub trait TxPoolDb: Send + Sync {
 
     fn is_message_spent(&self, message_id: &Nonce) -> StorageResult<bool>;
 
+    fn is_message_cancelled(&self, message_id: &Nonce) -> StorageResult<bool>;
+
     fn current_block_height(&self) -> StorageResult<BlockHeight>;
 
     fn transaction_status(&self, tx_id: &Bytes32) -> StorageResult<TransactionStatus>;
 }
+
+/// Provides the gas price of the next block calculated by the gas price algorithm.
+pub trait GasPriceProvider: Debug + Send + Sync {
+    /// Returns the gas price of the next block.
+    fn gas_price(&self) -> u64;
+}
+
+/// The operator-defined policy checked before the transaction is admitted into the pool.
+/// It is called for the transactions submitted via API and gossiped by the peers.
+pub trait AdmissionPolicy: Debug + Send + Sync {
+    /// Returns an error with the reason of the rejection if the transaction
+    /// is not allowed into the pool.
+    fn check(&self, tx: &Transaction) -> anyhow::Result<()>;
+}
@@ -4,13 +4,14 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
-    transaction_selector::select_transactions,
     txpool::{
         check_single_tx,
         check_transactions,
+        find_invalidated,
     },
     Config,
     Error as TxPoolError,
+    Limits,
     TxInfo,
     TxPool,
 };
@@ -30,6 +31,7 @@ use fuel_core_types::{
         UniqueIdentifier,
     },
     fuel_types::{
+        Address,
         BlockHeight,
         Bytes32,
     },
@@ -39,12 +41,16 @@ use fuel_core_types::{
             GossipData,
             GossipsubMessageAcceptance,
             GossipsubMessageInfo,
+            PeerId,
             TransactionGossipData,
+            TxRejection,
+            TxRejectionReason,
         },
         txpool::{
             ArcPoolTx,
             Error,
             InsertionResult,
+            PendingCoins,
             TransactionStatus,
         },
     },
@@ -52,14 +58,41 @@ use fuel_core_types::{
 };
 
 use parking_lot::Mutex as ParkingMutex;
-use std::sync::Arc;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+};
 use tokio::{
-    sync::broadcast,
+    sync::broadcast::{
+        self,
+        error::RecvError,
+    },
     time::MissedTickBehavior,
 };
 use tokio_stream::StreamExt;
 use update_sender::UpdateSender;
 
+/// The max number of the transactions requested from the peer at once
+/// during the reconciliation of the pools.
+const MAX_TXS_PER_SYNC_REQUEST: usize = 128;
+
+/// The max number of the gossiped transactions with the remembered peers
+/// notified when the transactions are squeezed out.
+const MAX_GOSSIP_ORIGINS: usize = 10_000;
+
+/// The number of distinct peers that must reject the transaction submitted to this
+/// node as invalid to remove it from the pool.
+const PEER_REJECTIONS_QUORUM: usize = 3;
+
 use self::update_sender::{
     MpscChannel,
     TxStatusStream,
@@ -73,18 +106,29 @@ pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 pub struct TxStatusChange {
     new_tx_notification_sender: broadcast::Sender<TxId>,
     update_sender: UpdateSender,
+    all_updates_sender: broadcast::Sender<TxUpdate>,
+    squeezed_out_sender: broadcast::Sender<(TxId, TxPoolError)>,
 }
 
 impl TxStatusChange {
     pub fn new(capacity: usize) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
         let update_sender = UpdateSender::new(capacity);
+        let (all_updates_sender, _) = broadcast::channel(capacity);
+        let (squeezed_out_sender, _) = broadcast::channel(capacity);
         Self {
             new_tx_notification_sender,
             update_sender,
+            all_updates_sender,
+            squeezed_out_sender,
         }
     }
 
+    fn send(&self, update: TxUpdate) {
+        let _ = self.all_updates_sender.send(update.clone());
+        self.update_sender.send(update);
+    }
+
     pub fn send_complete(
         &self,
         id: Bytes32,
@@ -92,13 +136,13 @@ impl TxStatusChange {
         message: impl Into<TxStatusMessage>,
     ) {
         tracing::info!("Transaction {id} successfully included in block {block_height}");
-        self.update_sender.send(TxUpdate::new(id, message.into()));
+        self.send(TxUpdate::new(id, message.into()));
     }
 
     pub fn send_submitted(&self, id: Bytes32, time: Tai64) {
         tracing::info!("Transaction {id} successfully submitted to the tx pool");
         let _ = self.new_tx_notification_sender.send(id);
-        self.update_sender.send(TxUpdate::new(
+        self.send(TxUpdate::new(
             id,
             TxStatusMessage::Status(TransactionStatus::Submitted { time }),
         ));
@@ -106,7 +150,8 @@ impl TxStatusChange {
 
     pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
-        self.update_sender.send(TxUpdate::new(
+        let _ = self.squeezed_out_sender.send((id, reason.clone()));
+        self.send(TxUpdate::new(
             id,
             TxStatusMessage::Status(TransactionStatus::SqueezedOut {
                 reason: reason.to_string(),
@@ -122,6 +167,8 @@ pub struct SharedState<P2P, DB> {
     consensus_params: ConsensusParameters,
     db: DB,
     config: Config,
+    /// The pool rejects the new transactions while it is set.
+    insertions_paused: Arc<AtomicBool>,
 }
 
 impl<P2P, DB: Clone> Clone for SharedState<P2P, DB> {
@@ -133,22 +180,117 @@ impl<P2P, DB: Clone> Clone for SharedState<P2P, DB> {
             consensus_params: self.consensus_params.clone(),
             db: self.db.clone(),
             config: self.config.clone(),
+            insertions_paused: self.insertions_paused.clone(),
         }
     }
 }
 
 pub struct Task<P2P, DB> {
     gossiped_tx_stream: BoxStream<TransactionGossipData>,
+    new_peers_stream: BoxStream<PeerId>,
+    tx_rejections_stream: BoxStream<(PeerId, TxRejection)>,
     committed_block_stream: BoxStream<Arc<ImportResult>>,
+    squeezed_out: broadcast::Receiver<(TxId, TxPoolError)>,
     shared: SharedState<P2P, DB>,
     ttl_timer: tokio::time::Interval,
+    revalidation_timer: Option<tokio::time::Interval>,
+    /// The peers that gossiped the transactions in the pool.
+    gossip_origins: HashMap<TxId, PeerId>,
+    /// The peers that reported the transactions submitted to this node as invalid.
+    peer_rejections: HashMap<TxId, HashSet<PeerId>>,
+}
+
+impl<P2P, DB> Task<P2P, DB>
+where
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData>,
+    DB: TxPoolDb,
+{
+    fn notify_rejection(&self, peer_id: PeerId, rejection: TxRejection) {
+        if let Err(e) = self.shared.p2p.notify_tx_rejection(peer_id, rejection) {
+            tracing::debug!(
+                "Failed to notify the peer about the rejected transaction: {e}"
+            );
+        }
+    }
+
+    /// Notifies the peer that gossiped the squeezed out transaction.
+    fn on_squeezed_out(&mut self, tx_id: TxId, error: TxPoolError) {
+        self.peer_rejections.remove(&tx_id);
+        let Some(peer_id) = self.gossip_origins.remove(&tx_id) else {
+            return
+        };
+        if let Some(reason) = error.rejection_reason() {
+            let rejection = TxRejection {
+                tx_id,
+                reason,
+                message: error.to_string(),
+            };
+            self.notify_rejection(peer_id, rejection);
+        }
+    }
+
+    /// Removes the transaction from the pool after [`PEER_REJECTIONS_QUORUM`] distinct
+    /// peers rejected it as invalid, so the subscribers of its status receive the
+    /// reason instead of waiting for the inclusion. Any peer can send the rejection,
+    /// so a single peer can't evict the transaction. The other reasons depend on the
+    /// pool of the peer and are ignored. Only the transactions submitted to this node
+    /// are removed, the transactions gossiped by other peers stay in the pool.
+    fn on_peer_rejection(&mut self, peer_id: PeerId, rejection: TxRejection) {
+        let tx_id = rejection.tx_id;
+        if rejection.reason != TxRejectionReason::Invalid
+            || self.gossip_origins.contains_key(&tx_id)
+            || !self.shared.txpool.lock().txs().contains_key(&tx_id)
+        {
+            return
+        }
+        if !self.peer_rejections.contains_key(&tx_id)
+            && self.peer_rejections.len() >= MAX_GOSSIP_ORIGINS
+        {
+            return
+        }
+        let peers = self.peer_rejections.entry(tx_id).or_default();
+        peers.insert(peer_id);
+        if peers.len() < PEER_REJECTIONS_QUORUM {
+            return
+        }
+        self.peer_rejections.remove(&tx_id);
+        self.shared.txpool.lock().remove_with_reason(
+            &self.shared.tx_status_sender,
+            &tx_id,
+            Error::RejectedByPeer(rejection.message),
+        );
+    }
+}
+
+/// Waits for the next tick of the `timer`, or forever if there is no timer.
+async fn tick(timer: &mut Option<tokio::time::Interval>) {
+    match timer {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => core::future::pending().await,
+    }
+}
+
+/// Returns the rejection reported to the peer that gossiped the transaction,
+/// or `None` if the peer doesn't need to be notified.
+fn tx_rejection(tx_id: TxId, error: &anyhow::Error) -> Option<TxRejection> {
+    let reason = match error.downcast_ref::<Error>() {
+        Some(error) => error.rejection_reason()?,
+        None => TxRejectionReason::Invalid,
+    };
+    Some(TxRejection {
+        tx_id,
+        reason,
+        message: error.to_string(),
+    })
 }
 
 #[async_trait::async_trait]
 impl<P2P, DB> RunnableService for Task<P2P, DB>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + Send + Sync,
-    DB: TxPoolDb + Clone,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + Send + Sync + 'static,
+    DB: TxPoolDb + Clone + 'static,
 {
     const NAME: &'static str = "TxPool";
 
@@ -166,6 +308,9 @@ where
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.ttl_timer.reset();
+        if let Some(revalidation_timer) = &mut self.revalidation_timer {
+            revalidation_timer.reset();
+        }
         Ok(self)
     }
 }
@@ -173,8 +318,8 @@ where
 #[async_trait::async_trait]
 impl<P2P, DB> RunnableTask for Task<P2P, DB>
 where
-    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + Send + Sync,
-    DB: TxPoolDb,
+    P2P: PeerToPeer<GossipedTransaction = TransactionGossipData> + Send + Sync + 'static,
+    DB: TxPoolDb + Clone + 'static,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
         let should_continue;
@@ -195,6 +340,30 @@ where
                 should_continue = true
             }
 
+            _ = tick(&mut self.revalidation_timer) => {
+                // The pool is locked only to take the snapshot and to remove the
+                // invalidated transactions, so the insertions aren't blocked by the
+                // reads of the database.
+                let snapshot = self.shared.txpool.lock().revalidation_snapshot();
+                let db = self.shared.db.clone();
+                let config = self.shared.config.clone();
+                let invalidated = tokio::task::spawn_blocking(move || {
+                    find_invalidated(&snapshot, &db, &config)
+                })
+                .await;
+                match invalidated {
+                    Ok(invalidated) => {
+                        let sender = &self.shared.tx_status_sender;
+                        self.shared.txpool.lock().remove_invalidated(sender, invalidated);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to re-validate the transactions: {e}");
+                    }
+                }
+
+                should_continue = true
+            }
+
             result = self.committed_block_stream.next() => {
                 if let Some(result) = result {
                     let block = result
@@ -206,6 +375,10 @@ where
                         block.header().height(),
                         block.transactions()
                     );
+                    for tx_id in block.transactions() {
+                        self.gossip_origins.remove(tx_id);
+                        self.peer_rejections.remove(tx_id);
+                    }
                     should_continue = true;
                 } else {
                     should_continue = false;
@@ -213,14 +386,17 @@ where
             }
 
             new_transaction = self.gossiped_tx_stream.next() => {
-                if let Some(GossipData { data: Some(tx), message_id, peer_id }) = new_transaction {
+                if self.shared.insertions_paused() {
+                    // The gossiped transactions are dropped without the judgment of the peer.
+                    should_continue = new_transaction.is_some();
+                } else if let Some(GossipData { data: Some(tx), message_id, peer_id }) = new_transaction {
                     let id = tx.id(&self.shared.consensus_params.chain_id);
                     let current_height = self.shared.db.current_block_height()?;
 
                     // verify tx
                     let checked_tx = check_single_tx(tx, current_height, &self.shared.config).await;
 
-                    let acceptance = match checked_tx {
+                    let (acceptance, rejection) = match checked_tx {
                         Ok(tx) => {
                             let txs = vec![tx];
 
@@ -235,19 +411,26 @@ where
 
                             match result.pop() {
                                 Some(Ok(_)) => {
-                                    GossipsubMessageAcceptance::Accept
+                                    if self.gossip_origins.len() < MAX_GOSSIP_ORIGINS {
+                                        self.gossip_origins.insert(id, peer_id.clone());
+                                    }
+                                    (GossipsubMessageAcceptance::Accept, None)
                                 },
-                                Some(Err(_)) => {
-                                    GossipsubMessageAcceptance::Reject
+                                Some(Err(e)) => {
+                                    (GossipsubMessageAcceptance::Reject, tx_rejection(id, &e))
                                 }
-                                _ => GossipsubMessageAcceptance::Ignore
+                                _ => (GossipsubMessageAcceptance::Ignore, None)
                             }
                         }
-                        Err(_) => {
-                            GossipsubMessageAcceptance::Reject
+                        Err(e) => {
+                            (GossipsubMessageAcceptance::Reject, tx_rejection(id, &e))
                         }
                     };
 
+                    if let Some(rejection) = rejection {
+                        self.notify_rejection(peer_id.clone(), rejection);
+                    }
+
                     if acceptance != GossipsubMessageAcceptance::Ignore {
                         let message_info = GossipsubMessageInfo {
                             message_id,
@@ -262,6 +445,47 @@ where
                     should_continue = false;
                 }
             }
+
+            squeezed_out = self.squeezed_out.recv() => {
+                match squeezed_out {
+                    Ok((tx_id, error)) => {
+                        self.on_squeezed_out(tx_id, error);
+                        should_continue = true;
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::debug!("Skipped {skipped} squeezed out transactions");
+                        should_continue = true;
+                    }
+                    Err(RecvError::Closed) => {
+                        should_continue = false;
+                    }
+                }
+            }
+
+            rejection = self.tx_rejections_stream.next() => {
+                if let Some((peer_id, rejection)) = rejection {
+                    self.on_peer_rejection(peer_id, rejection);
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
+
+            new_peer = self.new_peers_stream.next() => {
+                if let Some(peer_id) = new_peer {
+                    // The reconciliation waits for the responses of the peer,
+                    // so it doesn't block the processing of other events.
+                    let shared = self.shared.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = shared.sync_with_peer(peer_id).await {
+                            tracing::debug!("Failed to reconcile the pool with the peer: {e}");
+                        }
+                    });
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
         }
         Ok(should_continue)
     }
@@ -295,6 +519,29 @@ where
         self.txpool.lock().remove(&self.tx_status_sender, &ids)
     }
 
+    pub fn limits(&self) -> Limits {
+        self.txpool.lock().limits()
+    }
+
+    /// Updates the limits of the running pool.
+    ///
+    /// The period of the pruning of the old transactions is set at the start of
+    /// the service, so the new TTL is checked with the old period.
+    pub fn set_limits(&self, limits: Limits) {
+        self.txpool.lock().set_limits(limits)
+    }
+
+    /// Stops or resumes the acceptance of the new transactions. The transactions
+    /// already in the pool are still included into the blocks.
+    pub fn pause_insertions(&self, paused: bool) {
+        self.insertions_paused.store(paused, Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the pool doesn't accept the new transactions.
+    pub fn insertions_paused(&self) -> bool {
+        self.insertions_paused.load(Ordering::Relaxed)
+    }
+
     pub fn find(&self, ids: Vec<TxId>) -> Vec<Option<TxInfo>> {
         self.txpool.lock().find(&ids)
     }
@@ -307,12 +554,41 @@ where
         self.txpool.lock().find_dependent(&ids)
     }
 
+    pub fn pending_coins(&self, owner: &Address) -> PendingCoins {
+        self.txpool.lock().pending_coins(owner)
+    }
+
+    /// Returns the ids of up to `max_txs` transactions in the order of their inclusion.
+    /// The private transactions are skipped, they are never shared with the peers.
+    pub fn tx_ids(&self, max_txs: usize) -> Vec<TxId> {
+        let txpool = self.txpool.lock();
+        txpool
+            .sorted_includable()
+            .map(|tx| tx.id())
+            .filter(|id| {
+                txpool
+                    .txs()
+                    .get(id)
+                    .map_or(false, |info| !info.is_private())
+            })
+            .take(max_txs)
+            .collect()
+    }
+
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
         let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        let txs = guard
+            .sorted_includable()
+            .filter_map(|tx| guard.txs().get(&tx.id()).cloned())
+            .collect();
+        let sorted_txs = self.config.selection_strategy.select(txs, max_gas);
 
+        // Links the block production to the transactions for the tracing.
+        let current_span = tracing::Span::current();
         for tx in sorted_txs.iter() {
+            if let Some(info) = guard.txs().get(&tx.id()) {
+                current_span.follows_from(info.span());
+            }
             guard.remove_committed_tx(&tx.id());
         }
         sorted_txs
@@ -326,6 +602,11 @@ where
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
 
+    /// Subscribes to the status updates of all transactions.
+    pub fn tx_updates_subscribe(&self) -> broadcast::Receiver<TxUpdate> {
+        self.tx_status_sender.all_updates_sender.subscribe()
+    }
+
     pub async fn tx_update_subscribe(&self, tx_id: Bytes32) -> TxStatusStream {
         self.tx_status_sender
             .update_sender
@@ -343,6 +624,126 @@ where
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_submitted(txs, false).await
+    }
+
+    /// Inserts the private transactions. Unlike [`Self::insert`], they aren't gossiped
+    /// or shared with the peers, so only the local block producer includes them.
+    #[tracing::instrument(name = "insert_private_txn", skip_all)]
+    pub async fn insert_private(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_submitted(txs, true).await
+    }
+
+    async fn insert_submitted(
+        &self,
+        txs: Vec<Arc<Transaction>>,
+        private: bool,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        if self.config.read_only {
+            return txs
+                .iter()
+                .map(|_| Err(Error::NotInsertedReadOnly.into()))
+                .collect()
+        }
+
+        // The state of the node far behind the network is stale, so the submissions
+        // are rejected until the node catches up.
+        if let Some(progress) = self.p2p.sync_progress() {
+            return txs
+                .iter()
+                .map(|_| {
+                    Err(Error::NotInsertedNodeSyncing {
+                        current: progress.current,
+                        target: progress.target,
+                    }
+                    .into())
+                })
+                .collect()
+        }
+
+        if self.insertions_paused() {
+            return txs
+                .iter()
+                .map(|_| Err(Error::NotInsertedInsertionsPaused.into()))
+                .collect()
+        }
+
+        let results = self.check_and_insert(&txs, private).await;
+        if private {
+            return results
+        }
+
+        for (result, tx) in results.iter().zip(txs.into_iter()) {
+            if result.is_ok() {
+                let result = self.p2p.broadcast_transaction(tx);
+                if let Err(e) = result {
+                    // It can be only in the case of p2p being down or requests overloading it.
+                    tracing::error!(
+                        "Unable to broadcast transaction, got an {} error",
+                        e
+                    );
+                }
+            }
+        }
+
+        results
+    }
+
+    /// Requests the transactions missing in the local pool from the pool of the peer.
+    /// They aren't gossiped, because the peer already knows them.
+    async fn sync_with_peer(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        if self.p2p.sync_progress().is_some() || self.insertions_paused() {
+            return Ok(())
+        }
+
+        let tx_ids = self.p2p.request_tx_ids(peer_id.clone()).await?;
+        let missing_tx_ids: Vec<_> = {
+            let txpool = self.txpool.lock();
+            tx_ids
+                .into_iter()
+                .filter(|tx_id| !txpool.txs().contains_key(tx_id))
+                .collect()
+        };
+
+        let mut missing_tx_ids = missing_tx_ids.as_slice();
+        while !missing_tx_ids.is_empty() {
+            let tx_ids =
+                &missing_tx_ids[..missing_tx_ids.len().min(MAX_TXS_PER_SYNC_REQUEST)];
+            let txs = self
+                .p2p
+                .request_txs(peer_id.clone(), tx_ids.to_vec())
+                .await?;
+            if txs.is_empty() {
+                break
+            }
+            // The peer returns only the prefix that fits into its response,
+            // so the rest of the transactions is requested again.
+            missing_tx_ids = &missing_tx_ids[txs.len().min(tx_ids.len())..];
+            // The peer may have already removed some transactions from its pool.
+            let txs: Vec<_> = txs.into_iter().flatten().map(Arc::new).collect();
+            let inserted = self
+                .check_and_insert(&txs, false)
+                .await
+                .into_iter()
+                .filter(Result::is_ok)
+                .count();
+            tracing::debug!(
+                "Inserted {inserted} of {} transactions from the pool of the peer",
+                txs.len()
+            );
+        }
+        Ok(())
+    }
+
+    /// Verifies the transactions and inserts the valid ones into the pool.
+    async fn check_and_insert(
+        &self,
+        txs: &[Arc<Transaction>],
+        private: bool,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // verify txs
         let block_height = self.db.current_block_height();
@@ -351,7 +752,7 @@ where
             Err(e) => return vec![Err(e.into())],
         };
 
-        let checked_txs = check_transactions(&txs, current_height, &self.config).await;
+        let checked_txs = check_transactions(txs, current_height, &self.config).await;
 
         let mut valid_txs = vec![];
 
@@ -367,23 +768,14 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
-
-        for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
-            match ret {
-                Ok(_) => {
-                    let result = self.p2p.broadcast_transaction(tx.clone());
-                    if let Err(e) = result {
-                        // It can be only in the case of p2p being down or requests overloading it.
-                        tracing::error!(
-                            "Unable to broadcast transaction, got an {} error",
-                            e
-                        );
-                    }
-                }
-                Err(_) => {}
+        let insertion = {
+            let mut txpool = self.txpool.lock();
+            if private {
+                txpool.insert_private(&self.tx_status_sender, valid_txs)
+            } else {
+                txpool.insert(&self.tx_status_sender, valid_txs)
             }
-        }
+        };
 
         let mut insertion = insertion.into_iter();
 
@@ -440,24 +832,40 @@ where
 {
     let p2p = Arc::new(p2p);
     let gossiped_tx_stream = p2p.gossiped_transaction_events();
+    let new_peers_stream = p2p.subscribe_new_peers();
+    let tx_rejections_stream = p2p.tx_rejection_events();
     let committed_block_stream = importer.block_events();
     let mut ttl_timer = tokio::time::interval(config.transaction_ttl);
     ttl_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let revalidation_timer = config.revalidation_interval.map(|interval| {
+        let mut timer = tokio::time::interval(interval);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        timer
+    });
     let consensus_params = config.chain_config.consensus_parameters.clone();
     let number_of_active_subscription = config.number_of_active_subscription;
     let txpool = Arc::new(ParkingMutex::new(TxPool::new(config.clone(), db.clone())));
+    let tx_status_sender = TxStatusChange::new(number_of_active_subscription);
+    let squeezed_out = tx_status_sender.squeezed_out_sender.subscribe();
     let task = Task {
         gossiped_tx_stream,
+        new_peers_stream,
+        tx_rejections_stream,
         committed_block_stream,
+        squeezed_out,
         shared: SharedState {
-            tx_status_sender: TxStatusChange::new(number_of_active_subscription),
+            tx_status_sender,
             txpool,
             p2p,
             consensus_params,
             db,
             config,
+            insertions_paused: Arc::new(AtomicBool::new(false)),
         },
         ttl_timer,
+        revalidation_timer,
+        gossip_origins: HashMap::new(),
+        peer_rejections: HashMap::new(),
     };
 
     Service::new(task)
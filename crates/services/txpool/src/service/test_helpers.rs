@@ -21,7 +21,11 @@ use fuel_core_types::{
         TransactionBuilder,
         Word,
     },
-    services::p2p::GossipsubMessageAcceptance,
+    services::p2p::{
+        GossipsubMessageAcceptance,
+        SyncProgress,
+        TxRejection,
+    },
 };
 use std::cell::RefCell;
 
@@ -63,6 +67,7 @@ impl TestContext {
 mockall::mock! {
     pub P2P {}
 
+    #[async_trait::async_trait]
     impl PeerToPeer for P2P {
         type GossipedTransaction = GossipedTransaction;
 
@@ -75,6 +80,26 @@ mockall::mock! {
             message_info: GossipsubMessageInfo,
             validity: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()>;
+
+        fn subscribe_new_peers(&self) -> BoxStream<PeerId>;
+
+        fn notify_tx_rejection(
+            &self,
+            peer_id: PeerId,
+            rejection: TxRejection,
+        ) -> anyhow::Result<()>;
+
+        fn tx_rejection_events(&self) -> BoxStream<(PeerId, TxRejection)>;
+
+        async fn request_tx_ids(&self, peer_id: PeerId) -> anyhow::Result<Vec<TxId>>;
+
+        async fn request_txs(
+            &self,
+            peer_id: PeerId,
+            tx_ids: Vec<TxId>,
+        ) -> anyhow::Result<Vec<Option<Transaction>>>;
+
+        fn sync_progress(&self) -> Option<SyncProgress>;
     }
 }
 
@@ -95,6 +120,14 @@ impl MockP2P {
         });
         p2p.expect_broadcast_transaction()
             .returning(move |_| Ok(()));
+        p2p.expect_subscribe_new_peers()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        p2p.expect_notify_gossip_transaction_validity()
+            .returning(|_, _| Ok(()));
+        p2p.expect_notify_tx_rejection().returning(|_, _| Ok(()));
+        p2p.expect_tx_rejection_events()
+            .returning(|| Box::pin(fuel_core_services::stream::pending()));
+        p2p.expect_sync_progress().returning(|| None);
         p2p
     }
 }
@@ -186,6 +186,53 @@ async fn test_prune_transactions_the_oldest() {
     service.stop_and_await().await.unwrap();
 }
 
+#[tokio::test]
+async fn insert_is_rejected_while_insertions_are_paused() {
+    let ctx = TestContextBuilder::new().build_and_start().await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+
+    service.shared.pause_insertions(true);
+    let out = service.shared.insert(vec![tx1.clone()]).await;
+    assert!(matches!(
+        out[0].as_ref().unwrap_err().downcast_ref::<Error>(),
+        Some(Error::NotInsertedInsertionsPaused)
+    ));
+    assert!(service.shared.find_one(tx1.cached_id().unwrap()).is_none());
+
+    service.shared.pause_insertions(false);
+    let out = service.shared.insert(vec![tx2.clone()]).await;
+    assert!(out[0].is_ok());
+}
+
+#[tokio::test]
+async fn insert_is_rejected_by_read_only_pool() {
+    let config = Config {
+        read_only: true,
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+
+    let tx = Arc::new(ctx.setup_script_tx(10));
+    let service = ctx.service();
+
+    for out in [
+        service.shared.insert(vec![tx.clone()]).await,
+        service.shared.insert_private(vec![tx.clone()]).await,
+    ] {
+        assert!(matches!(
+            out[0].as_ref().unwrap_err().downcast_ref::<Error>(),
+            Some(Error::NotInsertedReadOnly)
+        ));
+    }
+    assert!(service.shared.find_one(tx.cached_id().unwrap()).is_none());
+}
+
 #[tokio::test]
 async fn simple_insert_removal_subscription() {
     let ctx = TestContextBuilder::new().build_and_start().await;
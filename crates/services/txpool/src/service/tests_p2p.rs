@@ -4,9 +4,16 @@ use crate::service::test_helpers::{
     TestContextBuilder,
 };
 use fuel_core_services::Service;
-use fuel_core_types::fuel_tx::{
-    Transaction,
-    UniqueIdentifier,
+use fuel_core_types::{
+    fuel_tx::{
+        Transaction,
+        UniqueIdentifier,
+    },
+    services::p2p::{
+        SyncProgress,
+        TxRejection,
+        TxRejectionReason,
+    },
 };
 use std::{
     ops::Deref,
@@ -93,6 +100,69 @@ async fn insert_from_local_broadcasts_to_p2p() {
     }
 }
 
+#[tokio::test]
+async fn insert_private_is_not_broadcasted_or_shared_with_peers() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    p2p.expect_broadcast_transaction().times(0);
+    ctx_builder.with_p2p(p2p);
+    let ctx = ctx_builder.build_and_start().await;
+
+    let service = ctx.service();
+    let tx_id = tx1.id(&Default::default());
+    let out = service
+        .shared
+        .insert_private(vec![Arc::new(tx1.clone())])
+        .await;
+
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err");
+    let info = service
+        .shared
+        .find_one(tx_id)
+        .expect("Tx1 should be in the pool");
+    assert!(info.is_private());
+    assert!(service.shared.tx_ids(10).is_empty());
+    // The local block producer still selects the private transaction.
+    let selected = service.shared.select_transactions(u64::MAX);
+    assert_eq!(selected.len(), 1);
+    assert_eq!(selected[0].id(), tx_id);
+}
+
+#[tokio::test]
+async fn insert_from_local_is_rejected_while_node_is_syncing() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    p2p.expect_sync_progress().returning(|| {
+        Some(SyncProgress {
+            current: 5u32.into(),
+            target: 100u32.into(),
+        })
+    });
+    p2p.expect_broadcast_transaction().times(0);
+    ctx_builder.with_p2p(p2p);
+    let ctx = ctx_builder.build_and_start().await;
+
+    let service = ctx.service();
+    let out = service.shared.insert(vec![Arc::new(tx1.clone())]).await;
+
+    let err = out[0]
+        .as_ref()
+        .expect_err("Expected the node syncing error");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedNodeSyncing { current, target })
+            if *current == BlockHeight::from(5) && *target == BlockHeight::from(100)
+    ));
+    assert!(service
+        .shared
+        .find_one(tx1.id(&Default::default()))
+        .is_none());
+}
+
 #[tokio::test]
 async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
     let mut ctx_builder = TestContextBuilder::new();
@@ -133,3 +203,204 @@ async fn test_insert_from_p2p_does_not_broadcast_to_p2p() {
         "expected a timeout because no broadcast should have occurred"
     )
 }
+
+#[tokio::test]
+async fn missing_txs_are_requested_from_new_peer() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    p2p.expect_subscribe_new_peers().returning(|| {
+        let peer = Some(PeerId::from(vec![1]));
+        let stream = fuel_core_services::stream::unfold(peer, |peer| async {
+            if let Some(peer) = peer {
+                Some((peer, None))
+            } else {
+                core::future::pending().await
+            }
+        });
+        Box::pin(stream)
+    });
+    p2p.expect_request_tx_ids()
+        .returning(move |_| Ok(vec![tx1_id]));
+    let mock_tx1 = tx1.clone();
+    p2p.expect_request_txs()
+        .withf(move |_, tx_ids: &Vec<TxId>| tx_ids == &vec![tx1_id])
+        .times(1)
+        .returning(move |_, _| Ok(vec![Some(mock_tx1.clone())]));
+    let (send, mut receive) = broadcast::channel::<()>(1);
+    p2p.expect_broadcast_transaction().returning(move |_| {
+        send.send(()).unwrap();
+        Ok(())
+    });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service.shared.tx_update_subscribe(tx1_id).await;
+
+    service.start_and_await().await.unwrap();
+
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    let out = service.shared.find(vec![tx1_id]);
+    let got_tx: Transaction = out[0].as_ref().unwrap().tx().clone().deref().into();
+    assert_eq!(tx1, got_tx);
+
+    // The peer already knows the transaction, so it isn't gossiped.
+    let not_broadcast =
+        tokio::time::timeout(Duration::from_millis(100), receive.recv()).await;
+    assert!(not_broadcast.is_err());
+}
+
+#[tokio::test]
+async fn missing_txs_not_fitting_into_the_response_are_requested_again() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+    let tx2 = ctx_builder.setup_script_tx(10);
+    let tx2_id = tx2.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    p2p.expect_subscribe_new_peers().returning(|| {
+        let peer = Some(PeerId::from(vec![1]));
+        let stream = fuel_core_services::stream::unfold(peer, |peer| async {
+            if let Some(peer) = peer {
+                Some((peer, None))
+            } else {
+                core::future::pending().await
+            }
+        });
+        Box::pin(stream)
+    });
+    p2p.expect_request_tx_ids()
+        .returning(move |_| Ok(vec![tx1_id, tx2_id]));
+    // Only the first transaction fits into the response of the peer.
+    let mock_tx1 = tx1.clone();
+    p2p.expect_request_txs()
+        .withf(move |_, tx_ids: &Vec<TxId>| tx_ids == &vec![tx1_id, tx2_id])
+        .times(1)
+        .returning(move |_, _| Ok(vec![Some(mock_tx1.clone())]));
+    let mock_tx2 = tx2.clone();
+    p2p.expect_request_txs()
+        .withf(move |_, tx_ids: &Vec<TxId>| tx_ids == &vec![tx2_id])
+        .times(1)
+        .returning(move |_, _| Ok(vec![Some(mock_tx2.clone())]));
+    p2p.expect_broadcast_transaction().returning(|_| Ok(()));
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver1 = service.shared.tx_update_subscribe(tx1_id).await;
+    let mut receiver2 = service.shared.tx_update_subscribe(tx2_id).await;
+
+    service.start_and_await().await.unwrap();
+
+    for receiver in [&mut receiver1, &mut receiver2] {
+        let res = receiver.next().await;
+        assert!(matches!(
+            res,
+            Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+        ));
+    }
+    let out = service.shared.find(vec![tx1_id, tx2_id]);
+    assert!(out.iter().all(Option::is_some));
+}
+
+#[tokio::test]
+async fn rejected_gossiped_tx_is_reported_to_the_peer() {
+    let mut ctx_builder = TestContextBuilder::new();
+    // The coin of the transaction is missing in the database of the node.
+    let tx1 = TestContextBuilder::new().setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs(vec![tx1]);
+    let (send, mut receive) = broadcast::channel::<(PeerId, TxRejection)>(1);
+    p2p.expect_notify_tx_rejection()
+        .returning(move |peer_id, rejection| {
+            send.send((peer_id, rejection)).unwrap();
+            Ok(())
+        });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build_and_start().await;
+
+    let (peer_id, rejection) =
+        tokio::time::timeout(Duration::from_secs(1), receive.recv())
+            .await
+            .expect("The peer should be notified")
+            .unwrap();
+    assert_eq!(peer_id, PeerId::from(vec![]));
+    assert_eq!(rejection.tx_id, tx1_id);
+    assert_eq!(rejection.reason, TxRejectionReason::Invalid);
+    assert!(ctx.service().shared.find_one(tx1_id).is_none());
+}
+
+#[tokio::test]
+async fn peer_rejections_quorum_squeezes_out_submitted_tx() {
+    let mut ctx_builder = TestContextBuilder::new();
+    let tx1 = ctx_builder.setup_script_tx(10);
+    let tx1_id = tx1.id(&Default::default());
+
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let (rejections, _) = broadcast::channel::<(PeerId, TxRejection)>(16);
+    let sender = rejections.clone();
+    p2p.expect_tx_rejection_events().returning(move || {
+        use tokio_stream::wrappers::BroadcastStream;
+        Box::pin(BroadcastStream::new(sender.subscribe()).filter_map(|r| r.ok()))
+    });
+    ctx_builder.with_p2p(p2p);
+
+    let ctx = ctx_builder.build();
+    let service = ctx.service();
+    let mut receiver = service.shared.tx_update_subscribe(tx1_id).await;
+    service.start_and_await().await.unwrap();
+
+    let out = service.shared.insert(vec![Arc::new(tx1)]).await;
+    assert!(out[0].is_ok());
+    let rejection = |reason| TxRejection {
+        tx_id: tx1_id,
+        reason,
+        message: "The transaction is invalid".to_string(),
+    };
+    // The reasons depending on the pool of the peer are ignored.
+    for i in 0..PEER_REJECTIONS_QUORUM {
+        let peer_id = PeerId::from(vec![i as u8]);
+        let rejection = rejection(TxRejectionReason::GasPriceTooLow);
+        rejections.send((peer_id, rejection)).unwrap();
+    }
+    // The repeated rejections of the same peer are counted once.
+    for _ in 0..PEER_REJECTIONS_QUORUM {
+        let peer_id = PeerId::from(vec![0]);
+        let rejection = rejection(TxRejectionReason::Invalid);
+        rejections.send((peer_id, rejection)).unwrap();
+    }
+    for i in 1..PEER_REJECTIONS_QUORUM - 1 {
+        let peer_id = PeerId::from(vec![i as u8]);
+        let rejection = rejection(TxRejectionReason::Invalid);
+        rejections.send((peer_id, rejection)).unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(service.shared.find_one(tx1_id).is_some());
+
+    let peer_id = PeerId::from(vec![PEER_REJECTIONS_QUORUM as u8]);
+    let rejection = rejection(TxRejectionReason::Invalid);
+    rejections.send((peer_id, rejection)).unwrap();
+
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::Submitted { .. }))
+    ));
+    let res = receiver.next().await;
+    assert!(matches!(
+        res,
+        Some(TxStatusMessage::Status(TransactionStatus::SqueezedOut { reason }))
+            if reason.contains("The transaction is invalid")
+    ));
+    assert!(service.shared.find_one(tx1_id).is_none());
+}
@@ -0,0 +1,126 @@
+//! The fee sponsorship allows the registered sponsors to pay the fee of the
+//! transactions of other users. The sponsor attaches its coins to the transaction and
+//! signs them, while the user signs only its own inputs.
+//!
+//! The pool checks that the coins of the sponsor cover the max fee of the transaction,
+//! so the fee is never charged from the user, and limits the number of the sponsored
+//! transactions admitted per period, so one sponsor can't flood the pool.
+
+use crate::Error;
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+        Input,
+    },
+    services::txpool::PoolTransaction,
+};
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    time::Duration,
+};
+use tokio::time::Instant;
+
+#[derive(Debug, Clone)]
+pub struct SponsorshipConfig {
+    /// The registered sponsors with the max number of the sponsored transactions
+    /// admitted into the pool per `period`.
+    pub sponsors: HashMap<Address, usize>,
+    /// The period of the rate limit of the sponsors.
+    pub period: Duration,
+}
+
+impl Default for SponsorshipConfig {
+    fn default() -> Self {
+        Self {
+            sponsors: HashMap::new(),
+            period: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Tracks the transactions admitted for each sponsor.
+#[derive(Debug, Clone)]
+pub(crate) struct Sponsorships {
+    config: SponsorshipConfig,
+    admitted: HashMap<Address, VecDeque<Instant>>,
+}
+
+impl Sponsorships {
+    pub fn new(config: SponsorshipConfig) -> Self {
+        Self {
+            config,
+            admitted: HashMap::new(),
+        }
+    }
+
+    /// Returns the registered sponsor of the transaction, if any. The sponsor is the
+    /// owner of the first coin input registered as the sponsor.
+    ///
+    /// Returns an error if the coins of the sponsor don't cover the max fee of the
+    /// transaction or the sponsor hit its rate limit.
+    pub fn check(
+        &mut self,
+        tx: &PoolTransaction,
+        base_asset_id: &AssetId,
+        now: Instant,
+    ) -> Result<Option<Address>, Error> {
+        let coins = || {
+            tx.inputs().iter().filter(|input| {
+                matches!(
+                    input,
+                    Input::CoinSigned(_)
+                        | Input::CoinPredicate(_)
+                        | Input::MessageCoinSigned(_)
+                        | Input::MessageCoinPredicate(_)
+                )
+            })
+        };
+        let Some(sponsor) = coins()
+            .filter_map(Input::input_owner)
+            .find(|owner| self.config.sponsors.contains_key(*owner))
+            .copied()
+        else {
+            return Ok(None)
+        };
+
+        let covered = coins()
+            .filter(|input| {
+                input.input_owner() == Some(&sponsor)
+                    && input.asset_id(base_asset_id) == Some(base_asset_id)
+            })
+            .filter_map(Input::amount)
+            .fold(0u64, |sum, amount| sum.saturating_add(amount));
+        let max_fee = tx.max_fee();
+        if covered < max_fee {
+            return Err(Error::NotInsertedSponsorFeeNotCovered {
+                sponsor,
+                max_fee,
+                covered,
+            })
+        }
+
+        let max_txs = self.config.sponsors[&sponsor];
+        let admitted = self.admitted.entry(sponsor).or_default();
+        while let Some(oldest) = admitted.front() {
+            if now.duration_since(*oldest) >= self.config.period {
+                admitted.pop_front();
+            } else {
+                break
+            }
+        }
+        if admitted.len() >= max_txs {
+            return Err(Error::NotInsertedSponsorRateLimit(sponsor))
+        }
+
+        Ok(Some(sponsor))
+    }
+
+    /// Records the admission of the transaction sponsored by the `sponsor`.
+    pub fn record(&mut self, sponsor: Address, now: Instant) {
+        self.admitted.entry(sponsor).or_default().push_back(now);
+    }
+}
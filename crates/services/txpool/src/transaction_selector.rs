@@ -1,10 +1,119 @@
+//! The selection of the transactions from the pool into the next block.
+//!
+//! The block producer takes the transactions selected by the [`SelectionStrategy`]
+//! from the [`Config`](crate::Config). The node has the built-in strategies listed
+//! in the [`Strategy`]. The custom strategies implement the [`SelectionStrategy`]
+//! and are set into the config when the node is used as a library.
+
+use crate::TxInfo;
 use fuel_core_types::{
-    fuel_types::Word,
+    fuel_tx::{
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            contract::Contract,
+        },
+        Input,
+        Output,
+        TxId,
+    },
+    fuel_types::{
+        ContractId,
+        Word,
+    },
     services::txpool::ArcPoolTx,
 };
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    fmt::Debug,
+    str::FromStr,
+};
+
+/// The strategy of the selection of the transactions into the next block.
+pub trait SelectionStrategy: Debug + Send + Sync {
+    /// Returns the transactions to include into the block with the `max_gas`,
+    /// in the order of their execution. The `txs` are all includable transactions
+    /// of the pool, sorted by the gas price, the highest first.
+    ///
+    /// The transactions spending the outputs of other transactions from the pool
+    /// must follow them, otherwise the executor skips them.
+    fn select(&self, txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx>;
+}
+
+/// The built-in selection strategies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// The transactions with the higher gas price first. The transactions that don't
+    /// fit into the block are skipped in favor of the cheaper ones that fit.
+    #[default]
+    FeePriority,
+    /// The transactions in the order of their arrival into the pool, regardless of
+    /// the gas price. The selection stops at the first transaction that doesn't fit
+    /// into the block, so the later transactions never overtake it.
+    Fifo,
+    /// The transactions with the deadline, like the forced ones, first, the earliest
+    /// deadline first. The rest follow them by the gas price. The transactions that
+    /// don't fit into the block are skipped.
+    DeadlineFirst,
+}
+
+impl SelectionStrategy for Strategy {
+    fn select(&self, txs: Vec<TxInfo>, max_gas: u64) -> Vec<ArcPoolTx> {
+        match self {
+            Strategy::FeePriority => {
+                select_transactions(txs.into_iter().map(|info| info.tx), max_gas)
+            }
+            Strategy::Fifo => {
+                let mut txs = txs;
+                // The stable sort keeps the higher priced first among the simultaneous.
+                txs.sort_by_key(|info| info.submitted_time());
+                let txs = txs.into_iter().map(|info| info.tx).collect();
+                Selection::new(&txs, max_gas).fill(txs, true)
+            }
+            Strategy::DeadlineFirst => {
+                let mut txs = txs;
+                // The stable sort keeps the transactions without the deadline
+                // sorted by the gas price.
+                txs.sort_by_key(|info| (info.deadline().is_none(), info.deadline()));
+                let txs = txs.into_iter().map(|info| info.tx).collect();
+                Selection::new(&txs, max_gas).fill(txs, false)
+            }
+        }
+    }
+}
 
-// transaction selection could use a plugin based approach in the
-// future for block producers to customize block building (e.g. alternative priorities besides gas fees)
+impl core::fmt::Display for Strategy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            Strategy::FeePriority => "fee-priority",
+            Strategy::Fifo => "fifo",
+            Strategy::DeadlineFirst => "deadline-first",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fee-priority" => Ok(Strategy::FeePriority),
+            "fifo" => Ok(Strategy::Fifo),
+            "deadline-first" => Ok(Strategy::DeadlineFirst),
+            _ => Err(format!(
+                "Unknown selection strategy `{s}`, \
+                expected `fee-priority`, `fifo` or `deadline-first`"
+            )),
+        }
+    }
+}
 
 // Expects sorted by gas price transactions, highest first
 pub fn select_transactions(
@@ -15,24 +124,122 @@ pub fn select_transactions(
     //
     // Future improvements to this algorithm may take into account the parallel nature of
     // transactions to maximize throughput.
-    let mut used_block_space: Word = 0;
-
-    // Pick as many transactions as we can fit into the block (greedy)
-    includable_txs
-        .filter(|tx| {
-            let tx_block_space = tx.max_gas();
-            if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
-                if new_used_space <= max_gas {
-                    used_block_space = new_used_space;
-                    true
-                } else {
-                    false
+    let txs: Vec<_> = includable_txs.collect();
+    Selection::new(&txs, max_gas).fill(txs, false)
+}
+
+/// The greedy selection of the ordered transactions that fit into the block.
+/// The transaction spending the outputs of other transactions from the pool
+/// waits until all of them are selected and follows the last one.
+struct Selection {
+    max_gas: Word,
+    used_gas: Word,
+    /// The ids of all transactions considered for the selection.
+    pool: HashSet<TxId>,
+    /// The transactions from the pool creating the contracts.
+    contracts: HashMap<ContractId, TxId>,
+    selected_ids: HashSet<TxId>,
+    selected: Vec<ArcPoolTx>,
+}
+
+impl Selection {
+    fn new(txs: &[ArcPoolTx], max_gas: Word) -> Self {
+        let pool = txs.iter().map(|tx| tx.id()).collect();
+        let contracts = txs
+            .iter()
+            .flat_map(|tx| {
+                let tx_id = tx.id();
+                tx.outputs().iter().filter_map(move |output| match output {
+                    Output::ContractCreated { contract_id, .. } => {
+                        Some((*contract_id, tx_id))
+                    }
+                    _ => None,
+                })
+            })
+            .collect();
+        Self {
+            max_gas,
+            used_gas: 0,
+            pool,
+            contracts,
+            selected_ids: HashSet::new(),
+            selected: Vec::new(),
+        }
+    }
+
+    /// Returns the transactions from the pool whose outputs the `tx` spends.
+    fn parents<'a>(&'a self, tx: &'a ArcPoolTx) -> impl Iterator<Item = TxId> + 'a {
+        tx.inputs().iter().filter_map(|input| {
+            let parent = match input {
+                Input::CoinSigned(CoinSigned { utxo_id, .. })
+                | Input::CoinPredicate(CoinPredicate { utxo_id, .. }) => {
+                    Some(*utxo_id.tx_id())
                 }
-            } else {
-                false
-            }
+                Input::Contract(Contract { contract_id, .. }) => {
+                    self.contracts.get(contract_id).copied()
+                }
+                _ => None,
+            };
+            parent.filter(|parent| self.pool.contains(parent))
         })
-        .collect()
+    }
+
+    /// Adds the `tx` if it fits into the block. Returns `false` otherwise.
+    fn push(&mut self, tx: ArcPoolTx) -> bool {
+        match self.used_gas.checked_add(tx.max_gas()) {
+            Some(used_gas) if used_gas <= self.max_gas => {
+                self.used_gas = used_gas;
+                self.selected_ids.insert(tx.id());
+                self.selected.push(tx);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Selects the `ordered` transactions. If `stop_at_misfit` is set, the selection
+    /// stops at the first transaction that doesn't fit into the block.
+    fn fill(mut self, ordered: Vec<ArcPoolTx>, stop_at_misfit: bool) -> Vec<ArcPoolTx> {
+        // The waiting transactions by the ids of their parents that aren't selected yet.
+        let mut waiting: HashMap<TxId, Vec<ArcPoolTx>> = HashMap::new();
+        // The number of the parents that aren't selected yet of each waiting transaction.
+        let mut missing_parents: HashMap<TxId, usize> = HashMap::new();
+        let mut ready = VecDeque::new();
+        for tx in ordered {
+            let parents: HashSet<_> = self
+                .parents(&tx)
+                .filter(|parent| !self.selected_ids.contains(parent))
+                .collect();
+            if !parents.is_empty() {
+                missing_parents.insert(tx.id(), parents.len());
+                for parent in parents {
+                    waiting.entry(parent).or_default().push(tx.clone());
+                }
+                continue
+            }
+            ready.push_back(tx);
+            while let Some(tx) = ready.pop_front() {
+                let tx_id = tx.id();
+                if !self.push(tx) {
+                    if stop_at_misfit {
+                        return self.selected
+                    }
+                    continue
+                }
+                // The selected transaction may be the last parent of the waiting ones.
+                for child in waiting.remove(&tx_id).unwrap_or_default() {
+                    let child_id = child.id();
+                    let missing = missing_parents.entry(child_id).or_default();
+                    *missing = missing.saturating_sub(1);
+                    if *missing == 0 {
+                        missing_parents.remove(&child_id);
+                        ready.push_back(child);
+                    }
+                }
+            }
+        }
+        self.selected
+    }
 }
 
 #[cfg(test)]
@@ -51,6 +258,7 @@ mod tests {
             FeeParameters,
             Output,
             TransactionBuilder,
+            UtxoId,
         },
         fuel_vm::{
             checked_transaction::builder::TransactionBuilderExt,
@@ -68,9 +276,7 @@ mod tests {
         pub limit: u64,
     }
 
-    /// A test helper that generates set of txs with given gas prices and limits and runs
-    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
-    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+    fn make_tx(tx_gas: TxGas, utxo_id: UtxoId) -> ArcPoolTx {
         let mut rng = thread_rng();
 
         let fee_params = FeeParameters {
@@ -78,34 +284,41 @@ mod tests {
             ..FeeParameters::default()
         };
 
+        let tx = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .gas_price(tx_gas.price)
+        .gas_limit(tx_gas.limit)
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            utxo_id,
+            1_000_000,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .with_fee_params(fee_params)
+        // The block producer assumes transactions are already checked
+        // so it doesn't need to compute valid sigs for tests
+        .finalize_checked_basic(Default::default())
+        .into();
+        Arc::new(tx)
+    }
+
+    /// A test helper that generates set of txs with given gas prices and limits and runs
+    /// `select_transactions` against that, returning the list of selected gas price, limit pairs
+    fn make_txs_and_select(txs: &[TxGas], block_gas_limit: Word) -> Vec<TxGas> {
+        let mut rng = thread_rng();
+
         let mut txs = txs
             .iter()
-            .map(|tx_gas| {
-                TransactionBuilder::script(
-                    vec![op::ret(RegId::ONE)].into_iter().collect(),
-                    vec![],
-                )
-                .gas_price(tx_gas.price)
-                .gas_limit(tx_gas.limit)
-                .add_unsigned_coin_input(
-                    SecretKey::random(&mut rng),
-                    rng.gen(),
-                    1_000_000,
-                    Default::default(),
-                    Default::default(),
-                    Default::default(),
-                )
-                .add_output(Output::Change {
-                    to: Default::default(),
-                    amount: 0,
-                    asset_id: Default::default(),
-                })
-                .with_fee_params(fee_params)
-                // The block producer assumes transactions are already checked
-                // so it doesn't need to compute valid sigs for tests
-                .finalize_checked_basic(Default::default()).into()
-            })
-            .map(Arc::new)
+            .map(|tx_gas| make_tx(*tx_gas, rng.gen()))
             .collect::<Vec<ArcPoolTx>>();
         txs.sort_by_key(|a| core::cmp::Reverse(a.price()));
 
@@ -200,4 +413,137 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn selector_puts_dependent_tx_after_its_parent() {
+        let mut rng = thread_rng();
+        let parent = make_tx(
+            TxGas {
+                price: 1,
+                limit: 1000,
+            },
+            rng.gen(),
+        );
+        let child = make_tx(
+            TxGas {
+                price: 5,
+                limit: 1000,
+            },
+            UtxoId::new(parent.id(), 0),
+        );
+
+        let selected =
+            select_transactions(vec![child.clone(), parent.clone()].into_iter(), 10_000);
+
+        let selected: Vec<_> = selected.iter().map(|tx| tx.id()).collect();
+        assert_eq!(selected, vec![parent.id(), child.id()]);
+    }
+
+    #[test]
+    fn selector_skips_dependent_tx_without_its_parent() {
+        let mut rng = thread_rng();
+        let parent = make_tx(
+            TxGas {
+                price: 1,
+                limit: 3000,
+            },
+            rng.gen(),
+        );
+        let child = make_tx(
+            TxGas {
+                price: 5,
+                limit: 1000,
+            },
+            UtxoId::new(parent.id(), 0),
+        );
+
+        let selected = select_transactions(vec![child, parent].into_iter(), 2000);
+
+        assert!(selected.is_empty());
+    }
+
+    /// Returns the transactions with the `(price, limit)` submitted one after another,
+    /// sorted by the gas price like the includable transactions of the pool.
+    fn submitted_txs(gas: &[(u64, u64)]) -> Vec<TxInfo> {
+        let mut rng = thread_rng();
+        let mut txs: Vec<_> = gas
+            .iter()
+            .map(|&(price, limit)| {
+                // The submission time has the precision of the system clock.
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                TxInfo::new(make_tx(TxGas { price, limit }, rng.gen()))
+            })
+            .collect();
+        txs.sort_by_key(|info| core::cmp::Reverse(info.price()));
+        txs
+    }
+
+    #[test]
+    fn fifo_stops_at_the_first_tx_that_doesnt_fit() {
+        let txs = submitted_txs(&[(1, 1000), (5, 3000), (3, 1000)]);
+        let first = txs.iter().find(|info| info.price() == 1).unwrap().tx().id();
+
+        let fifo = Strategy::Fifo.select(txs.clone(), 2500);
+        let fee_priority = Strategy::FeePriority.select(txs, 2500);
+
+        assert_eq!(
+            fifo.iter().map(|tx| tx.id()).collect::<Vec<_>>(),
+            vec![first]
+        );
+        assert_eq!(
+            fee_priority.iter().map(|tx| tx.price()).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn deadline_first_selects_txs_by_their_deadlines() {
+        let now = tokio::time::Instant::now();
+        let deadline = |secs| now + std::time::Duration::from_secs(secs);
+        let mut txs = submitted_txs(&[(1, 1000), (2, 1000), (3, 1000), (4, 1000)]);
+        // The prices are 4, 3, 2, 1.
+        txs[2] = txs[2].clone().with_deadline(deadline(10));
+        txs[3] = txs[3].clone().with_deadline(deadline(5));
+
+        let selected = Strategy::DeadlineFirst.select(txs, 10_000);
+
+        assert_eq!(
+            selected.iter().map(|tx| tx.price()).collect::<Vec<_>>(),
+            vec![1, 2, 4, 3]
+        );
+    }
+
+    #[test]
+    fn selector_puts_chain_of_dependent_txs_in_order() {
+        let mut rng = thread_rng();
+        let gas = TxGas {
+            price: 1,
+            limit: 1000,
+        };
+        let mut chain = vec![make_tx(gas, rng.gen())];
+        for _ in 0..3 {
+            let parent = chain.last().unwrap().id();
+            chain.push(make_tx(gas, UtxoId::new(parent, 0)));
+        }
+        let expected: Vec<_> = chain.iter().map(|tx| tx.id()).collect();
+
+        let selected = select_transactions(chain.into_iter().rev(), 10_000);
+
+        assert_eq!(
+            selected.iter().map(|tx| tx.id()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn strategy_roundtrips_through_the_string() {
+        for strategy in [
+            Strategy::FeePriority,
+            Strategy::Fifo,
+            Strategy::DeadlineFirst,
+        ] {
+            assert_eq!(strategy.to_string().parse(), Ok(strategy));
+        }
+        assert!("random".parse::<Strategy>().is_err());
+    }
 }
@@ -1,22 +1,32 @@
 use crate::{
     containers::{
         dependency::Dependency,
+        owner_index::OwnerIndex,
         price_sort::PriceSort,
         time_sort::TimeSort,
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    sponsorship::Sponsorships,
     types::*,
     Config,
     Error,
+    Limits,
     TxInfo,
 };
 use fuel_core_types::{
+    entities::coins::coin::Coin,
     fuel_tx::{
         Chargeable,
+        Input,
+        Output,
         Transaction,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        BlockHeight,
     },
-    fuel_types::BlockHeight,
     fuel_vm::{
         checked_transaction::{
             CheckPredicates,
@@ -31,11 +41,16 @@ use fuel_core_types::{
     services::txpool::{
         ArcPoolTx,
         InsertionResult,
+        PendingCoins,
     },
     tai64::Tai64,
 };
 
 use fuel_core_metrics::txpool_metrics::txpool_metrics;
+use fuel_core_services::{
+    Resource,
+    ResourcePermit,
+};
 use fuel_core_types::fuel_vm::checked_transaction::CheckPredicateParams;
 use std::{
     cmp::Reverse,
@@ -51,6 +66,8 @@ pub struct TxPool<DB> {
     by_gas_price: PriceSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    by_owner: OwnerIndex,
+    sponsorships: Sponsorships,
     config: Config,
     database: DB,
 }
@@ -67,6 +84,8 @@ where
             by_gas_price: PriceSort::default(),
             by_time: TimeSort::default(),
             by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_owner: OwnerIndex::default(),
+            sponsorships: Sponsorships::new(config.sponsorship.clone()),
             config,
             database,
         }
@@ -77,6 +96,20 @@ where
         &self.config
     }
 
+    pub fn limits(&self) -> Limits {
+        self.config.limits()
+    }
+
+    /// Updates the limits of the pool. The transactions that are already inside
+    /// the pool are not removed, the new limits are applied to the next insertions
+    /// and the next pruning of the old transactions.
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.config.max_tx = limits.max_tx;
+        self.config.max_depth = limits.max_depth;
+        self.config.transaction_ttl = limits.transaction_ttl;
+        self.by_dependency.set_max_depth(limits.max_depth);
+    }
+
     pub fn txs(&self) -> &HashMap<TxId, TxInfo> {
         &self.by_hash
     }
@@ -114,10 +147,25 @@ where
             .into())
         }
 
+        // The gas price may grow since the check of the transaction.
+        if tx.price() < self.config.required_gas_price() {
+            return Err(Error::NotInsertedGasPriceTooLow.into())
+        }
+
         if self.by_hash.contains_key(&tx.id()) {
             return Err(Error::NotInsertedTxKnown.into())
         }
 
+        let now = tokio::time::Instant::now();
+        let sponsor = self.sponsorships.check(
+            &tx,
+            self.config
+                .chain_config
+                .consensus_parameters
+                .base_asset_id(),
+            now,
+        )?;
+
         let mut max_limit_hit = false;
         // check if we are hitting limit of pool
         if self.by_hash.len() >= self.config.max_tx {
@@ -128,6 +176,7 @@ where
                 return Err(Error::NotInsertedLimitHit.into())
             }
         }
+        let budget_permit = self.acquire_memory(&tx, max_limit_hit)?;
         if self.config.metrics {
             txpool_metrics()
                 .gas_price_histogram
@@ -141,18 +190,25 @@ where
         let rem = self
             .by_dependency
             .insert(&self.by_hash, &self.database, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        if let Some(sponsor) = sponsor {
+            self.sponsorships.record(sponsor, now);
+        }
+        let mut info = TxInfo::new(tx.clone());
+        info.budget_permit = Some(Arc::new(budget_permit));
         let submitted_time = info.submitted_time();
         self.by_gas_price.insert(&info);
         self.by_time.insert(&info);
+        self.by_owner.insert(&tx);
         self.by_hash.insert(tx.id(), info);
+        self.record_pool_change(&tx, 1);
 
         // if some transaction were removed so we don't need to check limit
         let removed = if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
                 let rem_tx = self.by_gas_price.lowest_tx().unwrap(); // safe to unwrap limit is hit
-                self.remove_inner(&rem_tx);
+                let evicted = self.remove_inner(&rem_tx);
+                self.record_eviction("pool_full", evicted.len());
                 vec![rem_tx]
             } else {
                 Vec::new()
@@ -162,6 +218,7 @@ where
             for rem in rem.iter() {
                 self.remove_tx(&rem.id());
             }
+            self.record_eviction("replaced", rem.len());
 
             rem
         };
@@ -182,6 +239,70 @@ where
             .map(|(_, tx)| tx.clone())
     }
 
+    /// Acquires the memory of the `tx` from the budget. The transactions evicted by
+    /// the insertion of the `tx` return their memory right after it, so the `tx` may
+    /// take their memory when the budget is exhausted.
+    fn acquire_memory(
+        &self,
+        tx: &ArcPoolTx,
+        max_limit_hit: bool,
+    ) -> anyhow::Result<ResourcePermit> {
+        let budget = &self.config.resource_budget;
+        let size = tx.metered_bytes_size();
+        let usage = budget.usage(Resource::Memory);
+        let fits = usage
+            .limit
+            .map_or(true, |limit| usage.used.saturating_add(size) <= limit);
+        let replaced = if fits {
+            0
+        } else {
+            self.evicted_memory(tx, max_limit_hit)?
+        };
+        let permit = budget
+            .try_acquire_replacing(Resource::Memory, size, replaced)
+            .map_err(|e| Error::NotInsertedResourceBudgetExhausted(e.to_string()))?;
+        Ok(permit)
+    }
+
+    /// Returns the memory of the transactions evicted by the insertion of the `tx`:
+    /// the replaced transactions or, if the pool is full, the lowest priced one.
+    /// The dependents of the evicted transactions are not counted.
+    fn evicted_memory(
+        &self,
+        tx: &ArcPoolTx,
+        max_limit_hit: bool,
+    ) -> anyhow::Result<usize> {
+        let collided = self
+            .by_dependency
+            .collided(&self.by_hash, &self.database, tx)?;
+        let evicted = if !collided.is_empty() {
+            collided
+                .iter()
+                .filter_map(|tx_id| self.by_hash.get(tx_id))
+                .map(|info| info.tx().metered_bytes_size())
+                .sum()
+        } else if max_limit_hit {
+            self.by_gas_price
+                .lowest_tx()
+                .map_or(0, |tx| tx.metered_bytes_size())
+        } else {
+            0
+        };
+        Ok(evicted)
+    }
+
+    /// Sets the time by which the pooled transaction should be included, like for
+    /// the forced transaction. Returns `false` if the transaction isn't in the pool.
+    pub fn set_deadline(&mut self, tx_id: &TxId, deadline: tokio::time::Instant) -> bool {
+        match self.by_hash.get_mut(tx_id) {
+            Some(info) => {
+                info.deadline = Some(deadline);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn remove_inner(&mut self, tx: &ArcPoolTx) -> Vec<ArcPoolTx> {
         self.remove_by_tx_id(&tx.id())
     }
@@ -206,11 +327,29 @@ where
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_gas_price.remove(info);
+            self.by_owner.remove(info.tx());
+            self.record_pool_change(info.tx(), -1);
         }
 
         info
     }
 
+    fn record_pool_change(&self, tx: &PoolTransaction, delta: i64) {
+        if self.config.metrics {
+            let tx_type = match tx {
+                PoolTransaction::Script(_) => "script",
+                PoolTransaction::Create(_) => "create",
+            };
+            txpool_metrics().record_pool_change(tx_type, tx.price(), delta);
+        }
+    }
+
+    fn record_eviction(&self, reason: &str, count: usize) {
+        if self.config.metrics && count > 0 {
+            txpool_metrics().record_eviction(reason, count);
+        }
+    }
+
     /// Removes transaction from `TxPool` with assumption that it is committed into the blockchain.
     // TODO: Don't remove recursively dependent transactions on block commit.
     //  The same logic should be fixed in the `select_transactions`.
@@ -226,13 +365,39 @@ where
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: Vec<Checked<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_with_privacy(tx_status_sender, txs, false)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    /// Imports a set of the private transactions. They are never gossiped
+    /// or shared with the peers, only the local block producer includes them.
+    pub fn insert_private(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.insert_with_privacy(tx_status_sender, txs, true)
+    }
+
+    fn insert_with_privacy(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<Checked<Transaction>>,
+        private: bool,
     ) -> Vec<anyhow::Result<InsertionResult>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
 
         for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx));
+            let result = self.insert_inner(tx);
+            if let (true, Ok(InsertionResult { inserted, .. })) = (private, &result) {
+                if let Some(info) = self.by_hash.get_mut(&inserted.id()) {
+                    info.private = true;
+                }
+            }
+            res.push(result);
         }
 
         // announce to subscribers
@@ -253,8 +418,9 @@ where
                         Tai64::from_unix(submitted_time.as_secs() as i64),
                     );
                 }
-                Err(_) => {
+                Err(e) => {
                     // @dev should not broadcast tx if error occurred
+                    record_rejection(&self.config, e);
                 }
             }
         }
@@ -295,6 +461,57 @@ where
         list
     }
 
+    /// Returns the coins and the messages of the `owner` spent by the transactions
+    /// in the pool, and the coins created for the `owner` in the order of submission.
+    pub fn pending_coins(&self, owner: &Address) -> PendingCoins {
+        let mut txs: Vec<_> = self
+            .by_owner
+            .txs(owner)
+            .filter_map(|tx_id| self.by_hash.get(tx_id))
+            .collect();
+        txs.sort_by_key(|info| info.submitted_time());
+
+        let mut pending = PendingCoins::default();
+        for info in txs {
+            let tx = info.tx();
+            for input in tx.inputs() {
+                if input.input_owner() != Some(owner) {
+                    continue
+                }
+                if let Some(utxo_id) = input.utxo_id() {
+                    pending.spent_coins.insert(*utxo_id);
+                } else if let Some(nonce) = input.nonce() {
+                    pending.spent_messages.insert(*nonce);
+                }
+            }
+
+            let tx_id = tx.id();
+            for (index, output) in tx.outputs().iter().enumerate() {
+                if let Output::Coin {
+                    to,
+                    amount,
+                    asset_id,
+                } = output
+                {
+                    if to == owner {
+                        pending.created_coins.push(Coin {
+                            utxo_id: UtxoId::new(tx_id, index as u8),
+                            owner: *to,
+                            amount: *amount,
+                            asset_id: *asset_id,
+                            maturity: Default::default(),
+                            tx_pointer: Default::default(),
+                        });
+                    }
+                }
+            }
+        }
+        pending
+            .created_coins
+            .retain(|coin| !pending.spent_coins.contains(&coin.utxo_id));
+        pending
+    }
+
     /// The number of pending transaction in the pool.
     pub fn pending_number(&self) -> usize {
         self.by_hash.len()
@@ -325,6 +542,33 @@ where
             tx_status_sender.send_complete(tx_id, height, result);
             self.remove_committed_tx(&tx_id);
         }
+        self.remove_cancelled_message_spenders(tx_status_sender);
+    }
+
+    /// Removes the transactions spending the messages cancelled by the DA layer
+    /// since their insertion, with their dependents.
+    fn remove_cancelled_message_spenders(&mut self, tx_status_sender: &TxStatusChange) {
+        let spenders = match self
+            .by_dependency
+            .cancelled_message_spenders(&self.database)
+        {
+            Ok(spenders) => spenders,
+            Err(e) => {
+                tracing::warn!("Failed to check the cancellations of the messages: {e}");
+                return
+            }
+        };
+        let mut removed = 0;
+        for (nonce, tx_id) in spenders {
+            removed += self
+                .remove_with_reason(
+                    tx_status_sender,
+                    &tx_id,
+                    Error::NotInsertedInputMessageCancelled(nonce),
+                )
+                .len();
+        }
+        self.record_eviction("cancelled", removed);
     }
 
     /// remove transaction from pool needed on user demand. Low priority
@@ -342,6 +586,55 @@ where
         removed
     }
 
+    /// Removes the transaction and its dependents from the pool
+    /// and notifies their subscribers about the `reason`.
+    pub fn remove_with_reason(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        tx_id: &TxId,
+        reason: Error,
+    ) -> Vec<ArcPoolTx> {
+        let removed = self.remove_by_tx_id(tx_id);
+        for tx in removed.iter() {
+            tx_status_sender.send_squeezed_out(tx.id(), reason.clone());
+        }
+        removed
+    }
+
+    /// Takes the snapshot of the pooled transactions to re-validate them by the
+    /// [`find_invalidated`] without holding the lock of the pool.
+    pub fn revalidation_snapshot(&self) -> Vec<RevalidatedTx> {
+        self.by_hash
+            .values()
+            .map(|info| RevalidatedTx {
+                tx: info.tx().clone(),
+                database_inputs: self.by_dependency.database_inputs(info.tx()),
+            })
+            .collect()
+    }
+
+    /// Removes the `invalidated` transactions with their dependents, so the block
+    /// producer doesn't select them. The transactions already removed from the pool
+    /// since the snapshot are skipped.
+    pub fn remove_invalidated(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        invalidated: Vec<(TxId, Error)>,
+    ) -> Vec<ArcPoolTx> {
+        let mut result = vec![];
+        for (tx_id, error) in invalidated {
+            let removed = self.remove_with_reason(
+                tx_status_sender,
+                &tx_id,
+                Error::Invalidated(error.to_string()),
+            );
+            result.extend(removed.into_iter());
+        }
+        self.record_eviction("invalidated", result.len());
+
+        result
+    }
+
     /// Remove all old transactions from the pool.
     pub fn prune_old_txs(&mut self) -> Vec<ArcPoolTx> {
         let deadline = tokio::time::Instant::now() - self.config.transaction_ttl;
@@ -357,11 +650,53 @@ where
                 break
             }
         }
+        self.record_eviction("ttl", result.len());
 
         result
     }
 }
 
+/// The pooled transaction with its inputs taken from the database at the time of
+/// the [`TxPool::revalidation_snapshot`].
+pub struct RevalidatedTx {
+    tx: ArcPoolTx,
+    database_inputs: Vec<Input>,
+}
+
+/// Re-validates the `snapshot` against the gas price required by the `config` and the
+/// latest state of the database. Returns the transactions invalidated by the recent
+/// blocks, like the ones spending the coins already spent by other transactions.
+pub fn find_invalidated(
+    snapshot: &[RevalidatedTx],
+    db: &dyn TxPoolDb,
+    config: &Config,
+) -> Vec<(TxId, Error)> {
+    let required_gas_price = config.required_gas_price();
+    snapshot
+        .iter()
+        .filter_map(|revalidated| {
+            let tx = &revalidated.tx;
+            if tx.price() < required_gas_price {
+                return Some((tx.id(), Error::NotInsertedGasPriceTooLow))
+            }
+            let result = Dependency::check_database_inputs(
+                db,
+                config.utxo_validation,
+                &revalidated.database_inputs,
+            );
+            match result.map_err(|e| e.downcast::<Error>()) {
+                Ok(()) => None,
+                Err(Ok(error)) => Some((tx.id(), error)),
+                Err(Err(e)) => {
+                    // The failure of the database doesn't invalidate the transaction.
+                    tracing::warn!("Failed to re-validate the transaction: {e}");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
 pub async fn check_transactions(
     txs: &[Arc<Transaction>],
     current_height: BlockHeight,
@@ -381,6 +716,18 @@ pub async fn check_single_tx(
     tx: Transaction,
     current_height: BlockHeight,
     config: &Config,
+) -> anyhow::Result<Checked<Transaction>> {
+    let result = verify_single_tx(tx, current_height, config).await;
+    if let Err(e) = &result {
+        record_rejection(config, e);
+    }
+    result
+}
+
+async fn verify_single_tx(
+    tx: Transaction,
+    current_height: BlockHeight,
+    config: &Config,
 ) -> anyhow::Result<Checked<Transaction>> {
     if tx.is_mint() {
         return Err(Error::NotSupportedTransactionType.into())
@@ -388,6 +735,20 @@ pub async fn check_single_tx(
 
     verify_tx_min_gas_price(&tx, config)?;
 
+    // The policy may run the WASM module, so it is checked on the blocking thread.
+    let tx = match config.admission_policy.clone() {
+        Some(policy) => {
+            let (tx, result) = tokio::task::spawn_blocking(move || {
+                let result = policy.check(&tx);
+                (tx, result)
+            })
+            .await?;
+            result.map_err(|e| Error::NotInsertedRejectedByPolicy(e.to_string()))?;
+            tx
+        }
+        None => tx,
+    };
+
     let tx: Checked<Transaction> = if config.utxo_validation {
         let consensus_params = &config.chain_config.consensus_parameters;
 
@@ -395,7 +756,7 @@ pub async fn check_single_tx(
             .into_checked_basic(current_height, consensus_params)
             .map_err(|e| anyhow::anyhow!("{e:?}"))?
             .check_signatures(&consensus_params.chain_id)
-            .map_err(|e| anyhow::anyhow!("{e:?}"))?;
+            .map_err(|e| InvalidSignature(format!("{e:?}")))?;
 
         let tx = tx
             .check_predicates_async::<TokioWithRayon>(&CheckPredicateParams::from(
@@ -427,12 +788,66 @@ fn verify_tx_min_gas_price(tx: &Transaction, config: &Config) -> Result<(), Erro
         // price if there is no minimum gas price
         txpool_metrics().gas_price_histogram.observe(price as f64);
     }
-    if price < config.min_gas_price {
+    if price < config.required_gas_price() {
         return Err(Error::NotInsertedGasPriceTooLow)
     }
     Ok(())
 }
 
+/// The signatures of the transaction don't match its inputs.
+#[derive(Debug)]
+struct InvalidSignature(String);
+
+impl std::fmt::Display for InvalidSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for InvalidSignature {}
+
+fn record_rejection(config: &Config, error: &anyhow::Error) {
+    if config.metrics {
+        txpool_metrics().record_rejection(rejection_reason(error));
+    }
+}
+
+/// Returns the reason of the rejection of the transaction reported by the metrics.
+fn rejection_reason(error: &anyhow::Error) -> &'static str {
+    if error.is::<InvalidSignature>() {
+        return "invalid_signature"
+    }
+    let Some(error) = error.downcast_ref::<Error>() else {
+        return "invalid"
+    };
+    match error {
+        Error::NotInsertedGasPriceTooLow
+        | Error::NotInsertedLimitHit
+        | Error::NotInsertedContractPricedLower(_)
+        | Error::NotInsertedSponsorFeeNotCovered { .. } => "insufficient_fee",
+        Error::NotInsertedOutputNotExisting(_)
+        | Error::NotInsertedInputContractNotExisting(_)
+        | Error::NotInsertedInputUtxoIdNotExisting(_)
+        | Error::NotInsertedInputMessageUnknown(_) => "dependency_missing",
+        Error::NotInsertedCollision(_, _)
+        | Error::NotInsertedCollisionContractId(_)
+        | Error::NotInsertedCollisionMessageId(_, _)
+        | Error::NotInsertedContractIdAlreadyTaken(_)
+        | Error::NotInsertedInputUtxoIdSpent(_)
+        | Error::NotInsertedInputMessageSpent(_)
+        | Error::NotInsertedInputMessageCancelled(_) => "conflict",
+        Error::NotInsertedRejectedByPolicy(_) => "blacklisted",
+        Error::NotInsertedTxKnown => "known",
+        Error::NotInsertedMaxDepth | Error::NotInsertedMaxGasLimit { .. } => "limit",
+        Error::NotInsertedSponsorRateLimit(_) => "rate_limit",
+        Error::NotInsertedNodeSyncing { .. }
+        | Error::NotInsertedInsertionsPaused
+        | Error::NotInsertedReadOnly
+        | Error::NotInsertedResourceBudgetExhausted(_) => "overloaded",
+        _ => "invalid",
+    }
+}
+
 pub struct TokioWithRayon;
 
 #[async_trait::async_trait]
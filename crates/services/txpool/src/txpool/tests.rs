@@ -1,5 +1,10 @@
 use crate::{
-    ports::TxPoolDb,
+    ports::{
+        AdmissionPolicy,
+        GasPriceProvider,
+        TxPoolDb,
+    },
+    service::TxStatusChange,
     test_helpers::{
         add_coin_to_state,
         create_output_and_input,
@@ -9,17 +14,26 @@ use crate::{
         IntoEstimated,
         TEST_COIN_AMOUNT,
     },
-    txpool::test_helpers::{
-        create_coin_output,
-        create_contract_input,
-        create_contract_output,
-        create_message_predicate_from_message,
+    txpool::{
+        find_invalidated,
+        test_helpers::{
+            create_coin_output,
+            create_contract_input,
+            create_contract_output,
+            create_message_predicate_from_message,
+        },
     },
     Config,
     Error,
+    Limits,
     MockDb,
     TxPool,
 };
+use fuel_core_services::{
+    Resource,
+    ResourceBudget,
+    ResourceLimits,
+};
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -34,6 +48,7 @@ use fuel_core_types::{
         input::coin::CoinPredicate,
         Address,
         AssetId,
+        Chargeable,
         Contract,
         Input,
         Output,
@@ -44,15 +59,21 @@ use fuel_core_types::{
     },
     fuel_types::ChainId,
     fuel_vm::checked_transaction::Checked,
+    services::txpool::ArcPoolTx,
 };
 
 use std::{
     cmp::Reverse,
     collections::HashMap,
+    sync::Arc,
     vec,
 };
 
-use super::check_single_tx;
+use super::{
+    check_single_tx,
+    rejection_reason,
+    InvalidSignature,
+};
 
 const GAS_LIMIT: Word = 1000;
 
@@ -278,6 +299,33 @@ async fn try_to_insert_tx2_missing_utxo() {
     ));
 }
 
+#[tokio::test]
+async fn rejections_are_classified_by_the_reason() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut txpool = TxPool::new(Default::default(), MockDb::default());
+
+    let (_, input) = setup_coin(&mut rng, None);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+    let tx = check_unwrap_tx(tx, txpool.database.clone(), &txpool.config).await;
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert_eq!(rejection_reason(&err), "dependency_missing");
+
+    let err = Error::NotInsertedGasPriceTooLow.into();
+    assert_eq!(rejection_reason(&err), "insufficient_fee");
+    let err = Error::NotInsertedRejectedByPolicy("denied".to_string()).into();
+    assert_eq!(rejection_reason(&err), "blacklisted");
+    let err = InvalidSignature("mismatch".to_string()).into();
+    assert_eq!(rejection_reason(&err), "invalid_signature");
+    let err = anyhow::anyhow!("malformed");
+    assert_eq!(rejection_reason(&err), "invalid");
+}
+
 #[tokio::test]
 async fn higher_priced_tx_removes_lower_priced_tx() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -577,6 +625,198 @@ async fn tx_limit_hit() {
     ));
 }
 
+#[tokio::test]
+async fn tx_rejected_when_memory_budget_is_exhausted() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let budget = ResourceBudget::new(ResourceLimits {
+        memory: Some(1),
+        ..Default::default()
+    });
+    let mut txpool = TxPool::new(
+        Config {
+            resource_budget: budget.clone(),
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedResourceBudgetExhausted(_))
+    ));
+    assert_eq!(budget.usage(Resource::Memory).used, 0);
+}
+
+#[tokio::test]
+async fn replacing_tx_takes_the_memory_of_the_replaced_tx() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .finalize_as_transaction();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(11)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let config = Config::default();
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &config).await;
+
+    // The budget fits only one of the transactions of the same size.
+    let size = {
+        let budget = ResourceBudget::default();
+        let mut txpool = TxPool::new(
+            Config {
+                resource_budget: budget.clone(),
+                ..Default::default()
+            },
+            db.clone(),
+        );
+        txpool.insert_inner(tx1.clone()).expect("Tx1 should be Ok");
+        budget.usage(Resource::Memory).used
+    };
+    let budget = ResourceBudget::new(ResourceLimits {
+        memory: Some(size),
+        ..Default::default()
+    });
+    let mut txpool = TxPool::new(
+        Config {
+            resource_budget: budget.clone(),
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    let replaced = txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+    assert_eq!(replaced.removed.len(), 1);
+    assert_eq!(budget.usage(Resource::Memory).used, size);
+}
+
+#[tokio::test]
+async fn tx_memory_is_returned_to_the_budget_on_removal() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let budget = ResourceBudget::new(ResourceLimits {
+        memory: Some(1024 * 1024),
+        ..Default::default()
+    });
+    let mut txpool = TxPool::new(
+        Config {
+            resource_budget: budget.clone(),
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx).expect("Tx should be Ok, got Err");
+    assert!(budget.usage(Resource::Memory).used > 0);
+
+    txpool.remove_by_tx_id(&tx_id);
+    assert_eq!(budget.usage(Resource::Memory).used, 0);
+}
+
+#[tokio::test]
+async fn tx_limit_updated_after_hit() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(
+        Config {
+            max_tx: 1,
+            ..Default::default()
+        },
+        db.clone(),
+    );
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(create_coin_output())
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be Ok, got Err");
+    txpool
+        .insert_inner(tx2.clone())
+        .expect_err("Tx2 should be Err, got Ok");
+
+    txpool.set_limits(Limits {
+        max_tx: 2,
+        ..txpool.limits()
+    });
+
+    txpool.insert_inner(tx2).expect("Tx2 should be Ok, got Err");
+}
+
+#[tokio::test]
+async fn tx_depth_hit_after_update() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Config::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let (output, unset_input) = create_output_and_input(&mut rng, 10_000);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+
+    txpool.set_limits(Limits {
+        max_depth: 1,
+        ..txpool.limits()
+    });
+
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Tx2 should be Err, got Ok");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedMaxDepth)
+    ));
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut rng = StdRng::seed_from_u64(0);
@@ -783,6 +1023,107 @@ async fn tx_below_min_gas_price_is_not_insertable() {
     ));
 }
 
+/// Provides the fixed gas price of the next block.
+#[derive(Debug)]
+struct FixedGasPrice(Word);
+
+impl GasPriceProvider for FixedGasPrice {
+    fn gas_price(&self) -> Word {
+        self.0
+    }
+}
+
+#[tokio::test]
+async fn tx_below_gas_price_of_next_block_is_not_insertable() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    // The gas price grows after the check of the transaction.
+    let tx = check_unwrap_tx(tx, db.clone(), &Default::default()).await;
+    let mut txpool = TxPool::new(
+        Config {
+            gas_price_provider: Some(Arc::new(FixedGasPrice(11))),
+            ..Default::default()
+        },
+        db,
+    );
+
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("expected insertion failure");
+
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedGasPriceTooLow
+    ));
+}
+
+/// Rejects the transactions with the gas price below the limit.
+#[derive(Debug)]
+struct MinGasPricePolicy(Word);
+
+impl AdmissionPolicy for MinGasPricePolicy {
+    fn check(&self, tx: &Transaction) -> anyhow::Result<()> {
+        match tx {
+            Transaction::Script(script) if script.price() < self.0 => {
+                Err(anyhow::anyhow!("The gas price is below {}", self.0))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[tokio::test]
+async fn tx_rejected_by_admission_policy() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let config = Config {
+        admission_policy: Some(Arc::new(MinGasPricePolicy(11))),
+        ..Default::default()
+    };
+
+    let err = check_tx(tx, db, &config)
+        .await
+        .expect_err("expected insertion failure");
+
+    assert!(matches!(
+        err.root_cause().downcast_ref::<Error>().unwrap(),
+        Error::NotInsertedRejectedByPolicy(_)
+    ));
+}
+
+#[tokio::test]
+async fn tx_accepted_by_admission_policy() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&db));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(11)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let config = Config {
+        admission_policy: Some(Arc::new(MinGasPricePolicy(11))),
+        ..Default::default()
+    };
+
+    check_unwrap_tx(tx, db, &config).await;
+}
+
 #[tokio::test]
 async fn tx_inserted_into_pool_when_input_message_id_exists_in_db() {
     let (message, input) = create_message_predicate_from_message(5000, 0);
@@ -829,6 +1170,53 @@ async fn tx_rejected_when_input_message_id_is_spent() {
     ));
 }
 
+#[tokio::test]
+async fn tx_rejected_when_input_message_id_is_cancelled() {
+    let (message, input) = create_message_predicate_from_message(5_000, 0);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let db = MockDb::default();
+    db.insert_message(message.clone());
+    db.cancel_message(*message.id());
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    let err = txpool.insert_inner(tx).expect_err("should fail");
+
+    // check error
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedInputMessageCancelled(msg_id)) if msg_id == message.id()
+    ));
+}
+
+#[tokio::test]
+async fn block_update_removes_txs_spending_cancelled_messages() {
+    let (message, input) = create_message_predicate_from_message(5_000, 0);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .finalize_as_transaction();
+
+    let db = MockDb::default();
+    db.insert_message(message.clone());
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10);
+
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be OK, got Err");
+
+    db.cancel_message(*message.id());
+    txpool.block_update(&tx_status_sender, &1u32.into(), &[]);
+
+    assert!(txpool.txs().is_empty());
+}
+
 #[tokio::test]
 async fn tx_rejected_from_pool_when_input_message_id_does_not_exist_in_db() {
     let (message, input) = create_message_predicate_from_message(5000, 0);
@@ -1080,3 +1468,258 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+fn sponsored_tx(rng: &mut StdRng, db: &MockDb, sponsor_coin: Input) -> Transaction {
+    let (_, user_coin) = setup_coin(rng, Some(db));
+    let (_, sponsor_coin) = add_coin_to_state(sponsor_coin, Some(db));
+    TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(user_coin)
+        .add_input(sponsor_coin)
+        .finalize_as_transaction()
+}
+
+fn sponsorship_config(sponsor: Address, max_txs: usize) -> Config {
+    let mut config = Config::default();
+    config.sponsorship.sponsors.insert(sponsor, max_txs);
+    config
+}
+
+#[tokio::test]
+async fn sponsored_tx_rejected_when_sponsor_does_not_cover_fee() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let sponsor_coin =
+        random_predicate(&mut rng, AssetId::from([1; 32]), TEST_COIN_AMOUNT, None);
+    let sponsor = *sponsor_coin.input_owner().unwrap();
+    let mut txpool = TxPool::new(sponsorship_config(sponsor, 10), db.clone());
+
+    let tx = sponsored_tx(&mut rng, &db, sponsor_coin);
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+
+    let err = txpool
+        .insert_inner(tx)
+        .expect_err("Sponsor doesn't pay the fee");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedSponsorFeeNotCovered { covered: 0, .. })
+    ));
+}
+
+#[tokio::test(start_paused = true)]
+async fn sponsored_txs_are_rate_limited_per_sponsor() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let sponsor_coin = |rng: &mut StdRng| {
+        let mut coin = random_predicate(rng, AssetId::BASE, TEST_COIN_AMOUNT, None);
+        if let Input::CoinPredicate(CoinPredicate {
+            owner, predicate, ..
+        }) = &mut coin
+        {
+            *predicate = vec![op::ret(RegId::ONE)].into_iter().collect();
+            *owner = Input::predicate_owner(predicate, &ChainId::default());
+        }
+        coin
+    };
+    let sponsor = *sponsor_coin(&mut rng).input_owner().unwrap();
+    let mut txpool = TxPool::new(sponsorship_config(sponsor, 1), db.clone());
+    let period = txpool.config.sponsorship.period;
+
+    let tx1 = sponsored_tx(&mut rng, &db, sponsor_coin(&mut rng));
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = sponsored_tx(&mut rng, &db, sponsor_coin(&mut rng));
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = sponsored_tx(&mut rng, &db, sponsor_coin(&mut rng));
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+
+    txpool.insert_inner(tx1).expect("Tx1 should be OK");
+    let err = txpool
+        .insert_inner(tx2)
+        .expect_err("Sponsor hit the rate limit");
+    assert!(matches!(
+        err.downcast_ref::<Error>(),
+        Some(Error::NotInsertedSponsorRateLimit(owner)) if owner == &sponsor
+    ));
+
+    tokio::time::advance(period).await;
+    txpool.insert_inner(tx3).expect("Tx3 should be OK");
+}
+
+#[tokio::test]
+async fn pending_coins_exclude_the_spent_and_include_the_created_coins() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let sender = *gas_coin.input_owner().unwrap();
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let recipient = *output.to().unwrap();
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin.clone())
+        .add_output(output)
+        .finalize_as_transaction();
+    let created = UtxoId::new(tx1.id(&Default::default()), 0);
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+
+    let pending = txpool.pending_coins(&sender);
+    assert_eq!(
+        pending.spent_coins.into_iter().collect::<Vec<_>>(),
+        vec![*gas_coin.utxo_id().unwrap()]
+    );
+    assert!(pending.created_coins.is_empty());
+
+    let pending = txpool.pending_coins(&recipient);
+    assert!(pending.spent_coins.is_empty());
+    assert_eq!(pending.created_coins.len(), 1);
+    assert_eq!(pending.created_coins[0].utxo_id, created);
+    assert_eq!(pending.created_coins[0].amount, 1);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(unset_input.into_input(created))
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+
+    // The created coin is spent by the dependent transaction.
+    let pending = txpool.pending_coins(&recipient);
+    assert!(pending.spent_coins.contains(&created));
+    assert!(pending.created_coins.is_empty());
+
+    // The removed transactions are removed from the index of the owners.
+    txpool.remove_by_tx_id(created.tx_id());
+    assert_eq!(txpool.pending_coins(&sender), Default::default());
+    assert_eq!(txpool.pending_coins(&recipient), Default::default());
+    assert!(txpool.by_owner.txs(&sender).next().is_none());
+    assert!(txpool.by_owner.txs(&recipient).next().is_none());
+}
+
+fn revalidate(
+    txpool: &mut TxPool<MockDb>,
+    tx_status_sender: &TxStatusChange,
+) -> Vec<ArcPoolTx> {
+    let snapshot = txpool.revalidation_snapshot();
+    let invalidated = find_invalidated(&snapshot, &txpool.database, &txpool.config);
+    txpool.remove_invalidated(tx_status_sender, invalidated)
+}
+
+#[tokio::test]
+async fn revalidation_removes_txs_spending_the_spent_coins() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let spent = *gas_coin.utxo_id().unwrap();
+    let (output, unset_input) = create_output_and_input(&mut rng, 1);
+    let tx1 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&Default::default()), 0));
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(input)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx3 = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx3_id = tx3.id(&Default::default());
+
+    let tx1 = check_unwrap_tx(tx1, db.clone(), &txpool.config).await;
+    let tx2 = check_unwrap_tx(tx2, db.clone(), &txpool.config).await;
+    let tx3 = check_unwrap_tx(tx3, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx1).expect("Tx1 should be OK, got Err");
+    txpool.insert_inner(tx2).expect("Tx2 should be OK, got Err");
+    txpool.insert_inner(tx3).expect("Tx3 should be OK, got Err");
+
+    assert!(revalidate(&mut txpool, &tx_status_sender).is_empty());
+
+    // The coin is spent by the transaction of another node.
+    db.data.lock().unwrap().coins.remove(&spent);
+    let removed = revalidate(&mut txpool, &tx_status_sender);
+
+    assert_eq!(
+        removed.len(),
+        2,
+        "Tx1 and its dependent Tx2 should be removed"
+    );
+    assert_eq!(txpool.txs().len(), 1);
+    assert!(txpool.txs().contains_key(&tx3_id));
+}
+
+#[tokio::test]
+async fn revalidation_removes_txs_below_gas_price_of_next_block() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(10)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be OK, got Err");
+
+    txpool.config.gas_price_provider = Some(Arc::new(FixedGasPrice(10)));
+    assert!(revalidate(&mut txpool, &tx_status_sender).is_empty());
+
+    txpool.config.gas_price_provider = Some(Arc::new(FixedGasPrice(11)));
+    let removed = revalidate(&mut txpool, &tx_status_sender);
+
+    assert_eq!(removed.len(), 1);
+    assert!(txpool.txs().is_empty());
+}
+
+#[tokio::test]
+async fn revalidation_skips_txs_removed_after_snapshot() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let db = MockDb::default();
+    let mut txpool = TxPool::new(Default::default(), db.clone());
+    let tx_status_sender = TxStatusChange::new(10);
+
+    let (_, gas_coin) = setup_coin(&mut rng, Some(&txpool.database));
+    let spent = *gas_coin.utxo_id().unwrap();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx_id = tx.id(&Default::default());
+    let tx = check_unwrap_tx(tx, db.clone(), &txpool.config).await;
+    txpool.insert_inner(tx).expect("Tx should be OK, got Err");
+
+    let snapshot = txpool.revalidation_snapshot();
+    db.data.lock().unwrap().coins.remove(&spent);
+    let invalidated = find_invalidated(&snapshot, &txpool.database, &txpool.config);
+    assert_eq!(invalidated.len(), 1);
+
+    // The transaction is included into the block while the snapshot is re-validated.
+    txpool.remove_by_tx_id(&tx_id);
+    let removed = txpool.remove_invalidated(&tx_status_sender, invalidated);
+
+    assert!(removed.is_empty());
+}
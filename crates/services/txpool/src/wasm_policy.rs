@@ -0,0 +1,228 @@
+//! The admission policy implemented by the WASM module of the operator.
+//!
+//! The module doesn't have any imports and exports:
+//! - `memory` - the linear memory of the module.
+//! - `alloc(len: i32) -> i32` - allocates `len` bytes for the transaction and returns
+//!   the pointer to them.
+//! - `check(ptr: i32, len: i32) -> i32` - checks the canonically serialized transaction
+//!   at `ptr`. It returns `0` if the transaction is allowed and the non-zero code of the
+//!   rejection otherwise.
+//!
+//! Each check runs in a new instance of the module, so the checks don't share any state.
+//! The fuel and the memory limits stop the module that takes too many resources, and
+//! the transaction is rejected in this case.
+
+use crate::ports::AdmissionPolicy;
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::Serialize,
+};
+use std::{
+    fmt,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+use wasmtime::{
+    Engine,
+    Instance,
+    Module,
+    Store,
+    StoreLimits,
+    StoreLimitsBuilder,
+};
+
+/// The resource limits of one check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmPolicyLimits {
+    /// The max amount of the fuel consumed by the module. Roughly one unit
+    /// of the fuel is consumed per WASM instruction.
+    pub fuel: u64,
+    /// The max size of the linear memory of the module in bytes.
+    pub memory_bytes: usize,
+}
+
+impl Default for WasmPolicyLimits {
+    fn default() -> Self {
+        Self {
+            fuel: 10_000_000,
+            memory_bytes: 16 * 1024 * 1024,
+        }
+    }
+}
+
+pub struct WasmPolicy {
+    path: PathBuf,
+    engine: Engine,
+    module: Module,
+    limits: WasmPolicyLimits,
+}
+
+impl WasmPolicy {
+    /// Compiles the module at the `path`. The module is validated during the loading,
+    /// so the node fails to start with the broken policy.
+    pub fn load(path: &Path, limits: WasmPolicyLimits) -> anyhow::Result<Self> {
+        let engine = Self::engine()?;
+        let module = Module::from_file(&engine, path).with_context(|| {
+            format!("failed to load the admission policy {}", path.display())
+        })?;
+        Self::new(path.to_path_buf(), engine, module, limits)
+    }
+
+    fn engine() -> anyhow::Result<Engine> {
+        let mut config = wasmtime::Config::new();
+        config.consume_fuel(true);
+        Engine::new(&config)
+    }
+
+    fn new(
+        path: PathBuf,
+        engine: Engine,
+        module: Module,
+        limits: WasmPolicyLimits,
+    ) -> anyhow::Result<Self> {
+        if let Some(import) = module.imports().next() {
+            return Err(anyhow!(
+                "The admission policy can't import `{}::{}`",
+                import.module(),
+                import.name()
+            ))
+        }
+        Ok(Self {
+            path,
+            engine,
+            module,
+            limits,
+        })
+    }
+
+    /// Runs the `check` of the module on the `tx` and returns the code of the result.
+    fn run(&self, tx: &Transaction) -> anyhow::Result<i32> {
+        let bytes = tx.to_bytes();
+        let len = i32::try_from(bytes.len())?;
+
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.memory_bytes)
+            .instances(1)
+            .build();
+        let mut store: Store<StoreLimits> = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.add_fuel(self.limits.fuel)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| anyhow!("The admission policy doesn't export `memory`"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+        let check = instance.get_typed_func::<(i32, i32), i32>(&mut store, "check")?;
+
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, usize::try_from(ptr)?, &bytes)?;
+        check.call(&mut store, (ptr, len))
+    }
+}
+
+impl AdmissionPolicy for WasmPolicy {
+    fn check(&self, tx: &Transaction) -> anyhow::Result<()> {
+        match self.run(tx) {
+            Ok(0) => Ok(()),
+            Ok(code) => Err(anyhow!("Rejected with the code {code}")),
+            Err(e) => Err(anyhow!("The admission policy failed: {e}")),
+        }
+    }
+}
+
+impl fmt::Debug for WasmPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmPolicy")
+            .field("path", &self.path)
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    /// Rejects the transactions with more than `max_len` bytes with the code `1`.
+    fn max_len_policy(max_len: i32) -> String {
+        format!(
+            r#"(module
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) i32.const 0)
+                (func (export "check") (param i32 i32) (result i32)
+                    local.get 1
+                    i32.const {max_len}
+                    i32.gt_s))"#
+        )
+    }
+
+    const INFINITE_LOOP_POLICY: &str = r#"(module
+        (memory (export "memory") 1)
+        (func (export "alloc") (param i32) (result i32) i32.const 0)
+        (func (export "check") (param i32 i32) (result i32)
+            (loop br 0)
+            i32.const 0))"#;
+
+    fn policy(wat: &str, limits: WasmPolicyLimits) -> WasmPolicy {
+        let engine = WasmPolicy::engine().unwrap();
+        let module = Module::new(&engine, wat).unwrap();
+        WasmPolicy::new(PathBuf::new(), engine, module, limits).unwrap()
+    }
+
+    fn tx() -> Transaction {
+        TransactionBuilder::script(vec![], vec![]).finalize_as_transaction()
+    }
+
+    #[test]
+    fn policy_accepts_and_rejects_by_the_code() {
+        let len = tx().to_bytes().len() as i32;
+
+        let accepting = policy(&max_len_policy(len), Default::default());
+        let rejecting = policy(&max_len_policy(len - 1), Default::default());
+
+        assert!(accepting.check(&tx()).is_ok());
+        assert!(rejecting.check(&tx()).is_err());
+    }
+
+    #[test]
+    fn policy_out_of_fuel_rejects_transaction() {
+        let limits = WasmPolicyLimits {
+            fuel: 1000,
+            ..Default::default()
+        };
+        let policy = policy(INFINITE_LOOP_POLICY, limits);
+
+        assert!(policy.check(&tx()).is_err());
+    }
+
+    #[test]
+    fn policy_over_memory_limit_rejects_transaction() {
+        let limits = WasmPolicyLimits {
+            // Less than one page of the WASM memory.
+            memory_bytes: 1024,
+            ..Default::default()
+        };
+        let policy = policy(&max_len_policy(i32::MAX), limits);
+
+        assert!(policy.check(&tx()).is_err());
+    }
+
+    #[test]
+    fn policy_with_imports_is_rejected() {
+        let engine = WasmPolicy::engine().unwrap();
+        let wat = r#"(module (import "env" "now" (func)))"#;
+        let module = Module::new(&engine, wat).unwrap();
+
+        assert!(
+            WasmPolicy::new(PathBuf::new(), engine, module, Default::default()).is_err()
+        );
+    }
+}
@@ -11,7 +11,11 @@ use fuel_core_types::{
     entities::{
         coins::coin::CompressedCoin,
         contract::ContractUtxoInfo,
-        message::Message,
+        message::{
+            Message,
+            MessageCancellation,
+            MessageDeposit,
+        },
     },
     fuel_tx::{
         Receipt,
@@ -101,6 +105,28 @@ impl Mappable for Messages {
     type OwnedValue = Message;
 }
 
+/// The storage table of the deposits of the bridged Ethereum [`Message`](crate::model::Message)s.
+/// Unlike the [`Messages`], the deposit isn't removed when the message is spent.
+pub struct MessageDeposits;
+
+impl Mappable for MessageDeposits {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Nonce;
+    type Value = Self::OwnedValue;
+    type OwnedValue = MessageDeposit;
+}
+
+/// The storage table of the cancellations of the bridged Ethereum
+/// [`Message`](crate::model::Message)s by the Da layer.
+pub struct MessageCancellations;
+
+impl Mappable for MessageCancellations {
+    type Key = Self::OwnedKey;
+    type OwnedKey = Nonce;
+    type Value = Self::OwnedValue;
+    type OwnedValue = MessageCancellation;
+}
+
 /// The storage table that indicates if the [`Message`](crate::model::Message) is spent or not.
 pub struct SpentMessages;
 
@@ -16,7 +16,10 @@ use crate::{
 // Different types of consensus are represented as separate modules
 pub mod poa;
 
-use poa::PoAConsensus;
+use poa::{
+    PoAConsensus,
+    PoAMultiSigConsensus,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,10 +30,13 @@ pub enum Consensus {
     Genesis(Genesis),
     /// Proof of authority consensus
     PoA(PoAConsensus),
+    /// Proof of authority consensus signed by the k-of-n authorized keys
+    PoAMultiSig(PoAMultiSigConsensus),
 }
 
 impl Consensus {
-    /// Retrieve the block producer address from the consensus data
+    /// Retrieve the block producer address from the consensus data.
+    /// The producer of the multi-signature block is its first signer.
     pub fn block_producer(&self, block_id: &BlockId) -> anyhow::Result<Address> {
         match &self {
             Consensus::Genesis(_) => Ok(Address::zeroed()),
@@ -39,6 +45,14 @@ impl Consensus {
                 let address = Input::owner(&public_key);
                 Ok(address)
             }
+            Consensus::PoAMultiSig(multisig_data) => {
+                let signature = multisig_data
+                    .signatures
+                    .first()
+                    .ok_or_else(|| anyhow::anyhow!("The block has no signatures"))?;
+                let public_key = signature.recover(block_id.as_message())?;
+                Ok(Input::owner(&public_key))
+            }
         }
     }
 }
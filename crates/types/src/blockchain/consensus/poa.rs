@@ -17,3 +17,19 @@ impl PoAConsensus {
         Self { signature }
     }
 }
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The multi-signature seal of the [`FuelBlockHeader`] by the k-of-n authorized keys.
+pub struct PoAMultiSigConsensus {
+    /// The signatures of the [`FuelBlockHeader`], ordered by the addresses of
+    /// their signers, so each signer signs only once.
+    pub signatures: Vec<Signature>,
+}
+
+impl PoAMultiSigConsensus {
+    /// Create a new multi-signature block consensus.
+    pub fn new(signatures: Vec<Signature>) -> Self {
+        Self { signatures }
+    }
+}
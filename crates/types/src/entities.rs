@@ -3,9 +3,11 @@
 use coins::message_coin::MessageCoin;
 use message::Message;
 
+pub mod asset;
 pub mod coins;
 pub mod contract;
 pub mod message;
+pub mod receipt;
 
 impl TryFrom<Message> for MessageCoin {
     type Error = anyhow::Error;
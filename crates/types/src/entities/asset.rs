@@ -0,0 +1,26 @@
+//! Asset entities
+
+use crate::fuel_types::{
+    AssetId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+/// The metadata of the asset issued by the contract. It is recorded from the
+/// mint and burn receipts of the successful transactions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetMetadata {
+    /// The id of the asset.
+    pub asset_id: AssetId,
+    /// The contract that issued the asset.
+    pub contract_id: ContractId,
+    /// The sub identifier of the asset in the contract.
+    pub sub_id: Bytes32,
+    /// The amount minted minus the amount burned.
+    pub total_supply: Word,
+    /// The height of the block with the first issuance of the asset.
+    pub first_issued_at: BlockHeight,
+}
@@ -9,6 +9,7 @@ use crate::{
     fuel_tx::input::message::compute_message_id,
     fuel_types::{
         Address,
+        Bytes32,
         MessageId,
         Nonce,
         Word,
@@ -51,6 +52,28 @@ impl Message {
     }
 }
 
+/// The record of the message sent from the Da layer. Unlike the [`Message`],
+/// it is kept after the message is spent to track the deposit.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MessageDeposit {
+    /// The hash of the da transaction that sent the message
+    pub da_transaction: Bytes32,
+    /// The block height from the parent da layer that originated this message
+    pub da_height: DaBlockHeight,
+}
+
+/// The record of the cancellation of the message by the Da layer. The cancelled message
+/// isn't spendable by the blocks with the da height at or above the `da_height`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MessageCancellation {
+    /// The hash of the da transaction that cancelled the message
+    pub da_transaction: Bytes32,
+    /// The block height from the parent da layer that cancelled the message
+    pub da_height: DaBlockHeight,
+}
+
 /// Type containing merkle proof data.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct MerkleProof {
@@ -136,3 +159,15 @@ pub enum MessageState {
     /// There is no record of this Message
     NotFound,
 }
+
+/// The state of the commitment of the Fuel block on the Da layer. The output
+/// messages of the block can be relayed after the commitment is finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentState {
+    /// The block is not committed yet
+    NotCommitted,
+    /// The block is committed, but the commitment is not finalized
+    Committed,
+    /// The commitment is finalized
+    Finalized,
+}
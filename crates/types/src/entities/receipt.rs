@@ -0,0 +1,35 @@
+//! Receipt
+
+use crate::{
+    blockchain::header::BlockHeader,
+    entities::message::MerkleProof,
+    fuel_tx::{
+        Receipt,
+        Transaction,
+    },
+};
+
+/// Proves that the receipt was produced by the transaction included in a Fuel block.
+/// The receipt is a leaf of the receipts root of the script transaction, and
+/// the transaction is a leaf of the transactions root of the block header.
+#[derive(Debug, Clone)]
+pub struct ReceiptProof {
+    /// Proof that the receipt is contained within the receipts root of the transaction.
+    pub receipt_proof: MerkleProof,
+    /// Proof that the transaction is contained within the transactions root
+    /// of the block header.
+    pub transaction_proof: MerkleProof,
+    /// Proof that the block header is contained within the blockchain history.
+    pub block_proof: MerkleProof,
+    /// The header of the block that contains the transaction. Block height <
+    /// commit block height.
+    pub block_header: BlockHeader,
+    /// The header of the block used as the root of the block proof.
+    pub commit_block_header: BlockHeader,
+    /// The proven receipt.
+    pub receipt: Receipt,
+    /// The number of the receipts of the transaction.
+    pub receipts_count: u64,
+    /// The transaction that produced the receipt.
+    pub transaction: Transaction,
+}
@@ -3,6 +3,7 @@
 use crate::{
     blockchain::{
         header::BlockHeader,
+        primitives::BlockId,
         SealedBlock,
     },
     services::{
@@ -63,6 +64,17 @@ impl ImportResult {
     }
 }
 
+/// The valid block that competes with the canonical block at the same height.
+/// The first valid block imported at the height stays canonical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompetingBlock {
+    /// The id of the canonical block at the height of the competing block.
+    pub canonical: BlockId,
+    /// The competing block.
+    pub sealed_block: SealedBlock,
+}
+
 /// The block import info.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlockImportInfo {
@@ -1,6 +1,10 @@
 //! Types related to block producer service.
 
-use crate::blockchain::header::PartialBlockHeader;
+use crate::{
+    blockchain::header::PartialBlockHeader,
+    fuel_types::BlockHeight,
+};
+use tai64::Tai64;
 
 /// The components required to produce a block.
 #[derive(Debug)]
@@ -13,4 +17,16 @@ pub struct Components<Source> {
     pub transactions_source: Source,
     /// The gas limit of the block.
     pub gas_limit: u64,
+    /// The gas price of the block. The block producer skips the transactions
+    /// paying less than it.
+    pub gas_price: u64,
+}
+
+/// The overrides of the block simulated by the dry run.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DryRunOverrides {
+    /// The height of the simulated block instead of the next height.
+    pub block_height: Option<BlockHeight>,
+    /// The time of the simulated block instead of the current time.
+    pub block_time: Option<Tai64>,
 }
@@ -10,10 +10,14 @@ use crate::{
     },
     fuel_tx::{
         CheckError,
+        Transaction,
         TxId,
         UtxoId,
     },
     fuel_types::{
+        canonical::Serialize,
+        Address,
+        BlockHeight,
         Bytes32,
         ContractId,
         Nonce,
@@ -27,6 +31,13 @@ use crate::{
 };
 use std::error::Error as StdError;
 
+/// The version of the rules used by the executor to transition the state of the chain.
+pub type StateTransitionVersion = u32;
+
+/// The version of the state transition implemented by the executor. It is increased
+/// with every change of the execution rules that affects the state of the chain.
+pub const STATE_TRANSITION_VERSION: StateTransitionVersion = 0;
+
 /// The alias for executor result.
 pub type Result<T> = core::result::Result<T, Error>;
 /// The uncommitted result of the transaction execution.
@@ -241,6 +252,119 @@ impl ExecutionKind {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The share of the collected fees that goes to the `recipient` instead of
+/// the coinbase recipient.
+pub struct FeeShare {
+    /// The address that receives the share.
+    pub recipient: Address,
+    /// The size of the share in basis points, where `10_000` is the whole fee.
+    pub basis_points: u16,
+}
+
+impl FeeShare {
+    /// The number of basis points in the whole fee.
+    pub const MAX_BASIS_POINTS: u16 = 10_000;
+    /// The maximum number of fee shares.
+    pub const MAX_SHARES: usize = 7;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The limits of the block fullness defined by the chain configuration.
+/// The produced blocks are filled up to the limits, and the validated blocks
+/// exceeding them are rejected.
+pub struct BlockLimits {
+    /// The maximum gas used by all transactions of the block.
+    pub gas: u64,
+    /// The maximum size in bytes of all transactions of the block,
+    /// including the coinbase.
+    pub size: u64,
+}
+
+impl Default for BlockLimits {
+    fn default() -> Self {
+        Self {
+            gas: u64::MAX,
+            size: u64::MAX,
+        }
+    }
+}
+
+impl BlockLimits {
+    /// Returns the size of the `transactions` counted against the `size` limit.
+    pub fn size_of<'a>(transactions: impl IntoIterator<Item = &'a Transaction>) -> u64 {
+        transactions
+            .into_iter()
+            .map(|tx| tx.size() as u64)
+            .fold(0, u64::saturating_add)
+    }
+}
+
+/// The profile of the script execution collected during the dry run.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionProfile {
+    /// The gas consumed by each opcode, sorted from the most expensive.
+    pub opcodes: Vec<OpcodeProfile>,
+    /// The gas consumed under each stack of frames. The stack starts with the script
+    /// frame, followed by the called contracts, and ends with the opcode, so the
+    /// stacks can be rendered as a flamegraph.
+    pub stacks: Vec<StackProfile>,
+    /// The highest number of bytes used by the stack, including the call frames.
+    pub stack_high_water_mark: u64,
+    /// The highest number of bytes allocated on the heap.
+    pub heap_high_water_mark: u64,
+}
+
+/// The gas consumed by one opcode.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcodeProfile {
+    /// The name of the opcode.
+    pub opcode: String,
+    /// The number of executed instructions with the opcode.
+    pub count: u64,
+    /// The total gas consumed by the instructions.
+    pub gas: u64,
+}
+
+/// The gas consumed under one stack of frames.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackProfile {
+    /// The frames from the outermost to the innermost one.
+    pub frames: Vec<String>,
+    /// The total gas consumed by the instructions executed with this stack.
+    pub gas: u64,
+}
+
+/// The changes of the state made by the block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The changes ordered by the column and the key.
+    pub changes: Vec<StateChange>,
+}
+
+/// The change of the value in the database.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateChange {
+    /// The name of the column.
+    pub column: String,
+    /// The key of the value in the column.
+    pub key: Vec<u8>,
+    /// The new value, or `None` if the value is removed.
+    pub value: Option<Vec<u8>>,
+    /// The value before the change, or `None` if the value didn't exist.
+    /// It allows restoring the historical state from the latest one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub previous: Option<Vec<u8>>,
+}
+
 #[allow(missing_docs)]
 #[derive(thiserror::Error, Debug)]
 #[non_exhaustive]
@@ -286,12 +410,20 @@ pub enum Error {
     InvalidFeeAmount,
     #[error("Block id is invalid")]
     InvalidBlockId,
+    #[error("The block uses {used} gas, exceeding the limit {limit}")]
+    BlockGasLimitExceeded { used: u64, limit: u64 },
+    #[error("The block size {size} exceeds the limit {limit}")]
+    BlockSizeLimitExceeded { size: u64, limit: u64 },
+    #[error("The gas price {price} is lower than the gas price of the block {required}")]
+    GasPriceTooLow { price: u64, required: u64 },
     #[error("No matching utxo for contract id ${0:#x}")]
     ContractUtxoMissing(ContractId),
     #[error("message already spent {0:#x}")]
     MessageAlreadySpent(Nonce),
     #[error("Expected input of type {0}")]
     InputTypeMismatch(String),
+    #[error("{0}")]
+    ResourceBudgetExhausted(String),
 }
 
 impl From<Backtrace> for Error {
@@ -324,6 +456,8 @@ pub enum TransactionValidityError {
     MessageSpendTooEarly(Nonce),
     #[error("The specified message doesn't exist")]
     MessageDoesNotExist(Nonce),
+    #[error("The specified message was cancelled by the Da layer")]
+    MessageCancelled(Nonce),
     #[error("The input message sender doesn't match the relayer message sender")]
     MessageSenderMismatch(Nonce),
     #[error("The input message recipient doesn't match the relayer message recipient")]
@@ -1,10 +1,36 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    blockchain::primitives::{
+        BlockId,
+        DaBlockHeight,
+    },
+    fuel_crypto::{
+        Message,
+        PublicKey,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ChainId,
+        ContractId,
+        Nonce,
+    },
+    services::{
+        relayer::RelayerErrorKind,
+        txpool::TransactionStatus,
+    },
 };
+use core::time::Duration;
+use tai64::Tai64;
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
 pub struct Balance<Owner> {
@@ -21,3 +47,239 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The number of the calls of the contract within the window of the latest blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractCalls {
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// The number of the calls.
+    pub calls: u64,
+}
+
+/// The gas price and the fullness of the block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFees {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The lowest gas price paid by the transactions of the block,
+    /// `0` for the block without the transactions.
+    pub min_gas_price: u64,
+    /// The gas used by the transactions of the block.
+    pub gas_used: u64,
+    /// The size of the transactions of the block in bytes.
+    pub bytes_used: u64,
+}
+
+/// The approximate storage statistics of the database table.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TableStats {
+    /// The name of the table.
+    pub table: String,
+    /// The size of the table on the disk in bytes.
+    pub size: u64,
+    /// The number of keys in the table.
+    pub keys: u64,
+    /// The number of bytes written into the table per second since the previous refresh.
+    pub write_rate: u64,
+}
+
+/// The synchronization status of the node with the network and the DA layer.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncStatus {
+    /// The height of the latest block of the node.
+    pub current_height: BlockHeight,
+    /// The highest height observed from the network, if any.
+    pub network_height: Option<BlockHeight>,
+    /// The estimated time to import the blocks up to the `network_height`.
+    pub time_to_tip: Option<Duration>,
+    /// The number of the finalized DA blocks that the relayer hasn't synced yet.
+    pub da_lag: Option<u64>,
+    /// The kind of the error that failed the latest iteration of the relayer.
+    pub da_error: Option<RelayerErrorKind>,
+    /// Whether the balance of the wallet of the block committer is below the threshold.
+    pub committer_balance_low: Option<bool>,
+}
+
+impl SyncStatus {
+    /// Returns `true` if the network is ahead of the node.
+    pub fn is_syncing(&self) -> bool {
+        self.network_height
+            .is_some_and(|height| height > self.current_height)
+    }
+}
+
+/// The information about the node attested by the signature of its P2P identity key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeAttestation {
+    /// The version of the node.
+    pub node_version: String,
+    /// The id of the chain followed by the node.
+    pub chain_id: ChainId,
+    /// The height of the latest block of the node.
+    pub block_height: BlockHeight,
+    /// The id of the latest block of the node.
+    pub block_id: BlockId,
+    /// The time of the attestation.
+    pub timestamp: Tai64,
+    /// The challenge of the requester, proving that the attestation is fresh.
+    pub challenge: Option<Bytes32>,
+}
+
+impl NodeAttestation {
+    /// The tag prepended to the signed message, so the signature of the attestation
+    /// can't be reused as the signature of any other message of the P2P identity key.
+    pub const DOMAIN: &'static [u8] = b"fuel-core/node-attestation/v1";
+
+    /// Returns the message signed by the node.
+    pub fn message(&self) -> Message {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(Self::DOMAIN);
+        bytes.extend_from_slice(&(self.node_version.len() as u64).to_be_bytes());
+        bytes.extend_from_slice(self.node_version.as_bytes());
+        bytes.extend_from_slice(&u64::from(self.chain_id).to_be_bytes());
+        bytes.extend_from_slice(&self.block_height.to_bytes());
+        bytes.extend_from_slice(self.block_id.as_slice());
+        bytes.extend_from_slice(&self.timestamp.to_bytes());
+        match &self.challenge {
+            Some(challenge) => {
+                bytes.push(1);
+                bytes.extend_from_slice(challenge.as_ref());
+            }
+            None => bytes.push(0),
+        }
+        Message::new(bytes)
+    }
+
+    /// Signs the attestation with the `secret_key`.
+    pub fn sign(&self, secret_key: &SecretKey) -> Signature {
+        Signature::sign(secret_key, &self.message())
+    }
+
+    /// Returns `true` if the `signature` of the attestation is made by the `public_key`.
+    pub fn verify(&self, signature: &Signature, public_key: &PublicKey) -> bool {
+        signature.verify(public_key, &self.message()).is_ok()
+    }
+}
+
+/// The keys of the state changed by the block.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BlockStateChanges {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The coins created by the block.
+    pub coins_created: Vec<UtxoId>,
+    /// The coins spent by the block.
+    pub coins_spent: Vec<UtxoId>,
+    /// The storage slots of the contracts written by the block.
+    pub contract_slots: Vec<ContractSlotChange>,
+    /// The messages consumed by the block.
+    pub messages_consumed: Vec<Nonce>,
+}
+
+/// The write of the storage slot of the contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSlotChange {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub slot: Bytes32,
+    /// The new value of the slot, or `None` if the slot is cleared.
+    pub value: Option<Bytes32>,
+}
+
+/// The change of the account watched by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountEvent {
+    /// The account received the coin.
+    CoinReceived {
+        /// The owner of the coin.
+        owner: Address,
+        /// The id of the coin.
+        utxo_id: UtxoId,
+        /// The asset of the coin.
+        asset_id: AssetId,
+        /// The amount of the coin.
+        amount: u64,
+        /// The height of the block that created the coin.
+        block_height: BlockHeight,
+    },
+    /// The account spent the coin.
+    CoinSpent {
+        /// The owner of the coin.
+        owner: Address,
+        /// The id of the coin.
+        utxo_id: UtxoId,
+        /// The id of the transaction that spent the coin.
+        tx_id: TxId,
+        /// The height of the block that spent the coin.
+        block_height: BlockHeight,
+    },
+    /// The message from the DA layer to the account became spendable.
+    MessageReceived {
+        /// The recipient of the message.
+        recipient: Address,
+        /// The nonce of the message.
+        nonce: Nonce,
+        /// The amount of the base asset of the message.
+        amount: u64,
+        /// The DA height of the message.
+        da_height: DaBlockHeight,
+        /// The height of the first block that can spend the message.
+        block_height: BlockHeight,
+    },
+}
+
+impl AccountEvent {
+    /// Returns the address of the account changed by the event.
+    pub fn account(&self) -> &Address {
+        match self {
+            AccountEvent::CoinReceived { owner, .. }
+            | AccountEvent::CoinSpent { owner, .. } => owner,
+            AccountEvent::MessageReceived { recipient, .. } => recipient,
+        }
+    }
+}
+
+/// The event buffered for the resumption of the subscriptions.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionEvent {
+    /// The block was imported.
+    Block(BlockHeight),
+    /// The status of the transaction changed.
+    TxStatus(TxId, TransactionStatus),
+}
+
+/// The position of the event in the subscription buffer. The client passes the cursor
+/// of the last received event to resume the subscription after it.
+///
+/// The `epoch` identifies the run of the node, so the cursors issued before
+/// the restart are rejected instead of pointing at the unrelated events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubscriptionCursor {
+    /// The identifier of the run of the node.
+    pub epoch: u64,
+    /// The sequence number of the event.
+    pub sequence: u64,
+}
+
+impl core::fmt::Display for SubscriptionCursor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:x}-{}", self.epoch, self.sequence)
+    }
+}
+
+impl core::str::FromStr for SubscriptionCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid subscription cursor `{s}`");
+        let (epoch, sequence) = s.split_once('-').ok_or_else(invalid)?;
+        Ok(Self {
+            epoch: u64::from_str_radix(epoch, 16).map_err(|_| invalid())?,
+            sequence: sequence.parse().map_err(|_| invalid())?,
+        })
+    }
+}
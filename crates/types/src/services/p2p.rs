@@ -1,7 +1,10 @@
 //! Contains types related to P2P data
 
 use crate::{
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     fuel_types::BlockHeight,
 };
 use std::fmt::Debug;
@@ -111,6 +114,52 @@ pub struct BlockHeightHeartbeatData {
     pub block_height: BlockHeight,
 }
 
+/// The progress of the node catching up with the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// The height of the latest block of the node.
+    pub current: BlockHeight,
+    /// The highest block height reported by the peers.
+    pub target: BlockHeight,
+}
+
+/// The reason why the node didn't keep the transaction gossiped by the peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TxRejectionReason {
+    /// The transaction is invalid against the state of the node.
+    Invalid,
+    /// The pool has a higher priced transaction spending the same inputs.
+    Collision,
+    /// The gas price of the transaction is too low for the pool.
+    GasPriceTooLow,
+    /// The pool is full.
+    PoolLimit,
+    /// The admission policy of the node doesn't allow the transaction.
+    Policy,
+    /// The node temporarily doesn't accept the transactions.
+    Unavailable,
+    /// The transaction was removed from the pool by a higher priced one.
+    SqueezedOut,
+    /// The transaction exceeded the time to live in the pool.
+    Expired,
+    /// Any other reason.
+    Other,
+}
+
+/// The notification sent back to the peer that gossiped the transaction
+/// when the node rejects it or removes it from the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TxRejection {
+    /// The id of the rejected transaction.
+    pub tx_id: TxId,
+    /// The reason code.
+    pub reason: TxRejectionReason,
+    /// The human-readable description of the reason.
+    pub message: String,
+}
+
 /// Opaque peer identifier.
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -0,0 +1,50 @@
+//! Types related to the relayer service.
+
+use crate::blockchain::primitives::DaBlockHeight;
+
+/// The fees of the finalized DA block observed by the relayer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DaFeeSample {
+    /// The height of the DA block.
+    pub da_height: DaBlockHeight,
+    /// The base fee per gas of the DA block in wei.
+    pub base_fee: u64,
+    /// The base fee per blob gas of the DA block in wei.
+    /// It is `None` if the DA layer doesn't support the blobs.
+    pub blob_base_fee: Option<u64>,
+}
+
+/// The kind of the error that failed the iteration of the relayer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelayerErrorKind {
+    /// The request to the RPC of the DA node failed.
+    Rpc,
+    /// The data of the DA node can't be decoded.
+    Decode,
+    /// Reading or writing the database failed.
+    Storage,
+    /// The DA node doesn't have the requested data yet, the next iteration retries.
+    Transient,
+    /// The relayer got the stop signal.
+    Shutdown,
+}
+
+impl RelayerErrorKind {
+    /// Returns the name of the kind used by the metrics.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelayerErrorKind::Rpc => "rpc",
+            RelayerErrorKind::Decode => "decode",
+            RelayerErrorKind::Storage => "storage",
+            RelayerErrorKind::Transient => "transient",
+            RelayerErrorKind::Shutdown => "shutdown",
+        }
+    }
+}
+
+impl core::fmt::Display for RelayerErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
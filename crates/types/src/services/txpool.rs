@@ -2,6 +2,7 @@
 
 use crate::{
     blockchain::primitives::BlockId,
+    entities::coins::coin::Coin,
     fuel_asm::Word,
     fuel_tx::{
         field::{
@@ -19,6 +20,8 @@ use crate::{
         UtxoId,
     },
     fuel_types::{
+        Address,
+        BlockHeight,
         ContractId,
         Nonce,
     },
@@ -26,9 +29,11 @@ use crate::{
         checked_transaction::Checked,
         ProgramState,
     },
+    services::p2p::TxRejectionReason,
 };
 use fuel_vm_private::checked_transaction::CheckedTransaction;
 use std::{
+    collections::HashSet,
     sync::Arc,
     time::Duration,
 };
@@ -112,6 +117,13 @@ impl PoolTransaction {
             PoolTransaction::Create(create) => create.metadata().fee.max_gas(),
         }
     }
+
+    pub fn max_fee(&self) -> Word {
+        match self {
+            PoolTransaction::Script(script) => script.metadata().fee.max_fee(),
+            PoolTransaction::Create(create) => create.metadata().fee.max_fee(),
+        }
+    }
 }
 
 impl From<&PoolTransaction> for Transaction {
@@ -160,6 +172,19 @@ pub struct InsertionResult {
     pub removed: Vec<ArcPoolTx>,
 }
 
+/// The coins and the messages of the owner affected by the transactions in the pool.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PendingCoins {
+    /// The coins spent by the transactions in the pool.
+    pub spent_coins: HashSet<UtxoId>,
+    /// The messages spent by the transactions in the pool.
+    pub spent_messages: HashSet<Nonce>,
+    /// The coins created by the transactions in the pool and not spent by other
+    /// transactions in the pool. Only the `Output::Coin` outputs are known before
+    /// the execution, the amounts of the change and the variable outputs are not.
+    pub created_coins: Vec<Coin>,
+}
+
 /// The status of the transaction during its life from the tx pool until the block.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -196,6 +221,16 @@ pub enum TransactionStatus {
     },
 }
 
+/// The status of the transaction recorded by the status archive.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchivedTransactionStatus {
+    /// The time when the status was recorded
+    pub time: Tai64,
+    /// The status of the transaction
+    pub status: TransactionStatus,
+}
+
 #[allow(missing_docs)]
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
@@ -210,6 +245,8 @@ pub enum Error {
     NotInsertedLimitHit,
     #[error("Transaction is not inserted. The gas price is too low.")]
     NotInsertedGasPriceTooLow,
+    #[error("Transaction is not inserted. Rejected by the admission policy: {0}")]
+    NotInsertedRejectedByPolicy(String),
     #[error(
         "Transaction is not inserted. More priced tx {0:#x} already spend this UTXO output: {1:#x}"
     )]
@@ -238,6 +275,8 @@ pub enum Error {
     NotInsertedInputMessageSpent(Nonce),
     #[error("Transaction is not inserted. Message id {0:#x} does not match any received message from the DA layer.")]
     NotInsertedInputMessageUnknown(Nonce),
+    #[error("Transaction is not inserted. Message is cancelled by the DA layer: {0:#x}")]
+    NotInsertedInputMessageCancelled(Nonce),
     #[error(
         "Transaction is not inserted. UTXO requires Contract input {0:#x} that is priced lower"
     )]
@@ -260,6 +299,25 @@ pub enum Error {
     NotInsertedMaxDepth,
     #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
     NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("Transaction is not inserted. The inputs of the sponsor {sponsor:#x} cover {covered} of the max fee {max_fee}")]
+    NotInsertedSponsorFeeNotCovered {
+        sponsor: Address,
+        max_fee: Word,
+        covered: Word,
+    },
+    #[error("Transaction is not inserted. The sponsor {0:#x} hit the rate limit of the sponsored transactions")]
+    NotInsertedSponsorRateLimit(Address),
+    #[error("Transaction is not inserted. The node is syncing: the current height is {current}, the target height is {target}")]
+    NotInsertedNodeSyncing {
+        current: BlockHeight,
+        target: BlockHeight,
+    },
+    #[error("Transaction is not inserted. The node temporarily doesn't accept new transactions")]
+    NotInsertedInsertionsPaused,
+    #[error("Transaction is not inserted. The node is a read replica and doesn't accept the transactions")]
+    NotInsertedReadOnly,
+    #[error("Transaction is not inserted. {0}")]
+    NotInsertedResourceBudgetExhausted(String),
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
@@ -267,7 +325,41 @@ pub enum Error {
     TTLReason,
     #[error("Transaction squeezed out because {0}")]
     SqueezedOut(String),
+    #[error("Transaction is invalidated by the recent blocks: {0}")]
+    Invalidated(String),
+    #[error("Transaction is rejected by the peer: {0}")]
+    RejectedByPeer(String),
     // TODO: We need it for now until channels are removed from TxPool.
     #[error("Got some unexpected error: {0}")]
     Other(String),
 }
+
+impl Error {
+    /// Returns the reason code reported to the peer that gossiped the rejected
+    /// transaction. Returns `None` if the peer doesn't need to be notified,
+    /// because the node already has the transaction.
+    pub fn rejection_reason(&self) -> Option<TxRejectionReason> {
+        let reason = match self {
+            Error::NotInsertedTxKnown => return None,
+            Error::NotInsertedCollision(_, _)
+            | Error::NotInsertedCollisionContractId(_)
+            | Error::NotInsertedCollisionMessageId(_, _)
+            | Error::NotInsertedContractPricedLower(_) => TxRejectionReason::Collision,
+            Error::NotInsertedGasPriceTooLow => TxRejectionReason::GasPriceTooLow,
+            Error::NotInsertedLimitHit => TxRejectionReason::PoolLimit,
+            Error::NotInsertedRejectedByPolicy(_)
+            | Error::NotInsertedSponsorRateLimit(_) => TxRejectionReason::Policy,
+            Error::NotInsertedNodeSyncing { .. }
+            | Error::NotInsertedInsertionsPaused
+            | Error::NotInsertedReadOnly
+            | Error::NotInsertedResourceBudgetExhausted(_) => {
+                TxRejectionReason::Unavailable
+            }
+            Error::Removed | Error::SqueezedOut(_) => TxRejectionReason::SqueezedOut,
+            Error::TTLReason | Error::Invalidated(_) => TxRejectionReason::Expired,
+            Error::RejectedByPeer(_) | Error::Other(_) => TxRejectionReason::Other,
+            _ => TxRejectionReason::Invalid,
+        };
+        Some(reason)
+    }
+}
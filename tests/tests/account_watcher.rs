@@ -0,0 +1,70 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::AccountEvent,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        Output,
+        TransactionBuilder,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        ChainId,
+    },
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn account_events_include_received_coins_of_watched_accounts() {
+    let mut node_config = Config::local_node();
+    node_config.account_watcher = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let watched = Address::from([1; 32]);
+    let other = Address::from([2; 32]);
+
+    let mut events = client
+        .subscribe_account_events(&[watched, other])
+        .await
+        .unwrap();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_output(Output::coin(other, 5, AssetId::BASE))
+        .add_output(Output::coin(watched, 10, AssetId::BASE))
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let event = events.next().await.unwrap().unwrap();
+    assert_eq!(
+        event,
+        AccountEvent::CoinReceived {
+            owner: watched,
+            utxo_id: UtxoId::new(tx_id, 1),
+            asset_id: AssetId::BASE,
+            amount: 10,
+            block_height: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn account_events_fail_when_the_watcher_is_disabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut events = client
+        .subscribe_account_events(&[Address::from([1; 32])])
+        .await
+        .unwrap();
+
+    assert!(events.next().await.unwrap().is_err());
+}
@@ -0,0 +1,666 @@
+use fuel_core::{
+    admin_api::{
+        ports::{
+            ConfigReloadPort,
+            ConfigReloadReport,
+            LogFilterPort,
+        },
+        service::{
+            new_service,
+            ConfigReload,
+        },
+        Config as AdminConfig,
+    },
+    chain_config::{
+        default_consensus_dev_key,
+        ChainConfig,
+        ConsensusConfig,
+        SignedUpgradeDeclaration,
+        UpgradeDeclaration,
+    },
+    fuel_core_graphql_api::query_stats::OperationStats,
+    service::{
+        adapters::consensus_module::poa::{
+            RemoteCosigner,
+            RemoteCosignerConfig,
+        },
+        config::Trigger,
+        state_diff_follower,
+        Config,
+        FuelService,
+        NodeMode,
+        ServiceTrait,
+    },
+    types::{
+        blockchain::consensus::Consensus,
+        fuel_crypto::SecretKey,
+        fuel_tx::Input,
+        secrecy::Secret,
+        tai64::Tai64,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_poa::ports::BlockCosigner;
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use reqwest::{
+    header::{
+        AUTHORIZATION,
+        CONTENT_TYPE,
+    },
+    StatusCode,
+};
+use std::{
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+
+const TOKEN: &str = "admin-token";
+
+struct TestConfigReload;
+
+impl ConfigReloadPort for TestConfigReload {
+    fn reload(&self) -> anyhow::Result<ConfigReloadReport> {
+        Ok(ConfigReloadReport {
+            applied: vec!["tx-max-number".to_string()],
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct TestLogFilter(Mutex<String>);
+
+impl LogFilterPort for TestLogFilter {
+    fn current(&self) -> String {
+        self.0.lock().unwrap().clone()
+    }
+
+    fn set(&self, filter: &str) -> anyhow::Result<()> {
+        if filter.is_empty() {
+            anyhow::bail!("The filter is empty")
+        }
+        *self.0.lock().unwrap() = filter.to_string();
+        Ok(())
+    }
+}
+
+struct TestContext {
+    srv: FuelService,
+    admin: fuel_core::admin_api::service::Service,
+}
+
+impl TestContext {
+    async fn new(snapshot_dir: &Path, config_reload: Option<ConfigReload>) -> Self {
+        Self::with_config(Config::local_node(), snapshot_dir, config_reload).await
+    }
+
+    async fn with_config(
+        config: Config,
+        snapshot_dir: &Path,
+        config_reload: Option<ConfigReload>,
+    ) -> Self {
+        let srv = FuelService::new_node(config).await.unwrap();
+        let config = AdminConfig {
+            addr: "127.0.0.1:0".parse().unwrap(),
+            token: Secret::new(TOKEN.to_string()),
+            snapshot_dir: snapshot_dir.to_path_buf(),
+        };
+        let admin = new_service(config, srv.shared.clone(), None, config_reload).unwrap();
+        admin.start_and_await().await.unwrap();
+        Self { srv, admin }
+    }
+
+    async fn post(&self, path: &str, token: &str) -> reqwest::Response {
+        let address = self.admin.shared.bound_address;
+        reqwest::Client::new()
+            .post(format!("http://{address}{path}"))
+            .header(AUTHORIZATION, format!("Bearer {token}"))
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn post_upgrade(
+        &self,
+        upgrade: &SignedUpgradeDeclaration,
+    ) -> reqwest::Response {
+        let address = self.admin.shared.bound_address;
+        reqwest::Client::new()
+            .post(format!("http://{address}/upgrades"))
+            .header(AUTHORIZATION, format!("Bearer {TOKEN}"))
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_string(upgrade).unwrap())
+            .send()
+            .await
+            .unwrap()
+    }
+
+    async fn get(&self, path: &str) -> reqwest::Response {
+        let address = self.admin.shared.bound_address;
+        reqwest::Client::new()
+            .get(format!("http://{address}{path}"))
+            .header(AUTHORIZATION, format!("Bearer {TOKEN}"))
+            .send()
+            .await
+            .unwrap()
+    }
+}
+
+#[tokio::test]
+async fn admin_api_rejects_requests_without_token() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+    let address = ctx.admin.shared.bound_address;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{address}/snapshot"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = ctx.post("/snapshot", "wrong-token").await;
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn admin_api_writes_snapshot() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+    let client = FuelClient::from(ctx.srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let response = ctx.post("/snapshot", TOKEN).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let path = dir.path().join("snapshot-2.json");
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(body["path"], path.to_str().unwrap());
+    let snapshot: ChainConfig =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(snapshot.initial_state.unwrap().height, Some(2u32.into()));
+}
+
+#[tokio::test]
+async fn admin_api_reloads_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), Some(Arc::new(TestConfigReload))).await;
+
+    let response = ctx.post("/config/reload", TOKEN).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: ConfigReloadReport =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    assert_eq!(report.applied, vec!["tx-max-number".to_string()]);
+}
+
+#[tokio::test]
+async fn admin_api_returns_not_found_for_disabled_parts() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+
+    for path in ["/config/reload", "/relayer/pause", "/peers/invalid/ban"] {
+        let response = ctx.post(path, TOKEN).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{path}");
+    }
+}
+
+#[tokio::test]
+async fn admin_api_changes_log_filter() {
+    let dir = tempfile::tempdir().unwrap();
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let log_filter = Arc::new(TestLogFilter::default());
+    *log_filter.0.lock().unwrap() = "info".to_string();
+    let config = AdminConfig {
+        addr: "127.0.0.1:0".parse().unwrap(),
+        token: Secret::new(TOKEN.to_string()),
+        snapshot_dir: dir.path().to_path_buf(),
+    };
+    let admin =
+        new_service(config, srv.shared.clone(), Some(log_filter.clone()), None).unwrap();
+    admin.start_and_await().await.unwrap();
+    let url = format!("http://{}/log-filter", admin.shared.bound_address);
+    let put_filter = |body: &'static str| {
+        reqwest::Client::new()
+            .put(&url)
+            .header(AUTHORIZATION, format!("Bearer {TOKEN}"))
+            .header(CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+    };
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header(AUTHORIZATION, format!("Bearer {TOKEN}"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.text().await.unwrap(), r#"{"filter":"info"}"#);
+
+    let response = put_filter(r#"{"filter":"fuel_core_txpool=debug"}"#)
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.text().await.unwrap(),
+        r#"{"filter":"fuel_core_txpool=debug"}"#
+    );
+    assert_eq!(log_filter.current(), "fuel_core_txpool=debug");
+
+    let response = put_filter(r#"{"filter":""}"#).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(log_filter.current(), "fuel_core_txpool=debug");
+
+    // The log filter isn't exposed by the public API.
+    let response = reqwest::get(format!("http://{}/log-filter", srv.bound_address))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn admin_api_lists_competing_blocks() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+    let client = FuelClient::from(ctx.srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+
+    let database = &ctx.srv.shared.database;
+    let canonical = database
+        .get_sealed_block_by_height(&1u32.into())
+        .unwrap()
+        .unwrap();
+    let mut competing = canonical.clone();
+    let time = competing.entity.header().time().0;
+    competing.entity.header_mut().consensus.time = Tai64(time + 1);
+    competing.entity.header_mut().recalculate_metadata();
+
+    let result = ctx
+        .srv
+        .shared
+        .block_importer
+        .execute_and_commit(competing.clone())
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        database.get_block_id(&1u32.into()).unwrap(),
+        Some(canonical.entity.id())
+    );
+
+    let response = ctx.get("/importer/competing-blocks").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let competing_blocks = body.as_array().unwrap();
+    assert_eq!(competing_blocks.len(), 1);
+    assert_eq!(competing_blocks[0]["height"], 1);
+    assert_eq!(
+        competing_blocks[0]["canonical_block_id"],
+        serde_json::to_value(canonical.entity.id()).unwrap()
+    );
+    assert_eq!(
+        competing_blocks[0]["block_id"],
+        serde_json::to_value(competing.entity.id()).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn admin_api_returns_query_stats_by_operation_name() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = Config::local_node();
+    config.query_stats = true;
+    let ctx = TestContext::with_config(config, dir.path(), None).await;
+    for _ in 0..3 {
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/graphql", ctx.srv.bound_address))
+            .json(&serde_json::json!({
+                "query": "query TestHealth { health }",
+                "operationName": "TestHealth",
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = ctx.get("/graphql/query-stats").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let stats: Vec<OperationStats> =
+        serde_json::from_str(&response.text().await.unwrap()).unwrap();
+    let health = stats
+        .iter()
+        .find(|stats| stats.operation == "TestHealth")
+        .expect("The operation should be recorded");
+    assert_eq!(health.count, 3);
+    assert_eq!(health.errors, 0);
+}
+
+#[tokio::test]
+async fn admin_api_schedules_upgrade_not_supported_by_node() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+    let client = FuelClient::from(ctx.srv.bound_address);
+    let declaration = UpgradeDeclaration {
+        height: 2u32.into(),
+        required_version: "999.0.0".to_string(),
+        consensus_parameters: None,
+        state_transition_bytecode_root: Some([1; 32].into()),
+    };
+    let chain_id = ctx
+        .srv
+        .shared
+        .config
+        .chain_conf
+        .consensus_parameters
+        .chain_id;
+    let upgrade = declaration
+        .sign(&default_consensus_dev_key(), &chain_id)
+        .unwrap();
+
+    let response = ctx.post_upgrade(&upgrade).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let pending = client.pending_upgrades().await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].height, 2);
+    assert_eq!(pending[0].required_version, "999.0.0");
+    assert!(!pending[0].supported);
+
+    // The node produces the blocks until the height of the upgrade.
+    client.produce_blocks(1, None).await.unwrap();
+    assert!(client.produce_blocks(1, None).await.is_err());
+    assert_eq!(
+        ctx.srv.shared.database.latest_height().unwrap(),
+        1u32.into()
+    );
+}
+
+#[tokio::test]
+async fn admin_api_rejects_upgrade_not_signed_by_authority() {
+    let dir = tempfile::tempdir().unwrap();
+    let ctx = TestContext::new(dir.path(), None).await;
+    let declaration = UpgradeDeclaration {
+        height: 2u32.into(),
+        required_version: "0.0.1".to_string(),
+        consensus_parameters: None,
+        state_transition_bytecode_root: None,
+    };
+    let secret_key =
+        fuel_core::types::fuel_crypto::SecretKey::try_from([7u8; 32].as_slice()).unwrap();
+    let chain_id = ctx
+        .srv
+        .shared
+        .config
+        .chain_conf
+        .consensus_parameters
+        .chain_id;
+    let upgrade = declaration.sign(&secret_key, &chain_id).unwrap();
+
+    let response = ctx.post_upgrade(&upgrade).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = ctx.get("/upgrades").await;
+    assert_eq!(response.text().await.unwrap(), "[]");
+}
+
+#[tokio::test]
+async fn follower_applies_state_diffs_of_upstream() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = Config::local_node();
+    config.block_importer.state_diff_retention = Some(10);
+    let upstream = TestContext::with_config(config, dir.path(), None).await;
+    let client = FuelClient::from(upstream.srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let response = upstream.get("/state-diffs/3").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let mut config = Config::local_node();
+    config.state_diff_upstream = Some(state_diff_follower::Config {
+        url: format!("http://{}", upstream.admin.shared.bound_address),
+        token: Secret::new(TOKEN.to_string()),
+        poll_interval: Duration::from_millis(10),
+    });
+    let follower = FuelService::new_node(config).await.unwrap();
+    let mut imported_blocks = follower.shared.block_importer.block_importer.subscribe();
+
+    let upstream_block = upstream
+        .srv
+        .shared
+        .database
+        .get_sealed_block_by_height(&2u32.into())
+        .unwrap()
+        .unwrap();
+    for _ in 0..100 {
+        if follower.shared.database.latest_height().unwrap() == 2u32.into() {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    let follower_block = follower
+        .shared
+        .database
+        .get_sealed_block_by_height(&2u32.into())
+        .unwrap()
+        .unwrap();
+    assert_eq!(follower_block.entity.id(), upstream_block.entity.id());
+
+    // The subscribers of the importer are notified about the followed blocks.
+    client.produce_blocks(1, None).await.unwrap();
+    let imported = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let result = imported_blocks.recv().await.unwrap();
+            if *result.sealed_block.entity.header().height() == 3u32.into() {
+                break result
+            }
+        }
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        follower.shared.database.latest_height().unwrap(),
+        3u32.into()
+    );
+    assert_eq!(
+        imported.sealed_block.entity.id(),
+        upstream
+            .srv
+            .shared
+            .database
+            .get_sealed_block_by_height(&3u32.into())
+            .unwrap()
+            .unwrap()
+            .entity
+            .id()
+    );
+}
+
+#[tokio::test]
+async fn producer_collects_cosignature_from_admin_api() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let secrets: Vec<_> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+    let mut config = Config::local_node();
+    config.chain_conf.consensus = ConsensusConfig::PoAMultiSig {
+        signing_keys: secrets
+            .iter()
+            .map(|secret| Input::owner(&secret.public_key()))
+            .collect(),
+        threshold: 2,
+    };
+    let chain_conf = config.chain_conf.clone();
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut cosigner_config = config.clone();
+    cosigner_config.block_production = Trigger::Never;
+    cosigner_config.consensus_key = Some(Secret::new(secrets[1].into()));
+    let cosigner = TestContext::with_config(cosigner_config, dir.path(), None).await;
+
+    config.consensus_key = Some(Secret::new(secrets[0].into()));
+    config.consensus_cosigners = vec![RemoteCosignerConfig {
+        url: format!("http://{}", cosigner.admin.shared.bound_address),
+        token: Secret::new(TOKEN.to_string()),
+    }];
+    let producer = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(producer.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+
+    let sealed_block = producer
+        .shared
+        .database
+        .get_sealed_block_by_height(&1u32.into())
+        .unwrap()
+        .unwrap();
+    match &sealed_block.consensus {
+        Consensus::PoAMultiSig(multisig) => assert_eq!(multisig.signatures.len(), 2),
+        _ => panic!("Not expected consensus"),
+    }
+    assert!(fuel_core_poa::verifier::verify_consensus(
+        &chain_conf.consensus,
+        sealed_block.entity.header(),
+        &sealed_block.consensus,
+    ));
+}
+
+/// Returns the status of the rejected cosign request.
+fn cosign_error_status(error: anyhow::Error) -> Option<StatusCode> {
+    error.downcast_ref::<reqwest::Error>()?.status()
+}
+
+#[tokio::test]
+async fn cosign_validates_block_and_remembers_cosigned_height() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let secrets: Vec<_> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+    let mut config = Config::local_node();
+    config.chain_conf.consensus = ConsensusConfig::PoAMultiSig {
+        signing_keys: secrets
+            .iter()
+            .map(|secret| Input::owner(&secret.public_key()))
+            .collect(),
+        threshold: 2,
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let mut cosigner_config = config.clone();
+    cosigner_config.block_production = Trigger::Never;
+    cosigner_config.consensus_key = Some(Secret::new(secrets[1].into()));
+    let cosigner = TestContext::with_config(cosigner_config, dir.path(), None).await;
+    let remote_cosigner = |admin: &fuel_core::admin_api::service::Service| {
+        RemoteCosigner::new(RemoteCosignerConfig {
+            url: format!("http://{}", admin.shared.bound_address),
+            token: Secret::new(TOKEN.to_string()),
+        })
+    };
+
+    config.consensus_key = Some(Secret::new(secrets[0].into()));
+    config.consensus_cosigners = vec![RemoteCosignerConfig {
+        url: format!("http://{}", cosigner.admin.shared.bound_address),
+        token: Secret::new(TOKEN.to_string()),
+    }];
+    let producer = FuelService::new_node(config).await.unwrap();
+    FuelClient::from(producer.bound_address)
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+    let block = producer
+        .shared
+        .database
+        .get_sealed_block_by_height(&1u32.into())
+        .unwrap()
+        .unwrap()
+        .entity;
+
+    // The header is already cosigned, but the transactions don't match it.
+    let mut invalid_block = block.clone();
+    let transaction = invalid_block.transactions()[0].clone();
+    invalid_block.transactions_mut().push(transaction);
+    let error = remote_cosigner(&cosigner.admin)
+        .cosign(&invalid_block)
+        .await
+        .unwrap_err();
+    assert_eq!(cosign_error_status(error), Some(StatusCode::BAD_REQUEST));
+
+    // The same block is signed again.
+    remote_cosigner(&cosigner.admin)
+        .cosign(&block)
+        .await
+        .unwrap();
+
+    // The competing block is rejected by the restarted admin API.
+    let mut competing = block.clone();
+    let time = competing.header().time().0;
+    competing.header_mut().consensus.time = Tai64(time + 1);
+    competing.header_mut().recalculate_metadata();
+    cosigner.admin.stop_and_await().await.unwrap();
+    let admin = new_service(
+        AdminConfig {
+            addr: "127.0.0.1:0".parse().unwrap(),
+            token: Secret::new(TOKEN.to_string()),
+            snapshot_dir: dir.path().to_path_buf(),
+        },
+        cosigner.srv.shared.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    admin.start_and_await().await.unwrap();
+    let error = remote_cosigner(&admin)
+        .cosign(&competing)
+        .await
+        .unwrap_err();
+    assert_eq!(cosign_error_status(error), Some(StatusCode::CONFLICT));
+}
+
+#[tokio::test]
+async fn read_replica_follows_primary_and_rejects_transactions() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = Config::local_node();
+    config.block_importer.state_diff_retention = Some(10);
+    let primary = TestContext::with_config(config, dir.path(), None).await;
+    FuelClient::from(primary.srv.bound_address)
+        .produce_blocks(1, None)
+        .await
+        .unwrap();
+
+    let mut config = Config::local_node();
+    config.mode = NodeMode::ReadReplica;
+    config.state_diff_upstream = Some(state_diff_follower::Config {
+        url: format!("http://{}", primary.admin.shared.bound_address),
+        token: Secret::new(TOKEN.to_string()),
+        poll_interval: Duration::from_millis(10),
+    });
+    let replica = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(replica.bound_address);
+
+    for _ in 0..100 {
+        if replica.shared.database.latest_height().unwrap() == 1u32.into() {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(
+        client
+            .chain_info()
+            .await
+            .unwrap()
+            .latest_block
+            .header
+            .height,
+        1
+    );
+
+    let tx = fuel_core::types::fuel_tx::Transaction::default_test_tx();
+    assert!(client.submit(&tx).await.is_err());
+    assert!(replica.submit(tx).await.is_err());
+    assert!(client.produce_blocks(1, None).await.is_err());
+}
@@ -0,0 +1,22 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_types::AssetId;
+
+#[tokio::test]
+async fn asset_metadata_of_unknown_asset_is_null() {
+    let mut node_config = Config::local_node();
+    node_config.asset_registry = true;
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+
+    let metadata = client
+        .asset_metadata(&AssetId::from([1; 32]))
+        .await
+        .unwrap();
+
+    assert_eq!(metadata, None);
+}
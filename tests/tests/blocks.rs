@@ -1,4 +1,9 @@
 use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        MessageConfig,
+        StateConfig,
+    },
     database::Database,
     service::{
         Config,
@@ -8,6 +13,7 @@ use fuel_core::{
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
+        PaginationConfig,
         PaginationRequest,
     },
     types::TransactionStatus,
@@ -25,9 +31,13 @@ use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
         consensus::Consensus,
+        primitives::DaBlockHeight,
     },
     fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_types::{
+        ChainId,
+        Nonce,
+    },
     secrecy::ExposeSecret,
     tai64::Tai64,
 };
@@ -63,6 +73,89 @@ async fn block() {
     assert!(block.is_some());
 }
 
+#[tokio::test]
+async fn state_diff_returns_keys_changed_by_block() {
+    let owner = Address::new([7; 32]);
+    let spent_coin = UtxoId::new([1; 32].into(), 0);
+    let nonce = Nonce::from(1u64);
+    let mut config = Config::local_node();
+    config.block_importer.state_diff_retention = Some(10);
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![CoinConfig {
+            tx_id: Some(*spent_coin.tx_id()),
+            output_index: Some(spent_coin.output_index()),
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            maturity: None,
+            owner,
+            amount: 100,
+            asset_id: AssetId::BASE,
+        }]),
+        messages: Some(vec![MessageConfig {
+            sender: owner,
+            recipient: owner,
+            nonce,
+            amount: 50,
+            data: vec![],
+            da_height: DaBlockHeight::from(0u64),
+        }]),
+        ..StateConfig::default()
+    });
+    let chain_id = config.chain_conf.consensus_parameters.chain_id;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            spent_coin,
+            owner,
+            100,
+            AssetId::BASE,
+            Default::default(),
+            0,
+            Default::default(),
+        ))
+        .add_input(Input::message_coin_signed(owner, owner, 50, nonce, 0))
+        .add_output(Output::change(owner, 0, AssetId::BASE))
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    let created_coin = UtxoId::new(tx.id(&chain_id), 0);
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let diff = client.state_diff(1u32.into()).await.unwrap().unwrap();
+    assert_eq!(diff.height, 1);
+    assert!(diff.coins_created.contains(&created_coin));
+    assert_eq!(diff.coins_spent, vec![spent_coin]);
+    assert_eq!(diff.messages_consumed, vec![nonce]);
+    // The block is not produced yet.
+    assert!(client.state_diff(2u32.into()).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn fee_history_returns_fees_of_latest_blocks() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+
+    let history = client.fee_history(10).await.unwrap();
+
+    assert_eq!(
+        history.iter().map(|fees| fees.height).collect_vec(),
+        vec![1, 2]
+    );
+    let (with_tx, empty) = (history[0], history[1]);
+    assert!(with_tx.gas_used > 0);
+    assert!(with_tx.bytes_used > empty.bytes_used);
+    assert_eq!(empty.gas_used, 0);
+    assert_eq!(empty.min_gas_price, 0);
+    assert_eq!(client.fee_history(1).await.unwrap(), vec![empty]);
+}
+
 #[tokio::test]
 async fn get_genesis_block() {
     let mut config = Config::local_node();
@@ -149,6 +242,97 @@ async fn produce_block_manually() {
     assert_eq!(*actual_pub_key, expected_pub_key);
 }
 
+#[tokio::test]
+async fn advanced_time_is_used_by_instantly_produced_blocks() {
+    let config = Config::local_node();
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let week = 7 * 24 * 60 * 60;
+    let before = Tai64::now();
+    let chain_time = client.advance_time(week).await.unwrap();
+    assert!(chain_time >= before + week);
+
+    let tx = Transaction::default_test_tx();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let block = client.block_by_height(1).await.unwrap().unwrap();
+    assert!(block.header.time >= before + week);
+}
+
+#[tokio::test]
+async fn advance_time_requires_debug() {
+    let config = Config {
+        debug: false,
+        ..Config::local_node()
+    };
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.advance_time(60).await;
+
+    assert_eq!(
+        "Response errors; `debug` must be enabled to use this endpoint",
+        result.err().unwrap().to_string()
+    );
+}
+
+#[tokio::test]
+async fn subscribe_blocks_returns_new_blocks_in_order() {
+    use futures::StreamExt;
+
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let blocks = client.subscribe_blocks().await.unwrap();
+
+    client.produce_blocks(3, None).await.unwrap();
+
+    let heights: Vec<_> = blocks
+        .take(3)
+        .map(|block| block.unwrap().header.height)
+        .collect()
+        .await;
+    assert_eq!(heights, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn listen_blocks_with_receipts_returns_transactions_with_receipts() {
+    use futures::StreamExt;
+
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let mut blocks = client.listen_blocks_with_receipts(4).await.unwrap();
+
+    let tx = Transaction::default_test_tx();
+    let tx_id = tx.id(&ChainId::default());
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let block = blocks.next().await.unwrap().unwrap();
+    assert_eq!(block.block.header.height, 1);
+    assert_eq!(
+        block.transactions.iter().map(|tx| tx.id).collect_vec(),
+        block.block.transactions
+    );
+    let script = &block.transactions[0];
+    assert_eq!(script.id, tx_id);
+    assert_eq!(
+        script.transaction.transaction.id(&ChainId::default()),
+        tx_id
+    );
+    assert!(matches!(
+        script.receipts.last(),
+        Some(Receipt::ScriptResult { .. })
+    ));
+}
+
 #[tokio::test]
 async fn produce_block_negative() {
     let db = Database::default();
@@ -304,6 +488,40 @@ async fn block_connection_5(
     };
 }
 
+#[rstest]
+#[tokio::test]
+async fn blocks_stream_follows_cursors(
+    #[values(PageDirection::Forward, PageDirection::Backward)]
+    pagination_direction: PageDirection,
+) {
+    use futures::StreamExt;
+
+    // setup server & client
+    let srv = FuelService::from_database(Default::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client =
+        FuelClient::from(srv.bound_address).with_pagination_config(PaginationConfig {
+            page_size: 3,
+            direction: pagination_direction,
+            page_delay: Duration::from_millis(10),
+        });
+    // setup test data in the node
+    client.produce_blocks(9, None).await.unwrap();
+
+    // run test
+    let heights = client
+        .blocks_stream()
+        .map(|block| block.unwrap().header.height)
+        .collect::<Vec<_>>()
+        .await;
+
+    match pagination_direction {
+        PageDirection::Forward => assert_eq!(heights, (0..10).collect_vec()),
+        PageDirection::Backward => assert_eq!(heights, rev(0..10).collect_vec()),
+    }
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
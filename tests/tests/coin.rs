@@ -8,6 +8,7 @@ use fuel_core::{
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
+        PaginationConfig,
         PaginationRequest,
     },
     types::primitives::{
@@ -93,6 +94,48 @@ async fn first_5_coins(
     assert_eq!(coins.results.len(), 5)
 }
 
+#[tokio::test]
+async fn coins_stream_returns_all_coins() {
+    use futures::StreamExt;
+
+    let owner = Address::default();
+
+    // setup test data in the node
+    let mut db = Database::default();
+    for i in 1..10u8 {
+        let coin = Coin {
+            utxo_id: UtxoId::new([i; 32].into(), 0),
+            owner,
+            amount: i as Word,
+            asset_id: Default::default(),
+            maturity: Default::default(),
+            tx_pointer: Default::default(),
+        };
+        db.storage::<Coins>()
+            .insert(&coin.utxo_id.clone(), &coin.compress())
+            .unwrap();
+    }
+
+    // setup server & client
+    let srv = FuelService::from_database(db, Config::local_node())
+        .await
+        .unwrap();
+    let client =
+        FuelClient::from(srv.bound_address).with_pagination_config(PaginationConfig {
+            page_size: 2,
+            ..Default::default()
+        });
+
+    // run test
+    let mut amounts: Vec<_> = client
+        .coins_stream(&owner, None)
+        .map(|coin| coin.unwrap().amount)
+        .collect()
+        .await;
+    amounts.sort();
+    assert_eq!(amounts, (1..10).collect::<Vec<Word>>());
+}
+
 #[tokio::test]
 async fn only_asset_id_filtered_coins() {
     let owner = Address::default();
@@ -23,8 +23,15 @@ use rand::{
 
 mod coin {
     use super::*;
-    use fuel_core_client::client::types::CoinType;
-    use fuel_core_types::fuel_crypto::SecretKey;
+    use fuel_core_client::client::types::{
+        CoinSelectionStrategy,
+        CoinType,
+    };
+    use fuel_core_types::{
+        fuel_crypto::SecretKey,
+        fuel_types::ChainId,
+    };
+    use itertools::Itertools;
     use rand::Rng;
 
     async fn setup(
@@ -278,6 +285,185 @@ mod coin {
             CoinsQueryError::MaxCoinsReached.to_str_error_string()
         );
     }
+
+    #[tokio::test]
+    async fn branch_and_bound_selects_exact_match() {
+        let owner = Address::default();
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_strategy(
+                &owner,
+                vec![(asset_id_a, 200, None)],
+                None,
+                Some(CoinSelectionStrategy::BranchAndBound),
+                None,
+            )
+            .await
+            .unwrap();
+        let mut amounts = coins_per_asset[0]
+            .iter()
+            .map(CoinType::amount)
+            .collect_vec();
+        amounts.sort();
+        assert_eq!(amounts, vec![50, 150]);
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_strategy(
+                &owner,
+                vec![(asset_id_a, 200, None)],
+                None,
+                Some(CoinSelectionStrategy::LargestFirst),
+                None,
+            )
+            .await
+            .unwrap();
+        let amounts = coins_per_asset[0]
+            .iter()
+            .map(CoinType::amount)
+            .collect_vec();
+        assert_eq!(amounts, vec![150, 100]);
+    }
+
+    #[tokio::test]
+    async fn max_inputs_limits_coins_of_all_assets() {
+        let owner = Address::default();
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_strategy(
+                &owner,
+                vec![(asset_id_a, 150, None), (asset_id_b, 150, None)],
+                None,
+                Some(CoinSelectionStrategy::Random),
+                Some(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(coins_per_asset[0].len(), 1);
+        assert_eq!(coins_per_asset[1].len(), 1);
+
+        let coins_per_asset = context
+            .client
+            .coins_to_spend_with_strategy(
+                &owner,
+                vec![(asset_id_a, 200, None), (asset_id_b, 150, None)],
+                None,
+                Some(CoinSelectionStrategy::Random),
+                Some(2),
+            )
+            .await;
+        assert_eq!(
+            coins_per_asset.unwrap_err().to_string(),
+            CoinsQueryError::MaxCoinsReached.to_str_error_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn coins_to_consolidate_returns_dust() {
+        let owner = Address::default();
+        let asset_id_a = AssetId::new([1u8; 32]);
+        let asset_id_b = AssetId::new([2u8; 32]);
+        let context = setup(owner, asset_id_a, asset_id_b).await;
+
+        let coins = context
+            .client
+            .coins_to_consolidate(&owner, &asset_id_a, 120, None)
+            .await
+            .unwrap();
+        let amounts = coins.iter().map(CoinType::amount).collect_vec();
+        assert_eq!(amounts, vec![50, 100]);
+
+        let coins = context
+            .client
+            .coins_to_consolidate(&owner, &asset_id_a, 60, None)
+            .await
+            .unwrap();
+        assert!(coins.is_empty());
+    }
+
+    #[tokio::test]
+    async fn usable_coins_skip_the_coins_spent_by_the_pending_transactions() {
+        let mut rng = StdRng::seed_from_u64(1234);
+        let asset_id: AssetId = rng.gen();
+        let secret_key: SecretKey = SecretKey::random(&mut rng);
+        let owner = Input::owner(&secret_key.public_key());
+
+        let mut config = Config::local_node();
+        config.block_production = fuel_core_poa::Trigger::Never;
+        config.chain_conf.initial_state = Some(StateConfig {
+            height: None,
+            contracts: None,
+            coins: Some(
+                [50, 100]
+                    .into_iter()
+                    .map(|amount| CoinConfig {
+                        tx_id: None,
+                        output_index: None,
+                        tx_pointer_block_height: None,
+                        tx_pointer_tx_idx: None,
+                        maturity: None,
+                        owner,
+                        amount,
+                        asset_id,
+                    })
+                    .collect(),
+            ),
+            messages: None,
+        });
+        let srv = FuelService::new_node(config).await.unwrap();
+        let client = FuelClient::from(srv.bound_address);
+
+        let usable = client
+            .usable_coins(&owner, Some(&asset_id), None)
+            .await
+            .unwrap();
+        assert_eq!(usable.coins.len(), 2);
+        assert!(usable.pending_coins.is_empty());
+
+        // spend the smallest coin, sending a part of it back to the owner
+        let spent = usable
+            .coins
+            .iter()
+            .find_map(|coin| match coin {
+                CoinType::Coin(coin) if coin.amount == 50 => Some(coin),
+                _ => None,
+            })
+            .unwrap();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .add_unsigned_coin_input(
+                secret_key,
+                spent.utxo_id,
+                spent.amount,
+                spent.asset_id,
+                Default::default(),
+                spent.maturity.into(),
+            )
+            .add_output(Output::coin(owner, 20, asset_id))
+            .add_output(Output::change(owner, 0, asset_id))
+            .finalize_as_transaction();
+        client.submit(&tx).await.unwrap();
+
+        let usable = client
+            .usable_coins(&owner, Some(&asset_id), None)
+            .await
+            .unwrap();
+        let amounts = usable.coins.iter().map(CoinType::amount).collect_vec();
+        assert_eq!(amounts, vec![100]);
+        assert_eq!(usable.pending_coins.len(), 1);
+        assert_eq!(usable.pending_coins[0].amount, 20);
+        assert_eq!(
+            usable.pending_coins[0].utxo_id,
+            UtxoId::new(tx.id(&ChainId::default()), 0)
+        );
+    }
 }
 
 mod message_coin {
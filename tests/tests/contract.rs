@@ -57,6 +57,109 @@ async fn test_contract_balance(
     assert_eq!(balance, test_balance);
 }
 
+#[tokio::test]
+async fn contract_query_returns_bytecode_root() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let code: Vec<u8> = [op::addi(0x10, 0x10, 1), op::ret(0x10)]
+        .into_iter()
+        .collect();
+    let (salt, contract_id) = test_builder.setup_contract(code.clone(), None, None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let contract = client.contract(&contract_id).await.unwrap().unwrap();
+
+    assert_eq!(contract.bytecode, code);
+    assert_eq!(contract.salt, salt);
+    assert_eq!(contract.bytecode_root, Contract::from(code).root());
+}
+
+#[tokio::test]
+async fn contract_slot_values_returns_empty_slots_and_limits_the_request() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let slots = [Bytes32::new([1; 32]), Bytes32::new([2; 32])];
+    let values = client
+        .contract_slot_values(&contract_id, &slots, None)
+        .await
+        .unwrap();
+    assert_eq!(values, vec![(slots[0], None), (slots[1], None)]);
+
+    let too_many = vec![Bytes32::zeroed(); 257];
+    let result = client
+        .contract_slot_values(&contract_id, &too_many, None)
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn contract_snapshot_returns_the_state_of_the_contract() {
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let code: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    let asset = AssetId::new([1; 32]);
+    let (salt, contract_id) =
+        test_builder.setup_contract(code.clone(), Some(vec![(asset, 100)]), None, None);
+
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let snapshot = client
+        .contract_snapshot(&contract_id, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(snapshot.id, contract_id);
+    assert_eq!(snapshot.bytecode, code);
+    assert_eq!(snapshot.salt, salt);
+    assert_eq!(snapshot.slots, vec![]);
+    assert_eq!(snapshot.balances, vec![(asset, 100)]);
+
+    let unknown = client
+        .contract_snapshot(&ContractId::new([9; 32]), None)
+        .await
+        .unwrap();
+    assert!(unknown.is_none());
+}
+
+#[tokio::test]
+async fn top_contracts_requires_the_contract_call_analytics() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.top_contracts(10, None).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn top_contracts_limits_the_window() {
+    let mut config = Config::local_node();
+    config.contract_calls_window = Some(10);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let contracts = client.top_contracts(10, None).await.unwrap();
+    assert_eq!(contracts, vec![]);
+
+    let result = client.top_contracts(11, None).await;
+    assert!(result.is_err());
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_5_contract_balances(
@@ -0,0 +1,40 @@
+use fuel_core::service::{
+    disk_monitor,
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::fuel_tx::Transaction;
+use std::time::Duration;
+
+#[tokio::test]
+async fn node_low_on_disk_space_rejects_transactions() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut node_config = Config::local_node();
+    // No disk has that much free space.
+    node_config.disk_monitor = Some(disk_monitor::Config {
+        path: dir.path().to_path_buf(),
+        interval: Duration::from_millis(10),
+        min_free_space: u64::MAX,
+        resume_free_space: u64::MAX,
+    });
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let monitor = srv.shared.disk_monitor.clone().unwrap();
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !monitor.is_degraded() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The node should switch into the degraded mode");
+
+    let result = client.submit(&Transaction::default_test_tx()).await;
+
+    let error = result.expect_err("The transaction should be rejected");
+    assert!(error
+        .to_string()
+        .contains("doesn't accept new transactions"));
+    // The blocks are still produced.
+    client.produce_blocks(1, None).await.unwrap();
+}
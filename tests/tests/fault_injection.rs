@@ -0,0 +1,113 @@
+use fuel_core::{
+    p2p_test_helpers::*,
+    service::fault_injection::FaultInjectionConfig,
+};
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::time::Duration;
+
+async fn producer_and_validator(
+    seed: u64,
+    faults: FaultInjectionConfig,
+) -> (Node, Node, Nodes) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let secret = SecretKey::random(&mut rng);
+    let pub_key = Input::owner(&secret.public_key());
+    let mut nodes = make_nodes(
+        [Some(BootstrapSetup::new(pub_key))],
+        [Some(
+            ProducerSetup::new(secret)
+                .with_txs(1)
+                .with_name("Alice")
+                .with_faults(faults),
+        )],
+        [Some(ValidatorSetup::new(pub_key).with_name("Bob"))],
+    )
+    .await;
+    let producer = nodes.producers.pop().unwrap();
+    let validator = nodes.validators.pop().unwrap();
+    (producer, validator, nodes)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validator_rejects_the_blocks_with_invalid_seals() {
+    let faults = FaultInjectionConfig {
+        invalid_blocks: true,
+        ..Default::default()
+    };
+    let (mut producer, mut validator, _dont_drop) =
+        producer_and_validator(line!() as u64, faults).await;
+
+    let expected = producer.insert_txs().await;
+    // The faults don't affect the own chain of the producer.
+    producer.consistency_10s(&expected).await;
+
+    let synced =
+        tokio::time::timeout(Duration::from_secs(5), validator.consistency(&expected))
+            .await;
+    assert!(synced.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validator_does_not_sync_without_the_bodies() {
+    let faults = FaultInjectionConfig {
+        withhold_bodies: true,
+        ..Default::default()
+    };
+    let (mut producer, mut validator, _dont_drop) =
+        producer_and_validator(line!() as u64, faults).await;
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+
+    let synced =
+        tokio::time::timeout(Duration::from_secs(5), validator.consistency(&expected))
+            .await;
+    assert!(synced.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validator_follows_the_equivocating_producer_to_another_block() {
+    let faults = FaultInjectionConfig {
+        equivocation: true,
+        ..Default::default()
+    };
+    let (mut producer, mut validator, _dont_drop) =
+        producer_and_validator(line!() as u64, faults).await;
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    validator.consistency_20s(&expected).await;
+
+    // The validator imported the conflicting block, sealed by the same key.
+    let height = producer.db.latest_height().unwrap();
+    let produced = producer
+        .db
+        .get_sealed_block_header_by_height(&height)
+        .unwrap();
+    let imported = validator
+        .db
+        .get_sealed_block_header_by_height(&height)
+        .unwrap();
+    assert_ne!(produced.unwrap().entity.id(), imported.unwrap().entity.id());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validator_syncs_with_the_delayed_gossip() {
+    let faults = FaultInjectionConfig {
+        gossip_delay: Some(Duration::from_secs(1)),
+        ..Default::default()
+    };
+    let (mut producer, mut validator, _dont_drop) =
+        producer_and_validator(line!() as u64, faults).await;
+
+    let expected = producer.insert_txs().await;
+    producer.consistency_10s(&expected).await;
+    validator.consistency_20s(&expected).await;
+}
@@ -0,0 +1,127 @@
+#![allow(non_snake_case)]
+
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::TransactionBuilder;
+use std::time::Duration;
+
+fn config_with_gas_price(starting_gas_price: u64) -> Config {
+    let mut config = Config::local_node();
+    config.gas_price.starting_gas_price = starting_gas_price;
+    config.gas_price.gas_price_change_percent = 10;
+    config
+}
+
+/// Waits until the gas price service processes the new block and changes the price.
+async fn changed_gas_price(client: &FuelClient, gas_price: u64) -> u64 {
+    let mut new_gas_price = client.estimate_gas_price(0).await.unwrap().gas_price;
+    for _ in 0..100 {
+        if new_gas_price != gas_price {
+            break
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        new_gas_price = client.estimate_gas_price(0).await.unwrap().gas_price;
+    }
+    new_gas_price
+}
+
+#[tokio::test]
+async fn estimate_gas_price__next_block_returns_starting_price() {
+    let srv = FuelService::new_node(config_with_gas_price(100))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let estimate = client.estimate_gas_price(0).await.unwrap();
+    assert_eq!(estimate.gas_price, 100);
+
+    let estimate = client.estimate_gas_price(1).await.unwrap();
+    assert_eq!(estimate.gas_price, 100);
+}
+
+#[tokio::test]
+async fn estimate_gas_price__far_horizon_assumes_full_blocks() {
+    let srv = FuelService::new_node(config_with_gas_price(100))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let estimate = client.estimate_gas_price(3).await.unwrap();
+    assert_eq!(estimate.gas_price, 121);
+}
+
+#[tokio::test]
+async fn estimate_gas_price__empty_block_decreases_price() {
+    let srv = FuelService::new_node(config_with_gas_price(100))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    // The gas price service processes the block asynchronously.
+    let gas_price = changed_gas_price(&client, 100).await;
+    assert_eq!(gas_price, 90);
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn estimate_gas_price__continues_from_stored_price_after_restart() {
+    use fuel_core::{
+        database::Database,
+        service::ServiceTrait,
+    };
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+
+    {
+        let database = Database::open(tmp_dir.path(), None, Default::default()).unwrap();
+        let srv = FuelService::from_database(database, config_with_gas_price(100))
+            .await
+            .unwrap();
+        let client = FuelClient::from(srv.bound_address);
+        client.produce_blocks(1, None).await.unwrap();
+        assert_eq!(changed_gas_price(&client, 100).await, 90);
+        srv.stop_and_await().await.unwrap();
+    }
+
+    let database = Database::open(tmp_dir.path(), None, Default::default()).unwrap();
+    let srv = FuelService::from_database(database, config_with_gas_price(100))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let estimate = client.estimate_gas_price(0).await.unwrap();
+    assert_eq!(estimate.gas_price, 90);
+}
+
+#[tokio::test]
+async fn gas_price__is_not_enforced_by_default() {
+    let srv = FuelService::new_node(config_with_gas_price(100))
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The transaction pays less than the estimated gas price of the next block.
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn da_fee_history__is_empty_without_relayer() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let samples = client.da_fee_history(None).await.unwrap();
+    assert!(samples.is_empty());
+}
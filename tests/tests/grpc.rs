@@ -0,0 +1,122 @@
+use fuel_core::{
+    grpc_api::proto::{
+        self,
+        fuel_client::FuelClient,
+        get_block_request,
+        transaction_status,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::*,
+    fuel_types::canonical::Serialize,
+};
+
+async fn node_with_grpc() -> (FuelService, FuelClient<tonic::transport::Channel>) {
+    let mut config = Config::local_node();
+    config.grpc_addr = Some("127.0.0.1:0".parse().unwrap());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let addr = srv.shared.grpc.as_ref().unwrap().bound_address;
+    let client = FuelClient::connect(format!("http://{addr}")).await.unwrap();
+    (srv, client)
+}
+
+fn script_tx() -> Transaction {
+    TransactionBuilder::script(
+        u32::from(op::ret(RegId::ONE)).to_be_bytes().to_vec(),
+        vec![],
+    )
+    .gas_limit(10_000)
+    .add_random_fee_input()
+    .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn submitted_transaction_is_included_in_block() {
+    let (_srv, mut client) = node_with_grpc().await;
+    let tx = script_tx();
+    let tx_id = tx.id(&Default::default());
+
+    let response = client
+        .submit_transaction(proto::SubmitTransactionRequest {
+            transaction: tx.to_bytes(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(response.id, tx_id.to_vec());
+
+    let mut statuses = client
+        .subscribe_transaction_status(proto::SubscribeTransactionStatusRequest {
+            id: tx_id.to_vec(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    let mut last = None;
+    while let Some(status) = statuses.message().await.unwrap() {
+        last = status.status;
+    }
+    let Some(transaction_status::Status::Success(success)) = last.clone() else {
+        panic!("Expected the success status, got {last:?}");
+    };
+
+    let response = client
+        .get_transaction(proto::GetTransactionRequest { id: tx_id.to_vec() })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(response.transaction, tx.to_bytes());
+    assert!(matches!(
+        response.status.and_then(|status| status.status),
+        Some(transaction_status::Status::Success(_))
+    ));
+
+    let by_height = client
+        .get_block(proto::GetBlockRequest {
+            block: Some(get_block_request::Block::Height(1)),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .block
+        .unwrap();
+    assert_eq!(by_height.id, success.block_id);
+    assert_eq!(by_height.header.unwrap().height, 1);
+    assert!(by_height.transaction_ids.contains(&tx_id.to_vec()));
+
+    let by_id = client
+        .get_block(proto::GetBlockRequest {
+            block: Some(get_block_request::Block::Id(success.block_id)),
+        })
+        .await
+        .unwrap()
+        .into_inner()
+        .block
+        .unwrap();
+    assert_eq!(by_id, by_height);
+}
+
+#[tokio::test]
+async fn unknown_transaction_is_not_found() {
+    let (_srv, mut client) = node_with_grpc().await;
+
+    let status = client
+        .get_transaction(proto::GetTransactionRequest { id: vec![1; 32] })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+
+    let status = client
+        .get_transaction(proto::GetTransactionRequest { id: vec![1; 31] })
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
@@ -5,7 +5,11 @@ use fuel_core::{
         FuelService,
     },
 };
-use fuel_core_client::client::FuelClient;
+use fuel_core_client::client::{
+    failover::RetryConfig,
+    FuelClient,
+};
+use std::time::Duration;
 
 #[tokio::test]
 async fn health() {
@@ -18,6 +22,54 @@ async fn health() {
     assert!(health);
 }
 
+#[tokio::test]
+async fn health_fails_over_to_next_url() {
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    // Nobody listens on the port of the dropped listener.
+    let unreachable = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client =
+        FuelClient::with_urls(&[unreachable.to_string(), srv.bound_address.to_string()])
+            .unwrap()
+            .with_retry_config(RetryConfig {
+                initial_backoff: Duration::from_millis(1),
+                ..Default::default()
+            });
+
+    let health = client.health().await.unwrap();
+    assert!(health);
+
+    let endpoints = client.endpoints_health();
+    assert_eq!(endpoints[0].consecutive_failures, 1);
+    assert!(!endpoints[0].active);
+    assert_eq!(endpoints[1].consecutive_failures, 0);
+    assert!(endpoints[1].active);
+}
+
+#[tokio::test]
+async fn health_fails_after_max_attempts() {
+    let unreachable = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let client = FuelClient::with_urls(&[unreachable.to_string()])
+        .unwrap()
+        .with_retry_config(RetryConfig {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(1),
+            ..Default::default()
+        });
+
+    let result = client.health().await;
+
+    assert!(result.is_err());
+    assert_eq!(client.endpoints_health()[0].consecutive_failures, 2);
+}
+
 #[cfg(feature = "default")]
 #[tokio::test]
 async fn can_restart_node() {
@@ -27,7 +79,7 @@ async fn can_restart_node() {
     // start node once
     {
         use fuel_core::service::ServiceTrait;
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, Default::default()).unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -35,7 +87,7 @@ async fn can_restart_node() {
     }
 
     {
-        let database = Database::open(tmp_dir.path(), None).unwrap();
+        let database = Database::open(tmp_dir.path(), None, Default::default()).unwrap();
         let _second_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
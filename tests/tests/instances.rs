@@ -0,0 +1,46 @@
+use fuel_core::service::{
+    Config,
+    FuelServices,
+};
+use fuel_core_client::client::FuelClient;
+
+fn instance_config(name: &str) -> Config {
+    let mut config = Config::local_node();
+    config.name = name.to_string();
+    config.chain_conf.chain_name = format!("{name}-chain");
+    config
+}
+
+#[tokio::test]
+async fn instances_run_independent_chains() {
+    let services = FuelServices::new_nodes(vec![
+        instance_config("first"),
+        instance_config("second"),
+    ])
+    .await
+    .unwrap();
+    let first = FuelClient::from(services.instance("first").unwrap().bound_address);
+    let second = FuelClient::from(services.instance("second").unwrap().bound_address);
+
+    first.produce_blocks(3, None).await.unwrap();
+
+    let first_info = first.chain_info().await.unwrap();
+    let second_info = second.chain_info().await.unwrap();
+    assert_eq!(first_info.name, "first-chain");
+    assert_eq!(second_info.name, "second-chain");
+    assert_eq!(first_info.latest_block.header.height, 3);
+    assert_eq!(second_info.latest_block.header.height, 0);
+
+    services.stop_and_await().await;
+}
+
+#[tokio::test]
+async fn instances_with_the_same_name_are_rejected() {
+    let result = FuelServices::new_nodes(vec![
+        instance_config("instance"),
+        instance_config("instance"),
+    ])
+    .await;
+
+    assert!(result.is_err());
+}
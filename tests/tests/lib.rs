@@ -1,5 +1,8 @@
 #![deny(unused_must_use)]
 
+mod account_watcher;
+mod admin_api;
+mod asset_registry;
 mod balances;
 mod blocks;
 mod chain;
@@ -7,19 +10,33 @@ mod coin;
 mod coins;
 mod contract;
 mod dap;
+mod disk_monitor;
 mod debugger;
 mod deployment;
+#[cfg(feature = "fault-injection")]
+mod fault_injection;
+mod gas_price;
+#[cfg(feature = "grpc")]
+mod grpc;
 mod health;
 mod helpers;
+mod instances;
 mod messages;
 mod metrics;
 mod node_info;
+mod persisted_queries;
 mod poa;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod replay;
+mod resumable_subscriptions;
 mod snapshot;
+mod startup_checks;
 #[cfg(feature = "p2p")]
 mod sync;
+mod test_node;
+mod test_vector;
+mod transaction_helper;
 mod trigger_integration;
 mod tx;
 #[cfg(feature = "p2p")]
@@ -278,6 +278,69 @@ async fn message_status__can_get_spent() {
     assert_eq!(status, MessageStatus::Spent);
 }
 
+#[tokio::test]
+async fn message_status__reports_the_da_height_and_the_spending_transaction() {
+    // Given
+    let msg_recipient = Address::from([1; 32]);
+    let output_recipient = Address::from([2; 32]);
+    let msg_sender = Address::from([3; 32]);
+
+    let nonce = 1.into();
+    let amount = 1_000;
+
+    let msg = MessageConfig {
+        sender: msg_sender,
+        recipient: msg_recipient,
+        nonce,
+        amount,
+        da_height: 5u64.into(),
+        ..Default::default()
+    };
+
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        messages: Some(vec![msg]),
+        ..Default::default()
+    });
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let input = Input::message_coin_signed(
+        msg_sender,
+        msg_recipient,
+        amount,
+        nonce,
+        Default::default(),
+    );
+    let output = Output::coin(output_recipient, amount, Default::default());
+    let tx: Transaction = Transaction::script(
+        Default::default(),
+        1_000_000,
+        Default::default(),
+        vec![],
+        vec![],
+        vec![input],
+        vec![output],
+        vec![Vec::new().into()],
+    )
+    .into();
+    let tx_id = tx.id(&ChainId::default());
+
+    // When
+    let relayed = client.message_provenance(&nonce).await.unwrap();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    let spent = client.message_provenance(&nonce).await.unwrap();
+
+    // Then
+    assert_eq!(relayed.status, MessageStatus::Unspent);
+    assert_eq!(relayed.da_height, Some(5));
+    assert_eq!(relayed.spending_transaction_id, None);
+    assert_eq!(spent.status, MessageStatus::Spent);
+    assert_eq!(spent.spending_transaction_id, Some(tx_id));
+    assert_eq!(spent.spending_block_height, Some(1));
+}
+
 #[tokio::test]
 async fn message_status__can_get_notfound() {
     // Given
@@ -3,9 +3,13 @@ use fuel_core::service::{
     FuelService,
 };
 use fuel_core_client::client::{
-    types::NodeInfo,
+    types::{
+        NodeInfo,
+        SyncStatus,
+    },
     FuelClient,
 };
+use std::time::Duration;
 
 #[tokio::test]
 async fn node_info() {
@@ -28,3 +32,128 @@ async fn node_info() {
     assert_eq!(max_depth, node_config.txpool.max_depth as u64);
     assert_eq!(max_tx, node_config.txpool.max_tx as u64);
 }
+
+#[tokio::test]
+async fn table_stats_are_null_when_disabled() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let stats = client.table_stats().await.unwrap();
+
+    assert_eq!(stats, None);
+}
+
+#[tokio::test]
+async fn table_stats_are_null_when_interval_is_zero() {
+    let mut node_config = Config::local_node();
+    node_config.storage_stats_interval = Some(Duration::ZERO);
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let stats = client.table_stats().await.unwrap();
+
+    assert_eq!(stats, None);
+}
+
+#[tokio::test]
+async fn table_stats_report_all_tables() {
+    let mut node_config = Config::local_node();
+    node_config.storage_stats_interval = Some(Duration::from_millis(100));
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    let stats = client.table_stats().await.unwrap().unwrap();
+
+    let blocks = stats
+        .iter()
+        .find(|stats| stats.table == "FuelBlocks")
+        .expect("The stats should include all tables");
+    assert_eq!(blocks.keys, 2);
+    assert!(blocks.size > 0);
+}
+
+#[tokio::test]
+async fn sync_status_of_the_standalone_node() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(2, None).await.unwrap();
+
+    let status = client.sync_status().await.unwrap();
+
+    assert_eq!(
+        status,
+        SyncStatus {
+            is_syncing: false,
+            current_height: 2,
+            network_height: None,
+            time_to_tip: None,
+            da_lag: None,
+            da_error: None,
+            committer_balance_low: None,
+        }
+    );
+}
+
+#[cfg(feature = "p2p")]
+#[tokio::test]
+async fn signed_node_info_is_verified_by_the_p2p_identity_key() {
+    let node_config = Config::local_node();
+    let secret_key = node_config
+        .p2p
+        .as_ref()
+        .and_then(|p2p| p2p.identity_secret_key())
+        .unwrap();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+    let challenge = [7u8; 32].into();
+
+    let info = client.signed_node_info(Some(&challenge)).await.unwrap();
+
+    assert!(info.verify());
+    assert_eq!(info.public_key, secret_key.public_key());
+    assert_eq!(info.attestation.block_height, 1u32.into());
+    assert_eq!(info.attestation.challenge, Some(challenge));
+    assert_eq!(
+        info.attestation.chain_id,
+        node_config.chain_conf.consensus_parameters.chain_id
+    );
+
+    // The changed information doesn't match the signature.
+    let mut changed = info.clone();
+    changed.attestation.node_version = "0.0.0".to_string();
+    assert!(!changed.verify());
+}
+
+#[cfg(feature = "p2p")]
+#[tokio::test]
+async fn signed_node_info_is_rate_limited() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut results = vec![];
+    for _ in 0..100 {
+        results.push(client.signed_node_info(None).await);
+    }
+
+    assert!(results.iter().any(|result| result.is_ok()));
+    assert!(results.iter().any(|result| result.is_err()));
+}
+
+#[tokio::test]
+async fn signed_node_info_requires_the_p2p_identity_key() {
+    #[allow(unused_mut)]
+    let mut node_config = Config::local_node();
+    #[cfg(feature = "p2p")]
+    {
+        node_config.p2p = None;
+    }
+    let srv = FuelService::new_node(node_config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = client.signed_node_info(None).await;
+
+    assert!(result.is_err());
+}
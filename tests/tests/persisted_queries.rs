@@ -0,0 +1,87 @@
+use fuel_core::{
+    fuel_core_graphql_api::persisted_queries::{
+        query_hash,
+        PersistedQueriesConfig,
+        PERSISTED_QUERY_NOT_FOUND,
+        QUERY_NOT_ALLOWED,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use reqwest::header::CONTENT_TYPE;
+use serde_json::{
+    json,
+    Value,
+};
+
+const QUERY: &str = "query { chain { name } }";
+
+async fn post(srv: &FuelService, body: Value) -> Value {
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/graphql", srv.bound_address))
+        .header(CONTENT_TYPE, "application/json")
+        .body(body.to_string())
+        .send()
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    serde_json::from_str(&response).unwrap()
+}
+
+fn persisted_query(hash: &str) -> Value {
+    json!({ "persistedQuery": { "version": 1, "sha256Hash": hash } })
+}
+
+fn error(response: &Value) -> &str {
+    response["errors"][0]["message"].as_str().unwrap()
+}
+
+#[tokio::test]
+async fn persisted_query_is_executed_by_the_hash_after_the_registration() {
+    let mut config = Config::local_node();
+    config.persisted_queries = Some(PersistedQueriesConfig {
+        cache_size: 10,
+        allow_list: None,
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let hash = query_hash(QUERY);
+
+    let response = post(&srv, json!({ "extensions": persisted_query(&hash) })).await;
+    assert_eq!(error(&response), PERSISTED_QUERY_NOT_FOUND);
+
+    let registered = post(
+        &srv,
+        json!({ "query": QUERY, "extensions": persisted_query(&hash) }),
+    )
+    .await;
+    let response = post(&srv, json!({ "extensions": persisted_query(&hash) })).await;
+    assert_eq!(response["data"], registered["data"]);
+    assert!(response["data"]["chain"]["name"].is_string());
+}
+
+#[tokio::test]
+async fn allow_list_mode_rejects_other_queries() {
+    let mut config = Config::local_node();
+    config.persisted_queries = Some(PersistedQueriesConfig {
+        cache_size: 0,
+        allow_list: Some(vec![QUERY.to_string()]),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+
+    let response = post(
+        &srv,
+        json!({ "extensions": persisted_query(&query_hash(QUERY)) }),
+    )
+    .await;
+    assert!(response["data"]["chain"]["name"].is_string());
+
+    let response = post(&srv, json!({ "query": "query {\n  chain { name }\n}" })).await;
+    assert!(response["data"]["chain"]["name"].is_string());
+
+    let response = post(&srv, json!({ "query": "query { health }" })).await;
+    assert_eq!(error(&response), QUERY_NOT_ALLOWED);
+}
@@ -1,4 +1,5 @@
 use fuel_core::{
+    chain_config::ConsensusConfig,
     database::Database,
     service::{
         Config,
@@ -15,7 +16,10 @@ use fuel_core_types::{
         primitives::BlockId,
     },
     fuel_crypto::SecretKey,
-    fuel_tx::Transaction,
+    fuel_tx::{
+        Input,
+        Transaction,
+    },
     secrecy::Secret,
 };
 use rand::{
@@ -85,14 +89,93 @@ async fn can_get_sealed_block_from_poa_produced_block() {
         .expect("failed to verify signature");
 }
 
+#[tokio::test]
+async fn multisig_poa_seals_block_with_all_configured_keys() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let secrets: Vec<_> = (0..3).map(|_| SecretKey::random(&mut rng)).collect();
+
+    let db = Database::default();
+    let mut config = Config::local_node();
+    config.chain_conf.consensus = ConsensusConfig::PoAMultiSig {
+        signing_keys: secrets
+            .iter()
+            .map(|secret| Input::owner(&secret.public_key()))
+            .collect(),
+        threshold: 2,
+    };
+    config.consensus_key = Some(Secret::new(secrets[0].into()));
+    config.consensus_cosigner_keys = vec![Secret::new(secrets[1].into())];
+    let chain_conf = config.chain_conf.clone();
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    let (_, block_id) = db.ids_of_latest_block().unwrap().unwrap();
+    let sealed_block_header = db
+        .get_sealed_block_header(&block_id)
+        .unwrap()
+        .expect("expected sealed header to be available");
+    match &sealed_block_header.consensus {
+        Consensus::PoAMultiSig(multisig) => assert_eq!(multisig.signatures.len(), 2),
+        _ => panic!("Not expected consensus"),
+    }
+    assert!(fuel_core_poa::verifier::verify_consensus(
+        &chain_conf.consensus,
+        &sealed_block_header.entity,
+        &sealed_block_header.consensus,
+    ));
+}
+
+#[tokio::test]
+async fn validator_imports_batch_of_poa_blocks() {
+    let producer = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(producer.bound_address);
+    client.produce_blocks(3, None).await.unwrap();
+    let blocks: Vec<_> = (1..=3u32)
+        .map(|height| {
+            producer
+                .shared
+                .database
+                .get_sealed_block_by_height(&height.into())
+                .unwrap()
+                .unwrap()
+        })
+        .collect();
+
+    let mut config = Config::local_node();
+    config.block_production = fuel_core_poa::Trigger::Never;
+    // The blocks are committed with one write, so the blocks after the first one
+    // are verified against the uncommitted state of the batch.
+    config.block_importer.max_commit_batch_latency = std::time::Duration::from_secs(60);
+    let validator = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+
+    let result = validator
+        .shared
+        .block_importer
+        .execute_and_commit_batch(blocks)
+        .await
+        .unwrap();
+
+    assert!(result.error.is_none(), "{:?}", result.error);
+    assert_eq!(result.committed, 3);
+    assert_eq!(
+        validator.shared.database.latest_height().unwrap(),
+        3u32.into()
+    );
+}
+
 #[cfg(feature = "p2p")]
 mod p2p {
     use super::*;
     use fuel_core::{
-        chain_config::{
-            ChainConfig,
-            ConsensusConfig,
-        },
+        chain_config::ChainConfig,
         p2p_test_helpers::{
             make_config,
             make_node,
@@ -101,7 +184,6 @@ mod p2p {
         service::ServiceTrait,
     };
     use fuel_core_poa::Trigger;
-    use fuel_core_types::fuel_tx::Input;
     use std::time::Duration;
 
     // Starts first_producer which creates some blocks
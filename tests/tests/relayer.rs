@@ -1,10 +1,6 @@
-use ethers::{
-    providers::Middleware,
-    types::{
-        Log,
-        SyncingStatus,
-        U256,
-    },
+use ethers::types::{
+    Log,
+    U256,
 };
 use fuel_core::{
     database::Database,
@@ -20,11 +16,15 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        message::MessageStatus,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_relayer::{
     test_helpers::{
+        eth_node::spawn_eth_node,
         middleware::MockMiddleware,
         EvtToLog,
         LogTestHelper,
@@ -39,33 +39,17 @@ use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx::*,
-    fuel_types::Nonce,
-};
-use hyper::{
-    service::{
-        make_service_fn,
-        service_fn,
+    fuel_types::{
+        ChainId,
+        Nonce,
     },
-    Body,
-    Request,
-    Response,
-    Server,
 };
 use rand::{
     prelude::StdRng,
     Rng,
     SeedableRng,
 };
-use serde_json::json;
-use std::{
-    convert::Infallible,
-    net::{
-        Ipv4Addr,
-        SocketAddr,
-    },
-    sync::Arc,
-};
-use tokio::sync::oneshot::Sender;
+use std::sync::Arc;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn relayer_can_download_logs() {
@@ -228,6 +212,86 @@ async fn messages_are_spendable_after_relayer_is_synced() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn deposit_is_tracked_from_da_transaction_to_spending() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+
+    let secret_key: SecretKey = SecretKey::random(&mut rng);
+    let recipient = Input::owner(&secret_key.public_key());
+    let sender = Address::zeroed();
+    let amount = 100;
+    let nonce = Nonce::from(2u64);
+    let da_transaction = Bytes32::new([7; 32]);
+    let mut log = make_message_event(
+        nonce,
+        5,
+        contract_address,
+        Some(sender.into()),
+        Some(recipient.into()),
+        Some(amount),
+        None,
+    );
+    log.transaction_hash = Some((*da_transaction).into());
+    eth_node.update_data(|data| data.logs_batch = vec![vec![log]]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node = Arc::new(eth_node);
+    let eth_node_handle = spawn_eth_node(eth_node).await;
+
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    config.utxo_validation = true;
+
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // the deposit is received by the relayer, but not spent yet
+    let deposits = client
+        .deposits_by_da_transaction(&da_transaction)
+        .await
+        .unwrap();
+    assert_eq!(deposits.len(), 1);
+    let deposit = &deposits[0];
+    assert_eq!(deposit.nonce, nonce);
+    assert_eq!(deposit.status, MessageStatus::Unspent);
+    assert_eq!(deposit.da_transaction_hash, Some(da_transaction));
+    assert_eq!(deposit.da_height, Some(5));
+    assert_eq!(deposit.message.as_ref().map(|m| m.amount), Some(amount));
+    assert_eq!(deposit.spending_transaction_id, None);
+
+    let tx = TransactionBuilder::script(vec![op::ret(0)].into_iter().collect(), vec![])
+        .gas_limit(10_000)
+        .gas_price(0)
+        .add_unsigned_message_input(secret_key, sender, nonce, amount, vec![])
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize();
+    let tx_id = tx.id(&ChainId::default());
+    let status = client.submit_and_await_commit(&tx.into()).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    // the deposit is still tracked after the message is spent
+    let deposit = client.deposit(&nonce).await.unwrap();
+    assert_eq!(deposit.status, MessageStatus::Spent);
+    assert_eq!(deposit.da_transaction_hash, Some(da_transaction));
+    assert!(deposit.message.is_none());
+    assert_eq!(deposit.spending_transaction_id, Some(tx_id));
+    assert_eq!(deposit.spending_block_height, Some(1));
+
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
 fn make_message_event(
     nonce: Nonce,
     block_number: u64,
@@ -249,91 +313,3 @@ fn make_message_event(
     log.block_number = Some(block_number.into());
     log
 }
-
-async fn spawn_eth_node(eth_node: Arc<MockMiddleware>) -> EthNodeHandle {
-    // Construct our SocketAddr to listen on...
-    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 0));
-
-    // And a MakeService to handle each connection...
-    let make_service = make_service_fn(move |_conn| {
-        let eth_node = eth_node.clone();
-        async move {
-            Ok::<_, Infallible>(service_fn({
-                let eth_node = eth_node.clone();
-                move |req| handle(eth_node.clone(), req)
-            }))
-        }
-    });
-
-    // Then bind and serve...
-    let server = Server::bind(&addr).serve(make_service);
-    let addr = server.local_addr();
-
-    let (shutdown, rx) = tokio::sync::oneshot::channel();
-
-    tokio::spawn(async move {
-        let graceful = server.with_graceful_shutdown(async {
-            rx.await.ok();
-        });
-        // And run forever...
-        if let Err(e) = graceful.await {
-            eprintln!("server error: {e}");
-        }
-    });
-    EthNodeHandle {
-        shutdown,
-        address: addr,
-    }
-}
-
-pub(crate) struct EthNodeHandle {
-    pub(crate) shutdown: Sender<()>,
-    pub(crate) address: SocketAddr,
-}
-
-async fn handle(
-    mock: Arc<MockMiddleware>,
-    req: Request<Body>,
-) -> Result<Response<Body>, Infallible> {
-    let body = hyper::body::to_bytes(req).await.unwrap();
-
-    let v: serde_json::Value = serde_json::from_slice(body.as_ref()).unwrap();
-    let mut o = match v {
-        serde_json::Value::Object(o) => o,
-        _ => unreachable!(),
-    };
-    let id = o.get("id").unwrap().as_u64().unwrap();
-    let method = o.get("method").unwrap().as_str().unwrap();
-    let r = match method {
-        "eth_getBlockByNumber" => {
-            let r = mock.get_block(id).await.unwrap().unwrap();
-            json!({ "id": id, "jsonrpc": "2.0", "result": r })
-        }
-        "eth_syncing" => {
-            let r = mock.syncing().await.unwrap();
-            match r {
-                SyncingStatus::IsFalse => {
-                    json!({ "id": id, "jsonrpc": "2.0", "result": false })
-                }
-                SyncingStatus::IsSyncing(status) => {
-                    json!({ "id": id, "jsonrpc": "2.0", "result": {
-                        "starting_block": status.starting_block, 
-                        "current_block": status.current_block,
-                        "highest_block": status.highest_block,
-                    } })
-                }
-            }
-        }
-        "eth_getLogs" => {
-            let params = o.remove("params").unwrap();
-            let params: Vec<_> = serde_json::from_value(params).unwrap();
-            let r = mock.get_logs(&params[0]).await.unwrap();
-            json!({ "id": id, "jsonrpc": "2.0", "result": r })
-        }
-        _ => unreachable!("Mock handler for method not defined"),
-    };
-
-    let r = serde_json::to_vec(&r).unwrap();
-
-    Ok(Response::new(Body::from(r)))
-}
@@ -0,0 +1,120 @@
+use fuel_core::service::{
+    replay::{
+        replay,
+        Divergence,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_storage::{
+    tables::Receipts,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Finalizable,
+        Transaction,
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+};
+
+fn script_tx(value: u16) -> Transaction {
+    let script = [
+        op::addi(0x10, RegId::ZERO, value),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction()
+}
+
+async fn node_with_transactions(number_of_txs: u16) -> (FuelService, Vec<Transaction>) {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut txs = vec![];
+    for value in 0..number_of_txs {
+        let tx = script_tx(value);
+        client.submit_and_await_commit(&tx).await.unwrap();
+        txs.push(tx);
+    }
+    (srv, txs)
+}
+
+#[tokio::test]
+async fn replay_matches_the_stored_blocks() {
+    let (srv, _) = node_with_transactions(3).await;
+    let database = &srv.shared.database;
+    let latest_height = database.latest_height().unwrap();
+
+    let divergence =
+        replay(database, &srv.shared.config, 1u32.into(), latest_height).unwrap();
+
+    assert_eq!(divergence, None);
+}
+
+#[tokio::test]
+async fn replay_reports_the_first_corrupted_receipts() {
+    let (srv, txs) = node_with_transactions(3).await;
+    let mut database = srv.shared.database.clone();
+    let latest_height = database.latest_height().unwrap();
+    let chain_id = srv.shared.config.chain_conf.consensus_parameters.chain_id;
+
+    // Corrupt the receipts of the second and the third transactions.
+    for tx in &txs[1..] {
+        database
+            .storage::<Receipts>()
+            .insert(&tx.id(&chain_id), &vec![])
+            .unwrap();
+    }
+
+    let divergence =
+        replay(&database, &srv.shared.config, 1u32.into(), latest_height).unwrap();
+
+    match divergence {
+        Some(Divergence::Receipts {
+            tx_id,
+            expected,
+            actual,
+            ..
+        }) => {
+            assert_eq!(tx_id, txs[1].id(&chain_id));
+            assert!(expected.is_empty());
+            assert!(!actual.is_empty());
+        }
+        divergence => panic!("Unexpected divergence: {divergence:?}"),
+    }
+
+    // Each transaction is in its own block, so the first block is not corrupted.
+    let divergence =
+        replay(&database, &srv.shared.config, 1u32.into(), 1u32.into()).unwrap();
+    assert_eq!(divergence, None);
+}
+
+#[tokio::test]
+async fn replay_rejects_another_chain_config() {
+    let (srv, _) = node_with_transactions(1).await;
+    let database = &srv.shared.database;
+    let latest_height = database.latest_height().unwrap();
+
+    // The genesis block of another chain config has another state.
+    let mut config = srv.shared.config.clone();
+    config.chain_conf.initial_state = None;
+
+    let result = replay(database, &config, 1u32.into(), latest_height);
+
+    assert!(result.is_err());
+}
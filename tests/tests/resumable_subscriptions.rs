@@ -0,0 +1,77 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        TransactionBuilder,
+        UniqueIdentifier,
+    },
+    fuel_types::ChainId,
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn block_events_resume_after_the_cursor() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let mut events = client.subscribe_block_events(None).await.unwrap();
+    client.produce_blocks(1, None).await.unwrap();
+    let (cursor, block) = events.next().await.unwrap().unwrap();
+    assert_eq!(block.header.height, 1);
+    drop(events);
+
+    // The blocks are produced while the client is disconnected.
+    client.produce_blocks(2, None).await.unwrap();
+
+    let mut events = client.subscribe_block_events(Some(&cursor)).await.unwrap();
+    let (_, block) = events.next().await.unwrap().unwrap();
+    assert_eq!(block.header.height, 2);
+    let (_, block) = events.next().await.unwrap().unwrap();
+    assert_eq!(block.header.height, 3);
+}
+
+#[tokio::test]
+async fn transaction_status_events_resume_after_the_cursor() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let tx_id = tx.id(&ChainId::default());
+
+    let mut events = client
+        .subscribe_transaction_status_events(&tx_id, None)
+        .await
+        .unwrap();
+    client.submit(&tx).await.unwrap();
+    let (cursor, status) = events.next().await.unwrap().unwrap();
+    assert!(matches!(status, TransactionStatus::Submitted { .. }));
+    drop(events);
+
+    let mut events = client
+        .subscribe_transaction_status_events(&tx_id, Some(&cursor))
+        .await
+        .unwrap();
+    let (_, status) = events.next().await.unwrap().unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn block_events_reject_the_cursor_from_another_run() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let result = match client.subscribe_block_events(Some("0-0")).await {
+        Ok(mut events) => events.next().await.map(|event| event.map(|_| ())),
+        Err(e) => Some(Err(e)),
+    };
+
+    assert!(matches!(result, Some(Err(_))));
+}
@@ -0,0 +1,44 @@
+use fuel_core::{
+    database::Database,
+    service::{
+        Config,
+        FuelService,
+        ServiceTrait,
+        StartupChecks,
+    },
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_storage::{
+    tables::Transactions,
+    StorageAsMut,
+};
+use fuel_core_types::fuel_tx::Transaction;
+
+#[tokio::test]
+async fn node_with_the_missing_transaction_fails_the_startup_checks() {
+    let mut db = Database::default();
+    let srv = FuelService::from_database(db.clone(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client
+        .submit_and_await_commit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+    srv.stop_and_await().await.unwrap();
+
+    let mut config = Config::local_node();
+    config.startup_checks = StartupChecks::Full;
+    let srv = FuelService::from_database(db.clone(), config.clone())
+        .await
+        .unwrap();
+    srv.stop_and_await().await.unwrap();
+
+    let tx_id = db.get_current_block().unwrap().unwrap().transactions()[0];
+    db.storage::<Transactions>().remove(&tx_id).unwrap();
+    config.startup_checks = StartupChecks::Fast;
+    let result = FuelService::from_database(db, config).await;
+
+    let error = result.err().expect("The startup checks should fail");
+    assert!(error.to_string().contains("is missing"));
+}
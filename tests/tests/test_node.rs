@@ -0,0 +1,58 @@
+use fuel_core::test_helpers::TestNodeBuilder;
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::tai64::Tai64;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_node_produces_blocks_with_timestamps_of_its_clock() {
+    let start_time = Tai64(Tai64::UNIX_EPOCH.0 + 100);
+    let mut node = TestNodeBuilder::new()
+        .with_start_time(start_time)
+        .with_block_time(Duration::from_secs(10))
+        .start()
+        .await
+        .unwrap();
+
+    // The node doesn't produce blocks by itself.
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(*node.database.latest_height().unwrap(), 0);
+
+    let height = node.produce_blocks(2).await.unwrap();
+    assert_eq!(*height, 2);
+    assert_eq!(node.now().0, start_time.0 + 20);
+
+    node.advance_time(Duration::from_secs(100));
+    let height = node.produce_blocks(1).await.unwrap();
+    assert_eq!(*height, 3);
+
+    let db = &node.database;
+    assert_eq!(db.block_time(&1u32.into()).unwrap().0, start_time.0 + 10);
+    assert_eq!(db.block_time(&2u32.into()).unwrap().0, start_time.0 + 20);
+    assert_eq!(db.block_time(&3u32.into()).unwrap().0, start_time.0 + 130);
+
+    let client = FuelClient::from(node.bound_address());
+    let chain = client.chain_info().await.unwrap();
+    assert_eq!(chain.latest_block.header.height, 3);
+
+    node.shutdown().await.unwrap();
+}
+
+#[cfg(feature = "relayer")]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_node_syncs_relayer_with_mock_eth_node() {
+    use fuel_core_relayer::test_helpers::middleware::MockMiddleware;
+    use std::sync::Arc;
+
+    let eth_node = Arc::new(MockMiddleware::default());
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let node = TestNodeBuilder::new()
+        .with_mock_relayer(eth_node)
+        .start()
+        .await
+        .unwrap();
+
+    assert!(node.eth_node_address().is_some());
+    node.await_relayer_synced().await.unwrap();
+
+    node.shutdown().await.unwrap();
+}
@@ -0,0 +1,95 @@
+use fuel_core::service::{
+    replay::Divergence,
+    test_vector::{
+        export,
+        run,
+        TestVector,
+    },
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::FuelClient;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        Finalizable,
+        Receipt,
+        TransactionBuilder,
+    },
+};
+
+async fn exported_vector() -> (FuelService, TestVector) {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(1, None).await.unwrap();
+    let script = [
+        op::addi(0x10, RegId::ZERO, 7),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(
+        script
+            .iter()
+            .flat_map(|op| u32::from(*op).to_be_bytes())
+            .collect(),
+        vec![],
+    )
+    .gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+
+    let database = &srv.shared.database;
+    let height = database.latest_height().unwrap();
+    let vector = export(database, &srv.shared.config, height).unwrap();
+    (srv, vector)
+}
+
+#[tokio::test]
+async fn exported_vector_runs_without_the_node_database() {
+    let (srv, vector) = exported_vector().await;
+    let json = serde_json::to_string(&vector).unwrap();
+    let vector: TestVector = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(vector.receipts.len(), vector.block.transactions().len());
+    assert!(!vector.state.is_empty());
+    assert!(!vector.state_diff.changes.is_empty());
+    let divergence = run(&vector, &srv.shared.config).unwrap();
+    assert_eq!(divergence, None);
+}
+
+#[tokio::test]
+async fn run_reports_the_changed_receipts() {
+    let (srv, mut vector) = exported_vector().await;
+    let tx_id = vector.receipts[0].tx_id;
+    vector.receipts[0]
+        .receipts
+        .push(Receipt::ret(Default::default(), 0, 0, 0));
+
+    let divergence = run(&vector, &srv.shared.config).unwrap();
+
+    assert!(matches!(
+        divergence,
+        Some(Divergence::Receipts { tx_id: id, .. }) if id == tx_id
+    ));
+}
+
+#[tokio::test]
+async fn run_reports_the_changed_state() {
+    let (srv, mut vector) = exported_vector().await;
+    let removed = vector.state_diff.changes.pop();
+
+    let divergence = run(&vector, &srv.shared.config).unwrap();
+
+    assert_eq!(
+        divergence,
+        Some(Divergence::StateChange {
+            height: vector.state_diff.height,
+            expected: None,
+            actual: removed,
+        })
+    );
+}
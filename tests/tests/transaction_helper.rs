@@ -0,0 +1,176 @@
+use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    transaction_helper::TransactionHelper,
+    types::{
+        CoinType,
+        TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::Witnesses,
+        Address,
+        AssetId,
+        Input,
+        Output,
+        TransactionBuilder,
+        TxPointer,
+        UniqueIdentifier,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+
+async fn setup(owner: Address) -> FuelService {
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(
+            [1_000_000, 2_000_000]
+                .into_iter()
+                .map(|amount| CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    maturity: None,
+                    owner,
+                    amount,
+                    asset_id: AssetId::BASE,
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    });
+    FuelService::new_node(config).await.unwrap()
+}
+
+#[tokio::test]
+async fn build_transfer_creates_valid_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let recipient = Address::from([5u8; 32]);
+    let srv = setup(owner).await;
+    let client = FuelClient::from(srv.bound_address);
+    let helper = TransactionHelper::new(client.clone());
+
+    let tx = helper
+        .build_transfer(secret, recipient, 1_500_000, AssetId::BASE)
+        .await
+        .unwrap();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let balance = client.balance(&recipient, None).await.unwrap();
+    assert_eq!(balance, 1_500_000);
+}
+
+#[tokio::test]
+async fn consensus_parameters_are_the_same_as_in_chain_info() {
+    let srv = setup(Address::default()).await;
+    let client = FuelClient::from(srv.bound_address);
+    let helper = TransactionHelper::new(client.clone());
+
+    let cached = helper.consensus_parameters().await.unwrap();
+    assert_eq!(helper.consensus_parameters_height(), Some(0));
+    client.produce_blocks(1, None).await.unwrap();
+    // Without the pending upgrades the parameters aren't fetched again.
+    assert_eq!(helper.consensus_parameters().await.unwrap(), cached);
+    assert_eq!(helper.consensus_parameters_height(), Some(0));
+
+    helper.invalidate();
+    let refetched = helper.consensus_parameters().await.unwrap();
+    assert_eq!(helper.consensus_parameters_height(), Some(1));
+
+    let expected = client.chain_info().await.unwrap().consensus_parameters;
+    assert_eq!(cached, expected);
+    assert_eq!(refetched, expected);
+}
+
+#[tokio::test]
+async fn sponsor_pays_fee_of_user_transaction() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let user_secret = SecretKey::random(&mut rng);
+    let user = Input::owner(&user_secret.public_key());
+    let sponsor_secret = SecretKey::random(&mut rng);
+    let sponsor = Input::owner(&sponsor_secret.public_key());
+    let recipient = Address::from([5u8; 32]);
+    let asset_id = AssetId::from([7u8; 32]);
+    let coin = |owner, asset_id| CoinConfig {
+        tx_id: None,
+        output_index: None,
+        tx_pointer_block_height: None,
+        tx_pointer_tx_idx: None,
+        maturity: None,
+        owner,
+        amount: 1_000_000,
+        asset_id,
+    };
+
+    let mut config = Config::local_node();
+    config.utxo_validation = true;
+    config.txpool.sponsorship.sponsors.insert(sponsor, 10);
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![coin(user, asset_id), coin(sponsor, AssetId::BASE)]),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let helper = TransactionHelper::new(client.clone());
+
+    // The user doesn't have the base asset to pay the fee.
+    let CoinType::Coin(user_coin) = client
+        .coins_to_spend(&user, vec![(asset_id, 1_000_000, None)], None)
+        .await
+        .unwrap()
+        .remove(0)
+        .remove(0)
+    else {
+        panic!("Expected the coin of the user")
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_price(1)
+        .gas_limit(10_000)
+        .add_unsigned_coin_input(
+            user_secret,
+            user_coin.utxo_id,
+            user_coin.amount,
+            asset_id,
+            TxPointer::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(recipient, 1_000_000, asset_id))
+        .finalize_as_transaction();
+
+    let mut tx = helper.sponsor(tx, sponsor_secret).await.unwrap();
+    // The user signs the transaction after the sponsor added its inputs.
+    let chain_id = helper.consensus_parameters().await.unwrap().chain_id;
+    let signature =
+        Signature::sign(&user_secret, &Message::from_bytes(*tx.id(&chain_id)));
+    tx.as_script_mut().unwrap().witnesses_mut()[0] = signature.as_ref().to_vec().into();
+
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let balance = client.balance(&recipient, Some(&asset_id)).await.unwrap();
+    assert_eq!(balance, 1_000_000);
+    let sponsor_balance = client.balance(&sponsor, None).await.unwrap();
+    assert!(sponsor_balance < 1_000_000);
+}
@@ -14,7 +14,11 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        MerkleProof,
+        TransactionDirection,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_types::{
@@ -26,8 +30,15 @@ use fuel_core_types::{
         },
     },
     fuel_asm::*,
-    fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_merkle,
+    fuel_tx::{
+        field::ReceiptsRoot,
+        *,
+    },
+    fuel_types::{
+        canonical::Deserialize,
+        ChainId,
+    },
     services::executor::ExecutionBlock,
     tai64::Tai64,
 };
@@ -65,6 +76,43 @@ fn basic_script_snapshot() {
     insta::assert_snapshot!(format!("{script:?}"));
 }
 
+#[tokio::test]
+async fn dry_run_simulates_overridden_block_height_and_time() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::bhei(0x10),
+        op::time(0x11, 0x10),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let next_week = Tai64(Tai64::now().0 + 7 * 24 * 60 * 60);
+
+    let receipts = client
+        .dry_run_at(&tx, None, Some(100u32.into()), Some(next_week))
+        .await
+        .unwrap();
+    assert!(matches!(receipts[0],
+        Receipt::Log {
+            ra, rb, ..
+        } if ra == 100 && rb == next_week.0));
+
+    // The height is too far ahead of the next block.
+    let result = client
+        .dry_run_at(&tx, None, Some(u32::MAX.into()), None)
+        .await;
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn dry_run_script() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -113,6 +161,65 @@ async fn dry_run_script() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_profile_script() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::aloc(0x10),
+        op::cfei(32),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let (receipts, profile) = client.dry_run_profile(&tx, None).await.unwrap();
+    assert_eq!(receipts, client.dry_run(&tx).await.unwrap());
+
+    let count = |opcode: &str| {
+        profile
+            .opcodes
+            .iter()
+            .find(|profile| profile.opcode == opcode)
+            .map(|profile| profile.count)
+    };
+    assert_eq!(count("ADDI"), Some(2));
+    assert_eq!(count("LOG"), Some(1));
+    assert_eq!(count("ALOC"), Some(1));
+    assert_eq!(count("CFEI"), Some(1));
+    assert_eq!(count("RET"), Some(1));
+
+    let gas_used = receipts
+        .iter()
+        .find_map(|receipt| match receipt {
+            Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+            _ => None,
+        })
+        .unwrap();
+    let opcodes_gas: u64 = profile.opcodes.iter().map(|profile| profile.gas).sum();
+    let stacks_gas: u64 = profile.stacks.iter().map(|profile| profile.gas).sum();
+    assert_eq!(opcodes_gas, gas_used);
+    assert_eq!(stacks_gas, gas_used);
+    assert!(profile
+        .stacks
+        .iter()
+        .all(|stack| stack.frames.len() == 2 && stack.frames[0] == "script"));
+
+    assert_eq!(profile.heap_high_water_mark, 0xca);
+    assert_eq!(profile.stack_high_water_mark, 32);
+}
+
 #[tokio::test]
 async fn dry_run_create() {
     let mut rng = StdRng::seed_from_u64(2322);
@@ -142,6 +249,72 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_bundle_executes_transactions_on_shared_state() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // The first transaction deploys the contract.
+    let salt: Salt = rng.gen();
+    let contract_code: Vec<u8> = op::ret(RegId::ONE).to_bytes().to_vec();
+    let contract = Contract::from(contract_code.clone());
+    let root = contract.root();
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &root, &state_root);
+    let create = TransactionBuilder::create(contract_code.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+
+    // The second transaction calls the contract deployed by the first one.
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let script_data = contract_id.iter().copied().chain([0u8; 16]).collect();
+    let call = TransactionBuilder::script(script, script_data)
+        .gas_limit(1_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize_as_transaction();
+
+    let (receipts, state_diff) = client
+        .dry_run_bundle(&[create.clone(), call.clone()], None)
+        .await
+        .unwrap();
+
+    assert_eq!(receipts.len(), 2);
+    assert!(receipts[0].is_empty());
+    assert!(receipts[1].iter().any(
+        |receipt| matches!(receipt, Receipt::Call { to, .. } if to == &contract_id)
+    ));
+    assert!(state_diff.iter().any(|change| {
+        change.column == "ContractsRawCode" && change.key == contract_id.to_vec()
+    }));
+
+    // ensure the transactions aren't available in the blockchain history
+    for tx in [create, call] {
+        let err = client
+            .transaction_status(&tx.id(&Default::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), NotFound);
+    }
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -180,6 +353,43 @@ async fn submit() {
     assert_eq!(tx.id(&ChainId::default()), ret_tx.id(&ChainId::default()));
 }
 
+#[tokio::test]
+async fn submit_private_is_included_by_the_local_producer() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let tx_id = client.submit_private(&tx).await.unwrap();
+    let status = client.await_transaction_commit(&tx_id).await.unwrap();
+
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn submit_private_is_rejected_without_the_block_production() {
+    let mut config = Config::local_node();
+    config.block_production = fuel_core_poa::Trigger::Never;
+    config.debug = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    let result = client.submit_private(&tx).await;
+
+    assert!(result.is_err());
+    assert!(srv
+        .shared
+        .txpool
+        .find_one(tx.id(&ChainId::default()))
+        .is_none());
+}
+
 #[ignore]
 #[tokio::test]
 async fn transaction_status_submitted() {
@@ -208,6 +418,93 @@ async fn receipts() {
     assert!(receipts.is_some());
 }
 
+#[tokio::test]
+async fn receipt_proof_verifies_against_block_history() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    // The block with the transaction is proven against the next block.
+    client.produce_blocks(1, None).await.unwrap();
+    let receipts = client.receipts(&id).await.unwrap().unwrap();
+
+    for (index, receipt) in receipts.iter().enumerate() {
+        let proof = client
+            .receipt_proof(&id, index as u32, None, Some(2u32.into()))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(&proof.receipt, receipt);
+
+        // 1. Verify the receipt against the receipts root of the transaction.
+        let tx = Transaction::from_bytes(&proof.raw_transaction).unwrap();
+        let receipts_root = match tx {
+            Transaction::Script(script) => *script.receipts_root(),
+            _ => panic!("Only the script transactions have the receipts root"),
+        };
+        assert!(verify_merkle(
+            &receipts_root,
+            &proof.raw_receipt,
+            &proof.receipt_proof,
+            proof.receipts_count,
+        ));
+
+        // 2. Verify the transaction against the transactions root of the block.
+        assert!(verify_merkle(
+            &proof.block_header.transactions_root,
+            &proof.raw_transaction,
+            &proof.transaction_proof,
+            proof.block_header.transactions_count,
+        ));
+
+        // 3. Verify the block against the history of the commit block.
+        assert!(verify_merkle(
+            &proof.commit_block_header.prev_root,
+            &proof.block_header.id,
+            &proof.block_proof,
+            proof.commit_block_header.height as u64,
+        ));
+    }
+
+    let missing = client
+        .receipt_proof(&id, receipts.len() as u32, None, Some(2u32.into()))
+        .await
+        .unwrap();
+    assert!(missing.is_none());
+}
+
+fn verify_merkle<D: AsRef<[u8]>>(
+    root: &Bytes32,
+    data: &D,
+    proof: &MerkleProof,
+    leaf_count: u64,
+) -> bool {
+    let set: Vec<_> = proof.proof_set.iter().map(|bytes| **bytes).collect();
+    fuel_merkle::binary::verify(root, data, &set, proof.proof_index, leaf_count)
+}
+
+#[tokio::test]
+async fn subscribe_transaction_status_ws_returns_final_status() {
+    use futures::StreamExt;
+
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    let statuses: Vec<_> = client
+        .subscribe_transaction_status_ws(&id)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(statuses.len(), 1);
+    assert!(matches!(statuses[0], Ok(TransactionStatus::Success { .. })));
+}
+
 #[tokio::test]
 async fn receipts_decoding() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -240,6 +537,56 @@ async fn get_transaction_by_id() {
     }
 }
 
+#[tokio::test]
+async fn transaction_status_history_is_archived() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+
+    let mut config = Config::local_node();
+    config.tx_status_retention = Some(std::time::Duration::from_secs(3600));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    // The archive records the statuses asynchronously.
+    let history = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+        loop {
+            let history = client.transaction_status_history(&id).await.unwrap();
+            if history.len() == 2 {
+                break history
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The archive should record the submission and the inclusion");
+
+    assert!(matches!(
+        history[0].status,
+        TransactionStatus::Submitted { .. }
+    ));
+    assert!(matches!(
+        history[1].status,
+        TransactionStatus::Success { .. }
+    ));
+    assert!(history[0].time <= history[1].time);
+}
+
+#[tokio::test]
+async fn zero_tx_status_retention_disables_archive() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+
+    let mut config = Config::local_node();
+    config.tx_status_retention = Some(std::time::Duration::ZERO);
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.submit_and_await_commit(&transaction).await.unwrap();
+
+    let history = client.transaction_status_history(&id).await.unwrap();
+    assert!(history.is_empty());
+}
+
 #[tokio::test]
 async fn get_transparent_transaction_by_id() {
     let transaction = Transaction::default_test_tx();
@@ -649,6 +996,53 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn get_owned_transactions_by_direction() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    let mut context = TestContext::new(100).await;
+    let tx1 = context.transfer(alice, charlie, 1).await.unwrap();
+    let tx2 = context.transfer(charlie, bob, 2).await.unwrap();
+    let tx3 = context.transfer(bob, charlie, 3).await.unwrap();
+
+    let client = context.client;
+    let page_request = PaginationRequest {
+        cursor: None,
+        results: 5,
+        direction: PageDirection::Forward,
+    };
+    let incoming_txs = client
+        .transactions_by_owner_with_direction(
+            &charlie,
+            TransactionDirection::Incoming,
+            page_request.clone(),
+        )
+        .await
+        .unwrap()
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ChainId::default()))
+        .collect_vec();
+
+    let outgoing_txs = client
+        .transactions_by_owner_with_direction(
+            &charlie,
+            TransactionDirection::Outgoing,
+            page_request.clone(),
+        )
+        .await
+        .unwrap()
+        .results
+        .iter()
+        .map(|tx| tx.transaction.id(&ChainId::default()))
+        .collect_vec();
+
+    assert_eq!(&incoming_txs, &[tx1, tx3]);
+    assert_eq!(&outgoing_txs, &[tx2]);
+}
+
 impl TestContext {
     async fn transfer(
         &mut self,
@@ -694,6 +1088,7 @@ fn get_executor_and_db() -> (Executor<MaybeRelayerAdapter>, Database) {
         relayer,
         database: db.clone(),
         config: Default::default(),
+        execution_stats: None,
     };
 
     (executor, db)